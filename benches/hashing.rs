@@ -0,0 +1,53 @@
+// ============================================================================
+// 10장: "기본 해셔 SipHash 는 DoS 방어 때문에 약간 느림 - 빠른 해셔가 필요하면 fnv"
+// ============================================================================
+// 같은 키를 HashMap (SipHash 1-3) 과 FnvHashMap 에 넣고 찾기 (cargo bench --bench hashing)
+// FNV 는 키가 짧을수록 유리 - 바이트마다 곱하기 한 번이라 긴 문자열에서는 차이가 줄거나 뒤집힘
+// 그래서 정수 키와 짧은 문자열 키를 함께 잼
+// FNV 는 해시 충돌 공격에 약함 - 외부 입력을 키로 받는 서버에서는 기본 해셔를 유지
+//
+// 그룹 이름(10_hashmaps)이 장_절 이름이라 cargo run -- bench-report 에서 레슨으로 이어짐
+// ============================================================================
+
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use fnv::FnvBuildHasher;
+
+const KEYS: usize = 10_000;
+
+// 넣고 나서 모두 찾기 - 해셔만 다르고 나머지는 같은 코드
+fn insert_and_lookup<K: Hash + Eq + Clone, S: BuildHasher + Default>(keys: &[K]) -> usize {
+    let mut map: HashMap<K, usize, S> = HashMap::with_capacity_and_hasher(keys.len(), S::default());
+    for (i, key) in keys.iter().enumerate() {
+        map.insert(key.clone(), i);
+    }
+    keys.iter().filter(|key| map.contains_key(*key)).count()
+}
+
+fn hashing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("10_hashmaps");
+
+    let numbers: Vec<u64> = (0..KEYS as u64).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15)).collect();
+    group.bench_with_input(BenchmarkId::new("siphash", "u64"), &numbers, |b, keys| {
+        b.iter(|| insert_and_lookup::<_, std::hash::RandomState>(black_box(keys)))
+    });
+    group.bench_with_input(BenchmarkId::new("fnv", "u64"), &numbers, |b, keys| {
+        b.iter(|| insert_and_lookup::<_, FnvBuildHasher>(black_box(keys)))
+    });
+
+    let words: Vec<String> = (0..KEYS).map(|i| format!("key{}", i)).collect();
+    group.bench_with_input(BenchmarkId::new("siphash", "short_str"), &words, |b, keys| {
+        b.iter(|| insert_and_lookup::<_, std::hash::RandomState>(black_box(keys)))
+    });
+    group.bench_with_input(BenchmarkId::new("fnv", "short_str"), &words, |b, keys| {
+        b.iter(|| insert_and_lookup::<_, FnvBuildHasher>(black_box(keys)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, hashing);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+// ============================================================================
+// 11장: "이터레이터는 제로 코스트 추상화 - 수동 루프와 동일한 성능"
+// ============================================================================
+// 같은 계산(짝수의 제곱 합)을 세 가지로 - 인덱스 루프, for 루프, 이터레이터 체인
+// 릴리스 빌드에서 셋이 같은 기계어가 되는지를 시간으로 확인 (cargo bench --bench iterators)
+// 인덱스 루프는 v[i] 마다 경계 검사가 있지만 최적화기가 대부분 없앰 - 이터레이터는 처음부터 없음
+// 포인터 루프, 내적, 숨은 비용까지 장 안의 측정기로 재는 것은 50장 (cargo run --release -- 50)
+//
+// 그룹 이름(11_iterator_adaptors)이 장_절 이름이라 cargo run -- bench-report 에서 레슨으로 이어짐
+// ============================================================================
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn index_loop(v: &[u64]) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < v.len() {
+        if v[i].is_multiple_of(2) {
+            sum += v[i] * v[i];
+        }
+        i += 1;
+    }
+    sum
+}
+
+fn for_loop(v: &[u64]) -> u64 {
+    let mut sum = 0;
+    for &x in v {
+        if x.is_multiple_of(2) {
+            sum += x * x;
+        }
+    }
+    sum
+}
+
+fn iterator_chain(v: &[u64]) -> u64 {
+    v.iter().filter(|&&x| x.is_multiple_of(2)).map(|&x| x * x).sum()
+}
+
+fn iterators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("11_iterator_adaptors");
+    for n in [1_000u64, 100_000] {
+        let data: Vec<u64> = (0..n).collect();
+        // 셋이 같은 답을 내야 비교가 의미 있음
+        assert_eq!(index_loop(&data), iterator_chain(&data));
+        assert_eq!(for_loop(&data), iterator_chain(&data));
+
+        group.bench_with_input(BenchmarkId::new("index_loop", n), &data, |b, data| {
+            b.iter(|| index_loop(black_box(data)))
+        });
+        group.bench_with_input(BenchmarkId::new("for_loop", n), &data, |b, data| {
+            b.iter(|| for_loop(black_box(data)))
+        });
+        group.bench_with_input(BenchmarkId::new("iterator_chain", n), &data, |b, data| {
+            b.iter(|| iterator_chain(black_box(data)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, iterators);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+// ============================================================================
+// 12장: "Rc::clone 은 얕은 복사 (카운트만 증가)"
+// ============================================================================
+// 1KB 데이터를 Rc, Arc 로 나눠 갖기와 통째로 복사하기 (cargo bench --bench rc_clone)
+//   Rc::clone  - 참조 카운트 +1 (일반 정수 연산), drop 때 -1
+//   Arc::clone - 같은 일을 원자적 연산으로 - 스레드 사이에 나눌 수 있는 대가
+//   Vec::clone - 힙 할당 + 1KB 복사 (C++ 의 복사 생성자)
+// C++ 의 shared_ptr 복사는 항상 원자적 연산 - Rust 는 한 스레드용(Rc)을 따로 둬서 그 비용을 고를 수 있음
+//
+// 그룹 이름(12_rc_pointer)이 장_절 이름이라 cargo run -- bench-report 에서 레슨으로 이어짐
+// ============================================================================
+
+use std::hint::black_box;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const BYTES: usize = 1024;
+
+fn rc_clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("12_rc_pointer");
+
+    // 복사본은 b.iter 안에서 바로 drop - 카운트 증가와 감소(또는 할당과 해제)를 함께 잼
+    let rc = Rc::new(vec![7u8; BYTES]);
+    group.bench_function("rc_clone", |b| b.iter(|| Rc::clone(black_box(&rc))));
+
+    let arc = Arc::new(vec![7u8; BYTES]);
+    group.bench_function("arc_clone", |b| b.iter(|| Arc::clone(black_box(&arc))));
+
+    let vec = vec![7u8; BYTES];
+    group.bench_function("vec_clone_1kb", |b| b.iter(|| black_box(&vec).clone()));
+
+    group.finish();
+}
+
+criterion_group!(benches, rc_clone);
+criterion_main!(benches);
@@ -0,0 +1,166 @@
+// content/ 의 퀴즈 파일, 참고 답안, 설명 글(prose/)을 include_str! 목록으로 만들어 바이너리에 내장
+// 배포한 바이너리와 브라우저(web/)는 content/ 없이 내장 콘텐츠를 씀 (content::ContentSource)
+// 파일을 추가/삭제하면 다시 생성됨 - 내용 변경은 include_str! 이 추적
+//
+// 44장의 C++ 라이브러리(cpp/ffi/)도 여기서 컴파일 - 성공하면 cfg(cpp_ffi) 를 켬
+//
+// src/_NN_<이름>.rs 장 모듈도 여기서 찾아 mod 선언과 all_chapters() 를 만듦 (main.rs 가 include!)
+// 새 장은 파일을 만들고 Lesson 으로 Chapter 를 구현하기만 하면 실행 목록에 들어감
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// dir 안에서 확장자가 맞는 파일 - 정렬해서 빌드 결과를 고정
+fn files(dir: &Path, extensions: &[&str]) -> Vec<PathBuf> {
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let mut files: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .map(|entry| entry.unwrap().path())
+            .filter(|p| {
+                p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| extensions.contains(&e))
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    files.sort();
+    files
+}
+
+// pub const <name>: &[(&str, &str)] = &[(key, include_str!(path)), ...];
+fn list(name: &str, files: &[PathBuf], key: impl Fn(&Path) -> String) -> String {
+    let mut out = format!("pub const {}: &[(&str, &str)] = &[\n", name);
+    for path in files {
+        let path = path.canonicalize().unwrap();
+        out.push_str(&format!(
+            "    ({:?}, include_str!({:?})),\n",
+            key(&path),
+            path.display().to_string()
+        ));
+    }
+    out.push_str("];\n");
+    out
+}
+
+// "_07_traits" - 밑줄, 두 자리 번호, 밑줄로 시작하는 모듈 이름
+fn chapter_module(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    let rest = stem.strip_prefix('_')?;
+    let (id, name) = rest.split_once('_')?;
+    let valid = id.len() == 2 && id.chars().all(|c| c.is_ascii_digit()) && !name.is_empty();
+    valid.then(|| stem.to_string())
+}
+
+// 장 모듈 선언 + 번호 순 목록 - 파일 이름이 번호로 시작하므로 정렬 순서가 곧 장 순서
+fn chapter_registry(files: &[PathBuf]) -> String {
+    let modules: Vec<(String, PathBuf)> = files
+        .iter()
+        .filter_map(|p| Some((chapter_module(p)?, p.canonicalize().unwrap())))
+        .collect();
+    let mut out = String::from("// build.rs 가 src/_NN_*.rs 에서 생성 - 손으로 고치지 말 것\n\n");
+    for (name, path) in &modules {
+        out.push_str(&format!("#[path = {:?}]\nmod {};\n", path.display().to_string(), name));
+    }
+    out.push_str("\n// 모든 장 (번호 순)\nfn all_chapters() -> Vec<Box<dyn Chapter>> {\n    vec![\n");
+    for (name, _) in &modules {
+        out.push_str(&format!("        Box::new({}::Lesson),\n", name));
+    }
+    out.push_str("    ]\n}\n");
+    out
+}
+
+// #[cxx::bridge] 연결 코드와 cpp/ffi/*.cc 를 정적 라이브러리 하나로 (cxx-build 는 cc 크레이트의 Build 를 돌려줌)
+// cpp-ffi 기능이 꺼져 있거나 wasm32 대상이면 건너뜀, 컴파일에 실패하면 경고만 - 44장은 안내만 출력
+// 이 스크립트는 src/ 의 어느 파일이 바뀌어도 다시 돌므로(새 장 파일을 찾으려고) 브리지와 C++ 파일이
+// 지난번 컴파일과 같으면 OUT_DIR 의 라이브러리를 그대로 링크 - 컴파일은 이 파일들이 바뀔 때만
+fn compile_cpp_ffi() {
+    println!("cargo:rustc-check-cfg=cfg(cpp_ffi)");
+    let wasm = env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("wasm32");
+    if wasm || env::var_os("CARGO_FEATURE_CPP_FFI").is_none() {
+        return;
+    }
+    // 상대 경로 - 생성된 헤더 이름이 "rust-study/src/_44_cpp_ffi.rs.h" 가 되도록 (build.rs 는 패키지 폴더에서 실행)
+    let bridge = "src/_44_cpp_ffi.rs";
+    let sources = ["cpp/ffi/study_ffi.h", "cpp/ffi/study_ffi.cc"];
+    let target = env::var("TARGET").unwrap();
+    let mut stamp = format!("{}\n", target);
+    for path in [bridge].iter().chain(&sources) {
+        println!("cargo:rerun-if-changed={}", path);
+        stamp.push_str(&fs::read_to_string(path).unwrap_or_default());
+    }
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let stamp_path = out_dir.join("study_ffi.stamp");
+    let library = out_dir.join(if target.contains("msvc") { "study_ffi.lib" } else { "libstudy_ffi.a" });
+    let unchanged = library.exists() && fs::read_to_string(&stamp_path).is_ok_and(|old| old == stamp);
+
+    if !unchanged {
+        let _ = fs::remove_file(&stamp_path);
+        // cxx-build 는 브리지의 #[cfg(...)] 를 CARGO_CFG_* 환경 변수로 판단 - 컴파일에 성공하면 켤 cfg(cpp_ffi) 를 미리 알려 줌
+        env::set_var("CARGO_CFG_CPP_FFI", "");
+        // 링크 지시는 아래에서 직접 - 다시 컴파일하지 않은 실행에서도 같은 것을 내보내도록
+        let built = cxx_build::bridge(bridge)
+            .file("cpp/ffi/study_ffi.cc")
+            .std("c++17")
+            .cargo_warnings(false)
+            .cargo_metadata(false)
+            .try_compile("study_ffi");
+        if let Err(e) = built {
+            println!("cargo:warning=44장 C++ 라이브러리를 건너뜀 (C++ 컴파일러가 없으면 --no-default-features): {}", e);
+            return;
+        }
+        fs::write(&stamp_path, &stamp).unwrap();
+    }
+
+    println!("cargo:rustc-link-search=native={}", out_dir.display());
+    println!("cargo:rustc-link-lib=static=study_ffi");
+    // C++ 표준 라이브러리 - cc 크레이트가 대상마다 고르는 것과 같은 이름
+    let cpp_stdlib = if target.contains("msvc") {
+        None
+    } else if target.contains("android") {
+        Some("c++_shared")
+    } else if target.contains("apple") || target.contains("freebsd") || target.contains("openbsd") {
+        Some("c++")
+    } else {
+        Some("stdc++")
+    };
+    if let Some(lib) = cpp_stdlib {
+        println!("cargo:rustc-link-lib={}", lib);
+    }
+    println!("cargo:rustc-cfg=cpp_ffi");
+}
+
+fn main() {
+    compile_cpp_ffi();
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("content");
+    let content = files(&dir, &["toml", "json"]);
+    let solutions = files(&dir.join("solutions"), &["rs"]);
+    let prose = files(&dir.join("prose"), &["md"]);
+
+    let name = |p: &Path| p.file_name().unwrap().to_string_lossy().into_owned();
+    let stem = |p: &Path| p.file_stem().unwrap().to_string_lossy().into_owned();
+    let mut out = list("CONTENT", &content, name);
+    out.push_str(&list("SOLUTIONS", &solutions, stem));
+    out.push_str(&list("PROSE", &prose, name));
+    fs::write(
+        Path::new(&env::var("OUT_DIR").unwrap()).join("embedded_content.rs"),
+        out,
+    )
+    .unwrap();
+
+    // 레슨 출력의 영어 문자열 표 (src/i18n.rs)
+    fs::write(
+        Path::new(&env::var("OUT_DIR").unwrap()).join("translations.rs"),
+        list("EN", &files(&dir.join("i18n").join("en"), &["toml"]), stem),
+    )
+    .unwrap();
+
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    fs::write(
+        Path::new(&env::var("OUT_DIR").unwrap()).join("chapter_registry.rs"),
+        chapter_registry(&files(&src, &["rs"])),
+    )
+    .unwrap();
+}
@@ -0,0 +1,79 @@
+// 자동 생성 파일 - 직접 고치지 말고 capi/src/lib.rs 를 고친 뒤 cargo xtask capi-header
+
+#ifndef STUDY_CAPI_H
+#define STUDY_CAPI_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// 함수의 결과 - 0 이 성공
+typedef enum StudyStatus {
+  STUDY_STATUS_OK = 0,
+  // 핸들이나 문자열 인자가 NULL
+  STUDY_STATUS_NULL_POINTER = 1,
+  // 문자열이 UTF-8 이 아님
+  STUDY_STATUS_INVALID_UTF8 = 2,
+  // 세지 않은 단어
+  STUDY_STATUS_NOT_FOUND = 3,
+  // 라이브러리 안의 panic - 버그이므로 보고해 주세요
+  STUDY_STATUS_PANIC = 4,
+} StudyStatus;
+
+// 단어 수를 세는 객체 - C 쪽에서는 크기를 모르는 불투명 타입 (포인터로만 다룸)
+typedef struct StudyTally StudyTally;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// 라이브러리 버전 ("0.1.0") - 정적 문자열이므로 해제하지 마세요
+const char *study_capi_version(void);
+
+// 상태 코드의 설명 - 정적 문자열이므로 해제하지 마세요, 모르는 값이면 "unknown status"
+// enum StudyStatus 가 아니라 int 로 받음 - C 는 어떤 정수든 넘길 수 있음
+const char *study_status_message(int status);
+
+// 새 객체 - 다 쓰면 study_tally_free 로 해제
+struct StudyTally *study_tally_new(void);
+
+// study_tally_new 가 만든 객체 해제 - NULL 이면 아무것도 안 함, 두 번 부르면 안 됨
+//
+// # Safety
+// tally 는 NULL 이거나 study_tally_new 가 돌려준, 아직 해제하지 않은 포인터
+void study_tally_free(struct StudyTally *tally);
+
+// text 를 공백으로 나눠 단어마다 1 씩 (대소문자 구분 없음)
+//
+// # Safety
+// tally 는 살아 있는 객체, text 는 NUL 종료 문자열 (호출 동안만 읽고 보관하지 않음)
+enum StudyStatus study_tally_add(struct StudyTally *tally,
+                                 const char *text);
+
+// word 가 나온 횟수를 *out 에 - 세지 않은 단어면 STUDY_STATUS_NOT_FOUND (*out 은 그대로)
+//
+// # Safety
+// tally 는 살아 있는 객체, word 는 NUL 종료 문자열, out 은 NULL 이거나 쓸 수 있는 정수 하나
+enum StudyStatus study_tally_count(const struct StudyTally *tally,
+                                   const char *word,
+                                   uint32_t *out);
+
+// 가장 많이 나온 단어 - 새로 할당한 문자열이므로 study_capi_string_free 로 해제, 비어 있으면 NULL
+//
+// # Safety
+// tally 는 NULL 이거나 살아 있는 객체
+char *study_tally_top(const struct StudyTally *tally);
+
+// 이 라이브러리가 돌려준 문자열 해제 - free() 가 아니라 이 함수로 (할당자가 다를 수 있음)
+//
+// # Safety
+// s 는 NULL 이거나 study_tally_top 이 돌려준, 아직 해제하지 않은 포인터
+void study_capi_string_free(char *s);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* STUDY_CAPI_H */
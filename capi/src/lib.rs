@@ -0,0 +1,239 @@
+// ============================================================================
+// Rust 를 C 라이브러리로 (45장)
+// ============================================================================
+// 단어 수를 세는 작은 라이브러리를 C ABI 로 내보냄 - C/C++ 는 include/study_capi.h 만 보고 씀
+// 빌드: cargo build -p rust-study-capi --release
+//   → target/release/libstudy_capi.so (.dylib, .dll) 와 libstudy_capi.a
+// 헤더는 cargo xtask capi-header 가 cbindgen 으로 생성해 커밋 - 여기의 /// 주석이 헤더의 주석이 됨
+//
+// 경계의 규칙:
+//   #[no_mangle] extern "C"  이름 장식 없이 C 호출 규약으로
+//   불투명 핸들              Box::into_raw 로 넘기고 study_tally_free 에서 Box::from_raw
+//   에러 코드                Result 대신 StudyStatus, 값은 출력 포인터로
+//   문자열                   들어오는 것은 &CStr 로 빌림, 나가는 것은 CString::into_raw → study_capi_string_free
+//   panic                    경계를 넘으면 프로세스 중단 - catch_unwind 로 잡아 STUDY_STATUS_PANIC
+// ============================================================================
+
+use std::collections::HashMap;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{self, UnwindSafe};
+use std::ptr;
+
+/// 함수의 결과 - 0 이 성공
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StudyStatus {
+    Ok = 0,
+    /// 핸들이나 문자열 인자가 NULL
+    NullPointer = 1,
+    /// 문자열이 UTF-8 이 아님
+    InvalidUtf8 = 2,
+    /// 세지 않은 단어
+    NotFound = 3,
+    /// 라이브러리 안의 panic - 버그이므로 보고해 주세요
+    Panic = 4,
+}
+
+impl StudyStatus {
+    const ALL: [StudyStatus; 5] = [
+        StudyStatus::Ok,
+        StudyStatus::NullPointer,
+        StudyStatus::InvalidUtf8,
+        StudyStatus::NotFound,
+        StudyStatus::Panic,
+    ];
+
+    // C 가 넘긴 정수 → 상태 - 없는 값이면 None (그 값을 enum 으로 받는 것 자체가 UB)
+    fn from_raw(raw: c_int) -> Option<StudyStatus> {
+        StudyStatus::ALL.into_iter().find(|&status| status as c_int == raw)
+    }
+}
+
+/// 45장이 출력하고 검사하는 헤더 - 샌드박스처럼 저장소 밖에서도 같은 내용
+/// cbindgen:ignore
+pub const HEADER: &str = include_str!("../include/study_capi.h");
+
+/// 단어 수를 세는 객체 - C 쪽에서는 크기를 모르는 불투명 타입 (포인터로만 다룸)
+pub struct StudyTally {
+    words: HashMap<String, u32>,
+}
+
+impl StudyTally {
+    fn add(&mut self, text: &str) {
+        for word in text.split_whitespace() {
+            *self.words.entry(word.to_lowercase()).or_insert(0) += 1;
+        }
+    }
+
+    // 가장 많이 나온 단어 - 같으면 사전 순으로 앞선 것 (HashMap 순서와 상관없이 같은 답)
+    fn top(&self) -> Option<(&str, u32)> {
+        self.words
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(word, &count)| (word.as_str(), count))
+    }
+}
+
+// panic 이 C 로 넘어가지 않게 - extern "C" 함수에서 panic 이 빠져나가면 Rust 가 프로세스를 중단
+fn guard(f: impl FnOnce() -> StudyStatus + UnwindSafe) -> StudyStatus {
+    panic::catch_unwind(f).unwrap_or(StudyStatus::Panic)
+}
+
+// C 문자열 인자 → &str (NULL 과 UTF-8 검사)
+// SAFETY: 호출하는 쪽은 s 가 NULL 이거나 호출 동안 유효한 NUL 종료 문자열임을 보장
+unsafe fn text_arg<'a>(s: *const c_char) -> Result<&'a str, StudyStatus> {
+    if s.is_null() {
+        return Err(StudyStatus::NullPointer);
+    }
+    CStr::from_ptr(s).to_str().map_err(|_| StudyStatus::InvalidUtf8)
+}
+
+/// 라이브러리 버전 ("0.1.0") - 정적 문자열이므로 해제하지 마세요
+#[no_mangle]
+pub extern "C" fn study_capi_version() -> *const c_char {
+    c"0.1.0".as_ptr()
+}
+
+/// 상태 코드의 설명 - 정적 문자열이므로 해제하지 마세요, 모르는 값이면 "unknown status"
+/// enum StudyStatus 가 아니라 int 로 받음 - C 는 어떤 정수든 넘길 수 있음
+#[no_mangle]
+pub extern "C" fn study_status_message(status: c_int) -> *const c_char {
+    let message = match StudyStatus::from_raw(status) {
+        Some(StudyStatus::Ok) => c"ok",
+        Some(StudyStatus::NullPointer) => c"null pointer argument",
+        Some(StudyStatus::InvalidUtf8) => c"string is not valid UTF-8",
+        Some(StudyStatus::NotFound) => c"word not found",
+        Some(StudyStatus::Panic) => c"internal panic",
+        None => c"unknown status",
+    };
+    message.as_ptr()
+}
+
+/// 새 객체 - 다 쓰면 study_tally_free 로 해제
+#[no_mangle]
+pub extern "C" fn study_tally_new() -> *mut StudyTally {
+    Box::into_raw(Box::new(StudyTally { words: HashMap::new() }))
+}
+
+/// study_tally_new 가 만든 객체 해제 - NULL 이면 아무것도 안 함, 두 번 부르면 안 됨
+///
+/// # Safety
+/// tally 는 NULL 이거나 study_tally_new 가 돌려준, 아직 해제하지 않은 포인터
+#[no_mangle]
+pub unsafe extern "C" fn study_tally_free(tally: *mut StudyTally) {
+    if !tally.is_null() {
+        drop(Box::from_raw(tally));
+    }
+}
+
+/// text 를 공백으로 나눠 단어마다 1 씩 (대소문자 구분 없음)
+///
+/// # Safety
+/// tally 는 살아 있는 객체, text 는 NUL 종료 문자열 (호출 동안만 읽고 보관하지 않음)
+#[no_mangle]
+pub unsafe extern "C" fn study_tally_add(tally: *mut StudyTally, text: *const c_char) -> StudyStatus {
+    let Some(tally) = tally.as_mut() else {
+        return StudyStatus::NullPointer;
+    };
+    let text = match text_arg(text) {
+        Ok(text) => text,
+        Err(status) => return status,
+    };
+    // &mut 를 넘기므로 AssertUnwindSafe - panic 이면 tally 가 일부만 갱신됐을 수 있음
+    guard(panic::AssertUnwindSafe(|| {
+        tally.add(text);
+        StudyStatus::Ok
+    }))
+}
+
+/// word 가 나온 횟수를 *out 에 - 세지 않은 단어면 STUDY_STATUS_NOT_FOUND (*out 은 그대로)
+///
+/// # Safety
+/// tally 는 살아 있는 객체, word 는 NUL 종료 문자열, out 은 NULL 이거나 쓸 수 있는 정수 하나
+#[no_mangle]
+pub unsafe extern "C" fn study_tally_count(
+    tally: *const StudyTally,
+    word: *const c_char,
+    out: *mut u32,
+) -> StudyStatus {
+    let (Some(tally), Some(out)) = (tally.as_ref(), out.as_mut()) else {
+        return StudyStatus::NullPointer;
+    };
+    let word = match text_arg(word) {
+        Ok(word) => word,
+        Err(status) => return status,
+    };
+    match tally.words.get(&word.to_lowercase()) {
+        Some(&count) => {
+            *out = count;
+            StudyStatus::Ok
+        }
+        None => StudyStatus::NotFound,
+    }
+}
+
+/// 가장 많이 나온 단어 - 새로 할당한 문자열이므로 study_capi_string_free 로 해제, 비어 있으면 NULL
+///
+/// # Safety
+/// tally 는 NULL 이거나 살아 있는 객체
+#[no_mangle]
+pub unsafe extern "C" fn study_tally_top(tally: *const StudyTally) -> *mut c_char {
+    match tally.as_ref().and_then(StudyTally::top) {
+        // 단어는 공백으로 나눈 조각이라 NUL 이 없음 - 혹시 있으면 NULL
+        Some((word, _)) => CString::new(word).map_or(ptr::null_mut(), CString::into_raw),
+        None => ptr::null_mut(),
+    }
+}
+
+/// 이 라이브러리가 돌려준 문자열 해제 - free() 가 아니라 이 함수로 (할당자가 다를 수 있음)
+///
+/// # Safety
+/// s 는 NULL 이거나 study_tally_top 이 돌려준, 아직 해제하지 않은 포인터
+#[no_mangle]
+pub unsafe extern "C" fn study_capi_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_like_a_c_caller() {
+        unsafe {
+            let tally = study_tally_new();
+            assert_eq!(study_tally_add(tally, c"the cat and THE dog".as_ptr()), StudyStatus::Ok);
+
+            let mut n = 0;
+            assert_eq!(study_tally_count(tally, c"The".as_ptr(), &mut n), StudyStatus::Ok);
+            assert_eq!(n, 2);
+            assert_eq!(study_tally_count(tally, c"bird".as_ptr(), &mut n), StudyStatus::NotFound);
+            assert_eq!(study_tally_add(tally, ptr::null()), StudyStatus::NullPointer);
+            assert_eq!(study_tally_add(tally, c"\xff".as_ptr()), StudyStatus::InvalidUtf8);
+
+            let top = study_tally_top(tally);
+            assert_eq!(CStr::from_ptr(top), c"the");
+            study_capi_string_free(top);
+            study_tally_free(tally);
+            study_tally_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn unknown_status_values_get_a_message() {
+        let message = |raw| unsafe { CStr::from_ptr(study_status_message(raw)) };
+        assert_eq!(message(StudyStatus::NotFound as c_int), c"word not found");
+        assert_eq!(message(StudyStatus::Panic as c_int), c"internal panic");
+        for raw in [-1, 5, 99, c_int::MAX] {
+            assert_eq!(message(raw), c"unknown status");
+        }
+    }
+
+    #[test]
+    fn panics_become_status_codes() {
+        // 기본 panic 훅이 메시지를 출력하지만 테스트는 통과
+        assert_eq!(guard(|| panic!("bug")), StudyStatus::Panic);
+    }
+}
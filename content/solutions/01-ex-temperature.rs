@@ -0,0 +1,11 @@
+// 01-ex-temperature 참고 답안
+
+fn c_to_f(c: f64) -> f64 {
+    c * 9.0 / 5.0 + 32.0
+}
+
+fn main() {
+    for c in [-40.0, 0.0, 100.0] {
+        println!("{}°C = {}°F", c, c_to_f(c));
+    }
+}
@@ -0,0 +1,14 @@
+// 02-ex-take-and-give 참고 답안
+
+// s 의 소유권을 받아서 고친 뒤 다시 돌려줌
+fn shout(mut s: String) -> String {
+    s.push('!');
+    s
+}
+
+fn main() {
+    let hello = String::from("hello");
+    let loud = shout(hello);
+    // println!("{}", hello); // 에러! hello 는 shout 로 이동됨
+    println!("{}", loud);
+}
@@ -0,0 +1,17 @@
+// 03-ex-first-word 참고 답안
+
+fn first_word(s: &str) -> &str {
+    match s.find(' ') {
+        Some(i) => &s[..i],
+        None => s,
+    }
+}
+
+fn main() {
+    let mut text = String::from("hello world");
+    let word = first_word(&text);
+    // text.clear(); // 에러! word 가 text 를 빌리고 있는 동안 가변 빌림 불가
+    println!("{}", word);
+    text.clear();
+    println!("비운 뒤 길이: {}", text.len());
+}
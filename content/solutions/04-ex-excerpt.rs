@@ -0,0 +1,19 @@
+// 04-ex-excerpt 참고 답안
+
+// 본문(&'a str)보다 오래 살 수 없음
+struct Excerpt<'a> {
+    part: &'a str,
+}
+
+fn main() {
+    let excerpt;
+    {
+        let novel = String::from("Call me Ishmael. Some years ago...");
+        let first = novel.split('.').next().unwrap_or("");
+        let e = Excerpt { part: first };
+        println!("{}", e.part);
+        excerpt = String::from(e.part);
+        // excerpt = e; // 에러! novel 이 이 블록 끝에서 해제되는데 e 가 빌리고 있음
+    }
+    println!("복사해 둔 첫 문장: {}", excerpt);
+}
@@ -0,0 +1,35 @@
+// 05-ex-rectangle 참고 답안
+
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    fn square(size: u32) -> Self {
+        Rectangle {
+            width: size,
+            height: size,
+        }
+    }
+
+    fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+}
+
+fn main() {
+    let big = Rectangle {
+        width: 30,
+        height: 50,
+    };
+    let small = Rectangle::square(10);
+    println!("{:?} 넓이 {}", big, big.area());
+    println!("큰 것이 작은 것을 담을 수 있나: {}", big.can_hold(&small));
+    println!("작은 것이 큰 것을 담을 수 있나: {}", small.can_hold(&big));
+}
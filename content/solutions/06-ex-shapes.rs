@@ -0,0 +1,32 @@
+// 06-ex-shapes 참고 답안
+
+enum Shape {
+    Circle { r: f64 },
+    Rect { w: f64, h: f64 },
+    Triangle(f64, f64, f64),
+}
+
+impl Shape {
+    fn area(&self) -> f64 {
+        match self {
+            Shape::Circle { r } => std::f64::consts::PI * r * r,
+            Shape::Rect { w, h } => w * h,
+            // 헤론의 공식
+            Shape::Triangle(a, b, c) => {
+                let s = (a + b + c) / 2.0;
+                (s * (s - a) * (s - b) * (s - c)).sqrt()
+            }
+        }
+    }
+}
+
+fn main() {
+    let shapes = [
+        Shape::Circle { r: 1.0 },
+        Shape::Rect { w: 2.0, h: 3.0 },
+        Shape::Triangle(3.0, 4.0, 5.0),
+    ];
+    for shape in &shapes {
+        println!("{:.2}", shape.area());
+    }
+}
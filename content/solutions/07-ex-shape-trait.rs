@@ -0,0 +1,37 @@
+// 07-ex-shape-trait 참고 답안
+
+trait Shape {
+    fn area(&self) -> f64;
+
+    fn describe(&self) -> String {
+        format!("넓이 {:.2} 인 도형", self.area())
+    }
+}
+
+struct Circle(f64);
+struct Square(f64);
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.0 * self.0
+    }
+}
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn describe(&self) -> String {
+        format!("한 변이 {} 인 정사각형", self.0)
+    }
+}
+
+fn main() {
+    let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Circle(1.0)), Box::new(Square(2.0))];
+    for shape in &shapes {
+        println!("{}", shape.describe());
+    }
+    let total: f64 = shapes.iter().map(|s| s.area()).sum();
+    println!("총 넓이: {:.2}", total);
+}
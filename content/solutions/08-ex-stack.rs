@@ -0,0 +1,48 @@
+// 08-ex-stack 참고 답안
+
+use std::fmt::Display;
+
+struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+// T: Display 일 때만 있는 메서드
+impl<T: Display> Stack<T> {
+    fn print_all(&self) {
+        for item in self.items.iter().rev() {
+            println!("{}", item);
+        }
+    }
+}
+
+fn main() {
+    let mut stack = Stack::new();
+    stack.push(1);
+    stack.push(2);
+    stack.push(3);
+    println!("peek: {:?}, len: {}", stack.peek(), stack.len());
+    println!("pop: {:?}", stack.pop());
+    stack.print_all();
+}
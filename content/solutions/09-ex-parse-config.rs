@@ -0,0 +1,60 @@
+// 09-ex-parse-config 참고 답안
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+enum ConfigError {
+    Io(io::Error),
+    Format { line: usize },
+    Number(ParseIntError),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "읽기 실패: {}", e),
+            ConfigError::Format { line } => write!(f, "{}번째 줄: key=value 형식이 아님", line),
+            ConfigError::Number(e) => write!(f, "숫자가 아님: {}", e),
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<ParseIntError> for ConfigError {
+    fn from(e: ParseIntError) -> Self {
+        ConfigError::Number(e)
+    }
+}
+
+fn parse(text: &str) -> Result<HashMap<String, i64>, ConfigError> {
+    let mut map = HashMap::new();
+    for (i, line) in text.lines().enumerate() {
+        let (key, value) = line
+            .split_once('=')
+            .ok_or(ConfigError::Format { line: i + 1 })?;
+        map.insert(key.trim().to_string(), value.trim().parse()?);
+    }
+    Ok(map)
+}
+
+fn load(path: &str) -> Result<HashMap<String, i64>, ConfigError> {
+    let text = std::fs::read_to_string(path)?;
+    parse(&text)
+}
+
+fn main() {
+    println!("{:?}", parse("port=8080\nworkers=4"));
+    for result in [parse("port"), parse("port=abc"), load("/없는/파일")] {
+        if let Err(e) = result {
+            println!("{}", e);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+// 10-ex-word-count 참고 답안
+
+use std::collections::HashMap;
+
+fn main() {
+    let text = "the quick brown fox jumps over the lazy dog the fox";
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+
+    // 많이 나온 순, 같으면 단어 순
+    let mut sorted: Vec<(&str, usize)> = counts.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    for (word, count) in sorted.iter().take(3) {
+        println!("{}: {}", word, count);
+    }
+}
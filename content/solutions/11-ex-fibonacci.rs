@@ -0,0 +1,31 @@
+// 11-ex-fibonacci 참고 답안
+
+struct Fibonacci {
+    current: u64,
+    next: u64,
+}
+
+impl Fibonacci {
+    fn new() -> Self {
+        Fibonacci { current: 0, next: 1 }
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let value = self.current;
+        self.current = self.next;
+        self.next += value;
+        Some(value)
+    }
+}
+
+fn main() {
+    let sum: u64 = Fibonacci::new()
+        .take_while(|&n| n < 1000)
+        .filter(|n| n % 2 == 0)
+        .sum();
+    println!("1000 미만 짝수 피보나치 수의 합: {}", sum);
+}
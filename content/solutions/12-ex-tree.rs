@@ -0,0 +1,45 @@
+// 12-ex-tree 참고 답안
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+struct Node {
+    value: i32,
+    parent: RefCell<Weak<Node>>,
+    children: RefCell<Vec<Rc<Node>>>,
+}
+
+fn node(value: i32) -> Rc<Node> {
+    Rc::new(Node {
+        value,
+        parent: RefCell::new(Weak::new()),
+        children: RefCell::new(Vec::new()),
+    })
+}
+
+fn counts(name: &str, n: &Rc<Node>) {
+    println!(
+        "{} = {}: strong {}, weak {}",
+        name,
+        n.value,
+        Rc::strong_count(n),
+        Rc::weak_count(n)
+    );
+}
+
+fn main() {
+    let leaf = node(3);
+    counts("leaf", &leaf);
+    {
+        let branch = node(5);
+        branch.children.borrow_mut().push(Rc::clone(&leaf));
+        *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
+        counts("branch", &branch); // weak 1 - leaf 의 부모 참조
+        counts("leaf", &leaf); // strong 2 - branch 의 자식
+        let parent = leaf.parent.borrow().upgrade().map(|p| p.value);
+        println!("leaf 의 부모: {:?}", parent);
+    }
+    // branch 가 해제되어 부모 참조는 끊어짐 - 순환 참조 없음
+    println!("leaf 의 부모: {:?}", leaf.parent.borrow().upgrade().map(|p| p.value));
+    counts("leaf", &leaf);
+}
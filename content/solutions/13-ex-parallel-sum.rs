@@ -0,0 +1,25 @@
+// 13-ex-parallel-sum 참고 답안
+
+use std::sync::mpsc;
+use std::thread;
+
+fn main() {
+    const N: u64 = 1_000_000;
+    const THREADS: u64 = 4;
+    let (tx, rx) = mpsc::channel();
+
+    for i in 0..THREADS {
+        let tx = tx.clone();
+        let start = i * N / THREADS + 1;
+        let end = (i + 1) * N / THREADS;
+        thread::spawn(move || {
+            let partial: u64 = (start..=end).sum();
+            tx.send(partial).unwrap();
+        });
+    }
+    // 원래 송신자를 버려야 모든 스레드가 끝났을 때 rx 반복이 끝남
+    drop(tx);
+
+    let total: u64 = rx.iter().sum();
+    println!("합: {} (공식: {})", total, N * (N + 1) / 2);
+}
@@ -0,0 +1,16 @@
+// 15-ex-max 참고 답안
+
+macro_rules! max {
+    ($x:expr) => { $x };
+    ($x:expr, $($rest:expr),+) => {{
+        let a = $x;
+        let b = max!($($rest),+);
+        if a > b { a } else { b }
+    }};
+}
+
+fn main() {
+    println!("{}", max!(1));
+    println!("{}", max!(1, 5, 3));
+    println!("{}", max!(2.5, -1.0, 9.75, 3.0));
+}
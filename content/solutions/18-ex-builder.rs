@@ -0,0 +1,57 @@
+// 18-ex-builder 참고 답안
+
+#[derive(Debug)]
+struct HttpRequest {
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+}
+
+#[derive(Default)]
+struct HttpRequestBuilder {
+    method: Option<String>,
+    url: Option<String>,
+    headers: Vec<(String, String)>,
+}
+
+impl HttpRequestBuilder {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn method(mut self, method: &str) -> Self {
+        self.method = Some(method.to_string());
+        self
+    }
+
+    fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    fn header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    // 필수 필드(method, url)가 빠지면 Err
+    fn build(self) -> Result<HttpRequest, String> {
+        Ok(HttpRequest {
+            method: self.method.ok_or("method 가 없습니다")?,
+            url: self.url.ok_or("url 이 없습니다")?,
+            headers: self.headers,
+        })
+    }
+}
+
+fn main() {
+    let request = HttpRequestBuilder::new()
+        .method("GET")
+        .url("https://example.com")
+        .header("Accept", "text/html")
+        .build();
+    if let Ok(r) = &request {
+        println!("{} {} (헤더 {}개)", r.method, r.url, r.headers.len());
+    }
+    println!("{:?}", HttpRequestBuilder::new().method("POST").build());
+}
@@ -0,0 +1,18 @@
+// 02::move_semantics 의 C++ 대응 코드
+// Rust: let s2 = s1;  →  C++: 기본은 복사, 이동하려면 std::move
+#include <iostream>
+#include <string>
+#include <utility>
+
+int main() {
+    std::cout << "\n--- 이동 시맨틱스 ---\n";
+    int x = 5;
+    int y = x;  // 복사 - Rust 의 Copy 타입과 같음
+    std::cout << "x = " << x << ", y = " << y << "\n";
+
+    std::string s1 = "hello";
+    std::string s2 = std::move(s1);  // 명시적 이동
+    std::cout << "s2 = " << s2 << "\n";
+    // s1 은 "유효하지만 지정되지 않은 상태" - 써도 컴파일 에러가 나지 않음
+    // Rust 에서는 이동된 s1 을 쓰면 컴파일 에러
+}
@@ -0,0 +1,37 @@
+// 07::trait_objects 의 C++ 대응 코드
+// Rust: Vec<Box<dyn Summary>>  →  C++: std::vector<std::unique_ptr<Summary>> + 가상 함수
+#include <iostream>
+#include <memory>
+#include <string>
+#include <vector>
+
+struct Summary {
+    virtual ~Summary() = default;
+    virtual std::string summarize() const = 0;
+};
+
+struct NewsArticle : Summary {
+    std::string headline, location, author, content;
+    NewsArticle(std::string h, std::string l, std::string a, std::string c)
+        : headline(std::move(h)), location(std::move(l)), author(std::move(a)), content(std::move(c)) {}
+    std::string summarize() const override {
+        return headline + ", by " + author + " (" + location + ")";
+    }
+};
+
+struct Tweet : Summary {
+    std::string username, content;
+    bool reply = false, retweet = false;
+    Tweet(std::string u, std::string c) : username(std::move(u)), content(std::move(c)) {}
+    std::string summarize() const override { return username + ": " + content; }
+};
+
+int main() {
+    std::cout << "\n--- 트레이트 객체 ---\n";
+    std::vector<std::unique_ptr<Summary>> items;
+    items.push_back(std::make_unique<NewsArticle>("제목", "위치", "저자", "내용"));
+    items.push_back(std::make_unique<Tweet>("user", "내용"));
+    for (const auto& item : items) {
+        std::cout << "항목: " << item->summarize() << "\n";
+    }
+}
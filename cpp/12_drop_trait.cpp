@@ -0,0 +1,28 @@
+// 12::drop_trait 의 C++ 대응 코드
+// Rust: impl Drop  →  C++: 소멸자. 해제 순서(선언 역순)도 같음
+#include <iostream>
+#include <optional>
+#include <string>
+
+struct CustomSmartPointer {
+    std::string data;
+    explicit CustomSmartPointer(std::string d) : data(std::move(d)) {}
+    ~CustomSmartPointer() { std::cout << "CustomSmartPointer 해제: " << data << "\n"; }
+};
+
+int main() {
+    std::cout << "\n--- Drop 트레이트 ---\n";
+    {
+        CustomSmartPointer c("my stuff");
+        CustomSmartPointer d("other stuff");
+        std::cout << "CustomSmartPointers 생성됨\n";
+    }  // d 먼저, 그 다음 c
+    std::cout << "스코프 종료 후\n";
+
+    // Rust 의 drop(c) 같은 조기 해제는 C++ 에서 optional::reset 등으로 흉내
+    std::optional<CustomSmartPointer> c;
+    c.emplace("조기 해제");
+    std::cout << "조기 해제 전\n";
+    c.reset();
+    std::cout << "조기 해제 후\n";
+}
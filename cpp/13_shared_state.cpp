@@ -0,0 +1,32 @@
+// 13::shared_state 의 C++ 대응 코드
+// Rust: Arc<Mutex<i32>>  →  C++: 공유되는 int + 따로 있는 std::mutex
+// Rust 는 데이터를 Mutex 안에 넣어 잠그지 않고는 접근할 수 없게 함
+#include <iostream>
+#include <mutex>
+#include <thread>
+#include <vector>
+
+int main() {
+    std::cout << "\n--- 공유 상태 ---\n";
+    std::mutex m;
+    int value = 5;
+    {
+        std::lock_guard<std::mutex> guard(m);
+        value = 6;
+        std::cout << "Mutex 값: " << value << "\n";
+    }  // lock_guard 소멸 시 unlock
+    std::cout << "스코프 후: " << value << "\n";
+
+    int counter = 0;
+    std::vector<std::thread> handles;
+    for (int i = 0; i < 10; ++i) {
+        handles.emplace_back([&] {
+            std::lock_guard<std::mutex> guard(m);
+            counter += 1;  // 잠그지 않고 써도 컴파일됨 - 데이터 경쟁은 실행 시에야 드러남
+        });
+    }
+    for (auto& h : handles) {
+        h.join();
+    }
+    std::cout << "최종 카운터: " << counter << "\n";
+}
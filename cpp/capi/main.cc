@@ -0,0 +1,65 @@
+// 45장 - Rust 로 만든 study_capi 를 C++ 에서 사용
+// 헤더(capi/include/study_capi.h)는 cbindgen 이 생성, 구현은 capi/src/lib.rs
+//
+// 빌드 (저장소 루트에서):
+//   cargo build -p rust-study-capi --release
+//   c++ -std=c++17 cpp/capi/main.cc -Icapi/include -Ltarget/release -lstudy_capi -o /tmp/capi_demo
+//   LD_LIBRARY_PATH=target/release /tmp/capi_demo        (macOS 는 DYLD_LIBRARY_PATH)
+// 정적 링크는 target/release/libstudy_capi.a 를 직접 - 리눅스는 -lpthread -ldl 도 함께
+
+#include <cstdio>
+#include <memory>
+#include <stdexcept>
+#include <string>
+
+#include "study_capi.h"
+
+namespace {
+
+// 불투명 핸들을 unique_ptr 로 - 해제 함수를 deleter 로 (Rust 쪽의 Drop 과 같은 역할)
+struct TallyDeleter {
+    void operator()(StudyTally* t) const { study_tally_free(t); }
+};
+using Tally = std::unique_ptr<StudyTally, TallyDeleter>;
+
+// 라이브러리가 할당한 문자열은 free() 가 아니라 study_capi_string_free 로
+struct StringDeleter {
+    void operator()(char* s) const { study_capi_string_free(s); }
+};
+using RustString = std::unique_ptr<char, StringDeleter>;
+
+// 에러 코드 → 예외 (C++ 쪽 관례로 바꾸는 것은 C++ 쪽 래퍼의 일)
+void check(StudyStatus status) {
+    if (status != STUDY_STATUS_OK) {
+        throw std::runtime_error(study_status_message(status));
+    }
+}
+
+}  // namespace
+
+int main() {
+    std::printf("study_capi %s\n", study_capi_version());
+
+    Tally tally(study_tally_new());
+    check(study_tally_add(tally.get(), "the quick brown fox jumps over the lazy dog"));
+    check(study_tally_add(tally.get(), "The end"));
+
+    uint32_t n = 0;
+    check(study_tally_count(tally.get(), "the", &n));
+    std::printf("the: %u\n", n);
+
+    RustString top(study_tally_top(tally.get()));
+    std::printf("top: %s\n", top ? top.get() : "(empty)");
+
+    try {
+        check(study_tally_count(tally.get(), "cat", &n));
+    } catch (const std::runtime_error& e) {
+        std::printf("cat: %s\n", e.what());
+    }
+    try {
+        check(study_tally_add(tally.get(), "\xff"));
+    } catch (const std::runtime_error& e) {
+        std::printf("\\xff: %s\n", e.what());
+    }
+    return 0;
+}
@@ -0,0 +1,129 @@
+// 44장 - study_ffi.h 의 구현 (C++17)
+#include "rust-study/cpp/ffi/study_ffi.h"
+
+#include <atomic>
+#include <cstring>
+#include <stdexcept>
+
+// #[cxx::bridge] 가 생성한 헤더 - 공유 구조체 study::Item 의 정의
+#include "rust-study/src/_44_cpp_ffi.rs.h"
+
+namespace {
+std::atomic<int32_t> live{0};
+}
+
+// ----------------------------------------------------------------------------
+// extern "C"
+// ----------------------------------------------------------------------------
+
+struct StudyCounter {
+    int32_t value;
+};
+
+extern "C" {
+
+uint32_t study_checksum(const uint8_t* data, size_t len) {
+    // FNV-1a 32비트
+    uint32_t hash = 2166136261u;
+    for (size_t i = 0; i < len; ++i) {
+        hash ^= data[i];
+        hash *= 16777619u;
+    }
+    return hash;
+}
+
+char* study_greeting(const char* name) {
+    std::string text = std::string("안녕, ") + name + "!";
+    char* out = new char[text.size() + 1];
+    std::memcpy(out, text.c_str(), text.size() + 1);
+    return out;
+}
+
+void study_string_free(char* s) {
+    delete[] s;
+}
+
+StudyCounter* study_counter_new(int32_t start) {
+    ++live;
+    return new StudyCounter{start};
+}
+
+void study_counter_add(StudyCounter* counter, int32_t n) {
+    counter->value += n;
+}
+
+int32_t study_counter_get(const StudyCounter* counter) {
+    return counter->value;
+}
+
+int32_t study_counter_divide(StudyCounter* counter, int32_t by) {
+    if (by == 0) {
+        return -1;
+    }
+    counter->value /= by;
+    return 0;
+}
+
+void study_counter_free(StudyCounter* counter) {
+    if (counter != nullptr) {
+        --live;
+    }
+    delete counter;
+}
+
+int32_t study_live_objects() {
+    return live.load();
+}
+
+}  // extern "C"
+
+// ----------------------------------------------------------------------------
+// cxx
+// ----------------------------------------------------------------------------
+
+namespace study {
+
+Inventory::Inventory() {
+    ++live;
+}
+
+Inventory::~Inventory() {
+    --live;
+}
+
+void Inventory::add(rust::Str name, uint32_t count) {
+    stock_[std::string(name)] += count;
+}
+
+uint32_t Inventory::count(rust::Str name) const {
+    auto it = stock_.find(std::string(name));
+    return it == stock_.end() ? 0 : it->second;
+}
+
+uint32_t Inventory::take(rust::Str name, uint32_t count) {
+    auto it = stock_.find(std::string(name));
+    uint32_t have = it == stock_.end() ? 0 : it->second;
+    if (count > have) {
+        throw std::out_of_range(std::string(name) + " 재고 " + std::to_string(have) + "개, 요청 " +
+                                std::to_string(count) + "개");
+    }
+    if (it == stock_.end()) {
+        return 0;
+    }
+    it->second -= count;
+    return it->second;
+}
+
+rust::Vec<Item> Inventory::items() const {
+    rust::Vec<Item> out;
+    for (const auto& [name, count] : stock_) {
+        out.push_back(Item{rust::String(name), count});
+    }
+    return out;
+}
+
+std::unique_ptr<Inventory> new_inventory() {
+    return std::make_unique<Inventory>();
+}
+
+}  // namespace study
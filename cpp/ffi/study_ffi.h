@@ -0,0 +1,64 @@
+// 44장 - Rust 에서 부르는 작은 C++ 라이브러리
+// build.rs 가 cxx-build(안에서 cc 크레이트)로 study_ffi.cc 와 함께 정적 라이브러리로 컴파일
+//
+// 두 가지 경계:
+//   extern "C" 함수 - C ABI 만 씀 (포인터, 정수). Rust 쪽 선언은 src/_44_cpp_ffi.rs 의 mod raw
+//   namespace study  - #[cxx::bridge] 가 양쪽 연결 코드를 생성 (rust::Str, rust::Vec, unique_ptr)
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+#include <map>
+#include <memory>
+#include <string>
+
+#include "rust/cxx.h"
+
+extern "C" {
+
+// 빌림 - data 는 호출하는 동안만 읽고 보관하지 않음
+uint32_t study_checksum(const uint8_t* data, size_t len);
+
+// C++ 가 new[] 로 할당한 문자열 - 받은 쪽이 study_string_free 로 돌려줘야 함
+char* study_greeting(const char* name);
+void study_string_free(char* s);
+
+// 불투명 핸들 - Rust 는 크기도 멤버도 모르고 포인터만 들고 있음
+struct StudyCounter;
+StudyCounter* study_counter_new(int32_t start);
+void study_counter_add(StudyCounter* counter, int32_t n);
+int32_t study_counter_get(const StudyCounter* counter);
+// 예외는 extern "C" 경계를 넘으면 안 됨 - 0 성공, -1 은 0 으로 나누기
+int32_t study_counter_divide(StudyCounter* counter, int32_t by);
+void study_counter_free(StudyCounter* counter);
+
+// 살아 있는 C++ 객체 수 (StudyCounter + Inventory) - 해제가 제대로 되는지 확인용
+int32_t study_live_objects();
+
+}  // extern "C"
+
+namespace study {
+
+// Rust 쪽 #[cxx::bridge] 에 정의된 공유 구조체 - 생성된 헤더에서 완성됨
+struct Item;
+
+class Inventory {
+public:
+    Inventory();
+    ~Inventory();
+    Inventory(const Inventory&) = delete;
+    Inventory& operator=(const Inventory&) = delete;
+
+    void add(rust::Str name, uint32_t count);
+    uint32_t count(rust::Str name) const;
+    // 재고보다 많이 꺼내면 std::out_of_range - cxx 가 Rust 의 Err 로 바꿈
+    uint32_t take(rust::Str name, uint32_t count);
+    rust::Vec<Item> items() const;
+
+private:
+    std::map<std::string, uint32_t> stock_;
+};
+
+std::unique_ptr<Inventory> new_inventory();
+
+}  // namespace study
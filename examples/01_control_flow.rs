@@ -0,0 +1,75 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 01. 기본 문법 - 변수, 타입, 함수 - 제어 흐름
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 제어 흐름
+// ----------------------------------------------------------------------------
+fn control_flow() {
+    println!("\n--- 제어 흐름 ---");
+
+    let number = 6;
+
+    // if 문 - 조건에 괄호 불필요 (C++과 다름)
+    // C++: if (number < 5) { ... }
+    if number < 5 {
+        println!("5보다 작음");
+    } else if number > 5 {
+        println!("5보다 큼");
+    } else {
+        println!("5와 같음");
+    }
+
+    // if는 표현식! (C++의 삼항 연산자와 유사하지만 더 강력)
+    // C++: int result = (number > 5) ? 1 : 0;
+    let result = if number > 5 { "크다" } else { "작거나 같다" };
+    println!("결과: {}", result);
+
+    // loop - 무한 루프 (C++: while(true))
+    let mut counter = 0;
+    let result = loop {
+        counter += 1;
+        if counter == 10 {
+            break counter * 2;  // 값을 반환하며 탈출!
+        }
+    };
+    println!("loop 결과: {}", result);  // 20
+
+    // while
+    let mut n = 3;
+    while n != 0 {
+        println!("{}!", n);
+        n -= 1;  // n-- 는 Rust에 없음!
+    }
+
+    // for - C++11 range-based for와 유사
+    // C++: for (const auto& elem : arr) { ... }
+    let arr = [10, 20, 30, 40, 50];
+    for element in arr {
+        println!("값: {}", element);
+    }
+
+    // 범위 반복
+    // C++: for (int i = 0; i < 5; i++) { ... }
+    for i in 0..5 {  // 0, 1, 2, 3, 4 (5 미포함)
+        print!("{} ", i);
+    }
+    println!();
+
+    // 포함 범위
+    for i in 0..=5 {  // 0, 1, 2, 3, 4, 5 (5 포함)
+        print!("{} ", i);
+    }
+    println!();
+
+    // 역순 반복
+    for i in (1..4).rev() {
+        println!("카운트다운: {}!", i);
+    }
+}
+
+fn main() {
+    control_flow();
+}
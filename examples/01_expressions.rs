@@ -0,0 +1,35 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 01. 기본 문법 - 변수, 타입, 함수 - 표현식 vs 문장
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 표현식 vs 문장
+// ----------------------------------------------------------------------------
+fn expressions() {
+    println!("\n--- 표현식 ---");
+
+    // Rust에서 거의 모든 것은 표현식
+    // 블록 {}도 표현식이고, 마지막 표현식의 값을 반환
+
+    let y = {
+        let x = 3;
+        x + 1  // 세미콜론 없음 = 이 블록의 반환값
+    };
+    println!("블록 표현식 결과: {}", y);  // 4
+
+    // match도 표현식 (C++의 switch보다 강력)
+    let number = 13;
+    let description = match number {
+        1 => "하나",
+        2 | 3 | 5 | 7 | 11 | 13 => "소수",  // 여러 패턴
+        13..=19 => "십대",                   // 범위 패턴
+        _ => "기타",                         // default
+    };
+    println!("{} 는 {}", number, description);
+}
+
+fn main() {
+    expressions();
+}
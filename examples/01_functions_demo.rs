@@ -0,0 +1,51 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 01. 기본 문법 - 변수, 타입, 함수 - 함수
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 함수
+// ----------------------------------------------------------------------------
+fn functions_demo() {
+    println!("\n--- 함수 ---");
+
+    // 기본 함수 호출
+    let sum = add(5, 3);
+    println!("5 + 3 = {}", sum);
+
+    // 표현식 반환
+    let doubled = double(21);
+    println!("21 * 2 = {}", doubled);
+
+    // 여러 값 반환 (튜플 사용)
+    // C++: std::tuple<int, int> 또는 구조체 반환
+    let (quot, rem) = divide(17, 5);
+    println!("17 / 5 = {} 나머지 {}", quot, rem);
+}
+
+// C++: int add(int a, int b) { return a + b; }
+fn add(a: i32, b: i32) -> i32 {
+    return a + b;  // return 사용 가능
+}
+
+// 마지막 표현식이 반환값 (세미콜론 없음!)
+fn double(x: i32) -> i32 {
+    x * 2  // return 없이, 세미콜론도 없음
+    // x * 2; 라고 쓰면 ()를 반환하게 되어 컴파일 에러!
+}
+
+fn divide(dividend: i32, divisor: i32) -> (i32, i32) {
+    (dividend / divisor, dividend % divisor)
+}
+
+// 반환값이 없는 함수 - C++의 void
+// 실제로는 유닛 타입 ()를 반환
+fn _no_return() {
+    println!("반환값 없음");
+    // -> () 가 생략된 것
+}
+
+fn main() {
+    functions_demo();
+}
@@ -0,0 +1,85 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 01. 기본 문법 - 변수, 타입, 함수 - 기본 타입
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn double(x: i32) -> i32 {
+    x * 2  // return 없이, 세미콜론도 없음
+    // x * 2; 라고 쓰면 ()를 반환하게 되어 컴파일 에러!
+}
+
+// ----------------------------------------------------------------------------
+// 기본 타입
+// ----------------------------------------------------------------------------
+fn types() {
+    println!("\n--- 기본 타입 ---");
+
+    // 정수 타입 - C++보다 명확한 크기 지정
+    // C++: int, long, long long 등은 플랫폼마다 크기가 다름
+    // Rust: 크기가 이름에 명시됨
+    let _i8_val: i8 = -128;                    // C++: int8_t
+    let _i16_val: i16 = -32768;                // C++: int16_t
+    let _i32_val: i32 = -2147483648;           // C++: int32_t
+    let _i64_val: i64 = -9223372036854775808;  // C++: int64_t
+    let _i128_val: i128 = -1;                  // C++에는 없음!
+
+    let _u8_val: u8 = 255;                     // C++: uint8_t
+    let _u16_val: u16 = 65535;                 // C++: uint16_t
+    let _u32_val: u32 = 4294967295;            // C++: uint32_t
+    let _u64_val: u64 = 18446744073709551615;  // C++: uint64_t
+    let _u128_val: u128 = 1;                   // C++에는 없음!
+
+    // isize, usize - 포인터 크기와 동일 (32비트에서 32비트, 64비트에서 64비트)
+    // C++: size_t, ptrdiff_t와 유사
+    let _size: usize = 100;
+    let _ptr_diff: isize = -50;
+
+    // 부동소수점
+    let _f32_val: f32 = 3.14;   // C++: float
+    let _f64_val: f64 = 3.14;   // C++: double (기본값)
+    let _default_float = 3.14; // f64가 기본 (C++과 동일하게 double)
+
+    // 불리언
+    let _bool_val: bool = true;  // C++: bool
+
+    // 문자 - C++과 다르게 4바이트 (유니코드 스칼라 값)
+    // C++: char는 1바이트, wchar_t는 플랫폼 의존적
+    let _char_val: char = '가';  // 한글도 하나의 char에 저장 가능
+    let _emoji: char = '🦀';     // 이모지도 가능!
+    println!("Rust char 크기: {} 바이트", std::mem::size_of::<char>());
+
+    // 튜플 - C++: std::tuple
+    // C++: auto tuple = std::make_tuple(500, 6.4, true);
+    let tuple: (i32, f64, bool) = (500, 6.4, true);
+
+    // 구조 분해 (C++17 structured binding과 유사)
+    // C++: auto [x, y, z] = tuple;
+    let (a, b, c) = tuple;
+    println!("튜플 분해: {}, {}, {}", a, b, c);
+
+    // 인덱스 접근
+    // C++: std::get<0>(tuple)
+    println!("튜플 첫 번째 요소: {}", tuple.0);
+
+    // 배열 - 고정 크기, 스택에 할당
+    // C++: std::array<i32, 5> arr = {1, 2, 3, 4, 5};
+    let arr: [i32; 5] = [1, 2, 3, 4, 5];
+    println!("배열 첫 번째: {}", arr[0]);
+
+    // 같은 값으로 초기화
+    // C++에는 직접적인 대응이 없음 (fill 사용해야 함)
+    let zeros = [0; 10];  // [0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    println!("0으로 채운 배열 길이: {}", zeros.len());
+
+    // 슬라이스 - 배열의 일부를 참조
+    // C++20: std::span과 유사
+    let slice: &[i32] = &arr[1..4];  // [2, 3, 4]
+    println!("슬라이스: {:?}", slice);
+}
+
+fn main() {
+    types();
+}
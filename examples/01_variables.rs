@@ -0,0 +1,44 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 01. 기본 문법 - 변수, 타입, 함수 - 변수 선언
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 변수 선언
+// ----------------------------------------------------------------------------
+fn variables() {
+    println!("--- 변수 선언 ---");
+
+    // C++: int x = 5;           // 기본적으로 가변
+    // C++: const int x = 5;     // 불변으로 만들려면 const 필요
+
+    // Rust: 기본적으로 불변
+    let x = 5;
+    // x = 6;  // 컴파일 에러! cannot assign twice to immutable variable
+
+    // Rust: 가변으로 만들려면 mut 키워드 필요
+    let mut y = 5;
+    println!("y 변경 전: {}", y);
+    y = 6;  // OK
+    println!("y 변경 후: {}", y);
+
+    // 섀도잉(Shadowing) - C++에는 없는 개념
+    // 같은 이름으로 새 변수를 선언하면 이전 변수를 가림
+    let x = x + 1;  // 새로운 x가 이전 x를 가림
+    let x = x * 2;  // 또 다시 가림
+    println!("섀도잉된 x: {}", x);  // 12
+
+    // 섀도잉으로 타입도 변경 가능
+    let spaces = "   ";        // &str 타입
+    let spaces = spaces.len(); // usize 타입으로 변경
+    println!("공백 개수: {}", spaces);
+
+    // C++에서는 이렇게 해야 함:
+    // std::string spaces_str = "   ";
+    // size_t spaces = spaces_str.length();  // 다른 이름 필요
+}
+
+fn main() {
+    variables();
+}
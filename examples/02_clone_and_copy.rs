@@ -0,0 +1,47 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 02. 소유권 (Ownership) - Clone과 Copy
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// Clone과 Copy
+// ----------------------------------------------------------------------------
+fn clone_and_copy() {
+    println!("\n--- Clone과 Copy ---");
+
+    // 깊은 복사가 필요하면 clone() 명시적 호출
+    let s1 = String::from("hello");
+    let s2 = s1.clone();  // 힙 데이터까지 복사
+
+    println!("s1 = {}, s2 = {}", s1, s2);  // 둘 다 유효!
+
+    // C++: std::string s2 = s1;  // 암묵적 깊은 복사
+    // Rust는 비용이 큰 작업을 명시적으로 만듦
+
+    // Copy 트레이트 - 스택에만 있는 타입들
+    // 이 타입들은 이동 대신 복사됨:
+    // - 모든 정수 타입 (i32, u64 등)
+    // - 불리언 (bool)
+    // - 부동소수점 (f32, f64)
+    // - 문자 (char)
+    // - 튜플 (모든 요소가 Copy인 경우)
+
+    let a: i32 = 5;
+    let b = a;  // 복사됨
+    println!("a = {}, b = {}", a, b);  // 둘 다 OK
+
+    // Copy 타입인 튜플
+    let point = (3, 4);
+    let another_point = point;  // 복사
+    println!("point = {:?}, another = {:?}", point, another_point);
+
+    // Copy가 아닌 타입을 포함한 튜플은 이동됨
+    let mixed = (String::from("hello"), 5);
+    let _moved = mixed;
+    // println!("{:?}", mixed);  // 에러! mixed는 이동됨
+}
+
+fn main() {
+    clone_and_copy();
+}
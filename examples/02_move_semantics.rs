@@ -0,0 +1,54 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 02. 소유권 (Ownership) - 이동 시맨틱스 (Move Semantics)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 이동 시맨틱스 (Move Semantics)
+// ----------------------------------------------------------------------------
+fn move_semantics() {
+    println!("\n--- 이동 시맨틱스 ---");
+
+    // 스택에 저장되는 기본 타입은 복사됨
+    let x = 5;
+    let y = x;  // 값이 복사됨
+    println!("x = {}, y = {}", x, y);  // 둘 다 사용 가능
+
+    // 힙에 저장되는 String은 이동됨!
+    let s1 = String::from("hello");
+    let s2 = s1;  // s1의 소유권이 s2로 이동 (move)
+
+    // println!("s1 = {}", s1);  // 컴파일 에러! s1은 더 이상 유효하지 않음
+    // error[E0382]: borrow of moved value: `s1`
+
+    println!("s2 = {}", s2);  // OK
+
+    // C++과의 비교:
+    // C++: std::string s1 = "hello";
+    //      std::string s2 = s1;  // 복사! (깊은 복사)
+    //      std::string s3 = std::move(s1);  // 이동 (명시적)
+    //      // s1은 여전히 접근 가능하지만 "유효하지만 불특정" 상태
+
+    // Rust에서는 이동이 기본이고, 이동 후 원본 사용이 컴파일 에러
+    // 이것이 더 안전함 - "use after move" 버그를 원천 차단
+
+    // 왜 이동이 기본인가?
+    // String의 내부 구조:
+    // ┌──────────┬─────────┬─────────┐
+    // │   ptr    │   len   │   cap   │  <- 스택 (24바이트)
+    // └────┬─────┴─────────┴─────────┘
+    //      │
+    //      v
+    // ┌────┬────┬────┬────┬────┐
+    // │ h  │ e  │ l  │ l  │ o  │       <- 힙
+    // └────┴────┴────┴────┴────┘
+
+    // 만약 s1과 s2가 같은 힙 데이터를 가리키면?
+    // 둘 다 스코프를 벗어날 때 같은 메모리를 해제하려 함 = double free!
+    // Rust는 이동으로 이 문제를 해결
+}
+
+fn main() {
+    move_semantics();
+}
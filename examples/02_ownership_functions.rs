@@ -0,0 +1,72 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 02. 소유권 (Ownership) - 함수와 소유권
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 함수와 소유권
+// ----------------------------------------------------------------------------
+fn ownership_functions() {
+    println!("\n--- 함수와 소유권 ---");
+
+    // 함수에 값을 전달하면 소유권이 이동됨
+    let s = String::from("hello");
+    takes_ownership(s);
+    // println!("{}", s);  // 에러! s의 소유권은 함수로 이동됨
+
+    let x = 5;
+    makes_copy(x);
+    println!("x는 여전히 사용 가능: {}", x);  // OK, i32는 Copy
+
+    // 함수가 값을 반환하면 소유권이 호출자에게 이동
+    let s1 = gives_ownership();
+    println!("받은 소유권: {}", s1);
+
+    let s2 = String::from("hello");
+    let s3 = takes_and_gives_back(s2);
+    // println!("{}", s2);  // 에러! s2는 이동됨
+    println!("돌려받은 소유권: {}", s3);
+
+    // C++에서의 유사한 패턴:
+    // void takes_ownership(std::unique_ptr<std::string> s) { ... }
+    // std::unique_ptr<std::string> ptr = std::make_unique<std::string>("hello");
+    // takes_ownership(std::move(ptr));  // 명시적 move 필요
+    // // ptr은 이제 nullptr
+
+    println!("\n--- 소유권 주고받기 패턴 ---");
+
+    // 매번 소유권을 주고받는 것은 번거로움
+    // 해결책: 참조(borrowing) - 다음 챕터에서 다룸
+    let s4 = String::from("hello");
+    let (s5, len) = calculate_length_awkward(s4);
+    println!("'{}'의 길이: {}", s5, len);
+
+    // 더 좋은 방법은 참조를 사용하는 것 (03_borrowing.rs에서 다룸)
+}
+
+fn takes_ownership(some_string: String) {
+    println!("소유권을 받음: {}", some_string);
+}  // some_string이 스코프를 벗어나고 drop 호출
+
+fn makes_copy(some_integer: i32) {
+    println!("복사본을 받음: {}", some_integer);
+}  // some_integer가 스코프를 벗어나지만, 특별한 일은 없음
+
+fn gives_ownership() -> String {
+    let some_string = String::from("yours");
+    some_string  // 반환되면서 호출자에게 소유권 이동
+}
+
+fn takes_and_gives_back(a_string: String) -> String {
+    a_string  // 받은 것을 그대로 반환, 소유권 이동
+}
+
+fn calculate_length_awkward(s: String) -> (String, usize) {
+    let length = s.len();
+    (s, length)  // 소유권을 돌려주기 위해 튜플로 반환 (번거로움!)
+}
+
+fn main() {
+    ownership_functions();
+}
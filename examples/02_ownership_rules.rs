@@ -0,0 +1,33 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 02. 소유권 (Ownership) - 소유권 규칙
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 소유권 규칙
+// ----------------------------------------------------------------------------
+fn ownership_rules() {
+    println!("--- 소유권 규칙 ---");
+
+    // Rust의 세 가지 소유권 규칙:
+    // 1. 각 값은 해당 값의 소유자(owner)라고 불리는 변수를 가진다
+    // 2. 한 번에 하나의 소유자만 존재할 수 있다
+    // 3. 소유자가 스코프를 벗어나면, 값은 버려진다(dropped)
+
+    {
+        // s는 여기서 유효하지 않음 (아직 선언 안됨)
+        let s = String::from("hello");  // s가 이 시점부터 유효
+        println!("s = {}", s);
+        // s를 가지고 작업 수행
+    }  // 스코프 종료, s의 drop이 호출됨 (C++의 소멸자와 유사)
+
+    // C++에서의 RAII와 동일한 개념:
+    // {
+    //     std::string s = "hello";
+    // }  // s의 소멸자 호출
+}
+
+fn main() {
+    ownership_rules();
+}
@@ -0,0 +1,42 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 03. 빌림 (Borrowing)과 참조 (References) - 댕글링 참조 방지
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 댕글링 참조 방지
+// ----------------------------------------------------------------------------
+fn dangling_references() {
+    println!("\n--- 댕글링 참조 방지 ---");
+
+    // Rust는 댕글링 참조를 컴파일 타임에 방지
+
+    // 이 함수는 컴파일되지 않음:
+    // fn dangle() -> &String {
+    //     let s = String::from("hello");
+    //     &s  // s는 함수 끝에서 drop됨
+    //        // 반환되는 참조는 해제된 메모리를 가리킴!
+    // }
+    // error[E0106]: missing lifetime specifier
+
+    // 해결책: 소유권을 반환
+    let s = no_dangle();
+    println!("안전하게 반환: {}", s);
+
+    // C++에서 흔한 버그:
+    // const std::string& dangle() {
+    //     std::string s = "hello";
+    //     return s;  // 경고는 나오지만 컴파일됨!
+    // }
+    // // 호출 시 정의되지 않은 동작
+}
+
+fn no_dangle() -> String {
+    let s = String::from("hello");
+    s  // 소유권 이동, 안전함!
+}
+
+fn main() {
+    dangling_references();
+}
@@ -0,0 +1,48 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 03. 빌림 (Borrowing)과 참조 (References) - 가변 참조
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 가변 참조
+// ----------------------------------------------------------------------------
+fn mutable_references() {
+    println!("\n--- 가변 참조 ---");
+
+    let mut s = String::from("hello");
+
+    // 가변 참조로 값을 수정할 수 있음
+    // C++: std::string& ref = s; (비const 참조)
+    change(&mut s);
+
+    println!("변경 후: {}", s);
+
+    // 가변 참조의 핵심 규칙:
+    // 특정 스코프에서 특정 데이터에 대한 가변 참조는 하나만 가능!
+
+    let mut data = String::from("hello");
+
+    let r1 = &mut data;
+    // let r2 = &mut data;  // 컴파일 에러!
+    // error[E0499]: cannot borrow `data` as mutable more than once
+
+    println!("r1: {}", r1);
+    // r1의 사용이 끝난 후에는 새로운 가변 참조 가능
+    let r2 = &mut data;
+    println!("r2: {}", r2);
+
+    // 이 규칙이 데이터 레이스를 방지:
+    // - 두 개 이상의 포인터가 동시에 같은 데이터에 접근
+    // - 적어도 하나가 쓰기 작업
+    // - 동기화 없음
+    // Rust는 컴파일 타임에 이를 방지!
+}
+
+fn change(s: &mut String) {
+    s.push_str(", world");
+}
+
+fn main() {
+    mutable_references();
+}
@@ -0,0 +1,46 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 03. 빌림 (Borrowing)과 참조 (References) - 참조 규칙 상세
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 참조 규칙 상세
+// ----------------------------------------------------------------------------
+fn reference_rules() {
+    println!("\n--- 참조 규칙 ---");
+
+    let mut s = String::from("hello");
+
+    // 규칙: 불변 참조 여러 개 OR 가변 참조 하나
+    // 불변 참조 여러 개는 OK (모두 읽기만 하니까)
+    let r1 = &s;
+    let r2 = &s;
+    println!("r1: {}, r2: {}", r1, r2);
+    // r1, r2의 마지막 사용 지점 이후...
+
+    // 이제 가변 참조 가능 (NLL - Non-Lexical Lifetimes)
+    let r3 = &mut s;
+    println!("r3: {}", r3);
+
+    // 불변 참조와 가변 참조 동시 사용 불가
+    let mut data = String::from("hello");
+    let r_immut = &data;
+    // let r_mut = &mut data;  // 에러! 불변 참조가 아직 사용 중
+    println!("불변 참조: {}", r_immut);
+    // r_immut 사용 끝
+    let r_mut = &mut data;  // 이제 OK
+    println!("가변 참조: {}", r_mut);
+
+    // C++에서는 이런 버그가 런타임에 발생할 수 있음:
+    // std::vector<int> v = {1, 2, 3};
+    // int& ref = v[0];
+    // v.push_back(4);  // 재할당 가능성
+    // ref = 10;        // 댕글링 참조! 정의되지 않은 동작
+
+    // Rust에서는 컴파일 에러로 방지됨
+}
+
+fn main() {
+    reference_rules();
+}
@@ -0,0 +1,48 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 03. 빌림 (Borrowing)과 참조 (References) - 참조 기초
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 참조 기초
+// ----------------------------------------------------------------------------
+fn references_intro() {
+    println!("--- 참조 기초 ---");
+
+    let s1 = String::from("hello");
+
+    // & 연산자로 참조 생성 - 소유권을 넘기지 않고 빌려줌
+    // C++: const std::string& ref = s1;
+    let len = calculate_length(&s1);
+
+    // s1은 여전히 유효! 소유권이 이동하지 않았음
+    println!("'{}'의 길이: {}", s1, len);
+
+    // 참조는 소유하지 않으므로 drop되지 않음
+    // 참조가 가리키는 값은 참조가 사라져도 유지됨
+
+    // 참조 역참조 (dereference)
+    let x = 5;
+    let r = &x;
+
+    println!("x = {}", x);
+    println!("r = {}", r);      // 자동 역참조
+    println!("*r = {}", *r);    // 명시적 역참조
+
+    // C++ 참조 vs Rust 참조:
+    // C++: int& r = x;      // 참조, 재할당 불가
+    // C++: int* p = &x;     // 포인터, null 가능
+    // Rust: let r = &x;     // 참조, null 불가능, 재할당 가능
+    // Rust: let r: &i32;    // 초기화 없이 선언 불가 (C++과 달리)
+}
+
+fn calculate_length(s: &String) -> usize {
+    // s는 String에 대한 참조
+    // s를 통해 값을 읽을 수 있지만 수정할 수 없음
+    s.len()
+}  // s가 스코프를 벗어나지만, 소유권이 없으므로 drop 안 됨
+
+fn main() {
+    references_intro();
+}
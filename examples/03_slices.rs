@@ -0,0 +1,67 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 03. 빌림 (Borrowing)과 참조 (References) - 슬라이스 (Slice)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 슬라이스 (Slice)
+// ----------------------------------------------------------------------------
+fn slices() {
+    println!("\n--- 슬라이스 ---");
+
+    // 슬라이스는 컬렉션의 일부를 참조
+    // C++20: std::span과 유사
+
+    let s = String::from("hello world");
+
+    // 문자열 슬라이스 &str
+    let hello: &str = &s[0..5];   // "hello"
+    let world: &str = &s[6..11];  // "world"
+    println!("{} {}", hello, world);
+
+    // 범위 문법
+    let s = String::from("hello");
+    let slice1 = &s[0..2];    // "he"
+    let slice2 = &s[..2];     // "he" (0 생략)
+    let slice3 = &s[3..];     // "lo" (끝까지)
+    let slice4 = &s[..];      // "hello" (전체)
+    println!("{}, {}, {}, {}", slice1, slice2, slice3, slice4);
+
+    // 문자열 리터럴은 슬라이스!
+    let s: &str = "Hello, world!";  // 바이너리에 저장된 문자열을 가리킴
+    println!("리터럴: {}", s);
+
+    // 슬라이스의 장점 - 원본과 동기화
+    let mut s = String::from("hello world");
+
+    let word = first_word(&s);
+    println!("첫 단어: {}", word);
+
+    // s.clear();  // 에러! 불변 참조(word)가 있는 동안 가변 작업 불가
+    // error[E0502]: cannot borrow `s` as mutable because it is also borrowed as immutable
+
+    println!("word 사용 후: {}", word);
+
+    // 배열 슬라이스
+    let a = [1, 2, 3, 4, 5];
+    let slice: &[i32] = &a[1..3];  // [2, 3]
+    println!("배열 슬라이스: {:?}", slice);
+}
+
+fn first_word(s: &str) -> &str {
+    // &str을 받으면 String과 &str 모두 처리 가능
+    let bytes = s.as_bytes();
+
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+
+    &s[..]
+}
+
+fn main() {
+    slices();
+}
@@ -0,0 +1,95 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 04. 수명 (Lifetimes) - 수명 어노테이션
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 수명 어노테이션
+// ----------------------------------------------------------------------------
+fn lifetime_annotations() {
+    println!("\n--- 수명 어노테이션 ---");
+
+    // 두 문자열 중 긴 것을 반환하는 함수를 생각해보자
+    // 반환되는 참조는 어떤 수명을 가져야 할까?
+
+    let string1 = String::from("long string is long");
+    let result;
+
+    {
+        let string2 = String::from("xyz");
+        result = longest(string1.as_str(), string2.as_str());
+        println!("긴 문자열: {}", result);
+    }
+    // result를 여기서 사용하면? string2가 이미 drop됨
+    // 컴파일러는 result가 string2를 참조할 수 있음을 알고 있음
+
+    // 수명 어노테이션을 사용하지 않으면:
+    // fn longest(x: &str, y: &str) -> &str {  // 컴파일 에러!
+    //     if x.len() > y.len() { x } else { y }
+    // }
+    // error[E0106]: missing lifetime specifier
+
+    // 컴파일러가 물어보는 것:
+    // "반환값의 수명이 x와 같아? y와 같아? 둘 다?"
+
+    // 다른 예제: 항상 첫 번째 매개변수 반환
+    let s1 = String::from("hello");
+    let s2 = String::from("world");
+    let result = first(&s1, &s2);
+    println!("첫 번째: {}", result);
+}
+
+// 수명 어노테이션 문법: 'a (작은따옴표 + 소문자)
+// 'a는 "x와 y 중 더 짧은 수명"을 의미
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    // x와 y는 최소한 'a 만큼 살아있음
+    // 반환값도 최소한 'a 만큼 유효함
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+// 항상 첫 번째 매개변수만 반환하면 두 번째는 수명 불필요
+fn first<'a>(x: &'a str, _y: &str) -> &'a str {
+    x
+}
+
+// 수명은 함수 시그니처의 계약:
+// "이 참조들이 유효한 동안 반환값도 유효하다"
+
+// 수명 생략 규칙 (Lifetime Elision Rules)
+// 컴파일러가 자동으로 수명을 추론하는 규칙:
+// 1. 각 참조 매개변수는 자신만의 수명을 가짐
+// 2. 입력 수명이 하나면 출력 수명도 그것과 같음
+// 3. &self나 &mut self가 있으면 self의 수명이 출력 수명
+// 규칙의 가장자리(다른 인자를 돌려주는 메서드, impl Trait), for<'a>, 변성, 'static 바운드는 53장
+
+// 따라서 이것은:
+fn first_word(s: &str) -> &str {
+    // 수명 생략 규칙 적용됨
+    let bytes = s.as_bytes();
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+    &s[..]
+}
+
+// 이것과 동일:
+fn _first_word_explicit<'a>(s: &'a str) -> &'a str {
+    let bytes = s.as_bytes();
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+    &s[..]
+}
+
+fn main() {
+    lifetime_annotations();
+}
@@ -0,0 +1,40 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 04. 수명 (Lifetimes) - 수명 기초
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 수명 기초
+// ----------------------------------------------------------------------------
+fn lifetime_basics() {
+    println!("--- 수명 기초 ---");
+
+    // 모든 참조는 수명을 가짐 - 참조가 유효한 범위
+    // 대부분의 경우 수명은 암묵적이고 추론됨
+
+    let r;                      // 참조 선언
+    {
+        let x = 5;
+        r = &x;                 // x의 참조를 r에 저장
+        println!("r: {}", r);   // 여기서는 OK
+    }  // x가 스코프를 벗어남
+    // println!("r: {}", r);    // 에러! r은 댕글링 참조
+
+    // 위 코드가 컴파일되지 않는 이유:
+    // - r의 수명: 외부 스코프 전체
+    // - x의 수명: 내부 블록만
+    // - r이 x보다 오래 살아남으려 함 = 컴파일 에러
+
+    // C++에서는 이런 코드가 컴파일됨 (정의되지 않은 동작):
+    // int* r;
+    // {
+    //     int x = 5;
+    //     r = &x;
+    // }
+    // std::cout << *r;  // 댕글링 포인터!
+}
+
+fn main() {
+    lifetime_basics();
+}
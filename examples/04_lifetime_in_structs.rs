@@ -0,0 +1,55 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 04. 수명 (Lifetimes) - 구조체에서의 수명
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 구조체에서의 수명
+// ----------------------------------------------------------------------------
+fn lifetime_in_structs() {
+    println!("\n--- 구조체에서의 수명 ---");
+
+    // 구조체가 참조를 포함하면 수명 어노테이션 필요
+    // 구조체는 그 참조보다 오래 살 수 없음
+
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first_sentence = novel.split('.').next().unwrap();
+
+    let excerpt = ImportantExcerpt {
+        part: first_sentence,
+    };
+
+    println!("발췌: {}", excerpt.part);
+
+    // C++에서 비슷한 패턴 (위험할 수 있음):
+    // struct ImportantExcerpt {
+    //     std::string_view part;  // 댕글링 가능성 있음
+    // };
+
+    // Rust에서는 수명 어노테이션으로 안전성 보장
+    // excerpt는 novel보다 오래 살 수 없음 (컴파일러가 보장)
+}
+
+// 참조를 포함하는 구조체는 수명 어노테이션 필요
+struct ImportantExcerpt<'a> {
+    part: &'a str,  // 'a 수명 동안 유효한 문자열 슬라이스
+}
+
+// 구조체 메서드에서의 수명
+impl<'a> ImportantExcerpt<'a> {
+    // self 참조의 수명이 반환값에 자동 적용 (규칙 3)
+    fn level(&self) -> i32 {
+        3
+    }
+
+    // 여러 참조가 있어도 &self가 있으면 규칙 3 적용
+    fn announce_and_return_part(&self, announcement: &str) -> &str {
+        println!("주목하세요: {}", announcement);
+        self.part
+    }
+}
+
+fn main() {
+    lifetime_in_structs();
+}
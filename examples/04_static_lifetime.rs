@@ -0,0 +1,55 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 04. 수명 (Lifetimes) - 정적 수명
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 정적 수명
+// ----------------------------------------------------------------------------
+fn static_lifetime() {
+    println!("\n--- 정적 수명 ---");
+
+    // 'static 수명 = 프로그램 전체 기간 동안 유효
+    // 문자열 리터럴은 'static 수명을 가짐 (바이너리에 저장)
+
+    let s: &'static str = "프로그램 전체 동안 유효";
+    println!("{}", s);
+
+    // C++에서 유사한 개념:
+    // const char* s = "literal";  // 정적 저장 기간
+
+    // 'static을 남용하지 말 것!
+    // 대부분의 경우 댕글링 참조 문제는 수명 어노테이션으로 해결
+    // 'static은 정말 프로그램 전체 기간이 필요할 때만 사용
+
+    // 제네릭 + 트레이트 바운드 + 수명을 모두 함께 사용
+    use std::fmt::Display;
+
+    fn longest_with_announcement<'a, T>(
+        x: &'a str,
+        y: &'a str,
+        ann: T,
+    ) -> &'a str
+    where
+        T: Display,
+    {
+        println!("알림: {}", ann);
+        if x.len() > y.len() {
+            x
+        } else {
+            y
+        }
+    }
+
+    let result = longest_with_announcement(
+        "hello",
+        "world!",
+        "수명과 제네릭 함께 사용",
+    );
+    println!("결과: {}", result);
+}
+
+fn main() {
+    static_lifetime();
+}
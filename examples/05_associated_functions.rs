@@ -0,0 +1,91 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 05. 구조체 (Structs) - 연관 함수 (Associated Functions)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    // 메서드의 첫 번째 매개변수는 항상 self
+    // &self = 불변 빌림 (C++: const 멤버 함수)
+    fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    // &mut self = 가변 빌림 (C++: 비const 멤버 함수)
+    fn double_size(&mut self) {
+        self.width *= 2;
+        self.height *= 2;
+    }
+
+    // self = 소유권 획득 (C++: 이동 후 소멸)
+    fn consume(self) -> u32 {
+        self.width * self.height
+        // self는 이 함수가 끝나면 drop됨
+    }
+
+    // 여러 매개변수를 가진 메서드
+    fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+}
+
+impl Rectangle {
+    fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 연관 함수 (Associated Functions)
+// ----------------------------------------------------------------------------
+
+impl Rectangle {
+    // self가 없는 함수 = 연관 함수 (C++의 static 멤버 함수)
+    // 생성자 패턴으로 주로 사용
+    fn new(width: u32, height: u32) -> Rectangle {
+        Rectangle { width, height }
+    }
+
+    fn square(size: u32) -> Rectangle {
+        Rectangle {
+            width: size,
+            height: size,
+        }
+    }
+}
+
+fn associated_functions() {
+    println!("\n--- 연관 함수 ---");
+
+    // :: 문법으로 호출 (C++과 동일)
+    let rect = Rectangle::new(30, 50);
+    println!("새 사각형: {:?}", rect);
+
+    let square = Rectangle::square(25);
+    println!("정사각형: {:?}", square);
+    println!("정사각형인가? {}", square.is_square());
+
+    // C++ 비교:
+    // class Rectangle {
+    // public:
+    //     static Rectangle create(int w, int h) { return Rectangle{w, h}; }
+    //     int area() const { return width * height; }
+    // private:
+    //     int width, height;
+    // };
+    //
+    // auto rect = Rectangle::create(30, 50);
+    // rect.area();
+}
+
+fn main() {
+    associated_functions();
+}
@@ -0,0 +1,76 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 05. 구조체 (Structs) - 기본 구조체
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 기본 구조체
+// ----------------------------------------------------------------------------
+
+// C++:
+// struct User {
+//     bool active;
+//     std::string username;
+//     std::string email;
+//     int sign_in_count;
+// };
+
+// Rust:
+#[derive(Debug)]  // 디버그 출력을 위한 트레이트 자동 구현
+struct User {
+    active: bool,
+    username: String,
+    email: String,
+    sign_in_count: u64,
+}
+
+fn basic_struct() {
+    println!("--- 기본 구조체 ---");
+
+    // 인스턴스 생성 - 모든 필드 초기화 필수
+    // C++: User user1{true, "user1", "user1@example.com", 1};
+    let mut user1 = User {
+        active: true,
+        username: String::from("user1"),
+        email: String::from("user1@example.com"),
+        sign_in_count: 1,
+    };
+
+    // 필드 접근 (dot notation)
+    println!("사용자명: {}", user1.username);
+
+    // 가변 인스턴스면 필드 수정 가능
+    user1.email = String::from("new_email@example.com");
+    println!("새 이메일: {}", user1.email);
+
+    // 필드 초기화 단축 문법 (Field Init Shorthand)
+    // 변수명과 필드명이 같으면 한 번만 작성
+    let email = String::from("user2@example.com");
+    let username = String::from("user2");
+
+    let user2 = User {
+        email,           // email: email 대신
+        username,        // username: username 대신
+        active: true,
+        sign_in_count: 1,
+    };
+
+    println!("user2: {:?}", user2);
+
+    // 구조체 업데이트 문법 (Struct Update Syntax)
+    // C++에는 없는 기능
+    let user3 = User {
+        email: String::from("user3@example.com"),
+        ..user2  // 나머지 필드는 user2에서 가져옴
+    };
+
+    println!("user3 이메일: {}", user3.email);
+    // 주의: user2의 username이 이동됨! (String은 Copy가 아님)
+    // println!("{}", user2.username);  // 에러!
+    println!("user2 active: {}", user2.active);  // OK (bool은 Copy)
+}
+
+fn main() {
+    basic_struct();
+}
@@ -0,0 +1,100 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 05. 구조체 (Structs) - 메서드
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// --- 다른 절에서 가져온 정의 ---
+
+impl Rectangle {
+    // self가 없는 함수 = 연관 함수 (C++의 static 멤버 함수)
+    // 생성자 패턴으로 주로 사용
+    fn new(width: u32, height: u32) -> Rectangle {
+        Rectangle { width, height }
+    }
+
+    fn square(size: u32) -> Rectangle {
+        Rectangle {
+            width: size,
+            height: size,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 메서드
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+// 메서드는 impl 블록에 정의
+// C++과 달리 구조체 정의와 분리됨
+impl Rectangle {
+    // 메서드의 첫 번째 매개변수는 항상 self
+    // &self = 불변 빌림 (C++: const 멤버 함수)
+    fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    // &mut self = 가변 빌림 (C++: 비const 멤버 함수)
+    fn double_size(&mut self) {
+        self.width *= 2;
+        self.height *= 2;
+    }
+
+    // self = 소유권 획득 (C++: 이동 후 소멸)
+    fn consume(self) -> u32 {
+        self.width * self.height
+        // self는 이 함수가 끝나면 drop됨
+    }
+
+    // 여러 매개변수를 가진 메서드
+    fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+}
+
+fn methods() {
+    println!("\n--- 메서드 ---");
+
+    let mut rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+
+    // 메서드 호출 - 자동 참조/역참조
+    // Rust는 자동으로 &, &mut, * 를 추가
+    // rect.area()는 (&rect).area()와 동일
+    println!("넓이: {}", rect.area());
+
+    // 가변 메서드
+    rect.double_size();
+    println!("두 배 후 넓이: {}", rect.area());
+
+    let rect2 = Rectangle {
+        width: 10,
+        height: 40,
+    };
+
+    println!("rect가 rect2를 포함할 수 있나? {}", rect.can_hold(&rect2));
+
+    // 소유권을 가져가는 메서드
+    let final_area = rect.consume();
+    println!("최종 넓이: {}", final_area);
+    // println!("{:?}", rect);  // 에러! rect는 이동됨
+}
+
+// impl 블록은 여러 개 가능
+impl Rectangle {
+    fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+}
+
+fn main() {
+    methods();
+}
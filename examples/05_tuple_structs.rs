@@ -0,0 +1,46 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 05. 구조체 (Structs) - 튜플 구조체
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 튜플 구조체
+// ----------------------------------------------------------------------------
+
+// 이름 있는 튜플 - 타입 구분을 위해 사용
+// C++: using Color = std::tuple<int, int, int>; 와 비슷하지만 더 타입 안전
+
+struct Color(i32, i32, i32);
+struct Point(i32, i32, i32);
+
+fn tuple_structs() {
+    println!("\n--- 튜플 구조체 ---");
+
+    let black = Color(0, 0, 0);
+    let origin = Point(0, 0, 0);
+
+    // 같은 필드 구조여도 다른 타입!
+    // let c: Color = origin;  // 컴파일 에러!
+
+    // 인덱스로 접근
+    println!("Color R: {}", black.0);
+    println!("Point x: {}", origin.0);
+
+    // 구조 분해
+    let Color(r, g, b) = black;
+    println!("RGB: {}, {}, {}", r, g, b);
+
+    // Newtype 패턴 - 기존 타입을 감싸서 새 타입 생성
+    struct Meters(f64);
+    struct Kilometers(f64);
+
+    let distance = Meters(100.0);
+    // 실수로 다른 단위와 섞는 것을 방지
+    // let km: Kilometers = distance;  // 컴파일 에러!
+    println!("거리: {} 미터", distance.0);
+}
+
+fn main() {
+    tuple_structs();
+}
@@ -0,0 +1,27 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 05. 구조체 (Structs) - 유닛 구조체
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 유닛 구조체
+// ----------------------------------------------------------------------------
+
+// 필드가 없는 구조체 - 트레이트 구현에 주로 사용
+// C++: struct Empty {}; 와 유사
+
+struct AlwaysEqual;
+
+fn unit_struct() {
+    println!("\n--- 유닛 구조체 ---");
+
+    let _subject = AlwaysEqual;
+
+    // 주로 트레이트 구현할 때 사용
+    // impl SomeTrait for AlwaysEqual { ... }
+}
+
+fn main() {
+    unit_struct();
+}
@@ -0,0 +1,47 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 06. 열거형 (Enums)과 패턴 매칭 - 기본 열거형
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 기본 열거형
+// ----------------------------------------------------------------------------
+
+// C++:
+// enum class Direction { North, South, East, West };
+
+// Rust: (기본적으로 C++의 enum class처럼 스코프됨)
+#[derive(Debug)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+fn basic_enum() {
+    println!("--- 기본 열거형 ---");
+
+    let dir = Direction::North;
+    println!("방향: {:?}", dir);
+
+    // C++ enum class처럼 타입 안전
+    // let x: i32 = dir;  // 에러! 암묵적 변환 없음
+
+    // 정수 값 할당
+    #[derive(Debug)]
+    #[repr(u16)]  // 기본 타입 지정 (C++의 enum class : uint16_t)
+    enum HttpStatus {
+        Ok = 200,
+        NotFound = 404,
+        InternalError = 500,
+    }
+
+    let status = HttpStatus::Ok;
+    println!("상태 코드: {}", status as u16);
+}
+
+fn main() {
+    basic_enum();
+}
@@ -0,0 +1,56 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 06. 열거형 (Enums)과 패턴 매칭 - 데이터를 가진 열거형
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 데이터를 가진 열거형
+// ----------------------------------------------------------------------------
+
+// C++에서 비슷한 것: std::variant + struct
+// 각 variant가 서로 다른 타입과 개수의 데이터를 가질 수 있음
+
+#[derive(Debug)]
+enum Message {
+    Quit,                        // 데이터 없음
+    Move { x: i32, y: i32 },     // 익명 구조체
+    Write(String),               // String 하나
+    ChangeColor(i32, i32, i32),  // 튜플
+}
+
+// C++로 유사하게 구현:
+// struct Quit {};
+// struct Move { int x, y; };
+// struct Write { std::string text; };
+// struct ChangeColor { int r, g, b; };
+// using Message = std::variant<Quit, Move, Write, ChangeColor>;
+
+fn enum_with_data() {
+    println!("\n--- 데이터를 가진 열거형 ---");
+
+    let msg1 = Message::Quit;
+    let msg2 = Message::Move { x: 10, y: 20 };
+    let msg3 = Message::Write(String::from("hello"));
+    let msg4 = Message::ChangeColor(255, 128, 0);
+
+    println!("메시지들: {:?}, {:?}, {:?}, {:?}", msg1, msg2, msg3, msg4);
+
+    // 열거형에도 메서드 구현 가능
+    msg3.call();
+}
+
+impl Message {
+    fn call(&self) {
+        match self {
+            Message::Quit => println!("종료"),
+            Message::Move { x, y } => println!("이동: ({}, {})", x, y),
+            Message::Write(text) => println!("작성: {}", text),
+            Message::ChangeColor(r, g, b) => println!("색상: RGB({}, {}, {})", r, g, b),
+        }
+    }
+}
+
+fn main() {
+    enum_with_data();
+}
@@ -0,0 +1,65 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 06. 열거형 (Enums)과 패턴 매칭 - if let, while let
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// if let, while let
+// ----------------------------------------------------------------------------
+
+fn if_let_while_let() {
+    println!("\n--- if let, while let ---");
+
+    // 단일 패턴만 처리할 때 match는 장황함
+    let some_value = Some(3);
+
+    // match 사용
+    match some_value {
+        Some(3) => println!("match: 3이다!"),
+        _ => (),
+    }
+
+    // if let 사용 - 더 간결
+    if let Some(3) = some_value {
+        println!("if let: 3이다!");
+    }
+
+    // if let else
+    if let Some(n) = some_value {
+        println!("값: {}", n);
+    } else {
+        println!("값 없음");
+    }
+
+    // while let - 패턴이 매치하는 동안 반복
+    let mut stack = Vec::new();
+    stack.push(1);
+    stack.push(2);
+    stack.push(3);
+
+    while let Some(top) = stack.pop() {
+        println!("pop: {}", top);
+    }
+
+    // let else (Rust 1.65+) - 매치 실패 시 early return
+    fn get_count(s: &str) -> Option<usize> {
+        let count_str = s.strip_prefix("count: ")?;
+        count_str.parse().ok()
+    }
+
+    fn process(s: &str) {
+        let Some(count) = get_count(s) else {
+            println!("파싱 실패");
+            return;
+        };
+        println!("카운트: {}", count);
+    }
+
+    process("count: 42");
+    process("invalid");
+}
+
+fn main() {
+    if_let_while_let();
+}
@@ -0,0 +1,93 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 06. 열거형 (Enums)과 패턴 매칭 - match 표현식
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug)]
+enum Message {
+    Quit,                        // 데이터 없음
+    Move { x: i32, y: i32 },     // 익명 구조체
+    Write(String),               // String 하나
+    ChangeColor(i32, i32, i32),  // 튜플
+}
+
+impl Message {
+    fn call(&self) {
+        match self {
+            Message::Quit => println!("종료"),
+            Message::Move { x, y } => println!("이동: ({}, {})", x, y),
+            Message::Write(text) => println!("작성: {}", text),
+            Message::ChangeColor(r, g, b) => println!("색상: RGB({}, {}, {})", r, g, b),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// match 표현식
+// ----------------------------------------------------------------------------
+
+fn match_expression() {
+    println!("\n--- match 표현식 ---");
+
+    // match는 표현식! 값을 반환함
+    let number = 13;
+
+    let description = match number {
+        1 => "one",
+        2 => "two",
+        3 => "three",
+        13 => "thirteen",
+        _ => "other",  // _ 는 catch-all (C++의 default)
+    };
+    println!("{} is {}", number, description);
+
+    // 모든 케이스를 처리해야 함 (exhaustive)
+    // _ 를 빼면 컴파일 에러!
+
+    // 범위 패턴
+    let score = 85;
+    let grade = match score {
+        90..=100 => 'A',
+        80..=89 => 'B',
+        70..=79 => 'C',
+        60..=69 => 'D',
+        _ => 'F',
+    };
+    println!("점수 {}: 등급 {}", score, grade);
+
+    // 여러 패턴 (OR)
+    let die = 3;
+    match die {
+        1 | 2 | 3 => println!("작은 수"),
+        4 | 5 | 6 => println!("큰 수"),
+        _ => unreachable!(),  // 도달 불가능 표시
+    }
+
+    // 가드 (조건)
+    let pair = (2, -2);
+    match pair {
+        (x, y) if x == y => println!("같음"),
+        (x, y) if x + y == 0 => println!("합이 0"),
+        (x, _) if x % 2 == 0 => println!("첫 번째가 짝수"),
+        _ => println!("기타"),
+    }
+
+    // 바인딩 (@)
+    let msg = Message::Move { x: 10, y: 20 };
+    match msg {
+        Message::Move { x: 0..=10, y } => {
+            println!("x가 0-10 범위, y = {}", y);
+        }
+        Message::Move { x, y: y_val @ 15..=25 } => {
+            println!("x = {}, y가 15-25 범위 ({})", x, y_val);
+        }
+        _ => println!("기타"),
+    }
+}
+
+fn main() {
+    match_expression();
+}
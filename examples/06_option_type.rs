@@ -0,0 +1,74 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 06. 열거형 (Enums)과 패턴 매칭 - Option 타입 - null을 대체
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// Option 타입 - null을 대체
+// ----------------------------------------------------------------------------
+
+fn option_type() {
+    println!("\n--- Option 타입 ---");
+
+    // Rust에는 null이 없음!
+    // 대신 Option<T> 사용
+
+    // 표준 라이브러리 정의:
+    // enum Option<T> {
+    //     None,
+    //     Some(T),
+    // }
+
+    // C++: std::optional<int>
+    let some_number: Option<i32> = Some(5);
+    let no_number: Option<i32> = None;
+
+    println!("some_number: {:?}", some_number);
+    println!("no_number: {:?}", no_number);
+
+    // Option<T>와 T는 다른 타입!
+    // let sum = some_number + 5;  // 에러! Option<i32> + i32 불가
+
+    // 값을 사용하려면 Option을 처리해야 함
+    match some_number {
+        Some(n) => println!("값: {}", n),
+        None => println!("값 없음"),
+    }
+
+    // C++에서 흔한 null 버그:
+    // int* ptr = nullptr;
+    // *ptr = 5;  // 런타임 크래시!
+
+    // Rust에서는 불가능:
+    // 1. Option을 처리하지 않으면 컴파일 에러
+    // 2. unwrap()으로 강제 추출하면 None일 때 panic
+
+    // 유용한 Option 메서드들
+    let x = Some(5);
+
+    // unwrap: Some이면 값, None이면 panic
+    println!("unwrap: {}", x.unwrap());
+
+    // unwrap_or: None일 때 기본값
+    let y: Option<i32> = None;
+    println!("unwrap_or: {}", y.unwrap_or(0));
+
+    // expect: unwrap + 커스텀 에러 메시지
+    println!("expect: {}", x.expect("값이 있어야 함"));
+
+    // is_some, is_none
+    println!("is_some: {}, is_none: {}", x.is_some(), y.is_none());
+
+    // map: Some 내부 값 변환
+    let doubled = x.map(|n| n * 2);
+    println!("map: {:?}", doubled);
+
+    // and_then: flatMap (중첩 Option 방지)
+    let result = x.and_then(|n| Some(n + 1));
+    println!("and_then: {:?}", result);
+}
+
+fn main() {
+    option_type();
+}
@@ -0,0 +1,79 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 06. 열거형 (Enums)과 패턴 매칭 - 고급 패턴 매칭
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 고급 패턴 매칭
+// ----------------------------------------------------------------------------
+
+fn pattern_matching_advanced() {
+    println!("\n--- 고급 패턴 매칭 ---");
+
+    // 구조체 분해
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let p = Point { x: 0, y: 7 };
+
+    match p {
+        Point { x: 0, y } => println!("x축 위, y = {}", y),
+        Point { x, y: 0 } => println!("y축 위, x = {}", x),
+        Point { x, y } => println!("점 ({}, {})", x, y),
+    }
+
+    // 중첩 구조 분해
+    enum Color {
+        Rgb(i32, i32, i32),
+        Hsv(i32, i32, i32),
+    }
+
+    enum AdvancedMessage {
+        ChangeColor(Color),
+    }
+
+    let msg = AdvancedMessage::ChangeColor(Color::Rgb(255, 128, 0));
+
+    match msg {
+        AdvancedMessage::ChangeColor(Color::Rgb(r, g, b)) => {
+            println!("RGB: ({}, {}, {})", r, g, b);
+        }
+        AdvancedMessage::ChangeColor(Color::Hsv(h, s, v)) => {
+            println!("HSV: ({}, {}, {})", h, s, v);
+        }
+    }
+
+    // 무시 패턴
+    let numbers = (1, 2, 3, 4, 5);
+
+    match numbers {
+        (first, _, third, _, fifth) => {
+            println!("첫째: {}, 셋째: {}, 다섯째: {}", first, third, fifth);
+        }
+    }
+
+    // .. 으로 나머지 무시
+    match numbers {
+        (first, .., last) => {
+            println!("처음: {}, 마지막: {}", first, last);
+        }
+    }
+
+    // 참조 패턴
+    let robot_name = Some(String::from("Bors"));
+
+    match &robot_name {
+        Some(name) => println!("로봇 이름: {}", name),
+        None => (),
+    }
+
+    // robot_name은 여전히 유효 (참조로 매치했으므로)
+    println!("로봇: {:?}", robot_name);
+}
+
+fn main() {
+    pattern_matching_advanced();
+}
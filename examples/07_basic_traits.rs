@@ -0,0 +1,77 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 07. 트레이트 (Traits) - 기본 트레이트
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::{Debug, Display};
+use std::ops::Add;
+
+// ----------------------------------------------------------------------------
+// 기본 트레이트
+// ----------------------------------------------------------------------------
+
+// 트레이트 정의 - C++의 순수 가상 함수를 가진 추상 클래스와 유사
+// C++:
+// class Summary {
+// public:
+//     virtual std::string summarize() const = 0;
+// };
+
+trait Summary {
+    fn summarize(&self) -> String;
+}
+
+struct NewsArticle {
+    headline: String,
+    location: String,
+    author: String,
+    content: String,
+}
+
+struct Tweet {
+    username: String,
+    content: String,
+    reply: bool,
+    retweet: bool,
+}
+
+// 트레이트 구현
+// C++: class NewsArticle : public Summary { ... };
+
+impl Summary for NewsArticle {
+    fn summarize(&self) -> String {
+        format!("{}, by {} ({})", self.headline, self.author, self.location)
+    }
+}
+
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        format!("{}: {}", self.username, self.content)
+    }
+}
+
+fn basic_traits() {
+    println!("--- 기본 트레이트 ---");
+
+    let article = NewsArticle {
+        headline: String::from("Rust 2.0 출시!"),
+        location: String::from("서울"),
+        author: String::from("홍길동"),
+        content: String::from("대단한 내용..."),
+    };
+
+    let tweet = Tweet {
+        username: String::from("user123"),
+        content: String::from("Rust 최고!"),
+        reply: false,
+        retweet: false,
+    };
+
+    println!("기사: {}", article.summarize());
+    println!("트윗: {}", tweet.summarize());
+}
+
+fn main() {
+    basic_traits();
+}
@@ -0,0 +1,59 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 07. 트레이트 (Traits) - 기본 구현
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::{Debug, Display};
+use std::ops::Add;
+
+// ----------------------------------------------------------------------------
+// 기본 구현
+// ----------------------------------------------------------------------------
+
+trait Greet {
+    // 기본 구현 제공 - C++의 가상 함수 (순수 아닌)
+    fn greet(&self) -> String {
+        String::from("안녕하세요!")
+    }
+
+    // 다른 메서드 호출 가능
+    fn greet_twice(&self) -> String {
+        format!("{} {}", self.greet(), self.greet())
+    }
+}
+
+struct Person {
+    name: String,
+}
+
+struct Robot {
+    id: u32,
+}
+
+// 기본 구현 그대로 사용
+impl Greet for Person {}
+
+// 기본 구현 오버라이드
+impl Greet for Robot {
+    fn greet(&self) -> String {
+        format!("삐빅. 로봇 {} 입니다.", self.id)
+    }
+}
+
+fn default_implementations() {
+    println!("\n--- 기본 구현 ---");
+
+    let person = Person {
+        name: String::from("철수"),
+    };
+    let robot = Robot { id: 42 };
+
+    println!("사람: {}", person.greet());
+    println!("로봇: {}", robot.greet());
+    println!("로봇 두 번: {}", robot.greet_twice());
+}
+
+fn main() {
+    default_implementations();
+}
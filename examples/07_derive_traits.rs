@@ -0,0 +1,66 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 07. 트레이트 (Traits) - 파생 트레이트 (Derive)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::{Debug, Display};
+use std::ops::Add;
+
+// ----------------------------------------------------------------------------
+// 파생 트레이트 (Derive)
+// ----------------------------------------------------------------------------
+
+fn derive_traits() {
+    println!("\n--- 파생 트레이트 ---");
+
+    // #[derive]로 표준 트레이트 자동 구현
+    // C++: 컴파일러가 생성하는 특수 멤버 함수와 유사
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    // Debug: {:?} 포맷팅 - 서식 지정과 Debug 를 손으로 쓰는 법은 56장
+    let p = Point { x: 10, y: 20 };
+    println!("Debug: {:?}", p);
+
+    // Clone: 깊은 복사
+    let p2 = p.clone();
+    println!("Clone: {:?}", p2);
+
+    // PartialEq, Eq: == 비교
+    println!("같음: {}", p == p2);
+
+    // Default: 기본값 생성
+    let default_point: Point = Default::default();
+    println!("Default: {:?}", default_point);
+
+    // 주요 파생 트레이트:
+    // Debug     - 디버그 출력
+    // Clone     - 깊은 복사
+    // Copy      - 비트 복사 (Clone 필요)
+    // PartialEq - 부분 동등성 (== 연산자)
+    // Eq        - 완전 동등성 (PartialEq 필요)
+    // PartialOrd - 부분 순서 (< > 연산자)
+    // Ord       - 완전 순서 (PartialOrd + Eq 필요)
+    // Hash      - 해시 가능 (HashMap 키로 사용)
+    // Default   - 기본값 생성
+
+    // Copy 예제 - 스택 전용, 비용이 저렴한 복사
+    #[derive(Debug, Copy, Clone)]
+    struct SmallData {
+        a: i32,
+        b: i32,
+    }
+
+    let s1 = SmallData { a: 1, b: 2 };
+    let s2 = s1; // Copy이므로 이동 대신 복사
+    println!("s1: {:?}, s2: {:?}", s1, s2); // 둘 다 유효!
+}
+
+fn main() {
+    derive_traits();
+}
@@ -0,0 +1,74 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 07. 트레이트 (Traits) - 연산자 오버로딩
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::{Debug, Display};
+use std::ops::Add;
+
+// ----------------------------------------------------------------------------
+// 연산자 오버로딩
+// ----------------------------------------------------------------------------
+
+fn operator_overloading() {
+    println!("\n--- 연산자 오버로딩 ---");
+
+    // Rust의 연산자 오버로딩은 트레이트로 구현
+    // std::ops 모듈의 트레이트들 사용
+
+    // C++:
+    // Point operator+(const Point& other) const {
+    //     return Point{x + other.x, y + other.y};
+    // }
+
+    #[derive(Debug, Clone, Copy)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    // Add 트레이트 구현
+    impl Add for Point {
+        type Output = Point; // 연관 타입 (결과 타입)
+
+        fn add(self, other: Point) -> Point {
+            Point {
+                x: self.x + other.x,
+                y: self.y + other.y,
+            }
+        }
+    }
+
+    let p1 = Point { x: 1, y: 2 };
+    let p2 = Point { x: 3, y: 4 };
+    let p3 = p1 + p2; // Add::add(p1, p2) 호출
+    println!("{:?} + {:?} = {:?}", p1, p2, p3);
+
+    // 다른 타입과의 연산
+    impl Add<i32> for Point {
+        type Output = Point;
+
+        fn add(self, scalar: i32) -> Point {
+            Point {
+                x: self.x + scalar,
+                y: self.y + scalar,
+            }
+        }
+    }
+
+    let p4 = p1 + 10;
+    println!("{:?} + 10 = {:?}", p1, p4);
+
+    // 주요 연산자 트레이트:
+    // Add, Sub, Mul, Div, Rem     - 산술 연산자
+    // AddAssign, SubAssign, ...   - 복합 대입 (+=, -= 등)
+    // Neg, Not                    - 단항 연산자
+    // Index, IndexMut             - [] 연산자
+    // Deref, DerefMut             - * 연산자
+    // C++ 연산자 대응표와 Neg, Not, Index, AddAssign, 손으로 쓰는 Ord 의 예는 55장
+}
+
+fn main() {
+    operator_overloading();
+}
@@ -0,0 +1,52 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 07. 트레이트 (Traits) - 슈퍼트레이트
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::{Debug, Display};
+use std::ops::Add;
+
+// ----------------------------------------------------------------------------
+// 슈퍼트레이트
+// ----------------------------------------------------------------------------
+
+fn supertraits() {
+    println!("\n--- 슈퍼트레이트 ---");
+
+    // 트레이트가 다른 트레이트에 의존
+    // C++의 상속과 유사하지만 구현 상속이 아닌 요구사항
+
+    // Display를 요구하는 트레이트
+    trait OutlinePrint: Display {
+        fn outline_print(&self) {
+            let output = self.to_string();
+            let len = output.len();
+            println!("{}", "*".repeat(len + 4));
+            println!("* {} *", output);
+            println!("{}", "*".repeat(len + 4));
+        }
+    }
+
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    // Display 먼저 구현해야 함
+    impl Display for Point {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "({}, {})", self.x, self.y)
+        }
+    }
+
+    // 그 다음 OutlinePrint 구현 가능
+    impl OutlinePrint for Point {}
+
+    let p = Point { x: 1, y: 2 };
+    p.outline_print();
+}
+
+fn main() {
+    supertraits();
+}
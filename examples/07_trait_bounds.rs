@@ -0,0 +1,112 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 07. 트레이트 (Traits) - 트레이트 바운드
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::{Debug, Display};
+use std::ops::Add;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait Summary {
+    fn summarize(&self) -> String;
+}
+
+struct NewsArticle {
+    headline: String,
+    location: String,
+    author: String,
+    content: String,
+}
+
+struct Tweet {
+    username: String,
+    content: String,
+    reply: bool,
+    retweet: bool,
+}
+
+impl Summary for NewsArticle {
+    fn summarize(&self) -> String {
+        format!("{}, by {} ({})", self.headline, self.author, self.location)
+    }
+}
+
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        format!("{}: {}", self.username, self.content)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 트레이트 바운드
+// ----------------------------------------------------------------------------
+
+fn trait_bounds() {
+    println!("\n--- 트레이트 바운드 ---");
+
+    // 트레이트를 매개변수로 받기 (정적 디스패치)
+    // C++20: template<typename T> requires std::derived_from<T, Summary>
+    //        void notify(const T& item);
+
+    // 방법 1: impl Trait 문법 (간단한 경우)
+    fn notify_simple(item: &impl Summary) {
+        println!("속보! {}", item.summarize());
+    }
+
+    // 방법 2: 트레이트 바운드 문법 (복잡한 경우)
+    fn notify<T: Summary>(item: &T) {
+        println!("속보! {}", item.summarize());
+    }
+
+    // 여러 트레이트 요구
+    fn notify_display<T: Summary + Display>(item: &T) {
+        println!("Display: {}, Summary: {}", item, item.summarize());
+    }
+
+    // where 절로 가독성 향상
+    fn complex_function<T, U>(t: &T, u: &U) -> String
+    where
+        T: Summary + Clone,
+        U: Debug,
+    {
+        format!("{} - {:?}", t.summarize(), u)
+    }
+
+    let tweet = Tweet {
+        username: String::from("user123"),
+        content: String::from("테스트"),
+        reply: false,
+        retweet: false,
+    };
+
+    notify_simple(&tweet);
+    notify(&tweet);
+
+    // 반환 타입으로 impl Trait
+    fn create_summarizable() -> impl Summary {
+        Tweet {
+            username: String::from("bot"),
+            content: String::from("자동 생성"),
+            reply: false,
+            retweet: false,
+        }
+    }
+
+    let item = create_summarizable();
+    println!("생성된 항목: {}", item.summarize());
+
+    // 주의: impl Trait 반환은 단일 타입만 가능
+    // fn random_summarizable() -> impl Summary {
+    //     if true {
+    //         NewsArticle { ... }  // 에러!
+    //     } else {
+    //         Tweet { ... }
+    //     }
+    // }
+}
+
+fn main() {
+    trait_bounds();
+}
@@ -0,0 +1,86 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 07. 트레이트 (Traits) - 트레이트 객체 (동적 디스패치)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::{Debug, Display};
+use std::ops::Add;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait Summary {
+    fn summarize(&self) -> String;
+}
+
+struct NewsArticle {
+    headline: String,
+    location: String,
+    author: String,
+    content: String,
+}
+
+struct Tweet {
+    username: String,
+    content: String,
+    reply: bool,
+    retweet: bool,
+}
+
+impl Summary for NewsArticle {
+    fn summarize(&self) -> String {
+        format!("{}, by {} ({})", self.headline, self.author, self.location)
+    }
+}
+
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        format!("{}: {}", self.username, self.content)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 트레이트 객체 (동적 디스패치)
+// ----------------------------------------------------------------------------
+
+fn trait_objects() {
+    println!("\n--- 트레이트 객체 ---");
+
+    // dyn Trait = 런타임에 어떤 타입인지 결정
+    // C++: Summary* 또는 std::unique_ptr<Summary>
+
+    // 정적 디스패치 vs 동적 디스패치:
+    // 정적: 컴파일 타임에 어떤 메서드 호출할지 결정 (인라인 가능)
+    // 동적: vtable을 통해 런타임에 결정 (약간의 오버헤드)
+    // 실제 수치: 26장에서 같은 일을 세 가지로 재 봄 (cargo run --release -- 26)
+
+    let article = NewsArticle {
+        headline: String::from("제목"),
+        location: String::from("위치"),
+        author: String::from("저자"),
+        content: String::from("내용"),
+    };
+
+    let tweet = Tweet {
+        username: String::from("user"),
+        content: String::from("내용"),
+        reply: false,
+        retweet: false,
+    };
+
+    // 다양한 타입을 하나의 벡터에 저장 (C++: vector<unique_ptr<Summary>>)
+    let items: Vec<Box<dyn Summary>> = vec![Box::new(article), Box::new(tweet)];
+
+    for item in items {
+        println!("항목: {}", item.summarize());
+    }
+
+    // 트레이트 객체의 제한:
+    // 1. 객체 안전(object-safe)한 트레이트만 가능
+    // 2. 제네릭 메서드가 있으면 안 됨
+    // 3. Self를 반환하면 안 됨 (Clone 등)
+}
+
+fn main() {
+    trait_objects();
+}
@@ -0,0 +1,66 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 08. 제네릭 (Generics) - 연관 타입
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+
+// ----------------------------------------------------------------------------
+// 연관 타입
+// ----------------------------------------------------------------------------
+
+fn associated_types() {
+    println!("\n--- 연관 타입 ---");
+
+    // 연관 타입 = 트레이트 내의 타입 별칭
+    // 제네릭 매개변수와 비슷하지만 구현 시 결정
+
+    // 표준 Iterator 트레이트 예:
+    // trait Iterator {
+    //     type Item;  // 연관 타입
+    //     fn next(&mut self) -> Option<Self::Item>;
+    // }
+
+    // 제네릭 vs 연관 타입:
+    // 제네릭: trait Container<T> { ... }
+    //   - 같은 타입에 여러 구현 가능
+    //   - 사용 시 타입 지정 필요: Container<i32>
+    //
+    // 연관 타입: trait Container { type Item; ... }
+    //   - 타입당 하나의 구현
+    //   - 사용 시 타입 지정 불필요
+
+    struct Counter {
+        count: u32,
+    }
+
+    impl Counter {
+        fn new() -> Counter {
+            Counter { count: 0 }
+        }
+    }
+
+    impl Iterator for Counter {
+        type Item = u32; // 연관 타입 지정
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.count < 5 {
+                self.count += 1;
+                Some(self.count)
+            } else {
+                None
+            }
+        }
+    }
+
+    let mut counter = Counter::new();
+    while let Some(n) = counter.next() {
+        print!("{} ", n);
+    }
+    println!();
+}
+
+fn main() {
+    associated_types();
+}
@@ -0,0 +1,59 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 08. 제네릭 (Generics) - Const Generics (컴파일 타임 상수 매개변수)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+
+// ----------------------------------------------------------------------------
+// Const Generics (컴파일 타임 상수 매개변수)
+// ----------------------------------------------------------------------------
+
+fn const_generics() {
+    println!("\n--- Const Generics ---");
+
+    // C++: template<typename T, size_t N>
+    //      struct Array { T data[N]; };
+
+    // Rust 1.51+에서 안정화
+    #[derive(Debug)]
+    struct Array<T, const N: usize> {
+        data: [T; N],
+    }
+
+    impl<T, const N: usize> Array<T, N> {
+        fn len(&self) -> usize {
+            N
+        }
+    }
+
+    impl<T: Default + Copy, const N: usize> Array<T, N> {
+        fn new() -> Self {
+            Array {
+                data: [T::default(); N],
+            }
+        }
+    }
+
+    let arr: Array<i32, 5> = Array::new();
+    println!("배열 길이: {}", arr.len());
+    println!("배열: {:?}", arr);
+
+    // 배열 비교 - 같은 크기만 비교 가능
+    fn compare_arrays<T: PartialEq, const N: usize>(a: &[T; N], b: &[T; N]) -> bool {
+        a == b
+    }
+
+    let a1 = [1, 2, 3];
+    let a2 = [1, 2, 3];
+    let a3 = [1, 2, 4];
+    // let a4 = [1, 2, 3, 4];  // 크기가 다르면 비교 불가
+
+    println!("a1 == a2: {}", compare_arrays(&a1, &a2));
+    println!("a1 == a3: {}", compare_arrays(&a1, &a3));
+}
+
+fn main() {
+    const_generics();
+}
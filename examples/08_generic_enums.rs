@@ -0,0 +1,49 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 08. 제네릭 (Generics) - 제네릭 열거형
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+
+// ----------------------------------------------------------------------------
+// 제네릭 열거형
+// ----------------------------------------------------------------------------
+
+fn generic_enums() {
+    println!("\n--- 제네릭 열거형 ---");
+
+    // 표준 라이브러리의 Option과 Result가 대표적 예
+
+    // enum Option<T> {
+    //     Some(T),
+    //     None,
+    // }
+
+    // enum Result<T, E> {
+    //     Ok(T),
+    //     Err(E),
+    // }
+
+    // 커스텀 제네릭 열거형
+    #[derive(Debug)]
+    enum BinaryTree<T> {
+        Leaf(T),
+        Node {
+            left: Box<BinaryTree<T>>,
+            right: Box<BinaryTree<T>>,
+            value: T,
+        },
+    }
+
+    let tree = BinaryTree::Node {
+        value: 5,
+        left: Box::new(BinaryTree::Leaf(3)),
+        right: Box::new(BinaryTree::Leaf(7)),
+    };
+    println!("트리: {:?}", tree);
+}
+
+fn main() {
+    generic_enums();
+}
@@ -0,0 +1,181 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 08. 제네릭 (Generics) - 제네릭 함수
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+
+// --- rust-study 의 demo_data 모듈 ---
+mod demo_data {
+// ============================================================================
+// 예제 입력 데이터 (Seeded Demo Data)
+// ============================================================================
+// 레슨의 예제는 보통 vec![1, 2, 3] 처럼 고정된 값을 씀
+// 시드를 주면 같은 자리에 크기와 값이 다른 데이터를 만들어서,
+// 예제의 동작이 특정 입력 때문에 우연히 맞은 것이 아님을 확인할 수 있음
+//
+//   cargo run                      레슨에 적힌 기본값 그대로
+//   cargo run -- --seed 42         시드 42 로 만든 데이터 (다시 실행해도 같음)
+//   cargo run -- --seed random     매번 다른 시드 - 처음에 시드를 출력하므로 재현 가능
+//   RUST_STUDY_SEED=42 cargo run --example 08_generic_functions
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::sync::Mutex;
+
+// 시드를 지정하는 환경 변수 - 예제 프로그램(cargo run --example)도 같은 시드를 씀
+pub const SEED_VAR: &str = "RUST_STUDY_SEED";
+
+// 단어 데이터를 고르는 목록
+const WORDS: &[&str] = &[
+    "apple", "banana", "cherry", "rust", "borrow", "owner", "trait", "closure", "iterator",
+    "thread", "future", "macro", "slice", "vector", "string", "lifetime", "pattern", "module",
+];
+
+// 생성기 상태 - 스레드 예제(13장)에서도 같은 수열을 이어 쓰도록 전역
+// None = 아직 환경 변수를 읽지 않음, Some(None) = 시드 없음 (기본값 사용)
+static STATE: Mutex<Option<Option<u64>>> = Mutex::new(None);
+
+fn state() -> std::sync::MutexGuard<'static, Option<Option<u64>>> {
+    STATE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// cargo run -- --seed N 이 환경 변수보다 우선
+pub fn set_seed(seed: u64) {
+    *state() = Some(Some(seed));
+}
+
+// 지금의 생성기 상태 (시드에서 시작해 값을 만들 때마다 바뀜)
+pub fn seed() -> Option<u64> {
+    *state().get_or_insert_with(|| {
+        std::env::var(SEED_VAR).ok().and_then(|v| v.trim().parse().ok())
+    })
+}
+
+// splitmix64 - 작고 빠르며 시드가 같으면 항상 같은 수열
+fn next() -> Option<u64> {
+    let state = seed()?.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    set_seed(state);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    Some(z ^ (z >> 31))
+}
+
+// [low, high] 범위의 수 하나
+fn between(next: u64, low: i64, high: i64) -> i64 {
+    low + (next % (high - low + 1) as u64) as i64
+}
+
+// 정수 하나 - 시드가 없으면 default
+pub fn number(default: i32, low: i32, high: i32) -> i32 {
+    match next() {
+        Some(n) => between(n, low as i64, high as i64) as i32,
+        None => default,
+    }
+}
+
+// 정수 목록 - 시드가 있으면 기본값 개수 근처(최소 1개)의 1..=100 범위 값
+pub fn numbers(default: &[i32]) -> Vec<i32> {
+    let Some(n) = next() else {
+        return default.to_vec();
+    };
+    let len = between(n, 1.max(default.len() as i64 - 2), default.len() as i64 + 3) as usize;
+    (0..len).map(|_| number(0, 1, 100)).collect()
+}
+
+// 단어 목록 - 시드가 있으면 WORDS 에서 기본값과 같은 개수를 뽑음 (중복 가능)
+pub fn words(default: &[&'static str]) -> Vec<&'static str> {
+    if seed().is_none() {
+        return default.to_vec();
+    }
+    default
+        .iter()
+        .map(|_| WORDS[number(0, 0, WORDS.len() as i32 - 1) as usize])
+        .collect()
+}
+
+// 명령줄의 --seed N / --seed random 을 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> Result<(Option<u64>, Vec<String>), String> {
+    let mut seed = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg != "--seed" {
+            rest.push(arg.clone());
+            continue;
+        }
+        let value = iter.next().ok_or("--seed 뒤에 숫자 또는 random 을 지정하세요")?;
+        seed = Some(match value.as_str() {
+            "random" => random_seed(),
+            v => v.parse().map_err(|_| format!("잘못된 시드: {}", v))?,
+        });
+    }
+    Ok((seed, rest))
+}
+
+// 현재 시각으로 만든 시드 - 출력해 두면 같은 데이터를 다시 볼 수 있음
+fn random_seed() -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    nanos % 100_000
+}
+}
+
+// ----------------------------------------------------------------------------
+// 제네릭 함수
+// ----------------------------------------------------------------------------
+
+fn generic_functions() {
+    println!("--- 제네릭 함수 ---");
+
+    // C++ 템플릿:
+    // template<typename T>
+    // T largest(const std::vector<T>& list) {
+    //     return *std::max_element(list.begin(), list.end());
+    // }
+
+    // Rust: 트레이트 바운드 필수
+    fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
+        let mut largest = list[0];
+        for &item in list {
+            if item > largest {
+                largest = item;
+            }
+        }
+        largest
+    }
+
+    // 시드를 주면 매번 다른 목록 (cargo run -- --seed random) - 어떤 입력에서도 최댓값
+    let numbers = crate::demo_data::numbers(&[34, 50, 25, 100, 65]);
+    println!("가장 큰 수: {} ({:?} 중)", largest(&numbers), numbers);
+
+    let chars = vec!['y', 'm', 'a', 'q'];
+    println!("가장 큰 문자: {}", largest(&chars));
+
+    // 여러 타입 매개변수
+    fn pair<T, U>(a: T, b: U) -> (T, U) {
+        (a, b)
+    }
+
+    let p = pair(1, "hello");
+    println!("쌍: {:?}", p);
+
+    // 터보피시(Turbofish) - 타입 명시
+    // C++: function<int>() 대신 function::<int>()
+    let parsed = "42".parse::<i32>().unwrap();
+    println!("파싱됨: {}", parsed);
+
+    let collected: Vec<i32> = (0..5).collect();
+    // 또는
+    let collected = (0..5).collect::<Vec<i32>>();
+    println!("수집됨: {:?}", collected);
+}
+
+fn main() {
+    generic_functions();
+}
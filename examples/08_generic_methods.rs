@@ -0,0 +1,79 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 08. 제네릭 (Generics) - 제네릭 메서드
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+
+// ----------------------------------------------------------------------------
+// 제네릭 메서드
+// ----------------------------------------------------------------------------
+
+fn generic_methods() {
+    println!("\n--- 제네릭 메서드 ---");
+
+    #[derive(Debug)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    // 모든 T에 대한 메서드
+    impl<T> Point<T> {
+        fn x(&self) -> &T {
+            &self.x
+        }
+
+        fn y(&self) -> &T {
+            &self.y
+        }
+    }
+
+    // 특정 타입에만 적용되는 메서드
+    impl Point<f64> {
+        fn distance_from_origin(&self) -> f64 {
+            (self.x.powi(2) + self.y.powi(2)).sqrt()
+        }
+    }
+
+    // 트레이트 바운드가 있는 메서드
+    impl<T: Display> Point<T> {
+        fn print(&self) {
+            println!("Point: ({}, {})", self.x, self.y);
+        }
+    }
+
+    let p1 = Point { x: 5, y: 10 };
+    let p2 = Point { x: 5.0, y: 10.0 };
+
+    println!("p1.x = {}", p1.x());
+    // p1.distance_from_origin();  // 에러! i32에는 없음
+    println!("p2 원점 거리: {}", p2.distance_from_origin());
+    p1.print();
+    p2.print();
+
+    // 메서드에 추가 타입 매개변수
+    #[derive(Debug)]
+    struct Wrapper<T> {
+        value: T,
+    }
+
+    impl<T> Wrapper<T> {
+        // 메서드 자체에 새 타입 매개변수
+        fn mixup<U>(self, other: Wrapper<U>) -> Wrapper<(T, U)> {
+            Wrapper {
+                value: (self.value, other.value),
+            }
+        }
+    }
+
+    let w1 = Wrapper { value: "hello" };
+    let w2 = Wrapper { value: 42 };
+    let mixed = w1.mixup(w2);
+    println!("혼합: {:?}", mixed);
+}
+
+fn main() {
+    generic_methods();
+}
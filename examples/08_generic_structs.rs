@@ -0,0 +1,41 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 08. 제네릭 (Generics) - 제네릭 구조체
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+
+// ----------------------------------------------------------------------------
+// 제네릭 구조체
+// ----------------------------------------------------------------------------
+
+fn generic_structs() {
+    println!("\n--- 제네릭 구조체 ---");
+
+    // C++: template<typename T> struct Point { T x, y; };
+    #[derive(Debug)]
+    struct Point<T> {
+        x: T,
+        y: T,
+    }
+
+    let int_point = Point { x: 5, y: 10 };
+    let float_point = Point { x: 1.0, y: 4.0 };
+    println!("정수 점: {:?}", int_point);
+    println!("실수 점: {:?}", float_point);
+
+    // 다른 타입의 x, y
+    #[derive(Debug)]
+    struct MixedPoint<T, U> {
+        x: T,
+        y: U,
+    }
+
+    let mixed = MixedPoint { x: 5, y: 4.0 };
+    println!("혼합 점: {:?}", mixed);
+}
+
+fn main() {
+    generic_structs();
+}
@@ -0,0 +1,55 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 08. 제네릭 (Generics) - PhantomData - 컴파일러 힌트용 타입
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+
+// ----------------------------------------------------------------------------
+// PhantomData - 컴파일러 힌트용 타입
+// ----------------------------------------------------------------------------
+
+fn phantom_data() {
+    println!("\n--- PhantomData ---");
+
+    use std::marker::PhantomData;
+
+    // 타입 매개변수를 사용하지 않지만 "소유"하는 것처럼 표시
+    // 주로 안전한 API 설계, 수명 추적에 사용
+
+    // 예: 타입 레벨에서 단위 구분
+    struct Meters;
+    struct Kilometers;
+
+    struct Distance<Unit> {
+        value: f64,
+        _unit: PhantomData<Unit>,
+    }
+
+    impl<Unit> Distance<Unit> {
+        fn new(value: f64) -> Self {
+            Distance {
+                value,
+                _unit: PhantomData,
+            }
+        }
+    }
+
+    let meters: Distance<Meters> = Distance::new(100.0);
+    let kilometers: Distance<Kilometers> = Distance::new(1.5);
+
+    // 다른 단위끼리 실수로 연산하는 것을 방지
+    // let total = meters.value + kilometers.value;  // 논리적 버그!
+
+    println!("거리: {} 미터, {} 킬로미터", meters.value, kilometers.value);
+
+    // PhantomData<T>는 T를 "소유"하는 것처럼 행동
+    // - Send/Sync 트레이트 전파
+    // - Drop 검사에 영향
+    // - 수명 매개변수 연결
+}
+
+fn main() {
+    phantom_data();
+}
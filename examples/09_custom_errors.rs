@@ -0,0 +1,91 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 09. 에러 처리 (Error Handling) - 커스텀 에러
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs::File;
+use std::io::{self, Read};
+
+// ----------------------------------------------------------------------------
+// 커스텀 에러
+// ----------------------------------------------------------------------------
+
+fn custom_errors() {
+    println!("\n--- 커스텀 에러 ---");
+
+    // 간단한 에러 열거형
+    #[derive(Debug)]
+    enum ParseError {
+        Empty,
+        InvalidFormat,
+        OutOfRange(i32),
+    }
+
+    // std::error::Error 트레이트 구현
+    impl std::fmt::Display for ParseError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ParseError::Empty => write!(f, "입력이 비어있음"),
+                ParseError::InvalidFormat => write!(f, "잘못된 형식"),
+                ParseError::OutOfRange(n) => write!(f, "범위 초과: {}", n),
+            }
+        }
+    }
+
+    impl std::error::Error for ParseError {}
+
+    fn parse_positive(s: &str) -> Result<i32, ParseError> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let n: i32 = s.parse().map_err(|_| ParseError::InvalidFormat)?;
+
+        if n <= 0 {
+            return Err(ParseError::OutOfRange(n));
+        }
+
+        Ok(n)
+    }
+
+    // 테스트
+    for input in &["42", "", "abc", "-5"] {
+        match parse_positive(input) {
+            Ok(n) => println!("'{}' -> {}", input, n),
+            Err(e) => println!("'{}' -> 에러: {}", input, e),
+        }
+    }
+
+    // 에러 래핑 - 원인 에러 보존
+    #[derive(Debug)]
+    struct ReadConfigError {
+        filename: String,
+        source: io::Error,
+    }
+
+    impl std::fmt::Display for ReadConfigError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "설정 파일 '{}' 읽기 실패", self.filename)
+        }
+    }
+
+    impl std::error::Error for ReadConfigError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.source)
+        }
+    }
+
+    // 실무에서는 thiserror, anyhow 크레이트 사용 권장
+    // #[derive(thiserror::Error, Debug)]
+    // enum Error {
+    //     #[error("IO 에러: {0}")]
+    //     Io(#[from] io::Error),
+    //     #[error("파싱 에러")]
+    //     Parse,
+    // }
+}
+
+fn main() {
+    custom_errors();
+}
@@ -0,0 +1,52 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 09. 에러 처리 (Error Handling) - Option과 Result 변환
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs::File;
+use std::io::{self, Read};
+
+// ----------------------------------------------------------------------------
+// Option과 Result 변환
+// ----------------------------------------------------------------------------
+
+fn option_result_conversion() {
+    println!("\n--- Option과 Result 변환 ---");
+
+    // Option -> Result
+    let opt: Option<i32> = Some(42);
+    let result: Result<i32, &str> = opt.ok_or("값 없음");
+    println!("ok_or: {:?}", result);
+
+    let none: Option<i32> = None;
+    let result: Result<i32, &str> = none.ok_or("값 없음");
+    println!("ok_or (None): {:?}", result);
+
+    // Result -> Option
+    let result: Result<i32, &str> = Ok(42);
+    let opt: Option<i32> = result.ok();
+    println!("ok: {:?}", opt);
+
+    let err: Result<i32, &str> = Err("에러");
+    let opt: Option<i32> = err.ok();
+    println!("ok (Err): {:?}", opt);
+
+    // transpose: Option<Result<T, E>> <-> Result<Option<T>, E>
+    let opt_result: Option<Result<i32, &str>> = Some(Ok(42));
+    let result_opt: Result<Option<i32>, &str> = opt_result.transpose();
+    println!("transpose: {:?}", result_opt);
+
+    // collect로 Result<Vec<T>, E> 만들기
+    let strings = vec!["1", "2", "3"];
+    let numbers: Result<Vec<i32>, _> = strings.iter().map(|s| s.parse()).collect();
+    println!("collect Ok: {:?}", numbers);
+
+    let mixed = vec!["1", "two", "3"];
+    let numbers: Result<Vec<i32>, _> = mixed.iter().map(|s| s.parse::<i32>()).collect();
+    println!("collect Err: {:?}", numbers);
+}
+
+fn main() {
+    option_result_conversion();
+}
@@ -0,0 +1,37 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 09. 에러 처리 (Error Handling) - panic! - 복구 불가능한 에러
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs::File;
+use std::io::{self, Read};
+
+// ----------------------------------------------------------------------------
+// panic! - 복구 불가능한 에러
+// ----------------------------------------------------------------------------
+
+fn panic_demo() {
+    println!("--- panic! ---");
+
+    // panic!은 프로그램을 즉시 종료
+    // C++의 abort() 또는 throw 후 catch 없음과 유사
+
+    // panic!("크래시!");  // 프로그램 종료
+
+    // 배열 범위 초과도 panic
+    let v = vec![1, 2, 3];
+    // let x = v[99];  // panic: index out of bounds
+
+    // panic vs Result:
+    // - panic: 프로그래밍 에러 (버그), 복구 불가능
+    // - Result: 예상 가능한 에러, 복구 가능
+
+    // RUST_BACKTRACE=1로 실행하면 스택 트레이스 확인 가능
+
+    println!("panic 없이 계속 실행");
+}
+
+fn main() {
+    panic_demo();
+}
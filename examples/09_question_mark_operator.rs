@@ -0,0 +1,69 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 09. 에러 처리 (Error Handling) - ? 연산자
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs::File;
+use std::io::{self, Read};
+
+// ----------------------------------------------------------------------------
+// ? 연산자
+// ----------------------------------------------------------------------------
+
+fn question_mark_operator() {
+    println!("\n--- ? 연산자 ---");
+
+    // ? 연산자: Ok면 값 추출, Err면 조기 반환
+    // C++에는 직접적인 대응이 없음 (매크로나 예외로 구현)
+
+    // ? 없이 작성한 코드
+    fn read_username_long() -> Result<String, io::Error> {
+        let file_result = File::open("hello.txt");
+
+        let mut file = match file_result {
+            Ok(f) => f,
+            Err(e) => return Err(e),
+        };
+
+        let mut username = String::new();
+
+        match file.read_to_string(&mut username) {
+            Ok(_) => Ok(username),
+            Err(e) => Err(e),
+        }
+    }
+
+    // ? 사용 - 훨씬 간결
+    fn read_username_short() -> Result<String, io::Error> {
+        let mut file = File::open("hello.txt")?;
+        let mut username = String::new();
+        file.read_to_string(&mut username)?;
+        Ok(username)
+    }
+
+    // 더 짧게 - 체이닝
+    fn read_username_chained() -> Result<String, io::Error> {
+        let mut username = String::new();
+        File::open("hello.txt")?.read_to_string(&mut username)?;
+        Ok(username)
+    }
+
+    // 가장 짧게 - 표준 라이브러리 함수
+    fn read_username_fs() -> Result<String, io::Error> {
+        std::fs::read_to_string("hello.txt")
+    }
+
+    // 결과 확인
+    match read_username_short() {
+        Ok(name) => println!("사용자명: {}", name),
+        Err(e) => println!("읽기 실패: {}", e),
+    }
+
+    // ?는 From 트레이트로 에러 변환도 수행
+    // io::Error -> CustomError 자동 변환 가능
+}
+
+fn main() {
+    question_mark_operator();
+}
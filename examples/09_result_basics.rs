@@ -0,0 +1,54 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 09. 에러 처리 (Error Handling) - Result 기초
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs::File;
+use std::io::{self, Read};
+
+// ----------------------------------------------------------------------------
+// Result 기초
+// ----------------------------------------------------------------------------
+
+fn result_basics() {
+    println!("\n--- Result 기초 ---");
+
+    // Result 정의:
+    // enum Result<T, E> {
+    //     Ok(T),
+    //     Err(E),
+    // }
+
+    // C++: std::expected<T, E> (C++23) 또는 반환값 + 에러 코드
+
+    // 파일 열기 예제 (파일 API 자체는 36장)
+    let result = File::open("hello.txt");
+
+    // match로 처리
+    match result {
+        Ok(file) => println!("파일 열기 성공: {:?}", file),
+        Err(error) => println!("파일 열기 실패: {}", error),
+    }
+
+    // C++ 대비 장점:
+    // 1. 에러를 무시할 수 없음 (Result를 사용하지 않으면 경고)
+    // 2. 어떤 에러가 발생할 수 있는지 타입에서 명확
+    // 3. 컴파일 타임에 에러 처리 강제
+
+    // 에러 종류에 따른 처리
+    let result = File::open("hello.txt");
+
+    match result {
+        Ok(file) => println!("파일: {:?}", file),
+        Err(ref error) if error.kind() == io::ErrorKind::NotFound => {
+            println!("파일을 찾을 수 없음, 생성 시도...");
+            // File::create("hello.txt") 등
+        }
+        Err(error) => println!("기타 에러: {}", error),
+    }
+}
+
+fn main() {
+    result_basics();
+}
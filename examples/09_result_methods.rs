@@ -0,0 +1,78 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 09. 에러 처리 (Error Handling) - Result 메서드
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs::File;
+use std::io::{self, Read};
+
+// ----------------------------------------------------------------------------
+// Result 메서드
+// ----------------------------------------------------------------------------
+
+fn result_methods() {
+    println!("\n--- Result 메서드 ---");
+
+    // unwrap: Ok면 값, Err면 panic
+    // 프로토타입이나 확실히 성공하는 경우에만 사용
+    let ok_result: Result<i32, &str> = Ok(42);
+    println!("unwrap: {}", ok_result.unwrap());
+
+    // expect: unwrap + 커스텀 에러 메시지
+    let ok_result: Result<i32, &str> = Ok(42);
+    println!("expect: {}", ok_result.expect("값이 있어야 함"));
+
+    // unwrap_or: Err일 때 기본값
+    let err_result: Result<i32, &str> = Err("에러");
+    println!("unwrap_or: {}", err_result.unwrap_or(0));
+
+    // unwrap_or_else: Err일 때 클로저 실행
+    let err_result: Result<i32, &str> = Err("에러");
+    let value = err_result.unwrap_or_else(|e| {
+        println!("에러 발생: {}", e);
+        -1
+    });
+    println!("unwrap_or_else: {}", value);
+
+    // map: Ok 내부 값 변환
+    let ok_result: Result<i32, &str> = Ok(5);
+    let doubled = ok_result.map(|n| n * 2);
+    println!("map: {:?}", doubled);
+
+    // map_err: Err 변환
+    let err_result: Result<i32, &str> = Err("문자열 에러");
+    let mapped: Result<i32, String> = err_result.map_err(|e| format!("변환됨: {}", e));
+    println!("map_err: {:?}", mapped);
+
+    // and_then: 체이닝 (flatMap)
+    fn square(x: i32) -> Result<i32, &'static str> {
+        Ok(x * x)
+    }
+
+    let result: Result<i32, &str> = Ok(2);
+    let chained = result.and_then(square).and_then(square);
+    println!("and_then: {:?}", chained); // Ok(16)
+
+    // or_else: Err일 때 다른 Result 시도
+    fn fallback() -> Result<i32, &'static str> {
+        Ok(0)
+    }
+
+    let err_result: Result<i32, &str> = Err("에러");
+    let recovered = err_result.or_else(|_| fallback());
+    println!("or_else: {:?}", recovered);
+
+    // ok: Result -> Option (에러 무시)
+    let result: Result<i32, &str> = Ok(42);
+    let option = result.ok();
+    println!("ok: {:?}", option);
+
+    // is_ok, is_err
+    let result: Result<i32, &str> = Ok(42);
+    println!("is_ok: {}, is_err: {}", result.is_ok(), result.is_err());
+}
+
+fn main() {
+    result_methods();
+}
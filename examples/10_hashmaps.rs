@@ -0,0 +1,305 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 10. 컬렉션 (Collections) - HashMap<K, V>
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+
+// --- rust-study 의 macros 모듈 ---
+mod macros {
+// ============================================================================
+// 공용 매크로 모음 (Shared Macros)
+// ============================================================================
+// 15장에서 정의한 매크로 중 다른 모듈에서도 쓰는 것들을 모아둔 모듈
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 매크로는 #include 순서에 따라 보임 - Rust는 경로(path)로 가져옴
+// 2. #[macro_export] - 크레이트 루트에 매크로를 공개 (crate::my_vec!)
+// 3. pub(crate) use - 일반 아이템처럼 모듈 경로로 재공개 (crate::macros::hashmap!)
+// 4. $crate - 매크로가 어디서 확장되든 정의된 크레이트를 가리키는 경로
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// #[macro_export] 방식
+// ----------------------------------------------------------------------------
+// 모듈 위치와 상관없이 크레이트 루트에 등록됨
+// 사용: use crate::my_vec; 또는 crate::my_vec![...]
+// 라이브러리 크레이트라면 외부에서 my_crate::my_vec! 로 사용 가능
+
+// 요소 개수를 컴파일 타임에 세는 내부용 매크로
+// 다른 매크로가 $crate::count_exprs! 로 호출하므로 반드시 export 되어야 함
+#[doc(hidden)]
+#[macro_export]
+macro_rules! count_exprs {
+    () => { 0usize };
+    ($head:expr $(, $tail:expr)*) => { 1usize + $crate::count_exprs!($($tail),*) };
+}
+
+// vec! 매크로와 유사한 구현
+// $crate::count_exprs! - 호출하는 쪽에서 count_exprs를 use 하지 않아도 동작
+#[macro_export]
+macro_rules! my_vec {
+    // 빈 벡터
+    () => {
+        Vec::new()
+    };
+    // 요소가 있는 벡터 - 개수만큼 미리 할당
+    ($($element:expr),+ $(,)?) => {
+        {
+            let mut v = Vec::with_capacity($crate::count_exprs!($($element),+));
+            $(
+                v.push($element);
+            )+
+            v
+        }
+    };
+}
+
+// ----------------------------------------------------------------------------
+// pub(crate) use 방식 (Rust 2018+ 권장)
+// ----------------------------------------------------------------------------
+// macro_rules! 정의 후 use로 재공개하면 일반 함수처럼 모듈 경로를 가짐
+// 사용: use crate::macros::hashmap;
+
+// 해시맵 생성
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map = std::collections::HashMap::with_capacity(
+                $crate::count_exprs!($($key),*)
+            );
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+}
+
+// 메서드 체이닝 빌더
+macro_rules! builder_field {
+    ($name:ident, $t:ty) => {
+        pub fn $name(mut self, value: $t) -> Self {
+            self.$name = value;
+            self
+        }
+    };
+}
+
+pub(crate) use builder_field;
+pub(crate) use hashmap;
+}
+
+// --- rust-study 의 demo_data 모듈 ---
+mod demo_data {
+// ============================================================================
+// 예제 입력 데이터 (Seeded Demo Data)
+// ============================================================================
+// 레슨의 예제는 보통 vec![1, 2, 3] 처럼 고정된 값을 씀
+// 시드를 주면 같은 자리에 크기와 값이 다른 데이터를 만들어서,
+// 예제의 동작이 특정 입력 때문에 우연히 맞은 것이 아님을 확인할 수 있음
+//
+//   cargo run                      레슨에 적힌 기본값 그대로
+//   cargo run -- --seed 42         시드 42 로 만든 데이터 (다시 실행해도 같음)
+//   cargo run -- --seed random     매번 다른 시드 - 처음에 시드를 출력하므로 재현 가능
+//   RUST_STUDY_SEED=42 cargo run --example 08_generic_functions
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::sync::Mutex;
+
+// 시드를 지정하는 환경 변수 - 예제 프로그램(cargo run --example)도 같은 시드를 씀
+pub const SEED_VAR: &str = "RUST_STUDY_SEED";
+
+// 단어 데이터를 고르는 목록
+const WORDS: &[&str] = &[
+    "apple", "banana", "cherry", "rust", "borrow", "owner", "trait", "closure", "iterator",
+    "thread", "future", "macro", "slice", "vector", "string", "lifetime", "pattern", "module",
+];
+
+// 생성기 상태 - 스레드 예제(13장)에서도 같은 수열을 이어 쓰도록 전역
+// None = 아직 환경 변수를 읽지 않음, Some(None) = 시드 없음 (기본값 사용)
+static STATE: Mutex<Option<Option<u64>>> = Mutex::new(None);
+
+fn state() -> std::sync::MutexGuard<'static, Option<Option<u64>>> {
+    STATE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// cargo run -- --seed N 이 환경 변수보다 우선
+pub fn set_seed(seed: u64) {
+    *state() = Some(Some(seed));
+}
+
+// 지금의 생성기 상태 (시드에서 시작해 값을 만들 때마다 바뀜)
+pub fn seed() -> Option<u64> {
+    *state().get_or_insert_with(|| {
+        std::env::var(SEED_VAR).ok().and_then(|v| v.trim().parse().ok())
+    })
+}
+
+// splitmix64 - 작고 빠르며 시드가 같으면 항상 같은 수열
+fn next() -> Option<u64> {
+    let state = seed()?.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    set_seed(state);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    Some(z ^ (z >> 31))
+}
+
+// [low, high] 범위의 수 하나
+fn between(next: u64, low: i64, high: i64) -> i64 {
+    low + (next % (high - low + 1) as u64) as i64
+}
+
+// 정수 하나 - 시드가 없으면 default
+pub fn number(default: i32, low: i32, high: i32) -> i32 {
+    match next() {
+        Some(n) => between(n, low as i64, high as i64) as i32,
+        None => default,
+    }
+}
+
+// 정수 목록 - 시드가 있으면 기본값 개수 근처(최소 1개)의 1..=100 범위 값
+pub fn numbers(default: &[i32]) -> Vec<i32> {
+    let Some(n) = next() else {
+        return default.to_vec();
+    };
+    let len = between(n, 1.max(default.len() as i64 - 2), default.len() as i64 + 3) as usize;
+    (0..len).map(|_| number(0, 1, 100)).collect()
+}
+
+// 단어 목록 - 시드가 있으면 WORDS 에서 기본값과 같은 개수를 뽑음 (중복 가능)
+pub fn words(default: &[&'static str]) -> Vec<&'static str> {
+    if seed().is_none() {
+        return default.to_vec();
+    }
+    default
+        .iter()
+        .map(|_| WORDS[number(0, 0, WORDS.len() as i32 - 1) as usize])
+        .collect()
+}
+
+// 명령줄의 --seed N / --seed random 을 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> Result<(Option<u64>, Vec<String>), String> {
+    let mut seed = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg != "--seed" {
+            rest.push(arg.clone());
+            continue;
+        }
+        let value = iter.next().ok_or("--seed 뒤에 숫자 또는 random 을 지정하세요")?;
+        seed = Some(match value.as_str() {
+            "random" => random_seed(),
+            v => v.parse().map_err(|_| format!("잘못된 시드: {}", v))?,
+        });
+    }
+    Ok((seed, rest))
+}
+
+// 현재 시각으로 만든 시드 - 출력해 두면 같은 데이터를 다시 볼 수 있음
+fn random_seed() -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    nanos % 100_000
+}
+}
+
+// ----------------------------------------------------------------------------
+// HashMap<K, V>
+// ----------------------------------------------------------------------------
+
+fn hashmaps() {
+    println!("\n--- HashMap ---");
+
+    // C++: std::unordered_map<std::string, int>
+
+    // 생성
+    let mut scores: HashMap<String, i32> = HashMap::new();
+
+    // 삽입
+    // C++: scores["Blue"] = 10;
+    scores.insert(String::from("Blue"), 10);
+    scores.insert(String::from("Yellow"), 50);
+    println!("점수: {:?}", scores);
+
+    // 접근
+    let team_name = String::from("Blue");
+    let score = scores.get(&team_name); // Option<&V> 반환
+    println!("Blue 팀: {:?}", score);
+
+    // copied()로 Option<&i32> -> Option<i32>
+    let score = scores.get(&team_name).copied().unwrap_or(0);
+    println!("Blue 팀 점수: {}", score);
+
+    // 이터레이션
+    for (key, value) in &scores {
+        println!("{}: {}", key, value);
+    }
+
+    // 소유권 - String 키는 이동됨
+    let key = String::from("Red");
+    let value = 25;
+    scores.insert(key, value);
+    // println!("{}", key);  // 에러! key는 이동됨
+
+    // 참조 타입(&str)이면 이동 없음
+    let mut map: HashMap<&str, i32> = HashMap::new();
+    let key = "Green";
+    map.insert(key, 30);
+    println!("key 는 여전히 유효: {}", key);
+
+    // 리터럴로 초기화 - 15장의 hashmap! 매크로 재사용
+    // C++: std::unordered_map<std::string, int> m{{"Alice", 100}, {"Bob", 85}};
+    let literal = crate::macros::hashmap! {
+        "Alice" => 100,
+        "Bob" => 85,
+    };
+    println!("hashmap! 초기화: {} 항목", literal.len());
+
+    // 업데이트 패턴
+    let mut scores = HashMap::new();
+    scores.insert(String::from("Blue"), 10);
+
+    // 덮어쓰기
+    scores.insert(String::from("Blue"), 25);
+    println!("덮어쓰기: {:?}", scores);
+
+    // 없을 때만 삽입 (entry API)
+    // C++: if (map.find(key) == map.end()) map[key] = value;
+    scores.entry(String::from("Yellow")).or_insert(50);
+    scores.entry(String::from("Blue")).or_insert(50); // 이미 있으므로 무시
+    println!("or_insert: {:?}", scores);
+
+    // 기존 값 기반 업데이트
+    // 시드를 주면 다른 단어들 - 같은 단어가 여러 번 나오면 개수가 늘어남
+    let text = crate::demo_data::words(&["hello", "world", "wonderful", "world"]).join(" ");
+    let mut word_count = HashMap::new();
+
+    for word in text.split_whitespace() {
+        let count = word_count.entry(word).or_insert(0);
+        *count += 1;
+    }
+    println!("단어 수: {:?}", word_count);
+
+    // 삭제
+    scores.remove(&String::from("Blue"));
+    println!("삭제 후: {:?}", scores);
+
+    // 해셔 변경
+    // 기본: SipHash (DoS 방어, 약간 느림)
+    // 빠른 해셔 필요시: fnv 또는 ahash 크레이트 사용 (얼마나 빠른지는 benches/hashing.rs)
+    // use std::hash::BuildHasherDefault;
+    // use fnv::FnvHasher;
+    // let mut map: HashMap<i32, i32, BuildHasherDefault<FnvHasher>> = ...
+}
+
+fn main() {
+    hashmaps();
+}
@@ -0,0 +1,76 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 10. 컬렉션 (Collections) - 기타 컬렉션
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+
+// ----------------------------------------------------------------------------
+// 기타 컬렉션
+// ----------------------------------------------------------------------------
+
+fn other_collections() {
+    println!("\n--- 기타 컬렉션 ---");
+
+    // VecDeque - 양방향 큐
+    // C++: std::deque
+    use std::collections::VecDeque;
+
+    let mut deque = VecDeque::new();
+    deque.push_back(1);
+    deque.push_back(2);
+    deque.push_front(0);
+    println!("VecDeque: {:?}", deque);
+    println!("pop_front: {:?}", deque.pop_front());
+
+    // HashSet - 중복 없는 집합
+    // C++: std::unordered_set
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+    set.insert(1);
+    set.insert(2);
+    set.insert(2); // 중복 무시
+    println!("HashSet: {:?}", set);
+    println!("contains(1): {}", set.contains(&1));
+
+    // 집합 연산
+    let a: HashSet<i32> = [1, 2, 3].iter().cloned().collect();
+    let b: HashSet<i32> = [2, 3, 4].iter().cloned().collect();
+
+    println!("합집합: {:?}", a.union(&b).collect::<Vec<_>>());
+    println!("교집합: {:?}", a.intersection(&b).collect::<Vec<_>>());
+    println!("차집합: {:?}", a.difference(&b).collect::<Vec<_>>());
+
+    // BTreeMap - 정렬된 맵
+    // C++: std::map
+    use std::collections::BTreeMap;
+
+    let mut btree = BTreeMap::new();
+    btree.insert(3, "c");
+    btree.insert(1, "a");
+    btree.insert(2, "b");
+    println!("BTreeMap (정렬됨): {:?}", btree);
+
+    // BinaryHeap - 우선순위 큐 (최대 힙)
+    // C++: std::priority_queue
+    use std::collections::BinaryHeap;
+
+    let mut heap = BinaryHeap::new();
+    heap.push(3);
+    heap.push(1);
+    heap.push(4);
+    heap.push(1);
+    heap.push(5);
+
+    println!("BinaryHeap max: {:?}", heap.peek());
+    while let Some(value) = heap.pop() {
+        print!("{} ", value); // 5 4 3 1 1
+    }
+    println!();
+}
+
+fn main() {
+    other_collections();
+}
@@ -0,0 +1,104 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 10. 컬렉션 (Collections) - String - UTF-8 문자열
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+
+// ----------------------------------------------------------------------------
+// String - UTF-8 문자열
+// ----------------------------------------------------------------------------
+
+fn strings() {
+    println!("\n--- String ---");
+
+    // Rust의 문자열 타입:
+    // - String: 소유, 가변, 힙 할당, UTF-8
+    // - &str: 빌림, 불변, 문자열 슬라이스
+
+    // 생성
+    let mut s = String::new();
+    let s = String::from("안녕하세요");
+    let s = "hello".to_string();
+
+    println!("문자열: {}", s);
+
+    // 문자열 추가
+    let mut s = String::from("foo");
+
+    // push_str: 문자열 슬라이스 추가
+    s.push_str("bar");
+    println!("push_str 후: {}", s);
+
+    // push: 단일 문자 추가
+    s.push('!');
+    println!("push 후: {}", s);
+
+    // + 연산자 (소유권 이동 주의!)
+    let s1 = String::from("Hello, ");
+    let s2 = String::from("world!");
+    let s3 = s1 + &s2; // s1은 이동됨!
+    // println!("{}", s1);  // 에러!
+    println!("연결: {}", s3);
+
+    // format! 매크로 - 소유권 이동 없음
+    let s1 = String::from("tic");
+    let s2 = String::from("tac");
+    let s3 = String::from("toe");
+    let s = format!("{}-{}-{}", s1, s2, s3);
+    println!("format!: {}", s);
+    // s1, s2, s3 모두 여전히 유효
+
+    // UTF-8과 인덱싱
+    // C++: s[0] 으로 바이트 접근 가능
+    // Rust: 인덱싱 불가! UTF-8 문자는 가변 길이
+
+    let hello = String::from("안녕");
+    // let c = hello[0];  // 컴파일 에러!
+
+    // 왜 인덱싱이 안 되나?
+    // "안녕" = 6바이트 (한글은 3바이트씩)
+    // hello[0]이 뭘 반환해야 할지 모호함
+    println!("'안녕' 바이트 수: {}", hello.len()); // 6
+
+    // 문자 단위 이터레이션
+    print!("문자: ");
+    for c in "안녕".chars() {
+        print!("{} ", c);
+    }
+    println!();
+
+    // 바이트 단위 이터레이션
+    print!("바이트: ");
+    for b in "안녕".bytes() {
+        print!("{} ", b);
+    }
+    println!();
+
+    // 슬라이싱 (바이트 경계 주의!)
+    let s = "안녕하세요";
+    let slice = &s[0..3]; // "안" (3바이트)
+    println!("슬라이스: {}", slice);
+    // let bad = &s[0..2];  // panic! 문자 중간을 자름
+    // 입력이 정하는 인덱스로 자르면 이런 panic 이 숨어 있음 - 퍼저로 찾는 법은 43장 (s.get(a..b) 는 None)
+
+    // 문자 인덱스로 접근하려면
+    let s = "안녕하세요";
+    let second_char = s.chars().nth(1);
+    println!("두 번째 문자: {:?}", second_char);
+
+    // 유용한 메서드들
+    let s = "  hello world  ";
+    println!("trim: '{}'", s.trim());
+    println!("contains: {}", s.contains("world"));
+    println!("replace: {}", s.replace("world", "rust"));
+
+    let s = "hello,world,rust";
+    let parts: Vec<&str> = s.split(',').collect();
+    println!("split: {:?}", parts);
+}
+
+fn main() {
+    strings();
+}
@@ -0,0 +1,110 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 10. 컬렉션 (Collections) - Vec<T> - 가변 길이 배열
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+
+// ----------------------------------------------------------------------------
+// Vec<T> - 가변 길이 배열
+// ----------------------------------------------------------------------------
+
+fn vectors() {
+    println!("--- Vec<T> ---");
+
+    // 생성
+    // C++: std::vector<int> v;
+    let mut v: Vec<i32> = Vec::new();
+
+    // vec! 매크로 - C++ initializer_list와 유사
+    // C++: std::vector<int> v = {1, 2, 3};
+    let mut v = vec![1, 2, 3];
+    println!("초기 벡터: {:?}", v);
+
+    // 요소 추가
+    // C++: v.push_back(4);
+    v.push(4);
+    v.push(5);
+    println!("push 후: {:?}", v);
+
+    // 요소 접근
+    // 인덱스 접근 - 범위 초과 시 panic
+    let third = v[2];
+    println!("세 번째 요소: {}", third);
+
+    // get - Option 반환, 안전한 접근
+    // C++: v.at(2) 또는 범위 체크 후 v[2]
+    match v.get(2) {
+        Some(value) => println!("get(2): {}", value),
+        None => println!("인덱스 초과"),
+    }
+
+    match v.get(100) {
+        Some(value) => println!("get(100): {}", value),
+        None => println!("get(100): 범위 초과"),
+    }
+
+    // 이터레이션 - 불변 참조
+    // C++: for (const auto& elem : v) { ... }
+    print!("불변 이터레이션: ");
+    for elem in &v {
+        print!("{} ", elem);
+    }
+    println!();
+
+    // 이터레이션 - 가변 참조
+    // C++: for (auto& elem : v) { elem *= 2; }
+    for elem in &mut v {
+        *elem *= 2;
+    }
+    println!("두 배 후: {:?}", v);
+
+    // 요소 제거
+    // C++: v.pop_back();
+    let last = v.pop(); // Option<T> 반환
+    println!("pop: {:?}, 벡터: {:?}", last, v);
+
+    // 특정 인덱스 제거
+    // C++: v.erase(v.begin() + 1);
+    let removed = v.remove(1);
+    println!("remove(1): {}, 벡터: {:?}", removed, v);
+
+    // 소유권과 벡터
+    let v = vec![String::from("a"), String::from("b")];
+
+    // 인덱스로 접근하면 참조를 얻음
+    let first = &v[0];
+    println!("첫 번째: {}", first);
+
+    // 소유권을 가져오려면
+    let mut v = vec![String::from("a"), String::from("b")];
+    let owned = v.remove(0); // 벡터에서 제거하며 소유권 획득
+    println!("소유: {}, 벡터: {:?}", owned, v);
+
+    // 다양한 타입 저장 - enum 사용
+    #[derive(Debug)]
+    enum Cell {
+        Int(i32),
+        Float(f64),
+        Text(String),
+    }
+
+    let row = vec![
+        Cell::Int(3),
+        Cell::Float(10.5),
+        Cell::Text(String::from("hello")),
+    ];
+    println!("혼합 벡터: {:?}", row);
+
+    // 용량 관리
+    // C++: v.capacity(), v.reserve(100)
+    let mut v: Vec<i32> = Vec::with_capacity(100);
+    println!("용량: {}, 길이: {}", v.capacity(), v.len());
+    v.push(1);
+    println!("push 후 용량: {}, 길이: {}", v.capacity(), v.len());
+}
+
+fn main() {
+    vectors();
+}
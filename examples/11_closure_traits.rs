@@ -0,0 +1,67 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 11. 이터레이터와 클로저 (Iterators and Closures) - 클로저 트레이트
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 클로저 트레이트
+// ----------------------------------------------------------------------------
+
+fn closure_traits() {
+    println!("\n--- 클로저 트레이트 ---");
+
+    // Rust 클로저는 세 가지 트레이트 중 하나 이상 구현:
+    // - FnOnce: 한 번만 호출 가능 (소유권 이동)
+    // - FnMut: 여러 번 호출, 가변 참조
+    // - Fn: 여러 번 호출, 불변 참조
+
+    // FnOnce 예제 - 캡처한 값을 소비
+    fn call_once<F>(f: F)
+    where
+        F: FnOnce() -> String,
+    {
+        println!("결과: {}", f());
+    }
+
+    let s = String::from("hello");
+    let consume = move || s;  // s를 반환하며 소비
+    call_once(consume);
+    // call_once(consume);  // 에러! 이미 소비됨
+
+    // FnMut 예제 - 가변 상태 수정
+    fn call_mut_twice<F>(mut f: F)
+    where
+        F: FnMut(),
+    {
+        f();
+        f();
+    }
+
+    let mut total = 0;
+    let mut add_ten = || total += 10;
+    call_mut_twice(&mut add_ten);
+    println!("FnMut 호출 후 total: {}", total);
+
+    // Fn 예제 - 순수 함수처럼 동작
+    fn call_many_times<F>(f: F)
+    where
+        F: Fn() -> i32,
+    {
+        for _ in 0..3 {
+            println!("호출: {}", f());
+        }
+    }
+
+    let x = 10;
+    let get_x = || x;  // x를 불변 참조로 캡처
+    call_many_times(get_x);
+
+    // 트레이트 계층:
+    // Fn : FnMut : FnOnce
+    // Fn을 구현하면 FnMut과 FnOnce도 자동 구현
+}
+
+fn main() {
+    closure_traits();
+}
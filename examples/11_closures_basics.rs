@@ -0,0 +1,78 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 11. 이터레이터와 클로저 (Iterators and Closures) - 클로저 기초
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 클로저 기초
+// ----------------------------------------------------------------------------
+
+fn closures_basics() {
+    println!("--- 클로저 기초 ---");
+
+    // 클로저 = 익명 함수, 환경 캡처 가능
+    // C++: [captures](params) { body }
+    // Rust: |params| body
+
+    // 기본 클로저
+    let add_one = |x: i32| x + 1;
+    println!("add_one(5) = {}", add_one(5));
+
+    // 타입 추론 - 첫 사용에서 결정
+    let add = |a, b| a + b;
+    println!("add(2, 3) = {}", add(2, 3));
+
+    // 블록 본문
+    let complex = |x| {
+        let y = x * 2;
+        y + 1
+    };
+    println!("complex(5) = {}", complex(5));
+
+    // 환경 캡처
+    let x = 4;
+    let equal_to_x = |z| z == x;  // x를 캡처
+    println!("equal_to_x(4) = {}", equal_to_x(4));
+
+    // C++ 비교:
+    // auto equal_to_x = [x](int z) { return z == x; };
+
+    // 캡처 방식 - Rust는 자동으로 최소 권한 캡처
+    // 1. 불변 참조 (&T) - 기본
+    // 2. 가변 참조 (&mut T) - 수정 필요시
+    // 3. 소유권 (T) - 이동 필요시
+
+    // 불변 참조 캡처
+    let list = vec![1, 2, 3];
+    let print_list = || println!("리스트: {:?}", list);
+    print_list();
+    println!("여전히 사용 가능: {:?}", list);
+
+    // 가변 참조 캡처
+    let mut count = 0;
+    let mut increment = || {
+        count += 1;  // &mut count 캡처
+        println!("count = {}", count);
+    };
+    increment();
+    increment();
+    // println!("{}", count);  // increment가 살아있는 동안 에러
+    drop(increment);  // 명시적 drop으로 빌림 해제
+    println!("최종 count: {}", count);
+
+    // move 키워드 - 소유권 강제 이동
+    let data = vec![1, 2, 3];
+    let owns_data = move || {
+        println!("내 데이터: {:?}", data);
+    };
+    owns_data();
+    // println!("{:?}", data);  // 에러! data는 이동됨
+
+    // 스레드에 데이터 전달할 때 move 필수
+    // std::thread::spawn(move || { ... });
+}
+
+fn main() {
+    closures_basics();
+}
@@ -0,0 +1,75 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 11. 이터레이터와 클로저 (Iterators and Closures) - 커스텀 이터레이터
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 커스텀 이터레이터
+// ----------------------------------------------------------------------------
+
+fn custom_iterator() {
+    println!("\n--- 커스텀 이터레이터 ---");
+
+    // Iterator 트레이트 구현
+    struct Counter {
+        count: u32,
+        max: u32,
+    }
+
+    impl Counter {
+        fn new(max: u32) -> Counter {
+            Counter { count: 0, max }
+        }
+    }
+
+    impl Iterator for Counter {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.count < self.max {
+                self.count += 1;
+                Some(self.count)
+            } else {
+                None
+            }
+        }
+    }
+
+    // 사용
+    let counter = Counter::new(5);
+    println!("Counter: {:?}", counter.collect::<Vec<_>>());
+
+    // 어댑터도 사용 가능 - 다른 이터레이터를 감싸는 어댑터를 직접 만드는 법은 57장
+    let sum: u32 = Counter::new(5)
+        .zip(Counter::new(5).skip(1))
+        .map(|(a, b)| a * b)
+        .filter(|x| x % 3 == 0)
+        .sum();
+    println!("복잡한 계산: {}", sum);
+
+    // IntoIterator 트레이트 - for 루프 지원
+    struct Range {
+        start: i32,
+        end: i32,
+    }
+
+    impl IntoIterator for Range {
+        type Item = i32;
+        type IntoIter = std::ops::Range<i32>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.start..self.end
+        }
+    }
+
+    let range = Range { start: 1, end: 5 };
+    for i in range {
+        print!("{} ", i);
+    }
+    println!();
+}
+
+fn main() {
+    custom_iterator();
+}
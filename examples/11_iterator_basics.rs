@@ -0,0 +1,62 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 11. 이터레이터와 클로저 (Iterators and Closures) - 이터레이터 기초
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 이터레이터 기초
+// ----------------------------------------------------------------------------
+
+fn iterator_basics() {
+    println!("\n--- 이터레이터 기초 ---");
+
+    // Iterator 트레이트:
+    // trait Iterator {
+    //     type Item;
+    //     fn next(&mut self) -> Option<Self::Item>;
+    // }
+
+    let v = vec![1, 2, 3];
+
+    // iter() - 불변 참조 이터레이터
+    let mut iter = v.iter();
+    println!("next: {:?}", iter.next());  // Some(&1)
+    println!("next: {:?}", iter.next());  // Some(&2)
+    println!("next: {:?}", iter.next());  // Some(&3)
+    println!("next: {:?}", iter.next());  // None
+
+    // 세 가지 이터레이터 메서드:
+    // iter()      - &T 이터레이터
+    // iter_mut()  - &mut T 이터레이터
+    // into_iter() - T 이터레이터 (소유권 이동)
+
+    // C++ 비교:
+    // iter()      ~ cbegin()/cend()
+    // iter_mut()  ~ begin()/end()
+    // into_iter() ~ std::make_move_iterator()
+
+    let v = vec![1, 2, 3];
+
+    // for 루프는 into_iter() 호출
+    for val in v {  // v.into_iter()
+        println!("소유: {}", val);
+    }
+    // println!("{:?}", v);  // 에러! v는 이동됨
+
+    let v = vec![1, 2, 3];
+    for val in &v {  // (&v).into_iter() = v.iter()
+        println!("참조: {}", val);
+    }
+    println!("여전히 사용 가능: {:?}", v);
+
+    // 범위 이터레이터
+    for i in 0..5 {
+        print!("{} ", i);
+    }
+    println!();
+}
+
+fn main() {
+    iterator_basics();
+}
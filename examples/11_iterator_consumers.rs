@@ -0,0 +1,74 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 11. 이터레이터와 클로저 (Iterators and Closures) - 이터레이터 소비자
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 이터레이터 소비자
+// ----------------------------------------------------------------------------
+
+fn iterator_consumers() {
+    println!("\n--- 이터레이터 소비자 ---");
+
+    // 소비자 = 이터레이터를 소비하여 결과 생성
+    // 호출하면 실제로 이터레이션 수행
+
+    let v = vec![1, 2, 3, 4, 5];
+
+    // collect - 컬렉션으로 수집
+    let squared: Vec<i32> = v.iter().map(|x| x * x).collect();
+    println!("collect: {:?}", squared);
+
+    // sum, product
+    let sum: i32 = v.iter().sum();
+    let product: i32 = v.iter().product();
+    println!("sum: {}, product: {}", sum, product);
+
+    // fold - 누적 연산 (C++ std::accumulate)
+    let sum = v.iter().fold(0, |acc, x| acc + x);
+    let concat = v.iter().fold(String::new(), |acc, x| acc + &x.to_string());
+    println!("fold 합계: {}, 이어붙이기: {}", sum, concat);
+
+    // reduce - fold와 비슷하지만 초기값 없음
+    let max = v.iter().copied().reduce(|a, b| if a > b { a } else { b });
+    println!("reduce 최댓값: {:?}", max);
+
+    // find - 조건에 맞는 첫 요소
+    let first_even = v.iter().find(|x| *x % 2 == 0);
+    println!("find 첫 짝수: {:?}", first_even);
+
+    // position - 조건에 맞는 첫 인덱스
+    let pos = v.iter().position(|x| *x == 3);
+    println!("3 의 position: {:?}", pos);
+
+    // any, all
+    let has_even = v.iter().any(|x| x % 2 == 0);
+    let all_positive = v.iter().all(|x| *x > 0);
+    println!("any 짝수: {}, all 양수: {}", has_even, all_positive);
+
+    // count
+    let count = v.iter().filter(|x| *x % 2 == 0).count();
+    println!("짝수 개수: {}", count);
+
+    // min, max
+    println!("min: {:?}, max: {:?}", v.iter().min(), v.iter().max());
+
+    // min_by, max_by - 커스텀 비교
+    let strings = vec!["hello", "hi", "hey"];
+    let shortest = strings.iter().min_by_key(|s| s.len());
+    println!("shortest: {:?}", shortest);
+
+    // partition - 두 그룹으로 분리
+    let (evens, odds): (Vec<&i32>, Vec<&i32>) = v.iter().partition(|x| *x % 2 == 0);
+    println!("evens: {:?}, odds: {:?}", evens, odds);
+
+    // for_each - 부작용 수행
+    print!("for_each: ");
+    v.iter().for_each(|x| print!("{} ", x));
+    println!();
+}
+
+fn main() {
+    iterator_consumers();
+}
@@ -0,0 +1,54 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 12. 스마트 포인터 (Smart Pointers) - Box<T> - 힙 할당 단일 소유권
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// ----------------------------------------------------------------------------
+// Box<T> - 힙 할당 단일 소유권
+// ----------------------------------------------------------------------------
+
+fn box_pointer() {
+    println!("--- Box<T> ---");
+
+    // Box = 힙에 데이터 저장
+    // C++: std::unique_ptr<int> ptr = std::make_unique<int>(5);
+    let b = Box::new(5);
+    println!("Box: {}", b);
+
+    // Box 사용 이유:
+    // 1. 컴파일 타임에 크기를 알 수 없는 타입
+    // 2. 큰 데이터의 소유권 이전 (복사 방지)
+    // 3. 트레이트 객체
+
+    // 재귀 타입 정의에 필수
+    // 이것은 컴파일 에러:
+    // enum List { Cons(i32, List), Nil }  // 무한 크기!
+
+    // Box로 해결:
+    #[derive(Debug)]
+    enum List {
+        Cons(i32, Box<List>),
+        Nil,
+    }
+
+    use List::{Cons, Nil};
+
+    let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+    println!("List: {:?}", list);
+
+    // Box는 스택처럼 사용 가능 (Deref)
+    let x = 5;
+    let y = Box::new(x);
+
+    assert_eq!(5, x);
+    assert_eq!(5, *y);  // 역참조
+    println!("Box 역참조: {}", *y);
+}
+
+fn main() {
+    box_pointer();
+}
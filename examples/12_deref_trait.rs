@@ -0,0 +1,59 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 12. 스마트 포인터 (Smart Pointers) - Deref 트레이트 - 역참조 연산자 오버로딩
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// ----------------------------------------------------------------------------
+// Deref 트레이트 - 역참조 연산자 오버로딩
+// ----------------------------------------------------------------------------
+
+fn deref_trait() {
+    println!("\n--- Deref 트레이트 ---");
+
+    // Deref 트레이트로 * 연산자 커스터마이즈
+
+    use std::ops::Deref;
+
+    struct MyBox<T>(T);
+
+    impl<T> MyBox<T> {
+        fn new(x: T) -> MyBox<T> {
+            MyBox(x)
+        }
+    }
+
+    impl<T> Deref for MyBox<T> {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    let x = 5;
+    let y = MyBox::new(x);
+
+    assert_eq!(5, *y);  // *(y.deref()) 로 변환됨
+    println!("MyBox 역참조: {}", *y);
+
+    // 역참조 강제 변환 (Deref Coercion)
+    // &String -> &str 자동 변환이 이것 때문
+
+    fn hello(name: &str) {
+        println!("Hello, {}!", name);
+    }
+
+    let m = MyBox::new(String::from("Rust"));
+    hello(&m);  // &MyBox<String> -> &String -> &str 자동 변환
+
+    // DerefMut - 가변 역참조
+    // impl<T> DerefMut for MyBox<T> { ... }
+}
+
+fn main() {
+    deref_trait();
+}
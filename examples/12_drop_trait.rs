@@ -0,0 +1,54 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 12. 스마트 포인터 (Smart Pointers) - Drop 트레이트 - 소멸자
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// ----------------------------------------------------------------------------
+// Drop 트레이트 - 소멸자
+// ----------------------------------------------------------------------------
+
+fn drop_trait() {
+    println!("\n--- Drop 트레이트 ---");
+
+    // Drop = C++ 소멸자
+    // 스코프 벗어날 때 자동 호출
+
+    struct CustomSmartPointer {
+        data: String,
+    }
+
+    impl Drop for CustomSmartPointer {
+        fn drop(&mut self) {
+            println!("CustomSmartPointer 해제: {}", self.data);
+        }
+    }
+
+    {
+        let _c = CustomSmartPointer {
+            data: String::from("my stuff"),
+        };
+        let _d = CustomSmartPointer {
+            data: String::from("other stuff"),
+        };
+        println!("CustomSmartPointers 생성됨");
+    }  // d 먼저, 그 다음 c (역순) - 필드와 임시 값의 순서, ManuallyDrop, 스코프 가드는 54장
+
+    println!("스코프 종료 후");
+
+    // 조기 해제 - std::mem::drop 사용
+    let c = CustomSmartPointer {
+        data: String::from("조기 해제"),
+    };
+    println!("조기 해제 전");
+    drop(c);  // 여기서 해제
+    // c.drop();  // 이건 에러! drop()은 직접 호출 불가
+    println!("조기 해제 후");
+}
+
+fn main() {
+    drop_trait();
+}
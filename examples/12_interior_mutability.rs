@@ -0,0 +1,75 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 12. 스마트 포인터 (Smart Pointers) - 내부 가변성 패턴
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// ----------------------------------------------------------------------------
+// 내부 가변성 패턴
+// ----------------------------------------------------------------------------
+
+fn interior_mutability() {
+    println!("\n--- 내부 가변성 ---");
+
+    // 불변 참조를 통해 내부 데이터 수정 가능
+    // "눈속임" 가변성 - 외부에서는 불변으로 보임
+
+    // Rc<RefCell<T>> 조합 - 여러 소유자 + 가변성
+    #[derive(Debug)]
+    struct Node {
+        value: i32,
+        children: RefCell<Vec<Rc<Node>>>,
+    }
+
+    let leaf = Rc::new(Node {
+        value: 3,
+        children: RefCell::new(vec![]),
+    });
+
+    let branch = Rc::new(Node {
+        value: 5,
+        children: RefCell::new(vec![Rc::clone(&leaf)]),
+    });
+
+    // 불변 참조지만 children 수정 가능
+    branch.children.borrow_mut().push(Rc::new(Node {
+        value: 10,
+        children: RefCell::new(vec![]),
+    }));
+
+    println!("트리: {:?}", branch);
+
+    // Mock 객체 예제
+    pub trait Messenger {
+        fn send(&self, msg: &str);
+    }
+
+    struct MockMessenger {
+        sent_messages: RefCell<Vec<String>>,  // 내부 가변성
+    }
+
+    impl MockMessenger {
+        fn new() -> MockMessenger {
+            MockMessenger {
+                sent_messages: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl Messenger for MockMessenger {
+        fn send(&self, message: &str) {  // &self인데도
+            self.sent_messages.borrow_mut().push(String::from(message));  // 수정 가능
+        }
+    }
+
+    let mock = MockMessenger::new();
+    mock.send("테스트 메시지");
+    println!("전송된 메시지: {:?}", mock.sent_messages.borrow());
+}
+
+fn main() {
+    interior_mutability();
+}
@@ -0,0 +1,53 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 12. 스마트 포인터 (Smart Pointers) - Rc<T> - 참조 카운팅 (단일 스레드)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// ----------------------------------------------------------------------------
+// Rc<T> - 참조 카운팅 (단일 스레드)
+// ----------------------------------------------------------------------------
+
+fn rc_pointer() {
+    println!("\n--- Rc<T> ---");
+
+    // Rc = Reference Counted
+    // C++: std::shared_ptr (단일 스레드 전용)
+
+    // 여러 소유자가 필요한 경우
+    #[derive(Debug)]
+    enum List {
+        Cons(i32, Rc<List>),
+        Nil,
+    }
+
+    use List::{Cons, Nil};
+
+    // 공유 리스트
+    let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
+    println!("a 생성 후 카운트: {}", Rc::strong_count(&a));
+
+    // Rc::clone은 얕은 복사 (카운트만 증가) - 깊은 복사와의 차이는 benches/rc_clone.rs
+    let b = Cons(3, Rc::clone(&a));
+    println!("b 생성 후 카운트: {}", Rc::strong_count(&a));
+
+    {
+        let c = Cons(4, Rc::clone(&a));
+        println!("c 생성 후 카운트: {}", Rc::strong_count(&a));
+    }
+
+    println!("c 해제 후 카운트: {}", Rc::strong_count(&a));
+
+    // Rc는 불변! 데이터 수정 불가
+    // 가변이 필요하면 Rc<RefCell<T>> 사용
+
+    // 주의: Rc는 단일 스레드 전용!
+    // 멀티스레드에서는 Arc<T> 사용
+}
+
+fn main() {
+    rc_pointer();
+}
@@ -0,0 +1,50 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 12. 스마트 포인터 (Smart Pointers) - RefCell<T> - 런타임 빌림 검사
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// ----------------------------------------------------------------------------
+// RefCell<T> - 런타임 빌림 검사
+// ----------------------------------------------------------------------------
+
+fn refcell_pointer() {
+    println!("\n--- RefCell<T> ---");
+
+    // RefCell = 런타임에 빌림 규칙 검사
+    // 컴파일 타임에 안전성 증명 어려울 때 사용
+
+    // Box<T>: 컴파일 타임 빌림, 가변/불변 소유권
+    // Rc<T>: 컴파일 타임 빌림, 불변 공유 소유권
+    // RefCell<T>: 런타임 빌림, 가변/불변 단일 소유권
+    // Cell, OnceCell, LazyCell 과 스레드 안전한 짝(Mutex, OnceLock 등) 비교는 52장
+
+    let data = RefCell::new(5);
+
+    // borrow() - 불변 참조 (Ref<T>)
+    {
+        let r1 = data.borrow();
+        let r2 = data.borrow();  // 여러 불변 참조 OK
+        println!("불변 참조: {}, {}", *r1, *r2);
+    }
+
+    // borrow_mut() - 가변 참조 (RefMut<T>)
+    {
+        let mut r = data.borrow_mut();
+        *r += 10;
+        println!("가변 참조로 수정: {}", *r);
+    }
+
+    println!("최종 값: {}", data.borrow());
+
+    // 런타임 패닉 예제 (주석 해제하면 패닉)
+    // let r1 = data.borrow();
+    // let r2 = data.borrow_mut();  // 패닉! 불변 참조 있는데 가변 참조 시도
+}
+
+fn main() {
+    refcell_pointer();
+}
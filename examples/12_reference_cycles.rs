@@ -0,0 +1,88 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 12. 스마트 포인터 (Smart Pointers) - 순환 참조와 Weak<T>
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// ----------------------------------------------------------------------------
+// 순환 참조와 Weak<T>
+// ----------------------------------------------------------------------------
+
+fn reference_cycles() {
+    println!("\n--- 순환 참조 방지 ---");
+
+    // Rc로 순환 참조 만들면 메모리 누수!
+    // Weak<T>로 해결 (C++ weak_ptr과 동일)
+
+    // Weak 특징:
+    // - strong_count에 영향 없음
+    // - 참조 대상이 해제될 수 있음
+    // - 사용하려면 upgrade() -> Option<Rc<T>>
+
+    #[derive(Debug)]
+    struct TreeNode {
+        value: i32,
+        parent: RefCell<Weak<TreeNode>>,      // 부모는 Weak로
+        children: RefCell<Vec<Rc<TreeNode>>>, // 자식은 Rc로
+    }
+
+    let leaf = Rc::new(TreeNode {
+        value: 3,
+        parent: RefCell::new(Weak::new()),
+        children: RefCell::new(vec![]),
+    });
+
+    println!(
+        "leaf strong: {}, weak: {}",
+        Rc::strong_count(&leaf),
+        Rc::weak_count(&leaf)
+    );
+
+    {
+        let branch = Rc::new(TreeNode {
+            value: 5,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![Rc::clone(&leaf)]),
+        });
+
+        // leaf의 부모를 branch로 설정
+        *leaf.parent.borrow_mut() = Rc::downgrade(&branch);
+
+        println!(
+            "branch strong: {}, weak: {}",
+            Rc::strong_count(&branch),
+            Rc::weak_count(&branch)
+        );
+
+        println!(
+            "leaf strong: {}, weak: {}",
+            Rc::strong_count(&leaf),
+            Rc::weak_count(&leaf)
+        );
+
+        // 부모 접근
+        if let Some(parent) = leaf.parent.borrow().upgrade() {
+            println!("leaf의 부모 값: {}", parent.value);
+        }
+    }  // branch 해제됨
+
+    // branch 해제 후 부모 접근 시도
+    println!(
+        "branch 해제 후 leaf strong: {}, weak: {}",
+        Rc::strong_count(&leaf),
+        Rc::weak_count(&leaf)
+    );
+
+    let parent_upgrade = leaf.parent.borrow().upgrade();
+    match parent_upgrade {
+        Some(parent) => println!("부모: {}", parent.value),
+        None => println!("부모가 이미 해제됨"),
+    }
+}
+
+fn main() {
+    reference_cycles();
+}
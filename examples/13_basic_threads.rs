@@ -0,0 +1,40 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 13. 동시성 (Concurrency) - 기본 스레드
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+// ----------------------------------------------------------------------------
+// 기본 스레드
+// ----------------------------------------------------------------------------
+
+fn basic_threads() {
+    println!("--- 기본 스레드 ---");
+
+    // C++: std::thread t([] { ... });
+    let handle = thread::spawn(|| {
+        for i in 1..5 {
+            println!("  스레드: {}", i);
+            thread::sleep(Duration::from_millis(1));
+        }
+    });
+
+    for i in 1..3 {
+        println!("메인: {}", i);
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    // join으로 스레드 종료 대기
+    // C++: t.join();
+    handle.join().unwrap();
+
+    println!("모든 스레드 완료");
+}
+
+fn main() {
+    basic_threads();
+}
@@ -0,0 +1,83 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 13. 동시성 (Concurrency) - 채널 (Message Passing)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+// ----------------------------------------------------------------------------
+// 채널 (Message Passing)
+// ----------------------------------------------------------------------------
+
+fn channels() {
+    println!("\n--- 채널 ---");
+
+    // mpsc = Multiple Producer, Single Consumer
+    // C++에는 없음, Go의 채널과 유사
+    // 수신자도 여럿(MPMC)이거나 select 가 필요하면 crossbeam 채널: 33장
+
+    // 채널 생성
+    let (tx, rx) = mpsc::channel();
+
+    // 송신 스레드
+    thread::spawn(move || {
+        let val = String::from("안녕하세요");
+        tx.send(val).unwrap();
+        // println!("{}", val);  // 에러! val은 이동됨
+    });
+
+    // 수신 (블로킹)
+    let received = rx.recv().unwrap();
+    println!("수신: {}", received);
+
+    // 여러 값 전송
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let vals = vec!["a", "b", "c", "d"];
+        for val in vals {
+            tx.send(val).unwrap();
+            thread::sleep(Duration::from_millis(100));
+        }
+    });
+
+    // 이터레이터로 수신
+    print!("수신: ");
+    for received in rx {
+        print!("{} ", received);
+    }
+    println!();
+
+    // 여러 송신자
+    let (tx, rx) = mpsc::channel();
+    let tx2 = tx.clone();  // 송신자 복제
+
+    thread::spawn(move || {
+        tx.send("스레드1").unwrap();
+    });
+
+    thread::spawn(move || {
+        tx2.send("스레드2").unwrap();
+    });
+
+    for _ in 0..2 {
+        println!("다중 송신자: {}", rx.recv().unwrap());
+    }
+
+    // 비블로킹 수신
+    let (tx, rx) = mpsc::channel::<i32>();
+    drop(tx);  // 송신자 닫기
+
+    match rx.try_recv() {
+        Ok(val) => println!("값: {}", val),
+        Err(mpsc::TryRecvError::Empty) => println!("데이터 없음"),
+        Err(mpsc::TryRecvError::Disconnected) => println!("채널 닫힘"),
+    }
+}
+
+fn main() {
+    channels();
+}
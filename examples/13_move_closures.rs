@@ -0,0 +1,37 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 13. 동시성 (Concurrency) - move 클로저
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+// ----------------------------------------------------------------------------
+// move 클로저
+// ----------------------------------------------------------------------------
+
+fn move_closures() {
+    println!("\n--- move 클로저 ---");
+
+    let v = vec![1, 2, 3];
+
+    // move 없이는 컴파일 에러!
+    // 스레드가 v보다 오래 살 수 있으므로
+    let handle = thread::spawn(move || {
+        println!("스레드에서 벡터: {:?}", v);
+    });
+
+    // println!("{:?}", v);  // 에러! v는 이동됨
+
+    handle.join().unwrap();
+
+    // C++ 비교:
+    // std::vector<int> v = {1, 2, 3};
+    // std::thread t([v = std::move(v)] { ... });  // 캡처에 move 필요
+}
+
+fn main() {
+    move_closures();
+}
@@ -0,0 +1,67 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 13. 동시성 (Concurrency) - RwLock - 읽기/쓰기 락
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+// ----------------------------------------------------------------------------
+// RwLock - 읽기/쓰기 락
+// ----------------------------------------------------------------------------
+
+fn rwlock_example() {
+    println!("\n--- RwLock ---");
+
+    // RwLock - 여러 읽기 또는 하나의 쓰기
+    // C++: std::shared_mutex
+
+    let lock = RwLock::new(5);
+
+    // 여러 읽기 동시 가능
+    {
+        let r1 = lock.read().unwrap();
+        let r2 = lock.read().unwrap();
+        println!("읽기: {}, {}", *r1, *r2);
+    }
+
+    // 쓰기는 독점
+    {
+        let mut w = lock.write().unwrap();
+        *w += 1;
+        println!("쓰기 후: {}", *w);
+    }
+
+    // 멀티스레드에서 사용
+    let data = Arc::new(RwLock::new(vec![1, 2, 3]));
+    let mut handles = vec![];
+
+    // 읽기 스레드들
+    for i in 0..3 {
+        let data = Arc::clone(&data);
+        handles.push(thread::spawn(move || {
+            let read = data.read().unwrap();
+            println!("스레드 {} 읽기: {:?}", i, *read);
+        }));
+    }
+
+    // 쓰기 스레드
+    {
+        let data = Arc::clone(&data);
+        handles.push(thread::spawn(move || {
+            let mut write = data.write().unwrap();
+            write.push(4);
+            println!("쓰기 스레드: {:?}", *write);
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+fn main() {
+    rwlock_example();
+}
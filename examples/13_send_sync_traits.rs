@@ -0,0 +1,51 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 13. 동시성 (Concurrency) - Send와 Sync 트레이트
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+// ----------------------------------------------------------------------------
+// Send와 Sync 트레이트
+// ----------------------------------------------------------------------------
+
+fn send_sync_traits() {
+    println!("\n--- Send와 Sync 트레이트 ---");
+
+    // Send: 스레드 간 소유권 이전 가능
+    // Sync: 스레드 간 참조 공유 가능
+
+    // 대부분의 타입은 자동으로 Send + Sync
+    // Rc<T>는 Send/Sync 아님 (Arc 사용해야 함)
+    // RefCell<T>는 Sync 아님 (Mutex 사용해야 함)
+    // *const T, *mut T는 Send/Sync 아님
+
+    // 예: Rc를 스레드에 보내려 하면 컴파일 에러
+    // let rc = std::rc::Rc::new(5);
+    // thread::spawn(move || {
+    //     println!("{}", rc);  // 에러! Rc는 Send가 아님
+    // });
+
+    // 마커 트레이트 - 직접 구현할 일은 드묾
+    // unsafe impl Send for MyType {}
+    // unsafe impl Sync for MyType {}
+
+    println!("컴파일러가 스레드 안전성을 검증합니다!");
+
+    // C++에서는 이런 버그가 런타임에 발생:
+    // std::vector<int> v;
+    // std::thread t1([&v] { v.push_back(1); });
+    // std::thread t2([&v] { v.push_back(2); });
+    // // 데이터 레이스! 정의되지 않은 동작
+
+    // Rust에서는 컴파일 에러:
+    // let mut v = vec![];
+    // thread::spawn(|| v.push(1));  // 에러! &mut 참조를 여러 스레드에서 사용 불가
+}
+
+fn main() {
+    send_sync_traits();
+}
@@ -0,0 +1,61 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 13. 동시성 (Concurrency) - 공유 상태 (Shared State)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+// ----------------------------------------------------------------------------
+// 공유 상태 (Shared State)
+// ----------------------------------------------------------------------------
+
+fn shared_state() {
+    println!("\n--- 공유 상태 ---");
+
+    // Mutex - 상호 배제
+    // C++: std::mutex + std::lock_guard
+
+    let m = Mutex::new(5);
+
+    {
+        // lock()은 MutexGuard 반환 (RAII)
+        // C++: std::lock_guard<std::mutex> lock(m);
+        let mut num = m.lock().unwrap();
+        *num = 6;
+        println!("Mutex 값: {}", *num);
+    }  // MutexGuard가 drop되면서 자동 unlock
+
+    println!("스코프 후: {:?}", m);
+
+    // 스레드 간 공유 - Arc<Mutex<T>>
+    // Arc = Atomic Reference Counted (멀티스레드용 Rc)
+    // C++: std::shared_ptr + std::mutex
+
+    let counter = Arc::new(Mutex::new(0));
+    let mut handles = vec![];
+
+    for _ in 0..10 {
+        let counter = Arc::clone(&counter);
+        let handle = thread::spawn(move || {
+            let mut num = counter.lock().unwrap();
+            *num += 1;
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("최종 카운터: {}", *counter.lock().unwrap());
+
+    // Mutex 교착 상태 주의
+    // C++과 마찬가지로 여러 Mutex 동시 락 시 순서 주의
+}
+
+fn main() {
+    shared_state();
+}
@@ -0,0 +1,50 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 14. 모듈 시스템 (Module System) - 모듈 기초
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 모듈 기초
+// ----------------------------------------------------------------------------
+
+fn module_basics() {
+    println!("--- 모듈 기초 ---");
+
+    // 모듈은 코드를 그룹화하고 캡슐화
+    // C++: namespace와 유사하지만 가시성 규칙이 다름
+
+    // 인라인 모듈 정의
+    mod front_of_house {
+        // 기본적으로 private
+        pub mod hosting {
+            pub fn add_to_waitlist() {
+                println!("대기 명단에 추가");
+            }
+
+            fn seat_at_table() {
+                println!("테이블 배정");
+            }
+        }
+
+        mod serving {
+            fn take_order() {}
+            fn serve_order() {}
+            fn take_payment() {}
+        }
+    }
+
+    // 상대 경로로 호출 (같은 함수 내 모듈이므로)
+    front_of_house::hosting::add_to_waitlist();
+
+    // 한 번 더 호출해서 확인
+    front_of_house::hosting::add_to_waitlist();
+
+    // 비공개 함수는 접근 불가
+    // front_of_house::hosting::seat_at_table();  // 에러!
+    // front_of_house::serving::take_order();     // 에러! serving 모듈이 비공개
+}
+
+fn main() {
+    module_basics();
+}
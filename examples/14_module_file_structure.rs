@@ -0,0 +1,101 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 14. 모듈 시스템 (Module System) - 모듈 파일 구조
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 모듈 파일 구조
+// ----------------------------------------------------------------------------
+
+fn module_file_structure() {
+    println!("\n--- 모듈 파일 구조 ---");
+
+    // 파일 시스템과 모듈 매핑
+    //
+    // 방법 1: 단일 파일
+    // src/
+    // ├── main.rs (또는 lib.rs)
+    // └── garden.rs         // mod garden; 으로 선언
+    //
+    // 방법 2: 디렉터리 (구버전)
+    // src/
+    // ├── main.rs
+    // └── garden/
+    //     ├── mod.rs        // garden 모듈 정의
+    //     └── vegetables.rs // garden::vegetables 서브모듈
+    //
+    // 방법 3: 디렉터리 (신버전, 권장)
+    // src/
+    // ├── main.rs
+    // ├── garden.rs         // garden 모듈 정의
+    // └── garden/
+    //     └── vegetables.rs // garden::vegetables 서브모듈
+
+    // main.rs 또는 lib.rs 예:
+    // mod garden;  // garden.rs 또는 garden/mod.rs 로드
+    //
+    // use garden::vegetables;  // 서브모듈 사용
+
+    // garden.rs 예:
+    // pub mod vegetables;  // garden/vegetables.rs 로드
+    //
+    // pub fn plant() { ... }
+
+    // vegetables.rs 예:
+    // pub fn grow() { ... }
+
+    println!("현재 프로젝트 구조:");
+    println!("  src/");
+    println!("  ├── main.rs");
+    println!("  ├── 01_basics.rs");
+    println!("  ├── 02_ownership.rs");
+    println!("  └── ... (각 모듈 파일)");
+
+    // Cargo.toml로 외부 의존성 관리
+    // [dependencies]
+    // serde = "1.0"
+    // tokio = {{ version = "1", features = ["full"] }}
+
+    // 외부 크레이트 사용
+    // use serde::{{Serialize, Deserialize}};
+    //
+    // #[derive(Serialize, Deserialize)]
+    // struct Config {{ ... }}
+
+    // 워크스페이스 - 여러 패키지 관리
+    // workspace/
+    // ├── Cargo.toml     // [workspace] members = ["lib1", "app"]
+    // ├── lib1/
+    // │   └── Cargo.toml
+    // └── app/
+    //     └── Cargo.toml  // [dependencies] lib1 = {{ path = "../lib1" }}
+}
+
+// C++ 모듈(C++20)과의 비교:
+//
+// C++20:
+// // math.ixx
+// export module math;
+// export int add(int a, int b) { return a + b; }
+//
+// // main.cpp
+// import math;
+// int main() { return add(1, 2); }
+//
+// Rust:
+// // math.rs
+// pub fn add(a: i32, b: i32) -> i32 { a + b }
+//
+// // main.rs
+// mod math;
+// fn main() { math::add(1, 2); }
+//
+// 주요 차이:
+// - Rust는 별도의 모듈 인터페이스 파일 불필요
+// - Rust는 기본적으로 private, C++20 모듈은 export 명시
+// - Rust는 Cargo로 빌드/의존성 통합 관리
+
+fn main() {
+    module_file_structure();
+}
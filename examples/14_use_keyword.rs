@@ -0,0 +1,73 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 14. 모듈 시스템 (Module System) - use 키워드
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// use 키워드
+// ----------------------------------------------------------------------------
+
+fn use_keyword() {
+    println!("\n--- use 키워드 ---");
+
+    // use로 경로 단축
+    // C++: using namespace와 유사
+
+    mod shapes {
+        pub mod circle {
+            pub fn area(r: f64) -> f64 {
+                std::f64::consts::PI * r * r
+            }
+        }
+
+        pub mod rectangle {
+            pub fn area(w: f64, h: f64) -> f64 {
+                w * h
+            }
+        }
+    }
+
+    // 모듈 가져오기 (관용적)
+    use shapes::circle;
+    println!("원 넓이: {}", circle::area(5.0));
+
+    // 함수 직접 가져오기 (비추천 - 출처 불명확)
+    use shapes::rectangle::area as rect_area;  // 별칭으로 충돌 방지
+    println!("사각형 넓이: {}", rect_area(4.0, 5.0));
+
+    // 여러 항목 한 번에
+    use std::collections::{HashMap, HashSet};
+    let _map: HashMap<i32, i32> = HashMap::new();
+    let _set: HashSet<i32> = HashSet::new();
+
+    // 중첩 경로
+    // use std::io;
+    // use std::io::Write;
+    // 대신:
+    use std::io::{self, Write};
+
+    // 글롭 연산자 (*) - 모든 공개 항목 가져오기
+    // use std::collections::*;  // 테스트나 prelude에서 주로 사용
+
+    // 재내보내기 (re-export)
+    mod internal {
+        pub fn helper() {}
+    }
+    pub use internal::helper;  // 외부에서 internal::helper 대신 helper로 접근
+
+    // Prelude 패턴 예시 (실제 프로젝트에서는 루트 레벨에 정의)
+    // mod prelude {
+    //     pub use super::shapes::circle;
+    //     pub use super::shapes::rectangle;
+    // }
+    // 사용자는 prelude만 가져오면 됨
+    // use prelude::*;
+
+    // shapes 모듈 직접 사용
+    let _ = shapes::rectangle::area(3.0, 4.0);
+}
+
+fn main() {
+    use_keyword();
+}
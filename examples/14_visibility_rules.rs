@@ -0,0 +1,78 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 14. 모듈 시스템 (Module System) - 가시성 규칙
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 가시성 규칙
+// ----------------------------------------------------------------------------
+
+fn visibility_rules() {
+    println!("\n--- 가시성 규칙 ---");
+
+    mod outer {
+        pub mod inner {
+            pub fn public_function() {
+                println!("공개 함수");
+                private_function();  // 같은 모듈 내에서는 접근 가능
+            }
+
+            fn private_function() {
+                println!("비공개 함수");
+            }
+
+            // 구조체의 필드는 별도로 pub 지정 필요
+            pub struct Breakfast {
+                pub toast: String,      // 공개
+                seasonal_fruit: String, // 비공개
+            }
+
+            impl Breakfast {
+                // 생성자 패턴 - 비공개 필드가 있으면 필수
+                pub fn summer(toast: &str) -> Breakfast {
+                    Breakfast {
+                        toast: String::from(toast),
+                        seasonal_fruit: String::from("복숭아"),
+                    }
+                }
+            }
+        }
+
+        // 부모 모듈은 자식의 비공개 항목 접근 불가
+        pub fn demo() {
+            inner::public_function();
+            // inner::private_function();  // 에러!
+        }
+    }
+
+    outer::inner::public_function();
+
+    let mut meal = outer::inner::Breakfast::summer("호밀");
+    meal.toast = String::from("밀");  // 공개 필드 수정 가능
+    // meal.seasonal_fruit = String::from("블루베리");  // 에러! 비공개
+
+    // 열거형은 pub이면 모든 variant가 공개
+    mod menu {
+        pub enum Appetizer {
+            Soup,      // 자동으로 공개
+            Salad,     // 자동으로 공개
+        }
+    }
+
+    let _order1 = menu::Appetizer::Soup;
+    let _order2 = menu::Appetizer::Salad;
+
+    // pub(crate), pub(super), pub(in path) - 세밀한 가시성 제어
+    mod levels {
+        pub(crate) fn crate_visible() {}      // 크레이트 내에서만
+        pub(super) fn parent_visible() {}     // 부모 모듈에서만
+        // pub(in crate::levels) fn specific() {}  // 특정 경로에서만
+    }
+
+    levels::crate_visible();  // 같은 크레이트이므로 OK
+}
+
+fn main() {
+    visibility_rules();
+}
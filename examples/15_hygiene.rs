@@ -0,0 +1,149 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 15. 매크로 (Macros) - 위생성 (Hygiene)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::macros::{builder_field, hashmap};
+
+// --- rust-study 의 macros 모듈 ---
+mod macros {
+// ============================================================================
+// 공용 매크로 모음 (Shared Macros)
+// ============================================================================
+// 15장에서 정의한 매크로 중 다른 모듈에서도 쓰는 것들을 모아둔 모듈
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 매크로는 #include 순서에 따라 보임 - Rust는 경로(path)로 가져옴
+// 2. #[macro_export] - 크레이트 루트에 매크로를 공개 (crate::my_vec!)
+// 3. pub(crate) use - 일반 아이템처럼 모듈 경로로 재공개 (crate::macros::hashmap!)
+// 4. $crate - 매크로가 어디서 확장되든 정의된 크레이트를 가리키는 경로
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// #[macro_export] 방식
+// ----------------------------------------------------------------------------
+// 모듈 위치와 상관없이 크레이트 루트에 등록됨
+// 사용: use crate::my_vec; 또는 crate::my_vec![...]
+// 라이브러리 크레이트라면 외부에서 my_crate::my_vec! 로 사용 가능
+
+// 요소 개수를 컴파일 타임에 세는 내부용 매크로
+// 다른 매크로가 $crate::count_exprs! 로 호출하므로 반드시 export 되어야 함
+#[doc(hidden)]
+#[macro_export]
+macro_rules! count_exprs {
+    () => { 0usize };
+    ($head:expr $(, $tail:expr)*) => { 1usize + $crate::count_exprs!($($tail),*) };
+}
+
+// vec! 매크로와 유사한 구현
+// $crate::count_exprs! - 호출하는 쪽에서 count_exprs를 use 하지 않아도 동작
+#[macro_export]
+macro_rules! my_vec {
+    // 빈 벡터
+    () => {
+        Vec::new()
+    };
+    // 요소가 있는 벡터 - 개수만큼 미리 할당
+    ($($element:expr),+ $(,)?) => {
+        {
+            let mut v = Vec::with_capacity($crate::count_exprs!($($element),+));
+            $(
+                v.push($element);
+            )+
+            v
+        }
+    };
+}
+
+// ----------------------------------------------------------------------------
+// pub(crate) use 방식 (Rust 2018+ 권장)
+// ----------------------------------------------------------------------------
+// macro_rules! 정의 후 use로 재공개하면 일반 함수처럼 모듈 경로를 가짐
+// 사용: use crate::macros::hashmap;
+
+// 해시맵 생성
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map = std::collections::HashMap::with_capacity(
+                $crate::count_exprs!($($key),*)
+            );
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+}
+
+// 메서드 체이닝 빌더
+macro_rules! builder_field {
+    ($name:ident, $t:ty) => {
+        pub fn $name(mut self, value: $t) -> Self {
+            self.$name = value;
+            self
+        }
+    };
+}
+
+pub(crate) use builder_field;
+pub(crate) use hashmap;
+}
+
+// ----------------------------------------------------------------------------
+// 위생성 (Hygiene)
+// ----------------------------------------------------------------------------
+
+macro_rules! five_times {
+    ($x:expr) => {
+        5 * $x
+    };
+}
+
+macro_rules! create_var {
+    ($name:ident, $value:expr) => {
+        let $name = $value;
+    };
+}
+
+// 매크로 내부 변수는 외부와 충돌하지 않음
+macro_rules! using_temp {
+    ($e:expr) => {
+        {
+            let temp = $e;  // 이 temp는 외부 temp와 다름
+            temp * temp
+        }
+    };
+}
+
+fn hygiene() {
+    println!("\n--- 위생성 (Hygiene) ---");
+
+    // 기본적인 매크로 확장
+    let result = five_times!(2 + 3);  // 5 * (2 + 3) = 25
+    println!("five_times!(2 + 3) = {}", result);
+
+    // C++ 매크로의 문제:
+    // #define FIVE_TIMES(x) 5 * x
+    // FIVE_TIMES(2 + 3) = 5 * 2 + 3 = 13  // 의도와 다름!
+
+    // 변수 생성
+    create_var!(answer, 42);
+    println!("answer = {}", answer);
+
+    // 위생적 매크로 - 이름 충돌 방지
+    let temp = 10;
+    let squared = using_temp!(temp + 5);
+    println!("temp = {}, squared = {}", temp, squared);
+    // 매크로 내부의 temp와 외부의 temp는 별개
+
+    // C++ 매크로에서는 이름 충돌 위험:
+    // #define SQUARE(x) ({ int temp = (x); temp * temp; })
+    // int temp = 10;
+    // SQUARE(temp + 5);  // 이름 충돌 가능성!
+}
+
+fn main() {
+    hygiene();
+}
@@ -0,0 +1,165 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 15. 매크로 (Macros) - 매크로 패턴
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::macros::{builder_field, hashmap};
+
+// --- rust-study 의 macros 모듈 ---
+mod macros {
+// ============================================================================
+// 공용 매크로 모음 (Shared Macros)
+// ============================================================================
+// 15장에서 정의한 매크로 중 다른 모듈에서도 쓰는 것들을 모아둔 모듈
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 매크로는 #include 순서에 따라 보임 - Rust는 경로(path)로 가져옴
+// 2. #[macro_export] - 크레이트 루트에 매크로를 공개 (crate::my_vec!)
+// 3. pub(crate) use - 일반 아이템처럼 모듈 경로로 재공개 (crate::macros::hashmap!)
+// 4. $crate - 매크로가 어디서 확장되든 정의된 크레이트를 가리키는 경로
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// #[macro_export] 방식
+// ----------------------------------------------------------------------------
+// 모듈 위치와 상관없이 크레이트 루트에 등록됨
+// 사용: use crate::my_vec; 또는 crate::my_vec![...]
+// 라이브러리 크레이트라면 외부에서 my_crate::my_vec! 로 사용 가능
+
+// 요소 개수를 컴파일 타임에 세는 내부용 매크로
+// 다른 매크로가 $crate::count_exprs! 로 호출하므로 반드시 export 되어야 함
+#[doc(hidden)]
+#[macro_export]
+macro_rules! count_exprs {
+    () => { 0usize };
+    ($head:expr $(, $tail:expr)*) => { 1usize + $crate::count_exprs!($($tail),*) };
+}
+
+// vec! 매크로와 유사한 구현
+// $crate::count_exprs! - 호출하는 쪽에서 count_exprs를 use 하지 않아도 동작
+#[macro_export]
+macro_rules! my_vec {
+    // 빈 벡터
+    () => {
+        Vec::new()
+    };
+    // 요소가 있는 벡터 - 개수만큼 미리 할당
+    ($($element:expr),+ $(,)?) => {
+        {
+            let mut v = Vec::with_capacity($crate::count_exprs!($($element),+));
+            $(
+                v.push($element);
+            )+
+            v
+        }
+    };
+}
+
+// ----------------------------------------------------------------------------
+// pub(crate) use 방식 (Rust 2018+ 권장)
+// ----------------------------------------------------------------------------
+// macro_rules! 정의 후 use로 재공개하면 일반 함수처럼 모듈 경로를 가짐
+// 사용: use crate::macros::hashmap;
+
+// 해시맵 생성
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map = std::collections::HashMap::with_capacity(
+                $crate::count_exprs!($($key),*)
+            );
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+}
+
+// 메서드 체이닝 빌더
+macro_rules! builder_field {
+    ($name:ident, $t:ty) => {
+        pub fn $name(mut self, value: $t) -> Self {
+            self.$name = value;
+            self
+        }
+    };
+}
+
+pub(crate) use builder_field;
+pub(crate) use hashmap;
+}
+
+// ----------------------------------------------------------------------------
+// 매크로 패턴
+// ----------------------------------------------------------------------------
+
+// 다양한 지정자(designator) 사용
+// $name:지정자 형태로 인자 캡처
+
+// 주요 지정자:
+// expr  - 표현식
+// ident - 식별자 (변수명, 함수명 등)
+// ty    - 타입
+// pat   - 패턴
+// stmt  - 문장
+// block - 블록
+// item  - 아이템 (함수, 구조체 등)
+// path  - 경로 (std::vec::Vec)
+// tt    - 토큰 트리 (모든 것)
+// literal - 리터럴 값
+
+macro_rules! create_function {
+    ($func_name:ident) => {
+        fn $func_name() {
+            println!("함수 {}가 호출됨", stringify!($func_name));
+        }
+    };
+}
+
+macro_rules! print_type {
+    ($val:expr, $t:ty) => {
+        let _: $t = $val;
+        println!("{}: {}", stringify!($val), std::any::type_name::<$t>());
+    };
+}
+
+// 여러 패턴 매칭 (오버로딩과 유사)
+macro_rules! calculate {
+    // 패턴 1: 두 값 더하기
+    (add $a:expr, $b:expr) => {
+        $a + $b
+    };
+    // 패턴 2: 두 값 곱하기
+    (mul $a:expr, $b:expr) => {
+        $a * $b
+    };
+    // 패턴 3: 단일 값 제곱
+    (square $a:expr) => {
+        $a * $a
+    };
+}
+
+fn macro_patterns() {
+    println!("\n--- 매크로 패턴 ---");
+
+    // ident로 함수 생성
+    create_function!(foo);
+    create_function!(bar);
+    foo();
+    bar();
+
+    // ty로 타입 지정
+    print_type!(42, i32);
+    print_type!(3.14, f64);
+
+    // 패턴 매칭
+    println!("add: {}", calculate!(add 2, 3));
+    println!("mul: {}", calculate!(mul 4, 5));
+    println!("square: {}", calculate!(square 6));
+}
+
+fn main() {
+    macro_patterns();
+}
@@ -0,0 +1,153 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 15. 매크로 (Macros) - 절차적 매크로 소개
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::macros::{builder_field, hashmap};
+
+// --- rust-study 의 macros 모듈 ---
+mod macros {
+// ============================================================================
+// 공용 매크로 모음 (Shared Macros)
+// ============================================================================
+// 15장에서 정의한 매크로 중 다른 모듈에서도 쓰는 것들을 모아둔 모듈
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 매크로는 #include 순서에 따라 보임 - Rust는 경로(path)로 가져옴
+// 2. #[macro_export] - 크레이트 루트에 매크로를 공개 (crate::my_vec!)
+// 3. pub(crate) use - 일반 아이템처럼 모듈 경로로 재공개 (crate::macros::hashmap!)
+// 4. $crate - 매크로가 어디서 확장되든 정의된 크레이트를 가리키는 경로
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// #[macro_export] 방식
+// ----------------------------------------------------------------------------
+// 모듈 위치와 상관없이 크레이트 루트에 등록됨
+// 사용: use crate::my_vec; 또는 crate::my_vec![...]
+// 라이브러리 크레이트라면 외부에서 my_crate::my_vec! 로 사용 가능
+
+// 요소 개수를 컴파일 타임에 세는 내부용 매크로
+// 다른 매크로가 $crate::count_exprs! 로 호출하므로 반드시 export 되어야 함
+#[doc(hidden)]
+#[macro_export]
+macro_rules! count_exprs {
+    () => { 0usize };
+    ($head:expr $(, $tail:expr)*) => { 1usize + $crate::count_exprs!($($tail),*) };
+}
+
+// vec! 매크로와 유사한 구현
+// $crate::count_exprs! - 호출하는 쪽에서 count_exprs를 use 하지 않아도 동작
+#[macro_export]
+macro_rules! my_vec {
+    // 빈 벡터
+    () => {
+        Vec::new()
+    };
+    // 요소가 있는 벡터 - 개수만큼 미리 할당
+    ($($element:expr),+ $(,)?) => {
+        {
+            let mut v = Vec::with_capacity($crate::count_exprs!($($element),+));
+            $(
+                v.push($element);
+            )+
+            v
+        }
+    };
+}
+
+// ----------------------------------------------------------------------------
+// pub(crate) use 방식 (Rust 2018+ 권장)
+// ----------------------------------------------------------------------------
+// macro_rules! 정의 후 use로 재공개하면 일반 함수처럼 모듈 경로를 가짐
+// 사용: use crate::macros::hashmap;
+
+// 해시맵 생성
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map = std::collections::HashMap::with_capacity(
+                $crate::count_exprs!($($key),*)
+            );
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+}
+
+// 메서드 체이닝 빌더
+macro_rules! builder_field {
+    ($name:ident, $t:ty) => {
+        pub fn $name(mut self, value: $t) -> Self {
+            self.$name = value;
+            self
+        }
+    };
+}
+
+pub(crate) use builder_field;
+pub(crate) use hashmap;
+}
+
+// ----------------------------------------------------------------------------
+// 절차적 매크로 소개
+// ----------------------------------------------------------------------------
+
+fn procedural_macros_intro() {
+    println!("\n--- 절차적 매크로 소개 ---");
+
+    // 절차적 매크로는 별도 크레이트에서 정의해야 함
+    // 여기서는 개념만 설명
+
+    println!("절차적 매크로의 세 가지 종류:");
+    println!("1. derive 매크로 - #[derive(MyTrait)]");
+    println!("2. attribute 매크로 - #[my_attribute]");
+    println!("3. function-like 매크로 - my_macro!(...)");
+
+    // derive 매크로 예시 (serde)
+    // 속성(#[serde(rename, default, ..)])과 함께 실제로 쓰는 모습은 35장
+    // #[derive(Serialize, Deserialize)]
+    // struct User { name: String, age: u32 }
+
+    // attribute 매크로 예시 (tokio)
+    // #[tokio::main]
+    // async fn main() { ... }
+
+    // 절차적 매크로 작성 (별도 크레이트 필요):
+    //
+    // // my_macro/src/lib.rs
+    // use proc_macro::TokenStream;
+    //
+    // #[proc_macro_derive(MyTrait)]
+    // pub fn my_trait_derive(input: TokenStream) -> TokenStream {
+    //     // TokenStream 파싱 및 코드 생성
+    // }
+
+    println!("\n실제 사용 중인 derive 매크로들:");
+    println!("- Debug, Clone, Copy, PartialEq, Eq, Hash, Default");
+    println!("- serde: Serialize, Deserialize");
+    println!("- thiserror: Error");
+
+    // C++ 템플릿 메타프로그래밍과 비교:
+    // - Rust 매크로는 더 명시적이고 읽기 쉬움
+    // - 에러 메시지가 더 명확함
+    // - 절차적 매크로는 임의의 Rust 코드 실행 가능
+
+    // derive 예시 - 이미 표준 라이브러리에서 사용 중
+    #[derive(Debug, Clone, PartialEq)]
+    struct DemoPoint {
+        x: i32,
+        y: i32,
+    }
+
+    let p1 = DemoPoint { x: 1, y: 2 };
+    let p2 = p1.clone();
+    println!("Debug: {:?}", p1);
+    println!("PartialEq: {}", p1 == p2);
+}
+
+fn main() {
+    procedural_macros_intro();
+}
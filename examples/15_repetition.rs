@@ -0,0 +1,204 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 15. 매크로 (Macros) - 반복 (Repetition)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::macros::{builder_field, hashmap};
+
+// --- rust-study 의 macros 모듈 ---
+mod macros {
+// ============================================================================
+// 공용 매크로 모음 (Shared Macros)
+// ============================================================================
+// 15장에서 정의한 매크로 중 다른 모듈에서도 쓰는 것들을 모아둔 모듈
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 매크로는 #include 순서에 따라 보임 - Rust는 경로(path)로 가져옴
+// 2. #[macro_export] - 크레이트 루트에 매크로를 공개 (crate::my_vec!)
+// 3. pub(crate) use - 일반 아이템처럼 모듈 경로로 재공개 (crate::macros::hashmap!)
+// 4. $crate - 매크로가 어디서 확장되든 정의된 크레이트를 가리키는 경로
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// #[macro_export] 방식
+// ----------------------------------------------------------------------------
+// 모듈 위치와 상관없이 크레이트 루트에 등록됨
+// 사용: use crate::my_vec; 또는 crate::my_vec![...]
+// 라이브러리 크레이트라면 외부에서 my_crate::my_vec! 로 사용 가능
+
+// 요소 개수를 컴파일 타임에 세는 내부용 매크로
+// 다른 매크로가 $crate::count_exprs! 로 호출하므로 반드시 export 되어야 함
+#[doc(hidden)]
+#[macro_export]
+macro_rules! count_exprs {
+    () => { 0usize };
+    ($head:expr $(, $tail:expr)*) => { 1usize + $crate::count_exprs!($($tail),*) };
+}
+
+// vec! 매크로와 유사한 구현
+// $crate::count_exprs! - 호출하는 쪽에서 count_exprs를 use 하지 않아도 동작
+#[macro_export]
+macro_rules! my_vec {
+    // 빈 벡터
+    () => {
+        Vec::new()
+    };
+    // 요소가 있는 벡터 - 개수만큼 미리 할당
+    ($($element:expr),+ $(,)?) => {
+        {
+            let mut v = Vec::with_capacity($crate::count_exprs!($($element),+));
+            $(
+                v.push($element);
+            )+
+            v
+        }
+    };
+}
+
+// ----------------------------------------------------------------------------
+// pub(crate) use 방식 (Rust 2018+ 권장)
+// ----------------------------------------------------------------------------
+// macro_rules! 정의 후 use로 재공개하면 일반 함수처럼 모듈 경로를 가짐
+// 사용: use crate::macros::hashmap;
+
+// 해시맵 생성
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map = std::collections::HashMap::with_capacity(
+                $crate::count_exprs!($($key),*)
+            );
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+}
+
+// 메서드 체이닝 빌더
+macro_rules! builder_field {
+    ($name:ident, $t:ty) => {
+        pub fn $name(mut self, value: $t) -> Self {
+            self.$name = value;
+            self
+        }
+    };
+}
+
+pub(crate) use builder_field;
+pub(crate) use hashmap;
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+mod local_only {
+    macro_rules! shout {
+        ($s:expr) => {
+            $s.to_uppercase()
+        };
+    }
+
+    pub fn demo() -> String {
+        shout!("local")
+    }
+    // 모듈 밖에서 shout!은 보이지 않음 - use로 재공개하지 않았으므로
+}
+
+fn macro_visibility() {
+    println!("\n--- 매크로 가시성과 $crate ---");
+
+    // 1. 모듈 로컬 매크로
+    println!("local_only::demo() = {}", local_only::demo());
+
+    // 2. #[macro_export] - crate::my_vec 경로로 접근
+    let v = my_vec![1, 2, 3];
+    println!("my_vec! = {:?} (capacity: {})", v, v.capacity());
+
+    // use 없이 전체 경로로도 호출 가능
+    let w: Vec<&str> = crate::my_vec!["a", "b"];
+    println!("crate::my_vec! = {:?}", w);
+
+    // 3. pub(crate) use - crate::macros::hashmap 경로로 접근
+    let m = crate::macros::hashmap! { 1 => "one", 2 => "two" };
+    println!("crate::macros::hashmap! 항목 수 = {}", m.len());
+
+    // 4. $crate - my_vec!/hashmap! 내부에서 $crate::count_exprs! 호출
+    //    호출하는 쪽은 count_exprs를 use 하지 않아도 됨
+    //    라이브러리라면 $crate가 사용자 크레이트가 아닌 정의한 크레이트로 치환됨
+    println!("count_exprs!(a, b, c) = {}", crate::count_exprs!('a', 'b', 'c'));
+
+    // 같은 매크로를 다른 장에서도 재사용:
+    // - _10_collections::hashmaps() 에서 hashmap!
+    // - _18_idioms::builder_pattern() 에서 builder_field!
+
+    // C++과 비교:
+    // - C++ 매크로는 #include 한 순간부터 전역 - 네임스페이스 무시
+    // - Rust 매크로는 use/경로로 가져오므로 이름 충돌을 모듈 단위로 관리
+}
+
+// ----------------------------------------------------------------------------
+// 반복 (Repetition)
+// ----------------------------------------------------------------------------
+
+// $(...),* 형태로 반복
+// * : 0회 이상
+// + : 1회 이상
+// ? : 0회 또는 1회
+
+// vec! 매크로와 유사한 구현 - my_vec!은 macros 모듈로 옮김 (macro_visibility 참고)
+
+// 가변 인자 함수처럼 동작하는 매크로
+macro_rules! sum {
+    ($($x:expr),*) => {
+        {
+            let mut total = 0;
+            $(
+                total += $x;
+            )*
+            total
+        }
+    };
+}
+
+// 구조체 필드 생성
+macro_rules! make_struct {
+    ($name:ident { $($field:ident : $t:ty),* $(,)? }) => {
+        #[derive(Debug)]
+        struct $name {
+            $($field: $t),*
+        }
+    };
+}
+
+fn repetition() {
+    println!("\n--- 반복 ---");
+
+    // my_vec! 사용
+    let v1: Vec<i32> = my_vec!();
+    let v2 = my_vec![1, 2, 3];
+    let v3 = my_vec![10, 20, 30, 40,];  // 후행 쉼표 OK
+    println!("v1: {:?}", v1);
+    println!("v2: {:?}", v2);
+    println!("v3: {:?}", v3);
+
+    // sum! 사용
+    println!("sum: {}", sum!(1, 2, 3, 4, 5));
+    println!("빈 sum: {}", sum!());
+
+    // 구조체 생성
+    make_struct!(Point { x: i32, y: i32 });
+    let p = Point { x: 10, y: 20 };
+    println!("Point: {:?}", p);
+
+    // C++ 가변 인자 템플릿과 비교:
+    // template<typename... Args>
+    // auto sum(Args... args) { return (args + ...); }
+    //
+    // Rust 매크로가 더 유연하고 복잡한 패턴 가능
+}
+
+fn main() {
+    repetition();
+}
@@ -0,0 +1,185 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 15. 매크로 (Macros) - 유용한 매크로 패턴
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::macros::{builder_field, hashmap};
+
+// --- rust-study 의 macros 모듈 ---
+mod macros {
+// ============================================================================
+// 공용 매크로 모음 (Shared Macros)
+// ============================================================================
+// 15장에서 정의한 매크로 중 다른 모듈에서도 쓰는 것들을 모아둔 모듈
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 매크로는 #include 순서에 따라 보임 - Rust는 경로(path)로 가져옴
+// 2. #[macro_export] - 크레이트 루트에 매크로를 공개 (crate::my_vec!)
+// 3. pub(crate) use - 일반 아이템처럼 모듈 경로로 재공개 (crate::macros::hashmap!)
+// 4. $crate - 매크로가 어디서 확장되든 정의된 크레이트를 가리키는 경로
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// #[macro_export] 방식
+// ----------------------------------------------------------------------------
+// 모듈 위치와 상관없이 크레이트 루트에 등록됨
+// 사용: use crate::my_vec; 또는 crate::my_vec![...]
+// 라이브러리 크레이트라면 외부에서 my_crate::my_vec! 로 사용 가능
+
+// 요소 개수를 컴파일 타임에 세는 내부용 매크로
+// 다른 매크로가 $crate::count_exprs! 로 호출하므로 반드시 export 되어야 함
+#[doc(hidden)]
+#[macro_export]
+macro_rules! count_exprs {
+    () => { 0usize };
+    ($head:expr $(, $tail:expr)*) => { 1usize + $crate::count_exprs!($($tail),*) };
+}
+
+// vec! 매크로와 유사한 구현
+// $crate::count_exprs! - 호출하는 쪽에서 count_exprs를 use 하지 않아도 동작
+#[macro_export]
+macro_rules! my_vec {
+    // 빈 벡터
+    () => {
+        Vec::new()
+    };
+    // 요소가 있는 벡터 - 개수만큼 미리 할당
+    ($($element:expr),+ $(,)?) => {
+        {
+            let mut v = Vec::with_capacity($crate::count_exprs!($($element),+));
+            $(
+                v.push($element);
+            )+
+            v
+        }
+    };
+}
+
+// ----------------------------------------------------------------------------
+// pub(crate) use 방식 (Rust 2018+ 권장)
+// ----------------------------------------------------------------------------
+// macro_rules! 정의 후 use로 재공개하면 일반 함수처럼 모듈 경로를 가짐
+// 사용: use crate::macros::hashmap;
+
+// 해시맵 생성
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map = std::collections::HashMap::with_capacity(
+                $crate::count_exprs!($($key),*)
+            );
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+}
+
+// 메서드 체이닝 빌더
+macro_rules! builder_field {
+    ($name:ident, $t:ty) => {
+        pub fn $name(mut self, value: $t) -> Self {
+            self.$name = value;
+            self
+        }
+    };
+}
+
+pub(crate) use builder_field;
+pub(crate) use hashmap;
+}
+
+// ----------------------------------------------------------------------------
+// 유용한 매크로 패턴
+// ----------------------------------------------------------------------------
+
+// 에러와 함께 조기 반환
+macro_rules! try_or_return {
+    ($expr:expr) => {
+        match $expr {
+            Ok(val) => val,
+            Err(e) => {
+                println!("에러 발생: {:?}", e);
+                return;
+            }
+        }
+    };
+}
+
+// 해시맵 생성 - hashmap!은 macros 모듈에서 가져옴
+
+// 조건부 컴파일과 함께 사용
+macro_rules! debug_print {
+    ($($arg:tt)*) => {
+        #[cfg(debug_assertions)]
+        println!("[DEBUG] {}", format!($($arg)*));
+    };
+}
+
+// 메서드 체이닝 빌더 - builder_field!도 macros 모듈에서 가져옴
+struct RequestBuilder {
+    url: String,
+    method: String,
+    timeout: u32,
+}
+
+impl RequestBuilder {
+    fn new() -> Self {
+        RequestBuilder {
+            url: String::new(),
+            method: String::from("GET"),
+            timeout: 30,
+        }
+    }
+
+    builder_field!(url, String);
+    builder_field!(method, String);
+    builder_field!(timeout, u32);
+
+    fn build(self) -> String {
+        format!("{} {} (timeout: {}s)", self.method, self.url, self.timeout)
+    }
+}
+
+fn useful_macros() {
+    println!("\n--- 유용한 매크로 패턴 ---");
+
+    // hashmap! 매크로
+    let scores = hashmap! {
+        "Alice" => 100,
+        "Bob" => 85,
+        "Carol" => 92,
+    };
+    println!("점수: {:?}", scores);
+
+    // debug_print! - 디버그 빌드에서만 출력
+    debug_print!("이것은 디버그 메시지입니다: {}", 42);
+
+    // 빌더 패턴
+    let request = RequestBuilder::new()
+        .url(String::from("https://api.example.com"))
+        .method(String::from("POST"))
+        .timeout(60)
+        .build();
+    println!("요청: {}", request);
+
+    // 표준 라이브러리의 유용한 매크로들
+    // println!, format!, vec!, panic!, assert!, cfg!, include_str! 등
+
+    // concat! - 컴파일 타임 문자열 연결
+    let s = concat!("Hello", ", ", "World", "!");
+    println!("concat!: {}", s);
+
+    // include_str! - 파일 내용을 문자열로 포함
+    // let content = include_str!("data.txt");
+
+    // env! - 컴파일 타임 환경 변수
+    let version = env!("CARGO_PKG_VERSION");
+    println!("패키지 버전: {}", version);
+}
+
+fn main() {
+    useful_macros();
+}
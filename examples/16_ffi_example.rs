@@ -0,0 +1,64 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 16. Unsafe Rust - FFI (Foreign Function Interface)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ----------------------------------------------------------------------------
+// FFI (Foreign Function Interface)
+// ----------------------------------------------------------------------------
+
+// C 표준 라이브러리 함수 선언
+extern "C" {
+    fn abs(input: i32) -> i32;
+    fn strlen(s: *const i8) -> usize;
+}
+
+// Rust 함수를 C에서 호출 가능하게 만들기
+// 라이브러리 전체를 cdylib 로 내보내고 cbindgen 으로 헤더를 만드는 법은 45장
+#[no_mangle]
+pub extern "C" fn rust_function(x: i32) -> i32 {
+    x * 2
+}
+
+fn ffi_example() {
+    println!("\n--- FFI (외부 함수 인터페이스) ---");
+
+    // C 함수 호출
+    unsafe {
+        println!("C abs(-3) = {}", abs(-3));
+
+        // 문자열을 C 스타일로 변환
+        let s = "Hello\0";  // null 종료 문자열
+        let len = strlen(s.as_ptr() as *const i8);
+        println!("C strlen(\"Hello\") = {}", len);
+    }
+
+    // C++와의 상호운용:
+    // - extern "C"로 C ABI 사용
+    // - #[repr(C)]로 C 호환 메모리 레이아웃
+    // - bindgen 크레이트로 C 헤더에서 자동 바인딩 생성
+    // - 직접 쓴 C++ 코드를 build.rs 로 컴파일해 부르는 법과 cxx 크레이트는 44장
+
+    // C 호환 구조체
+    #[repr(C)]
+    struct CPoint {
+        x: i32,
+        y: i32,
+    }
+
+    let point = CPoint { x: 10, y: 20 };
+    println!("C 호환 구조체: ({}, {})", point.x, point.y);
+
+    // 호출 규약:
+    // extern "C"     - C 호출 규약 (기본)
+    // extern "system" - Windows API 호출 규약
+    // extern "stdcall" - Windows stdcall
+}
+
+fn main() {
+    ffi_example();
+}
@@ -0,0 +1,69 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 16. Unsafe Rust - Raw 포인터
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ----------------------------------------------------------------------------
+// Raw 포인터
+// ----------------------------------------------------------------------------
+
+fn raw_pointers() {
+    println!("\n--- Raw 포인터 ---");
+
+    // Raw 포인터 타입:
+    // *const T - 불변 raw 포인터 (C++: const T*)
+    // *mut T   - 가변 raw 포인터 (C++: T*)
+
+    let mut num = 5;
+
+    // 참조에서 raw 포인터 생성 - 안전함
+    let r1 = &num as *const i32;
+    let r2 = &mut num as *mut i32;
+
+    // raw 포인터 생성은 안전하지만, 역참조는 unsafe
+    println!("r1 주소: {:?}", r1);
+    println!("r2 주소: {:?}", r2);
+
+    // 역참조는 unsafe 블록 내에서만 가능
+    unsafe {
+        println!("r1 값: {}", *r1);
+        println!("r2 값: {}", *r2);
+
+        // 가변 포인터로 수정
+        *r2 = 10;
+        println!("수정 후 r2 값: {}", *r2);
+    }
+
+    // C++와의 차이:
+    // C++: int* ptr = &num; *ptr = 10;  // 어디서든 가능
+    // Rust: unsafe 블록 필요
+
+    // 임의의 주소에 포인터 생성 (매우 위험!)
+    let address = 0x012345usize;
+    let _r = address as *const i32;
+    // unsafe { println!("{}", *_r); }  // 거의 확실히 크래시!
+
+    // raw 포인터의 특징:
+    // - null 가능
+    // - 자동 해제 없음
+    // - 빌림 규칙 무시 가능
+    // - 유효성 보장 없음
+
+    // 가변/불변 포인터 동시 존재 가능 (일반 참조에서는 불가)
+    let mut value = 42;
+    let ptr1 = &value as *const i32;
+    let ptr2 = &mut value as *mut i32;
+
+    unsafe {
+        // 둘 다 접근 가능하지만, 동시 수정은 정의되지 않은 동작!
+        println!("ptr1: {}, ptr2: {}", *ptr1, *ptr2);
+    }
+}
+
+fn main() {
+    raw_pointers();
+}
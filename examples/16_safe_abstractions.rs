@@ -0,0 +1,563 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 16. Unsafe Rust - 안전한 추상화
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ----------------------------------------------------------------------------
+// 안전한 추상화
+// ----------------------------------------------------------------------------
+
+// 안전하지 않은 내부 구현을 안전한 API로 감싸기
+// C++: std::vector<T>를 직접 구현하는 것과 같은 연습
+// pub(crate) - 19장 테스트에서 다른 모듈의 API를 검증하는 예제로 사용
+pub(crate) mod safe_wrapper {
+    use std::alloc::{self, Layout};
+    use std::marker::PhantomData;
+    use std::mem::{self, ManuallyDrop, MaybeUninit};
+    use std::ops::{Deref, DerefMut};
+    use std::ptr::{self, NonNull};
+
+    // 불변식 (모든 unsafe 블록이 의존하는 조건):
+    // 1. ptr은 항상 non-null이고 T에 맞게 정렬됨 (cap == 0이면 dangling)
+    // 2. cap > 0 이고 T가 ZST가 아니면 ptr은 Layout::array::<T>(cap)로 할당된 메모리
+    // 3. ptr[0..len]은 초기화된 T, ptr[len..cap]은 미초기화 메모리 (MaybeUninit<T> 로만 봄 - 46장)
+    // 4. len <= cap
+    // 5. ZST(크기 0 타입)는 할당하지 않음 - cap을 usize::MAX로 간주
+    pub struct MyVec<T> {
+        ptr: NonNull<T>,
+        len: usize,
+        cap: usize,
+        // "T를 소유한다"고 drop checker에게 알림 - C++에는 없는 개념
+        _marker: PhantomData<T>,
+    }
+
+    // Send/Sync - raw 포인터가 있으면 자동 구현되지 않으므로 직접 선언
+    // SAFETY: MyVec<T>는 T 값들을 독점 소유하므로 Vec<T>와 같은 규칙을 따름
+    // - T가 Send면 MyVec<T>를 다른 스레드로 옮겨도 안전 (소유권이 함께 이동)
+    // - T가 Sync면 &MyVec<T>는 &[T]만 노출하므로 공유해도 안전
+    // 내부 가변성이 없고 포인터를 다른 곳과 공유하지 않음 (불변식 2)
+    unsafe impl<T: Send> Send for MyVec<T> {}
+    unsafe impl<T: Sync> Sync for MyVec<T> {}
+
+    impl<T> MyVec<T> {
+        const IS_ZST: bool = mem::size_of::<T>() == 0;
+
+        pub fn new() -> Self {
+            MyVec {
+                ptr: NonNull::dangling(),
+                len: 0,
+                cap: if Self::IS_ZST { usize::MAX } else { 0 },
+                _marker: PhantomData,
+            }
+        }
+
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        pub fn capacity(&self) -> usize {
+            self.cap
+        }
+
+        // 안전한 API - 내부적으로 unsafe 사용
+        // 미초기화 슬롯을 &mut T 로 만들면 UB - MaybeUninit<T> 로 보고 write (46장)
+        // *slot = value 로 쓰면 그 자리의 쓰레기 값을 drop 하려 함 - write 는 drop 하지 않고 덮어씀
+        pub fn push(&mut self, value: T) {
+            if self.len == self.cap {
+                self.grow();
+            }
+
+            self.spare_capacity_mut()[0].write(value);
+            // 방금 ptr[len]을 초기화했으므로 불변식 3 유지
+            self.len += 1;
+        }
+
+        // 남은 용량 ptr[len..cap] - Vec::spare_capacity_mut 와 같음
+        // 여기에 쓴 값은 len 을 늘리기 전까지 MyVec 의 요소가 아님 (drop 되지 않음)
+        pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+            // SAFETY: ptr[len..cap]은 할당된 메모리 (불변식 2, ZST 는 크기 0)
+            // MaybeUninit<T> 는 T 와 크기/정렬이 같고 미초기화 상태도 올바른 값
+            unsafe {
+                std::slice::from_raw_parts_mut(
+                    self.ptr.as_ptr().add(self.len) as *mut MaybeUninit<T>,
+                    self.cap - self.len,
+                )
+            }
+        }
+
+        // C++ pop_back()과 달리 빈 벡터에서도 UB 없이 None 반환
+        pub fn pop(&mut self) -> Option<T> {
+            if self.len == 0 {
+                None
+            } else {
+                self.len -= 1;
+                // SAFETY: ptr[len]은 초기화되어 있었고, len을 줄였으므로
+                // 이 값을 다시 읽거나 drop 하지 않음 - 소유권을 호출자에게 이동
+                unsafe { Some(ptr::read(self.ptr.as_ptr().add(self.len))) }
+            }
+        }
+
+        // index 위치에 삽입하고 뒤쪽 요소를 한 칸씩 민다
+        pub fn insert(&mut self, index: usize, value: T) {
+            assert!(index <= self.len, "insert index {} out of bounds (len {})", index, self.len);
+            if self.len == self.cap {
+                self.grow();
+            }
+
+            // SAFETY: len < cap 이므로 ptr[index..len]을 한 칸 뒤로 옮길 공간이 있음
+            // ptr::copy는 memmove처럼 겹치는 영역도 처리
+            unsafe {
+                let p = self.ptr.as_ptr().add(index);
+                ptr::copy(p, p.add(1), self.len - index);
+                ptr::write(p, value);
+            }
+            self.len += 1;
+        }
+
+        // index 위치의 요소를 꺼내고 뒤쪽 요소를 한 칸씩 당긴다
+        pub fn remove(&mut self, index: usize) -> T {
+            assert!(index < self.len, "remove index {} out of bounds (len {})", index, self.len);
+
+            // SAFETY: index < len 이므로 ptr[index]는 초기화됨
+            // 값을 읽은 뒤 뒤쪽을 덮어쓰므로 같은 값이 두 번 drop 되지 않음
+            unsafe {
+                self.len -= 1;
+                let p = self.ptr.as_ptr().add(index);
+                let value = ptr::read(p);
+                ptr::copy(p.add(1), p, self.len - index);
+                value
+            }
+        }
+
+        pub fn get(&self, index: usize) -> Option<&T> {
+            if index < self.len {
+                // SAFETY: index < len 이므로 초기화된 요소 (불변식 3)
+                unsafe { Some(&*self.ptr.as_ptr().add(index)) }
+            } else {
+                None
+            }
+        }
+
+        fn grow(&mut self) {
+            // ZST는 cap이 usize::MAX 이므로 여기까지 오면 len이 넘친 것
+            assert!(!Self::IS_ZST, "capacity overflow");
+
+            // cap * 2 는 릴리스 빌드에서 조용히 넘칠 수 있음 - 넘치면 std 와 같은 메시지로 panic
+            let new_cap = if self.cap == 0 { 1 } else { self.cap.checked_mul(2).expect("capacity overflow") };
+            let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
+
+            let new_ptr = if self.cap == 0 {
+                // SAFETY: T가 ZST가 아니고 new_cap >= 1 이므로 size > 0
+                unsafe { alloc::alloc(new_layout) }
+            } else {
+                let old_layout = Layout::array::<T>(self.cap).unwrap();
+                // SAFETY: ptr은 old_layout으로 할당된 메모리 (불변식 2)
+                unsafe { alloc::realloc(self.ptr.as_ptr() as *mut u8, old_layout, new_layout.size()) }
+            };
+
+            // 할당 실패 시 null - C++의 std::bad_alloc 대신 표준 핸들러 호출
+            self.ptr = match NonNull::new(new_ptr as *mut T) {
+                Some(p) => p,
+                None => alloc::handle_alloc_error(new_layout),
+            };
+            self.cap = new_cap;
+        }
+    }
+
+    impl<T> Default for MyVec<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T> Drop for MyVec<T> {
+        fn drop(&mut self) {
+            // 요소들 drop - 슬라이스로 한 번에
+            // SAFETY: ptr[0..len]은 초기화된 요소들 (불변식 3)
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+            }
+            // 메모리 해제
+            if !Self::IS_ZST && self.cap > 0 {
+                let layout = Layout::array::<T>(self.cap).unwrap();
+                // SAFETY: 불변식 2 - 같은 layout으로 할당됨
+                unsafe {
+                    alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout);
+                }
+            }
+        }
+    }
+
+    // Deref<Target=[T]> - 슬라이스의 모든 메서드(iter, len, sort, 인덱싱...)를 공짜로 얻음
+    // C++: std::span<T>으로의 암시적 변환과 유사
+    impl<T> Deref for MyVec<T> {
+        type Target = [T];
+
+        fn deref(&self) -> &[T] {
+            // SAFETY: ptr은 non-null/정렬됨(불변식 1), ptr[0..len]은 초기화됨(불변식 3)
+            unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    impl<T> DerefMut for MyVec<T> {
+        fn deref_mut(&mut self) -> &mut [T] {
+            // SAFETY: deref와 같음 + &mut self로 독점 접근 보장
+            unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    // 소유권을 가져가는 이터레이터 - for x in my_vec { ... }
+    // 남은 요소는 [start, end) 범위에 있음
+    pub struct IntoIter<T> {
+        buf: NonNull<T>,
+        cap: usize,
+        start: usize,
+        end: usize,
+        _marker: PhantomData<T>,
+    }
+
+    // SAFETY: MyVec과 같은 이유 - 남은 T 값들을 독점 소유
+    unsafe impl<T: Send> Send for IntoIter<T> {}
+    unsafe impl<T: Sync> Sync for IntoIter<T> {}
+
+    impl<T> IntoIterator for MyVec<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+
+        fn into_iter(self) -> IntoIter<T> {
+            // MyVec의 Drop이 실행되지 않도록 막고 버퍼 소유권을 IntoIter로 넘김
+            let vec = ManuallyDrop::new(self);
+            IntoIter {
+                buf: vec.ptr,
+                cap: vec.cap,
+                start: 0,
+                end: vec.len,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<T> Iterator for IntoIter<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            if self.start == self.end {
+                None
+            } else {
+                // SAFETY: [start, end)는 아직 꺼내지 않은 초기화된 요소
+                let value = unsafe { ptr::read(self.buf.as_ptr().add(self.start)) };
+                self.start += 1;
+                Some(value)
+            }
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.end - self.start;
+            (remaining, Some(remaining))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for IntoIter<T> {
+        fn next_back(&mut self) -> Option<T> {
+            if self.start == self.end {
+                None
+            } else {
+                self.end -= 1;
+                // SAFETY: next()와 같음 - end를 줄였으므로 다시 읽지 않음
+                unsafe { Some(ptr::read(self.buf.as_ptr().add(self.end))) }
+            }
+        }
+    }
+
+    impl<T> ExactSizeIterator for IntoIter<T> {}
+
+    impl<T> Drop for IntoIter<T> {
+        fn drop(&mut self) {
+            // 꺼내지 않은 요소 drop 후 버퍼 해제
+            for _ in &mut *self {}
+            if mem::size_of::<T>() != 0 && self.cap > 0 {
+                let layout = Layout::array::<T>(self.cap).unwrap();
+                // SAFETY: MyVec에서 넘겨받은 버퍼 - 같은 layout으로 할당됨
+                unsafe {
+                    alloc::dealloc(self.buf.as_ptr() as *mut u8, layout);
+                }
+            }
+        }
+    }
+
+    // 안전한 추상화는 테스트로 불변식을 검증하는 것이 관례
+    // cargo miri test 로 실행하면 UB까지 검사 가능
+    #[cfg(test)]
+    mod tests {
+        use super::MyVec;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        // drop 횟수를 세는 헬퍼 - 이중 해제/누수 검출용
+        struct DropCounter {
+            drops: Rc<Cell<usize>>,
+        }
+
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+            }
+        }
+
+        fn counters(n: usize, drops: &Rc<Cell<usize>>) -> MyVec<DropCounter> {
+            let mut v = MyVec::new();
+            for _ in 0..n {
+                v.push(DropCounter { drops: Rc::clone(drops) });
+            }
+            v
+        }
+
+        #[test]
+        fn new_is_empty() {
+            let v: MyVec<i32> = MyVec::new();
+            assert!(v.is_empty());
+            assert_eq!(v.len(), 0);
+            assert_eq!(v.capacity(), 0);
+            assert_eq!(v.get(0), None);
+            assert_eq!(&*v, &[] as &[i32]);
+        }
+
+        #[test]
+        fn push_and_get() {
+            let mut v = MyVec::new();
+            for i in 0..100 {
+                v.push(i);
+            }
+            assert_eq!(v.len(), 100);
+            assert!(v.capacity() >= 100);
+            assert_eq!(v.get(0), Some(&0));
+            assert_eq!(v.get(99), Some(&99));
+            assert_eq!(v.get(100), None);
+        }
+
+        #[test]
+        fn spare_capacity_is_the_uninit_tail() {
+            let mut v: MyVec<i32> = MyVec::new();
+            assert!(v.spare_capacity_mut().is_empty());
+            for i in 0..3 {
+                v.push(i);
+            }
+            // 용량 4, 길이 3 - 남은 칸에 써도 요소가 아님
+            assert_eq!(v.spare_capacity_mut().len(), v.capacity() - v.len());
+            v.spare_capacity_mut()[0].write(99);
+            assert_eq!(&*v, &[0, 1, 2]);
+            // push 가 같은 칸을 덮어씀
+            v.push(3);
+            assert_eq!(&*v, &[0, 1, 2, 3]);
+            assert!(v.spare_capacity_mut().is_empty());
+        }
+
+        #[test]
+        fn pop_returns_in_reverse_order() {
+            let mut v = MyVec::new();
+            v.push(1);
+            v.push(2);
+            v.push(3);
+            assert_eq!(v.pop(), Some(3));
+            assert_eq!(v.pop(), Some(2));
+            assert_eq!(v.pop(), Some(1));
+            assert_eq!(v.pop(), None);
+            assert!(v.is_empty());
+        }
+
+        #[test]
+        fn insert_at_front_middle_and_end() {
+            let mut v = MyVec::new();
+            v.insert(0, 2);
+            v.insert(0, 0);
+            v.insert(1, 1);
+            v.insert(3, 3);
+            assert_eq!(&*v, &[0, 1, 2, 3]);
+        }
+
+        #[test]
+        #[should_panic(expected = "insert index 2 out of bounds")]
+        fn insert_out_of_bounds_panics() {
+            let mut v = MyVec::new();
+            v.push(1);
+            v.insert(2, 5);
+        }
+
+        #[test]
+        fn remove_shifts_elements() {
+            let mut v = MyVec::new();
+            for i in 0..5 {
+                v.push(i);
+            }
+            assert_eq!(v.remove(0), 0);
+            assert_eq!(v.remove(3), 4);
+            assert_eq!(v.remove(1), 2);
+            assert_eq!(&*v, &[1, 3]);
+        }
+
+        #[test]
+        #[should_panic(expected = "remove index 0 out of bounds")]
+        fn remove_from_empty_panics() {
+            let mut v: MyVec<i32> = MyVec::new();
+            v.remove(0);
+        }
+
+        #[test]
+        fn deref_gives_slice_methods() {
+            let mut v = MyVec::new();
+            v.push(3);
+            v.push(1);
+            v.push(2);
+            // DerefMut으로 슬라이스 메서드 사용
+            v.sort();
+            assert_eq!(&*v, &[1, 2, 3]);
+            assert_eq!(v[0], 1);
+            assert_eq!(v.iter().sum::<i32>(), 6);
+            assert!(v.contains(&2));
+            v[2] = 30;
+            assert_eq!(v.last(), Some(&30));
+        }
+
+        #[test]
+        fn into_iter_yields_owned_values() {
+            let mut v = MyVec::new();
+            v.push(String::from("a"));
+            v.push(String::from("b"));
+            v.push(String::from("c"));
+            let mut it = v.into_iter();
+            assert_eq!(it.len(), 3);
+            assert_eq!(it.next().as_deref(), Some("a"));
+            assert_eq!(it.next_back().as_deref(), Some("c"));
+            assert_eq!(it.next().as_deref(), Some("b"));
+            assert_eq!(it.next(), None);
+            assert_eq!(it.next_back(), None);
+        }
+
+        #[test]
+        fn into_iter_collects() {
+            let mut v = MyVec::new();
+            for i in 1..=4 {
+                v.push(i);
+            }
+            let doubled: Vec<i32> = v.into_iter().map(|x| x * 2).collect();
+            assert_eq!(doubled, vec![2, 4, 6, 8]);
+        }
+
+        #[test]
+        fn drop_runs_once_per_element() {
+            let drops = Rc::new(Cell::new(0));
+            let v = counters(10, &drops);
+            assert_eq!(drops.get(), 0);
+            drop(v);
+            assert_eq!(drops.get(), 10);
+        }
+
+        #[test]
+        fn pop_and_remove_transfer_ownership() {
+            let drops = Rc::new(Cell::new(0));
+            let mut v = counters(4, &drops);
+            let popped = v.pop().unwrap();
+            let removed = v.remove(0);
+            assert_eq!(drops.get(), 0);
+            drop(popped);
+            drop(removed);
+            assert_eq!(drops.get(), 2);
+            drop(v);
+            assert_eq!(drops.get(), 4);
+        }
+
+        #[test]
+        fn partially_consumed_into_iter_drops_rest() {
+            let drops = Rc::new(Cell::new(0));
+            let mut it = counters(5, &drops).into_iter();
+            drop(it.next());
+            drop(it.next_back());
+            assert_eq!(drops.get(), 2);
+            drop(it);
+            assert_eq!(drops.get(), 5);
+        }
+
+        #[test]
+        fn zero_sized_types() {
+            let mut v = MyVec::new();
+            for _ in 0..1000 {
+                v.push(());
+            }
+            assert_eq!(v.len(), 1000);
+            assert_eq!(v.capacity(), usize::MAX);
+            v.insert(500, ());
+            assert_eq!(v.remove(0), ());
+            assert_eq!(v.pop(), Some(()));
+            assert_eq!(v.into_iter().count(), 999);
+        }
+
+        #[test]
+        fn send_and_sync_follow_element_type() {
+            fn assert_send<T: Send>() {}
+            fn assert_sync<T: Sync>() {}
+            assert_send::<MyVec<i32>>();
+            assert_sync::<MyVec<i32>>();
+            assert_send::<super::IntoIter<String>>();
+            // MyVec<Rc<i32>>는 Send가 아니므로 아래는 컴파일 에러
+            // assert_send::<MyVec<Rc<i32>>>();
+
+            let mut v = MyVec::new();
+            v.push(1);
+            v.push(2);
+            let handle = std::thread::spawn(move || v.iter().sum::<i32>());
+            assert_eq!(handle.join().unwrap(), 3);
+        }
+    }
+}
+
+fn safe_abstractions() {
+    println!("\n--- 안전한 추상화 ---");
+
+    use safe_wrapper::MyVec;
+
+    let mut v = MyVec::new();
+    v.push(1);
+    v.push(2);
+    v.push(3);
+
+    println!("MyVec 길이: {}, 용량: {}", v.len(), v.capacity());
+    println!("인덱스 1: {:?}", v.get(1));
+    println!("인덱스 10: {:?}", v.get(10));
+
+    // insert/remove - 내부적으로 ptr::copy (memmove)
+    v.insert(0, 0);
+    let removed = v.remove(2);
+    println!("insert(0, 0) 후 remove(2) = {}, 현재: {:?}", removed, &*v);
+    println!("pop: {:?}", v.pop());
+
+    // Deref<Target=[T]> - 슬라이스 메서드를 그대로 사용
+    v.push(10);
+    v.reverse();
+    println!("슬라이스 메서드 reverse(): {:?}, 합계: {}", &*v, v.iter().sum::<i32>());
+
+    // IntoIterator - 소유권을 가져가는 반복
+    let strings: Vec<String> = v.into_iter().map(|x| format!("#{}", x)).collect();
+    println!("into_iter(): {:?}", strings);
+    // println!("{}", v.len());  // 에러! v는 into_iter()로 이동됨
+
+    // Send - T가 Send이므로 다른 스레드로 이동 가능
+    let mut shared = MyVec::new();
+    shared.push(String::from("스레드로 이동"));
+    let handle = std::thread::spawn(move || shared.len());
+    println!("다른 스레드에서 길이: {}", handle.join().unwrap());
+
+    // 사용자는 unsafe 없이 안전하게 사용
+    // 내부 구현의 정확성은 라이브러리 작성자가 보장
+}
+
+fn main() {
+    safe_abstractions();
+}
@@ -0,0 +1,49 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 16. Unsafe Rust - 정적 가변 변수
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ----------------------------------------------------------------------------
+// 정적 가변 변수
+// ----------------------------------------------------------------------------
+// static mut 는 접근할 때마다 unsafe - 여러 스레드가 동시에 쓰면 데이터 레이스 (UB)
+// 2024 에디션은 &COUNTER 처럼 static mut 의 참조를 만드는 것도 에러 (static_mut_refs)
+// 가변 전역이 필요하면 내부 가변성을 가진 타입의 평범한 static 으로 - 정수는 Atomic*, 그 밖은 Mutex
+
+// static mut COUNTER: usize = 0;
+// fn add_to_counter(inc: usize) { unsafe { COUNTER += inc; } }   // 두 스레드가 부르면 더한 값을 잃을 수 있음
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn add_to_counter(inc: usize) {
+    // 읽고 더하고 쓰기가 하나의 원자적 연산 - unsafe 가 필요 없음
+    COUNTER.fetch_add(inc, Ordering::Relaxed);
+}
+
+fn static_mut_variables() {
+    println!("\n--- 정적 가변 변수 ---");
+
+    // C++ 에서는:
+    // static int counter = 0;                  // 전역 가변 변수 - 여러 스레드에서 ++counter 는 데이터 레이스 (UB)
+    // static std::atomic<std::size_t> counter;  // Rust 의 AtomicUsize 와 같음
+
+    add_to_counter(3);
+    add_to_counter(5);
+
+    // 스레드 여럿이 동시에 더해도 잃는 값이 없음 - 다른 데이터를 보호하지 않는 카운터라 Relaxed 로 충분 (Atomic 과 Mutex 비교는 52장)
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| add_to_counter(1));
+        }
+    });
+    println!("COUNTER = {}", COUNTER.load(Ordering::Relaxed));
+
+    // 처음 쓸 때 초기화하는 전역 (설정, 표, 레지스트리) 은 OnceLock, LazyLock - 52장
+}
+
+fn main() {
+    static_mut_variables();
+}
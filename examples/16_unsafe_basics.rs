@@ -0,0 +1,38 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 16. Unsafe Rust - Unsafe 기초
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ----------------------------------------------------------------------------
+// Unsafe 기초
+// ----------------------------------------------------------------------------
+
+fn unsafe_basics() {
+    println!("--- Unsafe 기초 ---");
+
+    // unsafe로 할 수 있는 5가지:
+    // 1. raw 포인터 역참조
+    // 2. unsafe 함수 또는 메서드 호출
+    // 3. 가변 정적 변수 접근 또는 수정
+    // 4. unsafe 트레이트 구현
+    // 5. union 필드 접근 (union 과 transmute 는 47장)
+
+    // unsafe는 빌림 검사기를 끄지 않음!
+    // 여전히 소유권 규칙은 적용됨
+
+    // 왜 unsafe가 필요한가?
+    // - 하드웨어 직접 제어
+    // - 성능 최적화
+    // - 다른 언어(C/C++)와 상호작용
+    // - 컴파일러가 증명할 수 없는 안전한 코드
+
+    println!("unsafe 블록은 '이 코드가 안전함을 내가 보장한다'는 의미입니다.");
+}
+
+fn main() {
+    unsafe_basics();
+}
@@ -0,0 +1,60 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 16. Unsafe Rust - Unsafe 함수
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ----------------------------------------------------------------------------
+// Unsafe 함수
+// ----------------------------------------------------------------------------
+
+// unsafe 함수 선언
+unsafe fn dangerous() {
+    println!("이 함수는 unsafe입니다!");
+}
+
+// 안전한 함수 내부에서 unsafe 사용
+fn split_at_mut(values: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
+    let len = values.len();
+    let ptr = values.as_mut_ptr();
+
+    assert!(mid <= len);
+
+    // 표준 라이브러리의 split_at_mut과 동일한 구현
+    // 빌림 검사기는 같은 슬라이스에서 두 개의 가변 참조를 만드는 것을 허용하지 않음
+    // 하지만 우리는 겹치지 않는 두 부분을 가리키므로 안전함
+    unsafe {
+        (
+            slice::from_raw_parts_mut(ptr, mid),
+            slice::from_raw_parts_mut(ptr.add(mid), len - mid),
+        )
+    }
+}
+
+fn unsafe_functions() {
+    println!("\n--- Unsafe 함수 ---");
+
+    // unsafe 함수 호출
+    unsafe {
+        dangerous();
+    }
+
+    // 안전한 추상화 사용
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+    let (left, right) = split_at_mut(&mut v, 3);
+
+    println!("left: {:?}", left);
+    println!("right: {:?}", right);
+
+    // 슬라이스 수정
+    left[0] = 100;
+    right[0] = 200;
+    println!("수정 후 v: {:?}", v);
+}
+
+fn main() {
+    unsafe_functions();
+}
@@ -0,0 +1,49 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 16. Unsafe Rust - Unsafe 트레이트
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ----------------------------------------------------------------------------
+// Unsafe 트레이트
+// ----------------------------------------------------------------------------
+
+// unsafe 트레이트 - 구현자가 불변 조건을 보장해야 함
+unsafe trait UnsafeTrait {
+    fn do_something(&self);
+}
+
+struct SafeType;
+
+// unsafe 트레이트 구현
+unsafe impl UnsafeTrait for SafeType {
+    fn do_something(&self) {
+        println!("SafeType이 UnsafeTrait을 구현했습니다.");
+    }
+}
+
+fn unsafe_traits() {
+    println!("\n--- Unsafe 트레이트 ---");
+
+    let s = SafeType;
+    s.do_something();
+
+    // 대표적인 unsafe 트레이트:
+    // Send - 스레드 간 소유권 이전 가능
+    // Sync - 스레드 간 참조 공유 가능
+
+    // 대부분의 타입은 자동으로 Send/Sync 구현
+    // raw 포인터, Rc 등은 구현 안 됨
+
+    println!("\nSend/Sync 트레이트:");
+    println!("- 컴파일러가 자동 구현 추론");
+    println!("- unsafe impl로 수동 구현 가능");
+    println!("- 잘못 구현하면 데이터 레이스 가능");
+}
+
+fn main() {
+    unsafe_traits();
+}
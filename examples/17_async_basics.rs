@@ -0,0 +1,70 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - Async 기초
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// Async 기초
+// ----------------------------------------------------------------------------
+
+// async fn은 Future를 반환하는 함수
+async fn say_hello() {
+    println!("안녕하세요!");
+}
+
+async fn delayed_message(msg: &str, delay_ms: u64) {
+    sleep(Duration::from_millis(delay_ms)).await;
+    println!("{}", msg);
+}
+
+async fn add_async(a: i32, b: i32) -> i32 {
+    // 비동기 계산 (여기서는 단순 예시)
+    sleep(Duration::from_millis(10)).await;
+    a + b
+}
+
+async fn async_basics() {
+    println!("--- Async 기초 ---");
+
+    // async 함수 호출 - Future 반환
+    let future = say_hello();
+
+    // .await로 Future 실행
+    future.await;
+
+    // 인자와 반환값이 있는 async 함수
+    let result = add_async(5, 3).await;
+    println!("5 + 3 = {}", result);
+
+    // async 블록 - 익명 Future 생성
+    let value = async {
+        let a = add_async(1, 2).await;
+        let b = add_async(3, 4).await;
+        a + b
+    }
+    .await;
+
+    println!("(1+2) + (3+4) = {}", value);
+
+    // C++20 코루틴과 비교:
+    // C++:
+    // task<int> add_async(int a, int b) {
+    //     co_await some_delay();
+    //     co_return a + b;
+    // }
+    //
+    // Rust:
+    // async fn add_async(a: i32, b: i32) -> i32 {
+    //     some_delay().await;
+    //     a + b
+    // }
+}
+
+#[tokio::main]
+async fn main() {
+    async_basics().await;
+}
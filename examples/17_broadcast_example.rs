@@ -0,0 +1,61 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - broadcast 채널 - 팬아웃 (모든 구독자가 모든 메시지 수신)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// broadcast 채널 - 팬아웃 (모든 구독자가 모든 메시지 수신)
+// ----------------------------------------------------------------------------
+
+async fn broadcast_example() {
+    println!("\n--- broadcast 채널 ---");
+
+    use tokio::sync::broadcast;
+
+    // mpsc: 메시지 하나를 소비자 하나가 받음
+    // broadcast: 메시지 하나를 모든 구독자가 받음 (T: Clone 필요)
+    let (tx, _) = broadcast::channel::<String>(16);
+
+    let mut handles = Vec::new();
+    for id in 1..=3 {
+        // subscribe() 이후에 보낸 메시지만 수신
+        let mut rx = tx.subscribe();
+        handles.push(tokio::spawn(async move {
+            let mut received = Vec::new();
+            while let Ok(msg) = rx.recv().await {
+                received.push(msg);
+            }
+            println!("구독자 {}: {:?}", id, received);
+        }));
+    }
+
+    for event in ["시작", "진행", "종료"] {
+        tx.send(event.to_string()).unwrap();
+    }
+    // 송신자를 모두 drop하면 recv()가 Err(Closed) 반환
+    drop(tx);
+
+    for h in handles {
+        h.await.unwrap();
+    }
+
+    // 느린 구독자 - 버퍼(용량)를 넘으면 오래된 메시지를 놓침
+    let (tx, mut slow) = broadcast::channel::<i32>(2);
+    for i in 0..5 {
+        tx.send(i).unwrap();
+    }
+    match slow.recv().await {
+        Err(broadcast::error::RecvError::Lagged(n)) => println!("느린 구독자: {}개 놓침", n),
+        other => println!("예상 밖: {:?}", other),
+    }
+    println!("놓친 뒤 다음 메시지: {:?}", slow.recv().await);
+}
+
+#[tokio::main]
+async fn main() {
+    broadcast_example().await;
+}
@@ -0,0 +1,66 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - 비동기 채널
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// 비동기 채널
+// ----------------------------------------------------------------------------
+
+async fn channels_async() {
+    println!("\n--- 비동기 채널 ---");
+
+    use tokio::sync::mpsc;
+
+    // 다중 생산자, 단일 소비자 채널
+    let (tx, mut rx) = mpsc::channel::<String>(32);
+
+    // 생산자 태스크
+    let tx1 = tx.clone();
+    tokio::spawn(async move {
+        for i in 0..3 {
+            tx1.send(format!("생산자1: {}", i)).await.unwrap();
+            sleep(Duration::from_millis(10)).await;
+        }
+    });
+
+    let tx2 = tx.clone();
+    tokio::spawn(async move {
+        for i in 0..3 {
+            tx2.send(format!("생산자2: {}", i)).await.unwrap();
+            sleep(Duration::from_millis(15)).await;
+        }
+    });
+
+    // 원본 tx drop (중요!)
+    drop(tx);
+
+    // 소비자
+    while let Some(msg) = rx.recv().await {
+        println!("수신: {}", msg);
+    }
+
+    println!("채널 종료");
+
+    // oneshot 채널 - 단일 값 전송
+    use tokio::sync::oneshot;
+
+    let (tx, rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        sleep(Duration::from_millis(50)).await;
+        tx.send("완료!").unwrap();
+    });
+
+    let result = rx.await.unwrap();
+    println!("oneshot 결과: {}", result);
+}
+
+#[tokio::main]
+async fn main() {
+    channels_async().await;
+}
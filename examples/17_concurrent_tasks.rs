@@ -0,0 +1,63 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - 동시 태스크
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// 동시 태스크
+// ----------------------------------------------------------------------------
+
+async fn fetch_data(id: u32) -> String {
+    println!("데이터 {} 요청 시작", id);
+    sleep(Duration::from_millis(100)).await;
+    println!("데이터 {} 요청 완료", id);
+    format!("데이터_{}", id)
+}
+
+async fn concurrent_tasks() {
+    println!("\n--- 동시 태스크 ---");
+
+    // 순차 실행 - 총 300ms
+    println!("순차 실행:");
+    let start = std::time::Instant::now();
+    let _d1 = fetch_data(1).await;
+    let _d2 = fetch_data(2).await;
+    let _d3 = fetch_data(3).await;
+    println!("순차 실행 시간: {:?}", start.elapsed());
+
+    // 동시 실행 - tokio::join!
+    println!("\n동시 실행 (join!):");
+    let start = std::time::Instant::now();
+    let (d1, d2, d3) = tokio::join!(fetch_data(1), fetch_data(2), fetch_data(3));
+    println!("결과: {}, {}, {}", d1, d2, d3);
+    println!("동시 실행 시간: {:?}", start.elapsed());
+
+    // 태스크 스폰 - 별도 태스크로 실행
+    println!("\n태스크 스폰:");
+    let handle1 = tokio::spawn(async {
+        fetch_data(10).await
+    });
+
+    let handle2 = tokio::spawn(async {
+        fetch_data(20).await
+    });
+
+    // 결과 대기
+    let result1 = handle1.await.unwrap();
+    let result2 = handle2.await.unwrap();
+    println!("스폰 결과: {}, {}", result1, result2);
+
+    // C++과 비교:
+    // C++: std::async, std::future
+    // Rust: tokio::spawn, Future
+    // 차이점: Rust는 런타임이 태스크를 효율적으로 스케줄링
+}
+
+#[tokio::main]
+async fn main() {
+    concurrent_tasks().await;
+}
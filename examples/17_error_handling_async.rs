@@ -0,0 +1,58 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - 비동기 에러 처리
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// 비동기 에러 처리
+// ----------------------------------------------------------------------------
+
+async fn might_fail(succeed: bool) -> Result<String, String> {
+    sleep(Duration::from_millis(10)).await;
+    if succeed {
+        Ok(String::from("성공!"))
+    } else {
+        Err(String::from("실패!"))
+    }
+}
+
+async fn error_handling_async() {
+    println!("\n--- 비동기 에러 처리 ---");
+
+    // ? 연산자 사용
+    async fn process() -> Result<(), String> {
+        let result = might_fail(true).await?;
+        println!("결과: {}", result);
+        Ok(())
+    }
+
+    match process().await {
+        Ok(_) => println!("process 성공"),
+        Err(e) => println!("process 에러: {}", e),
+    }
+
+    // try_join! - 모든 Future 성공해야 함
+    let result = tokio::try_join!(might_fail(true), might_fail(true));
+
+    match result {
+        Ok((a, b)) => println!("try_join 성공: {}, {}", a, b),
+        Err(e) => println!("try_join 실패: {}", e),
+    }
+
+    // 하나라도 실패하면 에러
+    let result = tokio::try_join!(might_fail(true), might_fail(false));
+
+    match result {
+        Ok((a, b)) => println!("try_join 성공: {}, {}", a, b),
+        Err(e) => println!("try_join 실패: {}", e),
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    error_handling_async().await;
+}
@@ -0,0 +1,76 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - Future 설명
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// Future 설명
+// ----------------------------------------------------------------------------
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+// 커스텀 Future 구현
+struct CountdownFuture {
+    count: u32,
+}
+
+impl Future for CountdownFuture {
+    type Output = String;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.count == 0 {
+            Poll::Ready(String::from("발사!"))
+        } else {
+            println!("카운트다운: {}", self.count);
+            self.count -= 1;
+            // 즉시 다시 poll하도록 waker 호출
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+async fn futures_explained() {
+    println!("\n--- Future 설명 ---");
+
+    // Future 트레이트:
+    // trait Future {
+    //     type Output;
+    //     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+    // }
+    // Pin 을 받는 이유 (자기 참조 상태 기계): 29장
+    // 트레이트 메서드를 async fn 으로 (dyn, Send 바운드): 31장
+
+    // Poll 열거형:
+    // enum Poll<T> {
+    //     Ready(T),    // 완료됨
+    //     Pending,     // 아직 완료 안 됨
+    // }
+
+    println!("Future는 poll될 때만 진행됩니다 (lazy)");
+
+    // 커스텀 Future 실행
+    let countdown = CountdownFuture { count: 3 };
+    let result = countdown.await;
+    println!("결과: {}", result);
+
+    // async/await는 컴파일러가 상태 머신으로 변환
+    // 각 .await 지점이 상태 전환점
+
+    println!("\n비동기의 핵심:");
+    println!("1. Future 생성 (실행 X)");
+    println!("2. 런타임이 poll 호출");
+    println!("3. Pending이면 나중에 다시 poll");
+    println!("4. Ready면 결과 반환");
+}
+
+#[tokio::main]
+async fn main() {
+    futures_explained().await;
+}
@@ -0,0 +1,56 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - interval - 주기적 작업
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// interval - 주기적 작업
+// ----------------------------------------------------------------------------
+
+async fn interval_example() {
+    println!("\n--- interval 주기 작업 ---");
+
+    use tokio::time::{interval, Instant, MissedTickBehavior};
+
+    let start = Instant::now();
+
+    // sleep 루프와 달리 작업 시간과 무관하게 일정한 주기 유지
+    let mut ticker = interval(Duration::from_millis(20));
+    // 작업이 늦어져 틱을 놓쳤을 때의 정책 (기본값: Burst)
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    for i in 0..3 {
+        // 첫 tick()은 즉시 완료
+        ticker.tick().await;
+        println!("tick {} (+{}ms)", i, start.elapsed().as_millis());
+    }
+
+    // 백그라운드 하트비트 + 종료 신호
+    let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel::<()>();
+    let heartbeat = tokio::spawn(async move {
+        let mut ticker = interval(Duration::from_millis(15));
+        let mut beats = 0;
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => beats += 1,
+                _ = &mut stop_rx => break,
+            }
+        }
+        beats
+    });
+
+    sleep(Duration::from_millis(50)).await;
+    let _ = stop_tx.send(());
+    println!("하트비트 횟수: {}", heartbeat.await.unwrap());
+
+    // tokio_stream::wrappers::IntervalStream 으로 스트림처럼 쓸 수도 있음
+}
+
+#[tokio::main]
+async fn main() {
+    interval_example().await;
+}
@@ -0,0 +1,52 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - select! 매크로
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// select! 매크로
+// ----------------------------------------------------------------------------
+
+async fn select_example() {
+    println!("\n--- select! 매크로 ---");
+
+    use tokio::sync::mpsc;
+
+    let (tx, mut rx) = mpsc::channel::<i32>(10);
+
+    tokio::spawn(async move {
+        for i in 0..5 {
+            sleep(Duration::from_millis(100)).await;
+            let _ = tx.send(i).await;
+        }
+    });
+
+    let timeout = sleep(Duration::from_millis(250));
+    tokio::pin!(timeout);
+
+    loop {
+        tokio::select! {
+            // 채널에서 수신
+            Some(msg) = rx.recv() => {
+                println!("수신: {}", msg);
+            }
+            // 타임아웃
+            _ = &mut timeout => {
+                println!("타임아웃!");
+                break;
+            }
+        }
+    }
+
+    // select!는 여러 Future 중 먼저 완료되는 것 선택
+    // C++에는 직접적인 대응이 없음 (직접 구현 필요)
+}
+
+#[tokio::main]
+async fn main() {
+    select_example().await;
+}
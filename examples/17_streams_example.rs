@@ -0,0 +1,62 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - Stream - 비동기 이터레이터
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// Stream - 비동기 이터레이터
+// ----------------------------------------------------------------------------
+// Iterator::next()   -> Option<T>
+// Stream::next()     -> impl Future<Output = Option<T>>
+// C++20: std::generator는 동기 - 비동기 버전은 표준에 없음
+
+async fn streams_example() {
+    println!("\n--- Stream (tokio_stream) ---");
+    // 더 자세히 (직접 구현, 묶음과 동시 처리, StreamMap): 30장
+
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use tokio_stream::StreamExt; // next(), map(), filter() 등 어댑터
+
+    // 이터레이터에서 스트림 만들기
+    let mut stream = tokio_stream::iter(vec![1, 2, 3]);
+    while let Some(v) = stream.next().await {
+        println!("iter 스트림: {}", v);
+    }
+
+    // 이터레이터와 같은 어댑터 체이닝
+    let evens: Vec<i32> = tokio_stream::iter(1..=10)
+        .filter(|x| x % 2 == 0)
+        .map(|x| x * 10)
+        .collect()
+        .await;
+    println!("filter + map + collect: {:?}", evens);
+
+    // 채널 수신자를 스트림으로 감싸기
+    let (tx, rx) = mpsc::channel::<&str>(4);
+    tokio::spawn(async move {
+        for word in ["하나", "둘", "셋"] {
+            let _ = tx.send(word).await;
+        }
+    });
+
+    // timeout 어댑터 - 각 항목에 시간 제한
+    // 내부에 Sleep(!Unpin)을 가지므로 next() 호출 전에 pin 필요
+    let words = ReceiverStream::new(rx).timeout(Duration::from_millis(100));
+    tokio::pin!(words);
+    while let Some(item) = words.next().await {
+        match item {
+            Ok(word) => println!("ReceiverStream: {}", word),
+            Err(_) => println!("항목 타임아웃"),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    streams_example().await;
+}
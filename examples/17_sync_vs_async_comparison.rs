@@ -0,0 +1,57 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - 동기 vs 비동기 비교
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// 동기 vs 비동기 비교
+// ----------------------------------------------------------------------------
+
+fn sync_vs_async_comparison() {
+    println!("\n--- 동기 vs 비동기 비교 ---");
+
+    println!("
+┌─────────────────────────────────────────────────────────────┐
+│                    동기 (Synchronous)                       │
+├─────────────────────────────────────────────────────────────┤
+│ - 블로킹 I/O                                                │
+│ - 스레드당 하나의 작업                                       │
+│ - 간단한 코드 흐름                                          │
+│ - 많은 동시 연결 시 스레드 수 증가                           │
+│                                                             │
+│ C++: std::thread + 블로킹 I/O                               │
+│ Rust: std::thread + std::io                                 │
+└─────────────────────────────────────────────────────────────┘
+
+┌─────────────────────────────────────────────────────────────┐
+│                   비동기 (Asynchronous)                     │
+├─────────────────────────────────────────────────────────────┤
+│ - 논블로킹 I/O                                              │
+│ - 소수의 스레드로 많은 작업                                  │
+│ - async/await로 동기 코드처럼 작성                          │
+│ - I/O 바운드 작업에 적합                                    │
+│                                                             │
+│ C++20: co_await + coroutines                                │
+│ Rust: async/await + tokio/async-std                         │
+└─────────────────────────────────────────────────────────────┘
+");
+
+    println!("언제 비동기를 사용할까?");
+    println!("✓ 네트워크 I/O (HTTP 서버, 클라이언트)"); // 38장에서 직접 만들어 봄
+    println!("✓ 파일 I/O (많은 파일 동시 처리)");
+    println!("✓ 타이머, 지연");
+    println!("✓ 많은 동시 연결");
+    println!();
+    println!("언제 동기를 사용할까?");
+    println!("✓ CPU 바운드 작업");
+    println!("✓ 간단한 스크립트");
+    println!("✓ 동시성이 필요 없는 경우");
+}
+
+fn main() {
+    sync_vs_async_comparison();
+}
@@ -0,0 +1,63 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 17. 비동기 프로그래밍 (Async/Await) - watch 채널 - 최신 값 하나만 유지 (설정 전파에 적합)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::time::Duration;
+use tokio::time::sleep;
+
+// ----------------------------------------------------------------------------
+// watch 채널 - 최신 값 하나만 유지 (설정 전파에 적합)
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+struct AppConfig {
+    log_level: &'static str,
+    max_connections: u32,
+}
+
+async fn watch_example() {
+    println!("\n--- watch 채널 ---");
+
+    use tokio::sync::watch;
+
+    let (tx, mut rx) = watch::channel(AppConfig {
+        log_level: "info",
+        max_connections: 100,
+    });
+
+    // 워커는 설정이 바뀔 때마다 깨어남
+    let worker = tokio::spawn(async move {
+        // changed()는 마지막으로 본 이후 값이 바뀌면 완료
+        // 송신자가 drop되면 Err 반환
+        while rx.changed().await.is_ok() {
+            // borrow_and_update() - 값을 읽고 "봤음"으로 표시
+            let config = rx.borrow_and_update().clone();
+            println!("워커: 새 설정 적용 {:?}", config);
+        }
+        println!("워커: 설정 채널 종료");
+    });
+
+    // 현재 값 읽기 - 대기 없음
+    println!("초기 설정: {:?}", *tx.borrow());
+
+    sleep(Duration::from_millis(10)).await;
+    tx.send_modify(|c| c.log_level = "debug");
+    sleep(Duration::from_millis(10)).await;
+
+    // 중간 값은 건너뛸 수 있음 - 수신자는 항상 "최신" 값만 봄
+    tx.send_modify(|c| c.max_connections = 200);
+    tx.send_modify(|c| c.max_connections = 500);
+    sleep(Duration::from_millis(10)).await;
+
+    drop(tx);
+    worker.await.unwrap();
+
+    // C++: std::atomic<std::shared_ptr<Config>> + condition_variable 조합과 유사
+}
+
+#[tokio::main]
+async fn main() {
+    watch_example().await;
+}
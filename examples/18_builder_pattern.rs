@@ -0,0 +1,235 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 1. 빌더 패턴 (Builder Pattern)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// --- rust-study 의 macros 모듈 ---
+mod macros {
+// ============================================================================
+// 공용 매크로 모음 (Shared Macros)
+// ============================================================================
+// 15장에서 정의한 매크로 중 다른 모듈에서도 쓰는 것들을 모아둔 모듈
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 매크로는 #include 순서에 따라 보임 - Rust는 경로(path)로 가져옴
+// 2. #[macro_export] - 크레이트 루트에 매크로를 공개 (crate::my_vec!)
+// 3. pub(crate) use - 일반 아이템처럼 모듈 경로로 재공개 (crate::macros::hashmap!)
+// 4. $crate - 매크로가 어디서 확장되든 정의된 크레이트를 가리키는 경로
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// #[macro_export] 방식
+// ----------------------------------------------------------------------------
+// 모듈 위치와 상관없이 크레이트 루트에 등록됨
+// 사용: use crate::my_vec; 또는 crate::my_vec![...]
+// 라이브러리 크레이트라면 외부에서 my_crate::my_vec! 로 사용 가능
+
+// 요소 개수를 컴파일 타임에 세는 내부용 매크로
+// 다른 매크로가 $crate::count_exprs! 로 호출하므로 반드시 export 되어야 함
+#[doc(hidden)]
+#[macro_export]
+macro_rules! count_exprs {
+    () => { 0usize };
+    ($head:expr $(, $tail:expr)*) => { 1usize + $crate::count_exprs!($($tail),*) };
+}
+
+// vec! 매크로와 유사한 구현
+// $crate::count_exprs! - 호출하는 쪽에서 count_exprs를 use 하지 않아도 동작
+#[macro_export]
+macro_rules! my_vec {
+    // 빈 벡터
+    () => {
+        Vec::new()
+    };
+    // 요소가 있는 벡터 - 개수만큼 미리 할당
+    ($($element:expr),+ $(,)?) => {
+        {
+            let mut v = Vec::with_capacity($crate::count_exprs!($($element),+));
+            $(
+                v.push($element);
+            )+
+            v
+        }
+    };
+}
+
+// ----------------------------------------------------------------------------
+// pub(crate) use 방식 (Rust 2018+ 권장)
+// ----------------------------------------------------------------------------
+// macro_rules! 정의 후 use로 재공개하면 일반 함수처럼 모듈 경로를 가짐
+// 사용: use crate::macros::hashmap;
+
+// 해시맵 생성
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {
+        {
+            let mut map = std::collections::HashMap::with_capacity(
+                $crate::count_exprs!($($key),*)
+            );
+            $(
+                map.insert($key, $value);
+            )*
+            map
+        }
+    };
+}
+
+// 메서드 체이닝 빌더
+macro_rules! builder_field {
+    ($name:ident, $t:ty) => {
+        pub fn $name(mut self, value: $t) -> Self {
+            self.$name = value;
+            self
+        }
+    };
+}
+
+pub(crate) use builder_field;
+pub(crate) use hashmap;
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait StrExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+    fn is_blank(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        // 바이트가 아닌 문자(char) 단위로 잘라야 한글이 깨지지 않음
+        if self.chars().count() <= max_chars {
+            return self.to_string();
+        }
+        let mut out: String = self.chars().take(max_chars.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+// ============================================================================
+// 1. 빌더 패턴 (Builder Pattern)
+// ============================================================================
+
+fn builder_pattern() {
+    println!("--- 빌더 패턴 ---");
+
+    // 복잡한 객체를 단계별로 생성
+    // C++: 빌더 클래스 + 메서드 체이닝
+
+    #[derive(Debug)]
+    struct Server {
+        host: String,
+        port: u16,
+        max_connections: u32,
+        timeout_secs: u64,
+        tls_enabled: bool,
+    }
+
+    // 빌더 구조체
+    #[derive(Default)]
+    struct ServerBuilder {
+        host: Option<String>,
+        port: Option<u16>,
+        max_connections: Option<u32>,
+        timeout_secs: Option<u64>,
+        tls_enabled: Option<bool>,
+    }
+
+    impl ServerBuilder {
+        fn new() -> Self {
+            Self::default()
+        }
+
+        // 각 메서드는 self를 소비하고 Self를 반환 (소유권 이동)
+        fn host(mut self, host: impl Into<String>) -> Self {
+            self.host = Some(host.into());
+            self
+        }
+
+        fn port(mut self, port: u16) -> Self {
+            self.port = Some(port);
+            self
+        }
+
+        fn max_connections(mut self, max: u32) -> Self {
+            self.max_connections = Some(max);
+            self
+        }
+
+        fn timeout(mut self, secs: u64) -> Self {
+            self.timeout_secs = Some(secs);
+            self
+        }
+
+        fn tls(mut self, enabled: bool) -> Self {
+            self.tls_enabled = Some(enabled);
+            self
+        }
+
+        // 최종 빌드 - 필수 필드 검증
+        fn build(self) -> Result<Server, &'static str> {
+            Ok(Server {
+                host: self.host.ok_or("host is required")?,
+                port: self.port.ok_or("port is required")?,
+                max_connections: self.max_connections.unwrap_or(100),
+                timeout_secs: self.timeout_secs.unwrap_or(30),
+                tls_enabled: self.tls_enabled.unwrap_or(false),
+            })
+        }
+    }
+
+    // 사용
+    let server = ServerBuilder::new()
+        .host("localhost")
+        .port(8080)
+        .max_connections(1000)
+        .tls(true)
+        .build()
+        .unwrap();
+
+    println!("서버 설정: {:?}", server);
+
+    // 필수 필드 누락 시 에러
+    let result = ServerBuilder::new().host("localhost").build();
+    println!("필수 필드 누락: {:?}", result);
+
+    // C++ 빌더와의 차이:
+    // - Rust는 소유권으로 빌더 재사용 방지 가능
+    // - Option으로 선택적 필드 명확히 표현
+    // - Result로 빌드 실패 처리
+
+    // 필드가 많은 단순 빌더는 매크로로 보일러플레이트 제거
+    // builder_field!는 15장에서 정의한 매크로 (crate::macros 모듈)
+    use crate::macros::builder_field;
+
+    #[derive(Debug)]
+    struct RetryPolicy {
+        max_retries: u32,
+        backoff_ms: u64,
+    }
+
+    impl RetryPolicy {
+        fn new() -> Self {
+            RetryPolicy { max_retries: 3, backoff_ms: 100 }
+        }
+
+        builder_field!(max_retries, u32);
+        builder_field!(backoff_ms, u64);
+    }
+
+    let policy = RetryPolicy::new().max_retries(5).backoff_ms(250);
+    println!("매크로로 만든 빌더: {:?}", policy);
+}
+
+fn main() {
+    builder_pattern();
+}
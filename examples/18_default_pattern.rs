@@ -0,0 +1,73 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 5. Default 트레이트 활용
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// ============================================================================
+// 5. Default 트레이트 활용
+// ============================================================================
+
+fn default_pattern() {
+    println!("\n--- Default 패턴 ---");
+
+    // 타입의 기본값 정의
+
+    #[derive(Debug)]
+    struct Config {
+        debug: bool,
+        log_level: String,
+        max_threads: usize,
+        timeout_ms: u64,
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Config {
+                debug: false,
+                log_level: String::from("info"),
+                max_threads: 4,
+                timeout_ms: 5000,
+            }
+        }
+    }
+
+    // 기본값 사용
+    let config1 = Config::default();
+    println!("기본 설정: {:?}", config1);
+
+    // 일부만 커스터마이즈 (구조체 업데이트 문법)
+    let config2 = Config {
+        debug: true,
+        max_threads: 8,
+        ..Default::default()
+    };
+    println!("커스텀 설정: {:?}", config2);
+
+    // derive로 자동 구현 (모든 필드가 Default 구현 시)
+    #[derive(Debug, Default)]
+    struct Stats {
+        count: u32,      // 0
+        total: f64,      // 0.0
+        name: String,    // ""
+    }
+
+    let stats = Stats::default();
+    println!("기본 통계: {:?}", stats);
+
+    // Option<T>의 unwrap_or_default
+    let maybe_value: Option<i32> = None;
+    let value = maybe_value.unwrap_or_default(); // 0
+    println!("기본값: {}", value);
+
+    // Vec의 기본값은 빈 벡터
+    let items: Vec<i32> = Default::default();
+    println!("빈 벡터: {:?}", items);
+}
+
+fn main() {
+    default_pattern();
+}
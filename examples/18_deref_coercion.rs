@@ -0,0 +1,95 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 6. Deref 강제 변환 (Deref Coercion)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait StrExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+    fn is_blank(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        // 바이트가 아닌 문자(char) 단위로 잘라야 한글이 깨지지 않음
+        if self.chars().count() <= max_chars {
+            return self.to_string();
+        }
+        let mut out: String = self.chars().take(max_chars.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+// ============================================================================
+// 6. Deref 강제 변환 (Deref Coercion)
+// ============================================================================
+
+fn deref_coercion() {
+    println!("\n--- Deref 강제 변환 ---");
+
+    // &T에서 &U로 자동 변환 (T: Deref<Target=U>)
+
+    // String -> &str
+    fn print_str(s: &str) {
+        println!("문자열: {}", s);
+    }
+
+    let owned = String::from("Hello");
+    print_str(&owned); // &String -> &str 자동 변환
+
+    // Box<T> -> &T
+    fn print_value(v: &i32) {
+        println!("값: {}", v);
+    }
+
+    let boxed = Box::new(42);
+    print_value(&boxed); // &Box<i32> -> &i32 자동 변환
+
+    // Vec<T> -> &[T]
+    fn print_slice(s: &[i32]) {
+        println!("슬라이스: {:?}", s);
+    }
+
+    let vec = vec![1, 2, 3];
+    print_slice(&vec); // &Vec<i32> -> &[i32] 자동 변환
+
+    // 커스텀 스마트 포인터
+    struct MyBox<T>(T);
+
+    impl<T> MyBox<T> {
+        fn new(value: T) -> Self {
+            MyBox(value)
+        }
+    }
+
+    impl<T> Deref for MyBox<T> {
+        type Target = T;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    let my_box = MyBox::new(String::from("Rust"));
+    print_str(&my_box); // &MyBox<String> -> &String -> &str
+
+    // 변환 체인:
+    // &MyBox<String> -> &String (Deref)
+    // &String -> &str (Deref)
+
+    println!("Deref 체인 동작 확인");
+}
+
+fn main() {
+    deref_coercion();
+}
@@ -0,0 +1,157 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 8. 에러 처리 Best Practices
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait StrExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+    fn is_blank(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        // 바이트가 아닌 문자(char) 단위로 잘라야 한글이 깨지지 않음
+        if self.chars().count() <= max_chars {
+            return self.to_string();
+        }
+        let mut out: String = self.chars().take(max_chars.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+// ============================================================================
+// 8. 에러 처리 Best Practices
+// ============================================================================
+
+fn error_handling_best_practices() {
+    println!("\n--- 에러 처리 Best Practices ---");
+
+    // 1. 커스텀 에러 타입 정의
+
+    #[derive(Debug)]
+    enum AppError {
+        NotFound { resource: String },
+        InvalidInput { field: String, message: String },
+        Io(std::io::Error),
+        Parse(std::num::ParseIntError),
+    }
+
+    // Display 구현 (사용자 친화적 메시지)
+    impl fmt::Display for AppError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                AppError::NotFound { resource } => {
+                    write!(f, "리소스를 찾을 수 없음: {}", resource)
+                }
+                AppError::InvalidInput { field, message } => {
+                    write!(f, "잘못된 입력 - {}: {}", field, message)
+                }
+                AppError::Io(err) => write!(f, "IO 에러: {}", err),
+                AppError::Parse(err) => write!(f, "파싱 에러: {}", err),
+            }
+        }
+    }
+
+    // std::error::Error 구현
+    impl std::error::Error for AppError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                AppError::Io(err) => Some(err),
+                AppError::Parse(err) => Some(err),
+                _ => None,
+            }
+        }
+    }
+
+    // From 구현으로 ? 연산자 지원
+    impl From<std::io::Error> for AppError {
+        fn from(err: std::io::Error) -> Self {
+            AppError::Io(err)
+        }
+    }
+
+    impl From<std::num::ParseIntError> for AppError {
+        fn from(err: std::num::ParseIntError) -> Self {
+            AppError::Parse(err)
+        }
+    }
+
+    // 2. 사용 예시
+
+    fn find_user(id: u64) -> Result<String, AppError> {
+        if id == 0 {
+            return Err(AppError::NotFound {
+                resource: format!("user/{}", id),
+            });
+        }
+        Ok(format!("User_{}", id))
+    }
+
+    fn validate_age(age_str: &str) -> Result<u32, AppError> {
+        let age: u32 = age_str.parse()?; // ParseIntError -> AppError
+
+        if age > 150 {
+            return Err(AppError::InvalidInput {
+                field: String::from("age"),
+                message: String::from("나이는 150 이하여야 함"),
+            });
+        }
+
+        Ok(age)
+    }
+
+    // 테스트
+    println!("find_user(1): {:?}", find_user(1));
+    println!("find_user(0): {:?}", find_user(0));
+    println!("validate_age(\"25\"): {:?}", validate_age("25"));
+    println!("validate_age(\"abc\"): {:?}", validate_age("abc"));
+    println!("validate_age(\"200\"): {:?}", validate_age("200"));
+
+    // 3. 에러 체이닝 (context 추가)
+    // 실무에서는 anyhow::Context 트레이트 사용
+
+    fn process_user(id: &str) -> Result<String, AppError> {
+        let user_id: u64 = id.parse()?;
+        let user = find_user(user_id)?;
+        Ok(format!("처리됨: {}", user))
+    }
+
+    println!("process_user(\"5\"): {:?}", process_user("5"));
+    println!("process_user(\"abc\"): {:?}", process_user("abc"));
+
+    // 4. thiserror 스타일 (실제로는 매크로 사용)
+    // #[derive(thiserror::Error, Debug)]
+    // enum MyError {
+    //     #[error("not found: {0}")]
+    //     NotFound(String),
+    //     #[error("io error")]
+    //     Io(#[from] std::io::Error),
+    // }
+
+    // 5. anyhow 스타일 (동적 에러)
+    // fn main() -> anyhow::Result<()> {
+    //     let result = operation().context("작업 실패")?;
+    //     Ok(())
+    // }
+
+    println!("\n실무 에러 처리 권장사항:");
+    println!("1. 라이브러리: 구체적인 에러 타입 (thiserror)");
+    println!("2. 애플리케이션: 동적 에러 (anyhow)");
+    println!("3. 에러 체인으로 컨텍스트 보존");
+    println!("4. Display로 사용자 메시지, Debug로 개발자 정보");
+}
+
+fn main() {
+    error_handling_best_practices();
+}
@@ -0,0 +1,73 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 9. 확장 트레이트 (Extension Trait)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// ============================================================================
+// 9. 확장 트레이트 (Extension Trait)
+// ============================================================================
+
+// 외부 타입(str)에 메서드 추가 - 고아 규칙(orphan rule) 안에서 가능
+// C++: 멤버 함수는 추가 불가 -> 자유 함수(truncate_ellipsis(s, n))로 대체
+// 관례: 이름은 <타입>Ext, 사용하려면 트레이트를 use 해야 함
+trait StrExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+    fn is_blank(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        // 바이트가 아닌 문자(char) 단위로 잘라야 한글이 깨지지 않음
+        if self.chars().count() <= max_chars {
+            return self.to_string();
+        }
+        let mut out: String = self.chars().take(max_chars.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+// 제네릭 확장 - 조건을 만족하는 모든 타입에 한 번에 추가 (blanket impl)
+trait IteratorExt: Iterator {
+    fn sum_by<F>(self, f: F) -> i64
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> i64,
+    {
+        self.map(f).sum()
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+fn extension_trait_pattern() {
+    println!("\n--- 확장 트레이트 ---");
+
+    let title = "러스트로 만드는 고성능 게임 서버";
+    println!("원본: {}", title);
+    println!("truncate_ellipsis(8): {}", title.truncate_ellipsis(8));
+    println!("truncate_ellipsis(100): {}", title.truncate_ellipsis(100));
+
+    // &String도 Deref로 str의 확장 메서드 사용 가능
+    let owned = String::from("   ");
+    println!("\"   \".is_blank(): {}", owned.is_blank());
+
+    // 모든 이터레이터에 추가된 메서드
+    let words = ["a", "bb", "ccc"];
+    println!("글자 수 합계: {}", words.iter().sum_by(|w| w.len() as i64));
+
+    // 표준/생태계의 예: itertools::Itertools, futures::StreamExt,
+    // tokio_stream::StreamExt (17장), std::io::Read 확장인 byteorder::ReadBytesExt
+}
+
+fn main() {
+    extension_trait_pattern();
+}
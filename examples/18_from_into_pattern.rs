@@ -0,0 +1,97 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 4. From/Into 트레이트 활용
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// ============================================================================
+// 4. From/Into 트레이트 활용
+// ============================================================================
+
+fn from_into_pattern() {
+    println!("\n--- From/Into 패턴 ---");
+
+    // From 트레이트 구현하면 Into는 자동 구현
+    // 타입 변환의 표준 방법
+
+    #[derive(Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    // 튜플에서 Point로 변환
+    impl From<(i32, i32)> for Point {
+        fn from(tuple: (i32, i32)) -> Self {
+            Point {
+                x: tuple.0,
+                y: tuple.1,
+            }
+        }
+    }
+
+    // 배열에서 Point로 변환
+    impl From<[i32; 2]> for Point {
+        fn from(arr: [i32; 2]) -> Self {
+            Point { x: arr[0], y: arr[1] }
+        }
+    }
+
+    // From 사용
+    let p1 = Point::from((10, 20));
+    let p2: Point = (30, 40).into();
+    let p3: Point = [50, 60].into();
+
+    println!("p1: {:?}, p2: {:?}, p3: {:?}", p1, p2, p3);
+
+    // 함수 매개변수에서 활용
+    fn process_point(p: impl Into<Point>) {
+        let point: Point = p.into();
+        println!("처리: {:?}", point);
+    }
+
+    process_point((1, 2));
+    process_point([3, 4]);
+    process_point(Point { x: 5, y: 6 });
+
+    // 에러 변환에 활용
+    #[derive(Debug)]
+    struct CustomError {
+        message: String,
+    }
+
+    impl From<std::io::Error> for CustomError {
+        fn from(err: std::io::Error) -> Self {
+            CustomError {
+                message: err.to_string(),
+            }
+        }
+    }
+
+    impl From<std::num::ParseIntError> for CustomError {
+        fn from(err: std::num::ParseIntError) -> Self {
+            CustomError {
+                message: err.to_string(),
+            }
+        }
+    }
+
+    // ? 연산자가 자동으로 From 호출
+    fn parse_and_read() -> Result<i32, CustomError> {
+        let num: i32 = "42".parse()?; // ParseIntError -> CustomError
+        Ok(num)
+    }
+
+    println!("파싱 결과: {:?}", parse_and_read());
+
+    // C++ 비교:
+    // C++: explicit 변환 생성자, 변환 연산자
+    // Rust: From/Into 트레이트로 일관된 패턴
+}
+
+fn main() {
+    from_into_pattern();
+}
@@ -0,0 +1,99 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 11. 안티패턴: 상속 흉내를 위한 Newtype + Deref
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait StrExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+    fn is_blank(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        // 바이트가 아닌 문자(char) 단위로 잘라야 한글이 깨지지 않음
+        if self.chars().count() <= max_chars {
+            return self.to_string();
+        }
+        let mut out: String = self.chars().take(max_chars.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+// ============================================================================
+// 11. 안티패턴: 상속 흉내를 위한 Newtype + Deref
+// ============================================================================
+
+fn newtype_deref_antipattern() {
+    println!("\n--- 안티패턴: Newtype + Deref ---");
+
+    // C++ 개발자가 흔히 하는 실수: "상속 대신 Deref로 부모 메서드 물려받기"
+    // class UserList : public std::vector<String> { ... };
+    struct UserList(Vec<String>);
+
+    impl Deref for UserList {
+        type Target = Vec<String>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl UserList {
+        // 불변식: 이름은 비어 있지 않아야 함
+        fn add(&mut self, name: &str) -> bool {
+            if name.is_blank() {
+                return false;
+            }
+            self.0.push(name.to_string());
+            true
+        }
+    }
+
+    let mut users = UserList(Vec::new());
+    users.add("alice");
+    users.add("   ");
+    // Vec의 메서드가 그대로 노출됨 - 편해 보이지만...
+    println!("users.len() = {}, first = {:?}", users.len(), users.first());
+
+    // 문제점:
+    // 1. Newtype의 목적(불변식/API 제한)이 무너짐 - Vec 전체 API가 공개됨
+    //    DerefMut까지 구현하면 users.push(String::new())로 불변식 우회 가능
+    // 2. 메서드 해석이 암묵적 - 어떤 메서드가 어디서 왔는지 읽기 어려움
+    // 3. 트레이트는 상속되지 않음 - Vec이 구현한 Extend, IntoIterator 등은
+    //    UserList에 없음 (C++ 상속처럼 동작하지 않음)
+    // 4. 제네릭 함수에 넘길 때 &*users 같은 명시적 역참조가 필요해짐
+
+    // 권장: 필요한 메서드만 명시적으로 위임하거나 as_slice()로 읽기 전용 뷰 제공
+    struct SafeUserList(Vec<String>);
+
+    impl SafeUserList {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn as_slice(&self) -> &[String] {
+            &self.0
+        }
+    }
+
+    let safe = SafeUserList(vec![String::from("bob")]);
+    println!("SafeUserList: len = {}, slice = {:?}", safe.len(), safe.as_slice());
+
+    // Deref가 적절한 경우: 스마트 포인터처럼 "T를 가리키는" 타입 (Box, Rc, MutexGuard)
+    // 공식 API 가이드라인(C-DEREF): Deref는 스마트 포인터에만 구현
+}
+
+fn main() {
+    newtype_deref_antipattern();
+}
@@ -0,0 +1,109 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 2. Newtype 패턴
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait StrExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+    fn is_blank(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        // 바이트가 아닌 문자(char) 단위로 잘라야 한글이 깨지지 않음
+        if self.chars().count() <= max_chars {
+            return self.to_string();
+        }
+        let mut out: String = self.chars().take(max_chars.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+// ============================================================================
+// 2. Newtype 패턴
+// ============================================================================
+
+fn newtype_pattern() {
+    println!("\n--- Newtype 패턴 ---");
+
+    // 기존 타입을 감싸서 새로운 타입 생성
+    // 컴파일 타임에 타입 구분, 런타임 오버헤드 없음
+
+    // 단위 구분
+    #[derive(Debug, Clone, Copy)]
+    struct Meters(f64);
+
+    #[derive(Debug, Clone, Copy)]
+    struct Kilometers(f64);
+
+    impl Meters {
+        fn to_kilometers(self) -> Kilometers {
+            Kilometers(self.0 / 1000.0)
+        }
+    }
+
+    impl Kilometers {
+        fn to_meters(self) -> Meters {
+            Meters(self.0 * 1000.0)
+        }
+    }
+
+    let distance_m = Meters(5000.0);
+    let distance_km = distance_m.to_kilometers();
+
+    println!("{:?} = {:?}", distance_m, distance_km);
+
+    // 실수 방지 - 다른 타입끼리 연산 불가
+    // let wrong = distance_m.0 + distance_km.0;  // 의도적 에러 유발 가능
+
+    // ID 타입 구분
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct UserId(u64);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct OrderId(u64);
+
+    fn get_user_orders(_user_id: UserId) -> Vec<OrderId> {
+        vec![OrderId(1001), OrderId(1002)]
+    }
+
+    let user = UserId(42);
+    let _order = OrderId(1001);
+
+    // get_user_orders(_order);  // 컴파일 에러! OrderId는 UserId가 아님
+    let orders = get_user_orders(user);
+    println!("사용자 {:?}의 주문: {:?}", user, orders);
+
+    // Deref로 내부 타입 노출
+    struct Email(String);
+
+    impl Deref for Email {
+        type Target = str;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    let email = Email(String::from("user@example.com"));
+    println!("이메일 길이: {}", email.len()); // str 메서드 사용 가능
+
+    // C++와의 비교:
+    // C++: using UserId = uint64_t;  // 타입 별칭, 실제로 같은 타입
+    // Rust: struct UserId(u64);      // 완전히 다른 타입
+}
+
+fn main() {
+    newtype_pattern();
+}
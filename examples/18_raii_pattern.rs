@@ -0,0 +1,95 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 7. RAII 패턴
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait StrExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+    fn is_blank(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        // 바이트가 아닌 문자(char) 단위로 잘라야 한글이 깨지지 않음
+        if self.chars().count() <= max_chars {
+            return self.to_string();
+        }
+        let mut out: String = self.chars().take(max_chars.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+// ============================================================================
+// 7. RAII 패턴
+// ============================================================================
+
+fn raii_pattern() {
+    println!("\n--- RAII 패턴 ---");
+
+    // Resource Acquisition Is Initialization
+    // C++과 동일한 개념, Rust에서는 Drop 트레이트로 구현
+
+    struct FileHandle {
+        name: String,
+    }
+
+    impl FileHandle {
+        fn new(name: &str) -> Self {
+            println!("파일 열기: {}", name);
+            FileHandle {
+                name: name.to_string(),
+            }
+        }
+
+        fn write(&self, data: &str) {
+            println!("'{}' 쓰기: {}", self.name, data);
+        }
+    }
+
+    impl Drop for FileHandle {
+        fn drop(&mut self) {
+            println!("파일 닫기: {}", self.name);
+        }
+    }
+
+    {
+        let file = FileHandle::new("test.txt");
+        file.write("Hello, RAII!");
+        // 스코프 끝에서 자동으로 drop 호출
+    }
+    println!("스코프 종료 후");
+
+    // 뮤텍스 가드도 RAII
+    use std::sync::Mutex;
+
+    let data = Mutex::new(0);
+    {
+        let mut guard = data.lock().unwrap();
+        *guard += 1;
+        println!("락 획득, 값: {}", *guard);
+        // guard가 스코프를 벗어나면 자동으로 unlock
+    }
+    println!("락 해제됨");
+
+    // 파일 자동 닫기
+    // std::fs::File은 Drop 구현으로 자동 닫힘
+
+    // C++와의 차이:
+    // - Rust는 소유권으로 리소스 해제 시점이 더 명확
+    // - 이동 후에는 원본에서 drop 호출 안 됨
+}
+
+fn main() {
+    raii_pattern();
+}
@@ -0,0 +1,97 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 10. 스코프 가드 (defer 패턴)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// ============================================================================
+// 10. 스코프 가드 (defer 패턴)
+// ============================================================================
+
+// Go의 defer, C++의 scope_exit (Library Fundamentals TS v3)와 같은 역할
+// 클로저를 보관했다가 Drop에서 실행 - 조기 반환/패닉에도 실행됨
+struct ScopeGuard<F: FnOnce()> {
+    on_exit: Option<F>,
+}
+
+impl<F: FnOnce()> ScopeGuard<F> {
+    fn new(on_exit: F) -> Self {
+        ScopeGuard { on_exit: Some(on_exit) }
+    }
+
+    // 정상 경로에서 정리 작업을 취소 (commit/rollback 패턴)
+    fn dismiss(mut self) {
+        self.on_exit = None;
+    }
+}
+
+impl<F: FnOnce()> Drop for ScopeGuard<F> {
+    fn drop(&mut self) {
+        // FnOnce는 한 번만 호출 가능 -> Option::take로 꺼내서 호출
+        if let Some(f) = self.on_exit.take() {
+            f();
+        }
+    }
+}
+
+// defer! { ... } - 변수 이름 없이 가드를 만드는 매크로
+// _guard 대신 _ 에 바인딩하면 즉시 drop 되므로 주의!
+macro_rules! defer {
+    ($($body:tt)*) => {
+        let _guard = ScopeGuard::new(|| { $($body)* });
+    };
+}
+
+fn scope_guard_pattern() {
+    println!("\n--- 스코프 가드 (defer) ---");
+
+    {
+        defer! { println!("defer: 스코프 끝에서 실행 (2)"); }
+        println!("본문 실행 (1)");
+    }
+
+    // 여러 가드는 선언의 역순으로 실행 - 지역 변수 drop 순서와 같음
+    {
+        let _a = ScopeGuard::new(|| println!("가드 A 정리"));
+        let _b = ScopeGuard::new(|| println!("가드 B 정리"));
+        println!("A, B 생성 완료");
+    }
+
+    // 트랜잭션 롤백 - 성공하면 dismiss()로 정리 취소
+    fn transfer(balance: &std::cell::Cell<i32>, amount: i32, fail: bool) -> Result<(), String> {
+        let before = balance.get();
+        balance.set(before - amount);
+        let rollback = ScopeGuard::new(|| {
+            balance.set(before);
+            println!("롤백: 잔액 {} 복구", before);
+        });
+
+        if fail {
+            return Err(String::from("송금 실패")); // rollback 가드가 실행됨
+        }
+
+        rollback.dismiss(); // 성공 - 롤백하지 않음
+        Ok(())
+    }
+
+    let balance = std::cell::Cell::new(100);
+    println!("송금 성공: {:?}, 잔액 {}", transfer(&balance, 30, false), balance.get());
+    println!("송금 실패: {:?}, 잔액 {}", transfer(&balance, 30, true), balance.get());
+
+    // 패닉 중에도 가드 실행 (unwind 시 Drop 호출)
+    // 패닉 메시지는 stderr에 출력되지만 프로그램은 계속 실행됨
+    let result = std::panic::catch_unwind(|| {
+        let _g = ScopeGuard::new(|| println!("패닉 중에도 정리 실행"));
+        panic!("의도적 패닉");
+    });
+    println!("catch_unwind 결과 is_err: {}", result.is_err());
+
+    // 실무: scopeguard 크레이트 (defer!, guard_on_success 등)
+}
+
+fn main() {
+    scope_guard_pattern();
+}
@@ -0,0 +1,114 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 18. 실무 Rust Idiom - 3. 타입 스테이트 패턴 (Type State Pattern)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::ops::Deref;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait StrExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+    fn is_blank(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        // 바이트가 아닌 문자(char) 단위로 잘라야 한글이 깨지지 않음
+        if self.chars().count() <= max_chars {
+            return self.to_string();
+        }
+        let mut out: String = self.chars().take(max_chars.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+// ============================================================================
+// 3. 타입 스테이트 패턴 (Type State Pattern)
+// ============================================================================
+
+fn typestate_pattern() {
+    println!("\n--- 타입 스테이트 패턴 ---");
+
+    // 컴파일 타임에 상태 전이를 강제
+    // 잘못된 상태에서 메서드 호출 방지
+
+    // 상태를 나타내는 마커 타입
+    struct Draft;
+    struct Published;
+
+    struct Post<State> {
+        content: String,
+        _state: std::marker::PhantomData<State>,
+    }
+
+    // Draft 상태에서만 사용 가능한 메서드
+    impl Post<Draft> {
+        fn new(content: impl Into<String>) -> Self {
+            Post {
+                content: content.into(),
+                _state: std::marker::PhantomData,
+            }
+        }
+
+        fn edit(&mut self, new_content: impl Into<String>) {
+            self.content = new_content.into();
+        }
+
+        // 상태 전이: Draft -> Published
+        fn publish(self) -> Post<Published> {
+            println!("게시물 발행!");
+            Post {
+                content: self.content,
+                _state: std::marker::PhantomData,
+            }
+        }
+    }
+
+    // Published 상태에서만 사용 가능한 메서드
+    impl Post<Published> {
+        fn view(&self) -> &str {
+            &self.content
+        }
+
+        // 상태 전이: Published -> Draft
+        fn unpublish(self) -> Post<Draft> {
+            println!("게시물 비공개!");
+            Post {
+                content: self.content,
+                _state: std::marker::PhantomData,
+            }
+        }
+    }
+
+    // 사용
+    let mut draft = Post::<Draft>::new("초안 내용");
+    draft.edit("수정된 초안");
+
+    // draft.view();  // 컴파일 에러! Draft 상태에서는 view 없음
+
+    let published = draft.publish();
+    println!("내용: {}", published.view());
+
+    // published.edit("...");  // 컴파일 에러! Published 상태에서는 edit 없음
+
+    let _draft_again = published.unpublish();
+
+    // 실무 예: HTTP 연결 상태
+    // Connection<Disconnected> -> Connection<Connected> -> Connection<Authenticated>
+
+    // C++에서는 런타임 상태 체크가 필요:
+    // if (state == State::Draft) { edit(); }
+    // Rust는 컴파일 타임에 강제!
+}
+
+fn main() {
+    typestate_pattern();
+}
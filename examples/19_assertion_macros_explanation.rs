@@ -0,0 +1,86 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 19. 테스트 (Testing) - 단언 매크로 (Assertion Macros)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub fn divide(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        panic!("0으로 나눌 수 없음");
+    }
+    a / b
+}
+
+// ============================================================================
+// 단언 매크로 (Assertion Macros)
+// ============================================================================
+
+fn assertion_macros_explanation() {
+    println!("\n--- 단언 매크로 ---");
+
+    // #[should_panic], Result 반환 테스트 등은 하단 mod tests에 실제로 있음
+    // - test_divide_by_zero: #[should_panic(expected = "...")]
+    // - test_with_result: -> Result<(), String>
+
+    // 실제 동작 예시
+    println!("실제 단언 동작:");
+
+    // assert!
+    let value = 10;
+    assert!(value > 0);
+    println!("  assert!(10 > 0) - 통과");
+
+    // assert_eq!
+    assert_eq!(2 + 2, 4);
+    println!("  assert_eq!(2 + 2, 4) - 통과");
+
+    // assert_ne!
+    assert_ne!("hello", "world");
+    println!("  assert_ne!(\"hello\", \"world\") - 통과");
+
+    // 커스텀 단언 매크로 - 부동소수점 비교
+    // 0.1 + 0.2 == 0.3 은 false! (C++도 동일)
+    assert_approx_eq!(0.1 + 0.2, 0.3);
+    println!("  assert_approx_eq!(0.1 + 0.2, 0.3) - 통과");
+    assert_approx_eq!(divide(7, 2) as f64, 3.5, 0.5);
+    println!("  assert_approx_eq!(3.0, 3.5, 0.5) - 통과");
+}
+
+// ----------------------------------------------------------------------------
+// 커스텀 단언 매크로
+// ----------------------------------------------------------------------------
+// assert_eq!처럼 실패 시 양쪽 값과 호출 위치를 보여주는 것이 핵심
+// 매크로이므로 panic 위치가 호출한 줄로 표시됨 (함수라면 #[track_caller] 필요)
+
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr) => {
+        assert_approx_eq!($left, $right, 1e-9)
+    };
+    ($left:expr, $right:expr, $eps:expr) => {
+        match (&$left, &$right, &$eps) {
+            (left, right, eps) => {
+                // abs() 대신 비교 - f32/f64 어느 쪽으로 추론되어도 동작
+                let diff = if *left > *right { *left - *right } else { *right - *left };
+                assert!(
+                    diff <= *eps,
+                    "assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}\n  diff: {:?} > eps {:?}",
+                    left,
+                    right,
+                    diff,
+                    eps
+                );
+            }
+        }
+    };
+}
+use assert_approx_eq;
+
+fn main() {
+    assertion_macros_explanation();
+}
@@ -0,0 +1,88 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 19. 테스트 (Testing) - 픽스처: 임시 디렉터리
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// ============================================================================
+// 픽스처: 임시 디렉터리
+// ============================================================================
+// 실무에서는 tempfile::TempDir 사용 - 여기서는 표준 라이브러리만으로 구현
+// C++ gtest: SetUp()/TearDown() -> Rust: 생성자 + Drop (RAII)
+
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    // 테스트가 병렬로 실행되므로 이름이 겹치지 않게 pid + 카운터 사용
+    pub fn new(prefix: &str) -> io::Result<Self> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!(
+            "{}-{}-{}",
+            prefix,
+            std::process::id(),
+            unique
+        ));
+        fs::create_dir_all(&path)?;
+        Ok(TempDir { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn write(&self, name: &str, contents: &str) -> io::Result<PathBuf> {
+        let file = self.path.join(name);
+        fs::write(&file, contents)?;
+        Ok(file)
+    }
+}
+
+impl Drop for TempDir {
+    // 테스트가 실패(패닉)해도 정리됨
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+// 픽스처로 테스트할 함수 - 디렉터리에서 확장자가 ext인 파일의 줄 수 합계
+pub fn count_lines(dir: &Path, ext: &str) -> io::Result<usize> {
+    let mut total = 0;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == ext) {
+            total += fs::read_to_string(&path)?.lines().count();
+        }
+    }
+    Ok(total)
+}
+
+fn fixtures_demo() {
+    println!("\n--- 픽스처: 임시 디렉터리 ---");
+
+    let kept_path;
+    {
+        let dir = TempDir::new("rust-study-demo").unwrap();
+        dir.write("a.rs", "fn main() {}\n// 주석\n").unwrap();
+        dir.write("b.rs", "mod x;\n").unwrap();
+        dir.write("notes.txt", "무시됨\n").unwrap();
+
+        println!("임시 디렉터리: {}", dir.path().display());
+        println!("count_lines(.rs) = {}", count_lines(dir.path(), "rs").unwrap());
+        kept_path = dir.path().to_path_buf();
+        // 스코프 끝에서 Drop -> 디렉터리 삭제
+    }
+    println!("스코프 종료 후 존재 여부: {}", kept_path.exists());
+}
+
+fn main() {
+    fixtures_demo();
+}
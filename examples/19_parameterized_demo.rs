@@ -0,0 +1,64 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 19. 테스트 (Testing) - 파라미터화 테스트 (rstest 스타일)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+pub fn is_even(n: i32) -> bool {
+    n % 2 == 0
+}
+
+// ============================================================================
+// 파라미터화 테스트 (rstest 스타일)
+// ============================================================================
+// rstest 크레이트:
+// #[rstest]
+// #[case(0, true)]
+// #[case(1, false)]
+// fn test_is_even(#[case] input: i32, #[case] expected: bool) { ... }
+//
+// 여기서는 macro_rules!로 같은 효과 - 케이스마다 별도의 #[test] 함수 생성
+// 반복문 테스트와 달리 실패한 케이스 이름이 테스트 결과에 그대로 표시됨
+// 입력을 고르지 않고 생성하려면 42장 (proptest - 같은 add, is_even 의 성질을 검사)
+
+#[cfg(test)]
+macro_rules! parameterized {
+    ($func:ident { $($case:ident: ($($arg:expr),*) => $expected:expr),+ $(,)? }) => {
+        mod $func {
+            use super::*;
+            $(
+                #[test]
+                fn $case() {
+                    assert_eq!($func($($arg),*), $expected);
+                }
+            )+
+        }
+    };
+}
+
+fn parameterized_demo() {
+    println!("\n--- 파라미터화 테스트 ---");
+
+    // 하단 mod parameterized_tests에서 케이스별 테스트로 생성되는 표
+    let cases = [(0, true), (1, false), (-2, true), (7, false)];
+    for (input, expected) in cases {
+        let ok = is_even(input) == expected;
+        println!("  is_even({:>2}) == {:<5} ... {}", input, expected, if ok { "ok" } else { "FAILED" });
+    }
+    println!("cargo test is_even:: 로 실행하면 케이스별 이름으로 결과 표시:");
+    println!("  test _19_testing::parameterized_tests::is_even::negative ... ok");
+}
+
+fn main() {
+    parameterized_demo();
+}
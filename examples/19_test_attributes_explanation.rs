@@ -0,0 +1,68 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 19. 테스트 (Testing) - 테스트 어트리뷰트
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub fn divide(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        panic!("0으로 나눌 수 없음");
+    }
+    a / b
+}
+
+// ============================================================================
+// 테스트 어트리뷰트
+// ============================================================================
+
+fn test_attributes_explanation() {
+    println!("\n--- 테스트 어트리뷰트 ---");
+
+    println!(r#"
+// 기본 테스트
+#[test]
+fn basic_test() {{ }}
+
+// 무시할 테스트
+#[test]
+#[ignore]
+fn slow_test() {{
+    // 시간이 오래 걸리는 테스트
+}}
+
+// 무시된 테스트 실행: cargo test -- --ignored
+// 모든 테스트 실행: cargo test -- --include-ignored
+
+// 패닉 예상
+#[test]
+#[should_panic]
+fn panics() {{ panic!(); }}
+
+// 특정 패닉 메시지 확인
+#[test]
+#[should_panic(expected = "0으로 나눌 수 없음")]
+fn panics_with_message() {{
+    divide(1, 0);
+}}
+
+// 조건부 컴파일
+#[test]
+#[cfg(target_os = "linux")]
+fn linux_only_test() {{ }}
+
+// 타임아웃 (nightly 기능)
+// #[test]
+// #[timeout(1000)]  // 1초
+// fn must_finish_quickly() {{ }}
+"#);
+}
+
+fn main() {
+    test_attributes_explanation();
+}
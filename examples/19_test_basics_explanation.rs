@@ -0,0 +1,58 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 19. 테스트 (Testing) - 테스트 기본 구조
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+pub fn subtract(a: i32, b: i32) -> i32 {
+    a - b
+}
+
+pub fn divide(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        panic!("0으로 나눌 수 없음");
+    }
+    a / b
+}
+
+pub fn is_even(n: i32) -> bool {
+    n % 2 == 0
+}
+
+// ============================================================================
+// 테스트 기본 구조
+// ============================================================================
+
+fn test_basics_explanation() {
+    println!("--- 테스트 기본 구조 ---");
+
+    // 이 파일 하단의 `mod tests`가 실제로 컴파일되고 실행되는 테스트
+    // #[cfg(test)] - cargo test 때만 컴파일 (C++: 별도 테스트 타깃 + gtest)
+    // use super::*;  - 부모 모듈의 private 함수까지 테스트 가능
+    println!("테스트 대상 함수 (아래 mod tests에서 검증):");
+    println!("  add(2, 3) = {}", add(2, 3));
+    println!("  subtract(5, 3) = {}", subtract(5, 3));
+    println!("  divide(7, 2) = {}", divide(7, 2));
+    println!("  is_even(4) = {}", is_even(4));
+    println!();
+
+    println!("실행 방법:");
+    println!("  cargo test              # 모든 테스트 실행");
+    println!("  cargo test test_name    # 특정 테스트만 실행");
+    println!("  cargo test --lib        # 라이브러리 테스트만");
+    println!("  cargo test --doc        # 문서 테스트만");
+}
+
+fn main() {
+    test_basics_explanation();
+}
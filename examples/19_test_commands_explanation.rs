@@ -0,0 +1,98 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 19. 테스트 (Testing) - cargo test 명령어
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// ============================================================================
+// cargo test 명령어
+// ============================================================================
+
+fn test_commands_explanation() {
+    println!("\n--- cargo test 명령어 ---");
+
+    println!(r#"
+=== 기본 명령어 ===
+cargo test                    # 모든 테스트 실행
+cargo test --release          # 릴리즈 모드로 테스트
+cargo test --no-fail-fast     # 실패해도 계속 실행
+
+=== 필터링 ===
+cargo test test_name          # 이름에 'test_name' 포함된 테스트
+cargo test tests::            # 'tests::' 모듈의 테스트
+cargo test --test integration # tests/integration.rs만 실행
+
+=== 테스트 종류 선택 ===
+cargo test --lib              # 단위 테스트만
+cargo test --doc              # 문서 테스트만
+cargo test --bins             # 바이너리 테스트만
+cargo test --examples         # 예제 테스트만
+
+=== 출력 제어 ===
+cargo test -- --nocapture     # println! 출력 보기
+cargo test -- --show-output   # 성공한 테스트 출력도 보기
+cargo test -- --test-threads=1  # 단일 스레드로 실행
+
+=== 무시된 테스트 ===
+cargo test -- --ignored       # #[ignore] 테스트만 실행
+cargo test -- --include-ignored  # 모든 테스트 (무시된 것 포함)
+
+=== 테스트 목록 ===
+cargo test -- --list          # 테스트 목록만 출력
+cargo test -- --list --ignored  # 무시된 테스트 목록
+"#);
+
+    println!("=== 예시 출력 ===");
+    println!(r#"
+$ cargo test
+
+running 3 tests
+test tests::test_addition ... ok
+test tests::test_subtraction ... ok
+test tests::test_multiplication ... ok
+
+test result: ok. 3 passed; 0 failed; 0 ignored
+
+   Doc-tests my_project
+
+running 2 tests
+test src/lib.rs - add (line 5) ... ok
+test src/lib.rs - subtract (line 15) ... ok
+
+test result: ok. 2 passed; 0 failed; 0 ignored
+"#);
+}
+
+// ============================================================================
+// 실제 테스트 예제 (이 파일 내에서)
+// ============================================================================
+
+// 테스트할 함수들
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+pub fn subtract(a: i32, b: i32) -> i32 {
+    a - b
+}
+
+pub fn divide(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        panic!("0으로 나눌 수 없음");
+    }
+    a / b
+}
+
+pub fn is_even(n: i32) -> bool {
+    n % 2 == 0
+}
+
+// 테스트 모듈
+
+fn main() {
+    test_commands_explanation();
+}
@@ -0,0 +1,95 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 19. 테스트 (Testing) - 테스트 구성
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+// ============================================================================
+// 테스트 구성
+// ============================================================================
+
+fn test_organization_explanation() {
+    println!("\n--- 테스트 구성 ---");
+
+    println!(r#"
+프로젝트 구조:
+my_project/
+├── Cargo.toml
+├── src/
+│   ├── lib.rs          # 라이브러리 루트
+│   ├── main.rs         # 바이너리 (있는 경우)
+│   └── utils.rs        # 모듈
+└── tests/              # 통합 테스트
+    ├── integration_test.rs
+    └── common/
+        └── mod.rs      # 테스트 헬퍼
+
+=== 1. 단위 테스트 (Unit Tests) ===
+- 같은 파일 내 #[cfg(test)] 모듈에 작성
+- private 함수도 테스트 가능
+- cargo test --lib로 실행
+
+// src/lib.rs
+pub fn public_fn() -> i32 {{ 42 }}
+fn private_fn() -> i32 {{ 100 }}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn test_public() {{
+        assert_eq!(public_fn(), 42);
+    }}
+
+    #[test]
+    fn test_private() {{
+        // private 함수도 테스트 가능!
+        assert_eq!(private_fn(), 100);
+    }}
+}}
+
+=== 2. 통합 테스트 (Integration Tests) ===
+- tests/ 디렉터리에 별도 파일로 작성
+- public API만 테스트 가능
+- 각 파일이 별도 크레이트로 컴파일
+
+// tests/integration_test.rs
+use my_project::public_fn;
+
+#[test]
+fn test_from_outside() {{
+    assert_eq!(public_fn(), 42);
+}}
+
+=== 3. 문서 테스트 (Doc Tests) ===
+- 문서 주석 내 코드 블록 자동 테스트
+- 문서와 코드 동기화 보장
+
+/// 두 수를 더합니다.
+///
+/// # Examples
+///
+/// ```
+/// let result = my_crate::add(2, 3);
+/// assert_eq!(result, 5);
+/// ```
+pub fn add(a: i32, b: i32) -> i32 {{
+    a + b
+}}
+"#);
+}
+
+fn main() {
+    test_organization_explanation();
+}
@@ -0,0 +1,287 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 20. 비동기 채팅 서버 (캡스톤) - 연결 처리
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, Lines, ReadHalf, WriteHalf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinSet;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug)]
+pub enum ChatError {
+    Io(io::Error),
+    NameTaken(String),
+    InvalidName(String),
+}
+
+impl fmt::Display for ChatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChatError::Io(e) => write!(f, "입출력 에러: {}", e),
+            ChatError::NameTaken(name) => write!(f, "이미 사용 중인 이름: {}", name),
+            ChatError::InvalidName(name) => {
+                write!(f, "잘못된 이름: '{}' (공백 없이 1~16자)", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ChatError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ChatError {
+    fn from(e: io::Error) -> Self {
+        ChatError::Io(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Joined(String),
+    Left(String),
+    Message { from: String, text: String },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Joined(name) => write!(f, "*** {} 님이 입장했습니다", name),
+            Event::Left(name) => write!(f, "*** {} 님이 나갔습니다", name),
+            Event::Message { from, text } => write!(f, "[{}] {}", from, text),
+        }
+    }
+}
+
+const CHANNEL_CAPACITY: usize = 64;
+
+pub struct Hub {
+    users: Mutex<HashSet<String>>,
+    events: broadcast::Sender<Event>,
+}
+
+impl Hub {
+    pub fn new() -> Arc<Hub> {
+        let (events, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Arc::new(Hub {
+            users: Mutex::new(HashSet::new()),
+            events,
+        })
+    }
+
+    // 이름을 등록하고 사건 구독 - 입장 알림보다 먼저 구독해서 자기 입장도 받음
+    pub fn join(&self, name: &str) -> Result<broadcast::Receiver<Event>, ChatError> {
+        if name.is_empty() || name.chars().count() > 16 || name.contains(char::is_whitespace) {
+            return Err(ChatError::InvalidName(name.to_string()));
+        }
+        // 잠금은 이 블록 안에서만 - .await 를 건너 잠금을 쥐고 있지 않도록
+        {
+            let mut users = self.lock_users();
+            if !users.insert(name.to_string()) {
+                return Err(ChatError::NameTaken(name.to_string()));
+            }
+        }
+        let rx = self.events.subscribe();
+        self.publish(Event::Joined(name.to_string()));
+        Ok(rx)
+    }
+
+    pub fn leave(&self, name: &str) {
+        if self.lock_users().remove(name) {
+            self.publish(Event::Left(name.to_string()));
+        }
+    }
+
+    pub fn say(&self, from: &str, text: &str) {
+        self.publish(Event::Message {
+            from: from.to_string(),
+            text: text.to_string(),
+        });
+    }
+
+    // unwrap: 잠금을 쥔 태스크가 패닉했을 때(poison)만 실패 - 이름 목록은 이미 믿을 수 없으니 같이 패닉
+    fn lock_users(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        self.users.lock().unwrap()
+    }
+
+    // 받는 쪽이 없으면 send 가 Err - 방송에서는 문제가 아님
+    fn publish(&self, event: Event) {
+        let _ = self.events.send(event);
+    }
+
+    pub fn users(&self) -> Vec<String> {
+        let mut users: Vec<String> = self.lock_users().iter().cloned().collect();
+        users.sort();
+        users
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 연결 처리
+// ----------------------------------------------------------------------------
+// 연결 하나 = 태스크 하나, 두 가지 입력을 tokio::select! 로 동시에 기다림
+//   - 클라이언트가 보낸 줄 → 방송
+//   - 방송 채널의 사건 → 클라이언트에게 쓰기
+// C++ (Asio): async_read_until 과 async_write 를 번갈아 거는 콜백 체인, 또는 co_spawn 두 개
+
+// 종료 신호를 받았는지 - 보내는 쪽이 사라져도 종료로 봄
+async fn shutdown_requested(shutdown: &mut watch::Receiver<bool>) {
+    while !*shutdown.borrow_and_update() {
+        if shutdown.changed().await.is_err() {
+            return;
+        }
+    }
+}
+
+// S 는 TcpStream 이든 메모리 안의 DuplexStream 이든 상관없음
+pub async fn handle_connection<S>(
+    stream: S,
+    hub: Arc<Hub>,
+    mut shutdown: watch::Receiver<bool>,
+) -> Result<(), ChatError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut lines = BufReader::new(reader).lines();
+
+    // 1단계: 이름 받기 - 쓸 수 있는 이름이 나올 때까지 다시 물음
+    let (name, mut events) = loop {
+        writer.write_all("이름을 입력하세요:\n".as_bytes()).await?;
+        let line = tokio::select! {
+            line = lines.next_line() => line?,
+            _ = shutdown_requested(&mut shutdown) => return Ok(()),
+        };
+        // 이름을 말하기 전에 끊긴 연결
+        let Some(line) = line else {
+            return Ok(());
+        };
+        match hub.join(line.trim()) {
+            Ok(events) => break (line.trim().to_string(), events),
+            Err(e) => writer.write_all(format!("{}\n", e).as_bytes()).await?,
+        }
+    };
+
+    // 2단계: 대화 - 어떤 이유로 끝나든 leave 는 반드시 (에러도 먼저 결과로 받아 둠)
+    let result = chat(&name, &hub, &mut lines, &mut writer, &mut events, &mut shutdown).await;
+    hub.leave(&name);
+    result
+}
+
+async fn chat<R, W>(
+    name: &str,
+    hub: &Hub,
+    lines: &mut Lines<BufReader<R>>,
+    writer: &mut W,
+    events: &mut broadcast::Receiver<Event>,
+    shutdown: &mut watch::Receiver<bool>,
+) -> Result<(), ChatError>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    loop {
+        tokio::select! {
+            line = lines.next_line() => match line?.as_deref().map(str::trim) {
+                // 연결이 끊기거나 /quit
+                None => return Ok(()),
+                Some("/quit") => {
+                    writer.write_all("안녕히 가세요\n".as_bytes()).await?;
+                    return Ok(());
+                }
+                Some("/who") => {
+                    let users = hub.users().join(", ");
+                    writer.write_all(format!("접속자: {}\n", users).as_bytes()).await?;
+                }
+                Some("") => {}
+                Some(text) => hub.say(name, text),
+            },
+            event = events.recv() => match event {
+                Ok(event) => writer.write_all(format!("{}\n", event).as_bytes()).await?,
+                // 너무 느린 연결 - 놓친 개수만 알리고 계속
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    writer.write_all(format!("*** 메시지 {}개를 놓쳤습니다\n", n).as_bytes()).await?
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+            _ = shutdown_requested(shutdown) => {
+                writer.write_all("*** 서버를 종료합니다\n".as_bytes()).await?;
+                return Ok(());
+            }
+        }
+    }
+}
+
+// 예제와 테스트에서 쓰는 줄 단위 클라이언트
+pub struct Client<S> {
+    lines: Lines<BufReader<ReadHalf<S>>>,
+    writer: WriteHalf<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite> Client<S> {
+    pub fn new(stream: S) -> Self {
+        let (reader, writer) = tokio::io::split(stream);
+        Client {
+            lines: BufReader::new(reader).lines(),
+            writer,
+        }
+    }
+
+    pub async fn send(&mut self, line: &str) -> io::Result<()> {
+        self.writer.write_all(format!("{}\n", line).as_bytes()).await
+    }
+
+    // 서버가 끊었거나 2초 안에 아무것도 오지 않으면 None
+    pub async fn recv(&mut self) -> Option<String> {
+        match tokio::time::timeout(Duration::from_secs(2), self.lines.next_line()).await {
+            Ok(Ok(line)) => line,
+            _ => None,
+        }
+    }
+}
+
+async fn connection_handling() {
+    println!("\n--- 연결 처리 ---");
+
+    let hub = Hub::new();
+    let (_stop, shutdown) = watch::channel(false);
+
+    // 실제 소켓 대신 메모리 안의 양방향 연결 - 한쪽은 서버 태스크, 다른 쪽은 클라이언트
+    let (client_side, server_side) = tokio::io::duplex(1024);
+    let server = tokio::spawn(handle_connection(server_side, Arc::clone(&hub), shutdown));
+    let mut client = Client::new(client_side);
+
+    println!("서버: {:?}", client.recv().await);
+    client.send("이름 에 공백").await.unwrap();
+    println!("서버: {:?}", client.recv().await);
+    println!("서버: {:?}", client.recv().await);
+    client.send("alice").await.unwrap();
+    println!("서버: {:?}", client.recv().await);
+    client.send("/who").await.unwrap();
+    println!("서버: {:?}", client.recv().await);
+    client.send("/quit").await.unwrap();
+    println!("서버: {:?}", client.recv().await);
+
+    // 태스크의 결과 - JoinError(패닉) 안에 ChatError
+    println!("연결 태스크 결과: {:?}", server.await.unwrap().map_err(|e| e.to_string()));
+    println!("남은 접속자: {:?}", hub.users());
+}
+
+#[tokio::main]
+async fn main() {
+    connection_handling().await;
+}
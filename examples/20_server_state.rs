@@ -0,0 +1,169 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 20. 비동기 채팅 서버 (캡스톤) - 서버 상태와 에러 타입
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, Lines, ReadHalf, WriteHalf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinSet;
+
+// ----------------------------------------------------------------------------
+// 서버 상태와 에러 타입
+// ----------------------------------------------------------------------------
+
+// 9장의 관례: 모듈의 에러를 열거형 하나로 모으고 Display + Error + From 구현
+#[derive(Debug)]
+pub enum ChatError {
+    Io(io::Error),
+    NameTaken(String),
+    InvalidName(String),
+}
+
+impl fmt::Display for ChatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChatError::Io(e) => write!(f, "입출력 에러: {}", e),
+            ChatError::NameTaken(name) => write!(f, "이미 사용 중인 이름: {}", name),
+            ChatError::InvalidName(name) => {
+                write!(f, "잘못된 이름: '{}' (공백 없이 1~16자)", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ChatError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// ? 가 io::Error 를 ChatError 로 자동 변환
+impl From<io::Error> for ChatError {
+    fn from(e: io::Error) -> Self {
+        ChatError::Io(e)
+    }
+}
+
+// 모든 연결이 받는 사건 - broadcast 채널로 보내므로 Clone
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Joined(String),
+    Left(String),
+    Message { from: String, text: String },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Joined(name) => write!(f, "*** {} 님이 입장했습니다", name),
+            Event::Left(name) => write!(f, "*** {} 님이 나갔습니다", name),
+            Event::Message { from, text } => write!(f, "[{}] {}", from, text),
+        }
+    }
+}
+
+// 방송 채널이 담아 두는 메시지 수 - 이보다 뒤처진 연결은 놓친 개수를 안내받음
+const CHANNEL_CAPACITY: usize = 64;
+
+// 모든 연결이 Arc 로 공유하는 서버 상태
+// C++: std::shared_ptr<Room> + std::mutex 로 감싼 std::set<std::string>
+pub struct Hub {
+    users: Mutex<HashSet<String>>,
+    events: broadcast::Sender<Event>,
+}
+
+impl Hub {
+    pub fn new() -> Arc<Hub> {
+        let (events, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Arc::new(Hub {
+            users: Mutex::new(HashSet::new()),
+            events,
+        })
+    }
+
+    // 이름을 등록하고 사건 구독 - 입장 알림보다 먼저 구독해서 자기 입장도 받음
+    pub fn join(&self, name: &str) -> Result<broadcast::Receiver<Event>, ChatError> {
+        if name.is_empty() || name.chars().count() > 16 || name.contains(char::is_whitespace) {
+            return Err(ChatError::InvalidName(name.to_string()));
+        }
+        // 잠금은 이 블록 안에서만 - .await 를 건너 잠금을 쥐고 있지 않도록
+        {
+            let mut users = self.lock_users();
+            if !users.insert(name.to_string()) {
+                return Err(ChatError::NameTaken(name.to_string()));
+            }
+        }
+        let rx = self.events.subscribe();
+        self.publish(Event::Joined(name.to_string()));
+        Ok(rx)
+    }
+
+    pub fn leave(&self, name: &str) {
+        if self.lock_users().remove(name) {
+            self.publish(Event::Left(name.to_string()));
+        }
+    }
+
+    pub fn say(&self, from: &str, text: &str) {
+        self.publish(Event::Message {
+            from: from.to_string(),
+            text: text.to_string(),
+        });
+    }
+
+    // unwrap: 잠금을 쥔 태스크가 패닉했을 때(poison)만 실패 - 이름 목록은 이미 믿을 수 없으니 같이 패닉
+    fn lock_users(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        self.users.lock().unwrap()
+    }
+
+    // 받는 쪽이 없으면 send 가 Err - 방송에서는 문제가 아님
+    fn publish(&self, event: Event) {
+        let _ = self.events.send(event);
+    }
+
+    pub fn users(&self) -> Vec<String> {
+        let mut users: Vec<String> = self.lock_users().iter().cloned().collect();
+        users.sort();
+        users
+    }
+}
+
+async fn server_state() {
+    println!("--- 서버 상태와 에러 타입 ---");
+
+    let hub = Hub::new();
+    // 빈 서버에 처음 들어오는 이름이므로 unwrap 해도 실패하지 않음
+    let mut alice = hub.join("alice").unwrap();
+    let _bob = hub.join("bob").unwrap();
+    println!("접속자: {:?}", hub.users());
+
+    // 실패는 타입으로 - 호출하는 쪽이 match 로 처리
+    for name in ["alice", "이름 에 공백", ""] {
+        match hub.join(name) {
+            Ok(_) => println!("'{}' 입장", name),
+            Err(e) => println!("거절: {}", e),
+        }
+    }
+
+    hub.say("bob", "안녕하세요");
+    hub.leave("bob");
+    // alice 는 자기 입장부터 모든 사건을 순서대로 받음
+    while let Ok(event) = alice.try_recv() {
+        println!("alice 가 받은 사건: {}", event);
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    server_state().await;
+}
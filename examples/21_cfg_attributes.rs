@@ -0,0 +1,99 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 21. 크로스 플랫폼 코드 (cfg) - cfg 속성과 cfg! 매크로
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// ----------------------------------------------------------------------------
+// cfg 속성과 cfg! 매크로
+// ----------------------------------------------------------------------------
+
+// 같은 이름의 함수를 운영체제마다 하나씩 - 컴파일되는 것은 항상 하나
+#[cfg(target_os = "linux")]
+fn os_greeting() -> &'static str {
+    "리눅스용으로 컴파일됨"
+}
+
+#[cfg(target_os = "macos")]
+fn os_greeting() -> &'static str {
+    "macOS 용으로 컴파일됨"
+}
+
+#[cfg(windows)]
+fn os_greeting() -> &'static str {
+    "Windows 용으로 컴파일됨"
+}
+
+// 위 셋에 해당하지 않는 모든 대상 - 빠뜨리면 그 대상에서만 "함수 없음" 컴파일 에러
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn os_greeting() -> &'static str {
+    "그 밖의 운영체제용으로 컴파일됨"
+}
+
+fn cfg_attributes() {
+    println!("--- cfg 속성과 cfg! 매크로 ---");
+
+    // C++ 에서는:
+    // #if defined(__linux__)
+    //     const char* greeting() { return "linux"; }
+    // #elif defined(_WIN32)
+    //     const char* greeting() { return "windows"; }
+    // #endif
+    // → 꺼진 쪽은 파싱조차 안 되므로 다른 OS 에서만 컴파일 에러가 드러남
+
+    println!("{}", os_greeting());
+
+    // 자주 쓰는 조건
+    //   target_os = "linux" | "macos" | "windows" | "android" | "ios" ...
+    //   unix, windows             = target_family 의 줄임
+    //   target_arch = "x86_64" | "aarch64" | "wasm32"
+    //   target_pointer_width = "64", target_endian = "little"
+    //   any(..), all(..), not(..) 로 조합
+
+    // cfg! 는 컴파일 시점에 정해진 bool - 양쪽 코드가 모두 컴파일되어야 함
+    let family = if cfg!(unix) {
+        "유닉스 계열"
+    } else if cfg!(windows) {
+        "Windows"
+    } else {
+        "기타"
+    };
+    println!("운영체제 계열: {}", family);
+    println!("64비트 포인터: {}", cfg!(target_pointer_width = "64"));
+
+    // 실행 중인 프로그램의 대상 정보 - 문자열이 필요할 때 (로그, 진단 출력)
+    println!(
+        "env::consts: OS={}, FAMILY={}, ARCH={}",
+        env::consts::OS,
+        env::consts::FAMILY,
+        env::consts::ARCH
+    );
+
+    // 블록, 문장, 구조체 필드에도 붙일 수 있음
+    #[cfg(debug_assertions)]
+    println!("디버그 빌드 (cargo build --release 에서는 이 줄이 사라짐)");
+
+    // cfg_attr: 조건이 맞을 때만 속성을 붙임 - 여기서는 유닉스에서만 Debug 구현
+    #[cfg_attr(unix, derive(Debug))]
+    struct Permissions {
+        #[cfg(unix)]
+        mode: u32, // 유닉스에만 있는 필드
+        read_only: bool,
+    }
+    let p = Permissions {
+        #[cfg(unix)]
+        mode: 0o644,
+        read_only: false,
+    };
+    #[cfg(unix)]
+    println!("{:?} - 권한 비트 {:o}", p, p.mode);
+    println!("읽기 전용: {}", p.read_only);
+}
+
+fn main() {
+    cfg_attributes();
+}
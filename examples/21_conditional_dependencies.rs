@@ -0,0 +1,56 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 21. 크로스 플랫폼 코드 (cfg) - 조건부 의존성
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// ----------------------------------------------------------------------------
+// 조건부 의존성
+// ----------------------------------------------------------------------------
+// Cargo.toml - 대상 조건이 맞을 때만 내려받고 링크 (이 크레이트의 실제 설정)
+//
+//   [target.'cfg(unix)'.dependencies]
+//   libc = "0.2"
+//
+//   [target.'cfg(windows)'.dependencies]
+//   windows-sys = { version = "0.61", features = ["Win32_System_Console"] }
+//
+//   [target.'cfg(not(target_arch = "wasm32"))'.dependencies]
+//   tokio = { version = "1", features = ["full"] }
+//
+// 코드에서 그 크레이트를 쓰는 곳도 같은 조건으로 감싸야 함
+// → 리눅스에서 cargo build 가 통과해도 windows 쪽 코드는 검사되지 않음
+//   cargo check --target x86_64-pc-windows-msvc 로 다른 대상도 확인 (rustup target add 필요)
+//
+// C++ (CMake):
+//   if(WIN32)
+//       target_link_libraries(app PRIVATE kernel32)
+//   endif()
+//
+// 선택 기능(feature)도 같은 방식: [features] json = ["dep:serde_json"] + #[cfg(feature = "json")]
+
+fn conditional_dependencies() {
+    println!("\n--- 조건부 의존성 ---");
+
+    // 이 빌드에 들어간 터미널 구현 - 조건마다 다른 의존성
+    let backend = if cfg!(unix) {
+        "libc::ioctl(TIOCGWINSZ)"
+    } else if cfg!(windows) {
+        "windows-sys GetConsoleScreenBufferInfo"
+    } else {
+        "없음 (환경 변수만)"
+    };
+    println!("터미널 크기를 알아내는 방법: {}", backend);
+
+    // cfg 조건은 컴파일 시점 - 실행 중에 바꿀 수 없음
+    // 실행 중에 고르려면 일반 if + 런타임 정보 (env::consts::OS, 환경 변수)
+    println!("빌드 대상이 wasm32: {}", cfg!(target_arch = "wasm32"));
+}
+
+fn main() {
+    conditional_dependencies();
+}
@@ -0,0 +1,107 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 21. 크로스 플랫폼 코드 (cfg) - 플랫폼 추상화 계층
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// ----------------------------------------------------------------------------
+// 플랫폼 추상화 계층
+// ----------------------------------------------------------------------------
+// 패턴: 공개 API 는 한 곳, 운영체제별 구현은 #[cfg] 로 고른 imp 모듈
+//   - 호출하는 쪽에는 cfg 가 하나도 없음
+//   - 새 운영체제를 지원하려면 imp 모듈 하나만 추가
+//   - 마지막 not(any(..)) 대체 구현으로 "지원하지 않는 대상"에서도 컴파일됨
+// C++: platform/linux.cpp, platform/win32.cpp 를 빌드 시스템이 골라 컴파일하는 방식과 같음
+// 이 크레이트의 src/terminal.rs (doctor, render --format tui 가 사용) 가 같은 구조
+
+pub mod platform {
+    use std::path::PathBuf;
+
+    // 공통 API - 설정 파일 위치 (각 운영체제의 관례)
+    pub fn config_dir() -> Option<PathBuf> {
+        imp::config_dir().map(|dir| dir.join("rust-study"))
+    }
+
+    pub fn line_ending() -> &'static str {
+        imp::LINE_ENDING
+    }
+
+    pub fn name() -> &'static str {
+        imp::NAME
+    }
+
+    #[cfg(target_os = "macos")]
+    mod imp {
+        use std::env;
+        use std::path::PathBuf;
+
+        pub const NAME: &str = "macOS";
+        pub const LINE_ENDING: &str = "\n";
+
+        pub fn config_dir() -> Option<PathBuf> {
+            env::var_os("HOME").map(|h| PathBuf::from(h).join("Library/Application Support"))
+        }
+    }
+
+    // macOS 를 뺀 유닉스 - XDG 규칙
+    #[cfg(all(unix, not(target_os = "macos")))]
+    mod imp {
+        use std::env;
+        use std::path::PathBuf;
+
+        pub const NAME: &str = "유닉스";
+        pub const LINE_ENDING: &str = "\n";
+
+        pub fn config_dir() -> Option<PathBuf> {
+            env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+        }
+    }
+
+    #[cfg(windows)]
+    mod imp {
+        use std::env;
+        use std::path::PathBuf;
+
+        pub const NAME: &str = "Windows";
+        pub const LINE_ENDING: &str = "\r\n";
+
+        pub fn config_dir() -> Option<PathBuf> {
+            env::var_os("APPDATA").map(PathBuf::from)
+        }
+    }
+
+    // 그 밖의 대상(wasm32 등) - 설정 디렉터리 없음
+    #[cfg(not(any(unix, windows)))]
+    mod imp {
+        use std::path::PathBuf;
+
+        pub const NAME: &str = "기타";
+        pub const LINE_ENDING: &str = "\n";
+
+        pub fn config_dir() -> Option<PathBuf> {
+            None
+        }
+    }
+}
+
+fn platform_abstraction() {
+    println!("\n--- 플랫폼 추상화 계층 ---");
+
+    // 호출하는 쪽은 운영체제를 모름
+    println!("구현: {}", platform::name());
+    match platform::config_dir() {
+        Some(dir) => println!("설정 디렉터리: .../{}", dir.file_name().unwrap_or_default().to_string_lossy()),
+        None => println!("설정 디렉터리: 없음"),
+    }
+    println!("줄 끝: {:?}", platform::line_ending());
+}
+
+fn main() {
+    platform_abstraction();
+}
@@ -0,0 +1,61 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 21. 크로스 플랫폼 코드 (cfg) - 운영체제별 경로
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// ----------------------------------------------------------------------------
+// 운영체제별 경로
+// ----------------------------------------------------------------------------
+
+fn platform_paths() {
+    println!("\n--- 운영체제별 경로 ---");
+
+    // C++ 에서는:
+    // std::filesystem::path p = "data";
+    // p /= "lessons.toml";              // 구분자는 path::preferred_separator
+    // → Rust 의 Path/PathBuf 와 거의 같음, 다만 문자열 → 경로 변환의 인코딩 문제가 남음
+
+    // 구분자를 직접 쓰지 말고 join - 운영체제에 맞는 구분자로 이어 붙임
+    let path = Path::new("content").join("prose").join("04_lifetimes.md");
+    println!("join 결과: {}", path.display());
+    println!("구분자: {:?}", std::path::MAIN_SEPARATOR);
+
+    // 구성 요소 단위로 다루면 구분자를 신경 쓸 일이 없음
+    let parts: Vec<_> = path.iter().map(|p| p.to_string_lossy()).collect();
+    println!("구성 요소: {:?}", parts);
+    println!("확장자: {:?}, 파일 이름: {:?}", path.extension(), path.file_stem());
+
+    // 같은 문자열도 운영체제마다 다르게 해석됨
+    let windows_style = Path::new(r"C:\Users\study\notes.txt");
+    println!(
+        "r\"C:\\Users\\study\\notes.txt\" 는 절대 경로? {} (Windows 에서만 true)",
+        windows_style.is_absolute()
+    );
+
+    // 홈 디렉터리 - 환경 변수 이름부터 다름
+    let home_var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    let home = env::var_os(home_var).map(PathBuf::from);
+    println!("홈 디렉터리 ({}): {}", home_var, if home.is_some() { "있음" } else { "없음" });
+
+    // PATH 같은 목록 변수의 구분자도 ':' 와 ';' 로 다름 - split_paths 가 처리
+    let dirs = env::var_os("PATH").map(|p| env::split_paths(&p).count()).unwrap_or(0);
+    println!("PATH 의 디렉터리 수: {}", dirs);
+
+    // 파일 이름은 UTF-8 이 아닐 수 있음 (유닉스: 임의의 바이트, Windows: UTF-16)
+    // → &str 이 아니라 &OsStr, to_str() 은 Option
+    let name = path.file_name().unwrap_or_default();
+    println!("파일 이름을 &str 로: {:?}", name.to_str());
+
+    // 줄 끝도 다름 - 파일을 쓸 때 "\n" 만 쓰고, 읽을 때는 lines() 가 "\r\n" 도 처리
+    let text = "첫 줄\r\n둘째 줄\n";
+    println!("lines(): {:?}", text.lines().collect::<Vec<_>>());
+}
+
+fn main() {
+    platform_paths();
+}
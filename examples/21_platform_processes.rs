@@ -0,0 +1,67 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 21. 크로스 플랫폼 코드 (cfg) - 운영체제별 프로세스
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// ----------------------------------------------------------------------------
+// 운영체제별 프로세스
+// ----------------------------------------------------------------------------
+
+// 셸 명령 한 줄 - 셸 자체가 운영체제마다 다름
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut c = Command::new("cmd");
+        c.args(["/C", command]);
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.args(["-c", command]);
+        c
+    }
+}
+
+fn platform_processes() {
+    println!("\n--- 운영체제별 프로세스 ---");
+
+    // C++ 에서는:
+    // #ifdef _WIN32
+    //     CreateProcessW(...);   // 명령줄 한 문자열
+    // #else
+    //     fork(); execvp(...);   // 인자 배열
+    // #endif
+    // Rust 의 std::process::Command 는 양쪽을 감싼 공통 API
+    // (출력 잡기, 파이프, 시간 제한, 환경 변수는 39장)
+
+    // 셸 명령이 꼭 필요할 때만 - 가능하면 프로그램을 직접 실행 (인자 이스케이프 문제 없음)
+    match shell("echo 안녕").output() {
+        Ok(out) => println!("셸 출력: {}", String::from_utf8_lossy(&out.stdout).trim()),
+        Err(e) => println!("셸을 실행할 수 없음: {}", e),
+    }
+
+    // 실행 파일 확장자 - Windows 는 ".exe"
+    println!("실행 파일 이름: rust-study{}", env::consts::EXE_SUFFIX);
+
+    // 종료 상태: 코드는 공통, 시그널은 유닉스에만
+    let status = shell("exit 3").status();
+    match status {
+        Ok(status) => {
+            println!("종료 코드: {:?}", status.code());
+            // 운영체제 전용 기능은 std::os::<os> 의 확장 트레이트로 - 가져와야만 보임
+            #[cfg(unix)]
+            {
+                use std::os::unix::process::ExitStatusExt;
+                println!("시그널로 종료: {:?} (유닉스 전용 ExitStatusExt)", status.signal());
+            }
+        }
+        Err(e) => println!("실행 실패: {}", e),
+    }
+}
+
+fn main() {
+    platform_processes();
+}
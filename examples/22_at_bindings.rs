@@ -0,0 +1,58 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 22. 고급 패턴 매칭 - @ 바인딩
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// @ 바인딩
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Request {
+    method: &'static str,
+    status: u16,
+    retries: u32,
+}
+
+fn at_bindings() {
+    println!("\n--- @ 바인딩 ---");
+
+    // C++ 에서는:
+    // auto [method, status, retries] = req;   // 분해만 - 조건은 if 로 따로
+    // if (status >= 500 && status < 600) log(status);
+
+    // 이름 @ 패턴: 패턴으로 검사하면서 값을 이름에 묶음
+    let requests = [
+        Request { method: "GET", status: 200, retries: 0 },
+        Request { method: "POST", status: 503, retries: 2 },
+        Request { method: "GET", status: 404, retries: 0 },
+        Request { method: "PUT", status: 502, retries: 5 },
+    ];
+    for req in &requests {
+        let verdict = match req {
+            Request { status: code @ 200..=299, .. } => format!("성공 {}", code),
+            Request { status: code @ 500..=599, retries: n @ 0..=3, .. } => {
+                format!("서버 에러 {} - 재시도 {}회째", code, n)
+            }
+            Request { status: 500..=599, retries, .. } => format!("재시도 {}회 - 포기", retries),
+            Request { method, status, .. } => format!("{} 실패 {}", method, status),
+        };
+        println!("{:?} → {}", req, verdict);
+    }
+
+    // 구조체 전체를 묶으면서 안쪽 필드도 검사
+    if let Some(slow @ Request { retries: 3.., .. }) = requests.iter().find(|r| r.retries >= 3) {
+        println!("재시도가 많은 요청: {} {}", slow.method, slow.status);
+    }
+
+    // let-else 와 함께 - 범위에 맞을 때만 이름에 묶고, 아니면 빠져나감
+    let id @ 1..=9 = 7 else {
+        unreachable!()
+    };
+    println!("한 자리 id: {}", id);
+}
+
+fn main() {
+    at_bindings();
+}
@@ -0,0 +1,60 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 22. 고급 패턴 매칭 - 바인딩 모드 (match ergonomics)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 바인딩 모드 (match ergonomics)
+// ----------------------------------------------------------------------------
+
+fn binding_modes() {
+    println!("\n--- 바인딩 모드 (match ergonomics) ---");
+
+    // C++ 에서는:
+    // auto [name, age] = person;    // 복사
+    // auto& [name, age] = person;   // 참조 - 선언마다 & 를 고름
+
+    let pair = (String::from("Ferris"), 7);
+
+    // 참조를 값 패턴으로 매치하면 바인딩 모드가 "참조로" 바뀜
+    // &(String, i32) 를 (name, age) 로 → name: &String, age: &i32
+    let (name, age) = &pair;
+    println!("이름 {} ({}), 나이 {}", name, type_name_of(&name), age);
+    println!("pair 는 그대로: {:?}", pair); // 옮겨지지 않음
+
+    // &mut 이면 바인딩도 &mut
+    let mut counts = vec![("a", 1), ("b", 2)];
+    for (key, count) in &mut counts {
+        *count *= 10;
+        println!("{} → {}", key, count);
+    }
+
+    // Option<String> 을 참조로 매치 - 안의 String 을 빌림
+    let title = Some(String::from("고급 패턴"));
+    match &title {
+        Some(t) => println!("제목 길이 {} ({})", t.len(), type_name_of(&t)),
+        None => println!("제목 없음"),
+    }
+    println!("title 도 그대로: {:?}", title);
+
+    // 값으로 매치하면 옮겨짐 - 아래 줄 이후 title 은 쓸 수 없음
+    if let Some(owned) = title {
+        println!("가져온 제목: {} ({})", owned, type_name_of(&owned));
+    }
+    // println!("{:?}", title); // 에러: 값이 이동됨
+
+    // 명시적으로 &(..) 패턴을 쓰면 참조를 벗김 - Copy 인 값만 꺼낼 때
+    let points = [(1, 2), (3, 4)];
+    let xs: Vec<i32> = points.iter().map(|&(x, _)| x).collect();
+    println!("x 좌표: {:?}", xs);
+}
+
+// 바인딩의 타입을 보여 주기 위한 도우미
+fn type_name_of<T: ?Sized>(_: &T) -> &'static str {
+    std::any::type_name::<T>()
+}
+
+fn main() {
+    binding_modes();
+}
@@ -0,0 +1,79 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 22. 고급 패턴 매칭 - 중첩 or 패턴
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 중첩 or 패턴
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+enum Shape {
+    Circle { radius: f64 },
+    Rect { w: f64, h: f64 },
+    Square(f64),
+}
+
+#[derive(Debug)]
+enum Event {
+    Key(char),
+    Click { x: i32, y: i32 },
+    Draw(Shape),
+    Quit,
+}
+
+fn handle(event: &Event) -> String {
+    match event {
+        // 안쪽에 | - 한 갈래에서 여러 키
+        Event::Key('q' | 'Q') | Event::Quit => "종료".to_string(),
+        Event::Key(c @ ('0'..='9' | 'a'..='f')) => format!("16진 숫자 {}", c),
+        Event::Key(c) => format!("키 {}", c),
+        // 좌표 중 하나가 0 - 갈래마다 같은 이름을 같은 타입으로 묶어야 함
+        Event::Click { x: 0, y: v } | Event::Click { x: v, y: 0 } => format!("축 위 클릭 ({})", v),
+        Event::Click { x, y } => format!("클릭 ({}, {})", x, y),
+        // 중첩 enum 도 한 패턴으로
+        Event::Draw(Shape::Square(side) | Shape::Rect { w: side, h: _ }) if *side > 10.0 => {
+            "큰 사각형".to_string()
+        }
+        Event::Draw(Shape::Circle { radius }) => format!("원 넓이 {:.1}", std::f64::consts::PI * radius * radius),
+        Event::Draw(Shape::Rect { w, h }) => format!("사각형 넓이 {:.1}", w * h),
+        Event::Draw(shape) => format!("작은 도형 {:?}", shape),
+    }
+}
+
+fn nested_or_patterns() {
+    println!("\n--- 중첩 or 패턴 ---");
+
+    // C++ 에서는:
+    // std::visit(overloaded{
+    //     [](Key k) { if (k.c == 'q' || k.c == 'Q') ... },
+    //     [](Click c) { ... },
+    //     [](Draw d) { std::visit(overloaded{ ... }, d.shape); },   // 중첩은 visit 을 한 번 더
+    // }, event);
+    // → 빠뜨린 타입은 컴파일 에러지만, 값 조건(q/Q, x == 0)은 람다 안의 if 로
+
+    let events = [
+        Event::Key('Q'),
+        Event::Key('b'),
+        Event::Key('z'),
+        Event::Click { x: 0, y: 5 },
+        Event::Click { x: 3, y: 4 },
+        Event::Draw(Shape::Rect { w: 12.0, h: 1.0 }),
+        Event::Draw(Shape::Rect { w: 2.0, h: 3.0 }),
+        Event::Draw(Shape::Circle { radius: 1.0 }),
+        Event::Draw(Shape::Square(2.0)),
+        Event::Quit,
+    ];
+    for event in &events {
+        println!("{:?} → {}", event, handle(event));
+    }
+
+    // matches! 도 같은 문법
+    let quits = events.iter().filter(|e| matches!(e, Event::Quit | Event::Key('q' | 'Q'))).count();
+    println!("종료 이벤트: {}개", quits);
+}
+
+fn main() {
+    nested_or_patterns();
+}
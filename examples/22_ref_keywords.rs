@@ -0,0 +1,47 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 22. 고급 패턴 매칭 - ref 와 ref mut
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// ref 와 ref mut
+// ----------------------------------------------------------------------------
+
+fn ref_keywords() {
+    println!("\n--- ref 와 ref mut ---");
+
+    // match ergonomics 이전(2015)에는 참조로 묶으려면 ref 가 필요했음
+    // 지금은 주로 "값을 매치하면서 일부만 빌릴 때" 씀
+
+    // 값(Option<String>) 을 매치하면서 안의 String 은 옮기지 않고 빌림
+    let message = Some(String::from("hello"));
+    if let Some(ref text) = message {
+        println!("빌린 메시지: {}", text);
+    }
+    println!("message 는 그대로: {:?}", message);
+
+    // ref mut - 그 자리에서 고치기
+    let mut config = (String::from("debug"), 3);
+    let (ref mut level, ref mut depth) = config;
+    level.push_str("+trace");
+    *depth += 1;
+    println!("고친 설정: {:?}", config);
+
+    // 한 패턴 안에서 옮기기와 빌리기를 섞을 수 있음
+    struct Job {
+        name: String,
+        tags: Vec<String>,
+    }
+    let job = Job { name: String::from("build"), tags: vec![String::from("ci")] };
+    let Job { name, ref tags } = job; // name 은 옮김, tags 는 빌림
+    println!("작업 {} 태그 {:?}", name, tags);
+    println!("job.tags 는 아직 쓸 수 있음: {}", job.tags.len());
+
+    // C++ 에서는:
+    // auto& [name, tags] = job;    // 전부 참조 또는 전부 복사 - 필드마다 고를 수 없음
+}
+
+fn main() {
+    ref_keywords();
+}
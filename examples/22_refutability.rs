@@ -0,0 +1,61 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 22. 고급 패턴 매칭 - 반박 가능한 패턴과 반박 불가능한 패턴
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 반박 가능한 패턴과 반박 불가능한 패턴
+// ----------------------------------------------------------------------------
+
+// 실패할 수 있는 분해 - Option 하나는 ? 로, ? 로 풀 수 없는 패턴(두 Result 를 한꺼번에)은 let-else 로 이른 반환
+fn parse_point(text: &str) -> Option<(i32, i32)> {
+    let (x, y) = text.split_once(',')?;
+    let (Ok(x), Ok(y)) = (x.trim().parse(), y.trim().parse()) else {
+        return None;
+    };
+    Some((x, y))
+}
+
+fn refutability() {
+    println!("\n--- 반박 가능한 패턴과 반박 불가능한 패턴 ---");
+
+    // 반박 불가능(irrefutable): 항상 맞는 패턴 - let, 함수 인자, for
+    let (a, b) = (1, 2);
+    let print_pair = |(x, y): (i32, i32)| println!("쌍: {} {}", x, y);
+    print_pair((a, b));
+
+    // 반박 가능(refutable): 맞지 않을 수 있는 패턴 - if let, while let, match, let-else
+    // let Some(x) = maybe;   // 에러 E0005: None 이 처리되지 않음
+    let maybe: Option<i32> = None;
+    if let Some(x) = maybe {
+        println!("값: {}", x);
+    } else {
+        println!("maybe 는 None");
+    }
+
+    for text in ["3, 4", "3;4", "x, 1"] {
+        println!("parse_point({:?}) = {:?}", text, parse_point(text));
+    }
+
+    // while let - 맞는 동안 반복
+    let mut stack = vec![1, 2, 3];
+    while let Some(top) = stack.pop() {
+        print!("{} ", top);
+    }
+    println!("(스택 비움)");
+
+    // 반박 불가능한 패턴을 if let 에 쓰면 경고 (irrefutable_let_patterns)
+    // 빈 enum 이나 Result<T, Infallible> 은 실패할 갈래가 없으므로 let 으로 바로 분해할 수 있음
+    let always: Result<u8, std::convert::Infallible> = Ok(42);
+    let Ok(value) = always;
+    println!("Infallible 에서 꺼낸 값: {}", value);
+
+    // C++ 에서는:
+    // auto [x, y] = point;       // 구조화된 바인딩은 항상 맞는 분해만
+    // if (auto* p = std::get_if<int>(&v)) { ... }   // 실패할 수 있는 분해는 포인터 검사로
+}
+
+fn main() {
+    refutability();
+}
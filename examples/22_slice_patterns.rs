@@ -0,0 +1,61 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 22. 고급 패턴 매칭 - 슬라이스 패턴
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+// ----------------------------------------------------------------------------
+// 슬라이스 패턴
+// ----------------------------------------------------------------------------
+
+// 명령줄 인자 하나를 해석 - 길이와 내용을 한 번에 검사
+fn describe_command(args: &[&str]) -> String {
+    match args {
+        [] => "명령 없음".to_string(),
+        ["help"] => "도움말".to_string(),
+        ["run", chapter] => format!("{}장 실행", chapter),
+        ["run", first, .., last] => format!("{}장부터 {}장까지 실행", first, last),
+        [command, rest @ ..] => format!("알 수 없는 명령 {} (인자 {}개)", command, rest.len()),
+    }
+}
+
+fn slice_patterns() {
+    println!("--- 슬라이스 패턴 ---");
+
+    // C++ 에서는:
+    // if (args.empty()) ...
+    // else if (args.size() == 2 && args[0] == "run") ...
+    // else if (args.size() > 2 && args[0] == "run") { auto first = args[1]; auto last = args.back(); }
+    // → 길이 검사와 인덱싱을 따로 - 순서를 틀리면 범위 밖 접근
+
+    for args in [&[][..], &["help"], &["run", "07"], &["run", "05", "06", "09"], &["build", "x"]] {
+        println!("{:?} → {}", args, describe_command(args));
+    }
+
+    // 처음과 끝, 가운데 나머지
+    let scores = [90, 72, 85, 64, 99];
+    if let [first, middle @ .., last] = &scores[..] {
+        println!("처음 {}, 끝 {}, 가운데 {:?}", first, last, middle);
+    }
+
+    // 재귀와 잘 어울림 - 머리와 꼬리로 나누기
+    fn sum(values: &[i32]) -> i32 {
+        match values {
+            [] => 0,
+            [head, tail @ ..] => head + sum(tail),
+        }
+    }
+    println!("합계: {}", sum(&scores));
+
+    // 배열은 길이가 타입에 있으므로 [a, b, c] 만으로 빠짐없음
+    let [r, g, b] = [255u8, 128, 0];
+    println!("RGB 분해: {} {} {}", r, g, b);
+
+    // 인접한 두 값 - windows 의 원소도 슬라이스
+    let rising = scores.windows(2).filter(|w| matches!(w, [a, b] if b > a)).count();
+    println!("이전보다 오른 횟수: {}", rising);
+}
+
+fn main() {
+    slice_patterns();
+}
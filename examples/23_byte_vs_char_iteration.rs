@@ -0,0 +1,52 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 23. 문자열과 텍스트 심화 - 바이트 순회와 char 순회
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
+
+// ----------------------------------------------------------------------------
+// 바이트 순회와 char 순회
+// ----------------------------------------------------------------------------
+
+fn byte_vs_char_iteration() {
+    println!("\n--- 바이트 순회와 char 순회 ---");
+
+    // C++ 에서는:
+    // for (char c : s)      // 항상 바이트 - 한글은 3조각
+    // std::count(s.begin(), s.end(), ',')
+
+    let line = "이름,나이,도시,name,age,city\n".repeat(10_000);
+
+    // ASCII 구분자를 찾을 때는 바이트로 충분 - UTF-8 의 다바이트 문자에는 ASCII 바이트가 들어가지 않음
+    let start = Instant::now();
+    let by_byte = black_box(&line).bytes().filter(|&b| b == b',').count();
+    let byte_time = start.elapsed();
+
+    // chars() 는 글자마다 UTF-8 을 해독 - 더 느림
+    let start = Instant::now();
+    let by_char = black_box(&line).chars().filter(|&c| c == ',').count();
+    let char_time = start.elapsed();
+
+    println!("쉼표 {}개 = {}개 (입력 {}KB)", by_byte, by_char, line.len() / 1024);
+    println!("bytes(): {:?}, chars(): {:?} (빌드와 기계에 따라 다름 - --release 로 비교)", byte_time, char_time);
+
+    // 표준 라이브러리의 검색(find, split, matches)은 내부적으로 바이트 검색 - 직접 고를 필요 없음
+    println!("matches(',').count() = {}", line.matches(',').count());
+
+    // 글자 수가 필요하면 chars().count() - len() 은 바이트 수
+    let sample = "héllo 세계";
+    println!("{:?}: len() {} 바이트, chars().count() {} 글자", sample, sample.len(), sample.chars().count());
+
+    // 바이트 리터럴과 is_ascii_* - ASCII 만 다루는 파서에 알맞음
+    let digits = b"2024-07-15".iter().filter(|b| b.is_ascii_digit()).count();
+    println!("ASCII 숫자: {}개", digits);
+}
+
+fn main() {
+    byte_vs_char_iteration();
+}
@@ -0,0 +1,58 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 23. 문자열과 텍스트 심화 - CString 과 CStr
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
+
+// ----------------------------------------------------------------------------
+// CString 과 CStr
+// ----------------------------------------------------------------------------
+
+fn c_strings() {
+    println!("\n--- CString 과 CStr ---");
+
+    // C++ 에서는:
+    // std::string s = "hi";
+    // puts(s.c_str());                  // 널 종료는 std::string 이 항상 보장
+    // std::string t("a\0b", 3); puts(t.c_str());   // 'a' 만 출력 - 조용히 잘림
+
+    // CString: 소유, 끝에 \0, 중간에 \0 없음 (String 과 짝)
+    // &CStr  : 빌림 (&str 과 짝) - C 함수에 넘길 때는 as_ptr()
+    let owned = CString::new("hello").expect("중간에 0 이 없음");
+    println!("CString: {:?}, 바이트(널 포함): {:?}", owned, owned.as_bytes_with_nul());
+
+    // 중간에 0 이 있으면 만들 때 에러 - C 쪽에서 잘리는 대신 바로 드러남
+    match CString::new("a\0b") {
+        Ok(s) => println!("만들어짐: {:?}", s),
+        Err(e) => println!("CString::new(\"a\\0b\") 에러: 위치 {} 에 0", e.nul_position()),
+    }
+
+    // C 문자열 리터럴 c"..." - 컴파일 시점에 &CStr
+    let literal: &CStr = c"리터럴";
+    println!("c\"리터럴\": {:?} ({}바이트 + 널)", literal, literal.count_bytes());
+
+    // C 에서 받은 버퍼 → CStr → &str (UTF-8 이 아니면 에러)
+    let buffer: Vec<u8> = b"from C\0".to_vec();
+    let from_c = CStr::from_bytes_with_nul(&buffer).unwrap();
+    println!("to_str: {:?}", from_c.to_str());
+    let buffer: Vec<u8> = b"caf\xE9\0".to_vec();
+    let latin1 = CStr::from_bytes_with_nul(&buffer).unwrap();
+    println!("UTF-8 이 아닌 C 문자열: {:?} → lossy {}", latin1.to_str().is_err(), latin1.to_string_lossy());
+
+    // 포인터를 넘길 때의 함정: 임시 CString 의 포인터는 문장이 끝나면 댕글링
+    // let p = CString::new("x").unwrap().as_ptr();  // 경고(dangling_pointers_from_temporaries)
+    let kept = CString::new("x").unwrap();
+    let ptr = kept.as_ptr(); // kept 가 살아 있는 동안만 유효
+    // 안전성: ptr 은 살아 있는 kept 의 널 종료 버퍼를 가리킴
+    let back = unsafe { CStr::from_ptr(ptr) };
+    println!("포인터로 다시 읽기: {:?}", back);
+}
+
+fn main() {
+    c_strings();
+}
@@ -0,0 +1,63 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 23. 문자열과 텍스트 심화 - String 용량 증가
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
+
+// ----------------------------------------------------------------------------
+// String 용량 증가
+// ----------------------------------------------------------------------------
+
+fn capacity_growth() {
+    println!("\n--- String 용량 증가 ---");
+
+    // C++ 에서는:
+    // std::string s;            // SSO - 짧은 문자열(보통 15~22바이트)은 힙 할당 없음
+    // s.reserve(100);
+    // → Rust 의 String 에는 SSO 가 없음: 빈 String 은 할당 없음, 한 글자부터 힙
+
+    let mut s = String::new();
+    println!("String::new(): 용량 {} (할당 없음)", s.capacity());
+
+    // 용량이 바뀌는 순간만 기록 - 대략 두 배씩 (구체적인 수치는 구현 세부)
+    let mut changes = Vec::new();
+    let mut last = s.capacity();
+    for _ in 0..100 {
+        s.push('x');
+        if s.capacity() != last {
+            last = s.capacity();
+            changes.push((s.len(), last));
+        }
+    }
+    println!("push 100번 동안 용량 변화 (길이, 용량): {:?}", changes);
+
+    // 최종 크기를 알면 한 번에
+    let mut joined = String::with_capacity(5 * 3);
+    for word in ["one", "two", "six", "ten", "red"] {
+        joined.push_str(word);
+    }
+    println!("with_capacity(15) 후 길이 {}, 용량 {}", joined.len(), joined.capacity());
+
+    // reserve 는 "추가로 최소 n" - 이미 충분하면 아무것도 안 함
+    joined.reserve(1);
+    println!("reserve(1) 후 용량: {} (모자라면 늘림)", joined.capacity());
+
+    // 줄이기 - 오래 두는 큰 문자열에만 (다시 자라면 재할당)
+    s.truncate(3);
+    println!("truncate(3) 후 길이 {}, 용량 {} (용량은 그대로)", s.len(), s.capacity());
+    s.shrink_to_fit();
+    println!("shrink_to_fit 후 용량: {}", s.capacity());
+
+    // format! 과 collect 도 미리 크기를 짐작 - 반복문의 + 는 매번 재할당할 수 있음
+    let csv: String = (1..=5).map(|n| n.to_string()).collect::<Vec<_>>().join(",");
+    println!("join: {:?} (용량 {})", csv, csv.capacity());
+}
+
+fn main() {
+    capacity_growth();
+}
@@ -0,0 +1,88 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 23. 문자열과 텍스트 심화 - char 와 자소 클러스터
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
+
+// ----------------------------------------------------------------------------
+// char 와 자소 클러스터
+// ----------------------------------------------------------------------------
+
+// 결합 문자(악센트, 한글 자모의 중성/종성)와 이음 문자(ZWJ), 이체 선택자를 앞 글자에 붙임
+// 간단한 근사 - 실제 규칙(UAX #29)은 unicode-segmentation 크레이트의 graphemes()
+fn rough_graphemes(text: &str) -> Vec<&str> {
+    let joins_previous = |c: char| {
+        matches!(c,
+            '\u{0300}'..='\u{036F}'     // 결합 분음 부호
+            | '\u{1160}'..='\u{11FF}'   // 한글 중성, 종성 자모
+            | '\u{FE00}'..='\u{FE0F}'   // 이체 선택자
+            | '\u{1F3FB}'..='\u{1F3FF}' // 피부색 수정자
+            | '\u{200D}')
+    };
+    let mut clusters: Vec<&str> = Vec::new();
+    let mut start = 0;
+    let mut after_zwj = false;
+    for (i, c) in text.char_indices().skip(1) {
+        if joins_previous(c) || after_zwj {
+            after_zwj = c == '\u{200D}';
+            continue;
+        }
+        clusters.push(&text[start..i]);
+        start = i;
+    }
+    if !text.is_empty() {
+        clusters.push(&text[start..]);
+    }
+    clusters
+}
+
+fn chars_and_graphemes() {
+    println!("\n--- char 와 자소 클러스터 ---");
+
+    // C++ 에서는:
+    // std::string s = "é";        // 바이트 2개 (UTF-8)
+    // std::u16string u = u"😀";   // char16_t 2개 (서로게이트 쌍)
+    // std::u32string w = U"é";    // 조합형이면 char32_t 2개 - "글자" 는 라이브러리 없이 못 셈
+
+    // char = 유니코드 스칼라 값 (4바이트) - 사람이 보는 "글자" 와 다를 수 있음
+    let samples = [
+        ("é (완성형)", "\u{00E9}"),
+        ("é (조합형)", "e\u{0301}"),
+        ("한 (완성형)", "한"),
+        ("한 (자모)", "\u{1112}\u{1161}\u{11AB}"),
+        ("👍🏽", "\u{1F44D}\u{1F3FD}"),
+        ("👨‍👩‍👧", "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+    ];
+    for (label, text) in samples {
+        println!(
+            "{:12} 바이트 {:2}, UTF-16 {}, char {}, 글자 {}",
+            label,
+            text.len(),
+            text.encode_utf16().count(),
+            text.chars().count(),
+            rough_graphemes(text).len()
+        );
+    }
+
+    // 같아 보여도 다른 문자열 - 비교 전에 정규화(NFC) 필요 (unicode-normalization 크레이트)
+    println!("완성형 é == 조합형 é ? {}", "\u{00E9}" == "e\u{0301}");
+
+    // 글자 단위로 뒤집기 - chars().rev() 는 조합형을 깨뜨림
+    let word = "cafe\u{0301}";
+    let by_char: String = word.chars().rev().collect();
+    let by_grapheme: String = rough_graphemes(word).into_iter().rev().collect();
+    println!("chars().rev(): {:?}, 글자 단위: {:?}", by_char, by_grapheme);
+
+    // char 의 분류 메서드는 유니코드 기준
+    let c = 'ß';
+    println!("{:?}: 알파벳 {}, 대문자 {:?} (한 글자가 두 글자로)", c, c.is_alphabetic(), c.to_uppercase().to_string());
+}
+
+fn main() {
+    chars_and_graphemes();
+}
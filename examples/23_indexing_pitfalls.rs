@@ -0,0 +1,70 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 23. 문자열과 텍스트 심화 - str 인덱싱의 함정
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
+
+// ----------------------------------------------------------------------------
+// str 인덱싱의 함정
+// ----------------------------------------------------------------------------
+
+// 최대 max 바이트로 자르되 글자 중간에서 자르지 않음
+fn truncate_at_boundary(text: &str, max: usize) -> &str {
+    if max >= text.len() {
+        return text;
+    }
+    let mut end = max;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+fn indexing_pitfalls() {
+    println!("\n--- str 인덱싱의 함정 ---");
+
+    // C++ 에서는:
+    // s.substr(0, 10)   // 바이트 단위 - 한글 중간에서 잘려도 아무 말 없음 (깨진 UTF-8)
+    // s.find("x")       // 바이트 위치
+
+    let text = "Rust 는 안전해요";
+
+    // 1. 범위 슬라이스는 바이트 위치 - 글자 중간이면 panic
+    // let bad = &text[..6];   // panic: byte index 6 is not a char boundary
+    println!("6 은 글자 경계? {} ('는' 은 5..8 바이트)", text.is_char_boundary(6));
+    println!("get(..6) 은 panic 대신 None: {:?}", text.get(..6));
+
+    // 2. find 가 돌려주는 위치도 바이트 - 그대로 슬라이스에 쓰면 안전
+    if let Some(at) = text.find("안전") {
+        println!("find(\"안전\") = {} (바이트), 그 뒤: {:?}", at, &text[at..]);
+    }
+
+    // 3. n 번째 글자는 O(n) - 루프 안에서 chars().nth(i) 를 반복하면 O(n²)
+    let fifth: Option<char> = text.chars().nth(5);
+    println!("chars().nth(5) = {:?}", fifth);
+    // 여러 번 쓸 거면 한 번 모아 두기
+    let chars: Vec<char> = text.chars().collect();
+    println!("Vec<char> 로 모으면 [5] = {:?} (대신 글자마다 4바이트)", chars[5]);
+
+    // 4. 길이 제한 (DB 컬럼, 트윗) - 글자 경계에서 자르기
+    for max in [5, 6, 7, 8] {
+        println!("앞 {}바이트 안에서: {:?}", max, truncate_at_boundary(text, max));
+    }
+
+    // 5. char_indices - 글자와 바이트 위치를 같이
+    let positions: Vec<(usize, char)> = "가a나".char_indices().collect();
+    println!("char_indices: {:?}", positions);
+
+    // 6. 대소문자 변환은 글자 수를 바꿀 수 있음 - 원래 위치로 되돌아가 자르면 안 됨
+    let upper = "straße".to_uppercase();
+    println!("\"straße\" {}글자 → {:?} {}글자", "straße".chars().count(), upper, upper.chars().count());
+}
+
+fn main() {
+    indexing_pitfalls();
+}
@@ -0,0 +1,60 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 23. 문자열과 텍스트 심화 - OsString 과 OsStr
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
+
+// ----------------------------------------------------------------------------
+// OsString 과 OsStr
+// ----------------------------------------------------------------------------
+
+fn os_strings() {
+    println!("--- OsString 과 OsStr ---");
+
+    // C++ 에서는:
+    // std::filesystem::path p = argv[1];   // 유닉스: char 바이트열, Windows: wchar_t(UTF-16)
+    // std::string s = p.string();          // Windows 에서 변환 실패 시 예외
+    // → Rust 는 "운영체제 문자열" 을 OsString 한 타입으로 - 변환은 Option/Cow 로 드러남
+
+    // 환경 변수, 명령줄 인자, 파일 이름은 UTF-8 이라는 보장이 없음
+    let home: Option<OsString> = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"));
+    println!("홈 디렉터리 변수: {}", if home.is_some() { "있음" } else { "없음" });
+
+    // OsStr → &str 은 실패할 수 있음 (Option), 손실을 감수하면 to_string_lossy (Cow, 51장)
+    let name = OsStr::new("notes.txt");
+    println!("to_str: {:?}, to_string_lossy: {}", name.to_str(), name.to_string_lossy());
+
+    // Path 는 OsStr 을 감싼 것 - 파일 이름, 확장자도 &OsStr
+    let path = Path::new("content").join("23_text.toml");
+    let ext: Option<&OsStr> = path.extension();
+    println!("확장자 {:?} (타입은 Option<&OsStr>)", ext);
+
+    // 유닉스에서는 UTF-8 이 아닌 바이트도 그대로 파일 이름이 될 수 있음
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let raw = OsStr::from_bytes(b"caf\xE9.txt"); // Latin-1 로 쓴 "café"
+        println!("Latin-1 파일 이름: to_str = {:?}", raw.to_str());
+        println!("           lossy  = {}", raw.to_string_lossy()); // 잘못된 바이트는 U+FFFD
+        println!("           바이트 = {:?}", raw.as_bytes());
+    }
+
+    // 운영체제와 상관없이 쓸 수 있는 바이트 보기 - 다시 OsStr 로 만들 때는 unsafe
+    let encoded = OsStr::new("한글.txt").as_encoded_bytes();
+    println!("as_encoded_bytes 길이: {} (UTF-8 부분은 UTF-8 그대로)", encoded.len());
+
+    // OsString 도 String 처럼 이어 붙일 수 있음
+    let mut file = OsString::from("report");
+    file.push(".md");
+    println!("OsString: {:?}", file);
+}
+
+fn main() {
+    os_strings();
+}
@@ -0,0 +1,61 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 24. 수치 타입과 변환 - as 와 TryFrom
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::num::Wrapping;
+
+// ----------------------------------------------------------------------------
+// as 와 TryFrom
+// ----------------------------------------------------------------------------
+
+fn as_vs_try_from() {
+    println!("\n--- as 와 TryFrom ---");
+
+    // C++ 에서는:
+    // static_cast<uint8_t>(300)      // 44 - 모듈로 (C++20 부터 정의됨)
+    // static_cast<int>(3.9e10)       // 미정의 동작! (범위 밖 float → int)
+    // gsl::narrow<uint8_t>(300)      // 예외 - 표준에는 없음
+
+    // as: 정수 → 정수는 비트를 자르거나(좁힘) 부호 확장(넓힘) - 실패하지 않음
+    println!("300i32 as u8    = {}", 300i32 as u8); // 44
+    println!("-1i32 as u32    = {}", -1i32 as u32); // 4294967295
+    println!("-1i8 as i32     = {}", -1i8 as i32); // -1 (부호 확장)
+    println!("200u8 as i8     = {}", 200u8 as i8); // -56
+
+    // as: float → int 는 포화 (Rust 1.45 부터) - C++ 과 달리 미정의 동작이 아님
+    println!("3.9e10 as i32   = {}", 3.9e10_f64 as i32); // i32::MAX
+    println!("-1.5 as u8      = {}", -1.5_f64 as u8); // 0
+    #[expect(clippy::cast_nan_to_int, reason = "NaN 이 0 으로 포화되는 것을 보여 줌")]
+    let nan_as_int = f64::NAN as i32;
+    println!("f64::NAN as i32 = {}", nan_as_int); // 0
+    println!("2.9 as i32      = {} (0 쪽으로 버림)", 2.9_f64 as i32);
+
+    // as: int → float 는 가장 가까운 값으로 - 큰 정수는 정밀도를 잃음
+    let big = 16_777_217u32; // 2^24 + 1
+    println!("{} as f32 = {} (f32 가수는 24비트)", big, big as f32);
+
+    // TryFrom / try_into: 값이 범위 안일 때만 - 실패는 Result 로
+    println!("u8::try_from(300i32) = {:?}", u8::try_from(300i32));
+    println!("u8::try_from(200i32) = {:?}", u8::try_from(200i32));
+    let index: Result<usize, _> = (-3i64).try_into();
+    println!("(-3i64).try_into::<usize>() = {:?}", index);
+
+    // From: 항상 성공하는 넓힘만 구현됨 - 손실이 없음이 타입으로 보장
+    let wide: i64 = i64::from(7i32);
+    let f: f64 = f64::from(1.5f32);
+    println!("i64::from(7i32) = {}, f64::from(1.5f32) = {}", wide, f);
+    // u32 → f32 는 From 이 없음 (위의 16_777_217 처럼 손실 가능)
+
+    // 실무 규칙: 길이, 인덱스 변환은 try_from + ? (또는 expect 로 불변식을 적음)
+    fn to_index(n: i64) -> Result<usize, String> {
+        usize::try_from(n).map_err(|e| format!("잘못된 인덱스 {}: {}", n, e))
+    }
+    println!("to_index(5) = {:?}, to_index(-1) = {:?}", to_index(5), to_index(-1));
+}
+
+fn main() {
+    as_vs_try_from();
+}
@@ -0,0 +1,60 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 24. 수치 타입과 변환 - 부동소수점 비교의 함정
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::num::Wrapping;
+
+// ----------------------------------------------------------------------------
+// 부동소수점 비교의 함정
+// ----------------------------------------------------------------------------
+
+// 상대 오차와 절대 오차를 함께 - 0 근처에서는 상대 오차만으로 부족
+fn approx_eq(a: f64, b: f64, rel: f64, abs: f64) -> bool {
+    let diff = (a - b).abs();
+    diff <= abs || diff <= rel * a.abs().max(b.abs())
+}
+
+fn float_comparison() {
+    println!("\n--- 부동소수점 비교의 함정 ---");
+
+    // C++ 과 같은 IEEE 754 - 함정도 같음, 다만 타입 시스템이 일부를 드러냄
+
+    // 1. 0.1 + 0.2 != 0.3
+    let sum = 0.1 + 0.2;
+    println!("0.1 + 0.2 = {:.17}, == 0.3 ? {}", sum, sum == 0.3);
+    println!("approx_eq: {}", approx_eq(sum, 0.3, 1e-12, 1e-12));
+
+    // 2. EPSILON 은 "1 근처의 간격" - 큰 수에는 너무 작고 작은 수에는 너무 큼
+    let big = 1e16;
+    println!("1e16 + 1.0 == 1e16 ? {} (간격이 2)", big + 1.0 == big);
+    println!("f64::EPSILON = {:e}", f64::EPSILON);
+
+    // 3. NaN 은 자기 자신과도 같지 않음 → f64 는 Eq, Ord 가 아님 (PartialEq, PartialOrd 만)
+    let nan = f64::NAN;
+    #[expect(clippy::eq_op, reason = "NaN 이 자기 자신과 같지 않음을 보여 줌")]
+    let nan_eq_nan = nan == nan;
+    println!("NaN == NaN ? {}, NaN < 1 ? {}, NaN > 1 ? {}", nan_eq_nan, nan < 1.0, nan > 1.0);
+    println!("partial_cmp(NaN, 1) = {:?}", nan.partial_cmp(&1.0));
+    // 그래서 HashMap<f64, _> 의 키, BTreeSet<f64>, v.sort() 는 컴파일 에러
+
+    // 4. -0.0 == 0.0 이지만 다른 값
+    println!("-0.0 == 0.0 ? {}, 1/-0.0 = {}", -0.0_f64 == 0.0, 1.0 / -0.0_f64);
+
+    // 5. max/min 은 NaN 을 무시 (C++ std::max 는 인자 순서에 따라 NaN 을 돌려줌)
+    println!("1.0.max(NaN) = {}, NaN.max(1.0) = {}", 1.0_f64.max(nan), nan.max(1.0));
+
+    // 6. 누적 오차 - 0.1 을 열 번 더하기
+    let total: f64 = (0..10).map(|_| 0.1).sum();
+    println!("0.1 을 10번 더하면 {:.17} (== 1.0 ? {})", total, total == 1.0);
+
+    // 돈은 정수(센트)로 - f64 로 계산하지 않음
+    let cents: i64 = 10 + 20;
+    println!("0.10 + 0.20 달러 = {}.{:02}", cents / 100, cents % 100);
+}
+
+fn main() {
+    float_comparison();
+}
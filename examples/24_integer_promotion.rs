@@ -0,0 +1,68 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 24. 수치 타입과 변환 - C++ 정수 승격과의 차이
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::num::Wrapping;
+
+// ----------------------------------------------------------------------------
+// C++ 정수 승격과의 차이
+// ----------------------------------------------------------------------------
+
+fn integer_promotion() {
+    println!("\n--- C++ 정수 승격과의 차이 ---");
+
+    // C++ 에서는:
+    // uint8_t a = 200, b = 100;
+    // auto c = a + b;            // int 300 - 작은 타입은 int 로 승격
+    // unsigned u = 1; int i = -1;
+    // if (i < u) ...             // false! i 가 unsigned 로 변환되어 4294967295
+    // auto s = sizeof(x) - 1;    // size_t - 음수가 되면 아주 큰 수
+
+    // Rust: 같은 타입끼리만 연산 - 결과도 같은 타입
+    let a: u8 = 200;
+    let b: u8 = 100;
+    // let c = a + b;            // 디버그 빌드에서 panic (u8 에 300 은 안 들어감)
+    let c = a as u16 + b as u16; // 넓힐 곳을 직접 고름
+    println!("200u8 + 100u8 을 u16 으로: {}", c);
+
+    // 부호가 다르면 비교도 안 됨 - 변환을 직접
+    let u: u32 = 1;
+    let i: i32 = -1;
+    // if i < u {}               // 에러: i32 와 u32 비교 불가
+    println!("i64 로 넓혀 비교: -1 < 1 ? {}", (i as i64) < (u as i64));
+    println!("try_from 으로 비교: {:?}", u32::try_from(i).map(|i| i < u));
+
+    // 정수 → 실수도 직접 - 평균 계산에서 자주 만남
+    let sum: u32 = 7;
+    let count: usize = 2;
+    let mean = sum as f64 / count as f64;
+    println!("평균 {} (정수 나눗셈이면 {})", mean, sum / count as u32);
+
+    // 리터럴은 문맥에서 타입을 추론 - 접미사로 정할 수도
+    let shifted = 1u64 << 40; // C++ 의 1 << 40 은 int 라서 미정의 동작
+    println!("1u64 << 40 = {}", shifted);
+
+    // 시프트 양이 비트 수 이상이면 디버그에서 panic - checked_shl / wrapping_shl
+    println!("1u32.checked_shl(32) = {:?}", 1u32.checked_shl(32));
+
+    // usize 의 뺄셈 - C++ 의 size_t 처럼 감기지 않고 (디버그) panic
+    let len: usize = 0;
+    println!("len.checked_sub(1) = {:?}, saturating_sub(1) = {}", len.checked_sub(1), len.saturating_sub(1));
+
+    // 비트 수가 필요한 연산은 메서드로
+    let flags: u16 = 0b1011_0000;
+    println!(
+        "count_ones {}, leading_zeros {}, trailing_zeros {}, swap_bytes {:#06x}",
+        flags.count_ones(),
+        flags.leading_zeros(),
+        flags.trailing_zeros(),
+        flags.swap_bytes()
+    );
+}
+
+fn main() {
+    integer_promotion();
+}
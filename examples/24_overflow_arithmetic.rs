@@ -0,0 +1,61 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 24. 수치 타입과 변환 - 오버플로를 다루는 산술
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::num::Wrapping;
+
+// ----------------------------------------------------------------------------
+// 오버플로를 다루는 산술
+// ----------------------------------------------------------------------------
+
+fn overflow_arithmetic() {
+    println!("--- 오버플로를 다루는 산술 ---");
+
+    // C++ 에서는:
+    // int32_t x = INT32_MAX; x + 1;     // 미정의 동작 - 컴파일러가 "일어나지 않는다" 고 가정
+    // uint8_t y = 255; y + 1;           // int 로 승격되어 256, 다시 uint8_t 에 넣으면 0
+    // __builtin_add_overflow(a, b, &r)  // 검사는 컴파일러 확장으로
+
+    let x: u8 = 250;
+    let step: u8 = 10;
+
+    // x + step 은 디버그 빌드에서 panic ("attempt to add with overflow"), 릴리스에서는 감김
+    // → 오버플로가 가능한 곳에서는 의도를 메서드로 적음
+    println!("wrapping_add    : {}", x.wrapping_add(step)); // 4 - 해시, 난수, 체크섬
+    println!("checked_add     : {:?}", x.checked_add(step)); // None - 입력 검증
+    println!("saturating_add  : {}", x.saturating_add(step)); // 255 - 음량, 색, 카운터
+    println!("overflowing_add : {:?}", x.overflowing_add(step)); // (4, true) - 올림수가 필요할 때
+
+    // 뺄셈과 곱셈, 나눗셈, 부호 있는 정수도 같은 네 벌
+    println!("0u32.checked_sub(1) = {:?}", 0u32.checked_sub(1));
+    println!("i32::MIN.checked_abs() = {:?} (|MIN| 은 표현 불가)", i32::MIN.checked_abs());
+    println!("i32::MIN.wrapping_div(-1) = {}", i32::MIN.wrapping_div(-1));
+    println!("10i32.checked_div(0) = {:?} (0 으로 나누기도 None)", 10i32.checked_div(0));
+
+    // ? 와 함께 - 중간에 넘치면 전체가 None
+    fn area(w: u32, h: u32, layers: u32) -> Option<u32> {
+        w.checked_mul(h)?.checked_mul(layers)
+    }
+    println!("area(1000, 1000, 10) = {:?}", area(1000, 1000, 10));
+    println!("area(100_000, 100_000, 1) = {:?}", area(100_000, 100_000, 1));
+
+    // Wrapping<T> - 모든 연산이 감기는 타입 (연산자를 그대로 쓰고 싶을 때)
+    let mut hash = Wrapping(5381u32);
+    for b in b"rust" {
+        hash = hash * Wrapping(33) + Wrapping(*b as u32);
+    }
+    println!("djb2(\"rust\") = {}", hash.0);
+
+    // 그냥 + 를 쓰면 빌드에 따라 다름 - overflow-checks 는 기본으로 debug_assertions 를 따름
+    println!(
+        "이 빌드에서 u8::MAX + 1 은: {} (Cargo.toml 의 [profile.*] overflow-checks 로 바꿀 수 있음)",
+        if cfg!(debug_assertions) { "panic" } else { "0 으로 감김" }
+    );
+}
+
+fn main() {
+    overflow_arithmetic();
+}
@@ -0,0 +1,63 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 24. 수치 타입과 변환 - total_cmp 로 정렬
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::num::Wrapping;
+
+// ----------------------------------------------------------------------------
+// total_cmp 로 정렬
+// ----------------------------------------------------------------------------
+
+fn total_ordering() {
+    println!("\n--- total_cmp 로 정렬 ---");
+
+    // C++ 에서는:
+    // std::sort(v.begin(), v.end());    // NaN 이 있으면 strict weak ordering 위반 - 미정의 동작
+    // std::strong_order(a, b)           // C++20 - IEEE totalOrder (Rust 의 total_cmp 와 같은 규칙)
+
+    let mut values = vec![3.5, -0.0, f64::NAN, 1.0, f64::NEG_INFINITY, 0.0, -2.0, f64::INFINITY];
+
+    // values.sort();                   // 에러: f64 는 Ord 가 아님
+    // values.sort_by(|a, b| a.partial_cmp(b).unwrap());  // NaN 이 있으면 panic
+
+    // total_cmp: IEEE 754 totalOrder - -NaN < -∞ < ... < -0 < +0 < ... < +∞ < NaN
+    values.sort_by(f64::total_cmp);
+    println!("total_cmp 정렬: {:?}", values);
+
+    // 최대/최소도 같은 방법
+    let max = values.iter().copied().max_by(f64::total_cmp);
+    println!("max_by(total_cmp) = {:?} (NaN 이 가장 큼)", max);
+
+    // NaN 을 빼고 싶으면 먼저 거르기
+    let finite_max = values.iter().copied().filter(|v| !v.is_nan()).max_by(f64::total_cmp);
+    println!("NaN 을 뺀 max = {:?}", finite_max);
+
+    // 구조체 필드로 정렬할 때
+    let mut scores: [(&str, f64); 4] = [("ann", 91.5), ("bob", 78.0), ("cy", 91.5), ("dee", 85.25)];
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(b.0)));
+    println!("점수 내림차순: {:?}", scores);
+
+    // BTreeMap 키 등 Ord 가 꼭 필요하면 감싸기 (ordered-float 크레이트가 같은 일을 함)
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Total(f64);
+    impl Eq for Total {}
+    impl PartialOrd for Total {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Total {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.total_cmp(&other.0)
+        }
+    }
+    let set: std::collections::BTreeSet<Total> = [2.5, 0.5, 1.5].into_iter().map(Total).collect();
+    println!("BTreeSet<Total>: {:?}", set);
+}
+
+fn main() {
+    total_ordering();
+}
@@ -0,0 +1,64 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 25. 배열과 슬라이스 심화 - [T; N], &[T], Vec<T>
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Reverse;
+
+// ----------------------------------------------------------------------------
+// [T; N], &[T], Vec<T>
+// ----------------------------------------------------------------------------
+
+// 슬라이스로 받으면 배열, Vec, 부분 범위를 모두 받을 수 있음 (std::span 과 같은 역할)
+fn average(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+fn array_slice_vec() {
+    println!("--- [T; N], &[T], Vec<T> ---");
+
+    // C++ 에서는:
+    // std::array<int, 3> a{1, 2, 3};     // 크기가 타입에, 스택
+    // std::vector<int> v{1, 2, 3};       // 힙, 자람
+    // std::span<const int> s{v};         // 빌린 보기 (C++20)
+
+    let array: [f64; 4] = [1.0, 2.0, 3.0, 4.0]; // 크기가 타입의 일부, 복사 가능(Copy 원소면)
+    let vec: Vec<f64> = vec![10.0, 20.0];
+    println!("크기: [f64; 4] = {}바이트, &[f64] = {}바이트 (포인터 + 길이), Vec<f64> = {}바이트 (포인터 + 길이 + 용량)",
+        size_of::<[f64; 4]>(),
+        size_of::<&[f64]>(),
+        size_of::<Vec<f64>>()
+    );
+
+    // 모두 &[f64] 로 - 자동 변환(deref, unsize)
+    println!("average(&array) = {:?}", average(&array));
+    println!("average(&vec) = {:?}", average(&vec));
+    println!("average(&array[1..3]) = {:?}", average(&array[1..3]));
+    println!("average(&[]) = {:?}", average(&[]));
+
+    // 범위 밖: 인덱스는 panic, get 은 None
+    println!("array.get(9) = {:?}, array.first() = {:?}, vec.last() = {:?}", array.get(9), array.first(), vec.last());
+
+    // 배열 ↔ 슬라이스 ↔ Vec
+    let from_slice: [f64; 2] = array[..2].try_into().expect("길이 2"); // 길이가 맞을 때만
+    let to_vec: Vec<f64> = array.to_vec(); // 복사해서 힙으로
+    let boxed: Box<[f64]> = vec.into_boxed_slice(); // 용량 없이 딱 맞게
+    println!("try_into [f64; 2] = {:?}, to_vec = {:?}, Box<[f64]> = {:?}", from_slice, to_vec, boxed);
+
+    // 배열은 값 - 대입하면 복사 (C 배열처럼 포인터로 바뀌지 않음)
+    let mut copy = array;
+    copy[0] = 99.0;
+    println!("복사본 수정 후 원본 {:?} / 복사본 {:?}", array[0], copy[0]);
+
+    // 배열의 map 과 from_fn - 크기를 유지한 채 변환
+    let squares: [u32; 5] = std::array::from_fn(|i| (i * i) as u32);
+    println!("from_fn: {:?}, map: {:?}", squares, squares.map(|x| x + 1));
+}
+
+fn main() {
+    array_slice_vec();
+}
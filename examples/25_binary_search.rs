@@ -0,0 +1,51 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 25. 배열과 슬라이스 심화 - binary_search
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Reverse;
+
+// ----------------------------------------------------------------------------
+// binary_search
+// ----------------------------------------------------------------------------
+
+fn binary_search() {
+    println!("\n--- binary_search ---");
+
+    // C++ 에서는:
+    // std::binary_search(...)           // bool 만
+    // std::lower_bound(...)             // 위치 - 찾았는지는 다시 비교
+    // → Rust 는 Result<찾은 위치, 넣을 위치> 하나로
+
+    let sorted = [2, 3, 5, 7, 11, 13, 17];
+    println!("binary_search(&7) = {:?}", sorted.binary_search(&7));
+    println!("binary_search(&8) = {:?} (Err = 정렬을 유지하며 넣을 위치)", sorted.binary_search(&8));
+
+    // 정렬을 유지하며 삽입 - Ok/Err 어느 쪽이든 위치
+    let mut v = sorted.to_vec();
+    let at = v.binary_search(&8).unwrap_or_else(|i| i);
+    v.insert(at, 8);
+    println!("8 삽입 후: {:?}", v);
+
+    // 키로 찾기 - 구조체의 정렬 기준 필드
+    let events = [(10, "boot"), (25, "login"), (40, "logout")];
+    match events.binary_search_by_key(&25, |&(t, _)| t) {
+        Ok(i) => println!("시각 25 의 이벤트: {}", events[i].1),
+        Err(_) => println!("시각 25 의 이벤트 없음"),
+    }
+
+    // lower_bound / upper_bound - partition_point (조건이 참인 앞부분의 길이)
+    let scores = [50, 60, 60, 60, 80, 95];
+    let lower = scores.partition_point(|&s| s < 60);
+    let upper = scores.partition_point(|&s| s <= 60);
+    println!("60 의 범위: [{}, {}) → {:?}", lower, upper, &scores[lower..upper]);
+
+    // 정렬되지 않은 슬라이스에서의 결과는 의미 없음 (panic 은 아님) - 미리 정렬
+    debug_assert!(scores.is_sorted());
+    println!("is_sorted() = {} (debug_assert! 로 전제를 적어 두기)", scores.is_sorted());
+}
+
+fn main() {
+    binary_search();
+}
@@ -0,0 +1,59 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 25. 배열과 슬라이스 심화 - chunks 와 windows
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Reverse;
+
+// ----------------------------------------------------------------------------
+// chunks 와 windows
+// ----------------------------------------------------------------------------
+
+fn chunks_and_windows() {
+    println!("\n--- chunks 와 windows ---");
+
+    // C++ 에서는:
+    // for (size_t i = 0; i < v.size(); i += 3) { auto end = std::min(i + 3, v.size()); ... }
+    // std::views::chunk(v, 3), std::views::slide(v, 2)   // C++23
+
+    let data = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // chunks: 겹치지 않게 n 개씩 (마지막은 짧을 수 있음)
+    let groups: Vec<&[i32]> = data.chunks(3).collect();
+    println!("chunks(3): {:?}", groups);
+
+    // chunks_exact: 정확히 n 개씩 + 나머지는 따로 - 경계 검사가 없어 빠른 루프
+    // (n 이 상수면 아래의 as_chunks 가 더 알맞음)
+    let width = data.len() / 2 - 1;
+    let exact = data.chunks_exact(width);
+    println!("chunks_exact({}) 나머지: {:?}", width, exact.remainder());
+
+    // 뒤에서부터: rchunks
+    println!("rchunks(3): {:?}", data.rchunks(3).collect::<Vec<_>>());
+
+    // windows: 겹치며 n 개씩 - 이동 평균, 인접 비교
+    let moving: Vec<f64> = data.windows(3).map(|w| w.iter().sum::<i32>() as f64 / 3.0).collect();
+    println!("windows(3) 이동 평균: {:?}", moving);
+    let sorted = data.windows(2).all(|w| w[0] <= w[1]);
+    println!("정렬되어 있나 (windows(2)): {} = is_sorted() {}", sorted, data.is_sorted());
+
+    // 길이를 타입으로 - as_chunks 는 &[[T; N]] 과 나머지를 돌려줌 (원소마다 경계 검사 없음)
+    let (pairs, rest): (&[[i32; 2]], &[i32]) = data.as_chunks::<2>();
+    println!("as_chunks::<2>(): {:?}, 나머지 {:?}", pairs, rest);
+
+    // 가변 버전 - 블록마다 그 자리에서 고치기
+    let mut pixels = [10u8, 20, 30, 40, 50, 60];
+    for rgb in pixels.as_chunks_mut::<3>().0 {
+        rgb.reverse(); // RGB → BGR
+    }
+    println!("as_chunks_mut 로 RGB → BGR: {:?}", pixels);
+
+    // 값에 따라 나누기 - chunk_by (연속으로 같은 조건인 구간)
+    let runs: Vec<&[i32]> = [1, 1, 2, 3, 3, 3, 1].chunk_by(|a, b| a == b).collect();
+    println!("chunk_by(==): {:?}", runs);
+}
+
+fn main() {
+    chunks_and_windows();
+}
@@ -0,0 +1,60 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 25. 배열과 슬라이스 심화 - rotate 와 select_nth_unstable
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Reverse;
+
+// ----------------------------------------------------------------------------
+// rotate 와 select_nth_unstable
+// ----------------------------------------------------------------------------
+
+fn rotate_and_select() {
+    println!("\n--- rotate 와 select_nth_unstable ---");
+
+    // C++ 에서는:
+    // std::rotate(v.begin(), v.begin() + 2, v.end())   → v.rotate_left(2)
+    // std::nth_element(v.begin(), v.begin() + k, v.end()) → v.select_nth_unstable(k)
+    // std::reverse, std::fill, std::swap_ranges         → reverse, fill, swap_with_slice
+
+    let mut ring = [1, 2, 3, 4, 5];
+    ring.rotate_left(2);
+    println!("rotate_left(2): {:?}", ring);
+    ring.rotate_right(2);
+    println!("rotate_right(2) 로 되돌림: {:?}", ring);
+
+    // 원소 하나를 앞으로 옮기기 - 부분 범위를 회전 (최근 사용 목록)
+    let mut recent = ["a", "b", "c", "d"];
+    recent[..3].rotate_right(1); // "c" 를 맨 앞으로
+    println!("c 를 맨 앞으로: {:?}", recent);
+
+    // select_nth_unstable: k 번째 원소를 제자리에, 앞은 작거나 같고 뒤는 크거나 같음 - 평균 O(n)
+    let mut latencies = [120, 35, 80, 300, 15, 95, 60, 250, 40];
+    let middle = latencies.len() / 2;
+    let (_, median, _) = latencies.select_nth_unstable(middle);
+    println!("중앙값 (정렬 없이): {}", median);
+
+    // 상위 k 개 - 내림차순으로 k-1 번째를 고르면 앞쪽이 상위 k 개 (순서는 보장 안 함)
+    let k = 3;
+    let (top, _, _) = latencies.select_nth_unstable_by_key(k - 1, |&x| Reverse(x));
+    let mut top = top.to_vec();
+    top.push(latencies[k - 1]);
+    top.sort_unstable_by_key(|&x| Reverse(x)); // partial_sort 처럼 앞 k 개만 정렬
+    println!("가장 느린 {}개: {:?}", k, top);
+
+    // 기타 제자리 알고리즘
+    let mut buffer = [0u8; 6];
+    buffer[..3].fill(7);
+    buffer[3..].copy_from_slice(&[1, 2, 3]);
+    buffer.reverse();
+    println!("fill, copy_from_slice, reverse: {:?}", buffer);
+    let (a, b) = buffer.split_at_mut(3);
+    a.swap_with_slice(b);
+    println!("swap_with_slice: {:?}", buffer);
+    println!("contains(&7) = {}, iter().position(== 2) = {:?}", buffer.contains(&7), buffer.iter().position(|&x| x == 2));
+}
+
+fn main() {
+    rotate_and_select();
+}
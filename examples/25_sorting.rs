@@ -0,0 +1,67 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 25. 배열과 슬라이스 심화 - 정렬
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Reverse;
+
+// ----------------------------------------------------------------------------
+// 정렬
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+struct Player {
+    name: &'static str,
+    score: u32,
+    level: u8,
+}
+
+fn sorting() {
+    println!("\n--- 정렬 ---");
+
+    // C++ 에서는:
+    // std::stable_sort(v.begin(), v.end(), cmp)        → v.sort_by(cmp)
+    // std::sort(v.begin(), v.end(), cmp)               → v.sort_unstable_by(cmp)
+    // std::ranges::sort(v, {}, &Player::score)         → v.sort_unstable_by_key(|p| p.score)
+    // std::partial_sort(...)                           → select_nth_unstable 후 정렬 (아래 절)
+
+    let players = vec![
+        Player { name: "ann", score: 90, level: 3 },
+        Player { name: "bob", score: 75, level: 5 },
+        Player { name: "cy", score: 90, level: 1 },
+        Player { name: "dee", score: 60, level: 5 },
+    ];
+
+    // sort: 안정 정렬 (같은 값의 원래 순서 유지), 추가 메모리 사용
+    // sort_unstable: 불안정, 제자리 - 보통 더 빠름 (std::sort 처럼)
+    let mut by_score = players.clone();
+    by_score.sort_unstable_by_key(|p| Reverse(p.score));
+    println!("점수 내림차순 (unstable): {:?}", by_score.iter().map(|p| p.name).collect::<Vec<_>>());
+
+    // 여러 키 - 튜플을 키로 (레벨 내림차순, 같으면 이름 오름차순)
+    let mut by_level = players.clone();
+    by_level.sort_unstable_by_key(|p| (Reverse(p.level), p.name));
+    println!("레벨 내림차순, 이름순: {:?}", by_level.iter().map(|p| (p.level, p.name)).collect::<Vec<_>>());
+
+    // 안정 정렬을 연달아 - 이전 정렬 순서가 동점에서 유지됨
+    let mut stable = players.clone();
+    stable.sort_by_key(|p| p.name);
+    stable.sort_by_key(|p| Reverse(p.score));
+    println!("stable (점수, 동점은 이름순): {:?}", stable.iter().map(|p| p.name).collect::<Vec<_>>());
+
+    // 키를 만드는 비용이 크면 sort_by_cached_key - 원소마다 한 번만 계산
+    let mut words = vec!["Banana", "apple", "Cherry"];
+    words.sort_by_cached_key(|w| w.to_lowercase());
+    println!("sort_by_cached_key(to_lowercase): {:?}", words);
+
+    // 중복 제거 - 정렬 후 dedup (std::unique + erase)
+    let mut tags = vec!["rust", "c++", "rust", "go", "c++"];
+    tags.sort_unstable();
+    tags.dedup();
+    println!("sort + dedup: {:?}", tags);
+}
+
+fn main() {
+    sorting();
+}
@@ -0,0 +1,54 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 25. 배열과 슬라이스 심화 - split_at 과 나누기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Reverse;
+
+// ----------------------------------------------------------------------------
+// split_at 과 나누기
+// ----------------------------------------------------------------------------
+
+fn splitting() {
+    println!("\n--- split_at 과 나누기 ---");
+
+    // C++ 에서는:
+    // std::span first = s.first(3), rest = s.subspan(3);
+    // 같은 vector 의 두 부분을 동시에 고치기 - 컴파일러는 겹침을 확인하지 않음
+
+    let mut numbers = [1, 2, 3, 4, 5, 6];
+    let (left, right) = numbers.split_at(2);
+    println!("split_at(2): {:?} | {:?}", left, right);
+
+    // 겹치지 않는 두 가변 슬라이스 - &mut numbers[..3] 와 &mut numbers[3..] 를 동시에 빌리면 에러
+    let (front, back) = numbers.split_at_mut(3);
+    front[0] += back[0] * 100;
+    back.swap(0, 2);
+    println!("split_at_mut 로 양쪽을 고친 뒤: {:?}", numbers);
+
+    // 범위를 벗어나면 panic - split_at_checked 는 None
+    println!("split_at_checked(10) = {:?}", numbers.split_at_checked(10));
+
+    // 처음/끝 하나와 나머지
+    if let Some((head, tail)) = numbers.split_first() {
+        println!("split_first: {} | {:?}", head, tail);
+    }
+
+    // 값으로 나누기 - split (구분 원소는 빠짐), splitn
+    let line = [3, 0, 4, 5, 0, 0, 6];
+    let parts: Vec<&[i32]> = line.split(|&x| x == 0).collect();
+    println!("split(== 0): {:?}", parts);
+    println!("splitn(2, == 0): {:?}", line.splitn(2, |&x| x == 0).collect::<Vec<_>>());
+
+    // 앞뒤 잘라 내기 - strip_prefix
+    let packet = [0xCA, 0xFE, 1, 2, 3];
+    match packet.strip_prefix(&[0xCA, 0xFE]) {
+        Some(payload) => println!("헤더를 뗀 내용: {:?}", payload),
+        None => println!("헤더 없음"),
+    }
+}
+
+fn main() {
+    splitting();
+}
@@ -0,0 +1,287 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 26. 정적 디스패치 vs 동적 디스패치 벤치마크 - 재 보기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::f64::consts::PI;
+use std::hint::black_box;
+
+// --- rust-study 의 microbench 모듈 ---
+mod microbench {
+// ============================================================================
+// 장 안의 작은 벤치마크 (Micro-benchmark)
+// ============================================================================
+// 레슨이 "더 빠르다", "비용이 없다" 고 말하는 곳에서 수치로 보여 주기 위한 최소한의 측정기
+//
+//   let m = crate::microbench::measure("enum", 1_000, || total(black_box(&shapes)));
+//   println!("{}", crate::microbench::table(&[m, ...]));
+//
+// 같은 조건으로 반복해서 재기: 예열 WARMUP 번 → SAMPLES 번 재고 중앙값 (한두 번 튀는 값에 흔들리지 않음)
+// 반복 횟수는 호출하는 쪽이 정함 (시간으로 맞추지 않음 - 실행마다 같은 일을 하도록)
+// 디버그 빌드의 수치는 최적화 전이라 비교에 쓰지 않음 - cargo run --release -- <장>
+// 정밀한 측정은 criterion 으로 (benches/, cargo bench 뒤 cargo run -- bench-report, 읽는 법은 26장)
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// 재기 전에 버리는 실행 수 - 캐시, 분기 예측기, CPU 클럭을 데움
+pub const WARMUP: usize = 3;
+// 재는 실행 수 - 홀수라서 중앙값이 하나
+pub const SAMPLES: usize = 11;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    // 한 번 실행(iterations 번 호출)의 중앙값과 최솟값
+    pub median: Duration,
+    pub min: Duration,
+    pub iterations: u32,
+}
+
+impl Measurement {
+    // 호출 한 번의 중앙값 (나노초)
+    pub fn per_call_ns(&self) -> f64 {
+        self.median.as_nanos() as f64 / self.iterations.max(1) as f64
+    }
+}
+
+// f 를 iterations 번 호출하는 실행을 WARMUP + SAMPLES 번 - 결과는 black_box 로 버려서 최적화로 사라지지 않게
+pub fn measure<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) -> Measurement {
+    let mut run = || {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(f());
+        }
+        start.elapsed()
+    };
+    for _ in 0..WARMUP {
+        run();
+    }
+    let mut samples: Vec<Duration> = (0..SAMPLES).map(|_| run()).collect();
+    samples.sort_unstable();
+    Measurement {
+        name: name.to_string(),
+        median: samples[SAMPLES / 2],
+        min: samples[0],
+        iterations,
+    }
+}
+
+// 이름, 호출당 시간, 가장 빠른 것 대비 배율 - 측정한 순서대로
+pub fn table(results: &[Measurement]) -> String {
+    let fastest = results
+        .iter()
+        .map(Measurement::per_call_ns)
+        .fold(f64::INFINITY, f64::min)
+        .max(f64::MIN_POSITIVE);
+    let width = results.iter().map(|m| m.name.chars().count()).max().unwrap_or(0);
+    results
+        .iter()
+        .map(|m| {
+            format!(
+                "  {:width$}  {:>12}  x{:.2}",
+                m.name,
+                format_ns(m.per_call_ns()),
+                m.per_call_ns() / fastest,
+                width = width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn format_ns(ns: f64) -> String {
+    if ns >= 1_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.2} µs", ns / 1_000.0)
+    } else {
+        format!("{:.1} ns", ns)
+    }
+}
+
+// 이 실행이 최적화 빌드인지 - 표 위에 함께 보여 줌
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "디버그 빌드 - 수치는 참고만 (cargo run --release 로 다시 재 보세요)"
+    } else {
+        "릴리스 빌드"
+    }
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Circle {
+    radius: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    w: f64,
+    h: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Triangle {
+    base: f64,
+    height: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+}
+
+impl Shape for Rect {
+    fn area(&self) -> f64 {
+        self.w * self.h
+    }
+}
+
+impl Shape for Triangle {
+    fn area(&self) -> f64 {
+        0.5 * self.base * self.height
+    }
+}
+
+fn total_generic<S: Shape>(shapes: &[S]) -> f64 {
+    shapes.iter().map(Shape::area).sum()
+}
+
+fn total_dyn(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|s| s.area()).sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AnyShape {
+    Circle(Circle),
+    Rect(Rect),
+    Triangle(Triangle),
+}
+
+impl AnyShape {
+    fn area(&self) -> f64 {
+        match self {
+            AnyShape::Circle(c) => c.area(),
+            AnyShape::Rect(r) => r.area(),
+            AnyShape::Triangle(t) => t.area(),
+        }
+    }
+}
+
+fn total_enum(shapes: &[AnyShape]) -> f64 {
+    shapes.iter().map(AnyShape::area).sum()
+}
+
+struct Workload {
+    circles: Vec<Circle>,
+    rects: Vec<Rect>,
+    triangles: Vec<Triangle>,
+    boxed: Vec<Box<dyn Shape>>,
+    enums: Vec<AnyShape>,
+}
+
+impl Workload {
+    fn new(count: usize) -> Workload {
+        let mut w = Workload {
+            circles: Vec::new(),
+            rects: Vec::new(),
+            triangles: Vec::new(),
+            boxed: Vec::new(),
+            enums: Vec::new(),
+        };
+        for i in 0..count {
+            let size = (i % 10) as f64 + 1.0;
+            // 세 종류를 섞어서 - dyn 과 enum 은 호출마다 다른 구현으로 감
+            match i % 3 {
+                0 => {
+                    let c = Circle { radius: size };
+                    w.circles.push(c);
+                    w.boxed.push(Box::new(c));
+                    w.enums.push(AnyShape::Circle(c));
+                }
+                1 => {
+                    let r = Rect { w: size, h: size * 2.0 };
+                    w.rects.push(r);
+                    w.boxed.push(Box::new(r));
+                    w.enums.push(AnyShape::Rect(r));
+                }
+                _ => {
+                    let t = Triangle { base: size, height: size };
+                    w.triangles.push(t);
+                    w.boxed.push(Box::new(t));
+                    w.enums.push(AnyShape::Triangle(t));
+                }
+            }
+        }
+        w
+    }
+
+    fn generic(&self) -> f64 {
+        total_generic(&self.circles) + total_generic(&self.rects) + total_generic(&self.triangles)
+    }
+
+    fn dynamic(&self) -> f64 {
+        total_dyn(&self.boxed)
+    }
+
+    fn enumerated(&self) -> f64 {
+        total_enum(&self.enums)
+    }
+}
+
+const SHAPES: usize = 3_000;
+
+const ITERATIONS: u32 = 20;
+
+// ----------------------------------------------------------------------------
+// 재 보기
+// ----------------------------------------------------------------------------
+
+fn measuring() {
+    println!("\n--- 재 보기 ---");
+
+    let w = Workload::new(SHAPES);
+    println!("{}", microbench::build_profile());
+    println!(
+        "도형 {}개의 합계를 {}번씩, 예열 {}번 후 {}번 재서 중앙값 (합계 한 번당 시간)",
+        SHAPES,
+        ITERATIONS,
+        microbench::WARMUP,
+        microbench::SAMPLES
+    );
+
+    // black_box: 컴파일러가 입력을 상수로 보고 결과를 미리 계산하지 못하게
+    let results = [
+        microbench::measure("제네릭 (단형화)", ITERATIONS, || black_box(&w).generic()),
+        microbench::measure("Box<dyn Shape>", ITERATIONS, || black_box(&w).dynamic()),
+        microbench::measure("enum + match", ITERATIONS, || black_box(&w).enumerated()),
+    ];
+    println!("{}", microbench::table(&results));
+
+    // 도형 하나당
+    for m in &results {
+        println!("  {}: 도형 하나당 {:.2} ns", m.name, m.per_call_ns() / SHAPES as f64);
+    }
+
+    // 흔히 보는 릴리스 빌드 결과 (기계마다 다름):
+    //   제네릭 < enum < dyn - dyn 은 인라인이 안 되고 원소마다 포인터를 따라감
+    //   제네릭은 타입별로 나눈 덕에 분기도 없음 - SIMD 로 벡터화되기도 함
+    //   enum 은 인라인되지만 원소마다 match 분기
+    // 디버그 빌드에서는 인라인이 꺼져서 셋 다 비슷하게 느림 - "비용이 없다" 는 최적화 후의 이야기
+}
+
+fn main() {
+    measuring();
+}
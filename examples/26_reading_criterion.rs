@@ -0,0 +1,226 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 26. 정적 디스패치 vs 동적 디스패치 벤치마크 - criterion 결과 읽기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::f64::consts::PI;
+use std::hint::black_box;
+
+// --- rust-study 의 microbench 모듈 ---
+mod microbench {
+// ============================================================================
+// 장 안의 작은 벤치마크 (Micro-benchmark)
+// ============================================================================
+// 레슨이 "더 빠르다", "비용이 없다" 고 말하는 곳에서 수치로 보여 주기 위한 최소한의 측정기
+//
+//   let m = crate::microbench::measure("enum", 1_000, || total(black_box(&shapes)));
+//   println!("{}", crate::microbench::table(&[m, ...]));
+//
+// 같은 조건으로 반복해서 재기: 예열 WARMUP 번 → SAMPLES 번 재고 중앙값 (한두 번 튀는 값에 흔들리지 않음)
+// 반복 횟수는 호출하는 쪽이 정함 (시간으로 맞추지 않음 - 실행마다 같은 일을 하도록)
+// 디버그 빌드의 수치는 최적화 전이라 비교에 쓰지 않음 - cargo run --release -- <장>
+// 정밀한 측정은 criterion 으로 (benches/, cargo bench 뒤 cargo run -- bench-report, 읽는 법은 26장)
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// 재기 전에 버리는 실행 수 - 캐시, 분기 예측기, CPU 클럭을 데움
+pub const WARMUP: usize = 3;
+// 재는 실행 수 - 홀수라서 중앙값이 하나
+pub const SAMPLES: usize = 11;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    // 한 번 실행(iterations 번 호출)의 중앙값과 최솟값
+    pub median: Duration,
+    pub min: Duration,
+    pub iterations: u32,
+}
+
+impl Measurement {
+    // 호출 한 번의 중앙값 (나노초)
+    pub fn per_call_ns(&self) -> f64 {
+        self.median.as_nanos() as f64 / self.iterations.max(1) as f64
+    }
+}
+
+// f 를 iterations 번 호출하는 실행을 WARMUP + SAMPLES 번 - 결과는 black_box 로 버려서 최적화로 사라지지 않게
+pub fn measure<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) -> Measurement {
+    let mut run = || {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(f());
+        }
+        start.elapsed()
+    };
+    for _ in 0..WARMUP {
+        run();
+    }
+    let mut samples: Vec<Duration> = (0..SAMPLES).map(|_| run()).collect();
+    samples.sort_unstable();
+    Measurement {
+        name: name.to_string(),
+        median: samples[SAMPLES / 2],
+        min: samples[0],
+        iterations,
+    }
+}
+
+// 이름, 호출당 시간, 가장 빠른 것 대비 배율 - 측정한 순서대로
+pub fn table(results: &[Measurement]) -> String {
+    let fastest = results
+        .iter()
+        .map(Measurement::per_call_ns)
+        .fold(f64::INFINITY, f64::min)
+        .max(f64::MIN_POSITIVE);
+    let width = results.iter().map(|m| m.name.chars().count()).max().unwrap_or(0);
+    results
+        .iter()
+        .map(|m| {
+            format!(
+                "  {:width$}  {:>12}  x{:.2}",
+                m.name,
+                format_ns(m.per_call_ns()),
+                m.per_call_ns() / fastest,
+                width = width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn format_ns(ns: f64) -> String {
+    if ns >= 1_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.2} µs", ns / 1_000.0)
+    } else {
+        format!("{:.1} ns", ns)
+    }
+}
+
+// 이 실행이 최적화 빌드인지 - 표 위에 함께 보여 줌
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "디버그 빌드 - 수치는 참고만 (cargo run --release 로 다시 재 보세요)"
+    } else {
+        "릴리스 빌드"
+    }
+}
+}
+
+// ----------------------------------------------------------------------------
+// criterion 결과 읽기
+// ----------------------------------------------------------------------------
+
+// 레슨의 성능 주장마다 benches/ 에 criterion 벤치마크가 있음
+//   benches/iterators.rs  11장 "이터레이터는 수동 루프와 같은 성능"
+//   benches/hashing.rs    10장 "SipHash 는 느림, 빠른 해셔는 fnv"
+//   benches/rc_clone.rs   12장 "Rc::clone 은 카운트만 증가"
+// cargo bench --bench iterators → target/criterion/ 에 결과 → cargo run -- bench-report 로 표
+
+// cargo bench 가 벤치마크마다 출력하는 모양 (한 기계에서 잰 예시)
+const SAMPLE_OUTPUT: &str = "\
+11_iterator_adaptors/iterator_chain/100000
+                        time:   [95.924 µs 98.351 µs 102.41 µs]
+                        change: [-1.8211% +0.6125% +3.1893%] (p = 0.64 > 0.05)
+                        No change in performance detected.
+Found 6 outliers among 100 measurements (6.00%)
+  4 (4.00%) high mild
+  2 (2.00%) high severe";
+
+// [낮은 값, 추정값, 높은 값] - 평균이 95% 확률로 이 구간 안 (신뢰 구간)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Interval {
+    low: f64,
+    estimate: f64,
+    high: f64,
+}
+
+// "time:   [95.924 µs 98.351 µs 102.41 µs]" → 나노초 구간
+fn parse_time(line: &str) -> Option<Interval> {
+    let inside = line.split_once('[')?.1.split_once(']')?.0;
+    let parts: Vec<&str> = inside.split_whitespace().collect();
+    let ns = |value: &str, unit: &str| {
+        let scale = match unit {
+            "ps" => 1e-3,
+            "ns" => 1.0,
+            "µs" | "us" => 1e3,
+            "ms" => 1e6,
+            "s" => 1e9,
+            _ => return None,
+        };
+        value.parse::<f64>().ok().map(|v| v * scale)
+    };
+    match parts.as_slice() {
+        [a, ua, b, ub, c, uc] => Some(Interval { low: ns(a, ua)?, estimate: ns(b, ub)?, high: ns(c, uc)? }),
+        _ => None,
+    }
+}
+
+// 두 구간이 겹치면 이 측정으로는 구분할 수 없음 - 추정값만 비교하면 잡음을 차이로 착각
+fn compare(name_a: &str, a: Interval, name_b: &str, b: Interval) -> String {
+    if a.high >= b.low && b.high >= a.low {
+        format!("{} 와 {}: 구간이 겹침 - 차이를 말할 수 없음", name_a, name_b)
+    } else if a.estimate < b.estimate {
+        format!("{} 가 약 {:.1}배 빠름", name_a, b.estimate / a.estimate)
+    } else {
+        format!("{} 가 약 {:.1}배 빠름", name_b, a.estimate / b.estimate)
+    }
+}
+
+fn reading_criterion() {
+    println!("\n--- criterion 결과 읽기 ---");
+
+    // C++ 에서는: Google Benchmark - BENCHMARK(BM_loop)->Range(1000, 100000);
+    // 출력은 평균과 CPU 시간 한 줄 - 반복 실행(--benchmark_repetitions)과 통계는 따로 켬
+    // criterion 은 기본이 통계: 예열 → 100번 표본 → 부트스트랩으로 신뢰 구간, 직전 실행과 비교
+
+    for line in SAMPLE_OUTPUT.lines() {
+        println!("  | {}", line);
+    }
+
+    // 한 줄씩 읽기
+    //   time:    [낮은 값 추정값 높은 값] - 반복 한 번의 평균 시간, 95% 신뢰 구간
+    //   change:  직전 실행(또는 --baseline) 대비 변화의 신뢰 구간
+    //            p < 0.05 이고 구간이 잡음 문턱(기본 2%)을 벗어나야 "improved/regressed"
+    //   outliers: 튄 표본 - 다른 프로그램, CPU 클럭 변화, 인터럽트 (high severe 가 많으면 환경을 의심)
+    if let Some(time) = SAMPLE_OUTPUT.lines().find_map(parse_time) {
+        println!(
+            "구간 폭: {:.1}% (좁을수록 안정된 측정)",
+            (time.high - time.low) / time.estimate * 100.0
+        );
+    }
+
+    // 두 벤치마크를 비교할 때는 추정값이 아니라 구간으로
+    let samples = [
+        ("for_loop", "time:   [84.031 µs 84.951 µs 86.027 µs]"),
+        ("iterator_chain", "time:   [95.924 µs 98.351 µs 102.41 µs]"),
+        ("index_loop", "time:   [96.067 µs 97.494 µs 99.074 µs]"),
+        ("rc_clone", "time:   [1.5907 ns 1.6424 ns 1.6927 ns]"),
+        ("arc_clone", "time:   [19.081 ns 19.497 ns 19.932 ns]"),
+    ];
+    let get = |name: &str| samples.iter().find(|(n, _)| *n == name).and_then(|(_, line)| parse_time(line));
+    for (a, b) in [("iterator_chain", "index_loop"), ("for_loop", "iterator_chain"), ("rc_clone", "arc_clone")] {
+        if let (Some(x), Some(y)) = (get(a), get(b)) {
+            println!("{}", compare(a, x, b, y));
+        }
+    }
+
+    // 숫자를 믿기 전에
+    //   1. 릴리스 빌드인가 - cargo bench 는 항상 bench 프로필(최적화)
+    //   2. 같은 답을 내는가 - benches/iterators.rs 는 재기 전에 assert_eq!
+    //   3. 최적화기가 일을 지우지 않았나 - 입력과 결과에 black_box (1ns 아래면 의심)
+    //   4. 한 번 더 재도 같은가 - 노트북의 전원 설정, 다른 프로그램 (--save-baseline 으로 기준선을 남겨 비교)
+    // "iterator_chain 이 for_loop 보다 느림" 처럼 나와도 결론 전에 cargo asm 이나 godbolt 로 기계어를 보기
+    println!("직접 재기: cargo bench --bench iterators → cargo run -- bench-report");
+}
+
+fn main() {
+    reading_criterion();
+}
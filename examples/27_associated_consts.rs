@@ -0,0 +1,90 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 27. 고급 트레이트 - 연관 상수
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+use std::ops::Add;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait Shout {
+    fn shout(&self) -> String;
+}
+
+impl<T: Display + ?Sized> Shout for T {
+    fn shout(&self) -> String {
+        format!("{}!", self.to_string().to_uppercase())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 연관 상수
+// ----------------------------------------------------------------------------
+
+// 타입마다 정해진 값 - 인스턴스 없이 T::BITS 처럼 읽음
+trait Codec {
+    const NAME: &'static str;
+    const BLOCK: usize;
+    // 기본값도 가능 - 구현이 덮어쓸 수 있음
+    const LOSSY: bool = false;
+}
+
+struct Base64;
+struct Hex;
+struct Jpeg;
+
+impl Codec for Base64 {
+    const NAME: &'static str = "base64";
+    const BLOCK: usize = 3;
+}
+
+impl Codec for Hex {
+    const NAME: &'static str = "hex";
+    const BLOCK: usize = 1;
+}
+
+impl Codec for Jpeg {
+    const NAME: &'static str = "jpeg";
+    const BLOCK: usize = 64;
+    const LOSSY: bool = true;
+}
+
+// 입력을 블록 단위로 채우면 몇 바이트가 되는지 - 타입만 보고 계산
+fn padded_len<C: Codec>(len: usize) -> usize {
+    len.div_ceil(C::BLOCK) * C::BLOCK
+}
+
+fn associated_consts() {
+    println!("--- 연관 상수 ---");
+
+    // C++ 에서는:
+    // struct Base64 { static constexpr std::string_view name = "base64"; static constexpr size_t block = 3; };
+    // template <class C> requires requires { C::block; }   // 있는지는 concept 으로 따로 확인
+    // size_t padded_len(size_t len) { return (len + C::block - 1) / C::block * C::block; }
+
+    fn describe<C: Codec>() {
+        println!(
+            "{:8} 블록 {:2}바이트, 손실 압축? {:5}, 10바이트 → {}바이트",
+            C::NAME,
+            C::BLOCK,
+            C::LOSSY,
+            padded_len::<C>(10)
+        );
+    }
+    describe::<Base64>();
+    describe::<Hex>();
+    describe::<Jpeg>();
+
+    // 표준 라이브러리의 연관 상수
+    println!("u8::MAX = {}, i32::BITS = {}, f64::EPSILON = {:e}", u8::MAX, i32::BITS, f64::EPSILON);
+
+    // 제한: 제네릭 안에서 배열 길이로 쓰기 ([u8; C::BLOCK]) 는 아직 안정화되지 않음
+    //   → const 제네릭 매개변수로 받거나 (8장 const_generics), Vec 을 씀
+    // 연관 상수가 있는 트레이트는 dyn 으로 쓸 수 없음 - 값이 타입에 묶여 있어서 vtable 에 못 넣음
+}
+
+fn main() {
+    associated_consts();
+}
@@ -0,0 +1,61 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 27. 고급 트레이트 - 포괄 구현 (blanket impl)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+use std::ops::Add;
+
+// ----------------------------------------------------------------------------
+// 포괄 구현 (blanket impl)
+// ----------------------------------------------------------------------------
+
+// Display 를 구현한 모든 타입에 한 번에
+trait Shout {
+    fn shout(&self) -> String;
+}
+
+impl<T: Display + ?Sized> Shout for T {
+    fn shout(&self) -> String {
+        format!("{}!", self.to_string().to_uppercase())
+    }
+}
+
+// 확장 트레이트 - 남의 타입(표준 라이브러리의 반복자)에 메서드를 더하는 흔한 방법
+trait SumBy: Iterator + Sized {
+    fn sum_by<F: FnMut(&Self::Item) -> i64>(self, mut f: F) -> i64 {
+        self.map(|x| f(&x)).sum()
+    }
+}
+
+impl<I: Iterator> SumBy for I {}
+
+fn blanket_impls() {
+    println!("\n--- 포괄 구현 ---");
+
+    // C++ 에서는 (CRTP 믹스인):
+    // template <class Derived> struct Shout {
+    //     std::string shout() const { return upper(static_cast<const Derived&>(*this).to_string()) + "!"; }
+    // };
+    // struct Name : Shout<Name> { ... };   // 타입마다 상속을 적어야 하고, int 같은 기본 타입에는 못 붙임
+
+    // i32, &str, String 모두 Display 라서 바로 사용
+    println!("{} / {} / {}", "hello".shout(), 42.shout(), String::from("rust").shout());
+
+    let words = ["a", "bb", "ccc"];
+    println!("길이 합: {}", words.iter().sum_by(|w| w.len() as i64));
+
+    // 표준 라이브러리의 포괄 구현
+    //   impl<T: Display + ?Sized> ToString for T   → Display 만 구현하면 to_string() 이 생김
+    //   impl<T, U: From<T>> Into<U> for T          → From 만 구현하면 into() 도 생김
+    //   impl<I: Iterator> IntoIterator for I
+
+    // 일관성(coherence): 한 타입에 같은 트레이트 구현은 하나뿐
+    //   포괄 구현이 있으면 그와 겹치는 구현은 추가 불가 (특수화는 불안정)
+    //   고아 규칙: 트레이트나 타입 중 하나는 내 크레이트 것이어야 함
+}
+
+fn main() {
+    blanket_impls();
+}
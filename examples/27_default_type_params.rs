@@ -0,0 +1,75 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 27. 고급 트레이트 - 기본 타입 매개변수
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+use std::ops::Add;
+
+// ----------------------------------------------------------------------------
+// 기본 타입 매개변수
+// ----------------------------------------------------------------------------
+
+// 표준 라이브러리: trait Add<Rhs = Self> { type Output; fn add(self, rhs: Rhs) -> Self::Output; }
+// Rhs 를 적지 않으면 Self - 대부분은 같은 타입끼리 더하므로
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Millimeters(u32);
+
+// Add 만 적음 = Add<Meters>
+impl Add for Meters {
+    type Output = Meters;
+    fn add(self, rhs: Meters) -> Meters {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+// 다른 타입과 더하기 - 기본값 대신 Rhs 를 적음
+impl Add<Millimeters> for Meters {
+    type Output = Meters;
+    fn add(self, rhs: Millimeters) -> Meters {
+        Meters(self.0 + rhs.0 as f64 / 1000.0)
+    }
+}
+
+// 직접 만드는 트레이트에도 - 기존 구현을 깨지 않고 매개변수를 추가할 때 유용
+trait Scale<Factor = f64> {
+    fn scale(&self, by: Factor) -> Self;
+}
+
+impl Scale for Meters {
+    fn scale(&self, by: f64) -> Meters {
+        Meters(self.0 * by)
+    }
+}
+
+impl Scale<u32> for Millimeters {
+    fn scale(&self, by: u32) -> Millimeters {
+        Millimeters(self.0 * by)
+    }
+}
+
+fn default_type_params() {
+    println!("\n--- 기본 타입 매개변수 ---");
+
+    // C++ 에서는:
+    // template <class Rhs = Meters> Meters operator+(Meters lhs, Rhs rhs);
+    // 또는 오버로드 두 개 - Rust 는 오버로드가 없으므로 트레이트의 타입 매개변수로 구분
+
+    let a = Meters(1.5) + Meters(2.0);
+    let b = Meters(1.5) + Millimeters(250);
+    println!("Meters + Meters = {:?}", a);
+    println!("Meters + Millimeters = {:?}", b);
+    println!("scale: {:?}, {:?}", Meters(2.0).scale(1.5), Millimeters(40).scale(3));
+
+    // 반대 방향(Millimeters + Meters)은 따로 구현하지 않으면 컴파일 에러
+    //   C++ 처럼 암시적 변환으로 맞춰 주지 않음
+}
+
+fn main() {
+    default_type_params();
+}
@@ -0,0 +1,112 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 27. 고급 트레이트 - GAT (generic associated types)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+use std::ops::Add;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait Shout {
+    fn shout(&self) -> String;
+}
+
+impl<T: Display + ?Sized> Shout for T {
+    fn shout(&self) -> String {
+        format!("{}!", self.to_string().to_uppercase())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// GAT (generic associated types)
+// ----------------------------------------------------------------------------
+
+// 빌려주는 반복자 - 다음 항목이 반복자 자신을 빌림
+//   Iterator 의 type Item 은 수명을 받을 수 없어서 이런 반복자를 표현 못 함
+trait LendingIterator {
+    type Item<'a>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+// 버퍼의 겹치는 창을 &mut 로 하나씩 - windows() 의 가변 버전은 Iterator 로 만들 수 없음
+struct WindowsMut<'s, T> {
+    slice: &'s mut [T],
+    size: usize,
+    start: usize,
+}
+
+impl<'s, T> LendingIterator for WindowsMut<'s, T> {
+    type Item<'a>
+        = &'a mut [T]
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<&mut [T]> {
+        let window = self.slice.get_mut(self.start..self.start + self.size)?;
+        self.start += 1;
+        Some(window)
+    }
+}
+
+fn windows_mut<T>(slice: &mut [T], size: usize) -> WindowsMut<'_, T> {
+    WindowsMut { slice, size, start: 0 }
+}
+
+// 타입 매개변수를 받는 연관 타입 - 어떤 컨테이너 "종류" 를 고를지 추상화
+trait Family {
+    type Member<T>;
+
+    fn wrap<T>(value: T) -> Self::Member<T>;
+}
+
+struct BoxFamily;
+struct VecFamily;
+
+impl Family for BoxFamily {
+    type Member<T> = Box<T>;
+
+    fn wrap<T>(value: T) -> Box<T> {
+        Box::new(value)
+    }
+}
+
+impl Family for VecFamily {
+    type Member<T> = Vec<T>;
+
+    fn wrap<T>(value: T) -> Vec<T> {
+        vec![value]
+    }
+}
+
+fn generic_associated_types() {
+    println!("\n--- GAT ---");
+
+    // C++ 에서는:
+    // template <class T> struct Allocator { template <class U> using rebind = Allocator<U>; };
+    // 멤버 별칭 템플릿 - 제약이 없어서 잘못 쓰면 인스턴스화 때 에러
+
+    // 앞 창을 고치면 다음 창에 반영됨 - 창마다 앞의 두 값을 더해 세 번째에 (피보나치처럼)
+    let mut values = [1, 1, 0, 0, 0, 0];
+    let mut windows = windows_mut(&mut values, 3);
+    while let Some(w) = windows.next() {
+        w[2] = w[0] + w[1];
+    }
+    println!("windows_mut 로 채운 값: {:?}", values);
+
+    let boxed: <BoxFamily as Family>::Member<i32> = BoxFamily::wrap(7);
+    let listed = VecFamily::wrap("seven");
+    println!("BoxFamily: {:?}, VecFamily: {:?}", boxed, listed);
+
+    // where Self: 'a - "Item<'a> 는 반복자가 'a 동안 살아 있을 때만" 이라는 조건
+    //   컴파일러가 요구하므로 거의 항상 적음
+    // 한계: for 문, map/filter 같은 어댑터는 Iterator 전용 - LendingIterator 는 while let 으로
+}
+
+fn main() {
+    generic_associated_types();
+}
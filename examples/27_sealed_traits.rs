@@ -0,0 +1,76 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 27. 고급 트레이트 - 봉인된 트레이트 (sealed trait)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt::Display;
+use std::ops::Add;
+
+// ----------------------------------------------------------------------------
+// 봉인된 트레이트 (sealed trait)
+// ----------------------------------------------------------------------------
+
+// 라이브러리라고 생각하고 - 밖에서는 Unit 을 쓸 수 있지만 구현은 이 모듈의 타입만
+mod units {
+    // 비공개 모듈의 공개 트레이트 - 밖에서는 이름을 부를 수 없음
+    mod private {
+        pub trait Sealed {}
+    }
+
+    pub trait Unit: private::Sealed {
+        const SYMBOL: &'static str;
+        const PER_BASE: f64;
+
+        fn to_base(value: f64) -> f64 {
+            value * Self::PER_BASE
+        }
+    }
+
+    pub struct Gram;
+    pub struct Kilogram;
+
+    impl private::Sealed for Gram {}
+    impl private::Sealed for Kilogram {}
+
+    impl Unit for Gram {
+        const SYMBOL: &'static str = "g";
+        const PER_BASE: f64 = 1.0;
+    }
+
+    impl Unit for Kilogram {
+        const SYMBOL: &'static str = "kg";
+        const PER_BASE: f64 = 1000.0;
+    }
+}
+
+use units::Unit;
+
+fn in_grams<U: Unit>(value: f64) -> String {
+    format!("{}{} = {}g", value, U::SYMBOL, U::to_base(value))
+}
+
+fn sealed_traits() {
+    println!("\n--- 봉인된 트레이트 ---");
+
+    // C++ 에는 직접 대응 없음
+    //   비슷한 효과: 생성자를 private 으로 두고 friend 로 허용한 타입만 상속 가능하게
+    //   또는 concept 에 "허용 목록" 을 적음 - 둘 다 우회가 쉬움
+
+    println!("{}", in_grams::<units::Gram>(250.0));
+    println!("{}", in_grams::<units::Kilogram>(1.5));
+
+    // 밖에서 구현하려고 하면:
+    //   struct Pound;
+    //   impl units::Unit for Pound { ... }
+    //   → error: the trait bound `Pound: Sealed` is not satisfied (Sealed 는 이름조차 부를 수 없음)
+
+    // 왜 봉인하는가:
+    //   1. 나중에 트레이트에 메서드를 추가해도 사용자 코드가 깨지지 않음 (구현한 사람이 나뿐이므로)
+    //   2. 구현 목록이 닫혀 있다는 것을 믿고 최적화하거나 unsafe 코드를 쓸 수 있음
+    // 표준 라이브러리 예: std::slice::SliceIndex, 여러 크레이트의 private::Sealed
+}
+
+fn main() {
+    sealed_traits();
+}
@@ -0,0 +1,85 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 28. 클로저 심화 - 구조체에 콜백 저장
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+
+// ----------------------------------------------------------------------------
+// 구조체에 콜백 저장
+// ----------------------------------------------------------------------------
+
+// 1. 제네릭 - 콜백 하나, 타입이 고정, 호출 비용 없음
+struct Button<F: Fn(&str)> {
+    label: String,
+    on_click: F,
+}
+
+impl<F: Fn(&str)> Button<F> {
+    fn click(&self) {
+        (self.on_click)(&self.label);
+    }
+}
+
+// 2. Box<dyn FnMut> 목록 - 종류가 다른 콜백 여럿 (std::vector<std::function<void(...)>>)
+//    'a: 콜백이 빌린 값보다 오래 살 수 없음
+type Handler<'a> = Box<dyn FnMut(&str) + 'a>;
+
+#[derive(Default)]
+struct EventBus<'a> {
+    handlers: Vec<(String, Handler<'a>)>,
+}
+
+impl<'a> EventBus<'a> {
+    fn subscribe(&mut self, topic: &str, handler: impl FnMut(&str) + 'a) {
+        self.handlers.push((topic.to_string(), Box::new(handler)));
+    }
+
+    // 받은 핸들러 수
+    fn publish(&mut self, topic: &str, message: &str) -> usize {
+        let mut delivered = 0;
+        for (t, handler) in &mut self.handlers {
+            if t == topic {
+                handler(message);
+                delivered += 1;
+            }
+        }
+        delivered
+    }
+}
+
+fn callbacks_in_structs() {
+    println!("\n--- 구조체에 콜백 저장 ---");
+
+    // C++ 에서는:
+    // struct EventBus { std::vector<std::pair<std::string, std::function<void(std::string_view)>>> handlers; };
+    // bus.subscribe("log", [&log](auto m) { log.push_back(m); });  // log 가 먼저 사라지면 매달린 참조
+
+    let button = Button { label: String::from("저장"), on_click: |label: &str| println!("'{}' 클릭", label) };
+    button.click();
+
+    let mut log: Vec<String> = Vec::new();
+    let mut count = 0;
+    {
+        let mut bus = EventBus::default();
+        bus.subscribe("log", |m| log.push(m.to_string()));
+        bus.subscribe("log", |_| count += 1);
+        bus.subscribe("alert", |m| println!("  경보: {}", m));
+
+        println!("log 전달: {}곳", bus.publish("log", "시작"));
+        bus.publish("log", "끝");
+        println!("alert 전달: {}곳", bus.publish("alert", "디스크 90%"));
+        // bus 가 log, count 를 빌리는 동안에는 여기서 읽을 수 없음 - 블록이 끝나면 빌림도 끝
+    }
+    println!("log = {:?}, count = {}", log, count);
+
+    // 고르는 기준
+    //   콜백 하나, 타입이 정해짐 → 제네릭 (Button<F>)
+    //   여러 개, 실행 중에 추가 → Box<dyn FnMut>
+    //   다른 스레드로 보냄 → Box<dyn FnMut + Send + 'static> (빌린 캡처 불가, move 로 소유)
+}
+
+fn main() {
+    callbacks_in_structs();
+}
@@ -0,0 +1,56 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 28. 클로저 심화 - 필드 단위 캡처 (2021 에디션)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+
+// ----------------------------------------------------------------------------
+// 필드 단위 캡처 (2021 에디션)
+// ----------------------------------------------------------------------------
+
+struct Editor {
+    text: String,
+    history: Vec<String>,
+    cursor: usize,
+}
+
+impl Editor {
+    // 클로저가 self.history 만 바꾸고 self.text 는 읽기만
+    //   2018 에디션에서는 클로저가 self 전체를 가변으로 빌려서 self.text 를 읽을 수 없었음
+    fn snapshot_words(&mut self) -> usize {
+        let mut record = |word: &str| self.history.push(word.to_string());
+        for word in self.text.split_whitespace() {
+            record(word);
+        }
+        self.history.len()
+    }
+}
+
+fn disjoint_captures() {
+    println!("\n--- 필드 단위 캡처 ---");
+
+    // C++ 에서는:
+    // [this] 는 객체 전체를 캡처 - 어떤 멤버를 쓰는지는 캡처와 상관없음
+    // [&h = history] 처럼 초기화 캡처로 멤버 하나만 잡을 수 있음 (C++14)
+
+    let mut editor = Editor { text: String::from("hello closure world"), history: Vec::new(), cursor: 0 };
+    println!("기록한 단어: {}개", editor.snapshot_words());
+
+    // 지역 변수에서도 - 구조체의 다른 필드를 동시에 빌릴 수 있음
+    let mut bump = || editor.cursor += 1;
+    bump();
+    bump();
+    // bump 가 editor.cursor 만 빌리므로 editor.text 는 여기서도 읽을 수 있음 (마지막 bump 호출 전이라도)
+    println!("text = {:?}, history = {:?}", editor.text, editor.history);
+    bump();
+    println!("cursor = {}", editor.cursor);
+
+    // 주의: 드롭 순서가 바뀔 수 있음 - 필드만 캡처하면 구조체 나머지는 원래 자리에서 드롭
+    //   cargo fix --edition 이 필요한 곳에 let _ = &x; 를 넣어 2018 동작을 유지
+}
+
+fn main() {
+    disjoint_captures();
+}
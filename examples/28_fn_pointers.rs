@@ -0,0 +1,81 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 28. 클로저 심화 - fn 포인터 vs 클로저
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+
+// --- 다른 절에서 가져온 정의 ---
+
+struct Button<F: Fn(&str)> {
+    label: String,
+    on_click: F,
+}
+
+impl<F: Fn(&str)> Button<F> {
+    fn click(&self) {
+        (self.on_click)(&self.label);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// fn 포인터 vs 클로저
+// ----------------------------------------------------------------------------
+
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+fn square(x: i32) -> i32 {
+    x * x
+}
+
+// fn(i32) -> i32 는 타입 (트레이트가 아님) - 크기는 포인터 하나, 캡처 없음
+fn apply_all(fs: &[fn(i32) -> i32], x: i32) -> Vec<i32> {
+    fs.iter().map(|f| f(x)).collect()
+}
+
+fn fn_pointers() {
+    println!("\n--- fn 포인터 vs 클로저 ---");
+
+    // C++ 에서는:
+    // int (*fp)(int) = double_it;
+    // int (*fp2)(int) = [](int x) { return x + 1; };   // 캡처 없는 람다는 변환됨
+    // int k = 3; fp2 = [k](int x) { return x + k; };  // 에러 - 캡처가 있으면 안 됨
+
+    // 함수와 캡처 없는 클로저를 한 배열에
+    let table: [fn(i32) -> i32; 3] = [double, square, |x| x + 1];
+    println!("apply_all(7) = {:?}", apply_all(&table, 7));
+
+    // 캡처가 있으면 fn 포인터가 아님
+    //   let k = 3;
+    //   let f: fn(i32) -> i32 = |x| x + k;   // error: closures can only be coerced to `fn` types if they do not capture
+    let k = 3;
+    let with_k = |x: i32| x + k;
+    println!("캡처한 클로저: {}", with_k(1));
+
+    // fn 포인터는 Fn, FnMut, FnOnce 를 모두 구현 → 클로저를 받는 곳에 그대로
+    let mapped: Vec<i32> = [1, 2, 3].into_iter().map(square).collect();
+    println!("map(square) = {:?}", mapped);
+
+    // 이름으로 찾는 명령표 - 흔한 용도 (C 의 함수 포인터 테이블)
+    let mut commands: HashMap<&str, fn(i32) -> i32> = HashMap::new();
+    commands.insert("double", double);
+    commands.insert("square", square);
+    let mut names: Vec<_> = commands.keys().copied().collect();
+    names.sort_unstable();
+    for name in names {
+        println!("  {} 4 → {}", name, commands[name](4));
+    }
+
+    println!(
+        "크기: fn 포인터 {}바이트, 캡처 없는 클로저 {}바이트",
+        size_of::<fn(i32) -> i32>(),
+        size_of_val(&|x: i32| x + 1)
+    );
+}
+
+fn main() {
+    fn_pointers();
+}
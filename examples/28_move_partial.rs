@@ -0,0 +1,58 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 28. 클로저 심화 - move 와 부분 캡처
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+
+// ----------------------------------------------------------------------------
+// move 와 부분 캡처
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Job {
+    name: String,
+    payload: Vec<u8>,
+    retries: u32,
+}
+
+fn move_partial() {
+    println!("\n--- move 와 부분 캡처 ---");
+
+    // C++ 에서는:
+    // auto task = [payload = std::move(job.payload)] { return payload.size(); };
+    // job.payload 는 "유효하지만 알 수 없는 상태" - 써도 컴파일됨
+
+    let job = Job { name: String::from("upload"), payload: vec![1, 2, 3, 4], retries: 2 };
+
+    // move 클로저도 쓰는 필드만 가져감 - job.payload 만 이동
+    let task = move || job.payload.len();
+    // job.name, job.retries 는 그대로 사용 가능
+    println!("{} (재시도 {}번) → 크기 {}", job.name, job.retries, task());
+    // println!("{:?}", job);          // error: job.payload 가 이동됨 → job 전체는 못 씀
+    // println!("{:?}", job.payload);  // error: borrow of moved value
+
+    // 일부만 복제해서 넘기고 원본은 유지 - 흔한 패턴
+    let names = vec![String::from("a"), String::from("b")];
+    let report = {
+        let names = names.clone();
+        move || names.join(",")
+    };
+    println!("원본 {:?}, 클로저 {:?}", names, report());
+
+    // Copy 타입은 move 해도 복사 - 원본을 계속 쓸 수 있음
+    let limit = 10;
+    let within = move |x: i32| x <= limit;
+    println!("within(7) = {}, limit 여전히 {}", within(7), limit);
+
+    // 참조를 move 하면 참조가 복사됨 - 값이 아니라 빌림을 옮기는 것
+    let data = vec![5, 6, 7];
+    let view = &data;
+    let sum = move || view.iter().sum::<i32>();
+    println!("sum = {}, data 여전히 {:?}", sum(), data);
+}
+
+fn main() {
+    move_partial();
+}
@@ -0,0 +1,89 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 28. 클로저 심화 - 클로저 반환
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+
+// --- 다른 절에서 가져온 정의 ---
+
+struct Button<F: Fn(&str)> {
+    label: String,
+    on_click: F,
+}
+
+impl<F: Fn(&str)> Button<F> {
+    fn click(&self) {
+        (self.on_click)(&self.label);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 클로저 반환
+// ----------------------------------------------------------------------------
+
+// impl Fn - 타입은 하나로 정해져 있지만 이름을 적을 수 없을 때 (C++ 의 auto 반환)
+//   힙 할당 없음, 호출은 인라인 가능
+fn adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+// 분기마다 다른 클로저 = 다른 타입 → impl Fn 으로는 안 됨, Box<dyn Fn> (std::function)
+fn operation(name: &str) -> Option<Box<dyn Fn(i32, i32) -> i32>> {
+    match name {
+        "add" => Some(Box::new(|a, b| a + b)),
+        "mul" => Some(Box::new(|a, b| a * b)),
+        "pow" => Some(Box::new(|a, b| a.pow(b as u32))),
+        _ => None,
+    }
+}
+
+// 상태를 가진 클로저 - FnMut 을 돌려주면 호출할 때마다 안의 값이 바뀜
+fn counter(start: u32) -> impl FnMut() -> u32 {
+    let mut next = start;
+    move || {
+        next += 1;
+        next - 1
+    }
+}
+
+// 클로저를 받아 클로저를 돌려주기 - 합성
+fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |x| g(f(x))
+}
+
+fn returning_closures() {
+    println!("--- 클로저 반환 ---");
+
+    // C++ 에서는:
+    // auto adder(int n) { return [n](int x) { return x + n; }; }
+    // std::function<int(int, int)> operation(std::string_view name);
+
+    let add5 = adder(5);
+    println!("adder(5)(10) = {}", add5(10));
+
+    for name in ["add", "mul", "pow", "div"] {
+        match operation(name) {
+            Some(op) => println!("{}(2, 10) = {}", name, op(2, 10)),
+            None => println!("{}: 없는 연산", name),
+        }
+    }
+
+    let mut ids = counter(100);
+    println!("counter: {}, {}, {}", ids(), ids(), ids());
+
+    let shout = compose(|s: &str| s.trim().to_string(), |s: String| s.to_uppercase());
+    println!("compose: {:?}", shout("  hello  "));
+
+    // 크기 비교 - impl Fn 은 캡처한 값만큼, Box<dyn Fn> 은 팻 포인터
+    println!(
+        "크기: adder(5) {}바이트, Box<dyn Fn> {}바이트",
+        size_of_val(&add5),
+        size_of::<Box<dyn Fn(i32, i32) -> i32>>()
+    );
+}
+
+fn main() {
+    returning_closures();
+}
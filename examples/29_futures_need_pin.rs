@@ -0,0 +1,86 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 29. Pin 과 자기 참조 타입 - Future 가 Pin 을 요구하는 이유
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::{pin, Pin};
+use std::ptr;
+use std::task::{Context, Poll, Waker};
+
+// ----------------------------------------------------------------------------
+// Future 가 Pin 을 요구하는 이유
+// ----------------------------------------------------------------------------
+
+// 처음 poll 에서는 Pending, 두 번째에 Ready - await 지점을 하나 만들기 위한 것
+struct YieldOnce {
+    yielded: bool,
+}
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            // YieldOnce 는 Unpin 이라 Pin<&mut Self> 에서 바로 필드를 고칠 수 있음
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+// await 너머로 지역 변수를 빌리는 async fn - 상태 기계 안에 words 와 그 참조가 함께 들어감
+async fn count_words(text: String) -> usize {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    YieldOnce { yielded: false }.await;
+    words.len()
+}
+
+// 아주 작은 실행기 - Pending 이면 다시 poll, 몇 번 poll 했는지 함께
+fn run_to_end<F: Future>(future: F) -> (F::Output, u32) {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        if let Poll::Ready(out) = future.as_mut().poll(&mut cx) {
+            return (out, polls);
+        }
+    }
+}
+
+fn futures_need_pin() {
+    println!("\n--- Future 가 Pin 을 요구하는 이유 ---");
+
+    // C++ 에서는:
+    // 코루틴 프레임은 처음부터 힙에 할당되고 옮겨지지 않음 - 문제가 생기지 않는 대신 항상 할당
+    // Rust 의 Future 는 그냥 값 (스택에 둘 수 있음) → 처음 poll 한 뒤로는 옮기지 않도록 Pin
+
+    // async fn 이 만드는 상태 기계 (개념상):
+    //   enum CountWords { Start { text: String },
+    //                     Waiting { text: String, words: Vec<&'self str>, yield_once: YieldOnce },
+    //                     Done }
+    //   words 가 같은 값 안의 text 를 가리킴 → 자기 참조
+    let future = count_words(String::from("pin keeps futures in place"));
+    println!("Future 크기: {}바이트 (text, words, 상태 태그)", size_of_val(&future));
+
+    let (count, polls) = run_to_end(future);
+    println!("단어 {}개, poll {}번", count, polls);
+
+    // 처음 poll 하기 전에는 자기 참조가 아직 없으므로 자유롭게 옮겨도 됨 (함수로 넘기기, Vec 에 넣기)
+    // poll 은 Pin<&mut Self> 를 받으므로 첫 poll 부터는 옮길 수 없음
+    // 종류가 다른 Future 를 모으려면 Pin<Box<dyn Future<Output = T>>> (tokio 의 BoxFuture)
+    let boxed: Vec<Pin<Box<dyn Future<Output = usize>>>> =
+        vec![Box::pin(count_words(String::from("a b"))), Box::pin(async { 42 })];
+    let outputs: Vec<usize> = boxed.into_iter().map(|f| run_to_end(f).0).collect();
+    println!("Box::pin 으로 모은 Future 결과: {:?}", outputs);
+}
+
+fn main() {
+    futures_need_pin();
+}
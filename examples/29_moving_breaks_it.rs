@@ -0,0 +1,75 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 29. Pin 과 자기 참조 타입 - 옮기면 깨지는 이유
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::{pin, Pin};
+use std::ptr;
+use std::task::{Context, Poll, Waker};
+
+// --- 다른 절에서 가져온 정의 ---
+
+struct Unpinned {
+    value: String,
+    ptr: *const String,
+}
+
+impl Unpinned {
+    fn new(value: &str) -> Unpinned {
+        Unpinned { value: value.to_string(), ptr: ptr::null() }
+    }
+
+    // 만든 뒤에 자기 주소를 적음 - 만드는 동안에는 최종 주소를 알 수 없음
+    fn init(&mut self) {
+        self.ptr = &self.value;
+    }
+
+    fn points_to_self(&self) -> bool {
+        ptr::eq(self.ptr, &self.value)
+    }
+
+    fn via_ptr(&self) -> &str {
+        // SAFETY: 이 장에서는 ptr 이 가리키는 Unpinned 가 아직 살아 있을 때만 호출
+        //   (살아 있어도 "자기" 값이 아닐 수 있다는 것이 이 절의 요점)
+        unsafe { &*self.ptr }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 옮기면 깨지는 이유
+// ----------------------------------------------------------------------------
+
+fn moving_breaks_it() {
+    println!("\n--- 옮기면 깨지는 이유 ---");
+
+    let mut a = Unpinned::new("a");
+    let mut b = Unpinned::new("b");
+    a.init();
+    b.init();
+
+    // 두 값의 바이트를 맞바꿈 - 이동과 같은 memcpy, ptr 필드는 고쳐지지 않음
+    std::mem::swap(&mut a, &mut b);
+    println!(
+        "swap 후 a: value {:?}, ptr 로 읽은 값 {:?}, 자기를 가리킴? {}",
+        a.value,
+        a.via_ptr(),
+        a.points_to_self()
+    );
+    // a 의 ptr 은 여전히 원래 자리(지금의 b)를 가리킴 - 읽은 값이 자기 value 와 다름
+    // b 가 먼저 드롭됐다면 매달린 포인터 → 정의되지 않은 동작
+
+    // 이동도 마찬가지 - Vec 이나 Box 에 넣거나, 함수에서 돌려주면 주소가 바뀔 수 있음
+    let mut c = Unpinned::new("c");
+    c.init();
+    let moved = Box::new(c);
+    println!("Box::new(c) 로 옮긴 뒤 자기를 가리킴? {}", moved.points_to_self());
+
+    // 해결: 값이 처음 자리에서 움직이지 않는다고 약속하게 만들기 → Pin
+}
+
+fn main() {
+    moving_breaks_it();
+}
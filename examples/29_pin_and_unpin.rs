@@ -0,0 +1,121 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 29. Pin 과 자기 참조 타입 - Pin 과 Unpin
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::{pin, Pin};
+use std::ptr;
+use std::task::{Context, Poll, Waker};
+
+// --- 다른 절에서 가져온 정의 ---
+
+struct Unpinned {
+    value: String,
+    ptr: *const String,
+}
+
+impl Unpinned {
+    fn new(value: &str) -> Unpinned {
+        Unpinned { value: value.to_string(), ptr: ptr::null() }
+    }
+
+    // 만든 뒤에 자기 주소를 적음 - 만드는 동안에는 최종 주소를 알 수 없음
+    fn init(&mut self) {
+        self.ptr = &self.value;
+    }
+
+    fn points_to_self(&self) -> bool {
+        ptr::eq(self.ptr, &self.value)
+    }
+
+    fn via_ptr(&self) -> &str {
+        // SAFETY: 이 장에서는 ptr 이 가리키는 Unpinned 가 아직 살아 있을 때만 호출
+        //   (살아 있어도 "자기" 값이 아닐 수 있다는 것이 이 절의 요점)
+        unsafe { &*self.ptr }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Pin 과 Unpin
+// ----------------------------------------------------------------------------
+
+// 옮기면 안 되는 버전 - PhantomPinned 가 있으면 !Unpin
+struct Pinned {
+    value: String,
+    ptr: *const String,
+    _pin: PhantomPinned,
+}
+
+impl Pinned {
+    fn unboxed(value: &str) -> Pinned {
+        Pinned { value: value.to_string(), ptr: ptr::null(), _pin: PhantomPinned }
+    }
+
+    // 힙에 만들고 바로 고정 - 안전한 방법
+    fn boxed(value: &str) -> Pin<Box<Pinned>> {
+        let mut pinned = Box::pin(Pinned::unboxed(value));
+        pinned.as_mut().init();
+        pinned
+    }
+
+    // 고정된 뒤에만 부를 수 있음 - 이 뒤로 주소가 바뀌지 않으므로 적은 주소가 계속 맞음
+    fn init(self: Pin<&mut Self>) {
+        let ptr: *const String = &self.value;
+        // SAFETY: ptr 필드 하나만 바꾸고 self 를 옮기지 않음
+        unsafe { self.get_unchecked_mut().ptr = ptr };
+    }
+
+    fn points_to_self(self: Pin<&Self>) -> bool {
+        ptr::eq(self.ptr, &self.value)
+    }
+
+    fn via_ptr(self: Pin<&Self>) -> &str {
+        // SAFETY: init 은 고정된 뒤에만 불리고, 고정된 값은 드롭될 때까지 옮겨지지 않음
+        unsafe { &*self.get_ref().ptr }
+    }
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+fn pin_and_unpin() {
+    println!("\n--- Pin 과 Unpin ---");
+
+    // C++ 에서는:
+    // struct Pinned { Pinned(Pinned&&) = delete; Pinned& operator=(Pinned&&) = delete; ... };
+    // 다만 C++ 은 타입 전체가 이동 불가 - Rust 는 고정된 뒤부터만 (고정 전에는 자유롭게 옮김)
+
+    let mut a = Pinned::boxed("a");
+    let mut b = Pinned::boxed("b");
+    // Pin<Box<T>> 끼리 바꾸면 Box(포인터)만 바뀜 - 힙의 값은 그대로
+    std::mem::swap(&mut a, &mut b);
+    println!(
+        "Pin<Box> swap 후 a: value {:?}, ptr 로 읽은 값 {:?}, 자기를 가리킴? {}",
+        a.value,
+        a.as_ref().via_ptr(),
+        a.as_ref().points_to_self()
+    );
+
+    // 안의 값을 바꾸려고 하면 컴파일 에러:
+    //   std::mem::swap(&mut *a, &mut *b);
+    //   error: the trait `Unpin` is not implemented for `PhantomPinned`
+    //   Pin<P> 는 T: Unpin 일 때만 &mut T 를 내줌 (DerefMut, get_mut)
+
+    // Unpin 은 자동 트레이트 - 필드가 모두 Unpin 이면 Unpin
+    assert_unpin::<String>();
+    assert_unpin::<Unpinned>();
+    assert_unpin::<Box<Pinned>>(); // Box 는 옮겨도 힙의 값은 그대로 → 항상 Unpin
+    // assert_unpin::<Pinned>();   // error: PhantomPinned 때문에 !Unpin
+
+    // Unpin 타입에서는 Pin 이 아무것도 막지 않음 - Pin::new 가 안전, get_mut 으로 다시 &mut
+    let mut n = 5;
+    let mut pinned_n = Pin::new(&mut n);
+    *pinned_n.as_mut().get_mut() += 1;
+    println!("Unpin 인 i32 는 Pin::new 로 고정해도 자유롭게: {}", n);
+}
+
+fn main() {
+    pin_and_unpin();
+}
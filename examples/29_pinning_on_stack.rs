@@ -0,0 +1,82 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 29. Pin 과 자기 참조 타입 - 스택에 고정하기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::{pin, Pin};
+use std::ptr;
+use std::task::{Context, Poll, Waker};
+
+// --- 다른 절에서 가져온 정의 ---
+
+struct Pinned {
+    value: String,
+    ptr: *const String,
+    _pin: PhantomPinned,
+}
+
+impl Pinned {
+    fn unboxed(value: &str) -> Pinned {
+        Pinned { value: value.to_string(), ptr: ptr::null(), _pin: PhantomPinned }
+    }
+
+    // 힙에 만들고 바로 고정 - 안전한 방법
+    fn boxed(value: &str) -> Pin<Box<Pinned>> {
+        let mut pinned = Box::pin(Pinned::unboxed(value));
+        pinned.as_mut().init();
+        pinned
+    }
+
+    // 고정된 뒤에만 부를 수 있음 - 이 뒤로 주소가 바뀌지 않으므로 적은 주소가 계속 맞음
+    fn init(self: Pin<&mut Self>) {
+        let ptr: *const String = &self.value;
+        // SAFETY: ptr 필드 하나만 바꾸고 self 를 옮기지 않음
+        unsafe { self.get_unchecked_mut().ptr = ptr };
+    }
+
+    fn points_to_self(self: Pin<&Self>) -> bool {
+        ptr::eq(self.ptr, &self.value)
+    }
+
+    fn via_ptr(self: Pin<&Self>) -> &str {
+        // SAFETY: init 은 고정된 뒤에만 불리고, 고정된 값은 드롭될 때까지 옮겨지지 않음
+        unsafe { &*self.get_ref().ptr }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 스택에 고정하기
+// ----------------------------------------------------------------------------
+
+fn pinning_on_stack() {
+    println!("\n--- 스택에 고정하기 ---");
+
+    // 1. Pin::new_unchecked - !Unpin 을 스택에 고정하는 unsafe 방법
+    let mut local = Pinned::unboxed("stack");
+    // SAFETY: 같은 이름으로 가려서 원래 값에 다시 접근할 방법이 없음 - 드롭될 때까지 옮기지 않음
+    let mut local = unsafe { Pin::new_unchecked(&mut local) };
+    local.as_mut().init();
+    println!("new_unchecked: 자기를 가리킴? {}, {:?}", local.as_ref().points_to_self(), local.as_ref().via_ptr());
+
+    // 약속을 어기는 예 (컴파일은 되지만 정의되지 않은 동작):
+    //   let mut x = Pinned::unboxed("x");
+    //   unsafe { Pin::new_unchecked(&mut x) }.init();
+    //   let y = x;   // 고정했던 값을 옮김 - y.ptr 은 옛 자리를 가리킴
+
+    // 2. pin! - 위의 가리기를 매크로가 대신 해서 안전
+    let mut by_macro = pin!(Pinned::unboxed("macro"));
+    by_macro.as_mut().init();
+    println!("pin!: 자기를 가리킴? {}, {:?}", by_macro.as_ref().points_to_self(), by_macro.as_ref().via_ptr());
+
+    // 고르는 기준
+    //   Box::pin(x)  - 힙 할당, 돌려주거나 저장할 수 있음 (Pin<Box<dyn Future>>)
+    //   pin!(x)      - 할당 없음, 현재 함수 안에서만 (select! 에 넘길 Future 등)
+    //   new_unchecked - 직접 만든 고정 컨테이너 안에서만, SAFETY 주석과 함께
+}
+
+fn main() {
+    pinning_on_stack();
+}
@@ -0,0 +1,68 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 29. Pin 과 자기 참조 타입 - 자기 참조 구조체
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::{pin, Pin};
+use std::ptr;
+use std::task::{Context, Poll, Waker};
+
+// ----------------------------------------------------------------------------
+// 자기 참조 구조체
+// ----------------------------------------------------------------------------
+
+// 자기 필드를 가리키는 포인터를 가진 구조체
+//   참조(&String)로는 만들 수 없음 - 수명을 적을 방법이 없음 → 원시 포인터
+struct Unpinned {
+    value: String,
+    ptr: *const String,
+}
+
+impl Unpinned {
+    fn new(value: &str) -> Unpinned {
+        Unpinned { value: value.to_string(), ptr: ptr::null() }
+    }
+
+    // 만든 뒤에 자기 주소를 적음 - 만드는 동안에는 최종 주소를 알 수 없음
+    fn init(&mut self) {
+        self.ptr = &self.value;
+    }
+
+    fn points_to_self(&self) -> bool {
+        ptr::eq(self.ptr, &self.value)
+    }
+
+    fn via_ptr(&self) -> &str {
+        // SAFETY: 이 장에서는 ptr 이 가리키는 Unpinned 가 아직 살아 있을 때만 호출
+        //   (살아 있어도 "자기" 값이 아닐 수 있다는 것이 이 절의 요점)
+        unsafe { &*self.ptr }
+    }
+}
+
+fn self_referential() {
+    println!("--- 자기 참조 구조체 ---");
+
+    // C++ 에서는:
+    // struct Parser {
+    //     std::string input;
+    //     const char* cursor = input.data();   // 자기 멤버를 가리킴
+    //     Parser(Parser&& o) : input(std::move(o.input)), cursor(input.data() + (o.cursor - o.input.data())) {}
+    // };
+    // 이동 생성자에서 포인터를 고칠 수 있음 - Rust 에는 이런 훅이 없음
+
+    let mut a = Unpinned::new("hello");
+    a.init();
+    println!("init 직후: 자기를 가리킴? {}, ptr 로 읽은 값 {:?}", a.points_to_self(), a.via_ptr());
+
+    // 왜 이런 타입이 필요한가
+    //   - 버퍼와 그 버퍼를 가리키는 커서를 함께 들고 다니는 파서
+    //   - 침입형 연결 리스트의 노드
+    //   - async fn 의 상태 기계 (마지막 절)
+}
+
+fn main() {
+    self_referential();
+}
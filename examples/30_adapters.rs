@@ -0,0 +1,64 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 30. 비동기 스트림 - 어댑터
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, Semaphore};
+use tokio::time::{interval, sleep, Interval};
+use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
+use tokio_stream::{Stream, StreamExt, StreamMap};
+
+// ----------------------------------------------------------------------------
+// 어댑터
+// ----------------------------------------------------------------------------
+
+async fn double_later(x: u32) -> u32 {
+    sleep(Duration::from_millis(1)).await;
+    x * 2
+}
+
+async fn adapters() {
+    println!("\n--- 어댑터 ---");
+
+    // Iterator 와 같은 이름 - 클로저는 동기
+    let odd_squares: Vec<u32> =
+        tokio_stream::iter(1..=10).filter(|x| x % 2 == 1).map(|x| x * x).take(3).collect().await;
+    println!("filter + map + take: {:?}", odd_squares);
+
+    // then - 항목마다 async 작업 (map 의 비동기 버전), 하나씩 차례로 기다림
+    let doubled: Vec<u32> = tokio_stream::iter([1, 2, 3]).then(double_later).collect().await;
+    println!("then: {:?}", doubled);
+
+    // fold, any, all - 스트림을 소비해서 값 하나로
+    let sum = tokio_stream::iter(1..=100).fold(0u32, |acc, x| acc + x).await;
+    let has_big = tokio_stream::iter([3, 9, 27]).any(|x| x > 10).await;
+    println!("fold 합: {}, any(> 10): {}", sum, has_big);
+
+    // merge - 두 스트림을 도착하는 순서대로 섞음 (둘 다 끝나야 끝)
+    let letters = tokio_stream::iter(["a", "b"]);
+    let more = tokio_stream::iter(["x", "y"]);
+    let mut merged: Vec<&str> = letters.merge(more).collect().await;
+    merged.sort_unstable();
+    println!("merge (정렬해서): {:?}", merged);
+
+    // timeout - 항목 사이가 너무 길면 Err 를 끼워 넣음 (스트림은 계속)
+    let slow = tokio_stream::iter([5u64, 40, 5]).then(|ms| async move {
+        sleep(Duration::from_millis(ms)).await;
+        ms
+    });
+    let summary: Vec<String> = slow
+        .timeout(Duration::from_millis(20))
+        .map(|r| r.map_or("시간 초과".to_string(), |ms| format!("{}ms", ms)))
+        .collect()
+        .await;
+    println!("timeout(20ms): {:?}", summary);
+}
+
+#[tokio::main]
+async fn main() {
+    adapters().await;
+}
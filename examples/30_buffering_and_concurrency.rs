@@ -0,0 +1,83 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 30. 비동기 스트림 - 묶음과 동시 처리
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, Semaphore};
+use tokio::time::{interval, sleep, Interval};
+use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
+use tokio_stream::{Stream, StreamExt, StreamMap};
+
+// ----------------------------------------------------------------------------
+// 묶음과 동시 처리
+// ----------------------------------------------------------------------------
+
+// 느린 작업 - 요청 하나에 10ms
+async fn fetch(id: u32) -> String {
+    sleep(Duration::from_millis(10)).await;
+    format!("#{}", id)
+}
+
+// 동시에 최대 limit 개까지 처리하고, 끝나는 순서대로 스트림으로
+//   futures 크레이트의 buffer_unordered(limit) 와 같은 일을 tokio 만으로
+fn fetch_concurrently(ids: Vec<u32>, limit: usize) -> ReceiverStream<String> {
+    let (tx, rx) = mpsc::channel(limit);
+    tokio::spawn(async move {
+        let permits = std::sync::Arc::new(Semaphore::new(limit));
+        for id in ids {
+            // 허가를 먼저 받음 - limit 개가 돌고 있으면 여기서 기다림 (역압)
+            let Ok(permit) = permits.clone().acquire_owned().await else { break };
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let _ = tx.send(fetch(id).await).await;
+                drop(permit);
+            });
+        }
+    });
+    ReceiverStream::new(rx)
+}
+
+async fn buffering_and_concurrency() {
+    println!("\n--- 묶음과 동시 처리 ---");
+
+    // chunks_timeout - 최대 n 개씩 묶되, 시간이 지나면 모인 만큼만 (로그/지표를 모아 보내기)
+    let (tx, rx) = mpsc::channel(16);
+    tokio::spawn(async move {
+        for burst in [vec![1, 2, 3, 4, 5], vec![6]] {
+            for x in burst {
+                let _ = tx.send(x).await;
+            }
+            sleep(Duration::from_millis(30)).await;
+        }
+    });
+    let batches: Vec<Vec<i32>> =
+        ReceiverStream::new(rx).chunks_timeout(3, Duration::from_millis(10)).collect().await;
+    println!("chunks_timeout(3, 10ms): {:?}", batches);
+
+    // then 은 하나씩 - 4개 × 10ms
+    let start = Instant::now();
+    let sequential: Vec<String> = tokio_stream::iter(1..=4).then(fetch).collect().await;
+    let sequential_time = start.elapsed();
+
+    // 동시에 2개씩 - 대략 절반
+    let start = Instant::now();
+    let mut concurrent: Vec<String> = fetch_concurrently((1..=4).collect(), 2).collect().await;
+    let concurrent_time = start.elapsed();
+    concurrent.sort();
+    println!("순차 {:?} / 동시 2개 {:?}", sequential, concurrent);
+    println!("동시 처리가 더 빠름? {}", concurrent_time < sequential_time);
+
+    // throttle - 항목 사이에 최소 간격 (외부 API 호출 속도 제한)
+    let start = Instant::now();
+    let throttled: Vec<i32> = tokio_stream::iter([1, 2, 3]).throttle(Duration::from_millis(5)).collect().await;
+    println!("throttle(5ms): {:?}, 10ms 이상 걸림? {}", throttled, start.elapsed() >= Duration::from_millis(10));
+}
+
+#[tokio::main]
+async fn main() {
+    buffering_and_concurrency().await;
+}
@@ -0,0 +1,68 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 30. 비동기 스트림 - 채널과 스트림 변환
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, Semaphore};
+use tokio::time::{interval, sleep, Interval};
+use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
+use tokio_stream::{Stream, StreamExt, StreamMap};
+
+// ----------------------------------------------------------------------------
+// 채널과 스트림 변환
+// ----------------------------------------------------------------------------
+
+async fn channels_and_streams() {
+    println!("\n--- 채널과 스트림 변환 ---");
+
+    // 채널 → 스트림: 수신자를 래퍼로 감쌈
+    //   mpsc::Receiver → ReceiverStream, UnboundedReceiver → UnboundedReceiverStream
+    //   broadcast::Receiver → BroadcastStream (뒤처지면 Err(Lagged)), watch → WatchStream
+    let (tx, rx) = broadcast::channel::<&str>(8);
+    let listener = BroadcastStream::new(rx);
+    for event in ["login", "click", "logout"] {
+        let _ = tx.send(event);
+    }
+    drop(tx); // 송신자가 모두 사라지면 스트림이 끝남
+    let events: Vec<&str> = listener.filter_map(Result::ok).collect().await;
+    println!("BroadcastStream: {:?}", events);
+
+    // 스트림 → 채널: 태스크 하나가 스트림을 돌며 보냄 (다른 태스크가 recv 로 받음)
+    let (tx, mut rx) = mpsc::channel(4);
+    tokio::spawn(async move {
+        let mut squares = tokio_stream::iter(1..=3).map(|x| x * x);
+        while let Some(v) = squares.next().await {
+            if tx.send(v).await.is_err() {
+                break; // 받는 쪽이 사라짐
+            }
+        }
+    });
+    let mut received = Vec::new();
+    while let Some(v) = rx.recv().await {
+        received.push(v);
+    }
+    println!("스트림 → mpsc: {:?}", received);
+
+    // StreamMap - 이름 붙은 스트림 여럿을 하나로, 어느 스트림에서 왔는지 함께
+    //   채팅방마다 수신자를 하나씩 두고 한 루프에서 받기 (20장의 방 목록과 비슷한 구조)
+    let mut rooms = StreamMap::new();
+    for (room, messages) in [("rust", vec!["안녕", "질문"]), ("cpp", vec!["hello"])] {
+        let (tx, rx) = mpsc::channel(4);
+        for m in messages {
+            let _ = tx.send(m).await;
+        }
+        rooms.insert(room, ReceiverStream::new(rx));
+    }
+    let mut by_room: Vec<(&str, &str)> = rooms.collect().await;
+    by_room.sort_unstable();
+    println!("StreamMap: {:?}", by_room);
+}
+
+#[tokio::main]
+async fn main() {
+    channels_and_streams().await;
+}
@@ -0,0 +1,85 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 30. 비동기 스트림 - 직접 구현 (poll_next)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, Semaphore};
+use tokio::time::{interval, sleep, Interval};
+use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
+use tokio_stream::{Stream, StreamExt, StreamMap};
+
+// ----------------------------------------------------------------------------
+// 직접 구현 (poll_next)
+// ----------------------------------------------------------------------------
+
+// 1. 기다릴 일이 없는 스트림 - 항상 Ready
+struct Countdown {
+    remaining: u32,
+}
+
+impl Stream for Countdown {
+    type Item = u32;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        if self.remaining == 0 {
+            return Poll::Ready(None);
+        }
+        self.remaining -= 1;
+        Poll::Ready(Some(self.remaining + 1))
+    }
+
+    // collect 가 미리 용량을 잡을 수 있게
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+// 2. 기다리는 스트림 - 안의 Interval 이 Pending 이면 그대로 Pending 을 돌려줌
+//    Interval::poll_tick 이 cx 의 waker 를 등록하므로 때가 되면 런타임이 다시 poll
+struct Paced<I> {
+    items: I,
+    tick: Interval,
+}
+
+impl<I: Iterator + Unpin> Stream for Paced<I> {
+    type Item = I::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        // Paced 는 Unpin (Interval 이 안에서 Sleep 을 Box 로 고정) → 필드에 바로 접근
+        match self.tick.poll_tick(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(_) => Poll::Ready(self.items.next()),
+        }
+    }
+}
+
+fn paced<I: IntoIterator>(items: I, every: Duration) -> Paced<I::IntoIter> {
+    Paced { items: items.into_iter(), tick: interval(every) }
+}
+
+async fn custom_stream() {
+    println!("\n--- 직접 구현 (poll_next) ---");
+
+    let countdown = Countdown { remaining: 3 };
+    println!("size_hint = {:?}", countdown.size_hint());
+    let values: Vec<u32> = countdown.collect().await;
+    println!("Countdown: {:?}", values);
+
+    // 첫 tick 은 바로, 그 뒤로 5ms 마다
+    let start = Instant::now();
+    let paced_values: Vec<char> = paced("abc".chars(), Duration::from_millis(5)).collect().await;
+    println!("Paced: {:?}, 10ms 이상 걸림? {}", paced_values, start.elapsed() >= Duration::from_millis(10));
+
+    // 안에 !Unpin 필드(직접 가진 Sleep, async 블록)가 있으면 Pin<&mut Self> 에서 필드를 꺼내기 어려움
+    //   → pin-project 크레이트, 또는 Box::pin 으로 감싸서 Unpin 으로 (29장)
+    // async 블록으로 스트림을 쓰고 싶다면 async-stream 크레이트의 stream! { yield x; } (제너레이터는 불안정)
+}
+
+#[tokio::main]
+async fn main() {
+    custom_stream().await;
+}
@@ -0,0 +1,49 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 30. 비동기 스트림 - Stream 트레이트
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, Semaphore};
+use tokio::time::{interval, sleep, Interval};
+use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
+use tokio_stream::{Stream, StreamExt, StreamMap};
+
+// ----------------------------------------------------------------------------
+// Stream 트레이트
+// ----------------------------------------------------------------------------
+
+async fn stream_trait() {
+    println!("--- Stream 트레이트 ---");
+
+    // C++ 에서는 (cppcoro):
+    // cppcoro::async_generator<int> numbers() { for (int i = 0; i < 3; ++i) co_yield i; }
+    // for co_await (int n : numbers()) { ... }   // 표준에는 아직 없음
+
+    // 정의 (futures_core::Stream, tokio_stream 이 다시 내보냄):
+    // trait Stream {
+    //     type Item;
+    //     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+    //     fn size_hint(&self) -> (usize, Option<usize>) { (0, None) }
+    // }
+    // Iterator::next 가 Option<Item> 이라면, poll_next 는 "아직 없음(Pending)" 이 하나 더
+
+    let mut numbers = tokio_stream::iter([1, 2, 3]);
+    // for 문은 Iterator 전용 - 스트림은 while let + .next().await (StreamExt 의 메서드)
+    while let Some(n) = numbers.next().await {
+        println!("항목 {}", n);
+    }
+
+    // 빈 스트림, 하나짜리 스트림
+    let empty: Vec<i32> = tokio_stream::empty().collect().await;
+    let once: Vec<&str> = tokio_stream::once("한 번").collect().await;
+    println!("empty = {:?}, once = {:?}", empty, once);
+}
+
+#[tokio::main]
+async fn main() {
+    stream_trait().await;
+}
@@ -0,0 +1,119 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 31. 트레이트의 async fn - 트레이트의 async fn
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait SendStorage: Send + Sync {
+    fn lookup(&self, key: &str) -> impl Future<Output = Option<String>> + Send;
+}
+
+impl SendStorage for MemoryStorage {
+    async fn lookup(&self, key: &str) -> Option<String> {
+        self.data.lock().await.get(key).cloned()
+    }
+}
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+trait DynStorage: Send + Sync {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>>;
+}
+
+impl<T: SendStorage> DynStorage for T {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(self.lookup(key))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 트레이트의 async fn
+// ----------------------------------------------------------------------------
+
+// 저장소 - 메모리와 (가짜) 원격 두 가지 구현
+trait Storage {
+    async fn get(&self, key: &str) -> Option<String>;
+    async fn put(&self, key: &str, value: String);
+}
+
+#[derive(Default)]
+struct MemoryStorage {
+    data: Mutex<HashMap<String, String>>,
+}
+
+impl Storage for MemoryStorage {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.data.lock().await.get(key).cloned()
+    }
+
+    async fn put(&self, key: &str, value: String) {
+        self.data.lock().await.insert(key.to_string(), value);
+    }
+}
+
+// 원격 저장소 흉내 - 호출마다 지연
+#[derive(Default)]
+struct RemoteStorage {
+    inner: MemoryStorage,
+    latency_ms: u64,
+}
+
+impl Storage for RemoteStorage {
+    async fn get(&self, key: &str) -> Option<String> {
+        sleep(Duration::from_millis(self.latency_ms)).await;
+        self.inner.get(key).await
+    }
+
+    async fn put(&self, key: &str, value: String) {
+        sleep(Duration::from_millis(self.latency_ms)).await;
+        self.inner.put(key, value).await;
+    }
+}
+
+// 제네릭 - 구현마다 따로 컴파일되므로 Future 의 크기와 타입을 컴파일러가 앎
+async fn copy_key<S: Storage>(from: &S, to: &S, key: &str) -> bool {
+    match from.get(key).await {
+        Some(value) => {
+            to.put(key, value).await;
+            true
+        }
+        None => false,
+    }
+}
+
+async fn async_fn_in_trait() {
+    println!("--- 트레이트의 async fn ---");
+
+    // C++ 에서는:
+    // struct Storage { virtual Task<std::optional<std::string>> get(std::string_view key) = 0; };
+    // 반환 타입이 Task<T> 로 모두 같음 - 프레임은 힙에
+
+    // Rust 1.75 부터: 트레이트에 async fn 을 그대로 적을 수 있음
+    //   async fn get(&self, key: &str) -> Option<String>;
+    //   = fn get(&self, key: &str) -> impl Future<Output = Option<String>>;  (구현마다 다른 타입)
+
+    let a = MemoryStorage::default();
+    let b = MemoryStorage::default();
+    a.put("lang", String::from("rust")).await;
+    println!("copy_key(lang): {}, copy_key(none): {}", copy_key(&a, &b, "lang").await, copy_key(&a, &b, "none").await);
+    println!("b.get(lang) = {:?}", b.get("lang").await);
+
+    let remote = RemoteStorage { latency_ms: 2, ..Default::default() };
+    remote.put("k", String::from("v")).await;
+    println!("remote.get(k) = {:?}", remote.get("k").await);
+}
+
+#[tokio::main]
+async fn main() {
+    async_fn_in_trait().await;
+}
@@ -0,0 +1,91 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 31. 트레이트의 async fn - async-trait 매크로
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait SendStorage: Send + Sync {
+    fn lookup(&self, key: &str) -> impl Future<Output = Option<String>> + Send;
+}
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+trait Handler: Send + Sync {
+    fn name(&self) -> &str;
+    fn handle<'a>(&'a self, request: &'a str) -> BoxFuture<'a, String>;
+}
+
+struct Echo;
+
+impl Handler for Echo {
+    fn name(&self) -> &str {
+        "echo"
+    }
+
+    fn handle<'a>(&'a self, request: &'a str) -> BoxFuture<'a, String> {
+        Box::pin(async move { request.to_string() })
+    }
+}
+
+trait DynStorage: Send + Sync {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>>;
+}
+
+impl<T: SendStorage> DynStorage for T {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(self.lookup(key))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// async-trait 매크로
+// ----------------------------------------------------------------------------
+
+fn async_trait_macro() {
+    println!("\n--- async-trait 매크로 ---");
+
+    // 1.75 이전의 표준적인 방법 - 지금도 dyn 이 필요하면 많이 씀 (이 저장소에는 의존성 없음)
+    //
+    //   #[async_trait::async_trait]
+    //   trait Handler {
+    //       async fn handle(&self, request: &str) -> String;
+    //   }
+    //
+    //   #[async_trait::async_trait]
+    //   impl Handler for Echo {
+    //       async fn handle(&self, request: &str) -> String { request.to_string() }
+    //   }
+    //
+    // 매크로가 펼친 모양 = 바로 앞 절의 손으로 쓴 버전
+    //   fn handle<'life0, 'life1, 'async_trait>(&'life0 self, request: &'life1 str)
+    //       -> Pin<Box<dyn Future<Output = String> + Send + 'async_trait>>
+    //   where 'life0: 'async_trait, 'life1: 'async_trait, Self: 'async_trait
+    //   { Box::pin(async move { request.to_string() }) }
+    //
+    // #[async_trait(?Send)] - Send 가 필요 없을 때 (단일 스레드, Rc 를 들고 await)
+
+    let approaches = [
+        ("async fn (1.75+)", "없음", "불가", "알 수 없음 (제네릭에서)"),
+        ("impl Future + Send", "없음", "불가", "보장"),
+        ("BoxFuture 직접", "호출마다 Box", "가능", "보장 (+ Send 적으면)"),
+        ("#[async_trait]", "호출마다 Box", "가능", "기본 보장, ?Send 로 끔"),
+    ];
+    println!("{:20} | {:12} | {:6} | Send", "방식", "할당", "dyn");
+    for (way, alloc, dyn_ok, send) in approaches {
+        println!("{:20} | {:12} | {:6} | {}", way, alloc, dyn_ok, send);
+    }
+}
+
+fn main() {
+    async_trait_macro();
+}
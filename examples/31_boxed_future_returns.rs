@@ -0,0 +1,91 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 31. 트레이트의 async fn - Box<dyn Future> 반환
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait SendStorage: Send + Sync {
+    fn lookup(&self, key: &str) -> impl Future<Output = Option<String>> + Send;
+}
+
+trait DynStorage: Send + Sync {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>>;
+}
+
+impl<T: SendStorage> DynStorage for T {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(self.lookup(key))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Box<dyn Future> 반환
+// ----------------------------------------------------------------------------
+
+// 크기를 모르는 Future 를 힙에 두고 포인터만 돌려줌 - futures::future::BoxFuture 와 같은 별칭
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+// 반환 타입이 하나로 고정 → dyn 으로 쓸 수 있음
+trait Handler: Send + Sync {
+    fn name(&self) -> &str;
+    fn handle<'a>(&'a self, request: &'a str) -> BoxFuture<'a, String>;
+}
+
+struct Echo;
+struct Slow {
+    delay_ms: u64,
+}
+
+impl Handler for Echo {
+    fn name(&self) -> &str {
+        "echo"
+    }
+
+    fn handle<'a>(&'a self, request: &'a str) -> BoxFuture<'a, String> {
+        Box::pin(async move { request.to_string() })
+    }
+}
+
+impl Handler for Slow {
+    fn name(&self) -> &str {
+        "slow"
+    }
+
+    fn handle<'a>(&'a self, request: &'a str) -> BoxFuture<'a, String> {
+        Box::pin(async move {
+            sleep(Duration::from_millis(self.delay_ms)).await;
+            request.to_uppercase()
+        })
+    }
+}
+
+async fn boxed_future_returns() {
+    println!("\n--- Box<dyn Future> 반환 ---");
+
+    // C++ 에서는 처음부터 이 모양 - Task<T> 가 힙 프레임을 가리키는 핸들
+
+    // 종류가 다른 구현을 한 목록에 - 라우터, 플러그인, 미들웨어
+    let handlers: Vec<Box<dyn Handler>> = vec![Box::new(Echo), Box::new(Slow { delay_ms: 2 })];
+    for h in &handlers {
+        println!("{} → {:?}", h.name(), h.handle("ping").await);
+    }
+
+    // 비용: 호출마다 Box 할당 하나 + vtable 을 거친 poll
+    //   네트워크나 디스크를 기다리는 일에 비하면 대개 무시할 만함 (26장처럼 재 보기)
+    // 'a: Future 가 &self, request 를 빌리므로 그보다 오래 살 수 없음
+}
+
+#[tokio::main]
+async fn main() {
+    boxed_future_returns().await;
+}
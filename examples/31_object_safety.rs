@@ -0,0 +1,99 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 31. 트레이트의 async fn - dyn 호환성 (object safety)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait Storage {
+    async fn get(&self, key: &str) -> Option<String>;
+    async fn put(&self, key: &str, value: String);
+}
+
+#[derive(Default)]
+struct MemoryStorage {
+    data: Mutex<HashMap<String, String>>,
+}
+
+impl Storage for MemoryStorage {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.data.lock().await.get(key).cloned()
+    }
+
+    async fn put(&self, key: &str, value: String) {
+        self.data.lock().await.insert(key.to_string(), value);
+    }
+}
+
+trait SendStorage: Send + Sync {
+    fn lookup(&self, key: &str) -> impl Future<Output = Option<String>> + Send;
+}
+
+impl SendStorage for MemoryStorage {
+    async fn lookup(&self, key: &str) -> Option<String> {
+        self.data.lock().await.get(key).cloned()
+    }
+}
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+// ----------------------------------------------------------------------------
+// dyn 호환성 (object safety)
+// ----------------------------------------------------------------------------
+
+// async fn 트레이트는 dyn 으로 못 씀:
+//   let s: Box<dyn Storage> = ...;
+//   error[E0038]: the trait `Storage` is not dyn compatible
+//   ... because method `get` is `async`
+// 해결: 박스로 감싸는 dyn 전용 트레이트를 따로 두고, 포괄 구현으로 잇기
+//   구현하는 쪽은 편한 async fn 트레이트만, 쓰는 쪽은 필요하면 dyn 버전
+trait DynStorage: Send + Sync {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>>;
+}
+
+impl<T: SendStorage> DynStorage for T {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(self.lookup(key))
+    }
+}
+
+struct Fixed(&'static str);
+
+impl SendStorage for Fixed {
+    async fn lookup(&self, _key: &str) -> Option<String> {
+        Some(self.0.to_string())
+    }
+}
+
+async fn object_safety() {
+    println!("\n--- dyn 호환성 ---");
+
+    // C++ 에서는:
+    // std::vector<std::unique_ptr<Storage>> backends;   // 가상 코루틴이라 그대로 됨
+
+    let memory = MemoryStorage::default();
+    Storage::put(&memory, "lang", String::from("rust")).await;
+    let backends: Vec<Box<dyn DynStorage>> = vec![Box::new(memory), Box::new(Fixed("고정값"))];
+    for (i, backend) in backends.iter().enumerate() {
+        println!("backend {}: {:?}", i, backend.lookup_boxed("lang").await);
+    }
+
+    // 고르는 기준
+    //   구현 타입을 컴파일 때 앎 (제네릭으로 충분) → async fn / impl Future + Send - 할당 없음
+    //   실행 중에 고름 (설정 파일, 플러그인) → BoxFuture (직접 또는 async-trait)
+    //   둘 다 → 이 절처럼 두 트레이트 + 포괄 구현
+}
+
+#[tokio::main]
+async fn main() {
+    object_safety().await;
+}
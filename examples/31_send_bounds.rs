@@ -0,0 +1,145 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 31. 트레이트의 async fn - Send 바운드
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+// --- 다른 절에서 가져온 정의 ---
+
+trait Storage {
+    async fn get(&self, key: &str) -> Option<String>;
+    async fn put(&self, key: &str, value: String);
+}
+
+#[derive(Default)]
+struct MemoryStorage {
+    data: Mutex<HashMap<String, String>>,
+}
+
+impl Storage for MemoryStorage {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.data.lock().await.get(key).cloned()
+    }
+
+    async fn put(&self, key: &str, value: String) {
+        self.data.lock().await.insert(key.to_string(), value);
+    }
+}
+
+#[derive(Default)]
+struct RemoteStorage {
+    inner: MemoryStorage,
+    latency_ms: u64,
+}
+
+impl Storage for RemoteStorage {
+    async fn get(&self, key: &str) -> Option<String> {
+        sleep(Duration::from_millis(self.latency_ms)).await;
+        self.inner.get(key).await
+    }
+
+    async fn put(&self, key: &str, value: String) {
+        sleep(Duration::from_millis(self.latency_ms)).await;
+        self.inner.put(key, value).await;
+    }
+}
+
+async fn copy_key<S: Storage>(from: &S, to: &S, key: &str) -> bool {
+    match from.get(key).await {
+        Some(value) => {
+            to.put(key, value).await;
+            true
+        }
+        None => false,
+    }
+}
+
+async fn async_fn_in_trait() {
+    println!("--- 트레이트의 async fn ---");
+
+    // C++ 에서는:
+    // struct Storage { virtual Task<std::optional<std::string>> get(std::string_view key) = 0; };
+    // 반환 타입이 Task<T> 로 모두 같음 - 프레임은 힙에
+
+    // Rust 1.75 부터: 트레이트에 async fn 을 그대로 적을 수 있음
+    //   async fn get(&self, key: &str) -> Option<String>;
+    //   = fn get(&self, key: &str) -> impl Future<Output = Option<String>>;  (구현마다 다른 타입)
+
+    let a = MemoryStorage::default();
+    let b = MemoryStorage::default();
+    a.put("lang", String::from("rust")).await;
+    println!("copy_key(lang): {}, copy_key(none): {}", copy_key(&a, &b, "lang").await, copy_key(&a, &b, "none").await);
+    println!("b.get(lang) = {:?}", b.get("lang").await);
+
+    let remote = RemoteStorage { latency_ms: 2, ..Default::default() };
+    remote.put("k", String::from("v")).await;
+    println!("remote.get(k) = {:?}", remote.get("k").await);
+}
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+trait DynStorage: Send + Sync {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>>;
+}
+
+impl<T: SendStorage> DynStorage for T {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(self.lookup(key))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Send 바운드
+// ----------------------------------------------------------------------------
+
+// async fn 대신 impl Future + Send 로 선언 - 구현은 여전히 async fn 으로 쓸 수 있음
+trait SendStorage: Send + Sync {
+    fn lookup(&self, key: &str) -> impl Future<Output = Option<String>> + Send;
+}
+
+impl SendStorage for MemoryStorage {
+    async fn lookup(&self, key: &str) -> Option<String> {
+        self.data.lock().await.get(key).cloned()
+    }
+}
+
+// 제네릭 S 의 Future 를 다른 스레드의 태스크로 보냄 - Send 가 필요
+fn spawn_lookup<S: SendStorage + 'static>(storage: Arc<S>, key: &'static str) -> tokio::task::JoinHandle<Option<String>> {
+    tokio::spawn(async move { storage.lookup(key).await })
+}
+
+async fn send_bounds() {
+    println!("\n--- Send 바운드 ---");
+
+    // Storage (async fn 버전) 으로 같은 함수를 쓰면:
+    //   fn spawn_lookup<S: Storage + Send + Sync + 'static>(s: Arc<S>, ..) { tokio::spawn(async move { s.get(key).await }) }
+    //   error: future cannot be sent between threads safely
+    //   → S::get 이 돌려주는 Future 가 Send 인지 트레이트가 약속하지 않았기 때문
+    // 그래서 공개 트레이트의 async fn 에는 경고가 뜸 (async_fn_in_trait 린트) - 이 장의 트레이트는 비공개
+
+    let storage = Arc::new(MemoryStorage::default());
+    Storage::put(&*storage, "city", String::from("Seoul")).await;
+    let handle = spawn_lookup(Arc::clone(&storage), "city");
+    match handle.await {
+        Ok(found) => println!("spawn 한 태스크에서 찾음: {:?}", found),
+        Err(e) => println!("태스크 실패: {}", e),
+    }
+
+    // 정리
+    //   트레이트를 내 크레이트 안에서만 쓰거나 단일 스레드 런타임 → async fn 그대로
+    //   공개 라이브러리, 멀티 스레드 런타임 → impl Future<Output = T> + Send
+    //   (trait-variant 크레이트는 두 버전을 한 번에 만들어 줌)
+}
+
+#[tokio::main]
+async fn main() {
+    send_bounds().await;
+}
@@ -0,0 +1,220 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 32. rayon 으로 데이터 병렬 처리 - par_iter
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use rayon::prelude::*;
+
+// --- rust-study 의 microbench 모듈 ---
+mod microbench {
+// ============================================================================
+// 장 안의 작은 벤치마크 (Micro-benchmark)
+// ============================================================================
+// 레슨이 "더 빠르다", "비용이 없다" 고 말하는 곳에서 수치로 보여 주기 위한 최소한의 측정기
+//
+//   let m = crate::microbench::measure("enum", 1_000, || total(black_box(&shapes)));
+//   println!("{}", crate::microbench::table(&[m, ...]));
+//
+// 같은 조건으로 반복해서 재기: 예열 WARMUP 번 → SAMPLES 번 재고 중앙값 (한두 번 튀는 값에 흔들리지 않음)
+// 반복 횟수는 호출하는 쪽이 정함 (시간으로 맞추지 않음 - 실행마다 같은 일을 하도록)
+// 디버그 빌드의 수치는 최적화 전이라 비교에 쓰지 않음 - cargo run --release -- <장>
+// 정밀한 측정은 criterion 으로 (benches/, cargo bench 뒤 cargo run -- bench-report, 읽는 법은 26장)
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// 재기 전에 버리는 실행 수 - 캐시, 분기 예측기, CPU 클럭을 데움
+pub const WARMUP: usize = 3;
+// 재는 실행 수 - 홀수라서 중앙값이 하나
+pub const SAMPLES: usize = 11;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    // 한 번 실행(iterations 번 호출)의 중앙값과 최솟값
+    pub median: Duration,
+    pub min: Duration,
+    pub iterations: u32,
+}
+
+impl Measurement {
+    // 호출 한 번의 중앙값 (나노초)
+    pub fn per_call_ns(&self) -> f64 {
+        self.median.as_nanos() as f64 / self.iterations.max(1) as f64
+    }
+}
+
+// f 를 iterations 번 호출하는 실행을 WARMUP + SAMPLES 번 - 결과는 black_box 로 버려서 최적화로 사라지지 않게
+pub fn measure<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) -> Measurement {
+    let mut run = || {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(f());
+        }
+        start.elapsed()
+    };
+    for _ in 0..WARMUP {
+        run();
+    }
+    let mut samples: Vec<Duration> = (0..SAMPLES).map(|_| run()).collect();
+    samples.sort_unstable();
+    Measurement {
+        name: name.to_string(),
+        median: samples[SAMPLES / 2],
+        min: samples[0],
+        iterations,
+    }
+}
+
+// 이름, 호출당 시간, 가장 빠른 것 대비 배율 - 측정한 순서대로
+pub fn table(results: &[Measurement]) -> String {
+    let fastest = results
+        .iter()
+        .map(Measurement::per_call_ns)
+        .fold(f64::INFINITY, f64::min)
+        .max(f64::MIN_POSITIVE);
+    let width = results.iter().map(|m| m.name.chars().count()).max().unwrap_or(0);
+    results
+        .iter()
+        .map(|m| {
+            format!(
+                "  {:width$}  {:>12}  x{:.2}",
+                m.name,
+                format_ns(m.per_call_ns()),
+                m.per_call_ns() / fastest,
+                width = width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn format_ns(ns: f64) -> String {
+    if ns >= 1_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.2} µs", ns / 1_000.0)
+    } else {
+        format!("{:.1} ns", ns)
+    }
+}
+
+// 이 실행이 최적화 빌드인지 - 표 위에 함께 보여 줌
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "디버그 빌드 - 수치는 참고만 (cargo run --release 로 다시 재 보세요)"
+    } else {
+        "릴리스 빌드"
+    }
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn quicksort<T: Ord + Send>(v: &mut [T]) {
+    // 작은 조각은 나누는 비용이 더 큼 - 순차로
+    if v.len() <= 32 {
+        v.sort_unstable();
+        return;
+    }
+    let mid = partition(v);
+    let (left, right) = v.split_at_mut(mid);
+    rayon::join(|| quicksort(left), || quicksort(&mut right[1..]));
+}
+
+fn partition<T: Ord>(v: &mut [T]) -> usize {
+    let pivot = v.len() - 1;
+    let mut store = 0;
+    for i in 0..pivot {
+        if v[i] <= v[pivot] {
+            v.swap(i, store);
+            store += 1;
+        }
+    }
+    v.swap(store, pivot);
+    store
+}
+
+fn join() {
+    println!("\n--- join ---");
+
+    // C++ 에서는 (TBB):
+    // tbb::parallel_invoke([&] { quicksort(left); }, [&] { quicksort(right); });
+    // 두 람다가 같은 원소를 건드려도 컴파일됨
+
+    // join 은 두 작업을 "할 수 있으면" 동시에 - 놀고 있는 스레드가 없으면 그냥 차례로 (훔치기)
+    //   그래서 재귀 깊이만큼 스레드가 늘지 않음 - thread::spawn 을 재귀로 부르는 것과 다름
+    let (sum, max) = rayon::join(|| (1..=1000u64).sum::<u64>(), || (1..=1000u64).max());
+    println!("join 두 결과: 합 {}, 최댓값 {:?}", sum, max);
+
+    // 결정적인 섞인 데이터 (선형 합동 생성기)
+    let mut state = 12345u64;
+    let mut data: Vec<u32> = (0..2_000)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 33) as u32 % 10_000
+        })
+        .collect();
+    let mut expected = data.clone();
+    expected.sort_unstable();
+    quicksort(&mut data);
+    println!("병렬 퀵정렬 {}개 - 정렬됨? {}, 앞 5개 {:?}", data.len(), data == expected, &data[..5]);
+
+    // 실무에서는 직접 쓰지 말고 par_sort / par_sort_unstable
+    let mut again = expected.clone();
+    again.reverse();
+    again.par_sort_unstable();
+    println!("par_sort_unstable 도 같은 결과? {}", again == expected);
+}
+
+// ----------------------------------------------------------------------------
+// par_iter
+// ----------------------------------------------------------------------------
+
+fn par_iter() {
+    println!("--- par_iter ---");
+
+    // C++ 에서는:
+    // std::transform_reduce(std::execution::par, v.begin(), v.end(), 0L, std::plus{}, [](int x) { return x * x; });
+
+    let v: Vec<i64> = (1..=10_000).collect();
+
+    // 11장의 파이프라인 그대로 - iter() → par_iter()
+    let sequential: i64 = v.iter().filter(|x| *x % 2 == 1).map(|x| x * x).sum();
+    let parallel: i64 = v.par_iter().filter(|x| *x % 2 == 1).map(|x| x * x).sum();
+    println!("홀수 제곱 합: 순차 {}, 병렬 {}", sequential, parallel);
+
+    // collect 는 순서를 지킴 - 나눠서 계산해도 결과는 원래 순서대로 이어 붙임
+    let squares: Vec<i64> = v.par_iter().take(5).map(|x| x * x).collect();
+    println!("collect (순서 유지): {:?}", squares);
+
+    // for_each 는 순서가 없음 - 출력하면 실행마다 달라짐 → 결과를 모아서 정렬
+    let words = ["rayon", "par", "iter", "join", "pool"];
+    let mut lengths: Vec<(usize, &str)> = words.par_iter().map(|w| (w.len(), *w)).collect();
+    lengths.sort_unstable();
+    println!("길이순: {:?}", lengths);
+
+    // reduce - 결합 법칙이 성립하는 연산만 (나누는 방식이 실행마다 다를 수 있음)
+    let longest = words.par_iter().copied().reduce(|| "", |a, b| if b.len() > a.len() { b } else { a });
+    println!("가장 긴 단어: {}", longest);
+
+    // 가변 병렬 처리 - 원소마다 겹치지 않으므로 안전
+    let mut grid = vec![1u32; 8];
+    grid.par_iter_mut().enumerate().for_each(|(i, x)| *x += i as u32);
+    println!("par_iter_mut: {:?}", grid);
+
+    // 공유 카운터를 몰래 고치면 컴파일 에러:
+    //   let mut count = 0;
+    //   v.par_iter().for_each(|_| count += 1);   // error: cannot assign to `count`, as it is a captured variable in a `Fn` closure
+    // → count() 로 세거나, AtomicUsize, 또는 fold + reduce
+}
+
+fn main() {
+    par_iter();
+}
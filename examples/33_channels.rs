@@ -0,0 +1,177 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 33. crossbeam 채널과 동시성 도구 - 채널 - std::sync::mpsc 와 crossbeam
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Barrier, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+use crossbeam::channel::{self, after, bounded, never, select, tick, unbounded, TrySendError};
+use crossbeam::queue::{ArrayQueue, SegQueue};
+use crossbeam::sync::WaitGroup;
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn select() {
+    println!("\n--- select! ---");
+
+    // C++ 에서는: 여러 큐를 동시에 기다리는 표준 방법이 없음
+    //   큐마다 condvar 를 두고 바쁘게 돌거나, 메시지를 variant 로 묶어 큐 하나로 합침
+
+    // 데이터와 종료 신호를 함께 기다리기
+    let (data_tx, data_rx) = unbounded::<u32>();
+    let (quit_tx, quit_rx) = bounded::<()>(1);
+    thread::scope(|s| {
+        s.spawn(move || {
+            for i in 1..=5 {
+                data_tx.send(i).unwrap();
+                thread::sleep(Duration::from_millis(1));
+            }
+            quit_tx.send(()).unwrap();
+        });
+
+        let mut sum = 0;
+        let mut data_rx = data_rx;
+        loop {
+            // 준비된 가지가 여럿이면 무작위로 하나 - 순서를 가정하지 말 것
+            select! {
+                recv(data_rx) -> msg => match msg {
+                    Ok(v) => sum += v,
+                    // 송신자가 사라진 채널은 계속 "준비됨" → never() 로 바꿔서 가지를 끔
+                    Err(_) => data_rx = never(),
+                },
+                recv(quit_rx) -> _ => break,
+            }
+        }
+        // quit 이 먼저 뽑힐 수 있으므로 남은 데이터를 마저 받음
+        sum += data_rx.try_iter().sum::<u32>();
+        println!("데이터 합 {}, 종료 신호로 끝남", sum);
+    });
+
+    // 시간 제한 - after(d) 는 d 뒤에 한 번 값을 내는 채널
+    let (_tx, rx) = unbounded::<u32>();
+    select! {
+        recv(rx) -> msg => println!("받음: {:?}", msg),
+        recv(after(Duration::from_millis(5))) -> _ => println!("5ms 동안 아무것도 안 옴"),
+    }
+
+    // default - 아무것도 준비 안 됐으면 바로 (try_recv 를 여러 채널에)
+    let (tx, rx) = unbounded::<u32>();
+    select! {
+        recv(rx) -> msg => println!("받음: {:?}", msg),
+        default => println!("default - 기다리지 않음"),
+    }
+    tx.send(7).unwrap();
+    println!("보낸 뒤 try_recv: {:?}", rx.try_recv());
+
+    // send 도 가지가 될 수 있음 - 자리가 먼저 나는 쪽으로
+    let (fast_tx, fast_rx) = bounded::<u32>(1);
+    let (slow_tx, _slow_rx) = bounded::<u32>(0);
+    select! {
+        send(fast_tx, 1) -> _ => println!("fast 에 보냄: {:?}", fast_rx.recv()),
+        send(slow_tx, 1) -> _ => println!("slow 에 보냄"),
+    }
+
+    // tick(d) - 주기적인 신호 (타이머 스레드 없이)
+    let ticker = tick(Duration::from_millis(2));
+    let deadline = after(Duration::from_millis(11));
+    let mut ticks = 0;
+    loop {
+        select! {
+            recv(ticker) -> _ => ticks += 1,
+            recv(deadline) -> _ => break,
+        }
+    }
+    // 횟수는 스케줄러에 따라 다름 (대략 5번)
+    println!("11ms 동안 2ms 틱을 받았나? {}", ticks > 0);
+
+    // 채널 수가 실행 중에 정해지면 select! 대신 channel::Select 로 하나씩 등록
+    let receivers: Vec<_> = (0..3).map(|_| unbounded::<usize>()).collect();
+    receivers[2].0.send(2).unwrap();
+    let mut sel = channel::Select::new();
+    for (_, rx) in &receivers {
+        sel.recv(rx);
+    }
+    let op = sel.select();
+    let index = op.index();
+    println!("Select 로 준비된 채널: {}번, 값 {:?}", index, op.recv(&receivers[index].1));
+}
+
+// ----------------------------------------------------------------------------
+// 채널 - std::sync::mpsc 와 crossbeam
+// ----------------------------------------------------------------------------
+
+// 작업자 여럿이 한 수신자에서 작업을 나눠 가져감 - 각 작업은 정확히 한 번 처리됨
+// 돌려주는 값: 작업자마다 처리한 작업 수
+fn work_queue(jobs: u32, workers: usize) -> Vec<usize> {
+    let (tx, rx) = unbounded::<u32>();
+    for job in 0..jobs {
+        tx.send(job).unwrap();
+    }
+    // 송신자를 모두 버리면 큐가 비었을 때 수신자의 for 가 끝남
+    drop(tx);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                let rx = rx.clone(); // std 의 Receiver 라면 여기서 컴파일 에러
+                s.spawn(move || rx.iter().count())
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+fn channels() {
+    println!("--- 채널 ---");
+
+    // 13장의 std::sync::mpsc - 송신자는 복제되지만 수신자는 하나
+    //   let (tx, rx) = mpsc::channel();
+    //   let rx2 = rx.clone();   // error: no method named `clone` found for struct `Receiver`
+    // 작업 큐를 만들려면 Arc<Mutex<Receiver<T>>> 로 감싸야 했음 - 받을 때마다 락
+    let (tx, rx) = mpsc::sync_channel::<u32>(1);
+    tx.send(1).unwrap();
+    println!("std sync_channel(1) 가득 찬 뒤 try_send: {:?}", tx.try_send(2).is_err());
+    drop(rx);
+
+    // crossbeam - 수신자도 Clone (Multi-Producer Multi-Consumer)
+    // C++ 에서는:
+    // std::mutex m; std::condition_variable cv; std::queue<int> q;   // + 작업자마다 wait 루프
+    let counts = work_queue(1000, 4);
+    println!("작업자 4명이 나눠 처리: 합 {} (작업자별 개수는 실행마다 다름)", counts.iter().sum::<usize>());
+
+    // unbounded - 무제한 (std 의 channel 과 같음), 생산자가 빠르면 메모리가 계속 늘어남
+    // bounded(n) - 가득 차면 send 가 기다림 = 배압(backpressure)
+    let (tx, rx) = bounded::<&str>(2);
+    tx.send("a").unwrap();
+    tx.send("b").unwrap();
+    match tx.try_send("c") {
+        Ok(()) => println!("보냄"),
+        Err(TrySendError::Full(v)) => println!("bounded(2) 가득 참 - {:?} 를 돌려받음", v),
+        Err(TrySendError::Disconnected(v)) => println!("수신자 없음 - {:?}", v),
+    }
+    // send_timeout - 정해진 시간만 기다림
+    println!("send_timeout 5ms 뒤 실패? {}", tx.send_timeout("c", Duration::from_millis(5)).is_err());
+    println!("받은 것: {:?}", rx.try_iter().collect::<Vec<_>>());
+
+    // bounded(0) - 랑데부: 받는 쪽이 있을 때까지 send 가 끝나지 않음 (sync_channel(0) 과 같음)
+    let (tx, rx) = bounded::<u32>(0);
+    thread::scope(|s| {
+        s.spawn(move || tx.send(42).unwrap());
+        println!("랑데부로 받음: {}", rx.recv().unwrap());
+    });
+
+    // 둘의 차이 정리
+    //   std::sync::mpsc              crossbeam::channel
+    //   channel() / sync_channel(n)  unbounded() / bounded(n)
+    //   수신자 하나                   수신자 복제 가능
+    //   select 없음                  select!, Select
+    //   표준 - 의존성 없음             외부 크레이트 (Rust 1.67 부터 std 의 구현이 crossbeam 기반)
+}
+
+fn main() {
+    channels();
+}
@@ -0,0 +1,139 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 33. crossbeam 채널과 동시성 도구 - condition_variable 은 어디로
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Barrier, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+use crossbeam::channel::{self, after, bounded, never, select, tick, unbounded, TrySendError};
+use crossbeam::queue::{ArrayQueue, SegQueue};
+use crossbeam::sync::WaitGroup;
+
+// ----------------------------------------------------------------------------
+// condition_variable 은 어디로
+// ----------------------------------------------------------------------------
+
+// C++ 코드를 그대로 옮긴 생산자-소비자 - Mutex + Condvar
+// 돌려주는 값: 소비자가 받은 값의 합
+fn condvar_queue(items: u32) -> u32 {
+    let state = (Mutex::new((VecDeque::new(), false)), Condvar::new());
+    thread::scope(|s| {
+        let consumer = s.spawn(|| {
+            let (lock, cv) = &state;
+            let mut sum = 0;
+            loop {
+                // C++: cv.wait(lock, [&] { return !q.empty() || done; });
+                let mut guard = cv.wait_while(lock.lock().unwrap(), |(q, done)| q.is_empty() && !*done).unwrap();
+                match guard.0.pop_front() {
+                    Some(v) => sum += v,
+                    None => return sum, // 비었고 done
+                }
+            }
+        });
+
+        let (lock, cv) = &state;
+        for i in 1..=items {
+            lock.lock().unwrap().0.push_back(i);
+            cv.notify_one();
+        }
+        lock.lock().unwrap().1 = true;
+        cv.notify_all();
+        consumer.join().unwrap()
+    })
+}
+
+fn condvar_mapping() {
+    println!("\n--- condition_variable 은 어디로 ---");
+
+    // C++ 개발자의 반사 신경: "기다려야 하네 → mutex + condition_variable"
+    // Rust 에도 Condvar 가 있고 그대로 옮길 수 있음 (wait_while 이 가짜 깨어남 루프를 대신)
+    println!("Mutex + Condvar 생산자-소비자: 합 {}", condvar_queue(100));
+
+    // 하지만 대부분은 더 좁은 도구가 있음 - 실수할 여지(notify 빠뜨림, 조건 확인 누락)가 적음
+    //
+    //   C++ 에서 condvar 로 하던 일                        Rust
+    //   큐에 넣고 소비자 깨우기                             채널 (mpsc, crossbeam unbounded)
+    //   가득 차면 생산자 재우기 (condvar 두 개)              bounded(n)
+    //   "준비됐다" 플래그를 모두에게 알리기 (notify_all)       송신자 drop → 모든 수신자의 recv 가 Err
+    //   N 개 작업이 끝날 때까지 (C++20 std::latch)          thread::scope, WaitGroup
+    //   모두 같은 지점에서 만나기 (C++20 std::barrier)       std::sync::Barrier
+    //   특정 스레드 하나 깨우기 (C++20 atomic::wait/notify)  thread::park / Thread::unpark
+    //   여러 필드에 걸친 복잡한 조건                          Mutex + Condvar 그대로
+    //   async 코드 안에서                                  tokio::sync::Notify, watch (17장)
+
+    // 채널로 같은 생산자-소비자 - 종료 플래그와 notify 가 사라짐
+    let (tx, rx) = unbounded();
+    thread::scope(|s| {
+        s.spawn(move || (1..=100u32).for_each(|i| tx.send(i).unwrap()));
+        println!("채널 생산자-소비자: 합 {}", rx.iter().sum::<u32>());
+    });
+
+    // "준비됐다"를 모두에게 - 값을 보내지 않고 송신자를 닫기만
+    let (ready_tx, ready_rx) = bounded::<()>(0);
+    let started = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..3 {
+            let ready_rx = ready_rx.clone();
+            let started = &started;
+            s.spawn(move || {
+                let _ = ready_rx.recv(); // 닫히면 Err 로 깨어남
+                started.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        drop(ready_tx);
+    });
+    println!("닫힌 채널로 깨어난 스레드: {}", started.load(Ordering::SeqCst));
+
+    // WaitGroup - C++20 latch 처럼 "N 개가 끝날 때까지", N 을 미리 정하지 않아도 됨 (복제할 때마다 +1)
+    let wg = WaitGroup::new();
+    let done = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..4 {
+            let wg = wg.clone();
+            let done = &done;
+            s.spawn(move || {
+                done.fetch_add(1, Ordering::SeqCst);
+                drop(wg); // 끝났음을 알림
+            });
+        }
+        wg.wait(); // 복제본이 모두 drop 될 때까지
+        println!("WaitGroup 대기 후 끝난 작업: {}", done.load(Ordering::SeqCst));
+    });
+
+    // Barrier - 단계를 맞춤 (모두 1단계를 끝내야 2단계로)
+    let barrier = Barrier::new(3);
+    let phase_one = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..3 {
+            s.spawn(|| {
+                phase_one.fetch_add(1, Ordering::SeqCst);
+                barrier.wait();
+                // 여기서는 세 스레드 모두 1단계를 마쳤음이 보장됨
+                assert_eq!(phase_one.load(Ordering::SeqCst), 3);
+            });
+        }
+    });
+    println!("Barrier 뒤 1단계 완료 수: {}", phase_one.load(Ordering::SeqCst));
+
+    // park/unpark - 스레드 하나를 재우고 깨우기 (가짜 깨어남이 있으므로 조건과 함께)
+    let flag = AtomicUsize::new(0);
+    thread::scope(|s| {
+        let sleeper = s.spawn(|| {
+            while flag.load(Ordering::Acquire) == 0 {
+                thread::park();
+            }
+            "깨어남"
+        });
+        flag.store(1, Ordering::Release);
+        sleeper.thread().unpark();
+        println!("park/unpark: {}", sleeper.join().unwrap());
+    });
+}
+
+fn main() {
+    condvar_mapping();
+}
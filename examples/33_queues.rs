@@ -0,0 +1,81 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 33. crossbeam 채널과 동시성 도구 - 락 없는 큐
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Barrier, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+use crossbeam::channel::{self, after, bounded, never, select, tick, unbounded, TrySendError};
+use crossbeam::queue::{ArrayQueue, SegQueue};
+use crossbeam::sync::WaitGroup;
+
+// ----------------------------------------------------------------------------
+// 락 없는 큐
+// ----------------------------------------------------------------------------
+
+// ArrayQueue 로 만든 버퍼 풀 - 할당을 재사용, 가득 차면 그냥 버림
+struct BufferPool {
+    free: ArrayQueue<Vec<u8>>,
+}
+
+impl BufferPool {
+    fn new(capacity: usize) -> Self {
+        BufferPool { free: ArrayQueue::new(capacity) }
+    }
+
+    fn get(&self) -> Vec<u8> {
+        self.free.pop().unwrap_or_else(|| Vec::with_capacity(1024))
+    }
+
+    fn put(&self, mut buf: Vec<u8>) {
+        buf.clear();
+        // 풀이 가득 차면 Err(buf) - 받아서 버림 (drop)
+        let _ = self.free.push(buf);
+    }
+}
+
+fn queues() {
+    println!("\n--- 락 없는 큐 ---");
+
+    // C++ 에서는: boost::lockfree::queue<int> q(128);  q.push(1);  q.pop(x);
+    // ArrayQueue - 크기 고정, 미리 할당, push 가 가득 차면 값을 돌려줌
+    let q = ArrayQueue::new(2);
+    q.push('a').unwrap();
+    q.push('b').unwrap();
+    println!("가득 찬 큐에 push: {:?}", q.push('c'));
+    // force_push - 가장 오래된 것을 밀어내고 넣음 (고리 버퍼)
+    println!("force_push 로 밀려난 값: {:?}", q.force_push('c'));
+    println!("pop: {:?}, {:?}, {:?}", q.pop(), q.pop(), q.pop());
+
+    // SegQueue - 크기 제한 없음, 필요할 때 조각(segment) 단위로 늘어남
+    let seg = SegQueue::new();
+    thread::scope(|s| {
+        for t in 0..4 {
+            let seg = &seg; // &SegQueue 를 여러 스레드가 공유 - 메서드가 &self 라 Mutex 없이
+            s.spawn(move || {
+                for i in 0..250 {
+                    seg.push(t * 1000 + i);
+                }
+            });
+        }
+    });
+    println!("SegQueue 에 4 스레드가 넣은 개수: {}", seg.len());
+
+    // 큐는 기다리지 않음 - 비어 있으면 pop 이 None 을 바로 돌려줌
+    //   소비자가 기다려야 하면 채널을, 기다리지 않고 있으면 꺼내는 곳(풀, 작업 훔치기)에 큐를
+    let pool = BufferPool::new(2);
+    let mut buf = pool.get();
+    buf.extend_from_slice(b"hello");
+    let ptr = buf.as_ptr();
+    pool.put(buf);
+    let again = pool.get();
+    println!("버퍼 풀: 다시 받은 버퍼가 같은 할당? {}, 길이 {}", again.as_ptr() == ptr, again.len());
+}
+
+fn main() {
+    queues();
+}
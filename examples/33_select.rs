@@ -0,0 +1,107 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 33. crossbeam 채널과 동시성 도구 - select!
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Barrier, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+use crossbeam::channel::{self, after, bounded, never, select, tick, unbounded, TrySendError};
+use crossbeam::queue::{ArrayQueue, SegQueue};
+use crossbeam::sync::WaitGroup;
+
+// ----------------------------------------------------------------------------
+// select!
+// ----------------------------------------------------------------------------
+
+fn select() {
+    println!("\n--- select! ---");
+
+    // C++ 에서는: 여러 큐를 동시에 기다리는 표준 방법이 없음
+    //   큐마다 condvar 를 두고 바쁘게 돌거나, 메시지를 variant 로 묶어 큐 하나로 합침
+
+    // 데이터와 종료 신호를 함께 기다리기
+    let (data_tx, data_rx) = unbounded::<u32>();
+    let (quit_tx, quit_rx) = bounded::<()>(1);
+    thread::scope(|s| {
+        s.spawn(move || {
+            for i in 1..=5 {
+                data_tx.send(i).unwrap();
+                thread::sleep(Duration::from_millis(1));
+            }
+            quit_tx.send(()).unwrap();
+        });
+
+        let mut sum = 0;
+        let mut data_rx = data_rx;
+        loop {
+            // 준비된 가지가 여럿이면 무작위로 하나 - 순서를 가정하지 말 것
+            select! {
+                recv(data_rx) -> msg => match msg {
+                    Ok(v) => sum += v,
+                    // 송신자가 사라진 채널은 계속 "준비됨" → never() 로 바꿔서 가지를 끔
+                    Err(_) => data_rx = never(),
+                },
+                recv(quit_rx) -> _ => break,
+            }
+        }
+        // quit 이 먼저 뽑힐 수 있으므로 남은 데이터를 마저 받음
+        sum += data_rx.try_iter().sum::<u32>();
+        println!("데이터 합 {}, 종료 신호로 끝남", sum);
+    });
+
+    // 시간 제한 - after(d) 는 d 뒤에 한 번 값을 내는 채널
+    let (_tx, rx) = unbounded::<u32>();
+    select! {
+        recv(rx) -> msg => println!("받음: {:?}", msg),
+        recv(after(Duration::from_millis(5))) -> _ => println!("5ms 동안 아무것도 안 옴"),
+    }
+
+    // default - 아무것도 준비 안 됐으면 바로 (try_recv 를 여러 채널에)
+    let (tx, rx) = unbounded::<u32>();
+    select! {
+        recv(rx) -> msg => println!("받음: {:?}", msg),
+        default => println!("default - 기다리지 않음"),
+    }
+    tx.send(7).unwrap();
+    println!("보낸 뒤 try_recv: {:?}", rx.try_recv());
+
+    // send 도 가지가 될 수 있음 - 자리가 먼저 나는 쪽으로
+    let (fast_tx, fast_rx) = bounded::<u32>(1);
+    let (slow_tx, _slow_rx) = bounded::<u32>(0);
+    select! {
+        send(fast_tx, 1) -> _ => println!("fast 에 보냄: {:?}", fast_rx.recv()),
+        send(slow_tx, 1) -> _ => println!("slow 에 보냄"),
+    }
+
+    // tick(d) - 주기적인 신호 (타이머 스레드 없이)
+    let ticker = tick(Duration::from_millis(2));
+    let deadline = after(Duration::from_millis(11));
+    let mut ticks = 0;
+    loop {
+        select! {
+            recv(ticker) -> _ => ticks += 1,
+            recv(deadline) -> _ => break,
+        }
+    }
+    // 횟수는 스케줄러에 따라 다름 (대략 5번)
+    println!("11ms 동안 2ms 틱을 받았나? {}", ticks > 0);
+
+    // 채널 수가 실행 중에 정해지면 select! 대신 channel::Select 로 하나씩 등록
+    let receivers: Vec<_> = (0..3).map(|_| unbounded::<usize>()).collect();
+    receivers[2].0.send(2).unwrap();
+    let mut sel = channel::Select::new();
+    for (_, rx) in &receivers {
+        sel.recv(rx);
+    }
+    let op = sel.select();
+    let index = op.index();
+    println!("Select 로 준비된 채널: {}번, 값 {:?}", index, op.recv(&receivers[index].1));
+}
+
+fn main() {
+    select();
+}
@@ -0,0 +1,267 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 34. 스레드 풀 직접 만들기 (캡스톤) - 정상 종료 (Drop)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+// --- 다른 절에서 가져온 정의 ---
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+#[derive(Debug)]
+pub enum PoolError {
+    ZeroSize,
+    Spawn(io::Error),
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoolError::ZeroSize => write!(f, "작업자 수는 1 이상이어야 함"),
+            PoolError::Spawn(e) => write!(f, "작업자 스레드를 만들 수 없음: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PoolError::Spawn(e) => Some(e),
+            PoolError::ZeroSize => None,
+        }
+    }
+}
+
+impl From<io::Error> for PoolError {
+    fn from(e: io::Error) -> Self {
+        PoolError::Spawn(e)
+    }
+}
+
+struct Worker {
+    id: usize,
+    thread: Option<JoinHandle<usize>>,
+}
+
+impl Worker {
+    fn spawn(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>, panicked: Arc<AtomicUsize>) -> io::Result<Worker> {
+        // thread::spawn 과 달리 Builder 는 실패를 io::Result 로 돌려줌 (panic 대신)
+        let thread = thread::Builder::new().name(format!("pool-worker-{}", id)).spawn(move || {
+            let mut done = 0;
+            loop {
+                // 락은 이 문장 끝에서 풀림 - 작업을 실행하는 동안 다른 작업자가 다음 작업을 받을 수 있음
+                //   while let Ok(job) = receiver.lock().unwrap().recv() { job(); }
+                //   로 쓰면 락 가드가 while 본문 끝까지 살아서 작업이 하나씩만 실행됨 (흔한 실수)
+                let message = receiver.lock().unwrap().recv();
+                match message {
+                    Ok(job) => {
+                        // 작업이 panic 해도 작업자는 살아서 다음 작업으로
+                        //   락을 잡지 않은 채 실행하므로 Mutex 가 오염(poison)되지도 않음
+                        if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+                            panicked.fetch_add(1, Ordering::SeqCst);
+                        }
+                        done += 1;
+                    }
+                    // 송신자가 모두 사라짐 = 풀이 닫힘, 큐에 남은 작업은 이미 다 받음
+                    Err(_) => return done,
+                }
+            }
+        })?;
+        Ok(Worker { id, thread: Some(thread) })
+    }
+}
+
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    // Option 인 이유: Drop 에서 먼저 송신자를 drop 해야 작업자의 recv 가 끝남
+    sender: Option<mpsc::Sender<Job>>,
+    panicked: Arc<AtomicUsize>,
+}
+
+impl ThreadPool {
+    // C++: explicit ThreadPool(size_t n) - 실패하면 예외, Rust 는 Result
+    pub fn new(size: usize) -> Result<ThreadPool, PoolError> {
+        if size == 0 {
+            return Err(PoolError::ZeroSize);
+        }
+        let (sender, receiver) = mpsc::channel();
+        // 수신자는 하나뿐이라 (mpsc) 작업자들이 Arc 로 공유하고 Mutex 로 번갈아 씀
+        let receiver = Arc::new(Mutex::new(receiver));
+        let panicked = Arc::new(AtomicUsize::new(0));
+
+        let mut workers = Vec::with_capacity(size);
+        for id in 0..size {
+            // 중간에 실패하면 ? 로 돌아가며 pool 이 만들어지지 않음
+            //   이미 만든 작업자는 sender 가 drop 되면서 recv 가 끝나 스스로 종료
+            workers.push(Worker::spawn(id, Arc::clone(&receiver), Arc::clone(&panicked))?);
+        }
+        Ok(ThreadPool { workers, sender: Some(sender), panicked })
+    }
+
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    // 작업을 큐에 넣고 바로 돌아옴
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        // 작업자는 panic 을 잡으므로 풀이 살아 있는 동안 수신자가 사라지지 않음
+        self.sender.as_ref().unwrap().send(Box::new(f)).unwrap();
+    }
+
+    // 결과를 돌려주는 작업 - 작업마다 채널을 하나씩 (C++ 의 std::packaged_task + future)
+    pub fn submit<F, T>(&self, f: F) -> JobHandle<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        // 받는 쪽이 손잡이를 버렸으면 send 가 실패 - 결과를 버리면 되므로 무시
+        self.execute(move || {
+            let _ = tx.send(f());
+        });
+        JobHandle { rx }
+    }
+
+    // 지금까지 panic 한 작업 수
+    pub fn panicked(&self) -> usize {
+        self.panicked.load(Ordering::SeqCst)
+    }
+
+    // 명시적으로 닫고 작업자마다 실행한 작업 수를 돌려받음 - drop 과 같은 일에 결과가 붙음
+    pub fn shutdown(mut self) -> Vec<usize> {
+        self.close()
+    }
+
+    // 큐를 닫고 작업자를 모두 join - shutdown 과 Drop 이 함께 씀, 두 번 불려도 안전
+    fn close(&mut self) -> Vec<usize> {
+        drop(self.sender.take());
+        self.workers
+            .iter_mut()
+            .filter_map(|worker| {
+                let thread = worker.thread.take()?;
+                // 작업자 스레드 자체가 panic 하는 일은 없음 (작업의 panic 은 잡으므로) - 혹시 있으면 0 으로
+                Some(thread.join().unwrap_or_else(|_| {
+                    eprintln!("작업자 {} 가 비정상 종료", worker.id);
+                    0
+                }))
+            })
+            .collect()
+    }
+}
+
+pub struct JobHandle<T> {
+    rx: mpsc::Receiver<T>,
+}
+
+impl<T> JobHandle<T> {
+    // 작업이 panic 했으면 tx 가 보내지 못하고 drop 되므로 None
+    pub fn join(self) -> Option<T> {
+        self.rx.recv().ok()
+    }
+}
+
+fn workers() {
+    println!("\n--- 작업자와 풀 ---");
+
+    // C++ 에서는:
+    // class ThreadPool {
+    //     std::vector<std::thread> workers; std::queue<std::function<void()>> jobs;
+    //     std::mutex m; std::condition_variable cv; bool stop = false;
+    // };
+    // 작업자 루프: unique_lock lk(m); cv.wait(lk, [&] { return stop || !jobs.empty(); }); ...
+
+    match ThreadPool::new(0) {
+        Ok(_) => println!("만들어짐"),
+        Err(e) => println!("ThreadPool::new(0): {}", e),
+    }
+
+    let pool = match ThreadPool::new(3) {
+        Ok(pool) => pool,
+        Err(e) => {
+            println!("풀을 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    println!("작업자 {}명", pool.size());
+
+    // 작업은 'static 이어야 하므로 공유 카운터는 Arc 로
+    let counter = Arc::new(AtomicUsize::new(0));
+    for _ in 0..10 {
+        let counter = Arc::clone(&counter);
+        pool.execute(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+    // 작업자가 어떻게 나눠 가졌는지는 실행마다 다름 - 합만 확인
+    let per_worker = pool.shutdown();
+    println!("실행한 작업: {}, 작업자별 합 {}", counter.load(Ordering::SeqCst), per_worker.iter().sum::<usize>());
+}
+
+// ----------------------------------------------------------------------------
+// 정상 종료 (Drop)
+// ----------------------------------------------------------------------------
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // shutdown 으로 이미 닫았으면 sender 도 thread 도 None 이라 아무 일도 안 함
+        self.close();
+    }
+}
+
+fn graceful_shutdown() {
+    println!("\n--- 정상 종료 ---");
+
+    // C++ 에서는:
+    // ~ThreadPool() {
+    //     { std::lock_guard lk(m); stop = true; }
+    //     cv.notify_all();
+    //     for (auto& t : workers) t.join();   // join 을 빼먹으면 std::terminate
+    // }
+
+    // 스코프를 벗어날 때 drop → 큐를 닫고 남은 작업을 모두 실행한 뒤 join
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    {
+        let pool = match ThreadPool::new(2) {
+            Ok(pool) => pool,
+            Err(e) => {
+                println!("풀을 만들 수 없음: {}", e);
+                return;
+            }
+        };
+        for i in 0..6 {
+            let finished = Arc::clone(&finished);
+            pool.execute(move || {
+                thread::sleep(std::time::Duration::from_millis(1));
+                finished.lock().unwrap().push(i);
+            });
+        }
+        println!("작업 6개를 넣고 곧바로 풀을 버림");
+    } // ← 여기서 Drop::drop - 작업이 끝날 때까지 기다림
+
+    let mut finished = finished.lock().unwrap().clone();
+    finished.sort_unstable();
+    println!("drop 뒤 끝난 작업: {:?}", finished);
+
+    // 순서가 중요한 이유
+    //   1. 송신자를 먼저 drop - 작업자의 recv 가 Err 로 끝날 수 있게
+    //   2. 그다음 join - 순서를 바꾸면 작업자가 영원히 recv 에서 기다리고 join 도 영원히 (데드락)
+    // 작업자가 '지금 하던 일만' 끝내고 멈추게 하려면 큐를 비우지 말고 멈춤 메시지를 먼저 보내야 함
+    //   enum Message { Run(Job), Stop } 을 보내고 작업자 수만큼 Stop (Rust 책의 예전 판 방식)
+
+    // 이 풀에 없는 것 - 실무에서는 rayon(32장), crossbeam(33장), tokio 의 spawn_blocking 을 씀
+    //   작업 훔치기, 스레드 수 자동 조절, 작업 우선순위, 결과 없는 작업의 panic 전달
+}
+
+fn main() {
+    graceful_shutdown();
+}
@@ -0,0 +1,54 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 34. 스레드 풀 직접 만들기 (캡스톤) - 작업 큐 - 무엇을 보내는가
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+// ----------------------------------------------------------------------------
+// 작업 큐 - 무엇을 보내는가
+// ----------------------------------------------------------------------------
+
+// 작업 = 한 번 실행하고 끝나는 클로저
+//   FnOnce  - 캡처한 값을 소비해도 됨 (한 번만 부르므로)
+//   Send    - 작업자 스레드로 보냄
+//   'static - 작업이 언제 실행될지 모르므로 지역 변수를 빌리면 안 됨
+// 클로저마다 타입이 다르므로 Box<dyn ..> 로 크기를 맞춰 채널 하나에 담음
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+fn job_queue() {
+    println!("--- 작업 큐 ---");
+
+    // C++ 에서는:
+    // std::queue<std::function<void()>> jobs;   // + mutex, condition_variable
+    // std::function 은 복사 가능해야 해서 unique_ptr 을 캡처한 람다는 못 담음 (C++23 move_only_function)
+
+    // 서로 다른 클로저를 한 채널로 - 작업자 하나가 차례로 실행
+    let (tx, rx) = mpsc::channel::<Job>();
+    let owned = String::from("캡처한 String");
+    tx.send(Box::new(|| println!("  작업 1: 캡처 없음"))).unwrap();
+    tx.send(Box::new(move || println!("  작업 2: {} (move 로 소유)", owned))).unwrap();
+    let boxed = Box::new(7);
+    tx.send(Box::new(move || println!("  작업 3: Box<i32> 를 소비 {}", *boxed + 1))).unwrap();
+    drop(tx);
+
+    // 이 장의 나머지도 같음 - 받는 쪽이 스레드가 될 뿐
+    for job in rx {
+        job();
+    }
+
+    // 빌린 값은 보낼 수 없음:
+    //   let local = vec![1, 2, 3];
+    //   tx.send(Box::new(|| println!("{:?}", local)));   // error: `local` does not live long enough
+    // → move 로 소유권을 넘기거나 Arc 로 공유 (13장의 thread::spawn 과 같은 규칙)
+}
+
+fn main() {
+    job_queue();
+}
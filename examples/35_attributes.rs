@@ -0,0 +1,101 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 35. serde 직렬화 - #[serde(...)] 속성
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// ----------------------------------------------------------------------------
+// #[serde(...)] 속성
+// ----------------------------------------------------------------------------
+
+fn default_port() -> u16 {
+    8080
+}
+
+// 바깥 형식(camelCase JSON)과 Rust 이름(snake_case)을 맞추고, 없는 값은 기본값으로
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServerConfig {
+    bind_address: String,
+    // 없으면 함수가 만든 값
+    #[serde(default = "default_port")]
+    port: u16,
+    // 없으면 Default::default() - bool 은 false
+    #[serde(default)]
+    verbose: bool,
+    // 필드 하나만 다른 이름 (rename_all 보다 우선)
+    #[serde(rename = "max-clients")]
+    max_clients: u32,
+    // None 이면 출력에서 뺌 (null 대신)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    motd: Option<String>,
+    // 실행 중에만 쓰는 값 - 읽지도 쓰지도 않음 (타입이 Default 여야 함)
+    #[serde(skip)]
+    connections: usize,
+}
+
+// 값 검사가 필요하면 try_from - 역직렬화 중에 TryFrom 이 실패하면 serde 에러로
+//   into 는 쓸 때 &self 를 복제해서 변환하므로 Clone 도 필요
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+struct Email(String);
+
+impl TryFrom<String> for Email {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.contains('@') {
+            Ok(Email(s))
+        } else {
+            Err(format!("이메일에 @ 가 없음: {}", s))
+        }
+    }
+}
+
+impl From<Email> for String {
+    fn from(e: Email) -> String {
+        e.0
+    }
+}
+
+#[derive(Debug, Deserialize)]
+// 모르는 필드가 있으면 에러 - 설정 파일의 오타를 잡음 (기본은 조용히 무시)
+#[serde(deny_unknown_fields)]
+struct Contact {
+    name: String,
+    email: Email,
+}
+
+fn attributes() {
+    println!("\n--- #[serde(...)] 속성 ---");
+
+    // 최소한의 입력 - 나머지는 기본값
+    let config: ServerConfig = serde_json::from_str(r#"{ "bindAddress": "0.0.0.0", "max-clients": 100 }"#).unwrap();
+    println!("읽은 설정: {:?}", config);
+
+    // 다시 쓰면 이름이 바깥 형식으로, motd 는 None 이라 빠지고, connections 는 skip
+    println!("쓴 설정: {}", serde_json::to_string(&config).unwrap());
+
+    // rename_all 로 받을 수 있는 값: lowercase, UPPERCASE, camelCase, PascalCase, snake_case,
+    //   SCREAMING_SNAKE_CASE, kebab-case, SCREAMING-KEBAB-CASE
+    // 그 밖에 자주 쓰는 것
+    //   #[serde(alias = "addr")]       - 예전 이름도 받음 (읽을 때만)
+    //   #[serde(flatten)]              - 안쪽 구조체의 필드를 바깥에 펼침
+    //   #[serde(with = "모듈")]          - 이 필드만 직접 만든 직렬화 함수로
+
+    let ok: Result<Contact, _> = serde_json::from_str(r#"{ "name": "kim", "email": "kim@example.com" }"#);
+    println!("올바른 이메일: {:?}", ok.map(|c| (c.name, c.email)));
+    let bad: Result<Contact, _> = serde_json::from_str(r#"{ "name": "lee", "email": "lee.example.com" }"#);
+    println!("@ 없는 이메일: {}", bad.map(|c| c.name).unwrap_or_else(|e| e.to_string()));
+    let typo: Result<Contact, _> = serde_json::from_str(r#"{ "name": "park", "emial": "park@example.com" }"#);
+    println!("필드 오타: {}", typo.map(|c| c.name).unwrap_or_else(|e| e.to_string()));
+}
+
+fn main() {
+    attributes();
+}
@@ -0,0 +1,91 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 35. serde 직렬화 - derive 기본
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+struct Email(String);
+
+impl TryFrom<String> for Email {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.contains('@') {
+            Ok(Email(s))
+        } else {
+            Err(format!("이메일에 @ 가 없음: {}", s))
+        }
+    }
+}
+
+impl From<Email> for String {
+    fn from(e: Email) -> String {
+        e.0
+    }
+}
+
+// ----------------------------------------------------------------------------
+// derive 기본
+// ----------------------------------------------------------------------------
+
+// 필드 타입이 모두 Serialize/Deserialize 면 derive 만으로 끝
+//   String, 정수, Vec, Option, HashMap/BTreeMap, 다른 derive 한 구조체 ...
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Book {
+    title: String,
+    author: String,
+    year: u16,
+    tags: Vec<String>,
+    rating: Option<f32>,
+}
+
+fn sample_book() -> Book {
+    Book {
+        title: "The Rust Programming Language".to_string(),
+        author: "Klabnik, Nichols".to_string(),
+        year: 2018,
+        tags: vec!["rust".to_string(), "입문".to_string()],
+        rating: Some(4.5),
+    }
+}
+
+fn derive_basics() {
+    println!("--- derive 기본 ---");
+
+    // C++ 에서는 (nlohmann::json):
+    // struct Book { std::string title; std::string author; int year; };
+    // NLOHMANN_DEFINE_TYPE_INTRUSIVE(Book, title, author, year)   // 필드를 다시 나열
+    // 필드를 추가하고 매크로에 빠뜨리면 조용히 직렬화에서 빠짐
+
+    let book = sample_book();
+    // to_string - 한 줄, to_string_pretty - 들여쓰기
+    let text = serde_json::to_string(&book).unwrap();
+    println!("JSON: {}", text);
+
+    // 역직렬화할 타입은 타입 표기로 알려 줌 (from_str::<Book> 또는 let book: Book)
+    let back: Book = serde_json::from_str(&text).unwrap();
+    println!("왕복 후 같음? {}", back == book);
+
+    // derive 가 만드는 것 (개념상):
+    //   impl Serialize for Book {
+    //       fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+    //           let mut st = s.serialize_struct("Book", 5)?;
+    //           st.serialize_field("title", &self.title)?; ...
+    //           st.end()
+    //       }
+    //   }
+    // Serializer 는 형식(JSON, TOML ...)이 구현 - 그래서 한 번의 derive 로 모든 형식에
+}
+
+fn main() {
+    derive_basics();
+}
@@ -0,0 +1,109 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 35. serde 직렬화 - 열거형 표현 방식
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+struct Email(String);
+
+impl TryFrom<String> for Email {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.contains('@') {
+            Ok(Email(s))
+        } else {
+            Err(format!("이메일에 @ 가 없음: {}", s))
+        }
+    }
+}
+
+impl From<Email> for String {
+    fn from(e: Email) -> String {
+        e.0
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 열거형 표현 방식
+// ----------------------------------------------------------------------------
+
+// 기본: 바깥 태그 (externally tagged) - {"Circle":{"radius":1.0}}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum External {
+    Circle { radius: f64 },
+    Rect { w: f64, h: f64 },
+    Empty,
+}
+
+// 안쪽 태그 (internally tagged) - {"kind":"Circle","radius":1.0}  (REST API 에서 흔함)
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum Internal {
+    Circle { radius: f64 },
+    Rect { w: f64, h: f64 },
+}
+
+// 이웃 태그 (adjacently tagged) - {"t":"Circle","c":{"radius":1.0}}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "t", content = "c")]
+enum Adjacent {
+    Circle { radius: f64 },
+    Label(String),
+}
+
+// 태그 없음 (untagged) - 모양만 보고 위에서부터 맞는 것 (C++ 의 std::variant 에 값을 넣어 보는 것과 비슷)
+//   port = 8080 과 port = "http" 를 둘 다 받고 싶을 때
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Port {
+    Number(u16),
+    Service(String),
+    Range { from: u16, to: u16 },
+}
+
+#[derive(Debug, Deserialize)]
+struct Listen {
+    port: Port,
+}
+
+fn enum_representations() {
+    println!("\n--- 열거형 표현 방식 ---");
+
+    // C++ 에서는: std::variant 를 JSON 으로 - 태그를 어떻게 쓸지 직접 정하고 to_json 을 손으로
+
+    let shapes = [External::Circle { radius: 1.0 }, External::Rect { w: 2.0, h: 3.0 }, External::Empty];
+    println!("바깥 태그: {}", serde_json::to_string(&shapes).unwrap());
+    println!("안쪽 태그: {}", serde_json::to_string(&Internal::Circle { radius: 1.0 }).unwrap());
+    println!("이웃 태그: {}", serde_json::to_string(&Adjacent::Label("원".to_string())).unwrap());
+
+    // 안쪽 태그 - 태그가 어디 있든 읽음
+    let read: Internal = serde_json::from_str(r#"{ "w": 2.0, "kind": "Rect", "h": 1.5 }"#).unwrap();
+    println!("안쪽 태그 읽기: {:?}", read);
+
+    // untagged - 같은 필드에 모양이 다른 값
+    for text in ["port = 8080", r#"port = "http""#, "port = { from = 8000, to = 8100 }"] {
+        match toml::from_str::<Listen>(text) {
+            Ok(listen) => println!("{:<35} → {:?}", text, listen.port),
+            Err(e) => println!("{:<35} → 에러 {}", text, e),
+        }
+    }
+    // 맞는 것이 없으면 에러 메시지가 뭉뚱그려짐 - 어느 변형이 왜 안 맞았는지 알려 주지 않음
+    let none: Result<Port, _> = serde_json::from_str("true");
+    println!("untagged 에 맞는 것 없음: {}", none.map(|p| format!("{:?}", p)).unwrap_or_else(|e| e.to_string()));
+    // 순서 주의 - 위에서부터 시도하므로 무엇이든 받는 변형(예: Raw(serde_json::Value))을 앞에 두면
+    //   아래 변형은 절대 선택되지 않음
+}
+
+fn main() {
+    enum_representations();
+}
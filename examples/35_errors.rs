@@ -0,0 +1,126 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 35. serde 직렬화 - 에러 다루기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Book {
+    title: String,
+    author: String,
+    year: u16,
+    tags: Vec<String>,
+    rating: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+struct Email(String);
+
+impl TryFrom<String> for Email {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.contains('@') {
+            Ok(Email(s))
+        } else {
+            Err(format!("이메일에 @ 가 없음: {}", s))
+        }
+    }
+}
+
+impl From<Email> for String {
+    fn from(e: Email) -> String {
+        e.0
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 에러 다루기
+// ----------------------------------------------------------------------------
+
+// 9장의 관례: 형식마다 다른 에러를 열거형 하나로 모아 ? 로 전파
+#[derive(Debug)]
+enum LoadError {
+    Json(serde_json::Error),
+    Toml(toml::de::Error),
+    UnknownFormat(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Json(e) => write!(f, "JSON: {}", e),
+            // toml 의 에러 메시지는 여러 줄 (입력 줄과 ^ 표시) - 첫 줄만
+            LoadError::Toml(e) => write!(f, "TOML: {}", e.message()),
+            LoadError::UnknownFormat(ext) => write!(f, "모르는 형식: {}", ext),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(e: serde_json::Error) -> Self {
+        LoadError::Json(e)
+    }
+}
+
+impl From<toml::de::Error> for LoadError {
+    fn from(e: toml::de::Error) -> Self {
+        LoadError::Toml(e)
+    }
+}
+
+// 확장자로 형식을 고름 - src/content.rs 의 콘텐츠 읽기와 같은 모양
+fn load_book(extension: &str, text: &str) -> Result<Book, LoadError> {
+    let book = match extension {
+        "json" => serde_json::from_str(text)?,
+        "toml" => toml::from_str(text)?,
+        other => return Err(LoadError::UnknownFormat(other.to_string())),
+    };
+    Ok(book)
+}
+
+fn errors() {
+    println!("\n--- 에러 다루기 ---");
+
+    // C++ (nlohmann::json): j.at("year").get<int>() 가 json::type_error 예외를 던짐
+    // Rust: from_str 이 Result - 어디서 무엇이 틀렸는지가 에러 값에
+
+    let inputs = [
+        ("json", r#"{ "title": "A", "author": "B", "year": 2020, "tags": [] }"#),
+        ("json", r#"{ "title": "A", "author": "B", "year": "2020", "tags": [] }"#),
+        ("json", r#"{ "title": "A", "author": "B", "tags": [] }"#),
+        ("json", r#"{ "title": "A", "#),
+        ("toml", "title = \"A\"\nauthor = \"B\"\nyear = 99999\ntags = []"),
+        ("yaml", "title: A"),
+    ];
+    for (ext, text) in inputs {
+        match load_book(ext, text) {
+            Ok(book) => println!("[{}] 성공: {} ({})", ext, book.title, book.year),
+            Err(e) => println!("[{}] {}", ext, e),
+        }
+    }
+
+    // serde_json::Error 는 위치와 분류를 알려 줌
+    if let Err(e) = serde_json::from_str::<Book>("{\n  \"title\": 1\n}") {
+        println!("줄 {}, 열 {}, 분류 {:?}", e.line(), e.column(), e.classify());
+    }
+
+    // 실무 팁
+    //   - 외부 입력은 unwrap 하지 말고 에러를 사용자에게 (이 저장소의 `cargo run -- check` 처럼)
+    //   - 모르는 필드는 기본적으로 무시 - 오타를 잡으려면 deny_unknown_fields
+    //   - 숫자 범위(u16 등)도 타입이 검사 - year = 99999 는 u16 에 안 들어가서 에러
+}
+
+fn main() {
+    errors();
+}
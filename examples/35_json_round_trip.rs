@@ -0,0 +1,96 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 35. serde 직렬화 - JSON 왕복
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Book {
+    title: String,
+    author: String,
+    year: u16,
+    tags: Vec<String>,
+    rating: Option<f32>,
+}
+
+fn sample_book() -> Book {
+    Book {
+        title: "The Rust Programming Language".to_string(),
+        author: "Klabnik, Nichols".to_string(),
+        year: 2018,
+        tags: vec!["rust".to_string(), "입문".to_string()],
+        rating: Some(4.5),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+struct Email(String);
+
+impl TryFrom<String> for Email {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.contains('@') {
+            Ok(Email(s))
+        } else {
+            Err(format!("이메일에 @ 가 없음: {}", s))
+        }
+    }
+}
+
+impl From<Email> for String {
+    fn from(e: Email) -> String {
+        e.0
+    }
+}
+
+// ----------------------------------------------------------------------------
+// JSON 왕복
+// ----------------------------------------------------------------------------
+
+fn json_round_trip() {
+    println!("\n--- JSON 왕복 ---");
+
+    let library = vec![
+        sample_book(),
+        Book { title: "Programming Rust".to_string(), author: "Blandy".to_string(), year: 2021, tags: vec![], rating: None },
+    ];
+    let pretty = serde_json::to_string_pretty(&library).unwrap();
+    println!("to_string_pretty:\n{}", pretty);
+
+    let back: Vec<Book> = serde_json::from_str(&pretty).unwrap();
+    println!("Vec<Book> 왕복 후 같음? {}", back == library);
+
+    // 맵 - 키는 문자열로 (BTreeMap 이라 키 순서가 일정)
+    let mut by_year = BTreeMap::new();
+    for book in &library {
+        by_year.insert(book.year, book.title.clone());
+    }
+    println!("BTreeMap<u16, String>: {}", serde_json::to_string(&by_year).unwrap());
+
+    // 구조를 모를 때 - serde_json::Value (C++ 의 nlohmann::json 객체와 비슷)
+    let value = json!({ "title": "Rust in Action", "year": 2021, "tags": ["systems"] });
+    println!("Value 에서 꺼내기: title={}, year={}", value["title"], value["year"]);
+    println!("없는 키는 Null: {}", value["missing"]);
+
+    // Value → 타입 (모자란 필드가 있으면 Err)
+    let partial: Result<Book, _> = serde_json::from_value(value.clone());
+    println!("from_value::<Book>: {}", partial.map(|b| b.title).unwrap_or_else(|e| e.to_string()));
+
+    // 타입 → Value - 필드 하나를 고쳐서 보내기
+    let mut as_value = serde_json::to_value(sample_book()).unwrap();
+    as_value["year"] = json!(2023);
+    println!("고친 year: {}", as_value["year"]);
+}
+
+fn main() {
+    json_round_trip();
+}
@@ -0,0 +1,101 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 35. serde 직렬화 - TOML 왕복
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Book {
+    title: String,
+    author: String,
+    year: u16,
+    tags: Vec<String>,
+    rating: Option<f32>,
+}
+
+fn sample_book() -> Book {
+    Book {
+        title: "The Rust Programming Language".to_string(),
+        author: "Klabnik, Nichols".to_string(),
+        year: 2018,
+        tags: vec!["rust".to_string(), "입문".to_string()],
+        rating: Some(4.5),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+struct Email(String);
+
+impl TryFrom<String> for Email {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.contains('@') {
+            Ok(Email(s))
+        } else {
+            Err(format!("이메일에 @ 가 없음: {}", s))
+        }
+    }
+}
+
+impl From<Email> for String {
+    fn from(e: Email) -> String {
+        e.0
+    }
+}
+
+// ----------------------------------------------------------------------------
+// TOML 왕복
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Catalog {
+    name: String,
+    version: u32,
+    // 구조체 Vec 은 TOML 의 [[books]] 테이블 배열
+    books: Vec<Book>,
+}
+
+fn toml_round_trip() {
+    println!("\n--- TOML 왕복 ---");
+
+    // 같은 derive 를 그대로 - 크레이트만 toml 로
+    let catalog = Catalog { name: "추천 도서".to_string(), version: 1, books: vec![sample_book()] };
+    let text = toml::to_string(&catalog).unwrap();
+    println!("toml::to_string:\n{}", text);
+
+    let back: Catalog = toml::from_str(&text).unwrap();
+    println!("왕복 후 같음? {}", back == catalog);
+
+    // 사람이 쓴 TOML - 주석, 빠진 Option 필드 (rating 이 없으면 None)
+    let hand_written = r#"
+        # 손으로 쓴 설정
+        name = "직접 쓴 목록"
+        version = 2
+
+        [[books]]
+        title = "Rust for Rustaceans"
+        author = "Gjengset"
+        year = 2021
+        tags = ["중급"]
+    "#;
+    match toml::from_str::<Catalog>(hand_written) {
+        Ok(c) => println!("읽음: {} - 책 {}권, 첫 책 rating {:?}", c.name, c.books.len(), c.books[0].rating),
+        Err(e) => println!("에러: {}", e),
+    }
+
+    // TOML 에 없는 것: null - Option 이 None 이면 키를 아예 쓰지 않음
+    // 순서 제약: 단순 값이 테이블보다 먼저 와야 함 - 직렬화기가 알아서 맞춤
+}
+
+fn main() {
+    toml_round_trip();
+}
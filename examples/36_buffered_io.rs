@@ -0,0 +1,95 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 36. 파일과 파일 시스템 - 버퍼 입출력
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn scratch_dir(name: &str) -> io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rust-study-36-{}-{}-{}", name, std::process::id(), unique));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+// ----------------------------------------------------------------------------
+// 버퍼 입출력
+// ----------------------------------------------------------------------------
+
+// 줄 단위로 읽으며 세기 - 파일 전체를 메모리에 올리지 않음
+// R: BufRead 라서 파일, 표준 입력, 메모리(&[u8]) 모두 받음 - 테스트하기 쉬움
+fn count_matching<R: BufRead>(reader: R, needle: &str) -> io::Result<(usize, usize)> {
+    let mut total = 0;
+    let mut matched = 0;
+    for line in reader.lines() {
+        let line = line?;
+        total += 1;
+        if line.contains(needle) {
+            matched += 1;
+        }
+    }
+    Ok((total, matched))
+}
+
+fn buffered_io_in(dir: &Path) -> io::Result<()> {
+    let path = dir.join("log.txt");
+
+    // BufWriter - 작은 쓰기를 모아서 한 번에 (C++ 의 ofstream 은 기본이 버퍼링, File 은 아님)
+    //   File 에 writeln! 을 1000번 하면 시스템 호출 1000번
+    {
+        let mut out = BufWriter::new(File::create(&path)?);
+        for i in 0..1000 {
+            let level = if i % 10 == 0 { "WARN" } else { "INFO" };
+            writeln!(out, "{} 요청 {}", level, i)?;
+        }
+        // drop 도 flush 하지만 에러를 무시함 - 에러를 받으려면 직접
+        out.flush()?;
+    }
+
+    // BufReader - lines() 로 한 줄씩 (C++ 의 std::getline)
+    let (total, warnings) = count_matching(BufReader::new(File::open(&path)?), "WARN")?;
+    println!("BufReader: {}줄 중 WARN {}줄", total, warnings);
+
+    // 같은 함수를 메모리 데이터로 - &[u8] 도 BufRead
+    let (total, matched) = count_matching("a\nb WARN\nc".as_bytes(), "WARN")?;
+    println!("메모리에서: {}줄 중 {}줄", total, matched);
+
+    // lines() 는 줄마다 새 String - 큰 파일에서 할당을 줄이려면 read_line 으로 버퍼 재사용
+    let mut reader = BufReader::new(File::open(&path)?);
+    let mut line = String::new();
+    let mut longest = 0;
+    while reader.read_line(&mut line)? > 0 {
+        longest = longest.max(line.trim_end().len());
+        line.clear();
+    }
+    println!("read_line 으로 가장 긴 줄: {} 바이트", longest);
+    Ok(())
+}
+
+fn buffered_io() {
+    println!("\n--- 버퍼 입출력 ---");
+
+    let dir = match scratch_dir("buffered") {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("임시 디렉터리를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = buffered_io_in(&dir) {
+        println!("에러: {}", e);
+    }
+    let _ = fs::remove_dir_all(&dir);
+}
+
+fn main() {
+    buffered_io();
+}
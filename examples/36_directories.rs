@@ -0,0 +1,106 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 36. 파일과 파일 시스템 - 디렉터리
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn scratch_dir(name: &str) -> io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rust-study-36-{}-{}-{}", name, std::process::id(), unique));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn relative(path: &Path, base: &Path) -> String {
+    path.strip_prefix(base).unwrap_or(path).display().to_string()
+}
+
+// ----------------------------------------------------------------------------
+// 디렉터리
+// ----------------------------------------------------------------------------
+
+// 재귀로 모든 파일 - C++ 의 recursive_directory_iterator (실무에서는 walkdir 크레이트)
+// read_dir 의 순서는 운영체제마다 다름 → 모은 뒤 정렬
+fn list_files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    walk(root, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        // file_type() 은 보통 추가 시스템 호출 없이 (read_dir 이 이미 앎)
+        if entry.file_type()?.is_dir() {
+            walk(&entry.path(), files)?;
+        } else {
+            files.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+fn directories_in(root: &Path) -> io::Result<()> {
+    // C++: std::filesystem::create_directories(p) - 중간 디렉터리까지
+    fs::create_dir_all(root.join("src/bin"))?;
+    fs::create_dir_all(root.join("docs"))?;
+    fs::write(root.join("Cargo.toml"), "[package]\n")?;
+    fs::write(root.join("src/main.rs"), "fn main() {}\n")?;
+    fs::write(root.join("src/lib.rs"), "")?;
+    fs::write(root.join("src/bin/tool.rs"), "fn main() {}\n")?;
+    fs::write(root.join("docs/guide.md"), "# 안내\n")?;
+
+    // 한 단계만 - read_dir
+    let mut top: Vec<String> = fs::read_dir(root)?
+        .map(|entry| entry.map(|e| e.file_name().to_string_lossy().into_owned()))
+        .collect::<io::Result<_>>()?;
+    top.sort();
+    println!("read_dir: {:?}", top);
+
+    // 재귀
+    for file in &list_files(root)? {
+        println!("  {:<16} {} 바이트", relative(file, root), fs::metadata(file)?.len());
+    }
+
+    // 복사, 이름 바꾸기, 지우기
+    fs::copy(root.join("docs/guide.md"), root.join("docs/guide.bak"))?;
+    fs::rename(root.join("src/lib.rs"), root.join("src/util.rs"))?;
+    fs::remove_file(root.join("docs/guide.bak"))?;
+    // remove_dir 는 빈 디렉터리만, remove_dir_all 은 안의 것까지 (rm -rf - 조심)
+    println!("비어 있지 않은 디렉터리를 remove_dir: {:?}", fs::remove_dir(root.join("src")).map_err(|e| e.kind()));
+    fs::remove_dir_all(root.join("src/bin"))?;
+
+    let after = list_files(root)?;
+    println!("정리 뒤: {:?}", after.iter().map(|f| relative(f, root)).collect::<Vec<_>>());
+    Ok(())
+}
+
+fn directories() {
+    println!("\n--- 디렉터리 ---");
+
+    let root = match scratch_dir("dirs") {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("임시 디렉터리를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = directories_in(&root) {
+        println!("에러: {}", e);
+    }
+    let _ = fs::remove_dir_all(&root);
+}
+
+fn main() {
+    directories();
+}
@@ -0,0 +1,118 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 36. 파일과 파일 시스템 - 없는 파일과 에러
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn scratch_dir(name: &str) -> io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rust-study-36-{}-{}-{}", name, std::process::id(), unique));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn relative(path: &Path, base: &Path) -> String {
+    path.strip_prefix(base).unwrap_or(path).display().to_string()
+}
+
+// ----------------------------------------------------------------------------
+// 없는 파일과 에러
+// ----------------------------------------------------------------------------
+
+// io::Error 에 경로를 붙인 에러 - "No such file or directory" 만으로는 어느 파일인지 모름
+#[derive(Debug)]
+struct FileError {
+    path: PathBuf,
+    source: io::Error,
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.source)
+    }
+}
+
+impl std::error::Error for FileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn read_with_path(path: &Path) -> Result<String, FileError> {
+    fs::read_to_string(path).map_err(|source| FileError { path: path.to_path_buf(), source })
+}
+
+// 흔한 패턴: 설정 파일이 없으면 기본값, 다른 에러(권한, 잘못된 UTF-8)는 그대로 전파
+fn read_or_default(path: &Path, default: &str) -> io::Result<String> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(default.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+fn missing_files() {
+    println!("\n--- 없는 파일과 에러 ---");
+
+    // C++ 에서는:
+    // std::ifstream in("missing.txt");
+    // if (!in) { /* 왜 실패했는지는 errno 를 따로 봐야 함 */ }
+    // std::filesystem::file_size("missing.txt");   // filesystem_error 예외
+
+    let dir = match scratch_dir("missing") {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("임시 디렉터리를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    let missing = dir.join("missing.txt");
+
+    // 에러의 종류 - kind() 로 나눠 처리 (9장)
+    match File::open(&missing) {
+        Ok(_) => println!("열림"),
+        Err(e) => println!("File::open: kind {:?}", e.kind()),
+    }
+
+    // 경로를 붙인 에러 - 출력에는 파일 이름만 (임시 디렉터리는 실행마다 다름)
+    if let Err(e) = read_with_path(&missing) {
+        println!("경로를 붙인 에러: ...{}{}", std::path::MAIN_SEPARATOR, relative(&e.path, &dir));
+        println!("  원인 kind: {:?}", e.source.kind());
+    }
+
+    // 없으면 기본값
+    match read_or_default(&missing, "기본 설정") {
+        Ok(text) => println!("read_or_default: {:?}", text),
+        Err(e) => println!("에러: {}", e),
+    }
+
+    // 있는지 먼저 묻기 - exists() 는 에러도 false 로 (권한 없음도 "없음")
+    //   try_exists() 는 모르는 경우를 Err 로 구분
+    println!("exists: {}, try_exists: {:?}", missing.exists(), missing.try_exists().map_err(|e| e.kind()));
+    // 확인 후 여는 것은 경쟁 조건(TOCTOU) - 그 사이에 지워질 수 있음
+    //   열어 보고 에러를 처리하는 것이 정석 (위의 read_or_default)
+
+    // UTF-8 이 아닌 파일을 문자열로 읽으면 InvalidData
+    let binary = dir.join("data.bin");
+    if fs::write(&binary, [0xff, 0xfe, 0x00]).is_ok() {
+        match fs::read_to_string(&binary) {
+            Ok(_) => println!("읽힘"),
+            Err(e) => println!("바이너리를 read_to_string: kind {:?}", e.kind()),
+        }
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+fn main() {
+    missing_files();
+}
@@ -0,0 +1,59 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 36. 파일과 파일 시스템 - Path 와 PathBuf
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ----------------------------------------------------------------------------
+// Path 와 PathBuf
+// ----------------------------------------------------------------------------
+
+fn paths() {
+    println!("--- Path 와 PathBuf ---");
+
+    // C++ 에서는:
+    // std::filesystem::path p = "docs/notes/todo.txt";
+    // p.parent_path(); p.filename(); p.extension(); p / "more";
+
+    // Path 는 빌린 경로 (&str 처럼), 문자열 리터럴에서 바로
+    let p = Path::new("docs/notes/todo.txt");
+    println!("parent: {:?}", p.parent());
+    println!("file_name: {:?}, file_stem: {:?}, extension: {:?}", p.file_name(), p.file_stem(), p.extension());
+
+    // PathBuf 는 소유한 경로 (String 처럼) - join 은 새 PathBuf, push 는 제자리
+    let mut out = PathBuf::from("build");
+    out.push("reports");
+    let report = out.join("2026.csv");
+    println!("join: {}", report.display());
+    println!("with_extension: {}", report.with_extension("json").display());
+
+    // 조각으로 나누기 - 구분자(/ 또는 \)는 운영체제에 맞게
+    let parts: Vec<_> = p.components().map(|c| c.as_os_str().to_string_lossy().into_owned()).collect();
+    println!("components: {:?}", parts);
+
+    // 주의: 절대 경로를 join 하면 앞을 버림 (C++ 의 operator/ 와 같음)
+    #[expect(clippy::join_absolute_paths, reason = "앞을 버리는 함정을 보여 줌")]
+    let replaced = Path::new("base").join("/etc");
+    println!("\"base\".join(\"/etc\"): {}", replaced.display());
+
+    // 경로는 OsStr - 문자열로 바꿀 때 UTF-8 이 아닐 수 있음
+    //   to_str()          → Option<&str>       (아니면 None)
+    //   to_string_lossy() → Cow<str>           (아니면 � 로 바꿈)
+    //   display()         → 출력용 (lossy 와 같음)
+    println!("to_str: {:?}", p.to_str());
+
+    // 확장자로 고르기 - 대소문자 구분 없이 (OsStr::eq_ignore_ascii_case)
+    let files = ["main.rs", "README.md", "lib.RS", "Cargo.toml"];
+    let rust: Vec<_> = files.iter().filter(|f| Path::new(f).extension().is_some_and(|e| e.eq_ignore_ascii_case("rs"))).collect();
+    println!("Rust 파일: {:?}", rust);
+}
+
+fn main() {
+    paths();
+}
@@ -0,0 +1,85 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 36. 파일과 파일 시스템 - 읽고 쓰기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn scratch_dir(name: &str) -> io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rust-study-36-{}-{}-{}", name, std::process::id(), unique));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+// ----------------------------------------------------------------------------
+// 읽고 쓰기
+// ----------------------------------------------------------------------------
+
+fn read_write_in(dir: &Path) -> io::Result<()> {
+    let path = dir.join("hello.txt");
+
+    // 한 번에 쓰기/읽기 - 작은 파일은 이것으로 충분 (9장의 hello.txt 도)
+    // C++: std::ofstream(path) << "..."; std::ifstream in(path); std::stringstream ss; ss << in.rdbuf();
+    fs::write(&path, "안녕하세요\n두 번째 줄\n")?;
+    let text = fs::read_to_string(&path)?;
+    println!("read_to_string: {:?}", text);
+
+    // 바이트로 - UTF-8 이 아닌 파일 (read_to_string 은 UTF-8 이 아니면 InvalidData 에러)
+    let bytes = fs::read(&path)?;
+    println!("read: {} 바이트, 앞 3바이트 {:?}", bytes.len(), &bytes[..3]);
+
+    // 덧붙이기 - OpenOptions 로 모드를 고름 (C++ 의 std::ios::app)
+    let mut file = OpenOptions::new().append(true).open(&path)?;
+    writeln!(file, "덧붙인 줄")?;
+    drop(file); // 닫기 - 스코프를 벗어나도 같음
+    println!("덧붙인 뒤 줄 수: {}", fs::read_to_string(&path)?.lines().count());
+
+    // 없을 때만 만들기 - 있으면 AlreadyExists (덮어쓰기 사고 방지)
+    match OpenOptions::new().write(true).create_new(true).open(&path) {
+        Ok(_) => println!("새로 만듦"),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => println!("create_new: 이미 있음"),
+        Err(e) => return Err(e),
+    }
+
+    // File + Read/Write 트레이트 - 직접 버퍼를 다룰 때
+    let mut file = File::open(&path)?;
+    let mut first = [0u8; 5];
+    file.read_exact(&mut first)?;
+    // 한글은 UTF-8 로 3바이트 - 5바이트는 두 번째 글자 중간에서 잘림
+    println!("read_exact 5바이트: {:?}", String::from_utf8_lossy(&first));
+
+    // 메타데이터
+    let meta = fs::metadata(&path)?;
+    println!("metadata: 파일? {}, 크기 {} 바이트, 읽기 전용? {}", meta.is_file(), meta.len(), meta.permissions().readonly());
+    Ok(())
+}
+
+fn read_write() {
+    println!("\n--- 읽고 쓰기 ---");
+
+    // 절 하나에 파일 연산이 여럿 - ? 로 모으려고 io::Result 를 돌려주는 함수로 나눔
+    let dir = match scratch_dir("read-write") {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("임시 디렉터리를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = read_write_in(&dir) {
+        println!("에러: {}", e);
+    }
+    let _ = fs::remove_dir_all(&dir);
+}
+
+fn main() {
+    read_write();
+}
@@ -0,0 +1,128 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 37. std::net 으로 TCP/UDP 네트워킹 - TCP 에코 서버와 클라이언트
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+// --- 다른 절에서 가져온 정의 ---
+
+const LOCALHOST: &str = "127.0.0.1:0";
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+// ----------------------------------------------------------------------------
+// TCP 에코 서버와 클라이언트
+// ----------------------------------------------------------------------------
+
+// 연결 하나 처리 - 받은 줄을 그대로 돌려주고, 상대가 쓰기를 닫으면(EOF) 끝
+// 돌려주는 값: 돌려준 줄 수
+fn handle_client(stream: TcpStream) -> io::Result<usize> {
+    // 읽기와 쓰기를 따로 쥐려고 복제 - 같은 소켓을 가리키는 두 핸들 (C++: dup(fd))
+    let reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    let mut lines = 0;
+    for line in reader.lines() {
+        writeln!(writer, "{}", line?)?;
+        lines += 1;
+    }
+    Ok(lines)
+}
+
+// 에코 서버 - 받아들이는 스레드 하나, 연결마다 처리 스레드 하나
+struct EchoServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    acceptor: Option<JoinHandle<usize>>,
+}
+
+impl EchoServer {
+    fn start() -> io::Result<EchoServer> {
+        // C++ 에서는:
+        // int fd = socket(AF_INET, SOCK_STREAM, 0);
+        // bind(fd, (sockaddr*)&addr, sizeof addr); listen(fd, SOMAXCONN);
+        // 셋이 bind 하나로 - 실패하면 Err (포트 사용 중이면 AddrInUse)
+        let listener = TcpListener::bind(LOCALHOST)?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let stop_flag = Arc::clone(&stop);
+        let acceptor = thread::spawn(move || {
+            let mut handlers = Vec::new();
+            // incoming() = accept() 를 부르는 끝없는 이터레이터
+            for stream in listener.incoming() {
+                // accept 는 블로킹이라 플래그만으로는 깨어나지 않음 - stop() 이 자기에게 접속해서 깨움
+                if stop_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => handlers.push(thread::spawn(move || handle_client(stream))),
+                    // 연결 하나의 실패(상대가 곧바로 끊음 등)로 서버를 멈추지 않음
+                    Err(e) => eprintln!("accept 실패: {}", e),
+                }
+            }
+            // 처리 중인 연결을 모두 기다림 - 받아들인 연결 수를 돌려줌
+            let served = handlers.len();
+            for handler in handlers {
+                let _ = handler.join();
+            }
+            served
+        });
+        Ok(EchoServer { addr, stop, acceptor: Some(acceptor) })
+    }
+
+    // 멈추고 처리한 연결 수를 돌려받음
+    fn stop(mut self) -> usize {
+        self.stop.store(true, Ordering::SeqCst);
+        // accept 에서 잠든 스레드를 깨우는 흔한 방법 - 자기 자신에게 접속 (C++ 에서도 같은 요령)
+        let _ = TcpStream::connect(self.addr);
+        self.acceptor.take().map_or(0, |t| t.join().unwrap_or(0))
+    }
+}
+
+// 클라이언트 - 줄들을 보내고 돌아온 것을 모두 읽음
+fn echo(addr: SocketAddr, lines: &[&str]) -> io::Result<Vec<String>> {
+    // C++: socket() + connect() - 여기서는 하나로
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    for line in lines {
+        writeln!(stream, "{}", line)?;
+    }
+    // 쓰기만 닫음(half-close) - 서버의 lines() 가 EOF 를 보고 끝남, 읽기는 계속 가능
+    // C++: shutdown(fd, SHUT_WR);
+    stream.shutdown(Shutdown::Write)?;
+    BufReader::new(stream).lines().collect()
+}
+
+fn tcp_echo() {
+    println!("--- TCP 에코 ---");
+
+    let server = match EchoServer::start() {
+        Ok(server) => server,
+        Err(e) => {
+            println!("서버를 시작할 수 없음: {}", e);
+            return;
+        }
+    };
+    // 주소의 포트는 실행마다 다름 - 출력하지 않음
+    println!("서버가 루프백에서 기다림 (포트는 운영체제가 고름)");
+
+    match echo(server.addr, &["안녕", "에코 서버", ""]) {
+        Ok(reply) => println!("돌아온 줄: {:?}", reply),
+        Err(e) => println!("클라이언트 에러: {}", e),
+    }
+
+    // TCP 는 바이트 흐름 - write 한 번이 read 한 번으로 온다는 보장이 없음
+    //   그래서 줄(\n), 길이 머리말 같은 "메시지 경계"를 직접 정해야 함 (여기서는 줄)
+    println!("처리한 연결: {}", server.stop());
+}
+
+fn main() {
+    tcp_echo();
+}
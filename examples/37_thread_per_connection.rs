@@ -0,0 +1,144 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 37. std::net 으로 TCP/UDP 네트워킹 - 연결마다 스레드
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+// --- 다른 절에서 가져온 정의 ---
+
+const LOCALHOST: &str = "127.0.0.1:0";
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+fn handle_client(stream: TcpStream) -> io::Result<usize> {
+    // 읽기와 쓰기를 따로 쥐려고 복제 - 같은 소켓을 가리키는 두 핸들 (C++: dup(fd))
+    let reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    let mut lines = 0;
+    for line in reader.lines() {
+        writeln!(writer, "{}", line?)?;
+        lines += 1;
+    }
+    Ok(lines)
+}
+
+struct EchoServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    acceptor: Option<JoinHandle<usize>>,
+}
+
+impl EchoServer {
+    fn start() -> io::Result<EchoServer> {
+        // C++ 에서는:
+        // int fd = socket(AF_INET, SOCK_STREAM, 0);
+        // bind(fd, (sockaddr*)&addr, sizeof addr); listen(fd, SOMAXCONN);
+        // 셋이 bind 하나로 - 실패하면 Err (포트 사용 중이면 AddrInUse)
+        let listener = TcpListener::bind(LOCALHOST)?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let stop_flag = Arc::clone(&stop);
+        let acceptor = thread::spawn(move || {
+            let mut handlers = Vec::new();
+            // incoming() = accept() 를 부르는 끝없는 이터레이터
+            for stream in listener.incoming() {
+                // accept 는 블로킹이라 플래그만으로는 깨어나지 않음 - stop() 이 자기에게 접속해서 깨움
+                if stop_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => handlers.push(thread::spawn(move || handle_client(stream))),
+                    // 연결 하나의 실패(상대가 곧바로 끊음 등)로 서버를 멈추지 않음
+                    Err(e) => eprintln!("accept 실패: {}", e),
+                }
+            }
+            // 처리 중인 연결을 모두 기다림 - 받아들인 연결 수를 돌려줌
+            let served = handlers.len();
+            for handler in handlers {
+                let _ = handler.join();
+            }
+            served
+        });
+        Ok(EchoServer { addr, stop, acceptor: Some(acceptor) })
+    }
+
+    // 멈추고 처리한 연결 수를 돌려받음
+    fn stop(mut self) -> usize {
+        self.stop.store(true, Ordering::SeqCst);
+        // accept 에서 잠든 스레드를 깨우는 흔한 방법 - 자기 자신에게 접속 (C++ 에서도 같은 요령)
+        let _ = TcpStream::connect(self.addr);
+        self.acceptor.take().map_or(0, |t| t.join().unwrap_or(0))
+    }
+}
+
+fn echo(addr: SocketAddr, lines: &[&str]) -> io::Result<Vec<String>> {
+    // C++: socket() + connect() - 여기서는 하나로
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    for line in lines {
+        writeln!(stream, "{}", line)?;
+    }
+    // 쓰기만 닫음(half-close) - 서버의 lines() 가 EOF 를 보고 끝남, 읽기는 계속 가능
+    // C++: shutdown(fd, SHUT_WR);
+    stream.shutdown(Shutdown::Write)?;
+    BufReader::new(stream).lines().collect()
+}
+
+// ----------------------------------------------------------------------------
+// 연결마다 스레드
+// ----------------------------------------------------------------------------
+
+fn thread_per_connection() {
+    println!("\n--- 연결마다 스레드 ---");
+
+    // C++ 에서는:
+    // while (true) { int client = accept(fd, ...); std::thread(handle, client).detach(); }
+    // detach 한 스레드는 기다릴 방법이 없음 - 여기서는 JoinHandle 을 모아 stop 에서 join
+
+    let server = match EchoServer::start() {
+        Ok(server) => server,
+        Err(e) => {
+            println!("서버를 시작할 수 없음: {}", e);
+            return;
+        }
+    };
+
+    // 클라이언트 5개가 동시에 - 서버도 연결마다 스레드라 서로 기다리지 않음
+    let replies: Vec<io::Result<Vec<String>>> = thread::scope(|s| {
+        let handles: Vec<_> = (0..5)
+            .map(|i| {
+                let addr = server.addr;
+                s.spawn(move || {
+                    let lines = [format!("클라이언트 {} 의 첫 줄", i), format!("클라이언트 {} 의 둘째 줄", i)];
+                    let refs: Vec<&str> = lines.iter().map(String::as_str).collect();
+                    echo(addr, &refs)
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    for (i, reply) in replies.iter().enumerate() {
+        match reply {
+            Ok(lines) => println!("클라이언트 {}: {}줄 돌아옴, 첫 줄 {:?}", i, lines.len(), lines.first()),
+            Err(e) => println!("클라이언트 {}: 에러 {}", i, e),
+        }
+    }
+    println!("처리한 연결: {}", server.stop());
+
+    // 한계
+    //   스레드마다 스택(기본 2MB 예약)과 문맥 전환 비용 - 연결 수천 개에서는 무거움
+    //   스레드 수를 제한하려면 34장의 스레드 풀에 handle_client 를 넣음
+    //   더 많은 연결은 비동기 I/O - tokio 의 TcpListener (17장, 20장 채팅 서버)
+}
+
+fn main() {
+    thread_per_connection();
+}
@@ -0,0 +1,81 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 37. std::net 으로 TCP/UDP 네트워킹 - 시간 제한과 에러
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+// --- 다른 절에서 가져온 정의 ---
+
+const LOCALHOST: &str = "127.0.0.1:0";
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+// ----------------------------------------------------------------------------
+// 시간 제한과 에러
+// ----------------------------------------------------------------------------
+
+fn timeouts_and_errors() {
+    println!("\n--- 시간 제한과 에러 ---");
+
+    // 아무도 듣지 않는 포트 - 리스너를 만들었다가 닫아서 빈 포트를 얻음 (클로저가 끝나면 l 이 drop)
+    let closed = TcpListener::bind(LOCALHOST).and_then(|l| l.local_addr());
+    if let Ok(addr) = closed {
+        // C++: connect() == -1 && errno == ECONNREFUSED
+        match TcpStream::connect_timeout(&addr, TIMEOUT) {
+            Ok(_) => println!("연결됨"),
+            Err(e) => println!("닫힌 포트에 연결: kind {:?}", e.kind()),
+        }
+    }
+
+    // 받아들이기만 하고 답하지 않는 서버 - 읽기 시간 제한이 없으면 영원히 기다림
+    let silent = match TcpListener::bind(LOCALHOST) {
+        Ok(listener) => listener,
+        Err(e) => {
+            println!("리스너를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    let result = silent.local_addr().and_then(|addr| {
+        let mut stream = TcpStream::connect(addr)?;
+        // C++: setsockopt(fd, SOL_SOCKET, SO_RCVTIMEO, &tv, sizeof tv);
+        stream.set_read_timeout(Some(Duration::from_millis(50)))?;
+        let mut buf = [0u8; 16];
+        stream.read(&mut buf)
+    });
+    match result {
+        Ok(n) => println!("{}바이트 읽음", n),
+        // 운영체제마다 kind 가 다름 (유닉스 WouldBlock, 윈도우 TimedOut) - 둘 다 시간 초과로
+        Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+            println!("50ms 안에 응답 없음 - 시간 초과")
+        }
+        Err(e) => println!("에러: {}", e),
+    }
+
+    // 상대가 끊으면 read 가 Ok(0) - 에러가 아니라 EOF (C++ 의 recv() == 0)
+    let eof = TcpListener::bind(LOCALHOST).and_then(|listener| {
+        let mut client = TcpStream::connect(listener.local_addr()?)?;
+        let (server_side, _) = listener.accept()?;
+        drop(server_side); // 서버 쪽 소켓을 닫음
+        let mut buf = [0u8; 16];
+        client.read(&mut buf)
+    });
+    println!("상대가 닫은 뒤 read: {:?}", eof.map_err(|e| e.kind()));
+
+    // 자주 보는 ErrorKind
+    //   ConnectionRefused - 듣는 프로그램이 없음
+    //   ConnectionReset   - 상대가 비정상 종료 (RST)
+    //   BrokenPipe        - 닫힌 연결에 쓰기 (C++ 에서는 SIGPIPE 로 프로세스가 죽을 수 있음 - Rust 는 무시하도록 설정)
+    //   AddrInUse         - 이미 쓰는 포트에 bind
+    //   WouldBlock        - 논블로킹 소켓(set_nonblocking)이나 시간 제한
+}
+
+fn main() {
+    timeouts_and_errors();
+}
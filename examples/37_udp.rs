@@ -0,0 +1,74 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 37. std::net 으로 TCP/UDP 네트워킹 - UDP
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+// --- 다른 절에서 가져온 정의 ---
+
+const LOCALHOST: &str = "127.0.0.1:0";
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+// ----------------------------------------------------------------------------
+// UDP
+// ----------------------------------------------------------------------------
+
+fn udp() {
+    println!("\n--- UDP ---");
+
+    // C++ 에서는:
+    // int fd = socket(AF_INET, SOCK_DGRAM, 0); bind(...);
+    // recvfrom(fd, buf, sizeof buf, 0, (sockaddr*)&from, &len);
+    // sendto(fd, buf, n, 0, (sockaddr*)&from, len);
+
+    let result = (|| -> io::Result<()> {
+        let server = UdpSocket::bind(LOCALHOST)?;
+        let client = UdpSocket::bind(LOCALHOST)?;
+        server.set_read_timeout(Some(TIMEOUT))?;
+        client.set_read_timeout(Some(TIMEOUT))?;
+
+        // 연결이 없음 - 보낼 때마다 주소를 줌
+        client.send_to("ping".as_bytes(), server.local_addr()?)?;
+        let mut buf = [0u8; 1500];
+        let (n, from) = server.recv_from(&mut buf)?;
+        println!("서버가 받음: {:?} (보낸 쪽이 클라이언트? {})", String::from_utf8_lossy(&buf[..n]), from == client.local_addr()?);
+        server.send_to("pong".as_bytes(), from)?;
+
+        let (n, _) = client.recv_from(&mut buf)?;
+        println!("클라이언트가 받음: {:?}", String::from_utf8_lossy(&buf[..n]));
+
+        // 데이터그램은 경계가 유지됨 - 두 번 보내면 두 번 받음 (TCP 와 다름)
+        client.connect(server.local_addr()?)?; // 상대를 고정 - 이후 send/recv 만으로
+        client.send(b"one")?;
+        client.send(b"two")?;
+        let (a, _) = server.recv_from(&mut buf)?;
+        let first = String::from_utf8_lossy(&buf[..a]).into_owned();
+        let (b, _) = server.recv_from(&mut buf)?;
+        println!("두 데이터그램: {:?}, {:?}", first, String::from_utf8_lossy(&buf[..b]));
+
+        // 버퍼보다 큰 데이터그램은 잘림 - 남은 부분은 버려짐
+        client.send(b"0123456789")?;
+        let mut small = [0u8; 4];
+        let n = server.recv(&mut small)?;
+        println!("4바이트 버퍼로 받음: {:?}", String::from_utf8_lossy(&small[..n]));
+        Ok(())
+    })();
+    if let Err(e) = result {
+        println!("UDP 에러: {}", e);
+    }
+
+    // UDP 는 도착, 순서, 중복 없음을 보장하지 않음 - 루프백에서는 거의 잃지 않지만 실제 네트워크는 다름
+    //   재전송, 순서 번호가 필요하면 직접 (또는 QUIC 같은 프로토콜)
+}
+
+fn main() {
+    udp();
+}
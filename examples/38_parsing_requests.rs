@@ -0,0 +1,313 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 38. 비동기 HTTP 서버와 클라이언트 - 요청 파싱
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::task::JoinSet;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn text(status: u16, body: impl Into<String>) -> Response {
+        Response { status, content_type: "text/plain; charset=utf-8", body: body.into() }
+    }
+
+    fn json(body: String) -> Response {
+        Response { status: 200, content_type: "application/json", body }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "",
+        }
+    }
+
+    // 상태 줄 + 머리 + 빈 줄 + 본문 - Content-Length 는 글자 수가 아니라 바이트 수
+    fn to_bytes(&self, keep_alive: bool) -> Vec<u8> {
+        let head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: {}\r\n\r\n",
+            self.status,
+            self.reason(),
+            self.content_type,
+            self.body.len(),
+            if keep_alive { "keep-alive" } else { "close" }
+        );
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(self.body.as_bytes());
+        bytes
+    }
+}
+
+#[derive(Default)]
+pub struct AppState {
+    requests: AtomicUsize,
+}
+
+pub fn route(req: &Request, state: &AppState) -> Response {
+    let count = state.requests.fetch_add(1, Ordering::SeqCst) + 1;
+    match (req.method.as_str(), req.path.as_str()) {
+        ("GET", "/") => Response::text(200, "작은 HTTP 서버입니다\n"),
+        ("GET", "/hello") => Response::text(200, format!("안녕하세요, {}님\n", req.query("name").unwrap_or("손님"))),
+        ("GET", "/stats") => Response::json(format!("{{\"requests\":{}}}", count)),
+        ("POST", "/echo") => Response::text(200, req.body.clone()),
+        // 경로는 있는데 메서드가 틀림
+        (_, "/" | "/hello" | "/stats" | "/echo") => Response::text(405, "허용되지 않는 메서드\n"),
+        _ => Response::text(404, format!("{} 를 찾을 수 없음\n", req.path)),
+    }
+}
+
+const IDLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub async fn handle_connection<S>(stream: S, state: Arc<AppState>) -> io::Result<usize>
+where
+    S: AsyncRead + AsyncWrite,
+{
+    // 읽는 쪽과 쓰는 쪽을 나눔 - 읽는 쪽만 BufReader 로 (37장의 try_clone 대신)
+    let (read, mut write) = tokio::io::split(stream);
+    let mut reader = BufReader::new(read);
+    let mut served = 0;
+    loop {
+        let request = match tokio::time::timeout(IDLE_TIMEOUT, read_request(&mut reader)).await {
+            Err(_) | Ok(Ok(None)) => break,
+            Ok(Ok(Some(request))) => request,
+            Ok(Err(HttpError::BadRequest(why))) => {
+                let response = Response::text(400, format!("{}\n", why));
+                write.write_all(&response.to_bytes(false)).await?;
+                break;
+            }
+            Ok(Err(HttpError::Io(e))) => return Err(e),
+        };
+        let keep_alive = request.keep_alive();
+        write.write_all(&route(&request, &state).to_bytes(keep_alive)).await?;
+        served += 1;
+        if !keep_alive {
+            break;
+        }
+    }
+    write.shutdown().await?;
+    Ok(served)
+}
+
+pub async fn serve(listener: TcpListener, state: Arc<AppState>, mut shutdown: watch::Receiver<bool>) -> io::Result<usize> {
+    let mut tasks = JoinSet::new();
+    let mut accepted = 0;
+    loop {
+        tokio::select! {
+            conn = listener.accept() => {
+                let (stream, _) = conn?;
+                accepted += 1;
+                let state = Arc::clone(&state);
+                // 연결마다 태스크 - 느린 클라이언트 하나가 다른 연결을 막지 않음
+                tasks.spawn(async move {
+                    if let Err(e) = handle_connection(stream, state).await {
+                        eprintln!("연결 에러: {}", e);
+                    }
+                });
+            }
+            _ = shutdown.changed() => break,
+        }
+    }
+    drop(listener);
+    while tasks.join_next().await.is_some() {}
+    Ok(accepted)
+}
+
+// ----------------------------------------------------------------------------
+// 요청 파싱
+// ----------------------------------------------------------------------------
+
+// 머리 줄 수와 본문 크기의 상한 - 없으면 악의적인 클라이언트가 메모리를 끝없이 쓰게 만듦
+const MAX_HEADERS: usize = 64;
+const MAX_BODY: usize = 64 * 1024;
+
+// 9장의 관례: 에러를 열거형 하나로 모으고 Display + Error + From 구현
+#[derive(Debug)]
+pub enum HttpError {
+    Io(io::Error),
+    // 클라이언트 잘못 - 400 으로 답하고 연결을 닫음
+    BadRequest(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Io(e) => write!(f, "입출력 에러: {}", e),
+            HttpError::BadRequest(why) => write!(f, "잘못된 요청: {}", why),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HttpError::Io(e) => Some(e),
+            HttpError::BadRequest(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for HttpError {
+    fn from(e: io::Error) -> Self {
+        HttpError::Io(e)
+    }
+}
+
+fn bad(why: &str) -> HttpError {
+    HttpError::BadRequest(why.to_string())
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    // 머리 이름은 대소문자를 가리지 않음 (HTTP 규칙)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    // HTTP/1.1 은 기본이 keep-alive - "Connection: close" 일 때만 닫음
+    pub fn keep_alive(&self) -> bool {
+        !self.header("connection").is_some_and(|v| v.eq_ignore_ascii_case("close"))
+    }
+}
+
+// "a=1&b=2" → [("a","1"), ("b","2")] (퍼센트 디코딩은 생략 - src/serve.rs 의 percent_decode 참고)
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (name.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+// 요청 하나를 읽음 - 연결이 깨끗하게 닫혔으면(다음 요청이 없음) None
+// R 이 AsyncBufRead 라 TcpStream, duplex, &[u8] 무엇이든
+pub async fn read_request<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<Option<Request>, HttpError> {
+    // 요청 줄: "GET /hello?name=kim HTTP/1.1"
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+    let mut parts = line.split_whitespace();
+    let (method, target, version) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(m), Some(t), Some(v)) => (m.to_string(), t.to_string(), v),
+        _ => return Err(bad("요청 줄은 '메서드 경로 버전'")),
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(bad("HTTP/1.x 만 지원"));
+    }
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_query(query)),
+        None => (target, Vec::new()),
+    };
+
+    // 머리: "이름: 값" 줄들, 빈 줄에서 끝
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).await? == 0 {
+            return Err(bad("머리가 끝나기 전에 연결이 닫힘"));
+        }
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() {
+            break;
+        }
+        let (name, value) = trimmed.split_once(':').ok_or_else(|| bad("머리에 ':' 가 없음"))?;
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+        if headers.len() > MAX_HEADERS {
+            return Err(bad("머리가 너무 많음"));
+        }
+    }
+
+    let mut request = Request { method, path, query, headers, body: String::new() };
+
+    // 본문: Content-Length 만큼 정확히 (청크 전송은 지원하지 않음)
+    let length = match request.header("content-length") {
+        Some(v) => v.parse::<usize>().map_err(|_| bad("Content-Length 가 숫자가 아님"))?,
+        None => 0,
+    };
+    if length > MAX_BODY {
+        return Err(bad("본문이 너무 큼"));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await?;
+    request.body = String::from_utf8(body).map_err(|_| bad("본문이 UTF-8 이 아님"))?;
+    Ok(Some(request))
+}
+
+async fn parsing_requests() {
+    println!("--- 요청 파싱 ---");
+
+    // C++ 에서는 (Boost.Beast):
+    // beast::flat_buffer buf; http::request<http::string_body> req;
+    // co_await http::async_read(stream, buf, req, use_awaitable);
+
+    // HTTP/1.1 요청은 글자 - 줄 끝은 \r\n, 머리 뒤에 빈 줄, 그다음 본문
+    let raw = "POST /echo?lang=ko&debug HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\n\r\nhello";
+    // &[u8] 도 AsyncRead - 네트워크 없이 파서만 시험
+    let mut reader = BufReader::new(raw.as_bytes());
+    match read_request(&mut reader).await {
+        Ok(Some(req)) => {
+            println!("메서드 {}, 경로 {}, 쿼리 {:?}", req.method, req.path, req.query);
+            println!("Host 머리: {:?}, 본문 {:?}", req.header("HOST"), req.body);
+            println!("keep-alive? {}", req.keep_alive());
+        }
+        Ok(None) => println!("요청 없음"),
+        Err(e) => println!("에러: {}", e),
+    }
+
+    // 잘못된 요청들 - 파서는 panic 하지 않고 BadRequest 로
+    let bad_requests = [
+        "GET\r\n\r\n",
+        "GET / SPDY/3\r\n\r\n",
+        "GET / HTTP/1.1\r\nno-colon\r\n\r\n",
+        "POST / HTTP/1.1\r\nContent-Length: ten\r\n\r\n",
+        "GET / HTTP/1.1\r\nHost: x\r\n",
+    ];
+    for raw in bad_requests {
+        let mut reader = BufReader::new(raw.as_bytes());
+        match read_request(&mut reader).await {
+            Ok(_) => println!("{:<45} → 통과", format!("{:?}", raw)),
+            Err(e) => println!("{:<45} → {}", format!("{:?}", raw), e),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    parsing_requests().await;
+}
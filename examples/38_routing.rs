@@ -0,0 +1,149 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 38. 비동기 HTTP 서버와 클라이언트 - 라우팅과 응답
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::task::JoinSet;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    // 머리 이름은 대소문자를 가리지 않음 (HTTP 규칙)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    // HTTP/1.1 은 기본이 keep-alive - "Connection: close" 일 때만 닫음
+    pub fn keep_alive(&self) -> bool {
+        !self.header("connection").is_some_and(|v| v.eq_ignore_ascii_case("close"))
+    }
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (name.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// 라우팅과 응답
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn text(status: u16, body: impl Into<String>) -> Response {
+        Response { status, content_type: "text/plain; charset=utf-8", body: body.into() }
+    }
+
+    fn json(body: String) -> Response {
+        Response { status: 200, content_type: "application/json", body }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "",
+        }
+    }
+
+    // 상태 줄 + 머리 + 빈 줄 + 본문 - Content-Length 는 글자 수가 아니라 바이트 수
+    fn to_bytes(&self, keep_alive: bool) -> Vec<u8> {
+        let head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: {}\r\n\r\n",
+            self.status,
+            self.reason(),
+            self.content_type,
+            self.body.len(),
+            if keep_alive { "keep-alive" } else { "close" }
+        );
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(self.body.as_bytes());
+        bytes
+    }
+}
+
+// 모든 연결 태스크가 Arc 로 공유하는 서버 상태
+#[derive(Default)]
+pub struct AppState {
+    requests: AtomicUsize,
+}
+
+// 경로로 나눔 - (메서드, 경로) 짝에 match (C++ 에서는 if/else 사슬이나 std::map<string, handler>)
+pub fn route(req: &Request, state: &AppState) -> Response {
+    let count = state.requests.fetch_add(1, Ordering::SeqCst) + 1;
+    match (req.method.as_str(), req.path.as_str()) {
+        ("GET", "/") => Response::text(200, "작은 HTTP 서버입니다\n"),
+        ("GET", "/hello") => Response::text(200, format!("안녕하세요, {}님\n", req.query("name").unwrap_or("손님"))),
+        ("GET", "/stats") => Response::json(format!("{{\"requests\":{}}}", count)),
+        ("POST", "/echo") => Response::text(200, req.body.clone()),
+        // 경로는 있는데 메서드가 틀림
+        (_, "/" | "/hello" | "/stats" | "/echo") => Response::text(405, "허용되지 않는 메서드\n"),
+        _ => Response::text(404, format!("{} 를 찾을 수 없음\n", req.path)),
+    }
+}
+
+fn routing() {
+    println!("\n--- 라우팅과 응답 ---");
+
+    // route 는 동기 함수 - 입출력이 없는 부분은 async 일 필요가 없고 테스트도 쉬움
+    let state = AppState::default();
+    let request = |method: &str, target: &str| {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: parse_query(query),
+            headers: Vec::new(),
+            body: String::new(),
+        }
+    };
+    for (method, target) in [("GET", "/"), ("GET", "/hello?name=민수"), ("DELETE", "/hello"), ("GET", "/nope"), ("GET", "/stats")] {
+        let response = route(&request(method, target), &state);
+        println!("{:<6} {:<18} → {} {:?}", method, target, response.status, response.body.trim_end());
+    }
+
+    // 응답의 실제 모양
+    let bytes = Response::text(200, "안녕").to_bytes(false);
+    println!("응답 바이트:\n{}", String::from_utf8_lossy(&bytes).replace("\r\n", "\\r\\n\n"));
+}
+
+fn main() {
+    routing();
+}
@@ -0,0 +1,369 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 38. 비동기 HTTP 서버와 클라이언트 - 서버 - 연결마다 태스크
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::task::JoinSet;
+
+// --- 다른 절에서 가져온 정의 ---
+
+const MAX_HEADERS: usize = 64;
+
+const MAX_BODY: usize = 64 * 1024;
+
+#[derive(Debug)]
+pub enum HttpError {
+    Io(io::Error),
+    // 클라이언트 잘못 - 400 으로 답하고 연결을 닫음
+    BadRequest(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Io(e) => write!(f, "입출력 에러: {}", e),
+            HttpError::BadRequest(why) => write!(f, "잘못된 요청: {}", why),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HttpError::Io(e) => Some(e),
+            HttpError::BadRequest(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for HttpError {
+    fn from(e: io::Error) -> Self {
+        HttpError::Io(e)
+    }
+}
+
+fn bad(why: &str) -> HttpError {
+    HttpError::BadRequest(why.to_string())
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    // 머리 이름은 대소문자를 가리지 않음 (HTTP 규칙)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    // HTTP/1.1 은 기본이 keep-alive - "Connection: close" 일 때만 닫음
+    pub fn keep_alive(&self) -> bool {
+        !self.header("connection").is_some_and(|v| v.eq_ignore_ascii_case("close"))
+    }
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (name.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+pub async fn read_request<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<Option<Request>, HttpError> {
+    // 요청 줄: "GET /hello?name=kim HTTP/1.1"
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+    let mut parts = line.split_whitespace();
+    let (method, target, version) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(m), Some(t), Some(v)) => (m.to_string(), t.to_string(), v),
+        _ => return Err(bad("요청 줄은 '메서드 경로 버전'")),
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(bad("HTTP/1.x 만 지원"));
+    }
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_query(query)),
+        None => (target, Vec::new()),
+    };
+
+    // 머리: "이름: 값" 줄들, 빈 줄에서 끝
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).await? == 0 {
+            return Err(bad("머리가 끝나기 전에 연결이 닫힘"));
+        }
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() {
+            break;
+        }
+        let (name, value) = trimmed.split_once(':').ok_or_else(|| bad("머리에 ':' 가 없음"))?;
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+        if headers.len() > MAX_HEADERS {
+            return Err(bad("머리가 너무 많음"));
+        }
+    }
+
+    let mut request = Request { method, path, query, headers, body: String::new() };
+
+    // 본문: Content-Length 만큼 정확히 (청크 전송은 지원하지 않음)
+    let length = match request.header("content-length") {
+        Some(v) => v.parse::<usize>().map_err(|_| bad("Content-Length 가 숫자가 아님"))?,
+        None => 0,
+    };
+    if length > MAX_BODY {
+        return Err(bad("본문이 너무 큼"));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await?;
+    request.body = String::from_utf8(body).map_err(|_| bad("본문이 UTF-8 이 아님"))?;
+    Ok(Some(request))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn text(status: u16, body: impl Into<String>) -> Response {
+        Response { status, content_type: "text/plain; charset=utf-8", body: body.into() }
+    }
+
+    fn json(body: String) -> Response {
+        Response { status: 200, content_type: "application/json", body }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "",
+        }
+    }
+
+    // 상태 줄 + 머리 + 빈 줄 + 본문 - Content-Length 는 글자 수가 아니라 바이트 수
+    fn to_bytes(&self, keep_alive: bool) -> Vec<u8> {
+        let head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: {}\r\n\r\n",
+            self.status,
+            self.reason(),
+            self.content_type,
+            self.body.len(),
+            if keep_alive { "keep-alive" } else { "close" }
+        );
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(self.body.as_bytes());
+        bytes
+    }
+}
+
+#[derive(Default)]
+pub struct AppState {
+    requests: AtomicUsize,
+}
+
+pub fn route(req: &Request, state: &AppState) -> Response {
+    let count = state.requests.fetch_add(1, Ordering::SeqCst) + 1;
+    match (req.method.as_str(), req.path.as_str()) {
+        ("GET", "/") => Response::text(200, "작은 HTTP 서버입니다\n"),
+        ("GET", "/hello") => Response::text(200, format!("안녕하세요, {}님\n", req.query("name").unwrap_or("손님"))),
+        ("GET", "/stats") => Response::json(format!("{{\"requests\":{}}}", count)),
+        ("POST", "/echo") => Response::text(200, req.body.clone()),
+        // 경로는 있는데 메서드가 틀림
+        (_, "/" | "/hello" | "/stats" | "/echo") => Response::text(405, "허용되지 않는 메서드\n"),
+        _ => Response::text(404, format!("{} 를 찾을 수 없음\n", req.path)),
+    }
+}
+
+pub struct Client {
+    reader: BufReader<tokio::net::tcp::OwnedReadHalf>,
+    writer: tokio::net::tcp::OwnedWriteHalf,
+}
+
+impl Client {
+    pub async fn connect(addr: SocketAddr) -> io::Result<Client> {
+        // into_split - 소유한 두 반쪽 (split 은 빌린 반쪽이라 구조체에 담기 어려움)
+        let (read, writer) = TcpStream::connect(addr).await?.into_split();
+        Ok(Client { reader: BufReader::new(read), writer })
+    }
+
+    // 요청을 보내고 (상태 코드, 본문) 을 받음
+    pub async fn send(&mut self, method: &str, path: &str, body: &str, close: bool) -> Result<(u16, String), HttpError> {
+        let request = format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            if close { "close" } else { "keep-alive" },
+            body
+        );
+        self.writer.write_all(request.as_bytes()).await?;
+
+        // 상태 줄 "HTTP/1.1 200 OK"
+        let mut line = String::new();
+        self.reader.read_line(&mut line).await?;
+        let status = line.split_whitespace().nth(1).and_then(|s| s.parse().ok()).ok_or_else(|| bad("상태 줄이 이상함"))?;
+
+        // 머리에서 Content-Length 만 봄
+        let mut length = 0;
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line).await? == 0 {
+                return Err(bad("응답 머리 중간에 연결이 닫힘"));
+            }
+            let trimmed = line.trim_end();
+            if trimmed.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = trimmed.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    length = value.trim().parse().map_err(|_| bad("Content-Length 가 숫자가 아님"))?;
+                }
+            }
+        }
+        let mut body = vec![0; length];
+        self.reader.read_exact(&mut body).await?;
+        Ok((status, String::from_utf8_lossy(&body).into_owned()))
+    }
+}
+
+pub async fn get(addr: SocketAddr, path: &str) -> Result<(u16, String), HttpError> {
+    Client::connect(addr).await?.send("GET", path, "", true).await
+}
+
+// ----------------------------------------------------------------------------
+// 서버 - 연결마다 태스크
+// ----------------------------------------------------------------------------
+
+// keep-alive 연결이 이 시간 동안 다음 요청을 보내지 않으면 닫음 - 정상 종료가 영원히 기다리지 않게
+const IDLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+// 연결 하나 - 요청을 읽고 답하기를 연결이 닫힐 때까지 반복, 처리한 요청 수를 돌려줌
+pub async fn handle_connection<S>(stream: S, state: Arc<AppState>) -> io::Result<usize>
+where
+    S: AsyncRead + AsyncWrite,
+{
+    // 읽는 쪽과 쓰는 쪽을 나눔 - 읽는 쪽만 BufReader 로 (37장의 try_clone 대신)
+    let (read, mut write) = tokio::io::split(stream);
+    let mut reader = BufReader::new(read);
+    let mut served = 0;
+    loop {
+        let request = match tokio::time::timeout(IDLE_TIMEOUT, read_request(&mut reader)).await {
+            Err(_) | Ok(Ok(None)) => break,
+            Ok(Ok(Some(request))) => request,
+            Ok(Err(HttpError::BadRequest(why))) => {
+                let response = Response::text(400, format!("{}\n", why));
+                write.write_all(&response.to_bytes(false)).await?;
+                break;
+            }
+            Ok(Err(HttpError::Io(e))) => return Err(e),
+        };
+        let keep_alive = request.keep_alive();
+        write.write_all(&route(&request, &state).to_bytes(keep_alive)).await?;
+        served += 1;
+        if !keep_alive {
+            break;
+        }
+    }
+    write.shutdown().await?;
+    Ok(served)
+}
+
+// 종료 신호가 올 때까지 연결을 받고, 진행 중인 연결이 끝나기를 기다림 - 처리한 연결 수
+pub async fn serve(listener: TcpListener, state: Arc<AppState>, mut shutdown: watch::Receiver<bool>) -> io::Result<usize> {
+    let mut tasks = JoinSet::new();
+    let mut accepted = 0;
+    loop {
+        tokio::select! {
+            conn = listener.accept() => {
+                let (stream, _) = conn?;
+                accepted += 1;
+                let state = Arc::clone(&state);
+                // 연결마다 태스크 - 느린 클라이언트 하나가 다른 연결을 막지 않음
+                tasks.spawn(async move {
+                    if let Err(e) = handle_connection(stream, state).await {
+                        eprintln!("연결 에러: {}", e);
+                    }
+                });
+            }
+            _ = shutdown.changed() => break,
+        }
+    }
+    drop(listener);
+    while tasks.join_next().await.is_some() {}
+    Ok(accepted)
+}
+
+// 서버를 띄우고 (주소, 종료 신호, 서버 태스크) 를 돌려줌 - 아래 두 절이 함께 씀
+async fn start_server() -> io::Result<(SocketAddr, watch::Sender<bool>, tokio::task::JoinHandle<io::Result<usize>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let (stop, shutdown) = watch::channel(false);
+    let server = tokio::spawn(serve(listener, Arc::new(AppState::default()), shutdown));
+    Ok((addr, stop, server))
+}
+
+async fn server() {
+    println!("\n--- 서버 - 연결마다 태스크 ---");
+
+    // C++ 에서는 (Boost.Asio 코루틴):
+    // for (;;) { auto socket = co_await acceptor.async_accept(use_awaitable);
+    //            co_spawn(ex, session(std::move(socket)), detached); }
+
+    let (addr, stop, server) = start_server().await.unwrap();
+
+    // 클라이언트 20개가 동시에 - 태스크라 스레드 20개가 필요하지 않음
+    let mut clients = JoinSet::new();
+    for i in 0..20 {
+        clients.spawn(async move { (i, get(addr, &format!("/hello?name=손님{}", i)).await) });
+    }
+    let mut replies = Vec::new();
+    while let Some(joined) = clients.join_next().await {
+        if let Ok((i, Ok((status, body)))) = joined {
+            replies.push((i, status, body));
+        }
+    }
+    // 끝난 순서는 실행마다 다름 - 번호순으로
+    replies.sort();
+    println!("동시 요청 {}개 성공, 모두 200? {}", replies.len(), replies.iter().all(|(_, status, _)| *status == 200));
+    println!("첫 응답: {:?}", replies.first().map(|(_, _, body)| body.trim_end()));
+
+    // 정상 종료 - 새 연결을 멈추고 진행 중인 연결을 기다림 (20장과 같은 모양)
+    stop.send(true).unwrap();
+    println!("처리한 연결 수: {:?}", server.await.unwrap());
+}
+
+#[tokio::main]
+async fn main() {
+    server().await;
+}
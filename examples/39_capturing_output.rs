@@ -0,0 +1,79 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 39. 프로세스 실행과 파이프 - 출력 잡기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn sh(script: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.args(["-c", script]);
+    command
+}
+
+// ----------------------------------------------------------------------------
+// 출력 잡기
+// ----------------------------------------------------------------------------
+
+fn capturing_output() {
+    println!("\n--- 출력 잡기 ---");
+
+    // C++ 에서는:
+    // FILE* p = popen("cmd 2>&1", "r");   // stdout 만, stderr 는 셸 리다이렉트로
+    // while (fgets(buf, sizeof buf, p)) ...; int rc = pclose(p);
+
+    // output() 은 stdout 과 stderr 를 따로 잡음 - 둘 다 Vec<u8> (UTF-8 이라는 보장이 없음)
+    match sh("echo 표준 출력; echo 표준 에러 >&2; exit 2").output() {
+        Ok(out) => {
+            println!("stdout: {:?}", String::from_utf8_lossy(&out.stdout).trim_end());
+            println!("stderr: {:?}", String::from_utf8_lossy(&out.stderr).trim_end());
+            println!("status: {:?}", out.status.code());
+        }
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // 줄 단위로 흘려 읽기 - 출력이 크거나 오래 걸리는 명령 (빌드 로그 등)
+    match sh("for i in 1 2 3; do echo 줄 $i; done").stdout(Stdio::piped()).spawn() {
+        Ok(mut child) => {
+            if let Some(stdout) = child.stdout.take() {
+                for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                    println!("  받음: {}", line);
+                }
+            }
+            let _ = child.wait();
+        }
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // stdin 에 쓰고 stdout 을 읽기 - 양쪽을 다 잡으면 교착(deadlock)에 주의
+    // 자식의 stdout 파이프 버퍼(보통 64KiB)가 차면 자식은 쓰기에서 멈추고,
+    // 부모는 stdin 쓰기에서 멈춤 → 둘 다 영원히 기다림
+    // 해결: 쓰기를 다른 스레드로 보내고, 이 스레드는 읽기 (wait_with_output)
+    let input: String = (0..20_000).map(|i| format!("{}\n", i % 7)).collect();
+    match filter(Command::new("sort").args(["-u"]), input) {
+        Ok(out) => println!("sort -u 결과 (입력 {}KB): {:?}", 20_000 * 2 / 1000, out.lines().collect::<Vec<_>>()),
+        Err(e) => println!("sort 실패: {}", e),
+    }
+}
+
+// 입력을 자식의 stdin 으로 흘리고 stdout 전체를 돌려줌 - 셸의 `echo input | command`
+fn filter(command: &mut Command, input: String) -> io::Result<String> {
+    let mut child = command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+    let mut stdin = child.stdin.take().ok_or(ErrorKind::BrokenPipe)?;
+    // 쓰기 스레드가 끝나면 stdin 이 drop 되어 닫힘 → 자식이 EOF 를 받음
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output()?;
+    writer.join().map_err(|_| io::Error::other("쓰기 스레드 panic"))??;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn main() {
+    capturing_output();
+}
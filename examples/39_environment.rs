@@ -0,0 +1,86 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 39. 프로세스 실행과 파이프 - 환경 변수와 작업 디렉터리
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn sh(script: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.args(["-c", script]);
+    command
+}
+
+fn filter(command: &mut Command, input: String) -> io::Result<String> {
+    let mut child = command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+    let mut stdin = child.stdin.take().ok_or(ErrorKind::BrokenPipe)?;
+    // 쓰기 스레드가 끝나면 stdin 이 drop 되어 닫힘 → 자식이 EOF 를 받음
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output()?;
+    writer.join().map_err(|_| io::Error::other("쓰기 스레드 panic"))??;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// ----------------------------------------------------------------------------
+// 환경 변수와 작업 디렉터리
+// ----------------------------------------------------------------------------
+
+fn environment() {
+    println!("\n--- 환경 변수와 작업 디렉터리 ---");
+
+    // C++ 에서는:
+    // fork 뒤 자식에서 setenv/unsetenv/chdir 후 exec, 또는 execve 에 envp 배열
+    // Windows: CreateProcess 의 lpEnvironment, lpCurrentDirectory
+    // Rust 는 Command 에 설정 - 부모의 환경은 바뀌지 않음
+
+    // env - 자식에게만 추가/덮어쓰기
+    match sh("echo $GREETING, $WHO").env("GREETING", "안녕").env("WHO", "자식").output() {
+        Ok(out) => println!("env: {}", String::from_utf8_lossy(&out.stdout).trim_end()),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+    println!("부모의 GREETING: {:?}", std::env::var("GREETING").ok());
+
+    // env_clear - 물려받은 환경을 모두 지움 (PATH 도 사라지므로 sh 는 절대 경로로 찾거나 PATH 를 다시 넣음)
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    match sh("env | sort").env_clear().env("PATH", &path).env("ONLY", "1").output() {
+        Ok(out) => {
+            let names: Vec<String> = String::from_utf8_lossy(&out.stdout)
+                .lines()
+                .filter_map(|line| line.split_once('=').map(|(name, _)| name.to_string()))
+                // 셸이 스스로 넣는 변수(PWD, SHLVL 등)는 셸마다 달라서 제외
+                .filter(|name| name == "ONLY" || name == "PATH" || name == "HOME")
+                .collect();
+            println!("env_clear 뒤 남은 변수: {:?}", names);
+        }
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // env_remove - 하나만 빼기 (예: 자식이 부모의 설정을 물려받지 않게)
+    match sh("echo ${HOME:-없음}").env_remove("HOME").output() {
+        Ok(out) => println!("env_remove(\"HOME\"): {}", String::from_utf8_lossy(&out.stdout).trim_end()),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // current_dir - 자식의 작업 디렉터리 (부모의 std::env::set_current_dir 는 전역이라 스레드 사이에 위험)
+    let dir = std::env::temp_dir();
+    match Command::new("pwd").current_dir(&dir).output() {
+        Ok(out) => {
+            let pwd = String::from_utf8_lossy(&out.stdout).trim_end().to_string();
+            // 심볼릭 링크(macOS 의 /tmp → /private/tmp) 때문에 정규화해서 비교
+            let same = std::fs::canonicalize(&pwd).ok() == std::fs::canonicalize(&dir).ok();
+            println!("current_dir(temp_dir) 에서 pwd 가 같은 곳? {}", same);
+        }
+        Err(e) => println!("pwd 실행 실패: {}", e),
+    }
+}
+
+fn main() {
+    environment();
+}
@@ -0,0 +1,134 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 39. 프로세스 실행과 파이프 - 종료 코드와 에러
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn sh(script: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.args(["-c", script]);
+    command
+}
+
+// ----------------------------------------------------------------------------
+// 종료 코드와 에러
+// ----------------------------------------------------------------------------
+
+// 9장의 관례대로 - 실행 실패와 "실행됐지만 실패" 를 구분한 에러 타입
+#[derive(Debug)]
+pub enum ProcessError {
+    // 실행조차 못 함 (없는 프로그램, 권한)
+    Spawn { program: String, source: io::Error },
+    // 실행했지만 0 이 아닌 코드로 끝남 (code 가 None 이면 시그널로 죽음)
+    Failed { program: String, code: Option<i32>, stderr: String },
+}
+
+impl fmt::Display for ProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessError::Spawn { program, source } => write!(f, "{} 를 실행할 수 없음: {}", program, source),
+            ProcessError::Failed { program, code: Some(code), stderr } => {
+                write!(f, "{} 가 코드 {} 로 실패: {}", program, code, stderr.trim_end())
+            }
+            ProcessError::Failed { program, code: None, .. } => write!(f, "{} 가 시그널로 종료됨", program),
+        }
+    }
+}
+
+impl std::error::Error for ProcessError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ProcessError::Spawn { source, .. } => Some(source),
+            ProcessError::Failed { .. } => None,
+        }
+    }
+}
+
+// 실행하고 성공하면 stdout, 아니면 ProcessError - ? 로 이어 쓰기 좋게
+pub fn run_checked(command: &mut Command) -> Result<String, ProcessError> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let output = command.output().map_err(|source| ProcessError::Spawn { program: program.clone(), source })?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(ProcessError::Failed {
+            program,
+            code: output.status.code(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+}
+
+// 시간 안에 끝나지 않으면 kill - None 이면 시간 초과
+// 표준 라이브러리에는 wait_timeout 이 없어서 try_wait 로 짧게 확인 (wait-timeout 크레이트도 있음)
+fn wait_with_timeout(command: &mut Command, limit: Duration) -> io::Result<Option<ExitStatus>> {
+    let mut child = command.spawn()?;
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if start.elapsed() >= limit {
+            child.kill()?;
+            // kill 뒤에도 wait 로 거둬야 좀비가 남지 않음
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn exit_codes() {
+    println!("\n--- 종료 코드와 에러 ---");
+
+    // C++ 에서는:
+    // if (WIFEXITED(status)) code = WEXITSTATUS(status);
+    // else if (WIFSIGNALED(status)) sig = WTERMSIG(status);
+
+    for script in ["exit 0", "exit 1", "exit 42"] {
+        match sh(script).status() {
+            Ok(status) => println!("{:<8} → success {}, code {:?}", script, status.success(), status.code()),
+            Err(e) => println!("sh 실행 실패: {}", e),
+        }
+    }
+
+    // 에러 타입으로 - 호출하는 쪽은 ? 하나로
+    let cases: [(&str, &[&str]); 3] = [
+        ("printf", &["좋음"]),
+        ("sh", &["-c", "echo 디스크가 가득 참 >&2; exit 3"]),
+        ("no-such-program-39", &[]),
+    ];
+    for (program, args) in cases {
+        match run_checked(Command::new(program).args(args)) {
+            Ok(out) => println!("성공: {:?}", out),
+            Err(e) => println!("에러: {}", e),
+        }
+    }
+
+    // 시간 제한 - 끝나지 않는 자식을 kill
+    match wait_with_timeout(&mut sh("sleep 5"), Duration::from_millis(100)) {
+        Ok(Some(status)) => println!("제때 끝남: {}", status),
+        Ok(None) => println!("시간 초과 - kill 하고 거둠"),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+    match wait_with_timeout(&mut sh("exit 0"), Duration::from_secs(5)) {
+        Ok(Some(status)) => println!("제때 끝남: code {:?}", status.code()),
+        Ok(None) => println!("시간 초과 - kill 하고 거둠"),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // 내 프로그램의 종료 코드 - main 에서 ExitCode 를 돌려주거나 std::process::exit(n)
+    // exit 는 소멸자(Drop)를 실행하지 않음 (C++ 의 std::exit 와 같음) - 가능하면 main 에서 돌려주기
+}
+
+fn main() {
+    exit_codes();
+}
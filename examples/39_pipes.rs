@@ -0,0 +1,100 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 39. 프로세스 실행과 파이프 - 프로세스끼리 파이프
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn sh(script: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.args(["-c", script]);
+    command
+}
+
+// ----------------------------------------------------------------------------
+// 프로세스끼리 파이프
+// ----------------------------------------------------------------------------
+
+// 셸의 `a | b | c` - 앞 자식의 stdout 을 다음 자식의 stdin 으로 직접 연결
+// 데이터가 부모를 거치지 않음 (운영체제 파이프 하나를 두 자식이 나눠 가짐)
+pub fn pipeline(commands: &mut [Command]) -> io::Result<String> {
+    let mut children = Vec::new();
+    let mut previous: Option<Stdio> = None;
+    let last = commands.len().saturating_sub(1);
+    for (i, command) in commands.iter_mut().enumerate() {
+        if let Some(stdin) = previous.take() {
+            command.stdin(stdin);
+        }
+        command.stdout(Stdio::piped());
+        let mut child = command.spawn()?;
+        if i < last {
+            // ChildStdout → Stdio - 다음 자식의 stdin 이 됨
+            previous = child.stdout.take().map(Stdio::from);
+        }
+        children.push(child);
+    }
+    // 마지막 자식의 출력을 읽고, 모든 자식을 기다림 (좀비 방지)
+    let mut output = String::new();
+    if let Some(stdout) = children.last_mut().and_then(|child| child.stdout.as_mut()) {
+        stdout.read_to_string(&mut output)?;
+    }
+    for mut child in children {
+        child.wait()?;
+    }
+    Ok(output)
+}
+
+fn pipes() {
+    println!("\n--- 프로세스끼리 파이프 ---");
+
+    // C++ 에서는:
+    // int fd[2]; pipe(fd);
+    // if (fork() == 0) { dup2(fd[1], 1); close(fd[0]); close(fd[1]); execlp("printf", ...); }
+    // if (fork() == 0) { dup2(fd[0], 0); close(fd[0]); close(fd[1]); execlp("sort", ...); }
+    // close(fd[0]); close(fd[1]);   // 하나라도 빠뜨리면 sort 가 EOF 를 못 받아 멈춤
+    // Rust 는 핸들이 RAII 라 닫기를 빠뜨릴 일이 없음
+
+    // printf 'banana\napple\ncherry\n' | sort | tr a-z A-Z
+    let mut commands = [
+        {
+            let mut c = Command::new("printf");
+            c.arg("banana\\napple\\ncherry\\napple\\n");
+            c
+        },
+        {
+            let mut c = Command::new("sort");
+            c.arg("-u");
+            c
+        },
+        {
+            let mut c = Command::new("tr");
+            c.args(["a-z", "A-Z"]);
+            c
+        },
+    ];
+    match pipeline(&mut commands) {
+        Ok(out) => println!("printf | sort -u | tr: {:?}", out.lines().collect::<Vec<_>>()),
+        Err(e) => println!("파이프라인 실패: {}", e),
+    }
+
+    // 같은 일을 셸에 맡기면 짧지만 - 인자에 사용자 입력이 들어가면 위험, 셸이 없는 환경도 있음
+    match sh("printf 'b\\na\\n' | sort").output() {
+        Ok(out) => println!("셸 파이프: {:?}", String::from_utf8_lossy(&out.stdout).lines().collect::<Vec<_>>()),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // 출력을 버리거나 부모 것을 그대로 - Stdio::null(), Stdio::inherit()
+    let quiet = sh("echo 보이지 않음; echo 이것도 >&2").stdout(Stdio::null()).stderr(Stdio::null()).status();
+    println!("출력을 버린 실행의 성공 여부: {:?}", quiet.map(|s| s.success()).ok());
+}
+
+fn main() {
+    pipes();
+}
@@ -0,0 +1,76 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 39. 프로세스 실행과 파이프 - 실행하고 기다리기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn sh(script: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.args(["-c", script]);
+    command
+}
+
+// ----------------------------------------------------------------------------
+// 실행하고 기다리기
+// ----------------------------------------------------------------------------
+
+fn spawning() {
+    println!("--- 실행하고 기다리기 ---");
+
+    // C++ 에서는:
+    // int rc = std::system("printf hello");         // 셸 경유, 출력은 그대로 터미널로
+    // pid_t pid = fork();
+    // if (pid == 0) { execlp("printf", "printf", "hello", nullptr); _exit(127); }
+    // waitpid(pid, &status, 0);
+
+    // Command 는 빌더 - 프로그램과 인자를 쌓고 마지막에 실행 방법을 고름
+    //   status()  - 실행하고 끝까지 기다림, 입출력은 부모 것을 물려받음
+    //   output()  - 실행하고 기다리며 stdout/stderr 를 모두 잡음
+    //   spawn()   - 실행만 하고 Child 를 돌려줌 (기다리기는 나중에)
+    match Command::new("printf").args(["%s + %s\n", "인자", "배열"]).output() {
+        Ok(out) => print!("output(): {}", String::from_utf8_lossy(&out.stdout)),
+        Err(e) => println!("printf 실행 실패: {}", e),
+    }
+
+    // 인자는 셸을 거치지 않으므로 공백이나 따옴표, ; 가 그대로 한 인자
+    // C++ 의 system("echo " + name) 은 name = "; rm -rf ~" 이면 큰일
+    let name = "; 이것은 명령이 아님";
+    match Command::new("printf").args(["[%s]\n", name]).output() {
+        Ok(out) => print!("그대로 전달: {}", String::from_utf8_lossy(&out.stdout)),
+        Err(e) => println!("printf 실행 실패: {}", e),
+    }
+
+    // spawn() - 자식이 도는 동안 부모도 일함
+    match sh("sleep 0.1; echo 자식 끝").stdout(Stdio::piped()).spawn() {
+        Ok(child) => {
+            println!("자식 실행 중 - 부모는 다른 일");
+            // wait_with_output 은 stdout 을 끝까지 읽고 기다림
+            match child.wait_with_output() {
+                Ok(out) => print!("{}", String::from_utf8_lossy(&out.stdout)),
+                Err(e) => println!("기다리기 실패: {}", e),
+            }
+        }
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // 없는 프로그램 - 셸의 127 이 아니라 spawn 단계의 io::Error
+    match Command::new("no-such-program-39").status() {
+        Ok(status) => println!("실행됨?: {}", status),
+        Err(e) => println!("없는 프로그램: ErrorKind::{:?}", e.kind()),
+    }
+
+    // Child 를 wait 하지 않고 버리면 좀비 프로세스가 남을 수 있음 (Drop 이 기다리지 않음)
+    // C++ 의 waitpid 를 빠뜨린 것과 같은 문제 - spawn 했으면 wait/wait_with_output 까지
+}
+
+fn main() {
+    spawning();
+}
@@ -0,0 +1,157 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 40. 시간, Duration, 시계 - Clock 트레이트 - 시간에 의존하는 코드를 테스트하기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// --- 다른 절에서 가져온 정의 ---
+
+const SECS_PER_DAY: u64 = 86_400;
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (yoe + era * 400 + i64::from(m <= 2), m, d)
+}
+
+pub fn format_utc(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (y, m, d) = civil_from_days((secs / SECS_PER_DAY) as i64);
+    let rest = secs % SECS_PER_DAY;
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, m, d, rest / 3600, rest % 3600 / 60, rest % 60)
+}
+
+// ----------------------------------------------------------------------------
+// Clock 트레이트 - 시간에 의존하는 코드를 테스트하기
+// ----------------------------------------------------------------------------
+
+// 지금 시각을 묻는 곳을 트레이트 하나로 - 실제 코드는 SystemClock, 테스트는 FakeClock
+// C++ 에서는 템플릿 매개변수(template<class Clock>)나 가상 함수 인터페이스
+pub trait Clock {
+    fn now(&self) -> Instant;
+    fn wall(&self) -> SystemTime;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn wall(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+// 손으로 돌리는 시계 - Instant 는 직접 만들 수 없으므로 만든 순간을 기준으로 더해 감
+// Cell 이라 &self 로 앞당길 수 있음 (한 스레드용, 여러 스레드면 Mutex)
+pub struct FakeClock {
+    base: Instant,
+    wall_base: SystemTime,
+    offset: Cell<Duration>,
+}
+
+impl FakeClock {
+    pub fn starting_at(wall: SystemTime) -> FakeClock {
+        FakeClock { base: Instant::now(), wall_base: wall, offset: Cell::new(Duration::ZERO) }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.offset.set(self.offset.get() + by);
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> Instant {
+        self.base + self.offset.get()
+    }
+
+    fn wall(&self) -> SystemTime {
+        self.wall_base + self.offset.get()
+    }
+}
+
+// 시계를 빌려 쓰는 쪽도 Clock - TtlCache<&FakeClock> 으로 만들고 바깥에서 advance
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn wall(&self) -> SystemTime {
+        (**self).wall()
+    }
+}
+
+// 시계에 의존하는 예: 항목마다 유효 시간(TTL)이 있는 캐시
+pub struct TtlCache<C: Clock> {
+    clock: C,
+    ttl: Duration,
+    entries: HashMap<String, (String, Instant)>,
+}
+
+impl<C: Clock> TtlCache<C> {
+    pub fn new(clock: C, ttl: Duration) -> Self {
+        TtlCache { clock, ttl, entries: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        let expires = self.clock.now() + self.ttl;
+        self.entries.insert(key.to_string(), (value.to_string(), expires));
+    }
+
+    // 만료된 항목은 없는 것과 같음 - 읽을 때 지움
+    pub fn get(&mut self, key: &str) -> Option<&str> {
+        let now = self.clock.now();
+        if self.entries.get(key).is_some_and(|(_, expires)| *expires <= now) {
+            self.entries.remove(key);
+        }
+        self.entries.get(key).map(|(value, _)| value.as_str())
+    }
+
+    // 사람에게 보여 줄 만료 시각 - 벽시계 기준
+    pub fn expires_at(&self, key: &str) -> Option<String> {
+        let (_, expires) = self.entries.get(key)?;
+        let left = expires.saturating_duration_since(self.clock.now());
+        Some(format_utc(self.clock.wall() + left))
+    }
+}
+
+fn clock_trait() {
+    println!("\n--- Clock 트레이트 ---");
+
+    // 실제 시계로는 "1분 뒤에 만료되는지" 를 보려면 1분을 기다려야 함
+    // 가짜 시계는 advance 한 번 - 테스트가 빠르고 결과가 항상 같음
+    let clock = FakeClock::starting_at(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+    let mut cache = TtlCache::new(&clock, Duration::from_secs(60));
+    cache.insert("session", "민수");
+    println!("지금 {} - 만료 {:?}", format_utc(clock.wall()), cache.expires_at("session"));
+    println!("바로 읽기: {:?}", cache.get("session"));
+
+    clock.advance(Duration::from_secs(59));
+    println!("59초 뒤 ({}): {:?}", format_utc(clock.wall()), cache.get("session"));
+
+    clock.advance(Duration::from_secs(1));
+    println!("60초 뒤 ({}): {:?}", format_utc(clock.wall()), cache.get("session"));
+
+    // 실제 코드에서는 같은 타입에 SystemClock 을 넣음 - 캐시 코드는 바뀌지 않음
+    let mut real = TtlCache::new(SystemClock, Duration::from_secs(60));
+    real.insert("k", "v");
+    println!("SystemClock 캐시: {:?}", real.get("k"));
+}
+
+fn main() {
+    clock_trait();
+}
@@ -0,0 +1,134 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 40. 시간, Duration, 시계 - Duration 계산
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// --- 다른 절에서 가져온 정의 ---
+
+const SECS_PER_DAY: u64 = 86_400;
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (yoe + era * 400 + i64::from(m <= 2), m, d)
+}
+
+pub fn format_utc(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (y, m, d) = civil_from_days((secs / SECS_PER_DAY) as i64);
+    let rest = secs % SECS_PER_DAY;
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, m, d, rest / 3600, rest % 3600 / 60, rest % 60)
+}
+
+pub trait Clock {
+    fn now(&self) -> Instant;
+    fn wall(&self) -> SystemTime;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn wall(&self) -> SystemTime {
+        (**self).wall()
+    }
+}
+
+pub struct TtlCache<C: Clock> {
+    clock: C,
+    ttl: Duration,
+    entries: HashMap<String, (String, Instant)>,
+}
+
+impl<C: Clock> TtlCache<C> {
+    pub fn new(clock: C, ttl: Duration) -> Self {
+        TtlCache { clock, ttl, entries: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        let expires = self.clock.now() + self.ttl;
+        self.entries.insert(key.to_string(), (value.to_string(), expires));
+    }
+
+    // 만료된 항목은 없는 것과 같음 - 읽을 때 지움
+    pub fn get(&mut self, key: &str) -> Option<&str> {
+        let now = self.clock.now();
+        if self.entries.get(key).is_some_and(|(_, expires)| *expires <= now) {
+            self.entries.remove(key);
+        }
+        self.entries.get(key).map(|(value, _)| value.as_str())
+    }
+
+    // 사람에게 보여 줄 만료 시각 - 벽시계 기준
+    pub fn expires_at(&self, key: &str) -> Option<String> {
+        let (_, expires) = self.entries.get(key)?;
+        let left = expires.saturating_duration_since(self.clock.now());
+        Some(format_utc(self.clock.wall() + left))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Duration 계산
+// ----------------------------------------------------------------------------
+
+fn duration_arithmetic() {
+    println!("\n--- Duration 계산 ---");
+
+    // C++ 에서는:
+    // using namespace std::chrono_literals;
+    // auto d = 1500ms; auto s = duration_cast<seconds>(d);   // 1s (잘림)
+    // auto f = duration<double>(d).count();                 // 1.5
+
+    // 만들기 - 단위마다 생성자 (리터럴 접미사는 없음)
+    let d = Duration::from_millis(1500);
+    println!("{:?} = {}초 + {}나노초", d, d.as_secs(), d.subsec_nanos());
+    println!("as_secs_f64: {}, as_millis: {}", d.as_secs_f64(), d.as_millis());
+    println!("from_secs_f64(0.25): {:?}", Duration::from_secs_f64(0.25));
+
+    // 산술 - 더하기, 곱하기, 나누기
+    let total = d + Duration::from_secs(2) * 3;
+    println!("1.5s + 2s × 3 = {:?}, 절반 = {:?}", total, total / 2);
+    println!("mul_f64(1.1): {:?}", Duration::from_secs(10).mul_f64(1.1));
+
+    // Duration 에는 음수가 없음 - 작은 값에서 큰 값을 빼면 panic
+    let short = Duration::from_millis(100);
+    println!("100ms - 1.5s: checked {:?}, saturating {:?}", short.checked_sub(d), short.saturating_sub(d));
+
+    // 비교와 합계
+    let laps = [Duration::from_millis(310), Duration::from_millis(295), Duration::from_millis(330)];
+    let sum: Duration = laps.iter().sum();
+    println!("랩 합계 {:?}, 가장 빠른 랩 {:?}", sum, laps.iter().min());
+
+    // Debug 출력은 알맞은 단위를 고름 - 사람에게 보여 줄 형식은 직접 (아래 format_duration)
+    println!("{:?} {:?} {:?}", Duration::from_nanos(1500), Duration::from_micros(1500), Duration::from_secs(3725));
+    println!("format_duration: {}", format_duration(Duration::from_secs(3725)));
+}
+
+// 3725초 → "1h 02m 05s" - 0인 앞 단위는 생략
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    let (h, m, s) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    match (h, m) {
+        (0, 0) => format!("{}.{:03}s", s, d.subsec_millis()),
+        (0, _) => format!("{}m {:02}s", m, s),
+        _ => format!("{}h {:02}m {:02}s", h, m, s),
+    }
+}
+
+fn main() {
+    duration_arithmetic();
+}
@@ -0,0 +1,145 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 40. 시간, Duration, 시계 - 타임스탬프 서식
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub trait Clock {
+    fn now(&self) -> Instant;
+    fn wall(&self) -> SystemTime;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn wall(&self) -> SystemTime {
+        (**self).wall()
+    }
+}
+
+pub struct TtlCache<C: Clock> {
+    clock: C,
+    ttl: Duration,
+    entries: HashMap<String, (String, Instant)>,
+}
+
+impl<C: Clock> TtlCache<C> {
+    pub fn new(clock: C, ttl: Duration) -> Self {
+        TtlCache { clock, ttl, entries: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        let expires = self.clock.now() + self.ttl;
+        self.entries.insert(key.to_string(), (value.to_string(), expires));
+    }
+
+    // 만료된 항목은 없는 것과 같음 - 읽을 때 지움
+    pub fn get(&mut self, key: &str) -> Option<&str> {
+        let now = self.clock.now();
+        if self.entries.get(key).is_some_and(|(_, expires)| *expires <= now) {
+            self.entries.remove(key);
+        }
+        self.entries.get(key).map(|(value, _)| value.as_str())
+    }
+
+    // 사람에게 보여 줄 만료 시각 - 벽시계 기준
+    pub fn expires_at(&self, key: &str) -> Option<String> {
+        let (_, expires) = self.entries.get(key)?;
+        let left = expires.saturating_duration_since(self.clock.now());
+        Some(format_utc(self.clock.wall() + left))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 타임스탬프 서식
+// ----------------------------------------------------------------------------
+
+const SECS_PER_DAY: u64 = 86_400;
+
+// 유닉스 일 수 → (연, 월, 일) - Howard Hinnant 의 civil_from_days (src/timelog.rs 와 같음)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (yoe + era * 400 + i64::from(m <= 2), m, d)
+}
+
+// 반대 방향 - (연, 월, 일) → 유닉스 일 수
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = i64::from((m + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(d) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// SystemTime → "2023-11-14T22:13:20Z" (RFC 3339, UTC, 1970년 이후만)
+pub fn format_utc(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (y, m, d) = civil_from_days((secs / SECS_PER_DAY) as i64);
+    let rest = secs % SECS_PER_DAY;
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, m, d, rest / 3600, rest % 3600 / 60, rest % 60)
+}
+
+// "2023-11-14T22:13:20Z" → SystemTime - 형식이 틀리거나 없는 날짜(2월 30일)면 None
+pub fn parse_utc(text: &str) -> Option<SystemTime> {
+    let (date, time) = text.strip_suffix('Z')?.split_once('T')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<u32>);
+    let (y, m, d) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let mut time = time.splitn(3, ':').map(str::parse::<u64>);
+    let (hh, mm, ss) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+    if y < 1970 || hh > 23 || mm > 59 || ss > 59 {
+        return None;
+    }
+    let days = days_from_civil(i64::from(y), m, d);
+    // 거꾸로 바꿔서 같은 날짜가 나와야 진짜 있는 날짜 (13월, 2월 30일 걸러냄)
+    if civil_from_days(days) != (i64::from(y), m, d) {
+        return None;
+    }
+    Some(UNIX_EPOCH + Duration::from_secs(days as u64 * SECS_PER_DAY + hh * 3600 + mm * 60 + ss))
+}
+
+fn formatting_timestamps() {
+    println!("\n--- 타임스탬프 서식 ---");
+
+    // C++ 에서는 (C++20):
+    // auto t = std::chrono::sys_seconds{std::chrono::seconds{1'700'000'000}};
+    // std::format("{:%FT%TZ}", t);    // 2023-11-14T22:13:20Z
+    // Rust 표준 라이브러리에는 달력 서식이 없음 → 직접 계산하거나 chrono/time/jiff
+
+    let t = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let text = format_utc(t);
+    println!("1_700_000_000 → {}", text);
+    println!("UNIX_EPOCH → {}", format_utc(UNIX_EPOCH));
+    println!("윤일 → {}", format_utc(UNIX_EPOCH + Duration::from_secs(days_from_civil(2024, 2, 29) as u64 * SECS_PER_DAY)));
+
+    // 왕복 - 저장한 문자열을 다시 읽음
+    println!("parse(format(t)) == t? {}", parse_utc(&text) == Some(t));
+    for bad in ["2023-02-30T00:00:00Z", "2023-13-01T00:00:00Z", "2023-11-14 22:13:20", "2023-11-14T24:00:00Z"] {
+        println!("parse_utc({:?}) = {:?}", bad, parse_utc(bad));
+    }
+
+    // 저장은 문자열보다 숫자(유닉스 초)가 간편 - 비교와 정렬이 쉽고 시간대 문제가 없음
+    // 사람에게 보여 줄 때만 서식을 입힘 (현지 시간대는 운영체제 데이터베이스가 필요 → 크레이트)
+}
+
+fn main() {
+    formatting_timestamps();
+}
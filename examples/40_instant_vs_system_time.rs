@@ -0,0 +1,119 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 40. 시간, Duration, 시계 - Instant 와 SystemTime
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// --- 다른 절에서 가져온 정의 ---
+
+const SECS_PER_DAY: u64 = 86_400;
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (yoe + era * 400 + i64::from(m <= 2), m, d)
+}
+
+pub fn format_utc(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (y, m, d) = civil_from_days((secs / SECS_PER_DAY) as i64);
+    let rest = secs % SECS_PER_DAY;
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, m, d, rest / 3600, rest % 3600 / 60, rest % 60)
+}
+
+pub trait Clock {
+    fn now(&self) -> Instant;
+    fn wall(&self) -> SystemTime;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn wall(&self) -> SystemTime {
+        (**self).wall()
+    }
+}
+
+pub struct TtlCache<C: Clock> {
+    clock: C,
+    ttl: Duration,
+    entries: HashMap<String, (String, Instant)>,
+}
+
+impl<C: Clock> TtlCache<C> {
+    pub fn new(clock: C, ttl: Duration) -> Self {
+        TtlCache { clock, ttl, entries: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        let expires = self.clock.now() + self.ttl;
+        self.entries.insert(key.to_string(), (value.to_string(), expires));
+    }
+
+    // 만료된 항목은 없는 것과 같음 - 읽을 때 지움
+    pub fn get(&mut self, key: &str) -> Option<&str> {
+        let now = self.clock.now();
+        if self.entries.get(key).is_some_and(|(_, expires)| *expires <= now) {
+            self.entries.remove(key);
+        }
+        self.entries.get(key).map(|(value, _)| value.as_str())
+    }
+
+    // 사람에게 보여 줄 만료 시각 - 벽시계 기준
+    pub fn expires_at(&self, key: &str) -> Option<String> {
+        let (_, expires) = self.entries.get(key)?;
+        let left = expires.saturating_duration_since(self.clock.now());
+        Some(format_utc(self.clock.wall() + left))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Instant 와 SystemTime
+// ----------------------------------------------------------------------------
+
+fn instant_vs_system_time() {
+    println!("--- Instant 와 SystemTime ---");
+
+    // C++ 에서는:
+    // auto start = std::chrono::steady_clock::now();
+    // ...
+    // auto took = std::chrono::steady_clock::now() - start;   // duration
+    // auto wall = std::chrono::system_clock::now();            // 달력 시각
+
+    // Instant - 단조(monotonic) 시계: 절대 거꾸로 가지 않음, 값 자체에는 의미가 없음 (차이만)
+    let start = Instant::now();
+    thread::sleep(Duration::from_millis(20));
+    let took = start.elapsed();
+    // 실제 값은 실행마다 다름 - 예제는 비교 결과만 출력
+    println!("sleep(20ms) 뒤 elapsed() >= 20ms? {}", took >= Duration::from_millis(20));
+
+    // SystemTime - 벽시계(wall clock): 달력 시각, 다른 프로세스/컴퓨터와 비교 가능, 하지만 거꾸로 갈 수 있음
+    let now = SystemTime::now();
+    let since_epoch = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+    println!("UNIX_EPOCH 부터 2020년 이후? {}", since_epoch.as_secs() > 1_577_836_800);
+
+    // 어느 쪽을 쓸까
+    //   경과 시간, 시간 제한, 벤치마크          → Instant
+    //   로그의 타임스탬프, 파일 수정 시각, 만료일 → SystemTime
+    // Instant 는 저장하거나 다른 프로세스로 보낼 수 없음 (기준점이 부팅 시각 등 운영체제마다 다름)
+    let modified = std::fs::metadata(std::env::temp_dir()).and_then(|m| m.modified());
+    println!("디렉터리 수정 시각(SystemTime)이 지금보다 과거? {}", modified.is_ok_and(|t| t <= SystemTime::now()));
+}
+
+fn main() {
+    instant_vs_system_time();
+}
@@ -0,0 +1,165 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 40. 시간, Duration, 시계 - 단조 시계의 함정
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// --- rust-study 의 microbench 모듈 ---
+mod microbench {
+// ============================================================================
+// 장 안의 작은 벤치마크 (Micro-benchmark)
+// ============================================================================
+// 레슨이 "더 빠르다", "비용이 없다" 고 말하는 곳에서 수치로 보여 주기 위한 최소한의 측정기
+//
+//   let m = crate::microbench::measure("enum", 1_000, || total(black_box(&shapes)));
+//   println!("{}", crate::microbench::table(&[m, ...]));
+//
+// 같은 조건으로 반복해서 재기: 예열 WARMUP 번 → SAMPLES 번 재고 중앙값 (한두 번 튀는 값에 흔들리지 않음)
+// 반복 횟수는 호출하는 쪽이 정함 (시간으로 맞추지 않음 - 실행마다 같은 일을 하도록)
+// 디버그 빌드의 수치는 최적화 전이라 비교에 쓰지 않음 - cargo run --release -- <장>
+// 정밀한 측정은 criterion 으로 (benches/, cargo bench 뒤 cargo run -- bench-report, 읽는 법은 26장)
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// 재기 전에 버리는 실행 수 - 캐시, 분기 예측기, CPU 클럭을 데움
+pub const WARMUP: usize = 3;
+// 재는 실행 수 - 홀수라서 중앙값이 하나
+pub const SAMPLES: usize = 11;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    // 한 번 실행(iterations 번 호출)의 중앙값과 최솟값
+    pub median: Duration,
+    pub min: Duration,
+    pub iterations: u32,
+}
+
+impl Measurement {
+    // 호출 한 번의 중앙값 (나노초)
+    pub fn per_call_ns(&self) -> f64 {
+        self.median.as_nanos() as f64 / self.iterations.max(1) as f64
+    }
+}
+
+// f 를 iterations 번 호출하는 실행을 WARMUP + SAMPLES 번 - 결과는 black_box 로 버려서 최적화로 사라지지 않게
+pub fn measure<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) -> Measurement {
+    let mut run = || {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(f());
+        }
+        start.elapsed()
+    };
+    for _ in 0..WARMUP {
+        run();
+    }
+    let mut samples: Vec<Duration> = (0..SAMPLES).map(|_| run()).collect();
+    samples.sort_unstable();
+    Measurement {
+        name: name.to_string(),
+        median: samples[SAMPLES / 2],
+        min: samples[0],
+        iterations,
+    }
+}
+
+// 이름, 호출당 시간, 가장 빠른 것 대비 배율 - 측정한 순서대로
+pub fn table(results: &[Measurement]) -> String {
+    let fastest = results
+        .iter()
+        .map(Measurement::per_call_ns)
+        .fold(f64::INFINITY, f64::min)
+        .max(f64::MIN_POSITIVE);
+    let width = results.iter().map(|m| m.name.chars().count()).max().unwrap_or(0);
+    results
+        .iter()
+        .map(|m| {
+            format!(
+                "  {:width$}  {:>12}  x{:.2}",
+                m.name,
+                format_ns(m.per_call_ns()),
+                m.per_call_ns() / fastest,
+                width = width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn format_ns(ns: f64) -> String {
+    if ns >= 1_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.2} µs", ns / 1_000.0)
+    } else {
+        format!("{:.1} ns", ns)
+    }
+}
+
+// 이 실행이 최적화 빌드인지 - 표 위에 함께 보여 줌
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "디버그 빌드 - 수치는 참고만 (cargo run --release 로 다시 재 보세요)"
+    } else {
+        "릴리스 빌드"
+    }
+}
+}
+
+// ----------------------------------------------------------------------------
+// 단조 시계의 함정
+// ----------------------------------------------------------------------------
+
+fn monotonic_pitfalls() {
+    println!("\n--- 단조 시계의 함정 ---");
+
+    // 함정 1: SystemTime 으로 경과 시간을 재기
+    // NTP 가 시계를 맞추거나 사용자가 시각을 바꾸면 "끝 - 시작" 이 음수가 될 수 있음
+    // 그래서 duration_since 는 Result - 예제에서는 일부러 거꾸로 빼 봄
+    let now = SystemTime::now();
+    let earlier = now - Duration::from_secs(5);
+    match earlier.duration_since(now) {
+        Ok(d) => println!("경과: {:?}", d),
+        Err(e) => println!("거꾸로 뺌 → Err, 얼마나 거꾸로? {:?}", e.duration()),
+    }
+    // elapsed() 도 SystemTime 에서는 Result
+    println!("SystemTime::elapsed 의 결과가 Ok? {}", now.elapsed().is_ok());
+
+    // 함정 2: Instant 끼리 빼기 - 순서가 바뀌면?
+    // Instant - Instant 는 (현재 버전에서) 0 으로 포화되지만, 의도를 드러내려면 명시적인 메서드를
+    let a = Instant::now();
+    let b = a + Duration::from_millis(10);
+    println!("a.checked_duration_since(b) = {:?}", a.checked_duration_since(b));
+    println!("a.saturating_duration_since(b) = {:?}", a.saturating_duration_since(b));
+    println!("b.duration_since(a) = {:?}", b.duration_since(a));
+
+    // 함정 3: sleep 은 "적어도" 그만큼 - 정확한 주기가 아님
+    // 반복마다 sleep(10ms) 하면 처리 시간과 지연이 쌓임 (drift)
+    // 다음 기준 시각을 더해 나가고, 남은 시간만큼만 잠
+    let start = Instant::now();
+    let period = Duration::from_millis(5);
+    let mut next = start;
+    for _ in 0..4 {
+        next += period;
+        thread::sleep(next.saturating_duration_since(Instant::now()));
+    }
+    println!("5ms 주기 4번 - 기준 시각 방식으로 20ms 이상? {}", start.elapsed() >= Duration::from_millis(20));
+
+    // 함정 4: 너무 작은 시간을 재기 - 시계의 해상도와 호출 비용보다 작으면 의미 없음
+    // 여러 번 반복해 합계를 재거나 벤치마크 도구(crate::microbench, criterion)로
+}
+
+fn main() {
+    monotonic_pitfalls();
+}
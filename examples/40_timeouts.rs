@@ -0,0 +1,149 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 40. 시간, Duration, 시계 - 시간 제한
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// --- 다른 절에서 가져온 정의 ---
+
+const SECS_PER_DAY: u64 = 86_400;
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (yoe + era * 400 + i64::from(m <= 2), m, d)
+}
+
+pub fn format_utc(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (y, m, d) = civil_from_days((secs / SECS_PER_DAY) as i64);
+    let rest = secs % SECS_PER_DAY;
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, m, d, rest / 3600, rest % 3600 / 60, rest % 60)
+}
+
+pub trait Clock {
+    fn now(&self) -> Instant;
+    fn wall(&self) -> SystemTime;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn wall(&self) -> SystemTime {
+        (**self).wall()
+    }
+}
+
+pub struct TtlCache<C: Clock> {
+    clock: C,
+    ttl: Duration,
+    entries: HashMap<String, (String, Instant)>,
+}
+
+impl<C: Clock> TtlCache<C> {
+    pub fn new(clock: C, ttl: Duration) -> Self {
+        TtlCache { clock, ttl, entries: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        let expires = self.clock.now() + self.ttl;
+        self.entries.insert(key.to_string(), (value.to_string(), expires));
+    }
+
+    // 만료된 항목은 없는 것과 같음 - 읽을 때 지움
+    pub fn get(&mut self, key: &str) -> Option<&str> {
+        let now = self.clock.now();
+        if self.entries.get(key).is_some_and(|(_, expires)| *expires <= now) {
+            self.entries.remove(key);
+        }
+        self.entries.get(key).map(|(value, _)| value.as_str())
+    }
+
+    // 사람에게 보여 줄 만료 시각 - 벽시계 기준
+    pub fn expires_at(&self, key: &str) -> Option<String> {
+        let (_, expires) = self.entries.get(key)?;
+        let left = expires.saturating_duration_since(self.clock.now());
+        Some(format_utc(self.clock.wall() + left))
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 시간 제한
+// ----------------------------------------------------------------------------
+
+// 마감 시각 - 여러 단계에 걸친 작업의 전체 시간 제한을 한 번만 정하고 단계마다 남은 시간을 씀
+#[derive(Debug, Clone, Copy)]
+pub struct Deadline(Instant);
+
+impl Deadline {
+    pub fn after(timeout: Duration) -> Deadline {
+        Deadline(Instant::now() + timeout)
+    }
+
+    // 남은 시간 - 이미 지났으면 None
+    pub fn remaining(&self) -> Option<Duration> {
+        self.0.checked_duration_since(Instant::now()).filter(|d| !d.is_zero())
+    }
+
+    pub fn expired(&self) -> bool {
+        self.remaining().is_none()
+    }
+}
+
+fn timeouts() {
+    println!("\n--- 시간 제한 ---");
+
+    // C++ 에서는:
+    // auto status = future.wait_for(100ms);               // std::future_status::timeout
+    // cv.wait_until(lock, deadline, pred);
+
+    // 블로킹 API 는 *_timeout 판이 있음 - recv_timeout, Condvar::wait_timeout, set_read_timeout(37장)
+    let (tx, rx) = mpsc::channel();
+    let worker = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        let _ = tx.send("늦은 결과");
+    });
+    match rx.recv_timeout(Duration::from_millis(20)) {
+        Ok(v) => println!("받음: {}", v),
+        Err(e) => println!("recv_timeout(20ms): {:?}", e),
+    }
+    // 시간이 지났다고 작업이 멈추지는 않음 - 기다리기만 그만둔 것 (취소는 직접 알려야 함, 12/17장)
+    println!("더 기다리면: {:?}", rx.recv_timeout(Duration::from_secs(2)));
+    worker.join().unwrap();
+
+    // 여러 단계에 걸친 전체 시간 제한 - 단계마다 고정 시간을 주면 합이 제한을 넘음
+    let deadline = Deadline::after(Duration::from_millis(100));
+    let mut finished = 0;
+    for _ in 0..10 {
+        let Some(left) = deadline.remaining() else {
+            break;
+        };
+        // 단계 하나가 30ms 걸린다고 치고 - 남은 시간보다 길면 남은 만큼만
+        thread::sleep(left.min(Duration::from_millis(30)));
+        if !deadline.expired() {
+            finished += 1;
+        }
+    }
+    // 몇 단계를 마쳤는지는 스케줄링에 따라 다름 (보통 3개)
+    println!("10단계를 다 못 하고 마감? {}, 마감 지남? {}", finished < 10, deadline.expired());
+
+    // async 에서는 tokio::time::timeout(d, future) - 38장의 유휴 연결 시간 제한
+}
+
+fn main() {
+    timeouts();
+}
@@ -0,0 +1,92 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 41. clap 으로 명령줄 도구 만들기 - derive 로 인자 파싱
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use clap::error::ErrorKind;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn argv(line: &str) -> Vec<&str> {
+    line.split_whitespace().collect()
+}
+
+fn one_line(e: &clap::Error) -> String {
+    e.to_string()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("Usage:") && !line.starts_with("For more information"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ----------------------------------------------------------------------------
+// derive 로 인자 파싱
+// ----------------------------------------------------------------------------
+
+// grep 을 흉내 낸 작은 도구 - 필드의 /// 주석이 --help 의 설명이 됨
+// (이 장에서만 // 대신 /// - clap 이 읽는 문서 주석)
+/// 파일에서 패턴이 든 줄을 찾습니다
+#[derive(Parser, Debug)]
+#[command(name = "mini-grep", version = "0.1.0")]
+pub struct GrepArgs {
+    /// 찾을 패턴
+    pub pattern: String,
+
+    /// 찾을 파일들 (없으면 표준 입력)
+    pub paths: Vec<PathBuf>,
+
+    /// 대소문자 구분 없이
+    #[arg(short, long)]
+    pub ignore_case: bool,
+
+    /// 줄 번호도 출력
+    #[arg(short = 'n', long)]
+    pub line_number: bool,
+
+    /// 이만큼 찾으면 멈춤
+    #[arg(short, long, value_name = "N")]
+    pub max_count: Option<usize>,
+
+    /// 자세히 출력 (-v, -vv 처럼 여러 번)
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+}
+
+fn derive_parsing() {
+    println!("--- derive 로 인자 파싱 ---");
+
+    // C++ 에서는 (getopt_long):
+    // static option opts[] = {{"ignore-case", no_argument, nullptr, 'i'}, {"max-count", required_argument, nullptr, 'm'}, ...};
+    // while ((c = getopt_long(argc, argv, "inm:v", opts, nullptr)) != -1) switch (c) { case 'm': max = atoi(optarg); ... }
+    // 위치 인자는 optind 부터 직접, atoi 는 "abc" 를 0 으로 - 검증도 직접
+
+    for line in [
+        "mini-grep fn src/main.rs",
+        "mini-grep -in FIXME a.rs b.rs",
+        "mini-grep --max-count 3 -vv unsafe",
+        "mini-grep --max-count=5 -- -pattern-with-dash",
+    ] {
+        match GrepArgs::try_parse_from(argv(line)) {
+            Ok(args) => println!("{:<48} → {:?}", line, args),
+            Err(e) => println!("{:<48} → {}", line, one_line(&e)),
+        }
+    }
+
+    // 틀린 입력은 파싱 단계에서 - 타입이 맞지 않거나 필수 인자가 없거나 모르는 옵션
+    for line in ["mini-grep", "mini-grep -m many fn", "mini-grep --colour fn"] {
+        match GrepArgs::try_parse_from(argv(line)) {
+            Ok(args) => println!("{:<24} → {:?}", line, args),
+            Err(e) => println!("{:<24} → {:?}: {}", line, e.kind(), one_line(&e)),
+        }
+    }
+}
+
+fn main() {
+    derive_parsing();
+}
@@ -0,0 +1,186 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 41. clap 으로 명령줄 도구 만들기 - 도움말과 에러
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use clap::error::ErrorKind;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn argv(line: &str) -> Vec<&str> {
+    line.split_whitespace().collect()
+}
+
+#[derive(Parser, Debug)]
+#[command(name = "mini-grep", version = "0.1.0")]
+pub struct GrepArgs {
+    /// 찾을 패턴
+    pub pattern: String,
+
+    /// 찾을 파일들 (없으면 표준 입력)
+    pub paths: Vec<PathBuf>,
+
+    /// 대소문자 구분 없이
+    #[arg(short, long)]
+    pub ignore_case: bool,
+
+    /// 줄 번호도 출력
+    #[arg(short = 'n', long)]
+    pub line_number: bool,
+
+    /// 이만큼 찾으면 멈춤
+    #[arg(short, long, value_name = "N")]
+    pub max_count: Option<usize>,
+
+    /// 자세히 출력 (-v, -vv 처럼 여러 번)
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+}
+
+#[derive(Parser, Debug)]
+#[command(name = "study")]
+pub struct StudyCli {
+    /// 출력 없이
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    #[command(subcommand)]
+    pub command: StudyCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StudyCommand {
+    /// 장을 실행
+    Run {
+        /// 실행할 장 (번호)
+        #[arg(required = true)]
+        chapters: Vec<ChapterId>,
+    },
+    /// 퀴즈 풀기
+    Quiz {
+        chapter: ChapterId,
+        /// 문제 수 (1~20)
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u8).range(1..=20))]
+        count: u8,
+    },
+    /// 설정 읽고 쓰기
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    Get { key: String },
+    Set { key: String, value: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChapterId(u8);
+
+pub const LAST_CHAPTER: u8 = 41;
+
+impl FromStr for ChapterId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let n: u8 = s.parse().map_err(|_| format!("장 번호는 숫자입니다: '{}'", s))?;
+        if (1..=LAST_CHAPTER).contains(&n) {
+            Ok(ChapterId(n))
+        } else {
+            Err(format!("장은 1~{} 입니다", LAST_CHAPTER))
+        }
+    }
+}
+
+impl std::fmt::Display for ChapterId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let n: u64 = number.parse().map_err(|_| format!("숫자로 시작해야 합니다: '{}'", s))?;
+    match unit {
+        "ms" => Ok(Duration::from_millis(n)),
+        "s" | "" => Ok(Duration::from_secs(n)),
+        "m" => Ok(Duration::from_secs(n * 60)),
+        _ => Err(format!("단위는 ms, s, m 중 하나: '{}'", unit)),
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Markdown,
+}
+
+#[derive(Parser, Debug)]
+#[command(name = "timed")]
+pub struct TimedArgs {
+    /// 실행할 장
+    pub chapter: ChapterId,
+
+    /// 시간 제한 (예: 500ms, 2s, 1m)
+    #[arg(long, value_parser = parse_duration, default_value = "30s")]
+    pub timeout: Duration,
+
+    /// 출력 형식
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// 예제 데이터 시드 (clap 의 env 기능을 켜면 #[arg(env = "STUDY_SEED")] 로 환경 변수에서도)
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+// ----------------------------------------------------------------------------
+// 도움말과 에러
+// ----------------------------------------------------------------------------
+
+fn help_text() {
+    println!("\n--- 도움말과 에러 ---");
+
+    // --help 와 --version 은 자동 - try_parse_from 에서는 "에러" 로 돌아옴 (출력하고 끝내라는 뜻)
+    match GrepArgs::try_parse_from(["mini-grep", "--version"]) {
+        Ok(_) => println!("파싱됨?"),
+        Err(e) => println!("--version → {:?}: {}", e.kind(), e.to_string().trim_end()),
+    }
+    if let Err(e) = GrepArgs::try_parse_from(["mini-grep", "--help"]) {
+        println!("--help → {:?}, 종료 코드 {}", e.kind(), e.exit_code());
+    }
+
+    // 도움말 문자열을 직접 얻기 - 문서 주석, 기본값, 값 목록이 들어 있음
+    println!("mini-grep --help:");
+    print!("{}", GrepArgs::command().render_help());
+    println!();
+    println!("study --help:");
+    print!("{}", StudyCli::command().render_help());
+
+    // 에러 종류로 나눠 처리 - 종료 코드는 사용법 에러가 2 (getopt 관례), 도움말은 0
+    for line in ["timed", "timed 7 --frmat json"] {
+        if let Err(e) = TimedArgs::try_parse_from(argv(line)) {
+            let what = match e.kind() {
+                ErrorKind::MissingRequiredArgument => "필수 인자 빠짐",
+                ErrorKind::UnknownArgument => "모르는 옵션",
+                _ => "기타",
+            };
+            println!("{:<22} → {} (종료 코드 {})", line, what, e.exit_code());
+        }
+    }
+
+    // 실제 main 에서는:
+    //   let args = GrepArgs::parse();   // 에러면 e.exit() - 메시지 출력 후 종료
+    // 라이브러리 쪽 검증이 더 있으면: GrepArgs::command().error(ErrorKind::ValueValidation, "...").exit()
+}
+
+fn main() {
+    help_text();
+}
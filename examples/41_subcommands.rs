@@ -0,0 +1,144 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 41. clap 으로 명령줄 도구 만들기 - 서브커맨드
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use clap::error::ErrorKind;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn argv(line: &str) -> Vec<&str> {
+    line.split_whitespace().collect()
+}
+
+fn one_line(e: &clap::Error) -> String {
+    e.to_string()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("Usage:") && !line.starts_with("For more information"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChapterId(u8);
+
+pub const LAST_CHAPTER: u8 = 41;
+
+impl FromStr for ChapterId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let n: u8 = s.parse().map_err(|_| format!("장 번호는 숫자입니다: '{}'", s))?;
+        if (1..=LAST_CHAPTER).contains(&n) {
+            Ok(ChapterId(n))
+        } else {
+            Err(format!("장은 1~{} 입니다", LAST_CHAPTER))
+        }
+    }
+}
+
+impl std::fmt::Display for ChapterId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 서브커맨드
+// ----------------------------------------------------------------------------
+
+// git 처럼 "프로그램 명령 [옵션]" - 명령마다 enum 변형 하나
+/// 장 단위 학습 도구 (예제용)
+#[derive(Parser, Debug)]
+#[command(name = "study")]
+pub struct StudyCli {
+    /// 출력 없이
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    #[command(subcommand)]
+    pub command: StudyCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StudyCommand {
+    /// 장을 실행
+    Run {
+        /// 실행할 장 (번호)
+        #[arg(required = true)]
+        chapters: Vec<ChapterId>,
+    },
+    /// 퀴즈 풀기
+    Quiz {
+        chapter: ChapterId,
+        /// 문제 수 (1~20)
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u8).range(1..=20))]
+        count: u8,
+    },
+    /// 설정 읽고 쓰기
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+// 서브커맨드 안의 서브커맨드 - study config get lang
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    Get { key: String },
+    Set { key: String, value: String },
+}
+
+// 파싱 결과를 실행 - enum 이라 빠뜨린 명령은 컴파일 에러
+fn execute(cli: &StudyCli) -> String {
+    let said = match &cli.command {
+        StudyCommand::Run { chapters } => {
+            let ids: Vec<String> = chapters.iter().map(ToString::to_string).collect();
+            format!("{} 장 실행", ids.join(", "))
+        }
+        StudyCommand::Quiz { chapter, count } => format!("{}장 퀴즈 {}문제", chapter, count),
+        StudyCommand::Config(ConfigCommand::Get { key }) => format!("설정 {} 읽기", key),
+        StudyCommand::Config(ConfigCommand::Set { key, value }) => format!("설정 {} = {}", key, value),
+    };
+    if cli.quiet {
+        format!("(조용히) {}", said)
+    } else {
+        said
+    }
+}
+
+fn subcommands() {
+    println!("\n--- 서브커맨드 ---");
+
+    // C++ 에서는 argv[1] 을 strcmp 로 나누고 명령마다 getopt 를 다시 (optind = 1 초기화에 주의)
+    // CLI11 은 app.add_subcommand("run") 과 콜백
+
+    for line in [
+        "study run 7 09 11",
+        "study quiz 13 --count 10",
+        "study quiz 13",
+        "study config set lang en -q",
+        "study config get lang",
+    ] {
+        match StudyCli::try_parse_from(argv(line)) {
+            Ok(cli) => println!("{:<30} → {}", line, execute(&cli)),
+            Err(e) => println!("{:<30} → {}", line, one_line(&e)),
+        }
+    }
+
+    // 명령이 틀리면 비슷한 이름을 제안
+    for line in ["study rum 7", "study run", "study quiz 13 --count 50"] {
+        match StudyCli::try_parse_from(argv(line)) {
+            Ok(cli) => println!("{:<26} → {}", line, execute(&cli)),
+            Err(e) => println!("{:<26} → {}", line, one_line(&e)),
+        }
+    }
+}
+
+fn main() {
+    subcommands();
+}
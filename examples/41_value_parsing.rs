@@ -0,0 +1,135 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 41. clap 으로 명령줄 도구 만들기 - 값 파싱 - 새 타입으로 검증
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use clap::error::ErrorKind;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn argv(line: &str) -> Vec<&str> {
+    line.split_whitespace().collect()
+}
+
+fn one_line(e: &clap::Error) -> String {
+    e.to_string()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("Usage:") && !line.starts_with("For more information"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ----------------------------------------------------------------------------
+// 값 파싱 - 새 타입으로 검증
+// ----------------------------------------------------------------------------
+
+// 장 번호 - 파싱이 끝나면 항상 있는 장 (5장의 새 타입 패턴)
+// FromStr 만 구현하면 clap 이 값 파서로 씀
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChapterId(u8);
+
+pub const LAST_CHAPTER: u8 = 41;
+
+impl FromStr for ChapterId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let n: u8 = s.parse().map_err(|_| format!("장 번호는 숫자입니다: '{}'", s))?;
+        if (1..=LAST_CHAPTER).contains(&n) {
+            Ok(ChapterId(n))
+        } else {
+            Err(format!("장은 1~{} 입니다", LAST_CHAPTER))
+        }
+    }
+}
+
+impl std::fmt::Display for ChapterId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+// FromStr 을 쓰기 어려운 타입(Duration 은 남의 타입)은 함수로 - value_parser = parse_duration
+// "500ms", "2s", "1m"
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let n: u64 = number.parse().map_err(|_| format!("숫자로 시작해야 합니다: '{}'", s))?;
+    match unit {
+        "ms" => Ok(Duration::from_millis(n)),
+        "s" | "" => Ok(Duration::from_secs(n)),
+        "m" => Ok(Duration::from_secs(n * 60)),
+        _ => Err(format!("단위는 ms, s, m 중 하나: '{}'", unit)),
+    }
+}
+
+// 정해진 값 중 하나 - ValueEnum 이 "text", "json", "markdown" 을 만들고 도움말에도 보여 줌
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Markdown,
+}
+
+/// 장을 시간 제한과 함께 실행
+#[derive(Parser, Debug)]
+#[command(name = "timed")]
+pub struct TimedArgs {
+    /// 실행할 장
+    pub chapter: ChapterId,
+
+    /// 시간 제한 (예: 500ms, 2s, 1m)
+    #[arg(long, value_parser = parse_duration, default_value = "30s")]
+    pub timeout: Duration,
+
+    /// 출력 형식
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// 예제 데이터 시드 (clap 의 env 기능을 켜면 #[arg(env = "STUDY_SEED")] 로 환경 변수에서도)
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+fn value_parsing() {
+    println!("\n--- 값 파싱 - 새 타입으로 검증 ---");
+
+    // C++ 에서는 (Boost.Program_options):
+    // ("timeout", po::value<std::string>(), ...) 로 받고 나서 직접 "500ms" 를 해석
+    // 검증 실패를 알리는 방법(예외, 에러 코드)도 직접 정함
+
+    for line in [
+        "timed 7",
+        "timed 07 --timeout 500ms --format json",
+        "timed 40 --timeout 1m --seed 42 --format markdown",
+    ] {
+        match TimedArgs::try_parse_from(argv(line)) {
+            Ok(args) => println!(
+                "{:<50} → {}장, {:?}, {:?}, 시드 {:?}",
+                line, args.chapter, args.timeout, args.format, args.seed
+            ),
+            Err(e) => println!("{:<50} → {}", line, one_line(&e)),
+        }
+    }
+
+    // 검증 실패 - 우리가 만든 메시지가 clap 의 에러에 들어감
+    for line in ["timed 99", "timed seven", "timed 7 --timeout 3h", "timed 7 --format yaml"] {
+        match TimedArgs::try_parse_from(argv(line)) {
+            Ok(args) => println!("{:<24} → {:?}", line, args),
+            Err(e) => println!("{:<24} → {}", line, one_line(&e)),
+        }
+    }
+
+    // 파싱이 끝난 값은 타입이 보장 - ChapterId 를 받는 함수는 범위를 다시 검사하지 않아도 됨
+    // ("검증하지 말고 파싱하라" - 검증 결과를 타입으로 남김)
+}
+
+fn main() {
+    value_parsing();
+}
@@ -0,0 +1,1479 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 42. 속성 기반 테스트 - 축소 (shrinking)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::{Config, RngAlgorithm, TestError, TestRng, TestRunner};
+use crate::_16_unsafe::equivalence::{apply, Op};
+use crate::_16_unsafe::safe_wrapper::MyVec;
+use crate::_19_testing::{add, is_even};
+
+// --- rust-study 의 _16_unsafe 모듈 ---
+mod _16_unsafe {
+// ============================================================================
+// 16. Unsafe Rust
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. Rust는 기본적으로 안전함 - unsafe는 명시적으로 선언
+// 2. unsafe 블록 내에서만 특정 작업 가능 - C++는 모든 곳에서 가능
+// 3. unsafe는 "컴파일러를 신뢰해줘"라는 의미 - 버그 있으면 정의되지 않은 동작
+// 4. FFI(외부 함수 인터페이스)로 C 코드와 상호작용
+// 5. 안전한 추상화로 unsafe 코드를 감싸는 것이 관례
+// ============================================================================
+
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// 절 목록 (실행 순서) - cargo run -- 16:unsafe_basics 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("unsafe_basics", unsafe_basics),
+    ("raw_pointers", raw_pointers),
+    ("unsafe_functions", unsafe_functions),
+    ("safe_abstractions", safe_abstractions),
+    ("validating_unsafe", validating_unsafe),
+    ("ffi_example", ffi_example),
+    ("static_mut_variables", static_mut_variables),
+    ("unsafe_traits", unsafe_traits),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "16"
+    }
+
+    fn name(&self) -> &'static str {
+        "Unsafe Rust"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rust는 기본적으로 안전함 - unsafe는 명시적으로 선언"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["unsafe", "raw 포인터", "FFI", "static mut"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Unsafe 기초
+// ----------------------------------------------------------------------------
+
+fn unsafe_basics() {
+    println!("--- Unsafe 기초 ---");
+
+    // unsafe로 할 수 있는 5가지:
+    // 1. raw 포인터 역참조
+    // 2. unsafe 함수 또는 메서드 호출
+    // 3. 가변 정적 변수 접근 또는 수정
+    // 4. unsafe 트레이트 구현
+    // 5. union 필드 접근 (union 과 transmute 는 47장)
+
+    // unsafe는 빌림 검사기를 끄지 않음!
+    // 여전히 소유권 규칙은 적용됨
+
+    // 왜 unsafe가 필요한가?
+    // - 하드웨어 직접 제어
+    // - 성능 최적화
+    // - 다른 언어(C/C++)와 상호작용
+    // - 컴파일러가 증명할 수 없는 안전한 코드
+
+    println!("unsafe 블록은 '이 코드가 안전함을 내가 보장한다'는 의미입니다.");
+}
+
+// ----------------------------------------------------------------------------
+// Raw 포인터
+// ----------------------------------------------------------------------------
+
+fn raw_pointers() {
+    println!("\n--- Raw 포인터 ---");
+
+    // Raw 포인터 타입:
+    // *const T - 불변 raw 포인터 (C++: const T*)
+    // *mut T   - 가변 raw 포인터 (C++: T*)
+
+    let mut num = 5;
+
+    // 참조에서 raw 포인터 생성 - 안전함
+    let r1 = &num as *const i32;
+    let r2 = &mut num as *mut i32;
+
+    // raw 포인터 생성은 안전하지만, 역참조는 unsafe
+    println!("r1 주소: {:?}", r1);
+    println!("r2 주소: {:?}", r2);
+
+    // 역참조는 unsafe 블록 내에서만 가능
+    unsafe {
+        println!("r1 값: {}", *r1);
+        println!("r2 값: {}", *r2);
+
+        // 가변 포인터로 수정
+        *r2 = 10;
+        println!("수정 후 r2 값: {}", *r2);
+    }
+
+    // C++와의 차이:
+    // C++: int* ptr = &num; *ptr = 10;  // 어디서든 가능
+    // Rust: unsafe 블록 필요
+
+    // 임의의 주소에 포인터 생성 (매우 위험!)
+    let address = 0x012345usize;
+    let _r = address as *const i32;
+    // unsafe { println!("{}", *_r); }  // 거의 확실히 크래시!
+
+    // raw 포인터의 특징:
+    // - null 가능
+    // - 자동 해제 없음
+    // - 빌림 규칙 무시 가능
+    // - 유효성 보장 없음
+
+    // 가변/불변 포인터 동시 존재 가능 (일반 참조에서는 불가)
+    let mut value = 42;
+    let ptr1 = &value as *const i32;
+    let ptr2 = &mut value as *mut i32;
+
+    unsafe {
+        // 둘 다 접근 가능하지만, 동시 수정은 정의되지 않은 동작!
+        println!("ptr1: {}, ptr2: {}", *ptr1, *ptr2);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Unsafe 함수
+// ----------------------------------------------------------------------------
+
+// unsafe 함수 선언
+unsafe fn dangerous() {
+    println!("이 함수는 unsafe입니다!");
+}
+
+// 안전한 함수 내부에서 unsafe 사용
+fn split_at_mut(values: &mut [i32], mid: usize) -> (&mut [i32], &mut [i32]) {
+    let len = values.len();
+    let ptr = values.as_mut_ptr();
+
+    assert!(mid <= len);
+
+    // 표준 라이브러리의 split_at_mut과 동일한 구현
+    // 빌림 검사기는 같은 슬라이스에서 두 개의 가변 참조를 만드는 것을 허용하지 않음
+    // 하지만 우리는 겹치지 않는 두 부분을 가리키므로 안전함
+    unsafe {
+        (
+            slice::from_raw_parts_mut(ptr, mid),
+            slice::from_raw_parts_mut(ptr.add(mid), len - mid),
+        )
+    }
+}
+
+fn unsafe_functions() {
+    println!("\n--- Unsafe 함수 ---");
+
+    // unsafe 함수 호출
+    unsafe {
+        dangerous();
+    }
+
+    // 안전한 추상화 사용
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+    let (left, right) = split_at_mut(&mut v, 3);
+
+    println!("left: {:?}", left);
+    println!("right: {:?}", right);
+
+    // 슬라이스 수정
+    left[0] = 100;
+    right[0] = 200;
+    println!("수정 후 v: {:?}", v);
+}
+
+// ----------------------------------------------------------------------------
+// 안전한 추상화
+// ----------------------------------------------------------------------------
+
+// 안전하지 않은 내부 구현을 안전한 API로 감싸기
+// C++: std::vector<T>를 직접 구현하는 것과 같은 연습
+// pub(crate) - 19장 테스트에서 다른 모듈의 API를 검증하는 예제로 사용
+pub(crate) mod safe_wrapper {
+    use std::alloc::{self, Layout};
+    use std::marker::PhantomData;
+    use std::mem::{self, ManuallyDrop, MaybeUninit};
+    use std::ops::{Deref, DerefMut};
+    use std::ptr::{self, NonNull};
+
+    // 불변식 (모든 unsafe 블록이 의존하는 조건):
+    // 1. ptr은 항상 non-null이고 T에 맞게 정렬됨 (cap == 0이면 dangling)
+    // 2. cap > 0 이고 T가 ZST가 아니면 ptr은 Layout::array::<T>(cap)로 할당된 메모리
+    // 3. ptr[0..len]은 초기화된 T, ptr[len..cap]은 미초기화 메모리 (MaybeUninit<T> 로만 봄 - 46장)
+    // 4. len <= cap
+    // 5. ZST(크기 0 타입)는 할당하지 않음 - cap을 usize::MAX로 간주
+    pub struct MyVec<T> {
+        ptr: NonNull<T>,
+        len: usize,
+        cap: usize,
+        // "T를 소유한다"고 drop checker에게 알림 - C++에는 없는 개념
+        _marker: PhantomData<T>,
+    }
+
+    // Send/Sync - raw 포인터가 있으면 자동 구현되지 않으므로 직접 선언
+    // SAFETY: MyVec<T>는 T 값들을 독점 소유하므로 Vec<T>와 같은 규칙을 따름
+    // - T가 Send면 MyVec<T>를 다른 스레드로 옮겨도 안전 (소유권이 함께 이동)
+    // - T가 Sync면 &MyVec<T>는 &[T]만 노출하므로 공유해도 안전
+    // 내부 가변성이 없고 포인터를 다른 곳과 공유하지 않음 (불변식 2)
+    unsafe impl<T: Send> Send for MyVec<T> {}
+    unsafe impl<T: Sync> Sync for MyVec<T> {}
+
+    impl<T> MyVec<T> {
+        const IS_ZST: bool = mem::size_of::<T>() == 0;
+
+        pub fn new() -> Self {
+            MyVec {
+                ptr: NonNull::dangling(),
+                len: 0,
+                cap: if Self::IS_ZST { usize::MAX } else { 0 },
+                _marker: PhantomData,
+            }
+        }
+
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        pub fn capacity(&self) -> usize {
+            self.cap
+        }
+
+        // 안전한 API - 내부적으로 unsafe 사용
+        // 미초기화 슬롯을 &mut T 로 만들면 UB - MaybeUninit<T> 로 보고 write (46장)
+        // *slot = value 로 쓰면 그 자리의 쓰레기 값을 drop 하려 함 - write 는 drop 하지 않고 덮어씀
+        pub fn push(&mut self, value: T) {
+            if self.len == self.cap {
+                self.grow();
+            }
+
+            self.spare_capacity_mut()[0].write(value);
+            // 방금 ptr[len]을 초기화했으므로 불변식 3 유지
+            self.len += 1;
+        }
+
+        // 남은 용량 ptr[len..cap] - Vec::spare_capacity_mut 와 같음
+        // 여기에 쓴 값은 len 을 늘리기 전까지 MyVec 의 요소가 아님 (drop 되지 않음)
+        pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+            // SAFETY: ptr[len..cap]은 할당된 메모리 (불변식 2, ZST 는 크기 0)
+            // MaybeUninit<T> 는 T 와 크기/정렬이 같고 미초기화 상태도 올바른 값
+            unsafe {
+                std::slice::from_raw_parts_mut(
+                    self.ptr.as_ptr().add(self.len) as *mut MaybeUninit<T>,
+                    self.cap - self.len,
+                )
+            }
+        }
+
+        // C++ pop_back()과 달리 빈 벡터에서도 UB 없이 None 반환
+        pub fn pop(&mut self) -> Option<T> {
+            if self.len == 0 {
+                None
+            } else {
+                self.len -= 1;
+                // SAFETY: ptr[len]은 초기화되어 있었고, len을 줄였으므로
+                // 이 값을 다시 읽거나 drop 하지 않음 - 소유권을 호출자에게 이동
+                unsafe { Some(ptr::read(self.ptr.as_ptr().add(self.len))) }
+            }
+        }
+
+        // index 위치에 삽입하고 뒤쪽 요소를 한 칸씩 민다
+        pub fn insert(&mut self, index: usize, value: T) {
+            assert!(index <= self.len, "insert index {} out of bounds (len {})", index, self.len);
+            if self.len == self.cap {
+                self.grow();
+            }
+
+            // SAFETY: len < cap 이므로 ptr[index..len]을 한 칸 뒤로 옮길 공간이 있음
+            // ptr::copy는 memmove처럼 겹치는 영역도 처리
+            unsafe {
+                let p = self.ptr.as_ptr().add(index);
+                ptr::copy(p, p.add(1), self.len - index);
+                ptr::write(p, value);
+            }
+            self.len += 1;
+        }
+
+        // index 위치의 요소를 꺼내고 뒤쪽 요소를 한 칸씩 당긴다
+        pub fn remove(&mut self, index: usize) -> T {
+            assert!(index < self.len, "remove index {} out of bounds (len {})", index, self.len);
+
+            // SAFETY: index < len 이므로 ptr[index]는 초기화됨
+            // 값을 읽은 뒤 뒤쪽을 덮어쓰므로 같은 값이 두 번 drop 되지 않음
+            unsafe {
+                self.len -= 1;
+                let p = self.ptr.as_ptr().add(index);
+                let value = ptr::read(p);
+                ptr::copy(p.add(1), p, self.len - index);
+                value
+            }
+        }
+
+        pub fn get(&self, index: usize) -> Option<&T> {
+            if index < self.len {
+                // SAFETY: index < len 이므로 초기화된 요소 (불변식 3)
+                unsafe { Some(&*self.ptr.as_ptr().add(index)) }
+            } else {
+                None
+            }
+        }
+
+        fn grow(&mut self) {
+            // ZST는 cap이 usize::MAX 이므로 여기까지 오면 len이 넘친 것
+            assert!(!Self::IS_ZST, "capacity overflow");
+
+            // cap * 2 는 릴리스 빌드에서 조용히 넘칠 수 있음 - 넘치면 std 와 같은 메시지로 panic
+            let new_cap = if self.cap == 0 { 1 } else { self.cap.checked_mul(2).expect("capacity overflow") };
+            let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
+
+            let new_ptr = if self.cap == 0 {
+                // SAFETY: T가 ZST가 아니고 new_cap >= 1 이므로 size > 0
+                unsafe { alloc::alloc(new_layout) }
+            } else {
+                let old_layout = Layout::array::<T>(self.cap).unwrap();
+                // SAFETY: ptr은 old_layout으로 할당된 메모리 (불변식 2)
+                unsafe { alloc::realloc(self.ptr.as_ptr() as *mut u8, old_layout, new_layout.size()) }
+            };
+
+            // 할당 실패 시 null - C++의 std::bad_alloc 대신 표준 핸들러 호출
+            self.ptr = match NonNull::new(new_ptr as *mut T) {
+                Some(p) => p,
+                None => alloc::handle_alloc_error(new_layout),
+            };
+            self.cap = new_cap;
+        }
+    }
+
+    impl<T> Default for MyVec<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T> Drop for MyVec<T> {
+        fn drop(&mut self) {
+            // 요소들 drop - 슬라이스로 한 번에
+            // SAFETY: ptr[0..len]은 초기화된 요소들 (불변식 3)
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+            }
+            // 메모리 해제
+            if !Self::IS_ZST && self.cap > 0 {
+                let layout = Layout::array::<T>(self.cap).unwrap();
+                // SAFETY: 불변식 2 - 같은 layout으로 할당됨
+                unsafe {
+                    alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout);
+                }
+            }
+        }
+    }
+
+    // Deref<Target=[T]> - 슬라이스의 모든 메서드(iter, len, sort, 인덱싱...)를 공짜로 얻음
+    // C++: std::span<T>으로의 암시적 변환과 유사
+    impl<T> Deref for MyVec<T> {
+        type Target = [T];
+
+        fn deref(&self) -> &[T] {
+            // SAFETY: ptr은 non-null/정렬됨(불변식 1), ptr[0..len]은 초기화됨(불변식 3)
+            unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    impl<T> DerefMut for MyVec<T> {
+        fn deref_mut(&mut self) -> &mut [T] {
+            // SAFETY: deref와 같음 + &mut self로 독점 접근 보장
+            unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    // 소유권을 가져가는 이터레이터 - for x in my_vec { ... }
+    // 남은 요소는 [start, end) 범위에 있음
+    pub struct IntoIter<T> {
+        buf: NonNull<T>,
+        cap: usize,
+        start: usize,
+        end: usize,
+        _marker: PhantomData<T>,
+    }
+
+    // SAFETY: MyVec과 같은 이유 - 남은 T 값들을 독점 소유
+    unsafe impl<T: Send> Send for IntoIter<T> {}
+    unsafe impl<T: Sync> Sync for IntoIter<T> {}
+
+    impl<T> IntoIterator for MyVec<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+
+        fn into_iter(self) -> IntoIter<T> {
+            // MyVec의 Drop이 실행되지 않도록 막고 버퍼 소유권을 IntoIter로 넘김
+            let vec = ManuallyDrop::new(self);
+            IntoIter {
+                buf: vec.ptr,
+                cap: vec.cap,
+                start: 0,
+                end: vec.len,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<T> Iterator for IntoIter<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            if self.start == self.end {
+                None
+            } else {
+                // SAFETY: [start, end)는 아직 꺼내지 않은 초기화된 요소
+                let value = unsafe { ptr::read(self.buf.as_ptr().add(self.start)) };
+                self.start += 1;
+                Some(value)
+            }
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.end - self.start;
+            (remaining, Some(remaining))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for IntoIter<T> {
+        fn next_back(&mut self) -> Option<T> {
+            if self.start == self.end {
+                None
+            } else {
+                self.end -= 1;
+                // SAFETY: next()와 같음 - end를 줄였으므로 다시 읽지 않음
+                unsafe { Some(ptr::read(self.buf.as_ptr().add(self.end))) }
+            }
+        }
+    }
+
+    impl<T> ExactSizeIterator for IntoIter<T> {}
+
+    impl<T> Drop for IntoIter<T> {
+        fn drop(&mut self) {
+            // 꺼내지 않은 요소 drop 후 버퍼 해제
+            for _ in &mut *self {}
+            if mem::size_of::<T>() != 0 && self.cap > 0 {
+                let layout = Layout::array::<T>(self.cap).unwrap();
+                // SAFETY: MyVec에서 넘겨받은 버퍼 - 같은 layout으로 할당됨
+                unsafe {
+                    alloc::dealloc(self.buf.as_ptr() as *mut u8, layout);
+                }
+            }
+        }
+    }
+
+    // 안전한 추상화는 테스트로 불변식을 검증하는 것이 관례
+    // cargo miri test 로 실행하면 UB까지 검사 가능
+}
+
+fn safe_abstractions() {
+    println!("\n--- 안전한 추상화 ---");
+
+    use safe_wrapper::MyVec;
+
+    let mut v = MyVec::new();
+    v.push(1);
+    v.push(2);
+    v.push(3);
+
+    println!("MyVec 길이: {}, 용량: {}", v.len(), v.capacity());
+    println!("인덱스 1: {:?}", v.get(1));
+    println!("인덱스 10: {:?}", v.get(10));
+
+    // insert/remove - 내부적으로 ptr::copy (memmove)
+    v.insert(0, 0);
+    let removed = v.remove(2);
+    println!("insert(0, 0) 후 remove(2) = {}, 현재: {:?}", removed, &*v);
+    println!("pop: {:?}", v.pop());
+
+    // Deref<Target=[T]> - 슬라이스 메서드를 그대로 사용
+    v.push(10);
+    v.reverse();
+    println!("슬라이스 메서드 reverse(): {:?}, 합계: {}", &*v, v.iter().sum::<i32>());
+
+    // IntoIterator - 소유권을 가져가는 반복
+    let strings: Vec<String> = v.into_iter().map(|x| format!("#{}", x)).collect();
+    println!("into_iter(): {:?}", strings);
+    // println!("{}", v.len());  // 에러! v는 into_iter()로 이동됨
+
+    // Send - T가 Send이므로 다른 스레드로 이동 가능
+    let mut shared = MyVec::new();
+    shared.push(String::from("스레드로 이동"));
+    let handle = std::thread::spawn(move || shared.len());
+    println!("다른 스레드에서 길이: {}", handle.join().unwrap());
+
+    // 사용자는 unsafe 없이 안전하게 사용
+    // 내부 구현의 정확성은 라이브러리 작성자가 보장
+}
+
+// ----------------------------------------------------------------------------
+// unsafe 코드 검증 - 동등성 검사
+// ----------------------------------------------------------------------------
+// 컴파일러는 unsafe 블록 안의 정확성을 보장하지 못함 → 안전한 참조 구현과 비교
+// 재현 가능한 입력을 많이 만들어 두 구현에 넣고 결과가 모두 같은지 확인 (차분 테스트)
+// C++: 직접 만든 컨테이너를 std:: 구현과 같은 입력으로 돌려 보며 퍼징하는 것과 같음
+// 아래 tests 와 이 절의 예제가 같은 검사 도구를 씀 - pub(crate) 라 다른 장의 테스트에서도 사용 가능
+// 같은 비교를 proptest 전략으로 - 실패한 연산 목록을 자동으로 줄여 주는 버전은 42장
+
+pub(crate) mod equivalence {
+    use std::fmt::Debug;
+
+    use super::safe_wrapper::MyVec;
+
+    // 입력 생성기 (splitmix64) - 시드가 같으면 같은 입력, 실패한 경우를 그대로 다시 만들 수 있음
+    pub struct Gen {
+        state: u64,
+    }
+
+    impl Gen {
+        pub fn new(seed: u64) -> Self {
+            Gen { state: seed }
+        }
+
+        pub fn next_u64(&mut self) -> u64 {
+            self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        // 0..=max
+        pub fn up_to(&mut self, max: usize) -> usize {
+            (self.next_u64() % (max as u64 + 1)) as usize
+        }
+
+        // -100..=100
+        pub fn value(&mut self) -> i32 {
+            self.up_to(200) as i32 - 100
+        }
+
+        // 길이 0..=max_len - 빈 입력도 섞이도록
+        pub fn vec(&mut self, max_len: usize) -> Vec<i32> {
+            let len = self.up_to(max_len);
+            (0..len).map(|_| self.value()).collect()
+        }
+    }
+
+    // 두 구현의 결과가 처음으로 달라진 입력
+    #[derive(Debug)]
+    pub struct Mismatch<I, O> {
+        pub case: usize,
+        pub input: I,
+        pub reference: O,
+        pub candidate: O,
+    }
+
+    // cases 개의 입력으로 비교 - 모두 같으면 검사한 개수
+    pub fn check<I, O>(
+        cases: usize,
+        seed: u64,
+        mut input: impl FnMut(&mut Gen) -> I,
+        reference: impl Fn(I) -> O,
+        candidate: impl Fn(I) -> O,
+    ) -> Result<usize, Mismatch<I, O>>
+    where
+        I: Clone + Debug,
+        O: PartialEq + Debug,
+    {
+        let mut gen = Gen::new(seed);
+        for case in 0..cases {
+            let i = input(&mut gen);
+            let r = reference(i.clone());
+            let c = candidate(i.clone());
+            if r != c {
+                return Err(Mismatch { case, input: i, reference: r, candidate: c });
+            }
+        }
+        Ok(cases)
+    }
+
+    // 컨테이너 연산 하나 - 인덱스는 적용할 때 길이에 맞게 줄임
+    #[derive(Debug, Clone, Copy)]
+    pub enum Op {
+        Push(i32),
+        Pop,
+        Insert(usize, i32),
+        Remove(usize),
+    }
+
+    pub fn ops(gen: &mut Gen, max_len: usize) -> Vec<Op> {
+        let len = gen.up_to(max_len);
+        (0..len)
+            .map(|_| match gen.up_to(3) {
+                0 => Op::Push(gen.value()),
+                1 => Op::Pop,
+                2 => Op::Insert(gen.up_to(64), gen.value()),
+                _ => Op::Remove(gen.up_to(64)),
+            })
+            .collect()
+    }
+
+    // 비교할 컨테이너가 구현하는 연산 - Vec 과 직접 만든 컨테이너를 같은 코드로 돌리기 위함
+    pub trait Sequence: Default {
+        fn push(&mut self, value: i32);
+        fn pop(&mut self) -> Option<i32>;
+        fn insert(&mut self, index: usize, value: i32);
+        fn remove(&mut self, index: usize) -> i32;
+        fn items(&self) -> &[i32];
+    }
+
+    impl Sequence for Vec<i32> {
+        fn push(&mut self, value: i32) {
+            Vec::push(self, value)
+        }
+        fn pop(&mut self) -> Option<i32> {
+            Vec::pop(self)
+        }
+        fn insert(&mut self, index: usize, value: i32) {
+            Vec::insert(self, index, value)
+        }
+        fn remove(&mut self, index: usize) -> i32 {
+            Vec::remove(self, index)
+        }
+        fn items(&self) -> &[i32] {
+            self
+        }
+    }
+
+    impl Sequence for MyVec<i32> {
+        fn push(&mut self, value: i32) {
+            MyVec::push(self, value)
+        }
+        fn pop(&mut self) -> Option<i32> {
+            MyVec::pop(self)
+        }
+        fn insert(&mut self, index: usize, value: i32) {
+            MyVec::insert(self, index, value)
+        }
+        fn remove(&mut self, index: usize) -> i32 {
+            MyVec::remove(self, index)
+        }
+        fn items(&self) -> &[i32] {
+            self
+        }
+    }
+
+    // 연산마다 (돌려받은 값, 그때의 내용) - 중간 상태까지 비교
+    pub fn apply<S: Sequence>(ops: &[Op]) -> Vec<(Option<i32>, Vec<i32>)> {
+        let mut s = S::default();
+        let mut trace = Vec::new();
+        for op in ops {
+            let len = s.items().len();
+            let returned = match *op {
+                Op::Push(v) => {
+                    s.push(v);
+                    None
+                }
+                Op::Pop => s.pop(),
+                Op::Insert(i, v) => {
+                    s.insert(i % (len + 1), v);
+                    None
+                }
+                Op::Remove(_) if len == 0 => None,
+                Op::Remove(i) => Some(s.remove(i % len)),
+            };
+            trace.push((returned, s.items().to_vec()));
+        }
+        trace
+    }
+}
+
+fn validating_unsafe() {
+    println!("\n--- unsafe 코드 검증 (동등성 검사) ---");
+
+    use equivalence::{apply, check, ops};
+    use safe_wrapper::MyVec;
+
+    // 직접 만든 split_at_mut vs 표준 라이브러리 - 길이 0..=20, 분할 위치는 0..=len
+    let result = check(
+        1000,
+        16,
+        |g| {
+            let v = g.vec(20);
+            let mid = g.up_to(v.len());
+            (v, mid)
+        },
+        |(mut v, mid)| {
+            let (a, b) = v.split_at_mut(mid);
+            (a.to_vec(), b.to_vec())
+        },
+        |(mut v, mid)| {
+            let (a, b) = split_at_mut(&mut v, mid);
+            (a.to_vec(), b.to_vec())
+        },
+    );
+    println!("split_at_mut 과 표준 라이브러리: {:?}", result.map(|n| format!("{}개 입력 모두 같음", n)));
+
+    // MyVec vs Vec - push/pop/insert/remove 를 무작위 순서로, 연산마다 내용까지 비교
+    let result = check(300, 16, |g| ops(g, 40), |o| apply::<Vec<i32>>(&o), |o| apply::<MyVec<i32>>(&o));
+    println!("MyVec 과 Vec: {:?}", result.map(|n| format!("연산 묶음 {}개 모두 같음", n)));
+
+    // 버그가 있는 구현은 첫 불일치 입력을 보여줌 - 분할 위치가 하나 어긋난 경우
+    let result = check(
+        1000,
+        16,
+        |g| {
+            let v = g.vec(20);
+            let mid = g.up_to(v.len());
+            (v, mid)
+        },
+        |(v, mid)| v.split_at(mid).0.len(),
+        |(mut v, mid)| split_at_mut(&mut v, mid.saturating_sub(1)).0.len(),
+    );
+    match result {
+        Ok(n) => println!("버그를 찾지 못함 ({}개 입력)", n),
+        Err(m) => println!(
+            "버그 발견: {}번째 입력 {:?} → 참조 {:?}, 후보 {:?}",
+            m.case, m.input, m.reference, m.candidate
+        ),
+    }
+
+    // 검사는 증명이 아님 - 생성한 입력에서만 같다는 뜻
+    // Miri(cargo +nightly miri test)와 함께 쓰면 같은 입력에서 정의되지 않은 동작까지 찾아냄
+}
+
+// ----------------------------------------------------------------------------
+// FFI (Foreign Function Interface)
+// ----------------------------------------------------------------------------
+
+// C 표준 라이브러리 함수 선언
+extern "C" {
+    fn abs(input: i32) -> i32;
+    fn strlen(s: *const i8) -> usize;
+}
+
+// Rust 함수를 C에서 호출 가능하게 만들기
+// 라이브러리 전체를 cdylib 로 내보내고 cbindgen 으로 헤더를 만드는 법은 45장
+#[no_mangle]
+pub extern "C" fn rust_function(x: i32) -> i32 {
+    x * 2
+}
+
+fn ffi_example() {
+    println!("\n--- FFI (외부 함수 인터페이스) ---");
+
+    // C 함수 호출
+    unsafe {
+        println!("C abs(-3) = {}", abs(-3));
+
+        // 문자열을 C 스타일로 변환
+        let s = "Hello\0";  // null 종료 문자열
+        let len = strlen(s.as_ptr() as *const i8);
+        println!("C strlen(\"Hello\") = {}", len);
+    }
+
+    // C++와의 상호운용:
+    // - extern "C"로 C ABI 사용
+    // - #[repr(C)]로 C 호환 메모리 레이아웃
+    // - bindgen 크레이트로 C 헤더에서 자동 바인딩 생성
+    // - 직접 쓴 C++ 코드를 build.rs 로 컴파일해 부르는 법과 cxx 크레이트는 44장
+
+    // C 호환 구조체
+    #[repr(C)]
+    struct CPoint {
+        x: i32,
+        y: i32,
+    }
+
+    let point = CPoint { x: 10, y: 20 };
+    println!("C 호환 구조체: ({}, {})", point.x, point.y);
+
+    // 호출 규약:
+    // extern "C"     - C 호출 규약 (기본)
+    // extern "system" - Windows API 호출 규약
+    // extern "stdcall" - Windows stdcall
+}
+
+// ----------------------------------------------------------------------------
+// 정적 가변 변수
+// ----------------------------------------------------------------------------
+// static mut 는 접근할 때마다 unsafe - 여러 스레드가 동시에 쓰면 데이터 레이스 (UB)
+// 2024 에디션은 &COUNTER 처럼 static mut 의 참조를 만드는 것도 에러 (static_mut_refs)
+// 가변 전역이 필요하면 내부 가변성을 가진 타입의 평범한 static 으로 - 정수는 Atomic*, 그 밖은 Mutex
+
+// static mut COUNTER: usize = 0;
+// fn add_to_counter(inc: usize) { unsafe { COUNTER += inc; } }   // 두 스레드가 부르면 더한 값을 잃을 수 있음
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn add_to_counter(inc: usize) {
+    // 읽고 더하고 쓰기가 하나의 원자적 연산 - unsafe 가 필요 없음
+    COUNTER.fetch_add(inc, Ordering::Relaxed);
+}
+
+fn static_mut_variables() {
+    println!("\n--- 정적 가변 변수 ---");
+
+    // C++ 에서는:
+    // static int counter = 0;                  // 전역 가변 변수 - 여러 스레드에서 ++counter 는 데이터 레이스 (UB)
+    // static std::atomic<std::size_t> counter;  // Rust 의 AtomicUsize 와 같음
+
+    add_to_counter(3);
+    add_to_counter(5);
+
+    // 스레드 여럿이 동시에 더해도 잃는 값이 없음 - 다른 데이터를 보호하지 않는 카운터라 Relaxed 로 충분 (Atomic 과 Mutex 비교는 52장)
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| add_to_counter(1));
+        }
+    });
+    println!("COUNTER = {}", COUNTER.load(Ordering::Relaxed));
+
+    // 처음 쓸 때 초기화하는 전역 (설정, 표, 레지스트리) 은 OnceLock, LazyLock - 52장
+}
+
+// ----------------------------------------------------------------------------
+// Unsafe 트레이트
+// ----------------------------------------------------------------------------
+
+// unsafe 트레이트 - 구현자가 불변 조건을 보장해야 함
+unsafe trait UnsafeTrait {
+    fn do_something(&self);
+}
+
+struct SafeType;
+
+// unsafe 트레이트 구현
+unsafe impl UnsafeTrait for SafeType {
+    fn do_something(&self) {
+        println!("SafeType이 UnsafeTrait을 구현했습니다.");
+    }
+}
+
+fn unsafe_traits() {
+    println!("\n--- Unsafe 트레이트 ---");
+
+    let s = SafeType;
+    s.do_something();
+
+    // 대표적인 unsafe 트레이트:
+    // Send - 스레드 간 소유권 이전 가능
+    // Sync - 스레드 간 참조 공유 가능
+
+    // 대부분의 타입은 자동으로 Send/Sync 구현
+    // raw 포인터, Rc 등은 구현 안 됨
+
+    println!("\nSend/Sync 트레이트:");
+    println!("- 컴파일러가 자동 구현 추론");
+    println!("- unsafe impl로 수동 구현 가능");
+    println!("- 잘못 구현하면 데이터 레이스 가능");
+}
+}
+
+// --- rust-study 의 _19_testing 모듈 ---
+mod _19_testing {
+// ============================================================================
+// 19. 테스트 (Testing)
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. 테스트가 언어/빌드 시스템에 내장 - 별도 프레임워크 불필요
+// 2. #[test] 어트리뷰트로 테스트 함수 표시
+// 3. cargo test로 모든 테스트 실행
+// 4. 단위 테스트는 같은 파일에, 통합 테스트는 tests/ 디렉터리에
+// 5. 문서 테스트 (doc tests) 지원
+// ============================================================================
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// 절 목록 (실행 순서) - cargo run -- 19:test_basics_explanation 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("test_basics_explanation", test_basics_explanation),
+    ("assertion_macros_explanation", assertion_macros_explanation),
+    ("fixtures_demo", fixtures_demo),
+    ("parameterized_demo", parameterized_demo),
+    ("cross_module_demo", cross_module_demo),
+    ("test_organization_explanation", test_organization_explanation),
+    ("test_attributes_explanation", test_attributes_explanation),
+    ("test_commands_explanation", test_commands_explanation),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "19"
+    }
+
+    fn name(&self) -> &'static str {
+        "테스트 (Testing)"
+    }
+
+    fn description(&self) -> &'static str {
+        "테스트가 언어/빌드 시스템에 내장 - 별도 프레임워크 불필요"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["#[test]", "단언 매크로", "픽스처", "테스트 구성"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ============================================================================
+// 테스트 기본 구조
+// ============================================================================
+
+fn test_basics_explanation() {
+    println!("--- 테스트 기본 구조 ---");
+
+    // 이 파일 하단의 `mod tests`가 실제로 컴파일되고 실행되는 테스트
+    // #[cfg(test)] - cargo test 때만 컴파일 (C++: 별도 테스트 타깃 + gtest)
+    // use super::*;  - 부모 모듈의 private 함수까지 테스트 가능
+    println!("테스트 대상 함수 (아래 mod tests에서 검증):");
+    println!("  add(2, 3) = {}", add(2, 3));
+    println!("  subtract(5, 3) = {}", subtract(5, 3));
+    println!("  divide(7, 2) = {}", divide(7, 2));
+    println!("  is_even(4) = {}", is_even(4));
+    println!();
+
+    println!("실행 방법:");
+    println!("  cargo test              # 모든 테스트 실행");
+    println!("  cargo test test_name    # 특정 테스트만 실행");
+    println!("  cargo test --lib        # 라이브러리 테스트만");
+    println!("  cargo test --doc        # 문서 테스트만");
+}
+
+// ============================================================================
+// 단언 매크로 (Assertion Macros)
+// ============================================================================
+
+fn assertion_macros_explanation() {
+    println!("\n--- 단언 매크로 ---");
+
+    // #[should_panic], Result 반환 테스트 등은 하단 mod tests에 실제로 있음
+    // - test_divide_by_zero: #[should_panic(expected = "...")]
+    // - test_with_result: -> Result<(), String>
+
+    // 실제 동작 예시
+    println!("실제 단언 동작:");
+
+    // assert!
+    let value = 10;
+    assert!(value > 0);
+    println!("  assert!(10 > 0) - 통과");
+
+    // assert_eq!
+    assert_eq!(2 + 2, 4);
+    println!("  assert_eq!(2 + 2, 4) - 통과");
+
+    // assert_ne!
+    assert_ne!("hello", "world");
+    println!("  assert_ne!(\"hello\", \"world\") - 통과");
+
+    // 커스텀 단언 매크로 - 부동소수점 비교
+    // 0.1 + 0.2 == 0.3 은 false! (C++도 동일)
+    assert_approx_eq!(0.1 + 0.2, 0.3);
+    println!("  assert_approx_eq!(0.1 + 0.2, 0.3) - 통과");
+    assert_approx_eq!(divide(7, 2) as f64, 3.5, 0.5);
+    println!("  assert_approx_eq!(3.0, 3.5, 0.5) - 통과");
+}
+
+// ----------------------------------------------------------------------------
+// 커스텀 단언 매크로
+// ----------------------------------------------------------------------------
+// assert_eq!처럼 실패 시 양쪽 값과 호출 위치를 보여주는 것이 핵심
+// 매크로이므로 panic 위치가 호출한 줄로 표시됨 (함수라면 #[track_caller] 필요)
+
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr) => {
+        assert_approx_eq!($left, $right, 1e-9)
+    };
+    ($left:expr, $right:expr, $eps:expr) => {
+        match (&$left, &$right, &$eps) {
+            (left, right, eps) => {
+                // abs() 대신 비교 - f32/f64 어느 쪽으로 추론되어도 동작
+                let diff = if *left > *right { *left - *right } else { *right - *left };
+                assert!(
+                    diff <= *eps,
+                    "assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}\n  diff: {:?} > eps {:?}",
+                    left,
+                    right,
+                    diff,
+                    eps
+                );
+            }
+        }
+    };
+}
+use assert_approx_eq;
+
+// ============================================================================
+// 픽스처: 임시 디렉터리
+// ============================================================================
+// 실무에서는 tempfile::TempDir 사용 - 여기서는 표준 라이브러리만으로 구현
+// C++ gtest: SetUp()/TearDown() -> Rust: 생성자 + Drop (RAII)
+
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    // 테스트가 병렬로 실행되므로 이름이 겹치지 않게 pid + 카운터 사용
+    pub fn new(prefix: &str) -> io::Result<Self> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!(
+            "{}-{}-{}",
+            prefix,
+            std::process::id(),
+            unique
+        ));
+        fs::create_dir_all(&path)?;
+        Ok(TempDir { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn write(&self, name: &str, contents: &str) -> io::Result<PathBuf> {
+        let file = self.path.join(name);
+        fs::write(&file, contents)?;
+        Ok(file)
+    }
+}
+
+impl Drop for TempDir {
+    // 테스트가 실패(패닉)해도 정리됨
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+// 픽스처로 테스트할 함수 - 디렉터리에서 확장자가 ext인 파일의 줄 수 합계
+pub fn count_lines(dir: &Path, ext: &str) -> io::Result<usize> {
+    let mut total = 0;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == ext) {
+            total += fs::read_to_string(&path)?.lines().count();
+        }
+    }
+    Ok(total)
+}
+
+fn fixtures_demo() {
+    println!("\n--- 픽스처: 임시 디렉터리 ---");
+
+    let kept_path;
+    {
+        let dir = TempDir::new("rust-study-demo").unwrap();
+        dir.write("a.rs", "fn main() {}\n// 주석\n").unwrap();
+        dir.write("b.rs", "mod x;\n").unwrap();
+        dir.write("notes.txt", "무시됨\n").unwrap();
+
+        println!("임시 디렉터리: {}", dir.path().display());
+        println!("count_lines(.rs) = {}", count_lines(dir.path(), "rs").unwrap());
+        kept_path = dir.path().to_path_buf();
+        // 스코프 끝에서 Drop -> 디렉터리 삭제
+    }
+    println!("스코프 종료 후 존재 여부: {}", kept_path.exists());
+}
+
+// ============================================================================
+// 파라미터화 테스트 (rstest 스타일)
+// ============================================================================
+// rstest 크레이트:
+// #[rstest]
+// #[case(0, true)]
+// #[case(1, false)]
+// fn test_is_even(#[case] input: i32, #[case] expected: bool) { ... }
+//
+// 여기서는 macro_rules!로 같은 효과 - 케이스마다 별도의 #[test] 함수 생성
+// 반복문 테스트와 달리 실패한 케이스 이름이 테스트 결과에 그대로 표시됨
+// 입력을 고르지 않고 생성하려면 42장 (proptest - 같은 add, is_even 의 성질을 검사)
+
+#[cfg(test)]
+macro_rules! parameterized {
+    ($func:ident { $($case:ident: ($($arg:expr),*) => $expected:expr),+ $(,)? }) => {
+        mod $func {
+            use super::*;
+            $(
+                #[test]
+                fn $case() {
+                    assert_eq!($func($($arg),*), $expected);
+                }
+            )+
+        }
+    };
+}
+
+fn parameterized_demo() {
+    println!("\n--- 파라미터화 테스트 ---");
+
+    // 하단 mod parameterized_tests에서 케이스별 테스트로 생성되는 표
+    let cases = [(0, true), (1, false), (-2, true), (7, false)];
+    for (input, expected) in cases {
+        let ok = is_even(input) == expected;
+        println!("  is_even({:>2}) == {:<5} ... {}", input, expected, if ok { "ok" } else { "FAILED" });
+    }
+    println!("cargo test is_even:: 로 실행하면 케이스별 이름으로 결과 표시:");
+    println!("  test _19_testing::parameterized_tests::is_even::negative ... ok");
+}
+
+// ============================================================================
+// 다른 모듈의 API 테스트
+// ============================================================================
+
+fn cross_module_demo() {
+    println!("\n--- 다른 모듈의 API 테스트 ---");
+
+    // 16장의 MyVec을 외부 사용자 입장에서 사용 - pub(crate) API만 접근 가능
+    use crate::_16_unsafe::safe_wrapper::MyVec;
+
+    let mut v = MyVec::new();
+    for word in ["unsafe", "안전한", "추상화"] {
+        v.push(word.to_string());
+    }
+    v.swap(0, 2);
+    println!("16장 MyVec 사용: {:?}", &*v);
+    // 내부 필드(ptr, len, cap)는 private이라 테스트에서도 접근 불가
+    // -> 공개 API로만 검증하는 것이 통합 테스트의 관점
+}
+
+// ============================================================================
+// 테스트 구성
+// ============================================================================
+
+fn test_organization_explanation() {
+    println!("\n--- 테스트 구성 ---");
+
+    println!(r#"
+프로젝트 구조:
+my_project/
+├── Cargo.toml
+├── src/
+│   ├── lib.rs          # 라이브러리 루트
+│   ├── main.rs         # 바이너리 (있는 경우)
+│   └── utils.rs        # 모듈
+└── tests/              # 통합 테스트
+    ├── integration_test.rs
+    └── common/
+        └── mod.rs      # 테스트 헬퍼
+
+=== 1. 단위 테스트 (Unit Tests) ===
+- 같은 파일 내 #[cfg(test)] 모듈에 작성
+- private 함수도 테스트 가능
+- cargo test --lib로 실행
+
+// src/lib.rs
+pub fn public_fn() -> i32 {{ 42 }}
+fn private_fn() -> i32 {{ 100 }}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn test_public() {{
+        assert_eq!(public_fn(), 42);
+    }}
+
+    #[test]
+    fn test_private() {{
+        // private 함수도 테스트 가능!
+        assert_eq!(private_fn(), 100);
+    }}
+}}
+
+=== 2. 통합 테스트 (Integration Tests) ===
+- tests/ 디렉터리에 별도 파일로 작성
+- public API만 테스트 가능
+- 각 파일이 별도 크레이트로 컴파일
+
+// tests/integration_test.rs
+use my_project::public_fn;
+
+#[test]
+fn test_from_outside() {{
+    assert_eq!(public_fn(), 42);
+}}
+
+=== 3. 문서 테스트 (Doc Tests) ===
+- 문서 주석 내 코드 블록 자동 테스트
+- 문서와 코드 동기화 보장
+
+/// 두 수를 더합니다.
+///
+/// # Examples
+///
+/// ```
+/// let result = my_crate::add(2, 3);
+/// assert_eq!(result, 5);
+/// ```
+pub fn add(a: i32, b: i32) -> i32 {{
+    a + b
+}}
+"#);
+}
+
+// ============================================================================
+// 테스트 어트리뷰트
+// ============================================================================
+
+fn test_attributes_explanation() {
+    println!("\n--- 테스트 어트리뷰트 ---");
+
+    println!(r#"
+// 기본 테스트
+#[test]
+fn basic_test() {{ }}
+
+// 무시할 테스트
+#[test]
+#[ignore]
+fn slow_test() {{
+    // 시간이 오래 걸리는 테스트
+}}
+
+// 무시된 테스트 실행: cargo test -- --ignored
+// 모든 테스트 실행: cargo test -- --include-ignored
+
+// 패닉 예상
+#[test]
+#[should_panic]
+fn panics() {{ panic!(); }}
+
+// 특정 패닉 메시지 확인
+#[test]
+#[should_panic(expected = "0으로 나눌 수 없음")]
+fn panics_with_message() {{
+    divide(1, 0);
+}}
+
+// 조건부 컴파일
+#[test]
+#[cfg(target_os = "linux")]
+fn linux_only_test() {{ }}
+
+// 타임아웃 (nightly 기능)
+// #[test]
+// #[timeout(1000)]  // 1초
+// fn must_finish_quickly() {{ }}
+"#);
+}
+
+// ============================================================================
+// cargo test 명령어
+// ============================================================================
+
+fn test_commands_explanation() {
+    println!("\n--- cargo test 명령어 ---");
+
+    println!(r#"
+=== 기본 명령어 ===
+cargo test                    # 모든 테스트 실행
+cargo test --release          # 릴리즈 모드로 테스트
+cargo test --no-fail-fast     # 실패해도 계속 실행
+
+=== 필터링 ===
+cargo test test_name          # 이름에 'test_name' 포함된 테스트
+cargo test tests::            # 'tests::' 모듈의 테스트
+cargo test --test integration # tests/integration.rs만 실행
+
+=== 테스트 종류 선택 ===
+cargo test --lib              # 단위 테스트만
+cargo test --doc              # 문서 테스트만
+cargo test --bins             # 바이너리 테스트만
+cargo test --examples         # 예제 테스트만
+
+=== 출력 제어 ===
+cargo test -- --nocapture     # println! 출력 보기
+cargo test -- --show-output   # 성공한 테스트 출력도 보기
+cargo test -- --test-threads=1  # 단일 스레드로 실행
+
+=== 무시된 테스트 ===
+cargo test -- --ignored       # #[ignore] 테스트만 실행
+cargo test -- --include-ignored  # 모든 테스트 (무시된 것 포함)
+
+=== 테스트 목록 ===
+cargo test -- --list          # 테스트 목록만 출력
+cargo test -- --list --ignored  # 무시된 테스트 목록
+"#);
+
+    println!("=== 예시 출력 ===");
+    println!(r#"
+$ cargo test
+
+running 3 tests
+test tests::test_addition ... ok
+test tests::test_subtraction ... ok
+test tests::test_multiplication ... ok
+
+test result: ok. 3 passed; 0 failed; 0 ignored
+
+   Doc-tests my_project
+
+running 2 tests
+test src/lib.rs - add (line 5) ... ok
+test src/lib.rs - subtract (line 15) ... ok
+
+test result: ok. 2 passed; 0 failed; 0 ignored
+"#);
+}
+
+// ============================================================================
+// 실제 테스트 예제 (이 파일 내에서)
+// ============================================================================
+
+// 테스트할 함수들
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+pub fn subtract(a: i32, b: i32) -> i32 {
+    a - b
+}
+
+pub fn divide(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        panic!("0으로 나눌 수 없음");
+    }
+    a / b
+}
+
+pub fn is_even(n: i32) -> bool {
+    n % 2 == 0
+}
+
+// 테스트 모듈
+
+// ============================================================================
+// 테스트 헬퍼 및 픽스처
+// ============================================================================
+
+// 픽스처를 사용하는 테스트
+
+// 파라미터화 테스트 - 케이스마다 별도 테스트 함수가 생성됨
+
+// 커스텀 단언 매크로 테스트
+
+// 다른 모듈(16장)의 API를 처음부터 끝까지 사용하는 테스트
+}
+
+// --- rust-study 의 chapter 모듈 ---
+mod chapter {
+// ============================================================================
+// 장(chapter) 공통 인터페이스
+// ============================================================================
+// 각 _NN_*.rs 모듈의 Lesson 이 구현 - main 은 Vec<Box<dyn Chapter>> 를 돌며 절(sections)을 하나씩 실행 (runner.rs)
+// C++: struct Chapter { virtual Sections sections() const = 0; ... }; 와 std::vector<std::unique_ptr<Chapter>>
+//
+// 표준 라이브러리만 사용 - 샌드박스(cargo run -- sandbox)에 이 파일이 그대로 들어감
+// 장 번호와 제목 표(chapters::CHAPTERS)는 wasm 에서도 쓰도록 라이브러리에 따로 있고,
+// 두 곳이 어긋나지 않는지는 main.rs 의 테스트가 확인
+// Sync - --parallel 에서 여러 스레드가 같은 장 목록을 빌려 씀 (Lesson 은 필드 없는 구조체라 자동)
+// ============================================================================
+
+pub trait Chapter: Sync {
+    // "07" - chapters::CHAPTERS 의 id
+    fn id(&self) -> &'static str;
+
+    // "트레이트 (Traits)" - 파일 머리의 제목
+    fn name(&self) -> &'static str;
+
+    // 한 줄 설명
+    fn description(&self) -> &'static str;
+
+    // 다루는 주제 (--list 와 검색에 표시)
+    fn topics(&self) -> &'static [&'static str];
+
+    // (절 이름, 진입 함수) - 실행 순서, cargo run -- 07:trait_objects 로 하나만 실행
+    fn sections(&self) -> &'static [(&'static str, fn())];
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn runner() -> TestRunner {
+    let config = Config { failure_persistence: None, ..Config::default() };
+    TestRunner::new_with_rng(config, TestRng::deterministic_rng(RngAlgorithm::ChaCha))
+}
+
+fn report<S>(name: &str, strategy: S, test: impl Fn(S::Value) -> Result<(), TestCaseError>)
+where
+    S: Strategy,
+    S::Value: std::fmt::Debug,
+{
+    let mut runner = runner();
+    let cases = runner.config().cases;
+    match runner.run(&strategy, test) {
+        Ok(()) => println!("  {:<28} 통과 ({} 개 입력)", name, cases),
+        Err(TestError::Fail(_, input)) => println!("  {:<28} 실패, 축소된 반례: {:?}", name, input),
+        Err(TestError::Abort(reason)) => println!("  {:<28} 중단: {}", name, reason),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 축소 (shrinking)
+// ----------------------------------------------------------------------------
+// 실패한 입력을 찾으면 "더 단순한 값" 으로 바꿔 가며 여전히 실패하는지 다시 돌려 봄
+// 정수는 0 쪽으로, Vec 은 원소를 빼고 원소 자체도 줄임 - 더 줄일 수 없을 때의 값이 보고됨
+// 처음 찾은 반례가 [37, -88, 12, 95, ...] 여도 보고는 최소 반례 하나
+// 단 축소는 탐색이라 항상 "가장" 작지는 않음 - 정수는 0 쪽으로 이분 탐색이라
+// 중간값이 성질을 통과하면(아래 is_odd 의 짝수) 거기서 멈춤
+
+// 일부러 틀린 구현 - 음수의 % 는 음수 (-3 % 2 == -1) 라 음수 홀수에서 false
+fn is_odd_buggy(n: i32) -> bool {
+    n % 2 == 1
+}
+
+// 일부러 틀린 구현 - 마지막 원소를 보지 않음 (끝 인덱스를 하나 덜 센 실수)
+fn largest_buggy(v: &[i32]) -> Option<i32> {
+    v[..v.len().saturating_sub(1)].iter().copied().max().or(v.first().copied())
+}
+
+fn shrinking() {
+    println!("\n--- 축소: 실패 입력을 가장 작게 ---");
+
+    // C++ 에서는:
+    // 임의 입력 테스트가 실패하면 "seed=3141592 에서 실패" 만 남음
+    // 1000 개짜리 입력을 손으로 잘라 가며 어느 부분이 문제인지 찾아야 함
+
+    report("is_odd 와 is_even 은 반대", any::<i32>(), |n| {
+        prop_assert_ne!(is_odd_buggy(n), is_even(n));
+        Ok(())
+    });
+    report("largest 는 max 와 같음", prop::collection::vec(any::<i32>(), 0..50), |v| {
+        prop_assert_eq!(largest_buggy(&v), v.iter().copied().max());
+        Ok(())
+    });
+
+    // 고친 구현은 같은 성질을 통과
+    report("n % 2 != 0 으로 고치면", any::<i32>(), |n| {
+        prop_assert_ne!(n % 2 != 0, is_even(n));
+        Ok(())
+    });
+    println!("[0, 1] 은 원인(마지막 원소)이 바로 보임");
+    println!("정수 반례는 -1 까지 못 갈 수 있음 (이분 탐색) - 그래도 \"음수 홀수\" 라는 모양은 드러남");
+}
+
+fn main() {
+    shrinking();
+}
@@ -0,0 +1,69 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 44. C++ 라이브러리 호출 - build.rs 에서 C++ 컴파일
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr::NonNull;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[cfg(not(cpp_ffi))]
+fn not_built() {
+    println!("C++ 라이브러리 없이 빌드됨 - c++ 또는 clang++ 를 설치하고 기본 기능(cpp-ffi)으로 다시 빌드");
+}
+
+#[cfg(cpp_ffi)]
+#[cxx::bridge(namespace = "study")]
+mod bridge {
+    // 양쪽에서 같은 모양으로 쓰는 구조체 - C++ 쪽 정의는 cxx 가 생성
+    #[derive(Debug)]
+    struct Item {
+        name: String,
+        count: u32,
+    }
+
+    unsafe extern "C++" {
+        include!("rust-study/cpp/ffi/study_ffi.h");
+
+        type Inventory;
+
+        fn new_inventory() -> UniquePtr<Inventory>;
+        // C++ 의 비 const 멤버 함수는 Pin<&mut T> - C++ 객체는 주소가 바뀌면 안 되므로
+        fn add(self: Pin<&mut Inventory>, name: &str, count: u32);
+        fn count(&self, name: &str) -> u32;
+        fn take(self: Pin<&mut Inventory>, name: &str, count: u32) -> Result<u32>;
+        fn items(&self) -> Vec<Item>;
+    }
+}
+
+// ----------------------------------------------------------------------------
+// build.rs 에서 C++ 컴파일
+// ----------------------------------------------------------------------------
+// build.rs (이 저장소의 것):
+//   cxx_build::bridge("src/_44_cpp_ffi.rs")     #[cxx::bridge] 의 C++ 쪽 연결 코드 생성
+//       .file("cpp/ffi/study_ffi.cc")           직접 쓴 C++ 파일 추가
+//       .std("c++17")
+//       .try_compile("study_ffi")               → libstudy_ffi.a, 링크 지시까지 cargo 에 출력
+// extern "C" 만 쓴다면 cxx-build 없이 cc 크레이트로 같은 일:
+//   cc::Build::new().cpp(true).file("cpp/ffi/study_ffi.cc").compile("study_ffi");
+// cc 는 CXX, CXXFLAGS 환경 변수와 대상 플랫폼을 보고 컴파일러와 플래그를 고름 (MSVC, gcc, clang)
+
+fn build_script() {
+    println!("--- build.rs 에서 C++ 컴파일 ---");
+
+    // C++ 에서는:
+    // CMake 의 add_library(study_ffi STATIC study_ffi.cc) + target_link_libraries
+    // Rust 는 build.rs 가 그 역할 - cargo build 한 번이면 C++ 도 함께 빌드됨
+
+    println!("cfg(cpp_ffi) 켜짐: {}", cfg!(cpp_ffi));
+    println!("빌드 순서: build.rs 실행 → cpp/ffi/*.cc 컴파일 → libstudy_ffi.a → rustc 가 링크");
+    println!("cpp/ffi/ 나 이 파일이 바뀌면 다시 컴파일 (cargo:rerun-if-changed)");
+    #[cfg(not(cpp_ffi))]
+    not_built();
+}
+
+fn main() {
+    build_script();
+}
@@ -0,0 +1,129 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 44. C++ 라이브러리 호출 - cxx - 양쪽 연결 코드 생성
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr::NonNull;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[cfg(not(cpp_ffi))]
+fn not_built() {
+    println!("C++ 라이브러리 없이 빌드됨 - c++ 또는 clang++ 를 설치하고 기본 기능(cpp-ffi)으로 다시 빌드");
+}
+
+#[cfg(cpp_ffi)]
+mod raw {
+    use std::ffi::c_char;
+
+    // build.rs 의 링크 지시는 라이브러리 타깃에만 붙음 - examples/ 처럼 이 파일을 따로 빌드해도 링크되도록
+    // C++ 표준 라이브러리(new[], 예외)는 cxx 크레이트가 링크해 줌
+    use cxx as _;
+
+    // C++ 의 struct StudyCounter - 크기를 모르는 타입 (포인터로만 다룸)
+    #[repr(C)]
+    pub struct StudyCounter {
+        _private: [u8; 0],
+    }
+
+    #[link(name = "study_ffi", kind = "static")]
+    extern "C" {
+        pub fn study_checksum(data: *const u8, len: usize) -> u32;
+        pub fn study_greeting(name: *const c_char) -> *mut c_char;
+        pub fn study_string_free(s: *mut c_char);
+        pub fn study_counter_new(start: i32) -> *mut StudyCounter;
+        pub fn study_counter_add(counter: *mut StudyCounter, n: i32);
+        pub fn study_counter_get(counter: *const StudyCounter) -> i32;
+        pub fn study_counter_divide(counter: *mut StudyCounter, by: i32) -> i32;
+        pub fn study_counter_free(counter: *mut StudyCounter);
+        pub fn study_live_objects() -> i32;
+    }
+}
+
+#[cfg(cpp_ffi)]
+pub fn live_objects() -> i32 {
+    // SAFETY: 인자 없는 조회
+    unsafe { raw::study_live_objects() }
+}
+
+// ----------------------------------------------------------------------------
+// cxx - 양쪽 연결 코드 생성
+// ----------------------------------------------------------------------------
+// 아래 mod 하나에서 Rust 쪽 선언과 C++ 쪽 연결 코드(_44_cpp_ffi.rs.h/.cc)가 함께 생성됨
+// C++ 헤더의 시그니처와 다르면 C++ 컴파일 에러 - extern "C" 처럼 조용히 틀리지 않음
+// &str ↔ rust::Str, String ↔ rust::String, Vec<T> ↔ rust::Vec<T>, UniquePtr<T> ↔ std::unique_ptr<T>
+// Result<T> 를 돌려주는 함수는 C++ 예외를 잡아 Err(cxx::Exception) 으로
+
+#[cfg(cpp_ffi)]
+#[cxx::bridge(namespace = "study")]
+mod bridge {
+    // 양쪽에서 같은 모양으로 쓰는 구조체 - C++ 쪽 정의는 cxx 가 생성
+    #[derive(Debug)]
+    struct Item {
+        name: String,
+        count: u32,
+    }
+
+    unsafe extern "C++" {
+        include!("rust-study/cpp/ffi/study_ffi.h");
+
+        type Inventory;
+
+        fn new_inventory() -> UniquePtr<Inventory>;
+        // C++ 의 비 const 멤버 함수는 Pin<&mut T> - C++ 객체는 주소가 바뀌면 안 되므로
+        fn add(self: Pin<&mut Inventory>, name: &str, count: u32);
+        fn count(&self, name: &str) -> u32;
+        fn take(self: Pin<&mut Inventory>, name: &str, count: u32) -> Result<u32>;
+        fn items(&self) -> Vec<Item>;
+    }
+}
+
+fn cxx_bridge() {
+    println!("\n--- #[cxx::bridge] ---");
+
+    // C++ 에서는:
+    // auto inv = study::new_inventory();
+    // inv->add("사과", 3);
+    // try { inv->take("사과", 5); } catch (const std::out_of_range& e) { ... }
+
+    #[cfg(not(cpp_ffi))]
+    not_built();
+
+    #[cfg(cpp_ffi)]
+    {
+        let before = live_objects();
+        let mut inventory = bridge::new_inventory();
+        // UniquePtr 는 null 일 수 있음 - as_ref/pin_mut 는 null 이면 None/panic
+        println!("null 아님: {}", !inventory.is_null());
+
+        inventory.pin_mut().add("사과", 3);
+        inventory.pin_mut().add("배", 5);
+        inventory.pin_mut().add("사과", 2);
+        println!("사과 {} 개, 귤 {} 개", inventory.count("사과"), inventory.count("귤"));
+
+        // C++ 의 rust::Vec<Item> 을 Rust 의 Vec<Item> 으로 그대로 받음 (복사 없이 소유권 이동)
+        for item in inventory.items() {
+            println!("  {:?}", item);
+        }
+
+        match inventory.pin_mut().take("배", 2) {
+            Ok(left) => println!("배 2 개 꺼냄, 남은 {} 개", left),
+            Err(e) => println!("에러: {}", e.what()),
+        }
+        // C++ 가 던진 std::out_of_range → Err - what() 은 예외 메시지
+        match inventory.pin_mut().take("사과", 10) {
+            Ok(left) => println!("사과 10 개 꺼냄, 남은 {} 개", left),
+            Err(e) => println!("C++ 예외 → Err: {}", e.what()),
+        }
+
+        println!("살아 있는 C++ 객체 +{}", live_objects() - before);
+        drop(inventory);
+        println!("UniquePtr drop 뒤 +{} (C++ 소멸자 실행)", live_objects() - before);
+    }
+}
+
+fn main() {
+    cxx_bridge();
+}
@@ -0,0 +1,125 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 44. C++ 라이브러리 호출 - extern "C" - 직접 선언하고 unsafe 로 호출
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr::NonNull;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[cfg(not(cpp_ffi))]
+fn not_built() {
+    println!("C++ 라이브러리 없이 빌드됨 - c++ 또는 clang++ 를 설치하고 기본 기능(cpp-ffi)으로 다시 빌드");
+}
+
+// ----------------------------------------------------------------------------
+// extern "C" - 직접 선언하고 unsafe 로 호출
+// ----------------------------------------------------------------------------
+// C++ 쪽은 extern "C" 로 이름 장식(name mangling)을 끄고 C ABI 타입만 씀
+// Rust 쪽 선언은 헤더(study_ffi.h)를 보고 손으로 - 틀려도 컴파일러가 모름 (bindgen 으로 생성 가능)
+// -sys 크레이트 관례처럼 날것의 선언은 mod raw 에 모으고 밖에는 안전한 함수만 내놓음
+
+#[cfg(cpp_ffi)]
+mod raw {
+    use std::ffi::c_char;
+
+    // build.rs 의 링크 지시는 라이브러리 타깃에만 붙음 - examples/ 처럼 이 파일을 따로 빌드해도 링크되도록
+    // C++ 표준 라이브러리(new[], 예외)는 cxx 크레이트가 링크해 줌
+    use cxx as _;
+
+    // C++ 의 struct StudyCounter - 크기를 모르는 타입 (포인터로만 다룸)
+    #[repr(C)]
+    pub struct StudyCounter {
+        _private: [u8; 0],
+    }
+
+    #[link(name = "study_ffi", kind = "static")]
+    extern "C" {
+        pub fn study_checksum(data: *const u8, len: usize) -> u32;
+        pub fn study_greeting(name: *const c_char) -> *mut c_char;
+        pub fn study_string_free(s: *mut c_char);
+        pub fn study_counter_new(start: i32) -> *mut StudyCounter;
+        pub fn study_counter_add(counter: *mut StudyCounter, n: i32);
+        pub fn study_counter_get(counter: *const StudyCounter) -> i32;
+        pub fn study_counter_divide(counter: *mut StudyCounter, by: i32) -> i32;
+        pub fn study_counter_free(counter: *mut StudyCounter);
+        pub fn study_live_objects() -> i32;
+    }
+}
+
+// 빌림 - 슬라이스를 (포인터, 길이) 로 넘기고 C++ 는 호출 동안만 읽음
+#[cfg(cpp_ffi)]
+pub fn checksum(data: &[u8]) -> u32 {
+    // SAFETY: data 는 호출 동안 살아 있는 len 바이트, C++ 는 읽기만 하고 보관하지 않음
+    unsafe { raw::study_checksum(data.as_ptr(), data.len()) }
+}
+
+// 같은 FNV-1a 를 Rust 로 - C++ 결과와 비교용
+#[cfg(cpp_ffi)]
+pub fn checksum_rust(data: &[u8]) -> u32 {
+    data.iter().fold(2166136261u32, |hash, &b| (hash ^ b as u32).wrapping_mul(16777619))
+}
+
+// C++ 가 할당한 문자열 - Drop 에서 C++ 의 해제 함수로 돌려줌
+#[cfg(cpp_ffi)]
+pub struct CppString {
+    ptr: NonNull<c_char>,
+}
+
+#[cfg(cpp_ffi)]
+impl CppString {
+    pub fn as_c_str(&self) -> &CStr {
+        // SAFETY: study_greeting 이 돌려준 NUL 종료 문자열, self 가 살아 있는 동안 유효
+        unsafe { CStr::from_ptr(self.ptr.as_ptr()) }
+    }
+}
+
+#[cfg(cpp_ffi)]
+impl Drop for CppString {
+    fn drop(&mut self) {
+        // SAFETY: study_greeting 이 new[] 로 할당한 포인터 - 한 번만 해제
+        unsafe { raw::study_string_free(self.ptr.as_ptr()) }
+    }
+}
+
+// 이름 안의 NUL 은 C 문자열로 만들 수 없음 - CString::new 가 Err
+#[cfg(cpp_ffi)]
+pub fn greeting(name: &str) -> Result<CppString, std::ffi::NulError> {
+    // Rust 가 할당한 CString - C++ 는 호출 동안 빌리기만 함, 해제는 여기서 (name 이 범위를 벗어날 때)
+    let name = CString::new(name)?;
+    // SAFETY: name 은 NUL 종료 문자열, 반환값은 null 이 아닌 new[] 할당 (study_ffi.h)
+    let ptr = unsafe { raw::study_greeting(name.as_ptr()) };
+    Ok(CppString { ptr: NonNull::new(ptr).expect("study_greeting 은 null 을 돌려주지 않음") })
+}
+
+fn extern_c() {
+    println!("\n--- extern \"C\" 로 직접 호출 ---");
+
+    // C++ 에서는:
+    // #include "study_ffi.h" 한 줄 - 선언이 곧 헤더라 어긋날 일이 없음
+
+    #[cfg(not(cpp_ffi))]
+    not_built();
+
+    #[cfg(cpp_ffi)]
+    {
+        let data = "퍼징과 FFI".as_bytes();
+        println!("C++ checksum == Rust checksum: {}", checksum(data) == checksum_rust(data));
+
+        let hello = greeting("Rust").unwrap();
+        // to_str 은 UTF-8 검사 - C++ 문자열은 아무 바이트나 담을 수 있으므로
+        println!("C++ 가 만든 문자열: {:?}", hello.as_c_str().to_str());
+        // 소유한 String 으로 복사해 두면 C++ 버퍼는 바로 돌려줄 수 있음
+        let owned: String = hello.as_c_str().to_string_lossy().into_owned();
+        drop(hello);
+        println!("복사본은 해제 뒤에도 사용: {}", owned);
+
+        println!("NUL 이 든 이름: {}", greeting("a\0b").is_err());
+    }
+}
+
+fn main() {
+    extern_c();
+}
@@ -0,0 +1,130 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 44. C++ 라이브러리 호출 - 불투명 핸들 - C++ 객체를 Rust 값으로
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr::NonNull;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[cfg(not(cpp_ffi))]
+fn not_built() {
+    println!("C++ 라이브러리 없이 빌드됨 - c++ 또는 clang++ 를 설치하고 기본 기능(cpp-ffi)으로 다시 빌드");
+}
+
+#[cfg(cpp_ffi)]
+mod raw {
+    use std::ffi::c_char;
+
+    // build.rs 의 링크 지시는 라이브러리 타깃에만 붙음 - examples/ 처럼 이 파일을 따로 빌드해도 링크되도록
+    // C++ 표준 라이브러리(new[], 예외)는 cxx 크레이트가 링크해 줌
+    use cxx as _;
+
+    // C++ 의 struct StudyCounter - 크기를 모르는 타입 (포인터로만 다룸)
+    #[repr(C)]
+    pub struct StudyCounter {
+        _private: [u8; 0],
+    }
+
+    #[link(name = "study_ffi", kind = "static")]
+    extern "C" {
+        pub fn study_checksum(data: *const u8, len: usize) -> u32;
+        pub fn study_greeting(name: *const c_char) -> *mut c_char;
+        pub fn study_string_free(s: *mut c_char);
+        pub fn study_counter_new(start: i32) -> *mut StudyCounter;
+        pub fn study_counter_add(counter: *mut StudyCounter, n: i32);
+        pub fn study_counter_get(counter: *const StudyCounter) -> i32;
+        pub fn study_counter_divide(counter: *mut StudyCounter, by: i32) -> i32;
+        pub fn study_counter_free(counter: *mut StudyCounter);
+        pub fn study_live_objects() -> i32;
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 불투명 핸들 - C++ 객체를 Rust 값으로
+// ----------------------------------------------------------------------------
+// C++ 객체를 만드는 함수와 해제하는 함수를 짝으로 받아, Rust 쪽 타입의 new 와 Drop 에 연결
+// 사용하는 쪽은 unsafe 없이 - 해제를 잊거나 두 번 하는 일이 타입으로 막힘
+// C++ 의 unique_ptr<T, Deleter> 와 같은 모양
+
+#[cfg(cpp_ffi)]
+pub struct Counter {
+    ptr: NonNull<raw::StudyCounter>,
+}
+
+#[cfg(cpp_ffi)]
+#[derive(Debug, PartialEq)]
+pub struct DivideByZero;
+
+#[cfg(cpp_ffi)]
+impl Counter {
+    pub fn new(start: i32) -> Counter {
+        // SAFETY: 인자 제약 없음, 반환값은 new 로 할당한 객체
+        let ptr = unsafe { raw::study_counter_new(start) };
+        Counter { ptr: NonNull::new(ptr).expect("new 는 실패하면 예외 - null 이 아님") }
+    }
+
+    pub fn add(&mut self, n: i32) {
+        // SAFETY: ptr 은 살아 있는 객체, &mut self 라 동시에 다른 곳에서 쓰지 않음
+        unsafe { raw::study_counter_add(self.ptr.as_ptr(), n) }
+    }
+
+    pub fn get(&self) -> i32 {
+        // SAFETY: ptr 은 살아 있는 객체, 읽기만 함
+        unsafe { raw::study_counter_get(self.ptr.as_ptr()) }
+    }
+
+    // C++ 의 에러 코드 → Result
+    pub fn divide(&mut self, by: i32) -> Result<(), DivideByZero> {
+        // SAFETY: add 와 같음
+        match unsafe { raw::study_counter_divide(self.ptr.as_ptr(), by) } {
+            0 => Ok(()),
+            _ => Err(DivideByZero),
+        }
+    }
+}
+
+#[cfg(cpp_ffi)]
+impl Drop for Counter {
+    fn drop(&mut self) {
+        // SAFETY: study_counter_new 가 만든 포인터, Drop 은 한 번만 실행됨
+        unsafe { raw::study_counter_free(self.ptr.as_ptr()) }
+    }
+}
+
+// 살아 있는 C++ 객체 수
+#[cfg(cpp_ffi)]
+pub fn live_objects() -> i32 {
+    // SAFETY: 인자 없는 조회
+    unsafe { raw::study_live_objects() }
+}
+
+fn opaque_handle() {
+    println!("\n--- 불투명 핸들과 Drop ---");
+
+    // C++ 에서는:
+    // std::unique_ptr<StudyCounter, decltype(&study_counter_free)> c(study_counter_new(10), study_counter_free);
+
+    #[cfg(not(cpp_ffi))]
+    not_built();
+
+    #[cfg(cpp_ffi)]
+    {
+        let before = live_objects();
+        {
+            let mut counter = Counter::new(10);
+            counter.add(32);
+            println!("10 + 32 = {}", counter.get());
+            println!("divide(2) = {:?}, 값 {}", counter.divide(2), counter.get());
+            println!("divide(0) = {:?} (C++ 는 -1 을 돌려줌)", counter.divide(0));
+            println!("살아 있는 C++ 객체 +{}", live_objects() - before);
+        }
+        println!("범위를 벗어난 뒤 +{} (Drop 이 study_counter_free 호출)", live_objects() - before);
+    }
+}
+
+fn main() {
+    opaque_handle();
+}
@@ -0,0 +1,79 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 44. C++ 라이브러리 호출 - 경계를 넘는 소유권 - 정리
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr::NonNull;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[cfg(cpp_ffi)]
+mod raw {
+    use std::ffi::c_char;
+
+    // build.rs 의 링크 지시는 라이브러리 타깃에만 붙음 - examples/ 처럼 이 파일을 따로 빌드해도 링크되도록
+    // C++ 표준 라이브러리(new[], 예외)는 cxx 크레이트가 링크해 줌
+    use cxx as _;
+
+    // C++ 의 struct StudyCounter - 크기를 모르는 타입 (포인터로만 다룸)
+    #[repr(C)]
+    pub struct StudyCounter {
+        _private: [u8; 0],
+    }
+
+    #[link(name = "study_ffi", kind = "static")]
+    extern "C" {
+        pub fn study_checksum(data: *const u8, len: usize) -> u32;
+        pub fn study_greeting(name: *const c_char) -> *mut c_char;
+        pub fn study_string_free(s: *mut c_char);
+        pub fn study_counter_new(start: i32) -> *mut StudyCounter;
+        pub fn study_counter_add(counter: *mut StudyCounter, n: i32);
+        pub fn study_counter_get(counter: *const StudyCounter) -> i32;
+        pub fn study_counter_divide(counter: *mut StudyCounter, by: i32) -> i32;
+        pub fn study_counter_free(counter: *mut StudyCounter);
+        pub fn study_live_objects() -> i32;
+    }
+}
+
+#[cfg(cpp_ffi)]
+pub fn live_objects() -> i32 {
+    // SAFETY: 인자 없는 조회
+    unsafe { raw::study_live_objects() }
+}
+
+// ----------------------------------------------------------------------------
+// 경계를 넘는 소유권 - 정리
+// ----------------------------------------------------------------------------
+// 규칙 하나: 할당한 쪽의 해제 함수로 돌려줌 - 두 언어의 할당자는 서로 다를 수 있음
+//   Rust → C++ 빌려주기   &[u8], CString::as_ptr()   C++ 는 보관 금지 (호출이 끝나면 무효)
+//   C++ → Rust 넘겨주기   new 로 만든 포인터           Rust 래퍼의 Drop 이 C++ 의 free 함수 호출
+//   Rust → C++ 넘겨주기   Box::into_raw(b)             C++ 가 끝나면 Rust 의 extern "C" fn 으로 돌려받아 Box::from_raw (45장)
+//   cxx                 UniquePtr<T>, Box<T>, Vec<T>  생성된 코드가 올바른 쪽의 해제를 부름
+
+fn ownership() {
+    println!("\n--- 경계를 넘는 소유권 ---");
+
+    // C++ 에서는:
+    // malloc 으로 받은 것은 free, new 는 delete, new[] 는 delete[] - 짝이 틀리면 UB
+    // 언어 경계에서는 같은 규칙이 "라이브러리의 free 함수" 로 바뀜
+
+    let rules = [
+        ("&[u8], &CStr 빌려주기", "C++ 는 호출 동안만 사용"),
+        ("C++ 의 new / new[]", "Drop 에서 C++ 의 해제 함수"),
+        ("Rust 의 Box::into_raw", "C++ 가 Rust 의 해제 함수를 불러 돌려줌"),
+        ("cxx 의 UniquePtr / Vec / String", "생성된 코드가 알아서"),
+        ("C++ 예외", "extern \"C\" 는 에러 코드, cxx 는 Result"),
+    ];
+    for (what, how) in rules {
+        println!("  {:<32} → {}", what, how);
+    }
+
+    #[cfg(cpp_ffi)]
+    println!("이 장의 모든 C++ 객체 해제됨: {}", live_objects() == 0);
+}
+
+fn main() {
+    ownership();
+}
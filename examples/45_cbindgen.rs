@@ -0,0 +1,46 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 45. Rust 를 C 라이브러리로 - cbindgen 으로 헤더 생성
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use study_capi::{
+    study_capi_string_free, study_capi_version, study_status_message, study_tally_add, study_tally_count,
+    study_tally_free, study_tally_new, study_tally_top, StudyStatus, StudyTally, HEADER,
+};
+
+// ----------------------------------------------------------------------------
+// cbindgen 으로 헤더 생성
+// ----------------------------------------------------------------------------
+// cargo xtask capi-header 가 src/lib.rs 를 읽어 include/study_capi.h 를 씀 - 빌드는 소스 트리에 쓰지 않음
+// lib.rs 를 고치고 다시 생성하지 않으면 xtask 의 테스트가 실패 (헤더와 구현이 어긋나지 않게)
+// capi/cbindgen.toml:
+//   language = "C", cpp_compat = true          C 헤더지만 C++ 에서 include 하면 extern "C" { } 로 감쌈
+//   rename_variants = "QualifiedScreamingSnakeCase"   StudyStatus::Ok → STUDY_STATUS_OK
+// /// 문서 주석이 헤더의 주석이 됨 - # Safety 절이 C 쪽 사용자에게도 보임
+// 헤더는 커밋해 둠 - C/C++ 팀은 Rust 도구 없이 include 만 하면 됨
+// xtask 없이: cargo install cbindgen && cbindgen --config cbindgen.toml -o include/study_capi.h
+// 헤더 내용은 study_capi::HEADER (capi 크레이트가 include_str! 로 넣어 둠)
+
+fn cbindgen() {
+    println!("\n--- cbindgen 으로 헤더 생성 ---");
+
+    // C++ 에서는:
+    // 헤더를 손으로 쓰고 구현과 맞는지는 링크와 리뷰로 확인
+
+    // 헤더에서 선언만 골라 보기 (여러 줄 선언은 첫 줄만)
+    for line in HEADER.lines() {
+        let line = line.trim_end();
+        let declaration = line.contains("study_") && line.contains('(') && !line.starts_with("//");
+        if declaration || line.starts_with("typedef struct") || line.starts_with("  STUDY_STATUS_") {
+            println!("  | {}", line);
+        }
+    }
+    println!("헤더 {} 줄 - 모두 capi/src/lib.rs 에서 생성", HEADER.lines().count());
+}
+
+fn main() {
+    cbindgen();
+}
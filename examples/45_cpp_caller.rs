@@ -0,0 +1,46 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 45. Rust 를 C 라이브러리로 - C++ 에서 쓰기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use study_capi::{
+    study_capi_string_free, study_capi_version, study_status_message, study_tally_add, study_tally_count,
+    study_tally_free, study_tally_new, study_tally_top, StudyStatus, StudyTally, HEADER,
+};
+
+// ----------------------------------------------------------------------------
+// C++ 에서 쓰기
+// ----------------------------------------------------------------------------
+// cpp/capi/main.cc - 핸들과 문자열은 unique_ptr + deleter, 상태 코드는 예외로 바꾸는 얇은 래퍼
+// 정적 라이브러리를 링크할 때 함께 넘길 시스템 라이브러리는
+//   cargo rustc -p rust-study-capi --release --crate-type staticlib -- --print native-static-libs
+// panic = "abort" 로 빌드하면 catch_unwind 가 잡지 못함 - 이 라이브러리는 기본값(unwind)을 가정 (48장)
+
+const CPP_BUILD: &[&str] = &[
+    "cargo build -p rust-study-capi --release",
+    "c++ -std=c++17 cpp/capi/main.cc -Icapi/include -Ltarget/release -lstudy_capi -o /tmp/capi_demo",
+    "LD_LIBRARY_PATH=target/release /tmp/capi_demo",
+];
+
+fn cpp_caller() {
+    println!("\n--- C++ 에서 쓰기 ---");
+
+    // C++ 에서는:
+    // #include "study_capi.h"
+    // Tally tally(study_tally_new());
+    // check(study_tally_add(tally.get(), "the quick brown fox"));   // check 는 상태 코드 → 예외
+
+    println!("저장소 루트에서:");
+    for command in CPP_BUILD {
+        println!("  $ {}", command);
+    }
+    println!("출력: study_capi 0.1.0 / the: 3 / top: the / cat: word not found / ...");
+    println!("같은 .so 를 Python(ctypes), C#(P/Invoke), Go(cgo) 에서도 - C ABI 는 공용어");
+}
+
+fn main() {
+    cpp_caller();
+}
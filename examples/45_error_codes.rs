@@ -0,0 +1,65 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 45. Rust 를 C 라이브러리로 - 에러 코드와 panic
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use study_capi::{
+    study_capi_string_free, study_capi_version, study_status_message, study_tally_add, study_tally_count,
+    study_tally_free, study_tally_new, study_tally_top, StudyStatus, StudyTally, HEADER,
+};
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn message(status: StudyStatus) -> &'static str {
+    // SAFETY: study_status_message 는 항상 정적 NUL 종료 ASCII 문자열을 돌려줌 (study_capi.h)
+    unsafe { CStr::from_ptr(study_status_message(status as c_int)) }.to_str().unwrap()
+}
+
+// ----------------------------------------------------------------------------
+// 에러 코드와 panic
+// ----------------------------------------------------------------------------
+// C 에는 Result 가 없음 - #[repr(C)] enum 상태 코드를 돌려주고 값은 출력 포인터(*mut u32)로
+//   pub unsafe extern "C" fn study_tally_count(tally, word, out: *mut u32) -> StudyStatus
+// 들어오는 포인터는 믿지 않음 - NULL 은 STUDY_STATUS_NULL_POINTER (as_ref/as_mut 가 Option 으로)
+// panic 이 extern "C" 함수 밖으로 나가면 Rust 가 프로세스를 중단 (C 에는 풀어낼 방법이 없음)
+//   fn guard(f) -> StudyStatus { panic::catch_unwind(f).unwrap_or(StudyStatus::Panic) }
+// 로 감싸 STUDY_STATUS_PANIC 으로 바꿈 - 43장의 퍼징 하네스와 같은 도구
+// C 에서 들어오는 상태 값은 enum 이 아니라 int 로 받음 - 없는 값(99)이 Rust enum 이 되는 순간 UB
+//   study_status_message(int status) 는 모르는 값이면 "unknown status"
+
+fn error_codes() {
+    println!("\n--- 에러 코드와 panic ---");
+
+    // C++ 에서는:
+    // 예외를 extern "C" 밖으로 던지면 안 됨 - catch (...) 로 잡아 에러 코드로 (44장의 study_counter_divide)
+
+    let tally = study_tally_new();
+    let mut n = 7;
+    // SAFETY: tally 는 살아 있는 객체, 문자열은 NUL 종료 - NULL 인자는 라이브러리가 검사함
+    let results = unsafe {
+        study_tally_add(tally, c"one two two".as_ptr());
+        [
+            ("count(\"two\")", study_tally_count(tally, c"two".as_ptr(), &mut n)),
+            ("count(\"three\")", study_tally_count(tally, c"three".as_ptr(), &mut n)),
+            ("count(NULL, ..)", study_tally_count(ptr::null(), c"two".as_ptr(), &mut n)),
+            ("add(tally, NULL)", study_tally_add(tally, ptr::null())),
+        ]
+    };
+    for (call, status) in results {
+        println!("  {:<18} → {:?} ({} = {})", call, status, status as i32, message(status));
+    }
+    // 실패한 호출은 *out 을 건드리지 않음 - 마지막으로 성공한 count("two") 의 값
+    println!("n = {}", n);
+    // SAFETY: study_status_message 는 어떤 int 에도 정적 NUL 종료 문자열을 돌려줌
+    let unknown = unsafe { CStr::from_ptr(study_status_message(99)) };
+    println!("study_status_message(99) → {:?}", unknown);
+    // SAFETY: 위에서 만든 객체를 한 번 해제
+    unsafe { study_tally_free(tally) };
+}
+
+fn main() {
+    error_codes();
+}
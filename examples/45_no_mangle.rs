@@ -0,0 +1,42 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 45. Rust 를 C 라이브러리로 - #[no_mangle] extern "C" - C 에서 부를 수 있는 함수
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use study_capi::{
+    study_capi_string_free, study_capi_version, study_status_message, study_tally_add, study_tally_count,
+    study_tally_free, study_tally_new, study_tally_top, StudyStatus, StudyTally, HEADER,
+};
+
+// ----------------------------------------------------------------------------
+// #[no_mangle] extern "C" - C 에서 부를 수 있는 함수
+// ----------------------------------------------------------------------------
+// capi/src/lib.rs:
+//   #[no_mangle]
+//   pub extern "C" fn study_capi_version() -> *const c_char { c"0.1.0".as_ptr() }
+// #[no_mangle]  심볼 이름을 그대로 "study_capi_version" 으로 (없으면 _ZN10study_capi...17h...E 같은 장식된 이름)
+// extern "C"    C 호출 규약 - 인자를 어느 레지스터로 넘기는지 (Rust 의 기본 ABI 는 정해져 있지 않음)
+// C 에는 네임스페이스가 없으므로 이름에 라이브러리 접두사 (study_) 를 붙임
+// 심볼 확인: nm -D target/release/libstudy_capi.so | grep study_   (macOS 는 nm -gU ...dylib)
+
+fn no_mangle() {
+    println!("--- #[no_mangle] extern \"C\" ---");
+
+    // C++ 에서는:
+    // extern "C" const char* study_capi_version() { return "0.1.0"; }
+    // C++ 도 extern "C" 가 없으면 _Z18study_capi_versionv 처럼 이름이 장식됨
+
+    // C 가 보는 것과 같은 모양 - 인자와 반환이 C 타입인 함수 포인터
+    let version: extern "C" fn() -> *const c_char = study_capi_version;
+    // SAFETY: 정적 NUL 종료 문자열
+    let text = unsafe { CStr::from_ptr(version()) };
+    println!("study_capi_version() = {:?}", text);
+    println!("Cargo.toml 의 crate-type = [\"cdylib\", \"staticlib\", \"rlib\"] - rlib 는 이 장과 cargo test 용");
+}
+
+fn main() {
+    no_mangle();
+}
@@ -0,0 +1,50 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 45. Rust 를 C 라이브러리로 - 불투명 핸들
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use study_capi::{
+    study_capi_string_free, study_capi_version, study_status_message, study_tally_add, study_tally_count,
+    study_tally_free, study_tally_new, study_tally_top, StudyStatus, StudyTally, HEADER,
+};
+
+// ----------------------------------------------------------------------------
+// 불투명 핸들
+// ----------------------------------------------------------------------------
+// StudyTally 는 HashMap 을 가진 보통 Rust 구조체 (#[repr(C)] 아님) - C 는 모양을 알 필요가 없음
+// 헤더에는 typedef struct StudyTally StudyTally; 만 - 크기를 모르니 C 는 포인터로만 다룸
+//   study_tally_new   Box::into_raw(Box::new(..))   Rust 가 할당, 소유권을 C 에 넘김
+//   study_tally_free  Box::from_raw(p) 를 drop      소유권을 돌려받아 해제
+// 필드를 바꿔도 C 쪽은 다시 컴파일할 필요가 없음 (ABI 가 포인터 하나)
+
+fn opaque_handle() {
+    println!("\n--- 불투명 핸들 ---");
+
+    // C++ 에서는:
+    // std::unique_ptr<StudyTally, TallyDeleter> tally(study_tally_new());
+    // study_tally_add(tally.get(), "...");   // 소멸자가 study_tally_free 호출
+
+    let tally: *mut StudyTally = study_tally_new();
+    println!("핸들은 포인터 하나: {} 바이트, null 아님: {}", size_of::<*mut StudyTally>(), !tally.is_null());
+
+    let mut the = 0;
+    // SAFETY: tally 는 study_tally_new 가 돌려준 살아 있는 객체, 문자열은 NUL 종료
+    unsafe {
+        study_tally_add(tally, c"the cat saw the dog".as_ptr());
+        study_tally_count(tally, c"the".as_ptr(), &mut the);
+        // 다 쓰면 C 가 해제 함수를 부름 - 부르지 않으면 누수, 두 번 부르면 이중 해제
+        study_tally_free(tally);
+    }
+    println!("the: {} 번", the);
+    // NULL 은 free() 처럼 아무것도 안 함
+    // SAFETY: NULL 은 허용된 인자
+    unsafe { study_tally_free(ptr::null_mut()) };
+    println!("study_tally_free(NULL) 은 안전");
+}
+
+fn main() {
+    opaque_handle();
+}
@@ -0,0 +1,57 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 45. Rust 를 C 라이브러리로 - 문자열 주고받기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use study_capi::{
+    study_capi_string_free, study_capi_version, study_status_message, study_tally_add, study_tally_count,
+    study_tally_free, study_tally_new, study_tally_top, StudyStatus, StudyTally, HEADER,
+};
+
+// ----------------------------------------------------------------------------
+// 문자열 주고받기
+// ----------------------------------------------------------------------------
+// 들어오는 const char*  CStr::from_ptr 로 빌림 → to_str 로 UTF-8 검사 (C 문자열은 아무 바이트나)
+// 나가는 char*          CString::into_raw 로 소유권을 넘기고, study_capi_string_free 의 CString::from_raw 로 돌려받음
+// C 가 free() 로 해제하면 안 됨 - Rust 의 할당자가 malloc 이라는 보장이 없음
+// Rust 에서 C 로 넘길 때: CString::new(s)? 는 중간의 NUL 을 거부, as_ptr() 는 CString 이 살아 있는 동안만 유효
+
+fn strings() {
+    println!("\n--- 문자열 주고받기 ---");
+
+    // C++ 에서는:
+    // std::unique_ptr<char, StringDeleter> top(study_tally_top(t));   // deleter 가 study_capi_string_free
+
+    let tally = study_tally_new();
+    // Rust 의 String 을 C 문자열로 - 변수에 묶어 두어야 포인터가 호출 동안 유효
+    // CString::new(text).unwrap().as_ptr() 를 바로 넘기면 임시 값이 먼저 해제됨 (clippy 가 막아 줌)
+    let text = CString::new("사과 배 사과 귤 사과").unwrap();
+    // SAFETY: tally 는 살아 있는 객체, text 는 이 블록 동안 살아 있음
+    let status = unsafe { study_tally_add(tally, text.as_ptr()) };
+    println!("한글 단어 추가: {:?}", status);
+
+    // 올바르지 않은 UTF-8 - C 쪽에서는 흔한 일
+    let latin1 = CString::new(vec![b'c', b'a', b'f', 0xe9]).unwrap();
+    // SAFETY: 위와 같음
+    let status = unsafe { study_tally_add(tally, latin1.as_ptr()) };
+    println!("Latin-1 \"café\": {:?}", status);
+
+    // SAFETY: tally 는 살아 있는 객체
+    let top = unsafe { study_tally_top(tally) };
+    if !top.is_null() {
+        // SAFETY: study_tally_top 이 돌려준 NUL 종료 문자열 - 해제 전까지 유효
+        let word = unsafe { CStr::from_ptr(top) }.to_string_lossy().into_owned();
+        println!("가장 많은 단어: {}", word);
+        // SAFETY: study_tally_top 이 돌려준 포인터를 한 번 해제
+        unsafe { study_capi_string_free(top) };
+    }
+    // SAFETY: 위에서 만든 객체를 한 번 해제
+    unsafe { study_tally_free(tally) };
+}
+
+fn main() {
+    strings();
+}
@@ -0,0 +1,73 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 46. MaybeUninit 과 미초기화 메모리 - 배열을 한 칸씩 초기화
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::rc::Rc;
+
+// ----------------------------------------------------------------------------
+// 배열을 한 칸씩 초기화
+// ----------------------------------------------------------------------------
+// Default 나 Copy 가 아닌 T 로 [T; N] 을 만들 때
+//   먼저 std::array::from_fn(|i| ..) - 안전하고 대부분 이것으로 충분
+//   중간에 실패할 수 있으면 (Result) MaybeUninit 배열에 하나씩 쓰고 전부 채운 뒤 [T; N] 으로
+// 주의: 실패나 panic 으로 중간에 빠져나가면 이미 쓴 칸은 직접 drop 해야 함 (MaybeUninit 은 drop 하지 않으므로)
+// C++ 의 std::uninitialized_copy 가 예외 때 이미 만든 원소를 파괴하는 것과 같은 일
+
+// 실패하면 이미 만든 원소를 drop 하고 에러를 돌려줌 (std 의 array::try_from_fn 은 아직 unstable)
+pub fn try_init_array<T, E, const N: usize>(mut f: impl FnMut(usize) -> Result<T, E>) -> Result<[T; N], E> {
+    // 초기화한 앞부분 [0..len] 을 기억했다가 빠져나갈 때 drop - panic 에도 동작
+    struct Guard<'a, T, const N: usize> {
+        array: &'a mut [MaybeUninit<T>; N],
+        len: usize,
+    }
+
+    impl<T, const N: usize> Drop for Guard<'_, T, N> {
+        fn drop(&mut self) {
+            for slot in &mut self.array[..self.len] {
+                // SAFETY: [0..len] 은 write 로 초기화한 칸
+                unsafe { slot.assume_init_drop() };
+            }
+        }
+    }
+
+    // const 블록 - MaybeUninit 은 Copy 가 아니어도 반복 식으로 배열을 만들 수 있음
+    let mut array = [const { MaybeUninit::<T>::uninit() }; N];
+    let mut guard = Guard { array: &mut array, len: 0 };
+    for i in 0..N {
+        guard.array[i].write(f(i)?);
+        guard.len += 1;
+    }
+    // 전부 채움 - guard 가 drop 하지 않게 잊음
+    mem::forget(guard);
+    // SAFETY: 모든 칸이 초기화됨, [MaybeUninit<T>; N] 과 [T; N] 은 배치가 같음
+    Ok(unsafe { ptr::read(&array as *const [MaybeUninit<T>; N] as *const [T; N]) })
+}
+
+fn array_init() {
+    println!("\n--- 배열을 한 칸씩 초기화 ---");
+
+    // C++ 에서는:
+    // alignas(T) unsigned char raw[N * sizeof(T)];
+    // std::uninitialized_copy(first, last, reinterpret_cast<T*>(raw));   // 예외면 만든 것만 파괴
+
+    // 먼저 안전한 방법
+    let names: [String; 3] = std::array::from_fn(|i| format!("item-{}", i));
+    println!("array::from_fn: {:?}", names);
+
+    // 실패할 수 있는 초기화
+    let parsed: Result<[u8; 4], _> = try_init_array(|i| ["10", "20", "30", "40"][i].parse::<u8>());
+    println!("모두 성공: {:?}", parsed);
+
+    // 3 번째에서 실패 - 이미 만든 두 원소가 drop 되는지 Rc 참조 수로 확인
+    let shared = Rc::new(());
+    let failed: Result<[Rc<()>; 4], &str> = try_init_array(|i| if i < 2 { Ok(Rc::clone(&shared)) } else { Err("2 번 칸 실패") });
+    println!("중간 실패: {:?}, 남은 Rc 참조 수 {} (만든 두 개는 drop 됨)", failed.map(|_| ()), Rc::strong_count(&shared));
+}
+
+fn main() {
+    array_init();
+}
@@ -0,0 +1,64 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 46. MaybeUninit 과 미초기화 메모리 - MaybeUninit<T>
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::rc::Rc;
+
+// ----------------------------------------------------------------------------
+// MaybeUninit<T>
+// ----------------------------------------------------------------------------
+// 크기와 정렬은 T 와 같고, 아무 바이트나 (미초기화도) 올바른 값인 union
+//   MaybeUninit::uninit()      미초기화 상태로 만들기 - 안전
+//   x.write(value)             값 넣기 - 안전, 이전 내용을 drop 하지 않음
+//   x.as_mut_ptr()             *mut T - 필드 하나씩 쓰거나 C 함수에 넘길 때
+//   x.assume_init()            T 로 꺼내기 - unsafe, 초기화됐다는 약속
+// MaybeUninit<T> 는 Drop 을 실행하지 않음 - 넣은 값을 꺼내지 않으면 누수 (C++ 의 aligned_storage 와 같음)
+
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+fn maybe_uninit() {
+    println!("\n--- MaybeUninit<T> ---");
+
+    // C++ 에서는:
+    // alignas(std::string) unsigned char buf[sizeof(std::string)];
+    // auto* s = new (buf) std::string("hello");   // placement new
+    // s->~basic_string();                          // 소멸자도 직접
+
+    let mut slot = MaybeUninit::<String>::uninit();
+    slot.write(String::from("hello"));
+    // SAFETY: 바로 위에서 write 로 초기화함
+    let s = unsafe { slot.assume_init() };
+    println!("write 후 assume_init: {:?}", s);
+    println!("MaybeUninit<String> 크기 {} == String 크기 {}", mem::size_of::<MaybeUninit<String>>(), mem::size_of::<String>());
+
+    // drop 하지 않는 상자 - 꺼내지 않은 값은 누수
+    let shared = Rc::new(());
+    let mut leaked = MaybeUninit::<Rc<()>>::uninit();
+    leaked.write(Rc::clone(&shared));
+    #[expect(clippy::drop_non_drop, reason = "drop 해도 안의 값이 drop 되지 않음을 보여 줌")]
+    drop(leaked);
+    println!("MaybeUninit 을 drop 한 뒤 Rc 참조 수: {} (안의 Rc 가 drop 되지 않음)", Rc::strong_count(&shared));
+
+    // 구조체를 필드 하나씩 - &raw mut 로 필드 주소만 얻음 (&mut (*p).name 은 미초기화 String 에 대한 참조라 UB)
+    let mut config = MaybeUninit::<Config>::uninit();
+    let p = config.as_mut_ptr();
+    // SAFETY: p 는 Config 크기의 정렬된 메모리, 각 필드를 참조 없이 한 번씩 씀
+    let config = unsafe {
+        (&raw mut (*p).name).write(String::from("server"));
+        (&raw mut (*p).retries).write(3);
+        // 모든 필드를 썼으므로 Config 전체가 초기화됨
+        config.assume_init()
+    };
+    println!("필드 하나씩 초기화: {} (재시도 {})", config.name, config.retries);
+}
+
+fn main() {
+    maybe_uninit();
+}
@@ -0,0 +1,63 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 46. MaybeUninit 과 미초기화 메모리 - 출력 인자와 버퍼
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::rc::Rc;
+
+// ----------------------------------------------------------------------------
+// 출력 인자와 버퍼
+// ----------------------------------------------------------------------------
+// C API 는 결과를 포인터 인자로 돌려줌 - 45장의 study_tally_count(tally, word, uint32_t* out)
+// 호출 전에 0 으로 채우는 대신 MaybeUninit 으로 넘기고, 성공했을 때만 assume_init
+// 버퍼는 Vec::with_capacity 의 남은 용량(spare_capacity_mut)에 쓰고 set_len 으로 길이를 늘림
+//   - read(2) 처럼 "n 바이트를 썼다" 고 알려주는 API 와 잘 맞음
+//   - set_len 전에 [len..new_len] 이 모두 초기화됐어야 함
+
+fn out_params() {
+    println!("\n--- 출력 인자와 버퍼 ---");
+
+    // C++ 에서는:
+    // uint32_t n;                                   // 초기화하지 않아도 컴파일됨
+    // if (study_tally_count(t, "the", &n) == STUDY_STATUS_OK) use(n);
+
+    use study_capi::{study_tally_add, study_tally_count, study_tally_free, study_tally_new, StudyStatus};
+
+    let tally = study_tally_new();
+    let mut found = MaybeUninit::<u32>::uninit();
+    let mut missing = MaybeUninit::<u32>::uninit();
+    // SAFETY: tally 는 살아 있는 객체, 문자열은 NUL 종료, 출력 포인터는 u32 하나를 쓸 수 있는 메모리
+    let (ok, not_found) = unsafe {
+        study_tally_add(tally, c"to be or not to be".as_ptr());
+        (
+            study_tally_count(tally, c"be".as_ptr(), found.as_mut_ptr()),
+            study_tally_count(tally, c"question".as_ptr(), missing.as_mut_ptr()),
+        )
+    };
+    if ok == StudyStatus::Ok {
+        // SAFETY: STUDY_STATUS_OK 면 *out 을 썼음 (study_capi.h)
+        println!("be: {} 번", unsafe { found.assume_init() });
+    }
+    // 실패하면 *out 을 쓰지 않음 - missing 은 그대로 미초기화라 읽으면 안 됨
+    println!("question: {:?} (출력 인자를 읽지 않음)", not_found);
+    // SAFETY: 위에서 만든 객체를 한 번 해제
+    unsafe { study_tally_free(tally) };
+
+    // 남은 용량에 쓰고 set_len
+    let mut buf: Vec<u8> = Vec::with_capacity(8);
+    let spare = buf.spare_capacity_mut();
+    let written = spare.len().min(5);
+    for (i, slot) in spare[..written].iter_mut().enumerate() {
+        slot.write(b'a' + i as u8);
+    }
+    // SAFETY: [0..written] 을 위에서 모두 썼고 written <= capacity
+    unsafe { buf.set_len(written) };
+    println!("spare_capacity_mut + set_len: {:?} (용량 {})", String::from_utf8_lossy(&buf), buf.capacity());
+}
+
+fn main() {
+    out_params();
+}
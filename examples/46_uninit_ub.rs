@@ -0,0 +1,54 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 46. MaybeUninit 과 미초기화 메모리 - 미초기화 메모리와 UB
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::rc::Rc;
+
+// ----------------------------------------------------------------------------
+// 미초기화 메모리와 UB
+// ----------------------------------------------------------------------------
+// 안전한 Rust 에서는 미초기화 값을 만들 수 없음 - 선언만 하고 읽으면 컴파일 에러 (E0381)
+// unsafe 로 만들 수 있는 길:
+//   mem::uninitialized::<T>()   폐기됨 - 어떤 T 에도 올바르게 쓸 수 없음
+//   mem::zeroed::<T>()          모든 바이트가 0 인 T - 0 이 올바른 값인 타입에만
+//   alloc::alloc(layout)        받은 메모리는 미초기화 - 16장의 MyVec
+// "올바른 값" (validity): bool 은 0/1, char 는 유니코드 스칼라, &T/Box<T>/NonNull 은 null 아님, enum 은 정의된 판별값
+// 미초기화 바이트는 어떤 타입에서도 올바른 값이 아님 - i32 도 마찬가지 (컴파일러가 아무 값으로 가정해도 됨)
+
+fn uninit_ub() {
+    println!("--- 미초기화 메모리와 UB ---");
+
+    // C++ 에서는:
+    // int x;                  // 컴파일됨, 읽으면 UB
+    // std::string s;          // 기본 생성자가 있으면 초기화됨 - 없는 타입은 배열로 만들 수도 없음
+
+    // let x: i32;
+    // println!("{}", x);      // 에러 E0381: used binding `x` isn't initialized
+
+    // 나중에 초기화 - 모든 경로에서 한 번 대입하면 됨 (컴파일러가 확인)
+    #[expect(clippy::needless_late_init, reason = "나중에 초기화하는 문법을 보여 줌")]
+    let label: &str;
+    if cfg!(debug_assertions) {
+        label = "debug";
+    } else {
+        label = "release";
+    }
+    println!("나중에 초기화한 변수: {}", label);
+
+    // 0 이 올바른 값인 타입이면 zeroed 가 안전
+    // SAFETY: [u64; 4] 와 (f64, *const u8) 은 모든 비트가 0 인 값이 올바름
+    let (counts, pair): ([u64; 4], (f64, *const u8)) = unsafe { (mem::zeroed(), mem::zeroed()) };
+    println!("zeroed [u64; 4] = {:?}, (f64, *const u8) = ({}, null: {})", counts, pair.0, pair.1.is_null());
+
+    // 0 이 될 수 없는 타입 - Option 이 그 자리를 None 으로 씀 (널 포인터 최적화)
+    println!("Option<&u8> 크기 {} == &u8 크기 {}", mem::size_of::<Option<&u8>>(), mem::size_of::<&u8>());
+    // let r: &u8 = unsafe { mem::zeroed() };   // UB - 널 참조 (Miri 가 바로 잡음)
+}
+
+fn main() {
+    uninit_ub();
+}
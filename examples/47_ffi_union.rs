@@ -0,0 +1,96 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 47. union 과 transmute - FFI 용 #[repr(C)] union
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::mem::{self, ManuallyDrop};
+
+// ----------------------------------------------------------------------------
+// FFI 용 #[repr(C)] union
+// ----------------------------------------------------------------------------
+// C 헤더에 union 이 있으면 Rust 도 같은 배치의 #[repr(C)] union 으로 받아야 함
+//   typedef struct { uint8_t kind; union { int32_t i; float f; uint8_t rgba[4]; } as; } CValue;
+// 경계를 넘자마자 꼬리표를 검사해 Rust 의 enum 으로 바꾸고, 안쪽 코드는 enum 만 씀
+// (44장의 "날것의 선언은 mod raw 에, 밖에는 안전한 타입" 과 같은 구조)
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union CValueData {
+    pub i: i32,
+    pub f: f32,
+    pub rgba: [u8; 4],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct CValue {
+    pub kind: u8,
+    pub data: CValueData,
+}
+
+pub const KIND_INT: u8 = 0;
+pub const KIND_FLOAT: u8 = 1;
+pub const KIND_COLOR: u8 = 2;
+
+// 안쪽 코드가 쓰는 타입
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sample {
+    Int(i32),
+    Float(f32),
+    Color([u8; 4]),
+}
+
+impl TryFrom<CValue> for Sample {
+    type Error = u8;
+
+    // 모르는 kind 는 Err - C 쪽이 보낸 값을 믿지 않음
+    fn try_from(raw: CValue) -> Result<Sample, u8> {
+        // SAFETY: 세 필드 모두 모든 비트 패턴이 올바른 값 (i32, f32, [u8; 4]) - kind 가 틀려도 UB 는 아님
+        unsafe {
+            match raw.kind {
+                KIND_INT => Ok(Sample::Int(raw.data.i)),
+                KIND_FLOAT => Ok(Sample::Float(raw.data.f)),
+                KIND_COLOR => Ok(Sample::Color(raw.data.rgba)),
+                other => Err(other),
+            }
+        }
+    }
+}
+
+impl From<Sample> for CValue {
+    fn from(sample: Sample) -> CValue {
+        match sample {
+            Sample::Int(i) => CValue { kind: KIND_INT, data: CValueData { i } },
+            Sample::Float(f) => CValue { kind: KIND_FLOAT, data: CValueData { f } },
+            Sample::Color(rgba) => CValue { kind: KIND_COLOR, data: CValueData { rgba } },
+        }
+    }
+}
+
+fn ffi_union() {
+    println!("\n--- FFI 용 #[repr(C)] union ---");
+
+    // C++ 에서는:
+    // switch (v.kind) { case KIND_INT: use(v.as.i); break; case KIND_FLOAT: ... }
+    // 꼬리표를 확인하는 switch 가 코드 곳곳에 흩어짐
+
+    // C 와 같은 배치인지 - C 컴파일러의 sizeof/offsetof 와 같아야 함
+    println!(
+        "CValue 크기 {}, 정렬 {}, data 오프셋 {}",
+        mem::size_of::<CValue>(),
+        mem::align_of::<CValue>(),
+        mem::offset_of!(CValue, data)
+    );
+
+    for sample in [Sample::Int(-7), Sample::Float(0.5), Sample::Color([255, 128, 0, 255])] {
+        let raw = CValue::from(sample);
+        println!("{:?} → kind {} → {:?}", sample, raw.kind, Sample::try_from(raw));
+    }
+    let unknown = CValue { kind: 9, data: CValueData { i: 0 } };
+    println!("모르는 kind: {:?}", Sample::try_from(unknown));
+}
+
+fn main() {
+    ffi_union();
+}
@@ -0,0 +1,60 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 47. union 과 transmute - 더 안전한 대안
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::mem::{self, ManuallyDrop};
+
+// ----------------------------------------------------------------------------
+// 더 안전한 대안
+// ----------------------------------------------------------------------------
+//   f32/f64 ↔ 비트      f32::to_bits / f32::from_bits              std::bit_cast<uint32_t>(f)
+//   정수 ↔ 바이트       to_le_bytes / from_le_bytes (ne, be)       memcpy + 엔디언 변환
+//   정수 ↔ 정수         as, From/TryFrom (24장)                    static_cast
+//   포인터 ↔ 포인터     ptr.cast::<U>()                            reinterpret_cast<U*>
+//   &[u8] → &[u32]      slice.align_to::<u32>() (unsafe, 앞뒤 남는 부분을 돌려줌) 또는 chunks_exact + from_le_bytes
+//   구조체 ↔ 바이트     bytemuck, zerocopy 크레이트 - 모든 비트가 올바른 타입인지 트레이트로 검사
+// 전부 const fn 이거나 안전한 함수 - unsafe 가 필요 없고 의도가 이름에 드러남
+
+// Quake III 의 빠른 역제곱근 - C 에서는 포인터 캐스팅, Rust 는 to_bits/from_bits 로 unsafe 없이
+pub fn fast_inv_sqrt(x: f32) -> f32 {
+    let i = 0x5f37_59df - (x.to_bits() >> 1);
+    let y = f32::from_bits(i);
+    // 뉴턴 방법 한 번 - 오차 약 0.2% 이내
+    y * (1.5 - 0.5 * x * y * y)
+}
+
+fn safer_alternatives() {
+    println!("\n--- 더 안전한 대안 ---");
+
+    // C++ 에서는:
+    // float q_rsqrt(float x) { long i = *(long*)&x; i = 0x5f3759df - (i >> 1); float y = *(float*)&i; ... }
+    // C++ 에서는 strict aliasing 위반 - C++20 이면 std::bit_cast 로
+
+    for x in [1.0f32, 2.0, 10.0] {
+        println!("fast_inv_sqrt({:>4}) = {:.5}, 1/sqrt = {:.5}", x, fast_inv_sqrt(x), 1.0 / x.sqrt());
+    }
+
+    // 바이트 순서를 이름으로 - 파일과 네트워크 형식은 보통 le 나 be 로 정해져 있음
+    let n = 0x1234_5678u32;
+    println!("to_le_bytes {:02x?}, to_be_bytes {:02x?}", n.to_le_bytes(), n.to_be_bytes());
+    println!("from_le_bytes([0x78, 0x56, 0x34, 0x12]) = {:#x}", u32::from_le_bytes([0x78, 0x56, 0x34, 0x12]));
+
+    // 바이트 버퍼를 u32 들로 - 정렬을 가정하지 않는 안전한 방법
+    let buffer = [1u8, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0];
+    // as_chunks::<4> 는 &[[u8; 4]] 와 남는 바이트 - 크기가 타입에 있어 try_into 가 필요 없음
+    let (chunks, _rest) = buffer.as_chunks::<4>();
+    let words: Vec<u32> = chunks.iter().map(|&c| u32::from_le_bytes(c)).collect();
+    println!("as_chunks::<4>() + from_le_bytes: {:?}", words);
+
+    // 포인터 캐스트 - 타입만 바꾸고 주소는 그대로 (역참조는 여전히 unsafe)
+    let value = 0x0102_0304u32;
+    let p: *const u8 = (&value as *const u32).cast::<u8>();
+    // SAFETY: p 는 value 의 첫 바이트를 가리킴, u8 은 정렬 제약이 없음
+    println!("ptr.cast::<u8>() 로 읽은 첫 바이트: {:#04x}", unsafe { *p });
+}
+
+fn main() {
+    safer_alternatives();
+}
@@ -0,0 +1,101 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 47. union 과 transmute - 꼬리표 붙은 union - enum 과 비교
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::mem::{self, ManuallyDrop};
+
+// ----------------------------------------------------------------------------
+// 꼬리표 붙은 union - enum 과 비교
+// ----------------------------------------------------------------------------
+// union 필드는 Copy 이거나 ManuallyDrop<T> - union 은 어느 필드를 drop 할지 모르므로 아예 drop 하지 않음
+// 꼬리표(tag)와 union 을 묶어 직접 관리하면 std::variant 를 손으로 만드는 것 - 그게 바로 enum
+// enum 은 꼬리표 검사, drop, 패턴 매칭을 컴파일러가 대신 해 줌 (게다가 niche 로 꼬리표를 숨기기도)
+
+union Payload {
+    number: i64,
+    text: ManuallyDrop<String>,
+}
+
+// 꼬리표 + union - 불변식: tag 가 TEXT 면 payload.text 가 초기화된 String
+struct Tagged {
+    tag: u8,
+    payload: Payload,
+}
+
+impl Tagged {
+    const NUMBER: u8 = 0;
+    const TEXT: u8 = 1;
+
+    fn number(n: i64) -> Tagged {
+        Tagged { tag: Self::NUMBER, payload: Payload { number: n } }
+    }
+
+    fn text(s: &str) -> Tagged {
+        Tagged { tag: Self::TEXT, payload: Payload { text: ManuallyDrop::new(s.to_string()) } }
+    }
+
+    fn describe(&self) -> String {
+        // SAFETY: tag 가 어느 필드가 초기화됐는지 알려줌 (불변식)
+        unsafe {
+            match self.tag {
+                Self::TEXT => format!("text {:?}", *self.payload.text),
+                _ => format!("number {}", self.payload.number),
+            }
+        }
+    }
+}
+
+impl Drop for Tagged {
+    fn drop(&mut self) {
+        if self.tag == Self::TEXT {
+            // SAFETY: tag 가 TEXT 면 text 가 살아 있음, Drop 은 한 번만 실행됨
+            unsafe { ManuallyDrop::drop(&mut self.payload.text) }
+        }
+    }
+}
+
+// 같은 것을 enum 으로 - unsafe 도 Drop 구현도 필요 없음
+#[derive(Debug)]
+enum Value {
+    Number(i64),
+    Text(String),
+}
+
+impl Value {
+    // match 가 꼬리표를 확인하고 필드를 꺼냄 - 잘못 읽을 방법이 없음
+    fn describe(&self) -> String {
+        match self {
+            Value::Text(text) => format!("text {:?}", text),
+            Value::Number(number) => format!("number {}", number),
+        }
+    }
+}
+
+fn tagged_union() {
+    println!("\n--- 꼬리표 붙은 union 과 enum ---");
+
+    // C++ 에서는:
+    // struct Tagged { uint8_t tag; union { int64_t number; std::string text; }; ~Tagged() { if (tag == 1) text.~basic_string(); } };
+    // 보통은 std::variant<int64_t, std::string> - 꼬리표와 소멸자를 라이브러리가 관리
+
+    let values = [Tagged::number(42), Tagged::text("hello")];
+    for v in &values {
+        println!("손으로 만든 꼬리표: {}", v.describe());
+    }
+    let enums = [Value::Number(42), Value::Text("hello".to_string())];
+    for v in &enums {
+        println!("enum:           {}", v.describe());
+    }
+    println!(
+        "크기 - Tagged {} 바이트, enum Value {} 바이트 (꼬리표를 String 의 빈 비트에 숨김)",
+        mem::size_of::<Tagged>(),
+        mem::size_of::<Value>()
+    );
+    // values 가 범위를 벗어나면 Tagged::drop 이 text 만 해제
+}
+
+fn main() {
+    tagged_union();
+}
@@ -0,0 +1,59 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 47. union 과 transmute - mem::transmute 의 함정
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::mem::{self, ManuallyDrop};
+
+// ----------------------------------------------------------------------------
+// mem::transmute 의 함정
+// ----------------------------------------------------------------------------
+// transmute::<A, B>(a) - A 의 비트를 그대로 B 로. 컴파일러가 검사하는 것은 "크기가 같은가" 하나뿐
+// 함정:
+//   올바르지 않은 값    transmute::<u8, bool>(2), transmute::<u32, char>(0xD800) - 즉시 UB
+//   바이트 순서         [u8; 4] → u32 결과가 기계마다 다름 (리틀/빅 엔디언)
+//   수명 늘리기         transmute::<&'a T, &'static T> - 빌림 검사기를 속여 댕글링 참조
+//   &T → &mut T         어떤 경우에도 UB
+//   배치가 정해지지 않은 타입   Vec<u32> → Vec<f32>, (u8, u32) → [u8; 8] - repr(Rust) 는 순서와 패딩이 보장되지 않음 (49장)
+//   포인터 → 정수       출처(provenance) 정보가 사라짐 - ptr.addr() / ptr.expose_provenance() 를 씀
+// 그래서 transmute 가 필요해 보이면 먼저 아래 절의 대안을 찾음
+
+// 아래 두 transmute 는 일부러 - 컴파일러도 unnecessary_transmutes 경고로 to_bits/from_ne_bytes 를 권함
+#[allow(unnecessary_transmutes)]
+fn transmute() {
+    println!("\n--- mem::transmute 의 함정 ---");
+
+    // C++ 에서는:
+    // reinterpret_cast<uint32_t&>(f)   // strict aliasing 위반 - UB
+    // std::memcpy(&u, &f, 4)           // 올바른 방법 (C++20 은 std::bit_cast)
+
+    // 크기가 다르면 컴파일 에러 - 유일한 검사
+    // let x: u64 = unsafe { mem::transmute(1.0f32) };   // 에러 E0512: cannot transmute between types of different sizes
+
+    // 크기가 같으면 통과 - 결과가 올바른지는 프로그래머의 책임
+    // SAFETY: f32 와 u32 는 크기가 같고 모든 비트가 올바른 u32
+    let bits: u32 = unsafe { mem::transmute(1.0f32) };
+    println!("transmute::<f32, u32>(1.0) = {:#x} (f32::to_bits 와 같음: {})", bits, bits == 1.0f32.to_bits());
+
+    // 바이트 배열 → 정수는 엔디언에 따라 결과가 다름
+    let bytes: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+    // SAFETY: [u8; 4] 와 u32 는 크기가 같고 모든 비트가 올바른 u32
+    let native: u32 = unsafe { mem::transmute(bytes) };
+    let endian = if cfg!(target_endian = "little") { "little" } else { "big" };
+    println!("transmute([1, 2, 3, 4]) = {:#010x} (이 기계는 {}-endian)", native, endian);
+
+    // Vec<u32> → Vec<f32> 는 transmute 대신 - into_iter + collect 는 같은 할당을 재사용 (제자리 수집)
+    let raw = vec![0x3f80_0000u32, 0x4000_0000, 0x4040_0000];
+    let ptr_before = raw.as_ptr() as usize;
+    let floats: Vec<f32> = raw.into_iter().map(f32::from_bits).collect();
+    println!("Vec<u32> → Vec<f32>: {:?}, 같은 버퍼 재사용: {}", floats, floats.as_ptr() as usize == ptr_before);
+
+    // let b: bool = unsafe { mem::transmute(2u8) };   // UB - bool 은 0 또는 1 (Miri 가 잡음)
+    // 검사가 필요하면 transmute 대신 변환 함수: char::from_u32, bool 은 match
+    println!("char::from_u32(0xD800) = {:?} (서로게이트는 char 가 아님)", char::from_u32(0xD800));
+}
+
+fn main() {
+    transmute();
+}
@@ -0,0 +1,54 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 47. union 과 transmute - union 기초
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::mem::{self, ManuallyDrop};
+
+// ----------------------------------------------------------------------------
+// union 기초
+// ----------------------------------------------------------------------------
+// 모든 필드가 같은 자리(오프셋 0)를 공유 - 크기는 가장 큰 필드, 정렬은 가장 엄격한 필드
+// 필드에 쓰기는 안전, 읽기는 unsafe (어느 필드가 "살아 있는지" 기록하지 않으므로)
+// 다른 필드로 쓰고 읽어도 됨 - 단 그 비트가 읽는 타입의 올바른 값이어야 (bool 에 2 를 읽으면 UB)
+
+#[derive(Clone, Copy)]
+union FloatBits {
+    f: f32,
+    u: u32,
+    bytes: [u8; 4],
+}
+
+fn union_basics() {
+    println!("--- union 기초 ---");
+
+    // C++ 에서는:
+    // union FloatBits { float f; uint32_t u; };
+    // FloatBits b; b.f = 1.0f;
+    // b.u;               // C++ 에서는 UB (C 에서는 허용) - C++20 은 std::bit_cast<uint32_t>(1.0f)
+
+    let mut bits = FloatBits { f: 1.0 };
+    // SAFETY: f32 의 모든 비트 패턴은 올바른 u32 와 [u8; 4]
+    unsafe {
+        println!("1.0f32 의 비트: {:#010x}, 바이트(이 기계의 순서): {:?}", bits.u, bits.bytes);
+    }
+    // 쓰기는 안전 - Copy 필드는 이전 값을 drop 할 일이 없음
+    bits.u = 0x4049_0fdb;
+    // SAFETY: u32 의 모든 비트 패턴은 올바른 f32 (NaN 포함)
+    println!("0x40490fdb 를 f32 로: {}", unsafe { bits.f });
+    println!("크기 {} 바이트 - 가장 큰 필드와 같음", mem::size_of::<FloatBits>());
+
+    // 패턴 매칭도 필드 읽기라 unsafe
+    // SAFETY: 위와 같음
+    unsafe {
+        match bits {
+            FloatBits { u: 0 } => println!("0"),
+            FloatBits { f } => println!("패턴으로 읽은 f = {:.5}", f),
+        }
+    }
+}
+
+fn main() {
+    union_basics();
+}
@@ -0,0 +1,212 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 48. panic 과 되감기 - 되감기 중의 Drop 과 이중 panic
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use crate::panic_hook::{silence_thread, silently};
+
+// --- rust-study 의 panic_hook 모듈 ---
+mod panic_hook {
+// ============================================================================
+// panic 훅 (Panic hook)
+// ============================================================================
+// 프로세스에 하나뿐인 panic 훅을 이 저장소에서 설치하는 유일한 곳 (훅과 catch_unwind 는 48장)
+//
+//   let (result, seen) = crate::panic_hook::silently(|| v[index]);
+//   // result: Err(payload), seen: Some(Seen { message, file, line }) - 출력은 없음
+//
+// 표준 훅(또는 먼저 설치된 훅)을 감싸 panic 마다 메시지와 위치를 thread_local 에 기록
+//   - 장 실행기(src/runner.rs)는 기록만 가져가고 출력은 표준 훅 그대로
+//   - silently / silence_thread 를 쓴 스레드는 출력하지 않음 (43장의 퍼저, 48장과 54장의 예제)
+// 상태는 모두 thread_local - --parallel 이나 테스트처럼 여러 스레드가 동시에 panic 해도 섞이지 않음
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+// 훅이 본 panic 하나 - "메시지 (파일:줄)" 로 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for Seen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.message, self.file, self.line)
+    }
+}
+
+thread_local! {
+    // true 면 이 스레드의 panic 을 출력하지 않음
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+    // 이 스레드에서 마지막으로 난 panic - 훅이 쓰고 take_last 가 가져감
+    static LAST: RefCell<Option<Seen>> = const { RefCell::new(None) };
+}
+
+// panic!("..") 는 &str, panic!("{}", x) 는 String - 그 밖의 payload(panic_any)는 읽을 수 없음
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(알 수 없는 panic)"))
+}
+
+// 기존 훅을 감싸는 훅을 한 번만 설치 - 몇 번 불러도 됨
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let seen = info.location().map(|l| Seen {
+                message: message(info.payload()),
+                file: l.file().to_string(),
+                line: l.line(),
+            });
+            LAST.with(|last| *last.borrow_mut() = seen);
+            if !SILENT.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// 이 스레드에서 마지막으로 난 panic 을 가져감 (한 번만)
+pub fn take_last() -> Option<Seen> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+// f 를 실행하고 panic 을 잡음 - 출력 대신 훅이 본 것을 함께 돌려줌
+pub fn silently<R>(f: impl FnOnce() -> R + UnwindSafe) -> (thread::Result<R>, Option<Seen>) {
+    install();
+    take_last();
+    let was = SILENT.replace(true);
+    let result = panic::catch_unwind(f);
+    SILENT.set(was);
+    (result, take_last())
+}
+
+// 지금 스레드의 panic 출력을 끔 - 곧 panic 으로 끝날 스레드 안에서 (join 이 payload 를 받음)
+pub fn silence_thread() {
+    install();
+    SILENT.set(true);
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub fn payload_text(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else if let Some(n) = payload.downcast_ref::<i32>() {
+        format!("i32 {}", n)
+    } else {
+        "(알 수 없는 payload)".to_string()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 되감기 중의 Drop 과 이중 panic
+// ----------------------------------------------------------------------------
+// 되감기는 스택의 값을 만든 반대 순서로 drop - RAII 정리는 panic 에서도 실행됨 (C++ 의 stack unwinding 과 같음)
+// 되감기 중에 불린 Drop 이 또 panic 하면 더 되감을 방법이 없어 abort (C++ 의 소멸자에서 throw → std::terminate)
+// Drop 에서 검사할 때는 thread::panicking() 으로 이미 panic 중인지 보고, 그렇다면 조용히 넘어감
+
+// 만들어진 이름과 drop 된 이름을 기록하는 값
+struct Noisy<'a> {
+    name: &'static str,
+    log: &'a RefCell<Vec<String>>,
+}
+
+impl Drop for Noisy<'_> {
+    fn drop(&mut self) {
+        let note = if thread::panicking() { " (되감기 중)" } else { "" };
+        self.log.borrow_mut().push(format!("{}{}", self.name, note));
+    }
+}
+
+// 끝나기 전에 commit 해야 하는 작업 - 잊으면 Drop 에서 알려줌, 단 이미 panic 중이면 또 panic 하지 않음
+pub struct Transaction {
+    committed: bool,
+}
+
+impl Transaction {
+    pub fn begin() -> Transaction {
+        Transaction { committed: false }
+    }
+
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        // panicking() 검사가 없으면: 작업 중 panic → 되감으며 여기서 또 panic → abort
+        if !self.committed && !thread::panicking() {
+            panic!("commit 하지 않은 Transaction");
+        }
+    }
+}
+
+// 되감기 중에 drop 된 순서 - 여기서 만든 값들의 이름
+pub fn unwind_drop_order() -> Vec<String> {
+    let log = RefCell::new(Vec::new());
+    let (result, _) = silently(AssertUnwindSafe(|| {
+        let _first = Noisy { name: "first", log: &log };
+        let _second = Noisy { name: "second", log: &log };
+        {
+            let _inner = Noisy { name: "inner", log: &log };
+        }
+        panic!("second 를 만든 뒤 panic");
+    }));
+    assert!(result.is_err());
+    log.into_inner()
+}
+
+fn drop_during_panic() {
+    println!("--- 되감기 중의 Drop 과 이중 panic ---");
+
+    // C++ 에서는:
+    // ~Transaction() { if (!committed) throw ...; }   // 소멸자는 기본 noexcept - 던지면 std::terminate
+    // std::uncaught_exceptions() 로 예외 처리 중인지 보는 것이 thread::panicking() 과 같음
+
+    println!("drop 순서: {:?}", unwind_drop_order());
+
+    // commit 하고 끝내면 조용함
+    Transaction::begin().commit();
+
+    // commit 을 잊으면 Drop 의 panic
+    let (forgot, seen) = silently(|| {
+        let _tx = Transaction::begin();
+    });
+    println!("commit 을 잊음: {} - {}", forgot.is_err(), seen.map(|s| s.to_string()).unwrap_or_default());
+
+    // 작업 중 panic - Drop 은 panicking() 을 보고 넘어가므로 abort 대신 원래 panic 만 전달됨
+    let (failed, _) = silently(|| {
+        let _tx = Transaction::begin();
+        panic!("작업 중 panic");
+    });
+    println!("작업 중 panic: {}", payload_text(failed.unwrap_err().as_ref()));
+    // panicking() 검사를 지우면 이 절에서 프로세스가 "panic in a destructor during cleanup" 으로 abort
+}
+
+fn main() {
+    drop_during_panic();
+}
@@ -0,0 +1,209 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 48. panic 과 되감기 - panic 훅
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use crate::panic_hook::{silence_thread, silently};
+
+// --- rust-study 의 panic_hook 모듈 ---
+mod panic_hook {
+// ============================================================================
+// panic 훅 (Panic hook)
+// ============================================================================
+// 프로세스에 하나뿐인 panic 훅을 이 저장소에서 설치하는 유일한 곳 (훅과 catch_unwind 는 48장)
+//
+//   let (result, seen) = crate::panic_hook::silently(|| v[index]);
+//   // result: Err(payload), seen: Some(Seen { message, file, line }) - 출력은 없음
+//
+// 표준 훅(또는 먼저 설치된 훅)을 감싸 panic 마다 메시지와 위치를 thread_local 에 기록
+//   - 장 실행기(src/runner.rs)는 기록만 가져가고 출력은 표준 훅 그대로
+//   - silently / silence_thread 를 쓴 스레드는 출력하지 않음 (43장의 퍼저, 48장과 54장의 예제)
+// 상태는 모두 thread_local - --parallel 이나 테스트처럼 여러 스레드가 동시에 panic 해도 섞이지 않음
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+// 훅이 본 panic 하나 - "메시지 (파일:줄)" 로 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for Seen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.message, self.file, self.line)
+    }
+}
+
+thread_local! {
+    // true 면 이 스레드의 panic 을 출력하지 않음
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+    // 이 스레드에서 마지막으로 난 panic - 훅이 쓰고 take_last 가 가져감
+    static LAST: RefCell<Option<Seen>> = const { RefCell::new(None) };
+}
+
+// panic!("..") 는 &str, panic!("{}", x) 는 String - 그 밖의 payload(panic_any)는 읽을 수 없음
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(알 수 없는 panic)"))
+}
+
+// 기존 훅을 감싸는 훅을 한 번만 설치 - 몇 번 불러도 됨
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let seen = info.location().map(|l| Seen {
+                message: message(info.payload()),
+                file: l.file().to_string(),
+                line: l.line(),
+            });
+            LAST.with(|last| *last.borrow_mut() = seen);
+            if !SILENT.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// 이 스레드에서 마지막으로 난 panic 을 가져감 (한 번만)
+pub fn take_last() -> Option<Seen> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+// f 를 실행하고 panic 을 잡음 - 출력 대신 훅이 본 것을 함께 돌려줌
+pub fn silently<R>(f: impl FnOnce() -> R + UnwindSafe) -> (thread::Result<R>, Option<Seen>) {
+    install();
+    take_last();
+    let was = SILENT.replace(true);
+    let result = panic::catch_unwind(f);
+    SILENT.set(was);
+    (result, take_last())
+}
+
+// 지금 스레드의 panic 출력을 끔 - 곧 panic 으로 끝날 스레드 안에서 (join 이 payload 를 받음)
+pub fn silence_thread() {
+    install();
+    SILENT.set(true);
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub fn payload_text(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else if let Some(n) = payload.downcast_ref::<i32>() {
+        format!("i32 {}", n)
+    } else {
+        "(알 수 없는 payload)".to_string()
+    }
+}
+
+fn catch_unwind() {
+    println!("--- catch_unwind 와 payload ---");
+
+    // C++ 에서는:
+    // try { risky(); } catch (const std::exception& e) { ... } catch (...) { ... }
+    // 예외 타입으로 골라 잡음 - Rust 는 모든 panic 을 한 번에 잡고 payload 를 downcast
+
+    let ok = panic::catch_unwind(|| 6 * 7);
+    println!("panic 없음: {:?}", ok);
+
+    // 아래의 panic 은 모두 silently 로 - 표준 훅의 "thread 'main' panicked at" 출력을 숨김 (panic_hook 절)
+    let (literal, _) = silently(|| panic!("리터럴 메시지"));
+    // 인자가 리터럴이면 컴파일할 때 문자열로 합쳐져 &str - 실행 중에 만든 값이어야 String
+    let answer = 42;
+    let (formatted, _) = silently(|| panic!("형식 있는 메시지 {}", answer));
+    let (any, _) = silently(|| panic::panic_any(7_i32));
+    for (what, result) in [("panic!(\"...\")", literal), ("panic!(\"{}\", x)", formatted), ("panic_any(7)", any)] {
+        let payload = result.unwrap_err();
+        let kind = if payload.is::<&str>() {
+            "&str"
+        } else if payload.is::<String>() {
+            "String"
+        } else {
+            "i32"
+        };
+        println!("{:<18} payload 타입 {:<6} → {}", what, kind, payload_text(payload.as_ref()));
+    }
+
+    // 잡아서 정리한 뒤 다시 던지기 - C++ 의 catch (...) { cleanup(); throw; }
+    let (outer, _) = silently(|| {
+        if let Err(payload) = panic::catch_unwind(|| panic!("안쪽 panic")) {
+            println!("안쪽에서 잡고 정리한 뒤 resume_unwind");
+            panic::resume_unwind(payload);
+        }
+    });
+    println!("바깥에서 다시 잡음: {}", payload_text(outer.unwrap_err().as_ref()));
+
+    // Option/Result 의 unwrap, 범위 밖 인덱스, 0 으로 나누기, RefCell 이중 빌림도 모두 panic
+    let v: &[i32] = &[1, 2, 3];
+    let index = v.len();
+    let (result, _) = silently(|| v[index]);
+    println!("v[3]: {}", payload_text(result.unwrap_err().as_ref()));
+}
+
+// ----------------------------------------------------------------------------
+// panic 훅
+// ----------------------------------------------------------------------------
+// panic 이 나면 되감기 전에 훅이 먼저 불림 - 기본 훅이 "thread '...' panicked at 파일:줄:칸" 과 메시지를 출력
+// set_hook 으로 바꾸고 take_hook 으로 꺼냄 - 프로세스 전체에 하나 (모든 스레드 공유)
+// 그래서 바꿀 때는 기존 훅을 감싸고, 켜고 끄는 상태는 thread_local 로 (테스트는 여러 스레드에서 동시에 돌므로)
+// 이 저장소의 훅은 src/panic_hook.rs 하나 - 장 실행기(runner::catch)는 위치를 기록하고, 43장의 퍼저와 이 장은 출력을 숨김
+
+fn panic_hook() {
+    println!("--- panic 훅 ---");
+
+    // C++ 에서는:
+    // std::set_terminate(handler);   // 잡히지 않은 예외로 끝날 때만 - 던지는 순간 불리는 훅은 없음
+    // Rust 의 훅은 잡히든 안 잡히든 panic 마다, 되감기 전에, panic 한 스레드에서 불림
+
+    let (result, seen) = silently(|| {
+        let config: Option<&str> = None;
+        #[expect(clippy::unnecessary_literal_unwrap, reason = "None 의 expect 가 내는 panic 을 보여 줌")]
+        config.expect("설정이 없음");
+    });
+    println!("잡은 payload: {}", payload_text(result.unwrap_err().as_ref()));
+    println!("훅이 본 것:   {}", seen.map(|s| s.to_string()).unwrap_or_default());
+
+    // 스레드의 panic 은 join 에서 Err(payload) - thread::spawn 이 안에서 catch_unwind 를 쓰는 셈
+    // 훅은 panic 한 스레드에서 불리므로 출력을 끄는 것도 그 스레드에서
+    let handle = thread::spawn(|| {
+        silence_thread();
+        let items: Vec<u32> = Vec::new();
+        items[0]
+    });
+    match handle.join() {
+        Ok(_) => println!("스레드 정상 종료"),
+        Err(payload) => println!("join 이 돌려준 payload: {}", payload_text(payload.as_ref())),
+    }
+
+    // RUST_BACKTRACE=1 이면 기본 훅이 백트레이스도 출력 - 훅 안에서 std::backtrace::Backtrace::capture() 로 직접도 가능
+    println!("RUST_BACKTRACE 설정: {}", std::env::var("RUST_BACKTRACE").unwrap_or_else(|_| "-".to_string()));
+}
+
+fn main() {
+    panic_hook();
+}
@@ -0,0 +1,277 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 48. panic 과 되감기 - UnwindSafe 와 Mutex 오염
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use crate::panic_hook::{silence_thread, silently};
+
+// --- rust-study 의 panic_hook 모듈 ---
+mod panic_hook {
+// ============================================================================
+// panic 훅 (Panic hook)
+// ============================================================================
+// 프로세스에 하나뿐인 panic 훅을 이 저장소에서 설치하는 유일한 곳 (훅과 catch_unwind 는 48장)
+//
+//   let (result, seen) = crate::panic_hook::silently(|| v[index]);
+//   // result: Err(payload), seen: Some(Seen { message, file, line }) - 출력은 없음
+//
+// 표준 훅(또는 먼저 설치된 훅)을 감싸 panic 마다 메시지와 위치를 thread_local 에 기록
+//   - 장 실행기(src/runner.rs)는 기록만 가져가고 출력은 표준 훅 그대로
+//   - silently / silence_thread 를 쓴 스레드는 출력하지 않음 (43장의 퍼저, 48장과 54장의 예제)
+// 상태는 모두 thread_local - --parallel 이나 테스트처럼 여러 스레드가 동시에 panic 해도 섞이지 않음
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+// 훅이 본 panic 하나 - "메시지 (파일:줄)" 로 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for Seen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.message, self.file, self.line)
+    }
+}
+
+thread_local! {
+    // true 면 이 스레드의 panic 을 출력하지 않음
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+    // 이 스레드에서 마지막으로 난 panic - 훅이 쓰고 take_last 가 가져감
+    static LAST: RefCell<Option<Seen>> = const { RefCell::new(None) };
+}
+
+// panic!("..") 는 &str, panic!("{}", x) 는 String - 그 밖의 payload(panic_any)는 읽을 수 없음
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(알 수 없는 panic)"))
+}
+
+// 기존 훅을 감싸는 훅을 한 번만 설치 - 몇 번 불러도 됨
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let seen = info.location().map(|l| Seen {
+                message: message(info.payload()),
+                file: l.file().to_string(),
+                line: l.line(),
+            });
+            LAST.with(|last| *last.borrow_mut() = seen);
+            if !SILENT.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// 이 스레드에서 마지막으로 난 panic 을 가져감 (한 번만)
+pub fn take_last() -> Option<Seen> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+// f 를 실행하고 panic 을 잡음 - 출력 대신 훅이 본 것을 함께 돌려줌
+pub fn silently<R>(f: impl FnOnce() -> R + UnwindSafe) -> (thread::Result<R>, Option<Seen>) {
+    install();
+    take_last();
+    let was = SILENT.replace(true);
+    let result = panic::catch_unwind(f);
+    SILENT.set(was);
+    (result, take_last())
+}
+
+// 지금 스레드의 panic 출력을 끔 - 곧 panic 으로 끝날 스레드 안에서 (join 이 payload 를 받음)
+pub fn silence_thread() {
+    install();
+    SILENT.set(true);
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub fn payload_text(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else if let Some(n) = payload.downcast_ref::<i32>() {
+        format!("i32 {}", n)
+    } else {
+        "(알 수 없는 payload)".to_string()
+    }
+}
+
+fn catch_unwind() {
+    println!("--- catch_unwind 와 payload ---");
+
+    // C++ 에서는:
+    // try { risky(); } catch (const std::exception& e) { ... } catch (...) { ... }
+    // 예외 타입으로 골라 잡음 - Rust 는 모든 panic 을 한 번에 잡고 payload 를 downcast
+
+    let ok = panic::catch_unwind(|| 6 * 7);
+    println!("panic 없음: {:?}", ok);
+
+    // 아래의 panic 은 모두 silently 로 - 표준 훅의 "thread 'main' panicked at" 출력을 숨김 (panic_hook 절)
+    let (literal, _) = silently(|| panic!("리터럴 메시지"));
+    // 인자가 리터럴이면 컴파일할 때 문자열로 합쳐져 &str - 실행 중에 만든 값이어야 String
+    let answer = 42;
+    let (formatted, _) = silently(|| panic!("형식 있는 메시지 {}", answer));
+    let (any, _) = silently(|| panic::panic_any(7_i32));
+    for (what, result) in [("panic!(\"...\")", literal), ("panic!(\"{}\", x)", formatted), ("panic_any(7)", any)] {
+        let payload = result.unwrap_err();
+        let kind = if payload.is::<&str>() {
+            "&str"
+        } else if payload.is::<String>() {
+            "String"
+        } else {
+            "i32"
+        };
+        println!("{:<18} payload 타입 {:<6} → {}", what, kind, payload_text(payload.as_ref()));
+    }
+
+    // 잡아서 정리한 뒤 다시 던지기 - C++ 의 catch (...) { cleanup(); throw; }
+    let (outer, _) = silently(|| {
+        if let Err(payload) = panic::catch_unwind(|| panic!("안쪽 panic")) {
+            println!("안쪽에서 잡고 정리한 뒤 resume_unwind");
+            panic::resume_unwind(payload);
+        }
+    });
+    println!("바깥에서 다시 잡음: {}", payload_text(outer.unwrap_err().as_ref()));
+
+    // Option/Result 의 unwrap, 범위 밖 인덱스, 0 으로 나누기, RefCell 이중 빌림도 모두 panic
+    let v: &[i32] = &[1, 2, 3];
+    let index = v.len();
+    let (result, _) = silently(|| v[index]);
+    println!("v[3]: {}", payload_text(result.unwrap_err().as_ref()));
+}
+
+fn panic_hook() {
+    println!("--- panic 훅 ---");
+
+    // C++ 에서는:
+    // std::set_terminate(handler);   // 잡히지 않은 예외로 끝날 때만 - 던지는 순간 불리는 훅은 없음
+    // Rust 의 훅은 잡히든 안 잡히든 panic 마다, 되감기 전에, panic 한 스레드에서 불림
+
+    let (result, seen) = silently(|| {
+        let config: Option<&str> = None;
+        #[expect(clippy::unnecessary_literal_unwrap, reason = "None 의 expect 가 내는 panic 을 보여 줌")]
+        config.expect("설정이 없음");
+    });
+    println!("잡은 payload: {}", payload_text(result.unwrap_err().as_ref()));
+    println!("훅이 본 것:   {}", seen.map(|s| s.to_string()).unwrap_or_default());
+
+    // 스레드의 panic 은 join 에서 Err(payload) - thread::spawn 이 안에서 catch_unwind 를 쓰는 셈
+    // 훅은 panic 한 스레드에서 불리므로 출력을 끄는 것도 그 스레드에서
+    let handle = thread::spawn(|| {
+        silence_thread();
+        let items: Vec<u32> = Vec::new();
+        items[0]
+    });
+    match handle.join() {
+        Ok(_) => println!("스레드 정상 종료"),
+        Err(payload) => println!("join 이 돌려준 payload: {}", payload_text(payload.as_ref())),
+    }
+
+    // RUST_BACKTRACE=1 이면 기본 훅이 백트레이스도 출력 - 훅 안에서 std::backtrace::Backtrace::capture() 로 직접도 가능
+    println!("RUST_BACKTRACE 설정: {}", std::env::var("RUST_BACKTRACE").unwrap_or_else(|_| "-".to_string()));
+}
+
+// ----------------------------------------------------------------------------
+// UnwindSafe 와 Mutex 오염
+// ----------------------------------------------------------------------------
+// panic 이 작업 중간에 나면 값이 "반쯤 바뀐" 상태로 남을 수 있음 - C++ 의 basic vs strong exception guarantee
+// catch_unwind 는 클로저가 UnwindSafe 이길 요구 - &mut T, &RefCell<T>, &Cell<T> 를 잡으면 컴파일 에러
+//   AssertUnwindSafe(...) 로 "panic 후의 상태는 내가 책임진다" 고 표시해야 통과 (메모리 안전과는 무관, 린트에 가까움)
+// Mutex 는 런타임에 같은 일을 함 - 잠금을 쥔 채 panic 하면 오염(poison), 다음 lock() 이 Err(PoisonError)
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Accounts {
+    pub from: i64,
+    pub to: i64,
+}
+
+impl Accounts {
+    pub fn total(&self) -> i64 {
+        self.from + self.to
+    }
+
+    // basic guarantee 만 - 빼고 나서 검사가 panic 하면 돈이 사라짐
+    pub fn transfer_in_place(&mut self, amount: i64) {
+        self.from -= amount;
+        assert!(self.from >= 0, "잔액 부족");
+        self.to += amount;
+    }
+
+    // strong guarantee - 새 값을 다 계산한 뒤 한 번에 반영, panic 이면 그대로
+    pub fn transfer(&mut self, amount: i64) {
+        let from = self.from - amount;
+        assert!(from >= 0, "잔액 부족");
+        *self = Accounts { from, to: self.to + amount };
+    }
+}
+
+fn unwind_safe() {
+    println!("--- UnwindSafe 와 Mutex 오염 ---");
+
+    // C++ 에서는:
+    // void transfer(int n) { from -= n; check(); to += n; }   // check() 가 던지면 from 만 줄어듦
+    // 컴파일러는 아무 말도 없음 - Rust 는 &mut 를 catch_unwind 로 넘기는 순간 한 번 멈춰 세움
+
+    let mut accounts = Accounts { from: 100, to: 0 };
+    // panic::catch_unwind(|| accounts.transfer_in_place(150));
+    //   에러: the type `&mut Accounts` may not be safely transferred across an unwind boundary
+    let (result, _) = silently(AssertUnwindSafe(|| accounts.transfer_in_place(150)));
+    println!("transfer_in_place(150): {} → {:?}, 합계 {}", result.is_err(), accounts, accounts.total());
+
+    let mut accounts = Accounts { from: 100, to: 0 };
+    let (result, _) = silently(AssertUnwindSafe(|| accounts.transfer(150)));
+    println!("transfer(150):          {} → {:?}, 합계 {}", result.is_err(), accounts, accounts.total());
+
+    // Mutex 오염 - 잠금을 쥔 스레드가 panic 하면 다른 스레드가 반쯤 바뀐 값을 보지 않도록 표시
+    let shared = Arc::new(Mutex::new(vec![1, 2, 3]));
+    let worker = Arc::clone(&shared);
+    let joined = thread::spawn(move || {
+        silence_thread();
+        let mut items = worker.lock().unwrap();
+        items.push(4);
+        panic!("push 하고 나서 panic");
+    })
+    .join();
+    println!("작업 스레드 panic: {}, 오염됨: {}", joined.is_err(), shared.is_poisoned());
+
+    match shared.lock() {
+        Ok(items) => println!("잠금 성공 {:?}", *items),
+        Err(poisoned) => {
+            // 값은 그대로 들어 있음 - 믿을 수 있는지 판단하고 꺼내 씀 (장 실행기의 결과 목록이 이렇게 함)
+            let items = poisoned.into_inner();
+            println!("PoisonError::into_inner 로 꺼낸 값: {:?}", *items);
+        }
+    }
+    // 다시 믿을 수 있게 만들었으면 표시를 지움
+    shared.clear_poison();
+    println!("clear_poison 후 오염됨: {}", shared.is_poisoned());
+}
+
+fn main() {
+    unwind_safe();
+}
@@ -0,0 +1,99 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 49. 메모리 배치와 repr - enum 의 꼬리표
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::marker::PhantomData;
+use std::mem::{self, offset_of};
+use std::num::NonZeroU32;
+use std::sync::atomic::AtomicU64;
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn show<T>(name: &str) {
+    println!("  {:<34} 크기 {:>3}, 정렬 {:>2}", name, size_of::<T>(), align_of::<T>());
+}
+
+// ----------------------------------------------------------------------------
+// enum 의 꼬리표
+// ----------------------------------------------------------------------------
+// 필드 없는 enum - 변형 수에 맞는 가장 작은 정수 (256 개 이하면 1 바이트)
+// #[repr(u8)], #[repr(i32)] 등으로 꼬리표 타입을 정함 - as 로 정수 변환, C enum 과 주고받을 때
+// 데이터가 있는 enum - 꼬리표 + 가장 큰 변형, 정렬에 맞춰 꼬리표 자리도 커짐
+// #[repr(u8)] 을 데이터 있는 enum 에 붙이면 "u8 꼬리표 + #[repr(C)] union" 배치가 보장됨 (47장의 CValue 를 enum 으로)
+
+#[allow(dead_code, reason = "크기와 West 의 꼬리표만 봄 - 나머지 변형은 만들지 않음")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Errno {
+    NotFound = -2,
+    Denied = -13,
+    Busy = -16,
+}
+
+impl Errno {
+    // 정수 → enum 은 as 로 안 됨 - 모든 정수가 올바른 변형은 아니므로 직접 match
+    pub fn from_code(code: i32) -> Option<Errno> {
+        match code {
+            -2 => Some(Errno::NotFound),
+            -13 => Some(Errno::Denied),
+            -16 => Some(Errno::Busy),
+            _ => None,
+        }
+    }
+}
+
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+pub enum Shape {
+    Point,
+    Circle(f32),
+    Rect(f32, f32),
+}
+
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+pub enum Message {
+    Quit,
+    Move(u8, u8),
+    Text(String),
+}
+
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+#[repr(u8)]
+pub enum Tagged {
+    Int(i32),
+    Byte(u8),
+}
+
+fn enum_layout() {
+    println!("--- enum 의 꼬리표 ---");
+
+    // C++ 에서는:
+    // enum class Errno : int32_t { NotFound = -2, Denied = -13 };   // 밑 타입을 정하는 것이 #[repr(i32)]
+    // std::variant<float, std::pair<float, float>> - 인덱스 + 저장소, 보통 Rust 의 enum 보다 큼
+
+    show::<Direction>("enum Direction");
+    show::<Errno>("#[repr(i32)] enum Errno");
+    println!("    Errno::Denied as i32 = {}, from_code(-16) = {:?}", Errno::Denied as i32, Errno::from_code(-16));
+    println!("    Direction::West as u8 = {} (꼬리표는 선언 순서로 0 부터)", Direction::West as u8);
+
+    show::<Shape>("enum Shape { f32 | (f32, f32) }");
+    show::<Message>("enum Message { (u8,u8) | String }");
+    show::<Tagged>("#[repr(u8)] enum { i32 | u8 }");
+
+    // 변형끼리 같은지만 비교 - 꼬리표 값은 보이지 않음
+    let same = mem::discriminant(&Shape::Circle(1.0)) == mem::discriminant(&Shape::Circle(2.0));
+    println!("    discriminant(Circle(1.0)) == discriminant(Circle(2.0)): {}", same);
+}
+
+fn main() {
+    enum_layout();
+}
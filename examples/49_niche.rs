@@ -0,0 +1,73 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 49. 메모리 배치와 repr - niche 최적화
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::marker::PhantomData;
+use std::mem::{self, offset_of};
+use std::num::NonZeroU32;
+use std::sync::atomic::AtomicU64;
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn show<T>(name: &str) {
+    println!("  {:<34} 크기 {:>3}, 정렬 {:>2}", name, size_of::<T>(), align_of::<T>());
+}
+
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+pub enum Message {
+    Quit,
+    Move(u8, u8),
+    Text(String),
+}
+
+// ----------------------------------------------------------------------------
+// niche 최적화
+// ----------------------------------------------------------------------------
+// 어떤 타입은 절대 가질 수 없는 비트 패턴(niche)이 있음 - &T, Box<T>, NonNull<T> 는 0 이 아님, bool 은 0/1 만, char 는 0x10FFFF 이하
+// enum 은 꼬리표를 따로 두지 않고 그 패턴으로 다른 변형을 표시 - Option<Box<T>> 의 None 은 null
+// Option<&T>, Option<Box<T>>, Option<NonZeroU32>, Option<extern "C" fn> 의 크기와 표현은 보장됨 - FFI 에서 nullable 포인터로
+// u32 처럼 모든 비트가 올바른 타입은 niche 가 없어 Option<u32> 는 꼬리표만큼 커짐
+// 위 절의 Message 가 String 과 같은 24 바이트인 것도 niche - String 의 용량 필드가 쓰지 않는 큰 값에 Quit, Move 를 넣음
+
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+pub enum OneBox {
+    Full(Box<u64>),
+    Empty,
+}
+
+fn niche() {
+    println!("--- niche 최적화 ---");
+
+    // C++ 에서는:
+    // sizeof(std::optional<int*>) == 16 - 포인터 + bool + 패딩, null 을 "없음" 으로 쓰려면 optional 대신 그냥 포인터
+    // Rust 는 Option<Box<T>> 가 포인터 하나 - 타입으로는 구분되고 비용은 같음
+
+    show::<Box<u64>>("Box<u64>");
+    show::<Option<Box<u64>>>("Option<Box<u64>>");
+    show::<Option<&u64>>("Option<&u64>");
+    show::<Option<&[u64]>>("Option<&[u64]>");
+    show::<Option<Vec<u64>>>("Option<Vec<u64>>");
+    show::<OneBox>("enum { Box<u64> | Empty }");
+    show::<u32>("u32");
+    show::<Option<u32>>("Option<u32>");
+    show::<Option<NonZeroU32>>("Option<NonZeroU32>");
+    show::<Option<bool>>("Option<bool>");
+    show::<Option<Option<bool>>>("Option<Option<bool>>");
+    show::<Option<char>>("Option<char>");
+    show::<Option<extern "C" fn()>>("Option<extern \"C\" fn()>");
+
+    // None 은 정말 null - 크기와 표현이 보장되므로 이렇게 확인해도 됨 (47장의 transmute 가 아니라 포인터로)
+    let none: Option<Box<u64>> = None;
+    // SAFETY: Option<Box<u64>> 는 크기와 정렬이 *const u64 와 같음이 보장됨
+    let bits = unsafe { *(&none as *const Option<Box<u64>>).cast::<*const u64>() };
+    println!("None 의 비트: {:?} (null = {})", bits, bits.is_null());
+
+    // 0 이 아님을 타입으로 - 0 을 넣으면 None
+    println!("NonZeroU32::new(0) = {:?}, new(7) = {:?}", NonZeroU32::new(0), NonZeroU32::new(7));
+}
+
+fn main() {
+    niche();
+}
@@ -0,0 +1,91 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 49. 메모리 배치와 repr - 패딩과 #[repr(C)]
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::marker::PhantomData;
+use std::mem::{self, offset_of};
+use std::num::NonZeroU32;
+use std::sync::atomic::AtomicU64;
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn show<T>(name: &str) {
+    println!("  {:<34} 크기 {:>3}, 정렬 {:>2}", name, size_of::<T>(), align_of::<T>());
+}
+
+// ----------------------------------------------------------------------------
+// 패딩과 #[repr(C)]
+// ----------------------------------------------------------------------------
+// 각 필드는 자기 정렬의 배수 오프셋에 - 그 사이를 패딩으로 채움, 구조체 정렬은 가장 엄격한 필드
+// #[repr(C)] - 선언 순서 그대로, C 의 규칙으로 패딩 (FFI 와 비트 재해석에 필요, 44장, 45장, 47장)
+// 기본 배치 - 순서를 바꿔도 됨, 실제로는 정렬이 큰 필드부터 놓아 패딩을 줄임 (보장은 아님)
+// offset_of!(타입, 필드) 로 실제 오프셋을 확인 - C 의 offsetof
+
+#[repr(C)]
+pub struct CLayout {
+    pub a: u8,
+    pub b: u32,
+    pub c: u8,
+    pub d: u16,
+}
+
+// 같은 필드, 기본 배치
+pub struct RustLayout {
+    pub a: u8,
+    pub b: u32,
+    pub c: u8,
+    pub d: u16,
+}
+
+// 패딩 없이 빽빽하게 - 필드 정렬이 1 이 되어 참조를 만들 수 없음 (네트워크 헤더, 파일 형식)
+#[repr(C, packed)]
+pub struct Packed {
+    pub a: u8,
+    pub b: u32,
+}
+
+// 정렬을 키움 - 스레드마다 쓰는 카운터를 캐시 줄(보통 64 바이트)마다 떨어뜨려 거짓 공유(false sharing)를 피함
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+#[repr(align(64))]
+pub struct CacheLine(pub AtomicU64);
+
+fn padding() {
+    println!("--- 패딩과 #[repr(C)] ---");
+
+    // C++ 에서는:
+    // struct S { uint8_t a; uint32_t b; uint8_t c; uint16_t d; };   // 선언 순서 그대로 - sizeof 12
+    // 패딩을 줄이려면 필드 순서를 손으로 바꿔야 함 (-Wpadded 가 알려줌)
+
+    show::<CLayout>("#[repr(C)] {u8, u32, u8, u16}");
+    println!(
+        "    오프셋 a {}, b {}, c {}, d {} - a 뒤에 3, c 뒤에 1 바이트 패딩",
+        offset_of!(CLayout, a),
+        offset_of!(CLayout, b),
+        offset_of!(CLayout, c),
+        offset_of!(CLayout, d)
+    );
+    show::<RustLayout>("repr(Rust) {u8, u32, u8, u16}");
+    println!(
+        "    오프셋 a {}, b {}, c {}, d {} - 컴파일러가 순서를 바꿈",
+        offset_of!(RustLayout, a),
+        offset_of!(RustLayout, b),
+        offset_of!(RustLayout, c),
+        offset_of!(RustLayout, d)
+    );
+
+    show::<Packed>("#[repr(C, packed)] {u8, u32}");
+    let packed = Packed { a: 1, b: 0x0102_0304 };
+    // let r = &packed.b;   에러 E0793: 정렬되지 않은 필드의 참조 - 정렬 안 된 &u32 는 UB
+    // 중괄호로 값을 복사해 읽음 (Copy 필드만)
+    let b = { packed.b };
+    println!("    packed.b 를 복사해 읽기: {:#x} (a = {})", b, { packed.a });
+
+    show::<CacheLine>("#[repr(align(64))] AtomicU64");
+    show::<[CacheLine; 4]>("[CacheLine; 4]");
+}
+
+fn main() {
+    padding();
+}
@@ -0,0 +1,80 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 49. 메모리 배치와 repr - #[repr(transparent)] newtype
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::marker::PhantomData;
+use std::mem::{self, offset_of};
+use std::num::NonZeroU32;
+use std::sync::atomic::AtomicU64;
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn show<T>(name: &str) {
+    println!("  {:<34} 크기 {:>3}, 정렬 {:>2}", name, size_of::<T>(), align_of::<T>());
+}
+
+// ----------------------------------------------------------------------------
+// #[repr(transparent)] newtype
+// ----------------------------------------------------------------------------
+// newtype(struct Meters(f64))은 타입 안전을 더하고 비용은 없음 - 크기와 정렬은 기본 배치로도 같지만 ABI 는 약속이 아님
+// #[repr(transparent)] - 크기가 0 이 아닌 필드 하나(+ PhantomData 같은 ZST)면, 메모리 배치와 호출 규약까지 그 필드와 같음을 보장
+// 그래서 extern "C" 함수의 인자로 f64 대신 넘기거나, &[f64] 를 &[Meters] 로 다시 볼 수 있음 (std 의 NonNull, Wrapping 등이 이렇게 선언됨)
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Meters(pub f64);
+
+// 단위를 타입 매개변수로 - ZST 필드는 있어도 transparent
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity<Unit> {
+    pub value: f64,
+    unit: PhantomData<Unit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Seconds;
+
+impl<Unit> Quantity<Unit> {
+    pub fn new(value: f64) -> Self {
+        Quantity { value, unit: PhantomData }
+    }
+}
+
+// 복사 없이 &[f64] 를 &[Meters] 로
+pub fn as_meters(values: &[f64]) -> &[Meters] {
+    // SAFETY: Meters 는 #[repr(transparent)] 라 f64 와 크기, 정렬, 올바른 값이 같음 - 길이와 수명은 그대로
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast::<Meters>(), values.len()) }
+}
+
+// C 쪽 선언은 double half(double) - Meters 로 받아도 같은 ABI
+extern "C" fn half(distance: Meters) -> Meters {
+    Meters(distance.0 / 2.0)
+}
+
+fn repr_transparent() {
+    println!("--- #[repr(transparent)] newtype ---");
+
+    // C++ 에서는:
+    // struct Meters { double v; };   // 크기는 같아도 호출 규약이 double 과 같다는 보장은 플랫폼마다
+    // reinterpret_cast<const Meters*>(doubles) 는 엄밀히는 엄격한 별칭 규칙 위반
+
+    show::<f64>("f64");
+    show::<Meters>("#[repr(transparent)] Meters");
+    show::<Quantity<Seconds>>("Quantity<Seconds>");
+
+    let readings = [1.5, 20.0, 7.25];
+    let meters = as_meters(&readings);
+    let longest = meters.iter().copied().fold(Meters(0.0), |a, b| if b > a { b } else { a });
+    println!("as_meters: {:?}, 가장 긴 것 {:?}", meters, longest);
+    println!("extern \"C\" fn half(Meters(10.0)) = {:?}", half(Meters(10.0)));
+
+    let timeout: Quantity<Seconds> = Quantity::new(2.5);
+    println!("Quantity<Seconds> 의 값: {}", timeout.value);
+}
+
+fn main() {
+    repr_transparent();
+}
@@ -0,0 +1,58 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 49. 메모리 배치와 repr - 크기와 정렬
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::marker::PhantomData;
+use std::mem::{self, offset_of};
+use std::num::NonZeroU32;
+use std::sync::atomic::AtomicU64;
+
+// --- 다른 절에서 가져온 정의 ---
+
+fn show<T>(name: &str) {
+    println!("  {:<34} 크기 {:>3}, 정렬 {:>2}", name, size_of::<T>(), align_of::<T>());
+}
+
+// ----------------------------------------------------------------------------
+// 크기와 정렬
+// ----------------------------------------------------------------------------
+// align_of::<T>() - T 는 이 값의 배수인 주소에만 놓임 (2 의 거듭제곱)
+// size_of::<T>() - 배열에서 다음 원소까지의 거리, 항상 정렬의 배수 (그래서 끝에도 패딩이 붙음)
+// 크기 0 인 타입(ZST) - (), PhantomData, 필드 없는 구조체 - Vec<()> 는 메모리를 할당하지 않음 (16장의 MyVec)
+// 크기를 컴파일할 때 모르는 타입(str, [T], dyn Trait)은 size_of_val 로 값마다
+
+fn size_align() {
+    println!("--- 크기와 정렬 ---");
+
+    // C++ 에서는:
+    // sizeof(T), alignof(T) - 같은 개념. 다만 빈 클래스도 sizeof 가 1 (주소가 달라야 하므로)
+    // Rust 의 ZST 는 크기 0 - [(); 1000] 도 0 바이트
+
+    show::<u8>("u8");
+    show::<u16>("u16");
+    show::<u64>("u64");
+    show::<u128>("u128");
+    show::<f32>("f32");
+    show::<char>("char");
+    show::<bool>("bool");
+    show::<usize>("usize");
+    show::<(u8, u64)>("(u8, u64)");
+    show::<[u16; 3]>("[u16; 3]");
+    show::<()>("()");
+    show::<[(); 1000]>("[(); 1000]");
+    show::<PhantomData<u64>>("PhantomData<u64>");
+
+    // 포인터 - 얇은 포인터는 usize 하나, 슬라이스와 트레이트 객체는 (포인터, 길이/vtable) 두 개 (25장, 26장)
+    show::<&u64>("&u64");
+    show::<&[u64]>("&[u64]");
+    show::<&dyn std::fmt::Debug>("&dyn Debug");
+
+    let text = "안녕";
+    println!("size_of_val(\"안녕\") = {} (UTF-8 바이트), size_of::<&str>() = {}", mem::size_of_val(text), size_of::<&str>());
+}
+
+fn main() {
+    size_align();
+}
@@ -0,0 +1,189 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 50. 제로 코스트 추상화 재 보기 - 경계 검사와 그 제거
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::hint::black_box;
+
+// --- rust-study 의 microbench 모듈 ---
+mod microbench {
+// ============================================================================
+// 장 안의 작은 벤치마크 (Micro-benchmark)
+// ============================================================================
+// 레슨이 "더 빠르다", "비용이 없다" 고 말하는 곳에서 수치로 보여 주기 위한 최소한의 측정기
+//
+//   let m = crate::microbench::measure("enum", 1_000, || total(black_box(&shapes)));
+//   println!("{}", crate::microbench::table(&[m, ...]));
+//
+// 같은 조건으로 반복해서 재기: 예열 WARMUP 번 → SAMPLES 번 재고 중앙값 (한두 번 튀는 값에 흔들리지 않음)
+// 반복 횟수는 호출하는 쪽이 정함 (시간으로 맞추지 않음 - 실행마다 같은 일을 하도록)
+// 디버그 빌드의 수치는 최적화 전이라 비교에 쓰지 않음 - cargo run --release -- <장>
+// 정밀한 측정은 criterion 으로 (benches/, cargo bench 뒤 cargo run -- bench-report, 읽는 법은 26장)
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// 재기 전에 버리는 실행 수 - 캐시, 분기 예측기, CPU 클럭을 데움
+pub const WARMUP: usize = 3;
+// 재는 실행 수 - 홀수라서 중앙값이 하나
+pub const SAMPLES: usize = 11;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    // 한 번 실행(iterations 번 호출)의 중앙값과 최솟값
+    pub median: Duration,
+    pub min: Duration,
+    pub iterations: u32,
+}
+
+impl Measurement {
+    // 호출 한 번의 중앙값 (나노초)
+    pub fn per_call_ns(&self) -> f64 {
+        self.median.as_nanos() as f64 / self.iterations.max(1) as f64
+    }
+}
+
+// f 를 iterations 번 호출하는 실행을 WARMUP + SAMPLES 번 - 결과는 black_box 로 버려서 최적화로 사라지지 않게
+pub fn measure<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) -> Measurement {
+    let mut run = || {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(f());
+        }
+        start.elapsed()
+    };
+    for _ in 0..WARMUP {
+        run();
+    }
+    let mut samples: Vec<Duration> = (0..SAMPLES).map(|_| run()).collect();
+    samples.sort_unstable();
+    Measurement {
+        name: name.to_string(),
+        median: samples[SAMPLES / 2],
+        min: samples[0],
+        iterations,
+    }
+}
+
+// 이름, 호출당 시간, 가장 빠른 것 대비 배율 - 측정한 순서대로
+pub fn table(results: &[Measurement]) -> String {
+    let fastest = results
+        .iter()
+        .map(Measurement::per_call_ns)
+        .fold(f64::INFINITY, f64::min)
+        .max(f64::MIN_POSITIVE);
+    let width = results.iter().map(|m| m.name.chars().count()).max().unwrap_or(0);
+    results
+        .iter()
+        .map(|m| {
+            format!(
+                "  {:width$}  {:>12}  x{:.2}",
+                m.name,
+                format_ns(m.per_call_ns()),
+                m.per_call_ns() / fastest,
+                width = width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn format_ns(ns: f64) -> String {
+    if ns >= 1_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.2} µs", ns / 1_000.0)
+    } else {
+        format!("{:.1} ns", ns)
+    }
+}
+
+// 이 실행이 최적화 빌드인지 - 표 위에 함께 보여 줌
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "디버그 빌드 - 수치는 참고만 (cargo run --release 로 다시 재 보세요)"
+    } else {
+        "릴리스 빌드"
+    }
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+const LEN: usize = 4_096;
+
+fn workload(len: usize) -> Vec<u64> {
+    (0..len as u64).map(|i| i * 7_919 % 1_000).collect()
+}
+
+// ----------------------------------------------------------------------------
+// 경계 검사와 그 제거
+// ----------------------------------------------------------------------------
+// v[i] 는 i < v.len() 을 검사하고 아니면 panic - 검사 자체는 비교 하나지만 벡터화(SIMD)를 막을 수 있음
+// 최적화기는 루프 조건에서 i < v.len() 을 알면 검사를 지움 - 길이가 다른 두 슬라이스면 증명하지 못하기도
+// 도와주는 법: 먼저 같은 길이로 잘라 두기(&a[..n]), 아니면 zip 처럼 검사가 필요 없는 이터레이터
+// get_unchecked 는 마지막 수단 - 빨라지는지 먼저 재 보고, 대부분 위 두 방법으로 충분
+
+// 두 슬라이스의 내적 - 짧은 쪽 길이까지
+pub fn dot_index(a: &[u64], b: &[u64]) -> u64 {
+    let n = a.len().min(b.len());
+    let mut sum = 0;
+    let mut i = 0;
+    while i < n {
+        // n 이 a, b 어느 쪽의 길이인지 최적화기가 따로 추론해야 함
+        sum += a[i] * b[i];
+        i += 1;
+    }
+    sum
+}
+
+// 같은 길이로 잘라 두면 i < a.len() 에서 b[i] 도 안전함이 보임
+pub fn dot_sliced(a: &[u64], b: &[u64]) -> u64 {
+    let n = a.len().min(b.len());
+    let (a, b) = (&a[..n], &b[..n]);
+    let mut sum = 0;
+    let mut i = 0;
+    while i < a.len() {
+        sum += a[i] * b[i];
+        i += 1;
+    }
+    sum
+}
+
+pub fn dot_zip(a: &[u64], b: &[u64]) -> u64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn bounds_checks() {
+    println!("--- 경계 검사와 그 제거 ---");
+
+    // C++ 에서는:
+    // v[i] 는 검사 없음 (범위 밖이면 UB), v.at(i) 는 검사하고 예외
+    // Rust 의 v[i] 는 at() 쪽 - 대신 최적화기가 지울 수 있는 검사는 지움
+
+    let a = workload(LEN);
+    let b: Vec<u64> = workload(LEN + 3).into_iter().rev().collect();
+    println!(
+        "길이 {} 와 {} 의 내적: index {}, sliced {}, zip {}",
+        a.len(),
+        b.len(),
+        dot_index(&a, &b),
+        dot_sliced(&a, &b),
+        dot_zip(&a, &b)
+    );
+
+    // 검사가 실제로 하는 일 - 범위 밖이면 UB 대신 panic (48장), 검사 결과를 직접 받으려면 get
+    let short = [1u64, 2, 3];
+    println!("short.get(3) = {:?} - short[3] 이었다면 panic", short.get(black_box(3)));
+
+    println!("release 기계어로 확인: cargo asm 이나 godbolt 에서 panic_bounds_check 호출이 루프 안에 남는지");
+}
+
+fn main() {
+    bounds_checks();
+}
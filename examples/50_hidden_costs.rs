@@ -0,0 +1,170 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 50. 제로 코스트 추상화 재 보기 - 비용이 없지 않은 추상화
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::hint::black_box;
+
+// --- rust-study 의 microbench 모듈 ---
+mod microbench {
+// ============================================================================
+// 장 안의 작은 벤치마크 (Micro-benchmark)
+// ============================================================================
+// 레슨이 "더 빠르다", "비용이 없다" 고 말하는 곳에서 수치로 보여 주기 위한 최소한의 측정기
+//
+//   let m = crate::microbench::measure("enum", 1_000, || total(black_box(&shapes)));
+//   println!("{}", crate::microbench::table(&[m, ...]));
+//
+// 같은 조건으로 반복해서 재기: 예열 WARMUP 번 → SAMPLES 번 재고 중앙값 (한두 번 튀는 값에 흔들리지 않음)
+// 반복 횟수는 호출하는 쪽이 정함 (시간으로 맞추지 않음 - 실행마다 같은 일을 하도록)
+// 디버그 빌드의 수치는 최적화 전이라 비교에 쓰지 않음 - cargo run --release -- <장>
+// 정밀한 측정은 criterion 으로 (benches/, cargo bench 뒤 cargo run -- bench-report, 읽는 법은 26장)
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// 재기 전에 버리는 실행 수 - 캐시, 분기 예측기, CPU 클럭을 데움
+pub const WARMUP: usize = 3;
+// 재는 실행 수 - 홀수라서 중앙값이 하나
+pub const SAMPLES: usize = 11;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    // 한 번 실행(iterations 번 호출)의 중앙값과 최솟값
+    pub median: Duration,
+    pub min: Duration,
+    pub iterations: u32,
+}
+
+impl Measurement {
+    // 호출 한 번의 중앙값 (나노초)
+    pub fn per_call_ns(&self) -> f64 {
+        self.median.as_nanos() as f64 / self.iterations.max(1) as f64
+    }
+}
+
+// f 를 iterations 번 호출하는 실행을 WARMUP + SAMPLES 번 - 결과는 black_box 로 버려서 최적화로 사라지지 않게
+pub fn measure<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) -> Measurement {
+    let mut run = || {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(f());
+        }
+        start.elapsed()
+    };
+    for _ in 0..WARMUP {
+        run();
+    }
+    let mut samples: Vec<Duration> = (0..SAMPLES).map(|_| run()).collect();
+    samples.sort_unstable();
+    Measurement {
+        name: name.to_string(),
+        median: samples[SAMPLES / 2],
+        min: samples[0],
+        iterations,
+    }
+}
+
+// 이름, 호출당 시간, 가장 빠른 것 대비 배율 - 측정한 순서대로
+pub fn table(results: &[Measurement]) -> String {
+    let fastest = results
+        .iter()
+        .map(Measurement::per_call_ns)
+        .fold(f64::INFINITY, f64::min)
+        .max(f64::MIN_POSITIVE);
+    let width = results.iter().map(|m| m.name.chars().count()).max().unwrap_or(0);
+    results
+        .iter()
+        .map(|m| {
+            format!(
+                "  {:width$}  {:>12}  x{:.2}",
+                m.name,
+                format_ns(m.per_call_ns()),
+                m.per_call_ns() / fastest,
+                width = width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn format_ns(ns: f64) -> String {
+    if ns >= 1_000_000.0 {
+        format!("{:.2} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.2} µs", ns / 1_000.0)
+    } else {
+        format!("{:.1} ns", ns)
+    }
+}
+
+// 이 실행이 최적화 빌드인지 - 표 위에 함께 보여 줌
+pub fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "디버그 빌드 - 수치는 참고만 (cargo run --release 로 다시 재 보세요)"
+    } else {
+        "릴리스 빌드"
+    }
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+const LEN: usize = 4_096;
+
+const ITERATIONS: u32 = 50;
+
+fn workload(len: usize) -> Vec<u64> {
+    (0..len as u64).map(|i| i * 7_919 % 1_000).collect()
+}
+
+pub fn iterator_chain(v: &[u64]) -> u64 {
+    v.iter().filter(|&&x| x.is_multiple_of(2)).map(|&x| x * x).sum()
+}
+
+// ----------------------------------------------------------------------------
+// 비용이 없지 않은 추상화
+// ----------------------------------------------------------------------------
+// "제로 코스트" 는 추상화 자체의 비용 이야기 - 하는 일이 늘면 그만큼 듦
+//   중간 collect    체인 사이에 Vec 을 만들면 할당과 한 번 더 도는 비용 (지연 평가가 깨짐)
+//   Box<dyn Iterator> next() 가 원소마다 vtable 을 거쳐 인라인되지 않음 (26장의 dyn 과 같은 이유)
+// 필요할 때는 쓰면 됨 - 다만 뜨거운 루프 안에서는 재 보고 고름
+
+pub fn with_collect(v: &[u64]) -> u64 {
+    let evens: Vec<u64> = v.iter().copied().filter(|x| x.is_multiple_of(2)).collect();
+    evens.iter().map(|&x| x * x).sum()
+}
+
+pub fn with_dyn(v: &[u64]) -> u64 {
+    let evens: Box<dyn Iterator<Item = &u64>> = Box::new(v.iter().filter(|&&x| x.is_multiple_of(2)));
+    evens.map(|&x| x * x).sum()
+}
+
+fn hidden_costs() {
+    println!("--- 비용이 없지 않은 추상화 ---");
+
+    // C++ 에서는:
+    // std::vector<uint64_t> evens; std::copy_if(...);       // 중간 vector - ranges 를 쓰면 피할 수 있음
+    // std::function<bool(uint64_t)> pred;                    // 타입 소거 - 호출마다 간접 호출
+
+    let v = workload(LEN);
+    println!("같은 답? {}", with_collect(&v) == iterator_chain(&v) && with_dyn(&v) == iterator_chain(&v));
+
+    let results = [
+        microbench::measure("iterator_chain", ITERATIONS, || iterator_chain(black_box(&v))),
+        microbench::measure("with_collect", ITERATIONS, || with_collect(black_box(&v))),
+        microbench::measure("with_dyn", ITERATIONS, || with_dyn(black_box(&v))),
+    ];
+    println!("{}", microbench::table(&results));
+
+    // 흔히 보는 릴리스 빌드 결과: iterator_chain < with_dyn < with_collect (할당과 두 번 도는 비용이 가장 큼)
+}
+
+fn main() {
+    hidden_costs();
+}
@@ -0,0 +1,82 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 51. Cow 와 clone-on-write - 바꿀 일이 없으면 할당하지 않기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[expect(clippy::ptr_arg, reason = "&B 로는 어느 변형인지 알 수 없음")]
+fn kind<B: ?Sized + ToOwned>(cow: &Cow<'_, B>) -> &'static str {
+    match cow {
+        Cow::Borrowed(_) => "Borrowed",
+        Cow::Owned(_) => "Owned",
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 바꿀 일이 없으면 할당하지 않기
+// ----------------------------------------------------------------------------
+// 입력 대부분이 그대로 나가는 변환 - 검사만 하고 그대로면 Borrowed, 바꿔야 할 때만 String 을 만듦
+// 돌려받는 쪽은 어느 쪽인지 몰라도 됨 - &str 처럼 읽거나, 필요하면 into_owned
+
+// HTML 특수 문자 이스케이프 - 특수 문자가 없으면 입력을 그대로
+pub fn escape_html(text: &str) -> Cow<'_, str> {
+    if !text.contains(['<', '>', '&', '"']) {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        match c {
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '&' => out.push_str("&amp;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+// 연속된 공백을 하나로 - 이미 그렇다면 그대로
+pub fn collapse_spaces(text: &str) -> Cow<'_, str> {
+    if !text.contains("  ") {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(text.split(' ').filter(|w| !w.is_empty()).collect::<Vec<_>>().join(" "))
+}
+
+fn avoid_allocation() {
+    println!("--- 바꿀 일이 없으면 할당하지 않기 ---");
+
+    // C++ 에서는:
+    // std::string escape_html(std::string_view s);   // 특수 문자가 없어도 매번 새 string
+    // 피하려면 bool 을 돌려주는 검사 함수 + 변환 함수 두 개로 나누고 호출하는 쪽이 분기
+
+    let lines = ["plain text", "a < b && c > d", "say \"hi\"", "nothing to do here", "x&y"];
+    let mut borrowed = 0;
+    for line in lines {
+        let escaped = escape_html(line);
+        // Borrowed 면 같은 메모리 - 포인터가 같음
+        let same = std::ptr::eq(escaped.as_ptr(), line.as_ptr());
+        if same {
+            borrowed += 1;
+        }
+        println!("  {:<8} 같은 메모리 {:<5} {}", kind(&escaped), same, escaped);
+    }
+    println!("{}줄 중 {}줄은 할당 없이", lines.len(), borrowed);
+
+    // 변환을 이어 붙이기 - 앞 단계가 Borrowed 면 뒤 단계도 같은 원본을 빌림
+    for text in ["one two", "one   two  <b>"] {
+        let collapsed = collapse_spaces(text);
+        let escaped = escape_html(&collapsed);
+        println!("  collapse {} → escape {}: {}", kind(&collapsed), kind(&escaped), escaped);
+    }
+}
+
+fn main() {
+    avoid_allocation();
+}
@@ -0,0 +1,83 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 51. Cow 와 clone-on-write - 매개변수와 필드 타입 고르기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[expect(clippy::ptr_arg, reason = "&B 로는 어느 변형인지 알 수 없음")]
+fn kind<B: ?Sized + ToOwned>(cow: &Cow<'_, B>) -> &'static str {
+    match cow {
+        Cow::Borrowed(_) => "Borrowed",
+        Cow::Owned(_) => "Owned",
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 매개변수와 필드 타입 고르기
+// ----------------------------------------------------------------------------
+// 매개변수:
+//   &str                   읽기만 - 거의 항상 이것 (String, Cow<str> 모두 &로 넘길 수 있음)
+//   String                 함수가 보관 - 호출하는 쪽이 이미 가진 String 을 복사 없이 넘김
+//   impl Into<String>      보관 + 호출 편의 ("lit" 와 String 모두) - 18장의 빌더
+//   Cow<'a, str>           매개변수로는 드묾 - 돌려주는 값과 필드에 씀
+// 필드: Cow<'static, str> - 대부분 문자열 리터럴이고 가끔 만든 문자열인 값 (에러 메시지, 라벨, 기본 이름)
+//   리터럴은 할당 없이, format! 결과는 그대로 - 둘을 한 타입으로
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub code: u32,
+    pub message: Cow<'static, str>,
+}
+
+impl Diagnostic {
+    // &'static str 과 String 모두 - Cow::from 이 알맞은 변형을 고름
+    pub fn new(code: u32, message: impl Into<Cow<'static, str>>) -> Diagnostic {
+        Diagnostic { code, message: message.into() }
+    }
+}
+
+// 대부분은 고정 문구, 드물게 만든 문구
+pub fn describe(count: usize) -> Cow<'static, str> {
+    match count {
+        0 => "항목 없음".into(),
+        1 => "항목 하나".into(),
+        n => format!("항목 {}개", n).into(),
+    }
+}
+
+fn choosing_parameters() {
+    println!("--- 매개변수와 필드 타입 고르기 ---");
+
+    // C++ 에서는:
+    // void log(std::string_view msg);          // 읽기만 → &str
+    // void set_name(std::string name);         // 보관 (move 로 받음) → String 이나 impl Into<String>
+    // 리터럴과 만든 문자열을 한 필드에 - const char* + std::string 두 필드나 항상 std::string
+
+    let diagnostics = [
+        Diagnostic::new(1, "세미콜론이 없음"),
+        Diagnostic::new(2, format!("알 수 없는 이름 `{}`", "foo")),
+    ];
+    for d in &diagnostics {
+        println!("  code {} {:<8} {}", d.code, kind(&d.message), d.message);
+    }
+
+    for n in [0, 1, 5] {
+        let text = describe(n);
+        println!("  describe({}) → {} {:?}", n, kind(&text), text);
+    }
+
+    // &str 을 받는 함수에는 어느 쪽이든 &로
+    fn char_count(text: &str) -> usize {
+        text.chars().count()
+    }
+    println!("&Cow<str> → &str: char_count = {}", char_count(&describe(3)));
+}
+
+fn main() {
+    choosing_parameters();
+}
@@ -0,0 +1,55 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 51. Cow 와 clone-on-write - Cow 의 생김새
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[expect(clippy::ptr_arg, reason = "&B 로는 어느 변형인지 알 수 없음")]
+fn kind<B: ?Sized + ToOwned>(cow: &Cow<'_, B>) -> &'static str {
+    match cow {
+        Cow::Borrowed(_) => "Borrowed",
+        Cow::Owned(_) => "Owned",
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Cow 의 생김새
+// ----------------------------------------------------------------------------
+// enum Cow<'a, B: ?Sized + ToOwned> { Borrowed(&'a B), Owned(<B as ToOwned>::Owned) }
+// ToOwned - 빌린 타입에서 가진 타입으로: str → String, [T] → Vec<T>, Path → PathBuf, CStr → CString
+// Deref 로 &B 처럼 씀 - Cow<str> 에 len(), contains() 를 바로 부르고 &str 을 받는 함수에 &cow 로 넘김
+
+fn cow_basics() {
+    println!("--- Cow 의 생김새 ---");
+
+    // C++ 에서는:
+    // std::variant<std::string_view, std::string> 을 직접 만들고 visit 으로 꺼내야 함
+    // Rust 는 표준 타입 하나 - 두 변형 모두 Deref 로 &str
+
+    let borrowed: Cow<str> = Cow::Borrowed("borrowed text");
+    let owned: Cow<str> = Cow::Owned(String::from("owned text"));
+    // From 구현 - &str 이면 Borrowed, String 이면 Owned
+    let from_literal = Cow::from("literal");
+    let from_string = Cow::from(format!("{}장에서 만듦", 51));
+    for cow in [&borrowed, &owned, &from_literal, &from_string] {
+        println!("  {:<8} len {:>2}: {}", kind(cow), cow.len(), cow);
+    }
+
+    // 표준 라이브러리가 돌려주는 Cow - 올바른 UTF-8 이면 입력을 그대로 빌려 줌
+    let valid = String::from_utf8_lossy(b"hello");
+    let invalid = String::from_utf8_lossy(b"hel\xfflo");
+    println!("from_utf8_lossy(올바른 UTF-8): {} {:?}", kind(&valid), valid);
+    println!("from_utf8_lossy(깨진 바이트): {} {:?}", kind(&invalid), invalid);
+
+    // 크기 - String 과 같음 (String 의 용량 필드에 꼬리표를 숨기는 niche, 49장)
+    println!("size_of: String {}, Cow<str> {}, &str {}", size_of::<String>(), size_of::<Cow<str>>(), size_of::<&str>());
+}
+
+fn main() {
+    cow_basics();
+}
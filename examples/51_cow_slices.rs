@@ -0,0 +1,70 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 51. Cow 와 clone-on-write - Cow<[T]>
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[expect(clippy::ptr_arg, reason = "&B 로는 어느 변형인지 알 수 없음")]
+fn kind<B: ?Sized + ToOwned>(cow: &Cow<'_, B>) -> &'static str {
+    match cow {
+        Cow::Borrowed(_) => "Borrowed",
+        Cow::Owned(_) => "Owned",
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Cow<[T]>
+// ----------------------------------------------------------------------------
+// [T] 의 ToOwned 는 Vec<T> - T: Clone 이면 슬라이스도 같은 방식
+// to_mut() 이 Vec 을 돌려주므로 원소를 고치거나 push 할 수 있음
+
+// 범위 밖 값만 고침 - 모두 범위 안이면 입력 그대로
+pub fn clamp_all(values: &[i32], lo: i32, hi: i32) -> Cow<'_, [i32]> {
+    let mut out = Cow::Borrowed(values);
+    for (i, &v) in values.iter().enumerate() {
+        if v < lo || v > hi {
+            out.to_mut()[i] = v.clamp(lo, hi);
+        }
+    }
+    out
+}
+
+// 정렬된 슬라이스의 중복 제거 - 중복이 없으면 그대로
+pub fn dedup_sorted<T: Clone + PartialEq>(items: &[T]) -> Cow<'_, [T]> {
+    if items.windows(2).all(|w| w[0] != w[1]) {
+        return Cow::Borrowed(items);
+    }
+    let mut owned = items.to_vec();
+    owned.dedup();
+    Cow::Owned(owned)
+}
+
+fn cow_slices() {
+    println!("--- Cow<[T]> ---");
+
+    // C++ 에서는:
+    // std::span<const int> 을 받아 std::vector<int> 를 돌려주면 항상 복사
+
+    for values in [&[1, 5, 9][..], &[-3, 5, 120, 7][..]] {
+        let clamped = clamp_all(values, 0, 100);
+        println!("  clamp_all({:?}) → {} {:?}", values, kind(&clamped), clamped);
+    }
+
+    for words in [&["a", "b", "c"][..], &["a", "a", "b", "c", "c"][..]] {
+        let unique = dedup_sorted(words);
+        println!("  dedup_sorted({:?}) → {} {:?}", words, kind(&unique), unique);
+    }
+
+    // Cow<[T]> 에서 Vec 이 필요하면 into_owned
+    let v: Vec<i32> = clamp_all(&[200, -1], 0, 100).into_owned();
+    println!("into_owned: {:?}", v);
+}
+
+fn main() {
+    cow_slices();
+}
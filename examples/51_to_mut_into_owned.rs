@@ -0,0 +1,81 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 51. Cow 와 clone-on-write - to_mut 과 into_owned
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[expect(clippy::ptr_arg, reason = "&B 로는 어느 변형인지 알 수 없음")]
+fn kind<B: ?Sized + ToOwned>(cow: &Cow<'_, B>) -> &'static str {
+    match cow {
+        Cow::Borrowed(_) => "Borrowed",
+        Cow::Owned(_) => "Owned",
+    }
+}
+
+// ----------------------------------------------------------------------------
+// to_mut 과 into_owned
+// ----------------------------------------------------------------------------
+// to_mut() -> &mut Owned - Borrowed 면 이때 복사해 Owned 로 바꾸고, 이미 Owned 면 그대로 (두 번째부터는 공짜)
+// into_owned() -> Owned - Borrowed 면 복사, Owned 면 이동 (할당 없음) - 입력보다 오래 보관해야 할 때
+// 참조 카운트로 공유하다 쓸 때 복사하는 것은 Rc::make_mut - 다른 Rc 가 있을 때만 복사
+
+// 첫 번째 # 부터 줄 끝까지 지우고, 탭을 공백으로 - 할 일이 있을 때만 복사
+pub fn clean_line(line: &str) -> Cow<'_, str> {
+    // 자르기는 빌린 채로 - 원본의 앞부분을 가리키는 &str
+    let kept = match line.find('#') {
+        Some(start) => line[..start].trim_end(),
+        None => line,
+    };
+    let mut text = Cow::Borrowed(kept);
+    if text.contains('\t') {
+        // 여기서 처음 새 String - 자르기만 한 앞 단계는 할당하지 않았음
+        text = Cow::Owned(text.replace('\t', " "));
+    }
+    text
+}
+
+fn to_mut_into_owned() {
+    println!("--- to_mut 과 into_owned ---");
+
+    // C++ 에서는:
+    // std::optional<std::string> copy; std::string_view view = input;
+    // if (needs_change) { copy = std::string(view); view = *copy; ... }   // 손으로 하는 clone-on-write
+
+    let mut greeting = Cow::Borrowed("hello");
+    println!("처음: {}", kind(&greeting));
+    greeting.to_mut().push_str(", world");
+    println!("to_mut 한 번: {} {:?}", kind(&greeting), greeting);
+    // 이미 Owned - 복사 없이 같은 String 에 push
+    greeting.to_mut().push('!');
+    println!("to_mut 두 번: {} {:?}", kind(&greeting), greeting);
+
+    for line in ["key = value", "key = value  # note", "key =\tvalue"] {
+        let cleaned = clean_line(line);
+        println!("  clean_line: {:<8} {:?}", kind(&cleaned), cleaned);
+    }
+
+    // 입력(line)이 사라진 뒤에도 쓰려면 into_owned - 'a 가 없는 String 이 됨
+    let kept: String = {
+        let line = String::from("temporary\tline");
+        clean_line(&line).into_owned()
+    };
+    println!("into_owned 로 보관: {:?}", kept);
+
+    // Rc::make_mut - 다른 Rc 가 있으면 복사, 혼자면 그 자리에서 수정 (12장)
+    let mut shared = Rc::new(vec![1, 2, 3]);
+    let other = Rc::clone(&shared);
+    Rc::make_mut(&mut shared).push(4);
+    println!("공유 중 make_mut: shared {:?}, other {:?} (복사됨)", shared, other);
+    let before = Rc::as_ptr(&shared);
+    Rc::make_mut(&mut shared).push(5);
+    println!("혼자일 때 make_mut: 같은 할당? {}", before == Rc::as_ptr(&shared));
+}
+
+fn main() {
+    to_mut_into_owned();
+}
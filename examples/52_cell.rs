@@ -0,0 +1,88 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 52. 내부 가변성 총정리 - Cell - 참조 없이 값을 넣고 빼기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::{Cell, LazyCell, OnceCell, RefCell};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, RwLock};
+use std::thread;
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub trait MaybeSync {
+    const SYNC: bool;
+}
+
+impl<T> MaybeSync for Cell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for RefCell<T> {
+    const SYNC: bool = false;
+}
+
+// ----------------------------------------------------------------------------
+// Cell - 참조 없이 값을 넣고 빼기
+// ----------------------------------------------------------------------------
+// get (T: Copy), set, replace, take (T: Default), into_inner - 안의 값에 대한 참조를 절대 주지 않음
+// 그래서 빌림 검사가 필요 없고 비용도 없음 (크기는 T 그대로) - 대신 큰 값은 통째로 복사
+// &self 메서드 안의 카운터, 플래그, 캐시한 작은 값에 알맞음
+
+pub struct Parser {
+    input: String,
+    // &self 로 파싱하면서 몇 번 불렸는지 세기 - C++ 의 mutable size_t calls
+    calls: Cell<usize>,
+}
+
+impl Parser {
+    pub fn new(input: &str) -> Parser {
+        Parser { input: input.to_string(), calls: Cell::new(0) }
+    }
+
+    pub fn words(&self) -> Vec<&str> {
+        self.calls.set(self.calls.get() + 1);
+        self.input.split_whitespace().collect()
+    }
+
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+}
+
+fn cell() {
+    println!("--- Cell ---");
+
+    // C++ 에서는:
+    // class Parser { mutable size_t calls = 0; public: auto words() const { ++calls; ... } };
+
+    let parser = Parser::new("a quick brown fox");
+    let shared = &parser;
+    shared.words();
+    shared.words();
+    println!("&Parser 로 두 번 호출: calls = {}", parser.calls());
+
+    let flag = Cell::new(false);
+    let old = flag.replace(true);
+    println!("replace: 이전 {}, 지금 {}", old, flag.get());
+
+    // Copy 가 아니어도 take / replace 로 통째로 교환
+    let name = Cell::new(String::from("first"));
+    let taken = name.take();
+    name.set(String::from("second"));
+    println!("take 로 꺼냄 {:?}, 남은 값 {:?}", taken, name.into_inner());
+
+    // 슬라이스의 각 칸을 Cell 로 - 같은 배열의 두 칸을 &로 동시에 바꾸기
+    let mut numbers = [1, 2, 3];
+    let cells = Cell::from_mut(&mut numbers[..]).as_slice_of_cells();
+    cells[0].swap(&cells[2]);
+    println!("as_slice_of_cells 로 swap: {:?}", numbers);
+
+    println!("size_of: Cell<u64> {}, RefCell<u64> {} (빌림 카운터만큼 큼)", size_of::<Cell<u64>>(), size_of::<RefCell<u64>>());
+}
+
+fn main() {
+    cell();
+}
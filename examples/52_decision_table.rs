@@ -0,0 +1,81 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 52. 내부 가변성 총정리 - 고르는 표
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::{Cell, LazyCell, OnceCell, RefCell};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, RwLock};
+use std::thread;
+
+// ----------------------------------------------------------------------------
+// 고르는 표
+// ----------------------------------------------------------------------------
+// 질문 순서대로:
+//   1. 여러 스레드가 공유하나?  아니면 Cell / RefCell / OnceCell / LazyCell, 그렇다면 Atomic / Mutex / RwLock / OnceLock / LazyLock
+//   2. 한 번만 쓰나?            그렇다면 Once* (값을 나중에 정함) 또는 Lazy* (만드는 법이 고정)
+//   3. 안의 값을 &로 빌려야 하나? 아니면 Cell / Atomic (Copy 이거나 통째로 교환), 그렇다면 RefCell / Mutex / RwLock
+//   4. static 에 둘 것인가?     Sync 가 필요 - OnceLock, LazyLock, Mutex, RwLock, Atomic (스레드마다면 thread_local!)
+
+fn decision_table() {
+    println!("--- 고르는 표 ---");
+
+    // C++ 에서는:
+    // mutable 하나로 모두 표현하고, 스레드 안전은 주석과 코드 리뷰에 맡김
+
+    println!("  타입          스레드  빌려 주는 것     실패하면");
+    println!("  Cell          하나    없음 (값 복사)   실패 없음");
+    println!("  RefCell       하나    Ref / RefMut     이중 빌림이면 panic (try_borrow 는 Err)");
+    println!("  OnceCell      하나    &T (채운 뒤)     두 번째 set 은 Err");
+    println!("  LazyCell      하나    &T               초기화가 panic 하면 이후 접근도 panic");
+    println!("  Atomic*       여럿    없음 (값 복사)   실패 없음 (compare_exchange 는 Err)");
+    println!("  Mutex         여럿    MutexGuard       기다림, 잡은 스레드가 panic 하면 오염");
+    println!("  RwLock        여럿    읽기/쓰기 가드   기다림, 오염은 Mutex 와 같음");
+    println!("  OnceLock      여럿    &T (채운 뒤)     두 번째 set 은 Err, 동시 초기화는 기다림");
+    println!("  LazyLock      여럿    &T               초기화가 panic 하면 이후 접근도 panic");
+
+    println!(
+        "Sync 인가? Cell {}, RefCell {}, OnceCell {}, Mutex {}, OnceLock {}",
+        is_sync::<Cell<u32>>(),
+        is_sync::<RefCell<u32>>(),
+        is_sync::<OnceCell<u32>>(),
+        is_sync::<Mutex<u32>>(),
+        is_sync::<OnceLock<u32>>()
+    );
+}
+
+// T 가 Sync 인지 - 실행 중에 물어볼 방법이 없어 표의 타입마다 상수로 적어 둠 (테스트에서 실제 Sync 와 대조)
+fn is_sync<T: ?Sized + MaybeSync>() -> bool {
+    T::SYNC
+}
+
+// Sync 를 값으로 - 표에 나오는 타입만
+pub trait MaybeSync {
+    const SYNC: bool;
+}
+
+impl<T> MaybeSync for Cell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for RefCell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for OnceCell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T: Send> MaybeSync for Mutex<T> {
+    const SYNC: bool = true;
+}
+
+impl<T: Send + Sync> MaybeSync for OnceLock<T> {
+    const SYNC: bool = true;
+}
+
+fn main() {
+    decision_table();
+}
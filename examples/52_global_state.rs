@@ -0,0 +1,119 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 52. 내부 가변성 총정리 - 전역 상태 - static mut 대신
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::{Cell, LazyCell, OnceCell, RefCell};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, RwLock};
+use std::thread;
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub trait MaybeSync {
+    const SYNC: bool;
+}
+
+impl<T> MaybeSync for Cell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for RefCell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T: Send> MaybeSync for Mutex<T> {
+    const SYNC: bool = true;
+}
+
+impl<T: Send + Sync> MaybeSync for OnceLock<T> {
+    const SYNC: bool = true;
+}
+
+// ----------------------------------------------------------------------------
+// 전역 상태 - static mut 대신
+// ----------------------------------------------------------------------------
+// static 의 초기값은 const 여야 함 - HashMap, String 처럼 실행 중에 만드는 값은 바로 못 넣음
+// 그래서 C 처럼 static mut 에 None 을 두고 init() 에서 채우는 코드가 나옴 - 모든 접근이 unsafe, 스레드 안전도 직접
+// 대신:
+//   static X: OnceLock<T>            프로그램이 정한 값으로 한 번 (명령행 인자, 설정 파일) - 이 저장소의 profile::ACTIVE
+//   static X: LazyLock<T>            고정된 계산으로 처음 쓸 때 - 표, 정규식, 키워드 집합 (i18n::table 은 OnceLock 으로 같은 일)
+//   static X: LazyLock<Mutex<T>>     바뀌는 전역 (레지스트리, 캐시)
+//   static X: AtomicUsize             카운터, 플래그 (16장의 COUNTER)
+//   thread_local! { Cell / RefCell }  스레드마다 따로 (src/panic_hook.rs 의 panic 기록, 48장)
+
+// 예전 방식 (16장 주석의 static mut):
+//   static mut CONFIG: Option<Config> = None;
+//   unsafe fn init(c: Config) { CONFIG = Some(c); }    // 두 스레드가 동시에 부르면 데이터 레이스
+//   unsafe fn config() -> &'static Config { CONFIG.as_ref().unwrap() }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub verbose: bool,
+    pub jobs: usize,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// 한 번만 - 두 번째부터는 Err 로 거절 (누가 먼저 정했는지 모르게 덮어쓰지 않음)
+pub fn init_config(config: Config) -> Result<(), Config> {
+    CONFIG.set(config)
+}
+
+// 아무도 정하지 않았으면 기본값으로 채움
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(|| Config { verbose: false, jobs: 1 })
+}
+
+// 처음 쓸 때 한 번 만드는 읽기 전용 표
+static KEYWORDS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    HashMap::from([("fn", "item"), ("let", "statement"), ("impl", "item"), ("trait", "item")])
+});
+
+// 바뀌는 전역 - 이름 → 번호, 같은 이름은 같은 번호
+static REGISTRY: LazyLock<Mutex<HashMap<String, usize>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+pub fn register(name: &str) -> usize {
+    let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    *registry.entry(name.to_string()).or_insert_with(|| NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+fn global_state() {
+    println!("--- 전역 상태 - static mut 대신 ---");
+
+    // C++ 에서는:
+    // const Config& config() { static Config c = load(); return c; }   // magic static - C++11 부터 스레드 안전
+    // 전역 객체 생성자의 순서는 번역 단위 사이에서 정해지지 않음 - Rust 의 static 은 const 초기화라 이 문제가 없음
+
+    // 테스트가 같은 프로세스에서 여러 번 불러도 되게 - 이미 정해졌으면 그 값을 씀
+    match init_config(Config { verbose: true, jobs: 4 }) {
+        Ok(()) => println!("init_config: 처음 설정함"),
+        Err(rejected) => println!("init_config: 이미 설정됨 - 거절된 값 {:?}", rejected),
+    }
+    println!("config(): {:?}", config());
+    println!("두 번째 init_config 는 Err: {}", init_config(Config { verbose: false, jobs: 8 }).is_err());
+
+    println!("KEYWORDS[\"impl\"] = {} (표를 처음 쓸 때 만듦)", KEYWORDS["impl"]);
+
+    // 여러 스레드에서 동시에 등록 - 같은 이름은 같은 번호
+    let ids: Vec<usize> = thread::scope(|s| {
+        let handles: Vec<_> = ["alpha", "beta", "alpha"].into_iter().map(|name| s.spawn(move || register(name))).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    println!("alpha, beta, alpha 의 번호가 같은 이름끼리 같음: {}", ids[0] == ids[2] && ids[0] != ids[1]);
+
+    // 스레드마다 따로인 상태 - static 이지만 스레드마다 한 벌 (Sync 가 필요 없어 Cell 로 충분)
+    thread_local! {
+        static DEPTH: Cell<u32> = const { Cell::new(0) };
+    }
+    DEPTH.set(3);
+    let other = thread::spawn(|| DEPTH.get()).join().unwrap();
+    println!("thread_local: 이 스레드 {}, 새 스레드 {}", DEPTH.get(), other);
+}
+
+fn main() {
+    global_state();
+}
@@ -0,0 +1,144 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 52. 내부 가변성 총정리 - OnceCell 과 LazyCell - 한 번만 쓰기
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::{Cell, LazyCell, OnceCell, RefCell};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, RwLock};
+use std::thread;
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub struct Parser {
+    input: String,
+    // &self 로 파싱하면서 몇 번 불렸는지 세기 - C++ 의 mutable size_t calls
+    calls: Cell<usize>,
+}
+
+impl Parser {
+    pub fn new(input: &str) -> Parser {
+        Parser { input: input.to_string(), calls: Cell::new(0) }
+    }
+
+    pub fn words(&self) -> Vec<&str> {
+        self.calls.set(self.calls.get() + 1);
+        self.input.split_whitespace().collect()
+    }
+
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+}
+
+fn cell() {
+    println!("--- Cell ---");
+
+    // C++ 에서는:
+    // class Parser { mutable size_t calls = 0; public: auto words() const { ++calls; ... } };
+
+    let parser = Parser::new("a quick brown fox");
+    let shared = &parser;
+    shared.words();
+    shared.words();
+    println!("&Parser 로 두 번 호출: calls = {}", parser.calls());
+
+    let flag = Cell::new(false);
+    let old = flag.replace(true);
+    println!("replace: 이전 {}, 지금 {}", old, flag.get());
+
+    // Copy 가 아니어도 take / replace 로 통째로 교환
+    let name = Cell::new(String::from("first"));
+    let taken = name.take();
+    name.set(String::from("second"));
+    println!("take 로 꺼냄 {:?}, 남은 값 {:?}", taken, name.into_inner());
+
+    // 슬라이스의 각 칸을 Cell 로 - 같은 배열의 두 칸을 &로 동시에 바꾸기
+    let mut numbers = [1, 2, 3];
+    let cells = Cell::from_mut(&mut numbers[..]).as_slice_of_cells();
+    cells[0].swap(&cells[2]);
+    println!("as_slice_of_cells 로 swap: {:?}", numbers);
+
+    println!("size_of: Cell<u64> {}, RefCell<u64> {} (빌림 카운터만큼 큼)", size_of::<Cell<u64>>(), size_of::<RefCell<u64>>());
+}
+
+pub trait MaybeSync {
+    const SYNC: bool;
+}
+
+impl<T> MaybeSync for Cell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for RefCell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for OnceCell<T> {
+    const SYNC: bool = false;
+}
+
+// ----------------------------------------------------------------------------
+// OnceCell 과 LazyCell - 한 번만 쓰기
+// ----------------------------------------------------------------------------
+// OnceCell<T>: 비어 있다가 한 번 채우면 그 뒤로는 &T 만 - 채운 뒤에는 빌림 검사가 필요 없음 (RefCell 보다 단순)
+//   get_or_init(f) - 처음이면 f 로 채우고 &T, 아니면 있는 값
+//   set(v) - 이미 있으면 Err(v)
+// LazyCell<T, F>: 초기화 함수를 만들 때 함께 받아 두고 처음 역참조할 때 실행
+// 구조체 필드로 계산이 비싼 값을 필요할 때 한 번만 (메모이제이션)
+
+pub struct Document {
+    text: String,
+    // 처음 물어볼 때 계산 - 이후에는 저장된 값
+    word_count: OnceCell<usize>,
+    computed: Cell<usize>,
+}
+
+impl Document {
+    pub fn new(text: &str) -> Document {
+        Document { text: text.to_string(), word_count: OnceCell::new(), computed: Cell::new(0) }
+    }
+
+    pub fn word_count(&self) -> usize {
+        *self.word_count.get_or_init(|| {
+            self.computed.set(self.computed.get() + 1);
+            self.text.split_whitespace().count()
+        })
+    }
+
+    // 계산한 횟수 - 몇 번을 물어도 1
+    pub fn times_computed(&self) -> usize {
+        self.computed.get()
+    }
+}
+
+fn once_cell() {
+    println!("--- OnceCell 과 LazyCell ---");
+
+    // C++ 에서는:
+    // mutable std::optional<size_t> word_count_;
+    // size_t word_count() const { if (!word_count_) word_count_ = count(); return *word_count_; }
+
+    let doc = Document::new("to be or not to be");
+    println!("word_count {} {} {}, 계산 횟수 {}", doc.word_count(), doc.word_count(), doc.word_count(), doc.times_computed());
+
+    let cell: OnceCell<&str> = OnceCell::new();
+    println!("비어 있을 때 get: {:?}", cell.get());
+    println!("set 첫 번째: {:?}, 두 번째: {:?}", cell.set("first"), cell.set("second"));
+    println!("남은 값: {:?}", cell.get());
+
+    // LazyCell - 만들 때 함수를 받고, 처음 쓸 때 실행 (지역 변수용, 스레드 사이에 못 넘김)
+    let runs = Cell::new(0);
+    let table = LazyCell::new(|| {
+        runs.set(runs.get() + 1);
+        (1..=5).map(|n| n * n).collect::<Vec<u32>>()
+    });
+    println!("만든 직후 실행 횟수: {}", runs.get());
+    println!("처음 사용: {:?}, 다시 사용: {}, 실행 횟수: {}", *table, table[4], runs.get());
+}
+
+fn main() {
+    once_cell();
+}
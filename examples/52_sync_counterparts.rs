@@ -0,0 +1,140 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 52. 내부 가변성 총정리 - 스레드 안전한 짝
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cell::{Cell, LazyCell, OnceCell, RefCell};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, RwLock};
+use std::thread;
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub struct Parser {
+    input: String,
+    // &self 로 파싱하면서 몇 번 불렸는지 세기 - C++ 의 mutable size_t calls
+    calls: Cell<usize>,
+}
+
+impl Parser {
+    pub fn new(input: &str) -> Parser {
+        Parser { input: input.to_string(), calls: Cell::new(0) }
+    }
+
+    pub fn words(&self) -> Vec<&str> {
+        self.calls.set(self.calls.get() + 1);
+        self.input.split_whitespace().collect()
+    }
+
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+}
+
+fn cell() {
+    println!("--- Cell ---");
+
+    // C++ 에서는:
+    // class Parser { mutable size_t calls = 0; public: auto words() const { ++calls; ... } };
+
+    let parser = Parser::new("a quick brown fox");
+    let shared = &parser;
+    shared.words();
+    shared.words();
+    println!("&Parser 로 두 번 호출: calls = {}", parser.calls());
+
+    let flag = Cell::new(false);
+    let old = flag.replace(true);
+    println!("replace: 이전 {}, 지금 {}", old, flag.get());
+
+    // Copy 가 아니어도 take / replace 로 통째로 교환
+    let name = Cell::new(String::from("first"));
+    let taken = name.take();
+    name.set(String::from("second"));
+    println!("take 로 꺼냄 {:?}, 남은 값 {:?}", taken, name.into_inner());
+
+    // 슬라이스의 각 칸을 Cell 로 - 같은 배열의 두 칸을 &로 동시에 바꾸기
+    let mut numbers = [1, 2, 3];
+    let cells = Cell::from_mut(&mut numbers[..]).as_slice_of_cells();
+    cells[0].swap(&cells[2]);
+    println!("as_slice_of_cells 로 swap: {:?}", numbers);
+
+    println!("size_of: Cell<u64> {}, RefCell<u64> {} (빌림 카운터만큼 큼)", size_of::<Cell<u64>>(), size_of::<RefCell<u64>>());
+}
+
+pub trait MaybeSync {
+    const SYNC: bool;
+}
+
+impl<T> MaybeSync for Cell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for RefCell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for OnceCell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T: Send> MaybeSync for Mutex<T> {
+    const SYNC: bool = true;
+}
+
+impl<T: Send + Sync> MaybeSync for OnceLock<T> {
+    const SYNC: bool = true;
+}
+
+// ----------------------------------------------------------------------------
+// 스레드 안전한 짝
+// ----------------------------------------------------------------------------
+// Cell → Atomic*       정수, bool, 포인터만 - 잠금 없이 load/store/fetch_add (Ordering 은 13장)
+// RefCell → Mutex      borrow_mut 대신 lock - 겹치면 panic 대신 기다림, panic 하면 오염 (48장)
+// RefCell → RwLock     읽기 여럿 또는 쓰기 하나 - borrow / borrow_mut 과 같은 규칙, 역시 기다림
+// OnceCell → OnceLock, LazyCell → LazyLock - 동시에 초기화하려 하면 하나만 실행하고 나머지는 기다림
+// Rc<RefCell<T>> 를 스레드로 넘기려면 Arc<Mutex<T>> (또는 Arc<RwLock<T>>)
+
+fn sync_counterparts() {
+    println!("--- 스레드 안전한 짝 ---");
+
+    // C++ 에서는:
+    // std::atomic<size_t>, std::mutex + 데이터, std::shared_mutex - 다만 뮤텍스와 데이터가 따로라 잠그지 않고 만질 수 있음
+    // Rust 의 Mutex<T> 는 데이터를 안에 가짐 - lock() 없이는 꺼낼 방법이 없음
+
+    // let shared = Rc::new(RefCell::new(0));
+    // thread::spawn(move || *shared.borrow_mut() += 1);   에러: Rc<RefCell<i32>> 는 Send 가 아님
+    let total = Arc::new(Mutex::new(0u64));
+    let hits = Arc::new(AtomicUsize::new(0));
+    let settings = Arc::new(RwLock::new(String::from("light")));
+
+    let handles: Vec<_> = (1..=4u64)
+        .map(|n| {
+            let (total, hits, settings) = (Arc::clone(&total), Arc::clone(&hits), Arc::clone(&settings));
+            thread::spawn(move || {
+                *total.lock().unwrap() += n;
+                hits.fetch_add(1, Ordering::Relaxed);
+                // 읽기는 여러 스레드가 동시에
+                settings.read().unwrap().len()
+            })
+        })
+        .collect();
+    let lengths: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    *settings.write().unwrap() = String::from("dark");
+    println!("Mutex 합 {}, Atomic 횟수 {}, RwLock 읽기 {:?} → 쓰기 후 {:?}", total.lock().unwrap(), hits.load(Ordering::Relaxed), lengths, settings.read().unwrap());
+
+    // 같은 스레드에서 겹쳐 빌리면: RefCell 은 panic, Mutex 는 교착(또는 panic) - try_* 로 확인
+    let cell = RefCell::new(1);
+    let _reading = cell.borrow();
+    println!("RefCell try_borrow_mut (읽는 중): {}", cell.try_borrow_mut().is_err());
+    let lock = Mutex::new(1);
+    let _held = lock.lock().unwrap();
+    println!("Mutex try_lock (잠근 중): {}", lock.try_lock().is_err());
+}
+
+fn main() {
+    sync_counterparts();
+}
@@ -0,0 +1,112 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 53. 고급 수명 - 생략 규칙의 가장자리
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::thread;
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub struct Collector<'v, 's> {
+    out: &'v mut Vec<&'s str>,
+}
+
+impl<'v, 's> Collector<'v, 's> {
+    pub fn new(out: &'v mut Vec<&'s str>) -> Self {
+        Collector { out }
+    }
+
+    pub fn add_words(&mut self, text: &'s str) {
+        self.out.extend(text.split_whitespace());
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 생략 규칙의 가장자리
+// ----------------------------------------------------------------------------
+// 규칙 (04장): 입력 참조마다 수명 하나, 입력이 하나면 출력이 그것, &self / &mut self 가 있으면 출력은 self 의 것
+// 세 번째 규칙 때문에 다른 인자를 돌려주는 메서드는 명시해야 함
+// impl Trait 반환 (edition 2021): 타입 매개변수는 캡처하지만 수명은 명시한 것만 - + '_ 를 붙임
+//   (edition 2024 부터는 모든 수명을 자동으로 캡처하고, 빼려면 use<..> 로 적음)
+// 경로 속 수명: Parser<'_> 처럼 '_ 로 "여기에 수명이 있다" 를 보여 주는 것이 관례 (elided_lifetimes_in_paths 린트)
+// &'a dyn Trait 는 &'a (dyn Trait + 'a), Box<dyn Trait> 는 Box<dyn Trait + 'static>
+
+pub struct Settings {
+    values: HashMap<String, String>,
+}
+
+impl Settings {
+    pub fn new(pairs: &[(&str, &str)]) -> Settings {
+        Settings { values: pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect() }
+    }
+
+    // 생략하면 fn get_or(&self, key: &str, fallback: &str) -> &str 의 출력은 self 에 묶임
+    //   → fallback 을 돌려주는 줄에서 에러
+    // 두 쪽 모두 돌려줄 수 있으므로 같은 'a 로 묶음
+    pub fn get_or<'a>(&'a self, key: &str, fallback: &'a str) -> &'a str {
+        self.values.get(key).map(String::as_str).unwrap_or(fallback)
+    }
+
+    // edition 2021 에서는 적지 않은 수명을 캡처하지 못함 - self 와 prefix 를 둘 다 빌리므로 use<'_, 'p>
+    // 하나만 빌린다면 impl Iterator<Item = &str> + '_ 로 충분
+    pub fn keys_with<'p>(&self, prefix: &'p str) -> impl Iterator<Item = &str> + use<'_, 'p> {
+        self.values.keys().filter(move |k| k.starts_with(prefix)).map(String::as_str)
+    }
+}
+
+// 입력에서 빌린 토큰 - 경로에 '_ 를 적어 "입력을 빌린다" 를 시그니처에서 보이게
+pub struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let trimmed = self.rest.trim_start();
+        if trimmed.is_empty() {
+            return None;
+        }
+        let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let (token, rest) = trimmed.split_at(end);
+        self.rest = rest;
+        Some(token)
+    }
+}
+
+pub fn tokens(input: &str) -> Tokens<'_> {
+    Tokens { rest: input }
+}
+
+fn elision_edge_cases() {
+    println!("--- 생략 규칙의 가장자리 ---");
+
+    // C++ 에서는:
+    // const std::string& get_or(const std::string& key, const std::string& fallback) const;
+    // get_or("x", "임시") 가 임시 객체를 가리키는 참조를 돌려줘도 컴파일됨 - Rust 는 'a 로 fallback 의 수명을 따짐
+
+    let settings = Settings::new(&[("color", "always"), ("color.theme", "dark"), ("jobs", "4")]);
+    let fallback = String::from("auto");
+    println!("get_or(color) = {}, get_or(pager) = {}", settings.get_or("color", &fallback), settings.get_or("pager", &fallback));
+
+    let mut colors: Vec<&str> = settings.keys_with("color").collect();
+    colors.sort();
+    println!("impl Iterator + use<'_, 'p> 로 빌린 키: {:?}", colors);
+
+    let input = String::from("let x = 1 ;");
+    let collected: Vec<&str> = tokens(&input).collect();
+    println!("Tokens<'_> 가 빌린 토큰: {:?}", collected);
+
+    // &dyn Display 의 숨은 수명은 참조의 수명 - 지역 값도 됨
+    let local = 7;
+    let shown: &dyn Display = &local;
+    println!("&dyn Display (숨은 + 'a): {}", shown);
+}
+
+fn main() {
+    elision_edge_cases();
+}
@@ -0,0 +1,89 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 53. 고급 수명 - for<'a> - 고차 트레이트 바운드 (HRTB)
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::thread;
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub struct Collector<'v, 's> {
+    out: &'v mut Vec<&'s str>,
+}
+
+impl<'v, 's> Collector<'v, 's> {
+    pub fn new(out: &'v mut Vec<&'s str>) -> Self {
+        Collector { out }
+    }
+
+    pub fn add_words(&mut self, text: &'s str) {
+        self.out.extend(text.split_whitespace());
+    }
+}
+
+// ----------------------------------------------------------------------------
+// for<'a> - 고차 트레이트 바운드 (HRTB)
+// ----------------------------------------------------------------------------
+// 함수의 수명 매개변수 'x 는 호출하는 쪽이 고름 - 함수 안에서 만든 지역 값은 'x 만큼 살 수 없음
+// F: for<'a> Fn(&'a str) -> &'a str = "어떤 'a 로 불러도 같은 'a 를 돌려주는" 함수 - 고르는 쪽이 호출자에서 함수 안으로 옮겨 옴
+// Fn(&str) -> &str 라고 쓰면 생략 규칙이 for<'a> 를 붙여 줌 - 직접 쓰는 것은 참조가 아닌 타입에 수명이 걸릴 때
+
+// 안 되는 버전:
+//   fn apply_to_local<'x, F: Fn(&'x str) -> &'x str>(f: F) -> usize {
+//       let line = String::from("  local  ");
+//       f(&line).len()      // 에러: line 은 'x 만큼 살지 않음 ('x 는 호출자가 정한 더 긴 수명일 수 있음)
+//   }
+pub fn apply_to_local<F>(f: F) -> usize
+where
+    F: for<'a> Fn(&'a str) -> &'a str,
+{
+    let line = String::from("  local value  ");
+    f(&line).len()
+}
+
+// 참조가 아닌 타입에 수명이 걸리는 바운드 - &'a C 가 무엇이든 &'a i32 를 내주는 컬렉션
+pub fn sum_by_ref<C>(collection: &C) -> i32
+where
+    for<'a> &'a C: IntoIterator<Item = &'a i32>,
+{
+    collection.into_iter().sum()
+}
+
+// 클로저는 인자와 반환이 모두 참조일 때 서로 묶인 수명을 추론하지 못함 - 이렇게 바운드를 걸어 주는 함수로 감쌈
+pub fn str_map<F: for<'a> Fn(&'a str) -> &'a str>(f: F) -> F {
+    f
+}
+
+fn hrtb() {
+    println!("--- for<'a> - 고차 트레이트 바운드 ---");
+
+    // C++ 에서는:
+    // template <class F> size_t apply_to_local(F f) { std::string line = "..."; return f(line).size(); }
+    // f 가 line 을 가리키는 string_view 를 돌려줘도, 다른 것을 돌려줘도 시그니처는 같음
+
+    println!("trim 으로: {}", apply_to_local(str::trim));
+    println!("클로저로: {}", apply_to_local(|s| s.trim_start()));
+
+    let numbers = vec![1, 2, 3];
+    let fixed = [10, 20];
+    println!("Vec 합 {}, 배열 합 {}", sum_by_ref(&numbers), sum_by_ref(&fixed));
+
+    // let first_word = |s: &str| -> &str { s.split(' ').next().unwrap_or("") };
+    //   에러: 반환 수명이 인자와 다를 수 있음 (클로저는 생략 규칙이 시그니처처럼 적용되지 않음)
+    let first_word = str_map(|s| s.split(' ').next().unwrap_or(""));
+    println!("str_map 으로 감싼 클로저: {:?}", first_word("hello lifetimes"));
+
+    // dyn 에도 같은 바운드 - Box<dyn Fn(&str) -> &str> 는 Box<dyn for<'a> Fn(&'a str) -> &'a str> 와 같음
+    #[expect(clippy::type_complexity, reason = "생략된 수명이 있는 타입 자체를 보여 줌")]
+    let pipeline: Vec<Box<dyn Fn(&str) -> &str>> = vec![Box::new(str::trim), Box::new(|s| s.trim_end_matches('!'))];
+    let result = pipeline.iter().fold("  hi!!  ", |acc, f| f(acc));
+    println!("dyn for<'a> 파이프라인: {:?}", result);
+}
+
+fn main() {
+    hrtb();
+}
@@ -0,0 +1,81 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 53. 고급 수명 - &mut T 가 T 에 대해 불변인 이유
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::thread;
+
+// ----------------------------------------------------------------------------
+// &mut T 가 T 에 대해 불변인 이유
+// ----------------------------------------------------------------------------
+// 만약 &mut &'static str 를 &mut &'short str 로 줄일 수 있다면:
+//   let mut kept: &'static str = "static";
+//   {
+//       let local = String::from("local");
+//       let slot: &mut &'short str = &mut kept;   // (가정) 공변이라 허용
+//       *slot = &local;                           // 'short 자리에 'short 참조를 씀 - 타입상 문제없음
+//   }                                             // local 해제
+//   println!("{}", kept);                         // kept 는 여전히 &'static str 타입인데 해제된 local 을 가리킴
+// 읽기만 하는 &T 는 줄여도 안전하지만, 쓸 수 있는 &mut T 는 안의 타입을 그대로 지켜야 함
+
+// 슬롯에 값을 써 넣기 - 슬롯과 값의 수명이 같아야 함 ('a 가 불변 위치에 있음)
+pub fn overwrite<'a>(slot: &mut &'a str, value: &'a str) {
+    *slot = value;
+}
+
+// 수명 하나로 모든 것을 묶은 버전 - out 이 'a 동안 빌려지고, 'a 는 불변이라 Vec 의 원소 수명과 같아져야 함
+//   struct GreedyCollector<'a> { out: &'a mut Vec<&'a str> }
+//   let mut words = Vec::new();
+//   { let mut c = GreedyCollector { out: &mut words }; c.add("x"); }
+//   println!("{:?}", words);    // 에러: words 는 원소 수명 전체 동안 가변으로 빌려져 있음
+// 빌림 수명과 원소 수명을 나누면 빌림은 Collector 와 함께 끝남
+pub struct Collector<'v, 's> {
+    out: &'v mut Vec<&'s str>,
+}
+
+impl<'v, 's> Collector<'v, 's> {
+    pub fn new(out: &'v mut Vec<&'s str>) -> Self {
+        Collector { out }
+    }
+
+    pub fn add_words(&mut self, text: &'s str) {
+        self.out.extend(text.split_whitespace());
+    }
+}
+
+fn invariant_mut() {
+    println!("--- &mut T 가 T 에 대해 불변인 이유 ---");
+
+    // C++ 에서는:
+    // Derived* d; Base** pp = &d;   // 에러 - *pp = new Base 로 d 에 Base 를 넣을 수 있으므로
+    // char** → const char** 도 같은 이유로 금지 - Rust 는 같은 규칙을 수명에 적용
+
+    let mut kept: &'static str = "static";
+    let local = String::from("local");
+    // overwrite(&mut kept, &local);   에러: kept 의 타입이 &'static str 라 'a = 'static, local 은 그만큼 살지 않음
+    overwrite(&mut kept, "another static");
+    println!("'static 슬롯에는 'static 만: {}", kept);
+
+    // 슬롯의 타입을 처음부터 짧게 두면 됨 - 추론이 'a 를 local 의 수명으로 고름
+    let mut slot: &str = "static";
+    overwrite(&mut slot, &local);
+    println!("짧은 수명의 슬롯: {}", slot);
+
+    let text = String::from("borrow only what you need");
+    let mut words = Vec::new();
+    {
+        let mut collector = Collector::new(&mut words);
+        collector.add_words(&text);
+        collector.add_words("and more");
+    }
+    // collector 가 끝나면 words 의 가변 빌림도 끝남
+    println!("Collector<'v, 's> 뒤에 다시 쓸 수 있는 Vec: {} 개 {:?}", words.len(), words);
+}
+
+fn main() {
+    invariant_mut();
+}
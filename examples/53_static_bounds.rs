@@ -0,0 +1,92 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 53. 고급 수명 - T: 'static - spawn 의 조건
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::thread;
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub struct Collector<'v, 's> {
+    out: &'v mut Vec<&'s str>,
+}
+
+impl<'v, 's> Collector<'v, 's> {
+    pub fn new(out: &'v mut Vec<&'s str>) -> Self {
+        Collector { out }
+    }
+
+    pub fn add_words(&mut self, text: &'s str) {
+        self.out.extend(text.split_whitespace());
+    }
+}
+
+// ----------------------------------------------------------------------------
+// T: 'static - spawn 의 조건
+// ----------------------------------------------------------------------------
+// T: 'static 은 "T 안에 'static 보다 짧은 빌림이 없다" - String, Vec<u8>, Arc<T> 는 모두 'static (소유한 값)
+// thread::spawn(f) 는 F: Send + 'static - 새 스레드가 언제 끝날지 모르므로 지역 변수를 빌린 클로저는 거절
+// tokio::spawn 도 같은 이유로 'static - 작업이 만든 함수보다 오래 살 수 있음
+// 빌리고 싶으면: move 로 소유권을 넘기거나, Arc 로 나누거나, thread::scope 로 끝나는 시점을 묶음 (13장)
+// Box<dyn Trait> 는 Box<dyn Trait + 'static> 의 줄임 - 빌린 것을 품게 하려면 + 'a 를 씀
+
+pub fn is_static<T: 'static>(_value: &T) -> bool {
+    true
+}
+
+// 빌린 prefix 를 품은 클로저 - + 'a 가 없으면 Box<dyn Fn + 'static> 이라 prefix 를 캡처할 수 없음
+#[expect(clippy::type_complexity, reason = "반환 타입의 + 'a 를 별칭 뒤에 숨기지 않음")]
+pub fn make_labeler<'a>(prefix: &'a str) -> Box<dyn Fn(&dyn Display) -> String + 'a> {
+    Box::new(move |value| format!("{}{}", prefix, value))
+}
+
+fn static_bounds() {
+    println!("--- T: 'static - spawn 의 조건 ---");
+
+    // C++ 에서는:
+    // std::string name = "local";
+    // std::thread t([&name] { use(name); });  t.detach();   // 함수가 끝나면 name 이 사라지고 댕글링
+    // Rust 는 &name 을 캡처한 클로저를 thread::spawn 에 넘기는 것부터 컴파일 에러
+
+    let owned = String::from("owned");
+    println!("String 은 'static: {}, &'static str 도: {}", is_static(&owned), is_static(&"literal"));
+    // is_static(&&owned[..]);   에러: &owned[..] 는 owned 를 빌리므로 'static 이 아님
+
+    let name = String::from("worker");
+    // thread::spawn(|| name.len());   에러: 클로저가 name 을 빌리는데 name 이 스레드보다 먼저 끝날 수 있음
+    let moved = thread::spawn(move || name.len()).join().unwrap();
+    println!("move 로 넘긴 String: {}", moved);
+
+    let shared = Arc::new(vec![1, 2, 3]);
+    let handle = {
+        let shared = Arc::clone(&shared);
+        thread::spawn(move || shared.iter().sum::<i32>())
+    };
+    let sum = handle.join().unwrap();
+    println!("Arc 로 나눔: 합 {}, 스레드가 끝난 뒤 소유자 수 {}", sum, Arc::strong_count(&shared));
+
+    let local = vec![4, 5, 6];
+    let total = thread::scope(|s| s.spawn(|| local.iter().sum::<i32>()).join().unwrap());
+    println!("thread::scope 는 빌림 허용: 합 {}, 지역 Vec 은 그대로 {:?}", total, local);
+
+    // tokio::spawn 도 'static - 작업 안에서 쓸 것은 소유해서 넘김
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let words = [String::from("async"), String::from("task")];
+    let joined = runtime.block_on(async move {
+        // tokio::spawn(async { words.len() })   에러: async 블록이 words 를 빌림
+        tokio::spawn(async move { words.join("-") }).await.unwrap()
+    });
+    println!("tokio::spawn 에 move 한 값: {}", joined);
+
+    let prefix = String::from("value=");
+    let label = make_labeler(&prefix);
+    println!("Box<dyn Fn + 'a> 로 빌린 prefix 캡처 → {}", label(&42));
+}
+
+fn main() {
+    static_bounds();
+}
@@ -0,0 +1,79 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 53. 고급 수명 - 변성 - 긴 수명을 짧은 수명 자리에
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::thread;
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub struct Collector<'v, 's> {
+    out: &'v mut Vec<&'s str>,
+}
+
+impl<'v, 's> Collector<'v, 's> {
+    pub fn new(out: &'v mut Vec<&'s str>) -> Self {
+        Collector { out }
+    }
+
+    pub fn add_words(&mut self, text: &'s str) {
+        self.out.extend(text.split_whitespace());
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 변성 - 긴 수명을 짧은 수명 자리에
+// ----------------------------------------------------------------------------
+// 'long: 'short 이면 &'long T 를 &'short T 자리에 써도 됨 - 이것이 공변(covariant)
+// 타입 생성자마다 매개변수별로 정해져 있음:
+//   &'a T             'a 공변, T 공변
+//   &'a mut T         'a 공변, T 불변(invariant) - 다음 절
+//   Box<T>, Vec<T>    T 공변 (소유하므로 바꿔 끼울 다른 별칭이 없음)
+//   Cell<T>, RefCell<T>, UnsafeCell<T>   T 불변 (&로도 쓸 수 있으므로 &mut 과 같은 이유)
+//   fn(T) -> U        T 반공변(contravariant), U 공변
+//   *const T 공변, *mut T 불변
+// 직접 만든 타입은 필드에서 정해짐 - raw 포인터만 가진 타입은 PhantomData 로 의도를 적음 (08장, 46장)
+//   PhantomData<T> 공변, PhantomData<fn(T)> 반공변, PhantomData<fn(T) -> T> 또는 PhantomData<Cell<T>> 불변
+
+// Vec<&'static str> 를 Vec<&'a str> 로 - 공변이므로 그냥 돌려주면 됨 (복사나 변환 없음)
+pub fn shorten<'a>(names: Vec<&'static str>) -> Vec<&'a str> {
+    names
+}
+
+// 어떤 수명의 &str 이든 받는 함수 - fn(&'static str) 자리에도 쓸 수 있음 (인자는 반공변)
+fn count_chars(s: &str) -> usize {
+    s.chars().count()
+}
+
+fn variance() {
+    println!("--- 변성 - 긴 수명을 짧은 수명 자리에 ---");
+
+    // C++ 에서는:
+    // Derived* 는 Base* 로 바뀌지만 std::vector<Derived*> 는 std::vector<Base*> 가 아님 - 템플릿은 무변성
+    // Rust 의 부분 타입 관계는 수명에만 있고, 컴파일러가 제네릭 타입의 변성을 필드에서 계산
+
+    let literal: &'static str = "static";
+    let owned = String::from("local");
+    // 'static 인 literal 이 owned 의 짧은 수명에 맞춰 줄어듦
+    let picked = if owned.len() > 3 { &owned[..] } else { literal };
+    println!("짧은 쪽에 맞춘 참조: {}", picked);
+
+    let mut names = shorten(vec!["alpha", "beta"]);
+    names.push(&owned);
+    println!("Vec<&'static str> 를 줄여서 지역 참조도 담음: {:?}", names);
+
+    // 인자의 반공변 - "아무 수명이나 받는 함수" 는 "'static 만 받는 함수" 자리에 들어감
+    let only_static: fn(&'static str) -> usize = count_chars;
+    println!("fn(&str) 을 fn(&'static str) 자리에: {}", only_static("chars"));
+    // 반대는 안 됨:
+    //   fn needs_static(s: &'static str) -> usize { s.len() }
+    //   let any: for<'a> fn(&'a str) -> usize = needs_static;   에러: 짧은 수명을 받을 수 없음
+}
+
+fn main() {
+    variance();
+}
@@ -0,0 +1,208 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 54. drop 순서와 ManuallyDrop - Drop 과 panic
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::panic_hook::silently;
+use std::cell::RefCell;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::panic::AssertUnwindSafe;
+use std::rc::{Rc, Weak};
+
+// --- rust-study 의 panic_hook 모듈 ---
+mod panic_hook {
+// ============================================================================
+// panic 훅 (Panic hook)
+// ============================================================================
+// 프로세스에 하나뿐인 panic 훅을 이 저장소에서 설치하는 유일한 곳 (훅과 catch_unwind 는 48장)
+//
+//   let (result, seen) = crate::panic_hook::silently(|| v[index]);
+//   // result: Err(payload), seen: Some(Seen { message, file, line }) - 출력은 없음
+//
+// 표준 훅(또는 먼저 설치된 훅)을 감싸 panic 마다 메시지와 위치를 thread_local 에 기록
+//   - 장 실행기(src/runner.rs)는 기록만 가져가고 출력은 표준 훅 그대로
+//   - silently / silence_thread 를 쓴 스레드는 출력하지 않음 (43장의 퍼저, 48장과 54장의 예제)
+// 상태는 모두 thread_local - --parallel 이나 테스트처럼 여러 스레드가 동시에 panic 해도 섞이지 않음
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+// 훅이 본 panic 하나 - "메시지 (파일:줄)" 로 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for Seen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.message, self.file, self.line)
+    }
+}
+
+thread_local! {
+    // true 면 이 스레드의 panic 을 출력하지 않음
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+    // 이 스레드에서 마지막으로 난 panic - 훅이 쓰고 take_last 가 가져감
+    static LAST: RefCell<Option<Seen>> = const { RefCell::new(None) };
+}
+
+// panic!("..") 는 &str, panic!("{}", x) 는 String - 그 밖의 payload(panic_any)는 읽을 수 없음
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(알 수 없는 panic)"))
+}
+
+// 기존 훅을 감싸는 훅을 한 번만 설치 - 몇 번 불러도 됨
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let seen = info.location().map(|l| Seen {
+                message: message(info.payload()),
+                file: l.file().to_string(),
+                line: l.line(),
+            });
+            LAST.with(|last| *last.borrow_mut() = seen);
+            if !SILENT.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// 이 스레드에서 마지막으로 난 panic 을 가져감 (한 번만)
+pub fn take_last() -> Option<Seen> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+// f 를 실행하고 panic 을 잡음 - 출력 대신 훅이 본 것을 함께 돌려줌
+pub fn silently<R>(f: impl FnOnce() -> R + UnwindSafe) -> (thread::Result<R>, Option<Seen>) {
+    install();
+    take_last();
+    let was = SILENT.replace(true);
+    let result = panic::catch_unwind(f);
+    SILENT.set(was);
+    (result, take_last())
+}
+
+// 지금 스레드의 panic 출력을 끔 - 곧 panic 으로 끝날 스레드 안에서 (join 이 payload 를 받음)
+pub fn silence_thread() {
+    install();
+    SILENT.set(true);
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub type Log = RefCell<Vec<&'static str>>;
+
+pub struct Tracer<'a> {
+    name: &'static str,
+    log: &'a Log,
+}
+
+impl<'a> Tracer<'a> {
+    pub fn new(name: &'static str, log: &'a Log) -> Tracer<'a> {
+        Tracer { name, log }
+    }
+}
+
+impl Drop for Tracer<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+pub struct Server<'a> {
+    _listener: Tracer<'a>,
+    _workers: Tracer<'a>,
+    _config: Tracer<'a>,
+    log: &'a Log,
+}
+
+impl Drop for Server<'_> {
+    fn drop(&mut self) {
+        // 이 시점에는 세 필드가 모두 살아 있음
+        self.log.borrow_mut().push("Server::drop");
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Drop 과 panic
+// ----------------------------------------------------------------------------
+// Drop::drop 이 panic 해도 그 값의 필드, 컬렉션의 나머지 원소는 되감기 중에 drop 됨
+// 값을 만드는 중간에 panic 하면 이미 만든 필드와 지역 변수만 역순으로 drop - 반쯤 만든 구조체의 Drop 은 불리지 않음
+// 되감기 중의 Drop 이 또 panic 하면 abort - Drop 안에서는 thread::panicking() 을 확인 (48장)
+
+// drop 될 때 기록하고, explode 면 panic
+pub struct Bomb<'a> {
+    name: &'static str,
+    explode: bool,
+    log: &'a Log,
+}
+
+impl Drop for Bomb<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+        if self.explode {
+            panic!("{} 의 Drop 에서 panic", self.name);
+        }
+    }
+}
+
+// 가운데 원소의 Drop 이 panic 하는 Vec 을 버렸을 때 - 뒤 원소도 drop 되는지
+pub fn vec_with_panicking_drop() -> (bool, Vec<&'static str>) {
+    let log = Log::default();
+    let (result, _) = silently(AssertUnwindSafe(|| {
+        let bombs = vec![
+            Bomb { name: "bomb-0", explode: false, log: &log },
+            Bomb { name: "bomb-1", explode: true, log: &log },
+            Bomb { name: "bomb-2", explode: false, log: &log },
+        ];
+        drop(bombs);
+    }));
+    (result.is_err(), log.into_inner())
+}
+
+fn drop_and_panic() {
+    println!("--- Drop 과 panic ---");
+
+    // C++ 에서는:
+    // ~Bomb() noexcept(false) { throw ...; } - vector 가 원소를 파괴하다 던지면 나머지 원소의 운명은 정의되지 않음
+    // 기본 noexcept 소멸자에서 던지면 바로 std::terminate
+
+    let (panicked, log) = vec_with_panicking_drop();
+    println!("Vec drop 중 panic: {}, drop 된 원소 {:?}", panicked, log);
+
+    let log = Log::default();
+    let (result, _) = silently(AssertUnwindSafe(|| Server {
+        _listener: Tracer::new("listener", &log),
+        _workers: Tracer::new("workers", &log),
+        _config: {
+            let _port: u16 = "http".parse().expect("포트 번호가 아님");
+            Tracer::new("config", &log)
+        },
+        log: &log,
+    }));
+    // Server 는 만들어지지 않았으므로 Server::drop 은 불리지 않고, 이미 만든 필드만 정리됨
+    println!("만드는 중 panic: {}, 정리된 것 {:?}", result.is_err(), log.borrow());
+}
+
+fn main() {
+    drop_and_panic();
+}
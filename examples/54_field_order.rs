@@ -0,0 +1,182 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 54. drop 순서와 ManuallyDrop - 구조체 필드와 컬렉션
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::panic_hook::silently;
+use std::cell::RefCell;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::panic::AssertUnwindSafe;
+use std::rc::{Rc, Weak};
+
+// --- rust-study 의 panic_hook 모듈 ---
+mod panic_hook {
+// ============================================================================
+// panic 훅 (Panic hook)
+// ============================================================================
+// 프로세스에 하나뿐인 panic 훅을 이 저장소에서 설치하는 유일한 곳 (훅과 catch_unwind 는 48장)
+//
+//   let (result, seen) = crate::panic_hook::silently(|| v[index]);
+//   // result: Err(payload), seen: Some(Seen { message, file, line }) - 출력은 없음
+//
+// 표준 훅(또는 먼저 설치된 훅)을 감싸 panic 마다 메시지와 위치를 thread_local 에 기록
+//   - 장 실행기(src/runner.rs)는 기록만 가져가고 출력은 표준 훅 그대로
+//   - silently / silence_thread 를 쓴 스레드는 출력하지 않음 (43장의 퍼저, 48장과 54장의 예제)
+// 상태는 모두 thread_local - --parallel 이나 테스트처럼 여러 스레드가 동시에 panic 해도 섞이지 않음
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+// 훅이 본 panic 하나 - "메시지 (파일:줄)" 로 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for Seen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.message, self.file, self.line)
+    }
+}
+
+thread_local! {
+    // true 면 이 스레드의 panic 을 출력하지 않음
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+    // 이 스레드에서 마지막으로 난 panic - 훅이 쓰고 take_last 가 가져감
+    static LAST: RefCell<Option<Seen>> = const { RefCell::new(None) };
+}
+
+// panic!("..") 는 &str, panic!("{}", x) 는 String - 그 밖의 payload(panic_any)는 읽을 수 없음
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(알 수 없는 panic)"))
+}
+
+// 기존 훅을 감싸는 훅을 한 번만 설치 - 몇 번 불러도 됨
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let seen = info.location().map(|l| Seen {
+                message: message(info.payload()),
+                file: l.file().to_string(),
+                line: l.line(),
+            });
+            LAST.with(|last| *last.borrow_mut() = seen);
+            if !SILENT.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// 이 스레드에서 마지막으로 난 panic 을 가져감 (한 번만)
+pub fn take_last() -> Option<Seen> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+// f 를 실행하고 panic 을 잡음 - 출력 대신 훅이 본 것을 함께 돌려줌
+pub fn silently<R>(f: impl FnOnce() -> R + UnwindSafe) -> (thread::Result<R>, Option<Seen>) {
+    install();
+    take_last();
+    let was = SILENT.replace(true);
+    let result = panic::catch_unwind(f);
+    SILENT.set(was);
+    (result, take_last())
+}
+
+// 지금 스레드의 panic 출력을 끔 - 곧 panic 으로 끝날 스레드 안에서 (join 이 payload 를 받음)
+pub fn silence_thread() {
+    install();
+    SILENT.set(true);
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub type Log = RefCell<Vec<&'static str>>;
+
+pub struct Tracer<'a> {
+    name: &'static str,
+    log: &'a Log,
+}
+
+impl<'a> Tracer<'a> {
+    pub fn new(name: &'static str, log: &'a Log) -> Tracer<'a> {
+        Tracer { name, log }
+    }
+}
+
+impl Drop for Tracer<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 구조체 필드와 컬렉션
+// ----------------------------------------------------------------------------
+// 구조체의 drop: ① 자신의 Drop::drop (필드가 모두 살아 있음) ② 필드를 선언 순서대로
+// 튜플, 배열, Vec, enum 의 필드도 앞에서부터
+// 순서가 중요하면 필드를 그 순서로 선언하거나, Drop 에서 직접 정리 (34장의 ThreadPool 은 Drop 에서 큐를 닫고 join)
+// Drop 을 구현한 타입에서는 필드를 밖으로 옮길 수 없음 (E0509) - Option::take 나 mem::replace 로 꺼냄
+
+// 필드는 drop 순서를 보이려고만 있음 - 읽지 않으므로 _ 로 시작
+pub struct Server<'a> {
+    _listener: Tracer<'a>,
+    _workers: Tracer<'a>,
+    _config: Tracer<'a>,
+    log: &'a Log,
+}
+
+impl Drop for Server<'_> {
+    fn drop(&mut self) {
+        // 이 시점에는 세 필드가 모두 살아 있음
+        self.log.borrow_mut().push("Server::drop");
+    }
+}
+
+// Server, 튜플, Vec 을 차례로 버린 순서
+pub fn field_drop_order() -> Vec<&'static str> {
+    let log = Log::default();
+    drop(Server {
+        _listener: Tracer::new("listener", &log),
+        _workers: Tracer::new("workers", &log),
+        _config: Tracer::new("config", &log),
+        log: &log,
+    });
+    drop((Tracer::new("tuple.0", &log), Tracer::new("tuple.1", &log)));
+    drop(vec![Tracer::new("vec[0]", &log), Tracer::new("vec[1]", &log)]);
+    log.into_inner()
+}
+
+fn field_order() {
+    println!("--- 구조체 필드와 컬렉션 ---");
+
+    // C++ 에서는:
+    // struct Server { Listener listener; Workers workers; Config config; };
+    // ~Server() 본문 → config → workers → listener (선언의 역순)
+    // std::vector 원소의 파괴 순서는 표준이 정하지 않음 (구현은 대개 앞에서부터)
+
+    println!("drop 순서: {:?}", field_drop_order());
+    println!("  C++ 였다면 listener 와 config 의 순서가 반대");
+}
+
+fn main() {
+    field_order();
+}
@@ -0,0 +1,232 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 54. drop 순서와 ManuallyDrop - mem::forget 과 안전한 누수
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::panic_hook::silently;
+use std::cell::RefCell;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::panic::AssertUnwindSafe;
+use std::rc::{Rc, Weak};
+
+// --- rust-study 의 panic_hook 모듈 ---
+mod panic_hook {
+// ============================================================================
+// panic 훅 (Panic hook)
+// ============================================================================
+// 프로세스에 하나뿐인 panic 훅을 이 저장소에서 설치하는 유일한 곳 (훅과 catch_unwind 는 48장)
+//
+//   let (result, seen) = crate::panic_hook::silently(|| v[index]);
+//   // result: Err(payload), seen: Some(Seen { message, file, line }) - 출력은 없음
+//
+// 표준 훅(또는 먼저 설치된 훅)을 감싸 panic 마다 메시지와 위치를 thread_local 에 기록
+//   - 장 실행기(src/runner.rs)는 기록만 가져가고 출력은 표준 훅 그대로
+//   - silently / silence_thread 를 쓴 스레드는 출력하지 않음 (43장의 퍼저, 48장과 54장의 예제)
+// 상태는 모두 thread_local - --parallel 이나 테스트처럼 여러 스레드가 동시에 panic 해도 섞이지 않음
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+// 훅이 본 panic 하나 - "메시지 (파일:줄)" 로 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for Seen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.message, self.file, self.line)
+    }
+}
+
+thread_local! {
+    // true 면 이 스레드의 panic 을 출력하지 않음
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+    // 이 스레드에서 마지막으로 난 panic - 훅이 쓰고 take_last 가 가져감
+    static LAST: RefCell<Option<Seen>> = const { RefCell::new(None) };
+}
+
+// panic!("..") 는 &str, panic!("{}", x) 는 String - 그 밖의 payload(panic_any)는 읽을 수 없음
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(알 수 없는 panic)"))
+}
+
+// 기존 훅을 감싸는 훅을 한 번만 설치 - 몇 번 불러도 됨
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let seen = info.location().map(|l| Seen {
+                message: message(info.payload()),
+                file: l.file().to_string(),
+                line: l.line(),
+            });
+            LAST.with(|last| *last.borrow_mut() = seen);
+            if !SILENT.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// 이 스레드에서 마지막으로 난 panic 을 가져감 (한 번만)
+pub fn take_last() -> Option<Seen> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+// f 를 실행하고 panic 을 잡음 - 출력 대신 훅이 본 것을 함께 돌려줌
+pub fn silently<R>(f: impl FnOnce() -> R + UnwindSafe) -> (thread::Result<R>, Option<Seen>) {
+    install();
+    take_last();
+    let was = SILENT.replace(true);
+    let result = panic::catch_unwind(f);
+    SILENT.set(was);
+    (result, take_last())
+}
+
+// 지금 스레드의 panic 출력을 끔 - 곧 panic 으로 끝날 스레드 안에서 (join 이 payload 를 받음)
+pub fn silence_thread() {
+    install();
+    SILENT.set(true);
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub type Log = RefCell<Vec<&'static str>>;
+
+pub struct Tracer<'a> {
+    name: &'static str,
+    log: &'a Log,
+}
+
+impl<'a> Tracer<'a> {
+    pub fn new(name: &'static str, log: &'a Log) -> Tracer<'a> {
+        Tracer { name, log }
+    }
+}
+
+impl Drop for Tracer<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+pub struct Rollback<'a, T> {
+    vec: &'a mut Vec<T>,
+    len: usize,
+    committed: bool,
+}
+
+impl<'a, T> Rollback<'a, T> {
+    pub fn new(vec: &'a mut Vec<T>) -> Rollback<'a, T> {
+        let len = vec.len();
+        Rollback { vec, len, committed: false }
+    }
+
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<T> Deref for Rollback<'_, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> DerefMut for Rollback<'_, T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> Drop for Rollback<'_, T> {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.vec.truncate(self.len);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// mem::forget 과 안전한 누수
+// ----------------------------------------------------------------------------
+// mem::forget(x) = ManuallyDrop::new(x) 를 버림 - unsafe 가 아님
+// 안전한 코드로도 누수는 가능 (Rc 순환, Box::leak, 끝나지 않는 스레드) - 그래서 Rust 는 "누수는 안전" 으로 정함
+// 결과: Drop 이 반드시 불린다고 믿는 안전 API 는 만들 수 없음
+//   옛 thread::scoped 는 돌려준 가드의 Drop 에서 join - forget 하면 빌린 값이 사라진 뒤에도 스레드가 돌아 제거됨
+//   지금의 thread::scope 는 클로저가 끝날 때 join 하므로 forget 할 가드가 없음 (13장)
+//   Vec::drain 은 시작할 때 len 을 줄여 둠 - drain 을 forget 해도 Vec 이 drop 된 원소를 보지 않음 (대신 원소가 샘)
+// 일부러 쓰는 곳: 초기화를 마친 가드를 끌 때 (46장의 try_init_array), 소유권을 C 에 넘길 때 (into_raw 류가 더 나음)
+
+// 서로를 가리키는 두 노드 - strong 이면 순환이라 drop 되지 않음, 되돌아가는 쪽을 Weak 로
+pub struct Node<'a> {
+    _tracer: Tracer<'a>,
+    next: RefCell<Option<Rc<Node<'a>>>>,
+    back: RefCell<Weak<Node<'a>>>,
+}
+
+impl<'a> Node<'a> {
+    pub fn new(name: &'static str, log: &'a Log) -> Rc<Node<'a>> {
+        Rc::new(Node { _tracer: Tracer::new(name, log), next: RefCell::new(None), back: RefCell::new(Weak::new()) })
+    }
+}
+
+fn forget_and_leak() {
+    println!("--- mem::forget 과 안전한 누수 ---");
+
+    // C++ 에서는:
+    // new T 를 delete 하지 않거나 shared_ptr 순환 - 누수는 C++ 에서도 "정의된 동작"
+    // forget 에 해당하는 표준 함수는 없음 - new (buf) T(...) 로 만들고 소멸자를 부르지 않는 것이 가장 가까움
+
+    let log = Log::default();
+    mem::forget(Tracer::new("forgotten", &log));
+    println!("forget 뒤 기록: {:?}", log.borrow());
+
+    // 프로그램이 끝날 때까지 쓸 값을 &'static 으로 - 설정처럼 한 번 만들고 놓지 않는 것
+    let banner: &'static str = Box::leak(String::from("leaked banner").into_boxed_str());
+    println!("Box::leak 로 얻은 &'static str: {}", banner);
+
+    let log = Log::default();
+    {
+        let first = Node::new("cycle-a", &log);
+        let second = Node::new("cycle-b", &log);
+        *first.next.borrow_mut() = Some(Rc::clone(&second));
+        *second.next.borrow_mut() = Some(Rc::clone(&first));
+    }
+    println!("strong 순환: {:?} (아무것도 drop 되지 않음)", log.borrow());
+    {
+        let first = Node::new("parent", &log);
+        let second = Node::new("child", &log);
+        *first.next.borrow_mut() = Some(Rc::clone(&second));
+        *second.back.borrow_mut() = Rc::downgrade(&first);
+    }
+    println!("되돌아가는 쪽을 Weak 로: {:?}", log.borrow());
+
+    let mut numbers = vec![1, 2, 3, 4, 5];
+    mem::forget(numbers.drain(1..3));
+    println!("drain 을 forget 한 Vec: {:?} (앞부분만 남고 나머지는 샘)", numbers);
+}
+
+fn main() {
+    forget_and_leak();
+}
@@ -0,0 +1,220 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 54. drop 순서와 ManuallyDrop - 지역 변수와 임시 값
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::panic_hook::silently;
+use std::cell::RefCell;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::panic::AssertUnwindSafe;
+use std::rc::{Rc, Weak};
+
+// --- rust-study 의 panic_hook 모듈 ---
+mod panic_hook {
+// ============================================================================
+// panic 훅 (Panic hook)
+// ============================================================================
+// 프로세스에 하나뿐인 panic 훅을 이 저장소에서 설치하는 유일한 곳 (훅과 catch_unwind 는 48장)
+//
+//   let (result, seen) = crate::panic_hook::silently(|| v[index]);
+//   // result: Err(payload), seen: Some(Seen { message, file, line }) - 출력은 없음
+//
+// 표준 훅(또는 먼저 설치된 훅)을 감싸 panic 마다 메시지와 위치를 thread_local 에 기록
+//   - 장 실행기(src/runner.rs)는 기록만 가져가고 출력은 표준 훅 그대로
+//   - silently / silence_thread 를 쓴 스레드는 출력하지 않음 (43장의 퍼저, 48장과 54장의 예제)
+// 상태는 모두 thread_local - --parallel 이나 테스트처럼 여러 스레드가 동시에 panic 해도 섞이지 않음
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+// 훅이 본 panic 하나 - "메시지 (파일:줄)" 로 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for Seen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.message, self.file, self.line)
+    }
+}
+
+thread_local! {
+    // true 면 이 스레드의 panic 을 출력하지 않음
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+    // 이 스레드에서 마지막으로 난 panic - 훅이 쓰고 take_last 가 가져감
+    static LAST: RefCell<Option<Seen>> = const { RefCell::new(None) };
+}
+
+// panic!("..") 는 &str, panic!("{}", x) 는 String - 그 밖의 payload(panic_any)는 읽을 수 없음
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(알 수 없는 panic)"))
+}
+
+// 기존 훅을 감싸는 훅을 한 번만 설치 - 몇 번 불러도 됨
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let seen = info.location().map(|l| Seen {
+                message: message(info.payload()),
+                file: l.file().to_string(),
+                line: l.line(),
+            });
+            LAST.with(|last| *last.borrow_mut() = seen);
+            if !SILENT.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// 이 스레드에서 마지막으로 난 panic 을 가져감 (한 번만)
+pub fn take_last() -> Option<Seen> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+// f 를 실행하고 panic 을 잡음 - 출력 대신 훅이 본 것을 함께 돌려줌
+pub fn silently<R>(f: impl FnOnce() -> R + UnwindSafe) -> (thread::Result<R>, Option<Seen>) {
+    install();
+    take_last();
+    let was = SILENT.replace(true);
+    let result = panic::catch_unwind(f);
+    SILENT.set(was);
+    (result, take_last())
+}
+
+// 지금 스레드의 panic 출력을 끔 - 곧 panic 으로 끝날 스레드 안에서 (join 이 payload 를 받음)
+pub fn silence_thread() {
+    install();
+    SILENT.set(true);
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub type Log = RefCell<Vec<&'static str>>;
+
+pub struct Tracer<'a> {
+    name: &'static str,
+    log: &'a Log,
+}
+
+impl<'a> Tracer<'a> {
+    pub fn new(name: &'static str, log: &'a Log) -> Tracer<'a> {
+        Tracer { name, log }
+    }
+}
+
+impl Drop for Tracer<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+pub struct Rollback<'a, T> {
+    vec: &'a mut Vec<T>,
+    len: usize,
+    committed: bool,
+}
+
+impl<'a, T> Rollback<'a, T> {
+    pub fn new(vec: &'a mut Vec<T>) -> Rollback<'a, T> {
+        let len = vec.len();
+        Rollback { vec, len, committed: false }
+    }
+
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<T> Deref for Rollback<'_, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> DerefMut for Rollback<'_, T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> Drop for Rollback<'_, T> {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.vec.truncate(self.len);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 지역 변수와 임시 값
+// ----------------------------------------------------------------------------
+// 지역 변수: 스코프 끝에서 선언의 반대 순서 - 뒤에 만든 것이 앞의 것을 빌릴 수 있으므로
+// 가린(shadowing) 변수도 이름만 가려질 뿐 스코프 끝까지 살아 있음
+// let _ = 식  → 식이 임시 값이면 바로 drop (이름이 없으므로), 이미 있는 변수면 아무 일도 없음 (옮기지도 않음)
+// let _x = 식 → 스코프 끝까지 - 가드에 _ 를 쓰면 바로 풀려 버리는 흔한 실수
+// 임시 값: 그 문장(;)이 끝날 때 - 단 let x = &임시 는 x 의 스코프까지 늘어남
+// match 와 if let 의 대상 식에서 나온 임시 값은 match 전체가 끝날 때까지 - mutex.lock() 을 대상에 쓰면 팔 안에서도 잠겨 있음
+// 블록의 꼬리 식(; 없는 마지막 식)의 임시 값은 블록의 지역 변수보다도 늦게 drop - 아래 match 를 맨 끝에 두면 scrutinee 가 a 뒤로 감
+// (edition 2024 부터 if let 의 임시 값은 else 전에, 꼬리 식의 임시 값은 지역 변수보다 먼저 drop - 이 저장소는 2021)
+
+fn consume(_value: Tracer<'_>) {
+    // 여기서 끝 - 소유권을 받은 쪽이 drop
+}
+
+// 아래 블록에서 drop 된 순서
+pub fn local_drop_order() -> Vec<&'static str> {
+    let log = Log::default();
+    {
+        let _a = Tracer::new("a", &log);
+        let _b = Tracer::new("b", &log);
+        let _extended = &Tracer::new("extended", &log);
+        let _ = Tracer::new("underscore", &log);
+        let _shadow = Tracer::new("shadow-1", &log);
+        let _shadow = Tracer::new("shadow-2", &log);
+        match Tracer::new("scrutinee", &log).name.len() {
+            0 => {}
+            _ => log.borrow_mut().push("match-arm"),
+        }
+        consume(Tracer::new("moved", &log));
+        let _len = Tracer::new("temporary", &log).name.len();
+    }
+    log.into_inner()
+}
+
+fn local_order() {
+    println!("--- 지역 변수와 임시 값 ---");
+
+    // C++ 에서는:
+    // 지역 변수는 같은 역순, 임시 객체는 완전식(full-expression) 끝에서 파괴
+    // const T& r = T{}; 처럼 const 참조에 묶으면 수명 연장 - Rust 의 let r = &T::new() 와 같음
+    // auto lock = std::lock_guard(m); 대신 std::lock_guard(m); 라고 쓰면 바로 풀림 - Rust 의 let _ = 와 같은 함정
+
+    println!("drop 순서: {:?}", local_drop_order());
+    println!("  underscore, moved, temporary 는 그 문장에서 / scrutinee 는 match-arm 뒤 / 나머지는 스코프 끝에서 역순");
+}
+
+fn main() {
+    local_order();
+}
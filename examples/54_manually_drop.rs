@@ -0,0 +1,222 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 54. drop 순서와 ManuallyDrop - ManuallyDrop - drop 을 직접
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::panic_hook::silently;
+use std::cell::RefCell;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::panic::AssertUnwindSafe;
+use std::rc::{Rc, Weak};
+
+// --- rust-study 의 panic_hook 모듈 ---
+mod panic_hook {
+// ============================================================================
+// panic 훅 (Panic hook)
+// ============================================================================
+// 프로세스에 하나뿐인 panic 훅을 이 저장소에서 설치하는 유일한 곳 (훅과 catch_unwind 는 48장)
+//
+//   let (result, seen) = crate::panic_hook::silently(|| v[index]);
+//   // result: Err(payload), seen: Some(Seen { message, file, line }) - 출력은 없음
+//
+// 표준 훅(또는 먼저 설치된 훅)을 감싸 panic 마다 메시지와 위치를 thread_local 에 기록
+//   - 장 실행기(src/runner.rs)는 기록만 가져가고 출력은 표준 훅 그대로
+//   - silently / silence_thread 를 쓴 스레드는 출력하지 않음 (43장의 퍼저, 48장과 54장의 예제)
+// 상태는 모두 thread_local - --parallel 이나 테스트처럼 여러 스레드가 동시에 panic 해도 섞이지 않음
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+// 훅이 본 panic 하나 - "메시지 (파일:줄)" 로 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for Seen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.message, self.file, self.line)
+    }
+}
+
+thread_local! {
+    // true 면 이 스레드의 panic 을 출력하지 않음
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+    // 이 스레드에서 마지막으로 난 panic - 훅이 쓰고 take_last 가 가져감
+    static LAST: RefCell<Option<Seen>> = const { RefCell::new(None) };
+}
+
+// panic!("..") 는 &str, panic!("{}", x) 는 String - 그 밖의 payload(panic_any)는 읽을 수 없음
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(알 수 없는 panic)"))
+}
+
+// 기존 훅을 감싸는 훅을 한 번만 설치 - 몇 번 불러도 됨
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let seen = info.location().map(|l| Seen {
+                message: message(info.payload()),
+                file: l.file().to_string(),
+                line: l.line(),
+            });
+            LAST.with(|last| *last.borrow_mut() = seen);
+            if !SILENT.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// 이 스레드에서 마지막으로 난 panic 을 가져감 (한 번만)
+pub fn take_last() -> Option<Seen> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+// f 를 실행하고 panic 을 잡음 - 출력 대신 훅이 본 것을 함께 돌려줌
+pub fn silently<R>(f: impl FnOnce() -> R + UnwindSafe) -> (thread::Result<R>, Option<Seen>) {
+    install();
+    take_last();
+    let was = SILENT.replace(true);
+    let result = panic::catch_unwind(f);
+    SILENT.set(was);
+    (result, take_last())
+}
+
+// 지금 스레드의 panic 출력을 끔 - 곧 panic 으로 끝날 스레드 안에서 (join 이 payload 를 받음)
+pub fn silence_thread() {
+    install();
+    SILENT.set(true);
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub type Log = RefCell<Vec<&'static str>>;
+
+pub struct Tracer<'a> {
+    name: &'static str,
+    log: &'a Log,
+}
+
+impl<'a> Tracer<'a> {
+    pub fn new(name: &'static str, log: &'a Log) -> Tracer<'a> {
+        Tracer { name, log }
+    }
+}
+
+impl Drop for Tracer<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+pub struct Rollback<'a, T> {
+    vec: &'a mut Vec<T>,
+    len: usize,
+    committed: bool,
+}
+
+impl<'a, T> Rollback<'a, T> {
+    pub fn new(vec: &'a mut Vec<T>) -> Rollback<'a, T> {
+        let len = vec.len();
+        Rollback { vec, len, committed: false }
+    }
+
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<T> Deref for Rollback<'_, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> DerefMut for Rollback<'_, T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> Drop for Rollback<'_, T> {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.vec.truncate(self.len);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ManuallyDrop - drop 을 직접
+// ----------------------------------------------------------------------------
+// ManuallyDrop<T> 는 T 를 감싸 자동 drop 을 끔 - 크기와 배치는 T 그대로 (#[repr(transparent)])
+//   ManuallyDrop::into_inner(md)         다시 보통 값으로 (이후 평소처럼 drop)
+//   unsafe ManuallyDrop::drop(&mut md)   제자리에서 drop - 두 번 부르거나 이후에 쓰면 UB
+//   unsafe ManuallyDrop::take(&mut md)   값을 꺼냄 - 이후 md 를 쓰면 UB
+// 쓰는 곳: 선언 순서와 다른 drop 순서, union 필드 (47장), Vec 을 raw 부분으로 나눌 때 (16장의 into_raw_parts)
+// 순서만 바꾸려면 먼저 필드 순서를 바꾸거나 Option 과 take 를 생각 - ManuallyDrop 은 unsafe 가 따라옴
+
+// texture 는 context 로 만든 것이라 먼저 버려야 함 - 그런데 필드 순서는 읽기 좋게 context 를 앞에 둠
+pub struct Renderer<'a> {
+    context: ManuallyDrop<Tracer<'a>>,
+    texture: ManuallyDrop<Tracer<'a>>,
+}
+
+impl Drop for Renderer<'_> {
+    fn drop(&mut self) {
+        // SAFETY: 두 필드를 여기서 한 번씩만 drop 하고 이후에 쓰지 않음 (필드의 자동 drop 은 ManuallyDrop 이 막음)
+        unsafe {
+            ManuallyDrop::drop(&mut self.texture);
+            ManuallyDrop::drop(&mut self.context);
+        }
+    }
+}
+
+fn manually_drop() {
+    println!("--- ManuallyDrop - drop 을 직접 ---");
+
+    // C++ 에서는:
+    // union { T value; };  또는  alignas(T) std::byte buf[sizeof(T)];  + 직접 value.~T() 호출
+    // Rust 의 ManuallyDrop 은 값은 늘 초기화되어 있고 "자동 소멸만 끈" 상태 - 초기화까지 미루려면 MaybeUninit (46장)
+
+    let log = Log::default();
+    drop(Renderer {
+        context: ManuallyDrop::new(Tracer::new("context", &log)),
+        texture: ManuallyDrop::new(Tracer::new("texture", &log)),
+    });
+    println!("Renderer: {:?} (선언은 context 가 먼저)", log.borrow());
+
+    let log = Log::default();
+    {
+        let _kept = ManuallyDrop::new(Tracer::new("never", &log));
+        let released = ManuallyDrop::new(Tracer::new("released", &log));
+        let _back = ManuallyDrop::into_inner(released);
+    }
+    println!("스코프를 나간 뒤: {:?} (never 는 drop 되지 않음 - 누수)", log.borrow());
+    println!("size_of: Tracer {}, ManuallyDrop<Tracer> {}", size_of::<Tracer>(), size_of::<ManuallyDrop<Tracer>>());
+}
+
+fn main() {
+    manually_drop();
+}
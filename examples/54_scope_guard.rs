@@ -0,0 +1,226 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 54. drop 순서와 ManuallyDrop - 스코프 가드 - defer
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use crate::panic_hook::silently;
+use std::cell::RefCell;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::panic::AssertUnwindSafe;
+use std::rc::{Rc, Weak};
+
+// --- rust-study 의 panic_hook 모듈 ---
+mod panic_hook {
+// ============================================================================
+// panic 훅 (Panic hook)
+// ============================================================================
+// 프로세스에 하나뿐인 panic 훅을 이 저장소에서 설치하는 유일한 곳 (훅과 catch_unwind 는 48장)
+//
+//   let (result, seen) = crate::panic_hook::silently(|| v[index]);
+//   // result: Err(payload), seen: Some(Seen { message, file, line }) - 출력은 없음
+//
+// 표준 훅(또는 먼저 설치된 훅)을 감싸 panic 마다 메시지와 위치를 thread_local 에 기록
+//   - 장 실행기(src/runner.rs)는 기록만 가져가고 출력은 표준 훅 그대로
+//   - silently / silence_thread 를 쓴 스레드는 출력하지 않음 (43장의 퍼저, 48장과 54장의 예제)
+// 상태는 모두 thread_local - --parallel 이나 테스트처럼 여러 스레드가 동시에 panic 해도 섞이지 않음
+//
+// 표준 라이브러리만 사용 - 절 예제(examples/)에 이 파일이 그대로 들어감
+// ============================================================================
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, UnwindSafe};
+use std::sync::Once;
+use std::thread;
+
+// 훅이 본 panic 하나 - "메시지 (파일:줄)" 로 출력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seen {
+    pub message: String,
+    pub file: String,
+    pub line: u32,
+}
+
+impl fmt::Display for Seen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}:{})", self.message, self.file, self.line)
+    }
+}
+
+thread_local! {
+    // true 면 이 스레드의 panic 을 출력하지 않음
+    static SILENT: Cell<bool> = const { Cell::new(false) };
+    // 이 스레드에서 마지막으로 난 panic - 훅이 쓰고 take_last 가 가져감
+    static LAST: RefCell<Option<Seen>> = const { RefCell::new(None) };
+}
+
+// panic!("..") 는 &str, panic!("{}", x) 는 String - 그 밖의 payload(panic_any)는 읽을 수 없음
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("(알 수 없는 panic)"))
+}
+
+// 기존 훅을 감싸는 훅을 한 번만 설치 - 몇 번 불러도 됨
+pub fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let seen = info.location().map(|l| Seen {
+                message: message(info.payload()),
+                file: l.file().to_string(),
+                line: l.line(),
+            });
+            LAST.with(|last| *last.borrow_mut() = seen);
+            if !SILENT.get() {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// 이 스레드에서 마지막으로 난 panic 을 가져감 (한 번만)
+pub fn take_last() -> Option<Seen> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+// f 를 실행하고 panic 을 잡음 - 출력 대신 훅이 본 것을 함께 돌려줌
+pub fn silently<R>(f: impl FnOnce() -> R + UnwindSafe) -> (thread::Result<R>, Option<Seen>) {
+    install();
+    take_last();
+    let was = SILENT.replace(true);
+    let result = panic::catch_unwind(f);
+    SILENT.set(was);
+    (result, take_last())
+}
+
+// 지금 스레드의 panic 출력을 끔 - 곧 panic 으로 끝날 스레드 안에서 (join 이 payload 를 받음)
+pub fn silence_thread() {
+    install();
+    SILENT.set(true);
+}
+}
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub type Log = RefCell<Vec<&'static str>>;
+
+// ----------------------------------------------------------------------------
+// 스코프 가드 - defer
+// ----------------------------------------------------------------------------
+// 클로저를 품은 값을 만들어 두면 스코프를 어떻게 나가든 (return, ?, panic) Drop 에서 실행
+// 성공했을 때는 끄는 (commit, cancel) 방법을 함께 둠 - 46장의 try_init_array 는 mem::forget 으로 끔
+// 이름은 반드시 붙일 것 - let _ = defer(..) 는 바로 실행되고 끝남
+// crates.io 의 scopeguard 가 같은 일을 하는 크레이트 (defer! 매크로, guard_on_unwind)
+
+pub struct Defer<F: FnOnce()> {
+    action: Option<F>,
+}
+
+impl<F: FnOnce()> Defer<F> {
+    // 실행하지 않고 끔
+    pub fn cancel(mut self) {
+        self.action = None;
+    }
+}
+
+impl<F: FnOnce()> Drop for Defer<F> {
+    fn drop(&mut self) {
+        if let Some(action) = self.action.take() {
+            action();
+        }
+    }
+}
+
+pub fn defer<F: FnOnce()>(action: F) -> Defer<F> {
+    Defer { action: Some(action) }
+}
+
+// 끝까지 가면 commit, 중간에 빠져나가면 (에러든 panic 이든) 처음 길이로 되돌림
+pub struct Rollback<'a, T> {
+    vec: &'a mut Vec<T>,
+    len: usize,
+    committed: bool,
+}
+
+impl<'a, T> Rollback<'a, T> {
+    pub fn new(vec: &'a mut Vec<T>) -> Rollback<'a, T> {
+        let len = vec.len();
+        Rollback { vec, len, committed: false }
+    }
+
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<T> Deref for Rollback<'_, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> DerefMut for Rollback<'_, T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> Drop for Rollback<'_, T> {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.vec.truncate(self.len);
+        }
+    }
+}
+
+// 모두 성공해야 추가 - 하나라도 Err 면 이미 넣은 것도 없던 일로
+pub fn push_all(vec: &mut Vec<u32>, items: &[&str]) -> Result<(), std::num::ParseIntError> {
+    let mut guard = Rollback::new(vec);
+    for item in items {
+        guard.push(item.parse()?);
+    }
+    guard.commit();
+    Ok(())
+}
+
+fn scope_guard() {
+    println!("--- 스코프 가드 - defer ---");
+
+    // C++ 에서는:
+    // auto guard = gsl::finally([&] { cleanup(); });
+    // std::experimental::scope_exit / scope_fail / scope_success (Library Fundamentals TS v3)
+
+    let log = Log::default();
+    {
+        let _cleanup = defer(|| log.borrow_mut().push("deferred"));
+        let cancelled = defer(|| log.borrow_mut().push("cancelled"));
+        log.borrow_mut().push("body");
+        cancelled.cancel();
+    }
+    println!("defer: {:?}", log.borrow());
+
+    let mut numbers = vec![1];
+    println!("push_all(2, 3): {:?} → {:?}", push_all(&mut numbers, &["2", "3"]).is_ok(), numbers);
+    println!("push_all(4, x): {:?} → {:?} (4 도 되돌림)", push_all(&mut numbers, &["4", "x"]).is_ok(), numbers);
+
+    // panic 으로 빠져나가도 가드가 되돌림
+    let (result, _) = silently(AssertUnwindSafe(|| {
+        let mut guard = Rollback::new(&mut numbers);
+        guard.push(5);
+        panic!("작업 중 panic");
+    }));
+    println!("panic 뒤: {} → {:?}", result.is_err(), numbers);
+}
+
+fn main() {
+    scope_guard();
+}
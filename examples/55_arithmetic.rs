@@ -0,0 +1,111 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 55. 연산자 오버로딩 - 산술과 복합 대입
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, BitAnd, BitOr, BitOrAssign, Index, IndexMut, Mul, Neg, Not, Sub};
+
+// ----------------------------------------------------------------------------
+// 산술과 복합 대입
+// ----------------------------------------------------------------------------
+// Copy 인 작은 값은 self 로 받아도 비용이 없음 - Vec2 처럼
+// 스칼라를 왼쪽에 두는 2.0 * v 는 impl Mul<Vec2> for f64 - 고아 규칙상 Vec2 가 이 크레이트 것이라 가능
+// += 는 AddAssign 을 따로 구현 - Add 가 있다고 자동으로 생기지 않음 (C++ 와 같음)
+// iter().sum() 을 쓰려면 Sum 을 구현 - Add 와 영벡터로
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vec2 {
+    pub const fn new(x: f64, y: f64) -> Vec2 {
+        Vec2 { x, y }
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+// v * 2.0
+impl Mul<f64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, k: f64) -> Vec2 {
+        Vec2::new(self.x * k, self.y * k)
+    }
+}
+
+// 2.0 * v - 왼쪽 피연산자의 타입에 구현
+impl Mul<Vec2> for f64 {
+    type Output = Vec2;
+
+    fn mul(self, v: Vec2) -> Vec2 {
+        v * self
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sum for Vec2 {
+    fn sum<I: Iterator<Item = Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::default(), Add::add)
+    }
+}
+
+fn arithmetic() {
+    println!("--- 산술과 복합 대입 ---");
+
+    // C++ 에서는:
+    // Vec2 operator*(double k, Vec2 v) { return v * k; }   // 자유 함수로 스칼라를 왼쪽에
+    // Vec2& operator+=(const Vec2& r) { x += r.x; y += r.y; return *this; }
+
+    let a = Vec2::new(1.0, 2.0);
+    let b = Vec2::new(0.5, -1.0);
+    println!("a + b = {:?}, a - b = {:?}", a + b, a - b);
+    println!("a * 3 = {:?}, 3 * a = {:?}, -a = {:?}", a * 3.0, 3.0 * a, -a);
+
+    let mut position = Vec2::default();
+    for step in [a, b, b] {
+        position += step;
+    }
+    println!("+= 로 이동한 위치: {:?}", position);
+
+    let total: Vec2 = [a, b, -a].into_iter().sum();
+    println!("Sum 으로 더한 값: {:?}", total);
+}
+
+fn main() {
+    arithmetic();
+}
@@ -0,0 +1,95 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 55. 연산자 오버로딩 - Not 과 비트 연산
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, BitAnd, BitOr, BitOrAssign, Index, IndexMut, Mul, Neg, Not, Sub};
+
+// ----------------------------------------------------------------------------
+// Not 과 비트 연산
+// ----------------------------------------------------------------------------
+// Not 은 ! 하나로 bool 의 논리 부정과 정수의 비트 반전을 모두 맡음
+// 비트 플래그 집합에 |, &, ! 를 주면 C 의 enum 플래그처럼 쓸 수 있음 (bitflags 크레이트가 이 일을 자동으로)
+// !flags 는 정의된 비트 안에서만 뒤집기 - 쓰지 않는 비트가 켜지지 않게
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Permissions(u8);
+
+impl Permissions {
+    pub const NONE: Permissions = Permissions(0);
+    pub const READ: Permissions = Permissions(0b100);
+    pub const WRITE: Permissions = Permissions(0b010);
+    pub const EXEC: Permissions = Permissions(0b001);
+    pub const ALL: Permissions = Permissions(0b111);
+
+    pub fn contains(self, other: Permissions) -> bool {
+        self & other == other
+    }
+}
+
+impl BitOr for Permissions {
+    type Output = Permissions;
+
+    fn bitor(self, rhs: Permissions) -> Permissions {
+        Permissions(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for Permissions {
+    type Output = Permissions;
+
+    fn bitand(self, rhs: Permissions) -> Permissions {
+        Permissions(self.0 & rhs.0)
+    }
+}
+
+impl BitOrAssign for Permissions {
+    fn bitor_assign(&mut self, rhs: Permissions) {
+        self.0 |= rhs.0;
+    }
+}
+
+// 정의된 세 비트 안에서만 뒤집음
+impl Not for Permissions {
+    type Output = Permissions;
+
+    fn not(self) -> Permissions {
+        Permissions(!self.0 & Permissions::ALL.0)
+    }
+}
+
+// ls -l 처럼 rwx
+impl fmt::Display for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (flag, c) in [(Permissions::READ, 'r'), (Permissions::WRITE, 'w'), (Permissions::EXEC, 'x')] {
+            write!(f, "{}", if self.contains(flag) { c } else { '-' })?;
+        }
+        Ok(())
+    }
+}
+
+fn bit_ops() {
+    println!("--- Not 과 비트 연산 ---");
+
+    // C++ 에서는:
+    // enum class Perm : uint8_t { Read = 4, Write = 2, Exec = 1 };
+    // constexpr Perm operator|(Perm a, Perm b) { return Perm(uint8_t(a) | uint8_t(b)); }   // enum class 마다 직접
+    // ~Perm::Read 은 쓰지 않는 상위 비트까지 켬
+
+    let mut owner = Permissions::READ | Permissions::WRITE;
+    println!("READ | WRITE = {}", owner);
+    owner |= Permissions::EXEC;
+    println!("|= EXEC 뒤 = {}", owner);
+    let others = !Permissions::WRITE;
+    println!("!WRITE = {} (비트 {:03b}, 상위 비트는 그대로 0)", others, others.0);
+    println!("others 에 WRITE 가 있나: {}, READ 가 있나: {}", others.contains(Permissions::WRITE), others.contains(Permissions::READ));
+    println!("!ALL == NONE: {}, 정수의 !0u8 = {}, bool 의 !true = {}", !Permissions::ALL == Permissions::NONE, !0u8, !true);
+}
+
+fn main() {
+    bit_ops();
+}
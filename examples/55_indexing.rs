@@ -0,0 +1,100 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 55. 연산자 오버로딩 - Index 와 IndexMut
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, BitAnd, BitOr, BitOrAssign, Index, IndexMut, Mul, Neg, Not, Sub};
+
+// ----------------------------------------------------------------------------
+// Index 와 IndexMut
+// ----------------------------------------------------------------------------
+// 인덱스 타입은 usize 가 아니어도 됨 - enum, 문자열, 튜플 (마지막 절의 (행, 열))
+// 돌려주는 것은 &Output / &mut Output - 값을 만들어 돌려줄 수는 없음 (그럴 땐 get 메서드)
+// 범위를 벗어나면 panic 이 관례 - 실패를 Option 으로 돌려주는 get / get_mut 을 함께 두는 것이 std 의 모양
+// a[i] = v 에는 IndexMut 이 필요 - Index 만 있으면 읽기 전용
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+// 채널 이름으로 접근하는 색 - 잘못된 인덱스가 타입에서 막힘 (panic 할 일이 없음)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rgb([u8; 3]);
+
+impl Index<Channel> for Rgb {
+    type Output = u8;
+
+    fn index(&self, channel: Channel) -> &u8 {
+        &self.0[channel as usize]
+    }
+}
+
+impl IndexMut<Channel> for Rgb {
+    fn index_mut(&mut self, channel: Channel) -> &mut u8 {
+        &mut self.0[channel as usize]
+    }
+}
+
+// 이름으로 찾는 점수표 - 없는 이름은 HashMap 의 [] 처럼 panic, get 은 Option
+pub struct Scores {
+    entries: Vec<(String, u32)>,
+}
+
+impl Scores {
+    pub fn new() -> Scores {
+        Scores { entries: Vec::new() }
+    }
+
+    pub fn insert(&mut self, name: &str, score: u32) {
+        self.entries.push((name.to_string(), score));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&u32> {
+        self.entries.iter().find(|(n, _)| n == name).map(|(_, s)| s)
+    }
+}
+
+impl Default for Scores {
+    fn default() -> Self {
+        Scores::new()
+    }
+}
+
+impl Index<&str> for Scores {
+    type Output = u32;
+
+    fn index(&self, name: &str) -> &u32 {
+        self.get(name).unwrap_or_else(|| panic!("점수표에 없는 이름: {}", name))
+    }
+}
+
+fn indexing() {
+    println!("--- Index 와 IndexMut ---");
+
+    // C++ 에서는:
+    // uint8_t& operator[](Channel c) { return v[static_cast<size_t>(c)]; }
+    // const uint8_t& operator[](Channel c) const { ... }   // const 판과 비 const 판을 따로 - Rust 는 Index 와 IndexMut
+    // std::map::operator[] 는 없는 키를 만들어 넣음 - Rust 의 HashMap 은 panic, 넣으려면 entry
+
+    let mut color = Rgb::default();
+    color[Channel::Red] = 255;
+    color[Channel::Blue] += 128;
+    println!("Rgb: {:?}, 빨강 {}, 초록 {}", color, color[Channel::Red], color[Channel::Green]);
+
+    let mut scores = Scores::new();
+    scores.insert("ann", 91);
+    scores.insert("bob", 78);
+    println!("scores[\"ann\"] = {}, get(\"cy\") = {:?}", scores["ann"], scores.get("cy"));
+    // scores["cy"] 는 panic - 있을지 모르면 get
+}
+
+fn main() {
+    indexing();
+}
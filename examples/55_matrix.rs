@@ -0,0 +1,192 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 55. 연산자 오버로딩 - 여러 연산자를 함께 - Matrix
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, BitAnd, BitOr, BitOrAssign, Index, IndexMut, Mul, Neg, Not, Sub};
+
+// ----------------------------------------------------------------------------
+// 여러 연산자를 함께 - Matrix
+// ----------------------------------------------------------------------------
+// Vec 을 가진 타입은 Copy 가 아님 - a + b 가 a 와 b 를 소비하면 불편
+//   impl Add<&Matrix> for &Matrix   → &a + &b 로 빌려서 새 행렬
+//   impl Add for Matrix             → a + b 는 왼쪽의 저장 공간을 재사용 (+= 로 위임)
+//   impl AddAssign<&Matrix>         → a += &b 는 제자리
+// 크기가 맞지 않는 덧셈과 곱셈은 panic - 슬라이스 인덱스처럼 호출자의 버그로 봄, 확인하려면 checked_mul
+// m[(행, 열)] - 튜플 인덱스
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<i64>,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Matrix {
+        Matrix { rows, cols, data: vec![0; rows * cols] }
+    }
+
+    pub fn identity(n: usize) -> Matrix {
+        let mut m = Matrix::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = 1;
+        }
+        m
+    }
+
+    // 행마다 길이가 같아야 함
+    pub fn from_rows<const C: usize>(rows: &[[i64; C]]) -> Matrix {
+        Matrix { rows: rows.len(), cols: C, data: rows.iter().flatten().copied().collect() }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut t = Matrix::zeros(self.cols, self.rows);
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                t[(c, r)] = self[(r, c)];
+            }
+        }
+        t
+    }
+
+    // 크기가 맞지 않으면 None - 연산자 * 는 panic
+    pub fn checked_mul(&self, rhs: &Matrix) -> Option<Matrix> {
+        if self.cols != rhs.rows {
+            return None;
+        }
+        let mut out = Matrix::zeros(self.rows, rhs.cols);
+        for r in 0..self.rows {
+            for c in 0..rhs.cols {
+                out[(r, c)] = (0..self.cols).map(|k| self[(r, k)] * rhs[(k, c)]).sum();
+            }
+        }
+        Some(out)
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = i64;
+
+    fn index(&self, (r, c): (usize, usize)) -> &i64 {
+        assert!(r < self.rows && c < self.cols, "행렬 범위 밖: ({}, {}), 크기 {}x{}", r, c, self.rows, self.cols);
+        &self.data[r * self.cols + c]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut i64 {
+        assert!(r < self.rows && c < self.cols, "행렬 범위 밖: ({}, {}), 크기 {}x{}", r, c, self.rows, self.cols);
+        &mut self.data[r * self.cols + c]
+    }
+}
+
+impl AddAssign<&Matrix> for Matrix {
+    fn add_assign(&mut self, rhs: &Matrix) {
+        assert_eq!(self.shape(), rhs.shape(), "크기가 다른 행렬의 덧셈");
+        for (a, b) in self.data.iter_mut().zip(&rhs.data) {
+            *a += b;
+        }
+    }
+}
+
+// 소비하는 덧셈 - 왼쪽의 Vec 을 그대로 결과로
+impl Add<&Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn add(mut self, rhs: &Matrix) -> Matrix {
+        self += rhs;
+        self
+    }
+}
+
+// 빌리는 덧셈 - 둘 다 남겨 둠
+impl Add<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: &Matrix) -> Matrix {
+        self.clone() + rhs
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        self.checked_mul(rhs).unwrap_or_else(|| panic!("곱할 수 없는 크기: {:?} * {:?}", self.shape(), rhs.shape()))
+    }
+}
+
+// 스칼라 곱 - 양쪽 순서 모두
+impl Mul<i64> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, k: i64) -> Matrix {
+        Matrix { rows: self.rows, cols: self.cols, data: self.data.iter().map(|v| v * k).collect() }
+    }
+}
+
+impl Mul<&Matrix> for i64 {
+    type Output = Matrix;
+
+    fn mul(self, m: &Matrix) -> Matrix {
+        m * self
+    }
+}
+
+impl Neg for &Matrix {
+    type Output = Matrix;
+
+    fn neg(self) -> Matrix {
+        self * -1
+    }
+}
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for r in 0..self.rows {
+            let row: Vec<String> = (0..self.cols).map(|c| format!("{:>4}", self[(r, c)])).collect();
+            writeln!(f, "  [{}]", row.join(""))?;
+        }
+        Ok(())
+    }
+}
+
+fn matrix() {
+    println!("--- 여러 연산자를 함께 - Matrix ---");
+
+    // C++ 에서는:
+    // Matrix operator+(const Matrix&, const Matrix&);   Matrix& operator+=(const Matrix&);
+    // double& operator()(size_t r, size_t c);           // C++23 전에는 operator[] 가 인자 하나라 operator() 를 씀
+    // Matrix operator+(Matrix&& a, const Matrix& b) { a += b; return std::move(a); }   // 임시 값의 저장 공간 재사용
+
+    let a = Matrix::from_rows(&[[1, 2], [3, 4]]);
+    let b = Matrix::from_rows(&[[0, 1], [1, 0]]);
+    print!("a =\n{}", a);
+    print!("&a + &b =\n{}", &a + &b);
+    print!("&a * &b (열 바꾸기) =\n{}", &a * &b);
+    print!("-&a + &(2 * &a) =\n{}", -&a + &(2 * &a));
+
+    let mut acc = Matrix::zeros(2, 2);
+    for _ in 0..3 {
+        acc += &a;
+    }
+    println!("+= 세 번 == 3 * &a: {}", acc == 3 * &a);
+    println!("a * I == a: {}, (aᵀ)ᵀ == a: {}", &a * &Matrix::identity(2) == a, a.transpose().transpose() == a);
+
+    let tall = Matrix::from_rows(&[[1, 0, 2]]);
+    println!("2x2 * 1x3 checked_mul: {:?}", a.checked_mul(&tall).map(|m| m.shape()));
+    println!("a[(1, 0)] = {}, 1x3 의 전치 크기 {:?}", a[(1, 0)], tall.transpose().shape());
+}
+
+fn main() {
+    matrix();
+}
@@ -0,0 +1,46 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 55. 연산자 오버로딩 - C++ 연산자와 Rust 트레이트
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, BitAnd, BitOr, BitOrAssign, Index, IndexMut, Mul, Neg, Not, Sub};
+
+// ----------------------------------------------------------------------------
+// C++ 연산자와 Rust 트레이트
+// ----------------------------------------------------------------------------
+// 같은 연산자라도 Rust 는 트레이트가 정한 시그니처를 따름
+//   Add::add(self, rhs: Rhs) -> Self::Output        값으로 받고 새 값을 돌려줌
+//   AddAssign::add_assign(&mut self, rhs: Rhs)      제자리에서 바꿈, 반환값 없음 (C++ 처럼 *this 를 돌려주지 않음)
+//   Index::index(&self, idx) -> &Self::Output       참조를 돌려줌 - 없는 값은 panic (get 같은 메서드를 함께 제공)
+//   PartialOrd::partial_cmp(&self, other) -> Option<Ordering>   <, <=, >, >= 는 기본 구현
+
+fn operator_table() {
+    println!("--- C++ 연산자와 Rust 트레이트 ---");
+
+    // C++ 에서는:
+    // T operator+(const T&, const T&);  T& operator+=(const T&);  auto operator<=>(const T&) const = default;
+
+    println!("  식         C++                Rust                      비고");
+    println!("  a + b      operator+          Add                       Sub, Mul, Div, Rem 도 같은 모양");
+    println!("  a += b     operator+=         AddAssign                 &mut self, 반환값 없음");
+    println!("  -a         단항 operator-     Neg");
+    println!("  !a, ~a     operator!, ~       Not                       Rust 는 ~ 가 없음 - 정수의 !x 가 비트 반전");
+    println!("  a & b      operator&, |, ^    BitAnd, BitOr, BitXor     &=, |=, ^= 는 *Assign");
+    println!("  a << b     operator<<, >>     Shl, Shr                  스트림 출력에는 쓰지 않음 - Display");
+    println!("  a[i]       operator[]         Index, IndexMut           참조를 돌려줌, 인덱스 타입은 자유");
+    println!("  a == b     operator==         PartialEq, Eq             != 는 자동");
+    println!("  a < b      operator<=>        PartialOrd, Ord           <, <=, >, >= 는 자동");
+    println!("  *a, a->b   operator*, ->      Deref, DerefMut           12장");
+    println!("  a(x)       operator()         Fn, FnMut, FnOnce         stable 에서는 직접 구현 불가 - 클로저로");
+    println!("  a && b     operator&&, ||     없음                      단락 평가를 잃지 않도록 오버로딩 불가");
+    println!("  a = b      operator=          없음                      이동 또는 Copy, 복제는 Clone");
+    println!("  T(a)       operator T()       From, Into                암시적 변환 없음");
+}
+
+fn main() {
+    operator_table();
+}
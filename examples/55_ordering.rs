@@ -0,0 +1,169 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 55. 연산자 오버로딩 - PartialOrd 와 Ord 를 손으로
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, BitAnd, BitOr, BitOrAssign, Index, IndexMut, Mul, Neg, Not, Sub};
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vec2 {
+    pub const fn new(x: f64, y: f64) -> Vec2 {
+        Vec2 { x, y }
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<f64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, k: f64) -> Vec2 {
+        Vec2::new(self.x * k, self.y * k)
+    }
+}
+
+impl Mul<Vec2> for f64 {
+    type Output = Vec2;
+
+    fn mul(self, v: Vec2) -> Vec2 {
+        v * self
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sum for Vec2 {
+    fn sum<I: Iterator<Item = Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::default(), Add::add)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// PartialOrd 와 Ord 를 손으로
+// ----------------------------------------------------------------------------
+// derive 는 필드 선언 순서대로 사전식 비교 - 대부분 이것으로 충분
+// 손으로 쓰는 경우: 비교 순서가 필드 순서와 다를 때, 일부 필드를 무시할 때, derive 의 규칙이 맞지 않을 때
+// 규칙: Ord 를 손으로 쓰면 PartialOrd 는 Some(self.cmp(other)) 로, PartialEq 도 cmp 와 모순되지 않게
+//   (a == b 인데 cmp 가 Equal 이 아니면 BTreeMap, sort, binary_search 가 이상하게 동작 - UB 는 아님)
+// Ordering::then / then_with 로 여러 기준을 잇고, cmp::Reverse 로 거꾸로
+
+// 유의적 버전 - 1.0.0-beta 는 1.0.0 보다 앞
+// derive 하면 Option 의 None 이 Some 보다 앞이라 정식 판(None)이 시험 판보다 먼저 와 버림
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub pre: Option<String>,
+}
+
+impl Version {
+    pub fn parse(text: &str) -> Option<Version> {
+        let (numbers, pre) = match text.split_once('-') {
+            Some((numbers, pre)) => (numbers, Some(pre.to_string())),
+            None => (text, None),
+        };
+        let mut parts = numbers.split('.').map(|p| p.parse::<u32>().ok());
+        let version = Version { major: parts.next()??, minor: parts.next()??, patch: parts.next()??, pre };
+        parts.next().is_none().then_some(version)
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                // 정식 판이 시험 판보다 뒤
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        Ok(())
+    }
+}
+
+fn ordering() {
+    println!("--- PartialOrd 와 Ord 를 손으로 ---");
+
+    // C++ 에서는:
+    // auto operator<=>(const Version&) const = default;   // derive 와 같음 - 필드 순서대로
+    // std::strong_ordering operator<=>(const Version& o) const { if (auto c = major <=> o.major; c != 0) return c; ... }
+    // strong_ordering 은 Ord, partial_ordering 은 PartialOrd (f64 처럼 비교 못 하는 값이 있을 때 None)
+
+    let mut versions: Vec<Version> = ["1.0.0", "1.0.0-beta", "0.9.12", "1.0.0-alpha", "1.2.0"].iter().filter_map(|v| Version::parse(v)).collect();
+    versions.sort();
+    let sorted: Vec<String> = versions.iter().map(Version::to_string).collect();
+    println!("정렬: {:?}", sorted);
+
+    let release = Version::parse("1.0.0").unwrap();
+    let beta = Version::parse("1.0.0-beta").unwrap();
+    println!("1.0.0-beta < 1.0.0: {}, max: {}", beta < release, std::cmp::max(&beta, &release));
+
+    // 최신 판부터 - Reverse 로 감싸 정렬 키를 뒤집음
+    versions.sort_by_key(|v| std::cmp::Reverse(v.clone()));
+    println!("최신 판: {}", versions[0]);
+
+    // 부분 순서 - f64 는 NaN 이 있어 PartialOrd 만 (24장)
+    println!("1.0.partial_cmp(NaN) = {:?}", 1.0f64.partial_cmp(&f64::NAN));
+}
+
+fn main() {
+    ordering();
+}
@@ -0,0 +1,114 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 56. Display 와 Debug 서식 깊이 보기 - Formatter 의 플래그를 지키는 Display
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+// ----------------------------------------------------------------------------
+// Formatter 의 플래그를 지키는 Display
+// ----------------------------------------------------------------------------
+// write!(f, "{}", x) 는 f 의 너비와 정렬을 무시 - 새 서식 문자열이라 {:>10} 이 사라짐
+// 가장 쉬운 방법: 글자를 만든 뒤 f.pad(&s) - 너비, 정렬, 채움, 정밀도(자르기)를 모두 적용
+//   숫자처럼 부호와 0 채움까지 지키려면 f.pad_integral(양수인가, 접두사, 숫자)
+// 정밀도를 "소수 자릿수" 처럼 다른 뜻으로 쓸 수도 있음 - f.precision() 을 읽어 직접 처리
+// f.alternate() 로 {:#} 에 다른 모양을 - 표준의 {:#x}, {:#?} 처럼
+
+// 섭씨 온도 - 정밀도는 소수 자릿수 (기본 1), 너비와 정렬은 pad 로
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Celsius(pub f64);
+
+impl fmt::Display for Celsius {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = f.precision().unwrap_or(1);
+        let text = if f.sign_plus() { format!("{:+.*}°C", digits, self.0) } else { format!("{:.*}°C", digits, self.0) };
+        // f.pad 는 정밀도를 "최대 글자 수" 로 보고 잘라 버리므로 너비와 정렬만 직접 적용
+        write_padded(f, &text)
+    }
+}
+
+// f 의 너비, 채움, 정렬만 적용해 text 를 씀 (정밀도는 무시)
+fn write_padded(f: &mut fmt::Formatter, text: &str) -> fmt::Result {
+    let len = text.chars().count();
+    let width = f.width().unwrap_or(0);
+    if len >= width {
+        return f.write_str(text);
+    }
+    let gap = width - len;
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) | None => (0, gap),
+        Some(fmt::Alignment::Right) => (gap, 0),
+        Some(fmt::Alignment::Center) => (gap / 2, gap - gap / 2),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+// 너비를 지키지 않는 흔한 구현 - 비교용
+pub struct NaiveTag(pub &'static str);
+
+impl fmt::Display for NaiveTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{}>", self.0)
+    }
+}
+
+// pad 로 너비를 지키는 구현
+pub struct Tag(pub &'static str);
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&format!("<{}>", self.0))
+    }
+}
+
+// 걸린 시간 - {} 는 1h 2m 3s, {:#} 는 01:02:03
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(pub u64);
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (h, m, s) = (self.0 / 3600, self.0 / 60 % 60, self.0 % 60);
+        let text = if f.alternate() {
+            format!("{:02}:{:02}:{:02}", h, m, s)
+        } else if h > 0 {
+            format!("{}h {}m {}s", h, m, s)
+        } else if m > 0 {
+            format!("{}m {}s", m, s)
+        } else {
+            format!("{}s", s)
+        };
+        f.pad(&text)
+    }
+}
+
+fn custom_display() {
+    println!("--- Formatter 의 플래그를 지키는 Display ---");
+
+    // C++ 에서는:
+    // std::ostream& operator<<(std::ostream& os, const Tag& t) { return os << '<' << t.name << '>'; }
+    // std::setw(10) 은 다음 한 번의 << 에만 적용 - '<' 에 쓰이고 나머지는 붙어 나옴 (같은 함정)
+    // std::formatter<Tag> 를 특수화하면 parse() 에서 서식 문자열을 직접 해석해야 함
+
+    println!("write! 로만: [{:>10}] [{:<10}]", NaiveTag("b"), NaiveTag("i"));
+    println!("pad 로:      [{:>10}] [{:<10}] [{:*^10}]", Tag("b"), Tag("i"), Tag("em"));
+
+    let t = Celsius(21.456);
+    println!("Celsius: [{}] [{:.0}] [{:.2}] [{:+}] [{:>10.1}] [{:-<9}]", t, t, t, t, t, t);
+
+    let e = Elapsed(3723);
+    println!("Elapsed: [{}] [{:#}] [{:>12}] [{}]", e, e, Elapsed(75), Elapsed(9));
+}
+
+fn main() {
+    custom_display();
+}
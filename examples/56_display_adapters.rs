@@ -0,0 +1,75 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 56. Display 와 Debug 서식 깊이 보기 - 할당 없는 Display 어댑터
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+// ----------------------------------------------------------------------------
+// 할당 없는 Display 어댑터
+// ----------------------------------------------------------------------------
+// String 을 돌려주는 함수 대신 Display 를 구현한 작은 값을 돌려주면, 호출자가 쓰는 곳에 바로 씀 (중간 String 없음)
+//   println!("{}", join(&items, ", ")) - items 를 한 번만 훑고 출력 버퍼에 직접
+// format_args!(..) 는 서식만 묶어 둔 fmt::Arguments - 할당하지 않고 다른 서식 함수에 넘김 (수명이 짧아 변수로는 잘 못 씀)
+// 이 저장소의 style 모듈도 색을 입힌 글자를 같은 방식으로 다룸
+
+// 구분자로 이어 붙여 보여 주기
+pub struct Join<'a, T> {
+    items: &'a [T],
+    sep: &'a str,
+}
+
+pub fn join<'a, T: fmt::Display>(items: &'a [T], sep: &'a str) -> Join<'a, T> {
+    Join { items, sep }
+}
+
+impl<T: fmt::Display> fmt::Display for Join<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.sep)?;
+            }
+            // 안의 원소에 같은 서식을 전달 - {:.1} 이면 각 원소가 소수 한 자리
+            fmt::Display::fmt(item, f)?;
+        }
+        Ok(())
+    }
+}
+
+// 로그 한 줄 - 호출자가 만든 fmt::Arguments 를 할당 없이 받아 앞에 수준을 붙임
+pub fn log_line(out: &mut String, level: &str, args: fmt::Arguments) {
+    writeln!(out, "[{:<5}] {}", level, args).unwrap();
+}
+
+fn display_adapters() {
+    println!("--- 할당 없는 Display 어댑터 ---");
+
+    // C++ 에서는:
+    // std::ranges::views::join_with 뒤 std::format("{}", ...) 또는 fmt::join (fmtlib)
+    // std::format_args / std::make_format_args 가 fmt::Arguments 와 같은 "묶어 둔 인자"
+
+    let prices = [1.5, 20.25, 3.0];
+    println!("join: {}", join(&prices, ", "));
+    println!("join 에 정밀도 전달: {:.1}", join(&prices, " / "));
+    println!("join 에 너비 전달: [{:>6}]", join(&["a", "b"], "|"));
+
+    // 한 번 쓰는 어댑터는 타입을 만들지 않고 fmt::from_fn 에 클로저로 - Display 와 Debug 를 모두 구현한 값
+    let bar = fmt::from_fn(|f| {
+        for _ in 0..f.width().unwrap_or(3) {
+            f.write_char('#')?;
+        }
+        Ok(())
+    });
+    println!("from_fn 막대: [{}] [{:8}]", bar, bar);
+
+    let mut log = String::new();
+    log_line(&mut log, "info", format_args!("processed={} done={:.1}%", 42, 87.456));
+    log_line(&mut log, "warn", format_args!("free={}MB", 12));
+    print!("{}", log);
+}
+
+fn main() {
+    display_adapters();
+}
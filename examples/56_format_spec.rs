@@ -0,0 +1,53 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 56. Display 와 Debug 서식 깊이 보기 - 서식 지정 문법
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+// ----------------------------------------------------------------------------
+// 서식 지정 문법
+// ----------------------------------------------------------------------------
+// {인자:채움 정렬 부호 # 0 너비 .정밀도 종류}
+//   인자     생략(차례대로), 0 1 (위치), name (이름 - 같은 이름의 지역 변수도 바로: {count})
+//   채움정렬  < 왼쪽, > 오른쪽, ^ 가운데 - 앞에 한 글자를 두면 채움 문자 ({:*^9})
+//   부호     + 면 양수에도 +    # 면 대체 형식 ({:#x} → 0x1f, {:#?} → 여러 줄)
+//   0        부호 뒤를 0 으로 채움 ({:08.3})
+//   너비     숫자, 또는 인자로 ({:>width$}, {:>1$})
+//   정밀도   실수는 소수 자릿수, 문자열은 최대 글자 수 ({:.3}), 인자로는 .prec$ 또는 .* (인자 두 개를 씀)
+//   종류     없음(Display) ? (Debug) x X o b (진법) e E (지수)
+// 너비와 정밀도는 글자(char) 수 기준 - 한글 한 글자도 1 (화면 폭 2 칸과는 다름, 23장)
+
+fn format_spec() {
+    println!("--- 서식 지정 문법 ---");
+
+    // C++ 에서는:
+    // std::format("{:>8.2f}|{:<6}|{:^7}|{:+}|{:#x}|{:08.3f}", 3.14159, "ab", "mid", 5, 31, -2.5)   // 거의 같은 문법
+    // std::cout << std::setw(8) << std::fixed << std::setprecision(2) << x;                   // setprecision 은 이후 출력에도 남음
+
+    let pi = std::f64::consts::PI;
+    let name = "ab";
+    println!("[{:>8.2}] [{:<6}] [{:^7}] [{:+}] [{:#x}] [{:08.3}]", pi, name, "mid", 5, 31, -2.5);
+    println!("[{:*^9}] [{:#b}] [{:o}] [{:e}] [{:X}]", "fill", 5u8, 64, 1234.5, 255);
+
+    // 인자로 너비와 정밀도 - 표를 그릴 때 열 너비를 계산해서
+    let width = 10;
+    let precision = 3;
+    println!("[{:>width$.precision$}] [{:>1$}] [{:.*}]", pi, 6, 2, 1.23456);
+
+    // 이름 있는 인자와 지역 변수 캡처
+    let count = 3;
+    println!("위치 {0} {1} {0}, 이름 {unit}, 캡처 {count}", "a", "b", unit = "kg");
+
+    // 정밀도는 문자열을 자름 - 글자 단위
+    println!("[{:.3}] [{:.2}] [{:>5}]", "abcdef", "가나다라", "한글");
+
+    // {{ }} 는 중괄호 그 자체
+    println!("중괄호: {{}} 와 {{{}}}", count);
+}
+
+fn main() {
+    format_spec();
+}
@@ -0,0 +1,87 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 56. Display 와 Debug 서식 깊이 보기 - Debug 를 손으로
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+// ----------------------------------------------------------------------------
+// Debug 를 손으로
+// ----------------------------------------------------------------------------
+// derive(Debug) 는 모든 필드를 그대로 - 손으로 쓰는 경우:
+//   비밀 값(비밀번호, 토큰)을 가릴 때, 캐시나 내부 포인터처럼 의미 없는 필드를 뺄 때, 더 읽기 좋은 모양이 있을 때
+// 빌더를 쓰면 {:?} 와 {:#?} (여러 줄, 들여쓰기)를 알아서 맞춰 줌 - write! 로 직접 쓰면 {:#?} 가 깨짐
+//   f.debug_struct("Name").field("a", &a).finish()
+//   .finish_non_exhaustive() - 일부만 보여 줄 때 끝에 ..
+//   debug_tuple, debug_list().entries(iter), debug_set, debug_map().entries(iter)
+
+pub struct Credentials {
+    pub user: String,
+    pub password: String,
+    // 보여 줄 필요가 없는 내부 상태
+    pub attempts: u32,
+}
+
+impl Credentials {
+    pub fn check(&self, attempt: &str) -> bool {
+        self.password == attempt
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Credentials").field("user", &self.user).field("password", &"***").finish_non_exhaustive()
+    }
+}
+
+// 희소 벡터 - 0 이 아닌 칸만 map 처럼
+pub struct Sparse {
+    pub len: usize,
+    pub values: BTreeMap<usize, f64>,
+}
+
+impl fmt::Debug for Sparse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Sparse[{}] ", self.len)?;
+        f.debug_map().entries(self.values.iter()).finish()
+    }
+}
+
+// 튜플 구조체의 모양 - 단위를 붙여서
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl fmt::Debug for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // {:?} 는 Rgb(#ff8000), {:#?} 는 채널을 한 줄씩
+        if f.alternate() {
+            f.debug_tuple("Rgb").field(&self.0).field(&self.1).field(&self.2).finish()
+        } else {
+            write!(f, "Rgb(#{:02x}{:02x}{:02x})", self.0, self.1, self.2)
+        }
+    }
+}
+
+fn manual_debug() {
+    println!("--- Debug 를 손으로 ---");
+
+    // C++ 에서는:
+    // 표준 디버그 출력이 없어 operator<< 를 하나 더 쓰거나 디버거의 pretty printer (natvis, gdb python) 에 맡김
+
+    let login = Credentials { user: String::from("ann"), password: String::from("hunter2"), attempts: 2 };
+    println!("{:?}", login);
+    println!("{:#?}", login);
+    println!("attempts 는 숨김: {}, 비밀번호는 가렸지만 그대로 있음: {}", login.attempts, login.check("hunter2"));
+
+    let sparse = Sparse { len: 1000, values: BTreeMap::from([(3, 1.5), (999, -2.0)]) };
+    println!("{:?}", sparse);
+
+    let orange = Rgb(255, 128, 0);
+    println!("{:?}", orange);
+    println!("{:#?}", orange);
+}
+
+fn main() {
+    manual_debug();
+}
@@ -0,0 +1,117 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 56. Display 와 Debug 서식 깊이 보기 - fmt::Write 싱크
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+// --- 다른 절에서 가져온 정의 ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(pub u64);
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (h, m, s) = (self.0 / 3600, self.0 / 60 % 60, self.0 % 60);
+        let text = if f.alternate() {
+            format!("{:02}:{:02}:{:02}", h, m, s)
+        } else if h > 0 {
+            format!("{}h {}m {}s", h, m, s)
+        } else if m > 0 {
+            format!("{}m {}s", m, s)
+        } else {
+            format!("{}s", s)
+        };
+        f.pad(&text)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// fmt::Write 싱크
+// ----------------------------------------------------------------------------
+// fmt::Write 는 write_str 하나만 구현하면 write! / writeln! 의 대상이 됨 (write_char, write_fmt 는 기본 구현)
+// String 이 fmt::Write - format! 대신 write!(s, ..) 로 같은 String 에 이어 쓰기 (재할당 줄이기)
+// 싱크에서 Err(fmt::Error) 를 돌려주면 서식이 그 자리에서 멈춤 - fmt::Error 는 정보가 없는 "그만" 신호
+// io::Write (바이트, 파일과 소켓) 와 다름 - 둘 다 use 하면 write! 가 모호하지 않게 한쪽만 가져옴
+
+// 바이트 수만 세는 싱크 - 할당 없이 출력 길이를 미리 앎
+#[derive(Default)]
+pub struct Counter {
+    pub bytes: usize,
+    pub chars: usize,
+}
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.bytes += s.len();
+        self.chars += s.chars().count();
+        Ok(())
+    }
+}
+
+// 앞에서부터 limit 글자만 받고 나머지는 버리는 싱크 - 넘치면 Err 로 서식을 멈춤
+pub struct Truncating {
+    pub out: String,
+    limit: usize,
+    pub truncated: bool,
+}
+
+impl Truncating {
+    pub fn new(limit: usize) -> Truncating {
+        Truncating { out: String::new(), limit, truncated: false }
+    }
+}
+
+impl Write for Truncating {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let room = self.limit - self.out.chars().count();
+        if s.chars().count() <= room {
+            self.out.push_str(s);
+            return Ok(());
+        }
+        self.out.extend(s.chars().take(room));
+        self.truncated = true;
+        Err(fmt::Error)
+    }
+}
+
+// 값 하나를 limit 글자로 - 넘치면 끝에 …
+pub fn preview(value: &dyn fmt::Debug, limit: usize) -> String {
+    let mut sink = Truncating::new(limit);
+    // Err 는 "잘림" 의 뜻 - 여기서는 정상 흐름
+    let _ = write!(sink, "{:?}", value);
+    if sink.truncated {
+        sink.out.push('…');
+    }
+    sink.out
+}
+
+fn write_sink() {
+    println!("--- fmt::Write 싱크 ---");
+
+    // C++ 에서는:
+    // std::format_to(std::back_inserter(s), "{}", x);   // 출력 반복자가 싱크
+    // std::formatted_size("{}", x)                     // Counter 와 같은 일
+    // 잘라 쓰기는 std::format_to_n
+
+    let mut report = String::new();
+    for (i, word) in ["alpha", "beta"].iter().enumerate() {
+        writeln!(report, "{}. {}", i + 1, word).unwrap();
+    }
+    write!(report, "total={}", 2).unwrap();
+    println!("String 에 이어 쓴 결과: {:?}", report);
+
+    let mut counter = Counter::default();
+    write!(counter, "{:>8}|{:?}|{}", Elapsed(75), "따옴표", 3.5).unwrap();
+    println!("출력하지 않고 센 길이: 바이트 {}, 글자 {}", counter.bytes, counter.chars);
+
+    let long: Vec<u32> = (1..=100).collect();
+    println!("앞 20 글자만: {}", preview(&long, 20));
+    println!("짧으면 그대로: {}", preview(&[1, 2, 3], 20));
+}
+
+fn main() {
+    write_sink();
+}
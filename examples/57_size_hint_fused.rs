@@ -0,0 +1,324 @@
+// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)
+// 57. 직접 만드는 이터레이터 어댑터 - size_hint 와 FusedIterator
+// rust-study 에서 추출한 예제
+
+#![allow(dead_code, unused, clippy::all)]
+
+use std::iter::{FusedIterator, Peekable};
+
+// --- 다른 절에서 가져온 정의 ---
+
+pub trait AdaptersExt: Iterator + Sized {
+    // size 개씩 묶어서 Vec 으로 - 마지막 묶음은 짧을 수 있음
+    fn chunks_of(self, size: usize) -> ChunksOf<Self> {
+        assert!(size > 0, "chunks_of: size 는 1 이상");
+        ChunksOf { iter: self, size }
+    }
+
+    // 연달아 same 인 원소는 첫 것만 남김 (Vec::dedup_by 의 이터레이터판)
+    fn dedup_by<F>(self, same: F) -> DedupBy<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupBy { iter: self.peekable(), same }
+    }
+
+    // 연달아 같은 키를 가진 원소를 (키, 묶음) 으로 - 정렬하지 않으므로 떨어진 같은 키는 다른 그룹
+    fn group_by<K, F>(self, key: F) -> GroupBy<Self, F>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        GroupBy { iter: self.peekable(), key }
+    }
+
+    // 원소 사이마다 sep 의 복제본을 끼워 넣음
+    fn interspersed(self, sep: Self::Item) -> Intersperse<Self>
+    where
+        Self::Item: Clone,
+    {
+        Intersperse { iter: self.peekable(), sep, needs_sep: false }
+    }
+}
+
+impl<I: Iterator> AdaptersExt for I {}
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksOf<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for ChunksOf<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(self.size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.div_ceil(self.size), hi.map(|hi| hi.div_ceil(self.size)))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for ChunksOf<I> {}
+
+impl<I: FusedIterator> FusedIterator for ChunksOf<I> {}
+
+fn chunks_of() {
+    println!("\n--- chunks_of ---");
+
+    // C++ 에서는 (C++23):
+    // for (auto chunk : lines | std::views::chunk(2)) { ... }           // chunk 는 원래 범위의 subrange (복사 없음)
+
+    let lines = ["GET /", "200", "GET /about", "404", "POST /login"];
+    for (i, pair) in lines.iter().chunks_of(2).enumerate() {
+        println!("  묶음 {}: {:?}", i, pair);
+    }
+
+    // 나누어떨어지지 않으면 마지막이 짧음, 비어 있으면 묶음도 없음
+    println!("7 개를 3 씩 = {:?}", (0..7).chunks_of(3).map(|c| c.len()).collect::<Vec<_>>());
+    println!("빈 이터레이터 = {:?}", std::iter::empty::<u8>().chunks_of(3).count());
+
+    // 안쪽이 ExactSizeIterator 면 len() 도 - 배치 개수를 미리 알 때
+    let batches = (0..1000).chunks_of(64);
+    println!("1000 개를 64 씩 = {} 묶음, size_hint {:?}", batches.len(), batches.size_hint());
+}
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupBy<I: Iterator, F> {
+    iter: Peekable<I>,
+    same: F,
+}
+
+impl<I, F> Iterator for DedupBy<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let kept = self.iter.next()?;
+        while self.iter.next_if(|next| (self.same)(&kept, next)).is_some() {}
+        Some(kept)
+    }
+
+    // 남은 게 하나라도 있으면 적어도 하나는 나옴, 모두 다르면 전부 나옴
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.min(1), hi)
+    }
+}
+
+impl<I: FusedIterator, F> FusedIterator for DedupBy<I, F> where F: FnMut(&I::Item, &I::Item) -> bool {}
+
+fn dedup_by() {
+    println!("\n--- dedup_by ---");
+
+    // C++ 에서는:
+    // auto last = std::unique(v.begin(), v.end(), pred); v.erase(last, v.end());   // 컨테이너 안에서 - 흐르는 데이터에는 직접 루프
+
+    // 센서가 같은 값을 반복해서 보냄 - 바뀔 때만 기록
+    let readings = [20, 20, 21, 21, 21, 20, 22, 22];
+    let changes: Vec<i32> = readings.iter().copied().dedup_by(|a, b| a == b).collect();
+    println!("바뀔 때만: {:?}", changes);
+
+    // 기준은 남긴 원소 - 1 만큼 흔들리는 것은 같은 값으로 봄
+    let jitter: Vec<i32> = [10i32, 11, 12, 13, 20, 21].into_iter().dedup_by(|kept, next| (next - kept).abs() <= 1).collect();
+    println!("남긴 값과 1 이내면 버림: {:?}", jitter);
+
+    // 대소문자 무시 - 소유하지 않은 &str 도 그대로
+    let words = ["Error", "error", "ERROR", "warn", "Error"];
+    let squeezed: Vec<&str> = words.into_iter().dedup_by(|a, b| a.eq_ignore_ascii_case(b)).collect();
+    println!("대소문자 무시: {:?}", squeezed);
+}
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct GroupBy<I: Iterator, F> {
+    iter: Peekable<I>,
+    key: F,
+}
+
+impl<I, K, F> Iterator for GroupBy<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let key = (self.key)(&first);
+        let mut group = vec![first];
+        while let Some(item) = self.iter.next_if(|item| (self.key)(item) == key) {
+            group.push(item);
+        }
+        Some((key, group))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.min(1), hi)
+    }
+}
+
+impl<I: FusedIterator, K: PartialEq, F: FnMut(&I::Item) -> K> FusedIterator for GroupBy<I, F> {}
+
+fn group_by() {
+    println!("\n--- group_by ---");
+
+    // C++ 에서는 (C++23):
+    // for (auto run : logs | std::views::chunk_by([](auto& a, auto& b) { return a.level == b.level; })) { ... }
+
+    let logs = [
+        ("INFO", "start"),
+        ("INFO", "listen :8080"),
+        ("WARN", "slow query"),
+        ("INFO", "request /"),
+        ("INFO", "request /about"),
+        ("INFO", "request /login"),
+    ];
+    for (level, run) in logs.iter().group_by(|(level, _)| *level) {
+        let messages: Vec<&str> = run.iter().map(|(_, message)| *message).collect();
+        println!("  {} x{}: {:?}", level, run.len(), messages);
+    }
+
+    // 연속한 것만 묶음 - 전체를 키별로 모으려면 먼저 정렬하거나 HashMap (10장)
+    let parity: Vec<(bool, usize)> = [1, 3, 5, 2, 4, 7]
+        .into_iter()
+        .group_by(|n| n % 2 == 0)
+        .map(|(even, run)| (even, run.len()))
+        .collect();
+    println!("(짝수인가, 길이) 묶음 = {:?}", parity);
+
+    // 글자 종류로 토큰 나누기 - 키가 enum 이어도 PartialEq 면 충분
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    enum Kind {
+        Digit,
+        Alpha,
+        Other,
+    }
+    let kind = |c: &char| match c {
+        '0'..='9' => Kind::Digit,
+        c if c.is_alphabetic() => Kind::Alpha,
+        _ => Kind::Other,
+    };
+    let tokens: Vec<String> = "abc123+x9".chars().group_by(kind).map(|(_, run)| run.into_iter().collect()).collect();
+    println!("\"abc123+x9\" 토큰 = {:?}", tokens);
+}
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I: Iterator> {
+    iter: Peekable<I>,
+    sep: I::Item,
+    needs_sep: bool,
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.needs_sep && self.iter.peek().is_some() {
+            self.needs_sep = false;
+            return Some(self.sep.clone());
+        }
+        let item = self.iter.next()?;
+        self.needs_sep = true;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let seps = |n: usize| if self.needs_sep { n } else { n.saturating_sub(1) };
+        (lo.saturating_add(seps(lo)), hi.and_then(|hi| hi.checked_add(seps(hi))))
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Intersperse<I> where I::Item: Clone {}
+
+// ----------------------------------------------------------------------------
+// size_hint 와 FusedIterator
+// ----------------------------------------------------------------------------
+// size_hint 의 약속: 하한 <= 실제로 남은 개수 <= 상한 (None 은 "모름")
+//   기본 구현은 (0, None) - 틀린 값은 아님, collect 가 용량을 예측하지 못할 뿐
+//   틀린 값은 UB 는 아니지만 버그 - Vec 이 너무 크게 잡거나, ExactSizeIterator::len 이 거짓말을 함
+//   안쪽 하한이 걸러질 수 있는 어댑터(filter, dedup_by) 는 하한을 낮춰야 함
+// FusedIterator: None 을 한 번 돌려준 뒤로는 영원히 None 이라는 표시 (메서드 없는 마커 트레이트)
+//   Iterator 의 약속에는 이것이 없음 - 채널의 try_recv 처럼 나중에 다시 Some 을 줄 수도
+//   .fuse() 는 Fuse 어댑터로 감싸는데 FusedIterator 인 타입에는 검사 비용 없이 그대로 통과
+//   어댑터는 "안쪽이 Fused 일 때만 Fused" - 위의 조건부 impl 들
+
+// None 을 준 뒤에도 다시 값을 주는 원천 - 폴링하는 큐를 흉내 냄
+struct Flaky {
+    script: std::vec::IntoIter<Option<i32>>,
+}
+
+impl Iterator for Flaky {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        self.script.next().flatten()
+    }
+}
+
+fn flaky() -> Flaky {
+    Flaky { script: vec![Some(1), Some(2), None, Some(3), Some(4), Some(5)].into_iter() }
+}
+
+// 다 쓰기 전에 size_hint 를 받아 두고, 세어 본 실제 개수가 범위 안인지
+fn hint_holds<I: Iterator>(iter: I) -> bool {
+    let (lo, hi) = iter.size_hint();
+    let n = iter.count();
+    lo <= n && hi.is_none_or(|hi| n <= hi)
+}
+
+fn size_hint_fused() {
+    println!("\n--- size_hint 와 FusedIterator ---");
+
+    // C++ 에서는:
+    // std::ranges::size(r)           // sized_range 일 때만 있음 - 추정치라는 개념은 없음
+    // ++it; (it == end 이후)         // UB - Rust 는 None 다음 next 도 안전, 대신 값이 보장되지 않음
+
+    println!("{:<28} {:?}", "(1..=10).chunks_of(3)", (1..=10).chunks_of(3).size_hint());
+    println!("{:<28} {:?}", "filter(..).chunks_of(3)", (1..=10).filter(|n| n % 3 == 0).chunks_of(3).size_hint());
+    println!("{:<28} {:?}", "(1..=10).dedup_by(..)", (1..=10).dedup_by(|a, b| a == b).size_hint());
+    println!("{:<28} {:?}", "(1..=10).interspersed(0)", (1..=10).interspersed(0).size_hint());
+    println!("{:<28} {:?}", "(1..).interspersed(0)", (1..).interspersed(0).size_hint());
+
+    let all_hold = hint_holds((1..=10).chunks_of(3))
+        && hint_holds((1..=10).filter(|n| n % 3 == 0).chunks_of(3))
+        && hint_holds([1, 1, 2].into_iter().dedup_by(|a, b| a == b))
+        && hint_holds([1, 1, 2].into_iter().group_by(|n| *n))
+        && hint_holds((1..=10).interspersed(0));
+    println!("모두 하한 <= 개수 <= 상한: {}", all_hold);
+
+    // Fused 가 아닌 원천 - None 다음에도 값이 나옴
+    let mut source = flaky();
+    let first_pass: Vec<i32> = source.by_ref().collect();
+    let second_pass: Vec<i32> = source.collect();
+    println!("Flaky: {:?} 그리고 다시 {:?}", first_pass, second_pass);
+
+    // 어댑터는 안쪽의 성질을 그대로 드러냄 - chunks_of 도 None 다음에 다시 묶음을 줌
+    let mut chunks = flaky().chunks_of(2);
+    println!("Flaky 위의 chunks_of: {:?} {:?} {:?} {:?}", chunks.next(), chunks.next(), chunks.next(), chunks.next());
+
+    // .fuse() 를 안쪽에 걸면 첫 None 에서 영원히 끝
+    let mut fused = flaky().fuse().chunks_of(2);
+    println!("fuse() 뒤의 chunks_of:   {:?} {:?} {:?}", fused.next(), fused.next(), fused.next());
+}
+
+fn main() {
+    size_hint_fused();
+}
@@ -8,14 +8,38 @@
 // 4. 모든 것이 표현식(expression) - if, match 등도 값을 반환
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 01. 기본 문법 ===\n");
-
-    variables();
-    types();
-    functions_demo();
-    control_flow();
-    expressions();
+// 절 목록 (실행 순서) - cargo run -- 01:variables 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("variables", variables),
+    ("types", types),
+    ("functions_demo", functions_demo),
+    ("control_flow", control_flow),
+    ("expressions", expressions),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "01"
+    }
+
+    fn name(&self) -> &'static str {
+        "기본 문법 - 변수, 타입, 함수"
+    }
+
+    fn description(&self) -> &'static str {
+        "변수는 기본적으로 불변(immutable) - C++의 const가 기본값"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["변수", "타입", "함수", "제어 흐름", "표현식"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -8,13 +8,37 @@
 // 4. 소유자가 스코프를 벗어나면 자동으로 해제 - RAII와 동일
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 02. 소유권 ===\n");
-
-    ownership_rules();
-    move_semantics();
-    clone_and_copy();
-    ownership_functions();
+// 절 목록 (실행 순서) - cargo run -- 02:ownership_rules 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("ownership_rules", ownership_rules),
+    ("move_semantics", move_semantics),
+    ("clone_and_copy", clone_and_copy),
+    ("ownership_functions", ownership_functions),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "02"
+    }
+
+    fn name(&self) -> &'static str {
+        "소유권 (Ownership)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rust는 컴파일 타임에 메모리 안전성 보장 - 런타임 오버헤드 없음"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["소유권", "이동", "Clone", "Copy"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
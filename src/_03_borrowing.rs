@@ -8,14 +8,38 @@
 // 4. 참조의 수명은 컴파일러가 추적 (다음 챕터에서 자세히)
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 03. 빌림과 참조 ===\n");
-
-    references_intro();
-    mutable_references();
-    reference_rules();
-    dangling_references();
-    slices();
+// 절 목록 (실행 순서) - cargo run -- 03:references_intro 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("references_intro", references_intro),
+    ("mutable_references", mutable_references),
+    ("reference_rules", reference_rules),
+    ("dangling_references", dangling_references),
+    ("slices", slices),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "03"
+    }
+
+    fn name(&self) -> &'static str {
+        "빌림 (Borrowing)과 참조 (References)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rust 참조는 항상 유효함 - 댕글링 참조 불가능 (컴파일 에러)"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["참조", "가변 참조", "빌림 규칙", "슬라이스"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -8,13 +8,37 @@
 // 4. 명시적 수명 어노테이션은 컴파일러에게 힌트를 주는 것
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 04. 수명 ===\n");
+// 절 목록 (실행 순서) - cargo run -- 04:lifetime_basics 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("lifetime_basics", lifetime_basics),
+    ("lifetime_annotations", lifetime_annotations),
+    ("lifetime_in_structs", lifetime_in_structs),
+    ("static_lifetime", static_lifetime),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "04"
+    }
+
+    fn name(&self) -> &'static str {
+        "수명 (Lifetimes)"
+    }
+
+    fn description(&self) -> &'static str {
+        "C++에는 수명 개념이 없음 - 댕글링 참조 검증을 프로그래머에게 맡김"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["수명", "수명 어노테이션", "'static"]
+    }
 
-    lifetime_basics();
-    lifetime_annotations();
-    lifetime_in_structs();
-    static_lifetime();
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -109,6 +133,7 @@ fn first<'a>(x: &'a str, _y: &str) -> &'a str {
 // 1. 각 참조 매개변수는 자신만의 수명을 가짐
 // 2. 입력 수명이 하나면 출력 수명도 그것과 같음
 // 3. &self나 &mut self가 있으면 self의 수명이 출력 수명
+// 규칙의 가장자리(다른 인자를 돌려주는 메서드, impl Trait), for<'a>, 변성, 'static 바운드는 53장
 
 // 따라서 이것은:
 fn first_word(s: &str) -> &str {
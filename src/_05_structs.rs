@@ -8,14 +8,38 @@
 // 4. 생성자 없음 - 연관 함수로 대체 (관례: new, from_* 등)
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 05. 구조체 ===\n");
-
-    basic_struct();
-    tuple_structs();
-    unit_struct();
-    methods();
-    associated_functions();
+// 절 목록 (실행 순서) - cargo run -- 05:basic_struct 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("basic_struct", basic_struct),
+    ("tuple_structs", tuple_structs),
+    ("unit_struct", unit_struct),
+    ("methods", methods),
+    ("associated_functions", associated_functions),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "05"
+    }
+
+    fn name(&self) -> &'static str {
+        "구조체 (Structs)"
+    }
+
+    fn description(&self) -> &'static str {
+        "struct와 class 구분 없음 - 모두 struct (기본 private 없음)"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["struct", "메서드", "연관 함수"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -8,15 +8,39 @@
 // 4. if let, while let으로 단일 패턴 간편하게 처리
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 06. 열거형과 패턴 매칭 ===\n");
-
-    basic_enum();
-    enum_with_data();
-    option_type();
-    match_expression();
-    if_let_while_let();
-    pattern_matching_advanced();
+// 절 목록 (실행 순서) - cargo run -- 06:basic_enum 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("basic_enum", basic_enum),
+    ("enum_with_data", enum_with_data),
+    ("option_type", option_type),
+    ("match_expression", match_expression),
+    ("if_let_while_let", if_let_while_let),
+    ("pattern_matching_advanced", pattern_matching_advanced),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "06"
+    }
+
+    fn name(&self) -> &'static str {
+        "열거형 (Enums)과 패턴 매칭"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rust enum은 데이터를 가질 수 있음 - C++ std::variant와 유사하지만 더 강력"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["enum", "Option", "match", "패턴"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
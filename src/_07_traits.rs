@@ -12,16 +12,40 @@
 use std::fmt::{Debug, Display};
 use std::ops::Add;
 
-pub fn run() {
-    println!("\n=== 07. 트레이트 ===\n");
-
-    basic_traits();
-    default_implementations();
-    trait_bounds();
-    trait_objects();
-    derive_traits();
-    operator_overloading();
-    supertraits();
+// 절 목록 (실행 순서) - cargo run -- 07:basic_traits 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("basic_traits", basic_traits),
+    ("default_implementations", default_implementations),
+    ("trait_bounds", trait_bounds),
+    ("trait_objects", trait_objects),
+    ("derive_traits", derive_traits),
+    ("operator_overloading", operator_overloading),
+    ("supertraits", supertraits),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "07"
+    }
+
+    fn name(&self) -> &'static str {
+        "트레이트 (Traits)"
+    }
+
+    fn description(&self) -> &'static str {
+        "트레이트 = 인터페이스 + C++20 concepts의 조합"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["trait", "트레이트 바운드", "dyn Trait", "derive", "연산자 오버로딩"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -217,6 +241,7 @@ fn trait_objects() {
     // 정적 디스패치 vs 동적 디스패치:
     // 정적: 컴파일 타임에 어떤 메서드 호출할지 결정 (인라인 가능)
     // 동적: vtable을 통해 런타임에 결정 (약간의 오버헤드)
+    // 실제 수치: 26장에서 같은 일을 세 가지로 재 봄 (cargo run --release -- 26)
 
     let article = NewsArticle {
         headline: String::from("제목"),
@@ -261,7 +286,7 @@ fn derive_traits() {
         y: i32,
     }
 
-    // Debug: {:?} 포맷팅
+    // Debug: {:?} 포맷팅 - 서식 지정과 Debug 를 손으로 쓰는 법은 56장
     let p = Point { x: 10, y: 20 };
     println!("Debug: {:?}", p);
 
@@ -358,6 +383,7 @@ fn operator_overloading() {
     // Neg, Not                    - 단항 연산자
     // Index, IndexMut             - [] 연산자
     // Deref, DerefMut             - * 연산자
+    // C++ 연산자 대응표와 Neg, Not, Index, AddAssign, 손으로 쓰는 Ord 의 예는 55장
 }
 
 // ----------------------------------------------------------------------------
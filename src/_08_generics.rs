@@ -11,16 +11,40 @@
 
 use std::fmt::Display;
 
-pub fn run() {
-    println!("\n=== 08. 제네릭 ===\n");
-
-    generic_functions();
-    generic_structs();
-    generic_enums();
-    generic_methods();
-    associated_types();
-    const_generics();
-    phantom_data();
+// 절 목록 (실행 순서) - cargo run -- 08:generic_functions 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("generic_functions", generic_functions),
+    ("generic_structs", generic_structs),
+    ("generic_enums", generic_enums),
+    ("generic_methods", generic_methods),
+    ("associated_types", associated_types),
+    ("const_generics", const_generics),
+    ("phantom_data", phantom_data),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "08"
+    }
+
+    fn name(&self) -> &'static str {
+        "제네릭 (Generics)"
+    }
+
+    fn description(&self) -> &'static str {
+        "단형화(Monomorphization) - C++ 템플릿과 동일, 제로 코스트 추상화"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["제네릭", "연관 타입", "const generics", "PhantomData"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -47,8 +71,9 @@ fn generic_functions() {
         largest
     }
 
-    let numbers = vec![34, 50, 25, 100, 65];
-    println!("가장 큰 수: {}", largest(&numbers));
+    // 시드를 주면 매번 다른 목록 (cargo run -- --seed random) - 어떤 입력에서도 최댓값
+    let numbers = crate::demo_data::numbers(&[34, 50, 25, 100, 65]);
+    println!("가장 큰 수: {} ({:?} 중)", largest(&numbers), numbers);
 
     let chars = vec!['y', 'm', 'a', 'q'];
     println!("가장 큰 문자: {}", largest(&chars));
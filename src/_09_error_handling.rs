@@ -12,15 +12,39 @@
 use std::fs::File;
 use std::io::{self, Read};
 
-pub fn run() {
-    println!("\n=== 09. 에러 처리 ===\n");
-
-    panic_demo();
-    result_basics();
-    result_methods();
-    question_mark_operator();
-    custom_errors();
-    option_result_conversion();
+// 절 목록 (실행 순서) - cargo run -- 09:panic_demo 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("panic_demo", panic_demo),
+    ("result_basics", result_basics),
+    ("result_methods", result_methods),
+    ("question_mark_operator", question_mark_operator),
+    ("custom_errors", custom_errors),
+    ("option_result_conversion", option_result_conversion),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "09"
+    }
+
+    fn name(&self) -> &'static str {
+        "에러 처리 (Error Handling)"
+    }
+
+    fn description(&self) -> &'static str {
+        "예외 없음 - Result<T, E>로 에러 반환"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["panic!", "Result", "? 연산자", "커스텀 에러"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -63,7 +87,7 @@ fn result_basics() {
 
     // C++: std::expected<T, E> (C++23) 또는 반환값 + 에러 코드
 
-    // 파일 열기 예제
+    // 파일 열기 예제 (파일 API 자체는 36장)
     let result = File::open("hello.txt");
 
     // match로 처리
@@ -10,13 +10,37 @@
 
 use std::collections::HashMap;
 
-pub fn run() {
-    println!("\n=== 10. 컬렉션 ===\n");
+// 절 목록 (실행 순서) - cargo run -- 10:vectors 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("vectors", vectors),
+    ("strings", strings),
+    ("hashmaps", hashmaps),
+    ("other_collections", other_collections),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "10"
+    }
+
+    fn name(&self) -> &'static str {
+        "컬렉션 (Collections)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Vec<T> = std::vector<T> - 거의 동일한 성능과 사용법"
+    }
 
-    vectors();
-    strings();
-    hashmaps();
-    other_collections();
+    fn topics(&self) -> &'static [&'static str] {
+        &["Vec", "String", "HashMap"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -193,6 +217,7 @@ fn strings() {
     let slice = &s[0..3]; // "안" (3바이트)
     println!("슬라이스: {}", slice);
     // let bad = &s[0..2];  // panic! 문자 중간을 자름
+    // 입력이 정하는 인덱스로 자르면 이런 panic 이 숨어 있음 - 퍼저로 찾는 법은 43장 (s.get(a..b) 는 None)
 
     // 문자 인덱스로 접근하려면
     let s = "안녕하세요";
@@ -252,7 +277,15 @@ fn hashmaps() {
     let mut map: HashMap<&str, i32> = HashMap::new();
     let key = "Green";
     map.insert(key, 30);
-    println!("key still valid: {}", key);
+    println!("key 는 여전히 유효: {}", key);
+
+    // 리터럴로 초기화 - 15장의 hashmap! 매크로 재사용
+    // C++: std::unordered_map<std::string, int> m{{"Alice", 100}, {"Bob", 85}};
+    let literal = crate::macros::hashmap! {
+        "Alice" => 100,
+        "Bob" => 85,
+    };
+    println!("hashmap! 초기화: {} 항목", literal.len());
 
     // 업데이트 패턴
     let mut scores = HashMap::new();
@@ -269,7 +302,8 @@ fn hashmaps() {
     println!("or_insert: {:?}", scores);
 
     // 기존 값 기반 업데이트
-    let text = "hello world wonderful world";
+    // 시드를 주면 다른 단어들 - 같은 단어가 여러 번 나오면 개수가 늘어남
+    let text = crate::demo_data::words(&["hello", "world", "wonderful", "world"]).join(" ");
     let mut word_count = HashMap::new();
 
     for word in text.split_whitespace() {
@@ -284,7 +318,7 @@ fn hashmaps() {
 
     // 해셔 변경
     // 기본: SipHash (DoS 방어, 약간 느림)
-    // 빠른 해셔 필요시: fnv 또는 ahash 크레이트 사용
+    // 빠른 해셔 필요시: fnv 또는 ahash 크레이트 사용 (얼마나 빠른지는 benches/hashing.rs)
     // use std::hash::BuildHasherDefault;
     // use fnv::FnvHasher;
     // let mut map: HashMap<i32, i32, BuildHasherDefault<FnvHasher>> = ...
@@ -5,18 +5,42 @@
 // 1. 이터레이터 = C++20 ranges와 매우 유사 (지연 평가)
 // 2. 클로저가 환경 캡처하는 방식이 명시적 (move, &, &mut)
 // 3. Fn, FnMut, FnOnce 트레이트로 클로저 타입 구분
-// 4. 제로 코스트 추상화 - 수동 루프와 동일한 성능
+// 4. 제로 코스트 추상화 - 수동 루프와 동일한 성능 (benches/iterators.rs 로 재 봄, 장 안에서 재는 것은 50장)
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 11. 이터레이터와 클로저 ===\n");
+// 절 목록 (실행 순서) - cargo run -- 11:closures_basics 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("closures_basics", closures_basics),
+    ("closure_traits", closure_traits),
+    ("iterator_basics", iterator_basics),
+    ("iterator_adaptors", iterator_adaptors),
+    ("iterator_consumers", iterator_consumers),
+    ("custom_iterator", custom_iterator),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "11"
+    }
+
+    fn name(&self) -> &'static str {
+        "이터레이터와 클로저 (Iterators and Closures)"
+    }
+
+    fn description(&self) -> &'static str {
+        "이터레이터 = C++20 ranges와 매우 유사 (지연 평가)"
+    }
 
-    closures_basics();
-    closure_traits();
-    iterator_basics();
-    iterator_adaptors();
-    iterator_consumers();
-    custom_iterator();
+    fn topics(&self) -> &'static [&'static str] {
+        &["클로저", "Fn 트레이트", "이터레이터 어댑터", "커스텀 이터레이터"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -125,7 +149,7 @@ fn closure_traits() {
     let mut total = 0;
     let mut add_ten = || total += 10;
     call_mut_twice(&mut add_ten);
-    println!("total after FnMut: {}", total);
+    println!("FnMut 호출 후 total: {}", total);
 
     // Fn 예제 - 순수 함수처럼 동작
     fn call_many_times<F>(f: F)
@@ -207,10 +231,13 @@ fn iterator_adaptors() {
     println!("\n--- 이터레이터 어댑터 ---");
 
     // 어댑터 = 이터레이터를 다른 이터레이터로 변환
+    // 같은 파이프라인을 여러 코어로 (iter → par_iter): 32장
     // 지연 평가 (lazy) - 소비될 때까지 실행 안 됨
     // C++20 views와 유사
 
-    let v = vec![1, 2, 3, 4, 5];
+    // 기본값은 [1, 2, 3, 4, 5] - cargo run -- --seed N 이면 다른 크기와 값 (demo_data.rs)
+    let v = crate::demo_data::numbers(&[1, 2, 3, 4, 5]);
+    println!("입력: {:?}", v);
 
     // map - 각 요소 변환
     // C++: v | std::views::transform(func)
@@ -299,28 +326,28 @@ fn iterator_consumers() {
     // fold - 누적 연산 (C++ std::accumulate)
     let sum = v.iter().fold(0, |acc, x| acc + x);
     let concat = v.iter().fold(String::new(), |acc, x| acc + &x.to_string());
-    println!("fold sum: {}, concat: {}", sum, concat);
+    println!("fold 합계: {}, 이어붙이기: {}", sum, concat);
 
     // reduce - fold와 비슷하지만 초기값 없음
     let max = v.iter().copied().reduce(|a, b| if a > b { a } else { b });
-    println!("reduce max: {:?}", max);
+    println!("reduce 최댓값: {:?}", max);
 
     // find - 조건에 맞는 첫 요소
     let first_even = v.iter().find(|x| *x % 2 == 0);
-    println!("find even: {:?}", first_even);
+    println!("find 첫 짝수: {:?}", first_even);
 
     // position - 조건에 맞는 첫 인덱스
     let pos = v.iter().position(|x| *x == 3);
-    println!("position of 3: {:?}", pos);
+    println!("3 의 position: {:?}", pos);
 
     // any, all
     let has_even = v.iter().any(|x| x % 2 == 0);
     let all_positive = v.iter().all(|x| *x > 0);
-    println!("any even: {}, all positive: {}", has_even, all_positive);
+    println!("any 짝수: {}, all 양수: {}", has_even, all_positive);
 
     // count
     let count = v.iter().filter(|x| *x % 2 == 0).count();
-    println!("even count: {}", count);
+    println!("짝수 개수: {}", count);
 
     // min, max
     println!("min: {:?}, max: {:?}", v.iter().min(), v.iter().max());
@@ -376,7 +403,7 @@ fn custom_iterator() {
     let counter = Counter::new(5);
     println!("Counter: {:?}", counter.collect::<Vec<_>>());
 
-    // 어댑터도 사용 가능
+    // 어댑터도 사용 가능 - 다른 이터레이터를 감싸는 어댑터를 직접 만드는 법은 57장
     let sum: u32 = Counter::new(5)
         .zip(Counter::new(5).skip(1))
         .map(|(a, b)| a * b)
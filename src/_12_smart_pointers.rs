@@ -12,16 +12,40 @@
 use std::cell::RefCell;
 use std::rc::{Rc, Weak};
 
-pub fn run() {
-    println!("\n=== 12. 스마트 포인터 ===\n");
-
-    box_pointer();
-    deref_trait();
-    drop_trait();
-    rc_pointer();
-    refcell_pointer();
-    interior_mutability();
-    reference_cycles();
+// 절 목록 (실행 순서) - cargo run -- 12:box_pointer 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("box_pointer", box_pointer),
+    ("deref_trait", deref_trait),
+    ("drop_trait", drop_trait),
+    ("rc_pointer", rc_pointer),
+    ("refcell_pointer", refcell_pointer),
+    ("interior_mutability", interior_mutability),
+    ("reference_cycles", reference_cycles),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "12"
+    }
+
+    fn name(&self) -> &'static str {
+        "스마트 포인터 (Smart Pointers)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Box<T> ≈ std::unique_ptr<T> - 단일 소유권, 힙 할당"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Box", "Deref", "Drop", "Rc", "RefCell", "Weak"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -141,7 +165,7 @@ fn drop_trait() {
             data: String::from("other stuff"),
         };
         println!("CustomSmartPointers 생성됨");
-    }  // d 먼저, 그 다음 c (역순)
+    }  // d 먼저, 그 다음 c (역순) - 필드와 임시 값의 순서, ManuallyDrop, 스코프 가드는 54장
 
     println!("스코프 종료 후");
 
@@ -178,7 +202,7 @@ fn rc_pointer() {
     let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
     println!("a 생성 후 카운트: {}", Rc::strong_count(&a));
 
-    // Rc::clone은 얕은 복사 (카운트만 증가)
+    // Rc::clone은 얕은 복사 (카운트만 증가) - 깊은 복사와의 차이는 benches/rc_clone.rs
     let b = Cons(3, Rc::clone(&a));
     println!("b 생성 후 카운트: {}", Rc::strong_count(&a));
 
@@ -209,6 +233,7 @@ fn refcell_pointer() {
     // Box<T>: 컴파일 타임 빌림, 가변/불변 소유권
     // Rc<T>: 컴파일 타임 빌림, 불변 공유 소유권
     // RefCell<T>: 런타임 빌림, 가변/불변 단일 소유권
+    // Cell, OnceCell, LazyCell 과 스레드 안전한 짝(Mutex, OnceLock 등) 비교는 52장
 
     let data = RefCell::new(5);
 
@@ -5,7 +5,7 @@
 // 1. 컴파일 타임에 데이터 레이스 방지 - Send/Sync 트레이트
 // 2. Arc<Mutex<T>> = C++의 shared_ptr<T> + mutex
 // 3. 채널(Channel) = C++ 없음 (직접 구현하거나 라이브러리 사용)
-// 4. std::thread::spawn은 move 클로저 필수
+// 4. std::thread::spawn은 move 클로저 필수 - 지역 데이터를 빌리려면 std::thread::scope
 // 5. Mutex 락은 RAII (C++과 동일)
 // ============================================================================
 
@@ -13,15 +13,40 @@ use std::sync::{mpsc, Arc, Mutex, RwLock};
 use std::thread;
 use std::time::Duration;
 
-pub fn run() {
-    println!("\n=== 13. 동시성 ===\n");
+// 절 목록 (실행 순서) - cargo run -- 13:basic_threads 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("basic_threads", basic_threads),
+    ("move_closures", move_closures),
+    ("scoped_threads", scoped_threads),
+    ("channels", channels),
+    ("shared_state", shared_state),
+    ("rwlock_example", rwlock_example),
+    ("send_sync_traits", send_sync_traits),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "13"
+    }
+
+    fn name(&self) -> &'static str {
+        "동시성 (Concurrency)"
+    }
 
-    basic_threads();
-    move_closures();
-    channels();
-    shared_state();
-    rwlock_example();
-    send_sync_traits();
+    fn description(&self) -> &'static str {
+        "컴파일 타임에 데이터 레이스 방지 - Send/Sync 트레이트"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["스레드", "scoped thread", "채널", "Mutex", "RwLock", "Send/Sync"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -75,6 +100,65 @@ fn move_closures() {
     // std::thread t([v = std::move(v)] { ... });  // 캡처에 move 필요
 }
 
+// ----------------------------------------------------------------------------
+// 스코프 스레드 (thread::scope)
+// ----------------------------------------------------------------------------
+
+fn scoped_threads() {
+    println!("\n--- 스코프 스레드 ---");
+
+    // thread::spawn 은 F: 'static - 스레드가 언제 끝날지 모르므로 지역 변수를 빌릴 수 없음
+    // thread::scope 는 스코프가 끝나기 전에 안에서 만든 스레드를 모두 join
+    //   → 스코프 밖의 지역 변수를 move 없이 빌릴 수 있음 (Arc 도 필요 없음)
+
+    // C++ 비교:
+    // std::vector<int> data = ...;
+    // { std::jthread t([&data] { sum(data); }); }   // jthread 는 소멸자에서 join - 모양은 같음
+    // 하지만 [&] 캡처가 스레드보다 먼저 사라지는 것을 컴파일러가 막아 주지 않음
+    //   std::jthread t; { std::vector<int> tmp; t = std::jthread([&tmp] { ... }); }  // 매달린 참조
+
+    let data: Vec<u64> = (1..=1000).collect();
+    let mut counts = [0usize; 4];
+
+    let total: u64 = thread::scope(|s| {
+        // 읽기 전용 빌림은 여러 스레드가 함께
+        let handles: Vec<_> = data.chunks(250).map(|chunk| s.spawn(move || chunk.iter().sum::<u64>())).collect();
+
+        // 가변 빌림도 가능 - 스레드마다 겹치지 않는 칸을 하나씩 (iter_mut 이 겹치지 않음을 보장)
+        for (i, slot) in counts.iter_mut().enumerate() {
+            let data = &data;
+            s.spawn(move || *slot = data.iter().filter(|&&x| x % 4 == i as u64).count());
+        }
+
+        // 값을 돌려받으려면 join - panic 했으면 Err
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+    // 여기서는 모든 스레드가 끝났으므로 data, counts 를 다시 자유롭게 씀
+    println!("부분합 4개의 합: {} (직접 계산: {})", total, data.iter().sum::<u64>());
+    println!("나머지별 개수: {:?}", counts);
+
+    // 같은 데이터를 한 스레드가 쓰는 동안 다른 스레드가 읽으려 하면 컴파일 에러:
+    //   thread::scope(|s| {
+    //       s.spawn(|| data.push(1));
+    //       s.spawn(|| println!("{}", data.len()));   // error: cannot borrow `data` as immutable
+    //   });
+    // 필요하면 scope 안에서도 Mutex/RwLock 을 씀 (Arc 없이 &Mutex 로)
+    let log = Mutex::new(Vec::new());
+    thread::scope(|s| {
+        for id in 0..3 {
+            let log = &log;
+            s.spawn(move || log.lock().unwrap().push(id));
+        }
+    });
+    let mut log = log.into_inner().unwrap();
+    log.sort_unstable();
+    println!("&Mutex 로 모은 값: {:?}", log);
+
+    // 언제 쓰나
+    //   spawn + move + Arc: 스레드가 함수보다 오래 살아야 할 때 (서버의 작업자)
+    //   scope: 계산을 나눠서 바로 합칠 때 (병렬 map, 분할 정복) - rayon 의 join/scope 도 같은 아이디어
+}
+
 // ----------------------------------------------------------------------------
 // 채널 (Message Passing)
 // ----------------------------------------------------------------------------
@@ -84,6 +168,7 @@ fn channels() {
 
     // mpsc = Multiple Producer, Single Consumer
     // C++에는 없음, Go의 채널과 유사
+    // 수신자도 여럿(MPMC)이거나 select 가 필요하면 crossbeam 채널: 33장
 
     // 채널 생성
     let (tx, rx) = mpsc::channel();
@@ -262,7 +347,7 @@ fn send_sync_traits() {
     // *const T, *mut T는 Send/Sync 아님
 
     // 예: Rc를 스레드에 보내려 하면 컴파일 에러
-    // let rc = Rc::new(5);
+    // let rc = std::rc::Rc::new(5);
     // thread::spawn(move || {
     //     println!("{}", rc);  // 에러! Rc는 Send가 아님
     // });
@@ -9,13 +9,37 @@
 // 5. mod.rs 또는 파일명으로 모듈 선언 (C++20 모듈과 유사)
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 14. 모듈 시스템 ===\n");
+// 절 목록 (실행 순서) - cargo run -- 14:module_basics 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("module_basics", module_basics),
+    ("visibility_rules", visibility_rules),
+    ("use_keyword", use_keyword),
+    ("module_file_structure", module_file_structure),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "14"
+    }
+
+    fn name(&self) -> &'static str {
+        "모듈 시스템 (Module System)"
+    }
+
+    fn description(&self) -> &'static str {
+        "헤더 파일 없음 - 모듈이 인터페이스와 구현을 함께 관리"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["mod", "pub", "use", "파일 구조"]
+    }
 
-    module_basics();
-    visibility_rules();
-    use_keyword();
-    module_file_structure();
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -9,15 +9,45 @@
 // 5. 절차적 매크로로 derive, attribute 등 구현 가능
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 15. 매크로 ===\n");
-
-    declarative_macros();
-    macro_patterns();
-    repetition();
-    hygiene();
-    useful_macros();
-    procedural_macros_intro();
+// 다른 모듈에 정의된 매크로 가져오기
+// #[macro_export] 매크로는 크레이트 루트 경로, pub(crate) use 매크로는 모듈 경로
+use crate::macros::{builder_field, hashmap};
+use crate::my_vec;
+
+// 절 목록 (실행 순서) - cargo run -- 15:declarative_macros 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("declarative_macros", declarative_macros),
+    ("macro_patterns", macro_patterns),
+    ("repetition", repetition),
+    ("hygiene", hygiene),
+    ("useful_macros", useful_macros),
+    ("macro_visibility", macro_visibility),
+    ("procedural_macros_intro", procedural_macros_intro),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "15"
+    }
+
+    fn name(&self) -> &'static str {
+        "매크로 (Macros)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rust 매크로는 AST 레벨에서 동작 - C++ 전처리기는 텍스트 치환"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["macro_rules!", "반복", "위생성", "절차적 매크로"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -136,23 +166,7 @@ fn macro_patterns() {
 // + : 1회 이상
 // ? : 0회 또는 1회
 
-// vec! 매크로와 유사한 구현
-macro_rules! my_vec {
-    // 빈 벡터
-    () => {
-        Vec::new()
-    };
-    // 요소가 있는 벡터
-    ($($element:expr),+ $(,)?) => {
-        {
-            let mut v = Vec::new();
-            $(
-                v.push($element);
-            )+
-            v
-        }
-    };
-}
+// vec! 매크로와 유사한 구현 - my_vec!은 macros 모듈로 옮김 (macro_visibility 참고)
 
 // 가변 인자 함수처럼 동작하는 매크로
 macro_rules! sum {
@@ -190,7 +204,7 @@ fn repetition() {
 
     // sum! 사용
     println!("sum: {}", sum!(1, 2, 3, 4, 5));
-    println!("sum empty: {}", sum!());
+    println!("빈 sum: {}", sum!());
 
     // 구조체 생성
     make_struct!(Point { x: i32, y: i32 });
@@ -274,18 +288,7 @@ macro_rules! try_or_return {
     };
 }
 
-// 해시맵 생성
-macro_rules! hashmap {
-    ($($key:expr => $value:expr),* $(,)?) => {
-        {
-            let mut map = std::collections::HashMap::new();
-            $(
-                map.insert($key, $value);
-            )*
-            map
-        }
-    };
-}
+// 해시맵 생성 - hashmap!은 macros 모듈에서 가져옴
 
 // 조건부 컴파일과 함께 사용
 macro_rules! debug_print {
@@ -295,16 +298,7 @@ macro_rules! debug_print {
     };
 }
 
-// 메서드 체이닝 빌더
-macro_rules! builder_field {
-    ($name:ident, $t:ty) => {
-        pub fn $name(mut self, value: $t) -> Self {
-            self.$name = value;
-            self
-        }
-    };
-}
-
+// 메서드 체이닝 빌더 - builder_field!도 macros 모듈에서 가져옴
 struct RequestBuilder {
     url: String,
     method: String,
@@ -366,6 +360,57 @@ fn useful_macros() {
     println!("패키지 버전: {}", version);
 }
 
+// ----------------------------------------------------------------------------
+// 매크로 가시성과 $crate
+// ----------------------------------------------------------------------------
+
+// 모듈 안에서 정의한 macro_rules!는 기본적으로 "텍스트 순서" 스코프
+// 정의 이후, 같은 모듈(과 하위 모듈)에서만 보임
+mod local_only {
+    macro_rules! shout {
+        ($s:expr) => {
+            $s.to_uppercase()
+        };
+    }
+
+    pub fn demo() -> String {
+        shout!("local")
+    }
+    // 모듈 밖에서 shout!은 보이지 않음 - use로 재공개하지 않았으므로
+}
+
+fn macro_visibility() {
+    println!("\n--- 매크로 가시성과 $crate ---");
+
+    // 1. 모듈 로컬 매크로
+    println!("local_only::demo() = {}", local_only::demo());
+
+    // 2. #[macro_export] - crate::my_vec 경로로 접근
+    let v = my_vec![1, 2, 3];
+    println!("my_vec! = {:?} (capacity: {})", v, v.capacity());
+
+    // use 없이 전체 경로로도 호출 가능
+    let w: Vec<&str> = crate::my_vec!["a", "b"];
+    println!("crate::my_vec! = {:?}", w);
+
+    // 3. pub(crate) use - crate::macros::hashmap 경로로 접근
+    let m = crate::macros::hashmap! { 1 => "one", 2 => "two" };
+    println!("crate::macros::hashmap! 항목 수 = {}", m.len());
+
+    // 4. $crate - my_vec!/hashmap! 내부에서 $crate::count_exprs! 호출
+    //    호출하는 쪽은 count_exprs를 use 하지 않아도 됨
+    //    라이브러리라면 $crate가 사용자 크레이트가 아닌 정의한 크레이트로 치환됨
+    println!("count_exprs!(a, b, c) = {}", crate::count_exprs!('a', 'b', 'c'));
+
+    // 같은 매크로를 다른 장에서도 재사용:
+    // - _10_collections::hashmaps() 에서 hashmap!
+    // - _18_idioms::builder_pattern() 에서 builder_field!
+
+    // C++과 비교:
+    // - C++ 매크로는 #include 한 순간부터 전역 - 네임스페이스 무시
+    // - Rust 매크로는 use/경로로 가져오므로 이름 충돌을 모듈 단위로 관리
+}
+
 // ----------------------------------------------------------------------------
 // 절차적 매크로 소개
 // ----------------------------------------------------------------------------
@@ -382,6 +427,7 @@ fn procedural_macros_intro() {
     println!("3. function-like 매크로 - my_macro!(...)");
 
     // derive 매크로 예시 (serde)
+    // 속성(#[serde(rename, default, ..)])과 함께 실제로 쓰는 모습은 35장
     // #[derive(Serialize, Deserialize)]
     // struct User { name: String, age: u32 }
 
@@ -10,17 +10,43 @@
 // ============================================================================
 
 use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// 절 목록 (실행 순서) - cargo run -- 16:unsafe_basics 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("unsafe_basics", unsafe_basics),
+    ("raw_pointers", raw_pointers),
+    ("unsafe_functions", unsafe_functions),
+    ("safe_abstractions", safe_abstractions),
+    ("validating_unsafe", validating_unsafe),
+    ("ffi_example", ffi_example),
+    ("static_mut_variables", static_mut_variables),
+    ("unsafe_traits", unsafe_traits),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "16"
+    }
+
+    fn name(&self) -> &'static str {
+        "Unsafe Rust"
+    }
 
-pub fn run() {
-    println!("\n=== 16. Unsafe Rust ===\n");
+    fn description(&self) -> &'static str {
+        "Rust는 기본적으로 안전함 - unsafe는 명시적으로 선언"
+    }
 
-    unsafe_basics();
-    raw_pointers();
-    unsafe_functions();
-    safe_abstractions();
-    ffi_example();
-    static_mut_variables();
-    unsafe_traits();
+    fn topics(&self) -> &'static [&'static str] {
+        &["unsafe", "raw 포인터", "FFI", "static mut"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -35,7 +61,7 @@ fn unsafe_basics() {
     // 2. unsafe 함수 또는 메서드 호출
     // 3. 가변 정적 변수 접근 또는 수정
     // 4. unsafe 트레이트 구현
-    // 5. union 필드 접근
+    // 5. union 필드 접근 (union 과 transmute 는 47장)
 
     // unsafe는 빌림 검사기를 끄지 않음!
     // 여전히 소유권 규칙은 적용됨
@@ -159,21 +185,46 @@ fn unsafe_functions() {
 // ----------------------------------------------------------------------------
 
 // 안전하지 않은 내부 구현을 안전한 API로 감싸기
-mod safe_wrapper {
-    use std::ptr;
-
+// C++: std::vector<T>를 직접 구현하는 것과 같은 연습
+// pub(crate) - 19장 테스트에서 다른 모듈의 API를 검증하는 예제로 사용
+pub(crate) mod safe_wrapper {
+    use std::alloc::{self, Layout};
+    use std::marker::PhantomData;
+    use std::mem::{self, ManuallyDrop, MaybeUninit};
+    use std::ops::{Deref, DerefMut};
+    use std::ptr::{self, NonNull};
+
+    // 불변식 (모든 unsafe 블록이 의존하는 조건):
+    // 1. ptr은 항상 non-null이고 T에 맞게 정렬됨 (cap == 0이면 dangling)
+    // 2. cap > 0 이고 T가 ZST가 아니면 ptr은 Layout::array::<T>(cap)로 할당된 메모리
+    // 3. ptr[0..len]은 초기화된 T, ptr[len..cap]은 미초기화 메모리 (MaybeUninit<T> 로만 봄 - 46장)
+    // 4. len <= cap
+    // 5. ZST(크기 0 타입)는 할당하지 않음 - cap을 usize::MAX로 간주
     pub struct MyVec<T> {
-        ptr: *mut T,
+        ptr: NonNull<T>,
         len: usize,
         cap: usize,
+        // "T를 소유한다"고 drop checker에게 알림 - C++에는 없는 개념
+        _marker: PhantomData<T>,
     }
 
+    // Send/Sync - raw 포인터가 있으면 자동 구현되지 않으므로 직접 선언
+    // SAFETY: MyVec<T>는 T 값들을 독점 소유하므로 Vec<T>와 같은 규칙을 따름
+    // - T가 Send면 MyVec<T>를 다른 스레드로 옮겨도 안전 (소유권이 함께 이동)
+    // - T가 Sync면 &MyVec<T>는 &[T]만 노출하므로 공유해도 안전
+    // 내부 가변성이 없고 포인터를 다른 곳과 공유하지 않음 (불변식 2)
+    unsafe impl<T: Send> Send for MyVec<T> {}
+    unsafe impl<T: Sync> Sync for MyVec<T> {}
+
     impl<T> MyVec<T> {
+        const IS_ZST: bool = mem::size_of::<T>() == 0;
+
         pub fn new() -> Self {
             MyVec {
-                ptr: ptr::null_mut(),
+                ptr: NonNull::dangling(),
                 len: 0,
-                cap: 0,
+                cap: if Self::IS_ZST { usize::MAX } else { 0 },
+                _marker: PhantomData,
             }
         }
 
@@ -185,62 +236,459 @@ mod safe_wrapper {
             self.len == 0
         }
 
+        pub fn capacity(&self) -> usize {
+            self.cap
+        }
+
         // 안전한 API - 내부적으로 unsafe 사용
+        // 미초기화 슬롯을 &mut T 로 만들면 UB - MaybeUninit<T> 로 보고 write (46장)
+        // *slot = value 로 쓰면 그 자리의 쓰레기 값을 drop 하려 함 - write 는 drop 하지 않고 덮어씀
         pub fn push(&mut self, value: T) {
             if self.len == self.cap {
                 self.grow();
             }
 
+            self.spare_capacity_mut()[0].write(value);
+            // 방금 ptr[len]을 초기화했으므로 불변식 3 유지
+            self.len += 1;
+        }
+
+        // 남은 용량 ptr[len..cap] - Vec::spare_capacity_mut 와 같음
+        // 여기에 쓴 값은 len 을 늘리기 전까지 MyVec 의 요소가 아님 (drop 되지 않음)
+        pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+            // SAFETY: ptr[len..cap]은 할당된 메모리 (불변식 2, ZST 는 크기 0)
+            // MaybeUninit<T> 는 T 와 크기/정렬이 같고 미초기화 상태도 올바른 값
             unsafe {
-                ptr::write(self.ptr.add(self.len), value);
+                std::slice::from_raw_parts_mut(
+                    self.ptr.as_ptr().add(self.len) as *mut MaybeUninit<T>,
+                    self.cap - self.len,
+                )
+            }
+        }
+
+        // C++ pop_back()과 달리 빈 벡터에서도 UB 없이 None 반환
+        pub fn pop(&mut self) -> Option<T> {
+            if self.len == 0 {
+                None
+            } else {
+                self.len -= 1;
+                // SAFETY: ptr[len]은 초기화되어 있었고, len을 줄였으므로
+                // 이 값을 다시 읽거나 drop 하지 않음 - 소유권을 호출자에게 이동
+                unsafe { Some(ptr::read(self.ptr.as_ptr().add(self.len))) }
+            }
+        }
+
+        // index 위치에 삽입하고 뒤쪽 요소를 한 칸씩 민다
+        pub fn insert(&mut self, index: usize, value: T) {
+            assert!(index <= self.len, "insert index {} out of bounds (len {})", index, self.len);
+            if self.len == self.cap {
+                self.grow();
+            }
+
+            // SAFETY: len < cap 이므로 ptr[index..len]을 한 칸 뒤로 옮길 공간이 있음
+            // ptr::copy는 memmove처럼 겹치는 영역도 처리
+            unsafe {
+                let p = self.ptr.as_ptr().add(index);
+                ptr::copy(p, p.add(1), self.len - index);
+                ptr::write(p, value);
             }
             self.len += 1;
         }
 
+        // index 위치의 요소를 꺼내고 뒤쪽 요소를 한 칸씩 당긴다
+        pub fn remove(&mut self, index: usize) -> T {
+            assert!(index < self.len, "remove index {} out of bounds (len {})", index, self.len);
+
+            // SAFETY: index < len 이므로 ptr[index]는 초기화됨
+            // 값을 읽은 뒤 뒤쪽을 덮어쓰므로 같은 값이 두 번 drop 되지 않음
+            unsafe {
+                self.len -= 1;
+                let p = self.ptr.as_ptr().add(index);
+                let value = ptr::read(p);
+                ptr::copy(p.add(1), p, self.len - index);
+                value
+            }
+        }
+
         pub fn get(&self, index: usize) -> Option<&T> {
             if index < self.len {
-                unsafe { Some(&*self.ptr.add(index)) }
+                // SAFETY: index < len 이므로 초기화된 요소 (불변식 3)
+                unsafe { Some(&*self.ptr.as_ptr().add(index)) }
             } else {
                 None
             }
         }
 
         fn grow(&mut self) {
-            let new_cap = if self.cap == 0 { 1 } else { self.cap * 2 };
-            let new_layout = std::alloc::Layout::array::<T>(new_cap).unwrap();
+            // ZST는 cap이 usize::MAX 이므로 여기까지 오면 len이 넘친 것
+            assert!(!Self::IS_ZST, "capacity overflow");
+
+            // cap * 2 는 릴리스 빌드에서 조용히 넘칠 수 있음 - 넘치면 std 와 같은 메시지로 panic
+            let new_cap = if self.cap == 0 { 1 } else { self.cap.checked_mul(2).expect("capacity overflow") };
+            let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
 
             let new_ptr = if self.cap == 0 {
-                unsafe { std::alloc::alloc(new_layout) as *mut T }
+                // SAFETY: T가 ZST가 아니고 new_cap >= 1 이므로 size > 0
+                unsafe { alloc::alloc(new_layout) }
             } else {
-                let old_layout = std::alloc::Layout::array::<T>(self.cap).unwrap();
-                unsafe {
-                    std::alloc::realloc(self.ptr as *mut u8, old_layout, new_layout.size())
-                        as *mut T
-                }
+                let old_layout = Layout::array::<T>(self.cap).unwrap();
+                // SAFETY: ptr은 old_layout으로 할당된 메모리 (불변식 2)
+                unsafe { alloc::realloc(self.ptr.as_ptr() as *mut u8, old_layout, new_layout.size()) }
             };
 
-            self.ptr = new_ptr;
+            // 할당 실패 시 null - C++의 std::bad_alloc 대신 표준 핸들러 호출
+            self.ptr = match NonNull::new(new_ptr as *mut T) {
+                Some(p) => p,
+                None => alloc::handle_alloc_error(new_layout),
+            };
             self.cap = new_cap;
         }
     }
 
+    impl<T> Default for MyVec<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
     impl<T> Drop for MyVec<T> {
         fn drop(&mut self) {
-            if self.cap > 0 {
-                // 요소들 drop
-                for i in 0..self.len {
-                    unsafe {
-                        ptr::drop_in_place(self.ptr.add(i));
-                    }
+            // 요소들 drop - 슬라이스로 한 번에
+            // SAFETY: ptr[0..len]은 초기화된 요소들 (불변식 3)
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+            }
+            // 메모리 해제
+            if !Self::IS_ZST && self.cap > 0 {
+                let layout = Layout::array::<T>(self.cap).unwrap();
+                // SAFETY: 불변식 2 - 같은 layout으로 할당됨
+                unsafe {
+                    alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout);
                 }
-                // 메모리 해제
-                let layout = std::alloc::Layout::array::<T>(self.cap).unwrap();
+            }
+        }
+    }
+
+    // Deref<Target=[T]> - 슬라이스의 모든 메서드(iter, len, sort, 인덱싱...)를 공짜로 얻음
+    // C++: std::span<T>으로의 암시적 변환과 유사
+    impl<T> Deref for MyVec<T> {
+        type Target = [T];
+
+        fn deref(&self) -> &[T] {
+            // SAFETY: ptr은 non-null/정렬됨(불변식 1), ptr[0..len]은 초기화됨(불변식 3)
+            unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    impl<T> DerefMut for MyVec<T> {
+        fn deref_mut(&mut self) -> &mut [T] {
+            // SAFETY: deref와 같음 + &mut self로 독점 접근 보장
+            unsafe { std::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    // 소유권을 가져가는 이터레이터 - for x in my_vec { ... }
+    // 남은 요소는 [start, end) 범위에 있음
+    pub struct IntoIter<T> {
+        buf: NonNull<T>,
+        cap: usize,
+        start: usize,
+        end: usize,
+        _marker: PhantomData<T>,
+    }
+
+    // SAFETY: MyVec과 같은 이유 - 남은 T 값들을 독점 소유
+    unsafe impl<T: Send> Send for IntoIter<T> {}
+    unsafe impl<T: Sync> Sync for IntoIter<T> {}
+
+    impl<T> IntoIterator for MyVec<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+
+        fn into_iter(self) -> IntoIter<T> {
+            // MyVec의 Drop이 실행되지 않도록 막고 버퍼 소유권을 IntoIter로 넘김
+            let vec = ManuallyDrop::new(self);
+            IntoIter {
+                buf: vec.ptr,
+                cap: vec.cap,
+                start: 0,
+                end: vec.len,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<T> Iterator for IntoIter<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            if self.start == self.end {
+                None
+            } else {
+                // SAFETY: [start, end)는 아직 꺼내지 않은 초기화된 요소
+                let value = unsafe { ptr::read(self.buf.as_ptr().add(self.start)) };
+                self.start += 1;
+                Some(value)
+            }
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.end - self.start;
+            (remaining, Some(remaining))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for IntoIter<T> {
+        fn next_back(&mut self) -> Option<T> {
+            if self.start == self.end {
+                None
+            } else {
+                self.end -= 1;
+                // SAFETY: next()와 같음 - end를 줄였으므로 다시 읽지 않음
+                unsafe { Some(ptr::read(self.buf.as_ptr().add(self.end))) }
+            }
+        }
+    }
+
+    impl<T> ExactSizeIterator for IntoIter<T> {}
+
+    impl<T> Drop for IntoIter<T> {
+        fn drop(&mut self) {
+            // 꺼내지 않은 요소 drop 후 버퍼 해제
+            for _ in &mut *self {}
+            if mem::size_of::<T>() != 0 && self.cap > 0 {
+                let layout = Layout::array::<T>(self.cap).unwrap();
+                // SAFETY: MyVec에서 넘겨받은 버퍼 - 같은 layout으로 할당됨
                 unsafe {
-                    std::alloc::dealloc(self.ptr as *mut u8, layout);
+                    alloc::dealloc(self.buf.as_ptr() as *mut u8, layout);
                 }
             }
         }
     }
+
+    // 안전한 추상화는 테스트로 불변식을 검증하는 것이 관례
+    // cargo miri test 로 실행하면 UB까지 검사 가능
+    #[cfg(test)]
+    mod tests {
+        use super::MyVec;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        // drop 횟수를 세는 헬퍼 - 이중 해제/누수 검출용
+        struct DropCounter {
+            drops: Rc<Cell<usize>>,
+        }
+
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+            }
+        }
+
+        fn counters(n: usize, drops: &Rc<Cell<usize>>) -> MyVec<DropCounter> {
+            let mut v = MyVec::new();
+            for _ in 0..n {
+                v.push(DropCounter { drops: Rc::clone(drops) });
+            }
+            v
+        }
+
+        #[test]
+        fn new_is_empty() {
+            let v: MyVec<i32> = MyVec::new();
+            assert!(v.is_empty());
+            assert_eq!(v.len(), 0);
+            assert_eq!(v.capacity(), 0);
+            assert_eq!(v.get(0), None);
+            assert_eq!(&*v, &[] as &[i32]);
+        }
+
+        #[test]
+        fn push_and_get() {
+            let mut v = MyVec::new();
+            for i in 0..100 {
+                v.push(i);
+            }
+            assert_eq!(v.len(), 100);
+            assert!(v.capacity() >= 100);
+            assert_eq!(v.get(0), Some(&0));
+            assert_eq!(v.get(99), Some(&99));
+            assert_eq!(v.get(100), None);
+        }
+
+        #[test]
+        fn spare_capacity_is_the_uninit_tail() {
+            let mut v: MyVec<i32> = MyVec::new();
+            assert!(v.spare_capacity_mut().is_empty());
+            for i in 0..3 {
+                v.push(i);
+            }
+            // 용량 4, 길이 3 - 남은 칸에 써도 요소가 아님
+            assert_eq!(v.spare_capacity_mut().len(), v.capacity() - v.len());
+            v.spare_capacity_mut()[0].write(99);
+            assert_eq!(&*v, &[0, 1, 2]);
+            // push 가 같은 칸을 덮어씀
+            v.push(3);
+            assert_eq!(&*v, &[0, 1, 2, 3]);
+            assert!(v.spare_capacity_mut().is_empty());
+        }
+
+        #[test]
+        fn pop_returns_in_reverse_order() {
+            let mut v = MyVec::new();
+            v.push(1);
+            v.push(2);
+            v.push(3);
+            assert_eq!(v.pop(), Some(3));
+            assert_eq!(v.pop(), Some(2));
+            assert_eq!(v.pop(), Some(1));
+            assert_eq!(v.pop(), None);
+            assert!(v.is_empty());
+        }
+
+        #[test]
+        fn insert_at_front_middle_and_end() {
+            let mut v = MyVec::new();
+            v.insert(0, 2);
+            v.insert(0, 0);
+            v.insert(1, 1);
+            v.insert(3, 3);
+            assert_eq!(&*v, &[0, 1, 2, 3]);
+        }
+
+        #[test]
+        #[should_panic(expected = "insert index 2 out of bounds")]
+        fn insert_out_of_bounds_panics() {
+            let mut v = MyVec::new();
+            v.push(1);
+            v.insert(2, 5);
+        }
+
+        #[test]
+        fn remove_shifts_elements() {
+            let mut v = MyVec::new();
+            for i in 0..5 {
+                v.push(i);
+            }
+            assert_eq!(v.remove(0), 0);
+            assert_eq!(v.remove(3), 4);
+            assert_eq!(v.remove(1), 2);
+            assert_eq!(&*v, &[1, 3]);
+        }
+
+        #[test]
+        #[should_panic(expected = "remove index 0 out of bounds")]
+        fn remove_from_empty_panics() {
+            let mut v: MyVec<i32> = MyVec::new();
+            v.remove(0);
+        }
+
+        #[test]
+        fn deref_gives_slice_methods() {
+            let mut v = MyVec::new();
+            v.push(3);
+            v.push(1);
+            v.push(2);
+            // DerefMut으로 슬라이스 메서드 사용
+            v.sort();
+            assert_eq!(&*v, &[1, 2, 3]);
+            assert_eq!(v[0], 1);
+            assert_eq!(v.iter().sum::<i32>(), 6);
+            assert!(v.contains(&2));
+            v[2] = 30;
+            assert_eq!(v.last(), Some(&30));
+        }
+
+        #[test]
+        fn into_iter_yields_owned_values() {
+            let mut v = MyVec::new();
+            v.push(String::from("a"));
+            v.push(String::from("b"));
+            v.push(String::from("c"));
+            let mut it = v.into_iter();
+            assert_eq!(it.len(), 3);
+            assert_eq!(it.next().as_deref(), Some("a"));
+            assert_eq!(it.next_back().as_deref(), Some("c"));
+            assert_eq!(it.next().as_deref(), Some("b"));
+            assert_eq!(it.next(), None);
+            assert_eq!(it.next_back(), None);
+        }
+
+        #[test]
+        fn into_iter_collects() {
+            let mut v = MyVec::new();
+            for i in 1..=4 {
+                v.push(i);
+            }
+            let doubled: Vec<i32> = v.into_iter().map(|x| x * 2).collect();
+            assert_eq!(doubled, vec![2, 4, 6, 8]);
+        }
+
+        #[test]
+        fn drop_runs_once_per_element() {
+            let drops = Rc::new(Cell::new(0));
+            let v = counters(10, &drops);
+            assert_eq!(drops.get(), 0);
+            drop(v);
+            assert_eq!(drops.get(), 10);
+        }
+
+        #[test]
+        fn pop_and_remove_transfer_ownership() {
+            let drops = Rc::new(Cell::new(0));
+            let mut v = counters(4, &drops);
+            let popped = v.pop().unwrap();
+            let removed = v.remove(0);
+            assert_eq!(drops.get(), 0);
+            drop(popped);
+            drop(removed);
+            assert_eq!(drops.get(), 2);
+            drop(v);
+            assert_eq!(drops.get(), 4);
+        }
+
+        #[test]
+        fn partially_consumed_into_iter_drops_rest() {
+            let drops = Rc::new(Cell::new(0));
+            let mut it = counters(5, &drops).into_iter();
+            drop(it.next());
+            drop(it.next_back());
+            assert_eq!(drops.get(), 2);
+            drop(it);
+            assert_eq!(drops.get(), 5);
+        }
+
+        #[test]
+        fn zero_sized_types() {
+            let mut v = MyVec::new();
+            for _ in 0..1000 {
+                v.push(());
+            }
+            assert_eq!(v.len(), 1000);
+            assert_eq!(v.capacity(), usize::MAX);
+            v.insert(500, ());
+            assert_eq!(v.remove(0), ());
+            assert_eq!(v.pop(), Some(()));
+            assert_eq!(v.into_iter().count(), 999);
+        }
+
+        #[test]
+        fn send_and_sync_follow_element_type() {
+            fn assert_send<T: Send>() {}
+            fn assert_sync<T: Sync>() {}
+            assert_send::<MyVec<i32>>();
+            assert_sync::<MyVec<i32>>();
+            assert_send::<super::IntoIter<String>>();
+            // MyVec<Rc<i32>>는 Send가 아니므로 아래는 컴파일 에러
+            // assert_send::<MyVec<Rc<i32>>>();
+
+            let mut v = MyVec::new();
+            v.push(1);
+            v.push(2);
+            let handle = std::thread::spawn(move || v.iter().sum::<i32>());
+            assert_eq!(handle.join().unwrap(), 3);
+        }
+    }
 }
 
 fn safe_abstractions() {
@@ -253,14 +701,316 @@ fn safe_abstractions() {
     v.push(2);
     v.push(3);
 
-    println!("MyVec 길이: {}", v.len());
+    println!("MyVec 길이: {}, 용량: {}", v.len(), v.capacity());
     println!("인덱스 1: {:?}", v.get(1));
     println!("인덱스 10: {:?}", v.get(10));
 
+    // insert/remove - 내부적으로 ptr::copy (memmove)
+    v.insert(0, 0);
+    let removed = v.remove(2);
+    println!("insert(0, 0) 후 remove(2) = {}, 현재: {:?}", removed, &*v);
+    println!("pop: {:?}", v.pop());
+
+    // Deref<Target=[T]> - 슬라이스 메서드를 그대로 사용
+    v.push(10);
+    v.reverse();
+    println!("슬라이스 메서드 reverse(): {:?}, 합계: {}", &*v, v.iter().sum::<i32>());
+
+    // IntoIterator - 소유권을 가져가는 반복
+    let strings: Vec<String> = v.into_iter().map(|x| format!("#{}", x)).collect();
+    println!("into_iter(): {:?}", strings);
+    // println!("{}", v.len());  // 에러! v는 into_iter()로 이동됨
+
+    // Send - T가 Send이므로 다른 스레드로 이동 가능
+    let mut shared = MyVec::new();
+    shared.push(String::from("스레드로 이동"));
+    let handle = std::thread::spawn(move || shared.len());
+    println!("다른 스레드에서 길이: {}", handle.join().unwrap());
+
     // 사용자는 unsafe 없이 안전하게 사용
     // 내부 구현의 정확성은 라이브러리 작성자가 보장
 }
 
+// ----------------------------------------------------------------------------
+// unsafe 코드 검증 - 동등성 검사
+// ----------------------------------------------------------------------------
+// 컴파일러는 unsafe 블록 안의 정확성을 보장하지 못함 → 안전한 참조 구현과 비교
+// 재현 가능한 입력을 많이 만들어 두 구현에 넣고 결과가 모두 같은지 확인 (차분 테스트)
+// C++: 직접 만든 컨테이너를 std:: 구현과 같은 입력으로 돌려 보며 퍼징하는 것과 같음
+// 아래 tests 와 이 절의 예제가 같은 검사 도구를 씀 - pub(crate) 라 다른 장의 테스트에서도 사용 가능
+// 같은 비교를 proptest 전략으로 - 실패한 연산 목록을 자동으로 줄여 주는 버전은 42장
+
+pub(crate) mod equivalence {
+    use std::fmt::Debug;
+
+    use super::safe_wrapper::MyVec;
+
+    // 입력 생성기 (splitmix64) - 시드가 같으면 같은 입력, 실패한 경우를 그대로 다시 만들 수 있음
+    pub struct Gen {
+        state: u64,
+    }
+
+    impl Gen {
+        pub fn new(seed: u64) -> Self {
+            Gen { state: seed }
+        }
+
+        pub fn next_u64(&mut self) -> u64 {
+            self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        // 0..=max
+        pub fn up_to(&mut self, max: usize) -> usize {
+            (self.next_u64() % (max as u64 + 1)) as usize
+        }
+
+        // -100..=100
+        pub fn value(&mut self) -> i32 {
+            self.up_to(200) as i32 - 100
+        }
+
+        // 길이 0..=max_len - 빈 입력도 섞이도록
+        pub fn vec(&mut self, max_len: usize) -> Vec<i32> {
+            let len = self.up_to(max_len);
+            (0..len).map(|_| self.value()).collect()
+        }
+    }
+
+    // 두 구현의 결과가 처음으로 달라진 입력
+    #[derive(Debug)]
+    pub struct Mismatch<I, O> {
+        pub case: usize,
+        pub input: I,
+        pub reference: O,
+        pub candidate: O,
+    }
+
+    // cases 개의 입력으로 비교 - 모두 같으면 검사한 개수
+    pub fn check<I, O>(
+        cases: usize,
+        seed: u64,
+        mut input: impl FnMut(&mut Gen) -> I,
+        reference: impl Fn(I) -> O,
+        candidate: impl Fn(I) -> O,
+    ) -> Result<usize, Mismatch<I, O>>
+    where
+        I: Clone + Debug,
+        O: PartialEq + Debug,
+    {
+        let mut gen = Gen::new(seed);
+        for case in 0..cases {
+            let i = input(&mut gen);
+            let r = reference(i.clone());
+            let c = candidate(i.clone());
+            if r != c {
+                return Err(Mismatch { case, input: i, reference: r, candidate: c });
+            }
+        }
+        Ok(cases)
+    }
+
+    // 컨테이너 연산 하나 - 인덱스는 적용할 때 길이에 맞게 줄임
+    #[derive(Debug, Clone, Copy)]
+    pub enum Op {
+        Push(i32),
+        Pop,
+        Insert(usize, i32),
+        Remove(usize),
+    }
+
+    pub fn ops(gen: &mut Gen, max_len: usize) -> Vec<Op> {
+        let len = gen.up_to(max_len);
+        (0..len)
+            .map(|_| match gen.up_to(3) {
+                0 => Op::Push(gen.value()),
+                1 => Op::Pop,
+                2 => Op::Insert(gen.up_to(64), gen.value()),
+                _ => Op::Remove(gen.up_to(64)),
+            })
+            .collect()
+    }
+
+    // 비교할 컨테이너가 구현하는 연산 - Vec 과 직접 만든 컨테이너를 같은 코드로 돌리기 위함
+    pub trait Sequence: Default {
+        fn push(&mut self, value: i32);
+        fn pop(&mut self) -> Option<i32>;
+        fn insert(&mut self, index: usize, value: i32);
+        fn remove(&mut self, index: usize) -> i32;
+        fn items(&self) -> &[i32];
+    }
+
+    impl Sequence for Vec<i32> {
+        fn push(&mut self, value: i32) {
+            Vec::push(self, value)
+        }
+        fn pop(&mut self) -> Option<i32> {
+            Vec::pop(self)
+        }
+        fn insert(&mut self, index: usize, value: i32) {
+            Vec::insert(self, index, value)
+        }
+        fn remove(&mut self, index: usize) -> i32 {
+            Vec::remove(self, index)
+        }
+        fn items(&self) -> &[i32] {
+            self
+        }
+    }
+
+    impl Sequence for MyVec<i32> {
+        fn push(&mut self, value: i32) {
+            MyVec::push(self, value)
+        }
+        fn pop(&mut self) -> Option<i32> {
+            MyVec::pop(self)
+        }
+        fn insert(&mut self, index: usize, value: i32) {
+            MyVec::insert(self, index, value)
+        }
+        fn remove(&mut self, index: usize) -> i32 {
+            MyVec::remove(self, index)
+        }
+        fn items(&self) -> &[i32] {
+            self
+        }
+    }
+
+    // 연산마다 (돌려받은 값, 그때의 내용) - 중간 상태까지 비교
+    pub fn apply<S: Sequence>(ops: &[Op]) -> Vec<(Option<i32>, Vec<i32>)> {
+        let mut s = S::default();
+        let mut trace = Vec::new();
+        for op in ops {
+            let len = s.items().len();
+            let returned = match *op {
+                Op::Push(v) => {
+                    s.push(v);
+                    None
+                }
+                Op::Pop => s.pop(),
+                Op::Insert(i, v) => {
+                    s.insert(i % (len + 1), v);
+                    None
+                }
+                Op::Remove(_) if len == 0 => None,
+                Op::Remove(i) => Some(s.remove(i % len)),
+            };
+            trace.push((returned, s.items().to_vec()));
+        }
+        trace
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::split_at_mut;
+        use super::*;
+
+        #[test]
+        fn split_at_mut_matches_std() {
+            let checked = check(
+                500,
+                1,
+                |g| {
+                    let v = g.vec(20);
+                    let mid = g.up_to(v.len());
+                    (v, mid)
+                },
+                |(mut v, mid)| {
+                    let (a, b) = v.split_at_mut(mid);
+                    (a.to_vec(), b.to_vec())
+                },
+                |(mut v, mid)| {
+                    let (a, b) = split_at_mut(&mut v, mid);
+                    (a.to_vec(), b.to_vec())
+                },
+            );
+            assert_eq!(checked.unwrap(), 500);
+        }
+
+        #[test]
+        fn my_vec_matches_vec() {
+            let checked = check(300, 2, |g| ops(g, 40), |o| apply::<Vec<i32>>(&o), |o| apply::<MyVec<i32>>(&o));
+            assert_eq!(checked.unwrap(), 300);
+        }
+
+        #[test]
+        fn mismatch_reports_first_failing_input() {
+            // 길이가 3 이상이면 틀리는 구현
+            let err = check(
+                100,
+                3,
+                |g| g.vec(10),
+                |v| v.iter().sum::<i32>(),
+                |v| v.iter().take(2).sum::<i32>(),
+            )
+            .unwrap_err();
+            assert!(err.input.len() >= 3);
+            assert_ne!(err.reference, err.candidate);
+
+            // 같은 시드는 같은 입력
+            let (mut a, mut b) = (Gen::new(9), Gen::new(9));
+            assert_eq!(a.vec(50), b.vec(50));
+        }
+    }
+}
+
+fn validating_unsafe() {
+    println!("\n--- unsafe 코드 검증 (동등성 검사) ---");
+
+    use equivalence::{apply, check, ops};
+    use safe_wrapper::MyVec;
+
+    // 직접 만든 split_at_mut vs 표준 라이브러리 - 길이 0..=20, 분할 위치는 0..=len
+    let result = check(
+        1000,
+        16,
+        |g| {
+            let v = g.vec(20);
+            let mid = g.up_to(v.len());
+            (v, mid)
+        },
+        |(mut v, mid)| {
+            let (a, b) = v.split_at_mut(mid);
+            (a.to_vec(), b.to_vec())
+        },
+        |(mut v, mid)| {
+            let (a, b) = split_at_mut(&mut v, mid);
+            (a.to_vec(), b.to_vec())
+        },
+    );
+    println!("split_at_mut 과 표준 라이브러리: {:?}", result.map(|n| format!("{}개 입력 모두 같음", n)));
+
+    // MyVec vs Vec - push/pop/insert/remove 를 무작위 순서로, 연산마다 내용까지 비교
+    let result = check(300, 16, |g| ops(g, 40), |o| apply::<Vec<i32>>(&o), |o| apply::<MyVec<i32>>(&o));
+    println!("MyVec 과 Vec: {:?}", result.map(|n| format!("연산 묶음 {}개 모두 같음", n)));
+
+    // 버그가 있는 구현은 첫 불일치 입력을 보여줌 - 분할 위치가 하나 어긋난 경우
+    let result = check(
+        1000,
+        16,
+        |g| {
+            let v = g.vec(20);
+            let mid = g.up_to(v.len());
+            (v, mid)
+        },
+        |(v, mid)| v.split_at(mid).0.len(),
+        |(mut v, mid)| split_at_mut(&mut v, mid.saturating_sub(1)).0.len(),
+    );
+    match result {
+        Ok(n) => println!("버그를 찾지 못함 ({}개 입력)", n),
+        Err(m) => println!(
+            "버그 발견: {}번째 입력 {:?} → 참조 {:?}, 후보 {:?}",
+            m.case, m.input, m.reference, m.candidate
+        ),
+    }
+
+    // 검사는 증명이 아님 - 생성한 입력에서만 같다는 뜻
+    // Miri(cargo +nightly miri test)와 함께 쓰면 같은 입력에서 정의되지 않은 동작까지 찾아냄
+}
+
 // ----------------------------------------------------------------------------
 // FFI (Foreign Function Interface)
 // ----------------------------------------------------------------------------
@@ -272,6 +1022,7 @@ extern "C" {
 }
 
 // Rust 함수를 C에서 호출 가능하게 만들기
+// 라이브러리 전체를 cdylib 로 내보내고 cbindgen 으로 헤더를 만드는 법은 45장
 #[no_mangle]
 pub extern "C" fn rust_function(x: i32) -> i32 {
     x * 2
@@ -294,6 +1045,7 @@ fn ffi_example() {
     // - extern "C"로 C ABI 사용
     // - #[repr(C)]로 C 호환 메모리 레이아웃
     // - bindgen 크레이트로 C 헤더에서 자동 바인딩 생성
+    // - 직접 쓴 C++ 코드를 build.rs 로 컴파일해 부르는 법과 cxx 크레이트는 44장
 
     // C 호환 구조체
     #[repr(C)]
@@ -314,37 +1066,38 @@ fn ffi_example() {
 // ----------------------------------------------------------------------------
 // 정적 가변 변수
 // ----------------------------------------------------------------------------
+// static mut 는 접근할 때마다 unsafe - 여러 스레드가 동시에 쓰면 데이터 레이스 (UB)
+// 2024 에디션은 &COUNTER 처럼 static mut 의 참조를 만드는 것도 에러 (static_mut_refs)
+// 가변 전역이 필요하면 내부 가변성을 가진 타입의 평범한 static 으로 - 정수는 Atomic*, 그 밖은 Mutex
 
-static mut COUNTER: u32 = 0;
+// static mut COUNTER: usize = 0;
+// fn add_to_counter(inc: usize) { unsafe { COUNTER += inc; } }   // 두 스레드가 부르면 더한 값을 잃을 수 있음
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
 
-fn add_to_counter(inc: u32) {
-    unsafe {
-        COUNTER += inc;
-    }
+fn add_to_counter(inc: usize) {
+    // 읽고 더하고 쓰기가 하나의 원자적 연산 - unsafe 가 필요 없음
+    COUNTER.fetch_add(inc, Ordering::Relaxed);
 }
 
 fn static_mut_variables() {
     println!("\n--- 정적 가변 변수 ---");
 
-    // 가변 정적 변수 접근은 항상 unsafe
-    // 멀티스레드에서 데이터 레이스 가능성
+    // C++ 에서는:
+    // static int counter = 0;                  // 전역 가변 변수 - 여러 스레드에서 ++counter 는 데이터 레이스 (UB)
+    // static std::atomic<std::size_t> counter;  // Rust 의 AtomicUsize 와 같음
 
     add_to_counter(3);
     add_to_counter(5);
 
-    unsafe {
-        println!("COUNTER = {}", COUNTER);
-    }
-
-    // 더 안전한 대안: AtomicU32, Mutex 등 사용
-    use std::sync::atomic::{AtomicU32, Ordering};
-
-    static SAFE_COUNTER: AtomicU32 = AtomicU32::new(0);
-
-    SAFE_COUNTER.fetch_add(1, Ordering::SeqCst);
-    SAFE_COUNTER.fetch_add(2, Ordering::SeqCst);
+    // 스레드 여럿이 동시에 더해도 잃는 값이 없음 - 다른 데이터를 보호하지 않는 카운터라 Relaxed 로 충분 (Atomic 과 Mutex 비교는 52장)
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| add_to_counter(1));
+        }
+    });
+    println!("COUNTER = {}", COUNTER.load(Ordering::Relaxed));
 
-    println!("SAFE_COUNTER = {}", SAFE_COUNTER.load(Ordering::SeqCst));
+    // 처음 쓸 때 초기화하는 전역 (설정, 표, 레지스트리) 은 OnceLock, LazyLock - 52장
 }
 
 // ----------------------------------------------------------------------------
@@ -13,22 +13,50 @@
 use std::time::Duration;
 use tokio::time::sleep;
 
-pub fn run() {
-    println!("\n=== 17. 비동기 프로그래밍 ===\n");
-
-    // 비동기 코드 실행을 위해 tokio 런타임 생성
-    let rt = tokio::runtime::Runtime::new().unwrap();
-
-    rt.block_on(async {
-        async_basics().await;
-        futures_explained().await;
-        concurrent_tasks().await;
-        channels_async().await;
-        select_example().await;
-        error_handling_async().await;
-    });
+// 절 목록 (실행 순서) - cargo run -- 17:async_basics 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("async_basics", || block_on(async_basics())),
+    ("futures_explained", || block_on(futures_explained())),
+    ("concurrent_tasks", || block_on(concurrent_tasks())),
+    ("channels_async", || block_on(channels_async())),
+    ("select_example", || block_on(select_example())),
+    ("streams_example", || block_on(streams_example())),
+    ("broadcast_example", || block_on(broadcast_example())),
+    ("watch_example", || block_on(watch_example())),
+    ("interval_example", || block_on(interval_example())),
+    ("error_handling_async", || block_on(error_handling_async())),
+    ("sync_vs_async_comparison", sync_vs_async_comparison),
+];
+
+// 비동기 절은 각자 tokio 런타임을 만들어 실행 - 절 하나만 실행할 때도 그대로 동작
+fn block_on(future: impl std::future::Future<Output = ()>) {
+    // 런타임을 만들 수 없으면(스레드 생성 실패 등) 예제를 진행할 수 없으므로 unwrap
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "17"
+    }
+
+    fn name(&self) -> &'static str {
+        "비동기 프로그래밍 (Async/Await)"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rust의 Future는 lazy - poll될 때만 실행 (C++ coroutine도 유사)"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["async/await", "Future", "tokio", "select!", "Stream"]
+    }
 
-    sync_vs_async_comparison();
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -125,6 +153,8 @@ async fn futures_explained() {
     //     type Output;
     //     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
     // }
+    // Pin 을 받는 이유 (자기 참조 상태 기계): 29장
+    // 트레이트 메서드를 async fn 으로 (dyn, Send 바운드): 31장
 
     // Poll 열거형:
     // enum Poll<T> {
@@ -291,6 +321,196 @@ async fn select_example() {
     // C++에는 직접적인 대응이 없음 (직접 구현 필요)
 }
 
+// ----------------------------------------------------------------------------
+// Stream - 비동기 이터레이터
+// ----------------------------------------------------------------------------
+// Iterator::next()   -> Option<T>
+// Stream::next()     -> impl Future<Output = Option<T>>
+// C++20: std::generator는 동기 - 비동기 버전은 표준에 없음
+
+async fn streams_example() {
+    println!("\n--- Stream (tokio_stream) ---");
+    // 더 자세히 (직접 구현, 묶음과 동시 처리, StreamMap): 30장
+
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use tokio_stream::StreamExt; // next(), map(), filter() 등 어댑터
+
+    // 이터레이터에서 스트림 만들기
+    let mut stream = tokio_stream::iter(vec![1, 2, 3]);
+    while let Some(v) = stream.next().await {
+        println!("iter 스트림: {}", v);
+    }
+
+    // 이터레이터와 같은 어댑터 체이닝
+    let evens: Vec<i32> = tokio_stream::iter(1..=10)
+        .filter(|x| x % 2 == 0)
+        .map(|x| x * 10)
+        .collect()
+        .await;
+    println!("filter + map + collect: {:?}", evens);
+
+    // 채널 수신자를 스트림으로 감싸기
+    let (tx, rx) = mpsc::channel::<&str>(4);
+    tokio::spawn(async move {
+        for word in ["하나", "둘", "셋"] {
+            let _ = tx.send(word).await;
+        }
+    });
+
+    // timeout 어댑터 - 각 항목에 시간 제한
+    // 내부에 Sleep(!Unpin)을 가지므로 next() 호출 전에 pin 필요
+    let words = ReceiverStream::new(rx).timeout(Duration::from_millis(100));
+    tokio::pin!(words);
+    while let Some(item) = words.next().await {
+        match item {
+            Ok(word) => println!("ReceiverStream: {}", word),
+            Err(_) => println!("항목 타임아웃"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// broadcast 채널 - 팬아웃 (모든 구독자가 모든 메시지 수신)
+// ----------------------------------------------------------------------------
+
+async fn broadcast_example() {
+    println!("\n--- broadcast 채널 ---");
+
+    use tokio::sync::broadcast;
+
+    // mpsc: 메시지 하나를 소비자 하나가 받음
+    // broadcast: 메시지 하나를 모든 구독자가 받음 (T: Clone 필요)
+    let (tx, _) = broadcast::channel::<String>(16);
+
+    let mut handles = Vec::new();
+    for id in 1..=3 {
+        // subscribe() 이후에 보낸 메시지만 수신
+        let mut rx = tx.subscribe();
+        handles.push(tokio::spawn(async move {
+            let mut received = Vec::new();
+            while let Ok(msg) = rx.recv().await {
+                received.push(msg);
+            }
+            println!("구독자 {}: {:?}", id, received);
+        }));
+    }
+
+    for event in ["시작", "진행", "종료"] {
+        tx.send(event.to_string()).unwrap();
+    }
+    // 송신자를 모두 drop하면 recv()가 Err(Closed) 반환
+    drop(tx);
+
+    for h in handles {
+        h.await.unwrap();
+    }
+
+    // 느린 구독자 - 버퍼(용량)를 넘으면 오래된 메시지를 놓침
+    let (tx, mut slow) = broadcast::channel::<i32>(2);
+    for i in 0..5 {
+        tx.send(i).unwrap();
+    }
+    match slow.recv().await {
+        Err(broadcast::error::RecvError::Lagged(n)) => println!("느린 구독자: {}개 놓침", n),
+        other => println!("예상 밖: {:?}", other),
+    }
+    println!("놓친 뒤 다음 메시지: {:?}", slow.recv().await);
+}
+
+// ----------------------------------------------------------------------------
+// watch 채널 - 최신 값 하나만 유지 (설정 전파에 적합)
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+struct AppConfig {
+    log_level: &'static str,
+    max_connections: u32,
+}
+
+async fn watch_example() {
+    println!("\n--- watch 채널 ---");
+
+    use tokio::sync::watch;
+
+    let (tx, mut rx) = watch::channel(AppConfig {
+        log_level: "info",
+        max_connections: 100,
+    });
+
+    // 워커는 설정이 바뀔 때마다 깨어남
+    let worker = tokio::spawn(async move {
+        // changed()는 마지막으로 본 이후 값이 바뀌면 완료
+        // 송신자가 drop되면 Err 반환
+        while rx.changed().await.is_ok() {
+            // borrow_and_update() - 값을 읽고 "봤음"으로 표시
+            let config = rx.borrow_and_update().clone();
+            println!("워커: 새 설정 적용 {:?}", config);
+        }
+        println!("워커: 설정 채널 종료");
+    });
+
+    // 현재 값 읽기 - 대기 없음
+    println!("초기 설정: {:?}", *tx.borrow());
+
+    sleep(Duration::from_millis(10)).await;
+    tx.send_modify(|c| c.log_level = "debug");
+    sleep(Duration::from_millis(10)).await;
+
+    // 중간 값은 건너뛸 수 있음 - 수신자는 항상 "최신" 값만 봄
+    tx.send_modify(|c| c.max_connections = 200);
+    tx.send_modify(|c| c.max_connections = 500);
+    sleep(Duration::from_millis(10)).await;
+
+    drop(tx);
+    worker.await.unwrap();
+
+    // C++: std::atomic<std::shared_ptr<Config>> + condition_variable 조합과 유사
+}
+
+// ----------------------------------------------------------------------------
+// interval - 주기적 작업
+// ----------------------------------------------------------------------------
+
+async fn interval_example() {
+    println!("\n--- interval 주기 작업 ---");
+
+    use tokio::time::{interval, Instant, MissedTickBehavior};
+
+    let start = Instant::now();
+
+    // sleep 루프와 달리 작업 시간과 무관하게 일정한 주기 유지
+    let mut ticker = interval(Duration::from_millis(20));
+    // 작업이 늦어져 틱을 놓쳤을 때의 정책 (기본값: Burst)
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    for i in 0..3 {
+        // 첫 tick()은 즉시 완료
+        ticker.tick().await;
+        println!("tick {} (+{}ms)", i, start.elapsed().as_millis());
+    }
+
+    // 백그라운드 하트비트 + 종료 신호
+    let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel::<()>();
+    let heartbeat = tokio::spawn(async move {
+        let mut ticker = interval(Duration::from_millis(15));
+        let mut beats = 0;
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => beats += 1,
+                _ = &mut stop_rx => break,
+            }
+        }
+        beats
+    });
+
+    sleep(Duration::from_millis(50)).await;
+    let _ = stop_tx.send(());
+    println!("하트비트 횟수: {}", heartbeat.await.unwrap());
+
+    // tokio_stream::wrappers::IntervalStream 으로 스트림처럼 쓸 수도 있음
+}
+
 // ----------------------------------------------------------------------------
 // 비동기 에러 처리
 // ----------------------------------------------------------------------------
@@ -370,7 +590,7 @@ fn sync_vs_async_comparison() {
 ");
 
     println!("언제 비동기를 사용할까?");
-    println!("✓ 네트워크 I/O (HTTP 서버, 클라이언트)");
+    println!("✓ 네트워크 I/O (HTTP 서버, 클라이언트)"); // 38장에서 직접 만들어 봄
     println!("✓ 파일 I/O (많은 파일 동시 처리)");
     println!("✓ 타이머, 지연");
     println!("✓ 많은 동시 연결");
@@ -12,17 +12,44 @@
 use std::fmt;
 use std::ops::Deref;
 
-pub fn run() {
-    println!("\n=== 18. 실무 Rust Idiom ===\n");
-
-    builder_pattern();
-    newtype_pattern();
-    typestate_pattern();
-    from_into_pattern();
-    default_pattern();
-    deref_coercion();
-    raii_pattern();
-    error_handling_best_practices();
+// 절 목록 (실행 순서) - cargo run -- 18:builder_pattern 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("builder_pattern", builder_pattern),
+    ("newtype_pattern", newtype_pattern),
+    ("typestate_pattern", typestate_pattern),
+    ("from_into_pattern", from_into_pattern),
+    ("default_pattern", default_pattern),
+    ("deref_coercion", deref_coercion),
+    ("raii_pattern", raii_pattern),
+    ("error_handling_best_practices", error_handling_best_practices),
+    ("extension_trait_pattern", extension_trait_pattern),
+    ("scope_guard_pattern", scope_guard_pattern),
+    ("newtype_deref_antipattern", newtype_deref_antipattern),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "18"
+    }
+
+    fn name(&self) -> &'static str {
+        "실무 Rust Idiom"
+    }
+
+    fn description(&self) -> &'static str {
+        "빌더 패턴이 소유권과 결합되어 더 안전함"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["빌더", "Newtype", "타입 스테이트", "From/Into", "RAII"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ============================================================================
@@ -116,6 +143,28 @@ fn builder_pattern() {
     // - Rust는 소유권으로 빌더 재사용 방지 가능
     // - Option으로 선택적 필드 명확히 표현
     // - Result로 빌드 실패 처리
+
+    // 필드가 많은 단순 빌더는 매크로로 보일러플레이트 제거
+    // builder_field!는 15장에서 정의한 매크로 (crate::macros 모듈)
+    use crate::macros::builder_field;
+
+    #[derive(Debug)]
+    struct RetryPolicy {
+        max_retries: u32,
+        backoff_ms: u64,
+    }
+
+    impl RetryPolicy {
+        fn new() -> Self {
+            RetryPolicy { max_retries: 3, backoff_ms: 100 }
+        }
+
+        builder_field!(max_retries, u32);
+        builder_field!(backoff_ms, u64);
+    }
+
+    let policy = RetryPolicy::new().max_retries(5).backoff_ms(250);
+    println!("매크로로 만든 빌더: {:?}", policy);
 }
 
 // ============================================================================
@@ -169,7 +218,7 @@ fn newtype_pattern() {
     let user = UserId(42);
     let _order = OrderId(1001);
 
-    // get_user_orders(order);  // 컴파일 에러! OrderId는 UserId가 아님
+    // get_user_orders(_order);  // 컴파일 에러! OrderId는 UserId가 아님
     let orders = get_user_orders(user);
     println!("사용자 {:?}의 주문: {:?}", user, orders);
 
@@ -658,3 +707,213 @@ fn error_handling_best_practices() {
     println!("3. 에러 체인으로 컨텍스트 보존");
     println!("4. Display로 사용자 메시지, Debug로 개발자 정보");
 }
+
+// ============================================================================
+// 9. 확장 트레이트 (Extension Trait)
+// ============================================================================
+
+// 외부 타입(str)에 메서드 추가 - 고아 규칙(orphan rule) 안에서 가능
+// C++: 멤버 함수는 추가 불가 -> 자유 함수(truncate_ellipsis(s, n))로 대체
+// 관례: 이름은 <타입>Ext, 사용하려면 트레이트를 use 해야 함
+trait StrExt {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String;
+    fn is_blank(&self) -> bool;
+}
+
+impl StrExt for str {
+    fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        // 바이트가 아닌 문자(char) 단위로 잘라야 한글이 깨지지 않음
+        if self.chars().count() <= max_chars {
+            return self.to_string();
+        }
+        let mut out: String = self.chars().take(max_chars.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+
+    fn is_blank(&self) -> bool {
+        self.trim().is_empty()
+    }
+}
+
+// 제네릭 확장 - 조건을 만족하는 모든 타입에 한 번에 추가 (blanket impl)
+trait IteratorExt: Iterator {
+    fn sum_by<F>(self, f: F) -> i64
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> i64,
+    {
+        self.map(f).sum()
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+fn extension_trait_pattern() {
+    println!("\n--- 확장 트레이트 ---");
+
+    let title = "러스트로 만드는 고성능 게임 서버";
+    println!("원본: {}", title);
+    println!("truncate_ellipsis(8): {}", title.truncate_ellipsis(8));
+    println!("truncate_ellipsis(100): {}", title.truncate_ellipsis(100));
+
+    // &String도 Deref로 str의 확장 메서드 사용 가능
+    let owned = String::from("   ");
+    println!("\"   \".is_blank(): {}", owned.is_blank());
+
+    // 모든 이터레이터에 추가된 메서드
+    let words = ["a", "bb", "ccc"];
+    println!("글자 수 합계: {}", words.iter().sum_by(|w| w.len() as i64));
+
+    // 표준/생태계의 예: itertools::Itertools, futures::StreamExt,
+    // tokio_stream::StreamExt (17장), std::io::Read 확장인 byteorder::ReadBytesExt
+}
+
+// ============================================================================
+// 10. 스코프 가드 (defer 패턴)
+// ============================================================================
+
+// Go의 defer, C++의 scope_exit (Library Fundamentals TS v3)와 같은 역할
+// 클로저를 보관했다가 Drop에서 실행 - 조기 반환/패닉에도 실행됨
+struct ScopeGuard<F: FnOnce()> {
+    on_exit: Option<F>,
+}
+
+impl<F: FnOnce()> ScopeGuard<F> {
+    fn new(on_exit: F) -> Self {
+        ScopeGuard { on_exit: Some(on_exit) }
+    }
+
+    // 정상 경로에서 정리 작업을 취소 (commit/rollback 패턴)
+    fn dismiss(mut self) {
+        self.on_exit = None;
+    }
+}
+
+impl<F: FnOnce()> Drop for ScopeGuard<F> {
+    fn drop(&mut self) {
+        // FnOnce는 한 번만 호출 가능 -> Option::take로 꺼내서 호출
+        if let Some(f) = self.on_exit.take() {
+            f();
+        }
+    }
+}
+
+// defer! { ... } - 변수 이름 없이 가드를 만드는 매크로
+// _guard 대신 _ 에 바인딩하면 즉시 drop 되므로 주의!
+macro_rules! defer {
+    ($($body:tt)*) => {
+        let _guard = ScopeGuard::new(|| { $($body)* });
+    };
+}
+
+fn scope_guard_pattern() {
+    println!("\n--- 스코프 가드 (defer) ---");
+
+    {
+        defer! { println!("defer: 스코프 끝에서 실행 (2)"); }
+        println!("본문 실행 (1)");
+    }
+
+    // 여러 가드는 선언의 역순으로 실행 - 지역 변수 drop 순서와 같음
+    {
+        let _a = ScopeGuard::new(|| println!("가드 A 정리"));
+        let _b = ScopeGuard::new(|| println!("가드 B 정리"));
+        println!("A, B 생성 완료");
+    }
+
+    // 트랜잭션 롤백 - 성공하면 dismiss()로 정리 취소
+    fn transfer(balance: &std::cell::Cell<i32>, amount: i32, fail: bool) -> Result<(), String> {
+        let before = balance.get();
+        balance.set(before - amount);
+        let rollback = ScopeGuard::new(|| {
+            balance.set(before);
+            println!("롤백: 잔액 {} 복구", before);
+        });
+
+        if fail {
+            return Err(String::from("송금 실패")); // rollback 가드가 실행됨
+        }
+
+        rollback.dismiss(); // 성공 - 롤백하지 않음
+        Ok(())
+    }
+
+    let balance = std::cell::Cell::new(100);
+    println!("송금 성공: {:?}, 잔액 {}", transfer(&balance, 30, false), balance.get());
+    println!("송금 실패: {:?}, 잔액 {}", transfer(&balance, 30, true), balance.get());
+
+    // 패닉 중에도 가드 실행 (unwind 시 Drop 호출)
+    // 패닉 메시지는 stderr에 출력되지만 프로그램은 계속 실행됨
+    let result = std::panic::catch_unwind(|| {
+        let _g = ScopeGuard::new(|| println!("패닉 중에도 정리 실행"));
+        panic!("의도적 패닉");
+    });
+    println!("catch_unwind 결과 is_err: {}", result.is_err());
+
+    // 실무: scopeguard 크레이트 (defer!, guard_on_success 등)
+}
+
+// ============================================================================
+// 11. 안티패턴: 상속 흉내를 위한 Newtype + Deref
+// ============================================================================
+
+fn newtype_deref_antipattern() {
+    println!("\n--- 안티패턴: Newtype + Deref ---");
+
+    // C++ 개발자가 흔히 하는 실수: "상속 대신 Deref로 부모 메서드 물려받기"
+    // class UserList : public std::vector<String> { ... };
+    struct UserList(Vec<String>);
+
+    impl Deref for UserList {
+        type Target = Vec<String>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl UserList {
+        // 불변식: 이름은 비어 있지 않아야 함
+        fn add(&mut self, name: &str) -> bool {
+            if name.is_blank() {
+                return false;
+            }
+            self.0.push(name.to_string());
+            true
+        }
+    }
+
+    let mut users = UserList(Vec::new());
+    users.add("alice");
+    users.add("   ");
+    // Vec의 메서드가 그대로 노출됨 - 편해 보이지만...
+    println!("users.len() = {}, first = {:?}", users.len(), users.first());
+
+    // 문제점:
+    // 1. Newtype의 목적(불변식/API 제한)이 무너짐 - Vec 전체 API가 공개됨
+    //    DerefMut까지 구현하면 users.push(String::new())로 불변식 우회 가능
+    // 2. 메서드 해석이 암묵적 - 어떤 메서드가 어디서 왔는지 읽기 어려움
+    // 3. 트레이트는 상속되지 않음 - Vec이 구현한 Extend, IntoIterator 등은
+    //    UserList에 없음 (C++ 상속처럼 동작하지 않음)
+    // 4. 제네릭 함수에 넘길 때 &*users 같은 명시적 역참조가 필요해짐
+
+    // 권장: 필요한 메서드만 명시적으로 위임하거나 as_slice()로 읽기 전용 뷰 제공
+    struct SafeUserList(Vec<String>);
+
+    impl SafeUserList {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn as_slice(&self) -> &[String] {
+            &self.0
+        }
+    }
+
+    let safe = SafeUserList(vec![String::from("bob")]);
+    println!("SafeUserList: len = {}, slice = {:?}", safe.len(), safe.as_slice());
+
+    // Deref가 적절한 경우: 스마트 포인터처럼 "T를 가리키는" 타입 (Box, Rc, MutexGuard)
+    // 공식 API 가이드라인(C-DEREF): Deref는 스마트 포인터에만 구현
+}
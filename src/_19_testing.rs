@@ -9,14 +9,45 @@
 // 5. 문서 테스트 (doc tests) 지원
 // ============================================================================
 
-pub fn run() {
-    println!("\n=== 19. 테스트 ===\n");
-
-    test_basics_explanation();
-    assertion_macros_explanation();
-    test_organization_explanation();
-    test_attributes_explanation();
-    test_commands_explanation();
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// 절 목록 (실행 순서) - cargo run -- 19:test_basics_explanation 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("test_basics_explanation", test_basics_explanation),
+    ("assertion_macros_explanation", assertion_macros_explanation),
+    ("fixtures_demo", fixtures_demo),
+    ("parameterized_demo", parameterized_demo),
+    ("cross_module_demo", cross_module_demo),
+    ("test_organization_explanation", test_organization_explanation),
+    ("test_attributes_explanation", test_attributes_explanation),
+    ("test_commands_explanation", test_commands_explanation),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "19"
+    }
+
+    fn name(&self) -> &'static str {
+        "테스트 (Testing)"
+    }
+
+    fn description(&self) -> &'static str {
+        "테스트가 언어/빌드 시스템에 내장 - 별도 프레임워크 불필요"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["#[test]", "단언 매크로", "픽스처", "테스트 구성"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
 }
 
 // ============================================================================
@@ -26,29 +57,15 @@ pub fn run() {
 fn test_basics_explanation() {
     println!("--- 테스트 기본 구조 ---");
 
-    println!(r#"
-// 테스트 함수 정의
-#[test]
-fn it_works() {{
-    assert_eq!(2 + 2, 4);
-}}
-
-// 테스트 모듈 (관례적 구조)
-#[cfg(test)]
-mod tests {{
-    use super::*;  // 부모 모듈의 항목 가져오기
-
-    #[test]
-    fn test_addition() {{
-        assert_eq!(add(2, 3), 5);
-    }}
-
-    #[test]
-    fn test_subtraction() {{
-        assert_eq!(subtract(5, 3), 2);
-    }}
-}}
-"#);
+    // 이 파일 하단의 `mod tests`가 실제로 컴파일되고 실행되는 테스트
+    // #[cfg(test)] - cargo test 때만 컴파일 (C++: 별도 테스트 타깃 + gtest)
+    // use super::*;  - 부모 모듈의 private 함수까지 테스트 가능
+    println!("테스트 대상 함수 (아래 mod tests에서 검증):");
+    println!("  add(2, 3) = {}", add(2, 3));
+    println!("  subtract(5, 3) = {}", subtract(5, 3));
+    println!("  divide(7, 2) = {}", divide(7, 2));
+    println!("  is_even(4) = {}", is_even(4));
+    println!();
 
     println!("실행 방법:");
     println!("  cargo test              # 모든 테스트 실행");
@@ -64,40 +81,9 @@ mod tests {{
 fn assertion_macros_explanation() {
     println!("\n--- 단언 매크로 ---");
 
-    println!(r#"
-// 기본 단언
-assert!(condition);              // condition이 true인지 확인
-assert!(value > 0, "값이 양수여야 함: {{}}", value);  // 커스텀 메시지
-
-// 동등성 비교
-assert_eq!(left, right);         // left == right
-assert_ne!(left, right);         // left != right
-
-// 실패 예상 테스트
-#[test]
-#[should_panic]
-fn test_panic() {{
-    panic!("의도된 패닉");
-}}
-
-// 특정 메시지로 패닉 확인
-#[test]
-#[should_panic(expected = "out of bounds")]
-fn test_specific_panic() {{
-    let v = vec![1, 2, 3];
-    v[99];  // 패닉 발생
-}}
-
-// Result를 반환하는 테스트
-#[test]
-fn test_with_result() -> Result<(), String> {{
-    if 2 + 2 == 4 {{
-        Ok(())
-    }} else {{
-        Err(String::from("수학이 이상해요"))
-    }}
-}}
-"#);
+    // #[should_panic], Result 반환 테스트 등은 하단 mod tests에 실제로 있음
+    // - test_divide_by_zero: #[should_panic(expected = "...")]
+    // - test_with_result: -> Result<(), String>
 
     // 실제 동작 예시
     println!("실제 단언 동작:");
@@ -114,6 +100,178 @@ fn test_with_result() -> Result<(), String> {{
     // assert_ne!
     assert_ne!("hello", "world");
     println!("  assert_ne!(\"hello\", \"world\") - 통과");
+
+    // 커스텀 단언 매크로 - 부동소수점 비교
+    // 0.1 + 0.2 == 0.3 은 false! (C++도 동일)
+    assert_approx_eq!(0.1 + 0.2, 0.3);
+    println!("  assert_approx_eq!(0.1 + 0.2, 0.3) - 통과");
+    assert_approx_eq!(divide(7, 2) as f64, 3.5, 0.5);
+    println!("  assert_approx_eq!(3.0, 3.5, 0.5) - 통과");
+}
+
+// ----------------------------------------------------------------------------
+// 커스텀 단언 매크로
+// ----------------------------------------------------------------------------
+// assert_eq!처럼 실패 시 양쪽 값과 호출 위치를 보여주는 것이 핵심
+// 매크로이므로 panic 위치가 호출한 줄로 표시됨 (함수라면 #[track_caller] 필요)
+
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr) => {
+        assert_approx_eq!($left, $right, 1e-9)
+    };
+    ($left:expr, $right:expr, $eps:expr) => {
+        match (&$left, &$right, &$eps) {
+            (left, right, eps) => {
+                // abs() 대신 비교 - f32/f64 어느 쪽으로 추론되어도 동작
+                let diff = if *left > *right { *left - *right } else { *right - *left };
+                assert!(
+                    diff <= *eps,
+                    "assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}\n  diff: {:?} > eps {:?}",
+                    left,
+                    right,
+                    diff,
+                    eps
+                );
+            }
+        }
+    };
+}
+use assert_approx_eq;
+
+// ============================================================================
+// 픽스처: 임시 디렉터리
+// ============================================================================
+// 실무에서는 tempfile::TempDir 사용 - 여기서는 표준 라이브러리만으로 구현
+// C++ gtest: SetUp()/TearDown() -> Rust: 생성자 + Drop (RAII)
+
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    // 테스트가 병렬로 실행되므로 이름이 겹치지 않게 pid + 카운터 사용
+    pub fn new(prefix: &str) -> io::Result<Self> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!(
+            "{}-{}-{}",
+            prefix,
+            std::process::id(),
+            unique
+        ));
+        fs::create_dir_all(&path)?;
+        Ok(TempDir { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn write(&self, name: &str, contents: &str) -> io::Result<PathBuf> {
+        let file = self.path.join(name);
+        fs::write(&file, contents)?;
+        Ok(file)
+    }
+}
+
+impl Drop for TempDir {
+    // 테스트가 실패(패닉)해도 정리됨
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+// 픽스처로 테스트할 함수 - 디렉터리에서 확장자가 ext인 파일의 줄 수 합계
+pub fn count_lines(dir: &Path, ext: &str) -> io::Result<usize> {
+    let mut total = 0;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == ext) {
+            total += fs::read_to_string(&path)?.lines().count();
+        }
+    }
+    Ok(total)
+}
+
+fn fixtures_demo() {
+    println!("\n--- 픽스처: 임시 디렉터리 ---");
+
+    let kept_path;
+    {
+        let dir = TempDir::new("rust-study-demo").unwrap();
+        dir.write("a.rs", "fn main() {}\n// 주석\n").unwrap();
+        dir.write("b.rs", "mod x;\n").unwrap();
+        dir.write("notes.txt", "무시됨\n").unwrap();
+
+        println!("임시 디렉터리: {}", dir.path().display());
+        println!("count_lines(.rs) = {}", count_lines(dir.path(), "rs").unwrap());
+        kept_path = dir.path().to_path_buf();
+        // 스코프 끝에서 Drop -> 디렉터리 삭제
+    }
+    println!("스코프 종료 후 존재 여부: {}", kept_path.exists());
+}
+
+// ============================================================================
+// 파라미터화 테스트 (rstest 스타일)
+// ============================================================================
+// rstest 크레이트:
+// #[rstest]
+// #[case(0, true)]
+// #[case(1, false)]
+// fn test_is_even(#[case] input: i32, #[case] expected: bool) { ... }
+//
+// 여기서는 macro_rules!로 같은 효과 - 케이스마다 별도의 #[test] 함수 생성
+// 반복문 테스트와 달리 실패한 케이스 이름이 테스트 결과에 그대로 표시됨
+// 입력을 고르지 않고 생성하려면 42장 (proptest - 같은 add, is_even 의 성질을 검사)
+
+#[cfg(test)]
+macro_rules! parameterized {
+    ($func:ident { $($case:ident: ($($arg:expr),*) => $expected:expr),+ $(,)? }) => {
+        mod $func {
+            use super::*;
+            $(
+                #[test]
+                fn $case() {
+                    assert_eq!($func($($arg),*), $expected);
+                }
+            )+
+        }
+    };
+}
+
+fn parameterized_demo() {
+    println!("\n--- 파라미터화 테스트 ---");
+
+    // 하단 mod parameterized_tests에서 케이스별 테스트로 생성되는 표
+    let cases = [(0, true), (1, false), (-2, true), (7, false)];
+    for (input, expected) in cases {
+        let ok = is_even(input) == expected;
+        println!("  is_even({:>2}) == {:<5} ... {}", input, expected, if ok { "ok" } else { "FAILED" });
+    }
+    println!("cargo test is_even:: 로 실행하면 케이스별 이름으로 결과 표시:");
+    println!("  test _19_testing::parameterized_tests::is_even::negative ... ok");
+}
+
+// ============================================================================
+// 다른 모듈의 API 테스트
+// ============================================================================
+
+fn cross_module_demo() {
+    println!("\n--- 다른 모듈의 API 테스트 ---");
+
+    // 16장의 MyVec을 외부 사용자 입장에서 사용 - pub(crate) API만 접근 가능
+    use crate::_16_unsafe::safe_wrapper::MyVec;
+
+    let mut v = MyVec::new();
+    for word in ["unsafe", "안전한", "추상화"] {
+        v.push(word.to_string());
+    }
+    v.swap(0, 2);
+    println!("16장 MyVec 사용: {:?}", &*v);
+    // 내부 필드(ptr, len, cap)는 private이라 테스트에서도 접근 불가
+    // -> 공개 API로만 검증하는 것이 통합 테스트의 관점
 }
 
 // ============================================================================
@@ -221,7 +379,7 @@ fn panics() {{ panic!(); }}
 
 // 특정 패닉 메시지 확인
 #[test]
-#[should_panic(expected = "divide by zero")]
+#[should_panic(expected = "0으로 나눌 수 없음")]
 fn panics_with_message() {{
     divide(1, 0);
 }}
@@ -312,7 +470,7 @@ pub fn subtract(a: i32, b: i32) -> i32 {
 
 pub fn divide(a: i32, b: i32) -> i32 {
     if b == 0 {
-        panic!("divide by zero");
+        panic!("0으로 나눌 수 없음");
     }
     a / b
 }
@@ -348,7 +506,7 @@ mod tests {
 
     // 패닉 테스트
     #[test]
-    #[should_panic(expected = "divide by zero")]
+    #[should_panic(expected = "0으로 나눌 수 없음")]
     fn test_divide_by_zero() {
         divide(1, 0);
     }
@@ -472,3 +630,124 @@ mod advanced_tests {
         assert_in_range(value, 0, 100);
     }
 }
+
+// 픽스처를 사용하는 테스트
+#[cfg(test)]
+mod fixture_tests {
+    use super::*;
+
+    #[test]
+    fn count_lines_filters_by_extension() -> io::Result<()> {
+        let dir = TempDir::new("count-lines")?;
+        dir.write("one.rs", "a\nb\nc\n")?;
+        dir.write("two.rs", "d\n")?;
+        dir.write("skip.md", "e\nf\n")?;
+
+        assert_eq!(count_lines(dir.path(), "rs")?, 4);
+        assert_eq!(count_lines(dir.path(), "md")?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn count_lines_empty_dir() -> io::Result<()> {
+        let dir = TempDir::new("count-lines-empty")?;
+        assert_eq!(count_lines(dir.path(), "rs")?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn temp_dir_is_removed_on_drop() -> io::Result<()> {
+        let path = {
+            let dir = TempDir::new("drop-check")?;
+            dir.write("f.txt", "x")?;
+            assert!(dir.path().exists());
+            dir.path().to_path_buf()
+        };
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn missing_dir_is_error() {
+        let dir = TempDir::new("missing").unwrap();
+        let missing = dir.path().join("nope");
+        assert!(count_lines(&missing, "rs").is_err());
+    }
+}
+
+// 파라미터화 테스트 - 케이스마다 별도 테스트 함수가 생성됨
+#[cfg(test)]
+mod parameterized_tests {
+    use super::*;
+
+    parameterized!(is_even {
+        zero: (0) => true,
+        one: (1) => false,
+        two: (2) => true,
+        negative: (-2) => true,
+        negative_odd: (-3) => false,
+    });
+
+    parameterized!(add {
+        positives: (2, 3) => 5,
+        with_zero: (0, 7) => 7,
+        negatives: (-4, -6) => -10,
+        mixed: (-1, 1) => 0,
+    });
+
+    parameterized!(divide {
+        exact: (10, 2) => 5,
+        truncates: (7, 2) => 3,
+        negative: (-9, 3) => -3,
+    });
+}
+
+// 커스텀 단언 매크로 테스트
+#[cfg(test)]
+mod custom_assertion_tests {
+    #[test]
+    fn approx_eq_passes_within_epsilon() {
+        assert_approx_eq!(0.1 + 0.2, 0.3);
+        assert_approx_eq!(1.0f32, 1.05f32, 0.1f32);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `left ≈ right` failed")]
+    fn approx_eq_fails_outside_epsilon() {
+        assert_approx_eq!(1.0, 1.1, 0.01);
+    }
+}
+
+// 다른 모듈(16장)의 API를 처음부터 끝까지 사용하는 테스트
+#[cfg(test)]
+mod cross_module_tests {
+    use crate::_16_unsafe::safe_wrapper::MyVec;
+
+    #[test]
+    fn my_vec_end_to_end() {
+        let mut v = MyVec::new();
+        for i in 1..=5 {
+            v.push(i * 10);
+        }
+        v.insert(0, 0);
+        assert_eq!(v.remove(3), 30);
+        assert_eq!(v.pop(), Some(50));
+
+        // Deref<Target=[T]>로 얻은 슬라이스 API
+        assert_eq!(&*v, &[0, 10, 20, 40]);
+        assert_eq!(v.iter().max(), Some(&40));
+
+        // 소유권을 가져가는 이터레이터로 마무리
+        let total: i32 = v.into_iter().sum();
+        assert_eq!(total, 70);
+    }
+
+    #[test]
+    fn shared_macros_build_collections() {
+        // 15장의 공용 매크로도 다른 모듈의 공개 API
+        let v: Vec<i32> = crate::my_vec![3, 1, 2];
+        let m = crate::macros::hashmap! { "a" => 1, "b" => 2 };
+        assert_eq!(v.len(), 3);
+        assert_eq!(m.get("b"), Some(&2));
+    }
+}
@@ -0,0 +1,551 @@
+// ============================================================================
+// 20. 비동기 채팅 서버 (캡스톤)
+// ============================================================================
+// 9장(에러 처리), 13장(동시성), 17장(async)을 한데 모아 줄 단위 채팅 서버를 만듦
+//   - 접속하면 이름을 묻고, 입장/퇴장을 모두에게 알림
+//   - 한 사람이 보낸 줄을 접속한 모든 사람에게 방송 (broadcast 채널)
+//   - 종료 신호를 받으면 새 접속을 멈추고 모든 연결을 정리한 뒤 끝냄 (watch 채널)
+//
+// C++20과의 핵심 차이점:
+// 1. C++ (Boost.Asio) 는 콜백/코루틴과 shared_from_this 로 연결 수명을 관리
+//    Rust 는 연결마다 태스크 하나 - 태스크가 끝나면 소유한 자원이 모두 drop
+// 2. 공유 상태는 Arc<Mutex<..>> - 잠금 없이 접근하면 컴파일 에러 (데이터 경쟁 방지)
+// 3. 연결 처리 함수가 AsyncRead + AsyncWrite 에 제네릭이라
+//    실제 TCP 대신 메모리 안의 연결(tokio::io::duplex)로 테스트 가능
+// 4. 에러는 ChatError 하나로 모으고 ? 로 전파 - 예외 대신 타입으로 드러남
+// ============================================================================
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, Lines, ReadHalf, WriteHalf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinSet;
+
+// 절 목록 (실행 순서) - cargo run -- 20:server_state 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("server_state", || block_on(server_state())),
+    ("connection_handling", || block_on(connection_handling())),
+    ("broadcasting", || block_on(broadcasting())),
+    ("graceful_shutdown", || block_on(graceful_shutdown())),
+];
+
+// 비동기 절은 각자 tokio 런타임을 만들어 실행 - 절 하나만 실행할 때도 그대로 동작
+fn block_on(future: impl std::future::Future<Output = ()>) {
+    // 런타임을 만들 수 없으면(스레드 생성 실패 등) 예제를 진행할 수 없으므로 unwrap
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "20"
+    }
+
+    fn name(&self) -> &'static str {
+        "비동기 채팅 서버 (캡스톤)"
+    }
+
+    fn description(&self) -> &'static str {
+        "9, 13, 17장을 모아 tokio 로 줄 단위 채팅 서버 만들기"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["TcpListener", "broadcast 채널", "watch 채널", "정상 종료"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 서버 상태와 에러 타입
+// ----------------------------------------------------------------------------
+
+// 9장의 관례: 모듈의 에러를 열거형 하나로 모으고 Display + Error + From 구현
+#[derive(Debug)]
+pub enum ChatError {
+    Io(io::Error),
+    NameTaken(String),
+    InvalidName(String),
+}
+
+impl fmt::Display for ChatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChatError::Io(e) => write!(f, "입출력 에러: {}", e),
+            ChatError::NameTaken(name) => write!(f, "이미 사용 중인 이름: {}", name),
+            ChatError::InvalidName(name) => {
+                write!(f, "잘못된 이름: '{}' (공백 없이 1~16자)", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ChatError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// ? 가 io::Error 를 ChatError 로 자동 변환
+impl From<io::Error> for ChatError {
+    fn from(e: io::Error) -> Self {
+        ChatError::Io(e)
+    }
+}
+
+// 모든 연결이 받는 사건 - broadcast 채널로 보내므로 Clone
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Joined(String),
+    Left(String),
+    Message { from: String, text: String },
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Joined(name) => write!(f, "*** {} 님이 입장했습니다", name),
+            Event::Left(name) => write!(f, "*** {} 님이 나갔습니다", name),
+            Event::Message { from, text } => write!(f, "[{}] {}", from, text),
+        }
+    }
+}
+
+// 방송 채널이 담아 두는 메시지 수 - 이보다 뒤처진 연결은 놓친 개수를 안내받음
+const CHANNEL_CAPACITY: usize = 64;
+
+// 모든 연결이 Arc 로 공유하는 서버 상태
+// C++: std::shared_ptr<Room> + std::mutex 로 감싼 std::set<std::string>
+pub struct Hub {
+    users: Mutex<HashSet<String>>,
+    events: broadcast::Sender<Event>,
+}
+
+impl Hub {
+    pub fn new() -> Arc<Hub> {
+        let (events, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Arc::new(Hub {
+            users: Mutex::new(HashSet::new()),
+            events,
+        })
+    }
+
+    // 이름을 등록하고 사건 구독 - 입장 알림보다 먼저 구독해서 자기 입장도 받음
+    pub fn join(&self, name: &str) -> Result<broadcast::Receiver<Event>, ChatError> {
+        if name.is_empty() || name.chars().count() > 16 || name.contains(char::is_whitespace) {
+            return Err(ChatError::InvalidName(name.to_string()));
+        }
+        // 잠금은 이 블록 안에서만 - .await 를 건너 잠금을 쥐고 있지 않도록
+        {
+            let mut users = self.lock_users();
+            if !users.insert(name.to_string()) {
+                return Err(ChatError::NameTaken(name.to_string()));
+            }
+        }
+        let rx = self.events.subscribe();
+        self.publish(Event::Joined(name.to_string()));
+        Ok(rx)
+    }
+
+    pub fn leave(&self, name: &str) {
+        if self.lock_users().remove(name) {
+            self.publish(Event::Left(name.to_string()));
+        }
+    }
+
+    pub fn say(&self, from: &str, text: &str) {
+        self.publish(Event::Message {
+            from: from.to_string(),
+            text: text.to_string(),
+        });
+    }
+
+    // unwrap: 잠금을 쥔 태스크가 패닉했을 때(poison)만 실패 - 이름 목록은 이미 믿을 수 없으니 같이 패닉
+    fn lock_users(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        self.users.lock().unwrap()
+    }
+
+    // 받는 쪽이 없으면 send 가 Err - 방송에서는 문제가 아님
+    fn publish(&self, event: Event) {
+        let _ = self.events.send(event);
+    }
+
+    pub fn users(&self) -> Vec<String> {
+        let mut users: Vec<String> = self.lock_users().iter().cloned().collect();
+        users.sort();
+        users
+    }
+}
+
+async fn server_state() {
+    println!("--- 서버 상태와 에러 타입 ---");
+
+    let hub = Hub::new();
+    // 빈 서버에 처음 들어오는 이름이므로 unwrap 해도 실패하지 않음
+    let mut alice = hub.join("alice").unwrap();
+    let _bob = hub.join("bob").unwrap();
+    println!("접속자: {:?}", hub.users());
+
+    // 실패는 타입으로 - 호출하는 쪽이 match 로 처리
+    for name in ["alice", "이름 에 공백", ""] {
+        match hub.join(name) {
+            Ok(_) => println!("'{}' 입장", name),
+            Err(e) => println!("거절: {}", e),
+        }
+    }
+
+    hub.say("bob", "안녕하세요");
+    hub.leave("bob");
+    // alice 는 자기 입장부터 모든 사건을 순서대로 받음
+    while let Ok(event) = alice.try_recv() {
+        println!("alice 가 받은 사건: {}", event);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 연결 처리
+// ----------------------------------------------------------------------------
+// 연결 하나 = 태스크 하나, 두 가지 입력을 tokio::select! 로 동시에 기다림
+//   - 클라이언트가 보낸 줄 → 방송
+//   - 방송 채널의 사건 → 클라이언트에게 쓰기
+// C++ (Asio): async_read_until 과 async_write 를 번갈아 거는 콜백 체인, 또는 co_spawn 두 개
+
+// 종료 신호를 받았는지 - 보내는 쪽이 사라져도 종료로 봄
+async fn shutdown_requested(shutdown: &mut watch::Receiver<bool>) {
+    while !*shutdown.borrow_and_update() {
+        if shutdown.changed().await.is_err() {
+            return;
+        }
+    }
+}
+
+// S 는 TcpStream 이든 메모리 안의 DuplexStream 이든 상관없음
+pub async fn handle_connection<S>(
+    stream: S,
+    hub: Arc<Hub>,
+    mut shutdown: watch::Receiver<bool>,
+) -> Result<(), ChatError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut lines = BufReader::new(reader).lines();
+
+    // 1단계: 이름 받기 - 쓸 수 있는 이름이 나올 때까지 다시 물음
+    let (name, mut events) = loop {
+        writer.write_all("이름을 입력하세요:\n".as_bytes()).await?;
+        let line = tokio::select! {
+            line = lines.next_line() => line?,
+            _ = shutdown_requested(&mut shutdown) => return Ok(()),
+        };
+        // 이름을 말하기 전에 끊긴 연결
+        let Some(line) = line else {
+            return Ok(());
+        };
+        match hub.join(line.trim()) {
+            Ok(events) => break (line.trim().to_string(), events),
+            Err(e) => writer.write_all(format!("{}\n", e).as_bytes()).await?,
+        }
+    };
+
+    // 2단계: 대화 - 어떤 이유로 끝나든 leave 는 반드시 (에러도 먼저 결과로 받아 둠)
+    let result = chat(&name, &hub, &mut lines, &mut writer, &mut events, &mut shutdown).await;
+    hub.leave(&name);
+    result
+}
+
+async fn chat<R, W>(
+    name: &str,
+    hub: &Hub,
+    lines: &mut Lines<BufReader<R>>,
+    writer: &mut W,
+    events: &mut broadcast::Receiver<Event>,
+    shutdown: &mut watch::Receiver<bool>,
+) -> Result<(), ChatError>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    loop {
+        tokio::select! {
+            line = lines.next_line() => match line?.as_deref().map(str::trim) {
+                // 연결이 끊기거나 /quit
+                None => return Ok(()),
+                Some("/quit") => {
+                    writer.write_all("안녕히 가세요\n".as_bytes()).await?;
+                    return Ok(());
+                }
+                Some("/who") => {
+                    let users = hub.users().join(", ");
+                    writer.write_all(format!("접속자: {}\n", users).as_bytes()).await?;
+                }
+                Some("") => {}
+                Some(text) => hub.say(name, text),
+            },
+            event = events.recv() => match event {
+                Ok(event) => writer.write_all(format!("{}\n", event).as_bytes()).await?,
+                // 너무 느린 연결 - 놓친 개수만 알리고 계속
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    writer.write_all(format!("*** 메시지 {}개를 놓쳤습니다\n", n).as_bytes()).await?
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+            _ = shutdown_requested(shutdown) => {
+                writer.write_all("*** 서버를 종료합니다\n".as_bytes()).await?;
+                return Ok(());
+            }
+        }
+    }
+}
+
+// 예제와 테스트에서 쓰는 줄 단위 클라이언트
+pub struct Client<S> {
+    lines: Lines<BufReader<ReadHalf<S>>>,
+    writer: WriteHalf<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite> Client<S> {
+    pub fn new(stream: S) -> Self {
+        let (reader, writer) = tokio::io::split(stream);
+        Client {
+            lines: BufReader::new(reader).lines(),
+            writer,
+        }
+    }
+
+    pub async fn send(&mut self, line: &str) -> io::Result<()> {
+        self.writer.write_all(format!("{}\n", line).as_bytes()).await
+    }
+
+    // 서버가 끊었거나 2초 안에 아무것도 오지 않으면 None
+    pub async fn recv(&mut self) -> Option<String> {
+        match tokio::time::timeout(Duration::from_secs(2), self.lines.next_line()).await {
+            Ok(Ok(line)) => line,
+            _ => None,
+        }
+    }
+}
+
+async fn connection_handling() {
+    println!("\n--- 연결 처리 ---");
+
+    let hub = Hub::new();
+    let (_stop, shutdown) = watch::channel(false);
+
+    // 실제 소켓 대신 메모리 안의 양방향 연결 - 한쪽은 서버 태스크, 다른 쪽은 클라이언트
+    let (client_side, server_side) = tokio::io::duplex(1024);
+    let server = tokio::spawn(handle_connection(server_side, Arc::clone(&hub), shutdown));
+    let mut client = Client::new(client_side);
+
+    println!("서버: {:?}", client.recv().await);
+    client.send("이름 에 공백").await.unwrap();
+    println!("서버: {:?}", client.recv().await);
+    println!("서버: {:?}", client.recv().await);
+    client.send("alice").await.unwrap();
+    println!("서버: {:?}", client.recv().await);
+    client.send("/who").await.unwrap();
+    println!("서버: {:?}", client.recv().await);
+    client.send("/quit").await.unwrap();
+    println!("서버: {:?}", client.recv().await);
+
+    // 태스크의 결과 - JoinError(패닉) 안에 ChatError
+    println!("연결 태스크 결과: {:?}", server.await.unwrap().map_err(|e| e.to_string()));
+    println!("남은 접속자: {:?}", hub.users());
+}
+
+// ----------------------------------------------------------------------------
+// 메시지 방송
+// ----------------------------------------------------------------------------
+// broadcast 채널: 보낸 값을 구독자마다 복제해서 전달 (17장 broadcast 채널)
+// C++: 방마다 std::vector<std::weak_ptr<Session>> 를 들고 돌면서 deliver() 호출
+//      → 끊긴 세션 정리와 잠금 순서를 직접 관리해야 함
+
+async fn broadcasting() {
+    println!("\n--- 메시지 방송 ---");
+
+    let hub = Hub::new();
+    let (_stop, shutdown) = watch::channel(false);
+
+    let connect = || {
+        let (client_side, server_side) = tokio::io::duplex(1024);
+        tokio::spawn(handle_connection(server_side, Arc::clone(&hub), shutdown.clone()));
+        Client::new(client_side)
+    };
+    let mut alice = connect();
+    let mut bob = connect();
+
+    alice.recv().await;
+    alice.send("alice").await.unwrap();
+    println!("alice: {:?}", alice.recv().await);
+
+    bob.recv().await;
+    bob.send("bob").await.unwrap();
+    println!("bob:   {:?}", bob.recv().await);
+    println!("alice: {:?}", alice.recv().await);
+
+    // 한 사람이 보낸 줄은 보낸 사람을 포함한 모두에게
+    bob.send("안녕 alice").await.unwrap();
+    println!("alice: {:?}", alice.recv().await);
+    println!("bob:   {:?}", bob.recv().await);
+
+    // 연결을 끊으면(drop) 서버 태스크가 EOF 를 보고 퇴장을 알림
+    drop(bob);
+    println!("alice: {:?}", alice.recv().await);
+}
+
+// ----------------------------------------------------------------------------
+// 정상 종료 (Graceful Shutdown)
+// ----------------------------------------------------------------------------
+// 1. 종료 신호(watch 채널)를 모든 태스크가 구독
+// 2. accept 루프는 신호를 받으면 새 연결을 받지 않음
+// 3. 연결 태스크는 작별 인사를 쓰고 끝냄 - JoinSet 으로 모두 끝날 때까지 기다림
+// C++: io_context::stop() 은 진행 중인 작업을 그냥 버림 → 정리 코드를 따로 만들어야 함
+
+// 연결을 받다가 종료 신호가 오면 모든 연결이 끝날 때까지 기다림 - 처리한 연결 수
+pub async fn serve(
+    listener: TcpListener,
+    hub: Arc<Hub>,
+    mut shutdown: watch::Receiver<bool>,
+) -> io::Result<usize> {
+    let mut tasks = JoinSet::new();
+    let mut accepted = 0;
+    loop {
+        tokio::select! {
+            conn = listener.accept() => {
+                let (stream, _) = conn?;
+                accepted += 1;
+                let (hub, shutdown) = (Arc::clone(&hub), shutdown.clone());
+                tasks.spawn(async move {
+                    if let Err(e) = handle_connection(stream, hub, shutdown).await {
+                        eprintln!("연결 에러: {}", e);
+                    }
+                });
+            }
+            _ = shutdown_requested(&mut shutdown) => break,
+        }
+    }
+    // 새 연결은 더 받지 않고 진행 중인 연결만 마무리
+    drop(listener);
+    while tasks.join_next().await.is_some() {}
+    Ok(accepted)
+}
+
+async fn graceful_shutdown() {
+    println!("\n--- 정상 종료 ---");
+
+    // 포트 0 = 운영체제가 빈 포트를 골라 줌
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop, shutdown) = watch::channel(false);
+    let server = tokio::spawn(serve(listener, Hub::new(), shutdown));
+
+    let mut alice = Client::new(TcpStream::connect(addr).await.unwrap());
+    alice.recv().await;
+    alice.send("alice").await.unwrap();
+    println!("alice: {:?}", alice.recv().await);
+    alice.send("곧 서버가 내려갑니다").await.unwrap();
+    println!("alice: {:?}", alice.recv().await);
+
+    // 종료 신호 - 연결마다 작별 인사를 받고 서버 태스크가 끝남
+    stop.send(true).unwrap();
+    println!("alice: {:?}", alice.recv().await);
+    println!("alice: {:?} (연결 종료)", alice.recv().await);
+    println!("처리한 연결 수: {}", server.await.unwrap().unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 메모리 안의 연결 하나를 서버 태스크에 붙이고 이름까지 등록
+    async fn join(hub: &Arc<Hub>, shutdown: &watch::Receiver<bool>, name: &str) -> Client<tokio::io::DuplexStream> {
+        let (client_side, server_side) = tokio::io::duplex(1024);
+        tokio::spawn(handle_connection(server_side, Arc::clone(hub), shutdown.clone()));
+        let mut client = Client::new(client_side);
+        assert_eq!(client.recv().await.as_deref(), Some("이름을 입력하세요:"));
+        client.send(name).await.unwrap();
+        assert_eq!(client.recv().await, Some(format!("*** {} 님이 입장했습니다", name)));
+        client
+    }
+
+    #[test]
+    fn hub_rejects_duplicate_and_invalid_names() {
+        let hub = Hub::new();
+        let _rx = hub.join("alice").unwrap();
+        assert!(matches!(hub.join("alice"), Err(ChatError::NameTaken(_))));
+        assert!(matches!(hub.join("a b"), Err(ChatError::InvalidName(_))));
+        assert!(matches!(hub.join(&"x".repeat(17)), Err(ChatError::InvalidName(_))));
+        hub.leave("alice");
+        assert!(hub.join("alice").is_ok());
+    }
+
+    #[tokio::test]
+    async fn messages_reach_everyone_and_leaving_is_announced() {
+        let hub = Hub::new();
+        let (_stop, shutdown) = watch::channel(false);
+        let mut alice = join(&hub, &shutdown, "alice").await;
+        let mut bob = join(&hub, &shutdown, "bob").await;
+        assert_eq!(alice.recv().await.as_deref(), Some("*** bob 님이 입장했습니다"));
+
+        alice.send("hi").await.unwrap();
+        assert_eq!(alice.recv().await.as_deref(), Some("[alice] hi"));
+        assert_eq!(bob.recv().await.as_deref(), Some("[alice] hi"));
+
+        bob.send("/quit").await.unwrap();
+        assert_eq!(bob.recv().await.as_deref(), Some("안녕히 가세요"));
+        assert_eq!(bob.recv().await, None);
+        assert_eq!(alice.recv().await.as_deref(), Some("*** bob 님이 나갔습니다"));
+        assert_eq!(hub.users(), vec!["alice"]);
+    }
+
+    #[tokio::test]
+    async fn taken_name_is_asked_again() {
+        let hub = Hub::new();
+        let (_stop, shutdown) = watch::channel(false);
+        let _alice = join(&hub, &shutdown, "alice").await;
+
+        let (client_side, server_side) = tokio::io::duplex(1024);
+        tokio::spawn(handle_connection(server_side, Arc::clone(&hub), shutdown.clone()));
+        let mut other = Client::new(client_side);
+        other.recv().await;
+        other.send("alice").await.unwrap();
+        assert_eq!(other.recv().await.as_deref(), Some("이미 사용 중인 이름: alice"));
+        assert_eq!(other.recv().await.as_deref(), Some("이름을 입력하세요:"));
+    }
+
+    #[tokio::test]
+    async fn shutdown_closes_connections_and_serve_returns() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hub = Hub::new();
+        let (stop, shutdown) = watch::channel(false);
+        let server = tokio::spawn(serve(listener, Arc::clone(&hub), shutdown));
+
+        let mut waiting = Client::new(TcpStream::connect(addr).await.unwrap());
+        let mut chatting = Client::new(TcpStream::connect(addr).await.unwrap());
+        waiting.recv().await;
+        chatting.recv().await;
+        chatting.send("carol").await.unwrap();
+        chatting.recv().await;
+
+        stop.send(true).unwrap();
+        assert_eq!(chatting.recv().await.as_deref(), Some("*** 서버를 종료합니다"));
+        assert_eq!(chatting.recv().await, None);
+        // 이름을 말하기 전인 연결도 함께 닫힘
+        assert_eq!(waiting.recv().await, None);
+        assert_eq!(server.await.unwrap().unwrap(), 2);
+        assert!(hub.users().is_empty());
+    }
+}
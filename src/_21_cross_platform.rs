@@ -0,0 +1,396 @@
+// ============================================================================
+// 21. 크로스 플랫폼 코드 (cfg)
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. #ifdef _WIN32 는 전처리기가 글자를 지움 - #[cfg(windows)] 는 문법 트리의 항목을 지움
+//    → 꺼진 쪽도 파싱은 되어야 하고, 매크로 이름 오타(_WIN23)가 조용히 넘어가지 않음 (check-cfg 경고)
+// 2. cfg!(unix) 는 bool 상수 - 양쪽 분기가 모두 타입 검사를 받음 (if constexpr 과 비슷)
+// 3. 경로, 프로세스, 환경 변수는 표준 라이브러리가 이미 추상화 (std::filesystem 수준 이상)
+//    운영체제 전용 기능은 std::os::unix / std::os::windows 확장 트레이트로 명시적으로
+// 4. 운영체제별 의존성을 Cargo.toml 에 선언 - [target.'cfg(windows)'.dependencies]
+//    (CMake 의 if(WIN32) target_link_libraries(...) 에 해당)
+// 5. 추상화 계층: #[cfg] 로 고른 imp 모듈 하나 + 공통 공개 함수 - 이 크레이트의 src/terminal.rs
+// ============================================================================
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// 절 목록 (실행 순서) - cargo run -- 21:cfg_attributes 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("cfg_attributes", cfg_attributes),
+    ("platform_paths", platform_paths),
+    ("platform_processes", platform_processes),
+    ("conditional_dependencies", conditional_dependencies),
+    ("platform_abstraction", platform_abstraction),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "21"
+    }
+
+    fn name(&self) -> &'static str {
+        "크로스 플랫폼 코드 (cfg)"
+    }
+
+    fn description(&self) -> &'static str {
+        "#[cfg] 로 운영체제별 코드를 나누고 추상화 계층으로 감추기"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["cfg", "target_os", "조건부 의존성", "플랫폼 추상화"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// cfg 속성과 cfg! 매크로
+// ----------------------------------------------------------------------------
+
+// 같은 이름의 함수를 운영체제마다 하나씩 - 컴파일되는 것은 항상 하나
+#[cfg(target_os = "linux")]
+fn os_greeting() -> &'static str {
+    "리눅스용으로 컴파일됨"
+}
+
+#[cfg(target_os = "macos")]
+fn os_greeting() -> &'static str {
+    "macOS 용으로 컴파일됨"
+}
+
+#[cfg(windows)]
+fn os_greeting() -> &'static str {
+    "Windows 용으로 컴파일됨"
+}
+
+// 위 셋에 해당하지 않는 모든 대상 - 빠뜨리면 그 대상에서만 "함수 없음" 컴파일 에러
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn os_greeting() -> &'static str {
+    "그 밖의 운영체제용으로 컴파일됨"
+}
+
+fn cfg_attributes() {
+    println!("--- cfg 속성과 cfg! 매크로 ---");
+
+    // C++ 에서는:
+    // #if defined(__linux__)
+    //     const char* greeting() { return "linux"; }
+    // #elif defined(_WIN32)
+    //     const char* greeting() { return "windows"; }
+    // #endif
+    // → 꺼진 쪽은 파싱조차 안 되므로 다른 OS 에서만 컴파일 에러가 드러남
+
+    println!("{}", os_greeting());
+
+    // 자주 쓰는 조건
+    //   target_os = "linux" | "macos" | "windows" | "android" | "ios" ...
+    //   unix, windows             = target_family 의 줄임
+    //   target_arch = "x86_64" | "aarch64" | "wasm32"
+    //   target_pointer_width = "64", target_endian = "little"
+    //   any(..), all(..), not(..) 로 조합
+
+    // cfg! 는 컴파일 시점에 정해진 bool - 양쪽 코드가 모두 컴파일되어야 함
+    let family = if cfg!(unix) {
+        "유닉스 계열"
+    } else if cfg!(windows) {
+        "Windows"
+    } else {
+        "기타"
+    };
+    println!("운영체제 계열: {}", family);
+    println!("64비트 포인터: {}", cfg!(target_pointer_width = "64"));
+
+    // 실행 중인 프로그램의 대상 정보 - 문자열이 필요할 때 (로그, 진단 출력)
+    println!(
+        "env::consts: OS={}, FAMILY={}, ARCH={}",
+        env::consts::OS,
+        env::consts::FAMILY,
+        env::consts::ARCH
+    );
+
+    // 블록, 문장, 구조체 필드에도 붙일 수 있음
+    #[cfg(debug_assertions)]
+    println!("디버그 빌드 (cargo build --release 에서는 이 줄이 사라짐)");
+
+    // cfg_attr: 조건이 맞을 때만 속성을 붙임 - 여기서는 유닉스에서만 Debug 구현
+    #[cfg_attr(unix, derive(Debug))]
+    struct Permissions {
+        #[cfg(unix)]
+        mode: u32, // 유닉스에만 있는 필드
+        read_only: bool,
+    }
+    let p = Permissions {
+        #[cfg(unix)]
+        mode: 0o644,
+        read_only: false,
+    };
+    #[cfg(unix)]
+    println!("{:?} - 권한 비트 {:o}", p, p.mode);
+    println!("읽기 전용: {}", p.read_only);
+}
+
+// ----------------------------------------------------------------------------
+// 운영체제별 경로
+// ----------------------------------------------------------------------------
+
+fn platform_paths() {
+    println!("\n--- 운영체제별 경로 ---");
+
+    // C++ 에서는:
+    // std::filesystem::path p = "data";
+    // p /= "lessons.toml";              // 구분자는 path::preferred_separator
+    // → Rust 의 Path/PathBuf 와 거의 같음, 다만 문자열 → 경로 변환의 인코딩 문제가 남음
+
+    // 구분자를 직접 쓰지 말고 join - 운영체제에 맞는 구분자로 이어 붙임
+    let path = Path::new("content").join("prose").join("04_lifetimes.md");
+    println!("join 결과: {}", path.display());
+    println!("구분자: {:?}", std::path::MAIN_SEPARATOR);
+
+    // 구성 요소 단위로 다루면 구분자를 신경 쓸 일이 없음
+    let parts: Vec<_> = path.iter().map(|p| p.to_string_lossy()).collect();
+    println!("구성 요소: {:?}", parts);
+    println!("확장자: {:?}, 파일 이름: {:?}", path.extension(), path.file_stem());
+
+    // 같은 문자열도 운영체제마다 다르게 해석됨
+    let windows_style = Path::new(r"C:\Users\study\notes.txt");
+    println!(
+        "r\"C:\\Users\\study\\notes.txt\" 는 절대 경로? {} (Windows 에서만 true)",
+        windows_style.is_absolute()
+    );
+
+    // 홈 디렉터리 - 환경 변수 이름부터 다름
+    let home_var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    let home = env::var_os(home_var).map(PathBuf::from);
+    println!("홈 디렉터리 ({}): {}", home_var, if home.is_some() { "있음" } else { "없음" });
+
+    // PATH 같은 목록 변수의 구분자도 ':' 와 ';' 로 다름 - split_paths 가 처리
+    let dirs = env::var_os("PATH").map(|p| env::split_paths(&p).count()).unwrap_or(0);
+    println!("PATH 의 디렉터리 수: {}", dirs);
+
+    // 파일 이름은 UTF-8 이 아닐 수 있음 (유닉스: 임의의 바이트, Windows: UTF-16)
+    // → &str 이 아니라 &OsStr, to_str() 은 Option
+    let name = path.file_name().unwrap_or_default();
+    println!("파일 이름을 &str 로: {:?}", name.to_str());
+
+    // 줄 끝도 다름 - 파일을 쓸 때 "\n" 만 쓰고, 읽을 때는 lines() 가 "\r\n" 도 처리
+    let text = "첫 줄\r\n둘째 줄\n";
+    println!("lines(): {:?}", text.lines().collect::<Vec<_>>());
+}
+
+// ----------------------------------------------------------------------------
+// 운영체제별 프로세스
+// ----------------------------------------------------------------------------
+
+// 셸 명령 한 줄 - 셸 자체가 운영체제마다 다름
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut c = Command::new("cmd");
+        c.args(["/C", command]);
+        c
+    } else {
+        let mut c = Command::new("sh");
+        c.args(["-c", command]);
+        c
+    }
+}
+
+fn platform_processes() {
+    println!("\n--- 운영체제별 프로세스 ---");
+
+    // C++ 에서는:
+    // #ifdef _WIN32
+    //     CreateProcessW(...);   // 명령줄 한 문자열
+    // #else
+    //     fork(); execvp(...);   // 인자 배열
+    // #endif
+    // Rust 의 std::process::Command 는 양쪽을 감싼 공통 API
+    // (출력 잡기, 파이프, 시간 제한, 환경 변수는 39장)
+
+    // 셸 명령이 꼭 필요할 때만 - 가능하면 프로그램을 직접 실행 (인자 이스케이프 문제 없음)
+    match shell("echo 안녕").output() {
+        Ok(out) => println!("셸 출력: {}", String::from_utf8_lossy(&out.stdout).trim()),
+        Err(e) => println!("셸을 실행할 수 없음: {}", e),
+    }
+
+    // 실행 파일 확장자 - Windows 는 ".exe"
+    println!("실행 파일 이름: rust-study{}", env::consts::EXE_SUFFIX);
+
+    // 종료 상태: 코드는 공통, 시그널은 유닉스에만
+    let status = shell("exit 3").status();
+    match status {
+        Ok(status) => {
+            println!("종료 코드: {:?}", status.code());
+            // 운영체제 전용 기능은 std::os::<os> 의 확장 트레이트로 - 가져와야만 보임
+            #[cfg(unix)]
+            {
+                use std::os::unix::process::ExitStatusExt;
+                println!("시그널로 종료: {:?} (유닉스 전용 ExitStatusExt)", status.signal());
+            }
+        }
+        Err(e) => println!("실행 실패: {}", e),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 조건부 의존성
+// ----------------------------------------------------------------------------
+// Cargo.toml - 대상 조건이 맞을 때만 내려받고 링크 (이 크레이트의 실제 설정)
+//
+//   [target.'cfg(unix)'.dependencies]
+//   libc = "0.2"
+//
+//   [target.'cfg(windows)'.dependencies]
+//   windows-sys = { version = "0.61", features = ["Win32_System_Console"] }
+//
+//   [target.'cfg(not(target_arch = "wasm32"))'.dependencies]
+//   tokio = { version = "1", features = ["full"] }
+//
+// 코드에서 그 크레이트를 쓰는 곳도 같은 조건으로 감싸야 함
+// → 리눅스에서 cargo build 가 통과해도 windows 쪽 코드는 검사되지 않음
+//   cargo check --target x86_64-pc-windows-msvc 로 다른 대상도 확인 (rustup target add 필요)
+//
+// C++ (CMake):
+//   if(WIN32)
+//       target_link_libraries(app PRIVATE kernel32)
+//   endif()
+//
+// 선택 기능(feature)도 같은 방식: [features] json = ["dep:serde_json"] + #[cfg(feature = "json")]
+
+fn conditional_dependencies() {
+    println!("\n--- 조건부 의존성 ---");
+
+    // 이 빌드에 들어간 터미널 구현 - 조건마다 다른 의존성
+    let backend = if cfg!(unix) {
+        "libc::ioctl(TIOCGWINSZ)"
+    } else if cfg!(windows) {
+        "windows-sys GetConsoleScreenBufferInfo"
+    } else {
+        "없음 (환경 변수만)"
+    };
+    println!("터미널 크기를 알아내는 방법: {}", backend);
+
+    // cfg 조건은 컴파일 시점 - 실행 중에 바꿀 수 없음
+    // 실행 중에 고르려면 일반 if + 런타임 정보 (env::consts::OS, 환경 변수)
+    println!("빌드 대상이 wasm32: {}", cfg!(target_arch = "wasm32"));
+}
+
+// ----------------------------------------------------------------------------
+// 플랫폼 추상화 계층
+// ----------------------------------------------------------------------------
+// 패턴: 공개 API 는 한 곳, 운영체제별 구현은 #[cfg] 로 고른 imp 모듈
+//   - 호출하는 쪽에는 cfg 가 하나도 없음
+//   - 새 운영체제를 지원하려면 imp 모듈 하나만 추가
+//   - 마지막 not(any(..)) 대체 구현으로 "지원하지 않는 대상"에서도 컴파일됨
+// C++: platform/linux.cpp, platform/win32.cpp 를 빌드 시스템이 골라 컴파일하는 방식과 같음
+// 이 크레이트의 src/terminal.rs (doctor, render --format tui 가 사용) 가 같은 구조
+
+pub mod platform {
+    use std::path::PathBuf;
+
+    // 공통 API - 설정 파일 위치 (각 운영체제의 관례)
+    pub fn config_dir() -> Option<PathBuf> {
+        imp::config_dir().map(|dir| dir.join("rust-study"))
+    }
+
+    pub fn line_ending() -> &'static str {
+        imp::LINE_ENDING
+    }
+
+    pub fn name() -> &'static str {
+        imp::NAME
+    }
+
+    #[cfg(target_os = "macos")]
+    mod imp {
+        use std::env;
+        use std::path::PathBuf;
+
+        pub const NAME: &str = "macOS";
+        pub const LINE_ENDING: &str = "\n";
+
+        pub fn config_dir() -> Option<PathBuf> {
+            env::var_os("HOME").map(|h| PathBuf::from(h).join("Library/Application Support"))
+        }
+    }
+
+    // macOS 를 뺀 유닉스 - XDG 규칙
+    #[cfg(all(unix, not(target_os = "macos")))]
+    mod imp {
+        use std::env;
+        use std::path::PathBuf;
+
+        pub const NAME: &str = "유닉스";
+        pub const LINE_ENDING: &str = "\n";
+
+        pub fn config_dir() -> Option<PathBuf> {
+            env::var_os("XDG_CONFIG_HOME")
+                .map(PathBuf::from)
+                .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+        }
+    }
+
+    #[cfg(windows)]
+    mod imp {
+        use std::env;
+        use std::path::PathBuf;
+
+        pub const NAME: &str = "Windows";
+        pub const LINE_ENDING: &str = "\r\n";
+
+        pub fn config_dir() -> Option<PathBuf> {
+            env::var_os("APPDATA").map(PathBuf::from)
+        }
+    }
+
+    // 그 밖의 대상(wasm32 등) - 설정 디렉터리 없음
+    #[cfg(not(any(unix, windows)))]
+    mod imp {
+        use std::path::PathBuf;
+
+        pub const NAME: &str = "기타";
+        pub const LINE_ENDING: &str = "\n";
+
+        pub fn config_dir() -> Option<PathBuf> {
+            None
+        }
+    }
+}
+
+fn platform_abstraction() {
+    println!("\n--- 플랫폼 추상화 계층 ---");
+
+    // 호출하는 쪽은 운영체제를 모름
+    println!("구현: {}", platform::name());
+    match platform::config_dir() {
+        Some(dir) => println!("설정 디렉터리: .../{}", dir.file_name().unwrap_or_default().to_string_lossy()),
+        None => println!("설정 디렉터리: 없음"),
+    }
+    println!("줄 끝: {:?}", platform::line_ending());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn platform_layer_matches_target() {
+        assert_eq!(platform::line_ending() == "\r\n", cfg!(windows));
+        if let Some(dir) = platform::config_dir() {
+            assert!(dir.ends_with("rust-study"));
+        }
+        assert!(!os_greeting().is_empty());
+    }
+
+    #[test]
+    fn join_uses_native_separator() {
+        let path = Path::new("a").join("b");
+        assert_eq!(path.to_string_lossy(), format!("a{}b", std::path::MAIN_SEPARATOR));
+    }
+}
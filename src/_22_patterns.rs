@@ -0,0 +1,374 @@
+// ============================================================================
+// 22. 고급 패턴 매칭
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. 구조화된 바인딩(auto [a, b] = ...)은 "분해"만 - 조건을 걸 수 없음
+//    Rust 패턴은 분해 + 검사: 리터럴, 범위, 슬라이스 길이, 중첩 enum 을 한 번에
+// 2. std::visit + overloaded{...} 는 variant 한 단계만 - Rust 는 중첩과 or(|)를 섞은 패턴
+// 3. 빠뜨린 경우는 컴파일 에러 (exhaustiveness) - C++ 의 switch 는 경고뿐
+// 4. 바인딩 모드: 참조를 매치하면 바인딩도 자동으로 참조 (match ergonomics)
+//    auto& [a, b] 처럼 매번 & 를 적지 않아도 됨
+// 5. let 은 반드시 맞는(irrefutable) 패턴만 - 실패할 수 있으면 let-else / if let
+// ============================================================================
+
+// 절 목록 (실행 순서) - cargo run -- 22:slice_patterns 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("slice_patterns", slice_patterns),
+    ("at_bindings", at_bindings),
+    ("binding_modes", binding_modes),
+    ("ref_keywords", ref_keywords),
+    ("nested_or_patterns", nested_or_patterns),
+    ("refutability", refutability),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "22"
+    }
+
+    fn name(&self) -> &'static str {
+        "고급 패턴 매칭"
+    }
+
+    fn description(&self) -> &'static str {
+        "슬라이스 패턴, @ 바인딩, 바인딩 모드, 반박 가능성 - 구조화된 바인딩, std::visit 과 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["슬라이스 패턴", "@ 바인딩", "바인딩 모드", "ref", "or 패턴", "반박 가능성"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 슬라이스 패턴
+// ----------------------------------------------------------------------------
+
+// 명령줄 인자 하나를 해석 - 길이와 내용을 한 번에 검사
+fn describe_command(args: &[&str]) -> String {
+    match args {
+        [] => "명령 없음".to_string(),
+        ["help"] => "도움말".to_string(),
+        ["run", chapter] => format!("{}장 실행", chapter),
+        ["run", first, .., last] => format!("{}장부터 {}장까지 실행", first, last),
+        [command, rest @ ..] => format!("알 수 없는 명령 {} (인자 {}개)", command, rest.len()),
+    }
+}
+
+fn slice_patterns() {
+    println!("--- 슬라이스 패턴 ---");
+
+    // C++ 에서는:
+    // if (args.empty()) ...
+    // else if (args.size() == 2 && args[0] == "run") ...
+    // else if (args.size() > 2 && args[0] == "run") { auto first = args[1]; auto last = args.back(); }
+    // → 길이 검사와 인덱싱을 따로 - 순서를 틀리면 범위 밖 접근
+
+    for args in [&[][..], &["help"], &["run", "07"], &["run", "05", "06", "09"], &["build", "x"]] {
+        println!("{:?} → {}", args, describe_command(args));
+    }
+
+    // 처음과 끝, 가운데 나머지
+    let scores = [90, 72, 85, 64, 99];
+    if let [first, middle @ .., last] = &scores[..] {
+        println!("처음 {}, 끝 {}, 가운데 {:?}", first, last, middle);
+    }
+
+    // 재귀와 잘 어울림 - 머리와 꼬리로 나누기
+    fn sum(values: &[i32]) -> i32 {
+        match values {
+            [] => 0,
+            [head, tail @ ..] => head + sum(tail),
+        }
+    }
+    println!("합계: {}", sum(&scores));
+
+    // 배열은 길이가 타입에 있으므로 [a, b, c] 만으로 빠짐없음
+    let [r, g, b] = [255u8, 128, 0];
+    println!("RGB 분해: {} {} {}", r, g, b);
+
+    // 인접한 두 값 - windows 의 원소도 슬라이스
+    let rising = scores.windows(2).filter(|w| matches!(w, [a, b] if b > a)).count();
+    println!("이전보다 오른 횟수: {}", rising);
+}
+
+// ----------------------------------------------------------------------------
+// @ 바인딩
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Request {
+    method: &'static str,
+    status: u16,
+    retries: u32,
+}
+
+fn at_bindings() {
+    println!("\n--- @ 바인딩 ---");
+
+    // C++ 에서는:
+    // auto [method, status, retries] = req;   // 분해만 - 조건은 if 로 따로
+    // if (status >= 500 && status < 600) log(status);
+
+    // 이름 @ 패턴: 패턴으로 검사하면서 값을 이름에 묶음
+    let requests = [
+        Request { method: "GET", status: 200, retries: 0 },
+        Request { method: "POST", status: 503, retries: 2 },
+        Request { method: "GET", status: 404, retries: 0 },
+        Request { method: "PUT", status: 502, retries: 5 },
+    ];
+    for req in &requests {
+        let verdict = match req {
+            Request { status: code @ 200..=299, .. } => format!("성공 {}", code),
+            Request { status: code @ 500..=599, retries: n @ 0..=3, .. } => {
+                format!("서버 에러 {} - 재시도 {}회째", code, n)
+            }
+            Request { status: 500..=599, retries, .. } => format!("재시도 {}회 - 포기", retries),
+            Request { method, status, .. } => format!("{} 실패 {}", method, status),
+        };
+        println!("{:?} → {}", req, verdict);
+    }
+
+    // 구조체 전체를 묶으면서 안쪽 필드도 검사
+    if let Some(slow @ Request { retries: 3.., .. }) = requests.iter().find(|r| r.retries >= 3) {
+        println!("재시도가 많은 요청: {} {}", slow.method, slow.status);
+    }
+
+    // let-else 와 함께 - 범위에 맞을 때만 이름에 묶고, 아니면 빠져나감
+    let id @ 1..=9 = 7 else {
+        unreachable!()
+    };
+    println!("한 자리 id: {}", id);
+}
+
+// ----------------------------------------------------------------------------
+// 바인딩 모드 (match ergonomics)
+// ----------------------------------------------------------------------------
+
+fn binding_modes() {
+    println!("\n--- 바인딩 모드 (match ergonomics) ---");
+
+    // C++ 에서는:
+    // auto [name, age] = person;    // 복사
+    // auto& [name, age] = person;   // 참조 - 선언마다 & 를 고름
+
+    let pair = (String::from("Ferris"), 7);
+
+    // 참조를 값 패턴으로 매치하면 바인딩 모드가 "참조로" 바뀜
+    // &(String, i32) 를 (name, age) 로 → name: &String, age: &i32
+    let (name, age) = &pair;
+    println!("이름 {} ({}), 나이 {}", name, type_name_of(&name), age);
+    println!("pair 는 그대로: {:?}", pair); // 옮겨지지 않음
+
+    // &mut 이면 바인딩도 &mut
+    let mut counts = vec![("a", 1), ("b", 2)];
+    for (key, count) in &mut counts {
+        *count *= 10;
+        println!("{} → {}", key, count);
+    }
+
+    // Option<String> 을 참조로 매치 - 안의 String 을 빌림
+    let title = Some(String::from("고급 패턴"));
+    match &title {
+        Some(t) => println!("제목 길이 {} ({})", t.len(), type_name_of(&t)),
+        None => println!("제목 없음"),
+    }
+    println!("title 도 그대로: {:?}", title);
+
+    // 값으로 매치하면 옮겨짐 - 아래 줄 이후 title 은 쓸 수 없음
+    if let Some(owned) = title {
+        println!("가져온 제목: {} ({})", owned, type_name_of(&owned));
+    }
+    // println!("{:?}", title); // 에러: 값이 이동됨
+
+    // 명시적으로 &(..) 패턴을 쓰면 참조를 벗김 - Copy 인 값만 꺼낼 때
+    let points = [(1, 2), (3, 4)];
+    let xs: Vec<i32> = points.iter().map(|&(x, _)| x).collect();
+    println!("x 좌표: {:?}", xs);
+}
+
+// 바인딩의 타입을 보여 주기 위한 도우미
+fn type_name_of<T: ?Sized>(_: &T) -> &'static str {
+    std::any::type_name::<T>()
+}
+
+// ----------------------------------------------------------------------------
+// ref 와 ref mut
+// ----------------------------------------------------------------------------
+
+fn ref_keywords() {
+    println!("\n--- ref 와 ref mut ---");
+
+    // match ergonomics 이전(2015)에는 참조로 묶으려면 ref 가 필요했음
+    // 지금은 주로 "값을 매치하면서 일부만 빌릴 때" 씀
+
+    // 값(Option<String>) 을 매치하면서 안의 String 은 옮기지 않고 빌림
+    let message = Some(String::from("hello"));
+    if let Some(ref text) = message {
+        println!("빌린 메시지: {}", text);
+    }
+    println!("message 는 그대로: {:?}", message);
+
+    // ref mut - 그 자리에서 고치기
+    let mut config = (String::from("debug"), 3);
+    let (ref mut level, ref mut depth) = config;
+    level.push_str("+trace");
+    *depth += 1;
+    println!("고친 설정: {:?}", config);
+
+    // 한 패턴 안에서 옮기기와 빌리기를 섞을 수 있음
+    struct Job {
+        name: String,
+        tags: Vec<String>,
+    }
+    let job = Job { name: String::from("build"), tags: vec![String::from("ci")] };
+    let Job { name, ref tags } = job; // name 은 옮김, tags 는 빌림
+    println!("작업 {} 태그 {:?}", name, tags);
+    println!("job.tags 는 아직 쓸 수 있음: {}", job.tags.len());
+
+    // C++ 에서는:
+    // auto& [name, tags] = job;    // 전부 참조 또는 전부 복사 - 필드마다 고를 수 없음
+}
+
+// ----------------------------------------------------------------------------
+// 중첩 or 패턴
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+enum Shape {
+    Circle { radius: f64 },
+    Rect { w: f64, h: f64 },
+    Square(f64),
+}
+
+#[derive(Debug)]
+enum Event {
+    Key(char),
+    Click { x: i32, y: i32 },
+    Draw(Shape),
+    Quit,
+}
+
+fn handle(event: &Event) -> String {
+    match event {
+        // 안쪽에 | - 한 갈래에서 여러 키
+        Event::Key('q' | 'Q') | Event::Quit => "종료".to_string(),
+        Event::Key(c @ ('0'..='9' | 'a'..='f')) => format!("16진 숫자 {}", c),
+        Event::Key(c) => format!("키 {}", c),
+        // 좌표 중 하나가 0 - 갈래마다 같은 이름을 같은 타입으로 묶어야 함
+        Event::Click { x: 0, y: v } | Event::Click { x: v, y: 0 } => format!("축 위 클릭 ({})", v),
+        Event::Click { x, y } => format!("클릭 ({}, {})", x, y),
+        // 중첩 enum 도 한 패턴으로
+        Event::Draw(Shape::Square(side) | Shape::Rect { w: side, h: _ }) if *side > 10.0 => {
+            "큰 사각형".to_string()
+        }
+        Event::Draw(Shape::Circle { radius }) => format!("원 넓이 {:.1}", std::f64::consts::PI * radius * radius),
+        Event::Draw(Shape::Rect { w, h }) => format!("사각형 넓이 {:.1}", w * h),
+        Event::Draw(shape) => format!("작은 도형 {:?}", shape),
+    }
+}
+
+fn nested_or_patterns() {
+    println!("\n--- 중첩 or 패턴 ---");
+
+    // C++ 에서는:
+    // std::visit(overloaded{
+    //     [](Key k) { if (k.c == 'q' || k.c == 'Q') ... },
+    //     [](Click c) { ... },
+    //     [](Draw d) { std::visit(overloaded{ ... }, d.shape); },   // 중첩은 visit 을 한 번 더
+    // }, event);
+    // → 빠뜨린 타입은 컴파일 에러지만, 값 조건(q/Q, x == 0)은 람다 안의 if 로
+
+    let events = [
+        Event::Key('Q'),
+        Event::Key('b'),
+        Event::Key('z'),
+        Event::Click { x: 0, y: 5 },
+        Event::Click { x: 3, y: 4 },
+        Event::Draw(Shape::Rect { w: 12.0, h: 1.0 }),
+        Event::Draw(Shape::Rect { w: 2.0, h: 3.0 }),
+        Event::Draw(Shape::Circle { radius: 1.0 }),
+        Event::Draw(Shape::Square(2.0)),
+        Event::Quit,
+    ];
+    for event in &events {
+        println!("{:?} → {}", event, handle(event));
+    }
+
+    // matches! 도 같은 문법
+    let quits = events.iter().filter(|e| matches!(e, Event::Quit | Event::Key('q' | 'Q'))).count();
+    println!("종료 이벤트: {}개", quits);
+}
+
+// ----------------------------------------------------------------------------
+// 반박 가능한 패턴과 반박 불가능한 패턴
+// ----------------------------------------------------------------------------
+
+// 실패할 수 있는 분해 - Option 하나는 ? 로, ? 로 풀 수 없는 패턴(두 Result 를 한꺼번에)은 let-else 로 이른 반환
+fn parse_point(text: &str) -> Option<(i32, i32)> {
+    let (x, y) = text.split_once(',')?;
+    let (Ok(x), Ok(y)) = (x.trim().parse(), y.trim().parse()) else {
+        return None;
+    };
+    Some((x, y))
+}
+
+fn refutability() {
+    println!("\n--- 반박 가능한 패턴과 반박 불가능한 패턴 ---");
+
+    // 반박 불가능(irrefutable): 항상 맞는 패턴 - let, 함수 인자, for
+    let (a, b) = (1, 2);
+    let print_pair = |(x, y): (i32, i32)| println!("쌍: {} {}", x, y);
+    print_pair((a, b));
+
+    // 반박 가능(refutable): 맞지 않을 수 있는 패턴 - if let, while let, match, let-else
+    // let Some(x) = maybe;   // 에러 E0005: None 이 처리되지 않음
+    let maybe: Option<i32> = None;
+    if let Some(x) = maybe {
+        println!("값: {}", x);
+    } else {
+        println!("maybe 는 None");
+    }
+
+    for text in ["3, 4", "3;4", "x, 1"] {
+        println!("parse_point({:?}) = {:?}", text, parse_point(text));
+    }
+
+    // while let - 맞는 동안 반복
+    let mut stack = vec![1, 2, 3];
+    while let Some(top) = stack.pop() {
+        print!("{} ", top);
+    }
+    println!("(스택 비움)");
+
+    // 반박 불가능한 패턴을 if let 에 쓰면 경고 (irrefutable_let_patterns)
+    // 빈 enum 이나 Result<T, Infallible> 은 실패할 갈래가 없으므로 let 으로 바로 분해할 수 있음
+    let always: Result<u8, std::convert::Infallible> = Ok(42);
+    let Ok(value) = always;
+    println!("Infallible 에서 꺼낸 값: {}", value);
+
+    // C++ 에서는:
+    // auto [x, y] = point;       // 구조화된 바인딩은 항상 맞는 분해만
+    // if (auto* p = std::get_if<int>(&v)) { ... }   // 실패할 수 있는 분해는 포인터 검사로
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patterns_pick_the_expected_arm() {
+        assert_eq!(describe_command(&["run", "05", "06", "09"]), "05장부터 09장까지 실행");
+        assert_eq!(describe_command(&["build"]), "알 수 없는 명령 build (인자 0개)");
+        assert_eq!(handle(&Event::Click { x: 7, y: 0 }), "축 위 클릭 (7)");
+        assert_eq!(handle(&Event::Key('q')), "종료");
+        assert_eq!(parse_point(" -2 ,5"), Some((-2, 5)));
+        assert_eq!(parse_point("1,two"), None);
+    }
+}
@@ -0,0 +1,376 @@
+// ============================================================================
+// 23. 문자열과 텍스트 심화
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. std::string 은 바이트열 (인코딩은 약속일 뿐) - String/&str 은 항상 올바른 UTF-8
+//    UTF-8 이 아닐 수 있는 텍스트는 타입이 따로: OsString(파일 이름, 환경 변수), CString(C API)
+// 2. std::u16string/wchar_t 대신 Windows 의 UTF-16 은 OsString 안에 감춰짐 (WTF-8)
+// 3. s[i] 가 없음 - 바이트, char(유니코드 스칼라 값), 자소 클러스터 중 무엇을 셀지 명시
+// 4. C 문자열의 널 종료는 타입(CStr)이 보장 - 중간에 0 이 있으면 CString::new 가 에러
+// 5. 용량 증가 규칙은 std::string 처럼 구현 세부 - reserve, with_capacity 로 직접 정함
+//
+// 10장(컬렉션)의 String 절을 본 뒤에 - 그곳의 기본(push_str, 슬라이싱)은 다시 설명하지 않음
+// ============================================================================
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::Instant;
+
+// 절 목록 (실행 순서) - cargo run -- 23:os_strings 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("os_strings", os_strings),
+    ("c_strings", c_strings),
+    ("chars_and_graphemes", chars_and_graphemes),
+    ("indexing_pitfalls", indexing_pitfalls),
+    ("byte_vs_char_iteration", byte_vs_char_iteration),
+    ("capacity_growth", capacity_growth),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "23"
+    }
+
+    fn name(&self) -> &'static str {
+        "문자열과 텍스트 심화"
+    }
+
+    fn description(&self) -> &'static str {
+        "OsString, CString, char 와 자소 클러스터, 인덱싱 함정, 순회 비용, 용량 증가 - std::string, std::u16string 과 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["OsString", "CString", "char", "자소 클러스터", "UTF-8", "용량"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// OsString 과 OsStr
+// ----------------------------------------------------------------------------
+
+fn os_strings() {
+    println!("--- OsString 과 OsStr ---");
+
+    // C++ 에서는:
+    // std::filesystem::path p = argv[1];   // 유닉스: char 바이트열, Windows: wchar_t(UTF-16)
+    // std::string s = p.string();          // Windows 에서 변환 실패 시 예외
+    // → Rust 는 "운영체제 문자열" 을 OsString 한 타입으로 - 변환은 Option/Cow 로 드러남
+
+    // 환경 변수, 명령줄 인자, 파일 이름은 UTF-8 이라는 보장이 없음
+    let home: Option<OsString> = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"));
+    println!("홈 디렉터리 변수: {}", if home.is_some() { "있음" } else { "없음" });
+
+    // OsStr → &str 은 실패할 수 있음 (Option), 손실을 감수하면 to_string_lossy (Cow, 51장)
+    let name = OsStr::new("notes.txt");
+    println!("to_str: {:?}, to_string_lossy: {}", name.to_str(), name.to_string_lossy());
+
+    // Path 는 OsStr 을 감싼 것 - 파일 이름, 확장자도 &OsStr
+    let path = Path::new("content").join("23_text.toml");
+    let ext: Option<&OsStr> = path.extension();
+    println!("확장자 {:?} (타입은 Option<&OsStr>)", ext);
+
+    // 유닉스에서는 UTF-8 이 아닌 바이트도 그대로 파일 이름이 될 수 있음
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let raw = OsStr::from_bytes(b"caf\xE9.txt"); // Latin-1 로 쓴 "café"
+        println!("Latin-1 파일 이름: to_str = {:?}", raw.to_str());
+        println!("           lossy  = {}", raw.to_string_lossy()); // 잘못된 바이트는 U+FFFD
+        println!("           바이트 = {:?}", raw.as_bytes());
+    }
+
+    // 운영체제와 상관없이 쓸 수 있는 바이트 보기 - 다시 OsStr 로 만들 때는 unsafe
+    let encoded = OsStr::new("한글.txt").as_encoded_bytes();
+    println!("as_encoded_bytes 길이: {} (UTF-8 부분은 UTF-8 그대로)", encoded.len());
+
+    // OsString 도 String 처럼 이어 붙일 수 있음
+    let mut file = OsString::from("report");
+    file.push(".md");
+    println!("OsString: {:?}", file);
+}
+
+// ----------------------------------------------------------------------------
+// CString 과 CStr
+// ----------------------------------------------------------------------------
+
+fn c_strings() {
+    println!("\n--- CString 과 CStr ---");
+
+    // C++ 에서는:
+    // std::string s = "hi";
+    // puts(s.c_str());                  // 널 종료는 std::string 이 항상 보장
+    // std::string t("a\0b", 3); puts(t.c_str());   // 'a' 만 출력 - 조용히 잘림
+
+    // CString: 소유, 끝에 \0, 중간에 \0 없음 (String 과 짝)
+    // &CStr  : 빌림 (&str 과 짝) - C 함수에 넘길 때는 as_ptr()
+    let owned = CString::new("hello").expect("중간에 0 이 없음");
+    println!("CString: {:?}, 바이트(널 포함): {:?}", owned, owned.as_bytes_with_nul());
+
+    // 중간에 0 이 있으면 만들 때 에러 - C 쪽에서 잘리는 대신 바로 드러남
+    match CString::new("a\0b") {
+        Ok(s) => println!("만들어짐: {:?}", s),
+        Err(e) => println!("CString::new(\"a\\0b\") 에러: 위치 {} 에 0", e.nul_position()),
+    }
+
+    // C 문자열 리터럴 c"..." - 컴파일 시점에 &CStr
+    let literal: &CStr = c"리터럴";
+    println!("c\"리터럴\": {:?} ({}바이트 + 널)", literal, literal.count_bytes());
+
+    // C 에서 받은 버퍼 → CStr → &str (UTF-8 이 아니면 에러)
+    let buffer: Vec<u8> = b"from C\0".to_vec();
+    let from_c = CStr::from_bytes_with_nul(&buffer).unwrap();
+    println!("to_str: {:?}", from_c.to_str());
+    let buffer: Vec<u8> = b"caf\xE9\0".to_vec();
+    let latin1 = CStr::from_bytes_with_nul(&buffer).unwrap();
+    println!("UTF-8 이 아닌 C 문자열: {:?} → lossy {}", latin1.to_str().is_err(), latin1.to_string_lossy());
+
+    // 포인터를 넘길 때의 함정: 임시 CString 의 포인터는 문장이 끝나면 댕글링
+    // let p = CString::new("x").unwrap().as_ptr();  // 경고(dangling_pointers_from_temporaries)
+    let kept = CString::new("x").unwrap();
+    let ptr = kept.as_ptr(); // kept 가 살아 있는 동안만 유효
+    // 안전성: ptr 은 살아 있는 kept 의 널 종료 버퍼를 가리킴
+    let back = unsafe { CStr::from_ptr(ptr) };
+    println!("포인터로 다시 읽기: {:?}", back);
+}
+
+// ----------------------------------------------------------------------------
+// char 와 자소 클러스터
+// ----------------------------------------------------------------------------
+
+// 결합 문자(악센트, 한글 자모의 중성/종성)와 이음 문자(ZWJ), 이체 선택자를 앞 글자에 붙임
+// 간단한 근사 - 실제 규칙(UAX #29)은 unicode-segmentation 크레이트의 graphemes()
+fn rough_graphemes(text: &str) -> Vec<&str> {
+    let joins_previous = |c: char| {
+        matches!(c,
+            '\u{0300}'..='\u{036F}'     // 결합 분음 부호
+            | '\u{1160}'..='\u{11FF}'   // 한글 중성, 종성 자모
+            | '\u{FE00}'..='\u{FE0F}'   // 이체 선택자
+            | '\u{1F3FB}'..='\u{1F3FF}' // 피부색 수정자
+            | '\u{200D}')
+    };
+    let mut clusters: Vec<&str> = Vec::new();
+    let mut start = 0;
+    let mut after_zwj = false;
+    for (i, c) in text.char_indices().skip(1) {
+        if joins_previous(c) || after_zwj {
+            after_zwj = c == '\u{200D}';
+            continue;
+        }
+        clusters.push(&text[start..i]);
+        start = i;
+    }
+    if !text.is_empty() {
+        clusters.push(&text[start..]);
+    }
+    clusters
+}
+
+fn chars_and_graphemes() {
+    println!("\n--- char 와 자소 클러스터 ---");
+
+    // C++ 에서는:
+    // std::string s = "é";        // 바이트 2개 (UTF-8)
+    // std::u16string u = u"😀";   // char16_t 2개 (서로게이트 쌍)
+    // std::u32string w = U"é";    // 조합형이면 char32_t 2개 - "글자" 는 라이브러리 없이 못 셈
+
+    // char = 유니코드 스칼라 값 (4바이트) - 사람이 보는 "글자" 와 다를 수 있음
+    let samples = [
+        ("é (완성형)", "\u{00E9}"),
+        ("é (조합형)", "e\u{0301}"),
+        ("한 (완성형)", "한"),
+        ("한 (자모)", "\u{1112}\u{1161}\u{11AB}"),
+        ("👍🏽", "\u{1F44D}\u{1F3FD}"),
+        ("👨‍👩‍👧", "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+    ];
+    for (label, text) in samples {
+        println!(
+            "{:12} 바이트 {:2}, UTF-16 {}, char {}, 글자 {}",
+            label,
+            text.len(),
+            text.encode_utf16().count(),
+            text.chars().count(),
+            rough_graphemes(text).len()
+        );
+    }
+
+    // 같아 보여도 다른 문자열 - 비교 전에 정규화(NFC) 필요 (unicode-normalization 크레이트)
+    println!("완성형 é == 조합형 é ? {}", "\u{00E9}" == "e\u{0301}");
+
+    // 글자 단위로 뒤집기 - chars().rev() 는 조합형을 깨뜨림
+    let word = "cafe\u{0301}";
+    let by_char: String = word.chars().rev().collect();
+    let by_grapheme: String = rough_graphemes(word).into_iter().rev().collect();
+    println!("chars().rev(): {:?}, 글자 단위: {:?}", by_char, by_grapheme);
+
+    // char 의 분류 메서드는 유니코드 기준
+    let c = 'ß';
+    println!("{:?}: 알파벳 {}, 대문자 {:?} (한 글자가 두 글자로)", c, c.is_alphabetic(), c.to_uppercase().to_string());
+}
+
+// ----------------------------------------------------------------------------
+// str 인덱싱의 함정
+// ----------------------------------------------------------------------------
+
+// 최대 max 바이트로 자르되 글자 중간에서 자르지 않음
+fn truncate_at_boundary(text: &str, max: usize) -> &str {
+    if max >= text.len() {
+        return text;
+    }
+    let mut end = max;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+fn indexing_pitfalls() {
+    println!("\n--- str 인덱싱의 함정 ---");
+
+    // C++ 에서는:
+    // s.substr(0, 10)   // 바이트 단위 - 한글 중간에서 잘려도 아무 말 없음 (깨진 UTF-8)
+    // s.find("x")       // 바이트 위치
+
+    let text = "Rust 는 안전해요";
+
+    // 1. 범위 슬라이스는 바이트 위치 - 글자 중간이면 panic
+    // let bad = &text[..6];   // panic: byte index 6 is not a char boundary
+    println!("6 은 글자 경계? {} ('는' 은 5..8 바이트)", text.is_char_boundary(6));
+    println!("get(..6) 은 panic 대신 None: {:?}", text.get(..6));
+
+    // 2. find 가 돌려주는 위치도 바이트 - 그대로 슬라이스에 쓰면 안전
+    if let Some(at) = text.find("안전") {
+        println!("find(\"안전\") = {} (바이트), 그 뒤: {:?}", at, &text[at..]);
+    }
+
+    // 3. n 번째 글자는 O(n) - 루프 안에서 chars().nth(i) 를 반복하면 O(n²)
+    let fifth: Option<char> = text.chars().nth(5);
+    println!("chars().nth(5) = {:?}", fifth);
+    // 여러 번 쓸 거면 한 번 모아 두기
+    let chars: Vec<char> = text.chars().collect();
+    println!("Vec<char> 로 모으면 [5] = {:?} (대신 글자마다 4바이트)", chars[5]);
+
+    // 4. 길이 제한 (DB 컬럼, 트윗) - 글자 경계에서 자르기
+    for max in [5, 6, 7, 8] {
+        println!("앞 {}바이트 안에서: {:?}", max, truncate_at_boundary(text, max));
+    }
+
+    // 5. char_indices - 글자와 바이트 위치를 같이
+    let positions: Vec<(usize, char)> = "가a나".char_indices().collect();
+    println!("char_indices: {:?}", positions);
+
+    // 6. 대소문자 변환은 글자 수를 바꿀 수 있음 - 원래 위치로 되돌아가 자르면 안 됨
+    let upper = "straße".to_uppercase();
+    println!("\"straße\" {}글자 → {:?} {}글자", "straße".chars().count(), upper, upper.chars().count());
+}
+
+// ----------------------------------------------------------------------------
+// 바이트 순회와 char 순회
+// ----------------------------------------------------------------------------
+
+fn byte_vs_char_iteration() {
+    println!("\n--- 바이트 순회와 char 순회 ---");
+
+    // C++ 에서는:
+    // for (char c : s)      // 항상 바이트 - 한글은 3조각
+    // std::count(s.begin(), s.end(), ',')
+
+    let line = "이름,나이,도시,name,age,city\n".repeat(10_000);
+
+    // ASCII 구분자를 찾을 때는 바이트로 충분 - UTF-8 의 다바이트 문자에는 ASCII 바이트가 들어가지 않음
+    let start = Instant::now();
+    let by_byte = black_box(&line).bytes().filter(|&b| b == b',').count();
+    let byte_time = start.elapsed();
+
+    // chars() 는 글자마다 UTF-8 을 해독 - 더 느림
+    let start = Instant::now();
+    let by_char = black_box(&line).chars().filter(|&c| c == ',').count();
+    let char_time = start.elapsed();
+
+    println!("쉼표 {}개 = {}개 (입력 {}KB)", by_byte, by_char, line.len() / 1024);
+    println!("bytes(): {:?}, chars(): {:?} (빌드와 기계에 따라 다름 - --release 로 비교)", byte_time, char_time);
+
+    // 표준 라이브러리의 검색(find, split, matches)은 내부적으로 바이트 검색 - 직접 고를 필요 없음
+    println!("matches(',').count() = {}", line.matches(',').count());
+
+    // 글자 수가 필요하면 chars().count() - len() 은 바이트 수
+    let sample = "héllo 세계";
+    println!("{:?}: len() {} 바이트, chars().count() {} 글자", sample, sample.len(), sample.chars().count());
+
+    // 바이트 리터럴과 is_ascii_* - ASCII 만 다루는 파서에 알맞음
+    let digits = b"2024-07-15".iter().filter(|b| b.is_ascii_digit()).count();
+    println!("ASCII 숫자: {}개", digits);
+}
+
+// ----------------------------------------------------------------------------
+// String 용량 증가
+// ----------------------------------------------------------------------------
+
+fn capacity_growth() {
+    println!("\n--- String 용량 증가 ---");
+
+    // C++ 에서는:
+    // std::string s;            // SSO - 짧은 문자열(보통 15~22바이트)은 힙 할당 없음
+    // s.reserve(100);
+    // → Rust 의 String 에는 SSO 가 없음: 빈 String 은 할당 없음, 한 글자부터 힙
+
+    let mut s = String::new();
+    println!("String::new(): 용량 {} (할당 없음)", s.capacity());
+
+    // 용량이 바뀌는 순간만 기록 - 대략 두 배씩 (구체적인 수치는 구현 세부)
+    let mut changes = Vec::new();
+    let mut last = s.capacity();
+    for _ in 0..100 {
+        s.push('x');
+        if s.capacity() != last {
+            last = s.capacity();
+            changes.push((s.len(), last));
+        }
+    }
+    println!("push 100번 동안 용량 변화 (길이, 용량): {:?}", changes);
+
+    // 최종 크기를 알면 한 번에
+    let mut joined = String::with_capacity(5 * 3);
+    for word in ["one", "two", "six", "ten", "red"] {
+        joined.push_str(word);
+    }
+    println!("with_capacity(15) 후 길이 {}, 용량 {}", joined.len(), joined.capacity());
+
+    // reserve 는 "추가로 최소 n" - 이미 충분하면 아무것도 안 함
+    joined.reserve(1);
+    println!("reserve(1) 후 용량: {} (모자라면 늘림)", joined.capacity());
+
+    // 줄이기 - 오래 두는 큰 문자열에만 (다시 자라면 재할당)
+    s.truncate(3);
+    println!("truncate(3) 후 길이 {}, 용량 {} (용량은 그대로)", s.len(), s.capacity());
+    s.shrink_to_fit();
+    println!("shrink_to_fit 후 용량: {}", s.capacity());
+
+    // format! 과 collect 도 미리 크기를 짐작 - 반복문의 + 는 매번 재할당할 수 있음
+    let csv: String = (1..=5).map(|n| n.to_string()).collect::<Vec<_>>().join(",");
+    println!("join: {:?} (용량 {})", csv, csv.capacity());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_and_cuts_by_character() {
+        assert_eq!(rough_graphemes("e\u{0301}x"), ["e\u{0301}", "x"]);
+        assert_eq!(rough_graphemes("\u{1F468}\u{200D}\u{1F469}!").len(), 2);
+        assert_eq!(rough_graphemes("\u{1112}\u{1161}\u{11AB}").len(), 1);
+        assert!(rough_graphemes("").is_empty());
+        assert_eq!(truncate_at_boundary("가나다", 4), "가");
+        assert_eq!(truncate_at_boundary("가나다", 6), "가나");
+        assert_eq!(truncate_at_boundary("ab", 9), "ab");
+    }
+}
@@ -0,0 +1,314 @@
+// ============================================================================
+// 24. 수치 타입과 변환
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. 부호 있는 정수 오버플로 - C++ 은 미정의 동작, Rust 는 디버그 빌드에서 panic,
+//    릴리스 빌드에서 2의 보수로 감김 (정의된 동작)
+//    의도를 드러내는 메서드: wrapping_*, checked_*, saturating_*, overflowing_*
+// 2. 암시적 변환이 없음 - int + long, int → double 도 직접 변환해야 함
+//    C++ 의 정수 승격(char + char → int), 부호 있는/없는 섞기 규칙이 없음
+// 3. as 는 절대 실패하지 않는 변환 (잘리거나 포화) - 실패를 알고 싶으면 TryFrom
+// 4. 부동소수점은 PartialOrd 만 (NaN) - 정렬에는 total_cmp
+// ============================================================================
+
+use std::cmp::Ordering;
+use std::num::Wrapping;
+
+// 절 목록 (실행 순서) - cargo run -- 24:overflow_arithmetic 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("overflow_arithmetic", overflow_arithmetic),
+    ("as_vs_try_from", as_vs_try_from),
+    ("float_comparison", float_comparison),
+    ("total_ordering", total_ordering),
+    ("integer_promotion", integer_promotion),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "24"
+    }
+
+    fn name(&self) -> &'static str {
+        "수치 타입과 변환"
+    }
+
+    fn description(&self) -> &'static str {
+        "오버플로를 다루는 네 가지 산술, as 와 TryFrom, 부동소수점 비교와 total_cmp, C++ 정수 승격과의 차이"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["오버플로", "wrapping", "checked", "saturating", "as", "TryFrom", "f64", "total_cmp"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 오버플로를 다루는 산술
+// ----------------------------------------------------------------------------
+
+fn overflow_arithmetic() {
+    println!("--- 오버플로를 다루는 산술 ---");
+
+    // C++ 에서는:
+    // int32_t x = INT32_MAX; x + 1;     // 미정의 동작 - 컴파일러가 "일어나지 않는다" 고 가정
+    // uint8_t y = 255; y + 1;           // int 로 승격되어 256, 다시 uint8_t 에 넣으면 0
+    // __builtin_add_overflow(a, b, &r)  // 검사는 컴파일러 확장으로
+
+    let x: u8 = 250;
+    let step: u8 = 10;
+
+    // x + step 은 디버그 빌드에서 panic ("attempt to add with overflow"), 릴리스에서는 감김
+    // → 오버플로가 가능한 곳에서는 의도를 메서드로 적음
+    println!("wrapping_add    : {}", x.wrapping_add(step)); // 4 - 해시, 난수, 체크섬
+    println!("checked_add     : {:?}", x.checked_add(step)); // None - 입력 검증
+    println!("saturating_add  : {}", x.saturating_add(step)); // 255 - 음량, 색, 카운터
+    println!("overflowing_add : {:?}", x.overflowing_add(step)); // (4, true) - 올림수가 필요할 때
+
+    // 뺄셈과 곱셈, 나눗셈, 부호 있는 정수도 같은 네 벌
+    println!("0u32.checked_sub(1) = {:?}", 0u32.checked_sub(1));
+    println!("i32::MIN.checked_abs() = {:?} (|MIN| 은 표현 불가)", i32::MIN.checked_abs());
+    println!("i32::MIN.wrapping_div(-1) = {}", i32::MIN.wrapping_div(-1));
+    println!("10i32.checked_div(0) = {:?} (0 으로 나누기도 None)", 10i32.checked_div(0));
+
+    // ? 와 함께 - 중간에 넘치면 전체가 None
+    fn area(w: u32, h: u32, layers: u32) -> Option<u32> {
+        w.checked_mul(h)?.checked_mul(layers)
+    }
+    println!("area(1000, 1000, 10) = {:?}", area(1000, 1000, 10));
+    println!("area(100_000, 100_000, 1) = {:?}", area(100_000, 100_000, 1));
+
+    // Wrapping<T> - 모든 연산이 감기는 타입 (연산자를 그대로 쓰고 싶을 때)
+    let mut hash = Wrapping(5381u32);
+    for b in b"rust" {
+        hash = hash * Wrapping(33) + Wrapping(*b as u32);
+    }
+    println!("djb2(\"rust\") = {}", hash.0);
+
+    // 그냥 + 를 쓰면 빌드에 따라 다름 - overflow-checks 는 기본으로 debug_assertions 를 따름
+    println!(
+        "이 빌드에서 u8::MAX + 1 은: {} (Cargo.toml 의 [profile.*] overflow-checks 로 바꿀 수 있음)",
+        if cfg!(debug_assertions) { "panic" } else { "0 으로 감김" }
+    );
+}
+
+// ----------------------------------------------------------------------------
+// as 와 TryFrom
+// ----------------------------------------------------------------------------
+
+fn as_vs_try_from() {
+    println!("\n--- as 와 TryFrom ---");
+
+    // C++ 에서는:
+    // static_cast<uint8_t>(300)      // 44 - 모듈로 (C++20 부터 정의됨)
+    // static_cast<int>(3.9e10)       // 미정의 동작! (범위 밖 float → int)
+    // gsl::narrow<uint8_t>(300)      // 예외 - 표준에는 없음
+
+    // as: 정수 → 정수는 비트를 자르거나(좁힘) 부호 확장(넓힘) - 실패하지 않음
+    println!("300i32 as u8    = {}", 300i32 as u8); // 44
+    println!("-1i32 as u32    = {}", -1i32 as u32); // 4294967295
+    println!("-1i8 as i32     = {}", -1i8 as i32); // -1 (부호 확장)
+    println!("200u8 as i8     = {}", 200u8 as i8); // -56
+
+    // as: float → int 는 포화 (Rust 1.45 부터) - C++ 과 달리 미정의 동작이 아님
+    println!("3.9e10 as i32   = {}", 3.9e10_f64 as i32); // i32::MAX
+    println!("-1.5 as u8      = {}", -1.5_f64 as u8); // 0
+    #[expect(clippy::cast_nan_to_int, reason = "NaN 이 0 으로 포화되는 것을 보여 줌")]
+    let nan_as_int = f64::NAN as i32;
+    println!("f64::NAN as i32 = {}", nan_as_int); // 0
+    println!("2.9 as i32      = {} (0 쪽으로 버림)", 2.9_f64 as i32);
+
+    // as: int → float 는 가장 가까운 값으로 - 큰 정수는 정밀도를 잃음
+    let big = 16_777_217u32; // 2^24 + 1
+    println!("{} as f32 = {} (f32 가수는 24비트)", big, big as f32);
+
+    // TryFrom / try_into: 값이 범위 안일 때만 - 실패는 Result 로
+    println!("u8::try_from(300i32) = {:?}", u8::try_from(300i32));
+    println!("u8::try_from(200i32) = {:?}", u8::try_from(200i32));
+    let index: Result<usize, _> = (-3i64).try_into();
+    println!("(-3i64).try_into::<usize>() = {:?}", index);
+
+    // From: 항상 성공하는 넓힘만 구현됨 - 손실이 없음이 타입으로 보장
+    let wide: i64 = i64::from(7i32);
+    let f: f64 = f64::from(1.5f32);
+    println!("i64::from(7i32) = {}, f64::from(1.5f32) = {}", wide, f);
+    // u32 → f32 는 From 이 없음 (위의 16_777_217 처럼 손실 가능)
+
+    // 실무 규칙: 길이, 인덱스 변환은 try_from + ? (또는 expect 로 불변식을 적음)
+    fn to_index(n: i64) -> Result<usize, String> {
+        usize::try_from(n).map_err(|e| format!("잘못된 인덱스 {}: {}", n, e))
+    }
+    println!("to_index(5) = {:?}, to_index(-1) = {:?}", to_index(5), to_index(-1));
+}
+
+// ----------------------------------------------------------------------------
+// 부동소수점 비교의 함정
+// ----------------------------------------------------------------------------
+
+// 상대 오차와 절대 오차를 함께 - 0 근처에서는 상대 오차만으로 부족
+fn approx_eq(a: f64, b: f64, rel: f64, abs: f64) -> bool {
+    let diff = (a - b).abs();
+    diff <= abs || diff <= rel * a.abs().max(b.abs())
+}
+
+fn float_comparison() {
+    println!("\n--- 부동소수점 비교의 함정 ---");
+
+    // C++ 과 같은 IEEE 754 - 함정도 같음, 다만 타입 시스템이 일부를 드러냄
+
+    // 1. 0.1 + 0.2 != 0.3
+    let sum = 0.1 + 0.2;
+    println!("0.1 + 0.2 = {:.17}, == 0.3 ? {}", sum, sum == 0.3);
+    println!("approx_eq: {}", approx_eq(sum, 0.3, 1e-12, 1e-12));
+
+    // 2. EPSILON 은 "1 근처의 간격" - 큰 수에는 너무 작고 작은 수에는 너무 큼
+    let big = 1e16;
+    println!("1e16 + 1.0 == 1e16 ? {} (간격이 2)", big + 1.0 == big);
+    println!("f64::EPSILON = {:e}", f64::EPSILON);
+
+    // 3. NaN 은 자기 자신과도 같지 않음 → f64 는 Eq, Ord 가 아님 (PartialEq, PartialOrd 만)
+    let nan = f64::NAN;
+    #[expect(clippy::eq_op, reason = "NaN 이 자기 자신과 같지 않음을 보여 줌")]
+    let nan_eq_nan = nan == nan;
+    println!("NaN == NaN ? {}, NaN < 1 ? {}, NaN > 1 ? {}", nan_eq_nan, nan < 1.0, nan > 1.0);
+    println!("partial_cmp(NaN, 1) = {:?}", nan.partial_cmp(&1.0));
+    // 그래서 HashMap<f64, _> 의 키, BTreeSet<f64>, v.sort() 는 컴파일 에러
+
+    // 4. -0.0 == 0.0 이지만 다른 값
+    println!("-0.0 == 0.0 ? {}, 1/-0.0 = {}", -0.0_f64 == 0.0, 1.0 / -0.0_f64);
+
+    // 5. max/min 은 NaN 을 무시 (C++ std::max 는 인자 순서에 따라 NaN 을 돌려줌)
+    println!("1.0.max(NaN) = {}, NaN.max(1.0) = {}", 1.0_f64.max(nan), nan.max(1.0));
+
+    // 6. 누적 오차 - 0.1 을 열 번 더하기
+    let total: f64 = (0..10).map(|_| 0.1).sum();
+    println!("0.1 을 10번 더하면 {:.17} (== 1.0 ? {})", total, total == 1.0);
+
+    // 돈은 정수(센트)로 - f64 로 계산하지 않음
+    let cents: i64 = 10 + 20;
+    println!("0.10 + 0.20 달러 = {}.{:02}", cents / 100, cents % 100);
+}
+
+// ----------------------------------------------------------------------------
+// total_cmp 로 정렬
+// ----------------------------------------------------------------------------
+
+fn total_ordering() {
+    println!("\n--- total_cmp 로 정렬 ---");
+
+    // C++ 에서는:
+    // std::sort(v.begin(), v.end());    // NaN 이 있으면 strict weak ordering 위반 - 미정의 동작
+    // std::strong_order(a, b)           // C++20 - IEEE totalOrder (Rust 의 total_cmp 와 같은 규칙)
+
+    let mut values = vec![3.5, -0.0, f64::NAN, 1.0, f64::NEG_INFINITY, 0.0, -2.0, f64::INFINITY];
+
+    // values.sort();                   // 에러: f64 는 Ord 가 아님
+    // values.sort_by(|a, b| a.partial_cmp(b).unwrap());  // NaN 이 있으면 panic
+
+    // total_cmp: IEEE 754 totalOrder - -NaN < -∞ < ... < -0 < +0 < ... < +∞ < NaN
+    values.sort_by(f64::total_cmp);
+    println!("total_cmp 정렬: {:?}", values);
+
+    // 최대/최소도 같은 방법
+    let max = values.iter().copied().max_by(f64::total_cmp);
+    println!("max_by(total_cmp) = {:?} (NaN 이 가장 큼)", max);
+
+    // NaN 을 빼고 싶으면 먼저 거르기
+    let finite_max = values.iter().copied().filter(|v| !v.is_nan()).max_by(f64::total_cmp);
+    println!("NaN 을 뺀 max = {:?}", finite_max);
+
+    // 구조체 필드로 정렬할 때
+    let mut scores: [(&str, f64); 4] = [("ann", 91.5), ("bob", 78.0), ("cy", 91.5), ("dee", 85.25)];
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(b.0)));
+    println!("점수 내림차순: {:?}", scores);
+
+    // BTreeMap 키 등 Ord 가 꼭 필요하면 감싸기 (ordered-float 크레이트가 같은 일을 함)
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Total(f64);
+    impl Eq for Total {}
+    impl PartialOrd for Total {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Total {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.total_cmp(&other.0)
+        }
+    }
+    let set: std::collections::BTreeSet<Total> = [2.5, 0.5, 1.5].into_iter().map(Total).collect();
+    println!("BTreeSet<Total>: {:?}", set);
+}
+
+// ----------------------------------------------------------------------------
+// C++ 정수 승격과의 차이
+// ----------------------------------------------------------------------------
+
+fn integer_promotion() {
+    println!("\n--- C++ 정수 승격과의 차이 ---");
+
+    // C++ 에서는:
+    // uint8_t a = 200, b = 100;
+    // auto c = a + b;            // int 300 - 작은 타입은 int 로 승격
+    // unsigned u = 1; int i = -1;
+    // if (i < u) ...             // false! i 가 unsigned 로 변환되어 4294967295
+    // auto s = sizeof(x) - 1;    // size_t - 음수가 되면 아주 큰 수
+
+    // Rust: 같은 타입끼리만 연산 - 결과도 같은 타입
+    let a: u8 = 200;
+    let b: u8 = 100;
+    // let c = a + b;            // 디버그 빌드에서 panic (u8 에 300 은 안 들어감)
+    let c = a as u16 + b as u16; // 넓힐 곳을 직접 고름
+    println!("200u8 + 100u8 을 u16 으로: {}", c);
+
+    // 부호가 다르면 비교도 안 됨 - 변환을 직접
+    let u: u32 = 1;
+    let i: i32 = -1;
+    // if i < u {}               // 에러: i32 와 u32 비교 불가
+    println!("i64 로 넓혀 비교: -1 < 1 ? {}", (i as i64) < (u as i64));
+    println!("try_from 으로 비교: {:?}", u32::try_from(i).map(|i| i < u));
+
+    // 정수 → 실수도 직접 - 평균 계산에서 자주 만남
+    let sum: u32 = 7;
+    let count: usize = 2;
+    let mean = sum as f64 / count as f64;
+    println!("평균 {} (정수 나눗셈이면 {})", mean, sum / count as u32);
+
+    // 리터럴은 문맥에서 타입을 추론 - 접미사로 정할 수도
+    let shifted = 1u64 << 40; // C++ 의 1 << 40 은 int 라서 미정의 동작
+    println!("1u64 << 40 = {}", shifted);
+
+    // 시프트 양이 비트 수 이상이면 디버그에서 panic - checked_shl / wrapping_shl
+    println!("1u32.checked_shl(32) = {:?}", 1u32.checked_shl(32));
+
+    // usize 의 뺄셈 - C++ 의 size_t 처럼 감기지 않고 (디버그) panic
+    let len: usize = 0;
+    println!("len.checked_sub(1) = {:?}, saturating_sub(1) = {}", len.checked_sub(1), len.saturating_sub(1));
+
+    // 비트 수가 필요한 연산은 메서드로
+    let flags: u16 = 0b1011_0000;
+    println!(
+        "count_ones {}, leading_zeros {}, trailing_zeros {}, swap_bytes {:#06x}",
+        flags.count_ones(),
+        flags.leading_zeros(),
+        flags.trailing_zeros(),
+        flags.swap_bytes()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_floats_with_tolerance() {
+        assert!(approx_eq(0.1 + 0.2, 0.3, 1e-12, 1e-12));
+        assert!(approx_eq(1e-20, 0.0, 1e-12, 1e-15));
+        assert!(!approx_eq(1.0, 1.001, 1e-6, 1e-9));
+    }
+}
@@ -0,0 +1,350 @@
+// ============================================================================
+// 25. 배열과 슬라이스 심화
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. [T; N] = std::array<T, N>, Vec<T> = std::vector<T>, &[T] = std::span<const T>
+//    다만 슬라이스는 언어에 내장 - 배열, Vec, 부분 범위 모두 &[T] 하나로 받음
+// 2. <algorithm> 의 자유 함수(반복자 쌍) 대신 슬라이스의 메서드
+//    std::sort(v.begin(), v.end()) → v.sort(), 범위를 잘못 섞을 수 없음
+// 3. 범위 밖 접근은 panic (v[i]) 또는 None (v.get(i)) - operator[] 의 미정의 동작이 없음
+// 4. 가변 슬라이스 두 개를 겹치지 않게 나누는 것은 split_at_mut 같은 API 로만 (빌림 규칙)
+// ============================================================================
+
+use std::cmp::Reverse;
+
+// 절 목록 (실행 순서) - cargo run -- 25:array_slice_vec 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("array_slice_vec", array_slice_vec),
+    ("chunks_and_windows", chunks_and_windows),
+    ("splitting", splitting),
+    ("sorting", sorting),
+    ("binary_search", binary_search),
+    ("rotate_and_select", rotate_and_select),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "25"
+    }
+
+    fn name(&self) -> &'static str {
+        "배열과 슬라이스 심화"
+    }
+
+    fn description(&self) -> &'static str {
+        "[T; N], &[T], Vec<T> 와 chunks, windows, split_at, 정렬, binary_search, rotate, select_nth - <algorithm> 과 대응"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["배열", "슬라이스", "chunks", "windows", "정렬", "binary_search", "select_nth_unstable"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// [T; N], &[T], Vec<T>
+// ----------------------------------------------------------------------------
+
+// 슬라이스로 받으면 배열, Vec, 부분 범위를 모두 받을 수 있음 (std::span 과 같은 역할)
+fn average(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+fn array_slice_vec() {
+    println!("--- [T; N], &[T], Vec<T> ---");
+
+    // C++ 에서는:
+    // std::array<int, 3> a{1, 2, 3};     // 크기가 타입에, 스택
+    // std::vector<int> v{1, 2, 3};       // 힙, 자람
+    // std::span<const int> s{v};         // 빌린 보기 (C++20)
+
+    let array: [f64; 4] = [1.0, 2.0, 3.0, 4.0]; // 크기가 타입의 일부, 복사 가능(Copy 원소면)
+    let vec: Vec<f64> = vec![10.0, 20.0];
+    println!("크기: [f64; 4] = {}바이트, &[f64] = {}바이트 (포인터 + 길이), Vec<f64> = {}바이트 (포인터 + 길이 + 용량)",
+        size_of::<[f64; 4]>(),
+        size_of::<&[f64]>(),
+        size_of::<Vec<f64>>()
+    );
+
+    // 모두 &[f64] 로 - 자동 변환(deref, unsize)
+    println!("average(&array) = {:?}", average(&array));
+    println!("average(&vec) = {:?}", average(&vec));
+    println!("average(&array[1..3]) = {:?}", average(&array[1..3]));
+    println!("average(&[]) = {:?}", average(&[]));
+
+    // 범위 밖: 인덱스는 panic, get 은 None
+    println!("array.get(9) = {:?}, array.first() = {:?}, vec.last() = {:?}", array.get(9), array.first(), vec.last());
+
+    // 배열 ↔ 슬라이스 ↔ Vec
+    let from_slice: [f64; 2] = array[..2].try_into().expect("길이 2"); // 길이가 맞을 때만
+    let to_vec: Vec<f64> = array.to_vec(); // 복사해서 힙으로
+    let boxed: Box<[f64]> = vec.into_boxed_slice(); // 용량 없이 딱 맞게
+    println!("try_into [f64; 2] = {:?}, to_vec = {:?}, Box<[f64]> = {:?}", from_slice, to_vec, boxed);
+
+    // 배열은 값 - 대입하면 복사 (C 배열처럼 포인터로 바뀌지 않음)
+    let mut copy = array;
+    copy[0] = 99.0;
+    println!("복사본 수정 후 원본 {:?} / 복사본 {:?}", array[0], copy[0]);
+
+    // 배열의 map 과 from_fn - 크기를 유지한 채 변환
+    let squares: [u32; 5] = std::array::from_fn(|i| (i * i) as u32);
+    println!("from_fn: {:?}, map: {:?}", squares, squares.map(|x| x + 1));
+}
+
+// ----------------------------------------------------------------------------
+// chunks 와 windows
+// ----------------------------------------------------------------------------
+
+fn chunks_and_windows() {
+    println!("\n--- chunks 와 windows ---");
+
+    // C++ 에서는:
+    // for (size_t i = 0; i < v.size(); i += 3) { auto end = std::min(i + 3, v.size()); ... }
+    // std::views::chunk(v, 3), std::views::slide(v, 2)   // C++23
+
+    let data = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    // chunks: 겹치지 않게 n 개씩 (마지막은 짧을 수 있음)
+    let groups: Vec<&[i32]> = data.chunks(3).collect();
+    println!("chunks(3): {:?}", groups);
+
+    // chunks_exact: 정확히 n 개씩 + 나머지는 따로 - 경계 검사가 없어 빠른 루프
+    // (n 이 상수면 아래의 as_chunks 가 더 알맞음)
+    let width = data.len() / 2 - 1;
+    let exact = data.chunks_exact(width);
+    println!("chunks_exact({}) 나머지: {:?}", width, exact.remainder());
+
+    // 뒤에서부터: rchunks
+    println!("rchunks(3): {:?}", data.rchunks(3).collect::<Vec<_>>());
+
+    // windows: 겹치며 n 개씩 - 이동 평균, 인접 비교
+    let moving: Vec<f64> = data.windows(3).map(|w| w.iter().sum::<i32>() as f64 / 3.0).collect();
+    println!("windows(3) 이동 평균: {:?}", moving);
+    let sorted = data.windows(2).all(|w| w[0] <= w[1]);
+    println!("정렬되어 있나 (windows(2)): {} = is_sorted() {}", sorted, data.is_sorted());
+
+    // 길이를 타입으로 - as_chunks 는 &[[T; N]] 과 나머지를 돌려줌 (원소마다 경계 검사 없음)
+    let (pairs, rest): (&[[i32; 2]], &[i32]) = data.as_chunks::<2>();
+    println!("as_chunks::<2>(): {:?}, 나머지 {:?}", pairs, rest);
+
+    // 가변 버전 - 블록마다 그 자리에서 고치기
+    let mut pixels = [10u8, 20, 30, 40, 50, 60];
+    for rgb in pixels.as_chunks_mut::<3>().0 {
+        rgb.reverse(); // RGB → BGR
+    }
+    println!("as_chunks_mut 로 RGB → BGR: {:?}", pixels);
+
+    // 값에 따라 나누기 - chunk_by (연속으로 같은 조건인 구간)
+    let runs: Vec<&[i32]> = [1, 1, 2, 3, 3, 3, 1].chunk_by(|a, b| a == b).collect();
+    println!("chunk_by(==): {:?}", runs);
+}
+
+// ----------------------------------------------------------------------------
+// split_at 과 나누기
+// ----------------------------------------------------------------------------
+
+fn splitting() {
+    println!("\n--- split_at 과 나누기 ---");
+
+    // C++ 에서는:
+    // std::span first = s.first(3), rest = s.subspan(3);
+    // 같은 vector 의 두 부분을 동시에 고치기 - 컴파일러는 겹침을 확인하지 않음
+
+    let mut numbers = [1, 2, 3, 4, 5, 6];
+    let (left, right) = numbers.split_at(2);
+    println!("split_at(2): {:?} | {:?}", left, right);
+
+    // 겹치지 않는 두 가변 슬라이스 - &mut numbers[..3] 와 &mut numbers[3..] 를 동시에 빌리면 에러
+    let (front, back) = numbers.split_at_mut(3);
+    front[0] += back[0] * 100;
+    back.swap(0, 2);
+    println!("split_at_mut 로 양쪽을 고친 뒤: {:?}", numbers);
+
+    // 범위를 벗어나면 panic - split_at_checked 는 None
+    println!("split_at_checked(10) = {:?}", numbers.split_at_checked(10));
+
+    // 처음/끝 하나와 나머지
+    if let Some((head, tail)) = numbers.split_first() {
+        println!("split_first: {} | {:?}", head, tail);
+    }
+
+    // 값으로 나누기 - split (구분 원소는 빠짐), splitn
+    let line = [3, 0, 4, 5, 0, 0, 6];
+    let parts: Vec<&[i32]> = line.split(|&x| x == 0).collect();
+    println!("split(== 0): {:?}", parts);
+    println!("splitn(2, == 0): {:?}", line.splitn(2, |&x| x == 0).collect::<Vec<_>>());
+
+    // 앞뒤 잘라 내기 - strip_prefix
+    let packet = [0xCA, 0xFE, 1, 2, 3];
+    match packet.strip_prefix(&[0xCA, 0xFE]) {
+        Some(payload) => println!("헤더를 뗀 내용: {:?}", payload),
+        None => println!("헤더 없음"),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 정렬
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+struct Player {
+    name: &'static str,
+    score: u32,
+    level: u8,
+}
+
+fn sorting() {
+    println!("\n--- 정렬 ---");
+
+    // C++ 에서는:
+    // std::stable_sort(v.begin(), v.end(), cmp)        → v.sort_by(cmp)
+    // std::sort(v.begin(), v.end(), cmp)               → v.sort_unstable_by(cmp)
+    // std::ranges::sort(v, {}, &Player::score)         → v.sort_unstable_by_key(|p| p.score)
+    // std::partial_sort(...)                           → select_nth_unstable 후 정렬 (아래 절)
+
+    let players = vec![
+        Player { name: "ann", score: 90, level: 3 },
+        Player { name: "bob", score: 75, level: 5 },
+        Player { name: "cy", score: 90, level: 1 },
+        Player { name: "dee", score: 60, level: 5 },
+    ];
+
+    // sort: 안정 정렬 (같은 값의 원래 순서 유지), 추가 메모리 사용
+    // sort_unstable: 불안정, 제자리 - 보통 더 빠름 (std::sort 처럼)
+    let mut by_score = players.clone();
+    by_score.sort_unstable_by_key(|p| Reverse(p.score));
+    println!("점수 내림차순 (unstable): {:?}", by_score.iter().map(|p| p.name).collect::<Vec<_>>());
+
+    // 여러 키 - 튜플을 키로 (레벨 내림차순, 같으면 이름 오름차순)
+    let mut by_level = players.clone();
+    by_level.sort_unstable_by_key(|p| (Reverse(p.level), p.name));
+    println!("레벨 내림차순, 이름순: {:?}", by_level.iter().map(|p| (p.level, p.name)).collect::<Vec<_>>());
+
+    // 안정 정렬을 연달아 - 이전 정렬 순서가 동점에서 유지됨
+    let mut stable = players.clone();
+    stable.sort_by_key(|p| p.name);
+    stable.sort_by_key(|p| Reverse(p.score));
+    println!("stable (점수, 동점은 이름순): {:?}", stable.iter().map(|p| p.name).collect::<Vec<_>>());
+
+    // 키를 만드는 비용이 크면 sort_by_cached_key - 원소마다 한 번만 계산
+    let mut words = vec!["Banana", "apple", "Cherry"];
+    words.sort_by_cached_key(|w| w.to_lowercase());
+    println!("sort_by_cached_key(to_lowercase): {:?}", words);
+
+    // 중복 제거 - 정렬 후 dedup (std::unique + erase)
+    let mut tags = vec!["rust", "c++", "rust", "go", "c++"];
+    tags.sort_unstable();
+    tags.dedup();
+    println!("sort + dedup: {:?}", tags);
+}
+
+// ----------------------------------------------------------------------------
+// binary_search
+// ----------------------------------------------------------------------------
+
+fn binary_search() {
+    println!("\n--- binary_search ---");
+
+    // C++ 에서는:
+    // std::binary_search(...)           // bool 만
+    // std::lower_bound(...)             // 위치 - 찾았는지는 다시 비교
+    // → Rust 는 Result<찾은 위치, 넣을 위치> 하나로
+
+    let sorted = [2, 3, 5, 7, 11, 13, 17];
+    println!("binary_search(&7) = {:?}", sorted.binary_search(&7));
+    println!("binary_search(&8) = {:?} (Err = 정렬을 유지하며 넣을 위치)", sorted.binary_search(&8));
+
+    // 정렬을 유지하며 삽입 - Ok/Err 어느 쪽이든 위치
+    let mut v = sorted.to_vec();
+    let at = v.binary_search(&8).unwrap_or_else(|i| i);
+    v.insert(at, 8);
+    println!("8 삽입 후: {:?}", v);
+
+    // 키로 찾기 - 구조체의 정렬 기준 필드
+    let events = [(10, "boot"), (25, "login"), (40, "logout")];
+    match events.binary_search_by_key(&25, |&(t, _)| t) {
+        Ok(i) => println!("시각 25 의 이벤트: {}", events[i].1),
+        Err(_) => println!("시각 25 의 이벤트 없음"),
+    }
+
+    // lower_bound / upper_bound - partition_point (조건이 참인 앞부분의 길이)
+    let scores = [50, 60, 60, 60, 80, 95];
+    let lower = scores.partition_point(|&s| s < 60);
+    let upper = scores.partition_point(|&s| s <= 60);
+    println!("60 의 범위: [{}, {}) → {:?}", lower, upper, &scores[lower..upper]);
+
+    // 정렬되지 않은 슬라이스에서의 결과는 의미 없음 (panic 은 아님) - 미리 정렬
+    debug_assert!(scores.is_sorted());
+    println!("is_sorted() = {} (debug_assert! 로 전제를 적어 두기)", scores.is_sorted());
+}
+
+// ----------------------------------------------------------------------------
+// rotate 와 select_nth_unstable
+// ----------------------------------------------------------------------------
+
+fn rotate_and_select() {
+    println!("\n--- rotate 와 select_nth_unstable ---");
+
+    // C++ 에서는:
+    // std::rotate(v.begin(), v.begin() + 2, v.end())   → v.rotate_left(2)
+    // std::nth_element(v.begin(), v.begin() + k, v.end()) → v.select_nth_unstable(k)
+    // std::reverse, std::fill, std::swap_ranges         → reverse, fill, swap_with_slice
+
+    let mut ring = [1, 2, 3, 4, 5];
+    ring.rotate_left(2);
+    println!("rotate_left(2): {:?}", ring);
+    ring.rotate_right(2);
+    println!("rotate_right(2) 로 되돌림: {:?}", ring);
+
+    // 원소 하나를 앞으로 옮기기 - 부분 범위를 회전 (최근 사용 목록)
+    let mut recent = ["a", "b", "c", "d"];
+    recent[..3].rotate_right(1); // "c" 를 맨 앞으로
+    println!("c 를 맨 앞으로: {:?}", recent);
+
+    // select_nth_unstable: k 번째 원소를 제자리에, 앞은 작거나 같고 뒤는 크거나 같음 - 평균 O(n)
+    let mut latencies = [120, 35, 80, 300, 15, 95, 60, 250, 40];
+    let middle = latencies.len() / 2;
+    let (_, median, _) = latencies.select_nth_unstable(middle);
+    println!("중앙값 (정렬 없이): {}", median);
+
+    // 상위 k 개 - 내림차순으로 k-1 번째를 고르면 앞쪽이 상위 k 개 (순서는 보장 안 함)
+    let k = 3;
+    let (top, _, _) = latencies.select_nth_unstable_by_key(k - 1, |&x| Reverse(x));
+    let mut top = top.to_vec();
+    top.push(latencies[k - 1]);
+    top.sort_unstable_by_key(|&x| Reverse(x)); // partial_sort 처럼 앞 k 개만 정렬
+    println!("가장 느린 {}개: {:?}", k, top);
+
+    // 기타 제자리 알고리즘
+    let mut buffer = [0u8; 6];
+    buffer[..3].fill(7);
+    buffer[3..].copy_from_slice(&[1, 2, 3]);
+    buffer.reverse();
+    println!("fill, copy_from_slice, reverse: {:?}", buffer);
+    let (a, b) = buffer.split_at_mut(3);
+    a.swap_with_slice(b);
+    println!("swap_with_slice: {:?}", buffer);
+    println!("contains(&7) = {}, iter().position(== 2) = {:?}", buffer.contains(&7), buffer.iter().position(|&x| x == 2));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn average_takes_any_slice() {
+        assert_eq!(average(&[1.0, 2.0, 3.0]), Some(2.0));
+        let vec = vec![4.0];
+        assert_eq!(average(&vec), Some(4.0));
+        assert_eq!(average(&[]), None);
+    }
+}
@@ -0,0 +1,423 @@
+// ============================================================================
+// 26. 정적 디스패치 vs 동적 디스패치 벤치마크
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. 제네릭 = 템플릿 - 타입마다 코드를 찍어 냄(단형화), 호출은 인라인 가능
+// 2. Box<dyn Trait> = 가상 함수 + unique_ptr - vtable 을 거친 간접 호출, 원소마다 힙 할당
+//    다만 vtable 포인터가 객체 안이 아니라 팻 포인터(데이터 + vtable)에 있음
+// 3. enum + match = std::variant + std::visit - 닫힌 타입 집합, 힙 없이 한 배열에
+// 4. 7장의 "정적 디스패치는 비용이 없다" 를 같은 일을 세 가지로 구현해 직접 재 봄
+//    (측정기: src/microbench.rs - 디버그 빌드의 수치는 참고만, cargo run --release -- 26)
+// ============================================================================
+
+use std::f64::consts::PI;
+use std::hint::black_box;
+
+use crate::microbench;
+
+// 절 목록 (실행 순서) - cargo run -- 26:three_implementations 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("three_implementations", three_implementations),
+    ("measuring", measuring),
+    ("why_the_difference", why_the_difference),
+    ("reading_criterion", reading_criterion),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "26"
+    }
+
+    fn name(&self) -> &'static str {
+        "정적 디스패치 vs 동적 디스패치 벤치마크"
+    }
+
+    fn description(&self) -> &'static str {
+        "같은 일을 제네릭, Box<dyn Trait>, enum 으로 구현하고 장 안의 측정기로 재서 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["단형화", "dyn Trait", "vtable", "enum 디스패치", "벤치마크", "black_box"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 같은 일을 세 가지로
+// ----------------------------------------------------------------------------
+
+// 일: 도형 목록의 넓이 합계 - 도형마다 계산이 작아서 호출 비용이 잘 드러남
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Circle {
+    radius: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rect {
+    w: f64,
+    h: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Triangle {
+    base: f64,
+    height: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+}
+
+impl Shape for Rect {
+    fn area(&self) -> f64 {
+        self.w * self.h
+    }
+}
+
+impl Shape for Triangle {
+    fn area(&self) -> f64 {
+        0.5 * self.base * self.height
+    }
+}
+
+// 1. 제네릭 - 타입마다 total_generic::<Circle>, ::<Rect>, ::<Triangle> 이 따로 생김
+//    한 Vec 에는 한 타입만 → 타입별 Vec 로 나눠 둠 (C++ 의 template + 타입별 vector)
+fn total_generic<S: Shape>(shapes: &[S]) -> f64 {
+    shapes.iter().map(Shape::area).sum()
+}
+
+// 2. 트레이트 객체 - 한 Vec 에 섞어 두고, 호출마다 vtable 에서 area 를 찾음
+fn total_dyn(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|s| s.area()).sum()
+}
+
+// 3. enum - 한 Vec 에 섞어 두지만 타입 집합이 닫혀 있음, match 가 분기
+#[derive(Debug, Clone, Copy)]
+enum AnyShape {
+    Circle(Circle),
+    Rect(Rect),
+    Triangle(Triangle),
+}
+
+impl AnyShape {
+    fn area(&self) -> f64 {
+        match self {
+            AnyShape::Circle(c) => c.area(),
+            AnyShape::Rect(r) => r.area(),
+            AnyShape::Triangle(t) => t.area(),
+        }
+    }
+}
+
+fn total_enum(shapes: &[AnyShape]) -> f64 {
+    shapes.iter().map(AnyShape::area).sum()
+}
+
+// 세 방식이 쓰는 같은 도형들 - 순서와 크기가 항상 같아서 실행마다 같은 일
+struct Workload {
+    circles: Vec<Circle>,
+    rects: Vec<Rect>,
+    triangles: Vec<Triangle>,
+    boxed: Vec<Box<dyn Shape>>,
+    enums: Vec<AnyShape>,
+}
+
+impl Workload {
+    fn new(count: usize) -> Workload {
+        let mut w = Workload {
+            circles: Vec::new(),
+            rects: Vec::new(),
+            triangles: Vec::new(),
+            boxed: Vec::new(),
+            enums: Vec::new(),
+        };
+        for i in 0..count {
+            let size = (i % 10) as f64 + 1.0;
+            // 세 종류를 섞어서 - dyn 과 enum 은 호출마다 다른 구현으로 감
+            match i % 3 {
+                0 => {
+                    let c = Circle { radius: size };
+                    w.circles.push(c);
+                    w.boxed.push(Box::new(c));
+                    w.enums.push(AnyShape::Circle(c));
+                }
+                1 => {
+                    let r = Rect { w: size, h: size * 2.0 };
+                    w.rects.push(r);
+                    w.boxed.push(Box::new(r));
+                    w.enums.push(AnyShape::Rect(r));
+                }
+                _ => {
+                    let t = Triangle { base: size, height: size };
+                    w.triangles.push(t);
+                    w.boxed.push(Box::new(t));
+                    w.enums.push(AnyShape::Triangle(t));
+                }
+            }
+        }
+        w
+    }
+
+    fn generic(&self) -> f64 {
+        total_generic(&self.circles) + total_generic(&self.rects) + total_generic(&self.triangles)
+    }
+
+    fn dynamic(&self) -> f64 {
+        total_dyn(&self.boxed)
+    }
+
+    fn enumerated(&self) -> f64 {
+        total_enum(&self.enums)
+    }
+}
+
+// 도형 수 - 캐시(L1/L2)에 들어가는 크기, 디버그 빌드의 테스트도 빨리 끝나도록
+const SHAPES: usize = 3_000;
+// 측정 한 번에 합계를 구하는 횟수
+const ITERATIONS: u32 = 20;
+
+fn three_implementations() {
+    println!("--- 같은 일을 세 가지로 ---");
+
+    // C++ 에서는:
+    // template <class S> double total(const std::vector<S>&);        // 1. 템플릿
+    // double total(const std::vector<std::unique_ptr<Shape>>&);      // 2. 가상 함수
+    // double total(const std::vector<std::variant<Circle, Rect, Triangle>>&);  // 3. variant + visit
+
+    let w = Workload::new(SHAPES);
+    println!(
+        "도형 {}개: 원 {}, 사각형 {}, 삼각형 {}",
+        SHAPES,
+        w.circles.len(),
+        w.rects.len(),
+        w.triangles.len()
+    );
+
+    // 먼저 세 방식이 같은 답을 내는지 - 빠르기만 하고 틀리면 의미 없음
+    // (더하는 순서가 달라서 마지막 자리가 다를 수 있음 → 오차 안에서 비교)
+    let (g, d, e) = (w.generic(), w.dynamic(), w.enumerated());
+    println!("합계: 제네릭 {:.3}, dyn {:.3}, enum {:.3}", g, d, e);
+    println!("같은 답? {}", (g - d).abs() < 1e-6 * g && (d - e).abs() < 1e-6 * d);
+
+    // 메모리 모양도 다름
+    println!(
+        "원소 크기: Circle {}바이트, Box<dyn Shape> {}바이트 (팻 포인터 - 실제 값은 힙), AnyShape {}바이트 (가장 큰 변형 + 태그)",
+        size_of::<Circle>(),
+        size_of::<Box<dyn Shape>>(),
+        size_of::<AnyShape>()
+    );
+}
+
+// ----------------------------------------------------------------------------
+// 재 보기
+// ----------------------------------------------------------------------------
+
+fn measuring() {
+    println!("\n--- 재 보기 ---");
+
+    let w = Workload::new(SHAPES);
+    println!("{}", microbench::build_profile());
+    println!(
+        "도형 {}개의 합계를 {}번씩, 예열 {}번 후 {}번 재서 중앙값 (합계 한 번당 시간)",
+        SHAPES,
+        ITERATIONS,
+        microbench::WARMUP,
+        microbench::SAMPLES
+    );
+
+    // black_box: 컴파일러가 입력을 상수로 보고 결과를 미리 계산하지 못하게
+    let results = [
+        microbench::measure("제네릭 (단형화)", ITERATIONS, || black_box(&w).generic()),
+        microbench::measure("Box<dyn Shape>", ITERATIONS, || black_box(&w).dynamic()),
+        microbench::measure("enum + match", ITERATIONS, || black_box(&w).enumerated()),
+    ];
+    println!("{}", microbench::table(&results));
+
+    // 도형 하나당
+    for m in &results {
+        println!("  {}: 도형 하나당 {:.2} ns", m.name, m.per_call_ns() / SHAPES as f64);
+    }
+
+    // 흔히 보는 릴리스 빌드 결과 (기계마다 다름):
+    //   제네릭 < enum < dyn - dyn 은 인라인이 안 되고 원소마다 포인터를 따라감
+    //   제네릭은 타입별로 나눈 덕에 분기도 없음 - SIMD 로 벡터화되기도 함
+    //   enum 은 인라인되지만 원소마다 match 분기
+    // 디버그 빌드에서는 인라인이 꺼져서 셋 다 비슷하게 느림 - "비용이 없다" 는 최적화 후의 이야기
+}
+
+// ----------------------------------------------------------------------------
+// 차이가 나는 이유
+// ----------------------------------------------------------------------------
+
+fn why_the_difference() {
+    println!("\n--- 차이가 나는 이유 ---");
+
+    // 1. 인라인: 제네릭과 enum 은 area 의 몸체가 보이므로 루프 안에 펼쳐짐
+    //    dyn 은 어떤 area 인지 실행 중에야 알 수 있어서 call 명령 (vtable → 함수 포인터)
+    // 2. 간접 참조: Box<dyn Shape> 의 Vec 은 포인터 배열 - 원소마다 힙의 다른 곳을 읽음 (캐시 미스)
+    //    Vec<Circle>, Vec<AnyShape> 는 값이 연속으로 놓임
+    // 3. 분기: enum 은 match 의 분기, dyn 은 간접 분기 - 순서가 규칙적이면 둘 다 예측이 잘 됨
+    //    무작위로 섞으면 차이가 커짐 (연습: Workload::new 에서 i % 3 대신 난수로)
+    // 4. 코드 크기: 제네릭은 타입마다 사본 - 타입이 많고 함수가 크면 바이너리가 커짐
+
+    let table = [
+        ("제네릭 (단형화)", "컴파일 시점", "타입마다 Vec", "열림 (새 타입 추가 자유)", "코드 크기 증가"),
+        ("Box<dyn Trait>", "실행 중 (vtable)", "섞어 둘 수 있음", "열림", "힙 할당, 인라인 불가"),
+        ("enum + match", "실행 중 (match)", "섞어 둘 수 있음", "닫힘 (변형 추가 = match 수정)", "가장 큰 변형 크기"),
+    ];
+    println!("{:16} | {:16} | {:16} | {:30} | 비용", "방식", "고르는 시점", "한 목록에", "확장");
+    for (name, when, mixed, open, cost) in table {
+        println!("{:16} | {:16} | {:16} | {:30} | {}", name, when, mixed, open, cost);
+    }
+
+    // 고르는 기준
+    //   타입 집합이 닫혀 있고 내가 정함 → enum (AST, 이벤트, 명령)
+    //   사용자/플러그인이 타입을 추가 → dyn Trait (핫 루프 밖이면 비용은 거의 안 보임)
+    //   핫 루프 + 한 타입씩 처리 가능 → 제네릭
+    // 측정 없이 고르지 않기 - 이 장의 measuring 절처럼 실제 크기, 실제 빌드로 재 보기
+    println!("정밀한 측정: criterion 벤치마크 → cargo run -- bench-report");
+}
+
+// ----------------------------------------------------------------------------
+// criterion 결과 읽기
+// ----------------------------------------------------------------------------
+
+// 레슨의 성능 주장마다 benches/ 에 criterion 벤치마크가 있음
+//   benches/iterators.rs  11장 "이터레이터는 수동 루프와 같은 성능"
+//   benches/hashing.rs    10장 "SipHash 는 느림, 빠른 해셔는 fnv"
+//   benches/rc_clone.rs   12장 "Rc::clone 은 카운트만 증가"
+// cargo bench --bench iterators → target/criterion/ 에 결과 → cargo run -- bench-report 로 표
+
+// cargo bench 가 벤치마크마다 출력하는 모양 (한 기계에서 잰 예시)
+const SAMPLE_OUTPUT: &str = "\
+11_iterator_adaptors/iterator_chain/100000
+                        time:   [95.924 µs 98.351 µs 102.41 µs]
+                        change: [-1.8211% +0.6125% +3.1893%] (p = 0.64 > 0.05)
+                        No change in performance detected.
+Found 6 outliers among 100 measurements (6.00%)
+  4 (4.00%) high mild
+  2 (2.00%) high severe";
+
+// [낮은 값, 추정값, 높은 값] - 평균이 95% 확률로 이 구간 안 (신뢰 구간)
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Interval {
+    low: f64,
+    estimate: f64,
+    high: f64,
+}
+
+// "time:   [95.924 µs 98.351 µs 102.41 µs]" → 나노초 구간
+fn parse_time(line: &str) -> Option<Interval> {
+    let inside = line.split_once('[')?.1.split_once(']')?.0;
+    let parts: Vec<&str> = inside.split_whitespace().collect();
+    let ns = |value: &str, unit: &str| {
+        let scale = match unit {
+            "ps" => 1e-3,
+            "ns" => 1.0,
+            "µs" | "us" => 1e3,
+            "ms" => 1e6,
+            "s" => 1e9,
+            _ => return None,
+        };
+        value.parse::<f64>().ok().map(|v| v * scale)
+    };
+    match parts.as_slice() {
+        [a, ua, b, ub, c, uc] => Some(Interval { low: ns(a, ua)?, estimate: ns(b, ub)?, high: ns(c, uc)? }),
+        _ => None,
+    }
+}
+
+// 두 구간이 겹치면 이 측정으로는 구분할 수 없음 - 추정값만 비교하면 잡음을 차이로 착각
+fn compare(name_a: &str, a: Interval, name_b: &str, b: Interval) -> String {
+    if a.high >= b.low && b.high >= a.low {
+        format!("{} 와 {}: 구간이 겹침 - 차이를 말할 수 없음", name_a, name_b)
+    } else if a.estimate < b.estimate {
+        format!("{} 가 약 {:.1}배 빠름", name_a, b.estimate / a.estimate)
+    } else {
+        format!("{} 가 약 {:.1}배 빠름", name_b, a.estimate / b.estimate)
+    }
+}
+
+fn reading_criterion() {
+    println!("\n--- criterion 결과 읽기 ---");
+
+    // C++ 에서는: Google Benchmark - BENCHMARK(BM_loop)->Range(1000, 100000);
+    // 출력은 평균과 CPU 시간 한 줄 - 반복 실행(--benchmark_repetitions)과 통계는 따로 켬
+    // criterion 은 기본이 통계: 예열 → 100번 표본 → 부트스트랩으로 신뢰 구간, 직전 실행과 비교
+
+    for line in SAMPLE_OUTPUT.lines() {
+        println!("  | {}", line);
+    }
+
+    // 한 줄씩 읽기
+    //   time:    [낮은 값 추정값 높은 값] - 반복 한 번의 평균 시간, 95% 신뢰 구간
+    //   change:  직전 실행(또는 --baseline) 대비 변화의 신뢰 구간
+    //            p < 0.05 이고 구간이 잡음 문턱(기본 2%)을 벗어나야 "improved/regressed"
+    //   outliers: 튄 표본 - 다른 프로그램, CPU 클럭 변화, 인터럽트 (high severe 가 많으면 환경을 의심)
+    if let Some(time) = SAMPLE_OUTPUT.lines().find_map(parse_time) {
+        println!(
+            "구간 폭: {:.1}% (좁을수록 안정된 측정)",
+            (time.high - time.low) / time.estimate * 100.0
+        );
+    }
+
+    // 두 벤치마크를 비교할 때는 추정값이 아니라 구간으로
+    let samples = [
+        ("for_loop", "time:   [84.031 µs 84.951 µs 86.027 µs]"),
+        ("iterator_chain", "time:   [95.924 µs 98.351 µs 102.41 µs]"),
+        ("index_loop", "time:   [96.067 µs 97.494 µs 99.074 µs]"),
+        ("rc_clone", "time:   [1.5907 ns 1.6424 ns 1.6927 ns]"),
+        ("arc_clone", "time:   [19.081 ns 19.497 ns 19.932 ns]"),
+    ];
+    let get = |name: &str| samples.iter().find(|(n, _)| *n == name).and_then(|(_, line)| parse_time(line));
+    for (a, b) in [("iterator_chain", "index_loop"), ("for_loop", "iterator_chain"), ("rc_clone", "arc_clone")] {
+        if let (Some(x), Some(y)) = (get(a), get(b)) {
+            println!("{}", compare(a, x, b, y));
+        }
+    }
+
+    // 숫자를 믿기 전에
+    //   1. 릴리스 빌드인가 - cargo bench 는 항상 bench 프로필(최적화)
+    //   2. 같은 답을 내는가 - benches/iterators.rs 는 재기 전에 assert_eq!
+    //   3. 최적화기가 일을 지우지 않았나 - 입력과 결과에 black_box (1ns 아래면 의심)
+    //   4. 한 번 더 재도 같은가 - 노트북의 전원 설정, 다른 프로그램 (--save-baseline 으로 기준선을 남겨 비교)
+    // "iterator_chain 이 for_loop 보다 느림" 처럼 나와도 결론 전에 cargo asm 이나 godbolt 로 기계어를 보기
+    println!("직접 재기: cargo bench --bench iterators → cargo run -- bench-report");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn criterion_intervals_are_parsed_in_nanoseconds() {
+        let t = parse_time("time:   [95.924 µs 98.351 µs 102.41 µs]").unwrap();
+        assert!((t.estimate - 98_351.0).abs() < 1e-6);
+        assert_eq!(parse_time("time: [1 ns 2 ns]"), None);
+        let fast = Interval { low: 1.0, estimate: 2.0, high: 3.0 };
+        let slow = Interval { low: 10.0, estimate: 20.0, high: 30.0 };
+        assert!(compare("a", fast, "b", slow).contains("10.0배"));
+        assert!(compare("a", fast, "b", fast).contains("겹침"));
+    }
+
+    #[test]
+    fn three_ways_agree() {
+        let w = Workload::new(300);
+        assert_eq!(w.boxed.len(), 300);
+        assert_eq!(w.enums.len(), 300);
+        assert!((w.generic() - w.dynamic()).abs() < 1e-6);
+        assert!((w.dynamic() - w.enumerated()).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,399 @@
+// ============================================================================
+// 27. 고급 트레이트
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. 연관 상수 = static constexpr 멤버 - 다만 트레이트가 "있어야 한다" 고 요구하고 컴파일러가 확인
+//    C++ 에서는 concept 의 requires { T::value; } 로 흉내
+// 2. 기본 타입 매개변수 (trait Add<Rhs = Self>) = template <class Rhs = T>
+// 3. 포괄 구현 (impl<T: Display> Trait for T) - 조건을 만족하는 모든 타입에 한 번에
+//    C++ 의 CRTP 믹스인은 타입마다 상속을 적어야 하고, 제약 위반은 인스턴스화 때에야 드러남
+// 4. 봉인된 트레이트 - 밖에서 쓸 수는 있지만 구현은 못 하게 (C++ 에는 직접 대응 없음)
+// 5. GAT (type Item<'a>) - 연관 타입에 매개변수 (C++ 의 template <class U> using rebind = ...)
+// ============================================================================
+
+use std::fmt::Display;
+use std::ops::Add;
+
+// 절 목록 (실행 순서) - cargo run -- 27:associated_consts 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("associated_consts", associated_consts),
+    ("default_type_params", default_type_params),
+    ("blanket_impls", blanket_impls),
+    ("sealed_traits", sealed_traits),
+    ("generic_associated_types", generic_associated_types),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "27"
+    }
+
+    fn name(&self) -> &'static str {
+        "고급 트레이트"
+    }
+
+    fn description(&self) -> &'static str {
+        "연관 상수, 기본 타입 매개변수, 포괄 구현, 봉인된 트레이트, GAT - CRTP, concept 과 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["연관 상수", "기본 타입 매개변수", "포괄 구현", "봉인된 트레이트", "GAT", "CRTP"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 연관 상수
+// ----------------------------------------------------------------------------
+
+// 타입마다 정해진 값 - 인스턴스 없이 T::BITS 처럼 읽음
+trait Codec {
+    const NAME: &'static str;
+    const BLOCK: usize;
+    // 기본값도 가능 - 구현이 덮어쓸 수 있음
+    const LOSSY: bool = false;
+}
+
+struct Base64;
+struct Hex;
+struct Jpeg;
+
+impl Codec for Base64 {
+    const NAME: &'static str = "base64";
+    const BLOCK: usize = 3;
+}
+
+impl Codec for Hex {
+    const NAME: &'static str = "hex";
+    const BLOCK: usize = 1;
+}
+
+impl Codec for Jpeg {
+    const NAME: &'static str = "jpeg";
+    const BLOCK: usize = 64;
+    const LOSSY: bool = true;
+}
+
+// 입력을 블록 단위로 채우면 몇 바이트가 되는지 - 타입만 보고 계산
+fn padded_len<C: Codec>(len: usize) -> usize {
+    len.div_ceil(C::BLOCK) * C::BLOCK
+}
+
+fn associated_consts() {
+    println!("--- 연관 상수 ---");
+
+    // C++ 에서는:
+    // struct Base64 { static constexpr std::string_view name = "base64"; static constexpr size_t block = 3; };
+    // template <class C> requires requires { C::block; }   // 있는지는 concept 으로 따로 확인
+    // size_t padded_len(size_t len) { return (len + C::block - 1) / C::block * C::block; }
+
+    fn describe<C: Codec>() {
+        println!(
+            "{:8} 블록 {:2}바이트, 손실 압축? {:5}, 10바이트 → {}바이트",
+            C::NAME,
+            C::BLOCK,
+            C::LOSSY,
+            padded_len::<C>(10)
+        );
+    }
+    describe::<Base64>();
+    describe::<Hex>();
+    describe::<Jpeg>();
+
+    // 표준 라이브러리의 연관 상수
+    println!("u8::MAX = {}, i32::BITS = {}, f64::EPSILON = {:e}", u8::MAX, i32::BITS, f64::EPSILON);
+
+    // 제한: 제네릭 안에서 배열 길이로 쓰기 ([u8; C::BLOCK]) 는 아직 안정화되지 않음
+    //   → const 제네릭 매개변수로 받거나 (8장 const_generics), Vec 을 씀
+    // 연관 상수가 있는 트레이트는 dyn 으로 쓸 수 없음 - 값이 타입에 묶여 있어서 vtable 에 못 넣음
+}
+
+// ----------------------------------------------------------------------------
+// 기본 타입 매개변수
+// ----------------------------------------------------------------------------
+
+// 표준 라이브러리: trait Add<Rhs = Self> { type Output; fn add(self, rhs: Rhs) -> Self::Output; }
+// Rhs 를 적지 않으면 Self - 대부분은 같은 타입끼리 더하므로
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Millimeters(u32);
+
+// Add 만 적음 = Add<Meters>
+impl Add for Meters {
+    type Output = Meters;
+    fn add(self, rhs: Meters) -> Meters {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+// 다른 타입과 더하기 - 기본값 대신 Rhs 를 적음
+impl Add<Millimeters> for Meters {
+    type Output = Meters;
+    fn add(self, rhs: Millimeters) -> Meters {
+        Meters(self.0 + rhs.0 as f64 / 1000.0)
+    }
+}
+
+// 직접 만드는 트레이트에도 - 기존 구현을 깨지 않고 매개변수를 추가할 때 유용
+trait Scale<Factor = f64> {
+    fn scale(&self, by: Factor) -> Self;
+}
+
+impl Scale for Meters {
+    fn scale(&self, by: f64) -> Meters {
+        Meters(self.0 * by)
+    }
+}
+
+impl Scale<u32> for Millimeters {
+    fn scale(&self, by: u32) -> Millimeters {
+        Millimeters(self.0 * by)
+    }
+}
+
+fn default_type_params() {
+    println!("\n--- 기본 타입 매개변수 ---");
+
+    // C++ 에서는:
+    // template <class Rhs = Meters> Meters operator+(Meters lhs, Rhs rhs);
+    // 또는 오버로드 두 개 - Rust 는 오버로드가 없으므로 트레이트의 타입 매개변수로 구분
+
+    let a = Meters(1.5) + Meters(2.0);
+    let b = Meters(1.5) + Millimeters(250);
+    println!("Meters + Meters = {:?}", a);
+    println!("Meters + Millimeters = {:?}", b);
+    println!("scale: {:?}, {:?}", Meters(2.0).scale(1.5), Millimeters(40).scale(3));
+
+    // 반대 방향(Millimeters + Meters)은 따로 구현하지 않으면 컴파일 에러
+    //   C++ 처럼 암시적 변환으로 맞춰 주지 않음
+}
+
+// ----------------------------------------------------------------------------
+// 포괄 구현 (blanket impl)
+// ----------------------------------------------------------------------------
+
+// Display 를 구현한 모든 타입에 한 번에
+trait Shout {
+    fn shout(&self) -> String;
+}
+
+impl<T: Display + ?Sized> Shout for T {
+    fn shout(&self) -> String {
+        format!("{}!", self.to_string().to_uppercase())
+    }
+}
+
+// 확장 트레이트 - 남의 타입(표준 라이브러리의 반복자)에 메서드를 더하는 흔한 방법
+trait SumBy: Iterator + Sized {
+    fn sum_by<F: FnMut(&Self::Item) -> i64>(self, mut f: F) -> i64 {
+        self.map(|x| f(&x)).sum()
+    }
+}
+
+impl<I: Iterator> SumBy for I {}
+
+fn blanket_impls() {
+    println!("\n--- 포괄 구현 ---");
+
+    // C++ 에서는 (CRTP 믹스인):
+    // template <class Derived> struct Shout {
+    //     std::string shout() const { return upper(static_cast<const Derived&>(*this).to_string()) + "!"; }
+    // };
+    // struct Name : Shout<Name> { ... };   // 타입마다 상속을 적어야 하고, int 같은 기본 타입에는 못 붙임
+
+    // i32, &str, String 모두 Display 라서 바로 사용
+    println!("{} / {} / {}", "hello".shout(), 42.shout(), String::from("rust").shout());
+
+    let words = ["a", "bb", "ccc"];
+    println!("길이 합: {}", words.iter().sum_by(|w| w.len() as i64));
+
+    // 표준 라이브러리의 포괄 구현
+    //   impl<T: Display + ?Sized> ToString for T   → Display 만 구현하면 to_string() 이 생김
+    //   impl<T, U: From<T>> Into<U> for T          → From 만 구현하면 into() 도 생김
+    //   impl<I: Iterator> IntoIterator for I
+
+    // 일관성(coherence): 한 타입에 같은 트레이트 구현은 하나뿐
+    //   포괄 구현이 있으면 그와 겹치는 구현은 추가 불가 (특수화는 불안정)
+    //   고아 규칙: 트레이트나 타입 중 하나는 내 크레이트 것이어야 함
+}
+
+// ----------------------------------------------------------------------------
+// 봉인된 트레이트 (sealed trait)
+// ----------------------------------------------------------------------------
+
+// 라이브러리라고 생각하고 - 밖에서는 Unit 을 쓸 수 있지만 구현은 이 모듈의 타입만
+mod units {
+    // 비공개 모듈의 공개 트레이트 - 밖에서는 이름을 부를 수 없음
+    mod private {
+        pub trait Sealed {}
+    }
+
+    pub trait Unit: private::Sealed {
+        const SYMBOL: &'static str;
+        const PER_BASE: f64;
+
+        fn to_base(value: f64) -> f64 {
+            value * Self::PER_BASE
+        }
+    }
+
+    pub struct Gram;
+    pub struct Kilogram;
+
+    impl private::Sealed for Gram {}
+    impl private::Sealed for Kilogram {}
+
+    impl Unit for Gram {
+        const SYMBOL: &'static str = "g";
+        const PER_BASE: f64 = 1.0;
+    }
+
+    impl Unit for Kilogram {
+        const SYMBOL: &'static str = "kg";
+        const PER_BASE: f64 = 1000.0;
+    }
+}
+
+use units::Unit;
+
+fn in_grams<U: Unit>(value: f64) -> String {
+    format!("{}{} = {}g", value, U::SYMBOL, U::to_base(value))
+}
+
+fn sealed_traits() {
+    println!("\n--- 봉인된 트레이트 ---");
+
+    // C++ 에는 직접 대응 없음
+    //   비슷한 효과: 생성자를 private 으로 두고 friend 로 허용한 타입만 상속 가능하게
+    //   또는 concept 에 "허용 목록" 을 적음 - 둘 다 우회가 쉬움
+
+    println!("{}", in_grams::<units::Gram>(250.0));
+    println!("{}", in_grams::<units::Kilogram>(1.5));
+
+    // 밖에서 구현하려고 하면:
+    //   struct Pound;
+    //   impl units::Unit for Pound { ... }
+    //   → error: the trait bound `Pound: Sealed` is not satisfied (Sealed 는 이름조차 부를 수 없음)
+
+    // 왜 봉인하는가:
+    //   1. 나중에 트레이트에 메서드를 추가해도 사용자 코드가 깨지지 않음 (구현한 사람이 나뿐이므로)
+    //   2. 구현 목록이 닫혀 있다는 것을 믿고 최적화하거나 unsafe 코드를 쓸 수 있음
+    // 표준 라이브러리 예: std::slice::SliceIndex, 여러 크레이트의 private::Sealed
+}
+
+// ----------------------------------------------------------------------------
+// GAT (generic associated types)
+// ----------------------------------------------------------------------------
+
+// 빌려주는 반복자 - 다음 항목이 반복자 자신을 빌림
+//   Iterator 의 type Item 은 수명을 받을 수 없어서 이런 반복자를 표현 못 함
+trait LendingIterator {
+    type Item<'a>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+// 버퍼의 겹치는 창을 &mut 로 하나씩 - windows() 의 가변 버전은 Iterator 로 만들 수 없음
+struct WindowsMut<'s, T> {
+    slice: &'s mut [T],
+    size: usize,
+    start: usize,
+}
+
+impl<'s, T> LendingIterator for WindowsMut<'s, T> {
+    type Item<'a>
+        = &'a mut [T]
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<&mut [T]> {
+        let window = self.slice.get_mut(self.start..self.start + self.size)?;
+        self.start += 1;
+        Some(window)
+    }
+}
+
+fn windows_mut<T>(slice: &mut [T], size: usize) -> WindowsMut<'_, T> {
+    WindowsMut { slice, size, start: 0 }
+}
+
+// 타입 매개변수를 받는 연관 타입 - 어떤 컨테이너 "종류" 를 고를지 추상화
+trait Family {
+    type Member<T>;
+
+    fn wrap<T>(value: T) -> Self::Member<T>;
+}
+
+struct BoxFamily;
+struct VecFamily;
+
+impl Family for BoxFamily {
+    type Member<T> = Box<T>;
+
+    fn wrap<T>(value: T) -> Box<T> {
+        Box::new(value)
+    }
+}
+
+impl Family for VecFamily {
+    type Member<T> = Vec<T>;
+
+    fn wrap<T>(value: T) -> Vec<T> {
+        vec![value]
+    }
+}
+
+fn generic_associated_types() {
+    println!("\n--- GAT ---");
+
+    // C++ 에서는:
+    // template <class T> struct Allocator { template <class U> using rebind = Allocator<U>; };
+    // 멤버 별칭 템플릿 - 제약이 없어서 잘못 쓰면 인스턴스화 때 에러
+
+    // 앞 창을 고치면 다음 창에 반영됨 - 창마다 앞의 두 값을 더해 세 번째에 (피보나치처럼)
+    let mut values = [1, 1, 0, 0, 0, 0];
+    let mut windows = windows_mut(&mut values, 3);
+    while let Some(w) = windows.next() {
+        w[2] = w[0] + w[1];
+    }
+    println!("windows_mut 로 채운 값: {:?}", values);
+
+    let boxed: <BoxFamily as Family>::Member<i32> = BoxFamily::wrap(7);
+    let listed = VecFamily::wrap("seven");
+    println!("BoxFamily: {:?}, VecFamily: {:?}", boxed, listed);
+
+    // where Self: 'a - "Item<'a> 는 반복자가 'a 동안 살아 있을 때만" 이라는 조건
+    //   컴파일러가 요구하므로 거의 항상 적음
+    // 한계: for 문, map/filter 같은 어댑터는 Iterator 전용 - LendingIterator 는 while let 으로
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traits_compose() {
+        assert_eq!(padded_len::<Base64>(10), 12);
+        assert_eq!(padded_len::<Hex>(10), 10);
+        assert_eq!(Meters(1.0) + Millimeters(500), Meters(1.5));
+        assert_eq!("ok".shout(), "OK!");
+
+        let mut values = [1, 1, 0, 0, 0];
+        let mut windows = windows_mut(&mut values, 3);
+        while let Some(w) = windows.next() {
+            w[2] = w[0] + w[1];
+        }
+        assert_eq!(values, [1, 1, 2, 3, 5]);
+    }
+}
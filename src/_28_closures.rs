@@ -0,0 +1,356 @@
+// ============================================================================
+// 28. 클로저 심화
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. 람다처럼 클로저마다 고유한 타입 - 돌려줄 때는 impl Fn (auto 반환) 또는 Box<dyn Fn> (std::function)
+// 2. 캡처 목록을 적지 않음 - 본문에서 쓰는 방식(읽기/쓰기/소유)을 보고 컴파일러가 정함
+//    move 는 [=] 보다 [x = std::move(x)] 에 가까움 - 복사가 아니라 이동 (Copy 타입만 복사)
+// 3. 2021 에디션: 필드 단위 캡처 - self.a 만 쓰면 self 전체가 아니라 self.a 만 빌림
+// 4. 캡처가 없는 클로저는 fn 포인터로 변환 (C++ 의 캡처 없는 람다 → 함수 포인터와 같음)
+// 5. 구조체에 저장할 때 빌린 캡처는 수명이 붙음 - 매달린 참조는 컴파일 에러 ([&] 의 함정이 없음)
+// ============================================================================
+
+use std::collections::HashMap;
+
+// 절 목록 (실행 순서) - cargo run -- 28:returning_closures 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("returning_closures", returning_closures),
+    ("fn_pointers", fn_pointers),
+    ("disjoint_captures", disjoint_captures),
+    ("move_partial", move_partial),
+    ("callbacks_in_structs", callbacks_in_structs),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "28"
+    }
+
+    fn name(&self) -> &'static str {
+        "클로저 심화"
+    }
+
+    fn description(&self) -> &'static str {
+        "클로저 반환, fn 포인터, 필드 단위 캡처, move, 구조체에 콜백 저장 - 람다와 std::function 과 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["impl Fn", "Box<dyn Fn>", "fn 포인터", "필드 단위 캡처", "move", "콜백"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 클로저 반환
+// ----------------------------------------------------------------------------
+
+// impl Fn - 타입은 하나로 정해져 있지만 이름을 적을 수 없을 때 (C++ 의 auto 반환)
+//   힙 할당 없음, 호출은 인라인 가능
+fn adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+// 분기마다 다른 클로저 = 다른 타입 → impl Fn 으로는 안 됨, Box<dyn Fn> (std::function)
+fn operation(name: &str) -> Option<Box<dyn Fn(i32, i32) -> i32>> {
+    match name {
+        "add" => Some(Box::new(|a, b| a + b)),
+        "mul" => Some(Box::new(|a, b| a * b)),
+        "pow" => Some(Box::new(|a, b| a.pow(b as u32))),
+        _ => None,
+    }
+}
+
+// 상태를 가진 클로저 - FnMut 을 돌려주면 호출할 때마다 안의 값이 바뀜
+fn counter(start: u32) -> impl FnMut() -> u32 {
+    let mut next = start;
+    move || {
+        next += 1;
+        next - 1
+    }
+}
+
+// 클로저를 받아 클로저를 돌려주기 - 합성
+fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |x| g(f(x))
+}
+
+fn returning_closures() {
+    println!("--- 클로저 반환 ---");
+
+    // C++ 에서는:
+    // auto adder(int n) { return [n](int x) { return x + n; }; }
+    // std::function<int(int, int)> operation(std::string_view name);
+
+    let add5 = adder(5);
+    println!("adder(5)(10) = {}", add5(10));
+
+    for name in ["add", "mul", "pow", "div"] {
+        match operation(name) {
+            Some(op) => println!("{}(2, 10) = {}", name, op(2, 10)),
+            None => println!("{}: 없는 연산", name),
+        }
+    }
+
+    let mut ids = counter(100);
+    println!("counter: {}, {}, {}", ids(), ids(), ids());
+
+    let shout = compose(|s: &str| s.trim().to_string(), |s: String| s.to_uppercase());
+    println!("compose: {:?}", shout("  hello  "));
+
+    // 크기 비교 - impl Fn 은 캡처한 값만큼, Box<dyn Fn> 은 팻 포인터
+    println!(
+        "크기: adder(5) {}바이트, Box<dyn Fn> {}바이트",
+        size_of_val(&add5),
+        size_of::<Box<dyn Fn(i32, i32) -> i32>>()
+    );
+}
+
+// ----------------------------------------------------------------------------
+// fn 포인터 vs 클로저
+// ----------------------------------------------------------------------------
+
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+fn square(x: i32) -> i32 {
+    x * x
+}
+
+// fn(i32) -> i32 는 타입 (트레이트가 아님) - 크기는 포인터 하나, 캡처 없음
+fn apply_all(fs: &[fn(i32) -> i32], x: i32) -> Vec<i32> {
+    fs.iter().map(|f| f(x)).collect()
+}
+
+fn fn_pointers() {
+    println!("\n--- fn 포인터 vs 클로저 ---");
+
+    // C++ 에서는:
+    // int (*fp)(int) = double_it;
+    // int (*fp2)(int) = [](int x) { return x + 1; };   // 캡처 없는 람다는 변환됨
+    // int k = 3; fp2 = [k](int x) { return x + k; };  // 에러 - 캡처가 있으면 안 됨
+
+    // 함수와 캡처 없는 클로저를 한 배열에
+    let table: [fn(i32) -> i32; 3] = [double, square, |x| x + 1];
+    println!("apply_all(7) = {:?}", apply_all(&table, 7));
+
+    // 캡처가 있으면 fn 포인터가 아님
+    //   let k = 3;
+    //   let f: fn(i32) -> i32 = |x| x + k;   // error: closures can only be coerced to `fn` types if they do not capture
+    let k = 3;
+    let with_k = |x: i32| x + k;
+    println!("캡처한 클로저: {}", with_k(1));
+
+    // fn 포인터는 Fn, FnMut, FnOnce 를 모두 구현 → 클로저를 받는 곳에 그대로
+    let mapped: Vec<i32> = [1, 2, 3].into_iter().map(square).collect();
+    println!("map(square) = {:?}", mapped);
+
+    // 이름으로 찾는 명령표 - 흔한 용도 (C 의 함수 포인터 테이블)
+    let mut commands: HashMap<&str, fn(i32) -> i32> = HashMap::new();
+    commands.insert("double", double);
+    commands.insert("square", square);
+    let mut names: Vec<_> = commands.keys().copied().collect();
+    names.sort_unstable();
+    for name in names {
+        println!("  {} 4 → {}", name, commands[name](4));
+    }
+
+    println!(
+        "크기: fn 포인터 {}바이트, 캡처 없는 클로저 {}바이트",
+        size_of::<fn(i32) -> i32>(),
+        size_of_val(&|x: i32| x + 1)
+    );
+}
+
+// ----------------------------------------------------------------------------
+// 필드 단위 캡처 (2021 에디션)
+// ----------------------------------------------------------------------------
+
+struct Editor {
+    text: String,
+    history: Vec<String>,
+    cursor: usize,
+}
+
+impl Editor {
+    // 클로저가 self.history 만 바꾸고 self.text 는 읽기만
+    //   2018 에디션에서는 클로저가 self 전체를 가변으로 빌려서 self.text 를 읽을 수 없었음
+    fn snapshot_words(&mut self) -> usize {
+        let mut record = |word: &str| self.history.push(word.to_string());
+        for word in self.text.split_whitespace() {
+            record(word);
+        }
+        self.history.len()
+    }
+}
+
+fn disjoint_captures() {
+    println!("\n--- 필드 단위 캡처 ---");
+
+    // C++ 에서는:
+    // [this] 는 객체 전체를 캡처 - 어떤 멤버를 쓰는지는 캡처와 상관없음
+    // [&h = history] 처럼 초기화 캡처로 멤버 하나만 잡을 수 있음 (C++14)
+
+    let mut editor = Editor { text: String::from("hello closure world"), history: Vec::new(), cursor: 0 };
+    println!("기록한 단어: {}개", editor.snapshot_words());
+
+    // 지역 변수에서도 - 구조체의 다른 필드를 동시에 빌릴 수 있음
+    let mut bump = || editor.cursor += 1;
+    bump();
+    bump();
+    // bump 가 editor.cursor 만 빌리므로 editor.text 는 여기서도 읽을 수 있음 (마지막 bump 호출 전이라도)
+    println!("text = {:?}, history = {:?}", editor.text, editor.history);
+    bump();
+    println!("cursor = {}", editor.cursor);
+
+    // 주의: 드롭 순서가 바뀔 수 있음 - 필드만 캡처하면 구조체 나머지는 원래 자리에서 드롭
+    //   cargo fix --edition 이 필요한 곳에 let _ = &x; 를 넣어 2018 동작을 유지
+}
+
+// ----------------------------------------------------------------------------
+// move 와 부분 캡처
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Job {
+    name: String,
+    payload: Vec<u8>,
+    retries: u32,
+}
+
+fn move_partial() {
+    println!("\n--- move 와 부분 캡처 ---");
+
+    // C++ 에서는:
+    // auto task = [payload = std::move(job.payload)] { return payload.size(); };
+    // job.payload 는 "유효하지만 알 수 없는 상태" - 써도 컴파일됨
+
+    let job = Job { name: String::from("upload"), payload: vec![1, 2, 3, 4], retries: 2 };
+
+    // move 클로저도 쓰는 필드만 가져감 - job.payload 만 이동
+    let task = move || job.payload.len();
+    // job.name, job.retries 는 그대로 사용 가능
+    println!("{} (재시도 {}번) → 크기 {}", job.name, job.retries, task());
+    // println!("{:?}", job);          // error: job.payload 가 이동됨 → job 전체는 못 씀
+    // println!("{:?}", job.payload);  // error: borrow of moved value
+
+    // 일부만 복제해서 넘기고 원본은 유지 - 흔한 패턴
+    let names = vec![String::from("a"), String::from("b")];
+    let report = {
+        let names = names.clone();
+        move || names.join(",")
+    };
+    println!("원본 {:?}, 클로저 {:?}", names, report());
+
+    // Copy 타입은 move 해도 복사 - 원본을 계속 쓸 수 있음
+    let limit = 10;
+    let within = move |x: i32| x <= limit;
+    println!("within(7) = {}, limit 여전히 {}", within(7), limit);
+
+    // 참조를 move 하면 참조가 복사됨 - 값이 아니라 빌림을 옮기는 것
+    let data = vec![5, 6, 7];
+    let view = &data;
+    let sum = move || view.iter().sum::<i32>();
+    println!("sum = {}, data 여전히 {:?}", sum(), data);
+}
+
+// ----------------------------------------------------------------------------
+// 구조체에 콜백 저장
+// ----------------------------------------------------------------------------
+
+// 1. 제네릭 - 콜백 하나, 타입이 고정, 호출 비용 없음
+struct Button<F: Fn(&str)> {
+    label: String,
+    on_click: F,
+}
+
+impl<F: Fn(&str)> Button<F> {
+    fn click(&self) {
+        (self.on_click)(&self.label);
+    }
+}
+
+// 2. Box<dyn FnMut> 목록 - 종류가 다른 콜백 여럿 (std::vector<std::function<void(...)>>)
+//    'a: 콜백이 빌린 값보다 오래 살 수 없음
+type Handler<'a> = Box<dyn FnMut(&str) + 'a>;
+
+#[derive(Default)]
+struct EventBus<'a> {
+    handlers: Vec<(String, Handler<'a>)>,
+}
+
+impl<'a> EventBus<'a> {
+    fn subscribe(&mut self, topic: &str, handler: impl FnMut(&str) + 'a) {
+        self.handlers.push((topic.to_string(), Box::new(handler)));
+    }
+
+    // 받은 핸들러 수
+    fn publish(&mut self, topic: &str, message: &str) -> usize {
+        let mut delivered = 0;
+        for (t, handler) in &mut self.handlers {
+            if t == topic {
+                handler(message);
+                delivered += 1;
+            }
+        }
+        delivered
+    }
+}
+
+fn callbacks_in_structs() {
+    println!("\n--- 구조체에 콜백 저장 ---");
+
+    // C++ 에서는:
+    // struct EventBus { std::vector<std::pair<std::string, std::function<void(std::string_view)>>> handlers; };
+    // bus.subscribe("log", [&log](auto m) { log.push_back(m); });  // log 가 먼저 사라지면 매달린 참조
+
+    let button = Button { label: String::from("저장"), on_click: |label: &str| println!("'{}' 클릭", label) };
+    button.click();
+
+    let mut log: Vec<String> = Vec::new();
+    let mut count = 0;
+    {
+        let mut bus = EventBus::default();
+        bus.subscribe("log", |m| log.push(m.to_string()));
+        bus.subscribe("log", |_| count += 1);
+        bus.subscribe("alert", |m| println!("  경보: {}", m));
+
+        println!("log 전달: {}곳", bus.publish("log", "시작"));
+        bus.publish("log", "끝");
+        println!("alert 전달: {}곳", bus.publish("alert", "디스크 90%"));
+        // bus 가 log, count 를 빌리는 동안에는 여기서 읽을 수 없음 - 블록이 끝나면 빌림도 끝
+    }
+    println!("log = {:?}, count = {}", log, count);
+
+    // 고르는 기준
+    //   콜백 하나, 타입이 정해짐 → 제네릭 (Button<F>)
+    //   여러 개, 실행 중에 추가 → Box<dyn FnMut>
+    //   다른 스레드로 보냄 → Box<dyn FnMut + Send + 'static> (빌린 캡처 불가, move 로 소유)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closures_keep_state() {
+        let mut next = counter(1);
+        assert_eq!((next(), next()), (1, 2));
+        assert_eq!(operation("pow").map(|op| op(2, 8)), Some(256));
+        assert!(operation("div").is_none());
+
+        let mut seen = Vec::new();
+        let mut bus = EventBus::default();
+        bus.subscribe("a", |m| seen.push(m.len()));
+        assert_eq!(bus.publish("a", "xyz"), 1);
+        assert_eq!(bus.publish("b", "xyz"), 0);
+        drop(bus);
+        assert_eq!(seen, [3]);
+    }
+}
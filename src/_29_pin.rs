@@ -0,0 +1,332 @@
+// ============================================================================
+// 29. Pin 과 자기 참조 타입
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. C++ 은 이동 생성자로 "옮길 때 내부 포인터를 고치는" 방법이 있음
+//    Rust 의 이동은 항상 memcpy - 훅이 없으므로 자기 자신을 가리키는 값은 옮기면 깨짐
+// 2. Pin<P> = "이 포인터가 가리키는 값은 다시는 옮기지 않겠다" 는 약속을 타입으로
+//    C++ 에서 이동/복사 생성자를 delete 한 타입과 비슷 - 다만 고정은 값을 만든 뒤 시작
+// 3. Unpin = 옮겨도 안전한 타입 (거의 모든 타입) - Pin 이 아무 제약도 걸지 않음
+// 4. async fn 이 만드는 Future 는 await 너머로 지역 변수를 빌리면 자기 참조가 됨
+//    → 17장의 poll(self: Pin<&mut Self>, ...) 가 Pin 을 받는 이유
+// ============================================================================
+
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::{pin, Pin};
+use std::ptr;
+use std::task::{Context, Poll, Waker};
+
+// 절 목록 (실행 순서) - cargo run -- 29:self_referential 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("self_referential", self_referential),
+    ("moving_breaks_it", moving_breaks_it),
+    ("pin_and_unpin", pin_and_unpin),
+    ("pinning_on_stack", pinning_on_stack),
+    ("futures_need_pin", futures_need_pin),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "29"
+    }
+
+    fn name(&self) -> &'static str {
+        "Pin 과 자기 참조 타입"
+    }
+
+    fn description(&self) -> &'static str {
+        "자기 참조 구조체가 이동으로 깨지는 이유, Pin/Unpin, pin!, Future 가 고정을 요구하는 이유"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Pin", "Unpin", "PhantomPinned", "pin!", "자기 참조", "Future"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 자기 참조 구조체
+// ----------------------------------------------------------------------------
+
+// 자기 필드를 가리키는 포인터를 가진 구조체
+//   참조(&String)로는 만들 수 없음 - 수명을 적을 방법이 없음 → 원시 포인터
+struct Unpinned {
+    value: String,
+    ptr: *const String,
+}
+
+impl Unpinned {
+    fn new(value: &str) -> Unpinned {
+        Unpinned { value: value.to_string(), ptr: ptr::null() }
+    }
+
+    // 만든 뒤에 자기 주소를 적음 - 만드는 동안에는 최종 주소를 알 수 없음
+    fn init(&mut self) {
+        self.ptr = &self.value;
+    }
+
+    fn points_to_self(&self) -> bool {
+        ptr::eq(self.ptr, &self.value)
+    }
+
+    fn via_ptr(&self) -> &str {
+        // SAFETY: 이 장에서는 ptr 이 가리키는 Unpinned 가 아직 살아 있을 때만 호출
+        //   (살아 있어도 "자기" 값이 아닐 수 있다는 것이 이 절의 요점)
+        unsafe { &*self.ptr }
+    }
+}
+
+fn self_referential() {
+    println!("--- 자기 참조 구조체 ---");
+
+    // C++ 에서는:
+    // struct Parser {
+    //     std::string input;
+    //     const char* cursor = input.data();   // 자기 멤버를 가리킴
+    //     Parser(Parser&& o) : input(std::move(o.input)), cursor(input.data() + (o.cursor - o.input.data())) {}
+    // };
+    // 이동 생성자에서 포인터를 고칠 수 있음 - Rust 에는 이런 훅이 없음
+
+    let mut a = Unpinned::new("hello");
+    a.init();
+    println!("init 직후: 자기를 가리킴? {}, ptr 로 읽은 값 {:?}", a.points_to_self(), a.via_ptr());
+
+    // 왜 이런 타입이 필요한가
+    //   - 버퍼와 그 버퍼를 가리키는 커서를 함께 들고 다니는 파서
+    //   - 침입형 연결 리스트의 노드
+    //   - async fn 의 상태 기계 (마지막 절)
+}
+
+// ----------------------------------------------------------------------------
+// 옮기면 깨지는 이유
+// ----------------------------------------------------------------------------
+
+fn moving_breaks_it() {
+    println!("\n--- 옮기면 깨지는 이유 ---");
+
+    let mut a = Unpinned::new("a");
+    let mut b = Unpinned::new("b");
+    a.init();
+    b.init();
+
+    // 두 값의 바이트를 맞바꿈 - 이동과 같은 memcpy, ptr 필드는 고쳐지지 않음
+    std::mem::swap(&mut a, &mut b);
+    println!(
+        "swap 후 a: value {:?}, ptr 로 읽은 값 {:?}, 자기를 가리킴? {}",
+        a.value,
+        a.via_ptr(),
+        a.points_to_self()
+    );
+    // a 의 ptr 은 여전히 원래 자리(지금의 b)를 가리킴 - 읽은 값이 자기 value 와 다름
+    // b 가 먼저 드롭됐다면 매달린 포인터 → 정의되지 않은 동작
+
+    // 이동도 마찬가지 - Vec 이나 Box 에 넣거나, 함수에서 돌려주면 주소가 바뀔 수 있음
+    let mut c = Unpinned::new("c");
+    c.init();
+    let moved = Box::new(c);
+    println!("Box::new(c) 로 옮긴 뒤 자기를 가리킴? {}", moved.points_to_self());
+
+    // 해결: 값이 처음 자리에서 움직이지 않는다고 약속하게 만들기 → Pin
+}
+
+// ----------------------------------------------------------------------------
+// Pin 과 Unpin
+// ----------------------------------------------------------------------------
+
+// 옮기면 안 되는 버전 - PhantomPinned 가 있으면 !Unpin
+struct Pinned {
+    value: String,
+    ptr: *const String,
+    _pin: PhantomPinned,
+}
+
+impl Pinned {
+    fn unboxed(value: &str) -> Pinned {
+        Pinned { value: value.to_string(), ptr: ptr::null(), _pin: PhantomPinned }
+    }
+
+    // 힙에 만들고 바로 고정 - 안전한 방법
+    fn boxed(value: &str) -> Pin<Box<Pinned>> {
+        let mut pinned = Box::pin(Pinned::unboxed(value));
+        pinned.as_mut().init();
+        pinned
+    }
+
+    // 고정된 뒤에만 부를 수 있음 - 이 뒤로 주소가 바뀌지 않으므로 적은 주소가 계속 맞음
+    fn init(self: Pin<&mut Self>) {
+        let ptr: *const String = &self.value;
+        // SAFETY: ptr 필드 하나만 바꾸고 self 를 옮기지 않음
+        unsafe { self.get_unchecked_mut().ptr = ptr };
+    }
+
+    fn points_to_self(self: Pin<&Self>) -> bool {
+        ptr::eq(self.ptr, &self.value)
+    }
+
+    fn via_ptr(self: Pin<&Self>) -> &str {
+        // SAFETY: init 은 고정된 뒤에만 불리고, 고정된 값은 드롭될 때까지 옮겨지지 않음
+        unsafe { &*self.get_ref().ptr }
+    }
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+fn pin_and_unpin() {
+    println!("\n--- Pin 과 Unpin ---");
+
+    // C++ 에서는:
+    // struct Pinned { Pinned(Pinned&&) = delete; Pinned& operator=(Pinned&&) = delete; ... };
+    // 다만 C++ 은 타입 전체가 이동 불가 - Rust 는 고정된 뒤부터만 (고정 전에는 자유롭게 옮김)
+
+    let mut a = Pinned::boxed("a");
+    let mut b = Pinned::boxed("b");
+    // Pin<Box<T>> 끼리 바꾸면 Box(포인터)만 바뀜 - 힙의 값은 그대로
+    std::mem::swap(&mut a, &mut b);
+    println!(
+        "Pin<Box> swap 후 a: value {:?}, ptr 로 읽은 값 {:?}, 자기를 가리킴? {}",
+        a.value,
+        a.as_ref().via_ptr(),
+        a.as_ref().points_to_self()
+    );
+
+    // 안의 값을 바꾸려고 하면 컴파일 에러:
+    //   std::mem::swap(&mut *a, &mut *b);
+    //   error: the trait `Unpin` is not implemented for `PhantomPinned`
+    //   Pin<P> 는 T: Unpin 일 때만 &mut T 를 내줌 (DerefMut, get_mut)
+
+    // Unpin 은 자동 트레이트 - 필드가 모두 Unpin 이면 Unpin
+    assert_unpin::<String>();
+    assert_unpin::<Unpinned>();
+    assert_unpin::<Box<Pinned>>(); // Box 는 옮겨도 힙의 값은 그대로 → 항상 Unpin
+    // assert_unpin::<Pinned>();   // error: PhantomPinned 때문에 !Unpin
+
+    // Unpin 타입에서는 Pin 이 아무것도 막지 않음 - Pin::new 가 안전, get_mut 으로 다시 &mut
+    let mut n = 5;
+    let mut pinned_n = Pin::new(&mut n);
+    *pinned_n.as_mut().get_mut() += 1;
+    println!("Unpin 인 i32 는 Pin::new 로 고정해도 자유롭게: {}", n);
+}
+
+// ----------------------------------------------------------------------------
+// 스택에 고정하기
+// ----------------------------------------------------------------------------
+
+fn pinning_on_stack() {
+    println!("\n--- 스택에 고정하기 ---");
+
+    // 1. Pin::new_unchecked - !Unpin 을 스택에 고정하는 unsafe 방법
+    let mut local = Pinned::unboxed("stack");
+    // SAFETY: 같은 이름으로 가려서 원래 값에 다시 접근할 방법이 없음 - 드롭될 때까지 옮기지 않음
+    let mut local = unsafe { Pin::new_unchecked(&mut local) };
+    local.as_mut().init();
+    println!("new_unchecked: 자기를 가리킴? {}, {:?}", local.as_ref().points_to_self(), local.as_ref().via_ptr());
+
+    // 약속을 어기는 예 (컴파일은 되지만 정의되지 않은 동작):
+    //   let mut x = Pinned::unboxed("x");
+    //   unsafe { Pin::new_unchecked(&mut x) }.init();
+    //   let y = x;   // 고정했던 값을 옮김 - y.ptr 은 옛 자리를 가리킴
+
+    // 2. pin! - 위의 가리기를 매크로가 대신 해서 안전
+    let mut by_macro = pin!(Pinned::unboxed("macro"));
+    by_macro.as_mut().init();
+    println!("pin!: 자기를 가리킴? {}, {:?}", by_macro.as_ref().points_to_self(), by_macro.as_ref().via_ptr());
+
+    // 고르는 기준
+    //   Box::pin(x)  - 힙 할당, 돌려주거나 저장할 수 있음 (Pin<Box<dyn Future>>)
+    //   pin!(x)      - 할당 없음, 현재 함수 안에서만 (select! 에 넘길 Future 등)
+    //   new_unchecked - 직접 만든 고정 컨테이너 안에서만, SAFETY 주석과 함께
+}
+
+// ----------------------------------------------------------------------------
+// Future 가 Pin 을 요구하는 이유
+// ----------------------------------------------------------------------------
+
+// 처음 poll 에서는 Pending, 두 번째에 Ready - await 지점을 하나 만들기 위한 것
+struct YieldOnce {
+    yielded: bool,
+}
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            // YieldOnce 는 Unpin 이라 Pin<&mut Self> 에서 바로 필드를 고칠 수 있음
+            self.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+// await 너머로 지역 변수를 빌리는 async fn - 상태 기계 안에 words 와 그 참조가 함께 들어감
+async fn count_words(text: String) -> usize {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    YieldOnce { yielded: false }.await;
+    words.len()
+}
+
+// 아주 작은 실행기 - Pending 이면 다시 poll, 몇 번 poll 했는지 함께
+fn run_to_end<F: Future>(future: F) -> (F::Output, u32) {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        if let Poll::Ready(out) = future.as_mut().poll(&mut cx) {
+            return (out, polls);
+        }
+    }
+}
+
+fn futures_need_pin() {
+    println!("\n--- Future 가 Pin 을 요구하는 이유 ---");
+
+    // C++ 에서는:
+    // 코루틴 프레임은 처음부터 힙에 할당되고 옮겨지지 않음 - 문제가 생기지 않는 대신 항상 할당
+    // Rust 의 Future 는 그냥 값 (스택에 둘 수 있음) → 처음 poll 한 뒤로는 옮기지 않도록 Pin
+
+    // async fn 이 만드는 상태 기계 (개념상):
+    //   enum CountWords { Start { text: String },
+    //                     Waiting { text: String, words: Vec<&'self str>, yield_once: YieldOnce },
+    //                     Done }
+    //   words 가 같은 값 안의 text 를 가리킴 → 자기 참조
+    let future = count_words(String::from("pin keeps futures in place"));
+    println!("Future 크기: {}바이트 (text, words, 상태 태그)", size_of_val(&future));
+
+    let (count, polls) = run_to_end(future);
+    println!("단어 {}개, poll {}번", count, polls);
+
+    // 처음 poll 하기 전에는 자기 참조가 아직 없으므로 자유롭게 옮겨도 됨 (함수로 넘기기, Vec 에 넣기)
+    // poll 은 Pin<&mut Self> 를 받으므로 첫 poll 부터는 옮길 수 없음
+    // 종류가 다른 Future 를 모으려면 Pin<Box<dyn Future<Output = T>>> (tokio 의 BoxFuture)
+    let boxed: Vec<Pin<Box<dyn Future<Output = usize>>>> =
+        vec![Box::pin(count_words(String::from("a b"))), Box::pin(async { 42 })];
+    let outputs: Vec<usize> = boxed.into_iter().map(|f| run_to_end(f).0).collect();
+    println!("Box::pin 으로 모은 Future 결과: {:?}", outputs);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pinned_value_keeps_pointing_to_itself() {
+        let mut a = Pinned::boxed("a");
+        let mut b = Pinned::boxed("b");
+        std::mem::swap(&mut a, &mut b);
+        assert!(a.as_ref().points_to_self());
+        assert_eq!(a.as_ref().via_ptr(), "b");
+
+        assert_eq!(run_to_end(count_words(String::from("one two three"))), (3, 2));
+    }
+}
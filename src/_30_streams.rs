@@ -0,0 +1,336 @@
+// ============================================================================
+// 30. 비동기 스트림
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. Stream = 비동기 Iterator - next() 가 Future 를 돌려줌 (C++23 의 std::generator 는 동기)
+//    C++ 에서는 co_yield 하는 비동기 제너레이터를 라이브러리(cppcoro::async_generator)로
+// 2. 표준 라이브러리에 아직 없음 - futures::Stream 트레이트를 tokio_stream 이 다시 내보냄
+// 3. 직접 구현할 때는 poll_next(Pin<&mut Self>, &mut Context) - Future::poll 과 같은 모양
+// 4. 어댑터는 Iterator 와 같은 이름 (map, filter, take) + 시간 관련 (timeout, throttle, chunks_timeout)
+// 5. 채널의 수신자는 래퍼 하나로 스트림이 됨 (ReceiverStream) - 생산자/소비자를 잇는 흔한 방법
+// ============================================================================
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{broadcast, mpsc, Semaphore};
+use tokio::time::{interval, sleep, Interval};
+use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
+use tokio_stream::{Stream, StreamExt, StreamMap};
+
+// 절 목록 (실행 순서) - cargo run -- 30:stream_trait 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("stream_trait", || block_on(stream_trait())),
+    ("adapters", || block_on(adapters())),
+    ("custom_stream", || block_on(custom_stream())),
+    ("buffering_and_concurrency", || block_on(buffering_and_concurrency())),
+    ("channels_and_streams", || block_on(channels_and_streams())),
+];
+
+// 비동기 절은 각자 tokio 런타임을 만들어 실행 - 17장과 같음
+fn block_on(future: impl std::future::Future<Output = ()>) {
+    // 런타임을 만들 수 없으면(스레드 생성 실패 등) 예제를 진행할 수 없으므로 unwrap
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "30"
+    }
+
+    fn name(&self) -> &'static str {
+        "비동기 스트림"
+    }
+
+    fn description(&self) -> &'static str {
+        "Stream 트레이트, tokio_stream 어댑터, poll_next 직접 구현, 묶음과 동시 처리, 채널과 스트림 변환"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Stream", "poll_next", "StreamExt", "chunks_timeout", "StreamMap", "ReceiverStream"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Stream 트레이트
+// ----------------------------------------------------------------------------
+
+async fn stream_trait() {
+    println!("--- Stream 트레이트 ---");
+
+    // C++ 에서는 (cppcoro):
+    // cppcoro::async_generator<int> numbers() { for (int i = 0; i < 3; ++i) co_yield i; }
+    // for co_await (int n : numbers()) { ... }   // 표준에는 아직 없음
+
+    // 정의 (futures_core::Stream, tokio_stream 이 다시 내보냄):
+    // trait Stream {
+    //     type Item;
+    //     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+    //     fn size_hint(&self) -> (usize, Option<usize>) { (0, None) }
+    // }
+    // Iterator::next 가 Option<Item> 이라면, poll_next 는 "아직 없음(Pending)" 이 하나 더
+
+    let mut numbers = tokio_stream::iter([1, 2, 3]);
+    // for 문은 Iterator 전용 - 스트림은 while let + .next().await (StreamExt 의 메서드)
+    while let Some(n) = numbers.next().await {
+        println!("항목 {}", n);
+    }
+
+    // 빈 스트림, 하나짜리 스트림
+    let empty: Vec<i32> = tokio_stream::empty().collect().await;
+    let once: Vec<&str> = tokio_stream::once("한 번").collect().await;
+    println!("empty = {:?}, once = {:?}", empty, once);
+}
+
+// ----------------------------------------------------------------------------
+// 어댑터
+// ----------------------------------------------------------------------------
+
+async fn double_later(x: u32) -> u32 {
+    sleep(Duration::from_millis(1)).await;
+    x * 2
+}
+
+async fn adapters() {
+    println!("\n--- 어댑터 ---");
+
+    // Iterator 와 같은 이름 - 클로저는 동기
+    let odd_squares: Vec<u32> =
+        tokio_stream::iter(1..=10).filter(|x| x % 2 == 1).map(|x| x * x).take(3).collect().await;
+    println!("filter + map + take: {:?}", odd_squares);
+
+    // then - 항목마다 async 작업 (map 의 비동기 버전), 하나씩 차례로 기다림
+    let doubled: Vec<u32> = tokio_stream::iter([1, 2, 3]).then(double_later).collect().await;
+    println!("then: {:?}", doubled);
+
+    // fold, any, all - 스트림을 소비해서 값 하나로
+    let sum = tokio_stream::iter(1..=100).fold(0u32, |acc, x| acc + x).await;
+    let has_big = tokio_stream::iter([3, 9, 27]).any(|x| x > 10).await;
+    println!("fold 합: {}, any(> 10): {}", sum, has_big);
+
+    // merge - 두 스트림을 도착하는 순서대로 섞음 (둘 다 끝나야 끝)
+    let letters = tokio_stream::iter(["a", "b"]);
+    let more = tokio_stream::iter(["x", "y"]);
+    let mut merged: Vec<&str> = letters.merge(more).collect().await;
+    merged.sort_unstable();
+    println!("merge (정렬해서): {:?}", merged);
+
+    // timeout - 항목 사이가 너무 길면 Err 를 끼워 넣음 (스트림은 계속)
+    let slow = tokio_stream::iter([5u64, 40, 5]).then(|ms| async move {
+        sleep(Duration::from_millis(ms)).await;
+        ms
+    });
+    let summary: Vec<String> = slow
+        .timeout(Duration::from_millis(20))
+        .map(|r| r.map_or("시간 초과".to_string(), |ms| format!("{}ms", ms)))
+        .collect()
+        .await;
+    println!("timeout(20ms): {:?}", summary);
+}
+
+// ----------------------------------------------------------------------------
+// 직접 구현 (poll_next)
+// ----------------------------------------------------------------------------
+
+// 1. 기다릴 일이 없는 스트림 - 항상 Ready
+struct Countdown {
+    remaining: u32,
+}
+
+impl Stream for Countdown {
+    type Item = u32;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        if self.remaining == 0 {
+            return Poll::Ready(None);
+        }
+        self.remaining -= 1;
+        Poll::Ready(Some(self.remaining + 1))
+    }
+
+    // collect 가 미리 용량을 잡을 수 있게
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+// 2. 기다리는 스트림 - 안의 Interval 이 Pending 이면 그대로 Pending 을 돌려줌
+//    Interval::poll_tick 이 cx 의 waker 를 등록하므로 때가 되면 런타임이 다시 poll
+struct Paced<I> {
+    items: I,
+    tick: Interval,
+}
+
+impl<I: Iterator + Unpin> Stream for Paced<I> {
+    type Item = I::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        // Paced 는 Unpin (Interval 이 안에서 Sleep 을 Box 로 고정) → 필드에 바로 접근
+        match self.tick.poll_tick(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(_) => Poll::Ready(self.items.next()),
+        }
+    }
+}
+
+fn paced<I: IntoIterator>(items: I, every: Duration) -> Paced<I::IntoIter> {
+    Paced { items: items.into_iter(), tick: interval(every) }
+}
+
+async fn custom_stream() {
+    println!("\n--- 직접 구현 (poll_next) ---");
+
+    let countdown = Countdown { remaining: 3 };
+    println!("size_hint = {:?}", countdown.size_hint());
+    let values: Vec<u32> = countdown.collect().await;
+    println!("Countdown: {:?}", values);
+
+    // 첫 tick 은 바로, 그 뒤로 5ms 마다
+    let start = Instant::now();
+    let paced_values: Vec<char> = paced("abc".chars(), Duration::from_millis(5)).collect().await;
+    println!("Paced: {:?}, 10ms 이상 걸림? {}", paced_values, start.elapsed() >= Duration::from_millis(10));
+
+    // 안에 !Unpin 필드(직접 가진 Sleep, async 블록)가 있으면 Pin<&mut Self> 에서 필드를 꺼내기 어려움
+    //   → pin-project 크레이트, 또는 Box::pin 으로 감싸서 Unpin 으로 (29장)
+    // async 블록으로 스트림을 쓰고 싶다면 async-stream 크레이트의 stream! { yield x; } (제너레이터는 불안정)
+}
+
+// ----------------------------------------------------------------------------
+// 묶음과 동시 처리
+// ----------------------------------------------------------------------------
+
+// 느린 작업 - 요청 하나에 10ms
+async fn fetch(id: u32) -> String {
+    sleep(Duration::from_millis(10)).await;
+    format!("#{}", id)
+}
+
+// 동시에 최대 limit 개까지 처리하고, 끝나는 순서대로 스트림으로
+//   futures 크레이트의 buffer_unordered(limit) 와 같은 일을 tokio 만으로
+fn fetch_concurrently(ids: Vec<u32>, limit: usize) -> ReceiverStream<String> {
+    let (tx, rx) = mpsc::channel(limit);
+    tokio::spawn(async move {
+        let permits = std::sync::Arc::new(Semaphore::new(limit));
+        for id in ids {
+            // 허가를 먼저 받음 - limit 개가 돌고 있으면 여기서 기다림 (역압)
+            let Ok(permit) = permits.clone().acquire_owned().await else { break };
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let _ = tx.send(fetch(id).await).await;
+                drop(permit);
+            });
+        }
+    });
+    ReceiverStream::new(rx)
+}
+
+async fn buffering_and_concurrency() {
+    println!("\n--- 묶음과 동시 처리 ---");
+
+    // chunks_timeout - 최대 n 개씩 묶되, 시간이 지나면 모인 만큼만 (로그/지표를 모아 보내기)
+    let (tx, rx) = mpsc::channel(16);
+    tokio::spawn(async move {
+        for burst in [vec![1, 2, 3, 4, 5], vec![6]] {
+            for x in burst {
+                let _ = tx.send(x).await;
+            }
+            sleep(Duration::from_millis(30)).await;
+        }
+    });
+    let batches: Vec<Vec<i32>> =
+        ReceiverStream::new(rx).chunks_timeout(3, Duration::from_millis(10)).collect().await;
+    println!("chunks_timeout(3, 10ms): {:?}", batches);
+
+    // then 은 하나씩 - 4개 × 10ms
+    let start = Instant::now();
+    let sequential: Vec<String> = tokio_stream::iter(1..=4).then(fetch).collect().await;
+    let sequential_time = start.elapsed();
+
+    // 동시에 2개씩 - 대략 절반
+    let start = Instant::now();
+    let mut concurrent: Vec<String> = fetch_concurrently((1..=4).collect(), 2).collect().await;
+    let concurrent_time = start.elapsed();
+    concurrent.sort();
+    println!("순차 {:?} / 동시 2개 {:?}", sequential, concurrent);
+    println!("동시 처리가 더 빠름? {}", concurrent_time < sequential_time);
+
+    // throttle - 항목 사이에 최소 간격 (외부 API 호출 속도 제한)
+    let start = Instant::now();
+    let throttled: Vec<i32> = tokio_stream::iter([1, 2, 3]).throttle(Duration::from_millis(5)).collect().await;
+    println!("throttle(5ms): {:?}, 10ms 이상 걸림? {}", throttled, start.elapsed() >= Duration::from_millis(10));
+}
+
+// ----------------------------------------------------------------------------
+// 채널과 스트림 변환
+// ----------------------------------------------------------------------------
+
+async fn channels_and_streams() {
+    println!("\n--- 채널과 스트림 변환 ---");
+
+    // 채널 → 스트림: 수신자를 래퍼로 감쌈
+    //   mpsc::Receiver → ReceiverStream, UnboundedReceiver → UnboundedReceiverStream
+    //   broadcast::Receiver → BroadcastStream (뒤처지면 Err(Lagged)), watch → WatchStream
+    let (tx, rx) = broadcast::channel::<&str>(8);
+    let listener = BroadcastStream::new(rx);
+    for event in ["login", "click", "logout"] {
+        let _ = tx.send(event);
+    }
+    drop(tx); // 송신자가 모두 사라지면 스트림이 끝남
+    let events: Vec<&str> = listener.filter_map(Result::ok).collect().await;
+    println!("BroadcastStream: {:?}", events);
+
+    // 스트림 → 채널: 태스크 하나가 스트림을 돌며 보냄 (다른 태스크가 recv 로 받음)
+    let (tx, mut rx) = mpsc::channel(4);
+    tokio::spawn(async move {
+        let mut squares = tokio_stream::iter(1..=3).map(|x| x * x);
+        while let Some(v) = squares.next().await {
+            if tx.send(v).await.is_err() {
+                break; // 받는 쪽이 사라짐
+            }
+        }
+    });
+    let mut received = Vec::new();
+    while let Some(v) = rx.recv().await {
+        received.push(v);
+    }
+    println!("스트림 → mpsc: {:?}", received);
+
+    // StreamMap - 이름 붙은 스트림 여럿을 하나로, 어느 스트림에서 왔는지 함께
+    //   채팅방마다 수신자를 하나씩 두고 한 루프에서 받기 (20장의 방 목록과 비슷한 구조)
+    let mut rooms = StreamMap::new();
+    for (room, messages) in [("rust", vec!["안녕", "질문"]), ("cpp", vec!["hello"])] {
+        let (tx, rx) = mpsc::channel(4);
+        for m in messages {
+            let _ = tx.send(m).await;
+        }
+        rooms.insert(room, ReceiverStream::new(rx));
+    }
+    let mut by_room: Vec<(&str, &str)> = rooms.collect().await;
+    by_room.sort_unstable();
+    println!("StreamMap: {:?}", by_room);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_streams_yield_in_order() {
+        block_on(async {
+            assert_eq!(Countdown { remaining: 3 }.collect::<Vec<_>>().await, [3, 2, 1]);
+            let paced_values: Vec<i32> = paced([1, 2], Duration::from_millis(1)).collect().await;
+            assert_eq!(paced_values, [1, 2]);
+            let mut fetched: Vec<String> = fetch_concurrently(vec![2, 1, 3], 2).collect().await;
+            fetched.sort();
+            assert_eq!(fetched, ["#1", "#2", "#3"]);
+        });
+    }
+}
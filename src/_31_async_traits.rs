@@ -0,0 +1,344 @@
+// ============================================================================
+// 31. 트레이트의 async fn
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. C++ 의 가상 함수는 코루틴이 될 수 있음 - virtual Task<int> get() 은 그냥 반환 타입이 Task<int>
+//    코루틴 프레임은 항상 힙에 할당되므로 호출자가 크기를 알 필요가 없음
+// 2. Rust 의 async fn 은 구현마다 크기가 다른 익명 Future 를 돌려줌
+//    → 제네릭(정적 디스패치)에서는 그대로 쓸 수 있지만(1.75 부터 안정), dyn Trait 으로는 못 씀
+// 3. 제네릭 코드에서 "이 Future 는 Send 인가" 를 알 수 없음 - tokio::spawn 에 넘길 때 문제
+//    → fn f(&self) -> impl Future<Output = T> + Send 로 트레이트에 적음
+// 4. dyn 이 필요하면 Pin<Box<dyn Future>> 를 돌려줌 - C++ 의 힙 코루틴 프레임과 같은 비용
+//    async-trait 크레이트는 이 변환을 매크로로 해 줌
+// ============================================================================
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+// 절 목록 (실행 순서) - cargo run -- 31:async_fn_in_trait 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("async_fn_in_trait", || block_on(async_fn_in_trait())),
+    ("send_bounds", || block_on(send_bounds())),
+    ("boxed_future_returns", || block_on(boxed_future_returns())),
+    ("async_trait_macro", async_trait_macro),
+    ("object_safety", || block_on(object_safety())),
+];
+
+// 비동기 절은 각자 tokio 런타임을 만들어 실행 - 17장과 같음
+fn block_on(future: impl Future<Output = ()>) {
+    // 런타임을 만들 수 없으면(스레드 생성 실패 등) 예제를 진행할 수 없으므로 unwrap
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "31"
+    }
+
+    fn name(&self) -> &'static str {
+        "트레이트의 async fn"
+    }
+
+    fn description(&self) -> &'static str {
+        "트레이트의 async fn, Send 바운드, Box<dyn Future> 반환, async-trait 매크로, dyn 호환성 - 가상 코루틴과 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["async fn in trait", "impl Future + Send", "BoxFuture", "async-trait", "dyn 호환성"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 트레이트의 async fn
+// ----------------------------------------------------------------------------
+
+// 저장소 - 메모리와 (가짜) 원격 두 가지 구현
+trait Storage {
+    async fn get(&self, key: &str) -> Option<String>;
+    async fn put(&self, key: &str, value: String);
+}
+
+#[derive(Default)]
+struct MemoryStorage {
+    data: Mutex<HashMap<String, String>>,
+}
+
+impl Storage for MemoryStorage {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.data.lock().await.get(key).cloned()
+    }
+
+    async fn put(&self, key: &str, value: String) {
+        self.data.lock().await.insert(key.to_string(), value);
+    }
+}
+
+// 원격 저장소 흉내 - 호출마다 지연
+#[derive(Default)]
+struct RemoteStorage {
+    inner: MemoryStorage,
+    latency_ms: u64,
+}
+
+impl Storage for RemoteStorage {
+    async fn get(&self, key: &str) -> Option<String> {
+        sleep(Duration::from_millis(self.latency_ms)).await;
+        self.inner.get(key).await
+    }
+
+    async fn put(&self, key: &str, value: String) {
+        sleep(Duration::from_millis(self.latency_ms)).await;
+        self.inner.put(key, value).await;
+    }
+}
+
+// 제네릭 - 구현마다 따로 컴파일되므로 Future 의 크기와 타입을 컴파일러가 앎
+async fn copy_key<S: Storage>(from: &S, to: &S, key: &str) -> bool {
+    match from.get(key).await {
+        Some(value) => {
+            to.put(key, value).await;
+            true
+        }
+        None => false,
+    }
+}
+
+async fn async_fn_in_trait() {
+    println!("--- 트레이트의 async fn ---");
+
+    // C++ 에서는:
+    // struct Storage { virtual Task<std::optional<std::string>> get(std::string_view key) = 0; };
+    // 반환 타입이 Task<T> 로 모두 같음 - 프레임은 힙에
+
+    // Rust 1.75 부터: 트레이트에 async fn 을 그대로 적을 수 있음
+    //   async fn get(&self, key: &str) -> Option<String>;
+    //   = fn get(&self, key: &str) -> impl Future<Output = Option<String>>;  (구현마다 다른 타입)
+
+    let a = MemoryStorage::default();
+    let b = MemoryStorage::default();
+    a.put("lang", String::from("rust")).await;
+    println!("copy_key(lang): {}, copy_key(none): {}", copy_key(&a, &b, "lang").await, copy_key(&a, &b, "none").await);
+    println!("b.get(lang) = {:?}", b.get("lang").await);
+
+    let remote = RemoteStorage { latency_ms: 2, ..Default::default() };
+    remote.put("k", String::from("v")).await;
+    println!("remote.get(k) = {:?}", remote.get("k").await);
+}
+
+// ----------------------------------------------------------------------------
+// Send 바운드
+// ----------------------------------------------------------------------------
+
+// async fn 대신 impl Future + Send 로 선언 - 구현은 여전히 async fn 으로 쓸 수 있음
+trait SendStorage: Send + Sync {
+    fn lookup(&self, key: &str) -> impl Future<Output = Option<String>> + Send;
+}
+
+impl SendStorage for MemoryStorage {
+    async fn lookup(&self, key: &str) -> Option<String> {
+        self.data.lock().await.get(key).cloned()
+    }
+}
+
+// 제네릭 S 의 Future 를 다른 스레드의 태스크로 보냄 - Send 가 필요
+fn spawn_lookup<S: SendStorage + 'static>(storage: Arc<S>, key: &'static str) -> tokio::task::JoinHandle<Option<String>> {
+    tokio::spawn(async move { storage.lookup(key).await })
+}
+
+async fn send_bounds() {
+    println!("\n--- Send 바운드 ---");
+
+    // Storage (async fn 버전) 으로 같은 함수를 쓰면:
+    //   fn spawn_lookup<S: Storage + Send + Sync + 'static>(s: Arc<S>, ..) { tokio::spawn(async move { s.get(key).await }) }
+    //   error: future cannot be sent between threads safely
+    //   → S::get 이 돌려주는 Future 가 Send 인지 트레이트가 약속하지 않았기 때문
+    // 그래서 공개 트레이트의 async fn 에는 경고가 뜸 (async_fn_in_trait 린트) - 이 장의 트레이트는 비공개
+
+    let storage = Arc::new(MemoryStorage::default());
+    Storage::put(&*storage, "city", String::from("Seoul")).await;
+    let handle = spawn_lookup(Arc::clone(&storage), "city");
+    match handle.await {
+        Ok(found) => println!("spawn 한 태스크에서 찾음: {:?}", found),
+        Err(e) => println!("태스크 실패: {}", e),
+    }
+
+    // 정리
+    //   트레이트를 내 크레이트 안에서만 쓰거나 단일 스레드 런타임 → async fn 그대로
+    //   공개 라이브러리, 멀티 스레드 런타임 → impl Future<Output = T> + Send
+    //   (trait-variant 크레이트는 두 버전을 한 번에 만들어 줌)
+}
+
+// ----------------------------------------------------------------------------
+// Box<dyn Future> 반환
+// ----------------------------------------------------------------------------
+
+// 크기를 모르는 Future 를 힙에 두고 포인터만 돌려줌 - futures::future::BoxFuture 와 같은 별칭
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+// 반환 타입이 하나로 고정 → dyn 으로 쓸 수 있음
+trait Handler: Send + Sync {
+    fn name(&self) -> &str;
+    fn handle<'a>(&'a self, request: &'a str) -> BoxFuture<'a, String>;
+}
+
+struct Echo;
+struct Slow {
+    delay_ms: u64,
+}
+
+impl Handler for Echo {
+    fn name(&self) -> &str {
+        "echo"
+    }
+
+    fn handle<'a>(&'a self, request: &'a str) -> BoxFuture<'a, String> {
+        Box::pin(async move { request.to_string() })
+    }
+}
+
+impl Handler for Slow {
+    fn name(&self) -> &str {
+        "slow"
+    }
+
+    fn handle<'a>(&'a self, request: &'a str) -> BoxFuture<'a, String> {
+        Box::pin(async move {
+            sleep(Duration::from_millis(self.delay_ms)).await;
+            request.to_uppercase()
+        })
+    }
+}
+
+async fn boxed_future_returns() {
+    println!("\n--- Box<dyn Future> 반환 ---");
+
+    // C++ 에서는 처음부터 이 모양 - Task<T> 가 힙 프레임을 가리키는 핸들
+
+    // 종류가 다른 구현을 한 목록에 - 라우터, 플러그인, 미들웨어
+    let handlers: Vec<Box<dyn Handler>> = vec![Box::new(Echo), Box::new(Slow { delay_ms: 2 })];
+    for h in &handlers {
+        println!("{} → {:?}", h.name(), h.handle("ping").await);
+    }
+
+    // 비용: 호출마다 Box 할당 하나 + vtable 을 거친 poll
+    //   네트워크나 디스크를 기다리는 일에 비하면 대개 무시할 만함 (26장처럼 재 보기)
+    // 'a: Future 가 &self, request 를 빌리므로 그보다 오래 살 수 없음
+}
+
+// ----------------------------------------------------------------------------
+// async-trait 매크로
+// ----------------------------------------------------------------------------
+
+fn async_trait_macro() {
+    println!("\n--- async-trait 매크로 ---");
+
+    // 1.75 이전의 표준적인 방법 - 지금도 dyn 이 필요하면 많이 씀 (이 저장소에는 의존성 없음)
+    //
+    //   #[async_trait::async_trait]
+    //   trait Handler {
+    //       async fn handle(&self, request: &str) -> String;
+    //   }
+    //
+    //   #[async_trait::async_trait]
+    //   impl Handler for Echo {
+    //       async fn handle(&self, request: &str) -> String { request.to_string() }
+    //   }
+    //
+    // 매크로가 펼친 모양 = 바로 앞 절의 손으로 쓴 버전
+    //   fn handle<'life0, 'life1, 'async_trait>(&'life0 self, request: &'life1 str)
+    //       -> Pin<Box<dyn Future<Output = String> + Send + 'async_trait>>
+    //   where 'life0: 'async_trait, 'life1: 'async_trait, Self: 'async_trait
+    //   { Box::pin(async move { request.to_string() }) }
+    //
+    // #[async_trait(?Send)] - Send 가 필요 없을 때 (단일 스레드, Rc 를 들고 await)
+
+    let approaches = [
+        ("async fn (1.75+)", "없음", "불가", "알 수 없음 (제네릭에서)"),
+        ("impl Future + Send", "없음", "불가", "보장"),
+        ("BoxFuture 직접", "호출마다 Box", "가능", "보장 (+ Send 적으면)"),
+        ("#[async_trait]", "호출마다 Box", "가능", "기본 보장, ?Send 로 끔"),
+    ];
+    println!("{:20} | {:12} | {:6} | Send", "방식", "할당", "dyn");
+    for (way, alloc, dyn_ok, send) in approaches {
+        println!("{:20} | {:12} | {:6} | {}", way, alloc, dyn_ok, send);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// dyn 호환성 (object safety)
+// ----------------------------------------------------------------------------
+
+// async fn 트레이트는 dyn 으로 못 씀:
+//   let s: Box<dyn Storage> = ...;
+//   error[E0038]: the trait `Storage` is not dyn compatible
+//   ... because method `get` is `async`
+// 해결: 박스로 감싸는 dyn 전용 트레이트를 따로 두고, 포괄 구현으로 잇기
+//   구현하는 쪽은 편한 async fn 트레이트만, 쓰는 쪽은 필요하면 dyn 버전
+trait DynStorage: Send + Sync {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>>;
+}
+
+impl<T: SendStorage> DynStorage for T {
+    fn lookup_boxed<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<String>> {
+        Box::pin(self.lookup(key))
+    }
+}
+
+struct Fixed(&'static str);
+
+impl SendStorage for Fixed {
+    async fn lookup(&self, _key: &str) -> Option<String> {
+        Some(self.0.to_string())
+    }
+}
+
+async fn object_safety() {
+    println!("\n--- dyn 호환성 ---");
+
+    // C++ 에서는:
+    // std::vector<std::unique_ptr<Storage>> backends;   // 가상 코루틴이라 그대로 됨
+
+    let memory = MemoryStorage::default();
+    Storage::put(&memory, "lang", String::from("rust")).await;
+    let backends: Vec<Box<dyn DynStorage>> = vec![Box::new(memory), Box::new(Fixed("고정값"))];
+    for (i, backend) in backends.iter().enumerate() {
+        println!("backend {}: {:?}", i, backend.lookup_boxed("lang").await);
+    }
+
+    // 고르는 기준
+    //   구현 타입을 컴파일 때 앎 (제네릭으로 충분) → async fn / impl Future + Send - 할당 없음
+    //   실행 중에 고름 (설정 파일, 플러그인) → BoxFuture (직접 또는 async-trait)
+    //   둘 다 → 이 절처럼 두 트레이트 + 포괄 구현
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_and_dynamic_storages_agree() {
+        block_on(async {
+            let memory = MemoryStorage::default();
+            Storage::put(&memory, "k", String::from("v")).await;
+            assert_eq!(Storage::get(&memory, "k").await.as_deref(), Some("v"));
+
+            let dynamic: Box<dyn DynStorage> = Box::new(memory);
+            assert_eq!(dynamic.lookup_boxed("k").await.as_deref(), Some("v"));
+            assert_eq!(dynamic.lookup_boxed("missing").await, None);
+            assert_eq!(Slow { delay_ms: 1 }.handle("a").await, "A");
+        });
+    }
+}
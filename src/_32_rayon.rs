@@ -0,0 +1,305 @@
+// ============================================================================
+// 32. rayon 으로 데이터 병렬 처리
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. std::execution::par 정책 = rayon 의 par_iter - 다만 rayon 은 라이브러리 (표준 아님)
+//    iter() 를 par_iter() 로 바꾸는 것만으로 11장의 파이프라인이 병렬로
+// 2. C++ 의 병렬 알고리즘은 람다가 데이터 레이스를 일으켜도 컴파일됨 (정의되지 않은 동작)
+//    rayon 은 클로저가 Send + Sync 여야 함 - 공유 상태를 몰래 고치면 컴파일 에러
+// 3. join(a, b) = 작업 훔치기(work stealing) 기반 분할 정복 - TBB 의 parallel_invoke 와 비슷
+// 4. 스레드 풀은 전역 하나가 기본, 필요하면 ThreadPoolBuilder 로 따로 (TBB 의 task_arena)
+// ============================================================================
+
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use rayon::prelude::*;
+
+use crate::microbench;
+
+// 절 목록 (실행 순서) - cargo run -- 32:par_iter 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("par_iter", par_iter),
+    ("join", join),
+    ("thread_pools", thread_pools),
+    ("benchmark", benchmark),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "32"
+    }
+
+    fn name(&self) -> &'static str {
+        "rayon 으로 데이터 병렬 처리"
+    }
+
+    fn description(&self) -> &'static str {
+        "11장의 이터레이터 파이프라인을 par_iter 로 병렬화, join 분할 정복, 스레드 풀, 순차 대 병렬 측정"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["rayon", "par_iter", "join", "ThreadPool", "work stealing", "벤치마크"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// par_iter
+// ----------------------------------------------------------------------------
+
+fn par_iter() {
+    println!("--- par_iter ---");
+
+    // C++ 에서는:
+    // std::transform_reduce(std::execution::par, v.begin(), v.end(), 0L, std::plus{}, [](int x) { return x * x; });
+
+    let v: Vec<i64> = (1..=10_000).collect();
+
+    // 11장의 파이프라인 그대로 - iter() → par_iter()
+    let sequential: i64 = v.iter().filter(|x| *x % 2 == 1).map(|x| x * x).sum();
+    let parallel: i64 = v.par_iter().filter(|x| *x % 2 == 1).map(|x| x * x).sum();
+    println!("홀수 제곱 합: 순차 {}, 병렬 {}", sequential, parallel);
+
+    // collect 는 순서를 지킴 - 나눠서 계산해도 결과는 원래 순서대로 이어 붙임
+    let squares: Vec<i64> = v.par_iter().take(5).map(|x| x * x).collect();
+    println!("collect (순서 유지): {:?}", squares);
+
+    // for_each 는 순서가 없음 - 출력하면 실행마다 달라짐 → 결과를 모아서 정렬
+    let words = ["rayon", "par", "iter", "join", "pool"];
+    let mut lengths: Vec<(usize, &str)> = words.par_iter().map(|w| (w.len(), *w)).collect();
+    lengths.sort_unstable();
+    println!("길이순: {:?}", lengths);
+
+    // reduce - 결합 법칙이 성립하는 연산만 (나누는 방식이 실행마다 다를 수 있음)
+    let longest = words.par_iter().copied().reduce(|| "", |a, b| if b.len() > a.len() { b } else { a });
+    println!("가장 긴 단어: {}", longest);
+
+    // 가변 병렬 처리 - 원소마다 겹치지 않으므로 안전
+    let mut grid = vec![1u32; 8];
+    grid.par_iter_mut().enumerate().for_each(|(i, x)| *x += i as u32);
+    println!("par_iter_mut: {:?}", grid);
+
+    // 공유 카운터를 몰래 고치면 컴파일 에러:
+    //   let mut count = 0;
+    //   v.par_iter().for_each(|_| count += 1);   // error: cannot assign to `count`, as it is a captured variable in a `Fn` closure
+    // → count() 로 세거나, AtomicUsize, 또는 fold + reduce
+}
+
+// ----------------------------------------------------------------------------
+// join - 분할 정복
+// ----------------------------------------------------------------------------
+
+// 병렬 퀵정렬 - 나눈 두 쪽을 join 으로 동시에 (25장의 split_at_mut 이 겹치지 않음을 보장)
+fn quicksort<T: Ord + Send>(v: &mut [T]) {
+    // 작은 조각은 나누는 비용이 더 큼 - 순차로
+    if v.len() <= 32 {
+        v.sort_unstable();
+        return;
+    }
+    let mid = partition(v);
+    let (left, right) = v.split_at_mut(mid);
+    rayon::join(|| quicksort(left), || quicksort(&mut right[1..]));
+}
+
+// 마지막 원소를 피벗으로 - 피벗의 최종 위치를 돌려줌
+fn partition<T: Ord>(v: &mut [T]) -> usize {
+    let pivot = v.len() - 1;
+    let mut store = 0;
+    for i in 0..pivot {
+        if v[i] <= v[pivot] {
+            v.swap(i, store);
+            store += 1;
+        }
+    }
+    v.swap(store, pivot);
+    store
+}
+
+fn join() {
+    println!("\n--- join ---");
+
+    // C++ 에서는 (TBB):
+    // tbb::parallel_invoke([&] { quicksort(left); }, [&] { quicksort(right); });
+    // 두 람다가 같은 원소를 건드려도 컴파일됨
+
+    // join 은 두 작업을 "할 수 있으면" 동시에 - 놀고 있는 스레드가 없으면 그냥 차례로 (훔치기)
+    //   그래서 재귀 깊이만큼 스레드가 늘지 않음 - thread::spawn 을 재귀로 부르는 것과 다름
+    let (sum, max) = rayon::join(|| (1..=1000u64).sum::<u64>(), || (1..=1000u64).max());
+    println!("join 두 결과: 합 {}, 최댓값 {:?}", sum, max);
+
+    // 결정적인 섞인 데이터 (선형 합동 생성기)
+    let mut state = 12345u64;
+    let mut data: Vec<u32> = (0..2_000)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 33) as u32 % 10_000
+        })
+        .collect();
+    let mut expected = data.clone();
+    expected.sort_unstable();
+    quicksort(&mut data);
+    println!("병렬 퀵정렬 {}개 - 정렬됨? {}, 앞 5개 {:?}", data.len(), data == expected, &data[..5]);
+
+    // 실무에서는 직접 쓰지 말고 par_sort / par_sort_unstable
+    let mut again = expected.clone();
+    again.reverse();
+    again.par_sort_unstable();
+    println!("par_sort_unstable 도 같은 결과? {}", again == expected);
+}
+
+// ----------------------------------------------------------------------------
+// 스레드 풀
+// ----------------------------------------------------------------------------
+
+fn thread_pools() {
+    println!("\n--- 스레드 풀 ---");
+
+    // 전역 풀 - 처음 쓸 때 만들어짐, 기본 크기는 논리 CPU 수 (RAYON_NUM_THREADS 로 바꿀 수 있음)
+    println!("전역 풀 스레드 수: {}", rayon::current_num_threads());
+
+    // 따로 만든 풀 - 일부 작업의 병렬도를 제한하거나 다른 작업과 섞이지 않게
+    //   C++ TBB: tbb::task_arena arena(2); arena.execute([&] { ... });
+    let pool = match rayon::ThreadPoolBuilder::new().num_threads(2).thread_name(|i| format!("worker-{}", i)).build() {
+        Ok(pool) => pool,
+        Err(e) => {
+            println!("풀을 만들 수 없음: {}", e);
+            return;
+        }
+    };
+
+    // install 안의 par_iter 는 이 풀에서 실행
+    let (threads, total) = pool.install(|| {
+        let total: u64 = (1..=100_000u64).into_par_iter().map(|x| x % 7).sum();
+        (rayon::current_num_threads(), total)
+    });
+    println!("install 안: 스레드 {}개, 결과 {}", threads, total);
+
+    // 스레드 이름 - 어느 풀에서 돌았는지 확인
+    let mut names: Vec<String> = pool.install(|| {
+        (0..4).into_par_iter().map(|_| std::thread::current().name().unwrap_or("?").to_string()).collect()
+    });
+    names.sort();
+    names.dedup();
+    println!("실행한 스레드 이름 (중복 제거): {:?}", names);
+
+    // 주의
+    //   1. tokio 의 async 태스크 안에서 무거운 par_iter 를 돌리면 실행기 스레드가 막힘
+    //      → tokio::task::spawn_blocking 이나 rayon::spawn + oneshot 채널로 넘김
+    //   2. 락을 잡은 채 par_iter 를 부르면 같은 락을 기다리는 작업과 데드락 위험
+    //   3. 너무 작은 작업(원소마다 몇 ns)은 나누는 비용이 더 큼 - with_min_len 으로 조각 크기 조절
+}
+
+// ----------------------------------------------------------------------------
+// 순차 vs 병렬 측정
+// ----------------------------------------------------------------------------
+
+// 현실적인 일: 접근 로그를 파싱해서 상태 코드별 개수와 평균 지연
+// "GET /items/42 200 35ms"
+fn make_log(lines: usize) -> Vec<String> {
+    let paths = ["/", "/items", "/items/42", "/login", "/search?q=rust"];
+    let statuses = [200, 200, 200, 304, 404, 500];
+    (0..lines)
+        .map(|i| {
+            format!(
+                "{} {} {} {}ms",
+                if i % 5 == 0 { "POST" } else { "GET" },
+                paths[i % paths.len()],
+                statuses[(i * 7) % statuses.len()],
+                (i * 37) % 250 + 1
+            )
+        })
+        .collect()
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Stats {
+    // 상태 코드 → (개수, 지연 합)
+    by_status: HashMap<u16, (u64, u64)>,
+}
+
+impl Stats {
+    fn add_line(mut self, line: &str) -> Stats {
+        let mut parts = line.split_whitespace().skip(2);
+        let status = parts.next().and_then(|s| s.parse::<u16>().ok());
+        let latency = parts.next().and_then(|s| s.trim_end_matches("ms").parse::<u64>().ok());
+        if let (Some(status), Some(latency)) = (status, latency) {
+            let entry = self.by_status.entry(status).or_default();
+            entry.0 += 1;
+            entry.1 += latency;
+        }
+        self
+    }
+
+    // 두 부분 결과를 합침 - 병렬 fold 뒤의 reduce 에서
+    fn merge(mut self, other: Stats) -> Stats {
+        for (status, (count, total)) in other.by_status {
+            let entry = self.by_status.entry(status).or_default();
+            entry.0 += count;
+            entry.1 += total;
+        }
+        self
+    }
+}
+
+fn analyze_sequential(log: &[String]) -> Stats {
+    log.iter().fold(Stats::default(), |acc, line| acc.add_line(line))
+}
+
+// fold 는 작업 조각마다 Stats 하나, reduce 가 조각들을 합침 (map-reduce)
+fn analyze_parallel(log: &[String]) -> Stats {
+    log.par_iter().fold(Stats::default, |acc, line| acc.add_line(line)).reduce(Stats::default, Stats::merge)
+}
+
+// 로그 줄 수 - 디버그 빌드의 테스트도 빨리 끝나도록
+const LOG_LINES: usize = 20_000;
+const ITERATIONS: u32 = 2;
+
+fn benchmark() {
+    println!("\n--- 순차 vs 병렬 측정 ---");
+
+    let log = make_log(LOG_LINES);
+    let seq = analyze_sequential(&log);
+    let par = analyze_parallel(&log);
+    println!("같은 결과? {}", seq == par);
+
+    let mut statuses: Vec<_> = seq.by_status.iter().collect();
+    statuses.sort();
+    for (status, (count, total)) in statuses {
+        println!("  {}: {}건, 평균 {}ms", status, count, total / count);
+    }
+
+    println!("{}", microbench::build_profile());
+    println!("로그 {}줄 분석을 {}번씩, 스레드 {}개", LOG_LINES, ITERATIONS, rayon::current_num_threads());
+    let results = [
+        microbench::measure("순차 (iter + fold)", ITERATIONS, || analyze_sequential(black_box(&log))),
+        microbench::measure("병렬 (par_iter + fold/reduce)", ITERATIONS, || analyze_parallel(black_box(&log))),
+    ];
+    println!("{}", microbench::table(&results));
+
+    // 흔히 보는 결과 (릴리스 빌드, 8코어): 병렬이 4~6배 빠름 - 코어 수만큼은 안 나옴
+    //   스레드가 1개(코어 하나, 또는 RAYON_NUM_THREADS=1)면 둘이 거의 같음
+    //   나누고 합치는 비용, HashMap 병합, 메모리 대역폭
+    // 줄 수를 100 으로 줄이면 병렬이 더 느려지기도 함 - 작업이 작으면 순차가 낫다
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let log = make_log(500);
+        assert_eq!(analyze_sequential(&log), analyze_parallel(&log));
+
+        let mut v: Vec<i32> = (0..300).rev().collect();
+        quicksort(&mut v);
+        assert!(v.windows(2).all(|w| w[0] <= w[1]));
+    }
+}
@@ -0,0 +1,412 @@
+// ============================================================================
+// 33. crossbeam 채널과 동시성 도구
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. std::sync::mpsc 는 수신자가 하나 (Receiver 는 Clone 이 아님)
+//    crossbeam 채널은 송신자도 수신자도 복제 가능 (MPMC) - 작업 큐를 그대로 만듦
+// 2. select! = 여러 채널 중 먼저 준비된 것 - C++ 표준에는 없음 (Go 의 select)
+// 3. ArrayQueue/SegQueue = 락 없는 큐 - boost::lockfree::queue 와 비슷, 기다리는 기능은 없음
+// 4. C++ 에서 condition_variable 로 짜던 것 대부분은 Rust 에서 더 좁은 도구가 있음
+//    (채널, Barrier, WaitGroup, park/unpark) - Condvar 는 그 어디에도 안 맞을 때
+// ============================================================================
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Barrier, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crossbeam::channel::{self, after, bounded, never, select, tick, unbounded, TrySendError};
+use crossbeam::queue::{ArrayQueue, SegQueue};
+use crossbeam::sync::WaitGroup;
+
+// 절 목록 (실행 순서) - cargo run -- 33:channels 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("channels", channels),
+    ("select", select),
+    ("queues", queues),
+    ("condvar_mapping", condvar_mapping),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "33"
+    }
+
+    fn name(&self) -> &'static str {
+        "crossbeam 채널과 동시성 도구"
+    }
+
+    fn description(&self) -> &'static str {
+        "std::sync::mpsc 와 crossbeam 채널 비교, select!, 락 없는 큐, condition_variable 을 어떤 도구로 옮길지"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["crossbeam", "MPMC 채널", "select!", "ArrayQueue", "Condvar", "WaitGroup"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 채널 - std::sync::mpsc 와 crossbeam
+// ----------------------------------------------------------------------------
+
+// 작업자 여럿이 한 수신자에서 작업을 나눠 가져감 - 각 작업은 정확히 한 번 처리됨
+// 돌려주는 값: 작업자마다 처리한 작업 수
+fn work_queue(jobs: u32, workers: usize) -> Vec<usize> {
+    let (tx, rx) = unbounded::<u32>();
+    for job in 0..jobs {
+        tx.send(job).unwrap();
+    }
+    // 송신자를 모두 버리면 큐가 비었을 때 수신자의 for 가 끝남
+    drop(tx);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                let rx = rx.clone(); // std 의 Receiver 라면 여기서 컴파일 에러
+                s.spawn(move || rx.iter().count())
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+fn channels() {
+    println!("--- 채널 ---");
+
+    // 13장의 std::sync::mpsc - 송신자는 복제되지만 수신자는 하나
+    //   let (tx, rx) = mpsc::channel();
+    //   let rx2 = rx.clone();   // error: no method named `clone` found for struct `Receiver`
+    // 작업 큐를 만들려면 Arc<Mutex<Receiver<T>>> 로 감싸야 했음 - 받을 때마다 락
+    let (tx, rx) = mpsc::sync_channel::<u32>(1);
+    tx.send(1).unwrap();
+    println!("std sync_channel(1) 가득 찬 뒤 try_send: {:?}", tx.try_send(2).is_err());
+    drop(rx);
+
+    // crossbeam - 수신자도 Clone (Multi-Producer Multi-Consumer)
+    // C++ 에서는:
+    // std::mutex m; std::condition_variable cv; std::queue<int> q;   // + 작업자마다 wait 루프
+    let counts = work_queue(1000, 4);
+    println!("작업자 4명이 나눠 처리: 합 {} (작업자별 개수는 실행마다 다름)", counts.iter().sum::<usize>());
+
+    // unbounded - 무제한 (std 의 channel 과 같음), 생산자가 빠르면 메모리가 계속 늘어남
+    // bounded(n) - 가득 차면 send 가 기다림 = 배압(backpressure)
+    let (tx, rx) = bounded::<&str>(2);
+    tx.send("a").unwrap();
+    tx.send("b").unwrap();
+    match tx.try_send("c") {
+        Ok(()) => println!("보냄"),
+        Err(TrySendError::Full(v)) => println!("bounded(2) 가득 참 - {:?} 를 돌려받음", v),
+        Err(TrySendError::Disconnected(v)) => println!("수신자 없음 - {:?}", v),
+    }
+    // send_timeout - 정해진 시간만 기다림
+    println!("send_timeout 5ms 뒤 실패? {}", tx.send_timeout("c", Duration::from_millis(5)).is_err());
+    println!("받은 것: {:?}", rx.try_iter().collect::<Vec<_>>());
+
+    // bounded(0) - 랑데부: 받는 쪽이 있을 때까지 send 가 끝나지 않음 (sync_channel(0) 과 같음)
+    let (tx, rx) = bounded::<u32>(0);
+    thread::scope(|s| {
+        s.spawn(move || tx.send(42).unwrap());
+        println!("랑데부로 받음: {}", rx.recv().unwrap());
+    });
+
+    // 둘의 차이 정리
+    //   std::sync::mpsc              crossbeam::channel
+    //   channel() / sync_channel(n)  unbounded() / bounded(n)
+    //   수신자 하나                   수신자 복제 가능
+    //   select 없음                  select!, Select
+    //   표준 - 의존성 없음             외부 크레이트 (Rust 1.67 부터 std 의 구현이 crossbeam 기반)
+}
+
+// ----------------------------------------------------------------------------
+// select!
+// ----------------------------------------------------------------------------
+
+fn select() {
+    println!("\n--- select! ---");
+
+    // C++ 에서는: 여러 큐를 동시에 기다리는 표준 방법이 없음
+    //   큐마다 condvar 를 두고 바쁘게 돌거나, 메시지를 variant 로 묶어 큐 하나로 합침
+
+    // 데이터와 종료 신호를 함께 기다리기
+    let (data_tx, data_rx) = unbounded::<u32>();
+    let (quit_tx, quit_rx) = bounded::<()>(1);
+    thread::scope(|s| {
+        s.spawn(move || {
+            for i in 1..=5 {
+                data_tx.send(i).unwrap();
+                thread::sleep(Duration::from_millis(1));
+            }
+            quit_tx.send(()).unwrap();
+        });
+
+        let mut sum = 0;
+        let mut data_rx = data_rx;
+        loop {
+            // 준비된 가지가 여럿이면 무작위로 하나 - 순서를 가정하지 말 것
+            select! {
+                recv(data_rx) -> msg => match msg {
+                    Ok(v) => sum += v,
+                    // 송신자가 사라진 채널은 계속 "준비됨" → never() 로 바꿔서 가지를 끔
+                    Err(_) => data_rx = never(),
+                },
+                recv(quit_rx) -> _ => break,
+            }
+        }
+        // quit 이 먼저 뽑힐 수 있으므로 남은 데이터를 마저 받음
+        sum += data_rx.try_iter().sum::<u32>();
+        println!("데이터 합 {}, 종료 신호로 끝남", sum);
+    });
+
+    // 시간 제한 - after(d) 는 d 뒤에 한 번 값을 내는 채널
+    let (_tx, rx) = unbounded::<u32>();
+    select! {
+        recv(rx) -> msg => println!("받음: {:?}", msg),
+        recv(after(Duration::from_millis(5))) -> _ => println!("5ms 동안 아무것도 안 옴"),
+    }
+
+    // default - 아무것도 준비 안 됐으면 바로 (try_recv 를 여러 채널에)
+    let (tx, rx) = unbounded::<u32>();
+    select! {
+        recv(rx) -> msg => println!("받음: {:?}", msg),
+        default => println!("default - 기다리지 않음"),
+    }
+    tx.send(7).unwrap();
+    println!("보낸 뒤 try_recv: {:?}", rx.try_recv());
+
+    // send 도 가지가 될 수 있음 - 자리가 먼저 나는 쪽으로
+    let (fast_tx, fast_rx) = bounded::<u32>(1);
+    let (slow_tx, _slow_rx) = bounded::<u32>(0);
+    select! {
+        send(fast_tx, 1) -> _ => println!("fast 에 보냄: {:?}", fast_rx.recv()),
+        send(slow_tx, 1) -> _ => println!("slow 에 보냄"),
+    }
+
+    // tick(d) - 주기적인 신호 (타이머 스레드 없이)
+    let ticker = tick(Duration::from_millis(2));
+    let deadline = after(Duration::from_millis(11));
+    let mut ticks = 0;
+    loop {
+        select! {
+            recv(ticker) -> _ => ticks += 1,
+            recv(deadline) -> _ => break,
+        }
+    }
+    // 횟수는 스케줄러에 따라 다름 (대략 5번)
+    println!("11ms 동안 2ms 틱을 받았나? {}", ticks > 0);
+
+    // 채널 수가 실행 중에 정해지면 select! 대신 channel::Select 로 하나씩 등록
+    let receivers: Vec<_> = (0..3).map(|_| unbounded::<usize>()).collect();
+    receivers[2].0.send(2).unwrap();
+    let mut sel = channel::Select::new();
+    for (_, rx) in &receivers {
+        sel.recv(rx);
+    }
+    let op = sel.select();
+    let index = op.index();
+    println!("Select 로 준비된 채널: {}번, 값 {:?}", index, op.recv(&receivers[index].1));
+}
+
+// ----------------------------------------------------------------------------
+// 락 없는 큐
+// ----------------------------------------------------------------------------
+
+// ArrayQueue 로 만든 버퍼 풀 - 할당을 재사용, 가득 차면 그냥 버림
+struct BufferPool {
+    free: ArrayQueue<Vec<u8>>,
+}
+
+impl BufferPool {
+    fn new(capacity: usize) -> Self {
+        BufferPool { free: ArrayQueue::new(capacity) }
+    }
+
+    fn get(&self) -> Vec<u8> {
+        self.free.pop().unwrap_or_else(|| Vec::with_capacity(1024))
+    }
+
+    fn put(&self, mut buf: Vec<u8>) {
+        buf.clear();
+        // 풀이 가득 차면 Err(buf) - 받아서 버림 (drop)
+        let _ = self.free.push(buf);
+    }
+}
+
+fn queues() {
+    println!("\n--- 락 없는 큐 ---");
+
+    // C++ 에서는: boost::lockfree::queue<int> q(128);  q.push(1);  q.pop(x);
+    // ArrayQueue - 크기 고정, 미리 할당, push 가 가득 차면 값을 돌려줌
+    let q = ArrayQueue::new(2);
+    q.push('a').unwrap();
+    q.push('b').unwrap();
+    println!("가득 찬 큐에 push: {:?}", q.push('c'));
+    // force_push - 가장 오래된 것을 밀어내고 넣음 (고리 버퍼)
+    println!("force_push 로 밀려난 값: {:?}", q.force_push('c'));
+    println!("pop: {:?}, {:?}, {:?}", q.pop(), q.pop(), q.pop());
+
+    // SegQueue - 크기 제한 없음, 필요할 때 조각(segment) 단위로 늘어남
+    let seg = SegQueue::new();
+    thread::scope(|s| {
+        for t in 0..4 {
+            let seg = &seg; // &SegQueue 를 여러 스레드가 공유 - 메서드가 &self 라 Mutex 없이
+            s.spawn(move || {
+                for i in 0..250 {
+                    seg.push(t * 1000 + i);
+                }
+            });
+        }
+    });
+    println!("SegQueue 에 4 스레드가 넣은 개수: {}", seg.len());
+
+    // 큐는 기다리지 않음 - 비어 있으면 pop 이 None 을 바로 돌려줌
+    //   소비자가 기다려야 하면 채널을, 기다리지 않고 있으면 꺼내는 곳(풀, 작업 훔치기)에 큐를
+    let pool = BufferPool::new(2);
+    let mut buf = pool.get();
+    buf.extend_from_slice(b"hello");
+    let ptr = buf.as_ptr();
+    pool.put(buf);
+    let again = pool.get();
+    println!("버퍼 풀: 다시 받은 버퍼가 같은 할당? {}, 길이 {}", again.as_ptr() == ptr, again.len());
+}
+
+// ----------------------------------------------------------------------------
+// condition_variable 은 어디로
+// ----------------------------------------------------------------------------
+
+// C++ 코드를 그대로 옮긴 생산자-소비자 - Mutex + Condvar
+// 돌려주는 값: 소비자가 받은 값의 합
+fn condvar_queue(items: u32) -> u32 {
+    let state = (Mutex::new((VecDeque::new(), false)), Condvar::new());
+    thread::scope(|s| {
+        let consumer = s.spawn(|| {
+            let (lock, cv) = &state;
+            let mut sum = 0;
+            loop {
+                // C++: cv.wait(lock, [&] { return !q.empty() || done; });
+                let mut guard = cv.wait_while(lock.lock().unwrap(), |(q, done)| q.is_empty() && !*done).unwrap();
+                match guard.0.pop_front() {
+                    Some(v) => sum += v,
+                    None => return sum, // 비었고 done
+                }
+            }
+        });
+
+        let (lock, cv) = &state;
+        for i in 1..=items {
+            lock.lock().unwrap().0.push_back(i);
+            cv.notify_one();
+        }
+        lock.lock().unwrap().1 = true;
+        cv.notify_all();
+        consumer.join().unwrap()
+    })
+}
+
+fn condvar_mapping() {
+    println!("\n--- condition_variable 은 어디로 ---");
+
+    // C++ 개발자의 반사 신경: "기다려야 하네 → mutex + condition_variable"
+    // Rust 에도 Condvar 가 있고 그대로 옮길 수 있음 (wait_while 이 가짜 깨어남 루프를 대신)
+    println!("Mutex + Condvar 생산자-소비자: 합 {}", condvar_queue(100));
+
+    // 하지만 대부분은 더 좁은 도구가 있음 - 실수할 여지(notify 빠뜨림, 조건 확인 누락)가 적음
+    //
+    //   C++ 에서 condvar 로 하던 일                        Rust
+    //   큐에 넣고 소비자 깨우기                             채널 (mpsc, crossbeam unbounded)
+    //   가득 차면 생산자 재우기 (condvar 두 개)              bounded(n)
+    //   "준비됐다" 플래그를 모두에게 알리기 (notify_all)       송신자 drop → 모든 수신자의 recv 가 Err
+    //   N 개 작업이 끝날 때까지 (C++20 std::latch)          thread::scope, WaitGroup
+    //   모두 같은 지점에서 만나기 (C++20 std::barrier)       std::sync::Barrier
+    //   특정 스레드 하나 깨우기 (C++20 atomic::wait/notify)  thread::park / Thread::unpark
+    //   여러 필드에 걸친 복잡한 조건                          Mutex + Condvar 그대로
+    //   async 코드 안에서                                  tokio::sync::Notify, watch (17장)
+
+    // 채널로 같은 생산자-소비자 - 종료 플래그와 notify 가 사라짐
+    let (tx, rx) = unbounded();
+    thread::scope(|s| {
+        s.spawn(move || (1..=100u32).for_each(|i| tx.send(i).unwrap()));
+        println!("채널 생산자-소비자: 합 {}", rx.iter().sum::<u32>());
+    });
+
+    // "준비됐다"를 모두에게 - 값을 보내지 않고 송신자를 닫기만
+    let (ready_tx, ready_rx) = bounded::<()>(0);
+    let started = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..3 {
+            let ready_rx = ready_rx.clone();
+            let started = &started;
+            s.spawn(move || {
+                let _ = ready_rx.recv(); // 닫히면 Err 로 깨어남
+                started.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        drop(ready_tx);
+    });
+    println!("닫힌 채널로 깨어난 스레드: {}", started.load(Ordering::SeqCst));
+
+    // WaitGroup - C++20 latch 처럼 "N 개가 끝날 때까지", N 을 미리 정하지 않아도 됨 (복제할 때마다 +1)
+    let wg = WaitGroup::new();
+    let done = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..4 {
+            let wg = wg.clone();
+            let done = &done;
+            s.spawn(move || {
+                done.fetch_add(1, Ordering::SeqCst);
+                drop(wg); // 끝났음을 알림
+            });
+        }
+        wg.wait(); // 복제본이 모두 drop 될 때까지
+        println!("WaitGroup 대기 후 끝난 작업: {}", done.load(Ordering::SeqCst));
+    });
+
+    // Barrier - 단계를 맞춤 (모두 1단계를 끝내야 2단계로)
+    let barrier = Barrier::new(3);
+    let phase_one = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..3 {
+            s.spawn(|| {
+                phase_one.fetch_add(1, Ordering::SeqCst);
+                barrier.wait();
+                // 여기서는 세 스레드 모두 1단계를 마쳤음이 보장됨
+                assert_eq!(phase_one.load(Ordering::SeqCst), 3);
+            });
+        }
+    });
+    println!("Barrier 뒤 1단계 완료 수: {}", phase_one.load(Ordering::SeqCst));
+
+    // park/unpark - 스레드 하나를 재우고 깨우기 (가짜 깨어남이 있으므로 조건과 함께)
+    let flag = AtomicUsize::new(0);
+    thread::scope(|s| {
+        let sleeper = s.spawn(|| {
+            while flag.load(Ordering::Acquire) == 0 {
+                thread::park();
+            }
+            "깨어남"
+        });
+        flag.store(1, Ordering::Release);
+        sleeper.thread().unpark();
+        println!("park/unpark: {}", sleeper.join().unwrap());
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_job_is_taken_exactly_once() {
+        let counts = work_queue(500, 3);
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts.iter().sum::<usize>(), 500);
+        assert_eq!(condvar_queue(10), 55);
+    }
+}
@@ -0,0 +1,413 @@
+// ============================================================================
+// 34. 스레드 풀 직접 만들기 (캡스톤)
+// ============================================================================
+// 9장(에러 처리), 12장(스마트 포인터), 13장(동시성), 7장(트레이트 객체)을 모아 작업자 스레드 풀을 만듦
+//   - 작업 큐: 채널 하나를 작업자들이 Arc<Mutex<Receiver>> 로 나눠 씀
+//   - execute 로 작업을 넣고, submit 은 결과를 받을 손잡이(JobHandle)를 돌려줌
+//   - 풀이 drop 되면 큐를 닫고 남은 작업을 모두 끝낸 뒤 작업자를 join (정상 종료)
+// 32장(rayon), 33장(crossbeam)이 실무에서 대신해 주는 일을 손으로 해 보는 장
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 의 std::function<void()> 작업 = Box<dyn FnOnce() + Send + 'static>
+//    다른 스레드로 보내도 되는지(Send), 빌린 것이 없는지('static)가 타입에 드러남
+// 2. C++ 풀은 mutex + condition_variable + stop 플래그 + queue 를 직접 맞춤
+//    Rust 는 채널이 그 셋을 대신 - 송신자를 drop 하면 "멈춤" 신호
+// 3. 소멸자(~ThreadPool)에서 notify_all + join = Drop 에서 송신자 drop + join
+// 4. 작업의 panic 은 catch_unwind 로 잡아 작업자를 살림 - C++ 에서 예외를 잡는 것과 비슷
+// ============================================================================
+
+use std::fmt;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+// 절 목록 (실행 순서) - cargo run -- 34:job_queue 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("job_queue", job_queue),
+    ("workers", workers),
+    ("results", results),
+    ("graceful_shutdown", graceful_shutdown),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "34"
+    }
+
+    fn name(&self) -> &'static str {
+        "스레드 풀 직접 만들기 (캡스톤)"
+    }
+
+    fn description(&self) -> &'static str {
+        "7, 9, 12, 13장을 모아 작업 큐, 결과 손잡이, Drop 으로 정상 종료하는 스레드 풀 만들기"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["스레드 풀", "Box<dyn FnOnce>", "Arc<Mutex<Receiver>>", "Drop", "정상 종료", "catch_unwind"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 작업 큐 - 무엇을 보내는가
+// ----------------------------------------------------------------------------
+
+// 작업 = 한 번 실행하고 끝나는 클로저
+//   FnOnce  - 캡처한 값을 소비해도 됨 (한 번만 부르므로)
+//   Send    - 작업자 스레드로 보냄
+//   'static - 작업이 언제 실행될지 모르므로 지역 변수를 빌리면 안 됨
+// 클로저마다 타입이 다르므로 Box<dyn ..> 로 크기를 맞춰 채널 하나에 담음
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+fn job_queue() {
+    println!("--- 작업 큐 ---");
+
+    // C++ 에서는:
+    // std::queue<std::function<void()>> jobs;   // + mutex, condition_variable
+    // std::function 은 복사 가능해야 해서 unique_ptr 을 캡처한 람다는 못 담음 (C++23 move_only_function)
+
+    // 서로 다른 클로저를 한 채널로 - 작업자 하나가 차례로 실행
+    let (tx, rx) = mpsc::channel::<Job>();
+    let owned = String::from("캡처한 String");
+    tx.send(Box::new(|| println!("  작업 1: 캡처 없음"))).unwrap();
+    tx.send(Box::new(move || println!("  작업 2: {} (move 로 소유)", owned))).unwrap();
+    let boxed = Box::new(7);
+    tx.send(Box::new(move || println!("  작업 3: Box<i32> 를 소비 {}", *boxed + 1))).unwrap();
+    drop(tx);
+
+    // 이 장의 나머지도 같음 - 받는 쪽이 스레드가 될 뿐
+    for job in rx {
+        job();
+    }
+
+    // 빌린 값은 보낼 수 없음:
+    //   let local = vec![1, 2, 3];
+    //   tx.send(Box::new(|| println!("{:?}", local)));   // error: `local` does not live long enough
+    // → move 로 소유권을 넘기거나 Arc 로 공유 (13장의 thread::spawn 과 같은 규칙)
+}
+
+// ----------------------------------------------------------------------------
+// 작업자와 풀
+// ----------------------------------------------------------------------------
+
+// 9장의 관례: 에러를 열거형 하나로 모으고 Display + Error + From 구현
+#[derive(Debug)]
+pub enum PoolError {
+    ZeroSize,
+    Spawn(io::Error),
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoolError::ZeroSize => write!(f, "작업자 수는 1 이상이어야 함"),
+            PoolError::Spawn(e) => write!(f, "작업자 스레드를 만들 수 없음: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PoolError::Spawn(e) => Some(e),
+            PoolError::ZeroSize => None,
+        }
+    }
+}
+
+// ? 가 thread::Builder::spawn 의 io::Error 를 PoolError 로 변환
+impl From<io::Error> for PoolError {
+    fn from(e: io::Error) -> Self {
+        PoolError::Spawn(e)
+    }
+}
+
+// 작업자 하나 - 스레드가 끝나면 자기가 실행한 작업 수를 돌려줌
+// thread 가 Option 인 이유: Drop 에서 &mut self 로 JoinHandle 을 꺼내(take) join 하려고
+struct Worker {
+    id: usize,
+    thread: Option<JoinHandle<usize>>,
+}
+
+impl Worker {
+    fn spawn(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>, panicked: Arc<AtomicUsize>) -> io::Result<Worker> {
+        // thread::spawn 과 달리 Builder 는 실패를 io::Result 로 돌려줌 (panic 대신)
+        let thread = thread::Builder::new().name(format!("pool-worker-{}", id)).spawn(move || {
+            let mut done = 0;
+            loop {
+                // 락은 이 문장 끝에서 풀림 - 작업을 실행하는 동안 다른 작업자가 다음 작업을 받을 수 있음
+                //   while let Ok(job) = receiver.lock().unwrap().recv() { job(); }
+                //   로 쓰면 락 가드가 while 본문 끝까지 살아서 작업이 하나씩만 실행됨 (흔한 실수)
+                let message = receiver.lock().unwrap().recv();
+                match message {
+                    Ok(job) => {
+                        // 작업이 panic 해도 작업자는 살아서 다음 작업으로
+                        //   락을 잡지 않은 채 실행하므로 Mutex 가 오염(poison)되지도 않음
+                        if panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+                            panicked.fetch_add(1, Ordering::SeqCst);
+                        }
+                        done += 1;
+                    }
+                    // 송신자가 모두 사라짐 = 풀이 닫힘, 큐에 남은 작업은 이미 다 받음
+                    Err(_) => return done,
+                }
+            }
+        })?;
+        Ok(Worker { id, thread: Some(thread) })
+    }
+}
+
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    // Option 인 이유: Drop 에서 먼저 송신자를 drop 해야 작업자의 recv 가 끝남
+    sender: Option<mpsc::Sender<Job>>,
+    panicked: Arc<AtomicUsize>,
+}
+
+impl ThreadPool {
+    // C++: explicit ThreadPool(size_t n) - 실패하면 예외, Rust 는 Result
+    pub fn new(size: usize) -> Result<ThreadPool, PoolError> {
+        if size == 0 {
+            return Err(PoolError::ZeroSize);
+        }
+        let (sender, receiver) = mpsc::channel();
+        // 수신자는 하나뿐이라 (mpsc) 작업자들이 Arc 로 공유하고 Mutex 로 번갈아 씀
+        let receiver = Arc::new(Mutex::new(receiver));
+        let panicked = Arc::new(AtomicUsize::new(0));
+
+        let mut workers = Vec::with_capacity(size);
+        for id in 0..size {
+            // 중간에 실패하면 ? 로 돌아가며 pool 이 만들어지지 않음
+            //   이미 만든 작업자는 sender 가 drop 되면서 recv 가 끝나 스스로 종료
+            workers.push(Worker::spawn(id, Arc::clone(&receiver), Arc::clone(&panicked))?);
+        }
+        Ok(ThreadPool { workers, sender: Some(sender), panicked })
+    }
+
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    // 작업을 큐에 넣고 바로 돌아옴
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        // 작업자는 panic 을 잡으므로 풀이 살아 있는 동안 수신자가 사라지지 않음
+        self.sender.as_ref().unwrap().send(Box::new(f)).unwrap();
+    }
+
+    // 결과를 돌려주는 작업 - 작업마다 채널을 하나씩 (C++ 의 std::packaged_task + future)
+    pub fn submit<F, T>(&self, f: F) -> JobHandle<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        // 받는 쪽이 손잡이를 버렸으면 send 가 실패 - 결과를 버리면 되므로 무시
+        self.execute(move || {
+            let _ = tx.send(f());
+        });
+        JobHandle { rx }
+    }
+
+    // 지금까지 panic 한 작업 수
+    pub fn panicked(&self) -> usize {
+        self.panicked.load(Ordering::SeqCst)
+    }
+
+    // 명시적으로 닫고 작업자마다 실행한 작업 수를 돌려받음 - drop 과 같은 일에 결과가 붙음
+    pub fn shutdown(mut self) -> Vec<usize> {
+        self.close()
+    }
+
+    // 큐를 닫고 작업자를 모두 join - shutdown 과 Drop 이 함께 씀, 두 번 불려도 안전
+    fn close(&mut self) -> Vec<usize> {
+        drop(self.sender.take());
+        self.workers
+            .iter_mut()
+            .filter_map(|worker| {
+                let thread = worker.thread.take()?;
+                // 작업자 스레드 자체가 panic 하는 일은 없음 (작업의 panic 은 잡으므로) - 혹시 있으면 0 으로
+                Some(thread.join().unwrap_or_else(|_| {
+                    eprintln!("작업자 {} 가 비정상 종료", worker.id);
+                    0
+                }))
+            })
+            .collect()
+    }
+}
+
+// 결과 손잡이 - join 은 결과가 올 때까지 기다림
+pub struct JobHandle<T> {
+    rx: mpsc::Receiver<T>,
+}
+
+impl<T> JobHandle<T> {
+    // 작업이 panic 했으면 tx 가 보내지 못하고 drop 되므로 None
+    pub fn join(self) -> Option<T> {
+        self.rx.recv().ok()
+    }
+}
+
+fn workers() {
+    println!("\n--- 작업자와 풀 ---");
+
+    // C++ 에서는:
+    // class ThreadPool {
+    //     std::vector<std::thread> workers; std::queue<std::function<void()>> jobs;
+    //     std::mutex m; std::condition_variable cv; bool stop = false;
+    // };
+    // 작업자 루프: unique_lock lk(m); cv.wait(lk, [&] { return stop || !jobs.empty(); }); ...
+
+    match ThreadPool::new(0) {
+        Ok(_) => println!("만들어짐"),
+        Err(e) => println!("ThreadPool::new(0): {}", e),
+    }
+
+    let pool = match ThreadPool::new(3) {
+        Ok(pool) => pool,
+        Err(e) => {
+            println!("풀을 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    println!("작업자 {}명", pool.size());
+
+    // 작업은 'static 이어야 하므로 공유 카운터는 Arc 로
+    let counter = Arc::new(AtomicUsize::new(0));
+    for _ in 0..10 {
+        let counter = Arc::clone(&counter);
+        pool.execute(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+    // 작업자가 어떻게 나눠 가졌는지는 실행마다 다름 - 합만 확인
+    let per_worker = pool.shutdown();
+    println!("실행한 작업: {}, 작업자별 합 {}", counter.load(Ordering::SeqCst), per_worker.iter().sum::<usize>());
+}
+
+// ----------------------------------------------------------------------------
+// 결과 돌려받기
+// ----------------------------------------------------------------------------
+
+fn results() {
+    println!("\n--- 결과 돌려받기 ---");
+
+    // C++ 에서는:
+    // std::packaged_task<int()> task([] { return 42; });
+    // std::future<int> f = task.get_future();   pool.push(std::move(task));   f.get();
+
+    let pool = match ThreadPool::new(4) {
+        Ok(pool) => pool,
+        Err(e) => {
+            println!("풀을 만들 수 없음: {}", e);
+            return;
+        }
+    };
+
+    // 일을 나눠 넣고 손잡이를 순서대로 join - 결과 순서는 넣은 순서
+    let chunks: Vec<Vec<u64>> = (0..4).map(|i| (i * 250 + 1..=(i + 1) * 250).collect()).collect();
+    let handles: Vec<JobHandle<u64>> = chunks.into_iter().map(|chunk| pool.submit(move || chunk.iter().sum())).collect();
+    let partial: Vec<u64> = handles.into_iter().filter_map(JobHandle::join).collect();
+    println!("부분 합 {:?} → 합 {}", partial, partial.iter().sum::<u64>());
+
+    // 작업이 panic 하면 - 손잡이는 None, 작업자는 살아서 다음 작업을 받음
+    let bad = pool.submit(|| -> u64 { panic!("작업 안에서 panic (의도한 것)") });
+    println!("panic 한 작업의 결과: {:?}", bad.join());
+    let after = pool.submit(|| 2 + 2);
+    println!("그 뒤의 작업: {:?}, panic 한 작업 수 {}", after.join(), pool.panicked());
+
+    // 타입이 다른 결과도 - submit 이 제네릭
+    let text = pool.submit(|| "안녕".repeat(2));
+    println!("String 결과: {:?}", text.join());
+}
+
+// ----------------------------------------------------------------------------
+// 정상 종료 (Drop)
+// ----------------------------------------------------------------------------
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // shutdown 으로 이미 닫았으면 sender 도 thread 도 None 이라 아무 일도 안 함
+        self.close();
+    }
+}
+
+fn graceful_shutdown() {
+    println!("\n--- 정상 종료 ---");
+
+    // C++ 에서는:
+    // ~ThreadPool() {
+    //     { std::lock_guard lk(m); stop = true; }
+    //     cv.notify_all();
+    //     for (auto& t : workers) t.join();   // join 을 빼먹으면 std::terminate
+    // }
+
+    // 스코프를 벗어날 때 drop → 큐를 닫고 남은 작업을 모두 실행한 뒤 join
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    {
+        let pool = match ThreadPool::new(2) {
+            Ok(pool) => pool,
+            Err(e) => {
+                println!("풀을 만들 수 없음: {}", e);
+                return;
+            }
+        };
+        for i in 0..6 {
+            let finished = Arc::clone(&finished);
+            pool.execute(move || {
+                thread::sleep(std::time::Duration::from_millis(1));
+                finished.lock().unwrap().push(i);
+            });
+        }
+        println!("작업 6개를 넣고 곧바로 풀을 버림");
+    } // ← 여기서 Drop::drop - 작업이 끝날 때까지 기다림
+
+    let mut finished = finished.lock().unwrap().clone();
+    finished.sort_unstable();
+    println!("drop 뒤 끝난 작업: {:?}", finished);
+
+    // 순서가 중요한 이유
+    //   1. 송신자를 먼저 drop - 작업자의 recv 가 Err 로 끝날 수 있게
+    //   2. 그다음 join - 순서를 바꾸면 작업자가 영원히 recv 에서 기다리고 join 도 영원히 (데드락)
+    // 작업자가 '지금 하던 일만' 끝내고 멈추게 하려면 큐를 비우지 말고 멈춤 메시지를 먼저 보내야 함
+    //   enum Message { Run(Job), Stop } 을 보내고 작업자 수만큼 Stop (Rust 책의 예전 판 방식)
+
+    // 이 풀에 없는 것 - 실무에서는 rayon(32장), crossbeam(33장), tokio 의 spawn_blocking 을 씀
+    //   작업 훔치기, 스레드 수 자동 조절, 작업 우선순위, 결과 없는 작업의 panic 전달
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_runs_every_job_and_survives_panics() {
+        assert!(matches!(ThreadPool::new(0), Err(PoolError::ZeroSize)));
+
+        let pool = ThreadPool::new(3).unwrap();
+        let counter = Arc::new(AtomicUsize::new(0));
+        for _ in 0..50 {
+            let counter = Arc::clone(&counter);
+            pool.execute(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        assert_eq!(pool.submit(|| -> i32 { panic!("테스트용 panic") }).join(), None);
+        assert_eq!(pool.submit(|| 6 * 7).join(), Some(42));
+
+        let per_worker = pool.shutdown();
+        assert_eq!(per_worker.len(), 3);
+        assert_eq!(per_worker.iter().sum::<usize>(), 52);
+        assert_eq!(counter.load(Ordering::SeqCst), 50);
+    }
+}
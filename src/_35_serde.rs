@@ -0,0 +1,447 @@
+// ============================================================================
+// 35. serde 직렬화
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. C++ 에는 리플렉션이 없어서 (C++26 전까지) 필드마다 to_json/from_json 을 손으로 쓰거나
+//    nlohmann::json 의 NLOHMANN_DEFINE_TYPE_INTRUSIVE 같은 매크로에 필드를 다시 나열
+//    Rust 는 #[derive(Serialize, Deserialize)] - 컴파일 타임에 코드 생성 (15장의 derive 매크로)
+// 2. 타입과 형식이 분리됨 - 같은 derive 로 JSON, TOML, YAML, bincode ... (serde_json, toml 크레이트)
+// 3. 역직렬화 결과가 타입 - 필드가 없거나 타입이 틀리면 Result::Err (예외가 아님)
+// 4. 이 저장소의 content/*.toml 과 서버의 JSON 응답(src/content.rs, src/api.rs)이 모두 이 방식
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+// 절 목록 (실행 순서) - cargo run -- 35:derive_basics 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("derive_basics", derive_basics),
+    ("json_round_trip", json_round_trip),
+    ("toml_round_trip", toml_round_trip),
+    ("attributes", attributes),
+    ("enum_representations", enum_representations),
+    ("errors", errors),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "35"
+    }
+
+    fn name(&self) -> &'static str {
+        "serde 직렬화"
+    }
+
+    fn description(&self) -> &'static str {
+        "derive 로 JSON/TOML 왕복, rename/default/skip 속성, 열거형 표현 방식, 역직렬화 에러 다루기"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["serde", "Serialize/Deserialize", "serde_json", "toml", "#[serde(...)]", "untagged"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// derive 기본
+// ----------------------------------------------------------------------------
+
+// 필드 타입이 모두 Serialize/Deserialize 면 derive 만으로 끝
+//   String, 정수, Vec, Option, HashMap/BTreeMap, 다른 derive 한 구조체 ...
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Book {
+    title: String,
+    author: String,
+    year: u16,
+    tags: Vec<String>,
+    rating: Option<f32>,
+}
+
+fn sample_book() -> Book {
+    Book {
+        title: "The Rust Programming Language".to_string(),
+        author: "Klabnik, Nichols".to_string(),
+        year: 2018,
+        tags: vec!["rust".to_string(), "입문".to_string()],
+        rating: Some(4.5),
+    }
+}
+
+fn derive_basics() {
+    println!("--- derive 기본 ---");
+
+    // C++ 에서는 (nlohmann::json):
+    // struct Book { std::string title; std::string author; int year; };
+    // NLOHMANN_DEFINE_TYPE_INTRUSIVE(Book, title, author, year)   // 필드를 다시 나열
+    // 필드를 추가하고 매크로에 빠뜨리면 조용히 직렬화에서 빠짐
+
+    let book = sample_book();
+    // to_string - 한 줄, to_string_pretty - 들여쓰기
+    let text = serde_json::to_string(&book).unwrap();
+    println!("JSON: {}", text);
+
+    // 역직렬화할 타입은 타입 표기로 알려 줌 (from_str::<Book> 또는 let book: Book)
+    let back: Book = serde_json::from_str(&text).unwrap();
+    println!("왕복 후 같음? {}", back == book);
+
+    // derive 가 만드는 것 (개념상):
+    //   impl Serialize for Book {
+    //       fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+    //           let mut st = s.serialize_struct("Book", 5)?;
+    //           st.serialize_field("title", &self.title)?; ...
+    //           st.end()
+    //       }
+    //   }
+    // Serializer 는 형식(JSON, TOML ...)이 구현 - 그래서 한 번의 derive 로 모든 형식에
+}
+
+// ----------------------------------------------------------------------------
+// JSON 왕복
+// ----------------------------------------------------------------------------
+
+fn json_round_trip() {
+    println!("\n--- JSON 왕복 ---");
+
+    let library = vec![
+        sample_book(),
+        Book { title: "Programming Rust".to_string(), author: "Blandy".to_string(), year: 2021, tags: vec![], rating: None },
+    ];
+    let pretty = serde_json::to_string_pretty(&library).unwrap();
+    println!("to_string_pretty:\n{}", pretty);
+
+    let back: Vec<Book> = serde_json::from_str(&pretty).unwrap();
+    println!("Vec<Book> 왕복 후 같음? {}", back == library);
+
+    // 맵 - 키는 문자열로 (BTreeMap 이라 키 순서가 일정)
+    let mut by_year = BTreeMap::new();
+    for book in &library {
+        by_year.insert(book.year, book.title.clone());
+    }
+    println!("BTreeMap<u16, String>: {}", serde_json::to_string(&by_year).unwrap());
+
+    // 구조를 모를 때 - serde_json::Value (C++ 의 nlohmann::json 객체와 비슷)
+    let value = json!({ "title": "Rust in Action", "year": 2021, "tags": ["systems"] });
+    println!("Value 에서 꺼내기: title={}, year={}", value["title"], value["year"]);
+    println!("없는 키는 Null: {}", value["missing"]);
+
+    // Value → 타입 (모자란 필드가 있으면 Err)
+    let partial: Result<Book, _> = serde_json::from_value(value.clone());
+    println!("from_value::<Book>: {}", partial.map(|b| b.title).unwrap_or_else(|e| e.to_string()));
+
+    // 타입 → Value - 필드 하나를 고쳐서 보내기
+    let mut as_value = serde_json::to_value(sample_book()).unwrap();
+    as_value["year"] = json!(2023);
+    println!("고친 year: {}", as_value["year"]);
+}
+
+// ----------------------------------------------------------------------------
+// TOML 왕복
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Catalog {
+    name: String,
+    version: u32,
+    // 구조체 Vec 은 TOML 의 [[books]] 테이블 배열
+    books: Vec<Book>,
+}
+
+fn toml_round_trip() {
+    println!("\n--- TOML 왕복 ---");
+
+    // 같은 derive 를 그대로 - 크레이트만 toml 로
+    let catalog = Catalog { name: "추천 도서".to_string(), version: 1, books: vec![sample_book()] };
+    let text = toml::to_string(&catalog).unwrap();
+    println!("toml::to_string:\n{}", text);
+
+    let back: Catalog = toml::from_str(&text).unwrap();
+    println!("왕복 후 같음? {}", back == catalog);
+
+    // 사람이 쓴 TOML - 주석, 빠진 Option 필드 (rating 이 없으면 None)
+    let hand_written = r#"
+        # 손으로 쓴 설정
+        name = "직접 쓴 목록"
+        version = 2
+
+        [[books]]
+        title = "Rust for Rustaceans"
+        author = "Gjengset"
+        year = 2021
+        tags = ["중급"]
+    "#;
+    match toml::from_str::<Catalog>(hand_written) {
+        Ok(c) => println!("읽음: {} - 책 {}권, 첫 책 rating {:?}", c.name, c.books.len(), c.books[0].rating),
+        Err(e) => println!("에러: {}", e),
+    }
+
+    // TOML 에 없는 것: null - Option 이 None 이면 키를 아예 쓰지 않음
+    // 순서 제약: 단순 값이 테이블보다 먼저 와야 함 - 직렬화기가 알아서 맞춤
+}
+
+// ----------------------------------------------------------------------------
+// #[serde(...)] 속성
+// ----------------------------------------------------------------------------
+
+fn default_port() -> u16 {
+    8080
+}
+
+// 바깥 형식(camelCase JSON)과 Rust 이름(snake_case)을 맞추고, 없는 값은 기본값으로
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServerConfig {
+    bind_address: String,
+    // 없으면 함수가 만든 값
+    #[serde(default = "default_port")]
+    port: u16,
+    // 없으면 Default::default() - bool 은 false
+    #[serde(default)]
+    verbose: bool,
+    // 필드 하나만 다른 이름 (rename_all 보다 우선)
+    #[serde(rename = "max-clients")]
+    max_clients: u32,
+    // None 이면 출력에서 뺌 (null 대신)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    motd: Option<String>,
+    // 실행 중에만 쓰는 값 - 읽지도 쓰지도 않음 (타입이 Default 여야 함)
+    #[serde(skip)]
+    connections: usize,
+}
+
+// 값 검사가 필요하면 try_from - 역직렬화 중에 TryFrom 이 실패하면 serde 에러로
+//   into 는 쓸 때 &self 를 복제해서 변환하므로 Clone 도 필요
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+struct Email(String);
+
+impl TryFrom<String> for Email {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.contains('@') {
+            Ok(Email(s))
+        } else {
+            Err(format!("이메일에 @ 가 없음: {}", s))
+        }
+    }
+}
+
+impl From<Email> for String {
+    fn from(e: Email) -> String {
+        e.0
+    }
+}
+
+#[derive(Debug, Deserialize)]
+// 모르는 필드가 있으면 에러 - 설정 파일의 오타를 잡음 (기본은 조용히 무시)
+#[serde(deny_unknown_fields)]
+struct Contact {
+    name: String,
+    email: Email,
+}
+
+fn attributes() {
+    println!("\n--- #[serde(...)] 속성 ---");
+
+    // 최소한의 입력 - 나머지는 기본값
+    let config: ServerConfig = serde_json::from_str(r#"{ "bindAddress": "0.0.0.0", "max-clients": 100 }"#).unwrap();
+    println!("읽은 설정: {:?}", config);
+
+    // 다시 쓰면 이름이 바깥 형식으로, motd 는 None 이라 빠지고, connections 는 skip
+    println!("쓴 설정: {}", serde_json::to_string(&config).unwrap());
+
+    // rename_all 로 받을 수 있는 값: lowercase, UPPERCASE, camelCase, PascalCase, snake_case,
+    //   SCREAMING_SNAKE_CASE, kebab-case, SCREAMING-KEBAB-CASE
+    // 그 밖에 자주 쓰는 것
+    //   #[serde(alias = "addr")]       - 예전 이름도 받음 (읽을 때만)
+    //   #[serde(flatten)]              - 안쪽 구조체의 필드를 바깥에 펼침
+    //   #[serde(with = "모듈")]          - 이 필드만 직접 만든 직렬화 함수로
+
+    let ok: Result<Contact, _> = serde_json::from_str(r#"{ "name": "kim", "email": "kim@example.com" }"#);
+    println!("올바른 이메일: {:?}", ok.map(|c| (c.name, c.email)));
+    let bad: Result<Contact, _> = serde_json::from_str(r#"{ "name": "lee", "email": "lee.example.com" }"#);
+    println!("@ 없는 이메일: {}", bad.map(|c| c.name).unwrap_or_else(|e| e.to_string()));
+    let typo: Result<Contact, _> = serde_json::from_str(r#"{ "name": "park", "emial": "park@example.com" }"#);
+    println!("필드 오타: {}", typo.map(|c| c.name).unwrap_or_else(|e| e.to_string()));
+}
+
+// ----------------------------------------------------------------------------
+// 열거형 표현 방식
+// ----------------------------------------------------------------------------
+
+// 기본: 바깥 태그 (externally tagged) - {"Circle":{"radius":1.0}}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum External {
+    Circle { radius: f64 },
+    Rect { w: f64, h: f64 },
+    Empty,
+}
+
+// 안쪽 태그 (internally tagged) - {"kind":"Circle","radius":1.0}  (REST API 에서 흔함)
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum Internal {
+    Circle { radius: f64 },
+    Rect { w: f64, h: f64 },
+}
+
+// 이웃 태그 (adjacently tagged) - {"t":"Circle","c":{"radius":1.0}}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "t", content = "c")]
+enum Adjacent {
+    Circle { radius: f64 },
+    Label(String),
+}
+
+// 태그 없음 (untagged) - 모양만 보고 위에서부터 맞는 것 (C++ 의 std::variant 에 값을 넣어 보는 것과 비슷)
+//   port = 8080 과 port = "http" 를 둘 다 받고 싶을 때
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Port {
+    Number(u16),
+    Service(String),
+    Range { from: u16, to: u16 },
+}
+
+#[derive(Debug, Deserialize)]
+struct Listen {
+    port: Port,
+}
+
+fn enum_representations() {
+    println!("\n--- 열거형 표현 방식 ---");
+
+    // C++ 에서는: std::variant 를 JSON 으로 - 태그를 어떻게 쓸지 직접 정하고 to_json 을 손으로
+
+    let shapes = [External::Circle { radius: 1.0 }, External::Rect { w: 2.0, h: 3.0 }, External::Empty];
+    println!("바깥 태그: {}", serde_json::to_string(&shapes).unwrap());
+    println!("안쪽 태그: {}", serde_json::to_string(&Internal::Circle { radius: 1.0 }).unwrap());
+    println!("이웃 태그: {}", serde_json::to_string(&Adjacent::Label("원".to_string())).unwrap());
+
+    // 안쪽 태그 - 태그가 어디 있든 읽음
+    let read: Internal = serde_json::from_str(r#"{ "w": 2.0, "kind": "Rect", "h": 1.5 }"#).unwrap();
+    println!("안쪽 태그 읽기: {:?}", read);
+
+    // untagged - 같은 필드에 모양이 다른 값
+    for text in ["port = 8080", r#"port = "http""#, "port = { from = 8000, to = 8100 }"] {
+        match toml::from_str::<Listen>(text) {
+            Ok(listen) => println!("{:<35} → {:?}", text, listen.port),
+            Err(e) => println!("{:<35} → 에러 {}", text, e),
+        }
+    }
+    // 맞는 것이 없으면 에러 메시지가 뭉뚱그려짐 - 어느 변형이 왜 안 맞았는지 알려 주지 않음
+    let none: Result<Port, _> = serde_json::from_str("true");
+    println!("untagged 에 맞는 것 없음: {}", none.map(|p| format!("{:?}", p)).unwrap_or_else(|e| e.to_string()));
+    // 순서 주의 - 위에서부터 시도하므로 무엇이든 받는 변형(예: Raw(serde_json::Value))을 앞에 두면
+    //   아래 변형은 절대 선택되지 않음
+}
+
+// ----------------------------------------------------------------------------
+// 에러 다루기
+// ----------------------------------------------------------------------------
+
+// 9장의 관례: 형식마다 다른 에러를 열거형 하나로 모아 ? 로 전파
+#[derive(Debug)]
+enum LoadError {
+    Json(serde_json::Error),
+    Toml(toml::de::Error),
+    UnknownFormat(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Json(e) => write!(f, "JSON: {}", e),
+            // toml 의 에러 메시지는 여러 줄 (입력 줄과 ^ 표시) - 첫 줄만
+            LoadError::Toml(e) => write!(f, "TOML: {}", e.message()),
+            LoadError::UnknownFormat(ext) => write!(f, "모르는 형식: {}", ext),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(e: serde_json::Error) -> Self {
+        LoadError::Json(e)
+    }
+}
+
+impl From<toml::de::Error> for LoadError {
+    fn from(e: toml::de::Error) -> Self {
+        LoadError::Toml(e)
+    }
+}
+
+// 확장자로 형식을 고름 - src/content.rs 의 콘텐츠 읽기와 같은 모양
+fn load_book(extension: &str, text: &str) -> Result<Book, LoadError> {
+    let book = match extension {
+        "json" => serde_json::from_str(text)?,
+        "toml" => toml::from_str(text)?,
+        other => return Err(LoadError::UnknownFormat(other.to_string())),
+    };
+    Ok(book)
+}
+
+fn errors() {
+    println!("\n--- 에러 다루기 ---");
+
+    // C++ (nlohmann::json): j.at("year").get<int>() 가 json::type_error 예외를 던짐
+    // Rust: from_str 이 Result - 어디서 무엇이 틀렸는지가 에러 값에
+
+    let inputs = [
+        ("json", r#"{ "title": "A", "author": "B", "year": 2020, "tags": [] }"#),
+        ("json", r#"{ "title": "A", "author": "B", "year": "2020", "tags": [] }"#),
+        ("json", r#"{ "title": "A", "author": "B", "tags": [] }"#),
+        ("json", r#"{ "title": "A", "#),
+        ("toml", "title = \"A\"\nauthor = \"B\"\nyear = 99999\ntags = []"),
+        ("yaml", "title: A"),
+    ];
+    for (ext, text) in inputs {
+        match load_book(ext, text) {
+            Ok(book) => println!("[{}] 성공: {} ({})", ext, book.title, book.year),
+            Err(e) => println!("[{}] {}", ext, e),
+        }
+    }
+
+    // serde_json::Error 는 위치와 분류를 알려 줌
+    if let Err(e) = serde_json::from_str::<Book>("{\n  \"title\": 1\n}") {
+        println!("줄 {}, 열 {}, 분류 {:?}", e.line(), e.column(), e.classify());
+    }
+
+    // 실무 팁
+    //   - 외부 입력은 unwrap 하지 말고 에러를 사용자에게 (이 저장소의 `cargo run -- check` 처럼)
+    //   - 모르는 필드는 기본적으로 무시 - 오타를 잡으려면 deny_unknown_fields
+    //   - 숫자 범위(u16 등)도 타입이 검사 - year = 99999 는 u16 에 안 들어가서 에러
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_attributes() {
+        let book = sample_book();
+        assert_eq!(serde_json::from_str::<Book>(&serde_json::to_string(&book).unwrap()).unwrap(), book);
+        let catalog = Catalog { name: "c".to_string(), version: 1, books: vec![book] };
+        assert_eq!(toml::from_str::<Catalog>(&toml::to_string(&catalog).unwrap()).unwrap(), catalog);
+
+        let config: ServerConfig = serde_json::from_str(r#"{ "bindAddress": "::", "max-clients": 1 }"#).unwrap();
+        assert_eq!((config.port, config.verbose, config.motd.as_deref()), (8080, false, None));
+        assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"bindAddress":"::","port":8080,"verbose":false,"max-clients":1}"#);
+
+        assert_eq!(serde_json::from_str::<Port>("443").unwrap(), Port::Number(443));
+        assert_eq!(serde_json::from_str::<Port>(r#""https""#).unwrap(), Port::Service("https".to_string()));
+        assert!(serde_json::from_str::<Email>(r#""nobody""#).is_err());
+        assert!(matches!(load_book("toml", "title = 1"), Err(LoadError::Toml(_))));
+    }
+}
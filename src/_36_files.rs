@@ -0,0 +1,431 @@
+// ============================================================================
+// 36. 파일과 파일 시스템
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. std::filesystem::path = Path(빌린 것)/PathBuf(소유) - &str/String 과 같은 짝
+//    경로는 OsStr 이라 UTF-8 이 아닐 수 있음 → to_str() 이 Option
+// 2. fstream 의 상태 비트(failbit, badbit) 대신 모든 연산이 io::Result
+//    std::filesystem 의 "예외 아니면 error_code 오버로드" 두 벌이 Result 하나로
+// 3. 파일은 RAII - drop 되면 닫힘 (C++ 의 fstream 소멸자와 같음)
+//    다만 BufWriter 는 drop 중 쓰기 에러를 삼킴 → 중요하면 flush() 를 직접
+// 4. io::Error 에는 경로가 없음 - 어느 파일인지는 직접 붙여야 함 (9장의 에러 열거형)
+// ============================================================================
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// 절 목록 (실행 순서) - cargo run -- 36:paths 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("paths", paths),
+    ("read_write", read_write),
+    ("buffered_io", buffered_io),
+    ("directories", directories),
+    ("missing_files", missing_files),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "36"
+    }
+
+    fn name(&self) -> &'static str {
+        "파일과 파일 시스템"
+    }
+
+    fn description(&self) -> &'static str {
+        "std::fs 로 읽고 쓰기, Path/PathBuf, BufReader/BufWriter, 디렉터리 순회, 없는 파일 다루기"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["std::fs", "Path/PathBuf", "BufReader/BufWriter", "read_dir", "io::ErrorKind"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// 절마다 쓰는 임시 작업 디렉터리 - 테스트가 병렬로 돌아도 겹치지 않게 pid + 카운터
+//   19장의 TempDir 픽스처와 같은 생각 (여기서는 절 끝에서 직접 지움)
+fn scratch_dir(name: &str) -> io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rust-study-36-{}-{}-{}", name, std::process::id(), unique));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+// 출력에는 임시 디렉터리 아래 부분만 - 실행마다 같은 출력
+fn relative(path: &Path, base: &Path) -> String {
+    path.strip_prefix(base).unwrap_or(path).display().to_string()
+}
+
+// ----------------------------------------------------------------------------
+// Path 와 PathBuf
+// ----------------------------------------------------------------------------
+
+fn paths() {
+    println!("--- Path 와 PathBuf ---");
+
+    // C++ 에서는:
+    // std::filesystem::path p = "docs/notes/todo.txt";
+    // p.parent_path(); p.filename(); p.extension(); p / "more";
+
+    // Path 는 빌린 경로 (&str 처럼), 문자열 리터럴에서 바로
+    let p = Path::new("docs/notes/todo.txt");
+    println!("parent: {:?}", p.parent());
+    println!("file_name: {:?}, file_stem: {:?}, extension: {:?}", p.file_name(), p.file_stem(), p.extension());
+
+    // PathBuf 는 소유한 경로 (String 처럼) - join 은 새 PathBuf, push 는 제자리
+    let mut out = PathBuf::from("build");
+    out.push("reports");
+    let report = out.join("2026.csv");
+    println!("join: {}", report.display());
+    println!("with_extension: {}", report.with_extension("json").display());
+
+    // 조각으로 나누기 - 구분자(/ 또는 \)는 운영체제에 맞게
+    let parts: Vec<_> = p.components().map(|c| c.as_os_str().to_string_lossy().into_owned()).collect();
+    println!("components: {:?}", parts);
+
+    // 주의: 절대 경로를 join 하면 앞을 버림 (C++ 의 operator/ 와 같음)
+    #[expect(clippy::join_absolute_paths, reason = "앞을 버리는 함정을 보여 줌")]
+    let replaced = Path::new("base").join("/etc");
+    println!("\"base\".join(\"/etc\"): {}", replaced.display());
+
+    // 경로는 OsStr - 문자열로 바꿀 때 UTF-8 이 아닐 수 있음
+    //   to_str()          → Option<&str>       (아니면 None)
+    //   to_string_lossy() → Cow<str>           (아니면 � 로 바꿈)
+    //   display()         → 출력용 (lossy 와 같음)
+    println!("to_str: {:?}", p.to_str());
+
+    // 확장자로 고르기 - 대소문자 구분 없이 (OsStr::eq_ignore_ascii_case)
+    let files = ["main.rs", "README.md", "lib.RS", "Cargo.toml"];
+    let rust: Vec<_> = files.iter().filter(|f| Path::new(f).extension().is_some_and(|e| e.eq_ignore_ascii_case("rs"))).collect();
+    println!("Rust 파일: {:?}", rust);
+}
+
+// ----------------------------------------------------------------------------
+// 읽고 쓰기
+// ----------------------------------------------------------------------------
+
+fn read_write_in(dir: &Path) -> io::Result<()> {
+    let path = dir.join("hello.txt");
+
+    // 한 번에 쓰기/읽기 - 작은 파일은 이것으로 충분 (9장의 hello.txt 도)
+    // C++: std::ofstream(path) << "..."; std::ifstream in(path); std::stringstream ss; ss << in.rdbuf();
+    fs::write(&path, "안녕하세요\n두 번째 줄\n")?;
+    let text = fs::read_to_string(&path)?;
+    println!("read_to_string: {:?}", text);
+
+    // 바이트로 - UTF-8 이 아닌 파일 (read_to_string 은 UTF-8 이 아니면 InvalidData 에러)
+    let bytes = fs::read(&path)?;
+    println!("read: {} 바이트, 앞 3바이트 {:?}", bytes.len(), &bytes[..3]);
+
+    // 덧붙이기 - OpenOptions 로 모드를 고름 (C++ 의 std::ios::app)
+    let mut file = OpenOptions::new().append(true).open(&path)?;
+    writeln!(file, "덧붙인 줄")?;
+    drop(file); // 닫기 - 스코프를 벗어나도 같음
+    println!("덧붙인 뒤 줄 수: {}", fs::read_to_string(&path)?.lines().count());
+
+    // 없을 때만 만들기 - 있으면 AlreadyExists (덮어쓰기 사고 방지)
+    match OpenOptions::new().write(true).create_new(true).open(&path) {
+        Ok(_) => println!("새로 만듦"),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => println!("create_new: 이미 있음"),
+        Err(e) => return Err(e),
+    }
+
+    // File + Read/Write 트레이트 - 직접 버퍼를 다룰 때
+    let mut file = File::open(&path)?;
+    let mut first = [0u8; 5];
+    file.read_exact(&mut first)?;
+    // 한글은 UTF-8 로 3바이트 - 5바이트는 두 번째 글자 중간에서 잘림
+    println!("read_exact 5바이트: {:?}", String::from_utf8_lossy(&first));
+
+    // 메타데이터
+    let meta = fs::metadata(&path)?;
+    println!("metadata: 파일? {}, 크기 {} 바이트, 읽기 전용? {}", meta.is_file(), meta.len(), meta.permissions().readonly());
+    Ok(())
+}
+
+fn read_write() {
+    println!("\n--- 읽고 쓰기 ---");
+
+    // 절 하나에 파일 연산이 여럿 - ? 로 모으려고 io::Result 를 돌려주는 함수로 나눔
+    let dir = match scratch_dir("read-write") {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("임시 디렉터리를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = read_write_in(&dir) {
+        println!("에러: {}", e);
+    }
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// ----------------------------------------------------------------------------
+// 버퍼 입출력
+// ----------------------------------------------------------------------------
+
+// 줄 단위로 읽으며 세기 - 파일 전체를 메모리에 올리지 않음
+// R: BufRead 라서 파일, 표준 입력, 메모리(&[u8]) 모두 받음 - 테스트하기 쉬움
+fn count_matching<R: BufRead>(reader: R, needle: &str) -> io::Result<(usize, usize)> {
+    let mut total = 0;
+    let mut matched = 0;
+    for line in reader.lines() {
+        let line = line?;
+        total += 1;
+        if line.contains(needle) {
+            matched += 1;
+        }
+    }
+    Ok((total, matched))
+}
+
+fn buffered_io_in(dir: &Path) -> io::Result<()> {
+    let path = dir.join("log.txt");
+
+    // BufWriter - 작은 쓰기를 모아서 한 번에 (C++ 의 ofstream 은 기본이 버퍼링, File 은 아님)
+    //   File 에 writeln! 을 1000번 하면 시스템 호출 1000번
+    {
+        let mut out = BufWriter::new(File::create(&path)?);
+        for i in 0..1000 {
+            let level = if i % 10 == 0 { "WARN" } else { "INFO" };
+            writeln!(out, "{} 요청 {}", level, i)?;
+        }
+        // drop 도 flush 하지만 에러를 무시함 - 에러를 받으려면 직접
+        out.flush()?;
+    }
+
+    // BufReader - lines() 로 한 줄씩 (C++ 의 std::getline)
+    let (total, warnings) = count_matching(BufReader::new(File::open(&path)?), "WARN")?;
+    println!("BufReader: {}줄 중 WARN {}줄", total, warnings);
+
+    // 같은 함수를 메모리 데이터로 - &[u8] 도 BufRead
+    let (total, matched) = count_matching("a\nb WARN\nc".as_bytes(), "WARN")?;
+    println!("메모리에서: {}줄 중 {}줄", total, matched);
+
+    // lines() 는 줄마다 새 String - 큰 파일에서 할당을 줄이려면 read_line 으로 버퍼 재사용
+    let mut reader = BufReader::new(File::open(&path)?);
+    let mut line = String::new();
+    let mut longest = 0;
+    while reader.read_line(&mut line)? > 0 {
+        longest = longest.max(line.trim_end().len());
+        line.clear();
+    }
+    println!("read_line 으로 가장 긴 줄: {} 바이트", longest);
+    Ok(())
+}
+
+fn buffered_io() {
+    println!("\n--- 버퍼 입출력 ---");
+
+    let dir = match scratch_dir("buffered") {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("임시 디렉터리를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = buffered_io_in(&dir) {
+        println!("에러: {}", e);
+    }
+    let _ = fs::remove_dir_all(&dir);
+}
+
+// ----------------------------------------------------------------------------
+// 디렉터리
+// ----------------------------------------------------------------------------
+
+// 재귀로 모든 파일 - C++ 의 recursive_directory_iterator (실무에서는 walkdir 크레이트)
+// read_dir 의 순서는 운영체제마다 다름 → 모은 뒤 정렬
+fn list_files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    walk(root, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        // file_type() 은 보통 추가 시스템 호출 없이 (read_dir 이 이미 앎)
+        if entry.file_type()?.is_dir() {
+            walk(&entry.path(), files)?;
+        } else {
+            files.push(entry.path());
+        }
+    }
+    Ok(())
+}
+
+fn directories_in(root: &Path) -> io::Result<()> {
+    // C++: std::filesystem::create_directories(p) - 중간 디렉터리까지
+    fs::create_dir_all(root.join("src/bin"))?;
+    fs::create_dir_all(root.join("docs"))?;
+    fs::write(root.join("Cargo.toml"), "[package]\n")?;
+    fs::write(root.join("src/main.rs"), "fn main() {}\n")?;
+    fs::write(root.join("src/lib.rs"), "")?;
+    fs::write(root.join("src/bin/tool.rs"), "fn main() {}\n")?;
+    fs::write(root.join("docs/guide.md"), "# 안내\n")?;
+
+    // 한 단계만 - read_dir
+    let mut top: Vec<String> = fs::read_dir(root)?
+        .map(|entry| entry.map(|e| e.file_name().to_string_lossy().into_owned()))
+        .collect::<io::Result<_>>()?;
+    top.sort();
+    println!("read_dir: {:?}", top);
+
+    // 재귀
+    for file in &list_files(root)? {
+        println!("  {:<16} {} 바이트", relative(file, root), fs::metadata(file)?.len());
+    }
+
+    // 복사, 이름 바꾸기, 지우기
+    fs::copy(root.join("docs/guide.md"), root.join("docs/guide.bak"))?;
+    fs::rename(root.join("src/lib.rs"), root.join("src/util.rs"))?;
+    fs::remove_file(root.join("docs/guide.bak"))?;
+    // remove_dir 는 빈 디렉터리만, remove_dir_all 은 안의 것까지 (rm -rf - 조심)
+    println!("비어 있지 않은 디렉터리를 remove_dir: {:?}", fs::remove_dir(root.join("src")).map_err(|e| e.kind()));
+    fs::remove_dir_all(root.join("src/bin"))?;
+
+    let after = list_files(root)?;
+    println!("정리 뒤: {:?}", after.iter().map(|f| relative(f, root)).collect::<Vec<_>>());
+    Ok(())
+}
+
+fn directories() {
+    println!("\n--- 디렉터리 ---");
+
+    let root = match scratch_dir("dirs") {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("임시 디렉터리를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = directories_in(&root) {
+        println!("에러: {}", e);
+    }
+    let _ = fs::remove_dir_all(&root);
+}
+
+// ----------------------------------------------------------------------------
+// 없는 파일과 에러
+// ----------------------------------------------------------------------------
+
+// io::Error 에 경로를 붙인 에러 - "No such file or directory" 만으로는 어느 파일인지 모름
+#[derive(Debug)]
+struct FileError {
+    path: PathBuf,
+    source: io::Error,
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.source)
+    }
+}
+
+impl std::error::Error for FileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn read_with_path(path: &Path) -> Result<String, FileError> {
+    fs::read_to_string(path).map_err(|source| FileError { path: path.to_path_buf(), source })
+}
+
+// 흔한 패턴: 설정 파일이 없으면 기본값, 다른 에러(권한, 잘못된 UTF-8)는 그대로 전파
+fn read_or_default(path: &Path, default: &str) -> io::Result<String> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(default.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+fn missing_files() {
+    println!("\n--- 없는 파일과 에러 ---");
+
+    // C++ 에서는:
+    // std::ifstream in("missing.txt");
+    // if (!in) { /* 왜 실패했는지는 errno 를 따로 봐야 함 */ }
+    // std::filesystem::file_size("missing.txt");   // filesystem_error 예외
+
+    let dir = match scratch_dir("missing") {
+        Ok(dir) => dir,
+        Err(e) => {
+            println!("임시 디렉터리를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    let missing = dir.join("missing.txt");
+
+    // 에러의 종류 - kind() 로 나눠 처리 (9장)
+    match File::open(&missing) {
+        Ok(_) => println!("열림"),
+        Err(e) => println!("File::open: kind {:?}", e.kind()),
+    }
+
+    // 경로를 붙인 에러 - 출력에는 파일 이름만 (임시 디렉터리는 실행마다 다름)
+    if let Err(e) = read_with_path(&missing) {
+        println!("경로를 붙인 에러: ...{}{}", std::path::MAIN_SEPARATOR, relative(&e.path, &dir));
+        println!("  원인 kind: {:?}", e.source.kind());
+    }
+
+    // 없으면 기본값
+    match read_or_default(&missing, "기본 설정") {
+        Ok(text) => println!("read_or_default: {:?}", text),
+        Err(e) => println!("에러: {}", e),
+    }
+
+    // 있는지 먼저 묻기 - exists() 는 에러도 false 로 (권한 없음도 "없음")
+    //   try_exists() 는 모르는 경우를 Err 로 구분
+    println!("exists: {}, try_exists: {:?}", missing.exists(), missing.try_exists().map_err(|e| e.kind()));
+    // 확인 후 여는 것은 경쟁 조건(TOCTOU) - 그 사이에 지워질 수 있음
+    //   열어 보고 에러를 처리하는 것이 정석 (위의 read_or_default)
+
+    // UTF-8 이 아닌 파일을 문자열로 읽으면 InvalidData
+    let binary = dir.join("data.bin");
+    if fs::write(&binary, [0xff, 0xfe, 0x00]).is_ok() {
+        match fs::read_to_string(&binary) {
+            Ok(_) => println!("읽힘"),
+            Err(e) => println!("바이너리를 read_to_string: kind {:?}", e.kind()),
+        }
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walk_and_missing_files() -> io::Result<()> {
+        let root = scratch_dir("test")?;
+        fs::create_dir_all(root.join("a/b"))?;
+        fs::write(root.join("a/b/c.txt"), "c")?;
+        fs::write(root.join("z.txt"), "WARN\nok\nWARN")?;
+
+        let names: Vec<_> = list_files(&root)?.iter().map(|f| relative(f, &root).replace('\\', "/")).collect();
+        assert_eq!(names, ["a/b/c.txt", "z.txt"]);
+
+        assert_eq!(count_matching(BufReader::new(File::open(root.join("z.txt"))?), "WARN")?, (3, 2));
+        assert_eq!(read_or_default(&root.join("nope"), "d")?, "d");
+        let err = read_with_path(&root.join("nope")).unwrap_err();
+        assert_eq!(err.source.kind(), ErrorKind::NotFound);
+        assert!(err.to_string().contains("nope"));
+
+        fs::remove_dir_all(&root)
+    }
+}
@@ -0,0 +1,335 @@
+// ============================================================================
+// 37. std::net 으로 TCP/UDP 네트워킹
+// ============================================================================
+// C++20과의 핵심 차이점:
+// 1. C++ 표준에는 네트워킹이 없음 (Networking TS 는 표준이 되지 못함)
+//    보통 버클리 소켓(socket/bind/listen/accept)을 직접 쓰거나 Boost.Asio
+//    Rust 는 std::net 에 블로킹 TCP/UDP 가 들어 있음
+// 2. 소켓은 RAII - drop 되면 close (close(fd) 를 빠뜨릴 일이 없음)
+// 3. 반환값 -1 과 errno 대신 io::Result - ErrorKind 로 나눠 처리
+// 4. TcpStream 은 Read + Write - 파일(36장)과 같은 BufReader, write! 를 그대로 씀
+// 5. 연결마다 스레드 하나는 간단하지만 수천 개에서는 무거움 → 17장 async, 20장 채팅 서버
+// ============================================================================
+
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+// 절 목록 (실행 순서) - cargo run -- 37:tcp_echo 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("tcp_echo", tcp_echo),
+    ("thread_per_connection", thread_per_connection),
+    ("timeouts_and_errors", timeouts_and_errors),
+    ("udp", udp),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "37"
+    }
+
+    fn name(&self) -> &'static str {
+        "std::net 으로 TCP/UDP 네트워킹"
+    }
+
+    fn description(&self) -> &'static str {
+        "TcpListener/TcpStream 블로킹 에코 서버와 클라이언트, 연결마다 스레드, 시간 제한과 에러, UdpSocket"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["TcpListener", "TcpStream", "UdpSocket", "버클리 소켓", "연결마다 스레드", "read_timeout"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// 이 장의 모든 예제는 루프백(127.0.0.1)에서 포트 0 - 운영체제가 빈 포트를 골라 줌
+const LOCALHOST: &str = "127.0.0.1:0";
+
+// 응답이 없을 때 예제가 멈추지 않도록
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+// ----------------------------------------------------------------------------
+// TCP 에코 서버와 클라이언트
+// ----------------------------------------------------------------------------
+
+// 연결 하나 처리 - 받은 줄을 그대로 돌려주고, 상대가 쓰기를 닫으면(EOF) 끝
+// 돌려주는 값: 돌려준 줄 수
+fn handle_client(stream: TcpStream) -> io::Result<usize> {
+    // 읽기와 쓰기를 따로 쥐려고 복제 - 같은 소켓을 가리키는 두 핸들 (C++: dup(fd))
+    let reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    let mut lines = 0;
+    for line in reader.lines() {
+        writeln!(writer, "{}", line?)?;
+        lines += 1;
+    }
+    Ok(lines)
+}
+
+// 에코 서버 - 받아들이는 스레드 하나, 연결마다 처리 스레드 하나
+struct EchoServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    acceptor: Option<JoinHandle<usize>>,
+}
+
+impl EchoServer {
+    fn start() -> io::Result<EchoServer> {
+        // C++ 에서는:
+        // int fd = socket(AF_INET, SOCK_STREAM, 0);
+        // bind(fd, (sockaddr*)&addr, sizeof addr); listen(fd, SOMAXCONN);
+        // 셋이 bind 하나로 - 실패하면 Err (포트 사용 중이면 AddrInUse)
+        let listener = TcpListener::bind(LOCALHOST)?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let stop_flag = Arc::clone(&stop);
+        let acceptor = thread::spawn(move || {
+            let mut handlers = Vec::new();
+            // incoming() = accept() 를 부르는 끝없는 이터레이터
+            for stream in listener.incoming() {
+                // accept 는 블로킹이라 플래그만으로는 깨어나지 않음 - stop() 이 자기에게 접속해서 깨움
+                if stop_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => handlers.push(thread::spawn(move || handle_client(stream))),
+                    // 연결 하나의 실패(상대가 곧바로 끊음 등)로 서버를 멈추지 않음
+                    Err(e) => eprintln!("accept 실패: {}", e),
+                }
+            }
+            // 처리 중인 연결을 모두 기다림 - 받아들인 연결 수를 돌려줌
+            let served = handlers.len();
+            for handler in handlers {
+                let _ = handler.join();
+            }
+            served
+        });
+        Ok(EchoServer { addr, stop, acceptor: Some(acceptor) })
+    }
+
+    // 멈추고 처리한 연결 수를 돌려받음
+    fn stop(mut self) -> usize {
+        self.stop.store(true, Ordering::SeqCst);
+        // accept 에서 잠든 스레드를 깨우는 흔한 방법 - 자기 자신에게 접속 (C++ 에서도 같은 요령)
+        let _ = TcpStream::connect(self.addr);
+        self.acceptor.take().map_or(0, |t| t.join().unwrap_or(0))
+    }
+}
+
+// 클라이언트 - 줄들을 보내고 돌아온 것을 모두 읽음
+fn echo(addr: SocketAddr, lines: &[&str]) -> io::Result<Vec<String>> {
+    // C++: socket() + connect() - 여기서는 하나로
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    for line in lines {
+        writeln!(stream, "{}", line)?;
+    }
+    // 쓰기만 닫음(half-close) - 서버의 lines() 가 EOF 를 보고 끝남, 읽기는 계속 가능
+    // C++: shutdown(fd, SHUT_WR);
+    stream.shutdown(Shutdown::Write)?;
+    BufReader::new(stream).lines().collect()
+}
+
+fn tcp_echo() {
+    println!("--- TCP 에코 ---");
+
+    let server = match EchoServer::start() {
+        Ok(server) => server,
+        Err(e) => {
+            println!("서버를 시작할 수 없음: {}", e);
+            return;
+        }
+    };
+    // 주소의 포트는 실행마다 다름 - 출력하지 않음
+    println!("서버가 루프백에서 기다림 (포트는 운영체제가 고름)");
+
+    match echo(server.addr, &["안녕", "에코 서버", ""]) {
+        Ok(reply) => println!("돌아온 줄: {:?}", reply),
+        Err(e) => println!("클라이언트 에러: {}", e),
+    }
+
+    // TCP 는 바이트 흐름 - write 한 번이 read 한 번으로 온다는 보장이 없음
+    //   그래서 줄(\n), 길이 머리말 같은 "메시지 경계"를 직접 정해야 함 (여기서는 줄)
+    println!("처리한 연결: {}", server.stop());
+}
+
+// ----------------------------------------------------------------------------
+// 연결마다 스레드
+// ----------------------------------------------------------------------------
+
+fn thread_per_connection() {
+    println!("\n--- 연결마다 스레드 ---");
+
+    // C++ 에서는:
+    // while (true) { int client = accept(fd, ...); std::thread(handle, client).detach(); }
+    // detach 한 스레드는 기다릴 방법이 없음 - 여기서는 JoinHandle 을 모아 stop 에서 join
+
+    let server = match EchoServer::start() {
+        Ok(server) => server,
+        Err(e) => {
+            println!("서버를 시작할 수 없음: {}", e);
+            return;
+        }
+    };
+
+    // 클라이언트 5개가 동시에 - 서버도 연결마다 스레드라 서로 기다리지 않음
+    let replies: Vec<io::Result<Vec<String>>> = thread::scope(|s| {
+        let handles: Vec<_> = (0..5)
+            .map(|i| {
+                let addr = server.addr;
+                s.spawn(move || {
+                    let lines = [format!("클라이언트 {} 의 첫 줄", i), format!("클라이언트 {} 의 둘째 줄", i)];
+                    let refs: Vec<&str> = lines.iter().map(String::as_str).collect();
+                    echo(addr, &refs)
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    for (i, reply) in replies.iter().enumerate() {
+        match reply {
+            Ok(lines) => println!("클라이언트 {}: {}줄 돌아옴, 첫 줄 {:?}", i, lines.len(), lines.first()),
+            Err(e) => println!("클라이언트 {}: 에러 {}", i, e),
+        }
+    }
+    println!("처리한 연결: {}", server.stop());
+
+    // 한계
+    //   스레드마다 스택(기본 2MB 예약)과 문맥 전환 비용 - 연결 수천 개에서는 무거움
+    //   스레드 수를 제한하려면 34장의 스레드 풀에 handle_client 를 넣음
+    //   더 많은 연결은 비동기 I/O - tokio 의 TcpListener (17장, 20장 채팅 서버)
+}
+
+// ----------------------------------------------------------------------------
+// 시간 제한과 에러
+// ----------------------------------------------------------------------------
+
+fn timeouts_and_errors() {
+    println!("\n--- 시간 제한과 에러 ---");
+
+    // 아무도 듣지 않는 포트 - 리스너를 만들었다가 닫아서 빈 포트를 얻음 (클로저가 끝나면 l 이 drop)
+    let closed = TcpListener::bind(LOCALHOST).and_then(|l| l.local_addr());
+    if let Ok(addr) = closed {
+        // C++: connect() == -1 && errno == ECONNREFUSED
+        match TcpStream::connect_timeout(&addr, TIMEOUT) {
+            Ok(_) => println!("연결됨"),
+            Err(e) => println!("닫힌 포트에 연결: kind {:?}", e.kind()),
+        }
+    }
+
+    // 받아들이기만 하고 답하지 않는 서버 - 읽기 시간 제한이 없으면 영원히 기다림
+    let silent = match TcpListener::bind(LOCALHOST) {
+        Ok(listener) => listener,
+        Err(e) => {
+            println!("리스너를 만들 수 없음: {}", e);
+            return;
+        }
+    };
+    let result = silent.local_addr().and_then(|addr| {
+        let mut stream = TcpStream::connect(addr)?;
+        // C++: setsockopt(fd, SOL_SOCKET, SO_RCVTIMEO, &tv, sizeof tv);
+        stream.set_read_timeout(Some(Duration::from_millis(50)))?;
+        let mut buf = [0u8; 16];
+        stream.read(&mut buf)
+    });
+    match result {
+        Ok(n) => println!("{}바이트 읽음", n),
+        // 운영체제마다 kind 가 다름 (유닉스 WouldBlock, 윈도우 TimedOut) - 둘 다 시간 초과로
+        Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+            println!("50ms 안에 응답 없음 - 시간 초과")
+        }
+        Err(e) => println!("에러: {}", e),
+    }
+
+    // 상대가 끊으면 read 가 Ok(0) - 에러가 아니라 EOF (C++ 의 recv() == 0)
+    let eof = TcpListener::bind(LOCALHOST).and_then(|listener| {
+        let mut client = TcpStream::connect(listener.local_addr()?)?;
+        let (server_side, _) = listener.accept()?;
+        drop(server_side); // 서버 쪽 소켓을 닫음
+        let mut buf = [0u8; 16];
+        client.read(&mut buf)
+    });
+    println!("상대가 닫은 뒤 read: {:?}", eof.map_err(|e| e.kind()));
+
+    // 자주 보는 ErrorKind
+    //   ConnectionRefused - 듣는 프로그램이 없음
+    //   ConnectionReset   - 상대가 비정상 종료 (RST)
+    //   BrokenPipe        - 닫힌 연결에 쓰기 (C++ 에서는 SIGPIPE 로 프로세스가 죽을 수 있음 - Rust 는 무시하도록 설정)
+    //   AddrInUse         - 이미 쓰는 포트에 bind
+    //   WouldBlock        - 논블로킹 소켓(set_nonblocking)이나 시간 제한
+}
+
+// ----------------------------------------------------------------------------
+// UDP
+// ----------------------------------------------------------------------------
+
+fn udp() {
+    println!("\n--- UDP ---");
+
+    // C++ 에서는:
+    // int fd = socket(AF_INET, SOCK_DGRAM, 0); bind(...);
+    // recvfrom(fd, buf, sizeof buf, 0, (sockaddr*)&from, &len);
+    // sendto(fd, buf, n, 0, (sockaddr*)&from, len);
+
+    let result = (|| -> io::Result<()> {
+        let server = UdpSocket::bind(LOCALHOST)?;
+        let client = UdpSocket::bind(LOCALHOST)?;
+        server.set_read_timeout(Some(TIMEOUT))?;
+        client.set_read_timeout(Some(TIMEOUT))?;
+
+        // 연결이 없음 - 보낼 때마다 주소를 줌
+        client.send_to("ping".as_bytes(), server.local_addr()?)?;
+        let mut buf = [0u8; 1500];
+        let (n, from) = server.recv_from(&mut buf)?;
+        println!("서버가 받음: {:?} (보낸 쪽이 클라이언트? {})", String::from_utf8_lossy(&buf[..n]), from == client.local_addr()?);
+        server.send_to("pong".as_bytes(), from)?;
+
+        let (n, _) = client.recv_from(&mut buf)?;
+        println!("클라이언트가 받음: {:?}", String::from_utf8_lossy(&buf[..n]));
+
+        // 데이터그램은 경계가 유지됨 - 두 번 보내면 두 번 받음 (TCP 와 다름)
+        client.connect(server.local_addr()?)?; // 상대를 고정 - 이후 send/recv 만으로
+        client.send(b"one")?;
+        client.send(b"two")?;
+        let (a, _) = server.recv_from(&mut buf)?;
+        let first = String::from_utf8_lossy(&buf[..a]).into_owned();
+        let (b, _) = server.recv_from(&mut buf)?;
+        println!("두 데이터그램: {:?}, {:?}", first, String::from_utf8_lossy(&buf[..b]));
+
+        // 버퍼보다 큰 데이터그램은 잘림 - 남은 부분은 버려짐
+        client.send(b"0123456789")?;
+        let mut small = [0u8; 4];
+        let n = server.recv(&mut small)?;
+        println!("4바이트 버퍼로 받음: {:?}", String::from_utf8_lossy(&small[..n]));
+        Ok(())
+    })();
+    if let Err(e) = result {
+        println!("UDP 에러: {}", e);
+    }
+
+    // UDP 는 도착, 순서, 중복 없음을 보장하지 않음 - 루프백에서는 거의 잃지 않지만 실제 네트워크는 다름
+    //   재전송, 순서 번호가 필요하면 직접 (또는 QUIC 같은 프로토콜)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn echo_server_serves_every_client() {
+        let server = EchoServer::start().unwrap();
+        assert_eq!(echo(server.addr, &["a", "b c"]).unwrap(), ["a", "b c"]);
+        assert_eq!(echo(server.addr, &[]).unwrap(), Vec::<String>::new());
+        assert_eq!(server.stop(), 2);
+    }
+}
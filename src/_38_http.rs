@@ -0,0 +1,597 @@
+// ============================================================================
+// 38. 비동기 HTTP 서버와 클라이언트
+// ============================================================================
+// 17장(async)과 37장(std::net)을 이어 tokio 위에 작은 HTTP/1.1 서버와 클라이언트를 직접 만듦
+//   - 요청 줄과 머리(header)를 한 줄씩 읽고 Content-Length 만큼 본문을 읽음
+//   - 경로로 나눠(routing) 응답을 만들고, 연결마다 태스크 하나
+//   - 클라이언트는 같은 연결로 요청을 여러 번 (keep-alive)
+// 이 저장소의 `cargo run -- serve` (src/serve.rs) 도 같은 방식 - 실무에서는 hyper, axum, reqwest
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 는 Boost.Beast (Asio 위) - 콜백이나 코루틴, 버퍼 수명을 직접 관리
+//    Rust 는 async fn 안에서 평범한 코드처럼 read_line().await
+// 2. AsyncReadExt/AsyncWriteExt/AsyncBufReadExt = std::io 의 Read/Write/BufRead 와 같은 메서드의 async 판
+// 3. 연결마다 tokio::spawn - 스레드가 아니라 태스크라 수천 개도 가벼움 (37장의 연결마다 스레드와 비교)
+// 4. 요청 처리 함수가 AsyncRead + AsyncWrite 에 제네릭 - 메모리 안의 연결(duplex)로 테스트 (20장과 같음)
+// ============================================================================
+
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::task::JoinSet;
+
+// 절 목록 (실행 순서) - cargo run -- 38:parsing_requests 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("parsing_requests", || block_on(parsing_requests())),
+    ("routing", routing),
+    ("server", || block_on(server())),
+    ("client", || block_on(client())),
+];
+
+// 비동기 절은 각자 tokio 런타임을 만들어 실행 - 절 하나만 실행할 때도 그대로 동작
+fn block_on(future: impl std::future::Future<Output = ()>) {
+    // 런타임을 만들 수 없으면(스레드 생성 실패 등) 예제를 진행할 수 없으므로 unwrap
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "38"
+    }
+
+    fn name(&self) -> &'static str {
+        "비동기 HTTP 서버와 클라이언트"
+    }
+
+    fn description(&self) -> &'static str {
+        "tokio 로 HTTP/1.1 요청을 직접 파싱하고 경로로 나누는 서버, keep-alive 클라이언트, 연결마다 태스크"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["HTTP/1.1", "tokio::net", "AsyncBufReadExt", "라우팅", "keep-alive", "연결마다 태스크"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 요청 파싱
+// ----------------------------------------------------------------------------
+
+// 머리 줄 수와 본문 크기의 상한 - 없으면 악의적인 클라이언트가 메모리를 끝없이 쓰게 만듦
+const MAX_HEADERS: usize = 64;
+const MAX_BODY: usize = 64 * 1024;
+
+// 9장의 관례: 에러를 열거형 하나로 모으고 Display + Error + From 구현
+#[derive(Debug)]
+pub enum HttpError {
+    Io(io::Error),
+    // 클라이언트 잘못 - 400 으로 답하고 연결을 닫음
+    BadRequest(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Io(e) => write!(f, "입출력 에러: {}", e),
+            HttpError::BadRequest(why) => write!(f, "잘못된 요청: {}", why),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HttpError::Io(e) => Some(e),
+            HttpError::BadRequest(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for HttpError {
+    fn from(e: io::Error) -> Self {
+        HttpError::Io(e)
+    }
+}
+
+fn bad(why: &str) -> HttpError {
+    HttpError::BadRequest(why.to_string())
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    // 머리 이름은 대소문자를 가리지 않음 (HTTP 규칙)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    // HTTP/1.1 은 기본이 keep-alive - "Connection: close" 일 때만 닫음
+    pub fn keep_alive(&self) -> bool {
+        !self.header("connection").is_some_and(|v| v.eq_ignore_ascii_case("close"))
+    }
+}
+
+// "a=1&b=2" → [("a","1"), ("b","2")] (퍼센트 디코딩은 생략 - src/serve.rs 의 percent_decode 참고)
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (name.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+// 요청 하나를 읽음 - 연결이 깨끗하게 닫혔으면(다음 요청이 없음) None
+// R 이 AsyncBufRead 라 TcpStream, duplex, &[u8] 무엇이든
+pub async fn read_request<R: AsyncBufRead + Unpin>(reader: &mut R) -> Result<Option<Request>, HttpError> {
+    // 요청 줄: "GET /hello?name=kim HTTP/1.1"
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+    let mut parts = line.split_whitespace();
+    let (method, target, version) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(m), Some(t), Some(v)) => (m.to_string(), t.to_string(), v),
+        _ => return Err(bad("요청 줄은 '메서드 경로 버전'")),
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(bad("HTTP/1.x 만 지원"));
+    }
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_query(query)),
+        None => (target, Vec::new()),
+    };
+
+    // 머리: "이름: 값" 줄들, 빈 줄에서 끝
+    let mut headers = Vec::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).await? == 0 {
+            return Err(bad("머리가 끝나기 전에 연결이 닫힘"));
+        }
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() {
+            break;
+        }
+        let (name, value) = trimmed.split_once(':').ok_or_else(|| bad("머리에 ':' 가 없음"))?;
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+        if headers.len() > MAX_HEADERS {
+            return Err(bad("머리가 너무 많음"));
+        }
+    }
+
+    let mut request = Request { method, path, query, headers, body: String::new() };
+
+    // 본문: Content-Length 만큼 정확히 (청크 전송은 지원하지 않음)
+    let length = match request.header("content-length") {
+        Some(v) => v.parse::<usize>().map_err(|_| bad("Content-Length 가 숫자가 아님"))?,
+        None => 0,
+    };
+    if length > MAX_BODY {
+        return Err(bad("본문이 너무 큼"));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await?;
+    request.body = String::from_utf8(body).map_err(|_| bad("본문이 UTF-8 이 아님"))?;
+    Ok(Some(request))
+}
+
+async fn parsing_requests() {
+    println!("--- 요청 파싱 ---");
+
+    // C++ 에서는 (Boost.Beast):
+    // beast::flat_buffer buf; http::request<http::string_body> req;
+    // co_await http::async_read(stream, buf, req, use_awaitable);
+
+    // HTTP/1.1 요청은 글자 - 줄 끝은 \r\n, 머리 뒤에 빈 줄, 그다음 본문
+    let raw = "POST /echo?lang=ko&debug HTTP/1.1\r\nHost: localhost\r\nContent-Length: 5\r\n\r\nhello";
+    // &[u8] 도 AsyncRead - 네트워크 없이 파서만 시험
+    let mut reader = BufReader::new(raw.as_bytes());
+    match read_request(&mut reader).await {
+        Ok(Some(req)) => {
+            println!("메서드 {}, 경로 {}, 쿼리 {:?}", req.method, req.path, req.query);
+            println!("Host 머리: {:?}, 본문 {:?}", req.header("HOST"), req.body);
+            println!("keep-alive? {}", req.keep_alive());
+        }
+        Ok(None) => println!("요청 없음"),
+        Err(e) => println!("에러: {}", e),
+    }
+
+    // 잘못된 요청들 - 파서는 panic 하지 않고 BadRequest 로
+    let bad_requests = [
+        "GET\r\n\r\n",
+        "GET / SPDY/3\r\n\r\n",
+        "GET / HTTP/1.1\r\nno-colon\r\n\r\n",
+        "POST / HTTP/1.1\r\nContent-Length: ten\r\n\r\n",
+        "GET / HTTP/1.1\r\nHost: x\r\n",
+    ];
+    for raw in bad_requests {
+        let mut reader = BufReader::new(raw.as_bytes());
+        match read_request(&mut reader).await {
+            Ok(_) => println!("{:<45} → 통과", format!("{:?}", raw)),
+            Err(e) => println!("{:<45} → {}", format!("{:?}", raw), e),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 라우팅과 응답
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn text(status: u16, body: impl Into<String>) -> Response {
+        Response { status, content_type: "text/plain; charset=utf-8", body: body.into() }
+    }
+
+    fn json(body: String) -> Response {
+        Response { status: 200, content_type: "application/json", body }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "",
+        }
+    }
+
+    // 상태 줄 + 머리 + 빈 줄 + 본문 - Content-Length 는 글자 수가 아니라 바이트 수
+    fn to_bytes(&self, keep_alive: bool) -> Vec<u8> {
+        let head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: {}\r\n\r\n",
+            self.status,
+            self.reason(),
+            self.content_type,
+            self.body.len(),
+            if keep_alive { "keep-alive" } else { "close" }
+        );
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(self.body.as_bytes());
+        bytes
+    }
+}
+
+// 모든 연결 태스크가 Arc 로 공유하는 서버 상태
+#[derive(Default)]
+pub struct AppState {
+    requests: AtomicUsize,
+}
+
+// 경로로 나눔 - (메서드, 경로) 짝에 match (C++ 에서는 if/else 사슬이나 std::map<string, handler>)
+pub fn route(req: &Request, state: &AppState) -> Response {
+    let count = state.requests.fetch_add(1, Ordering::SeqCst) + 1;
+    match (req.method.as_str(), req.path.as_str()) {
+        ("GET", "/") => Response::text(200, "작은 HTTP 서버입니다\n"),
+        ("GET", "/hello") => Response::text(200, format!("안녕하세요, {}님\n", req.query("name").unwrap_or("손님"))),
+        ("GET", "/stats") => Response::json(format!("{{\"requests\":{}}}", count)),
+        ("POST", "/echo") => Response::text(200, req.body.clone()),
+        // 경로는 있는데 메서드가 틀림
+        (_, "/" | "/hello" | "/stats" | "/echo") => Response::text(405, "허용되지 않는 메서드\n"),
+        _ => Response::text(404, format!("{} 를 찾을 수 없음\n", req.path)),
+    }
+}
+
+fn routing() {
+    println!("\n--- 라우팅과 응답 ---");
+
+    // route 는 동기 함수 - 입출력이 없는 부분은 async 일 필요가 없고 테스트도 쉬움
+    let state = AppState::default();
+    let request = |method: &str, target: &str| {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: parse_query(query),
+            headers: Vec::new(),
+            body: String::new(),
+        }
+    };
+    for (method, target) in [("GET", "/"), ("GET", "/hello?name=민수"), ("DELETE", "/hello"), ("GET", "/nope"), ("GET", "/stats")] {
+        let response = route(&request(method, target), &state);
+        println!("{:<6} {:<18} → {} {:?}", method, target, response.status, response.body.trim_end());
+    }
+
+    // 응답의 실제 모양
+    let bytes = Response::text(200, "안녕").to_bytes(false);
+    println!("응답 바이트:\n{}", String::from_utf8_lossy(&bytes).replace("\r\n", "\\r\\n\n"));
+}
+
+// ----------------------------------------------------------------------------
+// 서버 - 연결마다 태스크
+// ----------------------------------------------------------------------------
+
+// keep-alive 연결이 이 시간 동안 다음 요청을 보내지 않으면 닫음 - 정상 종료가 영원히 기다리지 않게
+const IDLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+// 연결 하나 - 요청을 읽고 답하기를 연결이 닫힐 때까지 반복, 처리한 요청 수를 돌려줌
+pub async fn handle_connection<S>(stream: S, state: Arc<AppState>) -> io::Result<usize>
+where
+    S: AsyncRead + AsyncWrite,
+{
+    // 읽는 쪽과 쓰는 쪽을 나눔 - 읽는 쪽만 BufReader 로 (37장의 try_clone 대신)
+    let (read, mut write) = tokio::io::split(stream);
+    let mut reader = BufReader::new(read);
+    let mut served = 0;
+    loop {
+        let request = match tokio::time::timeout(IDLE_TIMEOUT, read_request(&mut reader)).await {
+            Err(_) | Ok(Ok(None)) => break,
+            Ok(Ok(Some(request))) => request,
+            Ok(Err(HttpError::BadRequest(why))) => {
+                let response = Response::text(400, format!("{}\n", why));
+                write.write_all(&response.to_bytes(false)).await?;
+                break;
+            }
+            Ok(Err(HttpError::Io(e))) => return Err(e),
+        };
+        let keep_alive = request.keep_alive();
+        write.write_all(&route(&request, &state).to_bytes(keep_alive)).await?;
+        served += 1;
+        if !keep_alive {
+            break;
+        }
+    }
+    write.shutdown().await?;
+    Ok(served)
+}
+
+// 종료 신호가 올 때까지 연결을 받고, 진행 중인 연결이 끝나기를 기다림 - 처리한 연결 수
+pub async fn serve(listener: TcpListener, state: Arc<AppState>, mut shutdown: watch::Receiver<bool>) -> io::Result<usize> {
+    let mut tasks = JoinSet::new();
+    let mut accepted = 0;
+    loop {
+        tokio::select! {
+            conn = listener.accept() => {
+                let (stream, _) = conn?;
+                accepted += 1;
+                let state = Arc::clone(&state);
+                // 연결마다 태스크 - 느린 클라이언트 하나가 다른 연결을 막지 않음
+                tasks.spawn(async move {
+                    if let Err(e) = handle_connection(stream, state).await {
+                        eprintln!("연결 에러: {}", e);
+                    }
+                });
+            }
+            _ = shutdown.changed() => break,
+        }
+    }
+    drop(listener);
+    while tasks.join_next().await.is_some() {}
+    Ok(accepted)
+}
+
+// 서버를 띄우고 (주소, 종료 신호, 서버 태스크) 를 돌려줌 - 아래 두 절이 함께 씀
+async fn start_server() -> io::Result<(SocketAddr, watch::Sender<bool>, tokio::task::JoinHandle<io::Result<usize>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let (stop, shutdown) = watch::channel(false);
+    let server = tokio::spawn(serve(listener, Arc::new(AppState::default()), shutdown));
+    Ok((addr, stop, server))
+}
+
+async fn server() {
+    println!("\n--- 서버 - 연결마다 태스크 ---");
+
+    // C++ 에서는 (Boost.Asio 코루틴):
+    // for (;;) { auto socket = co_await acceptor.async_accept(use_awaitable);
+    //            co_spawn(ex, session(std::move(socket)), detached); }
+
+    let (addr, stop, server) = start_server().await.unwrap();
+
+    // 클라이언트 20개가 동시에 - 태스크라 스레드 20개가 필요하지 않음
+    let mut clients = JoinSet::new();
+    for i in 0..20 {
+        clients.spawn(async move { (i, get(addr, &format!("/hello?name=손님{}", i)).await) });
+    }
+    let mut replies = Vec::new();
+    while let Some(joined) = clients.join_next().await {
+        if let Ok((i, Ok((status, body)))) = joined {
+            replies.push((i, status, body));
+        }
+    }
+    // 끝난 순서는 실행마다 다름 - 번호순으로
+    replies.sort();
+    println!("동시 요청 {}개 성공, 모두 200? {}", replies.len(), replies.iter().all(|(_, status, _)| *status == 200));
+    println!("첫 응답: {:?}", replies.first().map(|(_, _, body)| body.trim_end()));
+
+    // 정상 종료 - 새 연결을 멈추고 진행 중인 연결을 기다림 (20장과 같은 모양)
+    stop.send(true).unwrap();
+    println!("처리한 연결 수: {:?}", server.await.unwrap());
+}
+
+// ----------------------------------------------------------------------------
+// 클라이언트
+// ----------------------------------------------------------------------------
+
+// 연결 하나를 붙잡고 요청을 여러 번 보내는 클라이언트 (keep-alive)
+pub struct Client {
+    reader: BufReader<tokio::net::tcp::OwnedReadHalf>,
+    writer: tokio::net::tcp::OwnedWriteHalf,
+}
+
+impl Client {
+    pub async fn connect(addr: SocketAddr) -> io::Result<Client> {
+        // into_split - 소유한 두 반쪽 (split 은 빌린 반쪽이라 구조체에 담기 어려움)
+        let (read, writer) = TcpStream::connect(addr).await?.into_split();
+        Ok(Client { reader: BufReader::new(read), writer })
+    }
+
+    // 요청을 보내고 (상태 코드, 본문) 을 받음
+    pub async fn send(&mut self, method: &str, path: &str, body: &str, close: bool) -> Result<(u16, String), HttpError> {
+        let request = format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            if close { "close" } else { "keep-alive" },
+            body
+        );
+        self.writer.write_all(request.as_bytes()).await?;
+
+        // 상태 줄 "HTTP/1.1 200 OK"
+        let mut line = String::new();
+        self.reader.read_line(&mut line).await?;
+        let status = line.split_whitespace().nth(1).and_then(|s| s.parse().ok()).ok_or_else(|| bad("상태 줄이 이상함"))?;
+
+        // 머리에서 Content-Length 만 봄
+        let mut length = 0;
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line).await? == 0 {
+                return Err(bad("응답 머리 중간에 연결이 닫힘"));
+            }
+            let trimmed = line.trim_end();
+            if trimmed.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = trimmed.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    length = value.trim().parse().map_err(|_| bad("Content-Length 가 숫자가 아님"))?;
+                }
+            }
+        }
+        let mut body = vec![0; length];
+        self.reader.read_exact(&mut body).await?;
+        Ok((status, String::from_utf8_lossy(&body).into_owned()))
+    }
+}
+
+// 요청 하나만 - 연결하고 보내고 닫음
+pub async fn get(addr: SocketAddr, path: &str) -> Result<(u16, String), HttpError> {
+    Client::connect(addr).await?.send("GET", path, "", true).await
+}
+
+async fn client() {
+    println!("\n--- 클라이언트 ---");
+
+    // C++ 에서는 (Boost.Beast): http::write(stream, req); http::read(stream, buffer, res);
+
+    let (addr, stop, server) = start_server().await.unwrap();
+
+    // 한 연결로 요청 셋 - 연결을 맺는 비용(TCP 핸드셰이크)을 한 번만
+    let mut client = Client::connect(addr).await.unwrap();
+    for (method, path, body) in [("GET", "/", ""), ("POST", "/echo", "메아리"), ("PUT", "/echo", "")] {
+        match client.send(method, path, body, false).await {
+            Ok((status, body)) => println!("{} {} → {} {:?}", method, path, status, body.trim_end()),
+            Err(e) => println!("{} {} → 에러 {}", method, path, e),
+        }
+    }
+    match client.send("GET", "/stats", "", true).await {
+        Ok((status, body)) => println!("GET /stats → {} {}", status, body),
+        Err(e) => println!("GET /stats → 에러 {}", e),
+    }
+    drop(client);
+
+    // 잘못된 요청을 직접 보내면 - 서버는 400 을 답하고 연결을 닫음
+    let mut raw = TcpStream::connect(addr).await.unwrap();
+    raw.write_all(b"HELLO\r\n\r\n").await.unwrap();
+    let mut response = String::new();
+    raw.read_to_string(&mut response).await.unwrap();
+    println!("잘못된 요청의 응답 첫 줄: {:?}", response.lines().next());
+
+    stop.send(true).unwrap();
+    println!("처리한 연결 수: {:?}", server.await.unwrap());
+
+    // 여기서 빠진 것 - 실무 라이브러리(hyper, reqwest)가 해 주는 일
+    //   청크 전송(Transfer-Encoding: chunked), 압축, HTTPS(TLS), HTTP/2, 리다이렉트, 퍼센트 디코딩
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn parse(raw: &str) -> Result<Option<Request>, HttpError> {
+        read_request(&mut raw.as_bytes()).await
+    }
+
+    #[tokio::test]
+    async fn reads_requests_and_rejects_malformed_ones() {
+        let req = parse("POST /echo?a=1&b HTTP/1.1\r\nContent-Length: 3\r\nCONNECTION: close\r\n\r\nabc")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((req.method.as_str(), req.path.as_str(), req.body.as_str()), ("POST", "/echo", "abc"));
+        assert_eq!((req.query("a"), req.query("b"), req.query("c")), (Some("1"), Some(""), None));
+        assert_eq!(req.header("content-length"), Some("3"));
+        assert!(!req.keep_alive());
+        assert!(parse("").await.unwrap().is_none());
+
+        for raw in [
+            "GET /\r\n\r\n",
+            "GET / HTTP/2\r\n\r\n",
+            "GET / HTTP/1.1\r\nHost\r\n\r\n",
+            "GET / HTTP/1.1\r\nContent-Length: x\r\n\r\n",
+            "GET / HTTP/1.1\r\nHost: a\r\n",
+        ] {
+            assert!(matches!(parse(raw).await, Err(HttpError::BadRequest(_))), "{:?}", raw);
+        }
+    }
+
+    #[tokio::test]
+    async fn routes_by_method_and_path() {
+        let state = AppState::default();
+        let mut status = Vec::new();
+        for line in ["GET / HTTP/1.1", "GET /hello HTTP/1.1", "DELETE /echo HTTP/1.1", "GET /nope HTTP/1.1"] {
+            let req = parse(&format!("{}\r\n\r\n", line)).await.unwrap().unwrap();
+            status.push(route(&req, &state).status);
+        }
+        assert_eq!(status, [200, 200, 405, 404]);
+        let stats = parse("GET /stats HTTP/1.1\r\n\r\n").await.unwrap().unwrap();
+        assert_eq!(route(&stats, &state), Response::json("{\"requests\":5}".to_string()));
+    }
+
+    #[tokio::test]
+    async fn keep_alive_connection_serves_many_requests() {
+        let state = Arc::new(AppState::default());
+        let (client_side, server_side) = tokio::io::duplex(4096);
+        let server = tokio::spawn(handle_connection(server_side, Arc::clone(&state)));
+
+        let (read, mut write) = tokio::io::split(client_side);
+        write
+            .write_all(b"GET /hello?name=a HTTP/1.1\r\n\r\nPOST /echo HTTP/1.1\r\nContent-Length: 2\r\nConnection: close\r\n\r\nhi")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        BufReader::new(read).read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("안녕하세요, a님"));
+        assert!(response.ends_with("Connection: close\r\n\r\nhi"));
+        assert_eq!(server.await.unwrap().unwrap(), 2);
+        assert_eq!(state.requests.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,435 @@
+// ============================================================================
+// 39. 프로세스 실행과 파이프
+// ============================================================================
+// 21장의 운영체제별 프로세스를 넓혀 std::process::Command 를 제대로 다룸
+//   - 실행하고 기다리기 (status, output, spawn), 출력 잡기, 프로세스끼리 파이프
+//   - 종료 코드를 에러 타입으로, 시간 제한과 kill, 환경 변수와 작업 디렉터리
+// 예제의 명령(sh, printf, sort, tr)은 유닉스 기준 - 없는 환경에서는 실패를 출력하고 넘어감
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 표준에는 프로세스 API 가 없음 - popen/system (셸 경유), fork+exec+pipe, Windows 는 CreateProcess
+//    Rust 는 std::process::Command 하나가 양쪽을 감쌈 (Boost.Process 와 비슷)
+// 2. 인자는 배열로 전달 - 셸을 거치지 않으므로 따옴표와 이스케이프, 셸 주입(injection) 걱정이 없음
+// 3. 파이프 핸들(ChildStdin/ChildStdout)은 RAII - drop 하면 닫혀서 자식이 EOF 를 받음
+// 4. 종료 상태는 ExitStatus - WIFEXITED/WEXITSTATUS 매크로 대신 code() 가 Option<i32>
+// 5. 실패는 io::Result - 프로그램이 없으면 ErrorKind::NotFound (popen 은 셸이 127 을 돌려줌)
+// ============================================================================
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// 절 목록 (실행 순서) - cargo run -- 39:spawning 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("spawning", spawning),
+    ("capturing_output", capturing_output),
+    ("pipes", pipes),
+    ("exit_codes", exit_codes),
+    ("environment", environment),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "39"
+    }
+
+    fn name(&self) -> &'static str {
+        "프로세스 실행과 파이프"
+    }
+
+    fn description(&self) -> &'static str {
+        "std::process::Command 로 실행하고 기다리기, stdout/stderr 잡기, 프로세스끼리 파이프, 종료 코드와 시간 제한, 환경 변수"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Command", "Stdio::piped", "ExitStatus", "파이프", "popen", "CreateProcess", "환경 변수"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// 셸 명령 한 줄 (21장의 shell 과 같음) - 셸 문법(|, >, $VAR)이 꼭 필요할 때만
+fn sh(script: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.args(["-c", script]);
+    command
+}
+
+// ----------------------------------------------------------------------------
+// 실행하고 기다리기
+// ----------------------------------------------------------------------------
+
+fn spawning() {
+    println!("--- 실행하고 기다리기 ---");
+
+    // C++ 에서는:
+    // int rc = std::system("printf hello");         // 셸 경유, 출력은 그대로 터미널로
+    // pid_t pid = fork();
+    // if (pid == 0) { execlp("printf", "printf", "hello", nullptr); _exit(127); }
+    // waitpid(pid, &status, 0);
+
+    // Command 는 빌더 - 프로그램과 인자를 쌓고 마지막에 실행 방법을 고름
+    //   status()  - 실행하고 끝까지 기다림, 입출력은 부모 것을 물려받음
+    //   output()  - 실행하고 기다리며 stdout/stderr 를 모두 잡음
+    //   spawn()   - 실행만 하고 Child 를 돌려줌 (기다리기는 나중에)
+    match Command::new("printf").args(["%s + %s\n", "인자", "배열"]).output() {
+        Ok(out) => print!("output(): {}", String::from_utf8_lossy(&out.stdout)),
+        Err(e) => println!("printf 실행 실패: {}", e),
+    }
+
+    // 인자는 셸을 거치지 않으므로 공백이나 따옴표, ; 가 그대로 한 인자
+    // C++ 의 system("echo " + name) 은 name = "; rm -rf ~" 이면 큰일
+    let name = "; 이것은 명령이 아님";
+    match Command::new("printf").args(["[%s]\n", name]).output() {
+        Ok(out) => print!("그대로 전달: {}", String::from_utf8_lossy(&out.stdout)),
+        Err(e) => println!("printf 실행 실패: {}", e),
+    }
+
+    // spawn() - 자식이 도는 동안 부모도 일함
+    match sh("sleep 0.1; echo 자식 끝").stdout(Stdio::piped()).spawn() {
+        Ok(child) => {
+            println!("자식 실행 중 - 부모는 다른 일");
+            // wait_with_output 은 stdout 을 끝까지 읽고 기다림
+            match child.wait_with_output() {
+                Ok(out) => print!("{}", String::from_utf8_lossy(&out.stdout)),
+                Err(e) => println!("기다리기 실패: {}", e),
+            }
+        }
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // 없는 프로그램 - 셸의 127 이 아니라 spawn 단계의 io::Error
+    match Command::new("no-such-program-39").status() {
+        Ok(status) => println!("실행됨?: {}", status),
+        Err(e) => println!("없는 프로그램: ErrorKind::{:?}", e.kind()),
+    }
+
+    // Child 를 wait 하지 않고 버리면 좀비 프로세스가 남을 수 있음 (Drop 이 기다리지 않음)
+    // C++ 의 waitpid 를 빠뜨린 것과 같은 문제 - spawn 했으면 wait/wait_with_output 까지
+}
+
+// ----------------------------------------------------------------------------
+// 출력 잡기
+// ----------------------------------------------------------------------------
+
+fn capturing_output() {
+    println!("\n--- 출력 잡기 ---");
+
+    // C++ 에서는:
+    // FILE* p = popen("cmd 2>&1", "r");   // stdout 만, stderr 는 셸 리다이렉트로
+    // while (fgets(buf, sizeof buf, p)) ...; int rc = pclose(p);
+
+    // output() 은 stdout 과 stderr 를 따로 잡음 - 둘 다 Vec<u8> (UTF-8 이라는 보장이 없음)
+    match sh("echo 표준 출력; echo 표준 에러 >&2; exit 2").output() {
+        Ok(out) => {
+            println!("stdout: {:?}", String::from_utf8_lossy(&out.stdout).trim_end());
+            println!("stderr: {:?}", String::from_utf8_lossy(&out.stderr).trim_end());
+            println!("status: {:?}", out.status.code());
+        }
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // 줄 단위로 흘려 읽기 - 출력이 크거나 오래 걸리는 명령 (빌드 로그 등)
+    match sh("for i in 1 2 3; do echo 줄 $i; done").stdout(Stdio::piped()).spawn() {
+        Ok(mut child) => {
+            if let Some(stdout) = child.stdout.take() {
+                for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                    println!("  받음: {}", line);
+                }
+            }
+            let _ = child.wait();
+        }
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // stdin 에 쓰고 stdout 을 읽기 - 양쪽을 다 잡으면 교착(deadlock)에 주의
+    // 자식의 stdout 파이프 버퍼(보통 64KiB)가 차면 자식은 쓰기에서 멈추고,
+    // 부모는 stdin 쓰기에서 멈춤 → 둘 다 영원히 기다림
+    // 해결: 쓰기를 다른 스레드로 보내고, 이 스레드는 읽기 (wait_with_output)
+    let input: String = (0..20_000).map(|i| format!("{}\n", i % 7)).collect();
+    match filter(Command::new("sort").args(["-u"]), input) {
+        Ok(out) => println!("sort -u 결과 (입력 {}KB): {:?}", 20_000 * 2 / 1000, out.lines().collect::<Vec<_>>()),
+        Err(e) => println!("sort 실패: {}", e),
+    }
+}
+
+// 입력을 자식의 stdin 으로 흘리고 stdout 전체를 돌려줌 - 셸의 `echo input | command`
+fn filter(command: &mut Command, input: String) -> io::Result<String> {
+    let mut child = command.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn()?;
+    let mut stdin = child.stdin.take().ok_or(ErrorKind::BrokenPipe)?;
+    // 쓰기 스레드가 끝나면 stdin 이 drop 되어 닫힘 → 자식이 EOF 를 받음
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output()?;
+    writer.join().map_err(|_| io::Error::other("쓰기 스레드 panic"))??;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// ----------------------------------------------------------------------------
+// 프로세스끼리 파이프
+// ----------------------------------------------------------------------------
+
+// 셸의 `a | b | c` - 앞 자식의 stdout 을 다음 자식의 stdin 으로 직접 연결
+// 데이터가 부모를 거치지 않음 (운영체제 파이프 하나를 두 자식이 나눠 가짐)
+pub fn pipeline(commands: &mut [Command]) -> io::Result<String> {
+    let mut children = Vec::new();
+    let mut previous: Option<Stdio> = None;
+    let last = commands.len().saturating_sub(1);
+    for (i, command) in commands.iter_mut().enumerate() {
+        if let Some(stdin) = previous.take() {
+            command.stdin(stdin);
+        }
+        command.stdout(Stdio::piped());
+        let mut child = command.spawn()?;
+        if i < last {
+            // ChildStdout → Stdio - 다음 자식의 stdin 이 됨
+            previous = child.stdout.take().map(Stdio::from);
+        }
+        children.push(child);
+    }
+    // 마지막 자식의 출력을 읽고, 모든 자식을 기다림 (좀비 방지)
+    let mut output = String::new();
+    if let Some(stdout) = children.last_mut().and_then(|child| child.stdout.as_mut()) {
+        stdout.read_to_string(&mut output)?;
+    }
+    for mut child in children {
+        child.wait()?;
+    }
+    Ok(output)
+}
+
+fn pipes() {
+    println!("\n--- 프로세스끼리 파이프 ---");
+
+    // C++ 에서는:
+    // int fd[2]; pipe(fd);
+    // if (fork() == 0) { dup2(fd[1], 1); close(fd[0]); close(fd[1]); execlp("printf", ...); }
+    // if (fork() == 0) { dup2(fd[0], 0); close(fd[0]); close(fd[1]); execlp("sort", ...); }
+    // close(fd[0]); close(fd[1]);   // 하나라도 빠뜨리면 sort 가 EOF 를 못 받아 멈춤
+    // Rust 는 핸들이 RAII 라 닫기를 빠뜨릴 일이 없음
+
+    // printf 'banana\napple\ncherry\n' | sort | tr a-z A-Z
+    let mut commands = [
+        {
+            let mut c = Command::new("printf");
+            c.arg("banana\\napple\\ncherry\\napple\\n");
+            c
+        },
+        {
+            let mut c = Command::new("sort");
+            c.arg("-u");
+            c
+        },
+        {
+            let mut c = Command::new("tr");
+            c.args(["a-z", "A-Z"]);
+            c
+        },
+    ];
+    match pipeline(&mut commands) {
+        Ok(out) => println!("printf | sort -u | tr: {:?}", out.lines().collect::<Vec<_>>()),
+        Err(e) => println!("파이프라인 실패: {}", e),
+    }
+
+    // 같은 일을 셸에 맡기면 짧지만 - 인자에 사용자 입력이 들어가면 위험, 셸이 없는 환경도 있음
+    match sh("printf 'b\\na\\n' | sort").output() {
+        Ok(out) => println!("셸 파이프: {:?}", String::from_utf8_lossy(&out.stdout).lines().collect::<Vec<_>>()),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // 출력을 버리거나 부모 것을 그대로 - Stdio::null(), Stdio::inherit()
+    let quiet = sh("echo 보이지 않음; echo 이것도 >&2").stdout(Stdio::null()).stderr(Stdio::null()).status();
+    println!("출력을 버린 실행의 성공 여부: {:?}", quiet.map(|s| s.success()).ok());
+}
+
+// ----------------------------------------------------------------------------
+// 종료 코드와 에러
+// ----------------------------------------------------------------------------
+
+// 9장의 관례대로 - 실행 실패와 "실행됐지만 실패" 를 구분한 에러 타입
+#[derive(Debug)]
+pub enum ProcessError {
+    // 실행조차 못 함 (없는 프로그램, 권한)
+    Spawn { program: String, source: io::Error },
+    // 실행했지만 0 이 아닌 코드로 끝남 (code 가 None 이면 시그널로 죽음)
+    Failed { program: String, code: Option<i32>, stderr: String },
+}
+
+impl fmt::Display for ProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessError::Spawn { program, source } => write!(f, "{} 를 실행할 수 없음: {}", program, source),
+            ProcessError::Failed { program, code: Some(code), stderr } => {
+                write!(f, "{} 가 코드 {} 로 실패: {}", program, code, stderr.trim_end())
+            }
+            ProcessError::Failed { program, code: None, .. } => write!(f, "{} 가 시그널로 종료됨", program),
+        }
+    }
+}
+
+impl std::error::Error for ProcessError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ProcessError::Spawn { source, .. } => Some(source),
+            ProcessError::Failed { .. } => None,
+        }
+    }
+}
+
+// 실행하고 성공하면 stdout, 아니면 ProcessError - ? 로 이어 쓰기 좋게
+pub fn run_checked(command: &mut Command) -> Result<String, ProcessError> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let output = command.output().map_err(|source| ProcessError::Spawn { program: program.clone(), source })?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(ProcessError::Failed {
+            program,
+            code: output.status.code(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+}
+
+// 시간 안에 끝나지 않으면 kill - None 이면 시간 초과
+// 표준 라이브러리에는 wait_timeout 이 없어서 try_wait 로 짧게 확인 (wait-timeout 크레이트도 있음)
+fn wait_with_timeout(command: &mut Command, limit: Duration) -> io::Result<Option<ExitStatus>> {
+    let mut child = command.spawn()?;
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if start.elapsed() >= limit {
+            child.kill()?;
+            // kill 뒤에도 wait 로 거둬야 좀비가 남지 않음
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn exit_codes() {
+    println!("\n--- 종료 코드와 에러 ---");
+
+    // C++ 에서는:
+    // if (WIFEXITED(status)) code = WEXITSTATUS(status);
+    // else if (WIFSIGNALED(status)) sig = WTERMSIG(status);
+
+    for script in ["exit 0", "exit 1", "exit 42"] {
+        match sh(script).status() {
+            Ok(status) => println!("{:<8} → success {}, code {:?}", script, status.success(), status.code()),
+            Err(e) => println!("sh 실행 실패: {}", e),
+        }
+    }
+
+    // 에러 타입으로 - 호출하는 쪽은 ? 하나로
+    let cases: [(&str, &[&str]); 3] = [
+        ("printf", &["좋음"]),
+        ("sh", &["-c", "echo 디스크가 가득 참 >&2; exit 3"]),
+        ("no-such-program-39", &[]),
+    ];
+    for (program, args) in cases {
+        match run_checked(Command::new(program).args(args)) {
+            Ok(out) => println!("성공: {:?}", out),
+            Err(e) => println!("에러: {}", e),
+        }
+    }
+
+    // 시간 제한 - 끝나지 않는 자식을 kill
+    match wait_with_timeout(&mut sh("sleep 5"), Duration::from_millis(100)) {
+        Ok(Some(status)) => println!("제때 끝남: {}", status),
+        Ok(None) => println!("시간 초과 - kill 하고 거둠"),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+    match wait_with_timeout(&mut sh("exit 0"), Duration::from_secs(5)) {
+        Ok(Some(status)) => println!("제때 끝남: code {:?}", status.code()),
+        Ok(None) => println!("시간 초과 - kill 하고 거둠"),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // 내 프로그램의 종료 코드 - main 에서 ExitCode 를 돌려주거나 std::process::exit(n)
+    // exit 는 소멸자(Drop)를 실행하지 않음 (C++ 의 std::exit 와 같음) - 가능하면 main 에서 돌려주기
+}
+
+// ----------------------------------------------------------------------------
+// 환경 변수와 작업 디렉터리
+// ----------------------------------------------------------------------------
+
+fn environment() {
+    println!("\n--- 환경 변수와 작업 디렉터리 ---");
+
+    // C++ 에서는:
+    // fork 뒤 자식에서 setenv/unsetenv/chdir 후 exec, 또는 execve 에 envp 배열
+    // Windows: CreateProcess 의 lpEnvironment, lpCurrentDirectory
+    // Rust 는 Command 에 설정 - 부모의 환경은 바뀌지 않음
+
+    // env - 자식에게만 추가/덮어쓰기
+    match sh("echo $GREETING, $WHO").env("GREETING", "안녕").env("WHO", "자식").output() {
+        Ok(out) => println!("env: {}", String::from_utf8_lossy(&out.stdout).trim_end()),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+    println!("부모의 GREETING: {:?}", std::env::var("GREETING").ok());
+
+    // env_clear - 물려받은 환경을 모두 지움 (PATH 도 사라지므로 sh 는 절대 경로로 찾거나 PATH 를 다시 넣음)
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    match sh("env | sort").env_clear().env("PATH", &path).env("ONLY", "1").output() {
+        Ok(out) => {
+            let names: Vec<String> = String::from_utf8_lossy(&out.stdout)
+                .lines()
+                .filter_map(|line| line.split_once('=').map(|(name, _)| name.to_string()))
+                // 셸이 스스로 넣는 변수(PWD, SHLVL 등)는 셸마다 달라서 제외
+                .filter(|name| name == "ONLY" || name == "PATH" || name == "HOME")
+                .collect();
+            println!("env_clear 뒤 남은 변수: {:?}", names);
+        }
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // env_remove - 하나만 빼기 (예: 자식이 부모의 설정을 물려받지 않게)
+    match sh("echo ${HOME:-없음}").env_remove("HOME").output() {
+        Ok(out) => println!("env_remove(\"HOME\"): {}", String::from_utf8_lossy(&out.stdout).trim_end()),
+        Err(e) => println!("sh 실행 실패: {}", e),
+    }
+
+    // current_dir - 자식의 작업 디렉터리 (부모의 std::env::set_current_dir 는 전역이라 스레드 사이에 위험)
+    let dir = std::env::temp_dir();
+    match Command::new("pwd").current_dir(&dir).output() {
+        Ok(out) => {
+            let pwd = String::from_utf8_lossy(&out.stdout).trim_end().to_string();
+            // 심볼릭 링크(macOS 의 /tmp → /private/tmp) 때문에 정규화해서 비교
+            let same = std::fs::canonicalize(&pwd).ok() == std::fs::canonicalize(&dir).ok();
+            println!("current_dir(temp_dir) 에서 pwd 가 같은 곳? {}", same);
+        }
+        Err(e) => println!("pwd 실행 실패: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn pipeline_and_checked_errors() {
+        let mut commands = [Command::new("printf"), Command::new("sort")];
+        commands[0].arg("c\\na\\nb\\n");
+        assert_eq!(pipeline(&mut commands).unwrap(), "a\nb\nc\n");
+
+        match run_checked(&mut sh("echo nope >&2; exit 7")) {
+            Err(ProcessError::Failed { code, stderr, .. }) => {
+                assert_eq!(code, Some(7));
+                assert_eq!(stderr, "nope\n");
+            }
+            other => panic!("Failed 이어야 함: {:?}", other),
+        }
+        assert!(matches!(run_checked(&mut Command::new("no-such-program-39")), Err(ProcessError::Spawn { .. })));
+    }
+}
@@ -0,0 +1,464 @@
+// ============================================================================
+// 40. 시간, Duration, 시계
+// ============================================================================
+// 경과 시간은 Instant, 달력 시각은 SystemTime, 길이는 Duration
+// 표준 라이브러리만으로 - 시간대와 달력 계산이 필요하면 chrono, time, jiff 크레이트
+//
+// C++20과의 핵심 차이점:
+// 1. std::chrono::steady_clock::time_point ≈ Instant, system_clock::time_point ≈ SystemTime
+//    C++ 은 시계가 타입 매개변수 - Rust 는 시계마다 타입 하나
+// 2. Duration 은 하나뿐 (초 + 나노초, 음수 없음) - duration<Rep, Period> 의 단위 변환 캐스트가 없음
+//    음수가 될 뺄셈은 panic 이나 checked_sub 로 None
+// 3. SystemTime::duration_since 는 Result - 시계가 거꾸로 가면(NTP 조정) Err
+// 4. C++20 의 std::chrono::year_month_day, format("{:%F}") 같은 달력/서식은 std 에 없음
+// 5. Instant 는 직접 만들 수 없음 - 테스트에서는 시계를 트레이트로 감싸 가짜 시계를 넣음
+// ============================================================================
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// 절 목록 (실행 순서) - cargo run -- 40:instant_vs_system_time 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("instant_vs_system_time", instant_vs_system_time),
+    ("duration_arithmetic", duration_arithmetic),
+    ("formatting_timestamps", formatting_timestamps),
+    ("monotonic_pitfalls", monotonic_pitfalls),
+    ("timeouts", timeouts),
+    ("clock_trait", clock_trait),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "40"
+    }
+
+    fn name(&self) -> &'static str {
+        "시간, Duration, 시계"
+    }
+
+    fn description(&self) -> &'static str {
+        "Instant 와 SystemTime, Duration 계산, 타임스탬프 서식, 단조 시계의 함정, 시간 제한, 테스트 가능한 Clock 트레이트"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Instant", "SystemTime", "Duration", "std::chrono", "타임스탬프", "시간 제한", "Clock 트레이트"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Instant 와 SystemTime
+// ----------------------------------------------------------------------------
+
+fn instant_vs_system_time() {
+    println!("--- Instant 와 SystemTime ---");
+
+    // C++ 에서는:
+    // auto start = std::chrono::steady_clock::now();
+    // ...
+    // auto took = std::chrono::steady_clock::now() - start;   // duration
+    // auto wall = std::chrono::system_clock::now();            // 달력 시각
+
+    // Instant - 단조(monotonic) 시계: 절대 거꾸로 가지 않음, 값 자체에는 의미가 없음 (차이만)
+    let start = Instant::now();
+    thread::sleep(Duration::from_millis(20));
+    let took = start.elapsed();
+    // 실제 값은 실행마다 다름 - 예제는 비교 결과만 출력
+    println!("sleep(20ms) 뒤 elapsed() >= 20ms? {}", took >= Duration::from_millis(20));
+
+    // SystemTime - 벽시계(wall clock): 달력 시각, 다른 프로세스/컴퓨터와 비교 가능, 하지만 거꾸로 갈 수 있음
+    let now = SystemTime::now();
+    let since_epoch = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+    println!("UNIX_EPOCH 부터 2020년 이후? {}", since_epoch.as_secs() > 1_577_836_800);
+
+    // 어느 쪽을 쓸까
+    //   경과 시간, 시간 제한, 벤치마크          → Instant
+    //   로그의 타임스탬프, 파일 수정 시각, 만료일 → SystemTime
+    // Instant 는 저장하거나 다른 프로세스로 보낼 수 없음 (기준점이 부팅 시각 등 운영체제마다 다름)
+    let modified = std::fs::metadata(std::env::temp_dir()).and_then(|m| m.modified());
+    println!("디렉터리 수정 시각(SystemTime)이 지금보다 과거? {}", modified.is_ok_and(|t| t <= SystemTime::now()));
+}
+
+// ----------------------------------------------------------------------------
+// Duration 계산
+// ----------------------------------------------------------------------------
+
+fn duration_arithmetic() {
+    println!("\n--- Duration 계산 ---");
+
+    // C++ 에서는:
+    // using namespace std::chrono_literals;
+    // auto d = 1500ms; auto s = duration_cast<seconds>(d);   // 1s (잘림)
+    // auto f = duration<double>(d).count();                 // 1.5
+
+    // 만들기 - 단위마다 생성자 (리터럴 접미사는 없음)
+    let d = Duration::from_millis(1500);
+    println!("{:?} = {}초 + {}나노초", d, d.as_secs(), d.subsec_nanos());
+    println!("as_secs_f64: {}, as_millis: {}", d.as_secs_f64(), d.as_millis());
+    println!("from_secs_f64(0.25): {:?}", Duration::from_secs_f64(0.25));
+
+    // 산술 - 더하기, 곱하기, 나누기
+    let total = d + Duration::from_secs(2) * 3;
+    println!("1.5s + 2s × 3 = {:?}, 절반 = {:?}", total, total / 2);
+    println!("mul_f64(1.1): {:?}", Duration::from_secs(10).mul_f64(1.1));
+
+    // Duration 에는 음수가 없음 - 작은 값에서 큰 값을 빼면 panic
+    let short = Duration::from_millis(100);
+    println!("100ms - 1.5s: checked {:?}, saturating {:?}", short.checked_sub(d), short.saturating_sub(d));
+
+    // 비교와 합계
+    let laps = [Duration::from_millis(310), Duration::from_millis(295), Duration::from_millis(330)];
+    let sum: Duration = laps.iter().sum();
+    println!("랩 합계 {:?}, 가장 빠른 랩 {:?}", sum, laps.iter().min());
+
+    // Debug 출력은 알맞은 단위를 고름 - 사람에게 보여 줄 형식은 직접 (아래 format_duration)
+    println!("{:?} {:?} {:?}", Duration::from_nanos(1500), Duration::from_micros(1500), Duration::from_secs(3725));
+    println!("format_duration: {}", format_duration(Duration::from_secs(3725)));
+}
+
+// 3725초 → "1h 02m 05s" - 0인 앞 단위는 생략
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    let (h, m, s) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    match (h, m) {
+        (0, 0) => format!("{}.{:03}s", s, d.subsec_millis()),
+        (0, _) => format!("{}m {:02}s", m, s),
+        _ => format!("{}h {:02}m {:02}s", h, m, s),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 타임스탬프 서식
+// ----------------------------------------------------------------------------
+
+const SECS_PER_DAY: u64 = 86_400;
+
+// 유닉스 일 수 → (연, 월, 일) - Howard Hinnant 의 civil_from_days (src/timelog.rs 와 같음)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (yoe + era * 400 + i64::from(m <= 2), m, d)
+}
+
+// 반대 방향 - (연, 월, 일) → 유닉스 일 수
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = i64::from((m + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(d) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// SystemTime → "2023-11-14T22:13:20Z" (RFC 3339, UTC, 1970년 이후만)
+pub fn format_utc(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (y, m, d) = civil_from_days((secs / SECS_PER_DAY) as i64);
+    let rest = secs % SECS_PER_DAY;
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, m, d, rest / 3600, rest % 3600 / 60, rest % 60)
+}
+
+// "2023-11-14T22:13:20Z" → SystemTime - 형식이 틀리거나 없는 날짜(2월 30일)면 None
+pub fn parse_utc(text: &str) -> Option<SystemTime> {
+    let (date, time) = text.strip_suffix('Z')?.split_once('T')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<u32>);
+    let (y, m, d) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let mut time = time.splitn(3, ':').map(str::parse::<u64>);
+    let (hh, mm, ss) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+    if y < 1970 || hh > 23 || mm > 59 || ss > 59 {
+        return None;
+    }
+    let days = days_from_civil(i64::from(y), m, d);
+    // 거꾸로 바꿔서 같은 날짜가 나와야 진짜 있는 날짜 (13월, 2월 30일 걸러냄)
+    if civil_from_days(days) != (i64::from(y), m, d) {
+        return None;
+    }
+    Some(UNIX_EPOCH + Duration::from_secs(days as u64 * SECS_PER_DAY + hh * 3600 + mm * 60 + ss))
+}
+
+fn formatting_timestamps() {
+    println!("\n--- 타임스탬프 서식 ---");
+
+    // C++ 에서는 (C++20):
+    // auto t = std::chrono::sys_seconds{std::chrono::seconds{1'700'000'000}};
+    // std::format("{:%FT%TZ}", t);    // 2023-11-14T22:13:20Z
+    // Rust 표준 라이브러리에는 달력 서식이 없음 → 직접 계산하거나 chrono/time/jiff
+
+    let t = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let text = format_utc(t);
+    println!("1_700_000_000 → {}", text);
+    println!("UNIX_EPOCH → {}", format_utc(UNIX_EPOCH));
+    println!("윤일 → {}", format_utc(UNIX_EPOCH + Duration::from_secs(days_from_civil(2024, 2, 29) as u64 * SECS_PER_DAY)));
+
+    // 왕복 - 저장한 문자열을 다시 읽음
+    println!("parse(format(t)) == t? {}", parse_utc(&text) == Some(t));
+    for bad in ["2023-02-30T00:00:00Z", "2023-13-01T00:00:00Z", "2023-11-14 22:13:20", "2023-11-14T24:00:00Z"] {
+        println!("parse_utc({:?}) = {:?}", bad, parse_utc(bad));
+    }
+
+    // 저장은 문자열보다 숫자(유닉스 초)가 간편 - 비교와 정렬이 쉽고 시간대 문제가 없음
+    // 사람에게 보여 줄 때만 서식을 입힘 (현지 시간대는 운영체제 데이터베이스가 필요 → 크레이트)
+}
+
+// ----------------------------------------------------------------------------
+// 단조 시계의 함정
+// ----------------------------------------------------------------------------
+
+fn monotonic_pitfalls() {
+    println!("\n--- 단조 시계의 함정 ---");
+
+    // 함정 1: SystemTime 으로 경과 시간을 재기
+    // NTP 가 시계를 맞추거나 사용자가 시각을 바꾸면 "끝 - 시작" 이 음수가 될 수 있음
+    // 그래서 duration_since 는 Result - 예제에서는 일부러 거꾸로 빼 봄
+    let now = SystemTime::now();
+    let earlier = now - Duration::from_secs(5);
+    match earlier.duration_since(now) {
+        Ok(d) => println!("경과: {:?}", d),
+        Err(e) => println!("거꾸로 뺌 → Err, 얼마나 거꾸로? {:?}", e.duration()),
+    }
+    // elapsed() 도 SystemTime 에서는 Result
+    println!("SystemTime::elapsed 의 결과가 Ok? {}", now.elapsed().is_ok());
+
+    // 함정 2: Instant 끼리 빼기 - 순서가 바뀌면?
+    // Instant - Instant 는 (현재 버전에서) 0 으로 포화되지만, 의도를 드러내려면 명시적인 메서드를
+    let a = Instant::now();
+    let b = a + Duration::from_millis(10);
+    println!("a.checked_duration_since(b) = {:?}", a.checked_duration_since(b));
+    println!("a.saturating_duration_since(b) = {:?}", a.saturating_duration_since(b));
+    println!("b.duration_since(a) = {:?}", b.duration_since(a));
+
+    // 함정 3: sleep 은 "적어도" 그만큼 - 정확한 주기가 아님
+    // 반복마다 sleep(10ms) 하면 처리 시간과 지연이 쌓임 (drift)
+    // 다음 기준 시각을 더해 나가고, 남은 시간만큼만 잠
+    let start = Instant::now();
+    let period = Duration::from_millis(5);
+    let mut next = start;
+    for _ in 0..4 {
+        next += period;
+        thread::sleep(next.saturating_duration_since(Instant::now()));
+    }
+    println!("5ms 주기 4번 - 기준 시각 방식으로 20ms 이상? {}", start.elapsed() >= Duration::from_millis(20));
+
+    // 함정 4: 너무 작은 시간을 재기 - 시계의 해상도와 호출 비용보다 작으면 의미 없음
+    // 여러 번 반복해 합계를 재거나 벤치마크 도구(crate::microbench, criterion)로
+}
+
+// ----------------------------------------------------------------------------
+// 시간 제한
+// ----------------------------------------------------------------------------
+
+// 마감 시각 - 여러 단계에 걸친 작업의 전체 시간 제한을 한 번만 정하고 단계마다 남은 시간을 씀
+#[derive(Debug, Clone, Copy)]
+pub struct Deadline(Instant);
+
+impl Deadline {
+    pub fn after(timeout: Duration) -> Deadline {
+        Deadline(Instant::now() + timeout)
+    }
+
+    // 남은 시간 - 이미 지났으면 None
+    pub fn remaining(&self) -> Option<Duration> {
+        self.0.checked_duration_since(Instant::now()).filter(|d| !d.is_zero())
+    }
+
+    pub fn expired(&self) -> bool {
+        self.remaining().is_none()
+    }
+}
+
+fn timeouts() {
+    println!("\n--- 시간 제한 ---");
+
+    // C++ 에서는:
+    // auto status = future.wait_for(100ms);               // std::future_status::timeout
+    // cv.wait_until(lock, deadline, pred);
+
+    // 블로킹 API 는 *_timeout 판이 있음 - recv_timeout, Condvar::wait_timeout, set_read_timeout(37장)
+    let (tx, rx) = mpsc::channel();
+    let worker = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        let _ = tx.send("늦은 결과");
+    });
+    match rx.recv_timeout(Duration::from_millis(20)) {
+        Ok(v) => println!("받음: {}", v),
+        Err(e) => println!("recv_timeout(20ms): {:?}", e),
+    }
+    // 시간이 지났다고 작업이 멈추지는 않음 - 기다리기만 그만둔 것 (취소는 직접 알려야 함, 12/17장)
+    println!("더 기다리면: {:?}", rx.recv_timeout(Duration::from_secs(2)));
+    worker.join().unwrap();
+
+    // 여러 단계에 걸친 전체 시간 제한 - 단계마다 고정 시간을 주면 합이 제한을 넘음
+    let deadline = Deadline::after(Duration::from_millis(100));
+    let mut finished = 0;
+    for _ in 0..10 {
+        let Some(left) = deadline.remaining() else {
+            break;
+        };
+        // 단계 하나가 30ms 걸린다고 치고 - 남은 시간보다 길면 남은 만큼만
+        thread::sleep(left.min(Duration::from_millis(30)));
+        if !deadline.expired() {
+            finished += 1;
+        }
+    }
+    // 몇 단계를 마쳤는지는 스케줄링에 따라 다름 (보통 3개)
+    println!("10단계를 다 못 하고 마감? {}, 마감 지남? {}", finished < 10, deadline.expired());
+
+    // async 에서는 tokio::time::timeout(d, future) - 38장의 유휴 연결 시간 제한
+}
+
+// ----------------------------------------------------------------------------
+// Clock 트레이트 - 시간에 의존하는 코드를 테스트하기
+// ----------------------------------------------------------------------------
+
+// 지금 시각을 묻는 곳을 트레이트 하나로 - 실제 코드는 SystemClock, 테스트는 FakeClock
+// C++ 에서는 템플릿 매개변수(template<class Clock>)나 가상 함수 인터페이스
+pub trait Clock {
+    fn now(&self) -> Instant;
+    fn wall(&self) -> SystemTime;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn wall(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+// 손으로 돌리는 시계 - Instant 는 직접 만들 수 없으므로 만든 순간을 기준으로 더해 감
+// Cell 이라 &self 로 앞당길 수 있음 (한 스레드용, 여러 스레드면 Mutex)
+pub struct FakeClock {
+    base: Instant,
+    wall_base: SystemTime,
+    offset: Cell<Duration>,
+}
+
+impl FakeClock {
+    pub fn starting_at(wall: SystemTime) -> FakeClock {
+        FakeClock { base: Instant::now(), wall_base: wall, offset: Cell::new(Duration::ZERO) }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.offset.set(self.offset.get() + by);
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> Instant {
+        self.base + self.offset.get()
+    }
+
+    fn wall(&self) -> SystemTime {
+        self.wall_base + self.offset.get()
+    }
+}
+
+// 시계를 빌려 쓰는 쪽도 Clock - TtlCache<&FakeClock> 으로 만들고 바깥에서 advance
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn wall(&self) -> SystemTime {
+        (**self).wall()
+    }
+}
+
+// 시계에 의존하는 예: 항목마다 유효 시간(TTL)이 있는 캐시
+pub struct TtlCache<C: Clock> {
+    clock: C,
+    ttl: Duration,
+    entries: HashMap<String, (String, Instant)>,
+}
+
+impl<C: Clock> TtlCache<C> {
+    pub fn new(clock: C, ttl: Duration) -> Self {
+        TtlCache { clock, ttl, entries: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        let expires = self.clock.now() + self.ttl;
+        self.entries.insert(key.to_string(), (value.to_string(), expires));
+    }
+
+    // 만료된 항목은 없는 것과 같음 - 읽을 때 지움
+    pub fn get(&mut self, key: &str) -> Option<&str> {
+        let now = self.clock.now();
+        if self.entries.get(key).is_some_and(|(_, expires)| *expires <= now) {
+            self.entries.remove(key);
+        }
+        self.entries.get(key).map(|(value, _)| value.as_str())
+    }
+
+    // 사람에게 보여 줄 만료 시각 - 벽시계 기준
+    pub fn expires_at(&self, key: &str) -> Option<String> {
+        let (_, expires) = self.entries.get(key)?;
+        let left = expires.saturating_duration_since(self.clock.now());
+        Some(format_utc(self.clock.wall() + left))
+    }
+}
+
+fn clock_trait() {
+    println!("\n--- Clock 트레이트 ---");
+
+    // 실제 시계로는 "1분 뒤에 만료되는지" 를 보려면 1분을 기다려야 함
+    // 가짜 시계는 advance 한 번 - 테스트가 빠르고 결과가 항상 같음
+    let clock = FakeClock::starting_at(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+    let mut cache = TtlCache::new(&clock, Duration::from_secs(60));
+    cache.insert("session", "민수");
+    println!("지금 {} - 만료 {:?}", format_utc(clock.wall()), cache.expires_at("session"));
+    println!("바로 읽기: {:?}", cache.get("session"));
+
+    clock.advance(Duration::from_secs(59));
+    println!("59초 뒤 ({}): {:?}", format_utc(clock.wall()), cache.get("session"));
+
+    clock.advance(Duration::from_secs(1));
+    println!("60초 뒤 ({}): {:?}", format_utc(clock.wall()), cache.get("session"));
+
+    // 실제 코드에서는 같은 타입에 SystemClock 을 넣음 - 캐시 코드는 바뀌지 않음
+    let mut real = TtlCache::new(SystemClock, Duration::from_secs(60));
+    real.insert("k", "v");
+    println!("SystemClock 캐시: {:?}", real.get("k"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps_round_trip_and_cache_expires_on_fake_clock() {
+        assert_eq!(format_utc(UNIX_EPOCH + Duration::from_secs(1_700_000_000)), "2023-11-14T22:13:20Z");
+        for text in ["1970-01-01T00:00:00Z", "2000-02-29T12:34:56Z", "2038-01-19T03:14:08Z"] {
+            assert_eq!(parse_utc(text).map(format_utc).as_deref(), Some(text));
+        }
+        assert_eq!(parse_utc("2100-02-29T00:00:00Z"), None);
+
+        let clock = FakeClock::starting_at(UNIX_EPOCH);
+        let mut cache = TtlCache::new(&clock, Duration::from_secs(10));
+        cache.insert("a", "1");
+        clock.advance(Duration::from_secs(9));
+        assert_eq!(cache.get("a"), Some("1"));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(cache.get("a"), None);
+    }
+}
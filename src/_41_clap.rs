@@ -0,0 +1,389 @@
+// ============================================================================
+// 41. clap 으로 명령줄 도구 만들기
+// ============================================================================
+// 구조체에 #[derive(Parser)] 를 붙이면 인자 파싱, 검증, 에러 메시지, --help 가 한꺼번에
+// 이 저장소도 장 고르기(--from 05 --to 09)를 clap 으로 파싱 (src/main.rs 의 Selection)
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 는 getopt/getopt_long (POSIX), Boost.Program_options, CLI11, cxxopts
+//    옵션을 문자열로 등록하고 값을 꺼낼 때 타입을 다시 적음 (vm["count"].as<int>())
+//    clap derive 는 구조체의 필드가 곧 옵션 - 파싱 결과가 처음부터 타입이 있는 값
+// 2. 필드 타입이 규칙이 됨 - Option<T> 는 선택, Vec<T> 는 여러 개, bool 은 플래그
+// 3. 서브커맨드는 enum - match 가 빠진 명령을 컴파일 에러로 알려 줌
+// 4. 값 변환은 FromStr 이나 함수 하나 - 잘못된 값은 main 에 오기 전에 에러 메시지로
+// 5. 도움말은 /// 문서 주석에서 생성 - 코드와 도움말이 어긋나지 않음
+// ============================================================================
+
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::error::ErrorKind;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+
+// 절 목록 (실행 순서) - cargo run -- 41:derive_parsing 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("derive_parsing", derive_parsing),
+    ("subcommands", subcommands),
+    ("value_parsing", value_parsing),
+    ("help_text", help_text),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "41"
+    }
+
+    fn name(&self) -> &'static str {
+        "clap 으로 명령줄 도구 만들기"
+    }
+
+    fn description(&self) -> &'static str {
+        "derive 로 인자 파싱, 서브커맨드 enum, 새 타입과 함수로 값 검증, 문서 주석에서 만든 도움말"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["clap", "derive(Parser)", "Subcommand", "ValueEnum", "FromStr", "getopt", "--help"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// 이 장의 예제는 실제 명령줄 대신 고정된 인자 배열로 파싱 (try_parse_from) - 결과가 항상 같음
+// 실제 프로그램에서는 Cli::parse() 한 줄 - 에러면 메시지를 출력하고 종료 코드 2 로 끝남
+fn argv(line: &str) -> Vec<&str> {
+    line.split_whitespace().collect()
+}
+
+// 에러 메시지를 한 줄로 - 빈 줄, 사용법, --help 안내는 빼고
+fn one_line(e: &clap::Error) -> String {
+    e.to_string()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("Usage:") && !line.starts_with("For more information"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ----------------------------------------------------------------------------
+// derive 로 인자 파싱
+// ----------------------------------------------------------------------------
+
+// grep 을 흉내 낸 작은 도구 - 필드의 /// 주석이 --help 의 설명이 됨
+// (이 장에서만 // 대신 /// - clap 이 읽는 문서 주석)
+/// 파일에서 패턴이 든 줄을 찾습니다
+#[derive(Parser, Debug)]
+#[command(name = "mini-grep", version = "0.1.0")]
+pub struct GrepArgs {
+    /// 찾을 패턴
+    pub pattern: String,
+
+    /// 찾을 파일들 (없으면 표준 입력)
+    pub paths: Vec<PathBuf>,
+
+    /// 대소문자 구분 없이
+    #[arg(short, long)]
+    pub ignore_case: bool,
+
+    /// 줄 번호도 출력
+    #[arg(short = 'n', long)]
+    pub line_number: bool,
+
+    /// 이만큼 찾으면 멈춤
+    #[arg(short, long, value_name = "N")]
+    pub max_count: Option<usize>,
+
+    /// 자세히 출력 (-v, -vv 처럼 여러 번)
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+}
+
+fn derive_parsing() {
+    println!("--- derive 로 인자 파싱 ---");
+
+    // C++ 에서는 (getopt_long):
+    // static option opts[] = {{"ignore-case", no_argument, nullptr, 'i'}, {"max-count", required_argument, nullptr, 'm'}, ...};
+    // while ((c = getopt_long(argc, argv, "inm:v", opts, nullptr)) != -1) switch (c) { case 'm': max = atoi(optarg); ... }
+    // 위치 인자는 optind 부터 직접, atoi 는 "abc" 를 0 으로 - 검증도 직접
+
+    for line in [
+        "mini-grep fn src/main.rs",
+        "mini-grep -in FIXME a.rs b.rs",
+        "mini-grep --max-count 3 -vv unsafe",
+        "mini-grep --max-count=5 -- -pattern-with-dash",
+    ] {
+        match GrepArgs::try_parse_from(argv(line)) {
+            Ok(args) => println!("{:<48} → {:?}", line, args),
+            Err(e) => println!("{:<48} → {}", line, one_line(&e)),
+        }
+    }
+
+    // 틀린 입력은 파싱 단계에서 - 타입이 맞지 않거나 필수 인자가 없거나 모르는 옵션
+    for line in ["mini-grep", "mini-grep -m many fn", "mini-grep --colour fn"] {
+        match GrepArgs::try_parse_from(argv(line)) {
+            Ok(args) => println!("{:<24} → {:?}", line, args),
+            Err(e) => println!("{:<24} → {:?}: {}", line, e.kind(), one_line(&e)),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 서브커맨드
+// ----------------------------------------------------------------------------
+
+// git 처럼 "프로그램 명령 [옵션]" - 명령마다 enum 변형 하나
+/// 장 단위 학습 도구 (예제용)
+#[derive(Parser, Debug)]
+#[command(name = "study")]
+pub struct StudyCli {
+    /// 출력 없이
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    #[command(subcommand)]
+    pub command: StudyCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StudyCommand {
+    /// 장을 실행
+    Run {
+        /// 실행할 장 (번호)
+        #[arg(required = true)]
+        chapters: Vec<ChapterId>,
+    },
+    /// 퀴즈 풀기
+    Quiz {
+        chapter: ChapterId,
+        /// 문제 수 (1~20)
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u8).range(1..=20))]
+        count: u8,
+    },
+    /// 설정 읽고 쓰기
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+// 서브커맨드 안의 서브커맨드 - study config get lang
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    Get { key: String },
+    Set { key: String, value: String },
+}
+
+// 파싱 결과를 실행 - enum 이라 빠뜨린 명령은 컴파일 에러
+fn execute(cli: &StudyCli) -> String {
+    let said = match &cli.command {
+        StudyCommand::Run { chapters } => {
+            let ids: Vec<String> = chapters.iter().map(ToString::to_string).collect();
+            format!("{} 장 실행", ids.join(", "))
+        }
+        StudyCommand::Quiz { chapter, count } => format!("{}장 퀴즈 {}문제", chapter, count),
+        StudyCommand::Config(ConfigCommand::Get { key }) => format!("설정 {} 읽기", key),
+        StudyCommand::Config(ConfigCommand::Set { key, value }) => format!("설정 {} = {}", key, value),
+    };
+    if cli.quiet {
+        format!("(조용히) {}", said)
+    } else {
+        said
+    }
+}
+
+fn subcommands() {
+    println!("\n--- 서브커맨드 ---");
+
+    // C++ 에서는 argv[1] 을 strcmp 로 나누고 명령마다 getopt 를 다시 (optind = 1 초기화에 주의)
+    // CLI11 은 app.add_subcommand("run") 과 콜백
+
+    for line in [
+        "study run 7 09 11",
+        "study quiz 13 --count 10",
+        "study quiz 13",
+        "study config set lang en -q",
+        "study config get lang",
+    ] {
+        match StudyCli::try_parse_from(argv(line)) {
+            Ok(cli) => println!("{:<30} → {}", line, execute(&cli)),
+            Err(e) => println!("{:<30} → {}", line, one_line(&e)),
+        }
+    }
+
+    // 명령이 틀리면 비슷한 이름을 제안
+    for line in ["study rum 7", "study run", "study quiz 13 --count 50"] {
+        match StudyCli::try_parse_from(argv(line)) {
+            Ok(cli) => println!("{:<26} → {}", line, execute(&cli)),
+            Err(e) => println!("{:<26} → {}", line, one_line(&e)),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 값 파싱 - 새 타입으로 검증
+// ----------------------------------------------------------------------------
+
+// 장 번호 - 파싱이 끝나면 항상 있는 장 (5장의 새 타입 패턴)
+// FromStr 만 구현하면 clap 이 값 파서로 씀
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChapterId(u8);
+
+pub const LAST_CHAPTER: u8 = 41;
+
+impl FromStr for ChapterId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let n: u8 = s.parse().map_err(|_| format!("장 번호는 숫자입니다: '{}'", s))?;
+        if (1..=LAST_CHAPTER).contains(&n) {
+            Ok(ChapterId(n))
+        } else {
+            Err(format!("장은 1~{} 입니다", LAST_CHAPTER))
+        }
+    }
+}
+
+impl std::fmt::Display for ChapterId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+// FromStr 을 쓰기 어려운 타입(Duration 은 남의 타입)은 함수로 - value_parser = parse_duration
+// "500ms", "2s", "1m"
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let n: u64 = number.parse().map_err(|_| format!("숫자로 시작해야 합니다: '{}'", s))?;
+    match unit {
+        "ms" => Ok(Duration::from_millis(n)),
+        "s" | "" => Ok(Duration::from_secs(n)),
+        "m" => Ok(Duration::from_secs(n * 60)),
+        _ => Err(format!("단위는 ms, s, m 중 하나: '{}'", unit)),
+    }
+}
+
+// 정해진 값 중 하나 - ValueEnum 이 "text", "json", "markdown" 을 만들고 도움말에도 보여 줌
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Markdown,
+}
+
+/// 장을 시간 제한과 함께 실행
+#[derive(Parser, Debug)]
+#[command(name = "timed")]
+pub struct TimedArgs {
+    /// 실행할 장
+    pub chapter: ChapterId,
+
+    /// 시간 제한 (예: 500ms, 2s, 1m)
+    #[arg(long, value_parser = parse_duration, default_value = "30s")]
+    pub timeout: Duration,
+
+    /// 출력 형식
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// 예제 데이터 시드 (clap 의 env 기능을 켜면 #[arg(env = "STUDY_SEED")] 로 환경 변수에서도)
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+fn value_parsing() {
+    println!("\n--- 값 파싱 - 새 타입으로 검증 ---");
+
+    // C++ 에서는 (Boost.Program_options):
+    // ("timeout", po::value<std::string>(), ...) 로 받고 나서 직접 "500ms" 를 해석
+    // 검증 실패를 알리는 방법(예외, 에러 코드)도 직접 정함
+
+    for line in [
+        "timed 7",
+        "timed 07 --timeout 500ms --format json",
+        "timed 40 --timeout 1m --seed 42 --format markdown",
+    ] {
+        match TimedArgs::try_parse_from(argv(line)) {
+            Ok(args) => println!(
+                "{:<50} → {}장, {:?}, {:?}, 시드 {:?}",
+                line, args.chapter, args.timeout, args.format, args.seed
+            ),
+            Err(e) => println!("{:<50} → {}", line, one_line(&e)),
+        }
+    }
+
+    // 검증 실패 - 우리가 만든 메시지가 clap 의 에러에 들어감
+    for line in ["timed 99", "timed seven", "timed 7 --timeout 3h", "timed 7 --format yaml"] {
+        match TimedArgs::try_parse_from(argv(line)) {
+            Ok(args) => println!("{:<24} → {:?}", line, args),
+            Err(e) => println!("{:<24} → {}", line, one_line(&e)),
+        }
+    }
+
+    // 파싱이 끝난 값은 타입이 보장 - ChapterId 를 받는 함수는 범위를 다시 검사하지 않아도 됨
+    // ("검증하지 말고 파싱하라" - 검증 결과를 타입으로 남김)
+}
+
+// ----------------------------------------------------------------------------
+// 도움말과 에러
+// ----------------------------------------------------------------------------
+
+fn help_text() {
+    println!("\n--- 도움말과 에러 ---");
+
+    // --help 와 --version 은 자동 - try_parse_from 에서는 "에러" 로 돌아옴 (출력하고 끝내라는 뜻)
+    match GrepArgs::try_parse_from(["mini-grep", "--version"]) {
+        Ok(_) => println!("파싱됨?"),
+        Err(e) => println!("--version → {:?}: {}", e.kind(), e.to_string().trim_end()),
+    }
+    if let Err(e) = GrepArgs::try_parse_from(["mini-grep", "--help"]) {
+        println!("--help → {:?}, 종료 코드 {}", e.kind(), e.exit_code());
+    }
+
+    // 도움말 문자열을 직접 얻기 - 문서 주석, 기본값, 값 목록이 들어 있음
+    println!("mini-grep --help:");
+    print!("{}", GrepArgs::command().render_help());
+    println!();
+    println!("study --help:");
+    print!("{}", StudyCli::command().render_help());
+
+    // 에러 종류로 나눠 처리 - 종료 코드는 사용법 에러가 2 (getopt 관례), 도움말은 0
+    for line in ["timed", "timed 7 --frmat json"] {
+        if let Err(e) = TimedArgs::try_parse_from(argv(line)) {
+            let what = match e.kind() {
+                ErrorKind::MissingRequiredArgument => "필수 인자 빠짐",
+                ErrorKind::UnknownArgument => "모르는 옵션",
+                _ => "기타",
+            };
+            println!("{:<22} → {} (종료 코드 {})", line, what, e.exit_code());
+        }
+    }
+
+    // 실제 main 에서는:
+    //   let args = GrepArgs::parse();   // 에러면 e.exit() - 메시지 출력 후 종료
+    // 라이브러리 쪽 검증이 더 있으면: GrepArgs::command().error(ErrorKind::ValueValidation, "...").exit()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsers_are_consistent_and_validate_values() {
+        // 같은 이름의 옵션이나 잘못된 설정을 개발 중에 잡아 줌
+        GrepArgs::command().debug_assert();
+        StudyCli::command().debug_assert();
+        TimedArgs::command().debug_assert();
+
+        let args = TimedArgs::try_parse_from(["timed", "07", "--timeout", "250ms"]).unwrap();
+        assert_eq!(args.chapter, ChapterId(7));
+        assert_eq!(args.timeout, Duration::from_millis(250));
+        assert_eq!(args.format, Format::Text);
+        assert!(TimedArgs::try_parse_from(["timed", "0"]).is_err());
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_duration("ms").is_err());
+    }
+}
@@ -0,0 +1,346 @@
+// ============================================================================
+// 42. 속성 기반 테스트
+// ============================================================================
+// 예제 입력 몇 개 대신 "모든 입력에 대해 성립해야 하는 성질" 을 적고, 입력은 라이브러리가 만듦
+// 실패하면 그 입력을 가장 작은 반례로 줄여서(축소, shrinking) 보여 줌
+// 19장의 add, is_even 과 16장의 MyVec 을 대상으로 - 16장의 직접 만든 생성기(equivalence::Gen)와 비교
+// quickcheck 크레이트도 같은 발상 (타입마다 Arbitrary) - 여기서는 전략을 값으로 조립하는 proptest
+//
+// C++20과의 핵심 차이점:
+// 1. gtest 의 TEST_P + INSTANTIATE_TEST_SUITE_P(Values(...)) 는 사람이 고른 입력 목록
+//    proptest 는 입력을 만드는 방법(Strategy)을 적고 매번 수백 개를 생성
+// 2. C++ 에도 RapidCheck 같은 라이브러리가 있지만 표준 도구 흐름 밖 - Rust 는 cargo test 에 그대로 섞임
+// 3. 실패 입력 축소가 자동 - 퍼저가 찾은 긴 입력을 손으로 줄이던 일을 라이브러리가 함
+// 4. 실패한 시드는 proptest-regressions/ 파일에 남아 다음 실행에서 먼저 다시 시도됨
+// 5. 생성기는 타입이 있는 값 (Strategy<Value = T>) - prop_map, prop_filter 로 조립
+// ============================================================================
+
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::{Config, RngAlgorithm, TestError, TestRng, TestRunner};
+
+use crate::_16_unsafe::equivalence::{apply, Op};
+use crate::_16_unsafe::safe_wrapper::MyVec;
+use crate::_19_testing::{add, is_even};
+
+// 절 목록 (실행 순서) - cargo run -- 42:properties 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("properties", properties),
+    ("strategies", strategies),
+    ("shrinking", shrinking),
+    ("model_testing", model_testing),
+    ("proptest_macro", proptest_macro),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "42"
+    }
+
+    fn name(&self) -> &'static str {
+        "속성 기반 테스트"
+    }
+
+    fn description(&self) -> &'static str {
+        "성질을 적고 입력은 생성, 전략 조립, 실패 입력 축소, Vec 을 모델로 MyVec 검사, proptest! 매크로"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["proptest", "quickcheck", "Strategy", "shrinking", "TEST_P", "모델 기반 테스트"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// 장 출력이 매번 같도록 시드를 고정한 실행기 - proptest! 매크로는 실행마다 새 시드를 씀
+fn runner() -> TestRunner {
+    let config = Config { failure_persistence: None, ..Config::default() };
+    TestRunner::new_with_rng(config, TestRng::deterministic_rng(RngAlgorithm::ChaCha))
+}
+
+// 성질 하나를 검사하고 결과를 한 줄로
+fn report<S>(name: &str, strategy: S, test: impl Fn(S::Value) -> Result<(), TestCaseError>)
+where
+    S: Strategy,
+    S::Value: std::fmt::Debug,
+{
+    let mut runner = runner();
+    let cases = runner.config().cases;
+    match runner.run(&strategy, test) {
+        Ok(()) => println!("  {:<28} 통과 ({} 개 입력)", name, cases),
+        Err(TestError::Fail(_, input)) => println!("  {:<28} 실패, 축소된 반례: {:?}", name, input),
+        Err(TestError::Abort(reason)) => println!("  {:<28} 중단: {}", name, reason),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 성질 (property)
+// ----------------------------------------------------------------------------
+// 예제 테스트: "add(2, 3) == 5" - 고른 입력에서만 맞음을 확인
+// 속성 테스트: "모든 a, b 에 대해 add(a, b) == add(b, a)" - 구현을 다시 적지 않고 관계만 적음
+// 좋은 성질의 모양: 교환/결합 법칙, 역연산으로 되돌리기, 다른 구현과 같음, 불변식 유지
+
+fn properties() {
+    println!("--- 성질을 적고 입력은 생성 ---");
+
+    // C++ 에서는 (gtest):
+    // class AddTest : public ::testing::TestWithParam<std::tuple<int, int>> {};
+    // TEST_P(AddTest, Commutative) { auto [a, b] = GetParam(); EXPECT_EQ(add(a, b), add(b, a)); }
+    // INSTANTIATE_TEST_SUITE_P(Some, AddTest, ::testing::Values(std::tuple{1, 2}, std::tuple{-3, 4}));
+    // - 입력은 사람이 고른 목록이 전부
+
+    // 범위를 좁힌 이유: add 는 a + b 라 i32 전체에서는 디버그 빌드에서 오버플로 panic
+    // (성질을 적다 보면 "정말 모든 입력에서?" 를 묻게 됨 - 이것도 속성 테스트의 수확)
+    let small = -10_000..10_000i32;
+
+    report("add 교환 법칙", (small.clone(), small.clone()), |(a, b)| {
+        prop_assert_eq!(add(a, b), add(b, a));
+        Ok(())
+    });
+    report("add 항등원 0", small.clone(), |a| {
+        prop_assert_eq!(add(a, 0), a);
+        Ok(())
+    });
+    report("짝+짝, 홀+홀 은 짝", (small.clone(), small), |(a, b)| {
+        prop_assert_eq!(is_even(add(a, b)), is_even(a) == is_even(b));
+        Ok(())
+    });
+    report("is_even 은 2 로 나눈 나머지", any::<i32>(), |n| {
+        prop_assert_eq!(is_even(n), n.rem_euclid(2) == 0);
+        Ok(())
+    });
+}
+
+// ----------------------------------------------------------------------------
+// 전략 (Strategy) - 입력 생성기
+// ----------------------------------------------------------------------------
+// any::<T>()       타입의 모든 값 (Arbitrary 구현)
+// 0..100, 'a'..='z' 범위 그대로가 전략
+// "[a-z]{1,8}"     정규식이 문자열 전략
+// (s1, s2)         튜플, prop::collection::vec(s, 0..10) 컬렉션
+// prop_map         값 변환, prop_filter 는 조건 (너무 많이 버리면 Abort)
+// prop_oneof![..]  여러 전략 중 하나 - enum 값 만들기
+
+// 16장 equivalence::ops 를 전략으로 - 같은 Op 를 만들지만 축소가 따라옴
+fn op() -> impl Strategy<Value = Op> {
+    let value = -100..=100i32;
+    prop_oneof![
+        value.clone().prop_map(Op::Push),
+        Just(Op::Pop),
+        (0..64usize, value).prop_map(|(i, v)| Op::Insert(i, v)),
+        (0..64usize).prop_map(Op::Remove),
+    ]
+}
+
+// 전략에서 값 n 개 뽑기 - new_tree 는 "값 + 줄이는 방법", current() 가 지금 값
+fn samples<S: Strategy>(strategy: &S, n: usize) -> Vec<S::Value> {
+    let mut runner = runner();
+    (0..n).map(|_| strategy.new_tree(&mut runner).unwrap().current()).collect()
+}
+
+fn strategies() {
+    println!("\n--- 전략: 입력을 만드는 값 ---");
+
+    // C++ 에서는:
+    // ::testing::Range(0, 100), ::testing::Combine(Values(...), Bool()) 정도가 전부
+    // 임의 입력은 std::mt19937 + std::uniform_int_distribution 을 직접 짜고, 실패하면 시드를 출력
+
+    let evens = prop::collection::vec((-50..50i32).prop_map(|n| n * 2), 0..8);
+    let generated = samples(&evens, 100);
+    println!("prop_map(|n| n * 2) 로 만든 Vec 100 개:");
+    println!("  모두 짝수: {}", generated.iter().flatten().all(|&n| is_even(n)));
+    println!("  길이 0..8: {}", generated.iter().all(|v| v.len() < 8));
+    println!("  빈 Vec 도 나옴: {}", generated.iter().any(|v| v.is_empty()));
+
+    let words = samples(&"[a-z]{1,8}", 100);
+    let lowercase = |w: &String| (1..=8).contains(&w.len()) && w.bytes().all(|b| b.is_ascii_lowercase());
+    println!("정규식 \"[a-z]{{1,8}}\" 문자열 100 개:");
+    println!("  모두 소문자 1~8 자: {}", words.iter().all(lowercase));
+
+    let generated = samples(&prop::collection::vec(op(), 0..20), 100);
+    let kinds = [
+        generated.iter().flatten().any(|o| matches!(o, Op::Push(_))),
+        generated.iter().flatten().any(|o| matches!(o, Op::Pop)),
+        generated.iter().flatten().any(|o| matches!(o, Op::Insert(..))),
+        generated.iter().flatten().any(|o| matches!(o, Op::Remove(_))),
+    ];
+    println!("prop_oneof! 로 만든 Op 목록 100 개:");
+    println!("  Push/Pop/Insert/Remove 가 모두 나옴: {}", kinds.iter().all(|&k| k));
+}
+
+// ----------------------------------------------------------------------------
+// 축소 (shrinking)
+// ----------------------------------------------------------------------------
+// 실패한 입력을 찾으면 "더 단순한 값" 으로 바꿔 가며 여전히 실패하는지 다시 돌려 봄
+// 정수는 0 쪽으로, Vec 은 원소를 빼고 원소 자체도 줄임 - 더 줄일 수 없을 때의 값이 보고됨
+// 처음 찾은 반례가 [37, -88, 12, 95, ...] 여도 보고는 최소 반례 하나
+// 단 축소는 탐색이라 항상 "가장" 작지는 않음 - 정수는 0 쪽으로 이분 탐색이라
+// 중간값이 성질을 통과하면(아래 is_odd 의 짝수) 거기서 멈춤
+
+// 일부러 틀린 구현 - 음수의 % 는 음수 (-3 % 2 == -1) 라 음수 홀수에서 false
+fn is_odd_buggy(n: i32) -> bool {
+    n % 2 == 1
+}
+
+// 일부러 틀린 구현 - 마지막 원소를 보지 않음 (끝 인덱스를 하나 덜 센 실수)
+fn largest_buggy(v: &[i32]) -> Option<i32> {
+    v[..v.len().saturating_sub(1)].iter().copied().max().or(v.first().copied())
+}
+
+fn shrinking() {
+    println!("\n--- 축소: 실패 입력을 가장 작게 ---");
+
+    // C++ 에서는:
+    // 임의 입력 테스트가 실패하면 "seed=3141592 에서 실패" 만 남음
+    // 1000 개짜리 입력을 손으로 잘라 가며 어느 부분이 문제인지 찾아야 함
+
+    report("is_odd 와 is_even 은 반대", any::<i32>(), |n| {
+        prop_assert_ne!(is_odd_buggy(n), is_even(n));
+        Ok(())
+    });
+    report("largest 는 max 와 같음", prop::collection::vec(any::<i32>(), 0..50), |v| {
+        prop_assert_eq!(largest_buggy(&v), v.iter().copied().max());
+        Ok(())
+    });
+
+    // 고친 구현은 같은 성질을 통과
+    report("n % 2 != 0 으로 고치면", any::<i32>(), |n| {
+        prop_assert_ne!(n % 2 != 0, is_even(n));
+        Ok(())
+    });
+    println!("[0, 1] 은 원인(마지막 원소)이 바로 보임");
+    println!("정수 반례는 -1 까지 못 갈 수 있음 (이분 탐색) - 그래도 \"음수 홀수\" 라는 모양은 드러남");
+}
+
+// ----------------------------------------------------------------------------
+// 모델 기반 테스트 - 검증된 구현과 비교
+// ----------------------------------------------------------------------------
+// 16장의 MyVec (unsafe 로 만든 컨테이너) 을 Vec 과 같은 연산 목록으로 돌려 결과를 비교
+// 16장 equivalence 는 splitmix64 생성기 + 고정 시드 - 실패하면 긴 연산 목록이 그대로 보고됨
+// 여기서는 연산 목록을 전략으로 만들어 같은 apply 로 비교 - 실패하면 연산 몇 개로 줄어듦
+
+fn model_testing() {
+    println!("\n--- 모델 기반 테스트: Vec 과 MyVec ---");
+
+    // C++ 에서는:
+    // 직접 만든 컨테이너와 std::vector 에 같은 연산을 넣어 비교 - 생성기와 축소는 직접 작성
+
+    let ops = || prop::collection::vec(op(), 0..40);
+    report("MyVec == Vec (연산 0~39 개)", ops(), |ops| {
+        prop_assert_eq!(apply::<MyVec<i32>>(&ops), apply::<Vec<i32>>(&ops));
+        Ok(())
+    });
+
+    // 버그가 있는 "구현" 을 넣어 보면 - 두 번째 Push 부터 값이 1 씩 틀리는 컨테이너
+    let buggy = |ops: &[Op]| {
+        let mut pushed = 0;
+        let altered: Vec<Op> = ops
+            .iter()
+            .map(|&op| match op {
+                Op::Push(v) => {
+                    pushed += 1;
+                    Op::Push(if pushed >= 2 { v + 1 } else { v })
+                }
+                other => other,
+            })
+            .collect();
+        apply::<Vec<i32>>(&altered)
+    };
+    report("버그 컨테이너 == Vec", ops(), |ops| {
+        prop_assert_eq!(buggy(&ops), apply::<Vec<i32>>(&ops));
+        Ok(())
+    });
+    println!("최소 반례는 Push 두 번 - 원인이 된 연산만 남음");
+}
+
+// ----------------------------------------------------------------------------
+// proptest! 매크로 - cargo test 에서 쓰는 모양
+// ----------------------------------------------------------------------------
+// 이 파일 하단 mod tests 에 실제로 있음 - 위 절의 성질을 #[test] 로
+//
+// proptest! {
+//     #![proptest_config(ProptestConfig::with_cases(500))]
+//     #[test]
+//     fn add_commutes(a in -10_000..10_000i32, b in -10_000..10_000i32) {
+//         prop_assert_eq!(add(a, b), add(b, a));
+//     }
+// }
+//
+// - 인자 자리에 `이름 in 전략` - 함수 몸체는 보통 테스트처럼
+// - assert! 대신 prop_assert! - panic 대신 Err 를 돌려줘서 축소가 빠르고 메시지가 깔끔
+// - 실패하면 proptest-regressions/<모듈 경로>.txt 에 시드 저장 - 저장소에 커밋해 두면 회귀 테스트
+// - 입력 개수: PROPTEST_CASES=10000 cargo test 로 늘림 (기본 256)
+
+// cargo test 가 proptest! 테스트 실패를 보고하는 모양 (largest_buggy 를 검사했을 때)
+const FAILURE_OUTPUT: &str = "\
+Test failed: assertion failed: `(left == right)`
+  left: `Some(0)`,
+ right: `Some(1)`.
+minimal failing input: v = [0, 1]
+        successes: 3
+        local rejects: 0
+        global rejects: 0";
+
+fn proptest_macro() {
+    println!("\n--- proptest! 매크로 ---");
+
+    // C++ 에서는:
+    // TEST_P 는 INSTANTIATE_TEST_SUITE_P 로 고른 값마다 테스트 이름이 하나씩 생김
+    // proptest! 의 테스트는 하나 - 안에서 입력을 수백 개 만들고 실패하면 최소 반례를 출력
+
+    println!("#[test] 하나 = 입력 {} 개 (PROPTEST_CASES 로 조절)", Config::default().cases);
+    println!("실패 출력 예:");
+    for line in FAILURE_OUTPUT.lines() {
+        println!("  | {}", line);
+    }
+    println!("실패 시드 파일: proptest-regressions/_42_proptest.txt (커밋하면 다음 실행에서 먼저 시도)");
+    println!("예제 테스트(19장) 와 함께 - 경계값은 예제로 못 박고, 나머지는 성질로");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinking_reports_small_counterexamples() {
+        let mut runner = runner();
+        match runner.run(&any::<i32>(), |n| {
+            prop_assert_ne!(is_odd_buggy(n), is_even(n));
+            Ok(())
+        }) {
+            Err(TestError::Fail(_, n)) => assert!(n < 0 && !is_even(n), "음수 홀수가 아님: {}", n),
+            other => panic!("반례를 찾지 못함: {:?}", other),
+        }
+
+        let mut runner = super::runner();
+        match runner.run(&prop::collection::vec(any::<i32>(), 0..50), |v| {
+            prop_assert_eq!(largest_buggy(&v), v.iter().copied().max());
+            Ok(())
+        }) {
+            Err(TestError::Fail(_, v)) => assert_eq!(v, vec![0, 1]),
+            other => panic!("반례를 찾지 못함: {:?}", other),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn add_commutes(a in -10_000..10_000i32, b in -10_000..10_000i32) {
+            prop_assert_eq!(add(a, b), add(b, a));
+        }
+
+        #[test]
+        fn sum_parity(a in -10_000..10_000i32, b in -10_000..10_000i32) {
+            prop_assert_eq!(is_even(add(a, b)), is_even(a) == is_even(b));
+        }
+
+        #[test]
+        fn my_vec_matches_vec(ops in prop::collection::vec(op(), 0..40)) {
+            prop_assert_eq!(apply::<MyVec<i32>>(&ops), apply::<Vec<i32>>(&ops));
+        }
+    }
+}
@@ -0,0 +1,415 @@
+// ============================================================================
+// 43. 퍼징
+// ============================================================================
+// 무작위로 바꾼 입력을 대량으로 넣어 panic 을 찾고, 찾은 입력을 가장 작게 줄임
+// 대상은 이 장의 작은 파서 - 10장에서 본 범위 밖 인덱스, UTF-8 문자 중간 자르기가 실제로 나옴
+// 여기서는 외부 도구 없이 장 안의 작은 퍼저로 - 실무 도구(cargo-fuzz)로 옮기는 방법은 마지막 절
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 의 범위 밖 접근은 정의되지 않은 동작 - 조용히 지나가므로 퍼징에 ASan 이 꼭 필요
+//    Rust 의 안전한 코드는 범위 검사가 항상 켜져 있어 같은 버그가 즉시 panic 으로 드러남
+// 2. 엔진은 같음 - cargo-fuzz 는 libFuzzer 를 씀 (LLVMFuzzerTestOneInput ≈ fuzz_target!)
+// 3. &str 은 항상 올바른 UTF-8 - 바이트 인덱스로 자르면 문자 경계를 검사 (std::string 은 바이트 배열)
+// 4. 정수 오버플로도 디버그 빌드에서는 panic - C++ 의 부호 있는 오버플로(UB)는 UBSan 이 있어야 보임
+// 5. panic 을 catch_unwind 로 잡아 프로세스를 살려 둔 채 다음 입력으로 - 장 안의 퍼저가 이 방식
+// ============================================================================
+
+use std::collections::BTreeMap;
+
+use crate::_16_unsafe::equivalence::Gen;
+use crate::panic_hook;
+
+// 절 목록 (실행 순서) - cargo run -- 43:buggy_parser 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("buggy_parser", buggy_parser),
+    ("harness", harness),
+    ("minimizing", minimizing),
+    ("fixed_parser", fixed_parser),
+    ("cargo_fuzz", cargo_fuzz),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "43"
+    }
+
+    fn name(&self) -> &'static str {
+        "퍼징"
+    }
+
+    fn description(&self) -> &'static str {
+        "작은 파서의 panic 을 무작위 입력으로 찾기, 반례 줄이기, get 과 checked 연산으로 고치기, cargo-fuzz"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["퍼징", "cargo-fuzz", "libFuzzer", "catch_unwind", "str 경계", "str::get", "최소화"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 퍼징 대상 - netstring 파서
+// ----------------------------------------------------------------------------
+// netstring: "<길이>:<내용>," 을 이어 붙인 형식 - "3:abc,5:hello," → ["abc", "hello"]
+// 길이는 바이트 수 - 한글 "가" 는 3 바이트라 "3:가,"
+// 짧고 흔한 모양이지만 길이를 믿고 자르는 코드는 입력이 틀리면 그대로 무너짐
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    MissingColon,
+    BadLength,
+    MissingComma,
+    // 고친 파서만 - 길이가 남은 입력보다 길거나 문자 중간에서 끝남
+    Truncated,
+}
+
+// 일부러 검사를 빠뜨린 버전 - 올바른 입력에서는 잘 동작해서 예제 테스트로는 안 보임
+// 길이는 u16 - usize 덧셈 오버플로(디버그/릴리스에서 결과가 다름)는 이 장에서 빼기 위함
+pub fn parse_buggy(input: &str) -> Result<Vec<&str>, ParseError> {
+    let mut items = Vec::new();
+    let mut rest = input;
+    while !rest.is_empty() {
+        let colon = rest.find(':').ok_or(ParseError::MissingColon)?;
+        let len: u16 = rest[..colon].parse().map_err(|_| ParseError::BadLength)?;
+        let start = colon + 1;
+        let end = start + len as usize;
+        // 버그 1: end 가 입력보다 길면 범위 밖, 버그 2: end 가 문자 중간이면 경계 panic
+        items.push(&rest[start..end]);
+        // 버그 3: 내용이 입력 끝까지면 쉼표 자리가 없음
+        if rest.as_bytes()[end] != b',' {
+            return Err(ParseError::MissingComma);
+        }
+        rest = &rest[end + 1..];
+    }
+    Ok(items)
+}
+
+// 퍼징 대상 함수 - 임의 바이트를 받음 (UTF-8 이 아니면 파서까지 가지 않음)
+// libFuzzer 의 LLVMFuzzerTestOneInput(const uint8_t*, size_t) 과 같은 모양
+pub fn target_buggy(data: &[u8]) {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = parse_buggy(text);
+    }
+}
+
+fn buggy_parser() {
+    println!("--- 퍼징 대상: netstring 파서 ---");
+
+    // C++ 에서는:
+    // std::string_view body = rest.substr(start, len);   // start > size() 면 out_of_range 예외
+    // if (rest[end] != ',') ...                           // end == size() 면 '\0', 넘으면 UB
+    // - operator[] 의 범위 밖 읽기는 대부분 조용히 지나감 → ASan 없이는 퍼저도 못 봄
+
+    for input in ["3:abc,5:hello,", "3:가,0:,", "3:abcd", "x:abc,"] {
+        println!("parse_buggy({:?}) = {:?}", input, parse_buggy(input));
+    }
+    println!("예제 테스트는 모두 통과 - 남은 버그는 \"길이가 거짓말하는\" 입력에서만 나옴");
+}
+
+// ----------------------------------------------------------------------------
+// 퍼저 - 입력 바꾸기, panic 잡기, 중복 없애기
+// ----------------------------------------------------------------------------
+// 1. 씨앗 입력(corpus)에서 하나를 골라 몇 바이트를 바꿈 (뒤집기, 넣기, 빼기, 이어 붙이기)
+// 2. catch_unwind 로 대상 실행 - panic 이면 충돌(crash)로 기록
+// 3. 같은 버그가 수천 번 나오므로 panic 위치(줄)와 메시지 앞부분으로 묶음 - 실무 도구는 호출 스택으로 묶음
+// 4. panic 없이 끝난 입력 중 새 모양(결과가 Ok)이면 corpus 에 더함 - libFuzzer 는 커버리지로 판단
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crash {
+    pub input: Vec<u8>,
+    pub message: String,
+    // 같은 버그인지 판단하는 기준 - "93:end byte index  is out of bounds for string of length " 처럼
+    // 줄 번호와 숫자를 뺀 메시지
+    // 한 줄의 &s[a..b] 가 범위 밖과 문자 경계, 두 가지로 panic 할 수 있어 줄만으로는 부족
+    pub signature: String,
+}
+
+// 대상을 한 번 실행 - panic 이면 Crash
+pub fn run_once(target: fn(&[u8]), input: &[u8]) -> Option<Crash> {
+    // 대상이 실행되는 동안 panic 출력은 숨기고 훅이 본 줄만 (src/panic_hook.rs, 훅과 catch_unwind 는 48장)
+    let (result, seen) = panic_hook::silently(|| target(input));
+    let payload = result.err()?;
+    let message = panic_hook::message(payload.as_ref());
+    let line = seen.map_or(0, |seen| seen.line);
+    // 숫자와 인용된 입력 조각(`...`, '가')을 뺀 메시지 - 길이만 다른 같은 버그를 하나로
+    let shape: String = message
+        .split(['`', '\''])
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_ascii_digit())
+        .collect();
+    Some(Crash { input: input.to_vec(), signature: format!("{}:{}", line, shape), message })
+}
+
+// 파서가 좋아할 만한 바이트 - 숫자, 구분자, 한글 "가"(EA B0 80)의 조각
+const INTERESTING: &[u8] = b"0123456789:,\xEA\xB0\x80";
+
+fn mutate(gen: &mut Gen, input: &[u8], corpus: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = input.to_vec();
+    for _ in 0..=gen.up_to(2) {
+        let at = gen.up_to(data.len());
+        let byte = INTERESTING[gen.up_to(INTERESTING.len() - 1)];
+        match gen.up_to(3) {
+            0 if at < data.len() => data[at] = byte,
+            1 => data.insert(at, byte),
+            2 if at < data.len() => {
+                data.remove(at);
+            }
+            _ => {
+                let other = &corpus[gen.up_to(corpus.len() - 1)];
+                data.extend_from_slice(other);
+            }
+        }
+    }
+    data
+}
+
+#[derive(Debug, Default)]
+pub struct Report {
+    pub runs: usize,
+    pub corpus: usize,
+    // 서명마다 처음 찾은 충돌
+    pub crashes: BTreeMap<String, Crash>,
+    // 모든 충돌 횟수 (같은 버그 포함)
+    pub crash_count: usize,
+}
+
+// 시드가 같으면 같은 입력 순서 - 찾은 충돌을 그대로 다시 만들 수 있음
+pub fn fuzz(target: fn(&[u8]), seeds: &[&str], runs: usize, seed: u64) -> Report {
+    let mut gen = Gen::new(seed);
+    let mut corpus: Vec<Vec<u8>> = seeds.iter().map(|s| s.as_bytes().to_vec()).collect();
+    let mut report = Report { runs, ..Report::default() };
+    for _ in 0..runs {
+        let parent = &corpus[gen.up_to(corpus.len() - 1)];
+        let input = mutate(&mut gen, parent, &corpus);
+        match run_once(target, &input) {
+            Some(crash) => {
+                report.crash_count += 1;
+                report.crashes.entry(crash.signature.clone()).or_insert(crash);
+            }
+            // 파싱에 성공한 새 입력은 다음 변형의 재료 - 올바른 모양에 가까운 입력이 늘어남
+            None if corpus.len() < 256
+                && !corpus.contains(&input)
+                && std::str::from_utf8(&input).is_ok_and(|t| parse_fixed(t).is_ok()) =>
+            {
+                corpus.push(input);
+            }
+            None => {}
+        }
+    }
+    report.corpus = corpus.len();
+    report
+}
+
+const SEEDS: &[&str] = &["3:abc,", "5:hello,3:가,", "0:,"];
+
+// 서명 대신 종류로 - 출력에 줄 번호를 찍지 않기 위함
+fn kind(message: &str) -> &'static str {
+    if message.contains("char boundary") {
+        "UTF-8 문자 중간을 자름"
+    } else if message.contains("out of bounds for string") {
+        "슬라이스 범위 밖"
+    } else if message.contains("index out of bounds") {
+        "인덱스 범위 밖"
+    } else {
+        "기타 panic"
+    }
+}
+
+fn harness() {
+    println!("\n--- 퍼저: 바꾸고, 실행하고, 잡기 ---");
+
+    // C++ 에서는 (libFuzzer):
+    // extern "C" int LLVMFuzzerTestOneInput(const uint8_t* data, size_t size) {
+    //     parse(std::string_view(reinterpret_cast<const char*>(data), size));
+    //     return 0;
+    // }
+    // clang++ -fsanitize=fuzzer,address parser.cpp - 충돌하면 프로세스가 죽고 입력을 파일로 남김
+
+    let report = fuzz(target_buggy, SEEDS, 20_000, 43);
+    println!("실행 {} 번, corpus {} 개로 늘어남", report.runs, report.corpus);
+    println!("panic {} 번 - 서명으로 묶으면 {} 가지:", report.crash_count, report.crashes.len());
+    let mut kinds: Vec<&str> = report.crashes.values().map(|c| kind(&c.message)).collect();
+    kinds.sort();
+    for k in kinds {
+        println!("  {}", k);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 최소화 - 충돌 입력을 가장 작게
+// ----------------------------------------------------------------------------
+// 퍼저가 찾은 입력은 보통 길고 지저분함 ("5:hello,3:가,\xEA3:a0:..." 같은)
+// 한 덩어리씩 빼 보고, 같은 서명으로 여전히 panic 이면 뺀 채로 계속
+// 더 뺄 수 있는 곳이 없으면 끝 - cargo fuzz tmin 이 하는 일
+
+pub fn minimize(target: fn(&[u8]), crash: &Crash) -> Crash {
+    let mut best = crash.clone();
+    let mut chunk = best.input.len().max(1);
+    while chunk > 0 {
+        let mut at = 0;
+        let mut shrunk = false;
+        while at < best.input.len() {
+            let mut candidate = best.input.clone();
+            candidate.drain(at..(at + chunk).min(candidate.len()));
+            match run_once(target, &candidate) {
+                Some(c) if c.signature == crash.signature => {
+                    best = c;
+                    shrunk = true;
+                }
+                _ => at += 1,
+            }
+        }
+        // 덩어리 크기를 하나씩 줄임 - 입력이 짧아 반씩 줄이는 것보다 느려도 괜찮고 더 작게 줄어듦
+        if !shrunk {
+            chunk -= 1;
+        }
+    }
+    best
+}
+
+fn minimizing() {
+    println!("\n--- 최소화: 충돌 입력 줄이기 ---");
+
+    // C++ 에서는:
+    // ./fuzzer -minimize_crash=1 crash-<hash> - 원리는 같음 (빼 보고 다시 실행)
+
+    let report = fuzz(target_buggy, SEEDS, 20_000, 43);
+    let mut minimized: Vec<(&str, Crash)> = report
+        .crashes
+        .values()
+        .map(|c| (kind(&c.message), minimize(target_buggy, c)))
+        .collect();
+    minimized.sort_by(|a, b| a.0.cmp(b.0));
+    for (kind, crash) in &minimized {
+        println!("{}", kind);
+        println!("  입력  {:?} ({} 바이트)", String::from_utf8_lossy(&crash.input), crash.input.len());
+        println!("  panic {}", crash.message);
+    }
+    println!("줄인 입력이 곧 회귀 테스트 - 하단 tests 의 minimized_crashes_are_fixed 참고");
+}
+
+// ----------------------------------------------------------------------------
+// 고치기 - 인덱스 대신 get, 덧셈 대신 checked
+// ----------------------------------------------------------------------------
+// &s[a..b] 는 범위 밖이거나 문자 중간이면 panic, s.get(a..b) 는 None
+// bytes[i] 는 범위 밖이면 panic, bytes.get(i) 는 None
+// 입력을 믿을 수 없는 코드(파서, 네트워크)에서는 인덱싱 대신 get 이 기본
+
+pub fn parse_fixed(input: &str) -> Result<Vec<&str>, ParseError> {
+    let mut items = Vec::new();
+    let mut rest = input;
+    while !rest.is_empty() {
+        let colon = rest.find(':').ok_or(ParseError::MissingColon)?;
+        let len: u16 = rest[..colon].parse().map_err(|_| ParseError::BadLength)?;
+        let start = colon + 1;
+        let end = start.checked_add(len as usize).ok_or(ParseError::Truncated)?;
+        let body = rest.get(start..end).ok_or(ParseError::Truncated)?;
+        if rest.as_bytes().get(end) != Some(&b',') {
+            return Err(ParseError::MissingComma);
+        }
+        items.push(body);
+        rest = &rest[end + 1..];
+    }
+    Ok(items)
+}
+
+pub fn target_fixed(data: &[u8]) {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = parse_fixed(text);
+    }
+}
+
+fn fixed_parser() {
+    println!("\n--- 고치기: get 과 checked_add ---");
+
+    // C++ 에서는:
+    // if (end > rest.size()) return std::unexpected(Truncated);  // 검사를 잊으면 그대로 UB
+    // UTF-8 경계는 std::string 이 모르므로 직접 검사해야 함
+
+    for input in ["3:", "0:", "3:9가"] {
+        println!("parse_fixed({:?}) = {:?}", input, parse_fixed(input));
+    }
+
+    let report = fuzz(target_fixed, SEEDS, 20_000, 43);
+    println!("같은 시드로 다시 퍼징: 실행 {} 번, panic {} 번", report.runs, report.crash_count);
+
+    // 차분 검사 - 버그 버전이 panic 하지 않은 입력에서는 두 버전의 결과가 같아야 함
+    let mut gen = Gen::new(7);
+    let corpus: Vec<Vec<u8>> = SEEDS.iter().map(|s| s.as_bytes().to_vec()).collect();
+    let mut compared = 0;
+    let mut same = true;
+    for _ in 0..2_000 {
+        let parent = gen.up_to(corpus.len() - 1);
+        let input = mutate(&mut gen, &corpus[parent], &corpus);
+        let Ok(text) = std::str::from_utf8(&input) else { continue };
+        if run_once(target_buggy, &input).is_none() {
+            compared += 1;
+            same &= parse_buggy(text) == parse_fixed(text);
+        }
+    }
+    println!("버그 버전이 통과한 입력 {} 개에서 결과 같음: {}", compared, same);
+}
+
+// ----------------------------------------------------------------------------
+// cargo-fuzz - 실무 도구로 옮기기
+// ----------------------------------------------------------------------------
+// 장 안의 퍼저는 원리를 보이기 위한 것 - 실제로는 커버리지를 보는 libFuzzer 가 훨씬 빠르게 찾음
+// 대상 함수를 fn(&[u8]) 모양으로 두었으므로 그대로 옮기면 됨
+// 퍼징할 코드는 라이브러리(lib.rs 쪽)에 있어야 함 - fuzz/ 는 별도 크레이트로 그 라이브러리를 씀
+
+const FUZZ_TARGET: &str = "\
+// fuzz/fuzz_targets/netstring.rs
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = my_crate::parse(text);
+    }
+});";
+
+fn cargo_fuzz() {
+    println!("\n--- cargo-fuzz 로 옮기기 ---");
+
+    // C++ 에서는:
+    // clang 의 -fsanitize=fuzzer 로 같은 libFuzzer 를 씀 - 빌드 설정과 corpus 관리를 직접
+
+    println!("cargo install cargo-fuzz && cargo fuzz init   (nightly 필요 - 새니타이저 플래그)");
+    for line in FUZZ_TARGET.lines() {
+        println!("  | {}", line);
+    }
+    println!("cargo +nightly fuzz run netstring                  실행 (Ctrl-C 까지)");
+    println!("cargo +nightly fuzz tmin netstring <충돌 파일>      최소화");
+    println!("fuzz/corpus/netstring/ 은 커밋 - 다음 실행이 이어서 탐색");
+    println!("입력이 구조체라면 arbitrary 크레이트의 #[derive(Arbitrary)] 로 바이트를 값으로");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimized_crashes_are_fixed() {
+        // 최소화한 충돌 입력 - 버그 버전은 panic, 고친 버전은 에러
+        for (input, expected) in [
+            ("3:", ParseError::Truncated),
+            ("0:", ParseError::MissingComma),
+            ("3:9가", ParseError::Truncated),
+        ] {
+            assert!(run_once(target_buggy, input.as_bytes()).is_some(), "{:?}", input);
+            assert_eq!(parse_fixed(input), Err(expected));
+        }
+
+        let report = fuzz(target_buggy, SEEDS, 20_000, 43);
+        assert_eq!(report.crashes.len(), 3);
+        assert_eq!(fuzz(target_fixed, SEEDS, 20_000, 43).crash_count, 0);
+    }
+}
@@ -0,0 +1,409 @@
+// ============================================================================
+// 44. C++ 라이브러리 호출
+// ============================================================================
+// 16장의 FFI 는 libc 의 abs/strlen 만 부름 - 여기서는 직접 쓴 C++ 코드(cpp/ffi/)를
+// build.rs 에서 컴파일해 링크하고, 두 가지 방법으로 부름
+//   extern "C"      - C ABI 로 감싼 함수를 unsafe 로 직접 (포인터와 정수만 오감)
+//   #[cxx::bridge]  - 양쪽 연결 코드를 생성 (&str, String, Vec, unique_ptr, 예외가 안전하게 오감)
+// cpp-ffi 기능(기본으로 켜짐)으로 빌드하고 C++ 컴파일에 성공하면 cfg(cpp_ffi) 가 켜짐
+// C++ 컴파일러가 없으면 cargo build --no-default-features - 이 장은 안내만 출력
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 는 헤더를 include 하면 끝 - Rust 는 C++ 타입을 모르므로 경계의 모양을 직접 선언하거나 생성
+// 2. extern "C" 경계에는 소유권 정보가 없음 - 누가 해제하는지는 문서와 래퍼 타입(Drop)으로 지킴
+// 3. 할당한 쪽이 해제 - C++ 의 new 로 만든 것을 Rust 의 Box 로 해제하면 안 됨 (할당자가 다름)
+// 4. 예외는 extern "C" 를 넘으면 정의되지 않은 동작 - 에러 코드로 바꾸거나 cxx 의 Result 로
+// 5. cxx 는 양쪽의 선언이 일치하는지 컴파일할 때 검사 - 틀린 extern "C" 선언은 링크까지 통과함
+// ============================================================================
+
+#[cfg(cpp_ffi)]
+use std::ffi::{c_char, CStr, CString};
+#[cfg(cpp_ffi)]
+use std::ptr::NonNull;
+
+// 절 목록 (실행 순서) - cargo run -- 44:build_script 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("build_script", build_script),
+    ("extern_c", extern_c),
+    ("opaque_handle", opaque_handle),
+    ("cxx_bridge", cxx_bridge),
+    ("ownership", ownership),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "44"
+    }
+
+    fn name(&self) -> &'static str {
+        "C++ 라이브러리 호출"
+    }
+
+    fn description(&self) -> &'static str {
+        "build.rs 와 cc 로 C++ 컴파일, extern \"C\" 와 불투명 핸들, cxx 브리지, 경계를 넘는 소유권"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["FFI", "build.rs", "cc", "cxx", "extern \"C\"", "UniquePtr", "CString", "소유권"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// C++ 컴파일러 없이 빌드했을 때 각 절이 출력하는 안내
+#[cfg(not(cpp_ffi))]
+fn not_built() {
+    println!("C++ 라이브러리 없이 빌드됨 - c++ 또는 clang++ 를 설치하고 기본 기능(cpp-ffi)으로 다시 빌드");
+}
+
+// ----------------------------------------------------------------------------
+// build.rs 에서 C++ 컴파일
+// ----------------------------------------------------------------------------
+// build.rs (이 저장소의 것):
+//   cxx_build::bridge("src/_44_cpp_ffi.rs")     #[cxx::bridge] 의 C++ 쪽 연결 코드 생성
+//       .file("cpp/ffi/study_ffi.cc")           직접 쓴 C++ 파일 추가
+//       .std("c++17")
+//       .try_compile("study_ffi")               → libstudy_ffi.a, 링크 지시까지 cargo 에 출력
+// extern "C" 만 쓴다면 cxx-build 없이 cc 크레이트로 같은 일:
+//   cc::Build::new().cpp(true).file("cpp/ffi/study_ffi.cc").compile("study_ffi");
+// cc 는 CXX, CXXFLAGS 환경 변수와 대상 플랫폼을 보고 컴파일러와 플래그를 고름 (MSVC, gcc, clang)
+
+fn build_script() {
+    println!("--- build.rs 에서 C++ 컴파일 ---");
+
+    // C++ 에서는:
+    // CMake 의 add_library(study_ffi STATIC study_ffi.cc) + target_link_libraries
+    // Rust 는 build.rs 가 그 역할 - cargo build 한 번이면 C++ 도 함께 빌드됨
+
+    println!("cfg(cpp_ffi) 켜짐: {}", cfg!(cpp_ffi));
+    println!("빌드 순서: build.rs 실행 → cpp/ffi/*.cc 컴파일 → libstudy_ffi.a → rustc 가 링크");
+    println!("cpp/ffi/ 나 이 파일이 바뀌면 다시 컴파일 (cargo:rerun-if-changed)");
+    #[cfg(not(cpp_ffi))]
+    not_built();
+}
+
+// ----------------------------------------------------------------------------
+// extern "C" - 직접 선언하고 unsafe 로 호출
+// ----------------------------------------------------------------------------
+// C++ 쪽은 extern "C" 로 이름 장식(name mangling)을 끄고 C ABI 타입만 씀
+// Rust 쪽 선언은 헤더(study_ffi.h)를 보고 손으로 - 틀려도 컴파일러가 모름 (bindgen 으로 생성 가능)
+// -sys 크레이트 관례처럼 날것의 선언은 mod raw 에 모으고 밖에는 안전한 함수만 내놓음
+
+#[cfg(cpp_ffi)]
+mod raw {
+    use std::ffi::c_char;
+
+    // build.rs 의 링크 지시는 라이브러리 타깃에만 붙음 - examples/ 처럼 이 파일을 따로 빌드해도 링크되도록
+    // C++ 표준 라이브러리(new[], 예외)는 cxx 크레이트가 링크해 줌
+    use cxx as _;
+
+    // C++ 의 struct StudyCounter - 크기를 모르는 타입 (포인터로만 다룸)
+    #[repr(C)]
+    pub struct StudyCounter {
+        _private: [u8; 0],
+    }
+
+    #[link(name = "study_ffi", kind = "static")]
+    extern "C" {
+        pub fn study_checksum(data: *const u8, len: usize) -> u32;
+        pub fn study_greeting(name: *const c_char) -> *mut c_char;
+        pub fn study_string_free(s: *mut c_char);
+        pub fn study_counter_new(start: i32) -> *mut StudyCounter;
+        pub fn study_counter_add(counter: *mut StudyCounter, n: i32);
+        pub fn study_counter_get(counter: *const StudyCounter) -> i32;
+        pub fn study_counter_divide(counter: *mut StudyCounter, by: i32) -> i32;
+        pub fn study_counter_free(counter: *mut StudyCounter);
+        pub fn study_live_objects() -> i32;
+    }
+}
+
+// 빌림 - 슬라이스를 (포인터, 길이) 로 넘기고 C++ 는 호출 동안만 읽음
+#[cfg(cpp_ffi)]
+pub fn checksum(data: &[u8]) -> u32 {
+    // SAFETY: data 는 호출 동안 살아 있는 len 바이트, C++ 는 읽기만 하고 보관하지 않음
+    unsafe { raw::study_checksum(data.as_ptr(), data.len()) }
+}
+
+// 같은 FNV-1a 를 Rust 로 - C++ 결과와 비교용
+#[cfg(cpp_ffi)]
+pub fn checksum_rust(data: &[u8]) -> u32 {
+    data.iter().fold(2166136261u32, |hash, &b| (hash ^ b as u32).wrapping_mul(16777619))
+}
+
+// C++ 가 할당한 문자열 - Drop 에서 C++ 의 해제 함수로 돌려줌
+#[cfg(cpp_ffi)]
+pub struct CppString {
+    ptr: NonNull<c_char>,
+}
+
+#[cfg(cpp_ffi)]
+impl CppString {
+    pub fn as_c_str(&self) -> &CStr {
+        // SAFETY: study_greeting 이 돌려준 NUL 종료 문자열, self 가 살아 있는 동안 유효
+        unsafe { CStr::from_ptr(self.ptr.as_ptr()) }
+    }
+}
+
+#[cfg(cpp_ffi)]
+impl Drop for CppString {
+    fn drop(&mut self) {
+        // SAFETY: study_greeting 이 new[] 로 할당한 포인터 - 한 번만 해제
+        unsafe { raw::study_string_free(self.ptr.as_ptr()) }
+    }
+}
+
+// 이름 안의 NUL 은 C 문자열로 만들 수 없음 - CString::new 가 Err
+#[cfg(cpp_ffi)]
+pub fn greeting(name: &str) -> Result<CppString, std::ffi::NulError> {
+    // Rust 가 할당한 CString - C++ 는 호출 동안 빌리기만 함, 해제는 여기서 (name 이 범위를 벗어날 때)
+    let name = CString::new(name)?;
+    // SAFETY: name 은 NUL 종료 문자열, 반환값은 null 이 아닌 new[] 할당 (study_ffi.h)
+    let ptr = unsafe { raw::study_greeting(name.as_ptr()) };
+    Ok(CppString { ptr: NonNull::new(ptr).expect("study_greeting 은 null 을 돌려주지 않음") })
+}
+
+fn extern_c() {
+    println!("\n--- extern \"C\" 로 직접 호출 ---");
+
+    // C++ 에서는:
+    // #include "study_ffi.h" 한 줄 - 선언이 곧 헤더라 어긋날 일이 없음
+
+    #[cfg(not(cpp_ffi))]
+    not_built();
+
+    #[cfg(cpp_ffi)]
+    {
+        let data = "퍼징과 FFI".as_bytes();
+        println!("C++ checksum == Rust checksum: {}", checksum(data) == checksum_rust(data));
+
+        let hello = greeting("Rust").unwrap();
+        // to_str 은 UTF-8 검사 - C++ 문자열은 아무 바이트나 담을 수 있으므로
+        println!("C++ 가 만든 문자열: {:?}", hello.as_c_str().to_str());
+        // 소유한 String 으로 복사해 두면 C++ 버퍼는 바로 돌려줄 수 있음
+        let owned: String = hello.as_c_str().to_string_lossy().into_owned();
+        drop(hello);
+        println!("복사본은 해제 뒤에도 사용: {}", owned);
+
+        println!("NUL 이 든 이름: {}", greeting("a\0b").is_err());
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 불투명 핸들 - C++ 객체를 Rust 값으로
+// ----------------------------------------------------------------------------
+// C++ 객체를 만드는 함수와 해제하는 함수를 짝으로 받아, Rust 쪽 타입의 new 와 Drop 에 연결
+// 사용하는 쪽은 unsafe 없이 - 해제를 잊거나 두 번 하는 일이 타입으로 막힘
+// C++ 의 unique_ptr<T, Deleter> 와 같은 모양
+
+#[cfg(cpp_ffi)]
+pub struct Counter {
+    ptr: NonNull<raw::StudyCounter>,
+}
+
+#[cfg(cpp_ffi)]
+#[derive(Debug, PartialEq)]
+pub struct DivideByZero;
+
+#[cfg(cpp_ffi)]
+impl Counter {
+    pub fn new(start: i32) -> Counter {
+        // SAFETY: 인자 제약 없음, 반환값은 new 로 할당한 객체
+        let ptr = unsafe { raw::study_counter_new(start) };
+        Counter { ptr: NonNull::new(ptr).expect("new 는 실패하면 예외 - null 이 아님") }
+    }
+
+    pub fn add(&mut self, n: i32) {
+        // SAFETY: ptr 은 살아 있는 객체, &mut self 라 동시에 다른 곳에서 쓰지 않음
+        unsafe { raw::study_counter_add(self.ptr.as_ptr(), n) }
+    }
+
+    pub fn get(&self) -> i32 {
+        // SAFETY: ptr 은 살아 있는 객체, 읽기만 함
+        unsafe { raw::study_counter_get(self.ptr.as_ptr()) }
+    }
+
+    // C++ 의 에러 코드 → Result
+    pub fn divide(&mut self, by: i32) -> Result<(), DivideByZero> {
+        // SAFETY: add 와 같음
+        match unsafe { raw::study_counter_divide(self.ptr.as_ptr(), by) } {
+            0 => Ok(()),
+            _ => Err(DivideByZero),
+        }
+    }
+}
+
+#[cfg(cpp_ffi)]
+impl Drop for Counter {
+    fn drop(&mut self) {
+        // SAFETY: study_counter_new 가 만든 포인터, Drop 은 한 번만 실행됨
+        unsafe { raw::study_counter_free(self.ptr.as_ptr()) }
+    }
+}
+
+// 살아 있는 C++ 객체 수
+#[cfg(cpp_ffi)]
+pub fn live_objects() -> i32 {
+    // SAFETY: 인자 없는 조회
+    unsafe { raw::study_live_objects() }
+}
+
+fn opaque_handle() {
+    println!("\n--- 불투명 핸들과 Drop ---");
+
+    // C++ 에서는:
+    // std::unique_ptr<StudyCounter, decltype(&study_counter_free)> c(study_counter_new(10), study_counter_free);
+
+    #[cfg(not(cpp_ffi))]
+    not_built();
+
+    #[cfg(cpp_ffi)]
+    {
+        let before = live_objects();
+        {
+            let mut counter = Counter::new(10);
+            counter.add(32);
+            println!("10 + 32 = {}", counter.get());
+            println!("divide(2) = {:?}, 값 {}", counter.divide(2), counter.get());
+            println!("divide(0) = {:?} (C++ 는 -1 을 돌려줌)", counter.divide(0));
+            println!("살아 있는 C++ 객체 +{}", live_objects() - before);
+        }
+        println!("범위를 벗어난 뒤 +{} (Drop 이 study_counter_free 호출)", live_objects() - before);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// cxx - 양쪽 연결 코드 생성
+// ----------------------------------------------------------------------------
+// 아래 mod 하나에서 Rust 쪽 선언과 C++ 쪽 연결 코드(_44_cpp_ffi.rs.h/.cc)가 함께 생성됨
+// C++ 헤더의 시그니처와 다르면 C++ 컴파일 에러 - extern "C" 처럼 조용히 틀리지 않음
+// &str ↔ rust::Str, String ↔ rust::String, Vec<T> ↔ rust::Vec<T>, UniquePtr<T> ↔ std::unique_ptr<T>
+// Result<T> 를 돌려주는 함수는 C++ 예외를 잡아 Err(cxx::Exception) 으로
+
+#[cfg(cpp_ffi)]
+#[cxx::bridge(namespace = "study")]
+mod bridge {
+    // 양쪽에서 같은 모양으로 쓰는 구조체 - C++ 쪽 정의는 cxx 가 생성
+    #[derive(Debug)]
+    struct Item {
+        name: String,
+        count: u32,
+    }
+
+    unsafe extern "C++" {
+        include!("rust-study/cpp/ffi/study_ffi.h");
+
+        type Inventory;
+
+        fn new_inventory() -> UniquePtr<Inventory>;
+        // C++ 의 비 const 멤버 함수는 Pin<&mut T> - C++ 객체는 주소가 바뀌면 안 되므로
+        fn add(self: Pin<&mut Inventory>, name: &str, count: u32);
+        fn count(&self, name: &str) -> u32;
+        fn take(self: Pin<&mut Inventory>, name: &str, count: u32) -> Result<u32>;
+        fn items(&self) -> Vec<Item>;
+    }
+}
+
+fn cxx_bridge() {
+    println!("\n--- #[cxx::bridge] ---");
+
+    // C++ 에서는:
+    // auto inv = study::new_inventory();
+    // inv->add("사과", 3);
+    // try { inv->take("사과", 5); } catch (const std::out_of_range& e) { ... }
+
+    #[cfg(not(cpp_ffi))]
+    not_built();
+
+    #[cfg(cpp_ffi)]
+    {
+        let before = live_objects();
+        let mut inventory = bridge::new_inventory();
+        // UniquePtr 는 null 일 수 있음 - as_ref/pin_mut 는 null 이면 None/panic
+        println!("null 아님: {}", !inventory.is_null());
+
+        inventory.pin_mut().add("사과", 3);
+        inventory.pin_mut().add("배", 5);
+        inventory.pin_mut().add("사과", 2);
+        println!("사과 {} 개, 귤 {} 개", inventory.count("사과"), inventory.count("귤"));
+
+        // C++ 의 rust::Vec<Item> 을 Rust 의 Vec<Item> 으로 그대로 받음 (복사 없이 소유권 이동)
+        for item in inventory.items() {
+            println!("  {:?}", item);
+        }
+
+        match inventory.pin_mut().take("배", 2) {
+            Ok(left) => println!("배 2 개 꺼냄, 남은 {} 개", left),
+            Err(e) => println!("에러: {}", e.what()),
+        }
+        // C++ 가 던진 std::out_of_range → Err - what() 은 예외 메시지
+        match inventory.pin_mut().take("사과", 10) {
+            Ok(left) => println!("사과 10 개 꺼냄, 남은 {} 개", left),
+            Err(e) => println!("C++ 예외 → Err: {}", e.what()),
+        }
+
+        println!("살아 있는 C++ 객체 +{}", live_objects() - before);
+        drop(inventory);
+        println!("UniquePtr drop 뒤 +{} (C++ 소멸자 실행)", live_objects() - before);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 경계를 넘는 소유권 - 정리
+// ----------------------------------------------------------------------------
+// 규칙 하나: 할당한 쪽의 해제 함수로 돌려줌 - 두 언어의 할당자는 서로 다를 수 있음
+//   Rust → C++ 빌려주기   &[u8], CString::as_ptr()   C++ 는 보관 금지 (호출이 끝나면 무효)
+//   C++ → Rust 넘겨주기   new 로 만든 포인터           Rust 래퍼의 Drop 이 C++ 의 free 함수 호출
+//   Rust → C++ 넘겨주기   Box::into_raw(b)             C++ 가 끝나면 Rust 의 extern "C" fn 으로 돌려받아 Box::from_raw (45장)
+//   cxx                 UniquePtr<T>, Box<T>, Vec<T>  생성된 코드가 올바른 쪽의 해제를 부름
+
+fn ownership() {
+    println!("\n--- 경계를 넘는 소유권 ---");
+
+    // C++ 에서는:
+    // malloc 으로 받은 것은 free, new 는 delete, new[] 는 delete[] - 짝이 틀리면 UB
+    // 언어 경계에서는 같은 규칙이 "라이브러리의 free 함수" 로 바뀜
+
+    let rules = [
+        ("&[u8], &CStr 빌려주기", "C++ 는 호출 동안만 사용"),
+        ("C++ 의 new / new[]", "Drop 에서 C++ 의 해제 함수"),
+        ("Rust 의 Box::into_raw", "C++ 가 Rust 의 해제 함수를 불러 돌려줌"),
+        ("cxx 의 UniquePtr / Vec / String", "생성된 코드가 알아서"),
+        ("C++ 예외", "extern \"C\" 는 에러 코드, cxx 는 Result"),
+    ];
+    for (what, how) in rules {
+        println!("  {:<32} → {}", what, how);
+    }
+
+    #[cfg(cpp_ffi)]
+    println!("이 장의 모든 C++ 객체 해제됨: {}", live_objects() == 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(cpp_ffi)]
+    #[test]
+    fn calls_cross_the_boundary() {
+        for data in [&b""[..], b"abc", "한글".as_bytes()] {
+            assert_eq!(checksum(data), checksum_rust(data));
+        }
+        assert_eq!(greeting("세계").unwrap().as_c_str().to_str(), Ok("안녕, 세계!"));
+
+        let mut counter = Counter::new(7);
+        assert_eq!(counter.divide(0), Err(DivideByZero));
+        assert_eq!(counter.get(), 7);
+
+        let mut inventory = bridge::new_inventory();
+        inventory.pin_mut().add("a", 1);
+        let err = inventory.pin_mut().take("a", 2).unwrap_err();
+        assert_eq!(err.what(), "a 재고 1개, 요청 2개");
+        assert_eq!(inventory.pin_mut().take("a", 1).unwrap(), 0);
+        assert_eq!(inventory.pin_mut().take("없음", 0).unwrap(), 0);
+    }
+}
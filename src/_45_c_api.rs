@@ -0,0 +1,292 @@
+// ============================================================================
+// 45. Rust 를 C 라이브러리로
+// ============================================================================
+// 44장의 반대 방향 - Rust 로 쓴 코드를 C/C++ 팀이 링크해서 쓰게 내보냄
+// 라이브러리는 워크스페이스의 capi/ 크레이트 (단어 수를 세는 StudyTally)
+//   crate-type = ["cdylib", "staticlib"]   libstudy_capi.so / libstudy_capi.a
+//   cargo xtask capi-header (cbindgen)      capi/include/study_capi.h 생성
+//   cpp/capi/main.cc                       그 헤더를 include 하는 C++ 프로그램
+// 이 장은 같은 함수를 rlib 로 링크해 C 가 부르는 것과 똑같이 (포인터와 상태 코드로) 불러 봄
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 의 extern "C" 와 같은 일을 #[no_mangle] + extern "C" 두 가지로 - 이름과 호출 규약은 따로 정함
+// 2. Rust 에는 안정된 ABI 가 없음 - 경계에는 #[repr(C)] 타입과 포인터만, 나머지는 불투명 핸들로
+// 3. Result 와 panic 은 C 로 넘길 수 없음 - 상태 코드로 바꾸고 panic 은 catch_unwind 로 막음
+// 4. 헤더를 손으로 쓰지 않음 - cbindgen 이 Rust 선언에서 생성하므로 선언과 구현이 어긋나지 않음
+// 5. 할당한 쪽이 해제 - Rust 가 준 포인터는 free()/delete 가 아니라 라이브러리의 해제 함수로
+// ============================================================================
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+
+use study_capi::{
+    study_capi_string_free, study_capi_version, study_status_message, study_tally_add, study_tally_count,
+    study_tally_free, study_tally_new, study_tally_top, StudyStatus, StudyTally, HEADER,
+};
+
+// 절 목록 (실행 순서) - cargo run -- 45:no_mangle 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("no_mangle", no_mangle),
+    ("opaque_handle", opaque_handle),
+    ("error_codes", error_codes),
+    ("strings", strings),
+    ("cbindgen", cbindgen),
+    ("cpp_caller", cpp_caller),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "45"
+    }
+
+    fn name(&self) -> &'static str {
+        "Rust 를 C 라이브러리로"
+    }
+
+    fn description(&self) -> &'static str {
+        "cdylib 와 #[no_mangle] extern \"C\", 불투명 핸들, 상태 코드와 catch_unwind, CString, cbindgen 헤더"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["FFI", "cdylib", "no_mangle", "extern \"C\"", "CString", "catch_unwind", "cbindgen"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// 상태 코드의 설명 - 라이브러리가 돌려준 정적 문자열
+fn message(status: StudyStatus) -> &'static str {
+    // SAFETY: study_status_message 는 항상 정적 NUL 종료 ASCII 문자열을 돌려줌 (study_capi.h)
+    unsafe { CStr::from_ptr(study_status_message(status as c_int)) }.to_str().unwrap()
+}
+
+// ----------------------------------------------------------------------------
+// #[no_mangle] extern "C" - C 에서 부를 수 있는 함수
+// ----------------------------------------------------------------------------
+// capi/src/lib.rs:
+//   #[no_mangle]
+//   pub extern "C" fn study_capi_version() -> *const c_char { c"0.1.0".as_ptr() }
+// #[no_mangle]  심볼 이름을 그대로 "study_capi_version" 으로 (없으면 _ZN10study_capi...17h...E 같은 장식된 이름)
+// extern "C"    C 호출 규약 - 인자를 어느 레지스터로 넘기는지 (Rust 의 기본 ABI 는 정해져 있지 않음)
+// C 에는 네임스페이스가 없으므로 이름에 라이브러리 접두사 (study_) 를 붙임
+// 심볼 확인: nm -D target/release/libstudy_capi.so | grep study_   (macOS 는 nm -gU ...dylib)
+
+fn no_mangle() {
+    println!("--- #[no_mangle] extern \"C\" ---");
+
+    // C++ 에서는:
+    // extern "C" const char* study_capi_version() { return "0.1.0"; }
+    // C++ 도 extern "C" 가 없으면 _Z18study_capi_versionv 처럼 이름이 장식됨
+
+    // C 가 보는 것과 같은 모양 - 인자와 반환이 C 타입인 함수 포인터
+    let version: extern "C" fn() -> *const c_char = study_capi_version;
+    // SAFETY: 정적 NUL 종료 문자열
+    let text = unsafe { CStr::from_ptr(version()) };
+    println!("study_capi_version() = {:?}", text);
+    println!("Cargo.toml 의 crate-type = [\"cdylib\", \"staticlib\", \"rlib\"] - rlib 는 이 장과 cargo test 용");
+}
+
+// ----------------------------------------------------------------------------
+// 불투명 핸들
+// ----------------------------------------------------------------------------
+// StudyTally 는 HashMap 을 가진 보통 Rust 구조체 (#[repr(C)] 아님) - C 는 모양을 알 필요가 없음
+// 헤더에는 typedef struct StudyTally StudyTally; 만 - 크기를 모르니 C 는 포인터로만 다룸
+//   study_tally_new   Box::into_raw(Box::new(..))   Rust 가 할당, 소유권을 C 에 넘김
+//   study_tally_free  Box::from_raw(p) 를 drop      소유권을 돌려받아 해제
+// 필드를 바꿔도 C 쪽은 다시 컴파일할 필요가 없음 (ABI 가 포인터 하나)
+
+fn opaque_handle() {
+    println!("\n--- 불투명 핸들 ---");
+
+    // C++ 에서는:
+    // std::unique_ptr<StudyTally, TallyDeleter> tally(study_tally_new());
+    // study_tally_add(tally.get(), "...");   // 소멸자가 study_tally_free 호출
+
+    let tally: *mut StudyTally = study_tally_new();
+    println!("핸들은 포인터 하나: {} 바이트, null 아님: {}", size_of::<*mut StudyTally>(), !tally.is_null());
+
+    let mut the = 0;
+    // SAFETY: tally 는 study_tally_new 가 돌려준 살아 있는 객체, 문자열은 NUL 종료
+    unsafe {
+        study_tally_add(tally, c"the cat saw the dog".as_ptr());
+        study_tally_count(tally, c"the".as_ptr(), &mut the);
+        // 다 쓰면 C 가 해제 함수를 부름 - 부르지 않으면 누수, 두 번 부르면 이중 해제
+        study_tally_free(tally);
+    }
+    println!("the: {} 번", the);
+    // NULL 은 free() 처럼 아무것도 안 함
+    // SAFETY: NULL 은 허용된 인자
+    unsafe { study_tally_free(ptr::null_mut()) };
+    println!("study_tally_free(NULL) 은 안전");
+}
+
+// ----------------------------------------------------------------------------
+// 에러 코드와 panic
+// ----------------------------------------------------------------------------
+// C 에는 Result 가 없음 - #[repr(C)] enum 상태 코드를 돌려주고 값은 출력 포인터(*mut u32)로
+//   pub unsafe extern "C" fn study_tally_count(tally, word, out: *mut u32) -> StudyStatus
+// 들어오는 포인터는 믿지 않음 - NULL 은 STUDY_STATUS_NULL_POINTER (as_ref/as_mut 가 Option 으로)
+// panic 이 extern "C" 함수 밖으로 나가면 Rust 가 프로세스를 중단 (C 에는 풀어낼 방법이 없음)
+//   fn guard(f) -> StudyStatus { panic::catch_unwind(f).unwrap_or(StudyStatus::Panic) }
+// 로 감싸 STUDY_STATUS_PANIC 으로 바꿈 - 43장의 퍼징 하네스와 같은 도구
+// C 에서 들어오는 상태 값은 enum 이 아니라 int 로 받음 - 없는 값(99)이 Rust enum 이 되는 순간 UB
+//   study_status_message(int status) 는 모르는 값이면 "unknown status"
+
+fn error_codes() {
+    println!("\n--- 에러 코드와 panic ---");
+
+    // C++ 에서는:
+    // 예외를 extern "C" 밖으로 던지면 안 됨 - catch (...) 로 잡아 에러 코드로 (44장의 study_counter_divide)
+
+    let tally = study_tally_new();
+    let mut n = 7;
+    // SAFETY: tally 는 살아 있는 객체, 문자열은 NUL 종료 - NULL 인자는 라이브러리가 검사함
+    let results = unsafe {
+        study_tally_add(tally, c"one two two".as_ptr());
+        [
+            ("count(\"two\")", study_tally_count(tally, c"two".as_ptr(), &mut n)),
+            ("count(\"three\")", study_tally_count(tally, c"three".as_ptr(), &mut n)),
+            ("count(NULL, ..)", study_tally_count(ptr::null(), c"two".as_ptr(), &mut n)),
+            ("add(tally, NULL)", study_tally_add(tally, ptr::null())),
+        ]
+    };
+    for (call, status) in results {
+        println!("  {:<18} → {:?} ({} = {})", call, status, status as i32, message(status));
+    }
+    // 실패한 호출은 *out 을 건드리지 않음 - 마지막으로 성공한 count("two") 의 값
+    println!("n = {}", n);
+    // SAFETY: study_status_message 는 어떤 int 에도 정적 NUL 종료 문자열을 돌려줌
+    let unknown = unsafe { CStr::from_ptr(study_status_message(99)) };
+    println!("study_status_message(99) → {:?}", unknown);
+    // SAFETY: 위에서 만든 객체를 한 번 해제
+    unsafe { study_tally_free(tally) };
+}
+
+// ----------------------------------------------------------------------------
+// 문자열 주고받기
+// ----------------------------------------------------------------------------
+// 들어오는 const char*  CStr::from_ptr 로 빌림 → to_str 로 UTF-8 검사 (C 문자열은 아무 바이트나)
+// 나가는 char*          CString::into_raw 로 소유권을 넘기고, study_capi_string_free 의 CString::from_raw 로 돌려받음
+// C 가 free() 로 해제하면 안 됨 - Rust 의 할당자가 malloc 이라는 보장이 없음
+// Rust 에서 C 로 넘길 때: CString::new(s)? 는 중간의 NUL 을 거부, as_ptr() 는 CString 이 살아 있는 동안만 유효
+
+fn strings() {
+    println!("\n--- 문자열 주고받기 ---");
+
+    // C++ 에서는:
+    // std::unique_ptr<char, StringDeleter> top(study_tally_top(t));   // deleter 가 study_capi_string_free
+
+    let tally = study_tally_new();
+    // Rust 의 String 을 C 문자열로 - 변수에 묶어 두어야 포인터가 호출 동안 유효
+    // CString::new(text).unwrap().as_ptr() 를 바로 넘기면 임시 값이 먼저 해제됨 (clippy 가 막아 줌)
+    let text = CString::new("사과 배 사과 귤 사과").unwrap();
+    // SAFETY: tally 는 살아 있는 객체, text 는 이 블록 동안 살아 있음
+    let status = unsafe { study_tally_add(tally, text.as_ptr()) };
+    println!("한글 단어 추가: {:?}", status);
+
+    // 올바르지 않은 UTF-8 - C 쪽에서는 흔한 일
+    let latin1 = CString::new(vec![b'c', b'a', b'f', 0xe9]).unwrap();
+    // SAFETY: 위와 같음
+    let status = unsafe { study_tally_add(tally, latin1.as_ptr()) };
+    println!("Latin-1 \"café\": {:?}", status);
+
+    // SAFETY: tally 는 살아 있는 객체
+    let top = unsafe { study_tally_top(tally) };
+    if !top.is_null() {
+        // SAFETY: study_tally_top 이 돌려준 NUL 종료 문자열 - 해제 전까지 유효
+        let word = unsafe { CStr::from_ptr(top) }.to_string_lossy().into_owned();
+        println!("가장 많은 단어: {}", word);
+        // SAFETY: study_tally_top 이 돌려준 포인터를 한 번 해제
+        unsafe { study_capi_string_free(top) };
+    }
+    // SAFETY: 위에서 만든 객체를 한 번 해제
+    unsafe { study_tally_free(tally) };
+}
+
+// ----------------------------------------------------------------------------
+// cbindgen 으로 헤더 생성
+// ----------------------------------------------------------------------------
+// cargo xtask capi-header 가 src/lib.rs 를 읽어 include/study_capi.h 를 씀 - 빌드는 소스 트리에 쓰지 않음
+// lib.rs 를 고치고 다시 생성하지 않으면 xtask 의 테스트가 실패 (헤더와 구현이 어긋나지 않게)
+// capi/cbindgen.toml:
+//   language = "C", cpp_compat = true          C 헤더지만 C++ 에서 include 하면 extern "C" { } 로 감쌈
+//   rename_variants = "QualifiedScreamingSnakeCase"   StudyStatus::Ok → STUDY_STATUS_OK
+// /// 문서 주석이 헤더의 주석이 됨 - # Safety 절이 C 쪽 사용자에게도 보임
+// 헤더는 커밋해 둠 - C/C++ 팀은 Rust 도구 없이 include 만 하면 됨
+// xtask 없이: cargo install cbindgen && cbindgen --config cbindgen.toml -o include/study_capi.h
+// 헤더 내용은 study_capi::HEADER (capi 크레이트가 include_str! 로 넣어 둠)
+
+fn cbindgen() {
+    println!("\n--- cbindgen 으로 헤더 생성 ---");
+
+    // C++ 에서는:
+    // 헤더를 손으로 쓰고 구현과 맞는지는 링크와 리뷰로 확인
+
+    // 헤더에서 선언만 골라 보기 (여러 줄 선언은 첫 줄만)
+    for line in HEADER.lines() {
+        let line = line.trim_end();
+        let declaration = line.contains("study_") && line.contains('(') && !line.starts_with("//");
+        if declaration || line.starts_with("typedef struct") || line.starts_with("  STUDY_STATUS_") {
+            println!("  | {}", line);
+        }
+    }
+    println!("헤더 {} 줄 - 모두 capi/src/lib.rs 에서 생성", HEADER.lines().count());
+}
+
+// ----------------------------------------------------------------------------
+// C++ 에서 쓰기
+// ----------------------------------------------------------------------------
+// cpp/capi/main.cc - 핸들과 문자열은 unique_ptr + deleter, 상태 코드는 예외로 바꾸는 얇은 래퍼
+// 정적 라이브러리를 링크할 때 함께 넘길 시스템 라이브러리는
+//   cargo rustc -p rust-study-capi --release --crate-type staticlib -- --print native-static-libs
+// panic = "abort" 로 빌드하면 catch_unwind 가 잡지 못함 - 이 라이브러리는 기본값(unwind)을 가정 (48장)
+
+const CPP_BUILD: &[&str] = &[
+    "cargo build -p rust-study-capi --release",
+    "c++ -std=c++17 cpp/capi/main.cc -Icapi/include -Ltarget/release -lstudy_capi -o /tmp/capi_demo",
+    "LD_LIBRARY_PATH=target/release /tmp/capi_demo",
+];
+
+fn cpp_caller() {
+    println!("\n--- C++ 에서 쓰기 ---");
+
+    // C++ 에서는:
+    // #include "study_capi.h"
+    // Tally tally(study_tally_new());
+    // check(study_tally_add(tally.get(), "the quick brown fox"));   // check 는 상태 코드 → 예외
+
+    println!("저장소 루트에서:");
+    for command in CPP_BUILD {
+        println!("  $ {}", command);
+    }
+    println!("출력: study_capi 0.1.0 / the: 3 / top: the / cat: word not found / ...");
+    println!("같은 .so 를 Python(ctypes), C#(P/Invoke), Go(cgo) 에서도 - C ABI 는 공용어");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_declares_every_export() {
+        for name in [
+            "study_capi_version",
+            "study_status_message",
+            "study_tally_new",
+            "study_tally_free",
+            "study_tally_add",
+            "study_tally_count",
+            "study_tally_top",
+            "study_capi_string_free",
+        ] {
+            assert!(HEADER.contains(&format!("{}(", name)), "{} 가 헤더에 없음", name);
+        }
+        assert!(HEADER.contains("typedef struct StudyTally StudyTally;"));
+        assert_eq!(message(StudyStatus::NotFound), "word not found");
+    }
+}
@@ -0,0 +1,320 @@
+// ============================================================================
+// 46. MaybeUninit 과 미초기화 메모리
+// ============================================================================
+// 16장의 MyVec 은 alloc 으로 받은 메모리에 ptr::write 로 값을 씀 - 그 메모리는 아직 T 가 아님
+// 이 장은 "아직 초기화되지 않은 T" 를 타입으로 나타내는 MaybeUninit<T> 와
+// 미초기화 메모리를 다룰 때 정의되지 않은 동작(UB)이 생기는 지점을 정리함
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 의 int x; 는 컴파일되고 읽으면 UB - Rust 는 초기화 전에 읽는 코드를 컴파일하지 않음
+// 2. 미초기화 값은 "아무 값" 이 아님 - i32 라도 읽는 순간 UB (C++ 의 불확정 값과 같은 규칙)
+// 3. mem::zeroed 는 0 이 올바른 값인 타입에만 - &T, Box<T>, NonZero 는 0 이 될 수 없음
+// 4. MaybeUninit<T> 는 drop 하지 않는 상자 - assume_init 으로 꺼내기 전까지 T 의 규칙이 적용되지 않음
+// 5. C++ 의 std::uninitialized_* + placement new 가 하는 일을 write 와 assume_init 으로
+// ============================================================================
+
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::rc::Rc;
+
+// 절 목록 (실행 순서) - cargo run -- 46:uninit_ub 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("uninit_ub", uninit_ub),
+    ("maybe_uninit", maybe_uninit),
+    ("array_init", array_init),
+    ("out_params", out_params),
+    ("myvec_push", myvec_push),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "46"
+    }
+
+    fn name(&self) -> &'static str {
+        "MaybeUninit 과 미초기화 메모리"
+    }
+
+    fn description(&self) -> &'static str {
+        "미초기화 메모리의 UB, MaybeUninit 의 write/assume_init, 배열 한 칸씩 초기화, 출력 인자, MyVec::push 다시 보기"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["MaybeUninit", "assume_init", "mem::zeroed", "spare_capacity_mut", "set_len", "unsafe", "UB"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 미초기화 메모리와 UB
+// ----------------------------------------------------------------------------
+// 안전한 Rust 에서는 미초기화 값을 만들 수 없음 - 선언만 하고 읽으면 컴파일 에러 (E0381)
+// unsafe 로 만들 수 있는 길:
+//   mem::uninitialized::<T>()   폐기됨 - 어떤 T 에도 올바르게 쓸 수 없음
+//   mem::zeroed::<T>()          모든 바이트가 0 인 T - 0 이 올바른 값인 타입에만
+//   alloc::alloc(layout)        받은 메모리는 미초기화 - 16장의 MyVec
+// "올바른 값" (validity): bool 은 0/1, char 는 유니코드 스칼라, &T/Box<T>/NonNull 은 null 아님, enum 은 정의된 판별값
+// 미초기화 바이트는 어떤 타입에서도 올바른 값이 아님 - i32 도 마찬가지 (컴파일러가 아무 값으로 가정해도 됨)
+
+fn uninit_ub() {
+    println!("--- 미초기화 메모리와 UB ---");
+
+    // C++ 에서는:
+    // int x;                  // 컴파일됨, 읽으면 UB
+    // std::string s;          // 기본 생성자가 있으면 초기화됨 - 없는 타입은 배열로 만들 수도 없음
+
+    // let x: i32;
+    // println!("{}", x);      // 에러 E0381: used binding `x` isn't initialized
+
+    // 나중에 초기화 - 모든 경로에서 한 번 대입하면 됨 (컴파일러가 확인)
+    #[expect(clippy::needless_late_init, reason = "나중에 초기화하는 문법을 보여 줌")]
+    let label: &str;
+    if cfg!(debug_assertions) {
+        label = "debug";
+    } else {
+        label = "release";
+    }
+    println!("나중에 초기화한 변수: {}", label);
+
+    // 0 이 올바른 값인 타입이면 zeroed 가 안전
+    // SAFETY: [u64; 4] 와 (f64, *const u8) 은 모든 비트가 0 인 값이 올바름
+    let (counts, pair): ([u64; 4], (f64, *const u8)) = unsafe { (mem::zeroed(), mem::zeroed()) };
+    println!("zeroed [u64; 4] = {:?}, (f64, *const u8) = ({}, null: {})", counts, pair.0, pair.1.is_null());
+
+    // 0 이 될 수 없는 타입 - Option 이 그 자리를 None 으로 씀 (널 포인터 최적화)
+    println!("Option<&u8> 크기 {} == &u8 크기 {}", mem::size_of::<Option<&u8>>(), mem::size_of::<&u8>());
+    // let r: &u8 = unsafe { mem::zeroed() };   // UB - 널 참조 (Miri 가 바로 잡음)
+}
+
+// ----------------------------------------------------------------------------
+// MaybeUninit<T>
+// ----------------------------------------------------------------------------
+// 크기와 정렬은 T 와 같고, 아무 바이트나 (미초기화도) 올바른 값인 union
+//   MaybeUninit::uninit()      미초기화 상태로 만들기 - 안전
+//   x.write(value)             값 넣기 - 안전, 이전 내용을 drop 하지 않음
+//   x.as_mut_ptr()             *mut T - 필드 하나씩 쓰거나 C 함수에 넘길 때
+//   x.assume_init()            T 로 꺼내기 - unsafe, 초기화됐다는 약속
+// MaybeUninit<T> 는 Drop 을 실행하지 않음 - 넣은 값을 꺼내지 않으면 누수 (C++ 의 aligned_storage 와 같음)
+
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+fn maybe_uninit() {
+    println!("\n--- MaybeUninit<T> ---");
+
+    // C++ 에서는:
+    // alignas(std::string) unsigned char buf[sizeof(std::string)];
+    // auto* s = new (buf) std::string("hello");   // placement new
+    // s->~basic_string();                          // 소멸자도 직접
+
+    let mut slot = MaybeUninit::<String>::uninit();
+    slot.write(String::from("hello"));
+    // SAFETY: 바로 위에서 write 로 초기화함
+    let s = unsafe { slot.assume_init() };
+    println!("write 후 assume_init: {:?}", s);
+    println!("MaybeUninit<String> 크기 {} == String 크기 {}", mem::size_of::<MaybeUninit<String>>(), mem::size_of::<String>());
+
+    // drop 하지 않는 상자 - 꺼내지 않은 값은 누수
+    let shared = Rc::new(());
+    let mut leaked = MaybeUninit::<Rc<()>>::uninit();
+    leaked.write(Rc::clone(&shared));
+    #[expect(clippy::drop_non_drop, reason = "drop 해도 안의 값이 drop 되지 않음을 보여 줌")]
+    drop(leaked);
+    println!("MaybeUninit 을 drop 한 뒤 Rc 참조 수: {} (안의 Rc 가 drop 되지 않음)", Rc::strong_count(&shared));
+
+    // 구조체를 필드 하나씩 - &raw mut 로 필드 주소만 얻음 (&mut (*p).name 은 미초기화 String 에 대한 참조라 UB)
+    let mut config = MaybeUninit::<Config>::uninit();
+    let p = config.as_mut_ptr();
+    // SAFETY: p 는 Config 크기의 정렬된 메모리, 각 필드를 참조 없이 한 번씩 씀
+    let config = unsafe {
+        (&raw mut (*p).name).write(String::from("server"));
+        (&raw mut (*p).retries).write(3);
+        // 모든 필드를 썼으므로 Config 전체가 초기화됨
+        config.assume_init()
+    };
+    println!("필드 하나씩 초기화: {} (재시도 {})", config.name, config.retries);
+}
+
+// ----------------------------------------------------------------------------
+// 배열을 한 칸씩 초기화
+// ----------------------------------------------------------------------------
+// Default 나 Copy 가 아닌 T 로 [T; N] 을 만들 때
+//   먼저 std::array::from_fn(|i| ..) - 안전하고 대부분 이것으로 충분
+//   중간에 실패할 수 있으면 (Result) MaybeUninit 배열에 하나씩 쓰고 전부 채운 뒤 [T; N] 으로
+// 주의: 실패나 panic 으로 중간에 빠져나가면 이미 쓴 칸은 직접 drop 해야 함 (MaybeUninit 은 drop 하지 않으므로)
+// C++ 의 std::uninitialized_copy 가 예외 때 이미 만든 원소를 파괴하는 것과 같은 일
+
+// 실패하면 이미 만든 원소를 drop 하고 에러를 돌려줌 (std 의 array::try_from_fn 은 아직 unstable)
+pub fn try_init_array<T, E, const N: usize>(mut f: impl FnMut(usize) -> Result<T, E>) -> Result<[T; N], E> {
+    // 초기화한 앞부분 [0..len] 을 기억했다가 빠져나갈 때 drop - panic 에도 동작
+    struct Guard<'a, T, const N: usize> {
+        array: &'a mut [MaybeUninit<T>; N],
+        len: usize,
+    }
+
+    impl<T, const N: usize> Drop for Guard<'_, T, N> {
+        fn drop(&mut self) {
+            for slot in &mut self.array[..self.len] {
+                // SAFETY: [0..len] 은 write 로 초기화한 칸
+                unsafe { slot.assume_init_drop() };
+            }
+        }
+    }
+
+    // const 블록 - MaybeUninit 은 Copy 가 아니어도 반복 식으로 배열을 만들 수 있음
+    let mut array = [const { MaybeUninit::<T>::uninit() }; N];
+    let mut guard = Guard { array: &mut array, len: 0 };
+    for i in 0..N {
+        guard.array[i].write(f(i)?);
+        guard.len += 1;
+    }
+    // 전부 채움 - guard 가 drop 하지 않게 잊음
+    mem::forget(guard);
+    // SAFETY: 모든 칸이 초기화됨, [MaybeUninit<T>; N] 과 [T; N] 은 배치가 같음
+    Ok(unsafe { ptr::read(&array as *const [MaybeUninit<T>; N] as *const [T; N]) })
+}
+
+fn array_init() {
+    println!("\n--- 배열을 한 칸씩 초기화 ---");
+
+    // C++ 에서는:
+    // alignas(T) unsigned char raw[N * sizeof(T)];
+    // std::uninitialized_copy(first, last, reinterpret_cast<T*>(raw));   // 예외면 만든 것만 파괴
+
+    // 먼저 안전한 방법
+    let names: [String; 3] = std::array::from_fn(|i| format!("item-{}", i));
+    println!("array::from_fn: {:?}", names);
+
+    // 실패할 수 있는 초기화
+    let parsed: Result<[u8; 4], _> = try_init_array(|i| ["10", "20", "30", "40"][i].parse::<u8>());
+    println!("모두 성공: {:?}", parsed);
+
+    // 3 번째에서 실패 - 이미 만든 두 원소가 drop 되는지 Rc 참조 수로 확인
+    let shared = Rc::new(());
+    let failed: Result<[Rc<()>; 4], &str> = try_init_array(|i| if i < 2 { Ok(Rc::clone(&shared)) } else { Err("2 번 칸 실패") });
+    println!("중간 실패: {:?}, 남은 Rc 참조 수 {} (만든 두 개는 drop 됨)", failed.map(|_| ()), Rc::strong_count(&shared));
+}
+
+// ----------------------------------------------------------------------------
+// 출력 인자와 버퍼
+// ----------------------------------------------------------------------------
+// C API 는 결과를 포인터 인자로 돌려줌 - 45장의 study_tally_count(tally, word, uint32_t* out)
+// 호출 전에 0 으로 채우는 대신 MaybeUninit 으로 넘기고, 성공했을 때만 assume_init
+// 버퍼는 Vec::with_capacity 의 남은 용량(spare_capacity_mut)에 쓰고 set_len 으로 길이를 늘림
+//   - read(2) 처럼 "n 바이트를 썼다" 고 알려주는 API 와 잘 맞음
+//   - set_len 전에 [len..new_len] 이 모두 초기화됐어야 함
+
+fn out_params() {
+    println!("\n--- 출력 인자와 버퍼 ---");
+
+    // C++ 에서는:
+    // uint32_t n;                                   // 초기화하지 않아도 컴파일됨
+    // if (study_tally_count(t, "the", &n) == STUDY_STATUS_OK) use(n);
+
+    use study_capi::{study_tally_add, study_tally_count, study_tally_free, study_tally_new, StudyStatus};
+
+    let tally = study_tally_new();
+    let mut found = MaybeUninit::<u32>::uninit();
+    let mut missing = MaybeUninit::<u32>::uninit();
+    // SAFETY: tally 는 살아 있는 객체, 문자열은 NUL 종료, 출력 포인터는 u32 하나를 쓸 수 있는 메모리
+    let (ok, not_found) = unsafe {
+        study_tally_add(tally, c"to be or not to be".as_ptr());
+        (
+            study_tally_count(tally, c"be".as_ptr(), found.as_mut_ptr()),
+            study_tally_count(tally, c"question".as_ptr(), missing.as_mut_ptr()),
+        )
+    };
+    if ok == StudyStatus::Ok {
+        // SAFETY: STUDY_STATUS_OK 면 *out 을 썼음 (study_capi.h)
+        println!("be: {} 번", unsafe { found.assume_init() });
+    }
+    // 실패하면 *out 을 쓰지 않음 - missing 은 그대로 미초기화라 읽으면 안 됨
+    println!("question: {:?} (출력 인자를 읽지 않음)", not_found);
+    // SAFETY: 위에서 만든 객체를 한 번 해제
+    unsafe { study_tally_free(tally) };
+
+    // 남은 용량에 쓰고 set_len
+    let mut buf: Vec<u8> = Vec::with_capacity(8);
+    let spare = buf.spare_capacity_mut();
+    let written = spare.len().min(5);
+    for (i, slot) in spare[..written].iter_mut().enumerate() {
+        slot.write(b'a' + i as u8);
+    }
+    // SAFETY: [0..written] 을 위에서 모두 썼고 written <= capacity
+    unsafe { buf.set_len(written) };
+    println!("spare_capacity_mut + set_len: {:?} (용량 {})", String::from_utf8_lossy(&buf), buf.capacity());
+}
+
+// ----------------------------------------------------------------------------
+// MyVec::push 다시 보기
+// ----------------------------------------------------------------------------
+// 16장의 처음 구현: ptr::write(self.ptr.as_ptr().add(self.len), value)
+//   - 동작은 맞지만 "이 칸은 미초기화" 라는 사실이 타입에 없음
+//   - 같은 자리에 *p = value 라고 쓰면 대입이 먼저 기존 "값"(쓰레기)을 drop - String 이면 아무 주소나 해제
+//   - &mut *p 로 참조를 만들면 미초기화 T 에 대한 참조 - 그 자체로 UB 가 될 수 있음
+// 지금 구현: spare_capacity_mut() 가 ptr[len..cap] 을 &mut [MaybeUninit<T>] 로 돌려주고 push 는 [0].write(value)
+//   - unsafe 는 spare_capacity_mut 한 곳에 모이고 push 는 안전한 코드
+//   - Vec 도 같은 API 를 제공 (위 절의 buf)
+// 검증: cargo +nightly miri test 로 16장 tests 를 돌리면 미초기화 읽기와 잘못된 참조를 찾아냄
+
+fn myvec_push() {
+    println!("\n--- MyVec::push 다시 보기 ---");
+
+    // C++ 에서는:
+    // ::new (static_cast<void*>(data_ + size_)) T(std::move(value));   // placement new - 대입(=)이 아님
+    // ++size_;
+
+    use crate::_16_unsafe::safe_wrapper::MyVec;
+
+    let mut v = MyVec::new();
+    for word in ["one", "two", "three"] {
+        v.push(String::from(word));
+    }
+    println!("MyVec: {:?}, 길이 {}, 용량 {}", &*v, v.len(), v.capacity());
+    println!("남은 칸 (MaybeUninit<String>): {} 개", v.spare_capacity_mut().len());
+
+    // 남은 칸에 써도 길이를 늘리지 않으면 요소가 아님 - drop 도 되지 않음
+    let shared = Rc::new(());
+    let mut rcs = MyVec::new();
+    for _ in 0..3 {
+        rcs.push(Rc::clone(&shared));
+    }
+    // 용량 4, 길이 3 - 남은 칸 하나
+    rcs.spare_capacity_mut()[0].write(Rc::clone(&shared));
+    println!("push 세 개 + 남은 칸에 쓴 한 개: 참조 수 {}", Rc::strong_count(&shared));
+    drop(rcs);
+    println!("MyVec drop 뒤 참조 수 {} (남은 칸의 값은 새어 나감)", Rc::strong_count(&shared));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_init_array_drops_the_initialized_prefix() {
+        let shared = Rc::new(());
+        let ok: Result<[Rc<()>; 3], ()> = try_init_array(|_| Ok(Rc::clone(&shared)));
+        assert_eq!(Rc::strong_count(&shared), 4);
+        drop(ok);
+        assert_eq!(Rc::strong_count(&shared), 1);
+
+        let err: Result<[Rc<()>; 3], usize> = try_init_array(|i| if i == 2 { Err(i) } else { Ok(Rc::clone(&shared)) });
+        assert!(matches!(err, Err(2)));
+        assert_eq!(Rc::strong_count(&shared), 1);
+
+        // panic 으로 빠져나가도 guard 가 앞부분을 drop
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            try_init_array::<_, (), 3>(|i| if i == 1 { panic!("stop") } else { Ok(Rc::clone(&shared)) })
+        }));
+        assert!(panicked.is_err());
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+}
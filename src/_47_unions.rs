@@ -0,0 +1,388 @@
+// ============================================================================
+// 47. union 과 transmute
+// ============================================================================
+// C++ 에서 습관처럼 쓰는 타입 펀닝(type punning) - 같은 비트를 다른 타입으로 읽기
+//   union { float f; uint32_t u; }, reinterpret_cast<uint32_t&>(f), memcpy, std::bit_cast
+// Rust 에도 union 과 mem::transmute 가 있지만 거의 항상 더 안전한 대안이 있음
+// 이 장은 union 이 꼭 필요한 곳 (C 와 같은 배치의 FFI 타입) 과 transmute 의 함정을 정리함
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 는 마지막에 쓰지 않은 union 멤버를 읽으면 UB - Rust 는 그 비트가 읽는 타입의 올바른 값이면 허용 (C 와 같음)
+// 2. 대신 union 필드 읽기는 항상 unsafe - 비트가 올바른 값인지는 컴파일러가 모름
+// 3. union 필드는 Copy 이거나 ManuallyDrop 이어야 함 - 어떤 필드를 drop 할지 Rust 가 모르므로
+// 4. 꼬리표가 붙은 union (std::variant) 은 enum - 손으로 만든 꼬리표 + union 은 FFI 경계에서만
+// 5. std::bit_cast 에 해당하는 것은 f32::to_bits/from_bits, to_ne_bytes/from_ne_bytes - transmute 는 마지막 수단
+// ============================================================================
+
+use std::mem::{self, ManuallyDrop};
+
+// 절 목록 (실행 순서) - cargo run -- 47:union_basics 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("union_basics", union_basics),
+    ("tagged_union", tagged_union),
+    ("ffi_union", ffi_union),
+    ("transmute", transmute),
+    ("safer_alternatives", safer_alternatives),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "47"
+    }
+
+    fn name(&self) -> &'static str {
+        "union 과 transmute"
+    }
+
+    fn description(&self) -> &'static str {
+        "union 과 ManuallyDrop 필드, FFI 용 #[repr(C)] union, transmute 의 함정, to_bits/from_bits 와 to_ne_bytes 같은 대안"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["union", "ManuallyDrop", "#[repr(C)]", "transmute", "to_bits", "to_ne_bytes", "타입 펀닝"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// union 기초
+// ----------------------------------------------------------------------------
+// 모든 필드가 같은 자리(오프셋 0)를 공유 - 크기는 가장 큰 필드, 정렬은 가장 엄격한 필드
+// 필드에 쓰기는 안전, 읽기는 unsafe (어느 필드가 "살아 있는지" 기록하지 않으므로)
+// 다른 필드로 쓰고 읽어도 됨 - 단 그 비트가 읽는 타입의 올바른 값이어야 (bool 에 2 를 읽으면 UB)
+
+#[derive(Clone, Copy)]
+union FloatBits {
+    f: f32,
+    u: u32,
+    bytes: [u8; 4],
+}
+
+fn union_basics() {
+    println!("--- union 기초 ---");
+
+    // C++ 에서는:
+    // union FloatBits { float f; uint32_t u; };
+    // FloatBits b; b.f = 1.0f;
+    // b.u;               // C++ 에서는 UB (C 에서는 허용) - C++20 은 std::bit_cast<uint32_t>(1.0f)
+
+    let mut bits = FloatBits { f: 1.0 };
+    // SAFETY: f32 의 모든 비트 패턴은 올바른 u32 와 [u8; 4]
+    unsafe {
+        println!("1.0f32 의 비트: {:#010x}, 바이트(이 기계의 순서): {:?}", bits.u, bits.bytes);
+    }
+    // 쓰기는 안전 - Copy 필드는 이전 값을 drop 할 일이 없음
+    bits.u = 0x4049_0fdb;
+    // SAFETY: u32 의 모든 비트 패턴은 올바른 f32 (NaN 포함)
+    println!("0x40490fdb 를 f32 로: {}", unsafe { bits.f });
+    println!("크기 {} 바이트 - 가장 큰 필드와 같음", mem::size_of::<FloatBits>());
+
+    // 패턴 매칭도 필드 읽기라 unsafe
+    // SAFETY: 위와 같음
+    unsafe {
+        match bits {
+            FloatBits { u: 0 } => println!("0"),
+            FloatBits { f } => println!("패턴으로 읽은 f = {:.5}", f),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 꼬리표 붙은 union - enum 과 비교
+// ----------------------------------------------------------------------------
+// union 필드는 Copy 이거나 ManuallyDrop<T> - union 은 어느 필드를 drop 할지 모르므로 아예 drop 하지 않음
+// 꼬리표(tag)와 union 을 묶어 직접 관리하면 std::variant 를 손으로 만드는 것 - 그게 바로 enum
+// enum 은 꼬리표 검사, drop, 패턴 매칭을 컴파일러가 대신 해 줌 (게다가 niche 로 꼬리표를 숨기기도)
+
+union Payload {
+    number: i64,
+    text: ManuallyDrop<String>,
+}
+
+// 꼬리표 + union - 불변식: tag 가 TEXT 면 payload.text 가 초기화된 String
+struct Tagged {
+    tag: u8,
+    payload: Payload,
+}
+
+impl Tagged {
+    const NUMBER: u8 = 0;
+    const TEXT: u8 = 1;
+
+    fn number(n: i64) -> Tagged {
+        Tagged { tag: Self::NUMBER, payload: Payload { number: n } }
+    }
+
+    fn text(s: &str) -> Tagged {
+        Tagged { tag: Self::TEXT, payload: Payload { text: ManuallyDrop::new(s.to_string()) } }
+    }
+
+    fn describe(&self) -> String {
+        // SAFETY: tag 가 어느 필드가 초기화됐는지 알려줌 (불변식)
+        unsafe {
+            match self.tag {
+                Self::TEXT => format!("text {:?}", *self.payload.text),
+                _ => format!("number {}", self.payload.number),
+            }
+        }
+    }
+}
+
+impl Drop for Tagged {
+    fn drop(&mut self) {
+        if self.tag == Self::TEXT {
+            // SAFETY: tag 가 TEXT 면 text 가 살아 있음, Drop 은 한 번만 실행됨
+            unsafe { ManuallyDrop::drop(&mut self.payload.text) }
+        }
+    }
+}
+
+// 같은 것을 enum 으로 - unsafe 도 Drop 구현도 필요 없음
+#[derive(Debug)]
+enum Value {
+    Number(i64),
+    Text(String),
+}
+
+impl Value {
+    // match 가 꼬리표를 확인하고 필드를 꺼냄 - 잘못 읽을 방법이 없음
+    fn describe(&self) -> String {
+        match self {
+            Value::Text(text) => format!("text {:?}", text),
+            Value::Number(number) => format!("number {}", number),
+        }
+    }
+}
+
+fn tagged_union() {
+    println!("\n--- 꼬리표 붙은 union 과 enum ---");
+
+    // C++ 에서는:
+    // struct Tagged { uint8_t tag; union { int64_t number; std::string text; }; ~Tagged() { if (tag == 1) text.~basic_string(); } };
+    // 보통은 std::variant<int64_t, std::string> - 꼬리표와 소멸자를 라이브러리가 관리
+
+    let values = [Tagged::number(42), Tagged::text("hello")];
+    for v in &values {
+        println!("손으로 만든 꼬리표: {}", v.describe());
+    }
+    let enums = [Value::Number(42), Value::Text("hello".to_string())];
+    for v in &enums {
+        println!("enum:           {}", v.describe());
+    }
+    println!(
+        "크기 - Tagged {} 바이트, enum Value {} 바이트 (꼬리표를 String 의 빈 비트에 숨김)",
+        mem::size_of::<Tagged>(),
+        mem::size_of::<Value>()
+    );
+    // values 가 범위를 벗어나면 Tagged::drop 이 text 만 해제
+}
+
+// ----------------------------------------------------------------------------
+// FFI 용 #[repr(C)] union
+// ----------------------------------------------------------------------------
+// C 헤더에 union 이 있으면 Rust 도 같은 배치의 #[repr(C)] union 으로 받아야 함
+//   typedef struct { uint8_t kind; union { int32_t i; float f; uint8_t rgba[4]; } as; } CValue;
+// 경계를 넘자마자 꼬리표를 검사해 Rust 의 enum 으로 바꾸고, 안쪽 코드는 enum 만 씀
+// (44장의 "날것의 선언은 mod raw 에, 밖에는 안전한 타입" 과 같은 구조)
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union CValueData {
+    pub i: i32,
+    pub f: f32,
+    pub rgba: [u8; 4],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct CValue {
+    pub kind: u8,
+    pub data: CValueData,
+}
+
+pub const KIND_INT: u8 = 0;
+pub const KIND_FLOAT: u8 = 1;
+pub const KIND_COLOR: u8 = 2;
+
+// 안쪽 코드가 쓰는 타입
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sample {
+    Int(i32),
+    Float(f32),
+    Color([u8; 4]),
+}
+
+impl TryFrom<CValue> for Sample {
+    type Error = u8;
+
+    // 모르는 kind 는 Err - C 쪽이 보낸 값을 믿지 않음
+    fn try_from(raw: CValue) -> Result<Sample, u8> {
+        // SAFETY: 세 필드 모두 모든 비트 패턴이 올바른 값 (i32, f32, [u8; 4]) - kind 가 틀려도 UB 는 아님
+        unsafe {
+            match raw.kind {
+                KIND_INT => Ok(Sample::Int(raw.data.i)),
+                KIND_FLOAT => Ok(Sample::Float(raw.data.f)),
+                KIND_COLOR => Ok(Sample::Color(raw.data.rgba)),
+                other => Err(other),
+            }
+        }
+    }
+}
+
+impl From<Sample> for CValue {
+    fn from(sample: Sample) -> CValue {
+        match sample {
+            Sample::Int(i) => CValue { kind: KIND_INT, data: CValueData { i } },
+            Sample::Float(f) => CValue { kind: KIND_FLOAT, data: CValueData { f } },
+            Sample::Color(rgba) => CValue { kind: KIND_COLOR, data: CValueData { rgba } },
+        }
+    }
+}
+
+fn ffi_union() {
+    println!("\n--- FFI 용 #[repr(C)] union ---");
+
+    // C++ 에서는:
+    // switch (v.kind) { case KIND_INT: use(v.as.i); break; case KIND_FLOAT: ... }
+    // 꼬리표를 확인하는 switch 가 코드 곳곳에 흩어짐
+
+    // C 와 같은 배치인지 - C 컴파일러의 sizeof/offsetof 와 같아야 함
+    println!(
+        "CValue 크기 {}, 정렬 {}, data 오프셋 {}",
+        mem::size_of::<CValue>(),
+        mem::align_of::<CValue>(),
+        mem::offset_of!(CValue, data)
+    );
+
+    for sample in [Sample::Int(-7), Sample::Float(0.5), Sample::Color([255, 128, 0, 255])] {
+        let raw = CValue::from(sample);
+        println!("{:?} → kind {} → {:?}", sample, raw.kind, Sample::try_from(raw));
+    }
+    let unknown = CValue { kind: 9, data: CValueData { i: 0 } };
+    println!("모르는 kind: {:?}", Sample::try_from(unknown));
+}
+
+// ----------------------------------------------------------------------------
+// mem::transmute 의 함정
+// ----------------------------------------------------------------------------
+// transmute::<A, B>(a) - A 의 비트를 그대로 B 로. 컴파일러가 검사하는 것은 "크기가 같은가" 하나뿐
+// 함정:
+//   올바르지 않은 값    transmute::<u8, bool>(2), transmute::<u32, char>(0xD800) - 즉시 UB
+//   바이트 순서         [u8; 4] → u32 결과가 기계마다 다름 (리틀/빅 엔디언)
+//   수명 늘리기         transmute::<&'a T, &'static T> - 빌림 검사기를 속여 댕글링 참조
+//   &T → &mut T         어떤 경우에도 UB
+//   배치가 정해지지 않은 타입   Vec<u32> → Vec<f32>, (u8, u32) → [u8; 8] - repr(Rust) 는 순서와 패딩이 보장되지 않음 (49장)
+//   포인터 → 정수       출처(provenance) 정보가 사라짐 - ptr.addr() / ptr.expose_provenance() 를 씀
+// 그래서 transmute 가 필요해 보이면 먼저 아래 절의 대안을 찾음
+
+// 아래 두 transmute 는 일부러 - 컴파일러도 unnecessary_transmutes 경고로 to_bits/from_ne_bytes 를 권함
+#[allow(unnecessary_transmutes)]
+fn transmute() {
+    println!("\n--- mem::transmute 의 함정 ---");
+
+    // C++ 에서는:
+    // reinterpret_cast<uint32_t&>(f)   // strict aliasing 위반 - UB
+    // std::memcpy(&u, &f, 4)           // 올바른 방법 (C++20 은 std::bit_cast)
+
+    // 크기가 다르면 컴파일 에러 - 유일한 검사
+    // let x: u64 = unsafe { mem::transmute(1.0f32) };   // 에러 E0512: cannot transmute between types of different sizes
+
+    // 크기가 같으면 통과 - 결과가 올바른지는 프로그래머의 책임
+    // SAFETY: f32 와 u32 는 크기가 같고 모든 비트가 올바른 u32
+    let bits: u32 = unsafe { mem::transmute(1.0f32) };
+    println!("transmute::<f32, u32>(1.0) = {:#x} (f32::to_bits 와 같음: {})", bits, bits == 1.0f32.to_bits());
+
+    // 바이트 배열 → 정수는 엔디언에 따라 결과가 다름
+    let bytes: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+    // SAFETY: [u8; 4] 와 u32 는 크기가 같고 모든 비트가 올바른 u32
+    let native: u32 = unsafe { mem::transmute(bytes) };
+    let endian = if cfg!(target_endian = "little") { "little" } else { "big" };
+    println!("transmute([1, 2, 3, 4]) = {:#010x} (이 기계는 {}-endian)", native, endian);
+
+    // Vec<u32> → Vec<f32> 는 transmute 대신 - into_iter + collect 는 같은 할당을 재사용 (제자리 수집)
+    let raw = vec![0x3f80_0000u32, 0x4000_0000, 0x4040_0000];
+    let ptr_before = raw.as_ptr() as usize;
+    let floats: Vec<f32> = raw.into_iter().map(f32::from_bits).collect();
+    println!("Vec<u32> → Vec<f32>: {:?}, 같은 버퍼 재사용: {}", floats, floats.as_ptr() as usize == ptr_before);
+
+    // let b: bool = unsafe { mem::transmute(2u8) };   // UB - bool 은 0 또는 1 (Miri 가 잡음)
+    // 검사가 필요하면 transmute 대신 변환 함수: char::from_u32, bool 은 match
+    println!("char::from_u32(0xD800) = {:?} (서로게이트는 char 가 아님)", char::from_u32(0xD800));
+}
+
+// ----------------------------------------------------------------------------
+// 더 안전한 대안
+// ----------------------------------------------------------------------------
+//   f32/f64 ↔ 비트      f32::to_bits / f32::from_bits              std::bit_cast<uint32_t>(f)
+//   정수 ↔ 바이트       to_le_bytes / from_le_bytes (ne, be)       memcpy + 엔디언 변환
+//   정수 ↔ 정수         as, From/TryFrom (24장)                    static_cast
+//   포인터 ↔ 포인터     ptr.cast::<U>()                            reinterpret_cast<U*>
+//   &[u8] → &[u32]      slice.align_to::<u32>() (unsafe, 앞뒤 남는 부분을 돌려줌) 또는 chunks_exact + from_le_bytes
+//   구조체 ↔ 바이트     bytemuck, zerocopy 크레이트 - 모든 비트가 올바른 타입인지 트레이트로 검사
+// 전부 const fn 이거나 안전한 함수 - unsafe 가 필요 없고 의도가 이름에 드러남
+
+// Quake III 의 빠른 역제곱근 - C 에서는 포인터 캐스팅, Rust 는 to_bits/from_bits 로 unsafe 없이
+pub fn fast_inv_sqrt(x: f32) -> f32 {
+    let i = 0x5f37_59df - (x.to_bits() >> 1);
+    let y = f32::from_bits(i);
+    // 뉴턴 방법 한 번 - 오차 약 0.2% 이내
+    y * (1.5 - 0.5 * x * y * y)
+}
+
+fn safer_alternatives() {
+    println!("\n--- 더 안전한 대안 ---");
+
+    // C++ 에서는:
+    // float q_rsqrt(float x) { long i = *(long*)&x; i = 0x5f3759df - (i >> 1); float y = *(float*)&i; ... }
+    // C++ 에서는 strict aliasing 위반 - C++20 이면 std::bit_cast 로
+
+    for x in [1.0f32, 2.0, 10.0] {
+        println!("fast_inv_sqrt({:>4}) = {:.5}, 1/sqrt = {:.5}", x, fast_inv_sqrt(x), 1.0 / x.sqrt());
+    }
+
+    // 바이트 순서를 이름으로 - 파일과 네트워크 형식은 보통 le 나 be 로 정해져 있음
+    let n = 0x1234_5678u32;
+    println!("to_le_bytes {:02x?}, to_be_bytes {:02x?}", n.to_le_bytes(), n.to_be_bytes());
+    println!("from_le_bytes([0x78, 0x56, 0x34, 0x12]) = {:#x}", u32::from_le_bytes([0x78, 0x56, 0x34, 0x12]));
+
+    // 바이트 버퍼를 u32 들로 - 정렬을 가정하지 않는 안전한 방법
+    let buffer = [1u8, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0];
+    // as_chunks::<4> 는 &[[u8; 4]] 와 남는 바이트 - 크기가 타입에 있어 try_into 가 필요 없음
+    let (chunks, _rest) = buffer.as_chunks::<4>();
+    let words: Vec<u32> = chunks.iter().map(|&c| u32::from_le_bytes(c)).collect();
+    println!("as_chunks::<4>() + from_le_bytes: {:?}", words);
+
+    // 포인터 캐스트 - 타입만 바꾸고 주소는 그대로 (역참조는 여전히 unsafe)
+    let value = 0x0102_0304u32;
+    let p: *const u8 = (&value as *const u32).cast::<u8>();
+    // SAFETY: p 는 value 의 첫 바이트를 가리킴, u8 은 정렬 제약이 없음
+    println!("ptr.cast::<u8>() 로 읽은 첫 바이트: {:#04x}", unsafe { *p });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions_and_bit_casts_agree() {
+        for sample in [Sample::Int(i32::MIN), Sample::Float(-0.0), Sample::Color([1, 2, 3, 4])] {
+            assert_eq!(Sample::try_from(CValue::from(sample)), Ok(sample));
+        }
+        assert_eq!(Sample::try_from(CValue { kind: 3, data: CValueData { i: 1 } }), Err(3));
+
+        let bits = FloatBits { f: -2.5 };
+        // SAFETY: f32 의 모든 비트는 올바른 u32
+        assert_eq!(unsafe { bits.u }, (-2.5f32).to_bits());
+
+        for x in [0.25f32, 1.0, 3.0, 100.0, 12345.0] {
+            let exact = 1.0 / x.sqrt();
+            assert!((fast_inv_sqrt(x) - exact).abs() / exact < 0.002, "{}", x);
+        }
+    }
+}
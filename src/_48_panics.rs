@@ -0,0 +1,434 @@
+// ============================================================================
+// 48. panic 과 되감기
+// ============================================================================
+// panic 은 "여기서 더 진행하면 안 되는 버그" - 기본 동작은 스택을 되감으며(unwind) 값들을 drop 하고 스레드를 끝냄
+// 복구할 수 있는 에러는 Result (9장) - 이 장은 panic 이 일어난 뒤의 일: 잡기, 훅, abort, 되감기 중의 안전성
+// 이 저장소의 장 실행기(src/runner.rs)가 절 하나가 panic 해도 다음 절로 넘어가는 방법이기도 함
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 예외는 평범한 에러 처리 수단 - Rust 의 panic 은 버그 신호이고, 에러는 Result 로 돌려줌
+// 2. catch_unwind 는 try/catch 가 아님 - 스레드, 작업 단위, FFI 경계에서 panic 을 격리할 때만
+// 3. panic = "abort" 로 빌드하면 되감기 없이 바로 종료 (-fno-exceptions 와 비슷) - catch_unwind 도 아무것도 못 잡음
+// 4. 예외 안전성(basic/strong guarantee)을 UnwindSafe 로 타입에 표시 - &mut 나 RefCell 을 넘기면 AssertUnwindSafe 로 명시
+// 5. 되감기 중 Drop 에서 또 panic 하면 abort - C++ 에서 소멸자가 던지면 std::terminate 인 것과 같음
+// ============================================================================
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::panic_hook::{silence_thread, silently};
+
+// 절 목록 (실행 순서) - cargo run -- 48:catch_unwind 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("catch_unwind", catch_unwind),
+    ("panic_hook", panic_hook),
+    ("unwind_vs_abort", unwind_vs_abort),
+    ("unwind_safe", unwind_safe),
+    ("drop_during_panic", drop_during_panic),
+    ("runner_isolation", runner_isolation),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "48"
+    }
+
+    fn name(&self) -> &'static str {
+        "panic 과 되감기"
+    }
+
+    fn description(&self) -> &'static str {
+        "catch_unwind 와 payload, set_hook, panic = \"abort\" 와 unwind, UnwindSafe 와 Mutex 오염, Drop 안의 이중 panic, 장 실행기의 절 격리"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["panic", "catch_unwind", "resume_unwind", "set_hook", "panic = \"abort\"", "UnwindSafe", "PoisonError", "thread::panicking"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// catch_unwind 와 payload
+// ----------------------------------------------------------------------------
+// catch_unwind(f) - f 가 정상 종료면 Ok(값), panic 이면 Err(payload)
+// payload 는 Box<dyn Any + Send> - panic!("리터럴") 은 &'static str, panic!("{}", x) 는 String
+// panic_any 로 아무 타입이나 던질 수 있고, resume_unwind 로 잡은 payload 를 다시 던짐 (훅은 다시 안 부름)
+// 잡을 수 있는 것은 되감기 panic 뿐 - abort, 스택 오버플로, 프로세스 종료는 못 잡음
+
+// payload 를 사람이 읽을 문자열로 - src/panic_hook.rs 의 message 와 같은 일
+pub fn payload_text(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else if let Some(n) = payload.downcast_ref::<i32>() {
+        format!("i32 {}", n)
+    } else {
+        "(알 수 없는 payload)".to_string()
+    }
+}
+
+fn catch_unwind() {
+    println!("--- catch_unwind 와 payload ---");
+
+    // C++ 에서는:
+    // try { risky(); } catch (const std::exception& e) { ... } catch (...) { ... }
+    // 예외 타입으로 골라 잡음 - Rust 는 모든 panic 을 한 번에 잡고 payload 를 downcast
+
+    let ok = panic::catch_unwind(|| 6 * 7);
+    println!("panic 없음: {:?}", ok);
+
+    // 아래의 panic 은 모두 silently 로 - 표준 훅의 "thread 'main' panicked at" 출력을 숨김 (panic_hook 절)
+    let (literal, _) = silently(|| panic!("리터럴 메시지"));
+    // 인자가 리터럴이면 컴파일할 때 문자열로 합쳐져 &str - 실행 중에 만든 값이어야 String
+    let answer = 42;
+    let (formatted, _) = silently(|| panic!("형식 있는 메시지 {}", answer));
+    let (any, _) = silently(|| panic::panic_any(7_i32));
+    for (what, result) in [("panic!(\"...\")", literal), ("panic!(\"{}\", x)", formatted), ("panic_any(7)", any)] {
+        let payload = result.unwrap_err();
+        let kind = if payload.is::<&str>() {
+            "&str"
+        } else if payload.is::<String>() {
+            "String"
+        } else {
+            "i32"
+        };
+        println!("{:<18} payload 타입 {:<6} → {}", what, kind, payload_text(payload.as_ref()));
+    }
+
+    // 잡아서 정리한 뒤 다시 던지기 - C++ 의 catch (...) { cleanup(); throw; }
+    let (outer, _) = silently(|| {
+        if let Err(payload) = panic::catch_unwind(|| panic!("안쪽 panic")) {
+            println!("안쪽에서 잡고 정리한 뒤 resume_unwind");
+            panic::resume_unwind(payload);
+        }
+    });
+    println!("바깥에서 다시 잡음: {}", payload_text(outer.unwrap_err().as_ref()));
+
+    // Option/Result 의 unwrap, 범위 밖 인덱스, 0 으로 나누기, RefCell 이중 빌림도 모두 panic
+    let v: &[i32] = &[1, 2, 3];
+    let index = v.len();
+    let (result, _) = silently(|| v[index]);
+    println!("v[3]: {}", payload_text(result.unwrap_err().as_ref()));
+}
+
+// ----------------------------------------------------------------------------
+// panic 훅
+// ----------------------------------------------------------------------------
+// panic 이 나면 되감기 전에 훅이 먼저 불림 - 기본 훅이 "thread '...' panicked at 파일:줄:칸" 과 메시지를 출력
+// set_hook 으로 바꾸고 take_hook 으로 꺼냄 - 프로세스 전체에 하나 (모든 스레드 공유)
+// 그래서 바꿀 때는 기존 훅을 감싸고, 켜고 끄는 상태는 thread_local 로 (테스트는 여러 스레드에서 동시에 돌므로)
+// 이 저장소의 훅은 src/panic_hook.rs 하나 - 장 실행기(runner::catch)는 위치를 기록하고, 43장의 퍼저와 이 장은 출력을 숨김
+
+fn panic_hook() {
+    println!("--- panic 훅 ---");
+
+    // C++ 에서는:
+    // std::set_terminate(handler);   // 잡히지 않은 예외로 끝날 때만 - 던지는 순간 불리는 훅은 없음
+    // Rust 의 훅은 잡히든 안 잡히든 panic 마다, 되감기 전에, panic 한 스레드에서 불림
+
+    let (result, seen) = silently(|| {
+        let config: Option<&str> = None;
+        #[expect(clippy::unnecessary_literal_unwrap, reason = "None 의 expect 가 내는 panic 을 보여 줌")]
+        config.expect("설정이 없음");
+    });
+    println!("잡은 payload: {}", payload_text(result.unwrap_err().as_ref()));
+    println!("훅이 본 것:   {}", seen.map(|s| s.to_string()).unwrap_or_default());
+
+    // 스레드의 panic 은 join 에서 Err(payload) - thread::spawn 이 안에서 catch_unwind 를 쓰는 셈
+    // 훅은 panic 한 스레드에서 불리므로 출력을 끄는 것도 그 스레드에서
+    let handle = thread::spawn(|| {
+        silence_thread();
+        let items: Vec<u32> = Vec::new();
+        items[0]
+    });
+    match handle.join() {
+        Ok(_) => println!("스레드 정상 종료"),
+        Err(payload) => println!("join 이 돌려준 payload: {}", payload_text(payload.as_ref())),
+    }
+
+    // RUST_BACKTRACE=1 이면 기본 훅이 백트레이스도 출력 - 훅 안에서 std::backtrace::Backtrace::capture() 로 직접도 가능
+    println!("RUST_BACKTRACE 설정: {}", std::env::var("RUST_BACKTRACE").unwrap_or_else(|_| "-".to_string()));
+}
+
+// ----------------------------------------------------------------------------
+// panic = "abort" 와 unwind
+// ----------------------------------------------------------------------------
+// 기본은 unwind - 스택을 되감으며 Drop 을 부르고, catch_unwind 나 thread join 에서 멈춤
+// Cargo.toml 의 [profile.release] panic = "abort" - 훅을 부른 뒤 바로 프로세스 종료
+//   장점: 되감기 코드(landing pad)가 없어 바이너리가 작고 조금 빠름
+//   단점: Drop 이 안 불림 (파일 버퍼, 임시 파일), catch_unwind 와 join 의 Err 가 의미 없음
+// 라이브러리는 둘 다에서 동작해야 함 - panic 을 잡는 것에 기대지 말고 Result 로 설계
+// 이 저장소는 unwind 가 필요함 - 장 실행기가 절마다 panic 을 잡으므로 (runner_isolation 절)
+
+fn unwind_vs_abort() {
+    println!("--- panic = \"abort\" 와 unwind ---");
+
+    // C++ 에서는:
+    // g++ -fno-exceptions      // throw 가 컴파일 에러, 라이브러리의 throw 는 std::abort
+    // Rust 의 panic = "abort" 는 코드는 그대로 두고 panic 의 결과만 바꿈
+
+    let strategy = if cfg!(panic = "unwind") { "unwind" } else { "abort" };
+    println!("이 빌드의 panic 전략: {}", strategy);
+
+    println!("Drop:                  unwind 는 되감으며 실행, abort 는 실행 안 됨");
+    println!("catch_unwind:          unwind 는 Err(payload), abort 는 잡지 못하고 종료");
+    println!("스레드의 panic:        unwind 는 join() 이 Err, abort 는 프로세스 종료");
+    println!("panic 훅:              둘 다 불림 (abort 는 종료 직전)");
+    println!("extern \"C\" 밖으로:     둘 다 abort");
+
+    // 전략과 상관없이 바로 끝내기 - 되감기도 훅도 없음 (C++ 의 std::abort)
+    println!("std::process::abort() - 상태가 망가져 Drop 조차 위험할 때 (여기서는 부르지 않음)");
+    // extern "C" 함수에서 panic 이 빠져나가면 abort - 45장의 guard 가 catch_unwind 로 상태 코드로 바꾸는 이유
+    // 되감기를 C 쪽으로 넘기려면 extern "C-unwind" (상대도 되감기를 이해해야 함)
+}
+
+// ----------------------------------------------------------------------------
+// UnwindSafe 와 Mutex 오염
+// ----------------------------------------------------------------------------
+// panic 이 작업 중간에 나면 값이 "반쯤 바뀐" 상태로 남을 수 있음 - C++ 의 basic vs strong exception guarantee
+// catch_unwind 는 클로저가 UnwindSafe 이길 요구 - &mut T, &RefCell<T>, &Cell<T> 를 잡으면 컴파일 에러
+//   AssertUnwindSafe(...) 로 "panic 후의 상태는 내가 책임진다" 고 표시해야 통과 (메모리 안전과는 무관, 린트에 가까움)
+// Mutex 는 런타임에 같은 일을 함 - 잠금을 쥔 채 panic 하면 오염(poison), 다음 lock() 이 Err(PoisonError)
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Accounts {
+    pub from: i64,
+    pub to: i64,
+}
+
+impl Accounts {
+    pub fn total(&self) -> i64 {
+        self.from + self.to
+    }
+
+    // basic guarantee 만 - 빼고 나서 검사가 panic 하면 돈이 사라짐
+    pub fn transfer_in_place(&mut self, amount: i64) {
+        self.from -= amount;
+        assert!(self.from >= 0, "잔액 부족");
+        self.to += amount;
+    }
+
+    // strong guarantee - 새 값을 다 계산한 뒤 한 번에 반영, panic 이면 그대로
+    pub fn transfer(&mut self, amount: i64) {
+        let from = self.from - amount;
+        assert!(from >= 0, "잔액 부족");
+        *self = Accounts { from, to: self.to + amount };
+    }
+}
+
+fn unwind_safe() {
+    println!("--- UnwindSafe 와 Mutex 오염 ---");
+
+    // C++ 에서는:
+    // void transfer(int n) { from -= n; check(); to += n; }   // check() 가 던지면 from 만 줄어듦
+    // 컴파일러는 아무 말도 없음 - Rust 는 &mut 를 catch_unwind 로 넘기는 순간 한 번 멈춰 세움
+
+    let mut accounts = Accounts { from: 100, to: 0 };
+    // panic::catch_unwind(|| accounts.transfer_in_place(150));
+    //   에러: the type `&mut Accounts` may not be safely transferred across an unwind boundary
+    let (result, _) = silently(AssertUnwindSafe(|| accounts.transfer_in_place(150)));
+    println!("transfer_in_place(150): {} → {:?}, 합계 {}", result.is_err(), accounts, accounts.total());
+
+    let mut accounts = Accounts { from: 100, to: 0 };
+    let (result, _) = silently(AssertUnwindSafe(|| accounts.transfer(150)));
+    println!("transfer(150):          {} → {:?}, 합계 {}", result.is_err(), accounts, accounts.total());
+
+    // Mutex 오염 - 잠금을 쥔 스레드가 panic 하면 다른 스레드가 반쯤 바뀐 값을 보지 않도록 표시
+    let shared = Arc::new(Mutex::new(vec![1, 2, 3]));
+    let worker = Arc::clone(&shared);
+    let joined = thread::spawn(move || {
+        silence_thread();
+        let mut items = worker.lock().unwrap();
+        items.push(4);
+        panic!("push 하고 나서 panic");
+    })
+    .join();
+    println!("작업 스레드 panic: {}, 오염됨: {}", joined.is_err(), shared.is_poisoned());
+
+    match shared.lock() {
+        Ok(items) => println!("잠금 성공 {:?}", *items),
+        Err(poisoned) => {
+            // 값은 그대로 들어 있음 - 믿을 수 있는지 판단하고 꺼내 씀 (장 실행기의 결과 목록이 이렇게 함)
+            let items = poisoned.into_inner();
+            println!("PoisonError::into_inner 로 꺼낸 값: {:?}", *items);
+        }
+    }
+    // 다시 믿을 수 있게 만들었으면 표시를 지움
+    shared.clear_poison();
+    println!("clear_poison 후 오염됨: {}", shared.is_poisoned());
+}
+
+// ----------------------------------------------------------------------------
+// 되감기 중의 Drop 과 이중 panic
+// ----------------------------------------------------------------------------
+// 되감기는 스택의 값을 만든 반대 순서로 drop - RAII 정리는 panic 에서도 실행됨 (C++ 의 stack unwinding 과 같음)
+// 되감기 중에 불린 Drop 이 또 panic 하면 더 되감을 방법이 없어 abort (C++ 의 소멸자에서 throw → std::terminate)
+// Drop 에서 검사할 때는 thread::panicking() 으로 이미 panic 중인지 보고, 그렇다면 조용히 넘어감
+
+// 만들어진 이름과 drop 된 이름을 기록하는 값
+struct Noisy<'a> {
+    name: &'static str,
+    log: &'a RefCell<Vec<String>>,
+}
+
+impl Drop for Noisy<'_> {
+    fn drop(&mut self) {
+        let note = if thread::panicking() { " (되감기 중)" } else { "" };
+        self.log.borrow_mut().push(format!("{}{}", self.name, note));
+    }
+}
+
+// 끝나기 전에 commit 해야 하는 작업 - 잊으면 Drop 에서 알려줌, 단 이미 panic 중이면 또 panic 하지 않음
+pub struct Transaction {
+    committed: bool,
+}
+
+impl Transaction {
+    pub fn begin() -> Transaction {
+        Transaction { committed: false }
+    }
+
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        // panicking() 검사가 없으면: 작업 중 panic → 되감으며 여기서 또 panic → abort
+        if !self.committed && !thread::panicking() {
+            panic!("commit 하지 않은 Transaction");
+        }
+    }
+}
+
+// 되감기 중에 drop 된 순서 - 여기서 만든 값들의 이름
+pub fn unwind_drop_order() -> Vec<String> {
+    let log = RefCell::new(Vec::new());
+    let (result, _) = silently(AssertUnwindSafe(|| {
+        let _first = Noisy { name: "first", log: &log };
+        let _second = Noisy { name: "second", log: &log };
+        {
+            let _inner = Noisy { name: "inner", log: &log };
+        }
+        panic!("second 를 만든 뒤 panic");
+    }));
+    assert!(result.is_err());
+    log.into_inner()
+}
+
+fn drop_during_panic() {
+    println!("--- 되감기 중의 Drop 과 이중 panic ---");
+
+    // C++ 에서는:
+    // ~Transaction() { if (!committed) throw ...; }   // 소멸자는 기본 noexcept - 던지면 std::terminate
+    // std::uncaught_exceptions() 로 예외 처리 중인지 보는 것이 thread::panicking() 과 같음
+
+    println!("drop 순서: {:?}", unwind_drop_order());
+
+    // commit 하고 끝내면 조용함
+    Transaction::begin().commit();
+
+    // commit 을 잊으면 Drop 의 panic
+    let (forgot, seen) = silently(|| {
+        let _tx = Transaction::begin();
+    });
+    println!("commit 을 잊음: {} - {}", forgot.is_err(), seen.map(|s| s.to_string()).unwrap_or_default());
+
+    // 작업 중 panic - Drop 은 panicking() 을 보고 넘어가므로 abort 대신 원래 panic 만 전달됨
+    let (failed, _) = silently(|| {
+        let _tx = Transaction::begin();
+        panic!("작업 중 panic");
+    });
+    println!("작업 중 panic: {}", payload_text(failed.unwrap_err().as_ref()));
+    // panicking() 검사를 지우면 이 절에서 프로세스가 "panic in a destructor during cleanup" 으로 abort
+}
+
+// ----------------------------------------------------------------------------
+// 장 실행기의 절 격리
+// ----------------------------------------------------------------------------
+// cargo run -- all 이 절 하나가 panic 해도 끝까지 도는 방법 (src/runner.rs)
+//   1. 절마다 runner::catch - catch_unwind 로 감싸 Ok 나 Panicked { message, location }
+//   2. location 은 표준 훅을 감싼 훅(src/panic_hook.rs)이 thread_local 에 기록 - 출력은 표준 훅 그대로
+//   3. 실패는 Mutex 의 목록에 모았다가 마지막 요약에 "실패 12::refcell - 메시지 (파일:줄)"
+//   4. --parallel 은 장마다 스레드 - 훅은 공유하지만 위치는 thread_local 이라 섞이지 않음
+// 절은 fn() 이라 잡은 상태가 없음 - 그래서 AssertUnwindSafe 없이 catch_unwind 에 그대로 넘어감
+// 여기서는 같은 구조를 작게 - 진짜 runner::run_section 을 부르면 실행 요약에 이 장의 가짜 실패가 섞이므로
+
+// 절 이름과 결과 (panic 이면 "메시지 (파일:줄)")
+pub fn run_isolated(sections: &[(&'static str, fn())]) -> Vec<(&'static str, Result<(), String>)> {
+    sections
+        .iter()
+        .map(|&(name, f)| {
+            let (result, seen) = silently(f);
+            (name, result.map_err(|payload| seen.map_or_else(|| payload_text(payload.as_ref()), |s| s.to_string())))
+        })
+        .collect()
+}
+
+fn fine() {}
+
+fn double_borrow() {
+    let cell = RefCell::new(1);
+    let _reading = cell.borrow();
+    *cell.borrow_mut() += 1;
+}
+
+fn bad_index() {
+    let data = [1, 2, 3];
+    let i = data.len();
+    let _ = data[i];
+}
+
+fn runner_isolation() {
+    println!("--- 장 실행기의 절 격리 ---");
+
+    // C++ 에서는:
+    // for (auto& [name, f] : sections) { try { f(); } catch (...) { failures.push_back(name); } }
+    // 다만 C++ 의 UB (범위 밖 인덱스 등) 는 잡을 예외조차 없음 - Rust 는 검사해서 panic 으로 바꿔 줌
+
+    let sections: &[(&'static str, fn())] = &[("fine", fine), ("double_borrow", double_borrow), ("bad_index", bad_index)];
+    let results = run_isolated(sections);
+    let passed = results.iter().filter(|(_, r)| r.is_ok()).count();
+    for (name, result) in &results {
+        match result {
+            Ok(()) => println!("  통과 {}", name),
+            Err(message) => println!("  실패 {} - {}", name, message),
+        }
+    }
+    println!("절 {}개 통과, {}개 실패 - 실패한 절 뒤의 절도 실행됨", passed, results.len() - passed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics_are_isolated_with_their_location() {
+        let results = run_isolated(&[("fine", fine), ("bad_index", bad_index), ("double_borrow", double_borrow)]);
+        assert_eq!(results[0], ("fine", Ok(())));
+        let message = results[1].1.as_ref().unwrap_err();
+        assert!(message.contains("index out of bounds") && message.contains("src/_48_panics.rs:"), "{}", message);
+        assert!(results[2].1.is_err());
+
+        // strong guarantee 는 panic 후에도 합계가 그대로
+        let mut accounts = Accounts { from: 100, to: 0 };
+        let (result, _) = silently(AssertUnwindSafe(|| accounts.transfer(150)));
+        assert!(result.is_err());
+        assert_eq!(accounts, Accounts { from: 100, to: 0 });
+        let (_, _) = silently(AssertUnwindSafe(|| accounts.transfer_in_place(150)));
+        assert_eq!(accounts.total(), -50);
+
+        assert_eq!(unwind_drop_order(), ["inner", "second (되감기 중)", "first (되감기 중)"]);
+    }
+}
@@ -0,0 +1,380 @@
+// ============================================================================
+// 49. 메모리 배치와 repr
+// ============================================================================
+// 값이 메모리에서 차지하는 크기(size_of)와 놓일 수 있는 주소의 배수(align_of), 그 사이의 패딩
+// Rust 의 기본 배치(repr(Rust))는 컴파일러 마음 - 필드 순서를 바꿔 패딩을 줄이고, 빈 비트에 enum 의 꼬리표를 숨김
+// 배치를 약속해야 할 때(FFI, 비트 재해석, 캐시 줄)만 #[repr(C)], #[repr(u8)], #[repr(transparent)], #[repr(align)] 을 붙임
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 는 선언 순서대로 배치 - Rust 의 기본 배치는 순서를 보장하지 않고 보통 패딩이 적게 재배치함
+// 2. C 와 같은 배치가 필요하면 #[repr(C)] 를 명시 - C++ 의 standard-layout 타입에 해당
+// 3. enum 의 꼬리표 크기는 컴파일러가 고름 - enum class : uint8_t 처럼 정하려면 #[repr(u8)]
+// 4. niche 최적화 - Option<Box<T>>, Option<&T> 는 null 을 None 으로 써서 포인터 하나 크기 (std::optional<T*> 는 꼬리표만큼 큼)
+// 5. #[repr(transparent)] 은 newtype 이 안쪽 타입과 ABI 까지 같음을 보장 - C++ 에는 대응하는 약속이 없음
+// ============================================================================
+
+use std::marker::PhantomData;
+use std::mem::{self, offset_of};
+use std::num::NonZeroU32;
+use std::sync::atomic::AtomicU64;
+
+// 절 목록 (실행 순서) - cargo run -- 49:size_align 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("size_align", size_align),
+    ("padding", padding),
+    ("enum_layout", enum_layout),
+    ("niche", niche),
+    ("repr_transparent", repr_transparent),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "49"
+    }
+
+    fn name(&self) -> &'static str {
+        "메모리 배치와 repr"
+    }
+
+    fn description(&self) -> &'static str {
+        "size_of/align_of 와 패딩, #[repr(C)] 와 기본 배치, enum 꼬리표 크기와 #[repr(u8)], Option<Box<T>> 의 niche 최적화, #[repr(transparent)] newtype"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["size_of", "align_of", "offset_of!", "패딩", "#[repr(C)]", "#[repr(u8)]", "niche", "#[repr(transparent)]", "#[repr(align)]"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// 타입 이름, 크기, 정렬을 한 줄로
+fn show<T>(name: &str) {
+    println!("  {:<34} 크기 {:>3}, 정렬 {:>2}", name, size_of::<T>(), align_of::<T>());
+}
+
+// ----------------------------------------------------------------------------
+// 크기와 정렬
+// ----------------------------------------------------------------------------
+// align_of::<T>() - T 는 이 값의 배수인 주소에만 놓임 (2 의 거듭제곱)
+// size_of::<T>() - 배열에서 다음 원소까지의 거리, 항상 정렬의 배수 (그래서 끝에도 패딩이 붙음)
+// 크기 0 인 타입(ZST) - (), PhantomData, 필드 없는 구조체 - Vec<()> 는 메모리를 할당하지 않음 (16장의 MyVec)
+// 크기를 컴파일할 때 모르는 타입(str, [T], dyn Trait)은 size_of_val 로 값마다
+
+fn size_align() {
+    println!("--- 크기와 정렬 ---");
+
+    // C++ 에서는:
+    // sizeof(T), alignof(T) - 같은 개념. 다만 빈 클래스도 sizeof 가 1 (주소가 달라야 하므로)
+    // Rust 의 ZST 는 크기 0 - [(); 1000] 도 0 바이트
+
+    show::<u8>("u8");
+    show::<u16>("u16");
+    show::<u64>("u64");
+    show::<u128>("u128");
+    show::<f32>("f32");
+    show::<char>("char");
+    show::<bool>("bool");
+    show::<usize>("usize");
+    show::<(u8, u64)>("(u8, u64)");
+    show::<[u16; 3]>("[u16; 3]");
+    show::<()>("()");
+    show::<[(); 1000]>("[(); 1000]");
+    show::<PhantomData<u64>>("PhantomData<u64>");
+
+    // 포인터 - 얇은 포인터는 usize 하나, 슬라이스와 트레이트 객체는 (포인터, 길이/vtable) 두 개 (25장, 26장)
+    show::<&u64>("&u64");
+    show::<&[u64]>("&[u64]");
+    show::<&dyn std::fmt::Debug>("&dyn Debug");
+
+    let text = "안녕";
+    println!("size_of_val(\"안녕\") = {} (UTF-8 바이트), size_of::<&str>() = {}", mem::size_of_val(text), size_of::<&str>());
+}
+
+// ----------------------------------------------------------------------------
+// 패딩과 #[repr(C)]
+// ----------------------------------------------------------------------------
+// 각 필드는 자기 정렬의 배수 오프셋에 - 그 사이를 패딩으로 채움, 구조체 정렬은 가장 엄격한 필드
+// #[repr(C)] - 선언 순서 그대로, C 의 규칙으로 패딩 (FFI 와 비트 재해석에 필요, 44장, 45장, 47장)
+// 기본 배치 - 순서를 바꿔도 됨, 실제로는 정렬이 큰 필드부터 놓아 패딩을 줄임 (보장은 아님)
+// offset_of!(타입, 필드) 로 실제 오프셋을 확인 - C 의 offsetof
+
+#[repr(C)]
+pub struct CLayout {
+    pub a: u8,
+    pub b: u32,
+    pub c: u8,
+    pub d: u16,
+}
+
+// 같은 필드, 기본 배치
+pub struct RustLayout {
+    pub a: u8,
+    pub b: u32,
+    pub c: u8,
+    pub d: u16,
+}
+
+// 패딩 없이 빽빽하게 - 필드 정렬이 1 이 되어 참조를 만들 수 없음 (네트워크 헤더, 파일 형식)
+#[repr(C, packed)]
+pub struct Packed {
+    pub a: u8,
+    pub b: u32,
+}
+
+// 정렬을 키움 - 스레드마다 쓰는 카운터를 캐시 줄(보통 64 바이트)마다 떨어뜨려 거짓 공유(false sharing)를 피함
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+#[repr(align(64))]
+pub struct CacheLine(pub AtomicU64);
+
+fn padding() {
+    println!("--- 패딩과 #[repr(C)] ---");
+
+    // C++ 에서는:
+    // struct S { uint8_t a; uint32_t b; uint8_t c; uint16_t d; };   // 선언 순서 그대로 - sizeof 12
+    // 패딩을 줄이려면 필드 순서를 손으로 바꿔야 함 (-Wpadded 가 알려줌)
+
+    show::<CLayout>("#[repr(C)] {u8, u32, u8, u16}");
+    println!(
+        "    오프셋 a {}, b {}, c {}, d {} - a 뒤에 3, c 뒤에 1 바이트 패딩",
+        offset_of!(CLayout, a),
+        offset_of!(CLayout, b),
+        offset_of!(CLayout, c),
+        offset_of!(CLayout, d)
+    );
+    show::<RustLayout>("repr(Rust) {u8, u32, u8, u16}");
+    println!(
+        "    오프셋 a {}, b {}, c {}, d {} - 컴파일러가 순서를 바꿈",
+        offset_of!(RustLayout, a),
+        offset_of!(RustLayout, b),
+        offset_of!(RustLayout, c),
+        offset_of!(RustLayout, d)
+    );
+
+    show::<Packed>("#[repr(C, packed)] {u8, u32}");
+    let packed = Packed { a: 1, b: 0x0102_0304 };
+    // let r = &packed.b;   에러 E0793: 정렬되지 않은 필드의 참조 - 정렬 안 된 &u32 는 UB
+    // 중괄호로 값을 복사해 읽음 (Copy 필드만)
+    let b = { packed.b };
+    println!("    packed.b 를 복사해 읽기: {:#x} (a = {})", b, { packed.a });
+
+    show::<CacheLine>("#[repr(align(64))] AtomicU64");
+    show::<[CacheLine; 4]>("[CacheLine; 4]");
+}
+
+// ----------------------------------------------------------------------------
+// enum 의 꼬리표
+// ----------------------------------------------------------------------------
+// 필드 없는 enum - 변형 수에 맞는 가장 작은 정수 (256 개 이하면 1 바이트)
+// #[repr(u8)], #[repr(i32)] 등으로 꼬리표 타입을 정함 - as 로 정수 변환, C enum 과 주고받을 때
+// 데이터가 있는 enum - 꼬리표 + 가장 큰 변형, 정렬에 맞춰 꼬리표 자리도 커짐
+// #[repr(u8)] 을 데이터 있는 enum 에 붙이면 "u8 꼬리표 + #[repr(C)] union" 배치가 보장됨 (47장의 CValue 를 enum 으로)
+
+#[allow(dead_code, reason = "크기와 West 의 꼬리표만 봄 - 나머지 변형은 만들지 않음")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Errno {
+    NotFound = -2,
+    Denied = -13,
+    Busy = -16,
+}
+
+impl Errno {
+    // 정수 → enum 은 as 로 안 됨 - 모든 정수가 올바른 변형은 아니므로 직접 match
+    pub fn from_code(code: i32) -> Option<Errno> {
+        match code {
+            -2 => Some(Errno::NotFound),
+            -13 => Some(Errno::Denied),
+            -16 => Some(Errno::Busy),
+            _ => None,
+        }
+    }
+}
+
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+pub enum Shape {
+    Point,
+    Circle(f32),
+    Rect(f32, f32),
+}
+
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+pub enum Message {
+    Quit,
+    Move(u8, u8),
+    Text(String),
+}
+
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+#[repr(u8)]
+pub enum Tagged {
+    Int(i32),
+    Byte(u8),
+}
+
+fn enum_layout() {
+    println!("--- enum 의 꼬리표 ---");
+
+    // C++ 에서는:
+    // enum class Errno : int32_t { NotFound = -2, Denied = -13 };   // 밑 타입을 정하는 것이 #[repr(i32)]
+    // std::variant<float, std::pair<float, float>> - 인덱스 + 저장소, 보통 Rust 의 enum 보다 큼
+
+    show::<Direction>("enum Direction");
+    show::<Errno>("#[repr(i32)] enum Errno");
+    println!("    Errno::Denied as i32 = {}, from_code(-16) = {:?}", Errno::Denied as i32, Errno::from_code(-16));
+    println!("    Direction::West as u8 = {} (꼬리표는 선언 순서로 0 부터)", Direction::West as u8);
+
+    show::<Shape>("enum Shape { f32 | (f32, f32) }");
+    show::<Message>("enum Message { (u8,u8) | String }");
+    show::<Tagged>("#[repr(u8)] enum { i32 | u8 }");
+
+    // 변형끼리 같은지만 비교 - 꼬리표 값은 보이지 않음
+    let same = mem::discriminant(&Shape::Circle(1.0)) == mem::discriminant(&Shape::Circle(2.0));
+    println!("    discriminant(Circle(1.0)) == discriminant(Circle(2.0)): {}", same);
+}
+
+// ----------------------------------------------------------------------------
+// niche 최적화
+// ----------------------------------------------------------------------------
+// 어떤 타입은 절대 가질 수 없는 비트 패턴(niche)이 있음 - &T, Box<T>, NonNull<T> 는 0 이 아님, bool 은 0/1 만, char 는 0x10FFFF 이하
+// enum 은 꼬리표를 따로 두지 않고 그 패턴으로 다른 변형을 표시 - Option<Box<T>> 의 None 은 null
+// Option<&T>, Option<Box<T>>, Option<NonZeroU32>, Option<extern "C" fn> 의 크기와 표현은 보장됨 - FFI 에서 nullable 포인터로
+// u32 처럼 모든 비트가 올바른 타입은 niche 가 없어 Option<u32> 는 꼬리표만큼 커짐
+// 위 절의 Message 가 String 과 같은 24 바이트인 것도 niche - String 의 용량 필드가 쓰지 않는 큰 값에 Quit, Move 를 넣음
+
+#[allow(dead_code, reason = "크기와 정렬만 재는 타입 - 값을 만들거나 읽지 않음")]
+pub enum OneBox {
+    Full(Box<u64>),
+    Empty,
+}
+
+fn niche() {
+    println!("--- niche 최적화 ---");
+
+    // C++ 에서는:
+    // sizeof(std::optional<int*>) == 16 - 포인터 + bool + 패딩, null 을 "없음" 으로 쓰려면 optional 대신 그냥 포인터
+    // Rust 는 Option<Box<T>> 가 포인터 하나 - 타입으로는 구분되고 비용은 같음
+
+    show::<Box<u64>>("Box<u64>");
+    show::<Option<Box<u64>>>("Option<Box<u64>>");
+    show::<Option<&u64>>("Option<&u64>");
+    show::<Option<&[u64]>>("Option<&[u64]>");
+    show::<Option<Vec<u64>>>("Option<Vec<u64>>");
+    show::<OneBox>("enum { Box<u64> | Empty }");
+    show::<u32>("u32");
+    show::<Option<u32>>("Option<u32>");
+    show::<Option<NonZeroU32>>("Option<NonZeroU32>");
+    show::<Option<bool>>("Option<bool>");
+    show::<Option<Option<bool>>>("Option<Option<bool>>");
+    show::<Option<char>>("Option<char>");
+    show::<Option<extern "C" fn()>>("Option<extern \"C\" fn()>");
+
+    // None 은 정말 null - 크기와 표현이 보장되므로 이렇게 확인해도 됨 (47장의 transmute 가 아니라 포인터로)
+    let none: Option<Box<u64>> = None;
+    // SAFETY: Option<Box<u64>> 는 크기와 정렬이 *const u64 와 같음이 보장됨
+    let bits = unsafe { *(&none as *const Option<Box<u64>>).cast::<*const u64>() };
+    println!("None 의 비트: {:?} (null = {})", bits, bits.is_null());
+
+    // 0 이 아님을 타입으로 - 0 을 넣으면 None
+    println!("NonZeroU32::new(0) = {:?}, new(7) = {:?}", NonZeroU32::new(0), NonZeroU32::new(7));
+}
+
+// ----------------------------------------------------------------------------
+// #[repr(transparent)] newtype
+// ----------------------------------------------------------------------------
+// newtype(struct Meters(f64))은 타입 안전을 더하고 비용은 없음 - 크기와 정렬은 기본 배치로도 같지만 ABI 는 약속이 아님
+// #[repr(transparent)] - 크기가 0 이 아닌 필드 하나(+ PhantomData 같은 ZST)면, 메모리 배치와 호출 규약까지 그 필드와 같음을 보장
+// 그래서 extern "C" 함수의 인자로 f64 대신 넘기거나, &[f64] 를 &[Meters] 로 다시 볼 수 있음 (std 의 NonNull, Wrapping 등이 이렇게 선언됨)
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Meters(pub f64);
+
+// 단위를 타입 매개변수로 - ZST 필드는 있어도 transparent
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity<Unit> {
+    pub value: f64,
+    unit: PhantomData<Unit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Seconds;
+
+impl<Unit> Quantity<Unit> {
+    pub fn new(value: f64) -> Self {
+        Quantity { value, unit: PhantomData }
+    }
+}
+
+// 복사 없이 &[f64] 를 &[Meters] 로
+pub fn as_meters(values: &[f64]) -> &[Meters] {
+    // SAFETY: Meters 는 #[repr(transparent)] 라 f64 와 크기, 정렬, 올바른 값이 같음 - 길이와 수명은 그대로
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast::<Meters>(), values.len()) }
+}
+
+// C 쪽 선언은 double half(double) - Meters 로 받아도 같은 ABI
+extern "C" fn half(distance: Meters) -> Meters {
+    Meters(distance.0 / 2.0)
+}
+
+fn repr_transparent() {
+    println!("--- #[repr(transparent)] newtype ---");
+
+    // C++ 에서는:
+    // struct Meters { double v; };   // 크기는 같아도 호출 규약이 double 과 같다는 보장은 플랫폼마다
+    // reinterpret_cast<const Meters*>(doubles) 는 엄밀히는 엄격한 별칭 규칙 위반
+
+    show::<f64>("f64");
+    show::<Meters>("#[repr(transparent)] Meters");
+    show::<Quantity<Seconds>>("Quantity<Seconds>");
+
+    let readings = [1.5, 20.0, 7.25];
+    let meters = as_meters(&readings);
+    let longest = meters.iter().copied().fold(Meters(0.0), |a, b| if b > a { b } else { a });
+    println!("as_meters: {:?}, 가장 긴 것 {:?}", meters, longest);
+    println!("extern \"C\" fn half(Meters(10.0)) = {:?}", half(Meters(10.0)));
+
+    let timeout: Quantity<Seconds> = Quantity::new(2.5);
+    println!("Quantity<Seconds> 의 값: {}", timeout.value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guaranteed_layouts() {
+        // #[repr(C)] 는 선언 순서와 C 의 패딩 규칙
+        assert_eq!((size_of::<CLayout>(), align_of::<CLayout>()), (12, 4));
+        assert_eq!([offset_of!(CLayout, b), offset_of!(CLayout, c), offset_of!(CLayout, d)], [4, 8, 10]);
+        assert_eq!(size_of::<Packed>(), 5);
+        assert_eq!((size_of::<CacheLine>(), align_of::<CacheLine>()), (64, 64));
+
+        assert_eq!(size_of::<Errno>(), 4);
+        assert_eq!(Errno::from_code(Errno::Busy as i32), Some(Errno::Busy));
+        assert_eq!(Errno::from_code(0), None);
+
+        // 표준 문서가 보장하는 niche
+        assert_eq!(size_of::<Option<Box<u64>>>(), size_of::<usize>());
+        assert_eq!(size_of::<Option<&u64>>(), size_of::<usize>());
+        assert_eq!(size_of::<Option<NonZeroU32>>(), 4);
+
+        assert_eq!(size_of::<Quantity<Seconds>>(), size_of::<f64>());
+        let values = [3.0, -1.0];
+        assert_eq!(as_meters(&values), [Meters(3.0), Meters(-1.0)]);
+        assert_eq!(as_meters(&values).as_ptr().cast::<f64>(), values.as_ptr());
+    }
+}
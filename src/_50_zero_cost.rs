@@ -0,0 +1,288 @@
+// ============================================================================
+// 50. 제로 코스트 추상화 재 보기
+// ============================================================================
+// 11장의 "이터레이터는 수동 루프와 같은 성능" 을 장 안에서 직접 재 봄
+// 같은 계산을 인덱스 루프, 이터레이터 체인, unsafe 포인터 루프로 - 답이 같은지 확인한 뒤 microbench 로 비교
+// 그리고 "비용이 없다" 가 성립하지 않는 경우 (중간 collect, Box<dyn Iterator>) 도 함께
+// (측정기: src/microbench.rs - 디버그 빌드의 수치는 참고만, cargo run --release -- 50)
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 의 "zero-overhead principle" 과 같은 약속 - 쓰지 않는 것에 비용이 없고, 쓰는 것은 손으로 짠 것보다 느리지 않음
+// 2. 이터레이터 체인은 ranges 파이프라인처럼 인라인되어 하나의 루프가 됨 - 다만 Rust 는 디버그 빌드에서도 같은 코드
+// 3. v[i] 는 항상 경계 검사 (C++ 의 v.at(i)) - 최적화기가 증명할 수 있으면 지우고, 이터레이터는 처음부터 검사가 없음
+// 4. C 스타일 포인터 루프는 unsafe 가 필요하고 더 빠르지도 않음 - 이터레이터가 안에서 같은 일을 함
+// 5. 추상화가 비용이 되는 곳: 할당(collect), 간접 호출(dyn) - C++ 의 std::function, 중간 vector 와 같음
+// ============================================================================
+
+use std::hint::black_box;
+
+use crate::microbench;
+
+// 절 목록 (실행 순서) - cargo run -- 50:three_loops 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("three_loops", three_loops),
+    ("bounds_checks", bounds_checks),
+    ("measuring", measuring),
+    ("hidden_costs", hidden_costs),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "50"
+    }
+
+    fn name(&self) -> &'static str {
+        "제로 코스트 추상화 재 보기"
+    }
+
+    fn description(&self) -> &'static str {
+        "같은 계산을 인덱스 루프, 이터레이터 체인, unsafe 포인터 루프로 - 장 안의 측정기로 비교표, 경계 검사 제거, collect 와 dyn 의 숨은 비용"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["제로 코스트", "이터레이터", "경계 검사", "black_box", "microbench", "포인터 루프", "Box<dyn Iterator>"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// 측정에 쓰는 입력 크기와 한 번 잴 때 호출 횟수
+const LEN: usize = 4_096;
+const ITERATIONS: u32 = 50;
+
+// 규칙적이지 않은 작은 값 - 짝수/홀수가 섞여 분기가 생기고, 제곱해 더해도 넘치지 않음
+fn workload(len: usize) -> Vec<u64> {
+    (0..len as u64).map(|i| i * 7_919 % 1_000).collect()
+}
+
+// ----------------------------------------------------------------------------
+// 같은 계산을 세 가지로
+// ----------------------------------------------------------------------------
+// 짝수의 제곱 합 - benches/iterators.rs 와 같은 계산 (criterion 으로 정밀하게 재는 쪽)
+// 1. 인덱스 루프 - C 처럼 i 를 늘리며 v[i] (매번 경계 검사가 있지만 최적화기가 지움)
+// 2. 이터레이터 체인 - filter, map, sum 이 인라인되어 루프 하나로
+// 3. 포인터 루프 - C++ 의 for (p = begin; p != end; ++p), Rust 에서는 역참조마다 unsafe
+
+pub fn index_loop(v: &[u64]) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < v.len() {
+        if v[i].is_multiple_of(2) {
+            sum += v[i] * v[i];
+        }
+        i += 1;
+    }
+    sum
+}
+
+pub fn iterator_chain(v: &[u64]) -> u64 {
+    v.iter().filter(|&&x| x.is_multiple_of(2)).map(|&x| x * x).sum()
+}
+
+pub fn pointer_loop(v: &[u64]) -> u64 {
+    let mut sum = 0;
+    // [start, end) - end 는 마지막 원소 바로 다음 (역참조하지 않음)
+    let std::ops::Range { start: mut p, end } = v.as_ptr_range();
+    while p != end {
+        // SAFETY: p 는 [start, end) 안 - v 를 빌리는 동안 가리키는 값이 살아 있음
+        let x = unsafe { *p };
+        if x.is_multiple_of(2) {
+            sum += x * x;
+        }
+        // SAFETY: p < end 이므로 한 칸 앞은 많아야 end (같은 할당의 끝 바로 다음까지는 허용)
+        p = unsafe { p.add(1) };
+    }
+    sum
+}
+
+fn three_loops() {
+    println!("--- 같은 계산을 세 가지로 ---");
+
+    // C++ 에서는:
+    // for (size_t i = 0; i < v.size(); ++i) if (v[i] % 2 == 0) sum += v[i] * v[i];
+    // auto r = v | views::filter(even) | views::transform(square); std::accumulate(r.begin(), r.end(), 0);
+    // for (auto p = v.data(); p != v.data() + v.size(); ++p) ...
+
+    let v = workload(LEN);
+    let answers = [index_loop(&v), iterator_chain(&v), pointer_loop(&v)];
+    println!("원소 {}개, 짝수의 제곱 합: index {}, iterator {}, pointer {}", v.len(), answers[0], answers[1], answers[2]);
+    println!("같은 답? {}", answers.iter().all(|&a| a == answers[0]));
+    // 빈 입력도 - 포인터 루프는 start == end 라 한 번도 돌지 않음
+    println!("빈 입력: {} {} {}", index_loop(&[]), iterator_chain(&[]), pointer_loop(&[]));
+}
+
+// ----------------------------------------------------------------------------
+// 경계 검사와 그 제거
+// ----------------------------------------------------------------------------
+// v[i] 는 i < v.len() 을 검사하고 아니면 panic - 검사 자체는 비교 하나지만 벡터화(SIMD)를 막을 수 있음
+// 최적화기는 루프 조건에서 i < v.len() 을 알면 검사를 지움 - 길이가 다른 두 슬라이스면 증명하지 못하기도
+// 도와주는 법: 먼저 같은 길이로 잘라 두기(&a[..n]), 아니면 zip 처럼 검사가 필요 없는 이터레이터
+// get_unchecked 는 마지막 수단 - 빨라지는지 먼저 재 보고, 대부분 위 두 방법으로 충분
+
+// 두 슬라이스의 내적 - 짧은 쪽 길이까지
+pub fn dot_index(a: &[u64], b: &[u64]) -> u64 {
+    let n = a.len().min(b.len());
+    let mut sum = 0;
+    let mut i = 0;
+    while i < n {
+        // n 이 a, b 어느 쪽의 길이인지 최적화기가 따로 추론해야 함
+        sum += a[i] * b[i];
+        i += 1;
+    }
+    sum
+}
+
+// 같은 길이로 잘라 두면 i < a.len() 에서 b[i] 도 안전함이 보임
+pub fn dot_sliced(a: &[u64], b: &[u64]) -> u64 {
+    let n = a.len().min(b.len());
+    let (a, b) = (&a[..n], &b[..n]);
+    let mut sum = 0;
+    let mut i = 0;
+    while i < a.len() {
+        sum += a[i] * b[i];
+        i += 1;
+    }
+    sum
+}
+
+pub fn dot_zip(a: &[u64], b: &[u64]) -> u64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn bounds_checks() {
+    println!("--- 경계 검사와 그 제거 ---");
+
+    // C++ 에서는:
+    // v[i] 는 검사 없음 (범위 밖이면 UB), v.at(i) 는 검사하고 예외
+    // Rust 의 v[i] 는 at() 쪽 - 대신 최적화기가 지울 수 있는 검사는 지움
+
+    let a = workload(LEN);
+    let b: Vec<u64> = workload(LEN + 3).into_iter().rev().collect();
+    println!(
+        "길이 {} 와 {} 의 내적: index {}, sliced {}, zip {}",
+        a.len(),
+        b.len(),
+        dot_index(&a, &b),
+        dot_sliced(&a, &b),
+        dot_zip(&a, &b)
+    );
+
+    // 검사가 실제로 하는 일 - 범위 밖이면 UB 대신 panic (48장), 검사 결과를 직접 받으려면 get
+    let short = [1u64, 2, 3];
+    println!("short.get(3) = {:?} - short[3] 이었다면 panic", short.get(black_box(3)));
+
+    println!("release 기계어로 확인: cargo asm 이나 godbolt 에서 panic_bounds_check 호출이 루프 안에 남는지");
+}
+
+// ----------------------------------------------------------------------------
+// 재 보기
+// ----------------------------------------------------------------------------
+// microbench::measure - 예열 후 여러 번 재서 중앙값, 결과는 black_box 로 (26장과 같은 측정기)
+// 입력도 black_box 로 감싸 - 컴파일러가 상수로 보고 답을 미리 계산하지 못하게
+
+fn measuring() {
+    println!("--- 재 보기 ---");
+
+    let v = workload(LEN);
+    let b: Vec<u64> = v.iter().rev().copied().collect();
+    println!("{}", microbench::build_profile());
+    println!(
+        "원소 {}개를 {}번씩, 예열 {}번 후 {}번 재서 중앙값 (호출 한 번당 시간)",
+        LEN,
+        ITERATIONS,
+        microbench::WARMUP,
+        microbench::SAMPLES
+    );
+
+    println!("짝수의 제곱 합:");
+    let sums = [
+        microbench::measure("index_loop", ITERATIONS, || index_loop(black_box(&v))),
+        microbench::measure("iterator_chain", ITERATIONS, || iterator_chain(black_box(&v))),
+        microbench::measure("pointer_loop", ITERATIONS, || pointer_loop(black_box(&v))),
+    ];
+    println!("{}", microbench::table(&sums));
+
+    println!("내적:");
+    let dots = [
+        microbench::measure("dot_index", ITERATIONS, || dot_index(black_box(&v), black_box(&b))),
+        microbench::measure("dot_sliced", ITERATIONS, || dot_sliced(black_box(&v), black_box(&b))),
+        microbench::measure("dot_zip", ITERATIONS, || dot_zip(black_box(&v), black_box(&b))),
+    ];
+    println!("{}", microbench::table(&dots));
+
+    for m in sums.iter().chain(&dots) {
+        println!("  {:<15} 원소 하나당 {:.3} ns", m.name, m.per_call_ns() / LEN as f64);
+    }
+
+    // 흔히 보는 릴리스 빌드 결과 (기계마다 다름):
+    //   세 루프가 x1.0 근처 - 같은 기계어가 되었다는 뜻 (11장의 주장)
+    //   dot_index, dot_sliced 가 dot_zip 보다 조금 느리면 루프 안에 경계 검사가 남아 벡터화를 막은 것
+    //   (어느 쪽의 검사를 지우는지는 컴파일러 버전마다 다름 - 그래서 재 봄)
+    // 디버그 빌드에서는 이터레이터 체인이 느림 - 인라인이 꺼져 클로저와 next() 가 진짜 함수 호출
+}
+
+// ----------------------------------------------------------------------------
+// 비용이 없지 않은 추상화
+// ----------------------------------------------------------------------------
+// "제로 코스트" 는 추상화 자체의 비용 이야기 - 하는 일이 늘면 그만큼 듦
+//   중간 collect    체인 사이에 Vec 을 만들면 할당과 한 번 더 도는 비용 (지연 평가가 깨짐)
+//   Box<dyn Iterator> next() 가 원소마다 vtable 을 거쳐 인라인되지 않음 (26장의 dyn 과 같은 이유)
+// 필요할 때는 쓰면 됨 - 다만 뜨거운 루프 안에서는 재 보고 고름
+
+pub fn with_collect(v: &[u64]) -> u64 {
+    let evens: Vec<u64> = v.iter().copied().filter(|x| x.is_multiple_of(2)).collect();
+    evens.iter().map(|&x| x * x).sum()
+}
+
+pub fn with_dyn(v: &[u64]) -> u64 {
+    let evens: Box<dyn Iterator<Item = &u64>> = Box::new(v.iter().filter(|&&x| x.is_multiple_of(2)));
+    evens.map(|&x| x * x).sum()
+}
+
+fn hidden_costs() {
+    println!("--- 비용이 없지 않은 추상화 ---");
+
+    // C++ 에서는:
+    // std::vector<uint64_t> evens; std::copy_if(...);       // 중간 vector - ranges 를 쓰면 피할 수 있음
+    // std::function<bool(uint64_t)> pred;                    // 타입 소거 - 호출마다 간접 호출
+
+    let v = workload(LEN);
+    println!("같은 답? {}", with_collect(&v) == iterator_chain(&v) && with_dyn(&v) == iterator_chain(&v));
+
+    let results = [
+        microbench::measure("iterator_chain", ITERATIONS, || iterator_chain(black_box(&v))),
+        microbench::measure("with_collect", ITERATIONS, || with_collect(black_box(&v))),
+        microbench::measure("with_dyn", ITERATIONS, || with_dyn(black_box(&v))),
+    ];
+    println!("{}", microbench::table(&results));
+
+    // 흔히 보는 릴리스 빌드 결과: iterator_chain < with_dyn < with_collect (할당과 두 번 도는 비용이 가장 큼)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_variant_computes_the_same_answer() {
+        for len in [0, 1, 2, 7, 100] {
+            let v = workload(len);
+            let expected: u64 = v.iter().filter(|&&x| x % 2 == 0).map(|&x| x * x).sum();
+            for f in [index_loop, iterator_chain, pointer_loop, with_collect, with_dyn] {
+                assert_eq!(f(&v), expected, "len {}", len);
+            }
+
+            let b = workload(len + 3);
+            let dot: u64 = (0..len).map(|i| v[i] * b[i]).sum();
+            for f in [dot_index, dot_sliced, dot_zip] {
+                assert_eq!(f(&v, &b), dot);
+                assert_eq!(f(&b, &v), dot);
+            }
+        }
+    }
+}
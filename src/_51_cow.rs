@@ -0,0 +1,345 @@
+// ============================================================================
+// 51. Cow 와 clone-on-write
+// ============================================================================
+// Cow<'a, B> = "빌린 것이거나 가진 것" - 바꿀 일이 없으면 빌린 채로, 바꿀 때만 복사해 소유
+// 입력을 대부분 그대로 돌려주는 함수(이스케이프, 정규화, 경계값 자르기)가 할당 없이 끝나게 해 줌
+// 이 저장소에서도: style::strip (색 코드가 없으면 그대로), i18n::translate (번역이 없으면 그대로)
+//
+// C++20과의 핵심 차이점:
+// 1. string_view 를 받아 string 을 돌려주면 바뀌지 않아도 복사 - Cow<str> 는 Borrowed 로 그대로 돌려줌
+// 2. C++11 이전 std::string 의 COW 는 참조 카운트로 숨겨져 있었음 - Rust 의 Cow 는 enum 이라 어느 쪽인지 보이고 카운트가 없음
+// 3. 빌린 쪽의 수명이 타입에 있음 ('a) - 입력보다 오래 살 수 없다는 것을 컴파일러가 검사 (댕글링 string_view 가 없음)
+// 4. to_mut() 이 처음 쓸 때만 복사 - "필요하면 복사" 를 손으로 짠 optional<string> + string_view 대신 한 타입으로
+// 5. 참조 카운트 COW (여럿이 공유하다 쓸 때 복사) 는 Rc::make_mut / Arc::make_mut (12장)
+// ============================================================================
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+// 절 목록 (실행 순서) - cargo run -- 51:cow_basics 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("cow_basics", cow_basics),
+    ("avoid_allocation", avoid_allocation),
+    ("to_mut_into_owned", to_mut_into_owned),
+    ("cow_slices", cow_slices),
+    ("choosing_parameters", choosing_parameters),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "51"
+    }
+
+    fn name(&self) -> &'static str {
+        "Cow 와 clone-on-write"
+    }
+
+    fn description(&self) -> &'static str {
+        "Cow<str>/Cow<[T]> 로 바꿀 일이 없으면 할당하지 않는 API, to_mut 과 into_owned, Rc::make_mut, &str/String/Cow 매개변수 고르기 - string_view 와 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Cow", "ToOwned", "to_mut", "into_owned", "from_utf8_lossy", "Rc::make_mut", "Cow<'static, str>", "string_view"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// 어느 변형인지 - 출력용
+#[expect(clippy::ptr_arg, reason = "&B 로는 어느 변형인지 알 수 없음")]
+fn kind<B: ?Sized + ToOwned>(cow: &Cow<'_, B>) -> &'static str {
+    match cow {
+        Cow::Borrowed(_) => "Borrowed",
+        Cow::Owned(_) => "Owned",
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Cow 의 생김새
+// ----------------------------------------------------------------------------
+// enum Cow<'a, B: ?Sized + ToOwned> { Borrowed(&'a B), Owned(<B as ToOwned>::Owned) }
+// ToOwned - 빌린 타입에서 가진 타입으로: str → String, [T] → Vec<T>, Path → PathBuf, CStr → CString
+// Deref 로 &B 처럼 씀 - Cow<str> 에 len(), contains() 를 바로 부르고 &str 을 받는 함수에 &cow 로 넘김
+
+fn cow_basics() {
+    println!("--- Cow 의 생김새 ---");
+
+    // C++ 에서는:
+    // std::variant<std::string_view, std::string> 을 직접 만들고 visit 으로 꺼내야 함
+    // Rust 는 표준 타입 하나 - 두 변형 모두 Deref 로 &str
+
+    let borrowed: Cow<str> = Cow::Borrowed("borrowed text");
+    let owned: Cow<str> = Cow::Owned(String::from("owned text"));
+    // From 구현 - &str 이면 Borrowed, String 이면 Owned
+    let from_literal = Cow::from("literal");
+    let from_string = Cow::from(format!("{}장에서 만듦", 51));
+    for cow in [&borrowed, &owned, &from_literal, &from_string] {
+        println!("  {:<8} len {:>2}: {}", kind(cow), cow.len(), cow);
+    }
+
+    // 표준 라이브러리가 돌려주는 Cow - 올바른 UTF-8 이면 입력을 그대로 빌려 줌
+    let valid = String::from_utf8_lossy(b"hello");
+    let invalid = String::from_utf8_lossy(b"hel\xfflo");
+    println!("from_utf8_lossy(올바른 UTF-8): {} {:?}", kind(&valid), valid);
+    println!("from_utf8_lossy(깨진 바이트): {} {:?}", kind(&invalid), invalid);
+
+    // 크기 - String 과 같음 (String 의 용량 필드에 꼬리표를 숨기는 niche, 49장)
+    println!("size_of: String {}, Cow<str> {}, &str {}", size_of::<String>(), size_of::<Cow<str>>(), size_of::<&str>());
+}
+
+// ----------------------------------------------------------------------------
+// 바꿀 일이 없으면 할당하지 않기
+// ----------------------------------------------------------------------------
+// 입력 대부분이 그대로 나가는 변환 - 검사만 하고 그대로면 Borrowed, 바꿔야 할 때만 String 을 만듦
+// 돌려받는 쪽은 어느 쪽인지 몰라도 됨 - &str 처럼 읽거나, 필요하면 into_owned
+
+// HTML 특수 문자 이스케이프 - 특수 문자가 없으면 입력을 그대로
+pub fn escape_html(text: &str) -> Cow<'_, str> {
+    if !text.contains(['<', '>', '&', '"']) {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        match c {
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '&' => out.push_str("&amp;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+// 연속된 공백을 하나로 - 이미 그렇다면 그대로
+pub fn collapse_spaces(text: &str) -> Cow<'_, str> {
+    if !text.contains("  ") {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(text.split(' ').filter(|w| !w.is_empty()).collect::<Vec<_>>().join(" "))
+}
+
+fn avoid_allocation() {
+    println!("--- 바꿀 일이 없으면 할당하지 않기 ---");
+
+    // C++ 에서는:
+    // std::string escape_html(std::string_view s);   // 특수 문자가 없어도 매번 새 string
+    // 피하려면 bool 을 돌려주는 검사 함수 + 변환 함수 두 개로 나누고 호출하는 쪽이 분기
+
+    let lines = ["plain text", "a < b && c > d", "say \"hi\"", "nothing to do here", "x&y"];
+    let mut borrowed = 0;
+    for line in lines {
+        let escaped = escape_html(line);
+        // Borrowed 면 같은 메모리 - 포인터가 같음
+        let same = std::ptr::eq(escaped.as_ptr(), line.as_ptr());
+        if same {
+            borrowed += 1;
+        }
+        println!("  {:<8} 같은 메모리 {:<5} {}", kind(&escaped), same, escaped);
+    }
+    println!("{}줄 중 {}줄은 할당 없이", lines.len(), borrowed);
+
+    // 변환을 이어 붙이기 - 앞 단계가 Borrowed 면 뒤 단계도 같은 원본을 빌림
+    for text in ["one two", "one   two  <b>"] {
+        let collapsed = collapse_spaces(text);
+        let escaped = escape_html(&collapsed);
+        println!("  collapse {} → escape {}: {}", kind(&collapsed), kind(&escaped), escaped);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// to_mut 과 into_owned
+// ----------------------------------------------------------------------------
+// to_mut() -> &mut Owned - Borrowed 면 이때 복사해 Owned 로 바꾸고, 이미 Owned 면 그대로 (두 번째부터는 공짜)
+// into_owned() -> Owned - Borrowed 면 복사, Owned 면 이동 (할당 없음) - 입력보다 오래 보관해야 할 때
+// 참조 카운트로 공유하다 쓸 때 복사하는 것은 Rc::make_mut - 다른 Rc 가 있을 때만 복사
+
+// 첫 번째 # 부터 줄 끝까지 지우고, 탭을 공백으로 - 할 일이 있을 때만 복사
+pub fn clean_line(line: &str) -> Cow<'_, str> {
+    // 자르기는 빌린 채로 - 원본의 앞부분을 가리키는 &str
+    let kept = match line.find('#') {
+        Some(start) => line[..start].trim_end(),
+        None => line,
+    };
+    let mut text = Cow::Borrowed(kept);
+    if text.contains('\t') {
+        // 여기서 처음 새 String - 자르기만 한 앞 단계는 할당하지 않았음
+        text = Cow::Owned(text.replace('\t', " "));
+    }
+    text
+}
+
+fn to_mut_into_owned() {
+    println!("--- to_mut 과 into_owned ---");
+
+    // C++ 에서는:
+    // std::optional<std::string> copy; std::string_view view = input;
+    // if (needs_change) { copy = std::string(view); view = *copy; ... }   // 손으로 하는 clone-on-write
+
+    let mut greeting = Cow::Borrowed("hello");
+    println!("처음: {}", kind(&greeting));
+    greeting.to_mut().push_str(", world");
+    println!("to_mut 한 번: {} {:?}", kind(&greeting), greeting);
+    // 이미 Owned - 복사 없이 같은 String 에 push
+    greeting.to_mut().push('!');
+    println!("to_mut 두 번: {} {:?}", kind(&greeting), greeting);
+
+    for line in ["key = value", "key = value  # note", "key =\tvalue"] {
+        let cleaned = clean_line(line);
+        println!("  clean_line: {:<8} {:?}", kind(&cleaned), cleaned);
+    }
+
+    // 입력(line)이 사라진 뒤에도 쓰려면 into_owned - 'a 가 없는 String 이 됨
+    let kept: String = {
+        let line = String::from("temporary\tline");
+        clean_line(&line).into_owned()
+    };
+    println!("into_owned 로 보관: {:?}", kept);
+
+    // Rc::make_mut - 다른 Rc 가 있으면 복사, 혼자면 그 자리에서 수정 (12장)
+    let mut shared = Rc::new(vec![1, 2, 3]);
+    let other = Rc::clone(&shared);
+    Rc::make_mut(&mut shared).push(4);
+    println!("공유 중 make_mut: shared {:?}, other {:?} (복사됨)", shared, other);
+    let before = Rc::as_ptr(&shared);
+    Rc::make_mut(&mut shared).push(5);
+    println!("혼자일 때 make_mut: 같은 할당? {}", before == Rc::as_ptr(&shared));
+}
+
+// ----------------------------------------------------------------------------
+// Cow<[T]>
+// ----------------------------------------------------------------------------
+// [T] 의 ToOwned 는 Vec<T> - T: Clone 이면 슬라이스도 같은 방식
+// to_mut() 이 Vec 을 돌려주므로 원소를 고치거나 push 할 수 있음
+
+// 범위 밖 값만 고침 - 모두 범위 안이면 입력 그대로
+pub fn clamp_all(values: &[i32], lo: i32, hi: i32) -> Cow<'_, [i32]> {
+    let mut out = Cow::Borrowed(values);
+    for (i, &v) in values.iter().enumerate() {
+        if v < lo || v > hi {
+            out.to_mut()[i] = v.clamp(lo, hi);
+        }
+    }
+    out
+}
+
+// 정렬된 슬라이스의 중복 제거 - 중복이 없으면 그대로
+pub fn dedup_sorted<T: Clone + PartialEq>(items: &[T]) -> Cow<'_, [T]> {
+    if items.windows(2).all(|w| w[0] != w[1]) {
+        return Cow::Borrowed(items);
+    }
+    let mut owned = items.to_vec();
+    owned.dedup();
+    Cow::Owned(owned)
+}
+
+fn cow_slices() {
+    println!("--- Cow<[T]> ---");
+
+    // C++ 에서는:
+    // std::span<const int> 을 받아 std::vector<int> 를 돌려주면 항상 복사
+
+    for values in [&[1, 5, 9][..], &[-3, 5, 120, 7][..]] {
+        let clamped = clamp_all(values, 0, 100);
+        println!("  clamp_all({:?}) → {} {:?}", values, kind(&clamped), clamped);
+    }
+
+    for words in [&["a", "b", "c"][..], &["a", "a", "b", "c", "c"][..]] {
+        let unique = dedup_sorted(words);
+        println!("  dedup_sorted({:?}) → {} {:?}", words, kind(&unique), unique);
+    }
+
+    // Cow<[T]> 에서 Vec 이 필요하면 into_owned
+    let v: Vec<i32> = clamp_all(&[200, -1], 0, 100).into_owned();
+    println!("into_owned: {:?}", v);
+}
+
+// ----------------------------------------------------------------------------
+// 매개변수와 필드 타입 고르기
+// ----------------------------------------------------------------------------
+// 매개변수:
+//   &str                   읽기만 - 거의 항상 이것 (String, Cow<str> 모두 &로 넘길 수 있음)
+//   String                 함수가 보관 - 호출하는 쪽이 이미 가진 String 을 복사 없이 넘김
+//   impl Into<String>      보관 + 호출 편의 ("lit" 와 String 모두) - 18장의 빌더
+//   Cow<'a, str>           매개변수로는 드묾 - 돌려주는 값과 필드에 씀
+// 필드: Cow<'static, str> - 대부분 문자열 리터럴이고 가끔 만든 문자열인 값 (에러 메시지, 라벨, 기본 이름)
+//   리터럴은 할당 없이, format! 결과는 그대로 - 둘을 한 타입으로
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub code: u32,
+    pub message: Cow<'static, str>,
+}
+
+impl Diagnostic {
+    // &'static str 과 String 모두 - Cow::from 이 알맞은 변형을 고름
+    pub fn new(code: u32, message: impl Into<Cow<'static, str>>) -> Diagnostic {
+        Diagnostic { code, message: message.into() }
+    }
+}
+
+// 대부분은 고정 문구, 드물게 만든 문구
+pub fn describe(count: usize) -> Cow<'static, str> {
+    match count {
+        0 => "항목 없음".into(),
+        1 => "항목 하나".into(),
+        n => format!("항목 {}개", n).into(),
+    }
+}
+
+fn choosing_parameters() {
+    println!("--- 매개변수와 필드 타입 고르기 ---");
+
+    // C++ 에서는:
+    // void log(std::string_view msg);          // 읽기만 → &str
+    // void set_name(std::string name);         // 보관 (move 로 받음) → String 이나 impl Into<String>
+    // 리터럴과 만든 문자열을 한 필드에 - const char* + std::string 두 필드나 항상 std::string
+
+    let diagnostics = [
+        Diagnostic::new(1, "세미콜론이 없음"),
+        Diagnostic::new(2, format!("알 수 없는 이름 `{}`", "foo")),
+    ];
+    for d in &diagnostics {
+        println!("  code {} {:<8} {}", d.code, kind(&d.message), d.message);
+    }
+
+    for n in [0, 1, 5] {
+        let text = describe(n);
+        println!("  describe({}) → {} {:?}", n, kind(&text), text);
+    }
+
+    // &str 을 받는 함수에는 어느 쪽이든 &로
+    fn char_count(text: &str) -> usize {
+        text.chars().count()
+    }
+    println!("&Cow<str> → &str: char_count = {}", char_count(&describe(3)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrows_when_nothing_changes() {
+        assert!(matches!(escape_html("plain"), Cow::Borrowed("plain")));
+        assert_eq!(escape_html("a<b & \"c\""), "a&lt;b &amp; &quot;c&quot;");
+        assert!(matches!(collapse_spaces("a b"), Cow::Borrowed(_)));
+        assert_eq!(collapse_spaces("  a   b "), "a b");
+
+        assert!(matches!(clean_line("k = v  # note"), Cow::Borrowed("k = v")));
+        assert_eq!(clean_line("k =\tv # note"), "k = v");
+
+        assert!(matches!(clamp_all(&[1, 2], 0, 10), Cow::Borrowed(_)));
+        assert_eq!(*clamp_all(&[-5, 2, 50], 0, 10), [0, 2, 10]);
+        assert!(matches!(dedup_sorted(&[1, 2, 3]), Cow::Borrowed(_)));
+        assert_eq!(*dedup_sorted(&[1, 1, 2, 3, 3]), [1, 2, 3]);
+
+        assert!(matches!(Diagnostic::new(1, "static").message, Cow::Borrowed(_)));
+        assert!(matches!(describe(7), Cow::Owned(ref s) if s == "항목 7개"));
+    }
+}
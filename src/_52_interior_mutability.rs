@@ -0,0 +1,408 @@
+// ============================================================================
+// 52. 내부 가변성 총정리
+// ============================================================================
+// &T 로도 값을 바꿀 수 있게 하는 타입들 - 12장의 RefCell 은 그중 하나
+// 고르는 기준은 세 가지: 스레드를 넘나드는가, 안의 값을 참조로 빌려야 하는가, 한 번만 쓰는가
+//   Cell          값을 통째로 넣고 빼기 (참조 없음)       한 스레드
+//   RefCell       빌림 규칙을 실행 중에 검사               한 스레드
+//   OnceCell      한 번만 쓰고 이후 &T                      한 스레드
+//   LazyCell      OnceCell + 초기화 함수                    한 스레드
+//   OnceLock      OnceCell 의 스레드 안전 판 (static 가능)
+//   LazyLock      LazyCell 의 스레드 안전 판 (static 가능)
+//   Mutex/RwLock  RefCell 의 스레드 안전 판 - 빌림 대신 잠금
+//   Atomic*       Cell 의 스레드 안전 판 - 정수와 bool, 포인터
+// static mut 전역(16장)은 대부분 위의 static OnceLock / LazyLock / Mutex / Atomic 으로 바꿀 수 있음
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 의 mutable 멤버는 const 메서드에서 아무 검사 없이 수정 - Rust 는 바꿀 수 있는 타입(Cell 등)으로 감싸야 함
+// 2. Cell, RefCell 은 Sync 가 아님 - 스레드 사이에 공유하려 하면 컴파일 에러 (C++ 의 mutable 은 데이터 레이스를 막지 않음)
+// 3. 함수 안의 static 지역 변수 (magic static) 는 LazyLock / OnceLock - 초기화가 스레드 안전한 것도 같음
+// 4. 전역 초기화 순서 문제(static initialization order fiasco)가 없음 - static 은 const 로만 초기화, 나머지는 처음 쓸 때
+// 5. std::call_once + std::once_flag 는 Once, 값을 함께 가지는 것은 OnceLock
+// ============================================================================
+
+use std::cell::{Cell, LazyCell, OnceCell, RefCell};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, RwLock};
+use std::thread;
+
+// 절 목록 (실행 순서) - cargo run -- 52:cell 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("cell", cell),
+    ("once_cell", once_cell),
+    ("global_state", global_state),
+    ("sync_counterparts", sync_counterparts),
+    ("decision_table", decision_table),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "52"
+    }
+
+    fn name(&self) -> &'static str {
+        "내부 가변성 총정리"
+    }
+
+    fn description(&self) -> &'static str {
+        "Cell, RefCell, OnceCell, LazyCell, OnceLock, LazyLock, Mutex/RwLock, Atomic 비교 - 고르는 표와 static mut 대신 쓰는 지연 초기화 전역"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Cell", "RefCell", "OnceCell", "LazyCell", "OnceLock", "LazyLock", "Mutex", "RwLock", "내부 가변성", "static mut"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Cell - 참조 없이 값을 넣고 빼기
+// ----------------------------------------------------------------------------
+// get (T: Copy), set, replace, take (T: Default), into_inner - 안의 값에 대한 참조를 절대 주지 않음
+// 그래서 빌림 검사가 필요 없고 비용도 없음 (크기는 T 그대로) - 대신 큰 값은 통째로 복사
+// &self 메서드 안의 카운터, 플래그, 캐시한 작은 값에 알맞음
+
+pub struct Parser {
+    input: String,
+    // &self 로 파싱하면서 몇 번 불렸는지 세기 - C++ 의 mutable size_t calls
+    calls: Cell<usize>,
+}
+
+impl Parser {
+    pub fn new(input: &str) -> Parser {
+        Parser { input: input.to_string(), calls: Cell::new(0) }
+    }
+
+    pub fn words(&self) -> Vec<&str> {
+        self.calls.set(self.calls.get() + 1);
+        self.input.split_whitespace().collect()
+    }
+
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+}
+
+fn cell() {
+    println!("--- Cell ---");
+
+    // C++ 에서는:
+    // class Parser { mutable size_t calls = 0; public: auto words() const { ++calls; ... } };
+
+    let parser = Parser::new("a quick brown fox");
+    let shared = &parser;
+    shared.words();
+    shared.words();
+    println!("&Parser 로 두 번 호출: calls = {}", parser.calls());
+
+    let flag = Cell::new(false);
+    let old = flag.replace(true);
+    println!("replace: 이전 {}, 지금 {}", old, flag.get());
+
+    // Copy 가 아니어도 take / replace 로 통째로 교환
+    let name = Cell::new(String::from("first"));
+    let taken = name.take();
+    name.set(String::from("second"));
+    println!("take 로 꺼냄 {:?}, 남은 값 {:?}", taken, name.into_inner());
+
+    // 슬라이스의 각 칸을 Cell 로 - 같은 배열의 두 칸을 &로 동시에 바꾸기
+    let mut numbers = [1, 2, 3];
+    let cells = Cell::from_mut(&mut numbers[..]).as_slice_of_cells();
+    cells[0].swap(&cells[2]);
+    println!("as_slice_of_cells 로 swap: {:?}", numbers);
+
+    println!("size_of: Cell<u64> {}, RefCell<u64> {} (빌림 카운터만큼 큼)", size_of::<Cell<u64>>(), size_of::<RefCell<u64>>());
+}
+
+// ----------------------------------------------------------------------------
+// OnceCell 과 LazyCell - 한 번만 쓰기
+// ----------------------------------------------------------------------------
+// OnceCell<T>: 비어 있다가 한 번 채우면 그 뒤로는 &T 만 - 채운 뒤에는 빌림 검사가 필요 없음 (RefCell 보다 단순)
+//   get_or_init(f) - 처음이면 f 로 채우고 &T, 아니면 있는 값
+//   set(v) - 이미 있으면 Err(v)
+// LazyCell<T, F>: 초기화 함수를 만들 때 함께 받아 두고 처음 역참조할 때 실행
+// 구조체 필드로 계산이 비싼 값을 필요할 때 한 번만 (메모이제이션)
+
+pub struct Document {
+    text: String,
+    // 처음 물어볼 때 계산 - 이후에는 저장된 값
+    word_count: OnceCell<usize>,
+    computed: Cell<usize>,
+}
+
+impl Document {
+    pub fn new(text: &str) -> Document {
+        Document { text: text.to_string(), word_count: OnceCell::new(), computed: Cell::new(0) }
+    }
+
+    pub fn word_count(&self) -> usize {
+        *self.word_count.get_or_init(|| {
+            self.computed.set(self.computed.get() + 1);
+            self.text.split_whitespace().count()
+        })
+    }
+
+    // 계산한 횟수 - 몇 번을 물어도 1
+    pub fn times_computed(&self) -> usize {
+        self.computed.get()
+    }
+}
+
+fn once_cell() {
+    println!("--- OnceCell 과 LazyCell ---");
+
+    // C++ 에서는:
+    // mutable std::optional<size_t> word_count_;
+    // size_t word_count() const { if (!word_count_) word_count_ = count(); return *word_count_; }
+
+    let doc = Document::new("to be or not to be");
+    println!("word_count {} {} {}, 계산 횟수 {}", doc.word_count(), doc.word_count(), doc.word_count(), doc.times_computed());
+
+    let cell: OnceCell<&str> = OnceCell::new();
+    println!("비어 있을 때 get: {:?}", cell.get());
+    println!("set 첫 번째: {:?}, 두 번째: {:?}", cell.set("first"), cell.set("second"));
+    println!("남은 값: {:?}", cell.get());
+
+    // LazyCell - 만들 때 함수를 받고, 처음 쓸 때 실행 (지역 변수용, 스레드 사이에 못 넘김)
+    let runs = Cell::new(0);
+    let table = LazyCell::new(|| {
+        runs.set(runs.get() + 1);
+        (1..=5).map(|n| n * n).collect::<Vec<u32>>()
+    });
+    println!("만든 직후 실행 횟수: {}", runs.get());
+    println!("처음 사용: {:?}, 다시 사용: {}, 실행 횟수: {}", *table, table[4], runs.get());
+}
+
+// ----------------------------------------------------------------------------
+// 전역 상태 - static mut 대신
+// ----------------------------------------------------------------------------
+// static 의 초기값은 const 여야 함 - HashMap, String 처럼 실행 중에 만드는 값은 바로 못 넣음
+// 그래서 C 처럼 static mut 에 None 을 두고 init() 에서 채우는 코드가 나옴 - 모든 접근이 unsafe, 스레드 안전도 직접
+// 대신:
+//   static X: OnceLock<T>            프로그램이 정한 값으로 한 번 (명령행 인자, 설정 파일) - 이 저장소의 profile::ACTIVE
+//   static X: LazyLock<T>            고정된 계산으로 처음 쓸 때 - 표, 정규식, 키워드 집합 (i18n::table 은 OnceLock 으로 같은 일)
+//   static X: LazyLock<Mutex<T>>     바뀌는 전역 (레지스트리, 캐시)
+//   static X: AtomicUsize             카운터, 플래그 (16장의 COUNTER)
+//   thread_local! { Cell / RefCell }  스레드마다 따로 (src/panic_hook.rs 의 panic 기록, 48장)
+
+// 예전 방식 (16장 주석의 static mut):
+//   static mut CONFIG: Option<Config> = None;
+//   unsafe fn init(c: Config) { CONFIG = Some(c); }    // 두 스레드가 동시에 부르면 데이터 레이스
+//   unsafe fn config() -> &'static Config { CONFIG.as_ref().unwrap() }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub verbose: bool,
+    pub jobs: usize,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// 한 번만 - 두 번째부터는 Err 로 거절 (누가 먼저 정했는지 모르게 덮어쓰지 않음)
+pub fn init_config(config: Config) -> Result<(), Config> {
+    CONFIG.set(config)
+}
+
+// 아무도 정하지 않았으면 기본값으로 채움
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(|| Config { verbose: false, jobs: 1 })
+}
+
+// 처음 쓸 때 한 번 만드는 읽기 전용 표
+static KEYWORDS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    HashMap::from([("fn", "item"), ("let", "statement"), ("impl", "item"), ("trait", "item")])
+});
+
+// 바뀌는 전역 - 이름 → 번호, 같은 이름은 같은 번호
+static REGISTRY: LazyLock<Mutex<HashMap<String, usize>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+pub fn register(name: &str) -> usize {
+    let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    *registry.entry(name.to_string()).or_insert_with(|| NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+fn global_state() {
+    println!("--- 전역 상태 - static mut 대신 ---");
+
+    // C++ 에서는:
+    // const Config& config() { static Config c = load(); return c; }   // magic static - C++11 부터 스레드 안전
+    // 전역 객체 생성자의 순서는 번역 단위 사이에서 정해지지 않음 - Rust 의 static 은 const 초기화라 이 문제가 없음
+
+    // 테스트가 같은 프로세스에서 여러 번 불러도 되게 - 이미 정해졌으면 그 값을 씀
+    match init_config(Config { verbose: true, jobs: 4 }) {
+        Ok(()) => println!("init_config: 처음 설정함"),
+        Err(rejected) => println!("init_config: 이미 설정됨 - 거절된 값 {:?}", rejected),
+    }
+    println!("config(): {:?}", config());
+    println!("두 번째 init_config 는 Err: {}", init_config(Config { verbose: false, jobs: 8 }).is_err());
+
+    println!("KEYWORDS[\"impl\"] = {} (표를 처음 쓸 때 만듦)", KEYWORDS["impl"]);
+
+    // 여러 스레드에서 동시에 등록 - 같은 이름은 같은 번호
+    let ids: Vec<usize> = thread::scope(|s| {
+        let handles: Vec<_> = ["alpha", "beta", "alpha"].into_iter().map(|name| s.spawn(move || register(name))).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    println!("alpha, beta, alpha 의 번호가 같은 이름끼리 같음: {}", ids[0] == ids[2] && ids[0] != ids[1]);
+
+    // 스레드마다 따로인 상태 - static 이지만 스레드마다 한 벌 (Sync 가 필요 없어 Cell 로 충분)
+    thread_local! {
+        static DEPTH: Cell<u32> = const { Cell::new(0) };
+    }
+    DEPTH.set(3);
+    let other = thread::spawn(|| DEPTH.get()).join().unwrap();
+    println!("thread_local: 이 스레드 {}, 새 스레드 {}", DEPTH.get(), other);
+}
+
+// ----------------------------------------------------------------------------
+// 스레드 안전한 짝
+// ----------------------------------------------------------------------------
+// Cell → Atomic*       정수, bool, 포인터만 - 잠금 없이 load/store/fetch_add (Ordering 은 13장)
+// RefCell → Mutex      borrow_mut 대신 lock - 겹치면 panic 대신 기다림, panic 하면 오염 (48장)
+// RefCell → RwLock     읽기 여럿 또는 쓰기 하나 - borrow / borrow_mut 과 같은 규칙, 역시 기다림
+// OnceCell → OnceLock, LazyCell → LazyLock - 동시에 초기화하려 하면 하나만 실행하고 나머지는 기다림
+// Rc<RefCell<T>> 를 스레드로 넘기려면 Arc<Mutex<T>> (또는 Arc<RwLock<T>>)
+
+fn sync_counterparts() {
+    println!("--- 스레드 안전한 짝 ---");
+
+    // C++ 에서는:
+    // std::atomic<size_t>, std::mutex + 데이터, std::shared_mutex - 다만 뮤텍스와 데이터가 따로라 잠그지 않고 만질 수 있음
+    // Rust 의 Mutex<T> 는 데이터를 안에 가짐 - lock() 없이는 꺼낼 방법이 없음
+
+    // let shared = Rc::new(RefCell::new(0));
+    // thread::spawn(move || *shared.borrow_mut() += 1);   에러: Rc<RefCell<i32>> 는 Send 가 아님
+    let total = Arc::new(Mutex::new(0u64));
+    let hits = Arc::new(AtomicUsize::new(0));
+    let settings = Arc::new(RwLock::new(String::from("light")));
+
+    let handles: Vec<_> = (1..=4u64)
+        .map(|n| {
+            let (total, hits, settings) = (Arc::clone(&total), Arc::clone(&hits), Arc::clone(&settings));
+            thread::spawn(move || {
+                *total.lock().unwrap() += n;
+                hits.fetch_add(1, Ordering::Relaxed);
+                // 읽기는 여러 스레드가 동시에
+                settings.read().unwrap().len()
+            })
+        })
+        .collect();
+    let lengths: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    *settings.write().unwrap() = String::from("dark");
+    println!("Mutex 합 {}, Atomic 횟수 {}, RwLock 읽기 {:?} → 쓰기 후 {:?}", total.lock().unwrap(), hits.load(Ordering::Relaxed), lengths, settings.read().unwrap());
+
+    // 같은 스레드에서 겹쳐 빌리면: RefCell 은 panic, Mutex 는 교착(또는 panic) - try_* 로 확인
+    let cell = RefCell::new(1);
+    let _reading = cell.borrow();
+    println!("RefCell try_borrow_mut (읽는 중): {}", cell.try_borrow_mut().is_err());
+    let lock = Mutex::new(1);
+    let _held = lock.lock().unwrap();
+    println!("Mutex try_lock (잠근 중): {}", lock.try_lock().is_err());
+}
+
+// ----------------------------------------------------------------------------
+// 고르는 표
+// ----------------------------------------------------------------------------
+// 질문 순서대로:
+//   1. 여러 스레드가 공유하나?  아니면 Cell / RefCell / OnceCell / LazyCell, 그렇다면 Atomic / Mutex / RwLock / OnceLock / LazyLock
+//   2. 한 번만 쓰나?            그렇다면 Once* (값을 나중에 정함) 또는 Lazy* (만드는 법이 고정)
+//   3. 안의 값을 &로 빌려야 하나? 아니면 Cell / Atomic (Copy 이거나 통째로 교환), 그렇다면 RefCell / Mutex / RwLock
+//   4. static 에 둘 것인가?     Sync 가 필요 - OnceLock, LazyLock, Mutex, RwLock, Atomic (스레드마다면 thread_local!)
+
+fn decision_table() {
+    println!("--- 고르는 표 ---");
+
+    // C++ 에서는:
+    // mutable 하나로 모두 표현하고, 스레드 안전은 주석과 코드 리뷰에 맡김
+
+    println!("  타입          스레드  빌려 주는 것     실패하면");
+    println!("  Cell          하나    없음 (값 복사)   실패 없음");
+    println!("  RefCell       하나    Ref / RefMut     이중 빌림이면 panic (try_borrow 는 Err)");
+    println!("  OnceCell      하나    &T (채운 뒤)     두 번째 set 은 Err");
+    println!("  LazyCell      하나    &T               초기화가 panic 하면 이후 접근도 panic");
+    println!("  Atomic*       여럿    없음 (값 복사)   실패 없음 (compare_exchange 는 Err)");
+    println!("  Mutex         여럿    MutexGuard       기다림, 잡은 스레드가 panic 하면 오염");
+    println!("  RwLock        여럿    읽기/쓰기 가드   기다림, 오염은 Mutex 와 같음");
+    println!("  OnceLock      여럿    &T (채운 뒤)     두 번째 set 은 Err, 동시 초기화는 기다림");
+    println!("  LazyLock      여럿    &T               초기화가 panic 하면 이후 접근도 panic");
+
+    println!(
+        "Sync 인가? Cell {}, RefCell {}, OnceCell {}, Mutex {}, OnceLock {}",
+        is_sync::<Cell<u32>>(),
+        is_sync::<RefCell<u32>>(),
+        is_sync::<OnceCell<u32>>(),
+        is_sync::<Mutex<u32>>(),
+        is_sync::<OnceLock<u32>>()
+    );
+}
+
+// T 가 Sync 인지 - 실행 중에 물어볼 방법이 없어 표의 타입마다 상수로 적어 둠 (테스트에서 실제 Sync 와 대조)
+fn is_sync<T: ?Sized + MaybeSync>() -> bool {
+    T::SYNC
+}
+
+// Sync 를 값으로 - 표에 나오는 타입만
+pub trait MaybeSync {
+    const SYNC: bool;
+}
+
+impl<T> MaybeSync for Cell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for RefCell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T> MaybeSync for OnceCell<T> {
+    const SYNC: bool = false;
+}
+
+impl<T: Send> MaybeSync for Mutex<T> {
+    const SYNC: bool = true;
+}
+
+impl<T: Send + Sync> MaybeSync for OnceLock<T> {
+    const SYNC: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 컴파일되면 Sync - 위 표의 MaybeSync 값과 실제 트레이트가 맞는지
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn each_cell_keeps_its_promise() {
+        let doc = Document::new("one two three");
+        assert_eq!((doc.word_count(), doc.word_count()), (3, 3));
+        assert_eq!(doc.times_computed(), 1);
+
+        let parser = Parser::new("x y");
+        assert_eq!(parser.words(), ["x", "y"]);
+        assert_eq!(parser.calls(), 1);
+
+        // 이미 정해졌든 아니든 config() 는 같은 값을 계속 돌려줌
+        let first = config().clone();
+        assert!(init_config(Config { verbose: true, jobs: 99 }).is_err());
+        assert_eq!(*config(), first);
+
+        let a = register("test-only-name");
+        assert_eq!(register("test-only-name"), a);
+        assert_ne!(register("test-only-other"), a);
+
+        assert_sync::<Mutex<u32>>();
+        assert_sync::<OnceLock<u32>>();
+        assert_sync::<LazyLock<u32>>();
+        assert!(!is_sync::<Cell<u32>>() && is_sync::<Mutex<u32>>());
+    }
+}
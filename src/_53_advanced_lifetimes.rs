@@ -0,0 +1,395 @@
+// ============================================================================
+// 53. 고급 수명
+// ============================================================================
+// 04장은 함수 시그니처의 'a 와 생략 규칙, 구조체의 참조까지 - 이 장은 그 다음
+//   for<'a> (HRTB)     "어떤 수명이 오든" 을 바운드로 - 함수 안에서 만든 값의 참조를 클로저에 넘길 때
+//   변성(variance)      긴 수명을 짧은 수명 자리에 써도 되는가 - &'a T 는 되고 &mut T 의 T 는 안 됨
+//   T: 'static          "영원히 산다" 가 아니라 "빌린 것을 품지 않았다" - thread::spawn, tokio::spawn 의 조건
+//   생략 규칙의 가장자리  &self 가 있으면 출력은 self 에 묶임, impl Trait 와 Box<dyn Trait> 의 숨은 수명
+//
+// C++20과의 핵심 차이점:
+// 1. C++ 타입에는 수명이 없음 - std::function<const char*(const char*)> 는 돌려준 포인터가 얼마나 사는지 말하지 않음
+// 2. for<'a> 는 제네릭 람다([](auto& s))처럼 "모든 경우" 를 받지만, 호출마다 새로 인스턴스화하는 대신 바운드 하나로 검사
+// 3. Derived** → Base** 변환을 막는 이유와 &mut T 가 T 에 대해 불변인 이유가 같음 - 쓰기로 다른 타입(수명)을 끼워 넣을 수 있기 때문
+// 4. std::thread 에 지역 변수 참조를 캡처한 람다를 넘기면 컴파일되고 댕글링 - Rust 는 'static 바운드로 거절 (빌리려면 thread::scope)
+// 5. 반환된 참조가 어느 인자에 묶이는지는 C++ 에서 문서뿐 - Rust 는 생략 규칙이나 명시한 'a 로 시그니처에 드러남
+// ============================================================================
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::thread;
+
+// 절 목록 (실행 순서) - cargo run -- 53:hrtb 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("hrtb", hrtb),
+    ("variance", variance),
+    ("invariant_mut", invariant_mut),
+    ("static_bounds", static_bounds),
+    ("elision_edge_cases", elision_edge_cases),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "53"
+    }
+
+    fn name(&self) -> &'static str {
+        "고급 수명"
+    }
+
+    fn description(&self) -> &'static str {
+        "for<'a> 고차 트레이트 바운드, 공변/불변/반공변과 &mut T 가 불변인 이유, spawn 의 'static 바운드, 생략 규칙의 가장자리 - 04장의 다음 단계"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["HRTB", "for<'a>", "변성", "공변", "불변", "반공변", "'static", "T: 'static", "수명 생략", "impl Trait + '_"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// for<'a> - 고차 트레이트 바운드 (HRTB)
+// ----------------------------------------------------------------------------
+// 함수의 수명 매개변수 'x 는 호출하는 쪽이 고름 - 함수 안에서 만든 지역 값은 'x 만큼 살 수 없음
+// F: for<'a> Fn(&'a str) -> &'a str = "어떤 'a 로 불러도 같은 'a 를 돌려주는" 함수 - 고르는 쪽이 호출자에서 함수 안으로 옮겨 옴
+// Fn(&str) -> &str 라고 쓰면 생략 규칙이 for<'a> 를 붙여 줌 - 직접 쓰는 것은 참조가 아닌 타입에 수명이 걸릴 때
+
+// 안 되는 버전:
+//   fn apply_to_local<'x, F: Fn(&'x str) -> &'x str>(f: F) -> usize {
+//       let line = String::from("  local  ");
+//       f(&line).len()      // 에러: line 은 'x 만큼 살지 않음 ('x 는 호출자가 정한 더 긴 수명일 수 있음)
+//   }
+pub fn apply_to_local<F>(f: F) -> usize
+where
+    F: for<'a> Fn(&'a str) -> &'a str,
+{
+    let line = String::from("  local value  ");
+    f(&line).len()
+}
+
+// 참조가 아닌 타입에 수명이 걸리는 바운드 - &'a C 가 무엇이든 &'a i32 를 내주는 컬렉션
+pub fn sum_by_ref<C>(collection: &C) -> i32
+where
+    for<'a> &'a C: IntoIterator<Item = &'a i32>,
+{
+    collection.into_iter().sum()
+}
+
+// 클로저는 인자와 반환이 모두 참조일 때 서로 묶인 수명을 추론하지 못함 - 이렇게 바운드를 걸어 주는 함수로 감쌈
+pub fn str_map<F: for<'a> Fn(&'a str) -> &'a str>(f: F) -> F {
+    f
+}
+
+fn hrtb() {
+    println!("--- for<'a> - 고차 트레이트 바운드 ---");
+
+    // C++ 에서는:
+    // template <class F> size_t apply_to_local(F f) { std::string line = "..."; return f(line).size(); }
+    // f 가 line 을 가리키는 string_view 를 돌려줘도, 다른 것을 돌려줘도 시그니처는 같음
+
+    println!("trim 으로: {}", apply_to_local(str::trim));
+    println!("클로저로: {}", apply_to_local(|s| s.trim_start()));
+
+    let numbers = vec![1, 2, 3];
+    let fixed = [10, 20];
+    println!("Vec 합 {}, 배열 합 {}", sum_by_ref(&numbers), sum_by_ref(&fixed));
+
+    // let first_word = |s: &str| -> &str { s.split(' ').next().unwrap_or("") };
+    //   에러: 반환 수명이 인자와 다를 수 있음 (클로저는 생략 규칙이 시그니처처럼 적용되지 않음)
+    let first_word = str_map(|s| s.split(' ').next().unwrap_or(""));
+    println!("str_map 으로 감싼 클로저: {:?}", first_word("hello lifetimes"));
+
+    // dyn 에도 같은 바운드 - Box<dyn Fn(&str) -> &str> 는 Box<dyn for<'a> Fn(&'a str) -> &'a str> 와 같음
+    #[expect(clippy::type_complexity, reason = "생략된 수명이 있는 타입 자체를 보여 줌")]
+    let pipeline: Vec<Box<dyn Fn(&str) -> &str>> = vec![Box::new(str::trim), Box::new(|s| s.trim_end_matches('!'))];
+    let result = pipeline.iter().fold("  hi!!  ", |acc, f| f(acc));
+    println!("dyn for<'a> 파이프라인: {:?}", result);
+}
+
+// ----------------------------------------------------------------------------
+// 변성 - 긴 수명을 짧은 수명 자리에
+// ----------------------------------------------------------------------------
+// 'long: 'short 이면 &'long T 를 &'short T 자리에 써도 됨 - 이것이 공변(covariant)
+// 타입 생성자마다 매개변수별로 정해져 있음:
+//   &'a T             'a 공변, T 공변
+//   &'a mut T         'a 공변, T 불변(invariant) - 다음 절
+//   Box<T>, Vec<T>    T 공변 (소유하므로 바꿔 끼울 다른 별칭이 없음)
+//   Cell<T>, RefCell<T>, UnsafeCell<T>   T 불변 (&로도 쓸 수 있으므로 &mut 과 같은 이유)
+//   fn(T) -> U        T 반공변(contravariant), U 공변
+//   *const T 공변, *mut T 불변
+// 직접 만든 타입은 필드에서 정해짐 - raw 포인터만 가진 타입은 PhantomData 로 의도를 적음 (08장, 46장)
+//   PhantomData<T> 공변, PhantomData<fn(T)> 반공변, PhantomData<fn(T) -> T> 또는 PhantomData<Cell<T>> 불변
+
+// Vec<&'static str> 를 Vec<&'a str> 로 - 공변이므로 그냥 돌려주면 됨 (복사나 변환 없음)
+pub fn shorten<'a>(names: Vec<&'static str>) -> Vec<&'a str> {
+    names
+}
+
+// 어떤 수명의 &str 이든 받는 함수 - fn(&'static str) 자리에도 쓸 수 있음 (인자는 반공변)
+fn count_chars(s: &str) -> usize {
+    s.chars().count()
+}
+
+fn variance() {
+    println!("--- 변성 - 긴 수명을 짧은 수명 자리에 ---");
+
+    // C++ 에서는:
+    // Derived* 는 Base* 로 바뀌지만 std::vector<Derived*> 는 std::vector<Base*> 가 아님 - 템플릿은 무변성
+    // Rust 의 부분 타입 관계는 수명에만 있고, 컴파일러가 제네릭 타입의 변성을 필드에서 계산
+
+    let literal: &'static str = "static";
+    let owned = String::from("local");
+    // 'static 인 literal 이 owned 의 짧은 수명에 맞춰 줄어듦
+    let picked = if owned.len() > 3 { &owned[..] } else { literal };
+    println!("짧은 쪽에 맞춘 참조: {}", picked);
+
+    let mut names = shorten(vec!["alpha", "beta"]);
+    names.push(&owned);
+    println!("Vec<&'static str> 를 줄여서 지역 참조도 담음: {:?}", names);
+
+    // 인자의 반공변 - "아무 수명이나 받는 함수" 는 "'static 만 받는 함수" 자리에 들어감
+    let only_static: fn(&'static str) -> usize = count_chars;
+    println!("fn(&str) 을 fn(&'static str) 자리에: {}", only_static("chars"));
+    // 반대는 안 됨:
+    //   fn needs_static(s: &'static str) -> usize { s.len() }
+    //   let any: for<'a> fn(&'a str) -> usize = needs_static;   에러: 짧은 수명을 받을 수 없음
+}
+
+// ----------------------------------------------------------------------------
+// &mut T 가 T 에 대해 불변인 이유
+// ----------------------------------------------------------------------------
+// 만약 &mut &'static str 를 &mut &'short str 로 줄일 수 있다면:
+//   let mut kept: &'static str = "static";
+//   {
+//       let local = String::from("local");
+//       let slot: &mut &'short str = &mut kept;   // (가정) 공변이라 허용
+//       *slot = &local;                           // 'short 자리에 'short 참조를 씀 - 타입상 문제없음
+//   }                                             // local 해제
+//   println!("{}", kept);                         // kept 는 여전히 &'static str 타입인데 해제된 local 을 가리킴
+// 읽기만 하는 &T 는 줄여도 안전하지만, 쓸 수 있는 &mut T 는 안의 타입을 그대로 지켜야 함
+
+// 슬롯에 값을 써 넣기 - 슬롯과 값의 수명이 같아야 함 ('a 가 불변 위치에 있음)
+pub fn overwrite<'a>(slot: &mut &'a str, value: &'a str) {
+    *slot = value;
+}
+
+// 수명 하나로 모든 것을 묶은 버전 - out 이 'a 동안 빌려지고, 'a 는 불변이라 Vec 의 원소 수명과 같아져야 함
+//   struct GreedyCollector<'a> { out: &'a mut Vec<&'a str> }
+//   let mut words = Vec::new();
+//   { let mut c = GreedyCollector { out: &mut words }; c.add("x"); }
+//   println!("{:?}", words);    // 에러: words 는 원소 수명 전체 동안 가변으로 빌려져 있음
+// 빌림 수명과 원소 수명을 나누면 빌림은 Collector 와 함께 끝남
+pub struct Collector<'v, 's> {
+    out: &'v mut Vec<&'s str>,
+}
+
+impl<'v, 's> Collector<'v, 's> {
+    pub fn new(out: &'v mut Vec<&'s str>) -> Self {
+        Collector { out }
+    }
+
+    pub fn add_words(&mut self, text: &'s str) {
+        self.out.extend(text.split_whitespace());
+    }
+}
+
+fn invariant_mut() {
+    println!("--- &mut T 가 T 에 대해 불변인 이유 ---");
+
+    // C++ 에서는:
+    // Derived* d; Base** pp = &d;   // 에러 - *pp = new Base 로 d 에 Base 를 넣을 수 있으므로
+    // char** → const char** 도 같은 이유로 금지 - Rust 는 같은 규칙을 수명에 적용
+
+    let mut kept: &'static str = "static";
+    let local = String::from("local");
+    // overwrite(&mut kept, &local);   에러: kept 의 타입이 &'static str 라 'a = 'static, local 은 그만큼 살지 않음
+    overwrite(&mut kept, "another static");
+    println!("'static 슬롯에는 'static 만: {}", kept);
+
+    // 슬롯의 타입을 처음부터 짧게 두면 됨 - 추론이 'a 를 local 의 수명으로 고름
+    let mut slot: &str = "static";
+    overwrite(&mut slot, &local);
+    println!("짧은 수명의 슬롯: {}", slot);
+
+    let text = String::from("borrow only what you need");
+    let mut words = Vec::new();
+    {
+        let mut collector = Collector::new(&mut words);
+        collector.add_words(&text);
+        collector.add_words("and more");
+    }
+    // collector 가 끝나면 words 의 가변 빌림도 끝남
+    println!("Collector<'v, 's> 뒤에 다시 쓸 수 있는 Vec: {} 개 {:?}", words.len(), words);
+}
+
+// ----------------------------------------------------------------------------
+// T: 'static - spawn 의 조건
+// ----------------------------------------------------------------------------
+// T: 'static 은 "T 안에 'static 보다 짧은 빌림이 없다" - String, Vec<u8>, Arc<T> 는 모두 'static (소유한 값)
+// thread::spawn(f) 는 F: Send + 'static - 새 스레드가 언제 끝날지 모르므로 지역 변수를 빌린 클로저는 거절
+// tokio::spawn 도 같은 이유로 'static - 작업이 만든 함수보다 오래 살 수 있음
+// 빌리고 싶으면: move 로 소유권을 넘기거나, Arc 로 나누거나, thread::scope 로 끝나는 시점을 묶음 (13장)
+// Box<dyn Trait> 는 Box<dyn Trait + 'static> 의 줄임 - 빌린 것을 품게 하려면 + 'a 를 씀
+
+pub fn is_static<T: 'static>(_value: &T) -> bool {
+    true
+}
+
+// 빌린 prefix 를 품은 클로저 - + 'a 가 없으면 Box<dyn Fn + 'static> 이라 prefix 를 캡처할 수 없음
+#[expect(clippy::type_complexity, reason = "반환 타입의 + 'a 를 별칭 뒤에 숨기지 않음")]
+pub fn make_labeler<'a>(prefix: &'a str) -> Box<dyn Fn(&dyn Display) -> String + 'a> {
+    Box::new(move |value| format!("{}{}", prefix, value))
+}
+
+fn static_bounds() {
+    println!("--- T: 'static - spawn 의 조건 ---");
+
+    // C++ 에서는:
+    // std::string name = "local";
+    // std::thread t([&name] { use(name); });  t.detach();   // 함수가 끝나면 name 이 사라지고 댕글링
+    // Rust 는 &name 을 캡처한 클로저를 thread::spawn 에 넘기는 것부터 컴파일 에러
+
+    let owned = String::from("owned");
+    println!("String 은 'static: {}, &'static str 도: {}", is_static(&owned), is_static(&"literal"));
+    // is_static(&&owned[..]);   에러: &owned[..] 는 owned 를 빌리므로 'static 이 아님
+
+    let name = String::from("worker");
+    // thread::spawn(|| name.len());   에러: 클로저가 name 을 빌리는데 name 이 스레드보다 먼저 끝날 수 있음
+    let moved = thread::spawn(move || name.len()).join().unwrap();
+    println!("move 로 넘긴 String: {}", moved);
+
+    let shared = Arc::new(vec![1, 2, 3]);
+    let handle = {
+        let shared = Arc::clone(&shared);
+        thread::spawn(move || shared.iter().sum::<i32>())
+    };
+    let sum = handle.join().unwrap();
+    println!("Arc 로 나눔: 합 {}, 스레드가 끝난 뒤 소유자 수 {}", sum, Arc::strong_count(&shared));
+
+    let local = vec![4, 5, 6];
+    let total = thread::scope(|s| s.spawn(|| local.iter().sum::<i32>()).join().unwrap());
+    println!("thread::scope 는 빌림 허용: 합 {}, 지역 Vec 은 그대로 {:?}", total, local);
+
+    // tokio::spawn 도 'static - 작업 안에서 쓸 것은 소유해서 넘김
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let words = [String::from("async"), String::from("task")];
+    let joined = runtime.block_on(async move {
+        // tokio::spawn(async { words.len() })   에러: async 블록이 words 를 빌림
+        tokio::spawn(async move { words.join("-") }).await.unwrap()
+    });
+    println!("tokio::spawn 에 move 한 값: {}", joined);
+
+    let prefix = String::from("value=");
+    let label = make_labeler(&prefix);
+    println!("Box<dyn Fn + 'a> 로 빌린 prefix 캡처 → {}", label(&42));
+}
+
+// ----------------------------------------------------------------------------
+// 생략 규칙의 가장자리
+// ----------------------------------------------------------------------------
+// 규칙 (04장): 입력 참조마다 수명 하나, 입력이 하나면 출력이 그것, &self / &mut self 가 있으면 출력은 self 의 것
+// 세 번째 규칙 때문에 다른 인자를 돌려주는 메서드는 명시해야 함
+// impl Trait 반환 (edition 2021): 타입 매개변수는 캡처하지만 수명은 명시한 것만 - + '_ 를 붙임
+//   (edition 2024 부터는 모든 수명을 자동으로 캡처하고, 빼려면 use<..> 로 적음)
+// 경로 속 수명: Parser<'_> 처럼 '_ 로 "여기에 수명이 있다" 를 보여 주는 것이 관례 (elided_lifetimes_in_paths 린트)
+// &'a dyn Trait 는 &'a (dyn Trait + 'a), Box<dyn Trait> 는 Box<dyn Trait + 'static>
+
+pub struct Settings {
+    values: HashMap<String, String>,
+}
+
+impl Settings {
+    pub fn new(pairs: &[(&str, &str)]) -> Settings {
+        Settings { values: pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect() }
+    }
+
+    // 생략하면 fn get_or(&self, key: &str, fallback: &str) -> &str 의 출력은 self 에 묶임
+    //   → fallback 을 돌려주는 줄에서 에러
+    // 두 쪽 모두 돌려줄 수 있으므로 같은 'a 로 묶음
+    pub fn get_or<'a>(&'a self, key: &str, fallback: &'a str) -> &'a str {
+        self.values.get(key).map(String::as_str).unwrap_or(fallback)
+    }
+
+    // edition 2021 에서는 적지 않은 수명을 캡처하지 못함 - self 와 prefix 를 둘 다 빌리므로 use<'_, 'p>
+    // 하나만 빌린다면 impl Iterator<Item = &str> + '_ 로 충분
+    pub fn keys_with<'p>(&self, prefix: &'p str) -> impl Iterator<Item = &str> + use<'_, 'p> {
+        self.values.keys().filter(move |k| k.starts_with(prefix)).map(String::as_str)
+    }
+}
+
+// 입력에서 빌린 토큰 - 경로에 '_ 를 적어 "입력을 빌린다" 를 시그니처에서 보이게
+pub struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let trimmed = self.rest.trim_start();
+        if trimmed.is_empty() {
+            return None;
+        }
+        let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let (token, rest) = trimmed.split_at(end);
+        self.rest = rest;
+        Some(token)
+    }
+}
+
+pub fn tokens(input: &str) -> Tokens<'_> {
+    Tokens { rest: input }
+}
+
+fn elision_edge_cases() {
+    println!("--- 생략 규칙의 가장자리 ---");
+
+    // C++ 에서는:
+    // const std::string& get_or(const std::string& key, const std::string& fallback) const;
+    // get_or("x", "임시") 가 임시 객체를 가리키는 참조를 돌려줘도 컴파일됨 - Rust 는 'a 로 fallback 의 수명을 따짐
+
+    let settings = Settings::new(&[("color", "always"), ("color.theme", "dark"), ("jobs", "4")]);
+    let fallback = String::from("auto");
+    println!("get_or(color) = {}, get_or(pager) = {}", settings.get_or("color", &fallback), settings.get_or("pager", &fallback));
+
+    let mut colors: Vec<&str> = settings.keys_with("color").collect();
+    colors.sort();
+    println!("impl Iterator + use<'_, 'p> 로 빌린 키: {:?}", colors);
+
+    let input = String::from("let x = 1 ;");
+    let collected: Vec<&str> = tokens(&input).collect();
+    println!("Tokens<'_> 가 빌린 토큰: {:?}", collected);
+
+    // &dyn Display 의 숨은 수명은 참조의 수명 - 지역 값도 됨
+    let local = 7;
+    let shown: &dyn Display = &local;
+    println!("&dyn Display (숨은 + 'a): {}", shown);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lifetimes_connect_the_right_values() {
+        assert_eq!(apply_to_local(str::trim), "local value".len());
+        assert_eq!(sum_by_ref(&vec![1, 2, 3]), 6);
+
+        let text = String::from("a b");
+        let mut words = Vec::new();
+        Collector::new(&mut words).add_words(&text);
+        assert_eq!(words, ["a", "b"]);
+
+        let settings = Settings::new(&[("k", "v")]);
+        assert_eq!(settings.get_or("k", "x"), "v");
+        assert_eq!(settings.get_or("missing", "x"), "x");
+        assert_eq!(tokens("  one  two ").collect::<Vec<_>>(), ["one", "two"]);
+    }
+}
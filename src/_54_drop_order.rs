@@ -0,0 +1,481 @@
+// ============================================================================
+// 54. drop 순서와 ManuallyDrop
+// ============================================================================
+// 언제, 어떤 순서로 drop 되는가 - 12장의 Drop 기초와 48장의 되감기 사이를 채움
+//   지역 변수는 선언의 반대 순서, 임시 값은 문장 끝 (match 와 if let 의 대상은 문장 전체)
+//   구조체 필드는 선언 순서 - 자신의 Drop 이 먼저, 그다음 필드
+//   ManuallyDrop 으로 직접 정하고, mem::forget 으로 아예 건너뜀 - 둘 다 안전한 코드 (누수는 UB 가 아님)
+//   Drop 이 panic 해도 나머지 필드와 원소는 drop 됨
+//   스코프 가드 - 클로저를 품은 값의 Drop 으로 defer 를 흉내 (이 저장소의 allocations::Pause, timelog::Tracker)
+//
+// C++20과의 핵심 차이점:
+// 1. 멤버 파괴 순서가 반대 - C++ 는 선언의 역순, Rust 는 선언 순서 (지역 변수는 둘 다 역순)
+// 2. 이동은 파괴적 - 옮겨진 값은 drop 되지 않음 (C++ 의 moved-from 객체는 소멸자가 또 불림)
+// 3. mem::forget 이 안전한 함수 - 소멸자가 반드시 불린다고 가정한 unsafe 코드는 틀림
+// 4. C++ 소멸자는 기본 noexcept 라 던지면 std::terminate - Rust 의 Drop panic 은 되감으며 나머지를 정리 (이중 panic 만 abort)
+// 5. 언어에 defer 가 없음 - std::experimental::scope_exit, gsl::finally 처럼 Drop 을 가진 가드 값으로
+// ============================================================================
+
+use crate::panic_hook::silently;
+use std::cell::RefCell;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::panic::AssertUnwindSafe;
+use std::rc::{Rc, Weak};
+
+// 절 목록 (실행 순서) - cargo run -- 54:local_order 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("local_order", local_order),
+    ("field_order", field_order),
+    ("manually_drop", manually_drop),
+    ("forget_and_leak", forget_and_leak),
+    ("drop_and_panic", drop_and_panic),
+    ("scope_guard", scope_guard),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "54"
+    }
+
+    fn name(&self) -> &'static str {
+        "drop 순서와 ManuallyDrop"
+    }
+
+    fn description(&self) -> &'static str {
+        "지역 변수와 임시 값, 구조체 필드의 drop 순서, ManuallyDrop, mem::forget 과 안전한 누수, Drop 과 panic, 스코프 가드(defer) - C++ 소멸자 순서와 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Drop", "drop 순서", "임시 값", "ManuallyDrop", "mem::forget", "Box::leak", "스코프 가드", "defer", "RAII"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// drop 된 이름을 차례로 모으는 기록
+pub type Log = RefCell<Vec<&'static str>>;
+
+// drop 될 때 이름을 남기는 값 - 순서를 눈으로 보기 위해
+pub struct Tracer<'a> {
+    name: &'static str,
+    log: &'a Log,
+}
+
+impl<'a> Tracer<'a> {
+    pub fn new(name: &'static str, log: &'a Log) -> Tracer<'a> {
+        Tracer { name, log }
+    }
+}
+
+impl Drop for Tracer<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 지역 변수와 임시 값
+// ----------------------------------------------------------------------------
+// 지역 변수: 스코프 끝에서 선언의 반대 순서 - 뒤에 만든 것이 앞의 것을 빌릴 수 있으므로
+// 가린(shadowing) 변수도 이름만 가려질 뿐 스코프 끝까지 살아 있음
+// let _ = 식  → 식이 임시 값이면 바로 drop (이름이 없으므로), 이미 있는 변수면 아무 일도 없음 (옮기지도 않음)
+// let _x = 식 → 스코프 끝까지 - 가드에 _ 를 쓰면 바로 풀려 버리는 흔한 실수
+// 임시 값: 그 문장(;)이 끝날 때 - 단 let x = &임시 는 x 의 스코프까지 늘어남
+// match 와 if let 의 대상 식에서 나온 임시 값은 match 전체가 끝날 때까지 - mutex.lock() 을 대상에 쓰면 팔 안에서도 잠겨 있음
+// 블록의 꼬리 식(; 없는 마지막 식)의 임시 값은 블록의 지역 변수보다도 늦게 drop - 아래 match 를 맨 끝에 두면 scrutinee 가 a 뒤로 감
+// (edition 2024 부터 if let 의 임시 값은 else 전에, 꼬리 식의 임시 값은 지역 변수보다 먼저 drop - 이 저장소는 2021)
+
+fn consume(_value: Tracer<'_>) {
+    // 여기서 끝 - 소유권을 받은 쪽이 drop
+}
+
+// 아래 블록에서 drop 된 순서
+pub fn local_drop_order() -> Vec<&'static str> {
+    let log = Log::default();
+    {
+        let _a = Tracer::new("a", &log);
+        let _b = Tracer::new("b", &log);
+        let _extended = &Tracer::new("extended", &log);
+        let _ = Tracer::new("underscore", &log);
+        let _shadow = Tracer::new("shadow-1", &log);
+        let _shadow = Tracer::new("shadow-2", &log);
+        match Tracer::new("scrutinee", &log).name.len() {
+            0 => {}
+            _ => log.borrow_mut().push("match-arm"),
+        }
+        consume(Tracer::new("moved", &log));
+        let _len = Tracer::new("temporary", &log).name.len();
+    }
+    log.into_inner()
+}
+
+fn local_order() {
+    println!("--- 지역 변수와 임시 값 ---");
+
+    // C++ 에서는:
+    // 지역 변수는 같은 역순, 임시 객체는 완전식(full-expression) 끝에서 파괴
+    // const T& r = T{}; 처럼 const 참조에 묶으면 수명 연장 - Rust 의 let r = &T::new() 와 같음
+    // auto lock = std::lock_guard(m); 대신 std::lock_guard(m); 라고 쓰면 바로 풀림 - Rust 의 let _ = 와 같은 함정
+
+    println!("drop 순서: {:?}", local_drop_order());
+    println!("  underscore, moved, temporary 는 그 문장에서 / scrutinee 는 match-arm 뒤 / 나머지는 스코프 끝에서 역순");
+}
+
+// ----------------------------------------------------------------------------
+// 구조체 필드와 컬렉션
+// ----------------------------------------------------------------------------
+// 구조체의 drop: ① 자신의 Drop::drop (필드가 모두 살아 있음) ② 필드를 선언 순서대로
+// 튜플, 배열, Vec, enum 의 필드도 앞에서부터
+// 순서가 중요하면 필드를 그 순서로 선언하거나, Drop 에서 직접 정리 (34장의 ThreadPool 은 Drop 에서 큐를 닫고 join)
+// Drop 을 구현한 타입에서는 필드를 밖으로 옮길 수 없음 (E0509) - Option::take 나 mem::replace 로 꺼냄
+
+// 필드는 drop 순서를 보이려고만 있음 - 읽지 않으므로 _ 로 시작
+pub struct Server<'a> {
+    _listener: Tracer<'a>,
+    _workers: Tracer<'a>,
+    _config: Tracer<'a>,
+    log: &'a Log,
+}
+
+impl Drop for Server<'_> {
+    fn drop(&mut self) {
+        // 이 시점에는 세 필드가 모두 살아 있음
+        self.log.borrow_mut().push("Server::drop");
+    }
+}
+
+// Server, 튜플, Vec 을 차례로 버린 순서
+pub fn field_drop_order() -> Vec<&'static str> {
+    let log = Log::default();
+    drop(Server {
+        _listener: Tracer::new("listener", &log),
+        _workers: Tracer::new("workers", &log),
+        _config: Tracer::new("config", &log),
+        log: &log,
+    });
+    drop((Tracer::new("tuple.0", &log), Tracer::new("tuple.1", &log)));
+    drop(vec![Tracer::new("vec[0]", &log), Tracer::new("vec[1]", &log)]);
+    log.into_inner()
+}
+
+fn field_order() {
+    println!("--- 구조체 필드와 컬렉션 ---");
+
+    // C++ 에서는:
+    // struct Server { Listener listener; Workers workers; Config config; };
+    // ~Server() 본문 → config → workers → listener (선언의 역순)
+    // std::vector 원소의 파괴 순서는 표준이 정하지 않음 (구현은 대개 앞에서부터)
+
+    println!("drop 순서: {:?}", field_drop_order());
+    println!("  C++ 였다면 listener 와 config 의 순서가 반대");
+}
+
+// ----------------------------------------------------------------------------
+// ManuallyDrop - drop 을 직접
+// ----------------------------------------------------------------------------
+// ManuallyDrop<T> 는 T 를 감싸 자동 drop 을 끔 - 크기와 배치는 T 그대로 (#[repr(transparent)])
+//   ManuallyDrop::into_inner(md)         다시 보통 값으로 (이후 평소처럼 drop)
+//   unsafe ManuallyDrop::drop(&mut md)   제자리에서 drop - 두 번 부르거나 이후에 쓰면 UB
+//   unsafe ManuallyDrop::take(&mut md)   값을 꺼냄 - 이후 md 를 쓰면 UB
+// 쓰는 곳: 선언 순서와 다른 drop 순서, union 필드 (47장), Vec 을 raw 부분으로 나눌 때 (16장의 into_raw_parts)
+// 순서만 바꾸려면 먼저 필드 순서를 바꾸거나 Option 과 take 를 생각 - ManuallyDrop 은 unsafe 가 따라옴
+
+// texture 는 context 로 만든 것이라 먼저 버려야 함 - 그런데 필드 순서는 읽기 좋게 context 를 앞에 둠
+pub struct Renderer<'a> {
+    context: ManuallyDrop<Tracer<'a>>,
+    texture: ManuallyDrop<Tracer<'a>>,
+}
+
+impl Drop for Renderer<'_> {
+    fn drop(&mut self) {
+        // SAFETY: 두 필드를 여기서 한 번씩만 drop 하고 이후에 쓰지 않음 (필드의 자동 drop 은 ManuallyDrop 이 막음)
+        unsafe {
+            ManuallyDrop::drop(&mut self.texture);
+            ManuallyDrop::drop(&mut self.context);
+        }
+    }
+}
+
+fn manually_drop() {
+    println!("--- ManuallyDrop - drop 을 직접 ---");
+
+    // C++ 에서는:
+    // union { T value; };  또는  alignas(T) std::byte buf[sizeof(T)];  + 직접 value.~T() 호출
+    // Rust 의 ManuallyDrop 은 값은 늘 초기화되어 있고 "자동 소멸만 끈" 상태 - 초기화까지 미루려면 MaybeUninit (46장)
+
+    let log = Log::default();
+    drop(Renderer {
+        context: ManuallyDrop::new(Tracer::new("context", &log)),
+        texture: ManuallyDrop::new(Tracer::new("texture", &log)),
+    });
+    println!("Renderer: {:?} (선언은 context 가 먼저)", log.borrow());
+
+    let log = Log::default();
+    {
+        let _kept = ManuallyDrop::new(Tracer::new("never", &log));
+        let released = ManuallyDrop::new(Tracer::new("released", &log));
+        let _back = ManuallyDrop::into_inner(released);
+    }
+    println!("스코프를 나간 뒤: {:?} (never 는 drop 되지 않음 - 누수)", log.borrow());
+    println!("size_of: Tracer {}, ManuallyDrop<Tracer> {}", size_of::<Tracer>(), size_of::<ManuallyDrop<Tracer>>());
+}
+
+// ----------------------------------------------------------------------------
+// mem::forget 과 안전한 누수
+// ----------------------------------------------------------------------------
+// mem::forget(x) = ManuallyDrop::new(x) 를 버림 - unsafe 가 아님
+// 안전한 코드로도 누수는 가능 (Rc 순환, Box::leak, 끝나지 않는 스레드) - 그래서 Rust 는 "누수는 안전" 으로 정함
+// 결과: Drop 이 반드시 불린다고 믿는 안전 API 는 만들 수 없음
+//   옛 thread::scoped 는 돌려준 가드의 Drop 에서 join - forget 하면 빌린 값이 사라진 뒤에도 스레드가 돌아 제거됨
+//   지금의 thread::scope 는 클로저가 끝날 때 join 하므로 forget 할 가드가 없음 (13장)
+//   Vec::drain 은 시작할 때 len 을 줄여 둠 - drain 을 forget 해도 Vec 이 drop 된 원소를 보지 않음 (대신 원소가 샘)
+// 일부러 쓰는 곳: 초기화를 마친 가드를 끌 때 (46장의 try_init_array), 소유권을 C 에 넘길 때 (into_raw 류가 더 나음)
+
+// 서로를 가리키는 두 노드 - strong 이면 순환이라 drop 되지 않음, 되돌아가는 쪽을 Weak 로
+pub struct Node<'a> {
+    _tracer: Tracer<'a>,
+    next: RefCell<Option<Rc<Node<'a>>>>,
+    back: RefCell<Weak<Node<'a>>>,
+}
+
+impl<'a> Node<'a> {
+    pub fn new(name: &'static str, log: &'a Log) -> Rc<Node<'a>> {
+        Rc::new(Node { _tracer: Tracer::new(name, log), next: RefCell::new(None), back: RefCell::new(Weak::new()) })
+    }
+}
+
+fn forget_and_leak() {
+    println!("--- mem::forget 과 안전한 누수 ---");
+
+    // C++ 에서는:
+    // new T 를 delete 하지 않거나 shared_ptr 순환 - 누수는 C++ 에서도 "정의된 동작"
+    // forget 에 해당하는 표준 함수는 없음 - new (buf) T(...) 로 만들고 소멸자를 부르지 않는 것이 가장 가까움
+
+    let log = Log::default();
+    mem::forget(Tracer::new("forgotten", &log));
+    println!("forget 뒤 기록: {:?}", log.borrow());
+
+    // 프로그램이 끝날 때까지 쓸 값을 &'static 으로 - 설정처럼 한 번 만들고 놓지 않는 것
+    let banner: &'static str = Box::leak(String::from("leaked banner").into_boxed_str());
+    println!("Box::leak 로 얻은 &'static str: {}", banner);
+
+    let log = Log::default();
+    {
+        let first = Node::new("cycle-a", &log);
+        let second = Node::new("cycle-b", &log);
+        *first.next.borrow_mut() = Some(Rc::clone(&second));
+        *second.next.borrow_mut() = Some(Rc::clone(&first));
+    }
+    println!("strong 순환: {:?} (아무것도 drop 되지 않음)", log.borrow());
+    {
+        let first = Node::new("parent", &log);
+        let second = Node::new("child", &log);
+        *first.next.borrow_mut() = Some(Rc::clone(&second));
+        *second.back.borrow_mut() = Rc::downgrade(&first);
+    }
+    println!("되돌아가는 쪽을 Weak 로: {:?}", log.borrow());
+
+    let mut numbers = vec![1, 2, 3, 4, 5];
+    mem::forget(numbers.drain(1..3));
+    println!("drain 을 forget 한 Vec: {:?} (앞부분만 남고 나머지는 샘)", numbers);
+}
+
+// ----------------------------------------------------------------------------
+// Drop 과 panic
+// ----------------------------------------------------------------------------
+// Drop::drop 이 panic 해도 그 값의 필드, 컬렉션의 나머지 원소는 되감기 중에 drop 됨
+// 값을 만드는 중간에 panic 하면 이미 만든 필드와 지역 변수만 역순으로 drop - 반쯤 만든 구조체의 Drop 은 불리지 않음
+// 되감기 중의 Drop 이 또 panic 하면 abort - Drop 안에서는 thread::panicking() 을 확인 (48장)
+
+// drop 될 때 기록하고, explode 면 panic
+pub struct Bomb<'a> {
+    name: &'static str,
+    explode: bool,
+    log: &'a Log,
+}
+
+impl Drop for Bomb<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+        if self.explode {
+            panic!("{} 의 Drop 에서 panic", self.name);
+        }
+    }
+}
+
+// 가운데 원소의 Drop 이 panic 하는 Vec 을 버렸을 때 - 뒤 원소도 drop 되는지
+pub fn vec_with_panicking_drop() -> (bool, Vec<&'static str>) {
+    let log = Log::default();
+    let (result, _) = silently(AssertUnwindSafe(|| {
+        let bombs = vec![
+            Bomb { name: "bomb-0", explode: false, log: &log },
+            Bomb { name: "bomb-1", explode: true, log: &log },
+            Bomb { name: "bomb-2", explode: false, log: &log },
+        ];
+        drop(bombs);
+    }));
+    (result.is_err(), log.into_inner())
+}
+
+fn drop_and_panic() {
+    println!("--- Drop 과 panic ---");
+
+    // C++ 에서는:
+    // ~Bomb() noexcept(false) { throw ...; } - vector 가 원소를 파괴하다 던지면 나머지 원소의 운명은 정의되지 않음
+    // 기본 noexcept 소멸자에서 던지면 바로 std::terminate
+
+    let (panicked, log) = vec_with_panicking_drop();
+    println!("Vec drop 중 panic: {}, drop 된 원소 {:?}", panicked, log);
+
+    let log = Log::default();
+    let (result, _) = silently(AssertUnwindSafe(|| Server {
+        _listener: Tracer::new("listener", &log),
+        _workers: Tracer::new("workers", &log),
+        _config: {
+            let _port: u16 = "http".parse().expect("포트 번호가 아님");
+            Tracer::new("config", &log)
+        },
+        log: &log,
+    }));
+    // Server 는 만들어지지 않았으므로 Server::drop 은 불리지 않고, 이미 만든 필드만 정리됨
+    println!("만드는 중 panic: {}, 정리된 것 {:?}", result.is_err(), log.borrow());
+}
+
+// ----------------------------------------------------------------------------
+// 스코프 가드 - defer
+// ----------------------------------------------------------------------------
+// 클로저를 품은 값을 만들어 두면 스코프를 어떻게 나가든 (return, ?, panic) Drop 에서 실행
+// 성공했을 때는 끄는 (commit, cancel) 방법을 함께 둠 - 46장의 try_init_array 는 mem::forget 으로 끔
+// 이름은 반드시 붙일 것 - let _ = defer(..) 는 바로 실행되고 끝남
+// crates.io 의 scopeguard 가 같은 일을 하는 크레이트 (defer! 매크로, guard_on_unwind)
+
+pub struct Defer<F: FnOnce()> {
+    action: Option<F>,
+}
+
+impl<F: FnOnce()> Defer<F> {
+    // 실행하지 않고 끔
+    pub fn cancel(mut self) {
+        self.action = None;
+    }
+}
+
+impl<F: FnOnce()> Drop for Defer<F> {
+    fn drop(&mut self) {
+        if let Some(action) = self.action.take() {
+            action();
+        }
+    }
+}
+
+pub fn defer<F: FnOnce()>(action: F) -> Defer<F> {
+    Defer { action: Some(action) }
+}
+
+// 끝까지 가면 commit, 중간에 빠져나가면 (에러든 panic 이든) 처음 길이로 되돌림
+pub struct Rollback<'a, T> {
+    vec: &'a mut Vec<T>,
+    len: usize,
+    committed: bool,
+}
+
+impl<'a, T> Rollback<'a, T> {
+    pub fn new(vec: &'a mut Vec<T>) -> Rollback<'a, T> {
+        let len = vec.len();
+        Rollback { vec, len, committed: false }
+    }
+
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<T> Deref for Rollback<'_, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> DerefMut for Rollback<'_, T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        self.vec
+    }
+}
+
+impl<T> Drop for Rollback<'_, T> {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.vec.truncate(self.len);
+        }
+    }
+}
+
+// 모두 성공해야 추가 - 하나라도 Err 면 이미 넣은 것도 없던 일로
+pub fn push_all(vec: &mut Vec<u32>, items: &[&str]) -> Result<(), std::num::ParseIntError> {
+    let mut guard = Rollback::new(vec);
+    for item in items {
+        guard.push(item.parse()?);
+    }
+    guard.commit();
+    Ok(())
+}
+
+fn scope_guard() {
+    println!("--- 스코프 가드 - defer ---");
+
+    // C++ 에서는:
+    // auto guard = gsl::finally([&] { cleanup(); });
+    // std::experimental::scope_exit / scope_fail / scope_success (Library Fundamentals TS v3)
+
+    let log = Log::default();
+    {
+        let _cleanup = defer(|| log.borrow_mut().push("deferred"));
+        let cancelled = defer(|| log.borrow_mut().push("cancelled"));
+        log.borrow_mut().push("body");
+        cancelled.cancel();
+    }
+    println!("defer: {:?}", log.borrow());
+
+    let mut numbers = vec![1];
+    println!("push_all(2, 3): {:?} → {:?}", push_all(&mut numbers, &["2", "3"]).is_ok(), numbers);
+    println!("push_all(4, x): {:?} → {:?} (4 도 되돌림)", push_all(&mut numbers, &["4", "x"]).is_ok(), numbers);
+
+    // panic 으로 빠져나가도 가드가 되돌림
+    let (result, _) = silently(AssertUnwindSafe(|| {
+        let mut guard = Rollback::new(&mut numbers);
+        guard.push(5);
+        panic!("작업 중 panic");
+    }));
+    println!("panic 뒤: {} → {:?}", result.is_err(), numbers);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drop_order_follows_the_rules() {
+        assert_eq!(
+            local_drop_order(),
+            ["underscore", "match-arm", "scrutinee", "moved", "temporary", "shadow-2", "shadow-1", "extended", "b", "a"]
+        );
+        assert_eq!(
+            field_drop_order(),
+            ["Server::drop", "listener", "workers", "config", "tuple.0", "tuple.1", "vec[0]", "vec[1]"]
+        );
+        assert_eq!(vec_with_panicking_drop(), (true, vec!["bomb-0", "bomb-1", "bomb-2"]));
+
+        let mut numbers = vec![1];
+        assert!(push_all(&mut numbers, &["2"]).is_ok());
+        assert!(push_all(&mut numbers, &["3", "oops"]).is_err());
+        assert_eq!(numbers, [1, 2]);
+    }
+}
@@ -0,0 +1,650 @@
+// ============================================================================
+// 55. 연산자 오버로딩
+// ============================================================================
+// 07장은 Add 하나, 27장은 Add<Rhs> 의 기본 타입 매개변수까지 - 이 장은 나머지 연산자 트레이트 전부
+// 연산자마다 std::ops (또는 std::cmp) 의 트레이트 하나 - a + b 는 Add::add(a, b) 의 문법 설탕
+//   산술        Add Sub Mul Div Rem, 단항 Neg        복합 대입 AddAssign ... (&mut self)
+//   비트        BitAnd BitOr BitXor Shl Shr, 단항 Not (정수의 ~ 와 bool 의 ! 를 함께)
+//   인덱스      Index / IndexMut - 돌려주는 것은 참조, a[i] 는 *a.index(i)
+//   비교        PartialEq / Eq, PartialOrd / Ord - ==, <, <= 등 모두 여기서 나옴
+// 마지막 절의 Matrix 가 이 중 여럿을 함께 씀
+//
+// C++20과의 핵심 차이점:
+// 1. 멤버 함수나 자유 함수 operator+ 대신 트레이트 구현 - 결과 타입(Output)을 연관 타입으로 적음
+// 2. 피연산자를 값으로 받음 (self) - 복사가 비싼 타입은 &a + &b 를 위해 impl Add<&T> for &T 를 따로 둠
+// 3. &&, ||, =, 콤마, ->, new/delete 는 오버로딩할 수 없음 - 단락 평가와 이동 의미를 지키기 위해
+// 4. operator<=> = default 에 해당하는 것이 #[derive(PartialOrd, Ord)] - 필드 선언 순서대로 사전식 비교
+// 5. 변환 연산자 operator T() 가 없음 - From/Into 로 명시적으로 (암시적 변환 없음)
+// ============================================================================
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, BitAnd, BitOr, BitOrAssign, Index, IndexMut, Mul, Neg, Not, Sub};
+
+// 절 목록 (실행 순서) - cargo run -- 55:operator_table 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("operator_table", operator_table),
+    ("arithmetic", arithmetic),
+    ("bit_ops", bit_ops),
+    ("indexing", indexing),
+    ("ordering", ordering),
+    ("matrix", matrix),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "55"
+    }
+
+    fn name(&self) -> &'static str {
+        "연산자 오버로딩"
+    }
+
+    fn description(&self) -> &'static str {
+        "C++ 연산자와 std::ops 트레이트 대응표, 산술과 복합 대입, Neg/Not 과 비트 연산, Index/IndexMut, 손으로 쓰는 PartialOrd/Ord, 여러 연산자를 쓰는 Matrix"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["연산자 오버로딩", "std::ops", "Add", "AddAssign", "Neg", "Not", "Index", "IndexMut", "PartialOrd", "Ord", "Matrix"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// C++ 연산자와 Rust 트레이트
+// ----------------------------------------------------------------------------
+// 같은 연산자라도 Rust 는 트레이트가 정한 시그니처를 따름
+//   Add::add(self, rhs: Rhs) -> Self::Output        값으로 받고 새 값을 돌려줌
+//   AddAssign::add_assign(&mut self, rhs: Rhs)      제자리에서 바꿈, 반환값 없음 (C++ 처럼 *this 를 돌려주지 않음)
+//   Index::index(&self, idx) -> &Self::Output       참조를 돌려줌 - 없는 값은 panic (get 같은 메서드를 함께 제공)
+//   PartialOrd::partial_cmp(&self, other) -> Option<Ordering>   <, <=, >, >= 는 기본 구현
+
+fn operator_table() {
+    println!("--- C++ 연산자와 Rust 트레이트 ---");
+
+    // C++ 에서는:
+    // T operator+(const T&, const T&);  T& operator+=(const T&);  auto operator<=>(const T&) const = default;
+
+    println!("  식         C++                Rust                      비고");
+    println!("  a + b      operator+          Add                       Sub, Mul, Div, Rem 도 같은 모양");
+    println!("  a += b     operator+=         AddAssign                 &mut self, 반환값 없음");
+    println!("  -a         단항 operator-     Neg");
+    println!("  !a, ~a     operator!, ~       Not                       Rust 는 ~ 가 없음 - 정수의 !x 가 비트 반전");
+    println!("  a & b      operator&, |, ^    BitAnd, BitOr, BitXor     &=, |=, ^= 는 *Assign");
+    println!("  a << b     operator<<, >>     Shl, Shr                  스트림 출력에는 쓰지 않음 - Display");
+    println!("  a[i]       operator[]         Index, IndexMut           참조를 돌려줌, 인덱스 타입은 자유");
+    println!("  a == b     operator==         PartialEq, Eq             != 는 자동");
+    println!("  a < b      operator<=>        PartialOrd, Ord           <, <=, >, >= 는 자동");
+    println!("  *a, a->b   operator*, ->      Deref, DerefMut           12장");
+    println!("  a(x)       operator()         Fn, FnMut, FnOnce         stable 에서는 직접 구현 불가 - 클로저로");
+    println!("  a && b     operator&&, ||     없음                      단락 평가를 잃지 않도록 오버로딩 불가");
+    println!("  a = b      operator=          없음                      이동 또는 Copy, 복제는 Clone");
+    println!("  T(a)       operator T()       From, Into                암시적 변환 없음");
+}
+
+// ----------------------------------------------------------------------------
+// 산술과 복합 대입
+// ----------------------------------------------------------------------------
+// Copy 인 작은 값은 self 로 받아도 비용이 없음 - Vec2 처럼
+// 스칼라를 왼쪽에 두는 2.0 * v 는 impl Mul<Vec2> for f64 - 고아 규칙상 Vec2 가 이 크레이트 것이라 가능
+// += 는 AddAssign 을 따로 구현 - Add 가 있다고 자동으로 생기지 않음 (C++ 와 같음)
+// iter().sum() 을 쓰려면 Sum 을 구현 - Add 와 영벡터로
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vec2 {
+    pub const fn new(x: f64, y: f64) -> Vec2 {
+        Vec2 { x, y }
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+// v * 2.0
+impl Mul<f64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, k: f64) -> Vec2 {
+        Vec2::new(self.x * k, self.y * k)
+    }
+}
+
+// 2.0 * v - 왼쪽 피연산자의 타입에 구현
+impl Mul<Vec2> for f64 {
+    type Output = Vec2;
+
+    fn mul(self, v: Vec2) -> Vec2 {
+        v * self
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sum for Vec2 {
+    fn sum<I: Iterator<Item = Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::default(), Add::add)
+    }
+}
+
+fn arithmetic() {
+    println!("--- 산술과 복합 대입 ---");
+
+    // C++ 에서는:
+    // Vec2 operator*(double k, Vec2 v) { return v * k; }   // 자유 함수로 스칼라를 왼쪽에
+    // Vec2& operator+=(const Vec2& r) { x += r.x; y += r.y; return *this; }
+
+    let a = Vec2::new(1.0, 2.0);
+    let b = Vec2::new(0.5, -1.0);
+    println!("a + b = {:?}, a - b = {:?}", a + b, a - b);
+    println!("a * 3 = {:?}, 3 * a = {:?}, -a = {:?}", a * 3.0, 3.0 * a, -a);
+
+    let mut position = Vec2::default();
+    for step in [a, b, b] {
+        position += step;
+    }
+    println!("+= 로 이동한 위치: {:?}", position);
+
+    let total: Vec2 = [a, b, -a].into_iter().sum();
+    println!("Sum 으로 더한 값: {:?}", total);
+}
+
+// ----------------------------------------------------------------------------
+// Not 과 비트 연산
+// ----------------------------------------------------------------------------
+// Not 은 ! 하나로 bool 의 논리 부정과 정수의 비트 반전을 모두 맡음
+// 비트 플래그 집합에 |, &, ! 를 주면 C 의 enum 플래그처럼 쓸 수 있음 (bitflags 크레이트가 이 일을 자동으로)
+// !flags 는 정의된 비트 안에서만 뒤집기 - 쓰지 않는 비트가 켜지지 않게
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Permissions(u8);
+
+impl Permissions {
+    pub const NONE: Permissions = Permissions(0);
+    pub const READ: Permissions = Permissions(0b100);
+    pub const WRITE: Permissions = Permissions(0b010);
+    pub const EXEC: Permissions = Permissions(0b001);
+    pub const ALL: Permissions = Permissions(0b111);
+
+    pub fn contains(self, other: Permissions) -> bool {
+        self & other == other
+    }
+}
+
+impl BitOr for Permissions {
+    type Output = Permissions;
+
+    fn bitor(self, rhs: Permissions) -> Permissions {
+        Permissions(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for Permissions {
+    type Output = Permissions;
+
+    fn bitand(self, rhs: Permissions) -> Permissions {
+        Permissions(self.0 & rhs.0)
+    }
+}
+
+impl BitOrAssign for Permissions {
+    fn bitor_assign(&mut self, rhs: Permissions) {
+        self.0 |= rhs.0;
+    }
+}
+
+// 정의된 세 비트 안에서만 뒤집음
+impl Not for Permissions {
+    type Output = Permissions;
+
+    fn not(self) -> Permissions {
+        Permissions(!self.0 & Permissions::ALL.0)
+    }
+}
+
+// ls -l 처럼 rwx
+impl fmt::Display for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (flag, c) in [(Permissions::READ, 'r'), (Permissions::WRITE, 'w'), (Permissions::EXEC, 'x')] {
+            write!(f, "{}", if self.contains(flag) { c } else { '-' })?;
+        }
+        Ok(())
+    }
+}
+
+fn bit_ops() {
+    println!("--- Not 과 비트 연산 ---");
+
+    // C++ 에서는:
+    // enum class Perm : uint8_t { Read = 4, Write = 2, Exec = 1 };
+    // constexpr Perm operator|(Perm a, Perm b) { return Perm(uint8_t(a) | uint8_t(b)); }   // enum class 마다 직접
+    // ~Perm::Read 은 쓰지 않는 상위 비트까지 켬
+
+    let mut owner = Permissions::READ | Permissions::WRITE;
+    println!("READ | WRITE = {}", owner);
+    owner |= Permissions::EXEC;
+    println!("|= EXEC 뒤 = {}", owner);
+    let others = !Permissions::WRITE;
+    println!("!WRITE = {} (비트 {:03b}, 상위 비트는 그대로 0)", others, others.0);
+    println!("others 에 WRITE 가 있나: {}, READ 가 있나: {}", others.contains(Permissions::WRITE), others.contains(Permissions::READ));
+    println!("!ALL == NONE: {}, 정수의 !0u8 = {}, bool 의 !true = {}", !Permissions::ALL == Permissions::NONE, !0u8, !true);
+}
+
+// ----------------------------------------------------------------------------
+// Index 와 IndexMut
+// ----------------------------------------------------------------------------
+// 인덱스 타입은 usize 가 아니어도 됨 - enum, 문자열, 튜플 (마지막 절의 (행, 열))
+// 돌려주는 것은 &Output / &mut Output - 값을 만들어 돌려줄 수는 없음 (그럴 땐 get 메서드)
+// 범위를 벗어나면 panic 이 관례 - 실패를 Option 으로 돌려주는 get / get_mut 을 함께 두는 것이 std 의 모양
+// a[i] = v 에는 IndexMut 이 필요 - Index 만 있으면 읽기 전용
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    Red,
+    Green,
+    Blue,
+}
+
+// 채널 이름으로 접근하는 색 - 잘못된 인덱스가 타입에서 막힘 (panic 할 일이 없음)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rgb([u8; 3]);
+
+impl Index<Channel> for Rgb {
+    type Output = u8;
+
+    fn index(&self, channel: Channel) -> &u8 {
+        &self.0[channel as usize]
+    }
+}
+
+impl IndexMut<Channel> for Rgb {
+    fn index_mut(&mut self, channel: Channel) -> &mut u8 {
+        &mut self.0[channel as usize]
+    }
+}
+
+// 이름으로 찾는 점수표 - 없는 이름은 HashMap 의 [] 처럼 panic, get 은 Option
+pub struct Scores {
+    entries: Vec<(String, u32)>,
+}
+
+impl Scores {
+    pub fn new() -> Scores {
+        Scores { entries: Vec::new() }
+    }
+
+    pub fn insert(&mut self, name: &str, score: u32) {
+        self.entries.push((name.to_string(), score));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&u32> {
+        self.entries.iter().find(|(n, _)| n == name).map(|(_, s)| s)
+    }
+}
+
+impl Default for Scores {
+    fn default() -> Self {
+        Scores::new()
+    }
+}
+
+impl Index<&str> for Scores {
+    type Output = u32;
+
+    fn index(&self, name: &str) -> &u32 {
+        self.get(name).unwrap_or_else(|| panic!("점수표에 없는 이름: {}", name))
+    }
+}
+
+fn indexing() {
+    println!("--- Index 와 IndexMut ---");
+
+    // C++ 에서는:
+    // uint8_t& operator[](Channel c) { return v[static_cast<size_t>(c)]; }
+    // const uint8_t& operator[](Channel c) const { ... }   // const 판과 비 const 판을 따로 - Rust 는 Index 와 IndexMut
+    // std::map::operator[] 는 없는 키를 만들어 넣음 - Rust 의 HashMap 은 panic, 넣으려면 entry
+
+    let mut color = Rgb::default();
+    color[Channel::Red] = 255;
+    color[Channel::Blue] += 128;
+    println!("Rgb: {:?}, 빨강 {}, 초록 {}", color, color[Channel::Red], color[Channel::Green]);
+
+    let mut scores = Scores::new();
+    scores.insert("ann", 91);
+    scores.insert("bob", 78);
+    println!("scores[\"ann\"] = {}, get(\"cy\") = {:?}", scores["ann"], scores.get("cy"));
+    // scores["cy"] 는 panic - 있을지 모르면 get
+}
+
+// ----------------------------------------------------------------------------
+// PartialOrd 와 Ord 를 손으로
+// ----------------------------------------------------------------------------
+// derive 는 필드 선언 순서대로 사전식 비교 - 대부분 이것으로 충분
+// 손으로 쓰는 경우: 비교 순서가 필드 순서와 다를 때, 일부 필드를 무시할 때, derive 의 규칙이 맞지 않을 때
+// 규칙: Ord 를 손으로 쓰면 PartialOrd 는 Some(self.cmp(other)) 로, PartialEq 도 cmp 와 모순되지 않게
+//   (a == b 인데 cmp 가 Equal 이 아니면 BTreeMap, sort, binary_search 가 이상하게 동작 - UB 는 아님)
+// Ordering::then / then_with 로 여러 기준을 잇고, cmp::Reverse 로 거꾸로
+
+// 유의적 버전 - 1.0.0-beta 는 1.0.0 보다 앞
+// derive 하면 Option 의 None 이 Some 보다 앞이라 정식 판(None)이 시험 판보다 먼저 와 버림
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub pre: Option<String>,
+}
+
+impl Version {
+    pub fn parse(text: &str) -> Option<Version> {
+        let (numbers, pre) = match text.split_once('-') {
+            Some((numbers, pre)) => (numbers, Some(pre.to_string())),
+            None => (text, None),
+        };
+        let mut parts = numbers.split('.').map(|p| p.parse::<u32>().ok());
+        let version = Version { major: parts.next()??, minor: parts.next()??, patch: parts.next()??, pre };
+        parts.next().is_none().then_some(version)
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                // 정식 판이 시험 판보다 뒤
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        Ok(())
+    }
+}
+
+fn ordering() {
+    println!("--- PartialOrd 와 Ord 를 손으로 ---");
+
+    // C++ 에서는:
+    // auto operator<=>(const Version&) const = default;   // derive 와 같음 - 필드 순서대로
+    // std::strong_ordering operator<=>(const Version& o) const { if (auto c = major <=> o.major; c != 0) return c; ... }
+    // strong_ordering 은 Ord, partial_ordering 은 PartialOrd (f64 처럼 비교 못 하는 값이 있을 때 None)
+
+    let mut versions: Vec<Version> = ["1.0.0", "1.0.0-beta", "0.9.12", "1.0.0-alpha", "1.2.0"].iter().filter_map(|v| Version::parse(v)).collect();
+    versions.sort();
+    let sorted: Vec<String> = versions.iter().map(Version::to_string).collect();
+    println!("정렬: {:?}", sorted);
+
+    let release = Version::parse("1.0.0").unwrap();
+    let beta = Version::parse("1.0.0-beta").unwrap();
+    println!("1.0.0-beta < 1.0.0: {}, max: {}", beta < release, std::cmp::max(&beta, &release));
+
+    // 최신 판부터 - Reverse 로 감싸 정렬 키를 뒤집음
+    versions.sort_by_key(|v| std::cmp::Reverse(v.clone()));
+    println!("최신 판: {}", versions[0]);
+
+    // 부분 순서 - f64 는 NaN 이 있어 PartialOrd 만 (24장)
+    println!("1.0.partial_cmp(NaN) = {:?}", 1.0f64.partial_cmp(&f64::NAN));
+}
+
+// ----------------------------------------------------------------------------
+// 여러 연산자를 함께 - Matrix
+// ----------------------------------------------------------------------------
+// Vec 을 가진 타입은 Copy 가 아님 - a + b 가 a 와 b 를 소비하면 불편
+//   impl Add<&Matrix> for &Matrix   → &a + &b 로 빌려서 새 행렬
+//   impl Add for Matrix             → a + b 는 왼쪽의 저장 공간을 재사용 (+= 로 위임)
+//   impl AddAssign<&Matrix>         → a += &b 는 제자리
+// 크기가 맞지 않는 덧셈과 곱셈은 panic - 슬라이스 인덱스처럼 호출자의 버그로 봄, 확인하려면 checked_mul
+// m[(행, 열)] - 튜플 인덱스
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<i64>,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Matrix {
+        Matrix { rows, cols, data: vec![0; rows * cols] }
+    }
+
+    pub fn identity(n: usize) -> Matrix {
+        let mut m = Matrix::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = 1;
+        }
+        m
+    }
+
+    // 행마다 길이가 같아야 함
+    pub fn from_rows<const C: usize>(rows: &[[i64; C]]) -> Matrix {
+        Matrix { rows: rows.len(), cols: C, data: rows.iter().flatten().copied().collect() }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut t = Matrix::zeros(self.cols, self.rows);
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                t[(c, r)] = self[(r, c)];
+            }
+        }
+        t
+    }
+
+    // 크기가 맞지 않으면 None - 연산자 * 는 panic
+    pub fn checked_mul(&self, rhs: &Matrix) -> Option<Matrix> {
+        if self.cols != rhs.rows {
+            return None;
+        }
+        let mut out = Matrix::zeros(self.rows, rhs.cols);
+        for r in 0..self.rows {
+            for c in 0..rhs.cols {
+                out[(r, c)] = (0..self.cols).map(|k| self[(r, k)] * rhs[(k, c)]).sum();
+            }
+        }
+        Some(out)
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = i64;
+
+    fn index(&self, (r, c): (usize, usize)) -> &i64 {
+        assert!(r < self.rows && c < self.cols, "행렬 범위 밖: ({}, {}), 크기 {}x{}", r, c, self.rows, self.cols);
+        &self.data[r * self.cols + c]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut i64 {
+        assert!(r < self.rows && c < self.cols, "행렬 범위 밖: ({}, {}), 크기 {}x{}", r, c, self.rows, self.cols);
+        &mut self.data[r * self.cols + c]
+    }
+}
+
+impl AddAssign<&Matrix> for Matrix {
+    fn add_assign(&mut self, rhs: &Matrix) {
+        assert_eq!(self.shape(), rhs.shape(), "크기가 다른 행렬의 덧셈");
+        for (a, b) in self.data.iter_mut().zip(&rhs.data) {
+            *a += b;
+        }
+    }
+}
+
+// 소비하는 덧셈 - 왼쪽의 Vec 을 그대로 결과로
+impl Add<&Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn add(mut self, rhs: &Matrix) -> Matrix {
+        self += rhs;
+        self
+    }
+}
+
+// 빌리는 덧셈 - 둘 다 남겨 둠
+impl Add<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: &Matrix) -> Matrix {
+        self.clone() + rhs
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        self.checked_mul(rhs).unwrap_or_else(|| panic!("곱할 수 없는 크기: {:?} * {:?}", self.shape(), rhs.shape()))
+    }
+}
+
+// 스칼라 곱 - 양쪽 순서 모두
+impl Mul<i64> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, k: i64) -> Matrix {
+        Matrix { rows: self.rows, cols: self.cols, data: self.data.iter().map(|v| v * k).collect() }
+    }
+}
+
+impl Mul<&Matrix> for i64 {
+    type Output = Matrix;
+
+    fn mul(self, m: &Matrix) -> Matrix {
+        m * self
+    }
+}
+
+impl Neg for &Matrix {
+    type Output = Matrix;
+
+    fn neg(self) -> Matrix {
+        self * -1
+    }
+}
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for r in 0..self.rows {
+            let row: Vec<String> = (0..self.cols).map(|c| format!("{:>4}", self[(r, c)])).collect();
+            writeln!(f, "  [{}]", row.join(""))?;
+        }
+        Ok(())
+    }
+}
+
+fn matrix() {
+    println!("--- 여러 연산자를 함께 - Matrix ---");
+
+    // C++ 에서는:
+    // Matrix operator+(const Matrix&, const Matrix&);   Matrix& operator+=(const Matrix&);
+    // double& operator()(size_t r, size_t c);           // C++23 전에는 operator[] 가 인자 하나라 operator() 를 씀
+    // Matrix operator+(Matrix&& a, const Matrix& b) { a += b; return std::move(a); }   // 임시 값의 저장 공간 재사용
+
+    let a = Matrix::from_rows(&[[1, 2], [3, 4]]);
+    let b = Matrix::from_rows(&[[0, 1], [1, 0]]);
+    print!("a =\n{}", a);
+    print!("&a + &b =\n{}", &a + &b);
+    print!("&a * &b (열 바꾸기) =\n{}", &a * &b);
+    print!("-&a + &(2 * &a) =\n{}", -&a + &(2 * &a));
+
+    let mut acc = Matrix::zeros(2, 2);
+    for _ in 0..3 {
+        acc += &a;
+    }
+    println!("+= 세 번 == 3 * &a: {}", acc == 3 * &a);
+    println!("a * I == a: {}, (aᵀ)ᵀ == a: {}", &a * &Matrix::identity(2) == a, a.transpose().transpose() == a);
+
+    let tall = Matrix::from_rows(&[[1, 0, 2]]);
+    println!("2x2 * 1x3 checked_mul: {:?}", a.checked_mul(&tall).map(|m| m.shape()));
+    println!("a[(1, 0)] = {}, 1x3 의 전치 크기 {:?}", a[(1, 0)], tall.transpose().shape());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators_agree_with_their_methods() {
+        let a = Matrix::from_rows(&[[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from_rows(&[[1, 0], [0, 1], [1, 1]]);
+        assert_eq!(&a * &b, Matrix::from_rows(&[[4, 5], [10, 11]]));
+        assert_eq!(&a + &a, 2 * &a);
+        assert_eq!(-&a + &a, Matrix::zeros(2, 3));
+        assert!(a.checked_mul(&a).is_none());
+
+        let mut versions: Vec<Version> = ["1.0.0", "1.0.0-rc.1", "0.10.0", "0.9.0"].iter().filter_map(|v| Version::parse(v)).collect();
+        versions.sort();
+        let order: Vec<String> = versions.iter().map(Version::to_string).collect();
+        assert_eq!(order, ["0.9.0", "0.10.0", "1.0.0-rc.1", "1.0.0"]);
+        assert!(Version::parse("1.0").is_none());
+
+        assert_eq!(!Permissions::READ, Permissions::WRITE | Permissions::EXEC);
+        assert_eq!((Permissions::READ | Permissions::EXEC).to_string(), "r-x");
+    }
+
+    #[test]
+    #[should_panic(expected = "곱할 수 없는 크기")]
+    fn mismatched_shapes_panic() {
+        let _ = &Matrix::zeros(2, 3) * &Matrix::zeros(2, 3);
+    }
+}
@@ -0,0 +1,443 @@
+// ============================================================================
+// 56. Display 와 Debug 서식 깊이 보기
+// ============================================================================
+// {} 는 Display, {:?} 는 Debug - 둘 다 fn fmt(&self, f: &mut Formatter) -> fmt::Result
+// 자리표시자의 서식 지정({:>8.2} 등)은 Formatter 에 담겨 fmt 로 넘어옴 - 지키는 것은 구현의 몫
+//   f.width(), f.precision(), f.fill(), f.align(), f.alternate() ({:#}), f.sign_plus() ({:+})
+//   f.pad(s) - 너비, 정렬, 채움, 정밀도(자르기)를 한 번에 적용해 s 를 씀
+//   f.debug_struct / debug_tuple / debug_list / debug_map - {:?} 와 {:#?} 를 모두 맞춰 주는 빌더
+// 서식 대상은 fmt::Write - String 에도, 직접 만든 싱크에도 같은 write! 로
+//
+// C++20과의 핵심 차이점:
+// 1. operator<<(ostream&, const T&) 대신 impl Display - std::setw 같은 조작자의 "끈적한" 상태 없이 자리표시자마다 서식
+// 2. std::format 의 서식 문법({:>8.2f})과 거의 같음 - 단 사용자 타입은 std::formatter<T>::parse 를 직접 쓰지 않고 이미 해석된 Formatter 를 받음
+// 3. Debug 가 별도 트레이트 - derive 한 줄로 {:?} 와 보기 좋은 {:#?}, C++ 에는 표준 디버그 출력이 없음
+// 4. 서식 문자열과 인자는 컴파일 시간에 검사 - 개수나 트레이트가 맞지 않으면 에러 (C++20 의 consteval 서식 문자열과 같은 보장)
+// 5. 텍스트 싱크(fmt::Write)와 바이트 싱크(io::Write)가 나뉨 - ostream 하나가 둘 다 하는 것과 다름
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+// 절 목록 (실행 순서) - cargo run -- 56:format_spec 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("format_spec", format_spec),
+    ("custom_display", custom_display),
+    ("manual_debug", manual_debug),
+    ("write_sink", write_sink),
+    ("display_adapters", display_adapters),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "56"
+    }
+
+    fn name(&self) -> &'static str {
+        "Display 와 Debug 서식 깊이 보기"
+    }
+
+    fn description(&self) -> &'static str {
+        "서식 지정 문법(너비, 정밀도, 정렬, {:#?}), Formatter 의 플래그를 지키는 Display 와 pad, debug_struct 로 쓰는 Debug, fmt::Write 싱크, 할당 없는 Display 어댑터 - operator<< 와 std::format 과 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Display", "Debug", "Formatter", "서식 지정", "pad", "debug_struct", "fmt::Write", "format_args!", "{:#?}"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 서식 지정 문법
+// ----------------------------------------------------------------------------
+// {인자:채움 정렬 부호 # 0 너비 .정밀도 종류}
+//   인자     생략(차례대로), 0 1 (위치), name (이름 - 같은 이름의 지역 변수도 바로: {count})
+//   채움정렬  < 왼쪽, > 오른쪽, ^ 가운데 - 앞에 한 글자를 두면 채움 문자 ({:*^9})
+//   부호     + 면 양수에도 +    # 면 대체 형식 ({:#x} → 0x1f, {:#?} → 여러 줄)
+//   0        부호 뒤를 0 으로 채움 ({:08.3})
+//   너비     숫자, 또는 인자로 ({:>width$}, {:>1$})
+//   정밀도   실수는 소수 자릿수, 문자열은 최대 글자 수 ({:.3}), 인자로는 .prec$ 또는 .* (인자 두 개를 씀)
+//   종류     없음(Display) ? (Debug) x X o b (진법) e E (지수)
+// 너비와 정밀도는 글자(char) 수 기준 - 한글 한 글자도 1 (화면 폭 2 칸과는 다름, 23장)
+
+fn format_spec() {
+    println!("--- 서식 지정 문법 ---");
+
+    // C++ 에서는:
+    // std::format("{:>8.2f}|{:<6}|{:^7}|{:+}|{:#x}|{:08.3f}", 3.14159, "ab", "mid", 5, 31, -2.5)   // 거의 같은 문법
+    // std::cout << std::setw(8) << std::fixed << std::setprecision(2) << x;                   // setprecision 은 이후 출력에도 남음
+
+    let pi = std::f64::consts::PI;
+    let name = "ab";
+    println!("[{:>8.2}] [{:<6}] [{:^7}] [{:+}] [{:#x}] [{:08.3}]", pi, name, "mid", 5, 31, -2.5);
+    println!("[{:*^9}] [{:#b}] [{:o}] [{:e}] [{:X}]", "fill", 5u8, 64, 1234.5, 255);
+
+    // 인자로 너비와 정밀도 - 표를 그릴 때 열 너비를 계산해서
+    let width = 10;
+    let precision = 3;
+    println!("[{:>width$.precision$}] [{:>1$}] [{:.*}]", pi, 6, 2, 1.23456);
+
+    // 이름 있는 인자와 지역 변수 캡처
+    let count = 3;
+    println!("위치 {0} {1} {0}, 이름 {unit}, 캡처 {count}", "a", "b", unit = "kg");
+
+    // 정밀도는 문자열을 자름 - 글자 단위
+    println!("[{:.3}] [{:.2}] [{:>5}]", "abcdef", "가나다라", "한글");
+
+    // {{ }} 는 중괄호 그 자체
+    println!("중괄호: {{}} 와 {{{}}}", count);
+}
+
+// ----------------------------------------------------------------------------
+// Formatter 의 플래그를 지키는 Display
+// ----------------------------------------------------------------------------
+// write!(f, "{}", x) 는 f 의 너비와 정렬을 무시 - 새 서식 문자열이라 {:>10} 이 사라짐
+// 가장 쉬운 방법: 글자를 만든 뒤 f.pad(&s) - 너비, 정렬, 채움, 정밀도(자르기)를 모두 적용
+//   숫자처럼 부호와 0 채움까지 지키려면 f.pad_integral(양수인가, 접두사, 숫자)
+// 정밀도를 "소수 자릿수" 처럼 다른 뜻으로 쓸 수도 있음 - f.precision() 을 읽어 직접 처리
+// f.alternate() 로 {:#} 에 다른 모양을 - 표준의 {:#x}, {:#?} 처럼
+
+// 섭씨 온도 - 정밀도는 소수 자릿수 (기본 1), 너비와 정렬은 pad 로
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Celsius(pub f64);
+
+impl fmt::Display for Celsius {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = f.precision().unwrap_or(1);
+        let text = if f.sign_plus() { format!("{:+.*}°C", digits, self.0) } else { format!("{:.*}°C", digits, self.0) };
+        // f.pad 는 정밀도를 "최대 글자 수" 로 보고 잘라 버리므로 너비와 정렬만 직접 적용
+        write_padded(f, &text)
+    }
+}
+
+// f 의 너비, 채움, 정렬만 적용해 text 를 씀 (정밀도는 무시)
+fn write_padded(f: &mut fmt::Formatter, text: &str) -> fmt::Result {
+    let len = text.chars().count();
+    let width = f.width().unwrap_or(0);
+    if len >= width {
+        return f.write_str(text);
+    }
+    let gap = width - len;
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) | None => (0, gap),
+        Some(fmt::Alignment::Right) => (gap, 0),
+        Some(fmt::Alignment::Center) => (gap / 2, gap - gap / 2),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+// 너비를 지키지 않는 흔한 구현 - 비교용
+pub struct NaiveTag(pub &'static str);
+
+impl fmt::Display for NaiveTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{}>", self.0)
+    }
+}
+
+// pad 로 너비를 지키는 구현
+pub struct Tag(pub &'static str);
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&format!("<{}>", self.0))
+    }
+}
+
+// 걸린 시간 - {} 는 1h 2m 3s, {:#} 는 01:02:03
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(pub u64);
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (h, m, s) = (self.0 / 3600, self.0 / 60 % 60, self.0 % 60);
+        let text = if f.alternate() {
+            format!("{:02}:{:02}:{:02}", h, m, s)
+        } else if h > 0 {
+            format!("{}h {}m {}s", h, m, s)
+        } else if m > 0 {
+            format!("{}m {}s", m, s)
+        } else {
+            format!("{}s", s)
+        };
+        f.pad(&text)
+    }
+}
+
+fn custom_display() {
+    println!("--- Formatter 의 플래그를 지키는 Display ---");
+
+    // C++ 에서는:
+    // std::ostream& operator<<(std::ostream& os, const Tag& t) { return os << '<' << t.name << '>'; }
+    // std::setw(10) 은 다음 한 번의 << 에만 적용 - '<' 에 쓰이고 나머지는 붙어 나옴 (같은 함정)
+    // std::formatter<Tag> 를 특수화하면 parse() 에서 서식 문자열을 직접 해석해야 함
+
+    println!("write! 로만: [{:>10}] [{:<10}]", NaiveTag("b"), NaiveTag("i"));
+    println!("pad 로:      [{:>10}] [{:<10}] [{:*^10}]", Tag("b"), Tag("i"), Tag("em"));
+
+    let t = Celsius(21.456);
+    println!("Celsius: [{}] [{:.0}] [{:.2}] [{:+}] [{:>10.1}] [{:-<9}]", t, t, t, t, t, t);
+
+    let e = Elapsed(3723);
+    println!("Elapsed: [{}] [{:#}] [{:>12}] [{}]", e, e, Elapsed(75), Elapsed(9));
+}
+
+// ----------------------------------------------------------------------------
+// Debug 를 손으로
+// ----------------------------------------------------------------------------
+// derive(Debug) 는 모든 필드를 그대로 - 손으로 쓰는 경우:
+//   비밀 값(비밀번호, 토큰)을 가릴 때, 캐시나 내부 포인터처럼 의미 없는 필드를 뺄 때, 더 읽기 좋은 모양이 있을 때
+// 빌더를 쓰면 {:?} 와 {:#?} (여러 줄, 들여쓰기)를 알아서 맞춰 줌 - write! 로 직접 쓰면 {:#?} 가 깨짐
+//   f.debug_struct("Name").field("a", &a).finish()
+//   .finish_non_exhaustive() - 일부만 보여 줄 때 끝에 ..
+//   debug_tuple, debug_list().entries(iter), debug_set, debug_map().entries(iter)
+
+pub struct Credentials {
+    pub user: String,
+    pub password: String,
+    // 보여 줄 필요가 없는 내부 상태
+    pub attempts: u32,
+}
+
+impl Credentials {
+    pub fn check(&self, attempt: &str) -> bool {
+        self.password == attempt
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Credentials").field("user", &self.user).field("password", &"***").finish_non_exhaustive()
+    }
+}
+
+// 희소 벡터 - 0 이 아닌 칸만 map 처럼
+pub struct Sparse {
+    pub len: usize,
+    pub values: BTreeMap<usize, f64>,
+}
+
+impl fmt::Debug for Sparse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Sparse[{}] ", self.len)?;
+        f.debug_map().entries(self.values.iter()).finish()
+    }
+}
+
+// 튜플 구조체의 모양 - 단위를 붙여서
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl fmt::Debug for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // {:?} 는 Rgb(#ff8000), {:#?} 는 채널을 한 줄씩
+        if f.alternate() {
+            f.debug_tuple("Rgb").field(&self.0).field(&self.1).field(&self.2).finish()
+        } else {
+            write!(f, "Rgb(#{:02x}{:02x}{:02x})", self.0, self.1, self.2)
+        }
+    }
+}
+
+fn manual_debug() {
+    println!("--- Debug 를 손으로 ---");
+
+    // C++ 에서는:
+    // 표준 디버그 출력이 없어 operator<< 를 하나 더 쓰거나 디버거의 pretty printer (natvis, gdb python) 에 맡김
+
+    let login = Credentials { user: String::from("ann"), password: String::from("hunter2"), attempts: 2 };
+    println!("{:?}", login);
+    println!("{:#?}", login);
+    println!("attempts 는 숨김: {}, 비밀번호는 가렸지만 그대로 있음: {}", login.attempts, login.check("hunter2"));
+
+    let sparse = Sparse { len: 1000, values: BTreeMap::from([(3, 1.5), (999, -2.0)]) };
+    println!("{:?}", sparse);
+
+    let orange = Rgb(255, 128, 0);
+    println!("{:?}", orange);
+    println!("{:#?}", orange);
+}
+
+// ----------------------------------------------------------------------------
+// fmt::Write 싱크
+// ----------------------------------------------------------------------------
+// fmt::Write 는 write_str 하나만 구현하면 write! / writeln! 의 대상이 됨 (write_char, write_fmt 는 기본 구현)
+// String 이 fmt::Write - format! 대신 write!(s, ..) 로 같은 String 에 이어 쓰기 (재할당 줄이기)
+// 싱크에서 Err(fmt::Error) 를 돌려주면 서식이 그 자리에서 멈춤 - fmt::Error 는 정보가 없는 "그만" 신호
+// io::Write (바이트, 파일과 소켓) 와 다름 - 둘 다 use 하면 write! 가 모호하지 않게 한쪽만 가져옴
+
+// 바이트 수만 세는 싱크 - 할당 없이 출력 길이를 미리 앎
+#[derive(Default)]
+pub struct Counter {
+    pub bytes: usize,
+    pub chars: usize,
+}
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.bytes += s.len();
+        self.chars += s.chars().count();
+        Ok(())
+    }
+}
+
+// 앞에서부터 limit 글자만 받고 나머지는 버리는 싱크 - 넘치면 Err 로 서식을 멈춤
+pub struct Truncating {
+    pub out: String,
+    limit: usize,
+    pub truncated: bool,
+}
+
+impl Truncating {
+    pub fn new(limit: usize) -> Truncating {
+        Truncating { out: String::new(), limit, truncated: false }
+    }
+}
+
+impl Write for Truncating {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let room = self.limit - self.out.chars().count();
+        if s.chars().count() <= room {
+            self.out.push_str(s);
+            return Ok(());
+        }
+        self.out.extend(s.chars().take(room));
+        self.truncated = true;
+        Err(fmt::Error)
+    }
+}
+
+// 값 하나를 limit 글자로 - 넘치면 끝에 …
+pub fn preview(value: &dyn fmt::Debug, limit: usize) -> String {
+    let mut sink = Truncating::new(limit);
+    // Err 는 "잘림" 의 뜻 - 여기서는 정상 흐름
+    let _ = write!(sink, "{:?}", value);
+    if sink.truncated {
+        sink.out.push('…');
+    }
+    sink.out
+}
+
+fn write_sink() {
+    println!("--- fmt::Write 싱크 ---");
+
+    // C++ 에서는:
+    // std::format_to(std::back_inserter(s), "{}", x);   // 출력 반복자가 싱크
+    // std::formatted_size("{}", x)                     // Counter 와 같은 일
+    // 잘라 쓰기는 std::format_to_n
+
+    let mut report = String::new();
+    for (i, word) in ["alpha", "beta"].iter().enumerate() {
+        writeln!(report, "{}. {}", i + 1, word).unwrap();
+    }
+    write!(report, "total={}", 2).unwrap();
+    println!("String 에 이어 쓴 결과: {:?}", report);
+
+    let mut counter = Counter::default();
+    write!(counter, "{:>8}|{:?}|{}", Elapsed(75), "따옴표", 3.5).unwrap();
+    println!("출력하지 않고 센 길이: 바이트 {}, 글자 {}", counter.bytes, counter.chars);
+
+    let long: Vec<u32> = (1..=100).collect();
+    println!("앞 20 글자만: {}", preview(&long, 20));
+    println!("짧으면 그대로: {}", preview(&[1, 2, 3], 20));
+}
+
+// ----------------------------------------------------------------------------
+// 할당 없는 Display 어댑터
+// ----------------------------------------------------------------------------
+// String 을 돌려주는 함수 대신 Display 를 구현한 작은 값을 돌려주면, 호출자가 쓰는 곳에 바로 씀 (중간 String 없음)
+//   println!("{}", join(&items, ", ")) - items 를 한 번만 훑고 출력 버퍼에 직접
+// format_args!(..) 는 서식만 묶어 둔 fmt::Arguments - 할당하지 않고 다른 서식 함수에 넘김 (수명이 짧아 변수로는 잘 못 씀)
+// 이 저장소의 style 모듈도 색을 입힌 글자를 같은 방식으로 다룸
+
+// 구분자로 이어 붙여 보여 주기
+pub struct Join<'a, T> {
+    items: &'a [T],
+    sep: &'a str,
+}
+
+pub fn join<'a, T: fmt::Display>(items: &'a [T], sep: &'a str) -> Join<'a, T> {
+    Join { items, sep }
+}
+
+impl<T: fmt::Display> fmt::Display for Join<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.sep)?;
+            }
+            // 안의 원소에 같은 서식을 전달 - {:.1} 이면 각 원소가 소수 한 자리
+            fmt::Display::fmt(item, f)?;
+        }
+        Ok(())
+    }
+}
+
+// 로그 한 줄 - 호출자가 만든 fmt::Arguments 를 할당 없이 받아 앞에 수준을 붙임
+pub fn log_line(out: &mut String, level: &str, args: fmt::Arguments) {
+    writeln!(out, "[{:<5}] {}", level, args).unwrap();
+}
+
+fn display_adapters() {
+    println!("--- 할당 없는 Display 어댑터 ---");
+
+    // C++ 에서는:
+    // std::ranges::views::join_with 뒤 std::format("{}", ...) 또는 fmt::join (fmtlib)
+    // std::format_args / std::make_format_args 가 fmt::Arguments 와 같은 "묶어 둔 인자"
+
+    let prices = [1.5, 20.25, 3.0];
+    println!("join: {}", join(&prices, ", "));
+    println!("join 에 정밀도 전달: {:.1}", join(&prices, " / "));
+    println!("join 에 너비 전달: [{:>6}]", join(&["a", "b"], "|"));
+
+    // 한 번 쓰는 어댑터는 타입을 만들지 않고 fmt::from_fn 에 클로저로 - Display 와 Debug 를 모두 구현한 값
+    let bar = fmt::from_fn(|f| {
+        for _ in 0..f.width().unwrap_or(3) {
+            f.write_char('#')?;
+        }
+        Ok(())
+    });
+    println!("from_fn 막대: [{}] [{:8}]", bar, bar);
+
+    let mut log = String::new();
+    log_line(&mut log, "info", format_args!("processed={} done={:.1}%", 42, 87.456));
+    log_line(&mut log, "warn", format_args!("free={}MB", 12));
+    print!("{}", log);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formatter_flags_are_respected() {
+        assert_eq!(format!("[{:>6}]", Tag("a")), "[   <a>]");
+        assert_eq!(format!("[{:>6}]", NaiveTag("a")), "[<a>]");
+        assert_eq!(format!("{:.2}", Celsius(1.005)), "1.00°C");
+        assert_eq!(format!("[{:>7}]", Celsius(-3.0)), "[ -3.0°C]");
+        assert_eq!(format!("{} {:#}", Elapsed(3723), Elapsed(3723)), "1h 2m 3s 01:02:03");
+        assert_eq!(format!("{:.1}", join(&[1.25, 2.0], ",")), "1.2,2.0");
+
+        let login = Credentials { user: "u".into(), password: "secret".into(), attempts: 0 };
+        assert!(!format!("{:?}", login).contains("secret"));
+        assert!(!format!("{:#?}", login).contains("secret"));
+
+        let mut counter = Counter::default();
+        write!(counter, "한글").unwrap();
+        assert_eq!((counter.bytes, counter.chars), (6, 2));
+        assert_eq!(preview(&"abcdef", 4), "\"abc…");
+    }
+}
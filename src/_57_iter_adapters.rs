@@ -0,0 +1,499 @@
+// ============================================================================
+// 57. 직접 만드는 이터레이터 어댑터
+// ============================================================================
+// 어댑터 = 다른 이터레이터를 감싸서 새 이터레이터를 만드는 구조체 (map, filter, zip 이 모두 이 모양)
+//   1. 구조체 - 안쪽 이터레이터와 상태를 필드로
+//   2. impl Iterator - next 하나만 쓰면 나머지 70여 개 메서드는 기본 구현
+//   3. 확장 트레이트 + blanket impl - 모든 이터레이터에 .chunks_of(3) 처럼 점으로 붙이기 (18장 IteratorExt)
+// 11장의 Counter 는 "처음부터 만드는" 이터레이터 - 이 장은 "감싸는" 이터레이터
+// 같이 챙길 것: size_hint (남은 개수 추정), FusedIterator (None 다음에도 계속 None), #[must_use] (게으름)
+//
+// C++20과의 핵심 차이점:
+// 1. views::chunk, views::chunk_by, views::join_with 는 C++23 - Rust 표준에는 슬라이스용 chunks, chunk_by 만 있고 이터레이터용은 직접 쓰거나 itertools
+// 2. 커스텀 view 는 view_interface + iterator + sentinel (+ C++23 range_adaptor_closure 로 | 파이프) - Rust 는 구조체 하나와 next 하나
+// 3. size_hint 는 (하한, 상한) 추정치 - 틀려도 메모리 안전은 그대로, sized_range 처럼 "크기를 안다/모른다" 둘 중 하나가 아님
+// 4. 끝난 뒤 ++ 는 C++ 에서 UB - Rust 에서 None 다음 next 는 안전하지만 무엇이 나올지는 구현 마음 (FusedIterator 가 약속)
+// 5. chunk_by view 는 원래 범위를 가리키는 subrange 를 줌 - Iterator::next 의 결과는 이터레이터를 빌릴 수 없어서 그룹을 Vec 으로 모음
+// ============================================================================
+
+use std::iter::{FusedIterator, Peekable};
+
+// 절 목록 (실행 순서) - cargo run -- 57:adapter_pattern 처럼 절 하나만 실행할 때도 사용
+pub const SECTIONS: &[(&str, fn())] = &[
+    ("adapter_pattern", adapter_pattern),
+    ("chunks_of", chunks_of),
+    ("dedup_by", dedup_by),
+    ("group_by", group_by),
+    ("intersperse", intersperse),
+    ("size_hint_fused", size_hint_fused),
+];
+
+// 이 장 - main 의 장 목록에 등록
+pub struct Lesson;
+
+impl crate::chapter::Chapter for Lesson {
+    fn id(&self) -> &'static str {
+        "57"
+    }
+
+    fn name(&self) -> &'static str {
+        "직접 만드는 이터레이터 어댑터"
+    }
+
+    fn description(&self) -> &'static str {
+        "chunks_of, dedup_by, group_by, intersperse 를 Iterator 구조체로 직접 구현 - 확장 트레이트로 체인에 붙이고, size_hint 와 FusedIterator, ExactSizeIterator 를 조건부로 챙기기 - C++23 views::chunk, chunk_by 와 비교"
+    }
+
+    fn topics(&self) -> &'static [&'static str] {
+        &["Iterator", "어댑터", "확장 트레이트", "Peekable", "next_if", "size_hint", "FusedIterator", "ExactSizeIterator", "#[must_use]"]
+    }
+
+    fn sections(&self) -> &'static [(&'static str, fn())] {
+        SECTIONS
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 어댑터의 세 조각
+// ----------------------------------------------------------------------------
+// 확장 트레이트의 메서드는 self 를 값으로 받아 구조체에 넣어 돌려줄 뿐 - 아무것도 꺼내지 않음
+// 실제 일은 바깥에서 next 를 부를 때 - std 어댑터처럼 #[must_use] 를 붙여 "만들고 안 쓴" 체인을 경고로
+// 이름 주의: std 에 Iterator::intersperse 가 불안정 기능으로 있어서 같은 이름을 쓰면
+//   unstable_name_collisions 경고 (itertools 가 실제로 겪는 문제) - 그래서 여기서는 interspersed
+
+pub trait AdaptersExt: Iterator + Sized {
+    // size 개씩 묶어서 Vec 으로 - 마지막 묶음은 짧을 수 있음
+    fn chunks_of(self, size: usize) -> ChunksOf<Self> {
+        assert!(size > 0, "chunks_of: size 는 1 이상");
+        ChunksOf { iter: self, size }
+    }
+
+    // 연달아 same 인 원소는 첫 것만 남김 (Vec::dedup_by 의 이터레이터판)
+    fn dedup_by<F>(self, same: F) -> DedupBy<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupBy { iter: self.peekable(), same }
+    }
+
+    // 연달아 같은 키를 가진 원소를 (키, 묶음) 으로 - 정렬하지 않으므로 떨어진 같은 키는 다른 그룹
+    fn group_by<K, F>(self, key: F) -> GroupBy<Self, F>
+    where
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        GroupBy { iter: self.peekable(), key }
+    }
+
+    // 원소 사이마다 sep 의 복제본을 끼워 넣음
+    fn interspersed(self, sep: Self::Item) -> Intersperse<Self>
+    where
+        Self::Item: Clone,
+    {
+        Intersperse { iter: self.peekable(), sep, needs_sep: false }
+    }
+}
+
+impl<I: Iterator> AdaptersExt for I {}
+
+fn adapter_pattern() {
+    println!("--- 어댑터의 세 조각 ---");
+
+    // C++ 에서는 (C++23):
+    // auto v = std::views::iota(1, 8) | std::views::chunk(3);                 // 직접 만들려면 view, iterator, sentinel 세 타입
+    // for (auto chunk : v) { for (int x : chunk) ... }
+
+    let chunks: Vec<Vec<i32>> = (1..=7).chunks_of(3).collect();
+    println!("(1..=7).chunks_of(3) = {:?}", chunks);
+
+    // std 어댑터와 자유롭게 섞임 - 확장 트레이트를 use 하면 모든 Iterator 에 메서드가 생김
+    let sums: Vec<i32> = (1..=10)
+        .filter(|n| n % 2 == 1)
+        .chunks_of(2)
+        .map(|pair| pair.iter().sum())
+        .collect();
+    println!("홀수를 둘씩 더하면 = {:?}", sums);
+
+    // 게으름 - 만들기만 해서는 아무것도 꺼내지 않고, 꺼낼 때도 필요한 만큼만
+    let mut pulled = 0;
+    let lazy = (1..=6).inspect(|_| pulled += 1).chunks_of(4);
+    let first: Vec<Vec<i32>> = lazy.take(1).collect();
+    println!("첫 묶음만 꺼냄: {:?}, 안쪽에서 꺼낸 원소 {}개 (6개 중)", first, pulled);
+}
+
+// ----------------------------------------------------------------------------
+// chunks_of - 상태는 크기 하나
+// ----------------------------------------------------------------------------
+// next 마다 by_ref().take(size) 로 최대 size 개를 꺼냄 - 빈 Vec 이면 끝
+// 슬라이스에는 이미 chunks 가 있지만 (25장) 그건 길이를 아는 메모리 위에서만 - 여기는 아무 이터레이터나
+// ExactSizeIterator 는 안쪽이 정확할 때만 정확 - 조건부 impl 로 그대로 전달
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksOf<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for ChunksOf<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(self.size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.div_ceil(self.size), hi.map(|hi| hi.div_ceil(self.size)))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for ChunksOf<I> {}
+
+// 안쪽이 끝난 뒤 계속 None 이면 우리도 계속 None - 조건 없이 붙이면 거짓 약속
+impl<I: FusedIterator> FusedIterator for ChunksOf<I> {}
+
+fn chunks_of() {
+    println!("\n--- chunks_of ---");
+
+    // C++ 에서는 (C++23):
+    // for (auto chunk : lines | std::views::chunk(2)) { ... }           // chunk 는 원래 범위의 subrange (복사 없음)
+
+    let lines = ["GET /", "200", "GET /about", "404", "POST /login"];
+    for (i, pair) in lines.iter().chunks_of(2).enumerate() {
+        println!("  묶음 {}: {:?}", i, pair);
+    }
+
+    // 나누어떨어지지 않으면 마지막이 짧음, 비어 있으면 묶음도 없음
+    println!("7 개를 3 씩 = {:?}", (0..7).chunks_of(3).map(|c| c.len()).collect::<Vec<_>>());
+    println!("빈 이터레이터 = {:?}", std::iter::empty::<u8>().chunks_of(3).count());
+
+    // 안쪽이 ExactSizeIterator 면 len() 도 - 배치 개수를 미리 알 때
+    let batches = (0..1000).chunks_of(64);
+    println!("1000 개를 64 씩 = {} 묶음, size_hint {:?}", batches.len(), batches.size_hint());
+}
+
+// ----------------------------------------------------------------------------
+// dedup_by - 한 칸 앞을 엿보기 (Peekable::next_if)
+// ----------------------------------------------------------------------------
+// 하나를 꺼내고, 다음 원소가 "같으면" next_if 로 꺼내서 버림 - 다르면 엿본 채로 남겨 둠
+// 비교 기준은 남긴 원소 (Vec::dedup_by 와 같음, 단 인자 순서는 Vec 과 반대로 (남긴 것, 다음)) - 1, 2, 3 을 |a, b| b - a <= 1 로 줄이면 1 과 3 은 남음
+// Peekable 은 엿본 원소 하나를 들고 있으므로 구조체에 I 가 아니라 Peekable<I> 를 둠
+// 주의: 엿보기는 안쪽의 next 를 한 번 먼저 부름 - inspect 나 I/O 처럼 부수 효과가 있으면 시점이 앞당겨짐
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupBy<I: Iterator, F> {
+    iter: Peekable<I>,
+    same: F,
+}
+
+impl<I, F> Iterator for DedupBy<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let kept = self.iter.next()?;
+        while self.iter.next_if(|next| (self.same)(&kept, next)).is_some() {}
+        Some(kept)
+    }
+
+    // 남은 게 하나라도 있으면 적어도 하나는 나옴, 모두 다르면 전부 나옴
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.min(1), hi)
+    }
+}
+
+impl<I: FusedIterator, F> FusedIterator for DedupBy<I, F> where F: FnMut(&I::Item, &I::Item) -> bool {}
+
+fn dedup_by() {
+    println!("\n--- dedup_by ---");
+
+    // C++ 에서는:
+    // auto last = std::unique(v.begin(), v.end(), pred); v.erase(last, v.end());   // 컨테이너 안에서 - 흐르는 데이터에는 직접 루프
+
+    // 센서가 같은 값을 반복해서 보냄 - 바뀔 때만 기록
+    let readings = [20, 20, 21, 21, 21, 20, 22, 22];
+    let changes: Vec<i32> = readings.iter().copied().dedup_by(|a, b| a == b).collect();
+    println!("바뀔 때만: {:?}", changes);
+
+    // 기준은 남긴 원소 - 1 만큼 흔들리는 것은 같은 값으로 봄
+    let jitter: Vec<i32> = [10i32, 11, 12, 13, 20, 21].into_iter().dedup_by(|kept, next| (next - kept).abs() <= 1).collect();
+    println!("남긴 값과 1 이내면 버림: {:?}", jitter);
+
+    // 대소문자 무시 - 소유하지 않은 &str 도 그대로
+    let words = ["Error", "error", "ERROR", "warn", "Error"];
+    let squeezed: Vec<&str> = words.into_iter().dedup_by(|a, b| a.eq_ignore_ascii_case(b)).collect();
+    println!("대소문자 무시: {:?}", squeezed);
+}
+
+// ----------------------------------------------------------------------------
+// group_by - 연속한 같은 키를 (키, Vec) 으로
+// ----------------------------------------------------------------------------
+// dedup_by 와 같은 뼈대에서 버리던 원소를 모음 - 키는 next_if 안에서 원소마다 계산
+//   경계 원소의 키는 두 번 계산됨 (엿볼 때, 다음 그룹의 첫 원소일 때) - 비싸면 키를 필드에 보관
+// 그룹을 Vec 대신 빌린 하위 이터레이터로 줄 수는 없음:
+//   type Item 은 next(&mut self) 의 빌림과 무관한 타입이라 self 를 빌린 값을 돌려줄 수 없음 ("lending iterator")
+//   itertools::chunk_by 는 RefCell 과 버퍼로 이 제약을 돌아감 - 여기서는 단순하게 모음
+// 슬라이스라면 할당 없는 slice::chunk_by 가 있음 (메모리를 가리키는 &[T] 를 주면 되므로)
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct GroupBy<I: Iterator, F> {
+    iter: Peekable<I>,
+    key: F,
+}
+
+impl<I, K, F> Iterator for GroupBy<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let key = (self.key)(&first);
+        let mut group = vec![first];
+        while let Some(item) = self.iter.next_if(|item| (self.key)(item) == key) {
+            group.push(item);
+        }
+        Some((key, group))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.min(1), hi)
+    }
+}
+
+impl<I: FusedIterator, K: PartialEq, F: FnMut(&I::Item) -> K> FusedIterator for GroupBy<I, F> {}
+
+fn group_by() {
+    println!("\n--- group_by ---");
+
+    // C++ 에서는 (C++23):
+    // for (auto run : logs | std::views::chunk_by([](auto& a, auto& b) { return a.level == b.level; })) { ... }
+
+    let logs = [
+        ("INFO", "start"),
+        ("INFO", "listen :8080"),
+        ("WARN", "slow query"),
+        ("INFO", "request /"),
+        ("INFO", "request /about"),
+        ("INFO", "request /login"),
+    ];
+    for (level, run) in logs.iter().group_by(|(level, _)| *level) {
+        let messages: Vec<&str> = run.iter().map(|(_, message)| *message).collect();
+        println!("  {} x{}: {:?}", level, run.len(), messages);
+    }
+
+    // 연속한 것만 묶음 - 전체를 키별로 모으려면 먼저 정렬하거나 HashMap (10장)
+    let parity: Vec<(bool, usize)> = [1, 3, 5, 2, 4, 7]
+        .into_iter()
+        .group_by(|n| n % 2 == 0)
+        .map(|(even, run)| (even, run.len()))
+        .collect();
+    println!("(짝수인가, 길이) 묶음 = {:?}", parity);
+
+    // 글자 종류로 토큰 나누기 - 키가 enum 이어도 PartialEq 면 충분
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    enum Kind {
+        Digit,
+        Alpha,
+        Other,
+    }
+    let kind = |c: &char| match c {
+        '0'..='9' => Kind::Digit,
+        c if c.is_alphabetic() => Kind::Alpha,
+        _ => Kind::Other,
+    };
+    let tokens: Vec<String> = "abc123+x9".chars().group_by(kind).map(|(_, run)| run.into_iter().collect()).collect();
+    println!("\"abc123+x9\" 토큰 = {:?}", tokens);
+}
+
+// ----------------------------------------------------------------------------
+// intersperse - 상태 기계 하나 (needs_sep)
+// ----------------------------------------------------------------------------
+// 원소를 내보낸 뒤에는 needs_sep = true - 다음 호출에서 뒤에 원소가 더 있을 때만 구분자
+//   "뒤에 더 있나" 는 peek 로 - 마지막 원소 뒤에 구분자가 붙지 않는 이유
+// size_hint: 남은 원소 n 개에 구분자가 n 개(needs_sep) 또는 n - 1 개 - 넘치면 상한은 None
+// join 과의 차이: join 은 문자열을 만들어 버림, 이쪽은 아무 타입의 흐름에 끼워 넣고 계속 체인
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I: Iterator> {
+    iter: Peekable<I>,
+    sep: I::Item,
+    needs_sep: bool,
+}
+
+impl<I> Iterator for Intersperse<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.needs_sep && self.iter.peek().is_some() {
+            self.needs_sep = false;
+            return Some(self.sep.clone());
+        }
+        let item = self.iter.next()?;
+        self.needs_sep = true;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let seps = |n: usize| if self.needs_sep { n } else { n.saturating_sub(1) };
+        (lo.saturating_add(seps(lo)), hi.and_then(|hi| hi.checked_add(seps(hi))))
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for Intersperse<I> where I::Item: Clone {}
+
+fn intersperse() {
+    println!("\n--- intersperse ---");
+
+    // C++ 에서는 (C++23):
+    // auto v = words | std::views::join_with(std::string_view(", "));    // 범위의 범위를 펼치면서 끼움 - 원소 하나짜리 끼우기는 직접
+
+    let words = ["alpha", "beta", "gamma"];
+    let line: String = words.iter().copied().interspersed(", ").collect();
+    println!("이어 붙임 = {}", line);
+
+    // 문자열이 아닌 흐름에도 - 패킷 사이에 구분 바이트
+    let framed: Vec<u8> = [[1u8, 2], [3, 4], [5, 6]].into_iter().flatten().chunks_of(2).interspersed(vec![0xFF]).flatten().collect();
+    println!("구분 바이트를 끼움 = {:02X?}", framed);
+
+    // 원소가 하나거나 없으면 구분자도 없음
+    println!("하나 = {:?}, 없음 = {:?}", [7].into_iter().interspersed(0).collect::<Vec<_>>(), std::iter::empty::<i32>().interspersed(0).count());
+
+    // size_hint 가 정확하면 collect 가 한 번에 알맞은 용량을 잡음
+    let spaced = (1..=4).interspersed(0);
+    println!("(1..=4) size_hint {:?} -> {:?}", spaced.size_hint(), spaced.collect::<Vec<_>>());
+}
+
+// ----------------------------------------------------------------------------
+// size_hint 와 FusedIterator
+// ----------------------------------------------------------------------------
+// size_hint 의 약속: 하한 <= 실제로 남은 개수 <= 상한 (None 은 "모름")
+//   기본 구현은 (0, None) - 틀린 값은 아님, collect 가 용량을 예측하지 못할 뿐
+//   틀린 값은 UB 는 아니지만 버그 - Vec 이 너무 크게 잡거나, ExactSizeIterator::len 이 거짓말을 함
+//   안쪽 하한이 걸러질 수 있는 어댑터(filter, dedup_by) 는 하한을 낮춰야 함
+// FusedIterator: None 을 한 번 돌려준 뒤로는 영원히 None 이라는 표시 (메서드 없는 마커 트레이트)
+//   Iterator 의 약속에는 이것이 없음 - 채널의 try_recv 처럼 나중에 다시 Some 을 줄 수도
+//   .fuse() 는 Fuse 어댑터로 감싸는데 FusedIterator 인 타입에는 검사 비용 없이 그대로 통과
+//   어댑터는 "안쪽이 Fused 일 때만 Fused" - 위의 조건부 impl 들
+
+// None 을 준 뒤에도 다시 값을 주는 원천 - 폴링하는 큐를 흉내 냄
+struct Flaky {
+    script: std::vec::IntoIter<Option<i32>>,
+}
+
+impl Iterator for Flaky {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        self.script.next().flatten()
+    }
+}
+
+fn flaky() -> Flaky {
+    Flaky { script: vec![Some(1), Some(2), None, Some(3), Some(4), Some(5)].into_iter() }
+}
+
+// 다 쓰기 전에 size_hint 를 받아 두고, 세어 본 실제 개수가 범위 안인지
+fn hint_holds<I: Iterator>(iter: I) -> bool {
+    let (lo, hi) = iter.size_hint();
+    let n = iter.count();
+    lo <= n && hi.is_none_or(|hi| n <= hi)
+}
+
+fn size_hint_fused() {
+    println!("\n--- size_hint 와 FusedIterator ---");
+
+    // C++ 에서는:
+    // std::ranges::size(r)           // sized_range 일 때만 있음 - 추정치라는 개념은 없음
+    // ++it; (it == end 이후)         // UB - Rust 는 None 다음 next 도 안전, 대신 값이 보장되지 않음
+
+    println!("{:<28} {:?}", "(1..=10).chunks_of(3)", (1..=10).chunks_of(3).size_hint());
+    println!("{:<28} {:?}", "filter(..).chunks_of(3)", (1..=10).filter(|n| n % 3 == 0).chunks_of(3).size_hint());
+    println!("{:<28} {:?}", "(1..=10).dedup_by(..)", (1..=10).dedup_by(|a, b| a == b).size_hint());
+    println!("{:<28} {:?}", "(1..=10).interspersed(0)", (1..=10).interspersed(0).size_hint());
+    println!("{:<28} {:?}", "(1..).interspersed(0)", (1..).interspersed(0).size_hint());
+
+    let all_hold = hint_holds((1..=10).chunks_of(3))
+        && hint_holds((1..=10).filter(|n| n % 3 == 0).chunks_of(3))
+        && hint_holds([1, 1, 2].into_iter().dedup_by(|a, b| a == b))
+        && hint_holds([1, 1, 2].into_iter().group_by(|n| *n))
+        && hint_holds((1..=10).interspersed(0));
+    println!("모두 하한 <= 개수 <= 상한: {}", all_hold);
+
+    // Fused 가 아닌 원천 - None 다음에도 값이 나옴
+    let mut source = flaky();
+    let first_pass: Vec<i32> = source.by_ref().collect();
+    let second_pass: Vec<i32> = source.collect();
+    println!("Flaky: {:?} 그리고 다시 {:?}", first_pass, second_pass);
+
+    // 어댑터는 안쪽의 성질을 그대로 드러냄 - chunks_of 도 None 다음에 다시 묶음을 줌
+    let mut chunks = flaky().chunks_of(2);
+    println!("Flaky 위의 chunks_of: {:?} {:?} {:?} {:?}", chunks.next(), chunks.next(), chunks.next(), chunks.next());
+
+    // .fuse() 를 안쪽에 걸면 첫 None 에서 영원히 끝
+    let mut fused = flaky().fuse().chunks_of(2);
+    println!("fuse() 뒤의 chunks_of:   {:?} {:?} {:?}", fused.next(), fused.next(), fused.next());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adapters_match_std_and_keep_their_hints() {
+        // slice::chunks, slice::chunk_by 와 같은 결과
+        let data = [1, 1, 2, 3, 3, 3, 1];
+        let by_slice: Vec<Vec<i32>> = data.chunks(3).map(<[i32]>::to_vec).collect();
+        assert_eq!(data.iter().copied().chunks_of(3).collect::<Vec<_>>(), by_slice);
+        let runs: Vec<Vec<i32>> = data.chunk_by(|a, b| a == b).map(<[i32]>::to_vec).collect();
+        assert_eq!(data.iter().copied().group_by(|n| *n).map(|(_, run)| run).collect::<Vec<_>>(), runs);
+
+        let mut deduped = data.to_vec();
+        deduped.dedup();
+        assert_eq!(data.iter().copied().dedup_by(|a, b| a == b).collect::<Vec<_>>(), deduped);
+
+        let line: String = ["a", "b", "c"].into_iter().interspersed("-").collect();
+        assert_eq!(line, "a-b-c");
+
+        // 중간에 멈춰도 size_hint 가 남은 개수와 맞음
+        let mut spaced = (1..=3).interspersed(0);
+        for remaining in (0..=5).rev() {
+            assert_eq!(spaced.size_hint(), (remaining, Some(remaining)));
+            spaced.next();
+        }
+        let mut chunks = (0..10).chunks_of(4);
+        assert_eq!(chunks.len(), 3);
+        chunks.next();
+        assert_eq!(chunks.len(), 2);
+
+        // Fused 가 아닌 원천 위에서는 어댑터도 다시 깨어남 - fuse() 를 걸면 끝
+        let mut woken = flaky().chunks_of(2);
+        assert_eq!((woken.next(), woken.next(), woken.next()), (Some(vec![1, 2]), None, Some(vec![3, 4])));
+        let mut fused = flaky().fuse().chunks_of(2);
+        assert_eq!((fused.next(), fused.next(), fused.next()), (Some(vec![1, 2]), None, None));
+    }
+
+    #[test]
+    #[should_panic(expected = "size 는 1 이상")]
+    fn zero_sized_chunks_panic() {
+        let _ = (0..3).chunks_of(0);
+    }
+}
@@ -0,0 +1,255 @@
+// ============================================================================
+// 장/절마다 힙 할당 횟수와 바이트 (--profile-alloc)
+// ============================================================================
+// cargo run -- --profile-alloc 10 12     컬렉션, 스마트 포인터 장이 얼마나 할당하는지
+// cargo run -- --profile-alloc all       끝에 장별, 절별 표와 가장 많이 할당한 절
+//
+// #[global_allocator] 로 System 할당자를 감싸 alloc/realloc 을 셈 (12장의 Box, 10장의 Vec 이 실제로 부르는 곳)
+//   C++: 전역 operator new 를 바꿔 세는 것과 같은 방법
+//   꺼져 있으면 원자 변수 하나를 읽고 바로 System 으로 - 카운터는 켜졌을 때만 올림
+// 절이 만든 스레드, tokio 작업의 할당도 세도록 스레드별이 아닌 전역 카운터
+//   → 여러 장을 동시에 실행하는 --parallel 과는 함께 쓸 수 없음
+// println!(i18n.rs) 의 서식화, 번역, 색, 페이저 버퍼 할당은 빼고 셈 - 장 코드가 만든 할당만
+// ============================================================================
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ops::Sub;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use rust_study::output;
+
+use crate::chapter::Chapter;
+use crate::i18n;
+
+// 가장 많이 할당한 절을 몇 개 보여 줄지
+const TOP: usize = 5;
+const BAR_WIDTH: usize = 20;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    // 이 스레드의 할당을 잠시 세지 않음 - 할당자 안에서 읽으므로 할당 없는 const 초기화
+    static PAUSED: Cell<bool> = const { Cell::new(false) };
+}
+
+pub struct Counting;
+
+// SAFETY: 할당과 해제는 그대로 System 에 맡기고, 그 앞에서 카운터만 올림
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    // Vec 이 자라는 것도 할당 한 번 (늘어난 크기만큼)
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size.saturating_sub(layout.size()));
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn count(bytes: usize) {
+    // 스레드가 끝나는 중이라 읽을 수 없으면 세지 않음
+    if ENABLED.load(Ordering::Relaxed) && !PAUSED.try_with(Cell::get).unwrap_or(true) {
+        ALLOCS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub allocs: u64,
+    pub bytes: u64,
+}
+
+impl Sub for Counts {
+    type Output = Counts;
+
+    fn sub(self, before: Counts) -> Counts {
+        Counts {
+            allocs: self.allocs - before.allocs,
+            bytes: self.bytes - before.bytes,
+        }
+    }
+}
+
+// 지금까지 센 값 - 절 앞뒤의 차이가 그 절의 할당
+pub fn snapshot() -> Counts {
+    Counts {
+        allocs: ALLOCS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+    }
+}
+
+// 살아 있는 동안 이 스레드의 할당을 세지 않음 (겹쳐도 됨)
+pub struct Pause(bool);
+
+pub fn pause() -> Pause {
+    Pause(PAUSED.with(|p| p.replace(true)))
+}
+
+impl Drop for Pause {
+    fn drop(&mut self) {
+        PAUSED.with(|p| p.set(self.0));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub chapter: &'static str,
+    pub name: &'static str,
+    // (절 이름, 할당) - 실행 순서
+    pub sections: Vec<(&'static str, Counts)>,
+}
+
+impl Record {
+    fn total(&self) -> Counts {
+        self.sections.iter().fold(Counts::default(), |sum, (_, c)| Counts {
+            allocs: sum.allocs + c.allocs,
+            bytes: sum.bytes + c.bytes,
+        })
+    }
+}
+
+// None = 꺼짐, Some = 이번 실행에서 잰 장들
+static RECORDS: Mutex<Option<Vec<Record>>> = Mutex::new(None);
+
+fn state() -> std::sync::MutexGuard<'static, Option<Vec<Record>>> {
+    RECORDS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn enable() {
+    state().get_or_insert_with(Vec::new);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+// 명령줄의 --profile-alloc 을 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> (bool, Vec<String>) {
+    let rest: Vec<String> = args.iter().filter(|a| *a != "--profile-alloc").cloned().collect();
+    (rest.len() != args.len(), rest)
+}
+
+// 장 하나(또는 절 하나만)의 할당 - 꺼져 있으면 버림
+pub fn record(chapter: &dyn Chapter, sections: Vec<(&'static str, Counts)>) {
+    if let Some(records) = state().as_mut() {
+        records.push(Record {
+            chapter: chapter.id(),
+            name: chapter.name(),
+            sections,
+        });
+    }
+}
+
+fn bytes(n: u64) -> String {
+    match n {
+        0..=1023 => format!("{} B", n),
+        1024..=1_048_575 => format!("{:.1} KB", n as f64 / 1024.0),
+        _ => format!("{:.1} MB", n as f64 / 1_048_576.0),
+    }
+}
+
+// 한글은 두 칸으로 세어 열을 맞춤 (timing 의 표와 같은 방식)
+fn pad(text: &str, width: usize) -> String {
+    let used: usize = text.chars().map(|c| if c.is_ascii() { 1 } else { 2 }).sum();
+    format!("{}{}", text, " ".repeat(width.saturating_sub(used)))
+}
+
+// 장마다 횟수, 바이트, 막대와 절별 할당 - 끝에 가장 많이 할당한 절
+pub fn summary(records: &[Record]) -> String {
+    let max = records.iter().map(|r| r.total().allocs).max().unwrap_or_default();
+    let mut out = tr!("\n=== 힙 할당 (--profile-alloc) ===\n");
+    for r in records {
+        let total = r.total();
+        out.push_str(&tr!(
+            "{:>2}. {} {:>8}회 {:>10}  {}\n",
+            r.chapter,
+            pad(&i18n::chapter_name(r.chapter, r.name), 32),
+            total.allocs,
+            bytes(total.bytes),
+            output::bar(total.allocs, max, BAR_WIDTH)
+        ));
+        for (name, c) in &r.sections {
+            out.push_str(&tr!("      {:<30} {:>8}회 {:>10}\n", name, c.allocs, bytes(c.bytes)));
+        }
+    }
+    let all: u64 = records.iter().map(|r| r.total().allocs).sum();
+    let all_bytes: u64 = records.iter().map(|r| r.total().bytes).sum();
+    out.push_str(&tr!("합계 {}개 장 {}회 {}\n", records.len(), all, bytes(all_bytes)));
+
+    let mut sections: Vec<(String, Counts)> = records
+        .iter()
+        .flat_map(|r| r.sections.iter().map(|(name, c)| (format!("{}::{}", r.chapter, name), *c)))
+        .collect();
+    if sections.len() > 1 {
+        sections.sort_by(|a, b| b.1.allocs.cmp(&a.1.allocs).then_with(|| a.0.cmp(&b.0)));
+        out.push_str(&tr!("\n가장 많이 할당한 절\n"));
+        for (i, (key, c)) in sections.iter().take(TOP).enumerate() {
+            out.push_str(&tr!("  {}. {:<32} {:>8}회 {:>10}\n", i + 1, key, c.allocs, bytes(c.bytes)));
+        }
+    }
+    out
+}
+
+// 켜져 있고 잰 것이 있으면 표 출력
+pub fn print_summary() {
+    let records = state().clone().unwrap_or_default();
+    if !records.is_empty() {
+        print!("{}", summary(&records));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_unless_paused() {
+        ENABLED.store(true, Ordering::Relaxed);
+        let before = snapshot();
+        let boxed = std::hint::black_box(Box::new([0u8; 100]));
+        let used = snapshot() - before;
+        drop(boxed);
+        // 다른 테스트 스레드의 할당도 섞일 수 있어 최소값만 확인
+        assert!(used.allocs >= 1 && used.bytes >= 100, "{:?}", used);
+
+        let outer = pause();
+        assert!(PAUSED.with(Cell::get));
+        drop(pause());
+        // 겹쳐 쓴 pause 가 끝나도 바깥 pause 는 그대로
+        assert!(PAUSED.with(Cell::get));
+        drop(outer);
+        assert!(!PAUSED.with(Cell::get));
+    }
+
+    #[test]
+    fn summary_lists_chapters_and_top_sections() {
+        let c = |allocs, bytes| Counts { allocs, bytes };
+        let records = vec![
+            Record { chapter: "10", name: "컬렉션", sections: vec![("vectors", c(12, 2048)), ("hashmaps", c(30, 512))] },
+            Record { chapter: "12", name: "스마트 포인터", sections: vec![("box_pointer", c(3, 48))] },
+        ];
+        let text = summary(&records);
+        assert!(text.contains("10. 컬렉션"));
+        assert!(text.contains("42회     2.5 KB"));
+        assert!(text.contains("합계 2개 장 45회 2.5 KB"));
+        let top = text.split("가장 많이 할당한 절").nth(1).unwrap();
+        assert!(top.find("10::hashmaps").unwrap() < top.find("10::vectors").unwrap());
+        assert_eq!(bytes(3 * 1_048_576), "3.0 MB");
+    }
+}
@@ -0,0 +1,317 @@
+// ============================================================================
+// JSON API (serve 모드)
+// ============================================================================
+// 외부 프런트엔드(모바일 앱, VS Code 확장 등)가 과정을 진행할 수 있도록
+// 진도 저장소(progress.json)를 상태로 쓰는 JSON 엔드포인트
+//
+//   GET  /lessons            장 목록 - 버전, 완료 여부, 퀴즈 최고 점수, 절 이름
+//   GET  /lessons/12         장의 블록 (머리, 설명, 코드, C++ 비교)
+//   GET  /lessons/12/run     절 예제를 모두 실행하고 출력을 모아서
+//   GET  /quiz/07            문제 (정답 없이)
+//   POST /quiz/07            {"answers": {"07-dyn": 1, ...}} → 채점, 진도에 기록
+//   GET  /progress           진도와 완료 후 갱신된 장
+//
+// 에러도 JSON: {"error": "..."}
+// ============================================================================
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::chapters::{self, ChapterInfo};
+use crate::content::{self, ContentBank};
+use crate::output;
+use crate::progress::{self, Progress, QuizScore};
+use crate::sections;
+use crate::serve::Reply;
+
+// 동시에 들어온 퀴즈 제출이 서로의 기록을 덮어쓰지 않도록
+static PROGRESS_LOCK: Mutex<()> = Mutex::new(());
+
+fn json_reply(status: u16, value: Value) -> Reply {
+    Reply::Json {
+        status,
+        body: value.to_string(),
+    }
+}
+
+fn error(status: u16, message: impl Into<String>) -> Reply {
+    json_reply(status, json!({ "error": message.into() }))
+}
+
+// API 경로가 아니면 None - serve 의 HTML 페이지로 넘어감
+pub fn route(method: &str, path: &str, body: &str, progress_path: &Path) -> Option<Reply> {
+    let parts: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let reply = match (method, parts.as_slice()) {
+        ("GET", ["lessons"]) => with_bank(|bank| lessons(bank, progress_path)),
+        ("GET", ["lessons", key]) => with_chapter(key, lesson),
+        ("GET", ["lessons", key, "run"]) => with_chapter(key, |info| {
+            let names = sections::sections(info)
+                .iter()
+                .map(|s| s.key().replace("::", "_"))
+                .collect();
+            Reply::RunJson(names)
+        }),
+        ("GET", ["quiz", key]) => with_chapter(key, |info| with_bank(|bank| quiz(info, bank))),
+        ("POST", ["quiz", key]) => with_chapter(key, |info| {
+            with_bank(|bank| submit(info, bank, body, progress_path))
+        }),
+        ("GET", ["progress"]) => with_bank(|bank| progress(bank, progress_path)),
+        (_, ["lessons", ..] | ["quiz", ..] | ["progress"]) => {
+            error(405, format!("{} {} 는 지원하지 않습니다", method, path))
+        }
+        _ => return None,
+    };
+    Some(reply)
+}
+
+fn with_chapter(key: &str, f: impl FnOnce(&'static ChapterInfo) -> Reply) -> Reply {
+    match chapters::find(key) {
+        Some(info) => f(info),
+        None => error(404, format!("없는 장: {}", key)),
+    }
+}
+
+fn with_bank(f: impl FnOnce(&ContentBank) -> Reply) -> Reply {
+    match content::source().load() {
+        Ok(bank) => f(&bank),
+        Err(e) => error(500, e.to_string()),
+    }
+}
+
+fn load_progress(path: &Path) -> Result<Progress, Reply> {
+    Progress::load(path).map_err(|e| error(500, format!("진도를 읽을 수 없습니다: {}", e)))
+}
+
+fn version_of(bank: &ContentBank, id: &str) -> u32 {
+    bank.chapter(id).map_or(1, |c| c.version)
+}
+
+fn lessons(bank: &ContentBank, progress_path: &Path) -> Reply {
+    let store = match load_progress(progress_path) {
+        Ok(store) => store,
+        Err(reply) => return reply,
+    };
+    let list: Vec<Value> = chapters::CHAPTERS
+        .iter()
+        .map(|info| {
+            let version = version_of(bank, info.id);
+            let sections: Vec<String> = sections::sections(info)
+                .into_iter()
+                .map(|s| s.name)
+                .collect();
+            json!({
+                "id": info.id,
+                "title": info.title,
+                "version": version,
+                "completed": store.completed.contains_key(info.id),
+                "updated_since_completion": store.updated_since_completion(info.id, version),
+                "quiz_best": store.quiz_best.get(info.id),
+                "sections": sections,
+            })
+        })
+        .collect();
+    json_reply(200, Value::from(list))
+}
+
+fn lesson(info: &'static ChapterInfo) -> Reply {
+    let Some(source) = chapters::source(info.id) else {
+        return error(404, format!("{}장의 소스가 없습니다", info.id));
+    };
+    let prose = match content::source().load_prose(info) {
+        Ok(prose) => prose,
+        Err(e) => return error(500, e.to_string()),
+    };
+    json_reply(
+        200,
+        json!({
+            "id": info.id,
+            "title": info.title,
+            "blocks": output::lesson_blocks(info.id, source, &prose),
+        }),
+    )
+}
+
+fn quiz(info: &ChapterInfo, bank: &ContentBank) -> Reply {
+    let Some(chapter) = bank.chapter(info.id).filter(|c| !c.questions.is_empty()) else {
+        return error(404, format!("{}장에는 아직 퀴즈가 없습니다", info.id));
+    };
+    // 정답과 설명은 제출한 뒤에만
+    let questions: Vec<Value> = chapter
+        .questions
+        .iter()
+        .map(|q| json!({ "id": q.id, "prompt": q.prompt, "choices": q.choices, "hint": q.hint }))
+        .collect();
+    json_reply(
+        200,
+        json!({
+            "chapter": info.id,
+            "title": info.title,
+            "version": chapter.version,
+            "questions": questions,
+        }),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+struct Submission {
+    // 문제 id → 고른 보기 (0부터), 빠진 문제는 오답
+    answers: std::collections::HashMap<String, usize>,
+}
+
+fn submit(info: &ChapterInfo, bank: &ContentBank, body: &str, progress_path: &Path) -> Reply {
+    let submission: Submission = match serde_json::from_str(body) {
+        Ok(s) => s,
+        Err(e) => return error(400, format!("잘못된 요청 본문: {}", e)),
+    };
+    let Some(chapter) = bank.chapter(info.id).filter(|c| !c.questions.is_empty()) else {
+        return error(404, format!("{}장에는 아직 퀴즈가 없습니다", info.id));
+    };
+
+    let results: Vec<Value> = chapter
+        .questions
+        .iter()
+        .map(|q| {
+            let picked = submission.answers.get(&q.id).copied();
+            json!({
+                "id": q.id,
+                "picked": picked,
+                "correct": picked == Some(q.answer),
+                "answer": q.answer,
+                "explanation": q.explanation,
+            })
+        })
+        .collect();
+    let score = QuizScore {
+        correct: results.iter().filter(|r| r["correct"] == true).count(),
+        total: results.len(),
+        at: progress::now(),
+    };
+
+    let _guard = PROGRESS_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut store = match load_progress(progress_path) {
+        Ok(store) => store,
+        Err(reply) => return reply,
+    };
+    let improved = store.record_quiz(info.id, score, chapter.version);
+    if let Err(e) = store.save(progress_path) {
+        return error(500, format!("진도를 저장할 수 없습니다: {}", e));
+    }
+    json_reply(
+        200,
+        json!({
+            "correct": score.correct,
+            "total": score.total,
+            "percent": score.percent(),
+            "passed": score.percent() >= progress::PASS_PERCENT,
+            "improved": improved,
+            "results": results,
+        }),
+    )
+}
+
+fn progress(bank: &ContentBank, progress_path: &Path) -> Reply {
+    let store = match load_progress(progress_path) {
+        Ok(store) => store,
+        Err(reply) => return reply,
+    };
+    let updated: Vec<&str> = chapters::CHAPTERS
+        .iter()
+        .map(|c| c.id)
+        .filter(|id| store.updated_since_completion(id, version_of(bank, id)))
+        .collect();
+    json_reply(
+        200,
+        json!({
+            "completed": store.completed,
+            "quiz_best": store.quiz_best,
+            "updated_since_completion": updated,
+            "total_chapters": chapters::CHAPTERS.len(),
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_progress(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("api-test-{}-{}", name, std::process::id()))
+            .join("progress.json")
+    }
+
+    fn call(method: &str, path: &str, body: &str, progress_path: &Path) -> (u16, Value) {
+        match route(method, path, body, progress_path) {
+            Some(Reply::Json { status, body }) => (status, serde_json::from_str(&body).unwrap()),
+            other => panic!("JSON 응답이 아님: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lessons_list_and_detail() {
+        let path = temp_progress("lessons");
+        let (status, list) = call("GET", "/lessons", "", &path);
+        assert_eq!(status, 200);
+        assert_eq!(list.as_array().unwrap().len(), chapters::CHAPTERS.len());
+        assert_eq!(list[6]["id"], "07");
+        assert_eq!(list[6]["completed"], false);
+        assert_eq!(list[6]["sections"][0], "basic_traits");
+
+        let (status, lesson) = call("GET", "/lessons/traits", "", &path);
+        assert_eq!(status, 200);
+        assert_eq!(lesson["blocks"][0]["header"]["level"], 1);
+        assert!(lesson["blocks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|b| b.get("comparison").is_some()));
+
+        assert_eq!(call("GET", "/lessons/99", "", &path).0, 404);
+        assert_eq!(call("DELETE", "/lessons", "", &path).0, 405);
+        assert!(route("GET", "/lesson/07", "", &path).is_none());
+    }
+
+    #[test]
+    fn run_returns_section_examples() {
+        let path = temp_progress("run");
+        match route("GET", "/lessons/07/run", "", &path) {
+            Some(Reply::RunJson(names)) => assert_eq!(names[0], "07_basic_traits"),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn quiz_submission_is_graded_and_recorded() {
+        let path = temp_progress("quiz");
+        let (_, quiz) = call("GET", "/quiz/02", "", &path);
+        let questions = quiz["questions"].as_array().unwrap();
+        assert!(questions[0].get("answer").is_none());
+
+        // 정답을 모두 맞히면 장 완료로 기록
+        let bank = content::source().load().unwrap();
+        let answers: serde_json::Map<String, Value> = bank
+            .chapter("02")
+            .unwrap()
+            .questions
+            .iter()
+            .map(|q| (q.id.clone(), Value::from(q.answer)))
+            .collect();
+        let body = json!({ "answers": answers }).to_string();
+        let (status, result) = call("POST", "/quiz/02", &body, &path);
+        assert_eq!(status, 200);
+        assert_eq!(result["correct"], result["total"]);
+        assert_eq!(result["passed"], true);
+        assert_eq!(result["improved"], true);
+
+        let (_, progress) = call("GET", "/progress", "", &path);
+        assert!(progress["completed"].get("02").is_some());
+        assert_eq!(progress["quiz_best"]["02"]["correct"], result["correct"]);
+
+        assert_eq!(call("POST", "/quiz/02", "not json", &path).0, 400);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -0,0 +1,271 @@
+// ============================================================================
+// 벤치마크 결과 요약 (criterion)
+// ============================================================================
+// cargo run -- bench-report                      target/criterion 의 결과를 표로 (cargo bench --bench <benches/ 의 파일> 뒤에)
+// cargo run -- bench-report --format markdown    문서나 이슈에 붙일 Markdown 표
+// cargo run -- bench-report --baseline main      cargo bench -- --save-baseline main 과 비교
+//
+// criterion 은 벤치마크마다 target/criterion/<그룹>/<함수>/ 아래에 결과를 남김
+//   new/benchmark.json    이름 (full_id)
+//   new/estimates.json    이번 실행의 평균 (나노초)
+//   change/estimates.json 직전 실행 대비 변화율 (없을 수 있음)
+//   <기준선>/estimates.json --save-baseline 으로 저장한 기준선
+// HTML 보고서를 뒤지지 않아도 레슨이 말하는 수치를 한눈에 보도록
+// 벤치마크 이름에 절 이름(refcell_pointer)이나 장(12, smart_pointers)이 들어 있으면 관련 레슨으로 표시
+// ============================================================================
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::chapters;
+use crate::sections;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchResult {
+    // "vec_push/1000"
+    pub id: String,
+    // 평균 실행 시간 (나노초)
+    pub mean_ns: f64,
+    // 기준선 대비 변화율 (0.05 = 5% 느려짐)
+    pub change: Option<f64>,
+    // "12::refcell_pointer" 또는 "12"
+    pub lesson: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Benchmark {
+    full_id: String,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+pub fn criterion_dir() -> PathBuf {
+    // CARGO_TARGET_DIR 로 target 위치를 바꾼 경우도
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("target"));
+    target.join("criterion")
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> io::Result<Option<T>> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    serde_json::from_str(&text)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))
+}
+
+// dir 아래의 모든 벤치마크 - 이름순
+// baseline 이 있으면 그 기준선과 비교, 없으면 criterion 이 계산한 직전 실행 대비 변화
+pub fn collect(dir: &Path, baseline: Option<&str>) -> io::Result<Vec<BenchResult>> {
+    let mut results = Vec::new();
+    visit(dir, baseline, &mut results)?;
+    results.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(results)
+}
+
+fn visit(dir: &Path, baseline: Option<&str>, results: &mut Vec<BenchResult>) -> io::Result<()> {
+    let new = dir.join("new");
+    if let (Some(bench), Some(estimates)) = (
+        read_json::<Benchmark>(&new.join("benchmark.json"))?,
+        read_json::<Estimates>(&new.join("estimates.json"))?,
+    ) {
+        let mean_ns = estimates.mean.point_estimate;
+        let change = match baseline {
+            Some(name) => read_json::<Estimates>(&dir.join(name).join("estimates.json"))?
+                .map(|base| mean_ns / base.mean.point_estimate - 1.0),
+            None => read_json::<Estimates>(&dir.join("change").join("estimates.json"))?
+                .map(|c| c.mean.point_estimate),
+        };
+        results.push(BenchResult {
+            lesson: related_lesson(&bench.full_id),
+            id: bench.full_id,
+            mean_ns,
+            change,
+        });
+        return Ok(());
+    }
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        // report/ 는 HTML 보고서
+        if path.is_dir() && path.file_name().is_some_and(|n| n != "report") {
+            visit(&path, baseline, results)?;
+        }
+    }
+    Ok(())
+}
+
+// 절 이름이 들어 있으면 그 절, 아니면 장 번호나 이름으로 시작하는 장
+pub fn related_lesson(id: &str) -> Option<String> {
+    let lower = id.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|w| !w.is_empty())
+        .collect();
+    let all: Vec<sections::Section> = chapters::CHAPTERS.iter().flat_map(sections::sections).collect();
+    // 긴 이름부터 - "iterator" 보다 "custom_iterator"
+    let mut by_length: Vec<&sections::Section> = all.iter().collect();
+    by_length.sort_by_key(|s| std::cmp::Reverse(s.name.len()));
+    if let Some(s) = by_length.iter().find(|s| {
+        words.iter().any(|w| *w == s.name || *w == format!("{}_{}", s.chapter, s.name))
+    }) {
+        return Some(s.key());
+    }
+    let first = words.first()?;
+    let chapter_key = first.split('_').next().unwrap_or(first);
+    chapters::find(first)
+        .or_else(|| chapters::find(chapter_key))
+        .map(|c| c.id.to_string())
+}
+
+// 1234.5 → "1.23 µs"
+pub fn format_time(ns: f64) -> String {
+    let (value, unit) = match ns {
+        n if n >= 1e9 => (n / 1e9, "s"),
+        n if n >= 1e6 => (n / 1e6, "ms"),
+        n if n >= 1e3 => (n / 1e3, "µs"),
+        n => (n, "ns"),
+    };
+    format!("{:.2} {}", value, unit)
+}
+
+// +5.2% (느려짐), -3.0% (빨라짐) - 2% 안쪽은 잡음으로 보고 표시만
+pub fn format_change(change: Option<f64>) -> String {
+    match change {
+        None => String::from("-"),
+        Some(c) if c.abs() < 0.02 => format!("{:+.1}%", c * 100.0),
+        Some(c) if c > 0.0 => format!("{:+.1}% 느려짐", c * 100.0),
+        Some(c) => format!("{:+.1}% 빨라짐", c * 100.0),
+    }
+}
+
+fn rows(results: &[BenchResult]) -> Vec<[String; 4]> {
+    results
+        .iter()
+        .map(|r| {
+            [
+                r.id.clone(),
+                format_time(r.mean_ns),
+                format_change(r.change),
+                r.lesson.clone().unwrap_or_else(|| String::from("-")),
+            ]
+        })
+        .collect()
+}
+
+const HEADERS: [&str; 4] = ["벤치마크", "평균", "변화", "관련 레슨"];
+
+pub fn markdown(results: &[BenchResult]) -> String {
+    let mut out = format!("| {} |\n|---|---:|---:|---|\n", HEADERS.join(" | "));
+    for row in rows(results) {
+        out.push_str(&format!("| {} |\n", row.join(" | ")));
+    }
+    out
+}
+
+// 터미널 표 - 한글은 두 칸으로 세어 열을 맞춤
+pub fn table(results: &[BenchResult]) -> String {
+    let rows = rows(results);
+    let width = |s: &str| s.chars().map(|c| if c.is_ascii() { 1 } else { 2 }).sum::<usize>();
+    let mut widths = HEADERS.map(width);
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(width(cell));
+        }
+    }
+    let line = |cells: [&str; 4]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, w)| format!("{}{}", cell, " ".repeat(w - width(cell))))
+            .collect();
+        format!("{}\n", padded.join("  ").trim_end())
+    };
+    let mut out = line(HEADERS);
+    out.push_str(&format!("{}\n", "-".repeat(widths.iter().sum::<usize>() + 6)));
+    for row in &rows {
+        out.push_str(&line([&row[0], &row[1], &row[2], &row[3]]));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_bench(root: &Path, dir: &str, id: &str, mean: f64, change: Option<f64>) {
+        let bench = root.join(dir);
+        fs::create_dir_all(bench.join("new")).unwrap();
+        fs::write(
+            bench.join("new/benchmark.json"),
+            format!("{{\"full_id\": \"{}\", \"group_id\": \"x\"}}", id),
+        )
+        .unwrap();
+        let estimates = |v: f64| format!("{{\"mean\": {{\"point_estimate\": {}, \"standard_error\": 1.0}}}}", v);
+        fs::write(bench.join("new/estimates.json"), estimates(mean)).unwrap();
+        fs::create_dir_all(bench.join("base")).unwrap();
+        fs::write(bench.join("base/estimates.json"), estimates(mean / 2.0)).unwrap();
+        if let Some(c) = change {
+            fs::create_dir_all(bench.join("change")).unwrap();
+            fs::write(bench.join("change/estimates.json"), estimates(c)).unwrap();
+        }
+    }
+
+    #[test]
+    fn collects_criterion_results() {
+        let root = std::env::temp_dir().join(format!("bench-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write_bench(&root, "refcell_pointer/borrow", "refcell_pointer/borrow", 12.5, Some(0.1));
+        write_bench(&root, "12_vec/push", "12_vec/push", 2500.0, None);
+        fs::create_dir_all(root.join("report")).unwrap();
+
+        let results = collect(&root, None).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id, "12_vec/push");
+        assert_eq!(results[0].change, None);
+        assert_eq!(results[0].lesson.as_deref(), Some("12"));
+        assert_eq!(results[1].lesson.as_deref(), Some("12::refcell_pointer"));
+        assert_eq!(results[1].change, Some(0.1));
+
+        // 기준선은 평균의 절반으로 써 두었음 → 100% 느려짐
+        let with_base = collect(&root, Some("base")).unwrap();
+        assert_eq!(with_base[0].change, Some(1.0));
+
+        let md = markdown(&results);
+        assert!(md.contains("| 12_vec/push | 2.50 µs | - | 12 |"));
+        assert!(table(&results).contains("+10.0% 느려짐"));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(collect(&root, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn formats_times_and_changes() {
+        assert_eq!(format_time(850.0), "850.00 ns");
+        assert_eq!(format_time(1_500_000.0), "1.50 ms");
+        assert_eq!(format_change(Some(-0.25)), "-25.0% 빨라짐");
+        assert_eq!(format_change(Some(0.01)), "+1.0%");
+        assert_eq!(related_lesson("something_else"), None);
+        assert_eq!(related_lesson("iterators/sum"), Some(String::from("11")));
+    }
+}
@@ -0,0 +1,195 @@
+// ============================================================================
+// 절이 필요로 하는 기능 (network, async, unsafe, ffi)
+// ============================================================================
+// cargo run -- --skip async,ffi all    tokio 나 C 라이브러리 심볼이 없는(원하지 않는) 환경에서 나머지 절만
+// cargo run -- --only unsafe all       unsafe 를 쓰는 절만
+//
+// 절마다 손으로 붙인 표가 아니라 절의 독립 실행 스니펫(sections::snippet)을 보고 정함
+//   → 장 소스를 고치면 태그도 따라 바뀌고, 다른 절에서 가져온 정의와 장의 use 까지 포함
+//   주석과 문자열 리터럴 속 낱말("unsafe 블록은 ...")은 세지 않음
+// ============================================================================
+
+use crate::sections::{self, Section};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Capability {
+    Network,
+    Async,
+    Unsafe,
+    Ffi,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 4] = [
+        Capability::Network,
+        Capability::Async,
+        Capability::Unsafe,
+        Capability::Ffi,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Capability::Network => "network",
+            Capability::Async => "async",
+            Capability::Unsafe => "unsafe",
+            Capability::Ffi => "ffi",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Capability> {
+        Capability::ALL.into_iter().find(|c| c.name() == name)
+    }
+
+    // 코드(주석, 문자열을 뺀 줄)에 있으면 이 기능을 쓰는 것
+    fn markers(self) -> &'static [&'static str] {
+        match self {
+            Capability::Network => &["std::net", "tokio::net", "TcpListener", "TcpStream", "UdpSocket"],
+            Capability::Async => &["tokio", "async ", ".await", "futures"],
+            Capability::Unsafe => &["unsafe ", "unsafe{"],
+            Capability::Ffi => &["extern ", "#[link", "#[no_mangle]", "CString", "CStr", "libc::"],
+        }
+    }
+}
+
+// 문자열 리터럴의 내용을 지운 줄 - extern "C" 는 extern "" 로 (줄 끝 주석은 그다음에 자름)
+fn without_strings(line: &str) -> String {
+    let mut out = String::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in line.chars() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => {
+                    in_string = false;
+                    out.push(c);
+                }
+                _ => {}
+            }
+        } else {
+            in_string = c == '"';
+            out.push(c);
+        }
+    }
+    out
+}
+
+// 코드 조각이 쓰는 기능 (Capability 순서)
+pub fn detect(code: &str) -> Vec<Capability> {
+    let lines: Vec<String> = code
+        .lines()
+        .map(|line| line.trim_start())
+        .filter(|line| !line.starts_with("//") && !line.starts_with("extern crate"))
+        .map(without_strings)
+        .map(|line| match line.find("//") {
+            Some(comment) => line[..comment].to_string(),
+            None => line,
+        })
+        .collect();
+    Capability::ALL
+        .into_iter()
+        .filter(|c| c.markers().iter().any(|m| lines.iter().any(|line| line.contains(m))))
+        .collect()
+}
+
+// 절 하나가 쓰는 기능
+pub fn of(section: &Section) -> Vec<Capability> {
+    detect(&sections::snippet(section))
+}
+
+// "async,ffi" → [Async, Ffi]
+pub fn parse_list(list: &str) -> Result<Vec<Capability>, String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            Capability::parse(name).ok_or_else(|| format!("없는 기능: {} (network, async, unsafe, ffi)", name))
+        })
+        .collect()
+}
+
+// --skip / --only 로 고른 절
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    pub skip: Vec<Capability>,
+    // 비어 있으면 모든 절
+    pub only: Vec<Capability>,
+}
+
+impl Filter {
+    pub fn is_empty(&self) -> bool {
+        self.skip.is_empty() && self.only.is_empty()
+    }
+
+    // 기능이 needs 인 절을 실행할지 - 안 하면 걸린 기능을 돌려줌
+    pub fn check(&self, needs: &[Capability]) -> Result<(), Vec<Capability>> {
+        let skipped: Vec<Capability> = needs.iter().copied().filter(|c| self.skip.contains(c)).collect();
+        if !skipped.is_empty() {
+            return Err(skipped);
+        }
+        if !self.only.is_empty() && !needs.iter().any(|c| self.only.contains(c)) {
+            return Err(Vec::new());
+        }
+        Ok(())
+    }
+}
+
+// 명령줄의 --skip <목록>, --only <목록> 을 빼고 나머지 인자를 돌려줌
+pub fn take_flags(args: &[String]) -> Result<(Filter, Vec<String>), String> {
+    let mut filter = Filter::default();
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let list = match arg.as_str() {
+            "--skip" => &mut filter.skip,
+            "--only" => &mut filter.only,
+            _ => {
+                rest.push(arg.clone());
+                continue;
+            }
+        };
+        let value = iter
+            .next()
+            .ok_or_else(|| format!("{} 뒤에 기능 목록을 지정하세요 (예: {} async,ffi)", arg, arg))?;
+        list.extend(parse_list(value)?);
+    }
+    Ok((filter, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chapters;
+
+    fn tags(key: &str) -> Vec<&'static str> {
+        of(&sections::find(key).unwrap()).into_iter().map(Capability::name).collect()
+    }
+
+    #[test]
+    fn detects_capabilities_from_code() {
+        assert_eq!(detect("extern \"C\" {\n    fn abs(x: i32) -> i32;\n}\nunsafe { abs(-1) };"), [Capability::Unsafe, Capability::Ffi]);
+        assert!(detect("// unsafe { }\nprintln!(\"unsafe 블록은 ...\");").is_empty());
+        assert!(tags("07::trait_objects").is_empty());
+        assert!(tags("17::async_basics").contains(&"async"));
+        let all: Vec<Vec<Capability>> = chapters::CHAPTERS.iter().flat_map(sections::sections).map(|s| of(&s)).collect();
+        for capability in Capability::ALL {
+            assert!(all.iter().any(|c| c.contains(&capability)), "{} 절이 하나도 없음", capability.name());
+        }
+    }
+
+    #[test]
+    fn filters_by_skip_and_only() {
+        let args: Vec<String> = ["--skip", "async,ffi", "all"].iter().map(|s| s.to_string()).collect();
+        let (filter, rest) = take_flags(&args).unwrap();
+        assert_eq!(filter.skip, [Capability::Async, Capability::Ffi]);
+        assert_eq!(rest, ["all"]);
+        assert_eq!(filter.check(&[Capability::Unsafe, Capability::Ffi]), Err(vec![Capability::Ffi]));
+        assert_eq!(filter.check(&[Capability::Unsafe]), Ok(()));
+
+        let only = Filter { only: vec![Capability::Unsafe], ..Filter::default() };
+        assert!(only.check(&[]).is_err());
+        assert!(only.check(&[Capability::Unsafe, Capability::Ffi]).is_ok());
+        assert!(take_flags(&["--only".to_string(), "gpu".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,236 @@
+// ============================================================================
+// 장(chapter) 목록
+// ============================================================================
+// 각 _NN_*.rs 모듈의 번호, 이름, 제목을 한 곳에 모아둔 표
+// 퀴즈/진도 등 학습 도구가 장을 찾을 때 사용
+// ============================================================================
+
+pub struct ChapterInfo {
+    pub id: &'static str,    // "07"
+    pub slug: &'static str,  // "traits" - 모듈 이름에서 번호를 뺀 부분
+    pub title: &'static str, // 파일 헤더의 제목
+}
+
+pub const CHAPTERS: &[ChapterInfo] = &[
+    ChapterInfo { id: "01", slug: "basics", title: "기본 문법 - 변수, 타입, 함수" },
+    ChapterInfo { id: "02", slug: "ownership", title: "소유권 (Ownership)" },
+    ChapterInfo { id: "03", slug: "borrowing", title: "빌림 (Borrowing)과 참조 (References)" },
+    ChapterInfo { id: "04", slug: "lifetimes", title: "수명 (Lifetimes)" },
+    ChapterInfo { id: "05", slug: "structs", title: "구조체 (Structs)" },
+    ChapterInfo { id: "06", slug: "enums", title: "열거형 (Enums)과 패턴 매칭" },
+    ChapterInfo { id: "07", slug: "traits", title: "트레이트 (Traits)" },
+    ChapterInfo { id: "08", slug: "generics", title: "제네릭 (Generics)" },
+    ChapterInfo { id: "09", slug: "error_handling", title: "에러 처리 (Error Handling)" },
+    ChapterInfo { id: "10", slug: "collections", title: "컬렉션 (Collections)" },
+    ChapterInfo { id: "11", slug: "iterators", title: "이터레이터와 클로저 (Iterators and Closures)" },
+    ChapterInfo { id: "12", slug: "smart_pointers", title: "스마트 포인터 (Smart Pointers)" },
+    ChapterInfo { id: "13", slug: "concurrency", title: "동시성 (Concurrency)" },
+    ChapterInfo { id: "14", slug: "modules", title: "모듈 시스템 (Module System)" },
+    ChapterInfo { id: "15", slug: "macros", title: "매크로 (Macros)" },
+    ChapterInfo { id: "16", slug: "unsafe", title: "Unsafe Rust" },
+    ChapterInfo { id: "17", slug: "async", title: "비동기 프로그래밍 (Async/Await)" },
+    ChapterInfo { id: "18", slug: "idioms", title: "실무 Rust Idiom" },
+    ChapterInfo { id: "19", slug: "testing", title: "테스트 (Testing)" },
+    ChapterInfo { id: "20", slug: "chat_server", title: "비동기 채팅 서버 (캡스톤)" },
+    ChapterInfo { id: "21", slug: "cross_platform", title: "크로스 플랫폼 코드 (cfg)" },
+    ChapterInfo { id: "22", slug: "patterns", title: "고급 패턴 매칭" },
+    ChapterInfo { id: "23", slug: "text", title: "문자열과 텍스트 심화" },
+    ChapterInfo { id: "24", slug: "numerics", title: "수치 타입과 변환" },
+    ChapterInfo { id: "25", slug: "slices", title: "배열과 슬라이스 심화" },
+    ChapterInfo { id: "26", slug: "dispatch", title: "정적 디스패치 vs 동적 디스패치 벤치마크" },
+    ChapterInfo { id: "27", slug: "advanced_traits", title: "고급 트레이트" },
+    ChapterInfo { id: "28", slug: "closures", title: "클로저 심화" },
+    ChapterInfo { id: "29", slug: "pin", title: "Pin 과 자기 참조 타입" },
+    ChapterInfo { id: "30", slug: "streams", title: "비동기 스트림" },
+    ChapterInfo { id: "31", slug: "async_traits", title: "트레이트의 async fn" },
+    ChapterInfo { id: "32", slug: "rayon", title: "rayon 으로 데이터 병렬 처리" },
+    ChapterInfo { id: "33", slug: "crossbeam", title: "crossbeam 채널과 동시성 도구" },
+    ChapterInfo { id: "34", slug: "thread_pool", title: "스레드 풀 직접 만들기 (캡스톤)" },
+    ChapterInfo { id: "35", slug: "serde", title: "serde 직렬화" },
+    ChapterInfo { id: "36", slug: "files", title: "파일과 파일 시스템" },
+    ChapterInfo { id: "37", slug: "networking", title: "std::net 으로 TCP/UDP 네트워킹" },
+    ChapterInfo { id: "38", slug: "http", title: "비동기 HTTP 서버와 클라이언트" },
+    ChapterInfo { id: "39", slug: "processes", title: "프로세스 실행과 파이프" },
+    ChapterInfo { id: "40", slug: "time", title: "시간, Duration, 시계" },
+    ChapterInfo { id: "41", slug: "clap", title: "clap 으로 명령줄 도구 만들기" },
+    ChapterInfo { id: "42", slug: "proptest", title: "속성 기반 테스트" },
+    ChapterInfo { id: "43", slug: "fuzzing", title: "퍼징" },
+    ChapterInfo { id: "44", slug: "cpp_ffi", title: "C++ 라이브러리 호출" },
+    ChapterInfo { id: "45", slug: "c_api", title: "Rust 를 C 라이브러리로" },
+    ChapterInfo { id: "46", slug: "maybe_uninit", title: "MaybeUninit 과 미초기화 메모리" },
+    ChapterInfo { id: "47", slug: "unions", title: "union 과 transmute" },
+    ChapterInfo { id: "48", slug: "panics", title: "panic 과 되감기" },
+    ChapterInfo { id: "49", slug: "layout", title: "메모리 배치와 repr" },
+    ChapterInfo { id: "50", slug: "zero_cost", title: "제로 코스트 추상화 재 보기" },
+    ChapterInfo { id: "51", slug: "cow", title: "Cow 와 clone-on-write" },
+    ChapterInfo { id: "52", slug: "interior_mutability", title: "내부 가변성 총정리" },
+    ChapterInfo { id: "53", slug: "advanced_lifetimes", title: "고급 수명" },
+    ChapterInfo { id: "54", slug: "drop_order", title: "drop 순서와 ManuallyDrop" },
+    ChapterInfo { id: "55", slug: "operators", title: "연산자 오버로딩" },
+    ChapterInfo { id: "56", slug: "formatting", title: "Display 와 Debug 서식 깊이 보기" },
+    ChapterInfo { id: "57", slug: "iter_adapters", title: "직접 만드는 이터레이터 어댑터" },
+];
+
+// 먼저 보면 좋은 장 - 건너뛰어 읽을 때 놓치면 이해하기 어려운 것만 (장 번호 순)
+pub const PREREQUISITES: &[(&str, &[&str])] = &[
+    ("02", &["01"]),
+    ("03", &["02"]),
+    ("04", &["03"]),
+    ("05", &["02"]),
+    ("06", &["05"]),
+    ("07", &["05"]),
+    ("08", &["07"]),
+    ("09", &["06"]),
+    ("10", &["02", "08"]),
+    ("11", &["07", "10"]),
+    ("12", &["02", "03", "07"]),
+    ("13", &["11", "12"]),
+    ("14", &["05"]),
+    ("15", &["08"]),
+    ("16", &["03", "12"]),
+    ("17", &["09", "13"]),
+    ("18", &["09", "11"]),
+    ("19", &["09", "14"]),
+    ("20", &["09", "13", "17"]),
+    ("21", &["14"]),
+    ("22", &["03", "06"]),
+    ("23", &["10"]),
+    ("24", &["01"]),
+    ("25", &["10", "11"]),
+    ("26", &["07", "08"]),
+    ("27", &["07", "08"]),
+    ("28", &["04", "11"]),
+    ("29", &["12", "16", "17"]),
+    ("30", &["11", "17"]),
+    ("31", &["07", "17"]),
+    ("32", &["11", "13"]),
+    ("33", &["13"]),
+    ("34", &["07", "09", "12", "13"]),
+    ("35", &["06", "09"]),
+    ("36", &["09"]),
+    ("37", &["09", "13", "36"]),
+    ("38", &["09", "17", "37"]),
+    ("39", &["09", "13", "21"]),
+    ("40", &["07", "13"]),
+    ("41", &["05", "06", "09"]),
+    ("42", &["16", "19"]),
+    ("43", &["10", "18", "42"]),
+    ("44", &["12", "16", "21"]),
+    ("45", &["16", "44"]),
+    ("46", &["16", "45"]),
+    ("47", &["16", "24", "46"]),
+    ("48", &["09", "12", "13"]),
+    ("49", &["12", "16", "47"]),
+    ("50", &["11", "16", "26"]),
+    ("51", &["03", "12", "23"]),
+    ("52", &["12", "13", "16"]),
+    ("53", &["04", "08", "13"]),
+    ("54", &["12", "16", "48"]),
+    ("55", &["07", "24", "27"]),
+    ("56", &["07", "09", "23"]),
+    ("57", &["11", "18", "25"]),
+];
+
+pub fn prerequisites(id: &str) -> &'static [&'static str] {
+    PREREQUISITES
+        .iter()
+        .find(|(chapter, _)| *chapter == id)
+        .map_or(&[], |(_, before)| before)
+}
+
+// "7", "07", "traits", "_07_traits" 모두 같은 장으로 찾기
+pub fn find(key: &str) -> Option<&'static ChapterInfo> {
+    let key = key.trim_start_matches('_');
+    let number = key.parse::<u32>().ok();
+    CHAPTERS.iter().find(|c| {
+        c.slug == key
+            || (number.is_some() && number == c.id.parse().ok())
+            || key == format!("{}_{}", c.id, c.slug)
+    })
+}
+
+// --from 05 --to 09 - 두 장과 그 사이의 장 (순서대로), 한쪽이 없으면 처음이나 끝까지
+pub fn range(from: Option<&str>, to: Option<&str>) -> Result<&'static [ChapterInfo], String> {
+    let position = |key: &str| {
+        let info = find(key).ok_or_else(|| format!("없는 장: {}", key))?;
+        Ok::<usize, String>(CHAPTERS.iter().position(|c| c.id == info.id).unwrap_or(0))
+    };
+    let start = from.map(position).transpose()?.unwrap_or(0);
+    let end = to.map(position).transpose()?.unwrap_or(CHAPTERS.len() - 1);
+    if start > end {
+        return Err(format!("--from {} 이 --to {} 보다 뒤의 장입니다", CHAPTERS[start].id, CHAPTERS[end].id));
+    }
+    Ok(&CHAPTERS[start..=end])
+}
+
+// 장의 원본 소스 - 바이너리에 포함되어 실행 위치와 상관없이 사용 가능
+pub fn source(id: &str) -> Option<&'static str> {
+    let text = match id {
+        "01" => include_str!("_01_basics.rs"),
+        "02" => include_str!("_02_ownership.rs"),
+        "03" => include_str!("_03_borrowing.rs"),
+        "04" => include_str!("_04_lifetimes.rs"),
+        "05" => include_str!("_05_structs.rs"),
+        "06" => include_str!("_06_enums.rs"),
+        "07" => include_str!("_07_traits.rs"),
+        "08" => include_str!("_08_generics.rs"),
+        "09" => include_str!("_09_error_handling.rs"),
+        "10" => include_str!("_10_collections.rs"),
+        "11" => include_str!("_11_iterators.rs"),
+        "12" => include_str!("_12_smart_pointers.rs"),
+        "13" => include_str!("_13_concurrency.rs"),
+        "14" => include_str!("_14_modules.rs"),
+        "15" => include_str!("_15_macros.rs"),
+        "16" => include_str!("_16_unsafe.rs"),
+        "17" => include_str!("_17_async.rs"),
+        "18" => include_str!("_18_idioms.rs"),
+        "19" => include_str!("_19_testing.rs"),
+        "20" => include_str!("_20_chat_server.rs"),
+        "21" => include_str!("_21_cross_platform.rs"),
+        "22" => include_str!("_22_patterns.rs"),
+        "23" => include_str!("_23_text.rs"),
+        "24" => include_str!("_24_numerics.rs"),
+        "25" => include_str!("_25_slices.rs"),
+        "26" => include_str!("_26_dispatch.rs"),
+        "27" => include_str!("_27_advanced_traits.rs"),
+        "28" => include_str!("_28_closures.rs"),
+        "29" => include_str!("_29_pin.rs"),
+        "30" => include_str!("_30_streams.rs"),
+        "31" => include_str!("_31_async_traits.rs"),
+        "32" => include_str!("_32_rayon.rs"),
+        "33" => include_str!("_33_crossbeam.rs"),
+        "34" => include_str!("_34_thread_pool.rs"),
+        "35" => include_str!("_35_serde.rs"),
+        "36" => include_str!("_36_files.rs"),
+        "37" => include_str!("_37_networking.rs"),
+        "38" => include_str!("_38_http.rs"),
+        "39" => include_str!("_39_processes.rs"),
+        "40" => include_str!("_40_time.rs"),
+        "41" => include_str!("_41_clap.rs"),
+        "42" => include_str!("_42_proptest.rs"),
+        "43" => include_str!("_43_fuzzing.rs"),
+        "44" => include_str!("_44_cpp_ffi.rs"),
+        "45" => include_str!("_45_c_api.rs"),
+        "46" => include_str!("_46_maybe_uninit.rs"),
+        "47" => include_str!("_47_unions.rs"),
+        "48" => include_str!("_48_panics.rs"),
+        "49" => include_str!("_49_layout.rs"),
+        "50" => include_str!("_50_zero_cost.rs"),
+        "51" => include_str!("_51_cow.rs"),
+        "52" => include_str!("_52_interior_mutability.rs"),
+        "53" => include_str!("_53_advanced_lifetimes.rs"),
+        "54" => include_str!("_54_drop_order.rs"),
+        "55" => include_str!("_55_operators.rs"),
+        "56" => include_str!("_56_formatting.rs"),
+        "57" => include_str!("_57_iter_adapters.rs"),
+        _ => return None,
+    };
+    Some(text)
+}
+
+impl ChapterInfo {
+    // 모듈 이름: "_07_traits"
+    pub fn module(&self) -> String {
+        format!("_{}_{}", self.id, self.slug)
+    }
+}
@@ -0,0 +1,1180 @@
+// ============================================================================
+// 명령줄 인터페이스
+// ============================================================================
+// cargo run               - 모든 장 실행 (main.rs)
+// cargo run -- <명령> ... - 학습 도구 명령 실행
+// ============================================================================
+
+use std::error::Error;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::progress::{self, Progress, QuizScore};
+use crate::transcript::{self, Transcript};
+use crate::timelog::{self, Period};
+use crate::{bench, capabilities, chapters, content, cpp, doctor, drill, examples, exercise, find, gallery, interview, lessons, levels, lint, output, profile, quiz, resume, rng, sandbox, search, sections, serve, terminal, tui, watch};
+
+type CliResult = Result<(), Box<dyn Error>>;
+
+pub fn run(args: &[String]) -> CliResult {
+    let (name, args) = profile::take_flag(args)?;
+    profile::select(name)?;
+    let (command, rest) = match args.split_first() {
+        Some((command, rest)) => (command.as_str(), rest),
+        None => return Ok(()),
+    };
+
+    match command {
+        "--list" | "list" => {
+            list_chapters();
+            Ok(())
+        }
+        "browse" => Ok(tui::run()?),
+        "quiz" => run_quiz(rest),
+        "check" => check(),
+        "progress" => run_progress(rest),
+        "resume" => run_resume(rest),
+        "drill" => run_drill(rest),
+        "sandbox" => run_sandbox(rest),
+        "lessons" => run_lessons(rest),
+        "share" => run_share(rest),
+        "examples" => run_examples(rest),
+        "record" => run_record(rest),
+        "doctor" => run_doctor(),
+        "time-track" => run_time_track(rest),
+        "time-report" => run_time_report(rest),
+        "stats" => run_stats(),
+        "bench-report" => run_bench_report(rest),
+        "profile" => run_profile(rest),
+        "notes" => run_notes(rest),
+        "exercise" => run_exercise(rest),
+        "replay" => run_replay(rest),
+        "interview" => run_interview(rest),
+        "cpp" => run_cpp(rest),
+        "errors" => run_errors(rest),
+        "find" => run_find(rest),
+        "search" => run_search(rest),
+        "changelog" => run_changelog(rest),
+        "render" => run_render(rest),
+        "watch" => run_watch(rest),
+        "serve" => run_serve(rest),
+        "help" | "--help" | "-h" => {
+            print_help();
+            Ok(())
+        }
+        other => Err(format!("알 수 없는 명령: '{}' (cargo run -- help 참고)", other).into()),
+    }
+}
+
+fn print_help() {
+    println!("사용법: cargo run -- <명령>");
+    println!();
+    println!("  (명령 없음)     장 선택 메뉴 - 번호를 골라 실행하고 q 로 종료 (터미널이 아니면 모든 장 실행)");
+    println!("  all             모든 장의 예제 실행");
+    println!("  <장>...         그 장의 예제만 실행 (예: 07, traits, 07 09)");
+    println!("  <장>:<절>       절 하나만 실행 (예: 07:trait_objects - 절 이름은 share <장>)");
+    println!("  resume [--show] 마지막으로 실행한 장/절의 다음부터 이어서 실행 (기록: .rust-study/state.toml)");
+    println!("  drill [--show]  오늘의 드릴 - 무작위 절 하나 실행 (최근에 본 절은 덜 고름)");
+    println!("  --list          모든 장의 번호, 모듈 이름, 제목과 한 줄 설명, 주제");
+    println!("  browse          터미널 장 탐색기 - 장 목록과 절 이름, Enter 로 실행한 출력을 스크롤하며 보기");
+    println!("  quiz <장>       장의 퀴즈 풀기 (예: quiz 07, quiz traits)");
+    println!("  check           content/ 의 퀴즈/연습문제, 레슨 규칙, examples/ 동기화 검증");
+    println!("  progress        학습 진도 보기");
+    println!("  progress export <파일>   진도를 JSON 파일로 내보내기");
+    println!("  progress import <파일>   다른 컴퓨터의 진도를 병합");
+    println!("  sandbox <장> [경로]      장의 예제로 독립 cargo 프로젝트 생성 (기본: sandbox/NN_이름)");
+    println!("  lessons add <git-url>     커뮤니티 레슨 팩 설치 (빌드 전에 확인을 물음)");
+    println!("  lessons add <url> --yes   확인 없이 빌드하고 설치 - 믿는 팩만");
+    println!("  lessons list              설치된 레슨 팩과 장 목록");
+    println!("  lessons run <장 id>       레슨 팩의 장 실행");
+    println!("  lessons remove <이름>     레슨 팩 삭제");
+    println!("  share <장>                장의 절 목록");
+    println!("  share <장>::<절> [--print]  절의 코드를 Rust Playground 링크로 (예: share 11::custom_iterator)");
+    println!("  examples sync   각 절을 examples/NN_<절>.rs 로 생성 (cargo run --example 12_refcell_pointer)");
+    println!("  record <파일> <명령...>   명령을 실행하며 입출력과 시간을 녹화 (예: record s.json quiz 07)");
+    println!("  replay <파일> [--speed N | --fast]  녹화한 세션을 원래 속도(또는 N배속)로 재생");
+    println!("  doctor          개발 환경 진단 (버전, 터미널, 로캘, 선택 도구)");
+    println!("  time-track on|off|status  학습 시간 기록 켜기/끄기 (기본: 꺼짐, 이 컴퓨터에만 저장)");
+    println!("  time-report [--weekly]    일별/주별 학습 시간");
+    println!("  stats                     학습한 날 달력, 장별 완료 막대, 퀴즈 점수 추이");
+    println!("  bench-report [--format text|markdown] [--baseline <이름>]  criterion 결과(target/criterion)를 평균, 변화, 관련 레슨 표로");
+    println!("  interview [--per-topic N] [--limit 초]  주제별 면접 대비 문제를 시간을 재며 풀고 준비도 리포트");
+    println!("  cpp [<장>::<절>]          C++ 대응 코드가 있는 절 목록 / Rust 와 C++ 출력을 나란히 비교");
+    println!("  errors [<번호>]           주석 처리된 \"에러!\" 코드 목록 / 코드와 실제 rustc 진단 보기 (예: errors 02-1)");
+    println!("  find <검색어> [--context N] [--limit N]  모든 장의 주석, 문자열, 코드에서 찾아 장, 절, 줄 번호와 문맥 보기");
+    println!("  search <키워드> [--source]  키워드를 다루는 장과 절 (C++ 용어도 됨: search unique_ptr), --source: 소스에 나온 횟수도 반영");
+    println!("  changelog [<장>]          완료 후 내용이 바뀐 장 목록 / 장의 버전별 변경 이력 (예: changelog 17)");
+    println!("  render <장> [--format text|tui|html] [--watch]  장의 설명, 코드, C++ 비교를 텍스트/터미널 화면/HTML 로 출력");
+    println!("                            --watch: content/prose/ 의 설명 글을 고칠 때마다 다시 출력");
+    println!("  watch <장>[:<절>]  장 소스(src/_NN_*.rs)를 저장할 때마다 다시 빌드해 실행 (예: watch 11, watch 07:trait_objects)");
+    println!("  serve [--port N]          브라우저 학습 모드 - 장 목록, 검색, 서버에서 예제 실행, JSON API (기본: 8080)");
+    println!("  profile [list | create <이름> | delete <이름>]  학습자 프로필 - 사람마다 진도, 퀴즈 기록, 메모, 학습 시간을 따로");
+    println!("  notes <장> [내용...]      장의 메모 보기 / 한 줄 추가 (예: notes 12 Rc 는 스레드 간 공유 불가)");
+    println!("  exercise list|start <id>|submit <id> [메시지]|diff <id>|review [--out <파일>]");
+    println!("                            연습문제 답안을 study/<프로필> 브랜치에 커밋, 참고 답안과 비교, 멘토 검토용 요약");
+    println!("  help            이 도움말");
+    println!();
+    println!("모든 명령에 --profile <이름> 을 붙이면 그 프로필로 실행 (또는 RUST_STUDY_PROFILE 환경 변수)");
+    println!("--seed N | --seed random 을 붙이면 예제 데이터를 시드로 생성 (또는 RUST_STUDY_SEED 환경 변수)");
+    println!("  drill 의 절, interview 의 문제도 같은 시드면 같음 - 스레드 실행 순서, HashMap 순서, 실행 시간은 제외");
+    println!("--lang en 을 붙이면 장의 출력을 영어로 (표는 content/i18n/en/, 또는 RUST_STUDY_LANG 환경 변수)");
+    println!("--from <장> --to <장> 으로 이어진 장들만 실행 (예: --from 05 --to 09 11, 장 번호보다 앞에, 한쪽을 빼면 처음이나 끝까지)");
+    println!("--timing 을 붙이면 장과 절마다 실행 시간을 재고 끝에 표로 (예: --timing all, --timing 13 17)");
+    println!("--profile-alloc 을 붙이면 장과 절마다 힙 할당 횟수와 바이트를 세고 끝에 표로 (예: --profile-alloc 10 12)");
+    println!("--theme dark|light|none 으로 장 출력의 색 테마 (기본 dark, NO_COLOR 환경 변수가 있으면 색 없이)");
+    println!("--parallel 을 붙이면 서로 독립인 장을 여러 스레드에서 동시에 실행하고 출력은 장 순서대로 (예: --parallel all)");
+    println!("--skip <기능,..> / --only <기능,..> 으로 network, async, unsafe, ffi 를 쓰는 절을 빼거나 그것만 실행 (예: --skip async,ffi all)");
+    println!("--level beginner|intermediate|advanced (쉼표로 여럿) 로 그 난이도의 절만 실행 (예: --level intermediate,advanced all)");
+    println!("--no-pager 를 붙이면 터미널에서도 장 출력을 페이저(less, PAGER 환경 변수) 없이 그대로");
+    println!("--step 을 붙이면 절을 하나 실행할 때마다 다음 절 이름을 보여 주고 Enter 를 기다림 (q: 그만)");
+    println!("--output <파일> 을 붙이면 장 출력을 화면 대신 파일로 (색 없이), --tee 를 더하면 화면에도");
+    println!("--strict 를 붙이면 먼저 볼 장(--list)을 완료하지 않은 장은 경고 대신 실행하지 않음");
+    println!("--format json 을 붙이면 장 실행 결과를 절마다 JSON 한 줄로 (chapter, section, start, duration_ms, status, output)");
+    println!("현재 디렉터리의 rust-study.toml 에 기본값 (chapters = [\"07\", \"11\"], lang, timing, seed, theme, pager, parallel, skip, only, level) - 명령줄이 우선");
+    println!("설정의 timing, parallel 을 이번에만 끄려면 --no-timing, --no-parallel");
+}
+
+fn list_chapters() {
+    for chapter in crate::all_chapters() {
+        let module = chapters::find(chapter.id()).map(|info| info.module()).unwrap_or_default();
+        println!("{}  {:<20}  {}", chapter.id(), module, chapter.name());
+        println!("    {}", chapter.description());
+        println!("    주제: {}", chapter.topics().join(", "));
+        let mut needs: Vec<_> = chapters::find(chapter.id())
+            .map(sections::sections)
+            .unwrap_or_default()
+            .iter()
+            .flat_map(capabilities::of)
+            .collect();
+        needs.sort();
+        needs.dedup();
+        if !needs.is_empty() {
+            let names: Vec<&str> = needs.into_iter().map(|c| c.name()).collect();
+            println!("    기능: {} (--skip 으로 뺄 수 있음)", names.join(", "));
+        }
+        // 장의 기본 난이도와 그와 다른 절
+        let level = levels::of(chapter.id(), "");
+        let other: Vec<String> = chapter
+            .sections()
+            .iter()
+            .map(|(name, _)| (name, levels::of(chapter.id(), name)))
+            .filter(|(_, l)| *l != level)
+            .map(|(name, l)| format!("{} {}", name, l.label()))
+            .collect();
+        if other.is_empty() {
+            println!("    난이도: {}", level.label());
+        } else {
+            println!("    난이도: {} (다른 절: {})", level.label(), other.join(", "));
+        }
+        let before = chapters::prerequisites(chapter.id());
+        if !before.is_empty() {
+            println!("    먼저 볼 장: {}", before.join(", "));
+        }
+    }
+    println!();
+    println!("cargo run -- <번호 또는 이름> 으로 그 장만 실행 (예: cargo run -- 07)");
+}
+
+fn run_quiz(args: &[String]) -> CliResult {
+    let key = args.first().ok_or("장을 지정하세요 (예: quiz 07)")?;
+
+    // 기본 장을 먼저 찾고, 없으면 설치된 레슨 팩에서 찾음
+    let packs;
+    let (id, title, bank) = match chapters::find(key) {
+        Some(info) => (info.id, info.title, content::source().load()?),
+        None => {
+            packs = lessons::installed();
+            let (pack, chapter) =
+                lessons::find(&packs, key).ok_or_else(|| format!("없는 장: {}", key))?;
+            (chapter.id.as_str(), chapter.title.as_str(), pack.content()?)
+        }
+    };
+    let chapter = bank
+        .chapter(id)
+        .filter(|c| !c.questions.is_empty())
+        .ok_or_else(|| format!("{}장에는 아직 퀴즈가 없습니다", id))?;
+
+    println!("=== {}. {} 퀴즈 ===", id, title);
+    let stdin = io::stdin();
+    let timer = timelog::Tracker::start(id, None, "quiz");
+    let result = quiz::run(chapter, &mut stdin.lock(), &mut io::stdout())?;
+    drop(timer);
+
+    let path = progress::default_path();
+    let mut store = Progress::load(&path)?;
+    let score = QuizScore {
+        correct: result.correct,
+        total: result.total,
+        at: progress::now(),
+    };
+    if store.record_quiz(id, score, chapter.version) {
+        println!("최고 점수 갱신!");
+    }
+    store.save(&path)?;
+    Ok(())
+}
+
+fn run_progress(args: &[String]) -> CliResult {
+    let path = progress::default_path();
+    match args {
+        [] => {
+            // 콘텐츠를 못 읽어도 진도는 보여줌 - 갱신 표시만 빠짐
+            let bank = content::source().load().ok();
+            show_progress(&Progress::load(&path)?, bank.as_ref());
+            Ok(())
+        }
+        [cmd, file] if cmd == "export" => {
+            Progress::load(&path)?.save(Path::new(file))?;
+            println!("진도를 {}에 저장했습니다", file);
+            Ok(())
+        }
+        [cmd, file] if cmd == "import" => {
+            let incoming = Progress::load(Path::new(file))?;
+            let mut store = Progress::load(&path)?;
+            let before = store.clone();
+            store.merge(&incoming);
+            store.save(&path)?;
+
+            let new_chapters = store.completed.len() - before.completed.len();
+            let improved = store
+                .quiz_best
+                .iter()
+                .filter(|(id, s)| before.quiz_best.get(*id) != Some(s))
+                .count();
+            println!("병합 완료: 새로 완료한 장 {}개, 갱신된 퀴즈 점수 {}개", new_chapters, improved);
+            Ok(())
+        }
+        _ => Err("사용법: progress [export <파일> | import <파일>]".into()),
+    }
+}
+
+fn show_progress(store: &Progress, bank: Option<&content::ContentBank>) {
+    if let Some(name) = profile::active() {
+        println!("프로필: {}", name);
+    }
+    println!("완료한 장: {}/{}", store.completed.len(), chapters::CHAPTERS.len());
+    let mut updated = 0;
+    for c in chapters::CHAPTERS {
+        let done = if store.completed.contains_key(c.id) { "✓" } else { " " };
+        let mut score = match store.quiz_best.get(c.id) {
+            Some(s) => format!("퀴즈 {}/{} ({}%)", s.correct, s.total, s.percent()),
+            None => String::new(),
+        };
+        let version = bank.and_then(|b| b.chapter(c.id)).map_or(1, |ch| ch.version);
+        if store.updated_since_completion(c.id, version) {
+            updated += 1;
+            score.push_str("  ↑ 완료 후 내용 추가됨");
+        }
+        println!("  [{}] {}. {:<40} {}", done, c.id, c.title, score);
+    }
+    if updated > 0 {
+        println!("\n완료 후 갱신된 장 {}개 - cargo run -- changelog <장> 으로 추가된 내용 보기", updated);
+    }
+}
+
+fn check() -> CliResult {
+    let source = content::source();
+    let bank = source.load_unchecked()?;
+    let mut issues = content::validate(&bank);
+
+    let questions = bank.questions().count();
+    let exercises: usize = bank.chapters.iter().map(|c| c.exercises.len()).sum();
+    println!(
+        "{}: {}개 장, 문제 {}개, 연습문제 {}개",
+        source,
+        bank.chapters.len(),
+        questions,
+        exercises
+    );
+
+    issues.extend(source.validate_prose()?);
+    if !issues.is_empty() {
+        return Err(content::ContentError::Invalid(issues).into());
+    }
+    println!("콘텐츠 검증 통과");
+
+    let findings = lint::lint_all();
+    if !findings.is_empty() {
+        for f in &findings {
+            println!("  {}", f);
+        }
+        return Err(format!("레슨 규칙 위반 {}건", findings.len()).into());
+    }
+    println!("레슨 규칙 검사 통과");
+
+    let report = examples::compare(&examples::examples_dir(), &examples::expected())?;
+    if !report.is_clean() {
+        print_sync_report(&report);
+        return Err("examples/ 가 장 소스와 다릅니다 (cargo run -- examples sync)".into());
+    }
+    println!("examples/ 동기화 확인");
+    Ok(())
+}
+
+fn print_sync_report(report: &examples::SyncReport) {
+    for name in &report.missing {
+        println!("  없음     {}", name);
+    }
+    for name in &report.stale {
+        println!("  변경됨   {}", name);
+    }
+    for name in &report.orphaned {
+        println!("  불필요   {}", name);
+    }
+}
+
+fn run_examples(args: &[String]) -> CliResult {
+    match args.first().map(String::as_str) {
+        Some("sync") => {
+            let report = examples::sync(&examples::examples_dir())?;
+            if report.is_clean() {
+                println!("examples/ 가 이미 최신입니다");
+            } else {
+                print_sync_report(&report);
+                println!("examples/ 를 갱신했습니다");
+            }
+            Ok(())
+        }
+        _ => Err("사용법: examples sync".into()),
+    }
+}
+
+fn run_sandbox(args: &[String]) -> CliResult {
+    let key = args.first().ok_or("장을 지정하세요 (예: sandbox 13)")?;
+    let info = chapters::find(key).ok_or_else(|| format!("없는 장: {}", key))?;
+    let dir = match args.get(1) {
+        Some(path) => PathBuf::from(path),
+        None => Path::new("sandbox").join(format!("{}_{}", info.id, info.slug)),
+    };
+
+    // 연습문제는 있으면 TODO 목록으로 - 콘텐츠 문제로 생성이 막히지는 않게
+    let bank = content::source().load().ok();
+    let exercises = bank
+        .as_ref()
+        .and_then(|b| b.chapter(info.id))
+        .map(|c| c.exercises.as_slice())
+        .unwrap_or_default();
+
+    let files = sandbox::generate(info, exercises)?;
+    sandbox::write(&dir, &files)?;
+    println!("{}. {} 샌드박스를 만들었습니다: {}", info.id, info.title, dir.display());
+    println!("  cd {} && cargo run", dir.display());
+    Ok(())
+}
+
+fn run_lessons(args: &[String]) -> CliResult {
+    let sub: Vec<&str> = args.iter().map(String::as_str).collect();
+    match sub.as_slice() {
+        ["add", url] => add_pack(url, false),
+        ["add", url, "--yes"] => add_pack(url, true),
+        ["list"] | [] => {
+            let packs = lessons::installed();
+            if packs.is_empty() {
+                println!("설치된 레슨 팩이 없습니다 (lessons add <git-url>)");
+            }
+            for pack in &packs {
+                println!("{} - {}", pack.manifest.name, pack.manifest.description);
+                for c in &pack.manifest.chapters {
+                    println!("  {:<16} {}", c.id, c.title);
+                }
+            }
+            Ok(())
+        }
+        ["run", id] => {
+            let packs = lessons::installed();
+            let (pack, chapter) =
+                lessons::find(&packs, id).ok_or_else(|| format!("설치된 팩에 없는 장: {}", id))?;
+            let _timer = timelog::Tracker::start(&chapter.id, None, "lesson");
+            lessons::run_chapter(pack, chapter)?;
+            Ok(())
+        }
+        ["remove", name] => {
+            lessons::remove(name)?;
+            println!("'{}' 팩을 삭제했습니다", name);
+            Ok(())
+        }
+        _ => Err("사용법: lessons [add <git-url> [--yes] | list | run <장 id> | remove <이름>]".into()),
+    }
+}
+
+fn add_pack(url: &str, yes: bool) -> CliResult {
+    let pack = lessons::add(url, |pack| confirm_build(pack, yes))?;
+    println!(
+        "'{}' 팩을 설치했습니다 (장 {}개): {}",
+        pack.manifest.name,
+        pack.manifest.chapters.len(),
+        pack.dir.display()
+    );
+    Ok(())
+}
+
+// cargo build 는 팩의 build.rs 와 절차적 매크로를 이 컴퓨터에서 그대로 실행함 - 믿는 팩만
+fn confirm_build(pack: &lessons::LessonPack, yes: bool) -> bool {
+    eprintln!(
+        "경고: '{}' 팩을 빌드하면 팩의 build.rs 와 절차적 매크로가 이 컴퓨터에서 실행됩니다",
+        pack.manifest.name
+    );
+    if yes {
+        return true;
+    }
+    eprintln!("  먼저 살펴보려면: git clone 으로 받아 build.rs 와 Cargo.toml 의 의존성을 확인");
+    eprint!("빌드하고 설치할까요? [y/N] ");
+    let _ = io::Write::flush(&mut io::stderr());
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).is_ok() && matches!(line.trim(), "y" | "Y" | "yes")
+}
+
+// 이보다 긴 URL은 일부 브라우저/메신저에서 잘릴 수 있음
+const LONG_URL: usize = 8000;
+
+fn run_share(args: &[String]) -> CliResult {
+    let key = args.first().ok_or("절을 지정하세요 (예: share 11::custom_iterator)")?;
+
+    if !key.contains("::") {
+        let info = chapters::find(key).ok_or_else(|| format!("없는 장: {}", key))?;
+        println!("{}. {}", info.id, info.title);
+        for s in sections::sections(info) {
+            println!("  {:<32} {}", s.key(), s.title);
+        }
+        return Ok(());
+    }
+
+    let section = sections::find(key)?;
+    let code = sections::snippet(&section);
+    if args.iter().any(|a| a == "--print") {
+        print!("{}", code);
+        return Ok(());
+    }
+
+    let url = sections::playground_url(&code);
+    println!("{}", url);
+    if url.len() > LONG_URL {
+        eprintln!(
+            "주의: URL이 {}자로 깁니다. 잘린다면 --print 로 코드를 복사해 붙여 넣으세요.",
+            url.len()
+        );
+    }
+    Ok(())
+}
+
+fn run_record(args: &[String]) -> CliResult {
+    let (file, command) = match args.split_first() {
+        Some((file, command)) if !command.is_empty() => (file, command),
+        _ => return Err("사용법: record <파일> <명령...> (예: record session.json quiz 07)".into()),
+    };
+    if command[0] == "record" {
+        return Err("record 안에서 record 는 실행할 수 없습니다".into());
+    }
+
+    let (transcript, status) = transcript::record(command)?;
+    transcript.save(Path::new(file))?;
+    eprintln!(
+        "\n녹화 완료: {} (이벤트 {}개, {:.1}초, 종료 코드 {})",
+        file,
+        transcript.events.len(),
+        transcript.duration().as_secs_f64(),
+        status.code().map_or("-".to_string(), |c| c.to_string())
+    );
+    Ok(())
+}
+
+fn run_replay(args: &[String]) -> CliResult {
+    let file = args.first().ok_or("사용법: replay <파일> [--speed N | --fast]")?;
+    let speed = match args.get(1).map(String::as_str) {
+        None => Some(1.0),
+        Some("--fast") => None,
+        Some("--speed") => {
+            let n = args.get(2).ok_or("--speed 뒤에 배속을 지정하세요")?;
+            Some(n.parse::<f64>().map_err(|_| format!("잘못된 배속: {}", n))?)
+        }
+        Some(other) => return Err(format!("알 수 없는 옵션: {}", other).into()),
+    };
+
+    let transcript = Transcript::load(Path::new(file))?;
+    println!("▶ 재생: cargo run -- {}", transcript.command.join(" "));
+    transcript::replay(&transcript, speed, &mut io::stdout())?;
+    println!(
+        "\n■ 재생 끝 ({:.1}초 세션, 입력 {}줄)",
+        transcript.duration().as_secs_f64(),
+        transcript.inputs().count()
+    );
+    Ok(())
+}
+
+fn run_interview(args: &[String]) -> CliResult {
+    let mut per_topic = interview::DEFAULT_PER_TOPIC;
+    let mut limit = None;
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or_else(|| format!("{} 뒤에 값을 지정하세요", flag))?;
+        let n = value
+            .parse::<u64>()
+            .map_err(|_| format!("잘못된 숫자: {}", value))?;
+        match flag.as_str() {
+            "--per-topic" => per_topic = n as usize,
+            "--limit" => limit = Some(std::time::Duration::from_secs(n)),
+            other => return Err(format!("알 수 없는 옵션: {}", other).into()),
+        }
+    }
+
+    let bank = content::source().load()?;
+    let picks = interview::select(&bank, per_topic, rng::for_tool("interview").next_u64());
+    if picks.is_empty() {
+        return Err("면접 주제에 해당하는 문제가 없습니다".into());
+    }
+
+    println!("=== 면접 대비: {}문제 ===", picks.len());
+    if let Some(limit) = limit {
+        println!("제한 시간 {}초 - 힌트 없이 풀어 보세요", limit.as_secs());
+    }
+    let stdin = io::stdin();
+    let timer = timelog::Tracker::start("interview", None, "interview");
+    let answers = interview::run(&picks, limit, &mut stdin.lock(), &mut io::stdout())?;
+    drop(timer);
+
+    let reports = interview::report(&answers);
+    interview::print_report(&reports, &picks, &answers, &bank, &mut io::stdout())?;
+    Ok(())
+}
+
+fn run_cpp(args: &[String]) -> CliResult {
+    let compiler = cpp::find_compiler(std::env::var("CXX").ok());
+    let Some(key) = args.first() else {
+        match &compiler {
+            Some(c) => println!("C++ 컴파일러: {} ({})", c.program, c.version),
+            None => println!("C++ 컴파일러 없음 - 비교 시 Rust 출력만 보여줍니다"),
+        }
+        println!("C++ 대응 코드가 있는 절:");
+        for name in cpp::counterparts(&cpp::cpp_dir())? {
+            println!("  {}", name.replacen('_', "::", 1));
+        }
+        return Ok(());
+    };
+
+    let name = cpp::normalize(key);
+    let rust = cpp::run_rust(&name)?;
+    let Some(compiler) = compiler else {
+        println!("{}", rust);
+        println!("C++ 컴파일러를 찾지 못해 Rust 출력만 보여줍니다 (CXX 환경 변수로 지정 가능)");
+        return Ok(());
+    };
+    let cpp_out = cpp::run_cpp(&compiler, &name)?;
+    print!("{}", cpp::side_by_side(&rust, &cpp_out, 38));
+    println!("\n소스: examples/{}.rs, cpp/{}.cpp", name, name);
+    Ok(())
+}
+
+fn run_errors(args: &[String]) -> CliResult {
+    let Some(id) = args.first() else {
+        let all = gallery::catalog();
+        let mut chapter = "";
+        for b in &all {
+            if b.section.chapter != chapter {
+                chapter = b.section.chapter;
+                let title = chapters::find(chapter).map_or("", |c| c.title);
+                println!("\n{}. {}", chapter, title);
+            }
+            let first = b.code();
+            let first = first.lines().next().unwrap_or_default().trim();
+            println!("  {:<6} {}", b.id, first.split("//").next().unwrap_or(first).trim());
+        }
+        println!("\n{}개 - cargo run -- errors <번호> 로 실제 진단 보기", all.len());
+        return Ok(());
+    };
+
+    let broken = gallery::find(id).ok_or_else(|| format!("없는 번호: {} (cargo run -- errors 로 목록 보기)", id))?;
+    println!("=== {} - {} ({}) ===", broken.id, broken.section.title, broken.section.key());
+    println!("\n코드 (src/{}.rs:{}):", chapters::find(broken.section.chapter).map_or(String::new(), |c| c.module()), broken.line);
+    println!("{}", broken.code());
+
+    println!("\nrustc:");
+    match gallery::diagnose(&broken) {
+        Ok(d) if d.compiled => println!("  (컴파일됨 - 이 툴체인에서는 에러가 아닙니다)"),
+        Ok(d) => {
+            print!("{}", d.text);
+            println!();
+            for code in d.codes() {
+                println!("자세한 설명: rustc --explain {}", code);
+            }
+        }
+        Err(e) => println!("  rustc 를 실행할 수 없습니다: {} (RUSTC 환경 변수로 지정 가능)", e),
+    }
+
+    let explanation: Vec<&str> = std::iter::once(broken.note.as_str())
+        .filter(|note| !note.is_empty())
+        .chain(broken.explanation())
+        .collect();
+    if !explanation.is_empty() {
+        println!("\n설명:");
+        for line in explanation {
+            println!("  {}", line);
+        }
+    }
+    println!("\n절 전체: cargo run -- share {}  /  cargo run --example {}", broken.section.key(), broken.section.key().replace("::", "_"));
+    Ok(())
+}
+
+fn run_find(args: &[String]) -> CliResult {
+    let mut context = find::DEFAULT_CONTEXT;
+    let mut limit = find::DEFAULT_LIMIT;
+    let mut words = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let target = match arg.as_str() {
+            "--context" => &mut context,
+            "--limit" => &mut limit,
+            _ => {
+                words.push(arg.as_str());
+                continue;
+            }
+        };
+        let value = iter.next().ok_or_else(|| format!("{} 뒤에 값을 지정하세요", arg))?;
+        *target = value.parse().map_err(|_| format!("잘못된 숫자: {}", value))?;
+    }
+    if words.is_empty() {
+        return Err("사용법: find <검색어> [--context N] [--limit N]".into());
+    }
+    let query = words.join(" ");
+
+    let found = find::search(&query, context);
+    if found.is_empty() {
+        println!("'{}' 을(를) 찾지 못했습니다", query);
+        return Ok(());
+    }
+    for m in found.iter().take(limit) {
+        let module = chapters::find(m.chapter).map_or(String::new(), |c| c.module());
+        let title = m.section.as_ref().map_or("", |s| s.title.as_str());
+        println!("{:<28} src/{}.rs:{}  [{}] {}", m.anchor(), module, m.line, m.kind.label(), title);
+        for (n, line) in &m.context {
+            let mark = if *n == m.line { '>' } else { ' ' };
+            println!("  {} {:>4} | {}", mark, n, line);
+        }
+        println!();
+    }
+    if found.len() > limit {
+        println!("{}건 중 {}건 표시 (--limit 으로 더 보기)", found.len(), limit);
+    } else {
+        println!("{}건", found.len());
+    }
+    Ok(())
+}
+
+fn run_search(args: &[String]) -> CliResult {
+    let with_source = args.iter().any(|a| a == "--source");
+    let query = args.iter().filter(|a| *a != "--source").cloned().collect::<Vec<_>>().join(" ");
+    if query.trim().is_empty() {
+        return Err("사용법: search <키워드> [--source]".into());
+    }
+    if let (_, Some(rust)) = search::expand(&query) {
+        println!("C++ {} → Rust {}", query, rust.join(", "));
+    }
+    let hits = search::search(&crate::all_chapters(), &query, with_source);
+    if hits.is_empty() {
+        println!("'{}' 을(를) 다루는 장을 찾지 못했습니다 (소스까지 찾기: --source, 줄 단위 검색: find)", query);
+        return Ok(());
+    }
+    for hit in &hits {
+        println!("\n{}. {}  (점수 {})", hit.id, hit.name, hit.score);
+        if !hit.matched.is_empty() {
+            println!("   주제: {}", hit.matched.join(", "));
+        }
+        for s in &hit.sections {
+            let count = if s.occurrences > 0 { format!("  (소스 {}회)", s.occurrences) } else { String::new() };
+            println!("   {:<32} {}{}", s.key, s.title, count);
+        }
+    }
+    println!("\n{}개 장 - 절 실행: cargo run -- <장>:<절>", hits.len());
+    Ok(())
+}
+
+fn run_resume(args: &[String]) -> CliResult {
+    let show_only = match args {
+        [] => false,
+        [flag] if flag == "--show" => true,
+        _ => return Err("사용법: resume [--show]".into()),
+    };
+    let state = resume::load(&resume::default_path())?;
+    match &state {
+        Some(last) => println!("마지막 실행: {}", last.target()),
+        None => println!("실행 기록이 없어 첫 장부터 시작합니다"),
+    }
+    let Some((id, section)) = resume::next(&crate::all_chapters(), state.as_ref()) else {
+        println!("마지막 장까지 모두 실행했습니다 - 복습: cargo run -- <장>, 진도: cargo run -- progress");
+        return Ok(());
+    };
+    let info = chapters::find(id).ok_or_else(|| format!("없는 장: {}", id))?;
+    let next = section.map_or_else(|| id.to_string(), |s| format!("{}:{}", id, s));
+    if show_only {
+        println!("다음: {} ({}) - cargo run -- {}", next, info.title, next);
+        return Ok(());
+    }
+    println!("이어서: {}", next);
+    match section {
+        None => crate::run_chapter(info),
+        Some(name) => crate::run_section(info, name)?,
+    }
+    finish_run()
+}
+
+// 장을 실행한 명령(resume, drill)의 끝 - 통과/실패 요약
+fn finish_run() -> CliResult {
+    if !crate::runner::finish() {
+        return Err("panic 한 절이 있습니다".into());
+    }
+    Ok(())
+}
+
+fn run_drill(args: &[String]) -> CliResult {
+    let show_only = match args {
+        [] => false,
+        [flag] if flag == "--show" => true,
+        _ => return Err("사용법: drill [--show]".into()),
+    };
+    let recent = resume::load(&resume::default_path())?.map(|s| s.recent).unwrap_or_default();
+    let candidates = drill::candidates(&crate::all_chapters(), &recent);
+    let picked = drill::pick(&candidates, drill::roll()).ok_or("실행할 절이 없습니다")?;
+    let info = chapters::find(picked.chapter).ok_or_else(|| format!("없는 장: {}", picked.chapter))?;
+    let title = sections::find(&format!("{}::{}", picked.chapter, picked.section)).map(|s| s.title).unwrap_or_default();
+    println!("오늘의 드릴: {} - {} ({})", picked.target(), title, info.title);
+    if show_only {
+        println!("실행: cargo run -- {}", picked.target());
+        return Ok(());
+    }
+    crate::run_section(info, picked.section)?;
+    finish_run()
+}
+
+fn run_changelog(args: &[String]) -> CliResult {
+    let bank = content::source().load()?;
+    let store = Progress::load(&progress::default_path())?;
+
+    let Some(key) = args.first() else {
+        let updated: Vec<_> = chapters::CHAPTERS
+            .iter()
+            .filter_map(|info| Some((info, bank.chapter(info.id)?)))
+            .filter(|(info, ch)| store.updated_since_completion(info.id, ch.version))
+            .collect();
+        if updated.is_empty() {
+            println!("완료한 장 중 이후에 바뀐 장이 없습니다");
+        }
+        for (info, ch) in updated {
+            let seen = store.completed[info.id].version;
+            println!("{}. {} - v{} 에서 완료, 현재 v{}", info.id, info.title, seen, ch.version);
+            for entry in ch.changes_since(seen) {
+                for change in &entry.changes {
+                    println!("    + {}", change);
+                }
+            }
+        }
+        return Ok(());
+    };
+
+    let info = chapters::find(key).ok_or_else(|| format!("없는 장: {}", key))?;
+    let chapter = bank
+        .chapter(info.id)
+        .ok_or_else(|| format!("{}장의 콘텐츠가 없습니다", info.id))?;
+    // 완료하지 않았으면 전부 새 내용
+    let seen = store.completed.get(info.id).map(|c| c.version);
+    println!("=== {}. {} (v{}) ===", info.id, info.title, chapter.version);
+    match seen {
+        Some(v) if v >= chapter.version => println!("v{} 에서 완료 - 이후 바뀐 내용 없음", v),
+        Some(v) => println!("v{} 에서 완료 - 새로 추가된 내용은 * 표시", v),
+        None => println!("아직 완료하지 않은 장입니다"),
+    }
+    if chapter.changelog.is_empty() {
+        println!("\n변경 이력이 없습니다 (첫 버전)");
+    }
+    for entry in chapter.changes_since(0) {
+        let new = seen.is_some_and(|v| entry.version > v);
+        println!("\n{} v{} ({})", if new { "*" } else { " " }, entry.version, entry.date);
+        for change in &entry.changes {
+            println!("    - {}", change);
+        }
+    }
+    Ok(())
+}
+
+fn run_render(args: &[String]) -> CliResult {
+    const USAGE: &str = "사용법: render <장> [--format text|tui|html] [--watch]";
+    let (key, rest) = args.split_first().ok_or(USAGE)?;
+    let mut format = "text";
+    let mut watch = false;
+    let mut iter = rest.iter();
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--format" => format = iter.next().ok_or(USAGE)?,
+            "--watch" => watch = true,
+            _ => return Err(USAGE.into()),
+        }
+    }
+    if !matches!(format, "text" | "tui" | "html") {
+        return Err(format!("알 수 없는 형식: {} (text, tui, html)", format).into());
+    }
+    let info = chapters::find(key).ok_or_else(|| format!("없는 장: {}", key))?;
+    render(info, format)?;
+    if !watch {
+        return Ok(());
+    }
+
+    // 설명 글 파일이 바뀔 때마다 다시 출력 - 코드는 장 소스에 내장되어 있으므로 글만 감시
+    let path = content::source()
+        .prose_path(info)
+        .ok_or("내장 콘텐츠는 바뀌지 않습니다 (content/ 디렉터리에서 실행하거나 RUST_STUDY_CONTENT 지정)")?;
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    eprintln!("{} 를 감시합니다 (Ctrl+C 로 종료)", path.display());
+    let mut last = modified(&path);
+    loop {
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        let now = modified(&path);
+        if now != last {
+            last = now;
+            eprintln!("\n--- {} 변경됨, 다시 렌더링 ---\n", path.display());
+            // 글을 고치는 도중의 에러로 감시가 멈추지 않게
+            if let Err(e) = render(info, format) {
+                eprintln!("에러: {}", e);
+            }
+        }
+    }
+}
+
+// 블록을 먼저 모두 모은 뒤 형식에 맞는 싱크로 보냄
+fn render(info: &chapters::ChapterInfo, format: &str) -> CliResult {
+    let mut lesson = output::CaptureSink::new();
+    output::render_lesson(info, &mut lesson)?;
+    let stdout = io::stdout();
+    match format {
+        "tui" => {
+            // 터미널 폭 - 알 수 없으면(파이프, 파일) 100칸
+            let width = terminal::columns_or(100);
+            lesson.replay(&mut output::TuiSink::new(stdout.lock(), width))?
+        }
+        "html" => {
+            let mut html = output::HtmlSink::new();
+            lesson.replay(&mut html)?;
+            print!("{}", html.finish());
+        }
+        _ => lesson.replay(&mut output::PlainSink::new(stdout.lock()))?,
+    }
+    Ok(())
+}
+
+fn run_watch(args: &[String]) -> CliResult {
+    let [target] = args else {
+        return Err("사용법: watch <장>[:<절>] (예: watch 11)".into());
+    };
+    let (key, section) = match target.split_once(':') {
+        Some((key, name)) => (key, Some(name.trim_start_matches(':'))),
+        None => (target.as_str(), None),
+    };
+    let info = chapters::find(key).ok_or_else(|| format!("없는 장: {}", key))?;
+    // 자식에게는 번호로 넘김 - 장 이름("iterators")으로 골라도 같은 장
+    let target = section.map_or_else(|| info.id.to_string(), |s| format!("{}:{}", info.id, s));
+    Ok(watch::run(info, &target)?)
+}
+
+fn run_serve(args: &[String]) -> CliResult {
+    let port = match args {
+        [] => serve::DEFAULT_PORT,
+        [flag, port] if flag == "--port" => port.parse().map_err(|_| format!("잘못된 포트: {}", port))?,
+        _ => return Err("사용법: serve [--port N]".into()),
+    };
+    let rt = tokio::runtime::Runtime::new()?;
+    rt.block_on(async {
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await?;
+        println!("http://127.0.0.1:{} 에서 학습 서버 실행 중 (Ctrl+C 로 종료)", port);
+        serve::serve(listener).await
+    })?;
+    Ok(())
+}
+
+fn run_doctor() -> CliResult {
+    let checks = doctor::run_checks();
+    for c in &checks {
+        let mark = match c.status {
+            doctor::Status::Ok => "✓",
+            doctor::Status::Warn => "!",
+            doctor::Status::Fail => "✗",
+        };
+        println!("[{}] {:<14} {}", mark, c.name, c.detail);
+        if let Some(fix) = &c.fix {
+            println!("    → {}", fix);
+        }
+    }
+
+    let count = |s| checks.iter().filter(|c| c.status == s).count();
+    let (warns, fails) = (count(doctor::Status::Warn), count(doctor::Status::Fail));
+    println!("\n경고 {}개, 실패 {}개", warns, fails);
+    if fails > 0 {
+        return Err("환경 문제를 먼저 해결하세요".into());
+    }
+    Ok(())
+}
+
+fn run_time_track(args: &[String]) -> CliResult {
+    match args.first().map(String::as_str) {
+        Some("on") => {
+            timelog::set_enabled(true)?;
+            println!("학습 시간 기록을 켰습니다 (time-report 로 확인)");
+        }
+        Some("off") => {
+            timelog::set_enabled(false)?;
+            println!("학습 시간 기록을 껐습니다 (기존 기록은 남아 있음)");
+        }
+        Some("status") | None => {
+            let state = if timelog::enabled() { "켜짐" } else { "꺼짐" };
+            println!("학습 시간 기록: {}", state);
+        }
+        Some(other) => return Err(format!("알 수 없는 옵션: {} (on|off|status)", other).into()),
+    }
+    Ok(())
+}
+
+fn run_time_report(args: &[String]) -> CliResult {
+    let period = match args.first().map(String::as_str) {
+        None | Some("--daily") => Period::Daily,
+        Some("--weekly") => Period::Weekly,
+        Some(other) => return Err(format!("알 수 없는 옵션: {}", other).into()),
+    };
+    let entries = timelog::load()?;
+    if entries.is_empty() {
+        println!("기록이 없습니다 (time-track on 으로 기록을 켜세요)");
+        return Ok(());
+    }
+
+    println!("=== 기간별 학습 시간 (UTC 기준) ===");
+    for (label, chapters) in timelog::breakdown(&entries, period) {
+        let total: u64 = chapters.values().sum();
+        println!("{}  {}", label, timelog::format_duration(total));
+        for (chapter, secs) in chapters {
+            println!("    {:<16} {}", chapter, timelog::format_duration(secs));
+        }
+    }
+
+    println!("\n=== 가장 오래 공부한 곳 ===");
+    for (topic, secs) in timelog::by_topic(&entries).iter().take(10) {
+        println!("  {:<32} {}", topic, timelog::format_duration(*secs));
+    }
+    Ok(())
+}
+
+const HEATMAP_WEEKS: usize = 20;
+const BAR_WIDTH: usize = 20;
+
+fn run_stats() -> CliResult {
+    let store = Progress::load(&progress::default_path())?;
+    let bank = content::source().load().ok();
+    let entries = timelog::load()?;
+
+    // 학습한 날 = 시간 기록, 퀴즈 시도, 장 완료가 있는 날 (UTC 기준)
+    let day = |secs: u64| (secs / 86_400) as i64;
+    let mut days = std::collections::BTreeMap::new();
+    let attempts = store.quiz_history.values().flatten().map(|s| s.at);
+    let completions = store.completed.values().map(|c| c.at);
+    for at in entries.iter().map(|e| e.start).chain(attempts).chain(completions) {
+        *days.entry(day(at)).or_insert(0u64) += 1;
+    }
+
+    let today = day(progress::now());
+    let first = today - (today + 3).rem_euclid(7) - 7 * (HEATMAP_WEEKS as i64 - 1);
+    let label = |d: i64| {
+        let (y, m, d) = timelog::civil_from_days(d);
+        format!("{:04}-{:02}-{:02}", y, m, d)
+    };
+    println!("=== 학습한 날 ({} ~ {}) ===", label(first), label(today));
+    let studied = days.keys().filter(|&&d| d >= first).count();
+    for (name, row) in ["월", "화", "수", "목", "금", "토", "일"]
+        .iter()
+        .zip(output::heatmap(&days, today, HEATMAP_WEEKS))
+    {
+        println!("  {} {}", name, row.trim_end());
+    }
+    println!("  {}일 학습  (· 없음  ░▒▓█ 적음 → 많음)", studied);
+
+    println!("\n=== 장별 진도 (퀴즈 최고 점수) ===");
+    for c in chapters::CHAPTERS {
+        let done = if store.completed.contains_key(c.id) { "✓" } else { " " };
+        let percent = store.quiz_best.get(c.id).map_or(0, |s| s.percent());
+        let version = bank.as_ref().and_then(|b| b.chapter(c.id)).map_or(1, |ch| ch.version);
+        let mark = if store.updated_since_completion(c.id, version) { " ↑" } else { "" };
+        println!(
+            "  [{}] {} {} {:>3}% {}{}",
+            done,
+            c.id,
+            output::bar(percent as u64, 100, BAR_WIDTH),
+            percent,
+            c.title,
+            mark
+        );
+    }
+    println!(
+        "  완료 {}/{}  {}",
+        store.completed.len(),
+        chapters::CHAPTERS.len(),
+        output::bar(store.completed.len() as u64, chapters::CHAPTERS.len() as u64, BAR_WIDTH)
+    );
+
+    println!("\n=== 퀴즈 점수 추이 (시도 순, 높이 = 정답률) ===");
+    if store.quiz_history.is_empty() {
+        println!("  아직 푼 퀴즈가 없습니다 (cargo run -- quiz <장>)");
+        return Ok(());
+    }
+    let percents = |scores: &[QuizScore]| -> Vec<u64> {
+        scores.iter().map(|s| s.percent() as u64).collect()
+    };
+    let mut all: Vec<QuizScore> = store.quiz_history.values().flatten().copied().collect();
+    all.sort_by_key(|s| s.at);
+    println!("  전체  {}", output::sparkline(&percents(&all), 100));
+    for (chapter, scores) in &store.quiz_history {
+        let last = scores.last().map_or(0, |s| s.percent());
+        println!(
+            "  {:<4}  {:<12} 최근 {:>3}%  ({}회)",
+            chapter,
+            output::sparkline(&percents(scores), 100),
+            last,
+            scores.len()
+        );
+    }
+    Ok(())
+}
+
+fn run_bench_report(args: &[String]) -> CliResult {
+    const USAGE: &str = "사용법: bench-report [--format text|markdown] [--baseline <이름>]";
+    let mut format = "text";
+    let mut baseline = None;
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter.next().ok_or(USAGE)?;
+        match flag.as_str() {
+            "--format" => format = value,
+            "--baseline" => baseline = Some(value.as_str()),
+            _ => return Err(USAGE.into()),
+        }
+    }
+
+    let dir = bench::criterion_dir();
+    let results = bench::collect(&dir, baseline)?;
+    if results.is_empty() {
+        println!("{} 에 벤치마크 결과가 없습니다 (cargo bench 를 먼저 실행하세요)", dir.display());
+        return Ok(());
+    }
+    match format {
+        "text" => print!("{}", bench::table(&results)),
+        "markdown" | "md" => print!("{}", bench::markdown(&results)),
+        other => return Err(format!("알 수 없는 형식: {} (text, markdown)", other).into()),
+    }
+    Ok(())
+}
+
+fn run_profile(args: &[String]) -> CliResult {
+    match args {
+        [] => {
+            println!("지금 프로필: {}", profile::active().unwrap_or(profile::DEFAULT));
+            println!("데이터: {}", profile::dir().display());
+            Ok(())
+        }
+        [cmd] if cmd == "list" => {
+            let active = profile::active().unwrap_or(profile::DEFAULT);
+            for name in profile::list()? {
+                let mark = if name == active { "*" } else { " " };
+                println!("  {} {}", mark, name);
+            }
+            Ok(())
+        }
+        [cmd, name] if cmd == "create" => {
+            let dir = profile::create(name)?;
+            println!("'{}' 프로필을 만들었습니다 ({})", name, dir.display());
+            println!("사용: cargo run -- --profile {} <명령>", name);
+            Ok(())
+        }
+        [cmd, name] if cmd == "delete" => {
+            profile::delete(name)?;
+            println!("'{}' 프로필과 그 진도, 메모, 학습 시간을 삭제했습니다", name);
+            Ok(())
+        }
+        _ => Err("사용법: profile [list | create <이름> | delete <이름>]".into()),
+    }
+}
+
+fn run_notes(args: &[String]) -> CliResult {
+    let (key, text) = args.split_first().ok_or("장을 지정하세요 (예: notes 12)")?;
+    let info = chapters::find(key).ok_or_else(|| format!("없는 장: {}", key))?;
+    if text.is_empty() {
+        let notes = profile::read_notes(info.id)?;
+        if notes.is_empty() {
+            println!("{}장 메모가 없습니다 (notes {} <내용> 으로 추가)", info.id, info.id);
+        } else {
+            println!("=== {}. {} 메모 ===", info.id, info.title);
+            print!("{}", notes);
+        }
+    } else {
+        profile::add_note(info.id, &text.join(" "))?;
+        println!("{}장에 메모를 추가했습니다", info.id);
+    }
+    Ok(())
+}
+
+fn run_exercise(args: &[String]) -> CliResult {
+    let bank = content::source().load()?;
+    let exercises: Vec<&content::Exercise> = bank.chapters.iter().flat_map(|c| &c.exercises).collect();
+    let find = |id: &str| {
+        exercises
+            .iter()
+            .copied()
+            .find(|e| e.id == id)
+            .ok_or_else(|| format!("없는 연습문제: {} (exercise list 참고)", id))
+    };
+    let workspace = exercise::Workspace::current();
+
+    match args {
+        [] => Err("사용법: exercise [list | start <id> | submit <id> [메시지] | diff <id> | review [--out <파일>]]".into()),
+        [cmd] if cmd == "list" => {
+            let submitted = workspace.submitted()?;
+            println!("제출 브랜치: {}", workspace.branch);
+            for e in &exercises {
+                let done = if submitted.contains(&e.id) { "✓" } else { " " };
+                println!("  [{}] {:<24} {:?}\t{}", done, e.id, e.difficulty, e.title);
+            }
+            Ok(())
+        }
+        [cmd, id] if cmd == "start" => {
+            let e = find(id)?;
+            match workspace.start(e)? {
+                (path, true) => println!("답안 파일을 만들었습니다: {}", path.display()),
+                (path, false) => println!("이미 있는 답안 파일: {}", path.display()),
+            }
+            println!("다 풀면: cargo run -- exercise submit {}", e.id);
+            Ok(())
+        }
+        [cmd, id, message @ ..] if cmd == "submit" => {
+            let e = find(id)?;
+            let message = message.join(" ");
+            let hash = workspace.submit(e, Some(message.as_str()).filter(|m| !m.is_empty()))?;
+            println!("{} 에 제출했습니다 ({})", workspace.branch, hash);
+            println!("참고 답안과 비교: cargo run -- exercise diff {}", e.id);
+            Ok(())
+        }
+        [cmd, id] if cmd == "diff" => {
+            let e = find(id)?;
+            let solution = bank
+                .solution(&e.id)
+                .ok_or_else(|| format!("{} 에는 아직 참고 답안이 없습니다", e.id))?;
+            workspace.show_diff(&e.id, solution)?;
+            Ok(())
+        }
+        [cmd, rest @ ..] if cmd == "review" => {
+            let summary = exercise::review(&workspace, &exercises, &bank.solutions)?;
+            match rest {
+                [] => print!("{}", summary),
+                [flag, file] if flag == "--out" => {
+                    std::fs::write(file, summary)?;
+                    println!("검토 요약을 {}에 저장했습니다", file);
+                }
+                _ => return Err("사용법: exercise review [--out <파일>]".into()),
+            }
+            Ok(())
+        }
+        _ => Err("사용법: exercise [list | start <id> | submit <id> [메시지] | diff <id> | review [--out <파일>]]".into()),
+    }
+}
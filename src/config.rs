@@ -0,0 +1,163 @@
+// ============================================================================
+// 기본 실행 설정 (rust-study.toml)
+// ============================================================================
+// 현재 디렉터리의 rust-study.toml - 매번 긴 명령줄을 쓰지 않도록
+//
+//   chapters = ["07", "11", "13"]   인자 없이 cargo run 하면 메뉴 대신 이 장들
+//   lang = "en"                     --lang en
+//   timing = true                   --timing
+//   seed = 42                       --seed 42
+//   theme = "light"                 --theme light
+//   pager = false                   --no-pager
+//   parallel = true                 --parallel
+//   skip = ["async", "ffi"]         --skip async,ffi  (only 도 같은 형식)
+//   level = ["advanced"]            --level advanced
+//
+// 설정은 명령줄 플래그로 바꿔서 명령줄 앞에 붙임 → 플래그 처리는 한 곳(main)
+// 명령줄에 같은 플래그가 있으면 설정의 것은 붙이지 않음 (명령줄이 우선)
+// 켜 둔 timing, parallel 을 한 번만 끄려면 --no-timing, --no-parallel (여기서 빼고 main 에는 넘기지 않음)
+// parallel 과 함께 쓸 수 없는 명령줄(--step, --profile-alloc, --format, 07:xxx)이면 설정의 parallel 은 붙이지 않음
+// 모르는 키는 에러 - 오타(chapter = ...)를 조용히 무시하지 않도록
+// ============================================================================
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::Deserialize;
+
+pub const FILE: &str = "rust-study.toml";
+
+// 설정의 불리언을 이번 실행에서만 끄는 플래그
+const NO_TIMING: &str = "--no-timing";
+const NO_PARALLEL: &str = "--no-parallel";
+
+// 명령줄에 있으면 --parallel 과 부딪히는 플래그 (main 이 에러로 처리)
+const PARALLEL_CONFLICTS: &[&str] = &["--step", "--profile-alloc", "--format"];
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub chapters: Vec<String>,
+    pub lang: Option<String>,
+    pub timing: bool,
+    pub seed: Option<u64>,
+    pub theme: Option<String>,
+    pub pager: Option<bool>,
+    pub parallel: bool,
+    pub skip: Vec<String>,
+    pub only: Vec<String>,
+    pub level: Vec<String>,
+}
+
+// 파일이 없으면 None
+pub fn load(path: &Path) -> Result<Option<Config>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("{} 을 읽을 수 없습니다: {}", path.display(), e)),
+    };
+    toml::from_str(&text)
+        .map(Some)
+        .map_err(|e| format!("{} 형식 오류: {}", path.display(), e))
+}
+
+impl Config {
+    // 설정을 명령줄 플래그로 - cli 에 이미 있는 플래그는 빼고
+    pub fn flags(&self, cli: &[String]) -> Vec<String> {
+        let given = |flag: &str| cli.iter().any(|a| a == flag);
+        let mut flags = Vec::new();
+        let mut push = |flag: &str, value: Option<String>| {
+            if !given(flag) {
+                flags.push(flag.to_string());
+                flags.extend(value);
+            }
+        };
+        if let Some(lang) = &self.lang {
+            push("--lang", Some(lang.clone()));
+        }
+        if self.timing && !given(NO_TIMING) {
+            push("--timing", None);
+        }
+        if let Some(seed) = self.seed {
+            push("--seed", Some(seed.to_string()));
+        }
+        if let Some(theme) = &self.theme {
+            push("--theme", Some(theme.clone()));
+        }
+        if self.pager == Some(false) {
+            push("--no-pager", None);
+        }
+        // 장:절 하나를 고른 경우도 장 단위인 --parallel 과 부딪힘
+        let conflicts = PARALLEL_CONFLICTS.iter().any(|flag| given(flag))
+            || cli.iter().any(|a| !a.starts_with('-') && a.contains(':'));
+        if self.parallel && !given(NO_PARALLEL) && !conflicts {
+            push("--parallel", None);
+        }
+        if !self.skip.is_empty() {
+            push("--skip", Some(self.skip.join(",")));
+        }
+        if !self.only.is_empty() {
+            push("--only", Some(self.only.join(",")));
+        }
+        if !self.level.is_empty() {
+            push("--level", Some(self.level.join(",")));
+        }
+        flags
+    }
+}
+
+// 설정의 플래그를 앞에 붙인 인자 - 장 목록은 플래그를 뺀 뒤 인자가 없을 때 main 이 씀
+pub fn apply(config: &Config, cli: &[String]) -> Vec<String> {
+    let rest = cli.iter().filter(|a| *a != NO_TIMING && *a != NO_PARALLEL).cloned();
+    config.flags(cli).into_iter().chain(rest).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn turns_settings_into_flags() {
+        let config: Config = toml::from_str(
+            "chapters = [\"07\", \"11\"]\nlang = \"en\"\ntiming = true\npager = false\nskip = [\"async\", \"ffi\"]\n",
+        )
+        .unwrap();
+        assert_eq!(config.chapters, ["07", "11"]);
+        assert_eq!(
+            apply(&config, &args(&["13"])),
+            args(&["--lang", "en", "--timing", "--no-pager", "--skip", "async,ffi", "13"])
+        );
+        // 명령줄이 우선
+        assert_eq!(
+            apply(&config, &args(&["--lang", "ko", "--skip", "unsafe"])),
+            args(&["--timing", "--no-pager", "--lang", "ko", "--skip", "unsafe"])
+        );
+        assert!(toml::from_str::<Config>("chapter = [\"07\"]").is_err());
+    }
+
+    #[test]
+    fn parallel_setting_yields_to_conflicting_flags() {
+        let config: Config = toml::from_str("timing = true\nparallel = true\n").unwrap();
+        assert_eq!(apply(&config, &args(&["all"])), args(&["--timing", "--parallel", "all"]));
+        // main 이 --parallel 과 함께 거절하는 명령줄에는 붙이지 않음
+        assert_eq!(apply(&config, &args(&["--step", "11"])), args(&["--timing", "--step", "11"]));
+        assert_eq!(
+            apply(&config, &args(&["--format", "json", "11"])),
+            args(&["--timing", "--format", "json", "11"])
+        );
+        assert_eq!(apply(&config, &args(&["--profile-alloc", "all"])), args(&["--timing", "--profile-alloc", "all"]));
+        assert_eq!(apply(&config, &args(&["11:custom_iterator"])), args(&["--timing", "11:custom_iterator"]));
+        // --no-* 는 설정만 끄고 main 에는 넘기지 않음
+        assert_eq!(apply(&config, &args(&["--no-parallel", "--no-timing", "all"])), args(&["all"]));
+    }
+
+    #[test]
+    fn missing_file_is_no_config() {
+        assert_eq!(load(Path::new("no-such-dir/rust-study.toml")), Ok(None));
+    }
+}
@@ -0,0 +1,766 @@
+// ============================================================================
+// 퀴즈/연습문제 콘텐츠 로더
+// ============================================================================
+// 문제와 힌트, 연습문제 정보는 content/ 디렉터리의 TOML/JSON 파일에 있음
+// Rust를 몰라도 파일만 수정하면 문제를 추가할 수 있고 재컴파일이 필요 없음
+//
+// 파일 하나 = 장 하나 (예: content/07_traits.toml), 참고 답안은 content/solutions/<id>.rs
+// 레슨 설명 글은 content/prose/07_traits.md - 렌더링할 때 장 소스의 절과 합침 (Prose)
+//
+// 빌드할 때 content/ 전체가 바이너리에 내장됨 (build.rs) - 바이너리 하나만 배포해도 오프라인으로 학습
+// 저장소에서 실행하거나 RUST_STUDY_CONTENT 를 지정하면 파일에서 읽음 (ContentSource)
+// 로드 후 validate()로 스키마 외의 규칙(중복 id, 정답 범위 등)을 검사
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::chapters::{self, ChapterInfo};
+use crate::sections;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChapterContent {
+    pub chapter: String,
+    #[serde(default)]
+    pub questions: Vec<Question>,
+    #[serde(default)]
+    pub exercises: Vec<Exercise>,
+    // 내용이 바뀔 때마다 올리는 버전 - 완료 후 갱신된 장을 알려주는 데 사용
+    #[serde(default = "first_version")]
+    pub version: u32,
+    #[serde(default)]
+    pub changelog: Vec<ChangeEntry>,
+    // 어느 파일에서 읽었는지 - 에러 메시지용
+    #[serde(skip)]
+    pub source: PathBuf,
+}
+
+fn first_version() -> u32 {
+    1
+}
+
+// 버전 하나에서 바뀐 내용
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeEntry {
+    pub version: u32,
+    // "2026-10-17"
+    pub date: String,
+    pub changes: Vec<String>,
+}
+
+impl ChapterContent {
+    // since 보다 새 버전의 변경 내역 - 새 것부터
+    pub fn changes_since(&self, since: u32) -> Vec<&ChangeEntry> {
+        let mut entries: Vec<&ChangeEntry> =
+            self.changelog.iter().filter(|e| e.version > since).collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.version));
+        entries
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Question {
+    pub id: String,
+    pub prompt: String,
+    pub choices: Vec<String>,
+    // 0부터 시작하는 정답 인덱스
+    pub answer: usize,
+    #[serde(default)]
+    pub hint: Option<String>,
+    #[serde(default)]
+    pub explanation: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Exercise {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub hints: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct ContentBank {
+    pub chapters: Vec<ChapterContent>,
+    // 연습문제 id → 참고 답안 소스
+    pub solutions: BTreeMap<String, String>,
+}
+
+impl ContentBank {
+    pub fn solution(&self, exercise_id: &str) -> Option<&str> {
+        self.solutions.get(exercise_id).map(String::as_str)
+    }
+
+    pub fn chapter(&self, id: &str) -> Option<&ChapterContent> {
+        self.chapters.iter().find(|c| c.chapter == id)
+    }
+
+    pub fn questions(&self) -> impl Iterator<Item = &Question> {
+        self.chapters.iter().flat_map(|c| c.questions.iter())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 에러 타입
+// ----------------------------------------------------------------------------
+
+// 검증 규칙 위반 하나 - 위치와 메시지
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    pub location: String,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+#[derive(Debug)]
+pub enum ContentError {
+    Io { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, message: String },
+    Invalid(Vec<Issue>),
+}
+
+impl fmt::Display for ContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            ContentError::Parse { path, message } => {
+                write!(f, "{} 파싱 실패: {}", path.display(), message)
+            }
+            ContentError::Invalid(issues) => {
+                writeln!(f, "콘텐츠 검증 실패 ({}건)", issues.len())?;
+                for issue in issues {
+                    writeln!(f, "  - {}", issue)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ContentError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 로드
+// ----------------------------------------------------------------------------
+
+// 파일에서 읽는 함수는 wasm32 에서 빠짐 - 브라우저(web/)는 내장 콘텐츠만 씀
+
+// build.rs 가 만든 목록: CONTENT (파일 이름, 내용), SOLUTIONS (연습문제 id, 참고 답안),
+// PROSE (prose/ 의 파일 이름, 설명 글)
+include!(concat!(env!("OUT_DIR"), "/embedded_content.rs"));
+
+// 콘텐츠를 어디서 읽을지
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentSource {
+    // 빌드할 때 내장한 content/
+    Embedded,
+    // 실행할 때 읽는 디렉터리 - 고친 내용이 재컴파일 없이 바로 반영
+    Dir(PathBuf),
+}
+
+impl ContentSource {
+    // RUST_STUDY_CONTENT 가 있으면 그 디렉터리, 저장소 안에서 실행하면 content/,
+    // 빌드한 곳이 없는 배포 바이너리는 내장 콘텐츠
+    pub fn detect() -> ContentSource {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let dir = content_dir();
+            if std::env::var_os("RUST_STUDY_CONTENT").is_some() || dir.is_dir() {
+                return ContentSource::Dir(dir);
+            }
+        }
+        ContentSource::Embedded
+    }
+
+    // 로드 + 검증 - 규칙 위반이 하나라도 있으면 Invalid
+    pub fn load(&self) -> Result<ContentBank, ContentError> {
+        checked(self.load_unchecked()?)
+    }
+
+    pub fn load_unchecked(&self) -> Result<ContentBank, ContentError> {
+        match self {
+            ContentSource::Embedded => {
+                let mut bank = ContentBank::default();
+                for (name, text) in CONTENT {
+                    bank.chapters.push(parse(Path::new(name), text)?);
+                }
+                for (id, text) in SOLUTIONS {
+                    bank.solutions.insert(id.to_string(), text.to_string());
+                }
+                Ok(bank)
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            ContentSource::Dir(dir) => load_unchecked(dir),
+            #[cfg(target_arch = "wasm32")]
+            ContentSource::Dir(dir) => Err(ContentError::Io {
+                path: dir.clone(),
+                source: io::Error::new(io::ErrorKind::Unsupported, "wasm 에서는 파일을 읽을 수 없음"),
+            }),
+        }
+    }
+}
+
+impl ContentSource {
+    // 장의 설명 글 - 파일이 없으면 빈 Prose
+    pub fn load_prose(&self, info: &ChapterInfo) -> Result<Prose, ContentError> {
+        let name = prose_file_name(info);
+        match self {
+            ContentSource::Embedded => Ok(PROSE
+                .iter()
+                .find(|(file, _)| *file == name)
+                .map(|(_, text)| Prose::parse(text))
+                .unwrap_or_default()),
+            #[cfg(not(target_arch = "wasm32"))]
+            ContentSource::Dir(dir) => {
+                let path = dir.join("prose").join(name);
+                match fs::read_to_string(&path) {
+                    Ok(text) => Ok(Prose::parse(&text)),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Prose::default()),
+                    Err(source) => Err(ContentError::Io { path, source }),
+                }
+            }
+            #[cfg(target_arch = "wasm32")]
+            ContentSource::Dir(_) => Ok(Prose::default()),
+        }
+    }
+
+    // 설명 글의 절 이름이 장 소스에 있는지 - 절 이름을 바꾸면 글이 조용히 빠지지 않도록
+    pub fn validate_prose(&self) -> Result<Vec<Issue>, ContentError> {
+        let mut issues = Vec::new();
+        for info in chapters::CHAPTERS {
+            let prose = self.load_prose(info)?;
+            let all = sections::sections(info);
+            for (name, _) in &prose.sections {
+                if !all.iter().any(|s| &s.name == name) {
+                    issues.push(Issue {
+                        location: format!("prose/{}#{}", prose_file_name(info), name),
+                        message: format!("{}장에 '{}' 절이 없음", info.id, name),
+                    });
+                }
+            }
+        }
+        Ok(issues)
+    }
+
+    // 설명 글 파일의 위치 - 내장 콘텐츠는 바뀌지 않으므로 None (render --watch 가 감시)
+    pub fn prose_path(&self, info: &ChapterInfo) -> Option<PathBuf> {
+        match self {
+            ContentSource::Embedded => None,
+            ContentSource::Dir(dir) => Some(dir.join("prose").join(prose_file_name(info))),
+        }
+    }
+}
+
+impl fmt::Display for ContentSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentSource::Embedded => write!(f, "내장 콘텐츠"),
+            ContentSource::Dir(dir) => write!(f, "{}", dir.display()),
+        }
+    }
+}
+
+// 학습 도구가 쓰는 기본 콘텐츠
+pub fn source() -> ContentSource {
+    ContentSource::detect()
+}
+
+// ----------------------------------------------------------------------------
+// 레슨 설명 글
+// ----------------------------------------------------------------------------
+// 코드가 있는 절은 지금처럼 장 소스(_NN_*.rs)에 두고, 덧붙이는 설명만 Markdown 파일로
+// 고쳐도 재컴파일 없이 render/serve 에 바로 반영 (content/ 디렉터리에서 읽을 때)
+//
+//   장 머리 설명 뒤에 붙는 글 (첫 ## 앞)
+//
+//   ## custom_iterator          ← 절 이름 (share 11::custom_iterator 의 뒷부분)
+//   이 절의 제목 바로 뒤에 붙는 글
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Prose {
+    pub intro: Option<String>,
+    // 절 이름 → 글 (파일에 나온 순서)
+    pub sections: Vec<(String, String)>,
+}
+
+impl Prose {
+    pub fn parse(text: &str) -> Prose {
+        // (절 이름, 줄들) - 첫 덩어리는 이름 없는 머리 글
+        let mut chunks: Vec<(Option<&str>, Vec<&str>)> = vec![(None, Vec::new())];
+        for line in text.lines() {
+            match line.strip_prefix("## ") {
+                Some(name) => chunks.push((Some(name.trim()), Vec::new())),
+                None => chunks.last_mut().unwrap().1.push(line),
+            }
+        }
+
+        let mut prose = Prose::default();
+        for (name, lines) in chunks {
+            let text = lines.join("\n").trim().to_string();
+            match name {
+                _ if text.is_empty() => {}
+                Some(name) => prose.sections.push((name.to_string(), text)),
+                None => prose.intro = Some(text),
+            }
+        }
+        prose
+    }
+
+    pub fn section(&self, name: &str) -> Option<&str> {
+        self.sections
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, text)| text.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intro.is_none() && self.sections.is_empty()
+    }
+}
+
+// "07_traits.md"
+fn prose_file_name(info: &ChapterInfo) -> String {
+    format!("{}_{}.md", info.id, info.slug)
+}
+
+fn checked(bank: ContentBank) -> Result<ContentBank, ContentError> {
+    let issues = validate(&bank);
+    if issues.is_empty() {
+        Ok(bank)
+    } else {
+        Err(ContentError::Invalid(issues))
+    }
+}
+
+// 기본 위치: RUST_STUDY_CONTENT 환경 변수 또는 크레이트의 content/
+#[cfg(not(target_arch = "wasm32"))]
+pub fn content_dir() -> PathBuf {
+    match std::env::var_os("RUST_STUDY_CONTENT") {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("content"),
+    }
+}
+
+// 로드 + 검증 - 규칙 위반이 하나라도 있으면 Invalid
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(dir: &Path) -> Result<ContentBank, ContentError> {
+    checked(load_unchecked(dir)?)
+}
+
+// 파싱만 하고 검증은 하지 않음 (check 명령에서 모든 문제를 한 번에 보여줄 때)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_unchecked(dir: &Path) -> Result<ContentBank, ContentError> {
+    let io_err = |source| ContentError::Io { path: dir.to_path_buf(), source };
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(io_err)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()
+        .map_err(io_err)?;
+    // 디렉터리 순회 순서는 OS마다 다름 - 정렬해서 결과를 고정
+    paths.sort();
+
+    let mut bank = ContentBank::default();
+    for path in paths {
+        if let Some(chapter) = parse_file(&path)? {
+            bank.chapters.push(chapter);
+        }
+    }
+    load_solutions(&dir.join("solutions"), &mut bank)?;
+    Ok(bank)
+}
+
+// solutions/<연습문제 id>.rs - 디렉터리가 없으면 참고 답안 없음
+#[cfg(not(target_arch = "wasm32"))]
+fn load_solutions(dir: &Path, bank: &mut ContentBank) -> Result<(), ContentError> {
+    let io_err = |path: &Path, source| ContentError::Io { path: path.to_path_buf(), source };
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(io_err(dir, e)),
+    };
+    for entry in entries {
+        let path = entry.map_err(|e| io_err(dir, e))?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("rs") {
+            continue;
+        }
+        let text = fs::read_to_string(&path).map_err(|e| io_err(&path, e))?;
+        let id = path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+        bank.solutions.insert(id, text);
+    }
+    Ok(())
+}
+
+// 확장자로 형식 결정 - 지원하지 않는 파일(README 등)은 None
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_file(path: &Path) -> Result<Option<ChapterContent>, ContentError> {
+    let ext = path.extension().and_then(|e| e.to_str());
+    if !matches!(ext, Some("toml") | Some("json")) {
+        return Ok(None);
+    }
+
+    let text = fs::read_to_string(path).map_err(|source| ContentError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    parse(path, &text).map(Some)
+}
+
+// 파일 내용 하나 - 형식은 path 의 확장자로 (toml 이 아니면 JSON)
+pub fn parse(path: &Path, text: &str) -> Result<ChapterContent, ContentError> {
+    let parse_err = |message: String| ContentError::Parse {
+        path: path.to_path_buf(),
+        message,
+    };
+
+    let mut chapter: ChapterContent = match path.extension().and_then(|e| e.to_str()) {
+        Some("toml") => toml::from_str(text).map_err(|e| parse_err(e.to_string()))?,
+        _ => serde_json::from_str(text).map_err(|e| parse_err(e.to_string()))?,
+    };
+    chapter.source = path.to_path_buf();
+    Ok(chapter)
+}
+
+// ----------------------------------------------------------------------------
+// 검증
+// ----------------------------------------------------------------------------
+
+pub fn validate(bank: &ContentBank) -> Vec<Issue> {
+    let known: Vec<&str> = chapters::CHAPTERS.iter().map(|c| c.id).collect();
+    validate_with(bank, &known)
+}
+
+// 알려진 장 목록을 직접 지정 - 레슨 팩처럼 기본 장이 아닌 콘텐츠 검증용
+pub fn validate_with(bank: &ContentBank, known: &[&str]) -> Vec<Issue> {
+    use std::collections::HashSet;
+
+    let mut issues = Vec::new();
+    let mut chapter_ids = HashSet::new();
+    let mut item_ids = HashSet::new();
+
+    for chapter in &bank.chapters {
+        let file = chapter
+            .source
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| chapter.chapter.clone());
+        let mut issue = |location: String, message: String| {
+            issues.push(Issue { location, message });
+        };
+
+        if !known.contains(&chapter.chapter.as_str()) {
+            issue(file.clone(), format!("존재하지 않는 장 '{}'", chapter.chapter));
+        }
+        if !chapter_ids.insert(chapter.chapter.clone()) {
+            issue(file.clone(), format!("장 '{}'이 여러 파일에 있음", chapter.chapter));
+        }
+        if !file.starts_with(&chapter.chapter) && !chapter.source.as_os_str().is_empty() {
+            issue(file.clone(), format!("파일 이름은 '{}'로 시작해야 함", chapter.chapter));
+        }
+
+        // id 규칙: "<장 번호>-<이름>", 전체 콘텐츠에서 유일
+        let prefix = format!("{}-", chapter.chapter);
+        let ids = chapter
+            .questions
+            .iter()
+            .map(|q| &q.id)
+            .chain(chapter.exercises.iter().map(|e| &e.id));
+        for id in ids {
+            if !id.starts_with(&prefix) {
+                issue(format!("{}#{}", file, id), format!("id는 '{}'로 시작해야 함", prefix));
+            }
+            if !item_ids.insert(id.clone()) {
+                issue(format!("{}#{}", file, id), String::from("중복된 id"));
+            }
+        }
+
+        for q in &chapter.questions {
+            let loc = format!("{}#{}", file, q.id);
+            if q.prompt.trim().is_empty() {
+                issue(loc.clone(), String::from("prompt가 비어 있음"));
+            }
+            if q.choices.len() < 2 {
+                issue(loc.clone(), String::from("선택지는 2개 이상이어야 함"));
+            }
+            if q.answer >= q.choices.len() {
+                issue(
+                    loc.clone(),
+                    format!("answer {}가 선택지 범위(0..{})를 벗어남", q.answer, q.choices.len()),
+                );
+            }
+            let unique: HashSet<_> = q.choices.iter().collect();
+            if unique.len() != q.choices.len() {
+                issue(loc, String::from("중복된 선택지"));
+            }
+        }
+
+        for e in &chapter.exercises {
+            let loc = format!("{}#{}", file, e.id);
+            if e.title.trim().is_empty() || e.description.trim().is_empty() {
+                issue(loc, String::from("title/description이 비어 있음"));
+            }
+        }
+
+        // 변경 내역: 버전마다 하나, 현재 버전 이하, 버전을 올렸으면 그 버전의 내역 필수
+        let mut versions = HashSet::new();
+        for entry in &chapter.changelog {
+            let loc = format!("{}#changelog-{}", file, entry.version);
+            if entry.version == 0 || entry.version > chapter.version {
+                issue(
+                    loc.clone(),
+                    format!("버전 {}이 장 버전 {}보다 큼", entry.version, chapter.version),
+                );
+            }
+            if !versions.insert(entry.version) {
+                issue(loc.clone(), String::from("중복된 버전"));
+            }
+            if !is_date(&entry.date) {
+                issue(loc.clone(), format!("날짜는 YYYY-MM-DD 형식이어야 함: {}", entry.date));
+            }
+            if entry.changes.is_empty() {
+                issue(loc, String::from("changes가 비어 있음"));
+            }
+        }
+        if chapter.version > 1 && !versions.contains(&chapter.version) {
+            issue(
+                file.clone(),
+                format!("버전 {}의 changelog 항목이 없음", chapter.version),
+            );
+        }
+    }
+
+    let exercise_ids: HashSet<&str> = bank
+        .chapters
+        .iter()
+        .flat_map(|c| &c.exercises)
+        .map(|e| e.id.as_str())
+        .collect();
+    for id in bank.solutions.keys() {
+        if !exercise_ids.contains(id.as_str()) {
+            issues.push(Issue {
+                location: format!("solutions/{}.rs", id),
+                message: String::from("연습문제가 없는 참고 답안"),
+            });
+        }
+    }
+
+    issues
+}
+
+fn is_date(text: &str) -> bool {
+    let parts: Vec<&str> = text.split('-').collect();
+    matches!(parts.as_slice(), [y, m, d]
+        if y.len() == 4 && m.len() == 2 && d.len() == 2
+            && parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chapter_from_toml(text: &str) -> ChapterContent {
+        let mut c: ChapterContent = toml::from_str(text).unwrap();
+        c.source = PathBuf::from(format!("{}_test.toml", c.chapter));
+        c
+    }
+
+    fn bank(chapters: Vec<ChapterContent>) -> ContentBank {
+        ContentBank {
+            chapters,
+            ..ContentBank::default()
+        }
+    }
+
+    const VALID: &str = r#"
+        chapter = "02"
+
+        [[questions]]
+        id = "02-move"
+        prompt = "let b = a; 이후 a는?"
+        choices = ["사용 가능", "이동되어 사용 불가"]
+        answer = 1
+
+        [[exercises]]
+        id = "02-ex-clone"
+        title = "clone 연습"
+        description = "이동 대신 복제하기"
+        difficulty = "easy"
+    "#;
+
+    // 저장소에 들어 있는 실제 콘텐츠가 항상 유효한지 검사
+    #[test]
+    fn shipped_content_is_valid() {
+        let bank = load(&content_dir()).unwrap_or_else(|e| panic!("{}", e));
+        assert!(!bank.chapters.is_empty());
+        assert!(bank.questions().count() >= bank.chapters.len());
+    }
+
+    // 배포 바이너리에 내장된 콘텐츠가 저장소의 content/ 와 같은지
+    #[test]
+    fn embedded_content_matches_content_dir() {
+        let embedded = ContentSource::Embedded.load().unwrap_or_else(|e| panic!("{}", e));
+        let on_disk = load(&content_dir()).unwrap();
+        assert_eq!(embedded.chapters.len(), on_disk.chapters.len());
+        assert_eq!(embedded.questions().count(), on_disk.questions().count());
+        assert_eq!(embedded.solutions, on_disk.solutions);
+        assert!(embedded.solution("07-ex-shape-trait").is_some());
+    }
+
+    #[test]
+    fn prose_matches_sections() {
+        let info = chapters::find("04").unwrap();
+        let embedded = ContentSource::Embedded.load_prose(info).unwrap();
+        assert_eq!(embedded, ContentSource::Dir(content_dir()).load_prose(info).unwrap());
+        assert!(embedded.section("lifetime_basics").is_some());
+        assert_eq!(ContentSource::Embedded.validate_prose().unwrap(), vec![]);
+
+        // 파일이 없는 장은 빈 글
+        let missing = ContentSource::Dir(PathBuf::from("no/such/dir"));
+        assert!(missing.load_prose(info).unwrap().is_empty());
+    }
+
+    #[test]
+    fn prose_splits_on_section_headings() {
+        let prose = Prose::parse("\n머리 글\n\n## basic_traits \n첫 줄\n둘째 줄\n\n## empty\n\n");
+        assert_eq!(prose.intro.as_deref(), Some("머리 글"));
+        assert_eq!(prose.section("basic_traits"), Some("첫 줄\n둘째 줄"));
+        assert_eq!(prose.section("empty"), None);
+        assert!(Prose::parse("").is_empty());
+    }
+
+    #[test]
+    fn solution_without_exercise_is_reported() {
+        let mut b = bank(vec![chapter_from_toml(VALID)]);
+        b.solutions.insert("02-ex-clone".to_string(), String::new());
+        assert_eq!(validate(&b), vec![]);
+        b.solutions.insert("02-ex-gone".to_string(), String::new());
+        let issues = validate(&b);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location, "solutions/02-ex-gone.rs");
+    }
+
+    #[test]
+    fn valid_chapter_has_no_issues() {
+        let b = bank(vec![chapter_from_toml(VALID)]);
+        assert_eq!(validate(&b), vec![]);
+    }
+
+    #[test]
+    fn answer_out_of_range_is_reported() {
+        let mut c = chapter_from_toml(VALID);
+        c.questions[0].answer = 2;
+        let issues = validate(&bank(vec![c]));
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("범위"));
+    }
+
+    #[test]
+    fn duplicate_ids_across_chapters_are_reported() {
+        let a = chapter_from_toml(VALID);
+        let mut b = chapter_from_toml(VALID);
+        b.chapter = String::from("03");
+        b.source = PathBuf::from("03_test.toml");
+        let issues = validate(&bank(vec![a, b]));
+        // 03 장의 id가 "02-"로 시작하는 문제 2건 + 중복 2건
+        assert_eq!(issues.iter().filter(|i| i.message == "중복된 id").count(), 2);
+    }
+
+    #[test]
+    fn unknown_chapter_is_reported() {
+        let mut c = chapter_from_toml(VALID);
+        c.chapter = String::from("99");
+        c.source = PathBuf::from("99_test.toml");
+        let issues = validate(&bank(vec![c]));
+        assert!(issues.iter().any(|i| i.message.contains("존재하지 않는 장")));
+    }
+
+    #[test]
+    fn too_few_choices_and_empty_prompt() {
+        let mut c = chapter_from_toml(VALID);
+        c.questions[0].prompt = String::from("  ");
+        c.questions[0].choices = vec![String::from("하나")];
+        c.questions[0].answer = 0;
+        let issues = validate(&bank(vec![c]));
+        assert_eq!(issues.len(), 2);
+    }
+
+    #[test]
+    fn changelog_must_cover_current_version() {
+        let mut c = chapter_from_toml(VALID);
+        c.version = 3;
+        c.changelog = vec![
+            ChangeEntry {
+                version: 2,
+                date: String::from("2026-10-17"),
+                changes: vec![String::from("문제 추가")],
+            },
+            ChangeEntry {
+                version: 4,
+                date: String::from("2026/10/18"),
+                changes: vec![],
+            },
+        ];
+        let messages: Vec<String> = validate(&bank(vec![c.clone()]))
+            .into_iter()
+            .map(|i| i.message)
+            .collect();
+        assert_eq!(messages.len(), 4, "{:?}", messages);
+        assert!(messages.iter().any(|m| m.contains("버전 3의 changelog")));
+
+        // 새 것부터, 완료한 버전 이후만
+        c.changelog.truncate(1);
+        assert_eq!(c.changes_since(1).len(), 1);
+        assert!(c.changes_since(2).is_empty());
+    }
+
+    #[test]
+    fn version_defaults_to_one() {
+        let c = chapter_from_toml(VALID);
+        assert_eq!(c.version, 1);
+        assert!(c.changelog.is_empty());
+    }
+
+    #[test]
+    fn unknown_difficulty_fails_to_parse() {
+        let text = VALID.replace("\"easy\"", "\"extreme\"");
+        assert!(toml::from_str::<ChapterContent>(&text).is_err());
+    }
+
+    #[test]
+    fn json_and_toml_are_equivalent() {
+        let from_toml = chapter_from_toml(VALID);
+        let json = serde_json::to_string(&from_toml).unwrap();
+        let from_json: ChapterContent = serde_json::from_str(&json).unwrap();
+        assert_eq!(from_json.questions[0].id, "02-move");
+        assert_eq!(from_json.exercises[0].difficulty, Difficulty::Easy);
+    }
+}
@@ -0,0 +1,189 @@
+// ============================================================================
+// C++ 비교 실행
+// ============================================================================
+// cargo run -- cpp                        C++ 대응 코드가 있는 절 목록
+// cargo run -- cpp 13::shared_state       Rust 예제와 C++ 코드를 실행해 출력을 나란히 비교
+//
+// 주석으로만 보여주던 C++ 코드를 cpp/NN_<절>.cpp 실제 파일로 두고
+// 시스템 컴파일러(CXX, c++, g++, clang++)로 빌드해서 실행
+// 컴파일러가 없으면 Rust 쪽만 보여주고 안내 - 선택 기능
+// ============================================================================
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::output::fit;
+
+pub fn cpp_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("cpp")
+}
+
+#[derive(Debug, Clone)]
+pub struct Compiler {
+    pub program: String,
+    // --version 의 첫 줄
+    pub version: String,
+}
+
+const CANDIDATES: &[&str] = &["c++", "g++", "clang++"];
+
+// CXX 환경 변수가 있으면 그것만, 없으면 흔한 이름을 차례로 시도
+pub fn find_compiler(cxx: Option<String>) -> Option<Compiler> {
+    let candidates: Vec<String> = match cxx {
+        Some(program) => vec![program],
+        None => CANDIDATES.iter().map(|s| s.to_string()).collect(),
+    };
+    candidates.into_iter().find_map(|program| {
+        let output = Command::new(&program).arg("--version").output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let version = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        Some(Compiler { program, version })
+    })
+}
+
+// cpp/ 의 대응 코드 이름 ("13_shared_state") - 정렬해서
+pub fn counterparts(dir: &Path) -> io::Result<Vec<String>> {
+    let mut names: Vec<String> = fs::read_dir(dir)?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "cpp" {
+                return None;
+            }
+            Some(path.file_stem()?.to_string_lossy().into_owned())
+        })
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+// "13::shared_state", "13_shared_state" 모두 파일 이름으로
+pub fn normalize(key: &str) -> String {
+    key.replace("::", "_")
+}
+
+pub fn compile(compiler: &Compiler, source: &Path, exe: &Path) -> io::Result<()> {
+    let output = Command::new(&compiler.program)
+        .args(["-std=c++20", "-O1", "-pthread", "-o"])
+        .arg(exe)
+        .arg(source)
+        .output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "{} 컴파일 실패:\n{}",
+            source.display(),
+            String::from_utf8_lossy(&output.stderr)
+        )))
+    }
+}
+
+fn stdout_of(command: &mut Command) -> io::Result<String> {
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "실행 실패 ({}):\n{}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// 같은 절의 cargo 예제 출력 (examples/NN_<절>.rs)
+pub fn run_rust(name: &str) -> io::Result<String> {
+    stdout_of(
+        Command::new(env!("CARGO"))
+            .args(["run", "--quiet", "--example", name])
+            .current_dir(env!("CARGO_MANIFEST_DIR")),
+    )
+}
+
+pub fn run_cpp(compiler: &Compiler, name: &str) -> io::Result<String> {
+    let source = cpp_dir().join(format!("{}.cpp", name));
+    if !source.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} 의 C++ 대응 코드가 없습니다", name),
+        ));
+    }
+    let dir = std::env::temp_dir().join(format!("rust-study-cpp-{}", std::process::id()));
+    fs::create_dir_all(&dir)?;
+    let exe = dir.join(name);
+    let result = compile(compiler, &source, &exe).and_then(|_| stdout_of(&mut Command::new(&exe)));
+    let _ = fs::remove_dir_all(&dir);
+    result
+}
+
+// ----------------------------------------------------------------------------
+// 나란히 출력
+// ----------------------------------------------------------------------------
+
+// 두 출력을 줄 단위로 나란히 - 다른 줄은 가운데에 ≠ 표시
+pub fn side_by_side(left: &str, right: &str, column: usize) -> String {
+    let left: Vec<&str> = left.lines().collect();
+    let right: Vec<&str> = right.lines().collect();
+    let mut out = format!("{} │ {}\n", fit("Rust", column), "C++");
+    out.push_str(&format!(
+        "{}─┼─{}\n",
+        "─".repeat(column),
+        "─".repeat(column)
+    ));
+    for i in 0..left.len().max(right.len()) {
+        let l = left.get(i).copied().unwrap_or_default();
+        let r = right.get(i).copied().unwrap_or_default();
+        let mark = if l == r { '│' } else { '≠' };
+        out.push_str(format!("{} {} {}", fit(l, column), mark, r).trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_counterpart_has_a_rust_example() {
+        let names = counterparts(&cpp_dir()).unwrap();
+        assert!(!names.is_empty());
+        for name in names {
+            let example = crate::examples::examples_dir().join(format!("{}.rs", name));
+            assert!(example.exists(), "{} 에 대응하는 예제가 없습니다", name);
+        }
+    }
+
+    #[test]
+    fn side_by_side_marks_differences() {
+        let text = side_by_side("같음\nRust 만\n", "같음\nC++ 만\n끝\n", 10);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[2], "같음       │ 같음");
+        assert_eq!(lines[3], "Rust 만    ≠ C++ 만");
+        assert_eq!(lines[4], "           ≠ 끝");
+    }
+
+    #[test]
+    fn key_forms_normalize_to_file_names() {
+        assert_eq!(normalize("13::shared_state"), "13_shared_state");
+        assert_eq!(normalize("13_shared_state"), "13_shared_state");
+    }
+
+    // 컴파일러가 없는 환경에서는 건너뜀
+    #[test]
+    fn counterparts_compile_when_a_compiler_exists() {
+        let Some(compiler) = find_compiler(std::env::var("CXX").ok()) else {
+            eprintln!("C++ 컴파일러가 없어 건너뜀");
+            return;
+        };
+        let output = run_cpp(&compiler, "12_drop_trait").unwrap();
+        assert!(output.contains("CustomSmartPointer 해제: other stuff"));
+    }
+}
@@ -0,0 +1,364 @@
+// ============================================================================
+// 환경 진단 (doctor)
+// ============================================================================
+// cargo run -- doctor
+// "제 컴퓨터에서는 깨져 보여요" 문제를 줄이기 위해 환경을 점검하고 해결 방법을 안내
+//   - rustc/cargo 버전, 빌드 설정
+//   - 터미널: 색상, 유니코드(한글/박스 문자), 크기
+//   - 선택 도구: git, mdbook, cargo-expand
+//   - 로캘
+// ============================================================================
+
+use std::env;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::process::Command;
+
+use crate::terminal;
+
+// 이 크레이트가 쓰는 표준 라이브러리 API 기준 (Option::is_some_and 등)
+pub const MIN_RUST: (u32, u32) = (1, 70);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    // 문제가 있을 때 해결 방법
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Check {
+        Check {
+            name,
+            status: Status::Ok,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Check {
+        Check {
+            name,
+            status: Status::Warn,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Check {
+        Check {
+            name,
+            status: Status::Fail,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+// 명령의 첫 줄 출력 - 실행할 수 없으면 None
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    text.lines().next().map(|l| l.trim().to_string())
+}
+
+// "rustc 1.95.0 (59807616e 2026-04-14)" → (1, 95)
+pub fn parse_version(text: &str) -> Option<(u32, u32)> {
+    let version = text.split_whitespace().nth(1)?;
+    let mut parts = version.split(['.', '-']);
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+fn check_toolchain(name: &'static str, program: &str) -> Check {
+    match command_output(program, &["--version"]) {
+        None => Check::fail(
+            name,
+            format!("{} 를 실행할 수 없음", program),
+            "https://rustup.rs 에서 rustup 을 설치하세요",
+        ),
+        Some(text) => match parse_version(&text) {
+            Some(v) if v < MIN_RUST => Check::fail(
+                name,
+                text,
+                format!(
+                    "Rust {}.{} 이상이 필요합니다: rustup update stable",
+                    MIN_RUST.0, MIN_RUST.1
+                ),
+            ),
+            _ => Check::ok(name, text),
+        },
+    }
+}
+
+// 켜진 cargo feature 목록 - Cargo.toml [features] 에 추가하면 여기에도 추가
+const FEATURES: &[(&str, bool)] = &[];
+
+fn check_build() -> Check {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    let features: Vec<&str> = FEATURES
+        .iter()
+        .filter(|(_, on)| *on)
+        .map(|(n, _)| *n)
+        .collect();
+    let features = if features.is_empty() {
+        "없음".to_string()
+    } else {
+        features.join(", ")
+    };
+    Check::ok(
+        "빌드",
+        format!(
+            "{} 빌드, {}-{}, feature: {}",
+            profile,
+            env::consts::OS,
+            env::consts::ARCH,
+            features
+        ),
+    )
+}
+
+// ----------------------------------------------------------------------------
+// 터미널
+// ----------------------------------------------------------------------------
+
+// 환경 변수 조회를 함수로 받음 - 테스트에서 임의의 환경을 흉내 내기 위해
+pub fn check_color(var: &dyn Fn(&str) -> Option<String>, is_terminal: bool) -> Check {
+    if var("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+        return Check::ok("색상", "NO_COLOR 설정됨 - 색상 없이 출력");
+    }
+    if !is_terminal {
+        return Check::ok(
+            "색상",
+            "출력이 터미널이 아님 (파이프/파일) - 색상 없이 출력",
+        );
+    }
+    match var("TERM").as_deref() {
+        Some("dumb") => Check::warn(
+            "색상",
+            "TERM=dumb - 색상과 커서 이동을 쓸 수 없음",
+            "xterm-256color 를 지원하는 터미널을 쓰거나 TERM 을 설정하세요",
+        ),
+        Some(term) => {
+            let truecolor = var("COLORTERM").is_some_and(|v| v == "truecolor" || v == "24bit");
+            let depth = if truecolor {
+                "트루컬러"
+            } else if term.contains("256") {
+                "256색"
+            } else {
+                "16색"
+            };
+            Check::ok("색상", format!("TERM={} ({})", term, depth))
+        }
+        // Windows 콘솔은 TERM 이 없음 - ANSI 이스케이프 처리를 켤 수 있으면 색상 지원
+        None if cfg!(windows) && terminal::enable_ansi() => Check::ok("색상", "Windows 콘솔 (ANSI 사용)"),
+        None if cfg!(windows) => Check::warn(
+            "색상",
+            "ANSI 이스케이프를 해석하지 못하는 콘솔",
+            "Windows Terminal 이나 Windows 10 이후의 콘솔을 쓰거나 NO_COLOR=1 로 색상을 끄세요",
+        ),
+        None => Check::warn(
+            "색상",
+            "TERM 이 설정되지 않음",
+            "터미널 에뮬레이터 안에서 실행하거나 NO_COLOR=1 로 색상을 끄세요",
+        ),
+    }
+}
+
+pub fn check_locale(var: &dyn Fn(&str) -> Option<String>) -> Check {
+    // 우선순위: LC_ALL > LC_CTYPE > LANG
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|k| var(k).filter(|v| !v.is_empty()));
+    match locale {
+        Some(l) if l.to_uppercase().replace('-', "").contains("UTF8") => Check::ok("로캘", l),
+        Some(l) => Check::warn(
+            "로캘",
+            format!("{} - UTF-8 이 아니면 한글과 박스 문자가 깨질 수 있음", l),
+            "export LANG=ko_KR.UTF-8 (또는 en_US.UTF-8)",
+        ),
+        None if cfg!(windows) => Check::warn(
+            "로캘",
+            "Windows 콘솔 코드 페이지를 확인하세요",
+            "chcp 65001 로 UTF-8 코드 페이지를 사용하세요",
+        ),
+        None => Check::warn(
+            "로캘",
+            "LANG/LC_ALL 이 설정되지 않음",
+            "export LANG=ko_KR.UTF-8",
+        ),
+    }
+}
+
+// 한글은 터미널에서 두 칸 - 줄 맞춤이 어긋나면 글꼴이나 터미널 설정 문제
+fn check_unicode() -> Check {
+    Check::ok(
+        "유니코드",
+        "아래 두 줄의 오른쪽 끝이 맞아야 합니다\n      │한글 넓이│\n      │abcdefgh│\n      ╚════════╝",
+    )
+}
+
+// 배너와 표가 80칸 기준
+const MIN_COLUMNS: usize = 80;
+
+pub fn check_size(columns: Option<usize>, rows: Option<usize>) -> Check {
+    match columns {
+        Some(c) if c < MIN_COLUMNS => Check::warn(
+            "터미널 크기",
+            format!("{}칸 - 표와 배너가 줄바꿈될 수 있음", c),
+            format!("창을 {}칸 이상으로 넓히세요", MIN_COLUMNS),
+        ),
+        Some(c) => Check::ok(
+            "터미널 크기",
+            match rows {
+                Some(r) => format!("{}x{}", c, r),
+                None => format!("{}칸", c),
+            },
+        ),
+        None => Check::ok("터미널 크기", "알 수 없음 (터미널이 아니고 COLUMNS 미설정)"),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 선택 도구
+// ----------------------------------------------------------------------------
+
+fn check_tool(
+    name: &'static str,
+    program: &str,
+    args: &[&str],
+    used_for: &str,
+    install: &str,
+) -> Check {
+    match command_output(program, args) {
+        Some(version) => Check::ok(name, version),
+        None => Check::warn(name, format!("없음 - {}", used_for), install),
+    }
+}
+
+fn check_writable(name: &'static str, dir: &Path) -> Check {
+    let probe = dir.join(".doctor-probe");
+    let result = std::fs::create_dir_all(dir).and_then(|_| std::fs::write(&probe, b""));
+    let _ = std::fs::remove_file(&probe);
+    match result {
+        Ok(()) => Check::ok(name, dir.display().to_string()),
+        Err(e) => Check::fail(
+            name,
+            format!("{}: {}", dir.display(), e),
+            "쓰기 권한이 있는 디렉터리에서 실행하거나 RUST_STUDY_HOME 을 지정하세요",
+        ),
+    }
+}
+
+pub fn run_checks() -> Vec<Check> {
+    let var = |k: &str| env::var(k).ok();
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let (cols, rows) = terminal::size();
+
+    vec![
+        check_toolchain("rustc", "rustc"),
+        check_toolchain("cargo", &cargo),
+        check_build(),
+        check_color(&var, std::io::stdout().is_terminal()),
+        check_locale(&var),
+        check_unicode(),
+        check_size(cols, rows),
+        check_tool(
+            "git",
+            "git",
+            &["--version"],
+            "lessons add 에 필요",
+            "https://git-scm.com 에서 설치",
+        ),
+        check_tool(
+            "mdbook",
+            "mdbook",
+            &["--version"],
+            "문서를 책 형태로 볼 때 사용",
+            "cargo install mdbook",
+        ),
+        check_tool(
+            "cargo-expand",
+            &cargo,
+            &["expand", "--version"],
+            "15장 매크로 확장 결과를 볼 때 사용",
+            "cargo install cargo-expand",
+        ),
+        check_writable("상태 디렉터리", &crate::progress::state_dir()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env_of(pairs: &'static [(&'static str, &'static str)]) -> impl Fn(&str) -> Option<String> {
+        move |k| {
+            pairs
+                .iter()
+                .find(|(key, _)| *key == k)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[test]
+    fn parses_toolchain_versions() {
+        assert_eq!(
+            parse_version("rustc 1.95.0 (59807616e 2026-04-14)"),
+            Some((1, 95))
+        );
+        assert_eq!(
+            parse_version("cargo 1.80.0-nightly (abc 2024-05-01)"),
+            Some((1, 80))
+        );
+        assert_eq!(parse_version("garbage"), None);
+        assert!(parse_version("rustc 1.60.0").unwrap() < MIN_RUST);
+    }
+
+    #[test]
+    fn locale_prefers_lc_all_and_needs_utf8() {
+        let c = check_locale(&env_of(&[("LANG", "C"), ("LC_ALL", "ko_KR.UTF-8")]));
+        assert_eq!(c.status, Status::Ok);
+        let c = check_locale(&env_of(&[("LANG", "en_US.utf8")]));
+        assert_eq!(c.status, Status::Ok);
+        let c = check_locale(&env_of(&[("LANG", "C")]));
+        assert_eq!(c.status, Status::Warn);
+        assert!(c.fix.unwrap().contains("UTF-8"));
+    }
+
+    #[test]
+    fn color_respects_no_color_and_dumb_terminals() {
+        let c = check_color(&env_of(&[("NO_COLOR", "1"), ("TERM", "dumb")]), true);
+        assert_eq!(c.status, Status::Ok);
+        let c = check_color(&env_of(&[("TERM", "dumb")]), true);
+        assert_eq!(c.status, Status::Warn);
+        let c = check_color(&env_of(&[("TERM", "xterm-256color")]), true);
+        assert!(c.detail.contains("256색"));
+        let c = check_color(&env_of(&[]), false);
+        assert_eq!(c.status, Status::Ok);
+    }
+
+    #[test]
+    fn narrow_terminal_is_a_warning() {
+        assert_eq!(check_size(Some(60), Some(20)).status, Status::Warn);
+        assert_eq!(check_size(Some(120), Some(40)).detail, "120x40");
+        assert_eq!(check_size(None, None).status, Status::Ok);
+    }
+}
@@ -0,0 +1,95 @@
+// ============================================================================
+// 오늘의 드릴 - 무작위 절 하나
+// ============================================================================
+// cargo run -- drill           아무 장의 아무 절 하나를 골라 실행 (짧은 복습용)
+// cargo run -- drill --show    실행하지 않고 고른 절만 보기
+//
+// 최근에 실행한 장/절(.rust-study/state.toml 의 recent - resume.rs)은 덜 고름
+//   가중치: 최근 목록에 없으면 RECENT + 1, 있으면 최근일수록 작게 (가장 최근 = 1)
+//   장 전체를 실행한 기록("07")은 그 장의 모든 절에 적용
+// 고른 절은 보통 절 실행과 같이 기록되므로 다음 드릴에서는 덜 나옴
+// ============================================================================
+
+use crate::chapter::Chapter;
+use crate::resume::RECENT;
+use crate::rng;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub chapter: &'static str,
+    pub section: &'static str,
+    pub weight: u64,
+}
+
+impl Candidate {
+    pub fn target(&self) -> String {
+        format!("{}:{}", self.chapter, self.section)
+    }
+}
+
+// 모든 절과 가중치 - recent 는 오래된 것부터 (resume::State::recent)
+pub fn candidates(chapters: &[Box<dyn Chapter>], recent: &[String]) -> Vec<Candidate> {
+    // 가장 최근 것이 나이 0
+    let age = |target: &str| recent.iter().rev().position(|t| t == target);
+    chapters
+        .iter()
+        .flat_map(|chapter| {
+            chapter.sections().iter().map(move |(section, _)| {
+                let seen = [age(&format!("{}:{}", chapter.id(), section)), age(chapter.id())]
+                    .into_iter()
+                    .flatten()
+                    .min();
+                Candidate {
+                    chapter: chapter.id(),
+                    section,
+                    weight: seen.map_or(RECENT as u64 + 1, |age| age as u64 + 1),
+                }
+            })
+        })
+        .collect()
+}
+
+// roll 을 가중치 합으로 나눈 나머지가 떨어지는 절
+pub fn pick(candidates: &[Candidate], roll: u64) -> Option<&Candidate> {
+    let total: u64 = candidates.iter().map(|c| c.weight).sum();
+    let mut point = roll.checked_rem(total)?;
+    candidates.iter().find(|c| {
+        if point < c.weight {
+            return true;
+        }
+        point -= c.weight;
+        false
+    })
+}
+
+// 드릴마다 다른 절 - --seed 가 있으면 같은 절 (rng.rs)
+pub fn roll() -> u64 {
+    rng::for_tool("drill").next_u64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_sections_weigh_less() {
+        let chapters = crate::all_chapters();
+        let recent = vec!["07".to_string(), "12:box_pointer".to_string()];
+        let all = candidates(&chapters, &recent);
+        let weight = |target: &str| all.iter().find(|c| c.target() == target).unwrap().weight;
+        assert_eq!(weight("12:box_pointer"), 1);
+        assert_eq!(weight("07:trait_objects"), 2);
+        assert_eq!(weight("01:variables"), RECENT as u64 + 1);
+        assert_eq!(all.len(), chapters.iter().map(|c| c.sections().len()).sum::<usize>());
+    }
+
+    #[test]
+    fn picks_by_weight() {
+        let candidate = |section, weight| Candidate { chapter: "01", section, weight };
+        let list = [candidate("a", 1), candidate("b", 3), candidate("c", 2)];
+        let picked: Vec<&str> = (0..6).map(|roll| pick(&list, roll).unwrap().section).collect();
+        assert_eq!(picked, ["a", "b", "b", "b", "c", "c"]);
+        assert_eq!(pick(&list, 6).unwrap().section, "a");
+        assert!(pick(&[], 3).is_none());
+    }
+}
@@ -0,0 +1,144 @@
+// ============================================================================
+// 절 → cargo 예제 생성
+// ============================================================================
+// 각 절을 examples/NN_<절 이름>.rs 로 내보냄
+//   cargo run --example 12_refcell_pointer
+//
+// 예제 파일은 장 소스에서 생성되므로 직접 고치지 않음
+//   cargo run -- examples sync    장 소스가 바뀐 뒤 다시 생성
+//   cargo run -- check            예제가 장 소스와 같은지 검사 (콘텐츠 검증과 함께)
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::chapters;
+use crate::sections;
+
+const GENERATED_HEADER: &str =
+    "// 자동 생성 파일 - 직접 수정하지 마세요 (cargo run -- examples sync)\n";
+
+pub fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+}
+
+// 파일 이름 → 내용
+pub fn expected() -> BTreeMap<String, String> {
+    chapters::CHAPTERS
+        .iter()
+        .flat_map(sections::sections)
+        .map(|s| {
+            let name = format!("{}_{}.rs", s.chapter, s.name);
+            (
+                name,
+                format!("{}{}", GENERATED_HEADER, sections::snippet(&s)),
+            )
+        })
+        .collect()
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct SyncReport {
+    pub missing: Vec<String>,
+    pub stale: Vec<String>,
+    // 더 이상 없는 절의 예제
+    pub orphaned: Vec<String>,
+}
+
+impl SyncReport {
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.stale.is_empty() && self.orphaned.is_empty()
+    }
+}
+
+// 생성 파일만 비교 - 머리 주석이 없는 손으로 쓴 예제는 건드리지 않음
+pub fn compare(dir: &Path, expected: &BTreeMap<String, String>) -> io::Result<SyncReport> {
+    let mut report = SyncReport::default();
+    for (name, contents) in expected {
+        match fs::read_to_string(dir.join(name)) {
+            Ok(actual) if actual == *contents => {}
+            Ok(_) => report.stale.push(name.clone()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => report.missing.push(name.clone()),
+            Err(e) => return Err(e),
+        }
+    }
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(report),
+        Err(e) => return Err(e),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if !expected.contains_key(name) && is_generated(&path) {
+            report.orphaned.push(name.to_string());
+        }
+    }
+    report.orphaned.sort();
+    Ok(report)
+}
+
+fn is_generated(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|text| text.starts_with(GENERATED_HEADER))
+}
+
+// 누락/변경된 예제를 쓰고 고아 예제를 지움
+pub fn sync(dir: &Path) -> io::Result<SyncReport> {
+    let expected = expected();
+    let report = compare(dir, &expected)?;
+    fs::create_dir_all(dir)?;
+    for name in report.missing.iter().chain(&report.stale) {
+        fs::write(dir.join(name), &expected[name])?;
+    }
+    for name in &report.orphaned {
+        fs::remove_file(dir.join(name))?;
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 장 소스를 고치고 examples sync 를 잊으면 실패
+    #[test]
+    fn shipped_examples_are_in_sync() {
+        let report = compare(&examples_dir(), &expected()).unwrap();
+        assert!(
+            report.is_clean(),
+            "cargo run -- examples sync 필요: {:?}",
+            report
+        );
+    }
+
+    #[test]
+    fn compare_reports_missing_stale_and_orphaned() {
+        let dir = std::env::temp_dir().join(format!("examples-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("01_a.rs"), "same").unwrap();
+        fs::write(dir.join("01_b.rs"), "old").unwrap();
+        fs::write(
+            dir.join("99_gone.rs"),
+            format!("{}fn main() {{}}", GENERATED_HEADER),
+        )
+        .unwrap();
+        fs::write(dir.join("hand_written.rs"), "fn main() {}").unwrap();
+
+        let expected: BTreeMap<String, String> =
+            [("01_a.rs", "same"), ("01_b.rs", "new"), ("01_c.rs", "c")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+        let report = compare(&dir, &expected).unwrap();
+        assert_eq!(report.missing, vec!["01_c.rs"]);
+        assert_eq!(report.stale, vec!["01_b.rs"]);
+        assert_eq!(report.orphaned, vec!["99_gone.rs"]);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
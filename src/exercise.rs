@@ -0,0 +1,387 @@
+// ============================================================================
+// 연습문제 제출 (git)
+// ============================================================================
+// cargo run -- exercise list                   연습문제와 제출 상태
+// cargo run -- exercise start 07-ex-shape-trait   exercises/<id>.rs 에 답안 파일 생성
+// cargo run -- exercise submit <id> [메시지]     답안을 study/<프로필> 브랜치에 커밋
+// cargo run -- exercise diff <id>              내 답안과 참고 답안 비교
+// cargo run -- exercise review [--out <파일>]   멘토 검토용 마크다운 요약
+//
+// 작업 트리와 지금 브랜치는 건드리지 않음 - 임시 인덱스(GIT_INDEX_FILE)로 트리를 만들고
+// commit-tree, update-ref 로 study/<프로필> 브랜치만 앞으로 옮김
+// 멘토는 git log study/jihoon, git show 등 평소 도구로 검토
+// 참고 답안은 content/solutions/<id>.rs (바이너리에도 내장 - ContentBank::solutions)
+// ============================================================================
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::content::Exercise;
+use crate::profile;
+
+#[derive(Debug)]
+pub enum ExerciseError {
+    Io(io::Error),
+    Git(String),
+    Command(String),
+}
+
+impl fmt::Display for ExerciseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExerciseError::Io(e) => write!(f, "{}", e),
+            ExerciseError::Git(msg) => write!(f, "git 실패: {}", msg),
+            ExerciseError::Command(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ExerciseError {}
+
+impl From<io::Error> for ExerciseError {
+    fn from(e: io::Error) -> Self {
+        ExerciseError::Io(e)
+    }
+}
+
+// 답안 브랜치의 커밋 하나
+#[derive(Debug, Clone, PartialEq)]
+pub struct Commit {
+    pub hash: String,
+    pub date: String,
+    pub subject: String,
+}
+
+// 답안을 두는 저장소와 제출 브랜치
+pub struct Workspace {
+    root: PathBuf,
+    pub branch: String,
+}
+
+impl Workspace {
+    // 지금 디렉터리의 저장소, 지금 프로필의 브랜치
+    pub fn current() -> Workspace {
+        Workspace::new(".", profile::active().unwrap_or(profile::DEFAULT))
+    }
+
+    pub fn new(root: impl Into<PathBuf>, profile: &str) -> Workspace {
+        Workspace {
+            root: root.into(),
+            branch: format!("study/{}", profile),
+        }
+    }
+
+    // 저장소 안 경로 - 브랜치 트리에서도 같은 경로
+    pub fn answer_path(id: &str) -> String {
+        format!("exercises/{}.rs", id)
+    }
+
+    fn git(&self, args: &[&str], index: Option<&Path>) -> Result<String, ExerciseError> {
+        let mut command = Command::new("git");
+        command.current_dir(&self.root).args(args);
+        if let Some(index) = index {
+            command.env("GIT_INDEX_FILE", index);
+        }
+        let output = command
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| ExerciseError::Command(format!("git 실행 실패: {}", e)))?;
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).trim_end().to_string())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(ExerciseError::Git(format!("git {}: {}", args.join(" "), stderr.trim())))
+        }
+    }
+
+    fn branch_head(&self) -> Result<Option<String>, ExerciseError> {
+        let reference = format!("refs/heads/{}", self.branch);
+        match self.git(&["rev-parse", "--verify", "--quiet", &reference], None) {
+            Ok(hash) => Ok(Some(hash)),
+            // 아직 한 번도 제출하지 않음
+            Err(ExerciseError::Git(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    // 설명과 힌트를 주석으로 넣은 답안 파일 - 이미 있으면 그대로 둠 (false)
+    pub fn start(&self, exercise: &Exercise) -> Result<(PathBuf, bool), ExerciseError> {
+        let path = self.root.join(Workspace::answer_path(&exercise.id));
+        if path.exists() {
+            return Ok((path, false));
+        }
+        let mut text = format!("// {}: {}\n// {}\n", exercise.id, exercise.title, exercise.description);
+        for hint in &exercise.hints {
+            text.push_str(&format!("// 힌트: {}\n", hint));
+        }
+        text.push_str("\nfn main() {\n}\n");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, text)?;
+        Ok((path, true))
+    }
+
+    // 답안 파일 하나를 브랜치에 커밋 - 새 커밋의 짧은 해시
+    pub fn submit(&self, exercise: &Exercise, message: Option<&str>) -> Result<String, ExerciseError> {
+        let path = Workspace::answer_path(&exercise.id);
+        if !self.root.join(&path).exists() {
+            return Err(ExerciseError::Command(format!(
+                "답안 파일이 없습니다: {} (exercise start {} 로 만드세요)",
+                path, exercise.id
+            )));
+        }
+
+        let git_dir = self.git(&["rev-parse", "--absolute-git-dir"], None)?;
+        let index = Path::new(&git_dir).join("rust-study-exercise.index");
+        let _ = fs::remove_file(&index);
+        let result = self.commit_file(&path, &index, exercise, message);
+        let _ = fs::remove_file(&index);
+        result
+    }
+
+    fn commit_file(
+        &self,
+        path: &str,
+        index: &Path,
+        exercise: &Exercise,
+        message: Option<&str>,
+    ) -> Result<String, ExerciseError> {
+        let parent = self.branch_head()?;
+        if let Some(parent) = &parent {
+            self.git(&["read-tree", parent], Some(index))?;
+        }
+        let blob = self.git(&["hash-object", "-w", "--", path], None)?;
+        let cacheinfo = format!("100644,{},{}", blob, path);
+        self.git(&["update-index", "--add", "--cacheinfo", &cacheinfo], Some(index))?;
+        let tree = self.git(&["write-tree"], Some(index))?;
+
+        let default_message = format!("{}: {} 답안", exercise.id, exercise.title);
+        let message = message.unwrap_or(&default_message);
+        let mut args = vec!["commit-tree", tree.as_str(), "-m", message];
+        if let Some(parent) = &parent {
+            let parent_tree = self.git(&["rev-parse", &format!("{}^{{tree}}", parent)], None)?;
+            if parent_tree == tree {
+                return Err(ExerciseError::Command(format!(
+                    "지난 제출 이후 바뀐 내용이 없습니다: {}",
+                    path
+                )));
+            }
+            args.extend(["-p", parent.as_str()]);
+        }
+        let commit = self.git(&args, None)?;
+
+        // 다른 제출이 끼어들었으면 실패 - 브랜치를 덮어쓰지 않음
+        let reference = format!("refs/heads/{}", self.branch);
+        let old = parent.unwrap_or_default();
+        self.git(&["update-ref", &reference, &commit, &old], None)?;
+        self.git(&["rev-parse", "--short", &commit], None)
+    }
+
+    // 브랜치에 있는 답안의 연습문제 id
+    pub fn submitted(&self) -> Result<Vec<String>, ExerciseError> {
+        if self.branch_head()?.is_none() {
+            return Ok(Vec::new());
+        }
+        let list = self.git(&["ls-tree", "--name-only", &self.branch, "exercises/"], None)?;
+        Ok(list
+            .lines()
+            .filter_map(|line| line.strip_prefix("exercises/")?.strip_suffix(".rs"))
+            .map(String::from)
+            .collect())
+    }
+
+    // 답안의 제출 이력 - 최신 먼저
+    pub fn history(&self, id: &str) -> Result<Vec<Commit>, ExerciseError> {
+        if self.branch_head()?.is_none() {
+            return Ok(Vec::new());
+        }
+        let log = self.git(
+            &[
+                "log",
+                "--format=%h%x09%ad%x09%s",
+                "--date=short",
+                &self.branch,
+                "--",
+                &Workspace::answer_path(id),
+            ],
+            None,
+        )?;
+        Ok(log
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.splitn(3, '\t');
+                Some(Commit {
+                    hash: parts.next()?.to_string(),
+                    date: parts.next()?.to_string(),
+                    subject: parts.next()?.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    // 제출한 답안과 참고 답안의 차이 (추가 줄, 삭제 줄)
+    pub fn diff_stat(&self, id: &str, solution: &str) -> Result<(usize, usize), ExerciseError> {
+        let file = reference_file(id, solution)?;
+        let reference = self.git(&["hash-object", "-w", "--", &file.to_string_lossy()], None)?;
+        let submitted = format!("{}:{}", self.branch, Workspace::answer_path(id));
+        let stat = self.git(&["diff", "--numstat", &reference, &submitted], None)?;
+        let mut numbers = stat.split_whitespace().map(|n| n.parse().unwrap_or(0));
+        Ok((numbers.next().unwrap_or(0), numbers.next().unwrap_or(0)))
+    }
+
+    // 작업 중인 답안과 참고 답안 비교를 터미널에 - 차이가 있어도 성공
+    pub fn show_diff(&self, id: &str, solution: &str) -> Result<(), ExerciseError> {
+        let answer = Workspace::answer_path(id);
+        if !self.root.join(&answer).exists() {
+            return Err(ExerciseError::Command(format!("답안 파일이 없습니다: {}", answer)));
+        }
+        let status = Command::new("git")
+            .current_dir(&self.root)
+            .args(["diff", "--no-index", "--"])
+            .arg(reference_file(id, solution)?)
+            .arg(&answer)
+            .status()
+            .map_err(|e| ExerciseError::Command(format!("git 실행 실패: {}", e)))?;
+        // --no-index 는 차이가 있으면 1
+        match status.code() {
+            Some(0 | 1) => Ok(()),
+            _ => Err(ExerciseError::Git(format!("git diff --no-index 실패 ({})", answer))),
+        }
+    }
+}
+
+// 참고 답안을 git 이 읽을 수 있는 파일로 - 내장 콘텐츠에는 경로가 없음
+fn reference_file(id: &str, solution: &str) -> Result<PathBuf, ExerciseError> {
+    let dir = std::env::temp_dir().join("rust-study-solutions");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.rs", id));
+    fs::write(&path, solution)?;
+    Ok(path)
+}
+
+// 멘토가 읽을 마크다운 요약 - 제출한 연습문제마다 난이도, 이력, 참고 답안과의 차이
+pub fn review(
+    workspace: &Workspace,
+    exercises: &[&Exercise],
+    solutions: &BTreeMap<String, String>,
+) -> Result<String, ExerciseError> {
+    let submitted = workspace.submitted()?;
+    let mut out = format!("# 연습문제 검토 요약 ({})\n\n", workspace.branch);
+    out.push_str(&format!(
+        "제출 {}/{}개 - `git log {}` 로 전체 이력\n\n",
+        submitted.len(),
+        exercises.len(),
+        workspace.branch
+    ));
+    out.push_str("| 연습문제 | 난이도 | 제출 횟수 | 마지막 제출 | 참고 답안 대비 |\n");
+    out.push_str("|---|---|---|---|---|\n");
+
+    let mut details = String::new();
+    for exercise in exercises.iter().filter(|e| submitted.contains(&e.id)) {
+        let history = workspace.history(&exercise.id)?;
+        let compared = match solutions.get(&exercise.id) {
+            Some(solution) => {
+                let (added, removed) = workspace.diff_stat(&exercise.id, solution)?;
+                format!("+{} -{}줄", added, removed)
+            }
+            None => "참고 답안 없음".to_string(),
+        };
+        let last = history.first().map_or("-", |c| c.date.as_str());
+        out.push_str(&format!(
+            "| {} {} | {:?} | {} | {} | {} |\n",
+            exercise.id,
+            exercise.title,
+            exercise.difficulty,
+            history.len(),
+            last,
+            compared
+        ));
+
+        details.push_str(&format!("\n## {} {}\n\n{}\n\n", exercise.id, exercise.title, exercise.description));
+        for commit in &history {
+            details.push_str(&format!("- `{}` {} {}\n", commit.hash, commit.date, commit.subject));
+        }
+        details.push_str(&format!(
+            "- 답안: `git show {}:{}`\n",
+            workspace.branch,
+            Workspace::answer_path(&exercise.id)
+        ));
+    }
+    out.push_str(&details);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::Difficulty;
+
+    fn exercise(id: &str) -> Exercise {
+        Exercise {
+            id: id.to_string(),
+            title: "연습".to_string(),
+            description: "설명".to_string(),
+            difficulty: Difficulty::Easy,
+            hints: vec!["힌트 하나".to_string()],
+        }
+    }
+
+    // 사용자 설정과 상관없이 커밋할 수 있는 임시 저장소
+    fn temp_repo(name: &str) -> Workspace {
+        let root = std::env::temp_dir().join(format!("exercise-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let workspace = Workspace::new(&root, "mina");
+        workspace.git(&["init", "--quiet"], None).unwrap();
+        workspace.git(&["config", "user.name", "Mina"], None).unwrap();
+        workspace.git(&["config", "user.email", "mina@example.com"], None).unwrap();
+        workspace
+    }
+
+    #[test]
+    fn submit_commits_to_study_branch_only() {
+        let ws = temp_repo("submit");
+        let ex = exercise("01-ex-test");
+        assert!(ws.submit(&ex, None).is_err()); // 답안 파일 없음
+
+        let (path, created) = ws.start(&ex).unwrap();
+        assert!(created);
+        assert!(fs::read_to_string(&path).unwrap().contains("// 힌트: 힌트 하나"));
+        assert!(!ws.start(&ex).unwrap().1);
+
+        ws.submit(&ex, None).unwrap();
+        assert!(ws.submit(&ex, None).is_err()); // 바뀐 내용 없음
+        fs::write(&path, "fn main() { println!(\"답\"); }\n").unwrap();
+        ws.submit(&ex, Some("두 번째")).unwrap();
+        fs::write(ws.root.join(Workspace::answer_path("02-ex-other")), "fn main() {}\n").unwrap();
+        ws.submit(&exercise("02-ex-other"), None).unwrap();
+
+        assert_eq!(ws.submitted().unwrap(), vec!["01-ex-test", "02-ex-other"]);
+        let history = ws.history("01-ex-test").unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].subject, "두 번째");
+        assert_eq!(history[1].subject, "01-ex-test: 연습 답안");
+
+        // 작업 트리의 인덱스와 HEAD 는 그대로
+        assert_eq!(ws.git(&["status", "--porcelain"], None).unwrap(), "?? exercises/");
+        assert!(ws.git(&["rev-parse", "--verify", "--quiet", "HEAD"], None).is_err());
+
+        let solution = "fn main() {\n    println!(\"답\");\n}\n";
+        assert_eq!(ws.diff_stat("01-ex-test", solution).unwrap(), (1, 3));
+
+        let one = exercise("01-ex-test");
+        let two = exercise("02-ex-other");
+        let solutions = BTreeMap::from([("01-ex-test".to_string(), solution.to_string())]);
+        let summary = review(&ws, &[&one, &two], &solutions).unwrap();
+        assert!(summary.contains("제출 2/2개"));
+        assert!(summary.contains("| 01-ex-test 연습 | Easy | 2 | "));
+        assert!(summary.contains("| +1 -3줄 |"));
+        assert!(summary.contains("참고 답안 없음"));
+        let _ = fs::remove_dir_all(&ws.root);
+    }
+}
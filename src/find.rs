@@ -0,0 +1,186 @@
+// ============================================================================
+// 레슨 전문 검색
+// ============================================================================
+// cargo run -- find dangling             모든 장의 소스에서 찾기
+// cargo run -- find "Rc::new" --context 2 --limit 10
+//
+// serve 의 /search 는 렌더링된 블록 단위로 찾지만, 여기서는 장 소스의 모든 줄을
+// 주석(설명), 문자열(println! 으로 보여주는 한글 문장), 코드로 나눠서 찾음
+// 결과마다 장, 절 키(11::custom_iterator), 소스 줄 번호, 앞뒤 문맥을 보여주고
+// 절 제목 > 주석 > 문자열 > 코드 순, 단어 전체 일치를 우선으로 정렬
+// ============================================================================
+
+use crate::chapters;
+use crate::sections::{self, Section};
+
+pub const DEFAULT_CONTEXT: usize = 1;
+pub const DEFAULT_LIMIT: usize = 20;
+
+// 찾은 줄의 종류 - 순서가 곧 가중치 (뒤쪽일수록 높음)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    Code,
+    Text,
+    Comment,
+    Title,
+}
+
+impl Kind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Kind::Code => "코드",
+            Kind::Text => "문자열",
+            Kind::Comment => "주석",
+            Kind::Title => "절 제목",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Match {
+    pub chapter: &'static str,
+    // 줄이 속한 절 - 장 머리(첫 절 앞)는 None
+    pub section: Option<Section>,
+    // 장 소스에서의 줄 번호 (1부터)
+    pub line: usize,
+    pub kind: Kind,
+    pub score: u32,
+    // 앞뒤 문맥을 포함한 줄들 (줄 번호, 내용)
+    pub context: Vec<(usize, &'static str)>,
+}
+
+impl Match {
+    // "11::custom_iterator" 또는 장 머리이면 "11"
+    pub fn anchor(&self) -> String {
+        match &self.section {
+            Some(s) => s.key(),
+            None => self.chapter.to_string(),
+        }
+    }
+}
+
+// 대소문자 구분 없이 찾고 점수 높은 순으로 (같은 점수는 장, 줄 순서)
+pub fn search(query: &str, context: usize) -> Vec<Match> {
+    let query = query.trim();
+    let mut found = Vec::new();
+    if query.is_empty() {
+        return found;
+    }
+    for info in chapters::CHAPTERS {
+        let Some(source) = chapters::source(info.id) else {
+            continue;
+        };
+        found.extend(search_source(info.id, source, &sections::parse(info.id, source), query, context));
+    }
+    found.sort_by(|a, b| b.score.cmp(&a.score).then(a.chapter.cmp(b.chapter)).then(a.line.cmp(&b.line)));
+    found
+}
+
+fn search_source(
+    chapter: &'static str,
+    source: &'static str,
+    all: &[Section],
+    query: &str,
+    context: usize,
+) -> Vec<Match> {
+    let lines: Vec<&'static str> = source.lines().collect();
+    let lower = query.to_lowercase();
+    let mut found = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        // 절 머리의 구분선은 검색 대상이 아님
+        if sections::is_rule(line) {
+            continue;
+        }
+        let section = all.iter().find(|s| s.lines.contains(&i));
+        let kind = classify(line, section.map(|s| s.lines.start + 1 == i));
+        let Some(score) = score(line, query, &lower, kind) else {
+            continue;
+        };
+        let from = i.saturating_sub(context);
+        let to = (i + context + 1).min(lines.len());
+        found.push(Match {
+            chapter,
+            section: section.cloned(),
+            line: i + 1,
+            kind,
+            score,
+            context: (from..to).map(|n| (n + 1, lines[n])).collect(),
+        });
+    }
+    found
+}
+
+// is_title: 절 머리의 제목 줄인지 (절 밖이면 None)
+fn classify(line: &str, is_title: Option<bool>) -> Kind {
+    let t = line.trim_start();
+    if is_title == Some(true) {
+        Kind::Title
+    } else if t.starts_with("//") {
+        Kind::Comment
+    } else if t.contains('"') {
+        Kind::Text
+    } else {
+        Kind::Code
+    }
+}
+
+// 일치하지 않으면 None - 종류 가중치 + 단어 전체 일치 + 대소문자까지 일치
+fn score(line: &str, query: &str, lower: &str, kind: Kind) -> Option<u32> {
+    let haystack = line.to_lowercase();
+    let at = haystack.find(lower)?;
+    let mut score = (kind as u32 + 1) * 10;
+    if is_whole_word(&haystack, at, lower.len()) {
+        score += 5;
+    }
+    if line.contains(query) {
+        score += 2;
+    }
+    Some(score)
+}
+
+fn is_whole_word(text: &str, at: usize, len: usize) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let before = text[..at].chars().next_back().is_none_or(|c| !is_word(c));
+    let after = text[at + len..].chars().next().is_none_or(|c| !is_word(c));
+    before && after
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranks_titles_and_comments_above_code() {
+        let hits = search("custom", 0);
+        assert!(!hits.is_empty());
+        assert!(hits.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let source = "// 머리\n\
+                      pub const SECTIONS: &[(&str, fn())] = &[\n    (\"demo\", demo),\n];\n\
+                      // ----\n// 커스텀 Demo\n// ----\n\
+                      fn demo() {\n    // demo 설명\n    println!(\"demo\");\n    let demo_x = 1;\n}\n";
+        let all = sections::parse("99", source);
+        let hits = search_source("99", source, &all, "demo", 1);
+        let kinds: Vec<Kind> = hits.iter().map(|m| m.kind).collect();
+        assert!(kinds.contains(&Kind::Title));
+        assert!(kinds.contains(&Kind::Comment));
+        assert!(kinds.contains(&Kind::Text));
+        let title = hits.iter().find(|m| m.kind == Kind::Title).unwrap();
+        assert_eq!(title.line, 6);
+        assert_eq!(title.anchor(), "99::demo");
+        assert_eq!(title.context.len(), 3);
+
+        // 단어 일부로만 들어간 경우는 같은 종류의 단어 일치보다 낮음
+        assert!(score("let demo_x", "demo", "demo", Kind::Code) < score("demo()", "demo", "demo", Kind::Code));
+        // 장 머리는 절 없이 장 번호만
+        let head = search_source("99", source, &all, "머리", 0);
+        assert_eq!(head[0].anchor(), "99");
+    }
+
+    #[test]
+    fn finds_prose_in_lessons() {
+        let hits = search("dangling", 1);
+        assert!(hits.iter().any(|m| m.chapter == "04" || m.chapter == "03"));
+        assert!(search("   ", 1).is_empty());
+    }
+}
@@ -0,0 +1,332 @@
+// ============================================================================
+// 컴파일 에러 갤러리
+// ============================================================================
+// cargo run -- errors            장마다 주석 처리된 "에러!" 코드 목록
+// cargo run -- errors 02-1       코드, 실제 rustc 진단, 설명하는 절을 함께 보기
+//
+// 레슨의 "// println!("{}", s1);  // 에러! ..." 같은 줄을 모아서
+// 해당 절을 독립 프로그램으로 만든 뒤(sections::snippet) 그 줄만 주석을 풀어 rustc 로 컴파일
+// → 설명만 읽던 에러를 실제 컴파일러 메시지로 확인
+// ============================================================================
+
+use std::fs;
+use std::io;
+use std::process::Command;
+
+use crate::chapters;
+use crate::sections::{self, Section};
+
+// 주석 처리된 잘못된 코드 하나
+#[derive(Debug, Clone)]
+pub struct Broken {
+    // "02-1" - 장 안에서 나오는 순서
+    pub id: String,
+    pub section: Section,
+    // 장 소스에서의 줄 번호 (1부터)
+    pub line: usize,
+    // 주석을 그대로 둔 원래 줄들
+    pub commented: Vec<String>,
+    // 줄 끝 주석의 설명 ("s1은 더 이상 유효하지 않음")
+    pub note: String,
+}
+
+impl Broken {
+    // 주석을 푼 코드
+    pub fn code(&self) -> String {
+        self.commented
+            .iter()
+            .map(|line| uncomment(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // 바로 위의 설명 주석 - 어떤 규칙 때문에 에러인지
+    pub fn explanation(&self) -> Vec<&'static str> {
+        let lines: Vec<&'static str> = self.section.code.lines().collect();
+        let at = self.line - 1 - self.section.lines.start;
+        let mut out: Vec<&'static str> = lines[..at]
+            .iter()
+            .rev()
+            .take_while(|l| {
+                l.trim_start().starts_with("//") && !is_broken_line(l) && !looks_like_code(l)
+            })
+            .map(|l| l.trim_start().trim_start_matches('/').trim())
+            .collect();
+        out.reverse();
+        out
+    }
+}
+
+// "    // x = 6;  // 컴파일 에러! ..." → 코드와 설명
+fn split_broken(line: &str) -> Option<(&str, &str)> {
+    let rest = line.trim_start().strip_prefix("// ")?;
+    let (code, note) = rest.split_once("//")?;
+    let code = code.trim();
+    // "NewsArticle { ... }" 처럼 생략이 있는 코드는 그대로 컴파일할 수 없음
+    if code.is_empty() || code.contains("...") || !note.contains("에러!") {
+        return None;
+    }
+    Some((code, note))
+}
+
+fn is_broken_line(line: &str) -> bool {
+    split_broken(line).is_some()
+}
+
+// 들여쓰기는 살리고 "// " 만 제거
+fn uncomment(line: &str) -> String {
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let rest = line.trim_start();
+    let rest = rest
+        .strip_prefix("// ")
+        .or_else(|| rest.strip_prefix("//"))
+        .unwrap_or(rest);
+    format!("{}{}", indent, rest)
+}
+
+// 주석 안의 들여쓰기 ("//     println!" → 4)
+fn inner_indent(line: &str) -> usize {
+    let rest = line.trim_start().trim_start_matches("//");
+    rest.len() - rest.trim_start().len() - 1
+}
+
+// 주석 처리된 코드처럼 보이는 줄 - 설명 문장과 구분
+fn looks_like_code(line: &str) -> bool {
+    let t = line.trim();
+    t.starts_with("//") && [";", "{", "}", ","].iter().any(|end| t.ends_with(end))
+}
+
+// 절 하나에서 찾기 - "{" 로 끝나거나 주석 블록 안에 들어 있는 줄은
+// 앞뒤로 이어진 주석 코드까지 한 덩어리 (예: let rc = ..; thread::spawn(move || { ... });)
+fn find_in(section: &Section) -> Vec<(usize, Vec<String>, String)> {
+    let lines: Vec<&str> = section.code.lines().collect();
+    let mut found = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let Some((code, note)) = split_broken(line) else {
+            continue;
+        };
+        let (mut first, mut last) = (i, i);
+        // 바로 위의 주석 처리된 let 은 이 줄이 쓰는 변수 (let mut v = vec![]; 다음 줄에서 에러)
+        while first > 0
+            && lines[first - 1].trim_start().starts_with("// let ")
+            && !is_broken_line(lines[first - 1])
+        {
+            first -= 1;
+        }
+        if code.ends_with('{') || inner_indent(line) > 0 {
+            while first > 0 && looks_like_code(lines[first - 1]) {
+                first -= 1;
+            }
+            while last + 1 < lines.len() && looks_like_code(lines[last + 1]) {
+                last += 1;
+            }
+        }
+        let block = lines[first..=last].iter().map(|l| l.to_string()).collect();
+        let note = note
+            .trim()
+            .trim_start_matches("컴파일")
+            .trim_start()
+            .trim_start_matches("이건")
+            .trim_start()
+            .trim_start_matches("에러!")
+            .trim()
+            .to_string();
+        found.push((section.lines.start + first + 1, block, note));
+    }
+    found
+}
+
+pub fn catalog() -> Vec<Broken> {
+    let mut all = Vec::new();
+    for info in chapters::CHAPTERS {
+        let mut n = 0;
+        for section in sections::sections(info) {
+            for (line, commented, note) in find_in(&section) {
+                n += 1;
+                all.push(Broken {
+                    id: format!("{}-{}", info.id, n),
+                    section: section.clone(),
+                    line,
+                    commented,
+                    note,
+                });
+            }
+        }
+    }
+    all
+}
+
+pub fn find(id: &str) -> Option<Broken> {
+    catalog().into_iter().find(|b| b.id == id)
+}
+
+// 절 전체를 독립 프로그램으로 만들고 해당 줄만 주석 해제
+pub fn program(broken: &Broken) -> String {
+    let snippet = sections::snippet(&broken.section);
+    // 다른 절에서 가져온 정의에 같은 줄이 있어도 절 본문 쪽을 바꿈
+    let body = snippet.rfind(broken.section.code).unwrap_or(0);
+    let (head, tail) = snippet.split_at(body);
+    let commented = broken.commented.join("\n");
+    format!("{}{}", head, tail.replacen(&commented, &broken.code(), 1))
+}
+
+// rustc 진단 - 경고는 빼고 에러만
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub compiled: bool,
+    pub text: String,
+}
+
+impl Diagnostic {
+    // "E0382" 같은 에러 코드
+    pub fn codes(&self) -> Vec<&str> {
+        let mut codes: Vec<&str> = self
+            .text
+            .match_indices("error[E")
+            .map(|(i, _)| &self.text[i + 6..i + 11])
+            .collect();
+        codes.dedup();
+        codes
+    }
+}
+
+pub fn diagnose(broken: &Broken) -> io::Result<Diagnostic> {
+    let dir = std::env::temp_dir().join(format!(
+        "rust-study-errors-{}-{}",
+        std::process::id(),
+        broken.id
+    ));
+    fs::create_dir_all(&dir)?;
+    let source = dir.join("main.rs");
+    fs::write(&source, program(broken))?;
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let output = Command::new(rustc)
+        .args([
+            "--edition",
+            "2021",
+            "--crate-type",
+            "bin",
+            "--crate-name",
+            "gallery",
+        ])
+        .args([
+            "--emit=metadata",
+            "--color",
+            "never",
+            "-A",
+            "warnings",
+            "--out-dir",
+        ])
+        .arg(&dir)
+        .arg(&source)
+        .output();
+    let _ = fs::remove_dir_all(&dir);
+    let output = output?;
+
+    // 임시 경로 대신 절 이름으로 표시, 끝의 "aborting", "For more information" 안내는 뺌
+    let text = String::from_utf8_lossy(&output.stderr)
+        .replace(
+            &source.display().to_string(),
+            &format!("{}.rs", broken.section.key().replace("::", "_")),
+        )
+        .lines()
+        .filter(|l| !l.starts_with("error: aborting") && !l.starts_with("For more information"))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+        + "\n";
+    Ok(Diagnostic {
+        compiled: output.status.success(),
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_commented_error_lines() {
+        assert_eq!(
+            split_broken("    // x = 6;  // 컴파일 에러! 불변"),
+            Some(("x = 6;", " 컴파일 에러! 불변"))
+        );
+        assert!(split_broken("    // 에러! 설명만 있는 줄").is_none());
+        assert!(split_broken("    //     NewsArticle { ... }  // 에러!").is_none());
+        assert!(split_broken("    let x = 5;  // 에러! 아님").is_none());
+        assert_eq!(
+            uncomment("        // println!(\"{}\", v);"),
+            "        println!(\"{}\", v);"
+        );
+    }
+
+    #[test]
+    fn catalog_covers_lessons_with_unique_ids() {
+        let all = catalog();
+        assert!(all.len() >= 30);
+        let mut ids: Vec<&str> = all.iter().map(|b| b.id.as_str()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), all.len());
+
+        let first = &all[0];
+        assert_eq!(first.id, "01-1");
+        assert_eq!(
+            first.code().trim(),
+            "x = 6;  // 컴파일 에러! cannot assign twice to immutable variable"
+        );
+        assert!(chapters::source("01")
+            .unwrap()
+            .lines()
+            .nth(first.line - 1)
+            .unwrap()
+            .contains("// x = 6;"));
+    }
+
+    #[test]
+    fn multi_line_block_is_taken_whole() {
+        let longest = catalog()
+            .into_iter()
+            .find(|b| b.code().contains("fn longest(x: &str, y: &str) -> &str"))
+            .unwrap();
+        assert_eq!(longest.commented.len(), 3);
+        assert!(program(&longest).contains("\n    fn longest(x: &str, y: &str) -> &str {"));
+
+        // 주석 블록 안의 줄은 블록 전체와 앞의 let 까지
+        let rc = catalog()
+            .into_iter()
+            .find(|b| b.note == "Rc는 Send가 아님")
+            .unwrap();
+        assert_eq!(rc.commented.len(), 4);
+        assert!(rc.code().starts_with("    let rc = std::rc::Rc::new(5);"));
+        assert_eq!(
+            rc.explanation(),
+            vec!["예: Rc를 스레드에 보내려 하면 컴파일 에러"]
+        );
+    }
+
+    // 갤러리의 모든 코드가 실제로 컴파일 에러인지 - rustc 를 여러 번 실행하므로 느림
+    #[test]
+    #[ignore]
+    fn every_entry_fails_to_compile() {
+        let handles: Vec<_> = catalog()
+            .into_iter()
+            .map(|b| std::thread::spawn(move || (b.id.clone(), diagnose(&b).unwrap())))
+            .collect();
+        for h in handles {
+            let (id, d) = h.join().unwrap();
+            assert!(!d.compiled, "{} 가 컴파일됨", id);
+            // 이름을 못 찾는 에러는 추출이 잘못된 것 - 레슨이 설명하는 에러가 아님
+            let codes = d.codes();
+            assert!(!codes.is_empty(), "{}", id);
+            assert!(
+                !codes.contains(&"E0425") && !codes.contains(&"E0433"),
+                "{}: {:?}",
+                id,
+                codes
+            );
+        }
+    }
+}
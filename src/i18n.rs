@@ -0,0 +1,334 @@
+// ============================================================================
+// 출력 언어 (--lang ko|en)
+// ============================================================================
+// 레슨은 한국어로 출력함. --lang en 이면 content/i18n/en/ 의 문자열 표로 바꿔서 영어로
+//   cargo run -- --lang en 07
+//   RUST_STUDY_LANG=en cargo run -- browse      (자식 프로세스도 같은 언어)
+//
+// 장 모듈의 println!/print! 는 이 파일의 같은 이름 매크로가 가로챔 (main.rs 에서 장 모듈보다 먼저 선언)
+//   한국어 : std 의 println! 그대로
+//   영어   : 한국어 서식 문자열로 표를 찾고, 출력된 한국어 문장에서 {} 자리의 값을 뽑아
+//            영어 서식 문자열의 같은 순서 {} 자리에 넣음 - 표에 없는 문장은 한국어 그대로
+// 표는 장마다 파일 하나 ([[lines]] ko = 소스의 서식 문자열 그대로, en = 영어)
+// {} 자리에 들어가는 값(예제 데이터 "홍길동", 에러 메시지 등)은 바꾸지 않음 - 서식 문자열만 번역
+// 도구 자신의 요약과 경고(runner, timing, prereq 등)는 format! 대신 tr! - 표는 main.toml
+// 장 소스는 그대로이므로 샌드박스와 examples/ 에서는 std 의 println! 로 한국어 출력
+// --format json 이면 stdout 대신 records.rs 가 절마다 모아 JSON 의 output 으로
+// 터미널에서 장을 실행하면 pager.rs 가 모았다가 화면보다 길면 페이저로
+// 색은 style.rs 가 줄마다 종류(장 머리, 절 제목, 코드, 에러)를 보고 입힘
+// ============================================================================
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use serde::Deserialize;
+
+// 언어를 지정하는 환경 변수 - browse, serve 가 실행하는 자식 프로세스도 같은 언어를 씀
+pub const LANG_VAR: &str = "RUST_STUDY_LANG";
+
+// (파일 이름, 내용) - build.rs 가 content/i18n/en/*.toml 로 생성
+include!(concat!(env!("OUT_DIR"), "/translations.rs"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    Ko,
+    En,
+}
+
+impl Lang {
+    pub fn parse(code: &str) -> Option<Lang> {
+        match code {
+            "ko" => Some(Lang::Ko),
+            "en" => Some(Lang::En),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::Ko => "ko",
+            Lang::En => "en",
+        }
+    }
+}
+
+// None = 아직 환경 변수를 읽지 않음 (demo_data 의 시드와 같은 방식)
+static LANG: Mutex<Option<Lang>> = Mutex::new(None);
+
+fn state() -> std::sync::MutexGuard<'static, Option<Lang>> {
+    LANG.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn set_lang(lang: Lang) {
+    *state() = Some(lang);
+}
+
+pub fn lang() -> Lang {
+    *state().get_or_insert_with(|| {
+        std::env::var(LANG_VAR)
+            .ok()
+            .and_then(|v| Lang::parse(v.trim()))
+            .unwrap_or(Lang::Ko)
+    })
+}
+
+pub fn translating() -> bool {
+    lang() != Lang::Ko
+}
+
+// 명령줄의 --lang ko|en 을 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> Result<(Option<Lang>, Vec<String>), String> {
+    let mut lang = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg != "--lang" {
+            rest.push(arg.clone());
+            continue;
+        }
+        let value = iter.next().ok_or("--lang 뒤에 ko 또는 en 을 지정하세요")?;
+        lang = Some(Lang::parse(value).ok_or_else(|| format!("지원하지 않는 언어: {} (ko, en)", value))?);
+    }
+    Ok((lang, rest))
+}
+
+#[derive(Deserialize)]
+struct Catalog {
+    lines: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    ko: String,
+    en: String,
+}
+
+// --lang en 이면 문자열 표를 미리 읽어 둠 - 첫 출력에서 읽으면 그 시간이 첫 장의 --timing 에 붙음
+pub fn warm_up() {
+    if lang() == Lang::En {
+        table();
+    }
+}
+
+// 한국어 서식 문자열 → 영어 서식 문자열 (모든 장의 표를 합침)
+fn table() -> &'static HashMap<String, String> {
+    static TABLE: OnceLock<HashMap<String, String>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        EN.iter()
+            .flat_map(|(name, text)| {
+                // 표 형식은 테스트(every_table_parses_and_keeps_placeholders)가 확인
+                let catalog: Catalog = toml::from_str(text)
+                    .unwrap_or_else(|e| panic!("content/i18n/en/{}.toml: {}", name, e));
+                catalog.lines.into_iter().map(|e| (e.ko, e.en))
+            })
+            .collect()
+    })
+}
+
+// "a {} b {x:?}" → 글자 조각 ["a ", " b ", ""] 과 자리 ["{}", "{x:?}"] ({{, }} 는 글자)
+fn split_template(template: &str) -> (Vec<String>, Vec<String>) {
+    let mut pieces = vec![String::new()];
+    let mut holes = Vec::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                pieces.last_mut().unwrap().push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                pieces.last_mut().unwrap().push('}');
+            }
+            '{' => {
+                let mut hole = String::from("{");
+                for c in chars.by_ref() {
+                    hole.push(c);
+                    if c == '}' {
+                        break;
+                    }
+                }
+                holes.push(hole);
+                pieces.push(String::new());
+            }
+            c => pieces.last_mut().unwrap().push(c),
+        }
+    }
+    (pieces, holes)
+}
+
+// 출력된 문장에서 자리마다 들어간 값 - 글자 조각 사이를 잘라냄
+// 자리가 붙어 있거나("{}{}") 조각이 맞지 않으면 None
+fn extract<'a>(pieces: &[String], text: &'a str) -> Option<Vec<&'a str>> {
+    let mut rest = text.strip_prefix(pieces[0].as_str())?;
+    let mut values = Vec::new();
+    let count = pieces.len() - 1;
+    for (i, piece) in pieces[1..].iter().enumerate() {
+        if i + 1 == count {
+            values.push(rest.strip_suffix(piece.as_str())?);
+            return Some(values);
+        }
+        if piece.is_empty() {
+            return None;
+        }
+        let end = rest.find(piece.as_str())?;
+        values.push(&rest[..end]);
+        rest = &rest[end + piece.len()..];
+    }
+    // 자리가 없는 문장 - 조각이 하나
+    rest.is_empty().then_some(values)
+}
+
+fn fill(ko: &str, en: &str, formatted: &str) -> Option<String> {
+    let (pieces, _) = split_template(ko);
+    let values = extract(&pieces, formatted)?;
+    let (en_pieces, en_holes) = split_template(en);
+    if en_holes.len() != values.len() {
+        return None;
+    }
+    let mut out = en_pieces[0].clone();
+    for (value, piece) in values.iter().zip(&en_pieces[1..]) {
+        out.push_str(value);
+        out.push_str(piece);
+    }
+    Some(out)
+}
+
+// template: 소스의 서식 문자열, formatted: 그것으로 만든 한국어 문장
+pub fn translate<'a>(template: &str, formatted: &'a str) -> Cow<'a, str> {
+    match table().get(template).and_then(|en| fill(template, en, formatted)) {
+        Some(text) => Cow::Owned(text),
+        None => Cow::Borrowed(formatted),
+    }
+}
+
+// 도구 자신의 문장(실행 요약, 경고) - format! 처럼 만들고 영어면 표(main.toml)로 바꿈
+pub fn format_args(template: &str, args: std::fmt::Arguments) -> String {
+    let formatted = args.to_string();
+    if translating() {
+        translate(template, &formatted).into_owned()
+    } else {
+        formatted
+    }
+}
+
+// 장 이름 - 영어면 각 장의 표에 있는 장 머리("\n=== 07. 트레이트 (Traits) ===\n")의 번역에서 꺼냄
+pub fn chapter_name(id: &str, name: &str) -> String {
+    if !translating() {
+        return name.to_string();
+    }
+    let prefix = format!("\n=== {}. ", id);
+    table()
+        .iter()
+        .find(|(ko, _)| ko.starts_with(&prefix) && ko.ends_with(" ===\n"))
+        .and_then(|(_, en)| en.strip_prefix(&prefix)?.strip_suffix(" ===\n"))
+        .map_or_else(|| name.to_string(), str::to_string)
+}
+
+// 장 모듈의 출력 한 조각 - 영어면 표로 바꾸고, --format json 이나 페이저면 모아 두고, 아니면 stdout
+pub fn write(template: &str, formatted: &str, newline: bool) {
+    // 출력 처리의 할당은 --profile-alloc 에서 장 코드의 것으로 세지 않음
+    let _quiet = crate::allocations::pause();
+    let mut text = if translating() {
+        translate(template, formatted).into_owned()
+    } else {
+        formatted.to_string()
+    };
+    if newline {
+        text.push('\n');
+    }
+    emit(&crate::style::paint(&text));
+}
+
+// 번역하고 색을 입힌 출력을 보냄 - 작업자 스레드의 버퍼(--parallel), JSON 기록,
+// 출력 파일(--output, --tee 면 화면에도), 페이저, 아니면 stdout
+pub fn emit(text: &str) {
+    if crate::parallel::write(text) || crate::records::write(text) || !crate::tee::write(text) {
+        return;
+    }
+    if !crate::pager::write(text) {
+        ::std::print!("{}", text);
+    }
+}
+
+// println!/print! 의 서식화 - 문자열을 만드는 할당도 write 와 같이 세지 않음
+pub fn write_args(template: &str, args: std::fmt::Arguments, newline: bool) {
+    let formatted = {
+        let _quiet = crate::allocations::pause();
+        args.to_string()
+    };
+    write(template, &formatted, newline);
+}
+
+// 장 모듈의 println! - 한국어면 std 와 같은 출력, 영어면 표로 바꿔서
+macro_rules! println {
+    () => {
+        $crate::i18n::write("", "", true)
+    };
+    ($fmt:literal $($arg:tt)*) => {
+        $crate::i18n::write_args($fmt, ::std::format_args!($fmt $($arg)*), true)
+    };
+    ($($arg:tt)*) => {
+        $crate::i18n::write_args("", ::std::format_args!($($arg)*), true)
+    };
+}
+
+// 도구 모듈의 format! - 한국어면 같은 문자열, 영어면 표로 바꿔서 (eprintln! 으로 내는 경고에도)
+macro_rules! tr {
+    ($fmt:literal $($arg:tt)*) => {
+        $crate::i18n::format_args($fmt, ::std::format_args!($fmt $($arg)*))
+    };
+}
+
+macro_rules! print {
+    ($fmt:literal $($arg:tt)*) => {
+        $crate::i18n::write_args($fmt, ::std::format_args!($fmt $($arg)*), false)
+    };
+    ($($arg:tt)*) => {
+        $crate::i18n::write_args("", ::std::format_args!($($arg)*), false)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_english_template_with_formatted_values() {
+        assert_eq!(
+            fill("{} 의 길이: {:?}개", "length of {}: {:?}", "abc 의 길이: 3개").as_deref(),
+            Some("length of abc: 3")
+        );
+        assert_eq!(fill("{{}} 안의 값 {x}", "value {x} in {{}}", "{} 안의 값 7").as_deref(), Some("value 7 in {}"));
+        assert_eq!(fill("\n--- 함수 ---", "\n--- Functions ---", "\n--- 함수 ---").as_deref(), Some("\n--- Functions ---"));
+        // 붙어 있는 자리는 값을 나눌 수 없음
+        assert_eq!(fill("{}{} 개", "{}{} items", "12 개"), None);
+        // 도구 자신의 문장은 main.toml
+        assert_eq!(translate("\n절 {}개 통과, {}개 실패", "\n절 5개 통과, 1개 실패"), "\n5 sections passed, 1 failed");
+        // 표에 없으면 한국어 그대로
+        assert_eq!(translate("표에 없는 문장 {}", "표에 없는 문장 1"), "표에 없는 문장 1");
+    }
+
+    #[test]
+    fn every_table_parses_and_keeps_placeholders() {
+        assert!(!EN.is_empty());
+        for (name, text) in EN {
+            let catalog: Catalog = toml::from_str(text).unwrap_or_else(|e| panic!("{}: {}", name, e));
+            for entry in catalog.lines {
+                let (_, ko) = split_template(&entry.ko);
+                let (_, en) = split_template(&entry.en);
+                assert_eq!(ko, en, "{}: {:?}", name, entry.ko);
+                assert!(!entry.en.is_empty(), "{}: {:?}", name, entry.ko);
+            }
+        }
+    }
+
+    #[test]
+    fn takes_lang_flag() {
+        let args: Vec<String> = ["--lang", "en", "07"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(take_flag(&args).unwrap(), (Some(Lang::En), vec!["07".to_string()]));
+        assert!(take_flag(&args[..1]).is_err());
+        assert!(take_flag(&["--lang".to_string(), "fr".to_string()]).is_err());
+    }
+}
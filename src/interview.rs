@@ -0,0 +1,414 @@
+// ============================================================================
+// 면접 대비 모드
+// ============================================================================
+// cargo run -- interview [--per-topic N] [--limit 초]
+//
+// 면접에 자주 나오는 주제별로 퀴즈 은행에서 문제를 골라 시간을 재며 풀고,
+// 주제별 정답률과 응답 시간으로 준비도 리포트를 보여줌
+// - 힌트 없음 (실제 면접처럼)
+// - 제한 시간을 넘기면 남은 문제는 미응답으로 처리
+// ============================================================================
+
+use std::io::{self, BufRead, Write};
+use std::time::{Duration, Instant};
+
+use crate::chapters;
+use crate::content::{ContentBank, Question};
+use crate::quiz;
+use crate::rng::Rng;
+
+// 면접 주제 - 문제의 태그 중 하나라도 맞으면 해당 주제
+pub struct Topic {
+    pub name: &'static str,
+    pub tags: &'static [&'static str],
+    // 준비가 부족할 때 복습할 장
+    pub chapters: &'static [&'static str],
+}
+
+pub const TOPICS: &[Topic] = &[
+    Topic {
+        name: "소유권 퍼즐",
+        tags: &["ownership", "move", "copy", "borrowing"],
+        chapters: &["02", "03"],
+    },
+    Topic {
+        name: "수명 시그니처",
+        tags: &["lifetimes"],
+        chapters: &["04"],
+    },
+    Topic {
+        name: "트레이트 객체 vs 제네릭",
+        tags: &[
+            "dispatch",
+            "object-safety",
+            "generics",
+            "zero-cost",
+            "trait-bounds",
+        ],
+        chapters: &["07", "08"],
+    },
+    Topic {
+        name: "동시성 함정",
+        tags: &["mutex", "deadlock", "threads", "send-sync", "arc"],
+        chapters: &["13"],
+    },
+];
+
+pub const DEFAULT_PER_TOPIC: usize = 3;
+
+// 뽑힌 문제 하나 - 주제는 TOPICS 의 인덱스
+#[derive(Debug, Clone, Copy)]
+pub struct Pick<'a> {
+    pub topic: usize,
+    pub question: &'a Question,
+}
+
+fn topic_of(q: &Question) -> Option<usize> {
+    TOPICS
+        .iter()
+        .position(|t| q.tags.iter().any(|tag| t.tags.contains(&tag.as_str())))
+}
+
+// 주제마다 최대 per_topic 문제 - "interview" 태그가 붙은 문제를 먼저, 나머지는 seed 로 섞어서
+// 같은 seed 면 같은 세트 (rng.rs - cargo run -- --seed N interview)
+pub fn select(bank: &ContentBank, per_topic: usize, seed: u64) -> Vec<Pick<'_>> {
+    let mut rng = Rng::new(seed);
+
+    let mut picks = Vec::new();
+    for (topic, _) in TOPICS.iter().enumerate() {
+        let mut pool: Vec<(bool, u64, &Question)> = bank
+            .questions()
+            .filter(|q| topic_of(q) == Some(topic))
+            .map(|q| (!q.tags.iter().any(|t| t == "interview"), rng.next_u64(), q))
+            .collect();
+        pool.sort_by_key(|(rest, order, _)| (*rest, *order));
+        picks.extend(
+            pool.into_iter()
+                .take(per_topic)
+                .map(|(_, _, question)| Pick { topic, question }),
+        );
+    }
+    picks
+}
+
+// 문제 하나의 결과 - 시간 초과나 종료로 답하지 못하면 picked 는 None
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Answer {
+    pub topic: usize,
+    pub picked: Option<usize>,
+    pub correct: bool,
+    pub took: Duration,
+}
+
+pub fn run<R: BufRead, W: Write>(
+    picks: &[Pick],
+    limit: Option<Duration>,
+    input: &mut R,
+    out: &mut W,
+) -> io::Result<Vec<Answer>> {
+    let start = Instant::now();
+    let mut answers = Vec::with_capacity(picks.len());
+    let mut stopped = false;
+
+    for (n, pick) in picks.iter().enumerate() {
+        let over_time = limit.is_some_and(|l| start.elapsed() >= l);
+        if stopped || over_time {
+            answers.push(Answer {
+                topic: pick.topic,
+                picked: None,
+                correct: false,
+                took: Duration::ZERO,
+            });
+            continue;
+        }
+
+        let label = format!("{}/{} {}", n + 1, picks.len(), TOPICS[pick.topic].name);
+        let asked = Instant::now();
+        let picked = quiz::ask(pick.question, &label, false, input, out)?;
+        let took = asked.elapsed();
+        stopped = picked.is_none();
+
+        let correct = picked == Some(pick.question.answer);
+        if picked.is_some() {
+            // 면접처럼 바로 채점만 하고 해설은 리포트 뒤로 미룸
+            writeln!(out, "{}", if correct { "정답" } else { "오답" })?;
+        }
+        answers.push(Answer {
+            topic: pick.topic,
+            picked,
+            correct,
+            took,
+        });
+    }
+
+    if limit.is_some_and(|l| start.elapsed() >= l) {
+        writeln!(out, "\n제한 시간이 지났습니다")?;
+    }
+    Ok(answers)
+}
+
+// ----------------------------------------------------------------------------
+// 준비도 리포트
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Readiness {
+    Ready,
+    NeedsWork,
+    Review,
+}
+
+impl Readiness {
+    pub fn from_percent(percent: u32) -> Readiness {
+        match percent {
+            80.. => Readiness::Ready,
+            50..=79 => Readiness::NeedsWork,
+            _ => Readiness::Review,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Readiness::Ready => "준비됨",
+            Readiness::NeedsWork => "보강 필요",
+            Readiness::Review => "복습 필요",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicReport {
+    pub topic: usize,
+    pub correct: usize,
+    pub total: usize,
+    pub unanswered: usize,
+    // 답한 문제의 평균 응답 시간
+    pub average: Duration,
+}
+
+impl TopicReport {
+    pub fn percent(&self) -> u32 {
+        (self.correct * 100).checked_div(self.total).unwrap_or(0) as u32
+    }
+
+    pub fn readiness(&self) -> Readiness {
+        Readiness::from_percent(self.percent())
+    }
+}
+
+// 문제가 하나도 없던 주제는 빼고 주제 순서대로
+pub fn report(answers: &[Answer]) -> Vec<TopicReport> {
+    (0..TOPICS.len())
+        .filter_map(|topic| {
+            let mine: Vec<&Answer> = answers.iter().filter(|a| a.topic == topic).collect();
+            if mine.is_empty() {
+                return None;
+            }
+            let answered: Vec<&&Answer> = mine.iter().filter(|a| a.picked.is_some()).collect();
+            let spent: Duration = answered.iter().map(|a| a.took).sum();
+            Some(TopicReport {
+                topic,
+                correct: mine.iter().filter(|a| a.correct).count(),
+                total: mine.len(),
+                unanswered: mine.len() - answered.len(),
+                average: spent.checked_div(answered.len() as u32).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+pub fn print_report<W: Write>(
+    reports: &[TopicReport],
+    picks: &[Pick],
+    answers: &[Answer],
+    bank: &ContentBank,
+    out: &mut W,
+) -> io::Result<()> {
+    writeln!(out, "\n=== 준비도 리포트 ===")?;
+    for r in reports {
+        write!(
+            out,
+            "  {}/{} ({:>3}%)  평균 {:>4.1}초  {} - {}",
+            r.correct,
+            r.total,
+            r.percent(),
+            r.average.as_secs_f64(),
+            TOPICS[r.topic].name,
+            r.readiness().label()
+        )?;
+        if r.unanswered > 0 {
+            write!(out, "  (미응답 {})", r.unanswered)?;
+        }
+        writeln!(out)?;
+    }
+
+    let correct: usize = reports.iter().map(|r| r.correct).sum();
+    let total: usize = reports.iter().map(|r| r.total).sum();
+    let overall = Readiness::from_percent((correct * 100).checked_div(total).unwrap_or(0) as u32);
+    writeln!(out, "  전체: {}/{} - {}", correct, total, overall.label())?;
+
+    // 틀린 문제는 해설과 함께 다시 보여줌
+    let missed: Vec<&Pick> = picks
+        .iter()
+        .zip(answers)
+        .filter(|(_, a)| !a.correct)
+        .map(|(p, _)| p)
+        .collect();
+    if !missed.is_empty() {
+        writeln!(out, "\n틀리거나 답하지 못한 문제:")?;
+        for p in missed {
+            let q = p.question;
+            writeln!(
+                out,
+                "  - {} → {}) {}",
+                q.prompt,
+                q.answer + 1,
+                q.choices[q.answer]
+            )?;
+            if let Some(explanation) = &q.explanation {
+                writeln!(out, "    {}", explanation)?;
+            }
+        }
+    }
+
+    let weak: Vec<&TopicReport> = reports
+        .iter()
+        .filter(|r| r.readiness() != Readiness::Ready)
+        .collect();
+    if !weak.is_empty() {
+        writeln!(out, "\n추천 복습:")?;
+        for r in weak {
+            let topic = &TOPICS[r.topic];
+            writeln!(out, "  {}", topic.name)?;
+            for id in topic.chapters {
+                if let Some(info) = chapters::find(id) {
+                    writeln!(
+                        out,
+                        "    - {}. {} (cargo run -- quiz {})",
+                        info.id, info.title, info.id
+                    )?;
+                }
+                for e in bank.chapter(id).map_or(&[][..], |c| &c.exercises) {
+                    writeln!(out, "      연습: {} - {}", e.title, e.description)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::ChapterContent;
+
+    fn bank() -> ContentBank {
+        let chapter: ChapterContent = toml::from_str(
+            r#"
+            chapter = "02"
+
+            [[questions]]
+            id = "02-a"
+            prompt = "이동"
+            choices = ["x", "y"]
+            answer = 1
+            tags = ["ownership", "move"]
+
+            [[questions]]
+            id = "02-b"
+            prompt = "면접 단골"
+            choices = ["x", "y"]
+            answer = 0
+            tags = ["ownership", "interview"]
+
+            [[questions]]
+            id = "02-c"
+            prompt = "수명"
+            choices = ["x", "y"]
+            answer = 0
+            tags = ["lifetimes"]
+
+            [[questions]]
+            id = "02-d"
+            prompt = "주제 밖"
+            choices = ["x", "y"]
+            answer = 0
+            tags = ["macros"]
+            "#,
+        )
+        .unwrap();
+        ContentBank {
+            chapters: vec![chapter],
+            ..ContentBank::default()
+        }
+    }
+
+    fn ids<'a>(picks: &[Pick<'a>]) -> Vec<&'a str> {
+        picks.iter().map(|p| p.question.id.as_str()).collect()
+    }
+
+    #[test]
+    fn select_prefers_interview_questions_and_skips_other_topics() {
+        let bank = bank();
+        let picks = select(&bank, 1, 7);
+        assert_eq!(ids(&picks), vec!["02-b", "02-c"]);
+        assert_eq!(picks[1].topic, 1);
+
+        let all = select(&bank, 10, 7);
+        assert_eq!(all.len(), 3);
+        assert_eq!(ids(&select(&bank, 10, 7)), ids(&all)); // 같은 seed 면 같은 순서
+    }
+
+    #[test]
+    fn run_and_report() {
+        let bank = bank();
+        let picks = select(&bank, 10, 1);
+        let mut input = "1\n1\n".as_bytes(); // 세 번째 문제 전에 입력이 끝남
+        let mut out = Vec::new();
+        let answers = run(&picks, None, &mut input, &mut out).unwrap();
+
+        assert_eq!(answers.len(), 3);
+        assert!(answers[0].correct); // 02-b
+        assert_eq!(answers[2].picked, None);
+
+        let reports = report(&answers);
+        assert_eq!(reports.len(), 2);
+        assert_eq!((reports[0].correct, reports[0].total), (1, 2));
+        assert_eq!(reports[0].readiness(), Readiness::NeedsWork);
+
+        let mut text = Vec::new();
+        print_report(&reports, &picks, &answers, &bank, &mut text).unwrap();
+        let text = String::from_utf8(text).unwrap();
+        assert!(text.contains("보강 필요"));
+        assert!(text.contains("추천 복습"));
+        assert!(text.contains("quiz 04"));
+    }
+
+    #[test]
+    fn expired_limit_leaves_questions_unanswered() {
+        let bank = bank();
+        let picks = select(&bank, 10, 1);
+        let mut input = "1\n1\n1\n".as_bytes();
+        let mut out = Vec::new();
+        let answers = run(&picks, Some(Duration::ZERO), &mut input, &mut out).unwrap();
+        assert!(answers.iter().all(|a| a.picked.is_none()));
+        assert!(String::from_utf8(out).unwrap().contains("제한 시간"));
+        assert_eq!(report(&answers)[0].unanswered, 2);
+    }
+
+    #[test]
+    fn readiness_thresholds() {
+        assert_eq!(Readiness::from_percent(80), Readiness::Ready);
+        assert_eq!(Readiness::from_percent(50), Readiness::NeedsWork);
+        assert_eq!(Readiness::from_percent(49), Readiness::Review);
+    }
+
+    #[test]
+    fn shipped_bank_covers_every_topic() {
+        let bank = crate::content::source().load().unwrap();
+        let picks = select(&bank, DEFAULT_PER_TOPIC, 0);
+        for (i, topic) in TOPICS.iter().enumerate() {
+            assert!(picks.iter().any(|p| p.topic == i), "{}", topic.name);
+        }
+    }
+}
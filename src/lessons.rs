@@ -0,0 +1,440 @@
+// ============================================================================
+// 커뮤니티 레슨 팩
+// ============================================================================
+// 저장소를 포크하지 않고 외부 장을 추가하는 방법
+//
+//   cargo run -- lessons add https://github.com/someone/rust-study-embedded
+//
+// 팩 저장소 구조:
+//   lesson-pack.toml   이름, 설명, 장 목록
+//   Cargo.toml         독립 바이너리 크레이트 - `cargo run -- <장 id>` 로 장 실행
+//   content/*.toml     퀴즈/연습문제 (content/README.md 와 같은 형식)
+//
+// 팩은 .rust-study/lessons/<이름>/ 에 설치되고 별도 크레이트로 빌드됨
+// 팩의 장은 기본 장과 같은 장 목록에서 찾을 수 있음 (quiz, lessons run)
+// ============================================================================
+
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::chapters;
+use crate::content::{self, ContentBank};
+use crate::progress;
+
+pub const MANIFEST: &str = "lesson-pack.toml";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PackManifest {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub chapters: Vec<PackChapter>,
+}
+
+// 팩의 장 id는 기본 장(숫자)과 겹치지 않도록 "<팩 이름>-NN" 형식
+#[derive(Debug, Clone, Deserialize)]
+pub struct PackChapter {
+    pub id: String,
+    pub title: String,
+}
+
+// 설치된 팩 하나
+#[derive(Debug)]
+pub struct LessonPack {
+    pub manifest: PackManifest,
+    pub dir: PathBuf,
+}
+
+impl LessonPack {
+    pub fn content(&self) -> Result<ContentBank, content::ContentError> {
+        let dir = self.dir.join("content");
+        if !dir.exists() {
+            return Ok(ContentBank::default());
+        }
+        content::load_unchecked(&dir)
+    }
+
+    pub fn chapter(&self, id: &str) -> Option<&PackChapter> {
+        self.manifest.chapters.iter().find(|c| c.id == id)
+    }
+}
+
+#[derive(Debug)]
+pub enum PackError {
+    Io(io::Error),
+    Manifest(String),
+    Invalid(Vec<String>),
+    Command(String),
+}
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackError::Io(e) => write!(f, "{}", e),
+            PackError::Manifest(msg) => write!(f, "{} 읽기 실패: {}", MANIFEST, msg),
+            PackError::Invalid(problems) => {
+                writeln!(f, "레슨 팩 검증 실패 ({}건)", problems.len())?;
+                for p in problems {
+                    writeln!(f, "  - {}", p)?;
+                }
+                Ok(())
+            }
+            PackError::Command(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PackError {}
+
+impl From<io::Error> for PackError {
+    fn from(e: io::Error) -> Self {
+        PackError::Io(e)
+    }
+}
+
+pub fn lessons_dir() -> PathBuf {
+    progress::state_dir().join("lessons")
+}
+
+// 팩 이름은 설치 디렉터리 이름 - 소문자, 숫자, '-' 만 (.., /abs, a/b 가 lessons 밖을 가리키지 않도록)
+// 매니페스트와 명령줄에서 온 이름은 파일 시스템을 건드리기 전에 이것부터
+pub fn check_name(name: &str) -> Result<(), PackError> {
+    let plain = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-');
+    if plain {
+        Ok(())
+    } else {
+        Err(PackError::Command(format!(
+            "잘못된 팩 이름 '{}' - 소문자, 숫자, '-' 만 쓸 수 있습니다",
+            name
+        )))
+    }
+}
+
+pub fn read_manifest(dir: &Path) -> Result<PackManifest, PackError> {
+    let text =
+        fs::read_to_string(dir.join(MANIFEST)).map_err(|e| PackError::Manifest(e.to_string()))?;
+    toml::from_str(&text).map_err(|e| PackError::Manifest(e.to_string()))
+}
+
+// 설치 전 검사 - 장 id 충돌과 콘텐츠 규칙
+pub fn validate(pack: &LessonPack, installed: &[LessonPack]) -> Vec<String> {
+    let mut problems = Vec::new();
+    let name = &pack.manifest.name;
+
+    if pack.manifest.chapters.is_empty() {
+        problems.push(String::from("장이 하나도 없음"));
+    }
+    for c in &pack.manifest.chapters {
+        if !c.id.starts_with(&format!("{}-", name)) {
+            problems.push(format!("장 id '{}'는 '{}-'로 시작해야 함", c.id, name));
+        }
+        if chapters::find(&c.id).is_some() {
+            problems.push(format!("장 id '{}'가 기본 장과 겹침", c.id));
+        }
+        let taken = installed
+            .iter()
+            .filter(|other| other.manifest.name != *name)
+            .any(|other| other.chapter(&c.id).is_some());
+        if taken {
+            problems.push(format!("장 id '{}'가 다른 팩과 겹침", c.id));
+        }
+    }
+
+    match pack.content() {
+        Ok(bank) => {
+            let known: Vec<&str> = pack
+                .manifest
+                .chapters
+                .iter()
+                .map(|c| c.id.as_str())
+                .collect();
+            problems.extend(
+                content::validate_with(&bank, &known)
+                    .iter()
+                    .map(|i| i.to_string()),
+            );
+        }
+        Err(e) => problems.push(e.to_string()),
+    }
+    problems
+}
+
+// 설치된 팩 목록 - 매니페스트가 깨진 디렉터리는 건너뜀
+pub fn installed() -> Vec<LessonPack> {
+    installed_in(&lessons_dir())
+}
+
+fn installed_in(root: &Path) -> Vec<LessonPack> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let mut packs: Vec<LessonPack> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        // 설치 중인 임시 디렉터리(.staging-*)는 제외
+        .filter(|dir| {
+            dir.is_dir()
+                && !dir
+                    .file_name()
+                    .is_some_and(|n| n.to_string_lossy().starts_with('.'))
+        })
+        .filter_map(|dir| {
+            let manifest = read_manifest(&dir).ok()?;
+            Some(LessonPack { manifest, dir })
+        })
+        .collect();
+    packs.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+    packs
+}
+
+// 팩의 장 찾기 - (팩, 장)
+pub fn find<'a>(packs: &'a [LessonPack], id: &str) -> Option<(&'a LessonPack, &'a PackChapter)> {
+    packs.iter().find_map(|p| p.chapter(id).map(|c| (p, c)))
+}
+
+// git clone 에 넘기는 URL - '-' 로 시작하면 git 이 옵션으로 읽음 (--upload-pack=<명령>, -c core.sshCommand=...)
+pub fn check_url(url: &str) -> Result<(), PackError> {
+    if url.is_empty() || url.starts_with('-') {
+        Err(PackError::Command(format!(
+            "잘못된 URL '{}' - 비어 있거나 '-' 로 시작할 수 없습니다",
+            url
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+// git clone → 검증 → 빌드 → 설치 (.rust-study/lessons/<팩 이름>/)
+// 임시 디렉터리에서 작업하고 마지막에 rename - 실패해도 반쯤 설치된 팩이 남지 않음
+// 빌드는 팩의 build.rs 와 절차적 매크로를 실행함 - confirm 이 false 면 빌드하지 않고 그만둠
+pub fn add(url: &str, confirm: impl FnOnce(&LessonPack) -> bool) -> Result<LessonPack, PackError> {
+    check_url(url)?;
+    let root = lessons_dir();
+    fs::create_dir_all(&root)?;
+    let staging = root.join(format!(".staging-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&staging);
+
+    let result = clone(url, &staging).and_then(|()| install(&staging, &root, confirm));
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    result
+}
+
+fn clone(url: &str, dir: &Path) -> Result<(), PackError> {
+    let status = Command::new("git")
+        // -- 뒤는 옵션이 아님 (check_url 과 함께 이중으로)
+        .args(["clone", "--depth", "1", "--quiet", "--", url])
+        .arg(dir)
+        .status()
+        .map_err(|e| PackError::Command(format!("git 실행 실패: {}", e)))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(PackError::Command(format!("git clone 실패: {}", url)))
+    }
+}
+
+fn install(staging: &Path, root: &Path, confirm: impl FnOnce(&LessonPack) -> bool) -> Result<LessonPack, PackError> {
+    let manifest = read_manifest(staging)?;
+    check_name(&manifest.name)?;
+    let dest = root.join(&manifest.name);
+    if dest.exists() {
+        return Err(PackError::Command(format!(
+            "'{}' 팩이 이미 설치되어 있습니다 (lessons remove {} 후 다시 시도)",
+            manifest.name, manifest.name
+        )));
+    }
+    let pack = LessonPack {
+        manifest,
+        dir: staging.to_path_buf(),
+    };
+
+    let problems = validate(&pack, &installed());
+    if !problems.is_empty() {
+        return Err(PackError::Invalid(problems));
+    }
+    if pack.dir.join("Cargo.toml").exists() {
+        if !confirm(&pack) {
+            return Err(PackError::Command(format!(
+                "'{}' 팩을 빌드하지 않았습니다 - 설치를 취소합니다",
+                pack.manifest.name
+            )));
+        }
+        cargo(&pack, &["build", "--quiet"])?;
+    }
+
+    fs::rename(staging, &dest)?;
+    Ok(LessonPack { dir: dest, ..pack })
+}
+
+pub fn remove(name: &str) -> Result<(), PackError> {
+    check_name(name)?;
+    let dir = lessons_dir().join(name);
+    if !dir.join(MANIFEST).exists() {
+        return Err(PackError::Command(format!("설치되지 않은 팩: {}", name)));
+    }
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+// 팩 크레이트의 바이너리로 장 실행
+pub fn run_chapter(pack: &LessonPack, chapter: &PackChapter) -> Result<(), PackError> {
+    cargo(pack, &["run", "--quiet", "--", &chapter.id])
+}
+
+// 팩마다 자기 target/ 을 씀 - 본 크레이트의 빌드와 섞이지 않음
+fn cargo(pack: &LessonPack, args: &[&str]) -> Result<(), PackError> {
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo")))
+        .arg(args[0])
+        .arg("--manifest-path")
+        .arg(pack.dir.join("Cargo.toml"))
+        .args(&args[1..])
+        .status()
+        .map_err(|e| PackError::Command(format!("cargo 실행 실패: {}", e)))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(PackError::Command(format!(
+            "cargo {} 실패 ({})",
+            args[0], pack.manifest.name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_pack(dir: &Path, manifest: &str, quiz: Option<(&str, &str)>) {
+        fs::create_dir_all(dir.join("content")).unwrap();
+        fs::write(dir.join(MANIFEST), manifest).unwrap();
+        if let Some((file, text)) = quiz {
+            fs::write(dir.join("content").join(file), text).unwrap();
+        }
+    }
+
+    fn temp(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("lessons-test-{}-{}", name, std::process::id()))
+    }
+
+    const MANIFEST_TOML: &str = r#"
+        name = "embedded"
+        description = "임베디드 Rust"
+
+        [[chapters]]
+        id = "embedded-01"
+        title = "GPIO와 no_std"
+    "#;
+
+    const QUIZ_TOML: &str = r#"
+        chapter = "embedded-01"
+
+        [[questions]]
+        id = "embedded-01-no-std"
+        prompt = "no_std 크레이트에서 쓸 수 없는 것은?"
+        choices = ["core::mem", "std::fs"]
+        answer = 1
+    "#;
+
+    #[test]
+    fn valid_pack_is_installed_and_found() {
+        let root = temp("ok");
+        let pack_dir = root.join("embedded");
+        write_pack(
+            &pack_dir,
+            MANIFEST_TOML,
+            Some(("embedded-01_gpio.toml", QUIZ_TOML)),
+        );
+
+        let packs = installed_in(&root);
+        assert_eq!(packs.len(), 1);
+        assert!(validate(&packs[0], &[]).is_empty());
+
+        let (pack, chapter) = find(&packs, "embedded-01").unwrap();
+        assert_eq!(chapter.title, "GPIO와 no_std");
+        assert_eq!(pack.content().unwrap().questions().count(), 1);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn clashing_ids_and_bad_content_are_reported() {
+        let root = temp("bad");
+        let manifest = MANIFEST_TOML.replace("embedded-01", "07");
+        let quiz = QUIZ_TOML.replace("answer = 1", "answer = 5");
+        write_pack(&root, &manifest, Some(("embedded-01_gpio.toml", &quiz)));
+
+        let pack = LessonPack {
+            manifest: read_manifest(&root).unwrap(),
+            dir: root.clone(),
+        };
+        let problems = validate(&pack, &[]);
+        assert!(problems.iter().any(|p| p.contains("기본 장과 겹침")));
+        assert!(problems.iter().any(|p| p.contains("'embedded-'로 시작")));
+        assert!(problems.iter().any(|p| p.contains("존재하지 않는 장")));
+        assert!(problems.iter().any(|p| p.contains("범위")));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn names_that_escape_the_lessons_dir_are_rejected() {
+        for name in ["..", "/abs", "a/b", "", "Embedded", "a\\b"] {
+            assert!(check_name(name).is_err(), "{:?}", name);
+            // 파일 시스템을 건드리기 전에 거절 - 설치되지 않은 팩이라는 에러가 아님
+            let err = remove(name).unwrap_err().to_string();
+            assert!(err.contains("잘못된 팩 이름"), "{:?}: {}", name, err);
+        }
+        assert!(check_name("embedded-2").is_ok());
+
+        // 매니페스트의 이름도 - 옮기기 전에 거절하고 lessons 밖에는 아무것도 생기지 않음
+        let root = temp("escape");
+        let staging = root.join("lessons").join(".staging");
+        write_pack(&staging, &MANIFEST_TOML.replace("name = \"embedded\"", "name = \"../x\""), None);
+        let err = install(&staging, &root.join("lessons"), |_| true).unwrap_err().to_string();
+        assert!(err.contains("잘못된 팩 이름"), "{}", err);
+        assert!(staging.exists());
+        assert!(!root.join("x").exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn urls_that_git_reads_as_options_are_rejected() {
+        for url in ["--upload-pack=touch /tmp/x", "-c", "-ccore.sshCommand=sh", ""] {
+            let err = add(url, |_| true).unwrap_err().to_string();
+            assert!(err.contains("잘못된 URL"), "{:?}: {}", url, err);
+        }
+        assert!(check_url("https://github.com/someone/rust-study-embedded").is_ok());
+    }
+
+    #[test]
+    fn declined_build_installs_nothing() {
+        let root = temp("decline");
+        let lessons = root.join("lessons");
+        let staging = lessons.join(".staging");
+        write_pack(&staging, MANIFEST_TOML, Some(("embedded-01_gpio.toml", QUIZ_TOML)));
+        fs::write(staging.join("Cargo.toml"), "[package]\nname = \"embedded\"\n").unwrap();
+
+        let mut asked = None;
+        let err = install(&staging, &lessons, |pack| {
+            asked = Some(pack.manifest.name.clone());
+            false
+        })
+        .unwrap_err()
+        .to_string();
+        assert_eq!(asked.as_deref(), Some("embedded"));
+        assert!(err.contains("설치를 취소"), "{}", err);
+        assert!(!lessons.join("embedded").exists());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
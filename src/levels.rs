@@ -0,0 +1,197 @@
+// ============================================================================
+// 절의 난이도 (beginner, intermediate, advanced)
+// ============================================================================
+// cargo run -- --level advanced all                 고급 절만 (unsafe, 비동기 내부, 타입 수준 기법 ...)
+// cargo run -- --level intermediate,advanced all    C++ 경험자 - 입문 절은 건너뜀
+//
+// 장마다 기본 난이도를 두고 (CHAPTER_LEVELS), 장의 기본과 다른 절만 SECTION_LEVELS 에 적음
+//   예: 08장(제네릭)은 중급이지만 const_generics, phantom_data 는 고급
+// 새 절은 따로 적지 않으면 장의 난이도 - 표의 이름이 실제 절인지는 테스트가 확인
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl Level {
+    pub const ALL: [Level; 3] = [Level::Beginner, Level::Intermediate, Level::Advanced];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Beginner => "beginner",
+            Level::Intermediate => "intermediate",
+            Level::Advanced => "advanced",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Level::Beginner => "입문",
+            Level::Intermediate => "중급",
+            Level::Advanced => "고급",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Level> {
+        Level::ALL.into_iter().find(|l| l.name() == name)
+    }
+}
+
+use Level::{Advanced, Beginner, Intermediate};
+
+// 장의 기본 난이도 (장 번호 순)
+pub const CHAPTER_LEVELS: &[(&str, Level)] = &[
+    ("01", Beginner),
+    ("02", Beginner),
+    ("03", Beginner),
+    ("04", Intermediate),
+    ("05", Beginner),
+    ("06", Beginner),
+    ("07", Intermediate),
+    ("08", Intermediate),
+    ("09", Intermediate),
+    ("10", Beginner),
+    ("11", Intermediate),
+    ("12", Intermediate),
+    ("13", Intermediate),
+    ("14", Beginner),
+    ("15", Intermediate),
+    ("16", Advanced),
+    ("17", Advanced),
+    ("18", Intermediate),
+    ("19", Intermediate),
+    ("20", Advanced),
+    ("21", Intermediate),
+    ("22", Intermediate),
+    ("23", Intermediate),
+    ("24", Beginner),
+    ("25", Intermediate),
+    ("26", Intermediate),
+    ("27", Advanced),
+    ("28", Intermediate),
+    ("29", Advanced),
+    ("30", Advanced),
+    ("31", Advanced),
+    ("32", Intermediate),
+    ("33", Intermediate),
+    ("34", Advanced),
+    ("35", Intermediate),
+    ("36", Beginner),
+    ("37", Intermediate),
+    ("38", Intermediate),
+    ("39", Intermediate),
+    ("40", Intermediate),
+    ("41", Beginner),
+    ("42", Intermediate),
+    ("43", Intermediate),
+    ("44", Advanced),
+    ("45", Advanced),
+    ("46", Advanced),
+    ("47", Advanced),
+    ("48", Advanced),
+    ("49", Advanced),
+    ("50", Intermediate),
+    ("51", Intermediate),
+    ("52", Intermediate),
+    ("53", Advanced),
+    ("54", Advanced),
+    ("55", Intermediate),
+    ("56", Intermediate),
+    ("57", Intermediate),
+];
+
+// 장의 기본과 다른 절 ("장::절")
+pub const SECTION_LEVELS: &[(&str, Level)] = &[
+    ("04::static_lifetime", Advanced),
+    ("06::pattern_matching_advanced", Intermediate),
+    ("07::basic_traits", Beginner),
+    ("07::derive_traits", Beginner),
+    ("08::generic_functions", Beginner),
+    ("08::const_generics", Advanced),
+    ("08::phantom_data", Advanced),
+    ("09::panic_demo", Beginner),
+    ("09::result_basics", Beginner),
+    ("11::closures_basics", Beginner),
+    ("11::iterator_basics", Beginner),
+    ("12::reference_cycles", Advanced),
+    ("13::send_sync_traits", Advanced),
+    ("15::hygiene", Advanced),
+    ("15::procedural_macros_intro", Advanced),
+    ("17::async_basics", Intermediate),
+    ("17::sync_vs_async_comparison", Intermediate),
+    ("18::typestate_pattern", Advanced),
+    ("18::newtype_deref_antipattern", Advanced),
+    ("19::test_basics_explanation", Beginner),
+    ("19::assertion_macros_explanation", Beginner),
+    ("22::ref_keywords", Advanced),
+    ("23::c_strings", Advanced),
+    ("24::total_ordering", Intermediate),
+    ("25::array_slice_vec", Beginner),
+    ("27::associated_consts", Intermediate),
+    ("27::default_type_params", Intermediate),
+];
+
+// 절의 난이도 - 표에 없으면 장의 기본, 장도 없으면(레슨 팩 등) 중급
+pub fn of(chapter: &str, section: &str) -> Level {
+    let key = format!("{}::{}", chapter, section);
+    SECTION_LEVELS
+        .iter()
+        .chain(CHAPTER_LEVELS)
+        .find(|(k, _)| *k == key || *k == chapter)
+        .map_or(Intermediate, |(_, level)| *level)
+}
+
+// 명령줄의 --level <목록> 을 빼고 나머지 인자를 돌려줌 - 비어 있으면 모든 난이도
+pub fn take_flag(args: &[String]) -> Result<(Vec<Level>, Vec<String>), String> {
+    let mut levels = Vec::new();
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg != "--level" {
+            rest.push(arg.clone());
+            continue;
+        }
+        let value = iter.next().ok_or("--level 뒤에 beginner, intermediate, advanced 중 하나 이상을 지정하세요")?;
+        for name in value.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            levels.push(
+                Level::parse(name)
+                    .ok_or_else(|| format!("없는 난이도: {} (beginner, intermediate, advanced)", name))?,
+            );
+        }
+    }
+    Ok((levels, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chapters, sections};
+
+    #[test]
+    fn sections_default_to_the_chapter_level() {
+        assert_eq!(of("08", "generic_structs"), Intermediate);
+        assert_eq!(of("08", "phantom_data"), Advanced);
+        assert_eq!(of("01", "variables"), Beginner);
+        assert_eq!(of("99", "anything"), Intermediate);
+
+        // 모든 장에 기본 난이도, 표의 절은 실제 절
+        for info in chapters::CHAPTERS {
+            assert!(CHAPTER_LEVELS.iter().any(|(id, _)| *id == info.id), "{}장", info.id);
+        }
+        for (key, _) in SECTION_LEVELS {
+            assert!(sections::find(key).is_ok(), "{}", key);
+        }
+    }
+
+    #[test]
+    fn takes_level_list() {
+        let args: Vec<String> = ["--level", "intermediate,advanced", "all"].iter().map(|s| s.to_string()).collect();
+        let (levels, rest) = take_flag(&args).unwrap();
+        assert_eq!(levels, [Intermediate, Advanced]);
+        assert_eq!(rest, ["all"]);
+        assert!(take_flag(&["--level".to_string(), "expert".to_string()]).is_err());
+    }
+}
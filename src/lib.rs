@@ -0,0 +1,16 @@
+// ============================================================================
+// rust-study 라이브러리 - 명령줄 도구와 브라우저(web/)가 함께 쓰는 부분
+// ============================================================================
+// 장 목록, 절 추출과 절이 쓰는 기능, 절의 난이도, 출력 싱크, 퀴즈 콘텐츠와 채점
+// 스레드, tokio, 파일 접근 없이도 동작해야 함 - web/ 에서 wasm32 로 빌드
+//   파일에서 콘텐츠를 읽는 함수는 wasm32 가 아닐 때만 (content::load)
+//   장 실행, 서버, 프로세스 실행은 바이너리(main.rs) 쪽 모듈에
+// ============================================================================
+
+pub mod capabilities;
+pub mod chapters;
+pub mod content;
+pub mod levels;
+pub mod output;
+pub mod quiz;
+pub mod sections;
@@ -0,0 +1,322 @@
+// ============================================================================
+// 레슨 규칙 검사 (lint)
+// ============================================================================
+// cargo run -- check 에서 콘텐츠 검증과 함께 실행
+//
+// 장 모듈(_NN_*.rs)마다 검사하는 규칙
+// - 머리 주석에 C++ 과의 비교가 있는지
+// - 모든 절이 SECTIONS 에 등록되어 있는지
+// - 출력 문자열이 번역되지 않은 영어 문장으로 남아 있지 않은지
+// - TODO 표시가 남아 있지 않은지 (xtask new-lesson 으로 만든 장의 미완성 부분)
+// - 에러 처리를 가르치는 절에서 설명 없는 unwrap() 을 쓰지 않는지
+// ============================================================================
+
+use std::fmt;
+
+use crate::chapters::{self, ChapterInfo};
+use crate::sections;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    CppHeader,
+    Unregistered,
+    Untranslated,
+    Todo,
+    Unwrap,
+}
+
+impl Rule {
+    pub fn name(self) -> &'static str {
+        match self {
+            Rule::CppHeader => "cpp-header",
+            Rule::Unregistered => "unregistered-section",
+            Rule::Untranslated => "untranslated",
+            Rule::Todo => "todo",
+            Rule::Unwrap => "unwrap",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    // "src/_07_traits.rs:12"
+    pub location: String,
+    pub rule: Rule,
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} [{}] {}",
+            self.location,
+            self.rule.name(),
+            self.message
+        )
+    }
+}
+
+pub fn lint_all() -> Vec<Finding> {
+    chapters::CHAPTERS
+        .iter()
+        .filter_map(|info| Some(lint_chapter(info, chapters::source(info.id)?)))
+        .flatten()
+        .collect()
+}
+
+pub fn lint_chapter(info: &ChapterInfo, source: &'static str) -> Vec<Finding> {
+    let file = format!("src/{}.rs", info.module());
+    let at = |line: usize| format!("{}:{}", file, line + 1);
+    let lines: Vec<&str> = source.lines().collect();
+    let mut findings = Vec::new();
+    let mut found = |location: String, rule: Rule, message: String| {
+        findings.push(Finding {
+            location,
+            rule,
+            message,
+        });
+    };
+
+    // 머리 주석 = 첫 줄부터 세 번째 "// ====" 까지
+    let header_end = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with("// ===="))
+        .nth(2)
+        .map_or(0, |(i, _)| i);
+    if !lines[..header_end].iter().any(|l| l.contains("C++")) {
+        found(
+            at(0),
+            Rule::CppHeader,
+            "머리 주석에 C++ 과의 차이점이 없습니다".to_string(),
+        );
+    }
+
+    let registered = sections::registered(source);
+    let all = sections::parse(info.id, source);
+    for s in &all {
+        if !registered.contains(&s.name.as_str()) {
+            found(
+                at(s.lines.start),
+                Rule::Unregistered,
+                format!(
+                    "절 '{}' 의 {}() 가 SECTIONS 에 등록되지 않았습니다",
+                    s.title, s.name
+                ),
+            );
+        }
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        if line.contains("TODO") {
+            found(
+                at(i),
+                Rule::Todo,
+                format!("TODO 가 남아 있습니다: {}", line.trim()),
+            );
+        }
+        if line.trim_start().starts_with("//") {
+            continue;
+        }
+        for text in output_strings(line) {
+            if looks_untranslated(text) {
+                found(
+                    at(i),
+                    Rule::Untranslated,
+                    format!("번역되지 않은 문자열: \"{}\"", text),
+                );
+            }
+        }
+    }
+
+    // 에러 처리를 가르치는 곳 - 09장 전체와 제목에 "에러" 가 들어간 절
+    for s in all
+        .iter()
+        .filter(|s| info.id == "09" || s.title.contains("에러"))
+    {
+        for i in s.lines.clone() {
+            if is_unexplained_unwrap(&lines, i) {
+                found(
+                    at(i),
+                    Rule::Unwrap,
+                    "에러 처리를 가르치는 절에서 설명 없이 unwrap() 을 씁니다".to_string(),
+                );
+            }
+        }
+    }
+    findings
+}
+
+// 출력 매크로의 첫 문자열 인자
+const OUTPUT_MACROS: &[&str] = &[
+    "println!(\"",
+    "print!(\"",
+    "eprintln!(\"",
+    "panic!(\"",
+    "format!(\"",
+];
+
+fn output_strings(line: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    for m in OUTPUT_MACROS {
+        for (start, _) in line.match_indices(m) {
+            let rest = &line[start + m.len()..];
+            // 이스케이프된 따옴표(\")는 건너뜀
+            let mut escaped = false;
+            let end = rest.char_indices().find_map(|(i, c)| {
+                let close = c == '"' && !escaped;
+                escaped = c == '\\' && !escaped;
+                close.then_some(i)
+            });
+            if let Some(end) = end {
+                out.push(&rest[..end]);
+            }
+        }
+    }
+    out
+}
+
+// 코드에서 흔한 영어 단어 - 문장이 아니라 코드 조각으로 봄
+const CODE_WORDS: &[&str] = &[
+    "let", "if", "while", "for", "in", "mut", "ref", "fn", "match", "else", "loop", "as", "move",
+    "async", "await", "impl", "dyn", "pub", "use", "mod", "ok", "test",
+];
+
+// 한글이 없고 일반 영어 단어가 두 개 이상 이어지면 번역되지 않은 문장
+// "x = {}", "Box<dyn Trait>", "if let, while let" 같은 코드 조각은 통과
+pub fn looks_untranslated(text: &str) -> bool {
+    if text.chars().any(|c| ('가'..='힣').contains(&c)) {
+        return false;
+    }
+    let mut run = 0;
+    for token in text.split(' ') {
+        let word = token.trim_end_matches([',', ':', ';', '!', '?']);
+        let is_word = word.len() >= 2
+            && word.chars().all(|c| c.is_ascii_lowercase())
+            && !CODE_WORDS.contains(&word);
+        run = if is_word { run + 1 } else { 0 };
+        if run >= 2 {
+            return true;
+        }
+        // "sum: {}" 처럼 구두점으로 끝나면 다음 단어와 이어지지 않음
+        if word.len() != token.len() {
+            run = 0;
+        }
+    }
+    false
+}
+
+// 주석 밖의 .unwrap() 인데 같은 줄 주석이나 바로 위 세 줄의 주석에서 unwrap 을 설명하지 않음
+fn is_unexplained_unwrap(lines: &[&str], i: usize) -> bool {
+    let line = lines[i];
+    let code = line.split("//").next().unwrap_or(line);
+    if line.trim_start().starts_with("//") || !code.contains(".unwrap()") {
+        return false;
+    }
+    if line.contains("//") {
+        return false;
+    }
+    !lines[i.saturating_sub(3)..i]
+        .iter()
+        .any(|l| l.trim_start().starts_with("//") && l.contains("unwrap"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(id: &'static str) -> ChapterInfo {
+        ChapterInfo {
+            id,
+            slug: "sample",
+            title: "예제",
+        }
+    }
+
+    const SAMPLE: &str = "\
+// ============================================================================
+// 98. 예제
+// ============================================================================
+// 설명만 있음
+// ============================================================================
+
+pub const SECTIONS: &[(&str, fn())] = &[
+    (\"first\", first),
+];
+
+// ----------------------------------------------------------------------------
+// 첫 절
+// ----------------------------------------------------------------------------
+
+fn first() {
+    println!(\"total after sum: {}\", 1); // TODO 번역
+}
+
+// ----------------------------------------------------------------------------
+// 에러 처리
+// ----------------------------------------------------------------------------
+
+fn second() {
+    let n: i32 = \"5\".parse().unwrap();
+    // unwrap: 실패하면 panic
+    let m: i32 = \"6\".parse().unwrap();
+}
+";
+
+    fn rules(findings: &[Finding]) -> Vec<(Rule, String)> {
+        findings
+            .iter()
+            .map(|f| (f.rule, f.location.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn finds_every_kind_of_violation() {
+        let findings = lint_chapter(&info("98"), SAMPLE);
+        assert_eq!(
+            rules(&findings),
+            vec![
+                (Rule::CppHeader, "src/_98_sample.rs:1".to_string()),
+                (Rule::Unregistered, "src/_98_sample.rs:19".to_string()),
+                (Rule::Todo, "src/_98_sample.rs:16".to_string()),
+                (Rule::Untranslated, "src/_98_sample.rs:16".to_string()),
+                (Rule::Unwrap, "src/_98_sample.rs:24".to_string()),
+            ]
+        );
+        assert!(findings[1].to_string().contains("second()"));
+    }
+
+    #[test]
+    fn code_fragments_are_not_untranslated() {
+        assert!(looks_untranslated("key still valid: {}"));
+        assert!(looks_untranslated("divide by zero"));
+        assert!(!looks_untranslated("x = {}, y = {}"));
+        assert!(!looks_untranslated("\\n--- if let, while let ---"));
+        assert!(!looks_untranslated("fold 합계: {}, concat: {}"));
+        assert!(!looks_untranslated("filter + map + collect: {:?}"));
+        assert!(!looks_untranslated("sum: {}, max: {}"));
+    }
+
+    #[test]
+    fn output_strings_handle_escaped_quotes() {
+        assert_eq!(
+            output_strings(r#"    println!("a \"b\" c", x); panic!("d");"#),
+            vec![r#"a \"b\" c"#, "d"]
+        );
+    }
+
+    #[test]
+    fn shipped_lessons_pass() {
+        let findings = lint_all();
+        assert!(
+            findings.is_empty(),
+            "{}",
+            findings
+                .iter()
+                .map(|f| f.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+}
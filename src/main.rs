@@ -5,58 +5,532 @@
 // 설계된 예제 모음입니다.
 //
 // 각 모듈은 C++ 코드와 비교하며 Rust의 핵심 개념을 설명합니다.
-// 실행: cargo run
-// 특정 모듈만 실행하려면 main() 함수에서 원하는 모듈만 호출하세요.
+// 실행: cargo run                 터미널이면 장 선택 메뉴 (파이프/CI 에서는 모든 장 실행)
+//       cargo run -- all          모든 장 실행
+// 특정 장만 실행: cargo run -- 07 (또는 cargo run -- traits, 여러 장: cargo run -- 07 09, 이어진 장: cargo run -- --from 05 --to 09)
+// 절 하나만 실행: cargo run -- 07:trait_objects
+// 소스를 고칠 때마다 다시 실행: cargo run -- watch 11
+// 마지막으로 실행한 다음 장/절부터: cargo run -- resume (무작위 절 하나: cargo run -- drill)
+// 학습 도구(퀴즈 등): cargo run -- help
+// 예제 데이터를 바꿔 가며 실행: cargo run -- --seed 42 (또는 --seed random, drill/interview 도 같은 시드로 재현)
+// 영어로 출력: cargo run -- --lang en 07
+// 장/절 실행 시간 표: cargo run -- --timing all (힙 할당 횟수와 바이트: --profile-alloc)
+// 다른 도구에 넘길 JSON 기록: cargo run -- --format json 07
+// 출력이 화면보다 길면 less 로 - 끄기: cargo run -- --no-pager all
+// 절마다 Enter 를 기다리며 한 절씩: cargo run -- --step 11
+// 장 출력을 파일로: cargo run -- --output 07.txt 07 (화면에도: --tee)
+// 먼저 볼 장을 완료하지 않았으면 경고 - 실행하지 않으려면: cargo run -- --strict 12
+// 색 테마: cargo run -- --theme light 07 (NO_COLOR=1 이면 색 없이)
+// 독립인 장을 여러 스레드에서 동시에 (출력은 장 순서대로): cargo run -- --parallel all
+// 기능(network, async, unsafe, ffi)으로 절 거르기: cargo run -- --skip async,ffi all (또는 --only unsafe)
+// 난이도로 절 거르기: cargo run -- --level intermediate,advanced all
+// 자주 쓰는 장과 플래그는 rust-study.toml 에 (chapters, lang, timing ... - 명령줄이 우선, src/config.rs)
 // ============================================================================
 
-// 모듈 선언 - 각 파일이 하나의 모듈
-mod _01_basics;
-mod _02_ownership;
-mod _03_borrowing;
-mod _04_lifetimes;
-mod _05_structs;
-mod _06_enums;
-mod _07_traits;
-mod _08_generics;
-mod _09_error_handling;
-mod _10_collections;
-mod _11_iterators;
-mod _12_smart_pointers;
-mod _13_concurrency;
-mod _14_modules;
-mod _15_macros;
-mod _16_unsafe;
-mod _17_async;
-mod _18_idioms;
-mod _19_testing;
+// 모듈 선언 - 각 파일이 하나의 모듈, 각 장은 Lesson 으로 Chapter 트레이트를 구현
+mod chapter; // 장 공통 인터페이스
+mod macros; // 여러 장에서 공유하는 매크로 (15장 참고)
+mod demo_data; // 예제 입력 데이터 - cargo run -- --seed N 으로 매번 다른 값
+mod microbench; // 장 안의 작은 벤치마크 - 같은 조건으로 여러 번 재서 중앙값 (26장)
+mod panic_hook; // 하나뿐인 panic 훅 - 장 실행기와 43, 48, 54장이 함께 씀
+
+// 출력 언어 - 아래 장 모듈의 println!/print! 를 영어 문자열 표로 바꿔 주는 매크로 (--lang en)
+#[macro_use]
+mod i18n;
+
+// 장 모듈(src/_NN_*.rs)의 mod 선언과 all_chapters() - build.rs 가 파일 목록에서 생성
+// 새 장은 파일만 추가하면 등록됨 (여기에 mod 를 쓰지 않음)
+include!(concat!(env!("OUT_DIR"), "/chapter_registry.rs"));
+
+// 학습 도구 - JSON API, 벤치마크 요약, 명령줄 처리, 연습문제 제출, 전문 검색, 주제 검색, 학습자 프로필, 샌드박스, 레슨 팩, 장 선택 메뉴, 세션 녹화, 환경 진단, 운영체제별 터미널 처리, 터미널 장 탐색기, 소스 감시, 학습 시간, 실행 시간 표, 힙 할당 표, 면접 대비, C++ 비교, 에러 갤러리, 레슨 규칙 검사, 웹 서버
+// 장 목록, 퀴즈 콘텐츠, 절 추출과 절이 쓰는 기능과 난이도, 출력 싱크는 wasm 에서도 쓰도록 라이브러리(src/lib.rs)에 있음
+use rust_study::{capabilities, chapters, content, levels, output, quiz, sections};
+
+use std::io::{self, IsTerminal};
+
+use chapter::Chapter;
+
+mod allocations;
+mod api;
+mod bench;
+mod cli;
+mod config;
+mod cpp;
+mod doctor;
+mod drill;
+mod examples;
+mod exercise;
+mod find;
+mod gallery;
+mod interview;
+mod lessons;
+mod lint;
+mod menu;
+mod pager;
+mod parallel;
+mod prereq;
+mod profile;
+mod progress;
+mod records;
+mod resume;
+mod rng;
+mod runner;
+mod sandbox;
+mod search;
+mod serve;
+mod step;
+mod tee;
+mod style;
+mod terminal;
+mod timelog;
+mod timing;
+mod transcript;
+mod tui;
+mod watch;
 
 fn main() {
+    let cli: Vec<String> = std::env::args().skip(1).collect();
+    let config = match config::load(std::path::Path::new(config::FILE)) {
+        Ok(config) => config.unwrap_or_default(),
+        Err(e) => {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+    };
+    let args = config::apply(&config, &cli);
+    let args = match demo_data::take_flag(&args) {
+        Ok((seed, rest)) => {
+            if let Some(seed) = seed {
+                demo_data::set_seed(seed);
+                // 예제 프로그램(serve 의 실행, cargo run --example)도 같은 시드를 쓰도록
+                std::env::set_var(demo_data::SEED_VAR, seed.to_string());
+            }
+            rest
+        }
+        Err(e) => {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+    };
+    let args = match i18n::take_flag(&args) {
+        Ok((lang, rest)) => {
+            if let Some(lang) = lang {
+                i18n::set_lang(lang);
+                // browse, serve 가 실행하는 자식 프로세스도 같은 언어로
+                std::env::set_var(i18n::LANG_VAR, lang.code());
+            }
+            i18n::warm_up();
+            rest
+        }
+        Err(e) => {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+    };
+    let (timing_on, args) = timing::take_flag(&args);
+    if timing_on {
+        timing::enable();
+    }
+    let (profile_alloc, args) = allocations::take_flag(&args);
+    let (no_pager, args) = pager::take_flag(&args);
+    let (parallel, args) = parallel::take_flag(&args);
+    let (step_on, args) = step::take_flag(&args);
+    let (strict, args) = prereq::take_flag(&args);
+    let (output, args) = match tee::take_flags(&args) {
+        Ok(taken) => taken,
+        Err(e) => {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+    };
+    if step_on && parallel {
+        eprintln!("에러: --step 은 한 절씩 실행하므로 --parallel 과 함께 쓸 수 없습니다");
+        std::process::exit(1);
+    }
+    if step_on {
+        step::enable();
+    }
+    if profile_alloc && parallel {
+        eprintln!("에러: --profile-alloc 은 할당을 전역으로 세므로 --parallel 과 함께 쓸 수 없습니다");
+        std::process::exit(1);
+    }
+    if profile_alloc {
+        allocations::enable();
+    }
+    let args = match capabilities::take_flags(&args) {
+        Ok((filter, rest)) => {
+            runner::set_filter(filter);
+            rest
+        }
+        Err(e) => {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+    };
+    let args = match levels::take_flag(&args) {
+        Ok((chosen, rest)) => {
+            runner::set_levels(chosen);
+            rest
+        }
+        Err(e) => {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+    };
+    let (theme, args) = match style::take_flag(&args) {
+        Ok(taken) => taken,
+        Err(e) => {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+    };
+    // 플래그만 있고 장이나 명령이 없으면 설정의 장 목록 (없으면 메뉴)
+    let args = if args.is_empty() { config.chapters.clone() } else { args };
+    // --format json 은 장을 실행할 때만 - render, bench-report 의 --format 은 학습 도구로 그대로 넘김
+    let (format, rest) = records::take_flag(&args);
+    // --from/--to 는 --format 을 뺀 뒤에 - "--format json --from 05" 처럼 앞에 다른 플래그가 와도 되게
+    let rest = match take_range(&rest) {
+        Ok(rest) => rest,
+        Err(e) => {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+    };
+    let runs_chapters = rest.is_empty() || rest == ["all"] || selected_chapters(&rest).is_some();
+    // 장을 실행하면 플래그를 뺀 장 목록으로, 학습 도구면 받은 인자 그대로
+    let args = if runs_chapters { rest.clone() } else { args };
+    if let (Some(format), true) = (format, runs_chapters) {
+        match records::parse(&format) {
+            Ok(true) if parallel => {
+                eprintln!("에러: --parallel 은 --format json 과 함께 쓸 수 없습니다");
+                std::process::exit(1);
+            }
+            Ok(true) => {
+                run_json(&rest);
+                return;
+            }
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("{}", tr!("에러: {}", e));
+                std::process::exit(1);
+            }
+        }
+    }
+    // 터미널에서 장을 실행할 때만 출력을 모아 페이저로 (메뉴와 학습 도구는 그대로)
+    // --step 은 절마다 바로 보여야 하므로 페이저 없이
+    let paging = !no_pager && !step_on && io::stdout().is_terminal();
+    // 색도 장을 실행할 때만 - 학습 도구의 출력은 그대로
+    if runs_chapters {
+        style::init(theme, io::stdout().is_terminal() && terminal::enable_ansi());
+    }
+    // 파일로 보내는 것도 장을 실행할 때만
+    if let (Some(output), true) = (&output, runs_chapters) {
+        if let Err(e) = tee::start(output) {
+            eprintln!("에러: {} 을 만들 수 없습니다: {}", output.path.display(), e);
+            std::process::exit(1);
+        }
+    }
+    // 인자가 모두 장 번호나 이름(또는 장:절)이면 그 장들만 실행
+    if let Some(selected) = selected_chapters(&args) {
+        if parallel && selected.iter().any(|(_, section)| section.is_some()) {
+            eprintln!("에러: --parallel 은 장 단위로만 실행합니다 (장:절 대신 장 번호)");
+            std::process::exit(1);
+        }
+        if let Err(e) = check_prerequisites(&selected, strict) {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+        if paging {
+            pager::start();
+        }
+        if parallel {
+            let infos: Vec<_> = selected.iter().map(|(info, _)| *info).collect();
+            parallel::run(&infos, |info| independent(info.id), |info| run_lesson(info), |info| {
+                resume::remember(info.id, None)
+            });
+            finish_run();
+            return;
+        }
+        for (info, section) in selected {
+            match section {
+                None => run_chapter(info),
+                Some(name) => {
+                    if let Err(e) = run_section(info, name) {
+                        pager::finish();
+                        eprintln!("{}", tr!("에러: {}", e));
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+        finish_run();
+        return;
+    }
+    if args == ["all"] {
+        if paging {
+            pager::start();
+        }
+        run_all_chapters(parallel);
+        finish_run();
+        return;
+    }
+    if !args.is_empty() {
+        if let Err(e) = cli::run(&args) {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // 사람이 입력할 수 있을 때만 메뉴 - 파이프나 CI 에서는 예전처럼 모든 장
+    if io::stdin().is_terminal() {
+        if let Err(e) = menu::run(&mut io::stdin().lock(), &mut io::stdout(), run_chapter) {
+            eprintln!("{}", tr!("에러: {}", e));
+            std::process::exit(1);
+        }
+    } else {
+        run_all_chapters(parallel);
+    }
+    finish_run();
+}
+
+// 장을 실행한 뒤 - 시간 표(--timing), 할당 표(--profile-alloc), 통과/실패 요약, 모아 둔 출력(페이저), panic 한 절이 있으면 종료 코드 1
+fn finish_run() {
+    timing::print_summary();
+    allocations::print_summary();
+    let passed = runner::finish();
+    pager::finish();
+    if !passed {
+        std::process::exit(1);
+    }
+}
+
+// 고른 장 중 먼저 볼 장을 완료하지 않은 것 - 경고만, --strict 면 Err
+fn check_prerequisites(selected: &[(&'static chapters::ChapterInfo, Option<&str>)], strict: bool) -> Result<(), String> {
+    let mut ids = Vec::new();
+    for (info, _) in selected {
+        if !ids.contains(&info.id) {
+            ids.push(info.id);
+        }
+    }
+    // 진도 파일을 읽을 수 없으면 확인하지 않음
+    let Ok(store) = progress::Progress::load(&progress::default_path()) else {
+        return Ok(());
+    };
+    let missing = prereq::missing(&ids, |id| store.completed.contains_key(id));
+    for (chapter, before) in &missing {
+        eprintln!("{}", tr!("주의: {}", prereq::message(chapter, before)));
+    }
+    if strict && !missing.is_empty() {
+        return Err(tr!("--strict: 먼저 볼 장을 완료한 뒤 실행하세요 (퀴즈를 통과하면 완료로 기록)"));
+    }
+    Ok(())
+}
+
+fn run_all_chapters(parallel: bool) {
     println!("╔══════════════════════════════════════════════════════════════╗");
     println!("║     Rust 학습 가이드 - C++20 개발자를 위한 예제 모음         ║");
     println!("╚══════════════════════════════════════════════════════════════╝");
+    if let Some(seed) = demo_data::seed() {
+        println!("예제 데이터 시드: {} (cargo run -- --seed {} 로 같은 데이터 다시 보기)", seed, seed);
+    }
 
-    // 각 모듈 실행 - 필요한 것만 주석 해제하여 실행
-    _01_basics::run();
-    _02_ownership::run();
-    _03_borrowing::run();
-    _04_lifetimes::run();
-    _05_structs::run();
-    _06_enums::run();
-    _07_traits::run();
-    _08_generics::run();
-    _09_error_handling::run();
-    _10_collections::run();
-    _11_iterators::run();
-    _12_smart_pointers::run();
-    _13_concurrency::run();
-    _14_modules::run();
-    _15_macros::run();
-    _16_unsafe::run();
-    _17_async::run();
-    _18_idioms::run();
-    _19_testing::run();
+    let all = all_chapters();
+    if parallel {
+        parallel::run(&all, |c| independent(c.id()), |c| runner::run_chapter(c.as_ref()), |_| {});
+    } else {
+        for chapter in &all {
+            runner::run_chapter(chapter.as_ref());
+        }
+    }
 
     println!("\n╔══════════════════════════════════════════════════════════════╗");
     println!("║                    모든 예제 실행 완료!                       ║");
     println!("╚══════════════════════════════════════════════════════════════╝");
 }
+
+// "07", "traits", "07:trait_objects" 같은 인자 목록 → (장, 절) 목록
+// 하나라도 장이 아니면 None - 학습 도구 명령으로 ("07::trait_objects" 도 같은 절)
+fn selected_chapters(args: &[String]) -> Option<Vec<(&'static chapters::ChapterInfo, Option<&str>)>> {
+    if args.is_empty() {
+        return None;
+    }
+    args.iter()
+        .map(|arg| match arg.split_once(':') {
+            Some((key, name)) => Some((chapters::find(key)?, Some(name.trim_start_matches(':')))),
+            None => Some((chapters::find(arg)?, None)),
+        })
+        .collect()
+}
+
+// 장 고르기 인자 - 41장의 clap derive 로 파싱
+// --from/--to 는 맨 앞에만: 첫 장 번호나 명령부터는 모두 rest 로 (학습 도구의 옵션은 건드리지 않음)
+// -h/--help 는 학습 도구의 help 명령이 처리하므로 clap 의 것은 끔
+#[derive(clap::Parser)]
+#[command(no_binary_name = true, disable_help_flag = true, disable_version_flag = true)]
+struct Selection {
+    // 이 장부터 (번호나 이름, 빼면 처음부터)
+    #[arg(long, value_name = "장", value_parser = parse_chapter)]
+    from: Option<&'static chapters::ChapterInfo>,
+    // 이 장까지 (빼면 끝까지)
+    #[arg(long, value_name = "장", value_parser = parse_chapter)]
+    to: Option<&'static chapters::ChapterInfo>,
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    rest: Vec<String>,
+}
+
+fn parse_chapter(key: &str) -> Result<&'static chapters::ChapterInfo, String> {
+    chapters::find(key).ok_or_else(|| format!("없는 장: {}", key))
+}
+
+// --from <장> --to <장> 을 그 사이의 장 번호로 바꿈 - 다른 장 인자는 뒤에 그대로
+// 장이 아닌 인자(학습 도구 명령)와는 함께 쓸 수 없음
+fn take_range(args: &[String]) -> Result<Vec<String>, String> {
+    use clap::Parser;
+    let selection = Selection::try_parse_from(args).map_err(|e| {
+        // clap 의 에러에서 첫 줄만 - 사용법과 --help 안내는 이 프로그램과 맞지 않음
+        let text = e.to_string();
+        let line = text.lines().next().unwrap_or_default().trim_start_matches("error: ").to_string();
+        format!("{} (예: --from 05 --to 09)", line)
+    })?;
+    let Selection { from, to, rest } = selection;
+    if from.is_none() && to.is_none() {
+        return Ok(rest);
+    }
+    if !rest.is_empty() && selected_chapters(&rest).is_none() {
+        return Err(format!("--from/--to 는 장 번호와만 함께 쓸 수 있습니다: {}", rest.join(" ")));
+    }
+    let range = chapters::range(from.map(|info| info.id), to.map(|info| info.id))?;
+    Ok(range.iter().map(|info| info.id.to_string()).chain(rest).collect())
+}
+
+fn find_chapter(info: &chapters::ChapterInfo) -> Option<Box<dyn Chapter>> {
+    all_chapters().into_iter().find(|c| c.id() == info.id)
+}
+
+fn run_chapter(info: &chapters::ChapterInfo) {
+    run_lesson(info);
+    resume::remember(info.id, None);
+}
+
+// 학습 시간을 기록하며 장 실행 - 마지막 실행 위치(resume)는 부르는 쪽이 순서대로 남김
+fn run_lesson(info: &chapters::ChapterInfo) {
+    let Some(chapter) = find_chapter(info) else {
+        return;
+    };
+    let _timer = timelog::Tracker::start(info.id, None, "lesson");
+    runner::run_chapter(chapter.as_ref());
+}
+
+// --parallel 에서 다른 장과 동시에 실행해도 되는 장인지 (스레드, 전역 상태를 쓰지 않음)
+fn independent(id: &str) -> bool {
+    chapters::source(id).is_some_and(parallel::independent)
+}
+
+fn find_section(chapter: &dyn Chapter, name: &str) -> Result<(&'static str, fn()), String> {
+    match chapter.sections().iter().find(|(n, _)| *n == name) {
+        Some((found, section)) => Ok((found, *section)),
+        None => {
+            let names: Vec<&str> = chapter.sections().iter().map(|(n, _)| *n).collect();
+            Err(format!(
+                "{}장에 '{}' 절이 없습니다 (있는 절: {})",
+                chapter.id(),
+                name,
+                names.join(", ")
+            ))
+        }
+    }
+}
+
+fn run_section(info: &chapters::ChapterInfo, name: &str) -> Result<(), String> {
+    let chapter = find_chapter(info).ok_or_else(|| format!("{}장을 실행할 수 없습니다", info.id))?;
+    let (found, section) = find_section(chapter.as_ref(), name)?;
+    println!("\n=== {}. {} - {} ===\n", info.id, i18n::chapter_name(info.id, chapter.name()), name);
+    let _timer = timelog::Tracker::start(info.id, Some(name), "lesson");
+    runner::run_one(chapter.as_ref(), found, section);
+    resume::remember(info.id, Some(found));
+    Ok(())
+}
+
+// --format json - 절마다 JSON 한 줄, 인자가 없거나 all 이면 모든 장
+fn run_json(args: &[String]) {
+    let selected = selected_chapters(args)
+        .unwrap_or_else(|| chapters::CHAPTERS.iter().map(|info| (info, None)).collect());
+    let mut ok = true;
+    for (info, name) in selected {
+        let Some(chapter) = find_chapter(info) else {
+            continue;
+        };
+        let _timer = timelog::Tracker::start(info.id, name, "lesson");
+        ok &= match name {
+            None => records::run_chapter(chapter.as_ref()),
+            Some(name) => match find_section(chapter.as_ref(), name) {
+                Ok((found, section)) => records::emit(&records::run_section(info.id, found, section)),
+                Err(e) => {
+                    eprintln!("{}", tr!("에러: {}", e));
+                    std::process::exit(1);
+                }
+            },
+        };
+    }
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Chapter 구현과 라이브러리의 장 표(CHAPTERS)가 같은 장을 같은 순서로
+    #[test]
+    fn chapters_match_the_table() {
+        let all = all_chapters();
+        assert_eq!(all.len(), chapters::CHAPTERS.len());
+        for (chapter, info) in all.iter().zip(chapters::CHAPTERS) {
+            assert_eq!(chapter.id(), info.id);
+            assert_eq!(chapter.name(), info.title, "{}장", info.id);
+            assert!(!chapter.description().is_empty(), "{}장", info.id);
+            assert!(!chapter.topics().is_empty(), "{}장", info.id);
+            // 실행할 수 있는 절과 소스에서 찾은 절(share, 예제 생성)이 같은 이름
+            let names: Vec<&str> = chapter.sections().iter().map(|(n, _)| *n).collect();
+            let parsed: Vec<String> = sections::sections(info).into_iter().map(|s| s.name).collect();
+            assert_eq!(names, parsed, "{}장", info.id);
+        }
+    }
+
+    #[test]
+    fn arguments_select_chapters_and_sections() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let picked = |list: &[&str]| {
+            selected_chapters(&args(list))
+                .map(|v| v.iter().map(|(info, s)| (info.id, s.map(str::to_string))).collect::<Vec<_>>())
+        };
+        assert_eq!(
+            picked(&["traits", "07:trait_objects", "11::custom_iterator"]),
+            Some(vec![
+                ("07", None),
+                ("07", Some("trait_objects".to_string())),
+                ("11", Some("custom_iterator".to_string())),
+            ])
+        );
+        assert_eq!(picked(&["quiz", "07"]), None);
+        assert!(run_section(chapters::find("07").unwrap(), "nope")
+            .unwrap_err()
+            .contains("trait_objects"));
+    }
+
+    #[test]
+    fn from_and_to_select_a_slice_of_chapters() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(take_range(&args(&["--from", "05", "--to", "generics"])).unwrap(), ["05", "06", "07", "08"]);
+        assert_eq!(take_range(&args(&["--from", "20", "--to", "21"])).unwrap(), ["20", "21"]);
+        assert_eq!(take_range(&args(&["--to", "2", "17"])).unwrap(), ["01", "02", "17"]);
+        assert_eq!(take_range(&args(&["quiz", "07"])).unwrap(), ["quiz", "07"]);
+        // 명령 뒤의 인자는 학습 도구의 것 - --from 이라도 그대로
+        assert_eq!(take_range(&args(&["render", "--from", "x"])).unwrap(), ["render", "--from", "x"]);
+        assert!(take_range(&args(&["--from", "09", "--to", "05"])).is_err());
+        assert!(take_range(&args(&["--from", "05", "quiz"])).is_err());
+        assert!(take_range(&args(&["--from", "99"])).is_err());
+    }
+}
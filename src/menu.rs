@@ -0,0 +1,109 @@
+// ============================================================================
+// 장 선택 메뉴
+// ============================================================================
+// 터미널에서 cargo run 을 인자 없이 실행하면 번호 메뉴를 보여주고
+// 고른 장만 실행한 뒤 다시 묻기를 반복 (q 로 종료)
+//   7, 07, traits  그 장 실행
+//   a              모든 장 실행
+//   l              메뉴 다시 보기
+//   q              종료 (입력이 끝나도 종료)
+// 입출력을 BufRead/Write 로 받아서 테스트에서는 메모리 버퍼로 대체 (quiz.rs 와 같은 방식)
+// ============================================================================
+
+use std::io::{self, BufRead, Write};
+
+use crate::chapters::{self, ChapterInfo};
+
+#[derive(Clone, Copy)]
+pub enum Choice {
+    Chapter(&'static ChapterInfo),
+    All,
+    List,
+    Quit,
+}
+
+// 빈 줄은 None (다시 묻기), 알 수 없는 입력은 Err
+pub fn parse(line: &str) -> Result<Option<Choice>, String> {
+    let choice = match line.trim() {
+        "" => return Ok(None),
+        "q" | "quit" | "exit" => Choice::Quit,
+        "a" | "all" => Choice::All,
+        "l" | "list" => Choice::List,
+        key => Choice::Chapter(
+            chapters::find(key).ok_or_else(|| format!("알 수 없는 장: '{}'", key))?,
+        ),
+    };
+    Ok(Some(choice))
+}
+
+pub fn print_menu<W: Write>(out: &mut W) -> io::Result<()> {
+    writeln!(out, "\n장을 골라 실행하세요")?;
+    for info in chapters::CHAPTERS {
+        writeln!(out, "  {:>2}. {}", info.id.trim_start_matches('0'), info.title)?;
+    }
+    writeln!(out, "   a. 모든 장 실행    l. 메뉴 다시 보기    q. 종료")
+}
+
+// run_chapter 는 고른 장을 실행 - main 에서는 runner 로 장의 절을, 테스트에서는 기록만
+pub fn run<R: BufRead, W: Write>(
+    input: &mut R,
+    out: &mut W,
+    mut run_chapter: impl FnMut(&'static ChapterInfo),
+) -> io::Result<()> {
+    print_menu(out)?;
+    loop {
+        write!(out, "\n번호 또는 이름 (a, l, q) > ")?;
+        out.flush()?;
+        let mut line = String::new();
+        // Ctrl+D 등으로 입력이 끝나면 종료
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(());
+        }
+        match parse(&line) {
+            Ok(None) => {}
+            Ok(Some(Choice::Quit)) => return Ok(()),
+            Ok(Some(Choice::List)) => print_menu(out)?,
+            Ok(Some(Choice::All)) => chapters::CHAPTERS.iter().for_each(&mut run_chapter),
+            Ok(Some(Choice::Chapter(info))) => run_chapter(info),
+            Err(e) => writeln!(out, "{} - 1~{} 의 번호나 장 이름 (예: traits)", e, chapters::CHAPTERS.len())?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers_names_and_commands() {
+        let chapter = |line: &str| match parse(line) {
+            Ok(Some(Choice::Chapter(info))) => Some(info.id),
+            _ => None,
+        };
+        assert_eq!(chapter(" 7 \n"), Some("07"));
+        assert_eq!(chapter("traits"), Some("07"));
+        assert!(matches!(parse("q"), Ok(Some(Choice::Quit))));
+        assert!(matches!(parse("a"), Ok(Some(Choice::All))));
+        assert!(matches!(parse(""), Ok(None)));
+        assert!(parse("99").is_err());
+    }
+
+    #[test]
+    fn loops_until_quit() {
+        let mut input = "3\nnope\n\nl\nasync\nq\n12\n".as_bytes();
+        let mut out = Vec::new();
+        let mut ran = Vec::new();
+        run(&mut input, &mut out, |info| ran.push(info.id)).unwrap();
+        // q 뒤의 12 는 실행되지 않음
+        assert_eq!(ran, vec!["03", "17"]);
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("알 수 없는 장: 'nope'"));
+        assert_eq!(text.matches("장을 골라 실행하세요").count(), 2);
+
+        // 입력이 끝나도 종료
+        let mut ran = 0;
+        run(&mut "a\n".as_bytes(), &mut Vec::new(), |_| ran += 1).unwrap();
+        assert_eq!(ran, chapters::CHAPTERS.len());
+    }
+}
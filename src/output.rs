@@ -0,0 +1,678 @@
+// ============================================================================
+// 레슨 출력 싱크 (OutputSink)
+// ============================================================================
+// cargo run -- render 07                 터미널에 일반 텍스트로
+// cargo run -- render 07 --format tui    상자 선과 나란히 비교가 있는 터미널 화면
+// cargo run -- render 07 --format html   독립 HTML 문서 (> 07.html)
+//
+// 장 소스를 절 머리, 설명 글, 코드 블록, C++/Rust 비교로 나눈 뒤(lesson_blocks)
+// content/prose/ 의 Markdown 설명 글(content::Prose)을 장 머리와 각 절 제목 뒤에 끼워 넣고
+// OutputSink 에 차례로 씀 - 다른 프로그램에 레슨을 넣을 때는 트레이트만 구현하면 됨
+// CaptureSink 는 블록을 모아 두었다가 다른 싱크로 다시 보낼 수 있음
+// 아래쪽 sparkline, bar, heatmap 은 stats 명령의 터미널 그래픽
+// ============================================================================
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use crate::chapters::{self, ChapterInfo};
+use crate::content::{self, Prose};
+use crate::sections;
+
+pub trait OutputSink {
+    // level 1 = 장 제목, 2 = 절
+    fn section_header(&mut self, level: usize, title: &str) -> io::Result<()>;
+    fn text(&mut self, text: &str) -> io::Result<()>;
+    fn code_block(&mut self, code: &str) -> io::Result<()>;
+    // 주석의 C++ 코드와 바로 뒤의 Rust 코드
+    fn comparison(&mut self, cpp: &str, rust: &str) -> io::Result<()>;
+}
+
+// 싱크에 쓰는 단위 하나 - JSON 으로는 {"text": "..."}, {"header": {"level", "title"}} 처럼
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Block {
+    Header { level: usize, title: String },
+    Text(String),
+    Code(String),
+    Comparison { cpp: String, rust: String },
+}
+
+impl Block {
+    pub fn write_to(&self, sink: &mut dyn OutputSink) -> io::Result<()> {
+        match self {
+            Block::Header { level, title } => sink.section_header(*level, title),
+            Block::Text(text) => sink.text(text),
+            Block::Code(code) => sink.code_block(code),
+            Block::Comparison { cpp, rust } => sink.comparison(cpp, rust),
+        }
+    }
+}
+
+// 설명 글은 기본 콘텐츠(content::source)에서 - 렌더링할 때마다 다시 읽으므로 고친 글이 바로 반영
+pub fn render_lesson(info: &ChapterInfo, sink: &mut dyn OutputSink) -> io::Result<()> {
+    let prose = content::source()
+        .load_prose(info)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    render_lesson_with(info, &prose, sink)
+}
+
+pub fn render_lesson_with(info: &ChapterInfo, prose: &Prose, sink: &mut dyn OutputSink) -> io::Result<()> {
+    let source = chapters::source(info.id).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{}장의 소스가 없습니다", info.id),
+        )
+    })?;
+    for block in lesson_blocks(info.id, source, prose) {
+        block.write_to(sink)?;
+    }
+    Ok(())
+}
+
+// ----------------------------------------------------------------------------
+// 장 소스 → 블록
+// ----------------------------------------------------------------------------
+
+// 줄 맨 앞의 주석만 설명 글 - 함수 안의 주석은 코드 블록에 남김
+fn comment_text(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("//")?;
+    Some(rest.strip_prefix(' ').unwrap_or(rest))
+}
+
+pub fn lesson_blocks(chapter: &'static str, source: &'static str, prose: &Prose) -> Vec<Block> {
+    let mut blocks = Vec::new();
+
+    // 파일 머리: 제목 + 설명
+    let lines: Vec<&str> = source.lines().collect();
+    if lines.len() > 1 && sections::is_rule(lines[0]) {
+        if let Some(title) = comment_text(lines[1]) {
+            blocks.push(Block::Header {
+                level: 1,
+                title: title.trim().to_string(),
+            });
+        }
+        let body: Vec<&str> = lines[2..]
+            .iter()
+            .skip_while(|l| sections::is_rule(l))
+            .take_while(|l| !sections::is_rule(l))
+            .filter_map(|l| comment_text(l))
+            .collect();
+        if !body.is_empty() {
+            blocks.push(Block::Text(body.join("\n")));
+        }
+    }
+    if let Some(intro) = &prose.intro {
+        blocks.push(Block::Text(intro.clone()));
+    }
+
+    for section in sections::parse(chapter, source) {
+        let start = blocks.len();
+        section_blocks(section.code, &mut blocks);
+        // 절 제목 바로 뒤 (제목이 없으면 절의 맨 앞)
+        if let Some(text) = prose.section(&section.name) {
+            let at = match blocks.get(start) {
+                Some(Block::Header { .. }) => start + 1,
+                _ => start,
+            };
+            blocks.insert(at, Block::Text(text.to_string()));
+        }
+    }
+    blocks
+}
+
+fn section_blocks(code: &str, blocks: &mut Vec<Block>) {
+    let lines: Vec<&str> = code.lines().collect();
+    // 비교를 기다리는 C++ 코드 - 다음 코드 블록과 짝지음
+    let mut pending_cpp: Option<String> = None;
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+
+        // 절 머리 (절 안의 하위 머리 포함)
+        if sections::is_rule(line) && i + 2 < lines.len() && sections::is_rule(lines[i + 2]) {
+            if let Some(title) = comment_text(lines[i + 1]) {
+                flush_cpp(&mut pending_cpp, blocks);
+                blocks.push(Block::Header {
+                    level: 2,
+                    title: title.trim().to_string(),
+                });
+            }
+            i += 3;
+            continue;
+        }
+        if line.trim().is_empty() {
+            i += 1;
+            continue;
+        }
+
+        if comment_text(line).is_some() {
+            // 빈 줄까지 이어진 주석 하나
+            let mut text = Vec::new();
+            let mut cpp: Option<Vec<&str>> = None;
+            while i < lines.len() && !sections::is_rule(lines[i]) {
+                let Some(t) = comment_text(lines[i]) else {
+                    break;
+                };
+                match (&mut cpp, t.trim_start().strip_prefix("C++:")) {
+                    (Some(cpp), _) => cpp.push(t),
+                    // "// C++: class A : public B { ... };" 한 줄짜리
+                    (None, Some(rest)) if !rest.trim().is_empty() => {
+                        cpp = Some(vec![rest.trim()]);
+                    }
+                    (None, Some(_)) => cpp = Some(Vec::new()),
+                    (None, None) => text.push(t),
+                }
+                i += 1;
+            }
+            flush_cpp(&mut pending_cpp, blocks);
+            if !text.is_empty() {
+                blocks.push(Block::Text(text.join("\n")));
+            }
+            pending_cpp = cpp.filter(|c| !c.is_empty()).map(|c| c.join("\n"));
+            continue;
+        }
+
+        // 다음 맨 앞 주석이나 절 머리까지가 코드 블록
+        let start = i;
+        while i < lines.len() && comment_text(lines[i]).is_none() {
+            i += 1;
+        }
+        let rust = lines[start..i].join("\n").trim_end().to_string();
+        match pending_cpp.take() {
+            Some(cpp) => blocks.push(Block::Comparison { cpp, rust }),
+            None => blocks.push(Block::Code(rust)),
+        }
+    }
+    flush_cpp(&mut pending_cpp, blocks);
+}
+
+// 짝이 되는 Rust 코드가 없는 C++ 코드는 그냥 코드로
+fn flush_cpp(pending: &mut Option<String>, blocks: &mut Vec<Block>) {
+    if let Some(cpp) = pending.take() {
+        blocks.push(Block::Code(cpp));
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 구현: 일반 텍스트, 터미널 화면, HTML, 캡처
+// ----------------------------------------------------------------------------
+
+// cargo run 으로 장을 실행할 때와 같은 모양
+pub struct PlainSink<W: Write> {
+    out: W,
+}
+
+impl<W: Write> PlainSink<W> {
+    pub fn new(out: W) -> Self {
+        PlainSink { out }
+    }
+}
+
+fn indented(code: &str) -> String {
+    code.lines()
+        .map(|l| format!("    {}", l).trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl<W: Write> OutputSink for PlainSink<W> {
+    fn section_header(&mut self, level: usize, title: &str) -> io::Result<()> {
+        match level {
+            1 => writeln!(self.out, "\n=== {} ===\n", title),
+            _ => writeln!(self.out, "\n--- {} ---\n", title),
+        }
+    }
+
+    fn text(&mut self, text: &str) -> io::Result<()> {
+        writeln!(self.out, "{}\n", text)
+    }
+
+    fn code_block(&mut self, code: &str) -> io::Result<()> {
+        writeln!(self.out, "{}\n", indented(code))
+    }
+
+    fn comparison(&mut self, cpp: &str, rust: &str) -> io::Result<()> {
+        writeln!(
+            self.out,
+            "C++:\n{}\nRust:\n{}\n",
+            indented(cpp),
+            indented(rust)
+        )
+    }
+}
+
+// 터미널 칸 수 - 한글 등 동아시아 전각 문자는 두 칸
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60 => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+// 칸 수에 맞춰 자르거나 공백으로 채움
+pub fn fit(s: &str, width: usize) -> String {
+    let mut out = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = display_width(c.encode_utf8(&mut [0; 4]));
+        if used + w > width {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push_str(&" ".repeat(width - used));
+    out
+}
+
+// 상자 선으로 구분하고 비교는 두 칸으로 나란히
+pub struct TuiSink<W: Write> {
+    out: W,
+    width: usize,
+}
+
+impl<W: Write> TuiSink<W> {
+    pub fn new(out: W, width: usize) -> Self {
+        TuiSink {
+            out,
+            width: width.max(40),
+        }
+    }
+}
+
+impl<W: Write> OutputSink for TuiSink<W> {
+    fn section_header(&mut self, level: usize, title: &str) -> io::Result<()> {
+        let line = if level == 1 { "━" } else { "─" };
+        writeln!(self.out, "\n{}\n{}", title, line.repeat(self.width))
+    }
+
+    fn text(&mut self, text: &str) -> io::Result<()> {
+        writeln!(self.out, "{}\n", text)
+    }
+
+    fn code_block(&mut self, code: &str) -> io::Result<()> {
+        for line in code.lines() {
+            writeln!(self.out, "  │ {}", line)?;
+        }
+        writeln!(self.out)
+    }
+
+    fn comparison(&mut self, cpp: &str, rust: &str) -> io::Result<()> {
+        let column = (self.width - 3) / 2;
+        let left: Vec<&str> = cpp.lines().collect();
+        let right: Vec<&str> = rust.lines().collect();
+        writeln!(self.out, "{} │ Rust", fit("C++", column))?;
+        writeln!(self.out, "{}─┼─{}", "─".repeat(column), "─".repeat(column))?;
+        for i in 0..left.len().max(right.len()) {
+            let l = left.get(i).copied().unwrap_or_default();
+            let r = right.get(i).copied().unwrap_or_default();
+            let row = format!("{} │ {}", fit(l, column), r);
+            writeln!(self.out, "{}", row.trim_end())?;
+        }
+        writeln!(self.out)
+    }
+}
+
+// <body> 안쪽만 쌓고 finish() 에서 문서로 감쌈
+#[derive(Debug, Default)]
+pub struct HtmlSink {
+    title: String,
+    body: String,
+}
+
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const STYLE: &str = "body{font-family:sans-serif;max-width:60rem;margin:auto;line-height:1.5}\
+pre{background:#f5f5f5;padding:.5rem;overflow-x:auto}\
+.comparison{display:grid;grid-template-columns:1fr 1fr;gap:.5rem}";
+
+impl HtmlSink {
+    pub fn new() -> Self {
+        HtmlSink::default()
+    }
+
+    pub fn finish(self) -> String {
+        page(&self.title, &self.body)
+    }
+
+    // 제목과 <body> 안쪽 - 다른 문서(serve 의 페이지)에 끼워 넣을 때
+    pub fn into_parts(self) -> (String, String) {
+        (self.title, self.body)
+    }
+}
+
+// 독립 HTML 문서 - 레슨 렌더링과 serve 의 페이지가 같은 모양
+pub fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+impl OutputSink for HtmlSink {
+    fn section_header(&mut self, level: usize, title: &str) -> io::Result<()> {
+        if self.title.is_empty() {
+            self.title = title.to_string();
+        }
+        let level = level.clamp(1, 6);
+        self.body
+            .push_str(&format!("<h{0}>{1}</h{0}>\n", level, escape(title)));
+        Ok(())
+    }
+
+    fn text(&mut self, text: &str) -> io::Result<()> {
+        self.body.push_str(&format!(
+            "<p>{}</p>\n",
+            escape(text).replace('\n', "<br>\n")
+        ));
+        Ok(())
+    }
+
+    fn code_block(&mut self, code: &str) -> io::Result<()> {
+        self.body.push_str(&format!(
+            "<pre><code class=\"language-rust\">{}</code></pre>\n",
+            escape(code)
+        ));
+        Ok(())
+    }
+
+    fn comparison(&mut self, cpp: &str, rust: &str) -> io::Result<()> {
+        self.body.push_str(&format!(
+            "<div class=\"comparison\">\n\
+             <pre><code class=\"language-cpp\">{}</code></pre>\n\
+             <pre><code class=\"language-rust\">{}</code></pre>\n</div>\n",
+            escape(cpp),
+            escape(rust)
+        ));
+        Ok(())
+    }
+}
+
+// 블록을 그대로 모음 - 테스트나 나중에 다른 싱크로 다시 보낼 때
+#[derive(Debug, Default)]
+pub struct CaptureSink {
+    pub blocks: Vec<Block>,
+}
+
+impl CaptureSink {
+    pub fn new() -> Self {
+        CaptureSink::default()
+    }
+
+    pub fn replay(&self, sink: &mut dyn OutputSink) -> io::Result<()> {
+        for block in &self.blocks {
+            block.write_to(sink)?;
+        }
+        Ok(())
+    }
+}
+
+impl OutputSink for CaptureSink {
+    fn section_header(&mut self, level: usize, title: &str) -> io::Result<()> {
+        self.blocks.push(Block::Header {
+            level,
+            title: title.to_string(),
+        });
+        Ok(())
+    }
+
+    fn text(&mut self, text: &str) -> io::Result<()> {
+        self.blocks.push(Block::Text(text.to_string()));
+        Ok(())
+    }
+
+    fn code_block(&mut self, code: &str) -> io::Result<()> {
+        self.blocks.push(Block::Code(code.to_string()));
+        Ok(())
+    }
+
+    fn comparison(&mut self, cpp: &str, rust: &str) -> io::Result<()> {
+        self.blocks.push(Block::Comparison {
+            cpp: cpp.to_string(),
+            rust: rust.to_string(),
+        });
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 터미널 그래픽 (cargo run -- stats)
+// ----------------------------------------------------------------------------
+// 색 없이 유니코드 블록 문자만 - 어느 터미널이나 파이프에서도 같은 모양
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const SHADES: [char; 5] = ['·', '░', '▒', '▓', '█'];
+
+// 값 하나당 한 칸 - 0 은 가장 낮은 막대, max 는 가장 높은 막대
+pub fn sparkline(values: &[u64], max: u64) -> String {
+    values
+        .iter()
+        .map(|&v| {
+            let level = (v.min(max) * 7).checked_div(max).unwrap_or(0);
+            SPARKS[level as usize]
+        })
+        .collect()
+}
+
+// width 칸짜리 가로 막대 - 채운 칸 █, 빈 칸 ░
+pub fn bar(value: u64, max: u64, width: usize) -> String {
+    let filled = (value.min(max) as usize * width)
+        .checked_div(max as usize)
+        .unwrap_or(0);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+// 달력 히트맵 - 줄은 월요일부터 일요일, 칸은 주 (왼쪽이 오래된 주)
+// counts 는 유닉스 일 수 → 활동 수, today 가 있는 주가 마지막 칸
+// 오늘 이후 칸은 공백
+pub fn heatmap(counts: &BTreeMap<i64, u64>, today: i64, weeks: usize) -> Vec<String> {
+    // 1970-01-01 은 목요일 - 월요일이 0
+    let weekday = |day: i64| (day + 3).rem_euclid(7);
+    let start = today - weekday(today) - 7 * (weeks as i64 - 1);
+    let max = counts.values().copied().max().unwrap_or(0);
+    (0..7)
+        .map(|row| {
+            (0..weeks as i64)
+                .map(|week| {
+                    let day = start + week * 7 + row;
+                    if day > today {
+                        return ' ';
+                    }
+                    match counts.get(&day).copied().unwrap_or(0) {
+                        0 => SHADES[0],
+                        n => SHADES[1 + ((n - 1) * 4 / max) as usize],
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+// ============================================================================
+// 98. 예제
+// ============================================================================
+// C++ 과의 차이점:
+// 1. 상속 대신 트레이트
+// ============================================================================
+
+pub const SECTIONS: &[(&str, fn())] = &[
+    (\"first\", first),
+];
+
+// ----------------------------------------------------------------------------
+// 첫 절
+// ----------------------------------------------------------------------------
+
+// 트레이트 정의
+// C++:
+// class Shape {
+//     virtual double area() = 0;
+// };
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+// C++ 주석 없이 설명만
+// C++: struct Unit;
+// 짝이 없는 C++ 코드
+
+fn first() {
+    // 함수 안 주석은 코드에 남음
+    println!(\"첫 절\");
+}
+";
+
+    fn header(level: usize, title: &str) -> Block {
+        Block::Header {
+            level,
+            title: title.to_string(),
+        }
+    }
+
+    #[test]
+    fn lesson_source_splits_into_blocks() {
+        let blocks = lesson_blocks("98", SAMPLE, &Prose::default());
+        assert_eq!(
+            blocks,
+            vec![
+                header(1, "98. 예제"),
+                Block::Text("C++ 과의 차이점:\n1. 상속 대신 트레이트".to_string()),
+                header(2, "첫 절"),
+                Block::Text("트레이트 정의".to_string()),
+                Block::Comparison {
+                    cpp: "class Shape {\n    virtual double area() = 0;\n};".to_string(),
+                    rust: "trait Shape {\n    fn area(&self) -> f64;\n}".to_string(),
+                },
+                Block::Text("C++ 주석 없이 설명만".to_string()),
+                Block::Comparison {
+                    cpp: "struct Unit;\n짝이 없는 C++ 코드".to_string(),
+                    rust: "fn first() {\n    // 함수 안 주석은 코드에 남음\n    println!(\"첫 절\");\n}"
+                        .to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn prose_is_stitched_after_headers() {
+        let prose = Prose::parse("머리에 덧붙임\n\n## first\n절 설명\n\n## missing\n없는 절\n");
+        let blocks = lesson_blocks("98", SAMPLE, &prose);
+        assert_eq!(blocks[2], Block::Text("머리에 덧붙임".to_string()));
+        assert_eq!(blocks[3], header(2, "첫 절"));
+        assert_eq!(blocks[4], Block::Text("절 설명".to_string()));
+        assert_eq!(blocks.len(), lesson_blocks("98", SAMPLE, &Prose::default()).len() + 2);
+    }
+
+    #[test]
+    fn capture_replays_into_other_sinks() {
+        let mut capture = CaptureSink::new();
+        for block in lesson_blocks("98", SAMPLE, &Prose::default()) {
+            block.write_to(&mut capture).unwrap();
+        }
+
+        let mut html = HtmlSink::new();
+        capture.replay(&mut html).unwrap();
+        let html = html.finish();
+        assert!(html.contains("<title>98. 예제</title>"));
+        assert!(html.contains("<h2>첫 절</h2>"));
+        assert!(html.contains("fn area(&amp;self) -&gt; f64;"));
+        assert!(html.contains("<div class=\"comparison\">"));
+
+        let mut plain = Vec::new();
+        capture.replay(&mut PlainSink::new(&mut plain)).unwrap();
+        let plain = String::from_utf8(plain).unwrap();
+        assert!(plain.contains("--- 첫 절 ---"));
+        assert!(plain.contains("C++:\n    class Shape {"));
+    }
+
+    #[test]
+    fn korean_text_is_two_columns_wide() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("값: 6"), 5);
+        assert_eq!(fit("한글abc", 5), "한글a");
+        assert_eq!(fit("ab", 4), "ab  ");
+    }
+
+    #[test]
+    fn tui_comparison_is_side_by_side() {
+        let mut out = Vec::new();
+        TuiSink::new(&mut out, 43)
+            .comparison("int x = 5;", "let x = 5;\nlet y = x;")
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], format!("C++{} │ Rust", " ".repeat(17)));
+        assert_eq!(
+            lines[2],
+            format!("int x = 5;{} │ let x = 5;", " ".repeat(10))
+        );
+        assert_eq!(lines[3], format!("{} │ let y = x;", " ".repeat(20)));
+    }
+
+    #[test]
+    fn every_lesson_renders() {
+        for info in chapters::CHAPTERS {
+            let mut capture = CaptureSink::new();
+            render_lesson(info, &mut capture).unwrap();
+            assert!(
+                matches!(&capture.blocks[0], Block::Header { level: 1, title } if title.starts_with(info.id)),
+                "{}",
+                info.id
+            );
+            assert!(!capture.blocks.iter().any(|b| match b {
+                Block::Code(c) | Block::Text(c) => c.trim().is_empty(),
+                _ => false,
+            }));
+        }
+
+        // 07장 첫 절: 주석의 C++ 추상 클래스와 트레이트 정의
+        let mut capture = CaptureSink::new();
+        render_lesson(chapters::find("07").unwrap(), &mut capture).unwrap();
+        assert!(capture.blocks.iter().any(|b| matches!(b,
+            Block::Comparison { cpp, rust }
+                if cpp.starts_with("class Summary {") && rust.starts_with("trait Summary {"))));
+    }
+
+    #[test]
+    fn sparkline_and_bar_scale_to_max() {
+        assert_eq!(sparkline(&[0, 50, 100, 150], 100), "▁▄██");
+        assert_eq!(sparkline(&[3], 0), "▁");
+        assert_eq!(bar(1, 2, 6), "███░░░");
+        assert_eq!(bar(0, 0, 3), "░░░");
+        assert_eq!(bar(9, 3, 2), "██");
+    }
+
+    #[test]
+    fn heatmap_puts_days_under_weekdays() {
+        // 2026-10-17 은 토요일 (유닉스 일 수 20743)
+        let today = 20743;
+        let counts = BTreeMap::from([(today, 4), (today - 5, 1), (today - 7, 2)]);
+        let rows = heatmap(&counts, today, 2);
+        assert_eq!(rows.len(), 7);
+        assert_eq!(rows[0], "·░"); // 월 - 이번 주 월요일 1
+        assert_eq!(rows[5], "▒█"); // 토 - 지난주 2, 이번 주 4
+        assert_eq!(rows[6], "· "); // 일 - 이번 주는 아직
+    }
+}
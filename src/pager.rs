@@ -0,0 +1,117 @@
+// ============================================================================
+// 긴 장 출력을 페이저로
+// ============================================================================
+// cargo run -- all               터미널이고 출력이 화면보다 길면 less 로 (Windows 는 more)
+// cargo run -- --no-pager all    페이저 없이 그대로
+// PAGER="less -S" cargo run -- 13   페이저 지정 (git 처럼 PAGER 환경 변수, 빈 값이면 페이저 없이)
+//
+// 장을 실행하는 동안 장 모듈과 main 의 println!(i18n.rs 의 매크로) 출력을 모았다가
+// 끝난 뒤 화면 높이와 비교 - 짧으면 그냥 출력, 길면 페이저의 stdin 으로
+// stdout 이 파이프나 파일이면 모으지 않음 (예전과 같은 출력), stderr 는 그대로 바로 나감
+// ============================================================================
+
+use std::env;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+
+use crate::terminal;
+
+// None = 모으지 않음, Some = 페이저로 보낼 출력
+static BUFFER: Mutex<Option<String>> = Mutex::new(None);
+
+fn buffer() -> std::sync::MutexGuard<'static, Option<String>> {
+    BUFFER.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// 명령줄의 --no-pager 를 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> (bool, Vec<String>) {
+    let rest: Vec<String> = args.iter().filter(|a| *a != "--no-pager").cloned().collect();
+    (rest.len() != args.len(), rest)
+}
+
+// 지금부터의 출력을 모음
+pub fn start() {
+    *buffer() = Some(String::new());
+}
+
+// 모으는 중이면 붙이고 true - i18n 의 println!/print! 가 호출
+pub fn write(text: &str) -> bool {
+    match buffer().as_mut() {
+        Some(text_so_far) => {
+            text_so_far.push_str(text);
+            true
+        }
+        None => false,
+    }
+}
+
+// 화면 높이(rows)에 다 들어가지 않으면 페이저 - 마지막 줄은 프롬프트 자리
+pub fn needs_pager(text: &str, rows: Option<usize>) -> bool {
+    rows.is_some_and(|rows| text.lines().count() >= rows)
+}
+
+// PAGER 환경 변수 값 → 실행할 명령과 인자 (빈 값이면 None = 페이저 없이)
+pub fn command(pager: Option<&str>) -> Option<Vec<String>> {
+    let words: Vec<String> = match pager {
+        Some(value) => value.split_whitespace().map(String::from).collect(),
+        // -R: 색상 이스케이프 그대로, -F: 한 화면이면 바로 끝냄, -X: 끝나도 화면을 지우지 않음
+        None if cfg!(windows) => vec!["more".to_string()],
+        None => ["less", "-RFX"].map(String::from).to_vec(),
+    };
+    (!words.is_empty()).then_some(words)
+}
+
+// 모은 출력을 내보냄 - 페이저를 실행할 수 없으면 그냥 출력
+pub fn finish() {
+    let Some(text) = buffer().take() else {
+        return;
+    };
+    let pager = env::var("PAGER").ok();
+    let paged = needs_pager(&text, terminal::size().1)
+        .then(|| command(pager.as_deref()))
+        .flatten()
+        .is_some_and(|cmd| page(&cmd, &text).is_ok());
+    if !paged {
+        print!("{}", text);
+    }
+}
+
+fn page(cmd: &[String], text: &str) -> io::Result<()> {
+    let (program, args) = cmd.split_first().ok_or(io::ErrorKind::InvalidInput)?;
+    let mut child = Command::new(program).args(args).stdin(Stdio::piped()).spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // 끝까지 보기 전에 q 로 닫으면 BrokenPipe - 정상 종료
+        match stdin.write_all(text.as_bytes()) {
+            Err(e) if e.kind() != io::ErrorKind::BrokenPipe => return Err(e),
+            _ => {}
+        }
+    }
+    child.wait()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_only_output_taller_than_the_screen() {
+        let text = "a\n".repeat(30);
+        assert!(needs_pager(&text, Some(24)));
+        assert!(!needs_pager(&text, Some(40)));
+        // 화면 높이를 모르면(파이프 등) 페이저 없이
+        assert!(!needs_pager(&text, None));
+    }
+
+    #[test]
+    fn chooses_pager_command() {
+        assert_eq!(command(Some("less -S")), Some(vec!["less".to_string(), "-S".to_string()]));
+        assert_eq!(command(Some("  ")), None);
+        let default = command(None).unwrap();
+        assert_eq!(default[0], if cfg!(windows) { "more" } else { "less" });
+
+        let args: Vec<String> = ["--no-pager", "all"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(take_flag(&args), (true, vec!["all".to_string()]));
+    }
+}
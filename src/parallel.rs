@@ -0,0 +1,146 @@
+// ============================================================================
+// 장을 여러 스레드에서 실행 (--parallel)
+// ============================================================================
+// cargo run -- --parallel all        서로 독립인 장은 스레드 풀에서 동시에, 출력은 장 순서대로
+// cargo run -- --parallel 01 07 11   고른 장만 (장:절 은 받지 않음)
+//
+// 13장(동시성)에서 배운 것을 그대로 씀
+//   std::thread::scope  - 작업자 스레드가 장 목록(&[T])을 빌려 씀, 스코프가 끝나기 전에 모두 join
+//   AtomicUsize         - 작업자가 다음 장 번호를 하나씩 가져감 (Mutex 없는 작업 큐)
+//   mpsc::channel       - 끝난 장의 (번호, 출력)을 메인 스레드로
+//   thread_local!       - 스레드마다 출력 버퍼 - i18n::write 가 여기에 먼저 넣어 봄
+// 메인 스레드는 장 순서대로 기다렸다가 모아 둔 출력을 내보냄 → 순차 실행과 같은 출력 순서
+//
+// 스레드를 만들거나(thread::spawn, thread::Builder, tokio) 전역 상태(static mut)를 쓰는 장은 독립이 아님
+//   그 장 안에서 만든 스레드의 출력은 버퍼에 모이지 않으므로 메인 스레드에서 차례가 왔을 때 바로 실행
+// 시간을 재는 장(crate::microbench)도 - 다른 장과 CPU 를 나눠 쓰면 수치가 흔들림
+// panic 은 runner.rs 가 절마다 잡으므로 작업자 스레드가 죽지 않음 (panic 메시지는 stderr 로 바로)
+// ============================================================================
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::i18n;
+
+// 장 소스에 있으면 독립이 아닌 것 - 주석 줄은 보지 않음
+const SHARED: &[&str] = &["thread::spawn", "thread::scope", "thread::Builder", "tokio", "static mut", "crate::microbench"];
+
+thread_local! {
+    // None = 모으지 않음 (메인 스레드), Some = 작업자가 실행 중인 장의 출력
+    static BUFFER: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// 명령줄의 --parallel 을 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> (bool, Vec<String>) {
+    let rest: Vec<String> = args.iter().filter(|a| *a != "--parallel").cloned().collect();
+    (rest.len() != args.len(), rest)
+}
+
+// 이 스레드가 모으는 중이면 붙이고 true - i18n 의 println!/print! 가 호출
+pub fn write(text: &str) -> bool {
+    BUFFER.with(|buffer| match buffer.borrow_mut().as_mut() {
+        Some(text_so_far) => {
+            text_so_far.push_str(text);
+            true
+        }
+        None => false,
+    })
+}
+
+// f 를 실행하는 동안 이 스레드의 출력을 모아서 돌려줌
+fn capture(f: impl FnOnce()) -> String {
+    BUFFER.with(|buffer| *buffer.borrow_mut() = Some(String::new()));
+    f();
+    BUFFER.with(|buffer| buffer.borrow_mut().take().unwrap_or_default())
+}
+
+// 다른 장과 동시에 실행해도 출력이 섞이지 않는 장인지
+pub fn independent(source: &str) -> bool {
+    !source
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .any(|line| SHARED.iter().any(|s| line.contains(s)))
+}
+
+// jobs 를 모두 실행 - independent 인 것은 작업자 스레드에서, 출력과 done 은 jobs 순서대로 메인 스레드에서
+pub fn run<T: Sync>(jobs: &[T], independent: impl Fn(&T) -> bool, run: impl Fn(&T) + Sync, mut done: impl FnMut(&T)) {
+    let queue: Vec<usize> = (0..jobs.len()).filter(|&i| independent(&jobs[i])).collect();
+    let workers = thread::available_parallelism().map_or(1, |n| n.get()).min(queue.len());
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let tx = tx.clone();
+            let (queue, next, run) = (&queue, &next, &run);
+            scope.spawn(move || {
+                while let Some(&i) = queue.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let output = capture(|| run(&jobs[i]));
+                    if tx.send((i, output)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        // 작업자가 모두 끝나면 recv 가 Err - 기다리던 출력이 없어도 멈추지 않음
+        drop(tx);
+
+        let mut finished = HashMap::new();
+        for (i, job) in jobs.iter().enumerate() {
+            if queue.binary_search(&i).is_err() {
+                run(job);
+            } else {
+                while !finished.contains_key(&i) {
+                    let Ok((j, output)) = rx.recv() else {
+                        break;
+                    };
+                    finished.insert(j, output);
+                }
+                if let Some(output) = finished.remove(&i) {
+                    i18n::emit(&output);
+                }
+            }
+            done(job);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn prints_in_order_whatever_finishes_first() {
+        // 먼저 시작한 장이 가장 늦게 끝나도 출력은 순서대로, 독립이 아닌 장(짝수)은 메인 스레드에서
+        let mut done = Vec::new();
+        let output = capture(|| {
+            run(
+                &[30u64, 1, 10, 2, 0],
+                |ms| ms % 2 == 0 && *ms != 2,
+                |ms| {
+                    thread::sleep(Duration::from_millis(*ms));
+                    println!("장 {}", ms);
+                },
+                |ms| done.push(*ms),
+            )
+        });
+        assert_eq!(output, "장 30\n장 1\n장 10\n장 2\n장 0\n");
+        assert_eq!(done, [30, 1, 10, 2, 0]);
+    }
+
+    #[test]
+    fn chapters_with_threads_are_not_independent() {
+        let source = |id| rust_study::chapters::source(id).unwrap();
+        assert!(independent(source("07")));
+        assert!(independent(source("11")), "주석 속 thread::spawn 은 세지 않음");
+        assert!(!independent(source("13")));
+        assert!(!independent(source("17")));
+        assert!(!independent(source("16")));
+        assert!(!independent(source("26")), "측정기는 CPU 를 혼자 써야 함");
+        assert!(!independent(source("34")), "thread::Builder 로 만든 스레드도");
+    }
+}
@@ -0,0 +1,69 @@
+// ============================================================================
+// 먼저 볼 장 확인 (--strict)
+// ============================================================================
+// cargo run -- 12             02, 03, 07장을 완료하지 않았으면 경고만 하고 실행
+// cargo run -- --strict 12    경고 대신 실행하지 않고 종료 코드 1
+//
+// 장마다 먼저 볼 장은 chapters::PREREQUISITES (라이브러리 - 웹에서도 보여 줄 수 있도록)
+// 완료 여부는 진도 파일(progress.json 의 completed - 퀴즈 통과 등)
+// 같은 실행에서 앞에 고른 장은 본 것으로 침 (cargo run -- 02 03 12, --from 05 --to 09)
+// 장을 직접 고를 때만 확인 - all, 메뉴, resume 은 순서대로 가므로 확인하지 않음
+// ============================================================================
+
+use rust_study::chapters;
+
+use crate::i18n;
+
+// 명령줄의 --strict 를 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> (bool, Vec<String>) {
+    let rest: Vec<String> = args.iter().filter(|a| *a != "--strict").cloned().collect();
+    (rest.len() != args.len(), rest)
+}
+
+// (장, 아직 안 본 먼저 볼 장) - selected 는 실행 순서, completed 는 진도 파일의 완료 여부
+pub fn missing(selected: &[&'static str], completed: impl Fn(&str) -> bool) -> Vec<(&'static str, Vec<&'static str>)> {
+    selected
+        .iter()
+        .enumerate()
+        .filter_map(|(i, id)| {
+            let before: Vec<&'static str> = chapters::prerequisites(id)
+                .iter()
+                .copied()
+                .filter(|p| !completed(p) && !selected[..i].contains(p))
+                .collect();
+            (!before.is_empty()).then_some((*id, before))
+        })
+        .collect()
+}
+
+pub fn message(chapter: &str, before: &[&str]) -> String {
+    let names: Vec<String> = before
+        .iter()
+        .map(|id| match chapters::find(id) {
+            Some(info) => format!("{} {}", id, i18n::chapter_name(id, info.title)),
+            None => id.to_string(),
+        })
+        .collect();
+    tr!("{}장 전에 먼저 볼 장을 완료하지 않았습니다: {}", chapter, names.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_prerequisites_not_completed_or_selected_earlier() {
+        let completed = |id: &str| id == "02";
+        assert_eq!(missing(&["12"], completed), [("12", vec!["03", "07"])]);
+        // 같은 실행에서 앞에 고른 장은 본 것
+        assert_eq!(missing(&["03", "07", "12"], completed), [("07", vec!["05"])]);
+        assert!(missing(&["01"], |_| false).is_empty());
+        assert!(message("12", &["03"]).contains("03 빌림"));
+
+        // 표의 장이 모두 있고, 먼저 볼 장은 항상 앞 번호
+        for (id, before) in chapters::PREREQUISITES {
+            assert!(chapters::find(id).is_some());
+            assert!(before.iter().all(|b| chapters::find(b).is_some() && b < id), "{}장", id);
+        }
+    }
+}
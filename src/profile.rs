@@ -0,0 +1,228 @@
+// ============================================================================
+// 학습자 프로필
+// ============================================================================
+// cargo run -- --profile jihoon quiz 07      jihoon 의 진도로 퀴즈 풀기
+// cargo run -- profile [list | create <이름> | delete <이름>]
+//
+// 실습실 컴퓨터나 가족이 함께 쓸 때 사람마다 진도, 퀴즈 기록, 메모, 학습 시간을 따로 저장
+// 기본 프로필은 예전처럼 .rust-study/ 바로 아래, 이름 있는 프로필은 .rust-study/profiles/<이름>/
+// 레슨 팩(lessons/)은 모든 프로필이 함께 씀
+// RUST_STUDY_PROFILE 환경 변수로도 지정 가능 (--profile 이 우선)
+// ============================================================================
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::progress;
+
+pub const DEFAULT: &str = "default";
+
+// --profile 로 고른 프로필 - 명령 하나를 실행하는 동안 바뀌지 않음
+static ACTIVE: OnceLock<Option<String>> = OnceLock::new();
+
+// 명령줄 어디에 있든 --profile <이름> 을 떼어 냄
+pub fn take_flag(args: &[String]) -> Result<(Option<String>, Vec<String>), String> {
+    let mut name = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--profile" {
+            let value = iter.next().ok_or("--profile 뒤에 프로필 이름을 지정하세요")?;
+            name = Some(value.clone());
+        } else if let Some(value) = arg.strip_prefix("--profile=") {
+            name = Some(value.to_string());
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    Ok((name, rest))
+}
+
+// 파일 이름으로 안전한 이름만 - 경로 탈출(../) 방지
+pub fn validate_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && name.len() <= 32
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "잘못된 프로필 이름: '{}' (영문, 숫자, -, _ 로 32자 이내)",
+            name
+        ))
+    }
+}
+
+// 없는 프로필을 고르면 에러 - 오타로 빈 진도를 새로 만들지 않도록
+pub fn select(name: Option<String>) -> Result<(), String> {
+    let name = name.or_else(|| std::env::var("RUST_STUDY_PROFILE").ok());
+    let name = match name {
+        Some(n) if n != DEFAULT => {
+            validate_name(&n)?;
+            if !dir_in(&progress::state_dir(), Some(&n)).is_dir() {
+                return Err(format!(
+                    "없는 프로필: {} (cargo run -- profile create {} 로 만드세요)",
+                    n, n
+                ));
+            }
+            Some(n)
+        }
+        _ => None,
+    };
+    ACTIVE
+        .set(name)
+        .map_err(|_| "프로필은 한 번만 고를 수 있습니다".to_string())
+}
+
+pub fn active() -> Option<&'static str> {
+    ACTIVE.get().and_then(|name| name.as_deref())
+}
+
+fn dir_in(base: &Path, name: Option<&str>) -> PathBuf {
+    match name {
+        Some(name) => base.join("profiles").join(name),
+        None => base.to_path_buf(),
+    }
+}
+
+// 지금 프로필의 개인 데이터 디렉터리 (progress.json, time.jsonl, notes/)
+pub fn dir() -> PathBuf {
+    dir_in(&progress::state_dir(), active())
+}
+
+fn list_in(base: &Path) -> io::Result<Vec<String>> {
+    let mut names = vec![DEFAULT.to_string()];
+    match fs::read_dir(base.join("profiles")) {
+        Ok(entries) => {
+            let mut named: Vec<String> = entries
+                .filter_map(Result::ok)
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| e.file_name().into_string().ok())
+                .collect();
+            named.sort();
+            names.extend(named);
+        }
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        Err(_) => {}
+    }
+    Ok(names)
+}
+
+fn create_in(base: &Path, name: &str) -> Result<PathBuf, String> {
+    validate_name(name)?;
+    if name == DEFAULT {
+        return Err("기본 프로필은 이미 있습니다".to_string());
+    }
+    let dir = dir_in(base, Some(name));
+    if dir.exists() {
+        return Err(format!("이미 있는 프로필: {}", name));
+    }
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn delete_in(base: &Path, name: &str) -> Result<(), String> {
+    validate_name(name)?;
+    if name == DEFAULT {
+        return Err("기본 프로필은 삭제할 수 없습니다".to_string());
+    }
+    let dir = dir_in(base, Some(name));
+    if !dir.is_dir() {
+        return Err(format!("없는 프로필: {}", name));
+    }
+    fs::remove_dir_all(dir).map_err(|e| e.to_string())
+}
+
+pub fn list() -> io::Result<Vec<String>> {
+    list_in(&progress::state_dir())
+}
+
+pub fn create(name: &str) -> Result<PathBuf, String> {
+    create_in(&progress::state_dir(), name)
+}
+
+pub fn delete(name: &str) -> Result<(), String> {
+    if active() == Some(name) {
+        return Err(format!("지금 쓰는 프로필은 삭제할 수 없습니다: {}", name));
+    }
+    delete_in(&progress::state_dir(), name)
+}
+
+// ----------------------------------------------------------------------------
+// 메모 - 장마다 notes/<장>.md 에 한 줄씩 추가
+// ----------------------------------------------------------------------------
+
+fn notes_path(chapter: &str) -> PathBuf {
+    dir().join("notes").join(format!("{}.md", chapter))
+}
+
+pub fn read_notes(chapter: &str) -> io::Result<String> {
+    match fs::read_to_string(notes_path(chapter)) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        other => other,
+    }
+}
+
+pub fn add_note(chapter: &str, line: &str) -> io::Result<()> {
+    let path = notes_path(chapter);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "- {}", line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn profile_flag_is_taken_from_anywhere() {
+        let (name, rest) = take_flag(&args(&["--profile", "jihoon", "quiz", "07"])).unwrap();
+        assert_eq!(name.as_deref(), Some("jihoon"));
+        assert_eq!(rest, args(&["quiz", "07"]));
+
+        let (name, rest) = take_flag(&args(&["stats", "--profile=mina"])).unwrap();
+        assert_eq!(name.as_deref(), Some("mina"));
+        assert_eq!(rest, args(&["stats"]));
+
+        assert_eq!(take_flag(&args(&["quiz"])).unwrap().0, None);
+        assert!(take_flag(&args(&["quiz", "--profile"])).is_err());
+    }
+
+    #[test]
+    fn names_cannot_escape_the_state_dir() {
+        assert!(validate_name("jihoon").is_ok());
+        assert!(validate_name("lab-2_pc").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("../other").is_err());
+        assert!(validate_name("지훈").is_err());
+    }
+
+    #[test]
+    fn create_list_delete() {
+        let base = std::env::temp_dir().join(format!("profile-test-{}", std::process::id()));
+        assert_eq!(list_in(&base).unwrap(), vec![DEFAULT]);
+
+        let dir = create_in(&base, "mina").unwrap();
+        assert_eq!(dir, base.join("profiles").join("mina"));
+        create_in(&base, "jihoon").unwrap();
+        assert!(create_in(&base, "mina").is_err());
+        assert!(create_in(&base, DEFAULT).is_err());
+        assert_eq!(list_in(&base).unwrap(), vec![DEFAULT, "jihoon", "mina"]);
+
+        delete_in(&base, "mina").unwrap();
+        assert!(delete_in(&base, "mina").is_err());
+        assert!(delete_in(&base, DEFAULT).is_err());
+        assert_eq!(list_in(&base).unwrap(), vec![DEFAULT, "jihoon"]);
+        let _ = fs::remove_dir_all(base);
+    }
+}
@@ -0,0 +1,263 @@
+// ============================================================================
+// 학습 진도 저장소
+// ============================================================================
+// 완료한 장과 퀴즈 최고 점수를 .rust-study/progress.json 에 저장 (프로필마다 따로 - profile.rs)
+// export/import 로 다른 컴퓨터와 동기화 - 병합 규칙:
+//   - 완료한 장: 합집합 (먼저 완료한 시각, 더 새 콘텐츠 버전 유지)
+//   - 퀴즈 점수: 더 좋은 점수 유지
+//   - 퀴즈 기록: 합집합 (같은 시각의 같은 점수는 하나로) - stats 의 점수 추이
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// BTreeMap - 장 번호 순으로 직렬화되어 파일 diff가 안정적
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Progress {
+    #[serde(default)]
+    pub completed: BTreeMap<String, Completion>,
+    #[serde(default)]
+    pub quiz_best: BTreeMap<String, QuizScore>,
+    // 장별 모든 퀴즈 시도 - 시각 순
+    #[serde(default)]
+    pub quiz_history: BTreeMap<String, Vec<QuizScore>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Completion {
+    // 유닉스 시각 (초)
+    pub at: u64,
+    // 완료할 때의 콘텐츠 버전 - 이후 장이 갱신되었는지 비교 (content::ChapterContent::version)
+    #[serde(default = "first_version")]
+    pub version: u32,
+}
+
+fn first_version() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuizScore {
+    pub correct: usize,
+    pub total: usize,
+    pub at: u64,
+}
+
+impl QuizScore {
+    // 비율로 비교 - 문제 수가 바뀌어도 공정하게
+    // 정수 교차 곱으로 부동소수점 비교를 피함
+    // 완전히 같은 점수면 먼저 얻은 쪽 - 병합 결과가 순서와 무관해지도록
+    pub fn is_better_than(&self, other: &QuizScore) -> bool {
+        let lhs = self.correct * other.total.max(1);
+        let rhs = other.correct * self.total.max(1);
+        (lhs, self.correct, std::cmp::Reverse(self.at))
+            > (rhs, other.correct, std::cmp::Reverse(other.at))
+    }
+
+    pub fn percent(&self) -> usize {
+        (self.correct * 100).checked_div(self.total).unwrap_or(0)
+    }
+}
+
+// 퀴즈를 이 비율 이상 맞히면 장을 완료한 것으로 기록
+pub const PASS_PERCENT: usize = 70;
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+impl Progress {
+    pub fn mark_completed(&mut self, chapter: &str, at: u64, version: u32) {
+        self.completed
+            .entry(chapter.to_string())
+            .and_modify(|c| {
+                c.at = c.at.min(at);
+                c.version = c.version.max(version);
+            })
+            .or_insert(Completion { at, version });
+    }
+
+    // 완료한 뒤에 콘텐츠가 새 버전으로 바뀌었는지 - 완료하지 않은 장은 false
+    pub fn updated_since_completion(&self, chapter: &str, current: u32) -> bool {
+        self.completed
+            .get(chapter)
+            .is_some_and(|c| c.version < current)
+    }
+
+    // 새 점수가 더 좋으면 기록하고 true 반환
+    // 통과하면 version(현재 콘텐츠 버전)까지 본 것으로 기록
+    pub fn record_quiz(&mut self, chapter: &str, score: QuizScore, version: u32) -> bool {
+        let improved = match self.quiz_best.get(chapter) {
+            Some(best) => score.is_better_than(best),
+            None => true,
+        };
+        if improved {
+            self.quiz_best.insert(chapter.to_string(), score);
+        }
+        self.add_history(chapter, &[score]);
+        if score.percent() >= PASS_PERCENT {
+            self.mark_completed(chapter, score.at, version);
+        }
+        improved
+    }
+
+    fn add_history(&mut self, chapter: &str, scores: &[QuizScore]) {
+        let history = self.quiz_history.entry(chapter.to_string()).or_default();
+        history.extend_from_slice(scores);
+        history.sort_by_key(|s| (s.at, s.correct, s.total));
+        history.dedup();
+    }
+
+    // 다른 컴퓨터의 진도를 합침 - 순서와 상관없이 같은 결과 (교환 법칙)
+    pub fn merge(&mut self, other: &Progress) {
+        for (chapter, c) in &other.completed {
+            self.mark_completed(chapter, c.at, c.version);
+        }
+        for (chapter, score) in &other.quiz_best {
+            match self.quiz_best.get(chapter) {
+                Some(best) if !score.is_better_than(best) => {}
+                _ => {
+                    self.quiz_best.insert(chapter.clone(), *score);
+                }
+            }
+        }
+        for (chapter, scores) in &other.quiz_history {
+            self.add_history(chapter, scores);
+        }
+    }
+
+    // 파일이 없으면 빈 진도
+    pub fn load(path: &Path) -> io::Result<Progress> {
+        match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    // 임시 파일에 쓴 뒤 rename - 쓰는 도중 종료돼도 기존 파일이 깨지지 않음
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, path)
+    }
+}
+
+// 학습 도구의 상태 디렉터리: RUST_STUDY_HOME 또는 ./.rust-study
+pub fn state_dir() -> PathBuf {
+    match std::env::var_os("RUST_STUDY_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(".rust-study"),
+    }
+}
+
+// 지금 프로필의 진도 (profile.rs)
+pub fn default_path() -> PathBuf {
+    crate::profile::dir().join("progress.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(correct: usize, total: usize, at: u64) -> QuizScore {
+        QuizScore { correct, total, at }
+    }
+
+    #[test]
+    fn better_score_compares_ratio() {
+        assert!(score(3, 3, 0).is_better_than(&score(2, 3, 0)));
+        assert!(score(2, 2, 0).is_better_than(&score(2, 3, 0)));
+        assert!(!score(1, 3, 0).is_better_than(&score(2, 4, 0)));
+        // 같은 비율이면 더 많이 맞힌 쪽
+        assert!(score(4, 6, 0).is_better_than(&score(2, 3, 0)));
+    }
+
+    #[test]
+    fn record_quiz_keeps_best_and_marks_completion() {
+        let mut p = Progress::default();
+        assert!(p.record_quiz("07", score(1, 3, 10), 1));
+        assert!(!p.completed.contains_key("07"));
+        assert!(p.record_quiz("07", score(3, 3, 20), 1));
+        assert!(!p.record_quiz("07", score(2, 3, 30), 1));
+        assert_eq!(p.quiz_best["07"], score(3, 3, 20));
+        assert_eq!(p.completed["07"].at, 20);
+        // 최고 점수가 아니어도 시도는 모두 기록
+        assert_eq!(p.quiz_history["07"].len(), 3);
+    }
+
+    #[test]
+    fn merge_unions_completion_and_keeps_best_scores() {
+        let mut home = Progress::default();
+        home.mark_completed("02", 100, 2);
+        home.record_quiz("07", score(2, 3, 100), 1);
+
+        let mut work = Progress::default();
+        work.mark_completed("02", 50, 1);
+        work.mark_completed("11", 60, 1);
+        work.record_quiz("07", score(3, 3, 70), 1);
+        work.record_quiz("09", score(1, 3, 80), 1);
+
+        let mut merged = home.clone();
+        merged.merge(&work);
+
+        assert_eq!(merged.completed["02"].at, 50); // 먼저 완료한 시각
+        assert_eq!(merged.completed["02"].version, 2); // 더 새 버전
+        assert!(merged.completed.contains_key("11"));
+        assert_eq!(merged.quiz_best["07"], score(3, 3, 70));
+        assert_eq!(merged.quiz_best["09"], score(1, 3, 80));
+        assert_eq!(merged.quiz_history["07"], vec![score(3, 3, 70), score(2, 3, 100)]);
+
+        // 반대 방향으로 합쳐도 같은 결과
+        let mut reverse = work.clone();
+        reverse.merge(&home);
+        assert_eq!(merged, reverse);
+    }
+
+    #[test]
+    fn content_update_after_completion_is_flagged() {
+        let mut p = Progress::default();
+        p.record_quiz("17", score(3, 3, 10), 1);
+        assert!(p.updated_since_completion("17", 2));
+        assert!(!p.updated_since_completion("17", 1));
+        assert!(!p.updated_since_completion("16", 2));
+
+        // 새 버전으로 다시 통과하면 표시가 사라짐
+        p.record_quiz("17", score(3, 3, 20), 2);
+        assert!(!p.updated_since_completion("17", 2));
+        assert_eq!(p.completed["17"].at, 10);
+    }
+
+    #[test]
+    fn old_progress_files_default_to_version_one() {
+        let p: Progress = serde_json::from_str(r#"{"completed": {"02": {"at": 5}}}"#).unwrap();
+        assert_eq!(p.completed["02"], Completion { at: 5, version: 1 });
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = std::env::temp_dir().join(format!("progress-test-{}", std::process::id()));
+        let path = dir.join("nested").join("progress.json");
+
+        assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+
+        let mut p = Progress::default();
+        p.record_quiz("05", score(3, 3, 1), 1);
+        p.save(&path).unwrap();
+        assert_eq!(Progress::load(&path).unwrap(), p);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,155 @@
+// ============================================================================
+// 퀴즈 실행기
+// ============================================================================
+// content/의 문제를 하나씩 묻고 점수를 계산
+// 입출력을 BufRead/Write로 받아서 테스트에서는 메모리 버퍼로 대체 가능
+// ============================================================================
+
+use std::io::{self, BufRead, Write};
+
+use crate::content::{ChapterContent, Difficulty, Question};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuizResult {
+    pub correct: usize,
+    pub total: usize,
+}
+
+pub fn run<R: BufRead, W: Write>(
+    chapter: &ChapterContent,
+    input: &mut R,
+    out: &mut W,
+) -> io::Result<QuizResult> {
+    let total = chapter.questions.len();
+    let mut correct = 0;
+
+    for (n, q) in chapter.questions.iter().enumerate() {
+        let label = format!("{}/{}", n + 1, total);
+        let Some(picked) = ask(q, &label, true, input, out)? else {
+            break;
+        };
+
+        if picked == q.answer {
+            correct += 1;
+            writeln!(out, "정답!")?;
+        } else {
+            writeln!(out, "오답 - 정답: {}) {}", q.answer + 1, q.choices[q.answer])?;
+        }
+        if let Some(explanation) = &q.explanation {
+            writeln!(out, "  {}", explanation)?;
+        }
+    }
+
+    writeln!(out, "\n점수: {}/{}", correct, total)?;
+
+    if !chapter.exercises.is_empty() {
+        writeln!(out, "\n이어서 해볼 연습문제:")?;
+        for e in &chapter.exercises {
+            let level = match e.difficulty {
+                Difficulty::Easy => "쉬움",
+                Difficulty::Medium => "보통",
+                Difficulty::Hard => "어려움",
+            };
+            writeln!(out, "  - [{}] {} - {}", level, e.title, e.description)?;
+        }
+    }
+
+    Ok(QuizResult { correct, total })
+}
+
+// 문제 하나를 보여주고 고른 보기의 인덱스를 반환 - q 또는 EOF 면 None
+// 번호가 아닌 입력은 다시 물음 (interview 모드도 같은 방식으로 물음)
+pub fn ask<R: BufRead, W: Write>(
+    q: &Question,
+    label: &str,
+    allow_hint: bool,
+    input: &mut R,
+    out: &mut W,
+) -> io::Result<Option<usize>> {
+    writeln!(out, "\n[{}] {}", label, q.prompt)?;
+    for (i, choice) in q.choices.iter().enumerate() {
+        writeln!(out, "  {}) {}", i + 1, choice)?;
+    }
+
+    loop {
+        if allow_hint {
+            write!(out, "답 (번호, h=힌트, q=종료): ")?;
+        } else {
+            write!(out, "답 (번호, q=종료): ")?;
+        }
+        out.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None); // 입력 끝 (EOF)
+        }
+        match line.trim() {
+            "q" => return Ok(None),
+            "h" if allow_hint => match &q.hint {
+                Some(hint) => writeln!(out, "힌트: {}", hint)?,
+                None => writeln!(out, "이 문제는 힌트가 없습니다")?,
+            },
+            s => match s.parse::<usize>() {
+                Ok(k) if (1..=q.choices.len()).contains(&k) => return Ok(Some(k - 1)),
+                _ => writeln!(out, "1부터 {} 사이의 번호를 입력하세요", q.choices.len())?,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> ChapterContent {
+        toml::from_str(
+            r#"
+            chapter = "02"
+
+            [[questions]]
+            id = "02-a"
+            prompt = "첫 번째"
+            choices = ["x", "y"]
+            answer = 1
+            hint = "y를 고르세요"
+
+            [[questions]]
+            id = "02-b"
+            prompt = "두 번째"
+            choices = ["x", "y", "z"]
+            answer = 0
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn play(answers: &str) -> (QuizResult, String) {
+        let mut input = answers.as_bytes();
+        let mut out = Vec::new();
+        let result = run(&sample(), &mut input, &mut out).unwrap();
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn all_correct() {
+        let (result, _) = play("2\n1\n");
+        assert_eq!(result, QuizResult { correct: 2, total: 2 });
+    }
+
+    #[test]
+    fn hint_and_invalid_input_reprompt() {
+        let (result, out) = play("h\n9\nabc\n2\n3\n");
+        assert_eq!(result.correct, 1);
+        assert!(out.contains("힌트: y를 고르세요"));
+        assert!(out.contains("1부터 2 사이의 번호"));
+        assert!(out.contains("오답 - 정답: 1) x"));
+    }
+
+    #[test]
+    fn quit_and_eof_stop_early() {
+        let (result, _) = play("2\nq\n");
+        assert_eq!(result, QuizResult { correct: 1, total: 2 });
+        let (result, _) = play("");
+        assert_eq!(result.correct, 0);
+    }
+}
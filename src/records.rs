@@ -0,0 +1,173 @@
+// ============================================================================
+// 기계가 읽는 실행 결과 (--format json)
+// ============================================================================
+// cargo run -- --format json 07            절마다 JSON 한 줄 (JSON Lines)
+// cargo run -- --format json all | jq .    모든 장
+// cargo run -- --format json 17:select_example
+//
+// {"chapter":"07","section":"trait_objects","start":1760000000,"duration_ms":0.4,"status":"ok","output":["..."]}
+//   start       시작 시각 (유닉스 초)
+//   status      "ok" 또는 "panic" (panic 이면 message 에 내용, location 에 panic 한 파일:줄)
+//   output      절이 출력한 줄 - 장 모듈의 println!/print! (i18n.rs 의 매크로)를 모아 둔 것
+//
+// 자유 형식 출력 대신 이 줄들만 stdout 으로 나감 (panic 메시지 등 stderr 는 그대로)
+// 절 하나가 panic 해도 나머지 절을 계속 실행하고, 하나라도 panic 이면 종료 코드 1
+// --skip / --only 로 거른 절은 줄을 내보내지 않음
+// ============================================================================
+
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::chapter::Chapter;
+use crate::{progress, runner};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Panic,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub chapter: &'static str,
+    pub section: &'static str,
+    pub start: u64,
+    pub duration_ms: f64,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    pub output: Vec<String>,
+}
+
+// 명령줄의 --format <형식> 을 빼고 나머지 인자를 돌려줌 (형식 확인은 parse)
+// render, bench-report 도 --format 을 쓰므로 main 은 장을 실행할 때만 이 결과를 씀
+pub fn take_flag(args: &[String]) -> (Option<String>, Vec<String>) {
+    let mut format = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--format" {
+            format = Some(iter.next().cloned().unwrap_or_default());
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    (format, rest)
+}
+
+// "json" → true, "text" → false (기본 출력)
+pub fn parse(format: &str) -> Result<bool, String> {
+    match format {
+        "json" => Ok(true),
+        "text" => Ok(false),
+        other => Err(format!("지원하지 않는 출력 형식: {} (text, json)", other)),
+    }
+}
+
+// None = 모으지 않음 (stdout 으로), Some = 지금 실행 중인 절의 출력
+// 절 안에서 만든 스레드(13장)와 tokio 작업(17장)의 출력도 같은 곳에 모임
+static CAPTURE: Mutex<Option<String>> = Mutex::new(None);
+
+fn capture() -> std::sync::MutexGuard<'static, Option<String>> {
+    CAPTURE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// 모으는 중이면 붙이고 true - i18n 의 println!/print! 가 호출
+pub fn write(text: &str) -> bool {
+    match capture().as_mut() {
+        Some(buffer) => {
+            buffer.push_str(text);
+            true
+        }
+        None => false,
+    }
+}
+
+// 절 하나를 출력을 모으며 실행
+pub fn run_section(chapter: &'static str, section: &'static str, f: fn()) -> Record {
+    *capture() = Some(String::new());
+    let start = progress::now();
+    let started = Instant::now();
+    let result = runner::catch(f);
+    let duration_ms = started.elapsed().as_secs_f64() * 1000.0;
+    let output = capture().take().unwrap_or_default();
+    let (status, message, location) = match result {
+        Ok(()) => (Status::Ok, None, None),
+        Err(panicked) => (Status::Panic, Some(panicked.message), panicked.location),
+    };
+    Record {
+        chapter,
+        section,
+        start,
+        duration_ms,
+        status,
+        message,
+        location,
+        output: output.lines().map(String::from).collect(),
+    }
+}
+
+// 한 줄 출력 - panic 이 없었으면 true
+pub fn emit(record: &Record) -> bool {
+    // Record 는 문자열과 수로만 이루어져 직렬화가 실패하지 않음
+    std::println!("{}", serde_json::to_string(record).expect("Record 직렬화"));
+    record.status == Status::Ok
+}
+
+// 장의 (--skip / --only 를 통과한) 모든 절 - panic 이 없었으면 true
+pub fn run_chapter(chapter: &dyn Chapter) -> bool {
+    // 하나가 panic 해도 끝까지 실행
+    let panicked = runner::selected(chapter)
+        .into_iter()
+        .filter(|(name, f)| !emit(&run_section(chapter.id(), name, *f)))
+        .count();
+    panicked == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prints() {
+        assert!(write("첫 줄\n"));
+        assert!(write("둘째 줄"));
+    }
+
+    fn panics() {
+        panic!("일부러 panic");
+    }
+
+    #[test]
+    fn takes_format_flag() {
+        let args: Vec<String> = ["--format", "json", "07"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(take_flag(&args), (Some("json".to_string()), vec!["07".to_string()]));
+        assert_eq!(parse("json"), Ok(true));
+        assert_eq!(parse("text"), Ok(false));
+        assert!(parse("xml").is_err());
+    }
+
+    // 출력을 모으는 상태가 전역이라 한 테스트에서 차례로 확인
+    #[test]
+    fn records_output_duration_and_panics() {
+        let ok = run_section("99", "prints", prints);
+        assert_eq!(ok.status, Status::Ok);
+        assert_eq!(ok.output, ["첫 줄", "둘째 줄"]);
+        assert!(ok.duration_ms >= 0.0);
+        assert!(!write("절 밖에서는 모으지 않음"));
+
+        let failed = run_section("99", "panics", panics);
+        assert_eq!(failed.status, Status::Panic);
+        assert_eq!(failed.message.as_deref(), Some("일부러 panic"));
+        assert!(failed.location.as_deref().is_some_and(|l| l.contains("src/records.rs:")));
+
+        let json: serde_json::Value = serde_json::to_value(&failed).unwrap();
+        assert_eq!(json["status"], "panic");
+        assert_eq!(json["section"], "panics");
+        assert!(serde_json::to_value(&ok).unwrap().get("message").is_none());
+        assert!(serde_json::to_value(&ok).unwrap().get("location").is_none());
+    }
+}
@@ -0,0 +1,170 @@
+// ============================================================================
+// 이어서 학습하기
+// ============================================================================
+// cargo run -- 07                   장을 실행하면 .rust-study/state.toml 에 마지막 장으로 기록
+// cargo run -- 07:trait_objects     절을 실행하면 마지막 절로 기록
+// cargo run -- resume               마지막 다음 것부터 - 장이었으면 다음 장, 절이었으면 다음 절
+// cargo run -- resume --show        실행하지 않고 다음에 할 것만 보기
+//
+// 최근에 실행한 장/절 목록(recent)도 함께 저장 - drill 이 최근에 본 절을 덜 고르는 데 씀
+//
+// 며칠에 걸쳐 차례로 공부할 때 어디까지 했는지 기억하지 않아도 되도록
+// 프로필마다 따로 저장 (profile.rs), 전체 실행(cargo run -- all)과 --format json 은 기록하지 않음
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::chapter::Chapter;
+use crate::{profile, progress};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct State {
+    pub chapter: String,
+    // None = 장 전체를 실행함
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    // 유닉스 시각 (초)
+    pub at: u64,
+    // 최근에 실행한 것의 target() - 오래된 것부터, 최대 RECENT 개
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent: Vec<String>,
+}
+
+// recent 에 남길 개수
+pub const RECENT: usize = 20;
+
+impl State {
+    // 이전 최근 목록 뒤에 이번 실행을 붙인 새 상태
+    pub fn after(mut recent: Vec<String>, chapter: &str, section: Option<&str>, at: u64) -> State {
+        let mut state = State {
+            chapter: chapter.to_string(),
+            section: section.map(str::to_string),
+            at,
+            recent: Vec::new(),
+        };
+        let target = state.target();
+        recent.retain(|t| *t != target);
+        recent.push(target);
+        let excess = recent.len().saturating_sub(RECENT);
+        recent.drain(..excess);
+        state.recent = recent;
+        state
+    }
+
+    // "07" 또는 "07:trait_objects" - 그대로 cargo run -- 에 넘길 수 있는 형태
+    pub fn target(&self) -> String {
+        match &self.section {
+            Some(section) => format!("{}:{}", self.chapter, section),
+            None => self.chapter.clone(),
+        }
+    }
+}
+
+pub fn default_path() -> PathBuf {
+    profile::dir().join("state.toml")
+}
+
+// 파일이 없으면 None - 아직 실행한 장이 없음
+pub fn load(path: &Path) -> io::Result<Option<State>> {
+    match fs::read_to_string(path) {
+        Ok(text) => toml::from_str(&text)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e))),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn save(path: &Path, state: &State) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let text = toml::to_string(state).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    fs::write(path, text)
+}
+
+// 장이나 절을 실행한 뒤 main 이 호출 - 기록 실패로 학습을 방해하지 않음 (경고만)
+pub fn remember(chapter: &str, section: Option<&str>) {
+    let path = default_path();
+    // 깨진 파일이면 최근 목록은 새로 시작
+    let recent = load(&path).ok().flatten().map(|s| s.recent).unwrap_or_default();
+    let state = State::after(recent, chapter, section, progress::now());
+    if let Err(e) = save(&path, &state) {
+        eprintln!("마지막 실행 위치 기록 실패: {}", e);
+    }
+}
+
+// 다음에 실행할 (장 번호, 절 이름) - 기록이 없으면 첫 장, 마지막 장까지 마쳤으면 None
+// 절 단위로 하던 사람은 장이 끝나면 다음 장의 첫 절로
+pub fn next(chapters: &[Box<dyn Chapter>], state: Option<&State>) -> Option<(&'static str, Option<&'static str>)> {
+    let Some(state) = state else {
+        return chapters.first().map(|c| (c.id(), None));
+    };
+    let index = chapters.iter().position(|c| c.id() == state.chapter)?;
+    let following = chapters.get(index + 1);
+    let Some(section) = &state.section else {
+        return following.map(|c| (c.id(), None));
+    };
+    let sections = chapters[index].sections();
+    let next_section = sections
+        .iter()
+        .position(|(name, _)| name == section)
+        .and_then(|i| sections.get(i + 1));
+    match next_section {
+        Some((name, _)) => Some((chapters[index].id(), Some(name))),
+        None => following.and_then(|c| Some((c.id(), Some(c.sections().first()?.0)))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(chapter: &str, section: Option<&str>) -> State {
+        State::after(Vec::new(), chapter, section, 1_700_000_000)
+    }
+
+    #[test]
+    fn saves_and_loads_state() {
+        let path = std::env::temp_dir().join(format!("resume-test-{}", std::process::id())).join("state.toml");
+        assert_eq!(load(&path).unwrap(), None);
+        let saved = state("07", Some("trait_objects"));
+        save(&path, &saved).unwrap();
+        assert_eq!(load(&path).unwrap(), Some(saved));
+        assert!(fs::read_to_string(&path).unwrap().contains("section = \"trait_objects\""));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn continues_from_next_chapter_or_section() {
+        let chapters = crate::all_chapters();
+        assert_eq!(next(&chapters, None), Some(("01", None)));
+        assert_eq!(next(&chapters, Some(&state("07", None))), Some(("08", None)));
+        let final_chapter = chapters.last().unwrap().id();
+        assert_eq!(next(&chapters, Some(&state(final_chapter, None))), None);
+
+        let traits = chapters.iter().find(|c| c.id() == "07").unwrap().sections();
+        assert_eq!(next(&chapters, Some(&state("07", Some(traits[0].0)))), Some(("07", Some(traits[1].0))));
+        // 장의 마지막 절 다음은 다음 장의 첫 절
+        let last = traits.last().unwrap().0;
+        let generics = chapters.iter().find(|c| c.id() == "08").unwrap().sections();
+        assert_eq!(next(&chapters, Some(&state("07", Some(last)))), Some(("08", Some(generics[0].0))));
+        assert_eq!(state("07", Some("trait_objects")).target(), "07:trait_objects");
+    }
+
+    #[test]
+    fn keeps_recent_runs_without_duplicates() {
+        let first = state("07", None);
+        assert_eq!(first.recent, ["07"]);
+        let second = State::after(first.recent, "08", Some("generic_functions"), 0);
+        let third = State::after(second.recent, "07", None, 0);
+        assert_eq!(third.recent, ["08:generic_functions", "07"]);
+
+        let many = (0..RECENT + 5).fold(Vec::new(), |recent, i| State::after(recent, &format!("{:02}", i), None, 0).recent);
+        assert_eq!(many.len(), RECENT);
+        assert_eq!(many.last().map(String::as_str), Some("24"));
+    }
+}
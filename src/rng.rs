@@ -0,0 +1,66 @@
+// ============================================================================
+// 학습 도구의 무작위 (--seed 로 재현)
+// ============================================================================
+// cargo run -- --seed 42 drill        매번 같은 절
+// cargo run -- --seed 42 interview    매번 같은 문제 세트
+//
+// 시드가 없으면 현재 시각 - 실행할 때마다 다름
+// 도구마다 이름으로 수열을 나눔 → 한 도구가 수를 더 뽑아도 다른 도구의 결과는 그대로
+// 장의 예제 데이터는 demo_data.rs (같은 splitmix64 - 예제에 그대로 들어가므로 따로 둠)
+// 시드로도 정해지지 않는 것: 스레드 실행 순서(13장 출력의 섞임), HashMap 순회 순서, 실행 시간
+// ============================================================================
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::demo_data;
+
+// splitmix64 - 작고 빠르며 시드가 같으면 항상 같은 수열
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+// --seed (main 이 환경 변수로도 넘김) 또는 RUST_STUDY_SEED - 없으면 None
+pub fn seed() -> Option<u64> {
+    std::env::var(demo_data::SEED_VAR).ok().and_then(|v| v.trim().parse().ok())
+}
+
+// 도구 하나의 수열 - 시드가 같고 이름이 같으면 같은 수열
+pub fn for_tool(name: &str) -> Rng {
+    let seed = seed().unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0)
+    });
+    Rng::new(name.bytes().fold(seed, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01B3)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let take = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..4).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(take(42), take(42));
+        assert_ne!(take(42), take(43));
+    }
+}
@@ -0,0 +1,243 @@
+// ============================================================================
+// 장 실행 - 절마다 panic 을 격리
+// ============================================================================
+// cargo run -- 12       절 하나가 panic 해도(예: 12장 RefCell 이중 빌림 예제의 주석을 풀었을 때) 다음 절로 계속
+// cargo run -- all      끝에 통과/실패 요약 - 실패한 절이 있으면 종료 코드 1 (CI 에서 바로 보임)
+//
+// 장 머리("=== 07. 트레이트 (Traits) ===")를 찍고 장의 SECTIONS 절을 하나씩 catch_unwind 로 감싸 실행
+// panic 메시지는 표준 panic 훅이 stderr 에 그대로 찍고, 여기서는 어느 절인지와 요약만
+// 표준 훅을 감싼 훅(panic_hook.rs)이 panic 한 위치(파일:줄)를 스레드마다 기록 - 요약에 함께 (48장의 set_hook)
+// 절마다 잰 시간과 할당은 timing, allocations 에 넘김 (--timing, --profile-alloc 일 때만 기록)
+// --step 이면 절 사이에서 Enter 를 기다림 (step.rs), q 로 그만두면 남은 절과 장은 실행하지 않음
+// --skip / --only, --level 로 거른 절은 실행하지 않고 요약에 건너뛴 수로 (절 하나를 직접 고른 07:xxx 는 그대로 실행)
+// ============================================================================
+
+use std::collections::HashMap;
+use std::panic;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use rust_study::capabilities::{self, Capability, Filter};
+use rust_study::levels::{self, Level};
+use rust_study::{chapters, sections};
+
+use crate::chapter::Chapter;
+use crate::allocations::{self, Counts};
+use crate::{i18n, panic_hook, step, timing};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub chapter: &'static str,
+    pub section: &'static str,
+    pub message: String,
+    pub location: Option<String>,
+}
+
+#[derive(Debug)]
+struct Results {
+    passed: usize,
+    failures: Vec<Failure>,
+    skipped: usize,
+}
+
+static RESULTS: Mutex<Results> = Mutex::new(Results {
+    passed: 0,
+    failures: Vec::new(),
+    skipped: 0,
+});
+
+fn results() -> std::sync::MutexGuard<'static, Results> {
+    RESULTS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+static FILTER: Mutex<Filter> = Mutex::new(Filter {
+    skip: Vec::new(),
+    only: Vec::new(),
+});
+
+fn filter() -> std::sync::MutexGuard<'static, Filter> {
+    FILTER.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn set_filter(new: Filter) {
+    *filter() = new;
+}
+
+// --level 로 고른 난이도 - 비어 있으면 모든 난이도
+static LEVELS: Mutex<Vec<Level>> = Mutex::new(Vec::new());
+
+fn chosen_levels() -> std::sync::MutexGuard<'static, Vec<Level>> {
+    LEVELS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn set_levels(new: Vec<Level>) {
+    *chosen_levels() = new;
+}
+
+// 장의 절 중 --skip / --only, --level 을 통과한 것 (실행 순서) - 건너뛴 절은 요약에 셈
+pub fn selected(chapter: &dyn Chapter) -> Vec<(&'static str, fn())> {
+    let filter = filter().clone();
+    let chosen = chosen_levels().clone();
+    if filter.is_empty() && chosen.is_empty() {
+        return chapter.sections().to_vec();
+    }
+    // 기능은 절의 스니펫을 만들어 찾으므로 --skip / --only 가 있을 때만
+    let needs: HashMap<String, Vec<Capability>> = if filter.is_empty() {
+        HashMap::new()
+    } else {
+        chapters::find(chapter.id())
+            .map(sections::sections)
+            .unwrap_or_default()
+            .iter()
+            .map(|s| (s.name.clone(), capabilities::of(s)))
+            .collect()
+    };
+    let (run, skipped): (Vec<_>, Vec<_>) = chapter.sections().iter().partition(|(name, _)| {
+        filter.check(needs.get(*name).map_or(&[], Vec::as_slice)).is_ok()
+            && (chosen.is_empty() || chosen.contains(&levels::of(chapter.id(), name)))
+    });
+    results().skipped += skipped.len();
+    run
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panicked {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+// 절 하나를 catch_unwind 로 격리 - panic 이면 메시지와 위치 (records.rs 의 --format json 도 사용)
+// 절 안에서 잡힌 panic(43장의 퍼저 등)도 위치를 남기지만, 빠져나온 panic 이 마지막에 기록되므로 그 위치가 남음
+// panic = "abort" 로 빌드했거나 Drop 안에서 두 번째 panic 이 나면 여기까지 오지 못하고 프로세스가 끝남
+pub fn catch(f: fn()) -> Result<(), Panicked> {
+    panic_hook::install();
+    panic_hook::take_last();
+    panic::catch_unwind(f).map_err(|payload| Panicked {
+        message: panic_hook::message(payload.as_ref()),
+        location: panic_hook::take_last().map(|seen| format!("{}:{}", seen.file, seen.line)),
+    })
+}
+
+// 절 하나 - panic 이면 기록하고 계속, 걸린 시간과 할당을 돌려줌
+pub fn run_section(chapter: &'static str, section: &'static str, f: fn()) -> (Duration, Counts) {
+    let before = allocations::snapshot();
+    let start = Instant::now();
+    let result = catch(f);
+    let elapsed = start.elapsed();
+    let used = allocations::snapshot() - before;
+    match result {
+        Ok(()) => results().passed += 1,
+        Err(Panicked { message, location }) => {
+            eprintln!("{}", tr!("\n!!! {}::{} 에서 panic - 다음 절로 계속합니다\n", chapter, section));
+            results().failures.push(Failure { chapter, section, message, location });
+        }
+    }
+    (elapsed, used)
+}
+
+// 장 전체 - 머리를 찍고 절마다 격리해서 실행
+pub fn run_chapter(chapter: &dyn Chapter) {
+    let selected = selected(chapter);
+    // 모든 절을 걸렀으면 장 머리도 찍지 않음
+    let Some((first, _)) = selected.first() else {
+        return;
+    };
+    // --step 이면 장 머리 앞에서 첫 절을 알림
+    if !step::before(chapter.id(), first) {
+        return;
+    }
+    let start = Instant::now();
+    // 영어 문자열 표(--lang en)는 이 머리 그대로를 찾음
+    let header = format!("\n=== {}. {} ===\n", chapter.id(), chapter.name());
+    i18n::write(&header, &header, true);
+    let mut times = Vec::new();
+    let mut allocs = Vec::new();
+    for (i, (name, f)) in selected.into_iter().enumerate() {
+        if i > 0 && !step::before(chapter.id(), name) {
+            break;
+        }
+        let (elapsed, used) = run_section(chapter.id(), name, f);
+        times.push((name, elapsed));
+        allocs.push((name, used));
+    }
+    timing::record(chapter, times, start.elapsed());
+    allocations::record(chapter, allocs);
+}
+
+// 절 하나만 (cargo run -- 07:trait_objects)
+pub fn run_one(chapter: &dyn Chapter, name: &'static str, f: fn()) {
+    if !step::before(chapter.id(), name) {
+        return;
+    }
+    let (elapsed, used) = run_section(chapter.id(), name, f);
+    timing::record(chapter, vec![(name, elapsed)], elapsed);
+    allocations::record(chapter, vec![(name, used)]);
+}
+
+pub fn summary(passed: usize, failures: &[Failure], skipped: usize) -> String {
+    let mut out = tr!("\n절 {}개 통과, {}개 실패", passed, failures.len());
+    if skipped > 0 {
+        out.push_str(&tr!(", {}개 건너뜀 (--skip/--only/--level)", skipped));
+    }
+    out.push('\n');
+    for f in failures {
+        out.push_str(&tr!("  실패 {}::{} - {}", f.chapter, f.section, f.message));
+        if let Some(location) = &f.location {
+            out.push_str(&format!(" ({})", location));
+        }
+        out.push('\n');
+    }
+    if !failures.is_empty() {
+        out.push_str(&tr!("  절 하나만 다시: cargo run -- <장>:<절>\n"));
+    }
+    out
+}
+
+// 실행한 절이 있으면 요약 출력 - 실패가 없으면 true
+pub fn finish() -> bool {
+    let results = results();
+    if results.passed + results.failures.len() + results.skipped > 0 {
+        print!("{}", summary(results.passed, &results.failures, results.skipped));
+    }
+    results.failures.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fine() {}
+
+    fn double_borrow() {
+        let cell = std::cell::RefCell::new(1);
+        let _first = cell.borrow_mut();
+        let _second = cell.borrow_mut();
+    }
+
+    #[test]
+    fn isolates_panicking_sections() {
+        run_section("99", "fine", fine);
+        run_section("99", "double_borrow", double_borrow);
+        let results = results();
+        let failure = results.failures.iter().find(|f| f.section == "double_borrow").unwrap();
+        assert!(failure.message.contains("borrowed"), "{}", failure.message);
+        // 훅이 기록한 위치 - RefCell 안이 아니라 borrow_mut 을 부른 이 파일 (#[track_caller])
+        assert!(failure.location.as_deref().is_some_and(|l| l.contains("src/runner.rs:")), "{:?}", failure.location);
+        assert!(results.passed >= 1);
+    }
+
+    #[test]
+    fn summarizes_passes_and_failures() {
+        let failure = Failure {
+            chapter: "12",
+            section: "refcell",
+            message: "already borrowed".to_string(),
+            location: Some("src/_12_smart_pointers.rs:120".to_string()),
+        };
+        let text = summary(5, &[failure], 0);
+        assert!(text.contains("절 5개 통과, 1개 실패\n"));
+        assert!(text.contains("실패 12::refcell - already borrowed (src/_12_smart_pointers.rs:120)\n"));
+        assert!(!summary(3, &[], 0).contains("다시"));
+        assert!(summary(3, &[], 2).starts_with("\n절 3개 통과, 0개 실패, 2개 건너뜀"));
+    }
+}
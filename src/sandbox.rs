@@ -0,0 +1,339 @@
+// ============================================================================
+// 장별 샌드박스 프로젝트 생성
+// ============================================================================
+// cargo run -- sandbox 13  →  sandbox/13_concurrency/ 에 독립 cargo 프로젝트 생성
+//
+// - 장의 소스를 그대로 복사 - 원본 크레이트를 건드리지 않고 마음껏 수정
+// - 각 절 머리에 TODO 표시, main.rs 에 연습문제 목록
+// - 소스가 참조하는 공용 모듈(macros.rs 등)과 다른 장, 외부 크레이트만 함께 넣음 - 참조의 참조까지
+// ============================================================================
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::chapters::{self, ChapterInfo};
+use crate::content::Exercise;
+use crate::sections::{self, is_rule};
+
+// 생성할 파일 하나 - 프로젝트 루트 기준 상대 경로
+#[derive(Debug)]
+pub struct SandboxFile {
+    pub path: String,
+    pub contents: String,
+}
+
+// 함께 넣은 모듈 중 하나라도 이 경로를 (주석 밖에서) 쓰면 해당 의존성을 Cargo.toml 에 추가
+// 장에서 새 크레이트를 쓰면 여기에도 - 빠지면 every_sandbox_has_what_it_uses 테스트가 알려 줌
+const DEPENDENCIES: &[(&str, &str)] = &[
+    ("tokio::", r#"tokio = { version = "1", features = ["full"] }"#),
+    ("tokio_stream::", r#"tokio-stream = { version = "0.1", features = ["sync"] }"#),
+    ("serde::", r#"serde = { version = "1", features = ["derive"] }"#),
+    ("serde_json::", r#"serde_json = "1""#),
+    ("rayon::", r#"rayon = "1""#),
+    ("crossbeam::", r#"crossbeam = "0.8""#),
+    ("toml::", r#"toml = "1""#),
+    ("clap::", r#"clap = { version = "4", features = ["derive"] }"#),
+    ("proptest::", r#"proptest = "1""#),
+    // 45장의 C 라이브러리 - 저장소의 capi/ 를 경로 의존성으로 (샌드박스는 이 저장소 밖에 있을 수도 있음)
+    ("study_capi::", concat!("rust-study-capi = { path = '", env!("CARGO_MANIFEST_DIR"), "/capi' }")),
+];
+
+const TODO_MARKER: &str = "// TODO(sandbox): 값을 바꾸거나 코드를 추가해 실행해 보세요";
+
+pub fn generate(info: &ChapterInfo, exercises: &[Exercise]) -> io::Result<Vec<SandboxFile>> {
+    let source = source_of(info)?;
+
+    // 장 본문 + crate:: 로 참조하는 공용 모듈과 다른 장 (절 예제와 같은 sections::crate_modules)
+    // 가져온 모듈이 참조하는 것까지 따라감 - 42장 → 19장 → macros, chapter
+    let mut modules = vec![(info.module(), add_todo_markers(source))];
+    let mut i = 0;
+    while i < modules.len() {
+        for (name, contents) in sections::crate_modules(&modules[i].1) {
+            if !modules.iter().any(|(existing, _)| *existing == name) {
+                modules.push((name, contents.to_string()));
+            }
+        }
+        i += 1;
+    }
+
+    let dependencies: Vec<&str> = DEPENDENCIES
+        .iter()
+        .filter(|(path, _)| modules.iter().any(|(_, src)| uses_path(src, path)))
+        .map(|(_, line)| *line)
+        .collect();
+
+    let mut files = vec![
+        SandboxFile {
+            path: "Cargo.toml".to_string(),
+            contents: cargo_toml(info, &dependencies),
+        },
+        SandboxFile {
+            path: "src/main.rs".to_string(),
+            contents: main_rs(info, &modules, exercises),
+        },
+    ];
+    for (name, contents) in modules {
+        files.push(SandboxFile {
+            path: format!("src/{}.rs", name),
+            contents,
+        });
+    }
+    Ok(files)
+}
+
+// 이미 있는 디렉터리에는 쓰지 않음 - 학습자가 고친 코드를 덮어쓰지 않도록
+pub fn write(dir: &Path, files: &[SandboxFile]) -> io::Result<()> {
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} 가 이미 있습니다 - 지우거나 다른 경로를 지정하세요", dir.display()),
+        ));
+    }
+    for file in files {
+        let path = dir.join(&file.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, &file.contents)?;
+    }
+    Ok(())
+}
+
+fn source_of(info: &ChapterInfo) -> io::Result<&'static str> {
+    chapters::source(info.id).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::NotFound, format!("{}장 소스가 없습니다", info.id))
+    })
+}
+
+// 주석 줄은 건너뜀 - "// use serde::..." 같은 설명 때문에 의존성이 붙지 않도록
+fn uses_path(source: &str, path: &str) -> bool {
+    source
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .any(|line| line.contains(path))
+}
+
+// 절 머리(// ---- / 제목 / // ----) 바로 아래에 TODO 표시 삽입 - 파일 첫 머리는 제외
+fn add_todo_markers(source: &str) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut out = String::with_capacity(source.len());
+    for (i, line) in lines.iter().enumerate() {
+        out.push_str(line);
+        out.push('\n');
+        let closes_header = i > 2
+            && is_rule(line)
+            && is_rule(lines[i - 2])
+            && !is_rule(lines[i - 1]);
+        if closes_header {
+            out.push_str(TODO_MARKER);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn cargo_toml(info: &ChapterInfo, dependencies: &[&str]) -> String {
+    let mut s = format!(
+        "[package]\nname = \"sandbox-{}-{}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n",
+        info.id,
+        info.slug.replace('_', "-")
+    );
+    // 빈 workspace - rust-study 디렉터리 안에 만들어도 독립 프로젝트로 빌드
+    s.push_str("[workspace]\n\n[dependencies]\n");
+    for line in dependencies {
+        s.push_str(line);
+        s.push('\n');
+    }
+    s
+}
+
+fn main_rs(info: &ChapterInfo, modules: &[(String, String)], exercises: &[Exercise]) -> String {
+    let mut s = format!("// {}. {} - 샌드박스\n", info.id, info.title);
+    s.push_str("// rust-study 의 `sandbox` 명령으로 생성됨. 원본과 독립적이니 마음껏 고쳐 보세요.\n");
+    s.push_str("// 실행: cargo run    테스트: cargo test\n");
+    if !exercises.is_empty() {
+        s.push_str("//\n// 연습문제\n");
+        for ex in exercises {
+            s.push_str(&format!("// TODO({}): {} - {}\n", ex.id, ex.title, ex.description));
+            for hint in &ex.hints {
+                s.push_str(&format!("//   힌트: {}\n", hint));
+            }
+        }
+    }
+    // 학습용 코드라 쓰지 않는 항목이 많음 - 경고 대신 실험에 집중
+    s.push_str("\n#![allow(dead_code, unused)]\n\n");
+    for (name, _) in modules {
+        s.push_str(&format!("mod {};\n", name));
+    }
+    // 장 머리를 찍고 절을 차례로 - rust-study 의 runner 와 같은 순서 (panic 격리는 없음)
+    s.push_str(&format!(
+        "\nfn main() {{\n    println!(\"\\n=== {}. {} ===\\n\");\n\n    for (_, section) in {}::SECTIONS {{\n        section();\n    }}\n}}\n",
+        info.id,
+        info.title,
+        info.module()
+    ));
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file<'a>(files: &'a [SandboxFile], path: &str) -> &'a str {
+        &files.iter().find(|f| f.path == path).unwrap().contents
+    }
+
+    #[test]
+    fn every_chapter_has_source() {
+        for c in chapters::CHAPTERS {
+            assert!(chapters::source(c.id).is_some(), "{}장", c.id);
+        }
+    }
+
+    #[test]
+    fn concurrency_sandbox_is_self_contained() {
+        let info = chapters::find("13").unwrap();
+        let files = generate(info, &[]).unwrap();
+
+        assert_eq!(files.len(), 4);
+        assert!(file(&files, "src/main.rs").contains("mod chapter;"));
+        assert!(!file(&files, "Cargo.toml").contains("tokio"));
+        assert!(file(&files, "src/main.rs").contains("mod _13_concurrency;"));
+        let chapter = file(&files, "src/_13_concurrency.rs");
+        assert!(chapter.contains(TODO_MARKER));
+        assert!(chapter.contains("fn basic_threads()"));
+    }
+
+    #[test]
+    fn async_sandbox_pulls_in_tokio() {
+        let files = generate(chapters::find("async").unwrap(), &[]).unwrap();
+        let manifest = file(&files, "Cargo.toml");
+        assert!(manifest.contains("tokio = "));
+        assert!(manifest.contains("tokio-stream = "));
+        assert!(!manifest.contains("serde"));
+    }
+
+    #[test]
+    fn referenced_modules_are_copied() {
+        // 19장은 공용 매크로와 16장의 MyVec 을 사용
+        let files = generate(chapters::find("19").unwrap(), &[]).unwrap();
+        let main = file(&files, "src/main.rs");
+        assert!(main.contains("mod macros;"));
+        assert!(main.contains("mod _16_unsafe;"));
+        assert!(files.iter().any(|f| f.path == "src/_16_unsafe.rs"));
+    }
+
+    #[test]
+    fn chapter_crates_are_added() {
+        for (id, line) in [("32", "rayon = "), ("33", "crossbeam = "), ("35", "toml = "), ("41", "clap = "), ("42", "proptest = "), ("45", "rust-study-capi = "), ("46", "rust-study-capi = ")] {
+            let files = generate(chapters::find(id).unwrap(), &[]).unwrap();
+            assert!(file(&files, "Cargo.toml").contains(line), "{}장: {}", id, line);
+        }
+    }
+
+    #[test]
+    fn measuring_chapters_copy_microbench() {
+        for id in ["26", "50"] {
+            let files = generate(chapters::find(id).unwrap(), &[]).unwrap();
+            assert!(file(&files, "src/main.rs").contains("mod microbench;"), "{}장", id);
+            assert!(files.iter().any(|f| f.path == "src/microbench.rs"), "{}장", id);
+        }
+    }
+
+    // code 에서 주석 밖의 crate::이름 - 뒤따르는 이름만 (crate::{a, b} 는 건너뜀)
+    fn crate_paths(code: &str) -> Vec<&str> {
+        let mut names = Vec::new();
+        for line in code.lines().filter(|line| !line.trim_start().starts_with("//")) {
+            for (at, _) in line.match_indices("crate::") {
+                // my_crate::parse 같은 다른 이름의 꼬리는 제외 ($crate:: 는 포함)
+                if line[..at].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                    continue;
+                }
+                let rest = &line[at + "crate::".len()..];
+                let end = rest.find(|c: char| !c.is_alphanumeric() && c != '_').unwrap_or(rest.len());
+                if end > 0 {
+                    names.push(&rest[..end]);
+                }
+            }
+        }
+        names
+    }
+
+    // 주석과 문자열 밖에서 krate:: 로 시작하는 경로 - my_toml:: 같은 다른 이름의 꼬리는 제외
+    fn mentions_crate(code: &str, krate: &str) -> bool {
+        let path = format!("{}::", krate);
+        code.lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .any(|line| {
+                line.match_indices(&path).any(|(at, _)| {
+                    let before = &line[..at];
+                    let in_string = before.matches('"').count() % 2 == 1;
+                    !in_string && !before.ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == ':')
+                })
+            })
+    }
+
+    // 루트 Cargo.toml 의 일반 의존성 - (패키지 이름, 코드에서 쓰는 크레이트 이름)
+    fn root_dependencies() -> Vec<(String, String)> {
+        let root: toml::Table = toml::from_str(include_str!("../Cargo.toml")).unwrap();
+        let capi: toml::Table = toml::from_str(include_str!("../capi/Cargo.toml")).unwrap();
+        let mut tables = vec![&root["dependencies"]];
+        for target in root["target"].as_table().unwrap().values() {
+            tables.extend(target.get("dependencies"));
+        }
+        let mut deps = Vec::new();
+        for table in tables {
+            for name in table.as_table().unwrap().keys() {
+                let krate = if *name == capi["package"]["name"].as_str().unwrap() {
+                    capi["lib"]["name"].as_str().unwrap().to_string()
+                } else {
+                    name.replace('-', "_")
+                };
+                deps.push((name.clone(), krate));
+            }
+        }
+        deps
+    }
+
+    #[test]
+    fn every_sandbox_has_what_it_uses() {
+        // macros.rs 가 #[macro_export] 로 크레이트 루트에 두는 매크로
+        const ROOT_MACROS: &[&str] = &["my_vec", "count_exprs"];
+        let deps = root_dependencies();
+        assert!(deps.iter().any(|(_, krate)| krate == "study_capi"));
+
+        for info in chapters::CHAPTERS {
+            let files = generate(info, &[]).unwrap();
+            let main = file(&files, "src/main.rs");
+            let manifest = file(&files, "Cargo.toml");
+            for source in files.iter().filter(|f| f.path.ends_with(".rs")) {
+                for name in crate_paths(&source.contents) {
+                    let module = if ROOT_MACROS.contains(&name) { "macros" } else { name };
+                    let declared = format!("mod {};", module);
+                    assert!(main.contains(&declared), "{}장 샌드박스의 {} 가 crate::{} 를 쓰는데 모듈이 없음", info.id, source.path, name);
+                }
+                for (package, krate) in &deps {
+                    // 44장의 cxx 는 cfg(cpp_ffi) 안에서만 - 샌드박스에는 C++ 를 빌드하는 build.rs 가 없어서 꺼짐
+                    if krate == "cxx" || !mentions_crate(&source.contents, krate) {
+                        continue;
+                    }
+                    let line = format!("\n{} = ", package);
+                    assert!(manifest.contains(&line), "{}장 샌드박스의 {} 가 {} 를 쓰는데 의존성이 없음", info.id, source.path, package);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn write_refuses_existing_directory() {
+        let dir = std::env::temp_dir().join(format!("sandbox-test-{}", std::process::id()));
+        let files = generate(chapters::find("01").unwrap(), &[]).unwrap();
+
+        write(&dir, &files).unwrap();
+        assert!(dir.join("src/_01_basics.rs").exists());
+        assert!(write(&dir, &files).is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,187 @@
+// ============================================================================
+// 주제 검색 - 어떤 장, 어떤 절에서 다루는지
+// ============================================================================
+// cargo run -- search lifetime            장 제목, 설명, 주제, 절 이름과 제목에서 찾기
+// cargo run -- search unique_ptr          C++ 용어는 대응하는 Rust 용어(Box)로 바꿔서도 찾음
+// cargo run -- search Arc --source        장 소스(include_str!)에 나온 횟수도 점수에 더함
+//
+// find 는 소스의 줄을 보여주고, search 는 "무엇을 어디서 배우는지"를 장/절 단위로 보여줌
+// 점수: 장 제목/설명/주제 10, 절 이름/제목 5, 소스에 나온 횟수 1 (--source)
+// ============================================================================
+
+use crate::chapter::Chapter;
+use crate::chapters;
+use crate::sections;
+
+// C++ 용어 → 같은 역할의 Rust 용어 (소문자로 비교, std:: 는 떼고)
+const CPP_TERMS: &[(&str, &[&str])] = &[
+    ("unique_ptr", &["Box"]),
+    ("shared_ptr", &["Rc", "Arc"]),
+    ("weak_ptr", &["Weak"]),
+    ("make_unique", &["Box::new"]),
+    ("make_shared", &["Rc::new", "Arc::new"]),
+    ("optional", &["Option"]),
+    ("nullptr", &["Option"]),
+    ("variant", &["enum", "match"]),
+    ("exception", &["Result", "panic"]),
+    ("try", &["Result", "?"]),
+    ("template", &["제네릭", "generic"]),
+    ("concept", &["트레이트 바운드", "trait"]),
+    ("virtual", &["dyn", "트레이트 객체"]),
+    ("lambda", &["클로저", "closure"]),
+    ("function", &["Fn", "클로저"]),
+    ("vector", &["Vec"]),
+    ("map", &["HashMap", "BTreeMap"]),
+    ("unordered_map", &["HashMap"]),
+    ("set", &["HashSet", "BTreeSet"]),
+    ("string_view", &["&str"]),
+    ("span", &["슬라이스", "slice"]),
+    ("move", &["이동", "소유권"]),
+    ("raii", &["Drop"]),
+    ("destructor", &["Drop"]),
+    ("mutex", &["Mutex"]),
+    ("lock_guard", &["Mutex", "MutexGuard"]),
+    ("shared_mutex", &["RwLock"]),
+    ("atomic", &["Atomic"]),
+    ("thread", &["스레드", "thread::spawn"]),
+    ("future", &["Future", "async"]),
+    ("co_await", &["async/await", ".await"]),
+    ("coroutine", &["async"]),
+    ("constexpr", &["const fn"]),
+    ("#ifdef", &["cfg"]),
+    ("#define", &["macro_rules!", "매크로"]),
+    ("namespace", &["mod", "모듈"]),
+    ("#include", &["use", "모듈"]),
+    ("reinterpret_cast", &["unsafe", "transmute"]),
+    ("static_cast", &["as", "From"]),
+    ("operator+", &["Add", "연산자 오버로딩"]),
+    ("gtest", &["#[test]"]),
+];
+
+const CHAPTER_SCORE: usize = 10;
+const SECTION_SCORE: usize = 5;
+
+#[derive(Debug)]
+pub struct SectionHit {
+    // "12::box_pointer"
+    pub key: String,
+    pub title: String,
+    // 절 이름이나 제목에 검색어가 있음
+    pub named: bool,
+    // --source 일 때 절 코드에 나온 횟수
+    pub occurrences: usize,
+}
+
+#[derive(Debug)]
+pub struct Hit {
+    pub id: &'static str,
+    pub name: &'static str,
+    // 장 제목, 설명, 주제 중 검색어가 나온 것
+    pub matched: Vec<&'static str>,
+    pub sections: Vec<SectionHit>,
+    pub score: usize,
+}
+
+// 검색어와 그 C++ 용어에 대응하는 Rust 용어 - (찾을 말들, 대응 설명)
+pub fn expand(query: &str) -> (Vec<String>, Option<&'static [&'static str]>) {
+    let query = query.trim();
+    let key = query.to_lowercase();
+    let key = key.strip_prefix("std::").unwrap_or(&key);
+    let rust = CPP_TERMS.iter().find(|(cpp, _)| *cpp == key).map(|(_, rust)| *rust);
+    let mut terms = vec![query.to_string()];
+    terms.extend(rust.unwrap_or_default().iter().map(|t| t.to_string()));
+    (terms, rust)
+}
+
+fn contains_any(text: &str, terms: &[String]) -> bool {
+    let text = text.to_lowercase();
+    terms.iter().any(|t| text.contains(&t.to_lowercase()))
+}
+
+// 대소문자 구분 없이 세기 - 겹치지 않게
+fn count(text: &str, term: &str) -> usize {
+    text.to_lowercase().matches(&term.to_lowercase()).count()
+}
+
+// 점수 높은 순 (같으면 장 번호 순)
+pub fn search(chapters_list: &[Box<dyn Chapter>], query: &str, with_source: bool) -> Vec<Hit> {
+    if query.trim().is_empty() {
+        return Vec::new();
+    }
+    let (terms, _) = expand(query);
+    let mut hits = Vec::new();
+    for chapter in chapters_list {
+        let matched: Vec<&'static str> = [chapter.name(), chapter.description()]
+            .into_iter()
+            .chain(chapter.topics().iter().copied())
+            .filter(|text| contains_any(text, &terms))
+            .collect();
+
+        let mut sections = Vec::new();
+        if let Some(info) = chapters::find(chapter.id()) {
+            for s in sections::sections(info) {
+                let named = contains_any(&s.name, &terms) || contains_any(&s.title, &terms);
+                let occurrences = if with_source {
+                    terms.iter().map(|t| count(s.code, t)).sum()
+                } else {
+                    0
+                };
+                if named || occurrences > 0 {
+                    sections.push(SectionHit {
+                        key: s.key(),
+                        title: s.title,
+                        named,
+                        occurrences,
+                    });
+                }
+            }
+        }
+        if matched.is_empty() && sections.is_empty() {
+            continue;
+        }
+        let score = matched.len() * CHAPTER_SCORE
+            + sections
+                .iter()
+                .map(|s| if s.named { SECTION_SCORE } else { 0 } + s.occurrences)
+                .sum::<usize>();
+        hits.push(Hit {
+            id: chapter.id(),
+            name: chapter.name(),
+            matched,
+            sections,
+            score,
+        });
+    }
+    hits.sort_by(|a, b| b.score.cmp(&a.score).then(a.id.cmp(b.id)));
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cpp_terms_map_to_rust_chapters() {
+        let (terms, rust) = expand("std::unique_ptr");
+        assert_eq!(terms, vec!["std::unique_ptr", "Box"]);
+        assert_eq!(rust, Some(&["Box"][..]));
+
+        let hits = search(&crate::all_chapters(), "unique_ptr", false);
+        assert_eq!(hits[0].id, "12");
+        assert!(hits[0].matched.contains(&"Box"));
+        assert!(hits[0].sections.iter().any(|s| s.key == "12::box_pointer"));
+    }
+
+    #[test]
+    fn finds_chapters_and_sections_by_keyword() {
+        let hits = search(&crate::all_chapters(), "lifetime", false);
+        assert_eq!(hits[0].id, "04");
+        assert!(hits[0].sections.iter().all(|s| s.named && s.occurrences == 0));
+
+        // 소스까지 보면 수명 어노테이션을 쓰는 다른 장도 나옴
+        let with_source = search(&crate::all_chapters(), "'a", true);
+        assert!(with_source.iter().any(|h| h.id == "04"));
+        assert!(with_source.len() > 1);
+        assert!(search(&crate::all_chapters(), "  ", true).is_empty());
+    }
+}
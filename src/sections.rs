@@ -0,0 +1,616 @@
+// ============================================================================
+// 절(section) 단위 소스 추출
+// ============================================================================
+// 각 장의 소스는 이런 머리로 절을 나눔 (// ==== 도 같음, 파일 첫 머리는 제외):
+//
+//   // ------------------------------------------------------------------------
+//   // 커스텀 이터레이터
+//   // ------------------------------------------------------------------------
+//
+// 진입 함수가 없는 머리(설명만 있는 하위 머리, 도우미 함수 모음)는 앞 절에 포함
+// 절의 이름은 SECTIONS 목록에 등록한 그 절의 진입 함수 이름 ("11::custom_iterator")
+// 절의 코드를 독립 실행 가능한 스니펫으로 만들어 공유/예제 생성에 사용
+// ============================================================================
+
+use crate::chapters::{self, ChapterInfo};
+
+#[derive(Debug, Clone)]
+pub struct Section {
+    pub chapter: &'static str,
+    pub name: String,
+    pub title: String,
+    pub code: &'static str,
+    // 장 소스에서의 줄 범위
+    pub lines: std::ops::Range<usize>,
+    // 진입 함수가 async fn 인지 (SECTIONS 에서는 block_on 으로 감싸서 등록)
+    pub is_async: bool,
+}
+
+impl Section {
+    // "11::custom_iterator"
+    pub fn key(&self) -> String {
+        format!("{}::{}", self.chapter, self.name)
+    }
+}
+
+pub fn sections(info: &ChapterInfo) -> Vec<Section> {
+    match chapters::source(info.id) {
+        Some(source) => parse(info.id, source),
+        None => Vec::new(),
+    }
+}
+
+// "11::custom_iterator", "iterators::custom_iterator" 모두 가능
+pub fn find(key: &str) -> Result<Section, String> {
+    let (chapter_key, name) = key
+        .split_once("::")
+        .ok_or_else(|| format!("'<장>::<절>' 형식이어야 합니다: {}", key))?;
+    let info = chapters::find(chapter_key).ok_or_else(|| format!("없는 장: {}", chapter_key))?;
+    let all = sections(info);
+    all.iter().find(|s| s.name == name).cloned().ok_or_else(|| {
+        let names: Vec<&str> = all.iter().map(|s| s.name.as_str()).collect();
+        format!(
+            "{}장에 '{}' 절이 없습니다 (있는 절: {})",
+            info.id,
+            name,
+            names.join(", ")
+        )
+    })
+}
+
+pub fn is_rule(line: &str) -> bool {
+    line.starts_with("// ----") || line.starts_with("// ====")
+}
+
+pub fn parse(chapter: &'static str, source: &'static str) -> Vec<Section> {
+    let lines: Vec<&str> = source.lines().collect();
+    let offsets = line_offsets(source);
+
+    // 절 머리의 시작 줄 번호
+    let starts: Vec<usize> = (1..lines.len().saturating_sub(2))
+        .filter(|&i| is_rule(lines[i]) && !is_rule(lines[i + 1]) && is_rule(lines[i + 2]))
+        .filter(|&i| lines[i + 1].starts_with("//"))
+        .collect();
+    let end_of_code = end_of_code(&lines);
+
+    let registered = registered(source);
+    let mut sections: Vec<Section> = Vec::new();
+    let mut section_start = 0;
+    for (n, &start) in starts.iter().enumerate() {
+        if start >= end_of_code {
+            break;
+        }
+        let end = starts
+            .get(n + 1)
+            .copied()
+            .unwrap_or(end_of_code)
+            .min(end_of_code);
+
+        // 진입 함수는 인자가 없는 함수만 - main() 에서 바로 호출
+        let fns = top_level_fns(&lines[start..end]);
+        let entry = fns
+            .iter()
+            .find(|(name, _)| registered.contains(&name.as_str()))
+            .or_else(|| fns.first());
+        match (entry, sections.last_mut()) {
+            (Some((name, is_async)), _) => {
+                section_start = start;
+                sections.push(Section {
+                    chapter,
+                    name: name.clone(),
+                    title: lines[start + 1].trim_start_matches('/').trim().to_string(),
+                    code: source[offsets[start]..offsets[end]].trim_end(),
+                    lines: start..end,
+                    is_async: *is_async,
+                });
+            }
+            (None, Some(prev)) => {
+                prev.code = source[offsets[section_start]..offsets[end]].trim_end();
+                prev.lines = section_start..end;
+            }
+            (None, None) => {}
+        }
+    }
+    sections
+}
+
+// 파일 끝의 #[cfg(test)] mod tests 는 절에 포함하지 않음
+fn end_of_code(lines: &[&str]) -> usize {
+    lines
+        .windows(2)
+        .position(|w| w[0] == "#[cfg(test)]" && w[1] == "mod tests {")
+        .unwrap_or(lines.len())
+}
+
+// 각 줄의 시작 바이트 위치 (+ 끝 위치)
+fn line_offsets(source: &str) -> Vec<usize> {
+    let mut offsets = vec![0];
+    for (i, b) in source.bytes().enumerate() {
+        if b == b'\n' {
+            offsets.push(i + 1);
+        }
+    }
+    if offsets.last() != Some(&source.len()) {
+        offsets.push(source.len());
+    }
+    offsets
+}
+
+// pub const SECTIONS 에 등록한 절 이름 - 절의 진입 함수를 찾는 데 사용
+//   ("trait_objects", trait_objects),
+//   ("async_basics", || block_on(async_basics())),
+pub fn registered(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .skip_while(|line| !line.starts_with("pub const SECTIONS"))
+        .skip(1)
+        .take_while(|line| !line.starts_with("];"))
+        .filter_map(|line| line.trim().strip_prefix("(\"")?.split_once('"'))
+        .map(|(name, _)| name)
+        .collect()
+}
+
+// 들여쓰기 없는 인자 없는 fn 정의 - (이름, async 여부)
+fn top_level_fns(lines: &[&str]) -> Vec<(String, bool)> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let line = line.strip_prefix("pub ").unwrap_or(line);
+            let (rest, is_async) = match line.strip_prefix("async fn ") {
+                Some(rest) => (rest, true),
+                None => (line.strip_prefix("fn ")?, false),
+            };
+            let name = ident(rest);
+            rest[name.len()..]
+                .starts_with("()")
+                .then_some((name, is_async))
+        })
+        .collect()
+}
+
+// 장 머리(SECTIONS 앞)의 use 선언 - 스니펫이 그대로 컴파일되도록 함께 넣음
+fn preamble_uses(source: &str) -> Vec<&str> {
+    let mut uses = Vec::new();
+    let mut in_use = false;
+    for line in source.lines() {
+        if line.starts_with("pub const SECTIONS") {
+            break;
+        }
+        if line.starts_with("use ") {
+            in_use = true;
+        }
+        if in_use {
+            uses.push(line);
+            in_use = !line.trim_end().ends_with(';');
+        }
+    }
+    uses
+}
+
+// ----------------------------------------------------------------------------
+// 다른 절에 정의된 아이템 끌어오기
+// ----------------------------------------------------------------------------
+// 예: 07::trait_objects 는 앞 절의 trait Summary, struct Tweet 을 사용
+// 절 코드에 이름이 나오는 최상위 아이템과 그 impl 블록을 고정점까지 반복해서 포함
+
+#[derive(Debug)]
+struct Item {
+    lines: std::ops::Range<usize>,
+    // 정의하는 이름 (impl 은 None)
+    name: Option<String>,
+    // impl 블록의 대상 타입
+    impl_for: Option<String>,
+    text: String,
+}
+
+const ITEM_KEYWORDS: &[&str] = &[
+    "struct ",
+    "enum ",
+    "trait ",
+    "type ",
+    "const ",
+    "static ",
+    "fn ",
+    "async fn ",
+    "union ",
+    "mod ",
+];
+
+fn ident(text: &str) -> String {
+    text.chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect()
+}
+
+// 최상위 아이템 - 위에 붙은 어트리뷰트(#[...])까지 포함
+fn items(source: &str) -> Vec<Item> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut items = Vec::new();
+    let end_of_code = end_of_code(&lines);
+    let mut i = 0;
+    while i < end_of_code {
+        let line = lines[i];
+        let body = line
+            .strip_prefix("pub(crate) ")
+            .or_else(|| line.strip_prefix("pub "))
+            .unwrap_or(line);
+        let (name, impl_for) = if let Some(rest) = body.strip_prefix("impl") {
+            // impl<T> Trait for Type / impl Type
+            let rest = rest.trim_start_matches(|c: char| c != ' ').trim_start();
+            let target = rest.split(" for ").nth(1).unwrap_or(rest);
+            (None, Some(ident(target.trim_start_matches('&'))))
+        } else if let Some(k) = ITEM_KEYWORDS.iter().find(|k| body.starts_with(*k)) {
+            (Some(ident(&body[k.len()..])), None)
+        } else {
+            i += 1;
+            continue;
+        };
+
+        let mut start = i;
+        while start > 0 && lines[start - 1].starts_with("#[") {
+            start -= 1;
+        }
+        let end = if line.trim_end().ends_with(';') || line.trim_end().ends_with('}') {
+            i + 1
+        } else {
+            (i + 1..lines.len())
+                .find(|&j| is_closing(lines[j]))
+                .map_or(lines.len(), |j| j + 1)
+        };
+        items.push(Item {
+            lines: start..end,
+            name: name.filter(|n| !n.is_empty() && n != "run"),
+            impl_for,
+            text: lines[start..end].join("\n"),
+        });
+        i = end;
+    }
+    items
+}
+
+// 최상위 블록의 닫는 줄 (SECTIONS 같은 배열은 "];") - 문자열 안의 "}}" 같은 줄은 제외
+fn is_closing(line: &str) -> bool {
+    match line.strip_prefix('}').or_else(|| line.strip_prefix(']')) {
+        Some(rest) => rest.is_empty() || rest.starts_with([';', ' ', '/']),
+        None => false,
+    }
+}
+
+fn contains_word(text: &str, word: &str) -> bool {
+    text.match_indices(word).any(|(at, _)| {
+        let before = text[..at].chars().next_back();
+        let after = text[at + word.len()..].chars().next();
+        let is_ident = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+        !is_ident(before) && !is_ident(after)
+    })
+}
+
+// 절 밖에서 가져와야 하는 아이템 (소스 순서)
+fn supporting_items(section: &Section, source: &str) -> Vec<String> {
+    let (inside, outside): (Vec<Item>, Vec<Item>) = items(source)
+        .into_iter()
+        .filter(|item| item.name.is_some() || item.impl_for.is_some())
+        .partition(|item| {
+            item.lines.start >= section.lines.start && item.lines.end <= section.lines.end
+        });
+    // 절 안에서 이미 정의한 이름은 다시 넣지 않음 (중복 정의 방지)
+    let all: Vec<Item> = outside
+        .into_iter()
+        .filter(|item| item.name.is_none() || inside.iter().all(|i| i.name != item.name))
+        .collect();
+
+    let mut included = vec![false; all.len()];
+    let mut text = section.code.to_string();
+    loop {
+        let mut changed = false;
+        for (n, item) in all.iter().enumerate() {
+            if included[n] {
+                continue;
+            }
+            let needed = match (&item.name, &item.impl_for) {
+                (Some(name), _) => contains_word(&text, name),
+                (None, Some(target)) => contains_word(&text, target),
+                (None, None) => false,
+            };
+            if needed {
+                included[n] = true;
+                text.push('\n');
+                text.push_str(&item.text);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    all.into_iter()
+        .zip(included)
+        .filter(|(_, inc)| *inc)
+        .map(|(item, _)| item.text)
+        .collect()
+}
+
+fn is_root_macro_import(line: &str) -> bool {
+    line.strip_prefix("use crate::")
+        .and_then(|rest| rest.strip_suffix(';'))
+        .is_some_and(|path| !path.contains("::"))
+}
+
+// #[cfg(test)] mod 이름 { ... } 블록을 뺀 모듈 소스 - 예제는 테스트로 빌드하지 않으므로 넣을 필요가 없음
+// 블록 끝은 mod 줄과 같은 들여쓰기의 "}" 줄 (저장소의 서식), 다른 #[cfg(test)] 항목(macro_rules! 등)은 그대로
+pub fn without_tests(source: &str) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut out = String::with_capacity(source.len());
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        if line.trim() == "#[cfg(test)]" {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            // 사이에 다른 속성이 있어도 됨 (#[allow(...)] 등)
+            let mut j = i + 1;
+            while j < lines.len() && lines[j].starts_with(indent) && lines[j].trim_start().starts_with("#[") {
+                j += 1;
+            }
+            let is_mod = lines.get(j).is_some_and(|l| {
+                l.strip_prefix(indent)
+                    .and_then(|rest| rest.strip_prefix("mod "))
+                    .is_some_and(|rest| rest.ends_with(" {"))
+            });
+            let end = format!("{}}}", indent);
+            if let Some(close) = is_mod.then(|| lines[j..].iter().position(|l| *l == end)).flatten() {
+                i = j + close + 1;
+                // 블록 앞의 빈 줄 하나도 함께
+                if out.ends_with("\n\n") {
+                    out.pop();
+                }
+                continue;
+            }
+        }
+        out.push_str(line);
+        out.push('\n');
+        i += 1;
+    }
+    out
+}
+
+// crate:: 로 참조하는 rust-study 의 다른 모듈 - 스니펫 안에 mod 로 넣음 (샌드박스도 같은 목록)
+pub fn crate_modules(code: &str) -> Vec<(String, &'static str)> {
+    let mut modules = Vec::new();
+    if ["crate::macros", "crate::my_vec", "crate::count_exprs"]
+        .iter()
+        .any(|p| code.contains(p))
+    {
+        modules.push(("macros".to_string(), include_str!("macros.rs")));
+    }
+    if code.contains("crate::demo_data") {
+        modules.push(("demo_data".to_string(), include_str!("demo_data.rs")));
+    }
+    if code.contains("crate::microbench") {
+        modules.push(("microbench".to_string(), include_str!("microbench.rs")));
+    }
+    if code.contains("crate::panic_hook") {
+        modules.push(("panic_hook".to_string(), include_str!("panic_hook.rs")));
+    }
+    for info in chapters::CHAPTERS {
+        let name = info.module();
+        if code.contains(&format!("crate::{}", name)) {
+            if let Some(source) = chapters::source(info.id) {
+                modules.push((name, source));
+            }
+        }
+    }
+    // 이 코드나 함께 넣은 장 모듈의 Lesson 이 구현하는 트레이트
+    if code.contains("crate::chapter::Chapter")
+        || modules.iter().any(|(_, source)| source.contains("crate::chapter::Chapter"))
+    {
+        modules.push(("chapter".to_string(), include_str!("chapter.rs")));
+    }
+    modules
+}
+
+// 절 하나로 만든 독립 실행 가능한 프로그램
+pub fn snippet(section: &Section) -> String {
+    let info = chapters::find(section.chapter);
+    let mut s = match info {
+        Some(info) => format!("// {}. {} - {}\n", info.id, info.title, section.title),
+        None => format!("// {}\n", section.title),
+    };
+    s.push_str("// rust-study 에서 추출한 예제\n\n#![allow(dead_code, unused, clippy::all)]\n\n");
+
+    let source = chapters::source(section.chapter).unwrap_or("");
+    // use crate::my_vec; 같은 크레이트 루트 매크로 import 는 스니펫 루트에서 중복 정의가 됨
+    let uses: Vec<&str> = preamble_uses(source)
+        .into_iter()
+        .filter(|line| !is_root_macro_import(line))
+        .collect();
+    for line in &uses {
+        s.push_str(line);
+        s.push('\n');
+    }
+    if !uses.is_empty() {
+        s.push('\n');
+    }
+
+    let support = supporting_items(section, source);
+    let all_code = format!(
+        "{}\n{}\n{}",
+        preamble_uses(source).join("\n"),
+        support.join("\n"),
+        section.code
+    );
+    for (name, module) in crate_modules(&all_code) {
+        s.push_str(&format!(
+            "// --- rust-study 의 {} 모듈 ---\nmod {} {{\n",
+            name, name
+        ));
+        s.push_str(&without_tests(module));
+        s.push_str("}\n\n");
+    }
+    if !support.is_empty() {
+        s.push_str("// --- 다른 절에서 가져온 정의 ---\n\n");
+        for item in support {
+            s.push_str(without_tests(&item).trim_end());
+            s.push_str("\n\n");
+        }
+    }
+
+    s.push_str(section.code);
+    s.push_str("\n\n");
+    if section.is_async {
+        s.push_str(&format!(
+            "#[tokio::main]\nasync fn main() {{\n    {}().await;\n}}\n",
+            section.name
+        ));
+    } else {
+        s.push_str(&format!("fn main() {{\n    {}();\n}}\n", section.name));
+    }
+    s
+}
+
+// ----------------------------------------------------------------------------
+// Rust Playground 공유 링크
+// ----------------------------------------------------------------------------
+
+pub const PLAYGROUND: &str = "https://play.rust-lang.org/";
+
+// 코드를 URL 쿼리에 직접 넣음 - gist 생성과 달리 계정/네트워크가 필요 없음
+pub fn playground_url(code: &str) -> String {
+    format!(
+        "{}?version=stable&mode=debug&edition=2021&code={}",
+        PLAYGROUND,
+        percent_encode(code)
+    )
+}
+
+// RFC 3986 비예약 문자만 그대로, 나머지는 UTF-8 바이트 단위로 %XX
+fn percent_encode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() * 3);
+    for b in text.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+use std::fmt;
+
+pub const SECTIONS: &[(&str, fn())] = &[
+    (\"first\", first),
+    (\"second\", || block_on(second())),
+];
+
+// ------------------------------------------------------------
+// 첫 번째 절
+// ------------------------------------------------------------
+
+fn helper() -> i32 { 1 }
+
+fn first() {
+    helper();
+}
+
+// ------------------------------------------------------------
+// 두 번째 절
+// ------------------------------------------------------------
+
+async fn second() {}
+
+#[cfg(test)]
+mod tests {
+}
+";
+
+    #[test]
+    fn sections_are_split_by_header_and_named_by_entry_fn() {
+        let all = parse("99", SAMPLE);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].name, "first"); // helper 가 먼저 나와도 SECTIONS 에 등록한 함수
+        assert_eq!(all[0].title, "첫 번째 절");
+        assert!(all[0].code.contains("fn helper()"));
+        assert!(!all[0].code.contains("두 번째"));
+        assert!(all[1].is_async);
+        assert!(!all[1].code.contains("cfg(test)"));
+    }
+
+    #[test]
+    fn sub_header_without_fn_stays_in_previous_section() {
+        // 19장 "커스텀 단언 매크로"는 단언 매크로 절의 일부
+        let s = find("19::assertion_macros_explanation").unwrap();
+        assert!(s.code.contains("macro_rules! assert_approx_eq"));
+    }
+
+    #[test]
+    fn every_chapter_has_named_sections() {
+        for info in chapters::CHAPTERS {
+            let all = sections(info);
+            assert!(!all.is_empty(), "{}장", info.id);
+            for s in &all {
+                assert!(!s.name.is_empty(), "{}", s.title);
+            }
+        }
+    }
+
+    #[test]
+    fn find_by_chapter_and_function_name() {
+        let s = find("11::custom_iterator").unwrap();
+        assert_eq!(s.title, "커스텀 이터레이터");
+        assert_eq!(
+            find("iterators::custom_iterator").unwrap().key(),
+            "11::custom_iterator"
+        );
+        assert!(find("11::nope").unwrap_err().contains("custom_iterator"));
+        assert!(find("custom_iterator").is_err());
+    }
+
+    #[test]
+    fn snippet_has_uses_and_main() {
+        let s = snippet(&find("12::refcell_pointer").unwrap());
+        assert!(s.contains("use std::cell::RefCell;"));
+        assert!(s.ends_with("fn main() {\n    refcell_pointer();\n}\n"));
+
+        let s = snippet(&find("17::async_basics").unwrap());
+        assert!(s.contains("#[tokio::main]"));
+    }
+
+    #[test]
+    fn inlined_modules_leave_out_tests() {
+        let source = "fn a() {}\n\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn t() {}\n}\n\
+                      mod inner {\n    #[cfg(test)]\n    #[allow(unused)]\n    mod tests {\n        fn u() {}\n    }\n    fn b() {}\n}\n\
+                      #[cfg(test)]\nmacro_rules! m {\n    () => {};\n}\n";
+        assert_eq!(
+            without_tests(source),
+            "fn a() {}\nmod inner {\n    fn b() {}\n}\n#[cfg(test)]\nmacro_rules! m {\n    () => {};\n}\n"
+        );
+
+        // 42장 예제는 모델 테스트의 도우미 모듈(16장 등)을 넣지만 그 테스트는 넣지 않음
+        let s = snippet(&find("42::model_testing").unwrap());
+        assert!(s.contains("mod _16_unsafe {"));
+        // 19장이 출력하는 문자열 안의 "mod tests {{" 는 예제의 글자이므로 그대로
+        assert!(!s.contains("#[cfg(test)]\nmod tests {\n"));
+    }
+
+    #[test]
+    fn snippet_pulls_in_items_from_other_sections() {
+        let s = snippet(&find("07::trait_objects").unwrap());
+        assert!(s.contains("trait Summary"));
+        assert!(s.contains("impl Summary for Tweet"));
+    }
+
+    #[test]
+    fn url_encodes_code() {
+        let url = playground_url("fn main() { println!(\"안\"); }");
+        assert!(url.starts_with("https://play.rust-lang.org/?version=stable"));
+        assert!(
+            url.ends_with("code=fn%20main%28%29%20%7B%20println%21%28%22%EC%95%88%22%29%3B%20%7D")
+        );
+    }
+}
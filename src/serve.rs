@@ -0,0 +1,655 @@
+// ============================================================================
+// 브라우저 학습 모드 (로컬 웹 서버)
+// ============================================================================
+// cargo run -- serve              http://127.0.0.1:8080
+// cargo run -- serve --port 3000
+//
+//   /                  장 목록
+//   /lesson/07         장을 HTML 로 (render --format html 과 같은 HtmlSink)
+//   /search?q=Rc       모든 장의 설명과 코드에서 찾기
+//   /run/07            장의 절 예제를 서버에서 차례로 실행하고 출력을 스트리밍
+//   /run/07::basic_traits  절 하나만
+//   /lessons, /quiz/07, /progress ...  JSON API (api.rs)
+//
+// tokio 로 직접 구현한 작은 HTTP/1.1 서버 - GET 과 API 의 POST, 요청마다 연결을 닫음
+// 이 컴퓨터에서만 접속 가능하도록 127.0.0.1 에만 바인딩 - Host 가 다르거나 다른 사이트에서 온 POST, 실행은 403 (local_request)
+// ============================================================================
+
+use std::io;
+use std::path::Path;
+use std::process::Stdio;
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::process::Command;
+
+use crate::api;
+use crate::chapters::{self, ChapterInfo};
+use crate::content;
+use crate::output::{self, Block, HtmlSink};
+use crate::progress;
+use crate::sections;
+
+pub const DEFAULT_PORT: u16 = 8080;
+
+// 검색 결과를 이 개수까지만 보여줌
+const MAX_RESULTS: usize = 100;
+
+#[derive(Debug, PartialEq)]
+pub enum Reply {
+    Page { status: u16, body: String },
+    Json { status: u16, body: String },
+    // 차례로 실행할 예제 이름 ("07_basic_traits") - 출력을 스트리밍
+    Run(Vec<String>),
+    // 같은 실행이지만 끝난 뒤 JSON 으로 한 번에 (API)
+    RunJson(Vec<String>),
+}
+
+fn not_found(what: &str) -> Reply {
+    Reply::Page {
+        status: 404,
+        body: output::page(
+            "찾을 수 없음",
+            &format!("{}<p>{}</p>\n", nav(None), output::escape(what)),
+        ),
+    }
+}
+
+fn ok(title: &str, body: String) -> Reply {
+    Reply::Page {
+        status: 200,
+        body: output::page(title, &body),
+    }
+}
+
+// 요청 줄의 메서드와 경로, 본문 → 응답
+pub fn route(method: &str, target: &str, body: &str) -> Reply {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let path = percent_decode(path, false);
+    let path = path.trim_end_matches('/');
+    if let Some(reply) = api::route(method, path, body, &progress::default_path()) {
+        return reply;
+    }
+    if method != "GET" {
+        return Reply::Page {
+            status: 405,
+            body: output::page("지원하지 않는 메서드", "<p>GET 만 지원합니다</p>\n"),
+        };
+    }
+    if path.is_empty() {
+        return ok("Rust 학습", index());
+    }
+    if path == "/search" {
+        let q = query_param(query, "q").unwrap_or_default();
+        return ok(&format!("검색: {}", q), search_page(&q));
+    }
+    if let Some(key) = path.strip_prefix("/lesson/") {
+        return match chapters::find(key) {
+            Some(info) => lesson_page(info),
+            None => not_found(&format!("없는 장: {}", key)),
+        };
+    }
+    if let Some(key) = path.strip_prefix("/run/") {
+        // 절 이름까지 확인한 예제만 실행 - 경로가 그대로 명령 인자가 되지 않도록
+        let sections = match key.split_once("::") {
+            Some(_) => sections::find(key).map(|s| vec![s]),
+            None => chapters::find(key)
+                .map(sections::sections)
+                .ok_or_else(|| format!("없는 장: {}", key)),
+        };
+        return match sections {
+            Ok(all) => Reply::Run(all.iter().map(|s| s.key().replace("::", "_")).collect()),
+            Err(e) => not_found(&e),
+        };
+    }
+    not_found(&format!("없는 페이지: {}", path))
+}
+
+// ----------------------------------------------------------------------------
+// 페이지
+// ----------------------------------------------------------------------------
+
+// 위쪽 탐색 막대 - 목록, 이전/다음 장, 검색
+fn nav(current: Option<&ChapterInfo>) -> String {
+    let mut links = vec![String::from("<a href=\"/\">목록</a>")];
+    if let Some(info) = current {
+        let i = chapters::CHAPTERS
+            .iter()
+            .position(|c| c.id == info.id)
+            .unwrap_or(0);
+        if let Some(prev) = i.checked_sub(1).and_then(|p| chapters::CHAPTERS.get(p)) {
+            links.push(format!(
+                "<a href=\"/lesson/{}\">← {}</a>",
+                prev.id,
+                output::escape(prev.title)
+            ));
+        }
+        if let Some(next) = chapters::CHAPTERS.get(i + 1) {
+            links.push(format!(
+                "<a href=\"/lesson/{}\">{} →</a>",
+                next.id,
+                output::escape(next.title)
+            ));
+        }
+    }
+    format!(
+        "<nav>{} <form action=\"/search\" style=\"display:inline\">\
+         <input name=\"q\" placeholder=\"검색\"></form></nav>\n<hr>\n",
+        links.join(" | ")
+    )
+}
+
+fn index() -> String {
+    let mut body = nav(None);
+    body.push_str("<h1>Rust 학습</h1>\n<ol>\n");
+    for c in chapters::CHAPTERS {
+        body.push_str(&format!(
+            "<li value=\"{0}\"><a href=\"/lesson/{0}\">{1}</a></li>\n",
+            c.id,
+            output::escape(c.title)
+        ));
+    }
+    body.push_str("</ol>\n");
+    body
+}
+
+// 실행 버튼: fetch 의 스트림을 읽는 대로 <pre> 에 덧붙임
+const RUN_SCRIPT: &str = r#"<script>
+document.getElementById('run').onclick = async (e) => {
+  const out = document.getElementById('output');
+  out.textContent = '';
+  e.target.disabled = true;
+  const res = await fetch(e.target.dataset.url);
+  const reader = res.body.getReader();
+  const decoder = new TextDecoder();
+  for (;;) {
+    const { done, value } = await reader.read();
+    if (done) break;
+    out.textContent += decoder.decode(value, { stream: true });
+  }
+  e.target.disabled = false;
+};
+</script>
+"#;
+
+fn lesson_page(info: &ChapterInfo) -> Reply {
+    let mut html = HtmlSink::new();
+    if let Err(e) = output::render_lesson(info, &mut html) {
+        return not_found(&e.to_string());
+    }
+    let (title, lesson) = html.into_parts();
+    let mut body = nav(Some(info));
+    body.push_str(&format!(
+        "<p><button id=\"run\" data-url=\"/run/{}\">▶ 실행</button> 절 예제를 서버에서 차례로 실행합니다</p>\n\
+         <pre id=\"output\"></pre>\n",
+        info.id
+    ));
+    body.push_str(&lesson);
+    body.push_str(RUN_SCRIPT);
+    ok(&title, body)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit {
+    pub chapter: &'static str,
+    // 가장 가까운 절 머리
+    pub section: String,
+    pub line: String,
+}
+
+// 대소문자 구분 없이 설명(prose/ 의 글 포함), 코드, C++ 비교의 줄에서 찾기
+pub fn search(query: &str) -> Vec<Hit> {
+    let query = query.trim().to_lowercase();
+    let mut hits = Vec::new();
+    if query.is_empty() {
+        return hits;
+    }
+    for info in chapters::CHAPTERS {
+        let Some(source) = chapters::source(info.id) else {
+            continue;
+        };
+        let mut section = info.title.to_string();
+        let prose = content::source().load_prose(info).unwrap_or_default();
+        for block in output::lesson_blocks(info.id, source, &prose) {
+            let text = match block {
+                Block::Header { title, .. } => {
+                    section = title;
+                    continue;
+                }
+                Block::Text(text) | Block::Code(text) => text,
+                Block::Comparison { cpp, rust } => format!("{}\n{}", cpp, rust),
+            };
+            for line in text.lines().filter(|l| l.to_lowercase().contains(&query)) {
+                hits.push(Hit {
+                    chapter: info.id,
+                    section: section.clone(),
+                    line: line.trim().to_string(),
+                });
+            }
+        }
+    }
+    hits
+}
+
+fn search_page(query: &str) -> String {
+    let hits = search(query);
+    let mut body = nav(None);
+    body.push_str(&format!(
+        "<h1>검색: {}</h1>\n<p>{}건</p>\n<ul>\n",
+        output::escape(query),
+        hits.len()
+    ));
+    for hit in hits.iter().take(MAX_RESULTS) {
+        body.push_str(&format!(
+            "<li><a href=\"/lesson/{}\">{} - {}</a>: <code>{}</code></li>\n",
+            hit.chapter,
+            hit.chapter,
+            output::escape(&hit.section),
+            output::escape(&hit.line)
+        ));
+    }
+    body.push_str("</ul>\n");
+    body
+}
+
+// ----------------------------------------------------------------------------
+// 경로와 쿼리 문자열
+// ----------------------------------------------------------------------------
+
+// %XX → 바이트 - '+' 를 공백으로 읽는 것은 폼 형식(쿼리 문자열)뿐, 경로의 '+' 는 그대로 (form = false)
+pub fn percent_decode(text: &str, form: bool) -> String {
+    let bytes = text.as_bytes();
+    let hex = |b: u8| (b as char).to_digit(16);
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' if form => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                (Some(hi), Some(lo)) => {
+                    out.push((hi * 16 + lo) as u8);
+                    i += 2;
+                }
+                _ => out.push(b'%'),
+            },
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn query_param(query: &str, name: &str) -> Option<String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| percent_decode(value, true))
+}
+
+// ----------------------------------------------------------------------------
+// HTTP
+// ----------------------------------------------------------------------------
+
+pub async fn serve(listener: TcpListener) -> io::Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(async move {
+            if let Err(e) = handle(stream).await {
+                eprintln!("요청 처리 실패: {}", e);
+            }
+        });
+    }
+}
+
+// 요청 머리 최대 크기
+const MAX_REQUEST: usize = 8 * 1024;
+
+// POST 본문 최대 크기
+const MAX_BODY: usize = 64 * 1024;
+
+async fn handle(mut stream: TcpStream) -> io::Result<()> {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+    let head_end = loop {
+        if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i + 4;
+        }
+        let n = stream.read(&mut buf).await?;
+        if n == 0 || request.len() > MAX_REQUEST {
+            return Ok(());
+        }
+        request.extend_from_slice(&buf[..n]);
+    };
+    let head = String::from_utf8_lossy(&request[..head_end]).into_owned();
+    let mut parts = head.lines().next().unwrap_or_default().split(' ');
+    let (method, target) = (
+        parts.next().unwrap_or_default(),
+        parts.next().unwrap_or("/"),
+    );
+
+    let header = |name: &str| {
+        head.lines()
+            .skip(1)
+            .filter_map(|l| l.split_once(':'))
+            .find(|(n, _)| n.trim().eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.trim())
+    };
+    let port = stream.local_addr()?.port();
+    let allowed =
+        |acts: bool| local_request(header("host"), header("origin"), header("sec-fetch-site"), port, acts);
+    // 진도를 바꾸는 POST 는 이 서버의 페이지에서 온 것만 - 본문을 읽기 전에 거절
+    if !allowed(method != "GET") {
+        return forbidden(&mut stream).await;
+    }
+
+    // 본문은 Content-Length 만큼 (청크 요청은 지원하지 않음)
+    let length = head
+        .lines()
+        .filter_map(|l| l.split_once(':'))
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+    if length > MAX_BODY {
+        return respond(
+            &mut stream,
+            413,
+            "text/plain; charset=utf-8",
+            "본문이 너무 큽니다\n",
+        )
+        .await;
+    }
+    let mut body = request[head_end..].to_vec();
+    while body.len() < length {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        body.extend_from_slice(&buf[..n]);
+    }
+    let body = String::from_utf8_lossy(&body[..length.min(body.len())]).into_owned();
+
+    let reply = route(method, target, &body);
+    // 실행(cargo run)은 GET 이지만 POST 와 같은 조건으로
+    if matches!(reply, Reply::Run(_) | Reply::RunJson(_)) && !allowed(true) {
+        return forbidden(&mut stream).await;
+    }
+
+    match reply {
+        Reply::Page { status, body } => {
+            respond(&mut stream, status, "text/html; charset=utf-8", &body).await
+        }
+        Reply::Json { status, body } => {
+            respond(&mut stream, status, "application/json", &body).await
+        }
+        // 실행 버튼은 응답 본문을 그대로 출력 칸에 보여 줌
+        Reply::Run(_) if source_tree().is_none() => {
+            respond(&mut stream, 503, "text/plain; charset=utf-8", &format!("{}\n", NO_SOURCE)).await
+        }
+        Reply::RunJson(_) if source_tree().is_none() => {
+            let body = serde_json::json!({ "error": NO_SOURCE }).to_string();
+            respond(&mut stream, 503, "application/json", &body).await
+        }
+        Reply::Run(names) => stream_run(&mut stream, &names).await,
+        Reply::RunJson(names) => {
+            let body = run_json(&names).await?;
+            respond(&mut stream, 200, "application/json", &body).await
+        }
+    }
+}
+
+// 127.0.0.1 에만 바인딩해도 브라우저는 다른 사이트의 페이지가 시키는 대로 이 서버에 요청을 보냄
+// Host 가 127.0.0.1:<포트> 나 localhost:<포트> 가 아니면 거절 - evil.example 이 127.0.0.1 을 가리키게 하는 DNS 리바인딩
+// acts(POST, 실행)면 Origin 이 있을 때 이 서버여야 하고, Sec-Fetch-Site 가 있을 때 다른 사이트가 아니어야 함
+// (<img src=".../run/07"> 같은 GET 에는 Origin 이 없지만 요즘 브라우저는 Sec-Fetch-Site: cross-site 를 붙임)
+pub fn local_request(host: Option<&str>, origin: Option<&str>, fetch_site: Option<&str>, port: u16, acts: bool) -> bool {
+    let ours = [format!("127.0.0.1:{}", port), format!("localhost:{}", port)];
+    if !host.is_some_and(|h| ours.iter().any(|o| o == h)) {
+        return false;
+    }
+    if !acts {
+        return true;
+    }
+    let origin_ok = origin.is_none_or(|o| ours.iter().any(|h| o == format!("http://{}", h)));
+    let site_ok = fetch_site.is_none_or(|site| matches!(site, "same-origin" | "none"));
+    origin_ok && site_ok
+}
+
+async fn forbidden(stream: &mut TcpStream) -> io::Result<()> {
+    respond(stream, 403, "text/plain; charset=utf-8", "다른 사이트에서 온 요청은 받지 않습니다\n").await
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+async fn respond(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &str,
+) -> io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason(status),
+        content_type,
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body.as_bytes()).await
+}
+
+// 예제는 이 바이너리를 빌드한 소스 트리(examples/)에서 그때의 cargo 로 실행 - 둘 다 빌드할 때 정해진 경로
+// 콘텐츠를 내장한 바이너리만 따로 배포하면 둘 다 없으므로 실행 요청은 NO_SOURCE 로 거절 (handle)
+fn source_tree() -> Option<&'static Path> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    (dir.join("examples").is_dir() && Path::new(env!("CARGO")).is_file()).then_some(dir)
+}
+
+const NO_SOURCE: &str = "예제 실행을 끔 - 이 바이너리를 빌드한 소스 트리나 cargo 가 없습니다 \
+                         (저장소를 받아 그 안에서 cargo run -- serve 로 실행하면 켜짐)";
+
+// 청크 인코딩 - 길이를 모르는 출력을 나오는 대로 보냄
+async fn chunk(stream: &mut TcpStream, text: &str) -> io::Result<()> {
+    if text.is_empty() {
+        return Ok(());
+    }
+    stream
+        .write_all(format!("{:x}\r\n{}\r\n", text.len(), text).as_bytes())
+        .await
+}
+
+async fn stream_run(stream: &mut TcpStream, names: &[String]) -> io::Result<()> {
+    stream
+        .write_all(
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\n\
+              Transfer-Encoding: chunked\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+        )
+        .await?;
+    for name in names {
+        chunk(stream, &format!("▶ cargo run --example {}\n", name)).await?;
+        // 브라우저가 연결을 끊으면 응답 쓰기가 실패하고 child 가 drop 되면서 종료됨
+        let mut child = Command::new(env!("CARGO"))
+            .args(["run", "--quiet", "--example", name])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+        let stdout = child.stdout.take().expect("stdout 은 piped");
+        let mut stderr = child.stderr.take().expect("stderr 은 piped");
+        // 빌드 경고/에러가 많아도 막히지 않도록 stderr 는 따로 읽음
+        let errors = tokio::spawn(async move {
+            let mut text = String::new();
+            let _ = stderr.read_to_string(&mut text).await;
+            text
+        });
+        let mut lines = BufReader::new(stdout).lines();
+        while let Some(line) = lines.next_line().await? {
+            chunk(stream, &format!("{}\n", line)).await?;
+        }
+        let status = child.wait().await?;
+        let errors = errors.await.unwrap_or_default();
+        if !status.success() {
+            chunk(stream, &format!("{}\n실행 실패 ({})\n", errors, status)).await?;
+        }
+        chunk(stream, "\n").await?;
+    }
+    stream.write_all(b"0\r\n\r\n").await
+}
+
+// 절마다 [{"name", "success", "stdout", "stderr"}]
+async fn run_json(names: &[String]) -> io::Result<String> {
+    let mut results = Vec::new();
+    for name in names {
+        let output = Command::new(env!("CARGO"))
+            .args(["run", "--quiet", "--example", name])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .kill_on_drop(true)
+            .output()
+            .await?;
+        results.push(serde_json::json!({
+            "name": name,
+            "success": output.status.success(),
+            "stdout": String::from_utf8_lossy(&output.stdout),
+            "stderr": String::from_utf8_lossy(&output.stderr),
+        }));
+    }
+    Ok(serde_json::Value::from(results).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(target: &str) -> (u16, String) {
+        match route("GET", target, "") {
+            Reply::Page { status, body } => (status, body),
+            other => panic!("페이지가 아님: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn index_lists_every_chapter() {
+        let (status, body) = page("/");
+        assert_eq!(status, 200);
+        for c in chapters::CHAPTERS {
+            assert!(body.contains(&format!("href=\"/lesson/{}\"", c.id)));
+        }
+    }
+
+    #[test]
+    fn lesson_page_has_navigation_and_run_button() {
+        let (status, body) = page("/lesson/traits");
+        assert_eq!(status, 200);
+        assert!(body.contains("<title>07. 트레이트 (Traits)</title>"));
+        assert!(body.contains("href=\"/lesson/06\""));
+        assert!(body.contains("href=\"/lesson/08\""));
+        assert!(body.contains("data-url=\"/run/07\""));
+        assert!(body.contains("<div class=\"comparison\">"));
+
+        assert_eq!(page("/lesson/99").0, 404);
+        assert_eq!(page("/nothing").0, 404);
+    }
+
+    #[test]
+    fn run_accepts_only_known_sections() {
+        let Reply::Run(names) = route("GET", "/run/07", "") else {
+            panic!("실행이어야 함");
+        };
+        assert_eq!(names[0], "07_basic_traits");
+        assert_eq!(
+            route("GET", "/run/07::operator_overloading", ""),
+            Reply::Run(vec![String::from("07_operator_overloading")])
+        );
+        assert!(matches!(
+            route("GET", "/run/07::no_such", ""),
+            Reply::Page { status: 404, .. }
+        ));
+        assert!(matches!(
+            route("GET", "/run/07;rm", ""),
+            Reply::Page { status: 404, .. }
+        ));
+    }
+
+    #[test]
+    fn search_finds_code_and_text() {
+        let hits = search("refcell");
+        assert!(hits.iter().any(|h| h.chapter == "12"));
+        assert!(search("  ").is_empty());
+
+        let (_, body) = page("/search?q=Rc%3A%3Anew");
+        assert!(body.contains("Rc::new"));
+        assert!(body.contains("href=\"/lesson/12\""));
+    }
+
+    #[test]
+    fn query_strings_are_decoded() {
+        assert_eq!(percent_decode("a+b%20c", true), "a b c");
+        assert_eq!(percent_decode("%ED%95%9C", true), "한");
+        assert_eq!(percent_decode("100%", true), "100%");
+        // 경로의 '+' 는 글자 그대로
+        assert_eq!(percent_decode("/lesson/c++%20ffi", false), "/lesson/c++ ffi");
+        assert_eq!(
+            query_param("x=1&q=Box%3Cdyn%3E", "q").as_deref(),
+            Some("Box<dyn>")
+        );
+    }
+
+    #[tokio::test]
+    async fn serves_pages_over_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(format!("GET /lesson/01 HTTP/1.1\r\nHost: localhost:{}\r\n\r\n", addr.port()).as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("<h1>01. "));
+
+        // 다른 사이트의 페이지가 보낸 POST - 본문을 읽기 전에 거절되어 진도에 기록되지 않음
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let body = r#"{"answers": {}}"#;
+        let request = format!(
+            "POST /quiz/02 HTTP/1.1\r\nHost: 127.0.0.1:{}\r\nOrigin: http://evil.example\r\nContent-Length: {}\r\n\r\n{}",
+            addr.port(),
+            body.len(),
+            body
+        );
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 403 Forbidden\r\n"), "{}", response);
+    }
+
+    #[test]
+    fn only_requests_from_this_server_may_act() {
+        let host = Some("127.0.0.1:8080");
+        assert!(local_request(host, None, None, 8080, true));
+        assert!(local_request(Some("localhost:8080"), Some("http://localhost:8080"), Some("same-origin"), 8080, true));
+        // DNS 리바인딩, 다른 포트
+        assert!(!local_request(Some("evil.example:8080"), None, None, 8080, false));
+        assert!(!local_request(Some("127.0.0.1:9000"), None, None, 8080, false));
+        assert!(!local_request(None, None, None, 8080, false));
+        // 다른 사이트에서 온 POST 와 실행 - 페이지 읽기(GET)는 괜찮음
+        assert!(!local_request(host, Some("http://evil.example"), None, 8080, true));
+        assert!(!local_request(host, None, Some("cross-site"), 8080, true));
+        assert!(local_request(host, None, Some("cross-site"), 8080, false));
+    }
+}
@@ -0,0 +1,81 @@
+// ============================================================================
+// 절 사이에서 멈추기 (--step)
+// ============================================================================
+// cargo run -- --step 11       절 하나를 실행할 때마다 "다음: 11::iterator_adaptors" 를 보여 주고 Enter 를 기다림
+//                              q 를 입력하면 남은 절을 실행하지 않고 끝 (요약은 그대로)
+//
+// 읽기 전에 출력이 화면 밖으로 밀려나지 않도록 - 그래서 페이저(모았다가 끝에 출력)는 쓰지 않음
+// 안내는 stderr 로 - --format json 이나 파이프로 받는 stdout 에 섞이지 않도록
+// 입력이 끝났으면(EOF) 더 기다리지 않고 끝까지 실행
+// ============================================================================
+
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+// 첫 절 앞에서는 멈추지 않음
+static STARTED: AtomicBool = AtomicBool::new(false);
+// q 를 입력함 - 남은 절은 실행하지 않음
+static STOPPED: AtomicBool = AtomicBool::new(false);
+
+// 명령줄의 --step 을 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> (bool, Vec<String>) {
+    let rest: Vec<String> = args.iter().filter(|a| *a != "--step").cloned().collect();
+    (rest.len() != args.len(), rest)
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn stopped() -> bool {
+    STOPPED.load(Ordering::Relaxed)
+}
+
+// 다음 절을 알리고 Enter 를 기다림 - q 면 false
+pub fn wait(input: &mut impl BufRead, out: &mut impl Write, next: &str) -> io::Result<bool> {
+    write!(out, "\n-- 다음: {} (Enter: 계속, q: 그만) ", next)?;
+    out.flush()?;
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        // EOF - 남은 절은 멈추지 않고 실행
+        ENABLED.store(false, Ordering::Relaxed);
+        writeln!(out)?;
+        return Ok(true);
+    }
+    Ok(!line.trim().eq_ignore_ascii_case("q"))
+}
+
+// 절을 실행하기 전 - 켜져 있으면 첫 절을 빼고 기다림, 그만두었으면 false
+pub fn before(chapter: &str, section: &str) -> bool {
+    if stopped() {
+        return false;
+    }
+    if !ENABLED.load(Ordering::Relaxed) || !STARTED.swap(true, Ordering::Relaxed) {
+        return true;
+    }
+    let next = format!("{}::{}", chapter, section);
+    // 입력을 읽을 수 없으면 멈추지 않고 계속
+    let go = wait(&mut io::stdin().lock(), &mut io::stderr(), &next).unwrap_or(true);
+    if !go {
+        STOPPED.store(true, Ordering::Relaxed);
+    }
+    go
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waits_for_enter_and_stops_on_q() {
+        let mut out = Vec::new();
+        assert!(wait(&mut "\n".as_bytes(), &mut out, "11::iterator_adaptors").unwrap());
+        assert!(String::from_utf8(out).unwrap().contains("다음: 11::iterator_adaptors"));
+        assert!(!wait(&mut "q\n".as_bytes(), &mut Vec::new(), "11::x").unwrap());
+        assert!(wait(&mut "".as_bytes(), &mut Vec::new(), "11::x").unwrap());
+
+        let args: Vec<String> = ["--step", "11"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(take_flag(&args), (true, vec!["11".to_string()]));
+    }
+}
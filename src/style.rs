@@ -0,0 +1,199 @@
+// ============================================================================
+// 출력 색상 (--theme dark|light|none)
+// ============================================================================
+// cargo run -- 07                    터미널이면 dark 테마로 색을 입힘
+// cargo run -- --theme light 07      밝은 배경용
+// NO_COLOR=1 cargo run -- 07         색 없이 (https://no-color.org), --theme none 과 같음
+// RUST_STUDY_THEME=light             환경 변수로도 지정 (--theme 이 우선)
+//
+// 장 모듈은 그대로 println! 을 쓰고, 모든 출력이 지나가는 i18n::write 에서 줄마다 종류를 보고 색을 입힘
+//   장 머리 "=== .. ==="  /  절 제목 "--- .. ---"  /  코드 (주석, 어트리뷰트, fn, 중괄호 ...)  /  에러 시연 (Err(, 에러, 실패, panic)
+// stdout 이 터미널이 아니면(파이프, 파일, browse 의 자식 프로세스) 색을 넣지 않음
+// ============================================================================
+
+use std::borrow::Cow;
+use std::env;
+use std::sync::Mutex;
+
+pub const THEME_VAR: &str = "RUST_STUDY_THEME";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    Dark,
+    Light,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Header,
+    Section,
+    Code,
+    Error,
+    Plain,
+}
+
+impl Theme {
+    pub fn parse(name: &str) -> Option<Theme> {
+        match name {
+            "dark" => Some(Theme::Dark),
+            "light" => Some(Theme::Light),
+            "none" => Some(Theme::None),
+            _ => None,
+        }
+    }
+
+    // 종류별 ANSI SGR 코드 - None 이면 색 없이
+    fn code(self, kind: Kind) -> Option<&'static str> {
+        match (self, kind) {
+            (Theme::None, _) | (_, Kind::Plain) => None,
+            (Theme::Dark, Kind::Header) => Some("1;96"),
+            (Theme::Dark, Kind::Section) => Some("1;93"),
+            (Theme::Dark, Kind::Code) => Some("92"),
+            (Theme::Dark, Kind::Error) => Some("91"),
+            (Theme::Light, Kind::Header) => Some("1;34"),
+            (Theme::Light, Kind::Section) => Some("1;35"),
+            (Theme::Light, Kind::Code) => Some("32"),
+            (Theme::Light, Kind::Error) => Some("31"),
+        }
+    }
+}
+
+// 기본은 색 없음 - main 이 터미널인지 확인한 뒤 init 으로 켬 (테스트, --format json 은 그대로)
+static THEME: Mutex<Theme> = Mutex::new(Theme::None);
+
+fn state() -> std::sync::MutexGuard<'static, Theme> {
+    THEME.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// 명령줄의 --theme <이름> 을 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> Result<(Option<Theme>, Vec<String>), String> {
+    let mut theme = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg != "--theme" {
+            rest.push(arg.clone());
+            continue;
+        }
+        let value = iter.next().ok_or("--theme 뒤에 dark, light, none 중 하나를 지정하세요")?;
+        theme = Some(Theme::parse(value).ok_or_else(|| format!("없는 테마: {} (dark, light, none)", value))?);
+    }
+    Ok((theme, rest))
+}
+
+// 쓸 테마 - NO_COLOR 가 가장 우선, 그다음 --theme, 환경 변수, 기본 dark
+pub fn choose(flag: Option<Theme>, env_theme: Option<&str>, no_color: bool, terminal: bool) -> Theme {
+    if no_color || !terminal {
+        return Theme::None;
+    }
+    flag.or_else(|| env_theme.and_then(Theme::parse)).unwrap_or(Theme::Dark)
+}
+
+pub fn init(flag: Option<Theme>, terminal: bool) {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let theme = choose(flag, env::var(THEME_VAR).ok().as_deref(), no_color, terminal);
+    *state() = theme;
+}
+
+const CODE_STARTS: &[&str] = &[
+    "//", "#[", "fn ", "pub ", "mod ", "use ", "impl", "let ", "struct ", "enum ", "trait ", "async ", "match ", "}",
+];
+
+// 출력 한 줄의 종류
+pub fn classify(line: &str) -> Kind {
+    let trimmed = line.trim();
+    if trimmed.starts_with("===") && trimmed.ends_with("===") && trimmed.len() > 6 {
+        Kind::Header
+    } else if trimmed.starts_with("---") && trimmed.ends_with("---") && trimmed.len() > 6 {
+        Kind::Section
+    } else if CODE_STARTS.iter().any(|s| trimmed.starts_with(s)) || trimmed.ends_with('{') || trimmed.ends_with(';') {
+        Kind::Code
+    } else if ["Err(", "에러", "실패", "panic"].iter().any(|w| trimmed.contains(w)) {
+        Kind::Error
+    } else {
+        Kind::Plain
+    }
+}
+
+// 줄마다 색을 입힘 (줄바꿈은 그대로)
+pub fn paint_with(theme: Theme, text: &str) -> String {
+    if theme == Theme::None {
+        return text.to_string();
+    }
+    text.split_inclusive('\n')
+        .map(|piece| {
+            let line = piece.trim_end_matches('\n');
+            match theme.code(classify(line)) {
+                Some(code) if !line.is_empty() => format!("\x1b[{}m{}\x1b[0m{}", code, line, &piece[line.len()..]),
+                _ => piece.to_string(),
+            }
+        })
+        .collect()
+}
+
+pub fn paint(text: &str) -> String {
+    paint_with(*state(), text)
+}
+
+// 색을 뺀 글 - 파일로 보낼 때 (--output)
+pub fn strip(text: &str) -> Cow<'_, str> {
+    if !text.contains('\x1b') {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        // ESC [ 숫자;숫자 m - 끝 글자(@ ~ ~)까지 건너뜀
+        if chars.next() == Some('[') {
+            for end in chars.by_ref() {
+                if ('@'..='~').contains(&end) {
+                    break;
+                }
+            }
+        }
+    }
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_lines() {
+        assert_eq!(classify("=== 07. 트레이트 ==="), Kind::Header);
+        assert_eq!(classify("--- 트레이트 객체 ---"), Kind::Section);
+        assert_eq!(classify("#[test]"), Kind::Code);
+        assert_eq!(classify("    assert_eq!(public_fn(), 42);"), Kind::Code);
+        assert_eq!(classify("map_err: Err(\"변환됨\")"), Kind::Error);
+        assert_eq!(classify("사람: 안녕하세요!"), Kind::Plain);
+        assert_eq!(classify("---"), Kind::Plain);
+    }
+
+    #[test]
+    fn paints_each_line_unless_disabled() {
+        let text = "\n=== 01. 기본 ===\n값: 5\n";
+        assert_eq!(paint_with(Theme::None, text), text);
+        assert_eq!(paint_with(Theme::Dark, text), "\n\x1b[1;96m=== 01. 기본 ===\x1b[0m\n값: 5\n");
+        assert_eq!(paint_with(Theme::Light, "에러: 없음"), "\x1b[31m에러: 없음\x1b[0m");
+        assert_eq!(strip(&paint_with(Theme::Dark, text)), text);
+    }
+
+    #[test]
+    fn no_color_and_pipes_win_over_theme() {
+        assert_eq!(choose(None, None, false, true), Theme::Dark);
+        assert_eq!(choose(None, Some("light"), false, true), Theme::Light);
+        assert_eq!(choose(Some(Theme::Dark), Some("light"), false, true), Theme::Dark);
+        assert_eq!(choose(Some(Theme::Light), None, true, true), Theme::None);
+        assert_eq!(choose(Some(Theme::Light), None, false, false), Theme::None);
+
+        let args: Vec<String> = ["--theme", "light", "07"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(take_flag(&args).unwrap(), (Some(Theme::Light), vec!["07".to_string()]));
+        assert!(take_flag(&["--theme".to_string(), "neon".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,93 @@
+// ============================================================================
+// 장 출력을 파일로 (--output, --tee)
+// ============================================================================
+// cargo run -- --output 07.txt 07          장 출력을 화면 대신 파일로
+// cargo run -- --output all.txt --tee all  화면에도 그대로, 파일에도 같이
+//
+// 장 모듈은 stdout 에 바로 쓰지 않고 모두 i18n::write → i18n::emit 을 지나므로
+// 장마다 writer 를 넘기는 대신 emit 에서 여기로 한 번 더 보냄 (스레드, tokio 작업의 출력도 같이)
+// 파일에는 색(ANSI 이스케이프)을 빼고 씀 - 나중에 편집기나 diff 로 볼 수 있도록
+// stderr(panic 메시지, --step 안내)는 파일에 넣지 않음
+// ============================================================================
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::style;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    pub path: PathBuf,
+    // true 면 화면에도 (--tee)
+    pub echo: bool,
+}
+
+// None = 파일로 보내지 않음
+static FILE: Mutex<Option<(File, bool)>> = Mutex::new(None);
+
+fn state() -> std::sync::MutexGuard<'static, Option<(File, bool)>> {
+    FILE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// 명령줄의 --output <파일>, --tee 를 빼고 나머지 인자를 돌려줌
+pub fn take_flags(args: &[String]) -> Result<(Option<Target>, Vec<String>), String> {
+    let mut path = None;
+    let mut echo = false;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--output" => {
+                let value = iter.next().ok_or("--output 뒤에 파일 경로를 지정하세요 (예: --output 07.txt)")?;
+                path = Some(PathBuf::from(value));
+            }
+            "--tee" => echo = true,
+            _ => rest.push(arg.clone()),
+        }
+    }
+    match path {
+        Some(path) => Ok((Some(Target { path, echo }), rest)),
+        None if echo => Err("--tee 는 --output <파일> 과 함께 쓰세요".to_string()),
+        None => Ok((None, rest)),
+    }
+}
+
+// 파일을 새로 만들고 지금부터의 출력을 보냄
+pub fn start(target: &Target) -> io::Result<()> {
+    let file = File::create(&target.path)?;
+    *state() = Some((file, target.echo));
+    Ok(())
+}
+
+// 파일로 보내는 중이면 쓰고, 화면에도 보여야 하면 true
+pub fn write(text: &str) -> bool {
+    let mut state = state();
+    let Some((file, echo)) = state.as_mut() else {
+        return true;
+    };
+    // 쓰다 실패하면 (디스크가 가득 참 등) 한 번 알리고 그만 - 화면에는 계속
+    if let Err(e) = file.write_all(style::strip(text).as_bytes()) {
+        eprintln!("출력 파일에 쓸 수 없습니다: {}", e);
+        *state = None;
+        return true;
+    }
+    *echo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_output_and_tee_flags() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let (target, rest) = take_flags(&args(&["--output", "out.txt", "--tee", "07"])).unwrap();
+        assert_eq!(target, Some(Target { path: PathBuf::from("out.txt"), echo: true }));
+        assert_eq!(rest, ["07"]);
+        assert_eq!(take_flags(&args(&["07"])).unwrap(), (None, args(&["07"])));
+        assert!(take_flags(&args(&["--tee", "07"])).is_err());
+        assert!(take_flags(&args(&["--output"])).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+// ============================================================================
+// 터미널 - 운영체제별 구현을 감춘 추상화 계층 (21장의 패턴)
+// ============================================================================
+// 터미널 크기와 ANSI 이스케이프 지원은 운영체제마다 묻는 방법이 다름
+//   유닉스   : ioctl(TIOCGWINSZ)                           - libc
+//   Windows  : GetConsoleScreenBufferInfo, SetConsoleMode  - windows-sys
+//   그 밖    : 알 수 없음 (환경 변수만)
+// Cargo.toml 의 [target.'cfg(unix)'.dependencies] 처럼 의존성도 운영체제별로만 받음
+// cfg 는 이 파일의 imp 모듈에만 두고, 바깥(doctor, render)은 size(), enable_ansi() 만 씀
+// ============================================================================
+
+use std::env;
+
+// (열, 행) - 환경 변수 COLUMNS/LINES 가 있으면 그 값이 우선 (사용자가 직접 지정한 크기)
+pub fn size() -> (Option<usize>, Option<usize>) {
+    let from_env = |k: &str| env::var(k).ok().and_then(|v| v.parse().ok());
+    let os = imp::size();
+    (
+        from_env("COLUMNS").or(os.map(|(c, _)| c)),
+        from_env("LINES").or(os.map(|(_, r)| r)),
+    )
+}
+
+// 터미널 폭 - 알 수 없으면(파이프, 파일) default
+pub fn columns_or(default: usize) -> usize {
+    size().0.unwrap_or(default)
+}
+
+// ANSI 이스케이프(색상, 커서 이동)를 쓸 수 있게 하고, 쓸 수 있는지 알려줌
+pub fn enable_ansi() -> bool {
+    imp::enable_ansi()
+}
+
+#[cfg(unix)]
+mod imp {
+    // stdout 이 파이프로 넘어가도 stderr/stdin 이 터미널이면 그 크기로
+    pub fn size() -> Option<(usize, usize)> {
+        [libc::STDOUT_FILENO, libc::STDERR_FILENO, libc::STDIN_FILENO]
+            .into_iter()
+            .find_map(|fd| {
+                // SAFETY: winsize 는 정수 네 개뿐이라 0 으로 채워도 유효한 값이고,
+                //         ioctl 은 넘겨준 구조체에만 씀 (fd 가 터미널이 아니면 -1 을 돌려줄 뿐)
+                let mut ws: libc::winsize = unsafe { std::mem::zeroed() };
+                let ok = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut ws) } == 0;
+                (ok && ws.ws_col > 0).then_some((ws.ws_col as usize, ws.ws_row as usize))
+            })
+    }
+
+    // 유닉스 터미널은 ANSI 이스케이프를 그대로 해석
+    pub fn enable_ansi() -> bool {
+        true
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use windows_sys::Win32::System::Console::{
+        GetConsoleMode, GetConsoleScreenBufferInfo, GetStdHandle, SetConsoleMode,
+        CONSOLE_SCREEN_BUFFER_INFO, ENABLE_VIRTUAL_TERMINAL_PROCESSING, STD_OUTPUT_HANDLE,
+    };
+
+    // 버퍼 전체(dwSize)가 아니라 보이는 창(srWindow)의 크기
+    pub fn size() -> Option<(usize, usize)> {
+        // SAFETY: GetStdHandle 은 핸들을 돌려주기만 하고, CONSOLE_SCREEN_BUFFER_INFO 는
+        //         정수로만 된 C 구조체라 0 으로 채워도 유효 - 실패하면 0 을 돌려줌
+        unsafe {
+            let handle = GetStdHandle(STD_OUTPUT_HANDLE);
+            let mut info: CONSOLE_SCREEN_BUFFER_INFO = std::mem::zeroed();
+            if GetConsoleScreenBufferInfo(handle, &mut info) == 0 {
+                return None;
+            }
+            let w = info.srWindow;
+            Some(((w.Right - w.Left + 1) as usize, (w.Bottom - w.Top + 1) as usize))
+        }
+    }
+
+    // Windows 10 이후 콘솔은 모드를 켜야 ANSI 이스케이프를 해석 - 옛 콘솔이나 리디렉션이면 false
+    pub fn enable_ansi() -> bool {
+        // SAFETY: 핸들은 GetStdHandle 이 준 그대로, mode 는 살아 있는 지역 변수
+        unsafe {
+            let handle = GetStdHandle(STD_OUTPUT_HANDLE);
+            let mut mode = 0;
+            if GetConsoleMode(handle, &mut mode) == 0 {
+                return false;
+            }
+            mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0
+                || SetConsoleMode(handle, mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0
+        }
+    }
+}
+
+// 유닉스도 Windows 도 아닌 대상 - 물어볼 방법이 없으니 모른다고 답함
+#[cfg(not(any(unix, windows)))]
+mod imp {
+    pub fn size() -> Option<(usize, usize)> {
+        None
+    }
+
+    pub fn enable_ansi() -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 테스트는 보통 터미널 없이 돌아가므로 값 자체보다 형태만 확인
+    #[test]
+    fn size_is_positive_when_known() {
+        if let Some((columns, _)) = imp::size() {
+            assert!(columns > 0);
+        }
+        assert!(columns_or(100) > 0);
+        // 유닉스 터미널은 항상 ANSI 를 해석
+        assert!(!cfg!(unix) || enable_ansi());
+    }
+}
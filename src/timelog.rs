@@ -0,0 +1,251 @@
+// ============================================================================
+// 학습 시간 기록 (선택 사항)
+// ============================================================================
+// cargo run -- time-track on     기록 시작 (기본은 꺼짐)
+// cargo run -- time-report       일별 / --weekly 주별 학습 시간
+//
+// 퀴즈, 레슨 팩 실행 등 활동마다 벽시계 시간을 .rust-study/time.jsonl 에 한 줄씩 추가 (프로필마다 따로)
+// 이 컴퓨터에만 저장되고 어디에도 전송하지 않음
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use crate::{profile, progress};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub chapter: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    // "quiz", "lesson", "run" 등
+    pub activity: String,
+    // 시작 시각 (유닉스 초)
+    pub start: u64,
+    pub secs: u64,
+}
+
+fn log_path() -> PathBuf {
+    profile::dir().join("time.jsonl")
+}
+
+// 기록 동의 표시 - 파일이 있으면 켜짐
+fn flag_path() -> PathBuf {
+    profile::dir().join("time-tracking.on")
+}
+
+pub fn enabled() -> bool {
+    flag_path().exists()
+}
+
+pub fn set_enabled(on: bool) -> io::Result<()> {
+    if on {
+        fs::create_dir_all(profile::dir())?;
+        fs::write(flag_path(), b"")
+    } else {
+        match fs::remove_file(flag_path()) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+// 활동 하나의 시간을 재는 가드 - 스코프를 벗어날 때 기록 (18장 스코프 가드와 같은 RAII)
+// 기록이 꺼져 있으면 아무것도 하지 않음
+pub struct Tracker {
+    entry: Option<Entry>,
+    started: Instant,
+}
+
+impl Tracker {
+    pub fn start(chapter: &str, section: Option<&str>, activity: &str) -> Tracker {
+        let entry = enabled().then(|| Entry {
+            chapter: chapter.to_string(),
+            section: section.map(str::to_string),
+            activity: activity.to_string(),
+            start: progress::now(),
+            secs: 0,
+        });
+        Tracker {
+            entry,
+            started: Instant::now(),
+        }
+    }
+}
+
+impl Drop for Tracker {
+    fn drop(&mut self) {
+        if let Some(mut entry) = self.entry.take() {
+            entry.secs = self.started.elapsed().as_secs();
+            // 기록 실패로 학습을 방해하지 않음 - 경고만
+            if let Err(e) = append(&entry) {
+                eprintln!("학습 시간 기록 실패: {}", e);
+            }
+        }
+    }
+}
+
+fn append(entry: &Entry) -> io::Result<()> {
+    fs::create_dir_all(profile::dir())?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path())?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)
+}
+
+// 깨진 줄은 건너뜀 - 쓰는 도중 종료된 마지막 줄 등
+pub fn load() -> io::Result<Vec<Entry>> {
+    match fs::read_to_string(log_path()) {
+        Ok(text) => Ok(text
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 집계
+// ----------------------------------------------------------------------------
+
+// 유닉스 일 수 → (연, 월, 일) - 그레고리력 (Howard Hinnant 의 civil_from_days)
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    (y, m, d)
+}
+
+fn date_label(days: i64) -> String {
+    let (y, m, d) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    Daily,
+    Weekly,
+}
+
+// 기간 라벨 → (장 → 초)
+// 날짜는 UTC 기준, 주는 월요일 시작 (1970-01-01 은 목요일)
+pub fn breakdown(entries: &[Entry], period: Period) -> BTreeMap<String, BTreeMap<String, u64>> {
+    let mut out: BTreeMap<String, BTreeMap<String, u64>> = BTreeMap::new();
+    for e in entries {
+        let days = (e.start / 86_400) as i64;
+        let label = match period {
+            Period::Daily => date_label(days),
+            Period::Weekly => {
+                let monday = days - (days + 3).rem_euclid(7);
+                format!("{} 주", date_label(monday))
+            }
+        };
+        *out.entry(label)
+            .or_default()
+            .entry(e.chapter.clone())
+            .or_default() += e.secs;
+    }
+    out
+}
+
+// 장/절별 누적 시간 - 많은 순
+pub fn by_topic(entries: &[Entry]) -> Vec<(String, u64)> {
+    let mut totals: BTreeMap<String, u64> = BTreeMap::new();
+    for e in entries {
+        let key = match &e.section {
+            Some(section) => format!("{}::{}", e.chapter, section),
+            None => e.chapter.clone(),
+        };
+        *totals.entry(key).or_default() += e.secs;
+    }
+    let mut list: Vec<(String, u64)> = totals.into_iter().collect();
+    list.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    list
+}
+
+// 3725 → "1시간 2분"
+pub fn format_duration(secs: u64) -> String {
+    let (h, m, s) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    match (h, m) {
+        (0, 0) => format!("{}초", s),
+        (0, _) => format!("{}분", m),
+        _ => format!("{}시간 {}분", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(chapter: &str, section: Option<&str>, start: u64, secs: u64) -> Entry {
+        Entry {
+            chapter: chapter.to_string(),
+            section: section.map(str::to_string),
+            activity: "quiz".to_string(),
+            start,
+            secs,
+        }
+    }
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn daily_and_weekly_breakdowns() {
+        // 2024-02-29(목) 두 번, 2024-03-04(월) 한 번
+        let day = 19_782 * 86_400;
+        let entries = vec![
+            entry("07", None, day + 100, 600),
+            entry("07", None, day + 5000, 300),
+            entry("13", None, day + 4 * 86_400, 1200),
+        ];
+
+        let daily = breakdown(&entries, Period::Daily);
+        assert_eq!(daily["2024-02-29"]["07"], 900);
+        assert_eq!(daily["2024-03-04"]["13"], 1200);
+
+        let weekly = breakdown(&entries, Period::Weekly);
+        assert_eq!(weekly.len(), 2);
+        assert_eq!(weekly["2024-02-26 주"]["07"], 900);
+        assert_eq!(weekly["2024-03-04 주"]["13"], 1200);
+    }
+
+    #[test]
+    fn topics_sorted_by_time() {
+        let entries = vec![
+            entry("07", None, 0, 10),
+            entry("11", Some("custom_iterator"), 0, 50),
+            entry("07", None, 0, 15),
+        ];
+        assert_eq!(
+            by_topic(&entries),
+            vec![
+                ("11::custom_iterator".to_string(), 50),
+                ("07".to_string(), 25)
+            ]
+        );
+    }
+
+    #[test]
+    fn durations_are_readable() {
+        assert_eq!(format_duration(42), "42초");
+        assert_eq!(format_duration(125), "2분");
+        assert_eq!(format_duration(3725), "1시간 2분");
+    }
+}
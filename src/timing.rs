@@ -0,0 +1,153 @@
+// ============================================================================
+// 장/절 실행 시간 (--timing)
+// ============================================================================
+// cargo run -- --timing all          모든 장을 실행하고 끝에 장별, 절별 시간 표
+// cargo run -- --timing 13 17        고른 장만
+// cargo run -- --timing 17:select_example  절 하나만
+//
+// 장은 runner.rs 가 절마다 Instant 로 재면서 실행하고, 켜져 있으면 그 시간을 여기에 기록
+// 비동기(17장), 동시성(13장) 예제처럼 오래 걸리는 절을 찾는 용도 - 벤치마크가 아님
+// 학습 시간 기록(timelog)과 달리 파일에 남기지 않고 이번 실행의 표만 출력
+// ============================================================================
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use rust_study::output;
+
+use crate::chapter::Chapter;
+use crate::i18n;
+
+// 가장 오래 걸린 절을 몇 개 보여 줄지
+const SLOWEST: usize = 5;
+const BAR_WIDTH: usize = 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub chapter: &'static str,
+    pub name: &'static str,
+    // (절 이름, 걸린 시간) - 실행 순서
+    pub sections: Vec<(&'static str, Duration)>,
+    pub elapsed: Duration,
+}
+
+// None = 꺼짐, Some = 이번 실행에서 잰 장들
+static RECORDS: Mutex<Option<Vec<Record>>> = Mutex::new(None);
+
+fn state() -> std::sync::MutexGuard<'static, Option<Vec<Record>>> {
+    RECORDS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn enable() {
+    state().get_or_insert_with(Vec::new);
+}
+
+// 명령줄의 --timing 을 빼고 나머지 인자를 돌려줌
+pub fn take_flag(args: &[String]) -> (bool, Vec<String>) {
+    let rest: Vec<String> = args.iter().filter(|a| *a != "--timing").cloned().collect();
+    (rest.len() != args.len(), rest)
+}
+
+// 장 하나(또는 절 하나만)를 실행한 시간 - 꺼져 있으면 버림
+pub fn record(chapter: &dyn Chapter, sections: Vec<(&'static str, Duration)>, elapsed: Duration) {
+    if let Some(records) = state().as_mut() {
+        records.push(Record {
+            chapter: chapter.id(),
+            name: chapter.name(),
+            sections,
+            elapsed,
+        });
+    }
+}
+
+// 한글은 두 칸으로 세어 열을 맞춤 (bench 의 표와 같은 방식)
+fn pad(text: &str, width: usize) -> String {
+    let used: usize = text.chars().map(|c| if c.is_ascii() { 1 } else { 2 }).sum();
+    format!("{}{}", text, " ".repeat(width.saturating_sub(used)))
+}
+
+fn millis(d: Duration) -> String {
+    format!("{:.1} ms", d.as_secs_f64() * 1000.0)
+}
+
+fn percent(part: Duration, total: Duration) -> String {
+    if total.is_zero() {
+        return String::from("-");
+    }
+    format!("{:.1}%", part.as_secs_f64() / total.as_secs_f64() * 100.0)
+}
+
+// 장마다 시간, 비율, 막대와 절별 시간 - 끝에 가장 오래 걸린 절
+pub fn summary(records: &[Record]) -> String {
+    let total: Duration = records.iter().map(|r| r.elapsed).sum();
+    let max = records.iter().map(|r| r.elapsed).max().unwrap_or_default();
+    let mut out = tr!("\n=== 실행 시간 (--timing) ===\n");
+    for r in records {
+        out.push_str(&format!(
+            "{:>2}. {} {:>10} {:>6}  {}\n",
+            r.chapter,
+            pad(&i18n::chapter_name(r.chapter, r.name), 32),
+            millis(r.elapsed),
+            percent(r.elapsed, total),
+            output::bar(r.elapsed.as_micros() as u64, max.as_micros() as u64, BAR_WIDTH)
+        ));
+        for (name, elapsed) in &r.sections {
+            out.push_str(&format!("      {:<30} {:>10} {:>6}\n", name, millis(*elapsed), percent(*elapsed, total)));
+        }
+    }
+    out.push_str(&tr!("합계 {}개 장 {}\n", records.len(), millis(total)));
+
+    let mut sections: Vec<(String, Duration)> = records
+        .iter()
+        .flat_map(|r| r.sections.iter().map(|(name, d)| (format!("{}::{}", r.chapter, name), *d)))
+        .collect();
+    if sections.len() > 1 {
+        sections.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        out.push_str(&tr!("\n가장 오래 걸린 절\n"));
+        for (i, (key, elapsed)) in sections.iter().take(SLOWEST).enumerate() {
+            out.push_str(&format!("  {}. {:<32} {:>10} {:>6}\n", i + 1, key, millis(*elapsed), percent(*elapsed, total)));
+        }
+    }
+    out
+}
+
+// 켜져 있고 잰 것이 있으면 표 출력
+pub fn print_summary() {
+    let records = state().clone().unwrap_or_default();
+    if !records.is_empty() {
+        print!("{}", summary(&records));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_timing_flag() {
+        let args: Vec<String> = ["--timing", "07", "--seed", "1"].iter().map(|s| s.to_string()).collect();
+        let (on, rest) = take_flag(&args);
+        assert!(on);
+        assert_eq!(rest, ["07", "--seed", "1"]);
+        assert!(!take_flag(&rest).0);
+    }
+
+    #[test]
+    fn summary_lists_chapters_sections_and_slowest() {
+        let ms = Duration::from_millis;
+        let records = vec![
+            Record { chapter: "13", name: "동시성", sections: vec![("threads", ms(30)), ("channels", ms(10))], elapsed: ms(40) },
+            Record { chapter: "17", name: "비동기", sections: vec![("select", ms(60))], elapsed: ms(60) },
+        ];
+        let text = summary(&records);
+        assert!(text.contains("13. 동시성"));
+        assert!(text.contains("40.0 ms  40.0%"));
+        assert!(text.contains("합계 2개 장 100.0 ms"));
+        let slowest = text.split("가장 오래 걸린 절").nth(1).unwrap();
+        let order: Vec<usize> = ["17::select", "13::threads", "13::channels"].iter().map(|k| slowest.find(k).unwrap()).collect();
+        assert!(order.windows(2).all(|w| w[0] < w[1]));
+
+        // 절 하나만 실행했으면 순위는 생략
+        assert!(!summary(&records[1..]).contains("가장 오래 걸린 절"));
+    }
+}
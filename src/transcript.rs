@@ -0,0 +1,275 @@
+// ============================================================================
+// 세션 녹화와 재생
+// ============================================================================
+//   cargo run -- record session.json quiz 07     명령을 실행하며 입출력을 녹화
+//   cargo run -- replay session.json [--speed 2]  원래 속도로 재생
+//
+// 명령을 자식 프로세스로 실행하고 stdin/stdout/stderr 를 가로채서 시각과 함께 기록
+// → 장 실행, 퀴즈 답, lessons run 등 어떤 명령이든 같은 방식으로 녹화됨
+// ============================================================================
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, BufRead, Read, Write};
+use std::path::Path;
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stream {
+    In,
+    Out,
+    Err,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    // 녹화 시작부터의 경과 시간 (밀리초)
+    pub at_ms: u64,
+    pub stream: Stream,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transcript {
+    pub command: Vec<String>,
+    // 유닉스 시각 (초)
+    pub started_at: u64,
+    pub exit_code: Option<i32>,
+    pub events: Vec<Event>,
+}
+
+impl Transcript {
+    pub fn load(path: &Path) -> io::Result<Transcript> {
+        let text = fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.events.last().map_or(0, |e| e.at_ms))
+    }
+
+    // 학습자가 입력한 줄 (퀴즈 답 등)
+    pub fn inputs(&self) -> impl Iterator<Item = &str> {
+        self.events
+            .iter()
+            .filter(|e| e.stream == Stream::In)
+            .map(|e| e.text.trim_end())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// 녹화
+// ----------------------------------------------------------------------------
+
+// 바이트 조각을 UTF-8 문자 경계에서 잘라 문자열로 - 한글이 조각 사이에 걸쳐도 깨지지 않음
+#[derive(Default)]
+struct Utf8Chunker {
+    pending: Vec<u8>,
+}
+
+impl Utf8Chunker {
+    fn push(&mut self, bytes: &[u8]) -> String {
+        self.pending.extend_from_slice(bytes);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(_) => self.pending.len(),
+            // 끝에 잘린 문자만 남김 - 중간의 잘못된 바이트는 그대로 손실 변환
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => self.pending.len(),
+        };
+        let rest = self.pending.split_off(valid);
+        let text = String::from_utf8_lossy(&self.pending).into_owned();
+        self.pending = rest;
+        text
+    }
+}
+
+// 자식의 출력 스트림을 읽어 화면에 그대로 쓰면서 이벤트로 보냄
+fn pump<R: Read + Send + 'static>(
+    mut reader: R,
+    stream: Stream,
+    start: Instant,
+    events: mpsc::Sender<Event>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut chunker = Utf8Chunker::default();
+        let mut buf = [0u8; 4096];
+        loop {
+            let n = match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => n,
+            };
+            let _ = match stream {
+                Stream::Err => io::stderr()
+                    .write_all(&buf[..n])
+                    .and_then(|_| io::stderr().flush()),
+                _ => io::stdout()
+                    .write_all(&buf[..n])
+                    .and_then(|_| io::stdout().flush()),
+            };
+            let text = chunker.push(&buf[..n]);
+            if !text.is_empty() {
+                let at_ms = start.elapsed().as_millis() as u64;
+                let _ = events.send(Event {
+                    at_ms,
+                    stream,
+                    text,
+                });
+            }
+        }
+    })
+}
+
+// 같은 바이너리를 args 로 다시 실행하며 녹화
+pub fn record(args: &[String]) -> io::Result<(Transcript, ExitStatus)> {
+    let exe = std::env::current_exe()?;
+    let mut child = Command::new(exe)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let start = Instant::now();
+    let started_at = crate::progress::now();
+    let (tx, rx) = mpsc::channel();
+
+    let out = pump(
+        child.stdout.take().expect("piped"),
+        Stream::Out,
+        start,
+        tx.clone(),
+    );
+    let err = pump(
+        child.stderr.take().expect("piped"),
+        Stream::Err,
+        start,
+        tx.clone(),
+    );
+
+    // stdin 은 줄 단위로 전달 - 자식이 끝난 뒤에도 read_line 에서 막혀 있을 수 있으므로 join 하지 않음
+    let mut child_in = child.stdin.take().expect("piped");
+    thread::spawn(move || {
+        let stdin = io::stdin();
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match stdin.lock().read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+            let at_ms = start.elapsed().as_millis() as u64;
+            let _ = tx.send(Event {
+                at_ms,
+                stream: Stream::In,
+                text: line.clone(),
+            });
+            if child_in.write_all(line.as_bytes()).is_err() {
+                break;
+            }
+        }
+    });
+
+    let status = child.wait()?;
+    let _ = out.join();
+    let _ = err.join();
+
+    let mut events: Vec<Event> = rx.try_iter().collect();
+    events.sort_by_key(|e| e.at_ms);
+    let transcript = Transcript {
+        command: args.to_vec(),
+        started_at,
+        exit_code: status.code(),
+        events,
+    };
+    Ok((transcript, status))
+}
+
+// ----------------------------------------------------------------------------
+// 재생
+// ----------------------------------------------------------------------------
+
+// 이보다 긴 공백은 줄여서 재생 - 학습자가 자리를 비운 시간까지 기다리지 않음
+const MAX_PAUSE: Duration = Duration::from_secs(3);
+
+// speed: 1.0 = 원래 속도, None = 기다리지 않고 바로 출력
+pub fn replay<W: Write>(
+    transcript: &Transcript,
+    speed: Option<f64>,
+    out: &mut W,
+) -> io::Result<()> {
+    let mut last = 0;
+    for event in &transcript.events {
+        if let Some(speed) = speed.filter(|s| *s > 0.0) {
+            let gap = Duration::from_millis(event.at_ms.saturating_sub(last));
+            thread::sleep(gap.min(MAX_PAUSE).div_f64(speed));
+        }
+        last = event.at_ms;
+
+        // 입력도 터미널에서 에코되던 그대로 보여줌
+        write!(out, "{}", event.text)?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Transcript {
+        let event = |at_ms, stream, text: &str| Event {
+            at_ms,
+            stream,
+            text: text.to_string(),
+        };
+        Transcript {
+            command: vec!["quiz".to_string(), "07".to_string()],
+            started_at: 0,
+            exit_code: Some(0),
+            events: vec![
+                event(0, Stream::Out, "[1/1] 질문\n답: "),
+                event(1500, Stream::In, "2\n"),
+                event(1501, Stream::Out, "정답!\n"),
+            ],
+        }
+    }
+
+    #[test]
+    fn chunker_keeps_split_multibyte_chars() {
+        let bytes = "정답".as_bytes();
+        let mut c = Utf8Chunker::default();
+        assert_eq!(c.push(&bytes[..4]), "정"); // '답' 의 첫 바이트만 들어옴
+        assert_eq!(c.push(&bytes[4..]), "답");
+    }
+
+    #[test]
+    fn replay_without_pacing_reproduces_session() {
+        let mut out = Vec::new();
+        replay(&sample(), None, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[1/1] 질문\n답: 2\n정답!\n"
+        );
+    }
+
+    #[test]
+    fn save_load_and_inputs() {
+        let path =
+            std::env::temp_dir().join(format!("transcript-test-{}.json", std::process::id()));
+        let t = sample();
+        t.save(&path).unwrap();
+        let loaded = Transcript::load(&path).unwrap();
+        assert_eq!(loaded, t);
+        assert_eq!(loaded.inputs().collect::<Vec<_>>(), vec!["2"]);
+        assert_eq!(loaded.duration(), Duration::from_millis(1501));
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,314 @@
+// ============================================================================
+// 터미널 장 탐색기 (ratatui + crossterm)
+// ============================================================================
+// cargo run -- browse
+//   왼쪽 위   장 목록          ↑/↓ (k/j) 로 고르기
+//   왼쪽 아래 고른 장의 절 이름
+//   오른쪽    장 실행 출력      Enter 로 실행, PgUp/PgDn (u/d) 스크롤, Home/End
+//   q, Esc    종료
+//
+// 장의 절은 println! 으로 바로 stdout 에 쓰므로 화면을 깨뜨리지 않도록
+// 이 바이너리를 `rust-study <장>` 으로 다시 실행하고 그 출력을 줄 단위로 받아서 흘려 보여줌
+// (출력이 끝날 때까지 기다리지 않음 - 20장 채팅 서버처럼 오래 걸리는 장도 바로 보임)
+// ============================================================================
+
+use std::io::{self, BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::chapters::{self, ChapterInfo};
+use crate::sections;
+
+// 화면에 필요한 상태 - 그리기와 키 처리를 터미널 없이 테스트할 수 있도록 분리
+pub struct App {
+    selected: usize,
+    sections: Vec<String>,
+    // 실행 중이거나 마지막으로 실행한 장
+    running: Option<&'static ChapterInfo>,
+    output: Vec<String>,
+    // 출력 창 맨 위에 보이는 줄
+    scroll: usize,
+    // 새 줄이 오면 맨 아래로 따라감 - 위로 스크롤하면 꺼짐
+    follow: bool,
+    finished: bool,
+}
+
+// 키 처리 결과 - 실행과 종료는 터미널/프로세스를 다루는 바깥 루프가 처리
+#[derive(Debug, PartialEq, Eq)]
+pub enum Action {
+    None,
+    Run(&'static str),
+    Quit,
+}
+
+impl App {
+    pub fn new() -> App {
+        let mut app = App {
+            selected: 0,
+            sections: Vec::new(),
+            running: None,
+            output: Vec::new(),
+            scroll: 0,
+            follow: true,
+            finished: false,
+        };
+        app.select(0);
+        app
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index.min(chapters::CHAPTERS.len() - 1);
+        self.sections = sections::sections(&chapters::CHAPTERS[self.selected])
+            .into_iter()
+            .map(|s| s.title)
+            .collect();
+    }
+
+    pub fn selected(&self) -> &'static ChapterInfo {
+        &chapters::CHAPTERS[self.selected]
+    }
+
+    // page: 출력 창에 보이는 줄 수 (스크롤 단위)
+    pub fn handle_key(&mut self, key: KeyCode, page: usize) -> Action {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Up | KeyCode::Char('k') => self.select(self.selected.saturating_sub(1)),
+            KeyCode::Down | KeyCode::Char('j') => self.select(self.selected + 1),
+            KeyCode::Enter => return Action::Run(self.selected().id),
+            KeyCode::PageUp | KeyCode::Char('u') => self.scroll_to(self.scroll.saturating_sub(page), page),
+            KeyCode::PageDown | KeyCode::Char('d') => self.scroll_to(self.scroll + page, page),
+            KeyCode::Home => self.scroll_to(0, page),
+            KeyCode::End => self.scroll_to(usize::MAX, page),
+            _ => {}
+        }
+        Action::None
+    }
+
+    fn max_scroll(&self, page: usize) -> usize {
+        self.output.len().saturating_sub(page)
+    }
+
+    fn scroll_to(&mut self, line: usize, page: usize) {
+        self.scroll = line.min(self.max_scroll(page));
+        self.follow = self.scroll == self.max_scroll(page);
+    }
+
+    pub fn start(&mut self, info: &'static ChapterInfo) {
+        self.running = Some(info);
+        self.output.clear();
+        self.scroll = 0;
+        self.follow = true;
+        self.finished = false;
+    }
+
+    pub fn push_line(&mut self, line: String, page: usize) {
+        self.output.push(line);
+        if self.follow {
+            self.scroll = self.max_scroll(page);
+        }
+    }
+
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let [sidebar, main] =
+            Layout::horizontal([Constraint::Length(34), Constraint::Min(20)]).areas(frame.area());
+        let [list_area, sections_area] =
+            Layout::vertical([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(sidebar);
+
+        let items: Vec<ListItem> = chapters::CHAPTERS
+            .iter()
+            .map(|c| ListItem::new(format!("{} {}", c.id, c.title)))
+            .collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(" 장 (↑↓, Enter) "))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("▶ ");
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, list_area, &mut state);
+
+        let section_lines: Vec<Line> = self.sections.iter().map(|t| Line::from(format!("· {}", t))).collect();
+        frame.render_widget(
+            Paragraph::new(section_lines).block(Block::bordered().title(" 절 ")),
+            sections_area,
+        );
+
+        let title = match self.running {
+            None => String::from(" 출력 - Enter 로 장 실행, q 종료 "),
+            Some(info) if self.finished => format!(" {}장 출력 (끝, {}줄) - PgUp/PgDn ", info.id, self.output.len()),
+            Some(info) => format!(" {}장 실행 중... ", info.id),
+        };
+        let page = Self::page(main);
+        let lines: Vec<Line> = self
+            .output
+            .iter()
+            .skip(self.scroll)
+            .take(page)
+            .map(|l| Line::from(l.as_str()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered().title(title)), main);
+    }
+
+    // 출력 창 안쪽 높이 (테두리 두 줄 제외)
+    fn page(area: Rect) -> usize {
+        area.height.saturating_sub(2).max(1) as usize
+    }
+}
+
+// 장을 자식 프로세스로 실행하고 stdout/stderr 를 줄 단위로 채널에 흘려 보냄
+fn spawn_chapter(id: &str) -> io::Result<(Child, Receiver<String>)> {
+    let mut child = Command::new(std::env::current_exe()?)
+        .arg(id)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let (tx, rx) = mpsc::channel();
+    let stdout = child.stdout.take().map(|s| Box::new(s) as Box<dyn io::Read + Send>);
+    let stderr = child.stderr.take().map(|s| Box::new(s) as Box<dyn io::Read + Send>);
+    for stream in [stdout, stderr].into_iter().flatten() {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                // 받는 쪽(화면)이 먼저 끝나면 그만 읽음
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+    // 두 스레드가 모두 끝나면 보내는 쪽이 모두 drop 되어 Disconnected - 실행이 끝났다는 신호
+    Ok((child, rx))
+}
+
+pub fn run() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut App::new());
+    // 에러가 나도 터미널은 원래 상태로 (raw 모드 해제, 대체 화면 종료)
+    ratatui::restore();
+    result
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()> {
+    let mut child: Option<(Child, Receiver<String>)> = None;
+    let result = loop {
+        let page = App::page(terminal.get_frame().area()).max(1);
+        if let Some((_, rx)) = &child {
+            loop {
+                match rx.try_recv() {
+                    Ok(line) => app.push_line(line, page),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        app.finish();
+                        if let Some((mut process, _)) = child.take() {
+                            let _ = process.wait();
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+        terminal.draw(|frame| app.draw(frame))?;
+
+        // 50ms 마다 깨어나서 자식의 새 출력을 반영
+        if !event::poll(Duration::from_millis(50))? {
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match app.handle_key(key.code, page) {
+            Action::None => {}
+            Action::Quit => break Ok(()),
+            Action::Run(id) => {
+                // 실행 중인 장이 있으면 멈추고 새 장으로
+                if let Some((mut process, _)) = child.take() {
+                    let _ = process.kill();
+                    let _ = process.wait();
+                }
+                app.start(app.selected());
+                match spawn_chapter(id) {
+                    Ok(spawned) => child = Some(spawned),
+                    Err(e) => {
+                        app.push_line(format!("실행할 수 없습니다: {}", e), page);
+                        app.finish();
+                    }
+                }
+            }
+        }
+    };
+    if let Some((mut process, _)) = child {
+        let _ = process.kill();
+        let _ = process.wait();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn keys_move_selection_and_scroll_output() {
+        let mut app = App::new();
+        assert_eq!(app.handle_key(KeyCode::Up, 10), Action::None);
+        assert_eq!(app.selected().id, "01");
+        app.handle_key(KeyCode::Down, 10);
+        app.handle_key(KeyCode::Char('j'), 10);
+        assert_eq!(app.handle_key(KeyCode::Enter, 10), Action::Run("03"));
+        assert!(!app.sections.is_empty());
+
+        app.start(app.selected());
+        for n in 0..30 {
+            app.push_line(format!("줄 {}", n), 10);
+        }
+        // 따라가는 중 - 마지막 줄이 보이도록
+        assert_eq!(app.scroll, 20);
+        app.handle_key(KeyCode::PageUp, 10);
+        assert_eq!(app.scroll, 10);
+        // 위로 스크롤한 뒤에는 새 줄이 와도 그 자리에
+        app.push_line(String::from("새 줄"), 10);
+        assert_eq!(app.scroll, 10);
+        app.handle_key(KeyCode::End, 10);
+        assert_eq!(app.scroll, 21);
+        assert_eq!(app.handle_key(KeyCode::Char('q'), 10), Action::Quit);
+    }
+
+    #[test]
+    fn draws_chapters_sections_and_output() {
+        let mut app = App::new();
+        app.start(app.selected());
+        app.push_line(String::from("=== 01. 기본 문법 ==="), 10);
+        app.finish();
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            // 한글은 두 칸 - 뒤 칸이 공백으로 채워지므로 공백을 빼고 비교
+            .filter(|s| !s.trim().is_empty())
+            .collect();
+        assert!(screen.contains("01장출력(끝"));
+        assert!(screen.contains("===01."));
+        assert!(screen.contains("▶01"));
+    }
+}
@@ -0,0 +1,99 @@
+// ============================================================================
+// 장 소스를 고칠 때마다 다시 실행
+// ============================================================================
+// cargo run -- watch 11                  src/_11_iterators.rs 를 저장할 때마다 11장 실행
+// cargo run -- watch 07:trait_objects    절 하나만 다시 실행
+//
+// 장은 이 프로그램 안에 컴파일되어 있으므로 바뀐 소스를 보려면 다시 빌드해야 함
+// → 변경을 알림(notify) 받으면 cargo run --quiet -- <장> 을 자식 프로세스로 실행
+//   빌드 에러도 그대로 보이고, 고치고 저장하면 다시 시도
+// 자식은 target/watch 에 따로 빌드 - 실행 중인 이 프로그램 파일을 덮어쓰지 않도록 (Windows 는 실행 중인 exe 를 못 바꿈)
+// 편집기는 저장할 때 파일을 새로 만들어 바꾸기도 하므로 파일이 아니라 src/ 를 감시하고 이름으로 거름
+// render --watch 는 내장되지 않은 설명 글만 보므로 시각 비교(폴링)로 충분 - 여기는 빌드가 무거워 변경 알림
+// ============================================================================
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use rust_study::chapters::ChapterInfo;
+
+// 저장 한 번에 알림이 여러 개 오므로 이 시간 동안 더 오는 것은 하나로 침
+const SETTLE: Duration = Duration::from_millis(200);
+
+pub fn source_path(info: &ChapterInfo) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(format!("{}.rs", info.module()))
+}
+
+// 이 파일이 바뀐 알림인지 - 내용이 바뀌지 않는 접근(읽기) 알림은 제외
+pub fn touches(event: &Event, file: &Path) -> bool {
+    !matches!(event.kind, EventKind::Access(_)) && event.paths.iter().any(|p| p.file_name() == file.file_name())
+}
+
+// cargo run --quiet -- --no-pager <target> - 출력은 그대로 터미널로
+pub fn run_once(target: &str) -> io::Result<ExitStatus> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    Command::new(env!("CARGO"))
+        // 출력이 길어도 페이저에서 멈추지 않고 다음 저장을 기다리도록
+        .args(["run", "--quiet", "--", "--no-pager", target])
+        .current_dir(root)
+        .env("CARGO_TARGET_DIR", root.join("target").join("watch"))
+        .status()
+}
+
+// 알림이 SETTLE 동안 끊길 때까지 기다리며 그 사이에 file 이 바뀌었는지
+fn wait_for_change(events: &Receiver<notify::Result<Event>>, file: &Path) -> io::Result<()> {
+    loop {
+        let event = events.recv().map_err(|_| io::Error::other("감시가 끝났습니다"))?;
+        if !matches!(event, Ok(ref e) if touches(e, file)) {
+            continue;
+        }
+        while events.recv_timeout(SETTLE).is_ok() {}
+        return Ok(());
+    }
+}
+
+// target: "11" 또는 "07:trait_objects" - Ctrl+C 로 끝날 때까지
+pub fn run(info: &ChapterInfo, target: &str) -> io::Result<()> {
+    let file = source_path(info);
+    let dir = file.parent().unwrap_or(Path::new("."));
+    let (tx, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(io::Error::other)?;
+    watcher.watch(dir, RecursiveMode::NonRecursive).map_err(io::Error::other)?;
+
+    eprintln!("{} 를 감시합니다 - 저장하면 cargo run -- {} 을 다시 실행 (Ctrl+C 로 종료)", file.display(), target);
+    loop {
+        let status = run_once(target)?;
+        if !status.success() {
+            eprintln!("\n실행 실패 ({}) - 고치고 저장하면 다시 실행합니다", status);
+        }
+        wait_for_change(&events, &file)?;
+        eprintln!("\n--- {} 변경됨, 다시 실행 ---\n", file.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, ModifyKind};
+    use rust_study::chapters;
+
+    #[test]
+    fn watches_the_chapter_source() {
+        let file = source_path(chapters::find("11").unwrap());
+        assert!(file.ends_with("src/_11_iterators.rs"));
+        assert!(file.exists());
+
+        let event = |kind, path: &Path| Event::new(kind).add_path(path.to_path_buf());
+        let modify = EventKind::Modify(ModifyKind::Any);
+        assert!(touches(&event(modify, &file), &file));
+        // 편집기가 임시 파일을 만들었다가 이름을 바꿔도 같은 이름이면 변경
+        assert!(touches(&event(EventKind::Create(notify::event::CreateKind::File), &file.with_file_name("_11_iterators.rs")), &file));
+        assert!(!touches(&event(modify, &file.with_file_name("_12_smart_pointers.rs")), &file));
+        assert!(!touches(&event(EventKind::Access(AccessKind::Any), &file), &file));
+    }
+}
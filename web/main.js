@@ -0,0 +1,65 @@
+// 주소의 #07 → 7장 레슨, #07/quiz → 7장 퀴즈
+import init, { chapter_list, lesson_html, quiz, grade } from './pkg/rust_study_web.js';
+
+const main = document.getElementById('main');
+
+function show() {
+  const [id, page] = location.hash.slice(1).split('/');
+  if (!id) {
+    main.innerHTML = '<h1>Rust 학습</h1><p>왼쪽에서 장을 고르세요.</p>';
+    return;
+  }
+  try {
+    if (page === 'quiz') {
+      showQuiz(id);
+    } else {
+      main.innerHTML = `<p><a href="#${id}/quiz">퀴즈 풀기</a></p>` + lesson_html(id);
+    }
+  } catch (e) {
+    main.textContent = e;
+  }
+  window.scrollTo(0, 0);
+}
+
+function showQuiz(id) {
+  const questions = JSON.parse(quiz(id));
+  let correct = 0;
+  let answered = 0;
+  main.innerHTML = `<h1>${id}장 퀴즈</h1><p><a href="#${id}">레슨으로</a></p><p id="score"></p>`;
+  for (const q of questions) {
+    const box = document.createElement('div');
+    box.className = 'question';
+    const prompt = document.createElement('p');
+    prompt.textContent = q.prompt;
+    box.append(prompt);
+    const result = document.createElement('p');
+    q.choices.forEach((choice, i) => {
+      const button = document.createElement('button');
+      button.textContent = `${i + 1}) ${choice}`;
+      button.onclick = () => {
+        const r = JSON.parse(grade(q.id, i));
+        answered += 1;
+        if (r.correct) correct += 1;
+        result.className = r.correct ? 'right' : 'wrong';
+        result.textContent = (r.correct ? '정답!' : `오답 - 정답: ${r.answer + 1}) ${q.choices[r.answer]}`)
+          + (r.explanation ? ` ${r.explanation}` : '');
+        box.querySelectorAll('button').forEach((b) => { b.disabled = true; });
+        document.getElementById('score').textContent = `점수: ${correct}/${answered} (전체 ${questions.length}문제)`;
+      };
+      box.append(button, document.createElement('br'));
+    });
+    box.append(result);
+    main.append(box);
+  }
+}
+
+await init();
+const nav = document.getElementById('chapters');
+for (const c of JSON.parse(chapter_list())) {
+  const a = document.createElement('a');
+  a.href = `#${c.id}`;
+  a.textContent = c.title;
+  nav.append(a);
+}
+window.addEventListener('hashchange', show);
+show();
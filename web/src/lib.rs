@@ -0,0 +1,136 @@
+// ============================================================================
+// 브라우저 프런트엔드 (wasm-bindgen)
+// ============================================================================
+// 서버 없이 브라우저 안에서 장 목록, 레슨(output::HtmlSink), 퀴즈 출제와 채점
+// 빌드와 실행 방법은 web/README.md
+//
+// JS 와는 JSON 문자열로 주고받음 - 함수마다 안쪽 구현(_json, _body)은
+// 보통 Rust 함수라서 네이티브 cargo test 로 검사
+// 레슨 코드 실행은 스레드와 표준 출력이 필요해 네이티브에서만 (cargo run -- serve)
+// ============================================================================
+
+use std::sync::OnceLock;
+
+use rust_study::chapters;
+use rust_study::content::{ContentBank, ContentSource};
+use rust_study::output::{self, HtmlSink};
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+// 라이브러리에 내장된 content/ 를 처음 쓸 때 한 번만 파싱하고 검증
+fn bank() -> Result<&'static ContentBank, String> {
+    static BANK: OnceLock<Result<ContentBank, String>> = OnceLock::new();
+    BANK.get_or_init(|| ContentSource::Embedded.load().map_err(|e| e.to_string()))
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+fn js_err(message: String) -> JsValue {
+    JsValue::from_str(&message)
+}
+
+// [{"id": "01", "title": "..."}, ...]
+#[wasm_bindgen]
+pub fn chapter_list() -> String {
+    let list: Vec<_> = chapters::CHAPTERS
+        .iter()
+        .map(|c| json!({ "id": c.id, "title": c.title }))
+        .collect();
+    serde_json::Value::from(list).to_string()
+}
+
+fn lesson_body(key: &str) -> Result<String, String> {
+    let info = chapters::find(key).ok_or_else(|| format!("없는 장: {}", key))?;
+    let mut html = HtmlSink::new();
+    output::render_lesson(info, &mut html).map_err(|e| e.to_string())?;
+    Ok(html.into_parts().1)
+}
+
+// 장 하나를 HTML 조각으로 - 페이지의 <main> 에 넣음
+#[wasm_bindgen]
+pub fn lesson_html(key: &str) -> Result<String, JsValue> {
+    lesson_body(key).map_err(js_err)
+}
+
+fn quiz_json(key: &str) -> Result<String, String> {
+    let info = chapters::find(key).ok_or_else(|| format!("없는 장: {}", key))?;
+    let chapter = bank()?
+        .chapter(info.id)
+        .ok_or_else(|| format!("{}장에는 아직 퀴즈가 없습니다", info.id))?;
+    // 정답과 설명은 grade 에서만 - 페이지 소스로 답을 미리 보지 않도록
+    let questions: Vec<_> = chapter
+        .questions
+        .iter()
+        .map(|q| json!({ "id": q.id, "prompt": q.prompt, "choices": q.choices, "hint": q.hint }))
+        .collect();
+    Ok(serde_json::Value::from(questions).to_string())
+}
+
+// [{"id", "prompt", "choices", "hint"}, ...]
+#[wasm_bindgen]
+pub fn quiz(key: &str) -> Result<String, JsValue> {
+    quiz_json(key).map_err(js_err)
+}
+
+fn grade_json(question_id: &str, picked: usize) -> Result<String, String> {
+    let q = bank()?
+        .questions()
+        .find(|q| q.id == question_id)
+        .ok_or_else(|| format!("없는 문제: {}", question_id))?;
+    Ok(json!({
+        "correct": picked == q.answer,
+        "answer": q.answer,
+        "explanation": q.explanation,
+    })
+    .to_string())
+}
+
+// {"correct", "answer", "explanation"} - picked 는 0부터
+#[wasm_bindgen]
+pub fn grade(question_id: &str, picked: usize) -> Result<String, JsValue> {
+    grade_json(question_id, picked).map_err(js_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn embedded_content_loads() {
+        assert!(bank().unwrap().chapter("07").is_some());
+    }
+
+    #[test]
+    fn chapter_list_and_lessons() {
+        let list: Value = serde_json::from_str(&chapter_list()).unwrap();
+        assert_eq!(list.as_array().unwrap().len(), chapters::CHAPTERS.len());
+        assert_eq!(list[0]["id"], "01");
+
+        let body = lesson_body("traits").unwrap();
+        assert!(body.starts_with("<h1>07. "));
+        assert!(body.contains("<div class=\"comparison\">"));
+        assert!(lesson_body("99").is_err());
+    }
+
+    #[test]
+    fn quiz_hides_answers_until_graded() {
+        let questions: Value = serde_json::from_str(&quiz_json("02").unwrap()).unwrap();
+        let first = &questions[0];
+        assert!(first.get("answer").is_none());
+
+        let id = first["id"].as_str().unwrap();
+        let answer = bank()
+            .unwrap()
+            .questions()
+            .find(|q| q.id == id)
+            .unwrap()
+            .answer;
+        let right: Value = serde_json::from_str(&grade_json(id, answer).unwrap()).unwrap();
+        assert_eq!(right["correct"], true);
+        let wrong: Value = serde_json::from_str(&grade_json(id, answer + 1).unwrap()).unwrap();
+        assert_eq!(wrong["correct"], false);
+        assert_eq!(wrong["answer"], answer);
+        assert!(grade_json("nope", 0).is_err());
+    }
+}
@@ -0,0 +1,683 @@
+// ============================================================================
+// xtask - 저장소 관리 명령
+// ============================================================================
+// cargo xtask new-lesson 21 "Serde"                   새 장 모듈 생성
+// cargo xtask new-lesson 21 "직렬화" --slug serde      제목이 한글이면 모듈 이름 지정
+// cargo xtask new-lesson 21 Serde --sections derive,json   절 이름 지정 (기본: basics)
+// cargo xtask new-lesson 21 Serde --level advanced --prereqs 07,09   난이도(기본: intermediate)와 먼저 볼 장
+// cargo xtask capi-header                              capi/include/study_capi.h 생성 (--check: 최신인지만)
+//
+// 새 장을 추가할 때 손으로 맞추던 규칙을 자동으로 지킴
+// - src/_NN_<이름>.rs : 표준 머리 주석, SECTIONS, Chapter 구현, 절 머리와 진입 함수, 테스트 모듈
+//                      (mod 선언과 실행 목록은 build.rs 가 이 파일에서 생성)
+// - src/chapters.rs   : CHAPTERS 표와 source() 의 include_str!, --prereqs 를 주면 PREREQUISITES
+// - src/levels.rs     : CHAPTER_LEVELS 의 장 난이도
+// - content/NN_<이름>.toml : 퀴즈/연습문제 파일
+// ============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+type Result<T> = std::result::Result<T, String>;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("new-lesson") => new_lesson(&args[1..]),
+        Some("capi-header") => capi_header(&args[1..]),
+        None | Some("help" | "--help" | "-h") => {
+            print_help();
+            Ok(())
+        }
+        Some(other) => Err(format!(
+            "알 수 없는 명령: '{}' (cargo xtask help 참고)",
+            other
+        )),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("에러: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn print_help() {
+    println!("사용법: cargo xtask <명령>");
+    println!();
+    println!("  new-lesson <번호> <제목> [--slug 이름] [--sections a,b] [--level 난이도] [--prereqs 07,09]");
+    println!(
+        "                  새 장 모듈과 장 목록, 난이도, 콘텐츠 파일 생성 (예: new-lesson 21 \"Serde\")"
+    );
+    println!("  capi-header [--check]");
+    println!("                  capi/src/lib.rs 에서 capi/include/study_capi.h 생성 (--check 는 최신인지만 확인)");
+    println!("  help            이 도움말");
+}
+
+// 저장소 루트 - xtask/ 의 상위
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask 는 저장소 안에 있음")
+        .to_path_buf()
+}
+
+// ----------------------------------------------------------------------------
+// new-lesson
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+struct Lesson {
+    id: String,    // "21"
+    slug: String,  // "serde"
+    title: String, // "Serde"
+    sections: Vec<String>,
+    level: String,              // "Intermediate" - levels.rs 의 Level 변형 이름
+    prerequisites: Vec<String>, // ["07", "09"] - 비어 있으면 PREREQUISITES 에 넣지 않음
+}
+
+impl Lesson {
+    fn module(&self) -> String {
+        format!("_{}_{}", self.id, self.slug)
+    }
+}
+
+fn new_lesson(args: &[String]) -> Result<()> {
+    let lesson = parse_lesson(args)?;
+    let root = root();
+
+    let module_path = root.join("src").join(format!("{}.rs", lesson.module()));
+    let content_path = root
+        .join("content")
+        .join(format!("{}_{}.toml", lesson.id, lesson.slug));
+    let i18n_path = root
+        .join("content/i18n/en")
+        .join(format!("{}_{}.toml", lesson.id, lesson.slug));
+
+    let chapters_path = root.join("src/chapters.rs");
+    let chapters_rs = read(&chapters_path)?;
+    check_unused(&lesson, &chapters_rs)?;
+    check_prerequisites(&lesson, &chapters_rs)?;
+    let levels_path = root.join("src/levels.rs");
+    let levels_rs = read(&levels_path)?;
+    if let Some(path) = [&module_path, &content_path, &i18n_path].into_iter().find(|p| p.exists()) {
+        return Err(format!("{} 가 이미 있습니다", path.display()));
+    }
+
+    // 모두 만든 뒤에 씀 - 중간에 실패해도 일부만 바뀌지 않도록
+    let chapters_rs = register_in_chapters(&chapters_rs, &lesson)?;
+    let levels_rs = register_in_levels(&levels_rs, &lesson)?;
+    write(&module_path, &module_source(&lesson))?;
+    write(&content_path, &content_stub(&lesson))?;
+    write(&i18n_path, &i18n_stub(&lesson))?;
+    write(&chapters_path, &chapters_rs)?;
+    write(&levels_path, &levels_rs)?;
+
+    println!("생성: src/{}.rs", lesson.module());
+    println!("생성: content/{}_{}.toml", lesson.id, lesson.slug);
+    println!("생성: content/i18n/en/{}_{}.toml", lesson.id, lesson.slug);
+    println!("수정: src/chapters.rs, src/levels.rs");
+    println!();
+    let mut steps = vec![
+        format!("src/{}.rs 의 TODO 를 채우기", lesson.module()),
+        format!(
+            "content/{}_{}.toml 에 문제와 연습문제",
+            lesson.id, lesson.slug
+        ),
+        format!(
+            "content/i18n/en/{}_{}.toml 의 lines 에 장 출력의 영어 문자열 (--lang en)",
+            lesson.id, lesson.slug
+        ),
+        "기본과 난이도가 다른 절은 src/levels.rs 의 SECTION_LEVELS 에".to_string(),
+    ];
+    if lesson.prerequisites.is_empty() {
+        steps.push(
+            "먼저 볼 장이 있으면 src/chapters.rs 의 PREREQUISITES 에 (--prereqs 로도 생성)"
+                .to_string(),
+        );
+    }
+    steps.push("cargo run -- examples sync   (절마다 examples/ 생성)".to_string());
+    steps.push("cargo run -- check && cargo test".to_string());
+    println!("다음 단계 (손으로):");
+    for (i, step) in steps.iter().enumerate() {
+        println!("  {}. {}", i + 1, step);
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn parse_lesson(args: &[String]) -> Result<Lesson> {
+    let usage = "사용법: cargo xtask new-lesson <번호> <제목> [--slug 이름] [--sections a,b]";
+    let (number, title) = match args {
+        [number, title, ..] => (number, title.trim()),
+        _ => return Err(usage.to_string()),
+    };
+    let number: u32 = number
+        .parse()
+        .map_err(|_| format!("장 번호는 숫자여야 합니다: {}", number))?;
+    if !(1..100).contains(&number) || title.is_empty() {
+        return Err(usage.to_string());
+    }
+
+    let mut slug = None;
+    let mut sections = vec!["basics".to_string()];
+    let mut level = "intermediate".to_string();
+    let mut prerequisites = Vec::new();
+    let mut rest = args[2..].iter();
+    while let Some(flag) = rest.next() {
+        let value = rest
+            .next()
+            .ok_or_else(|| format!("{} 뒤에 값을 지정하세요", flag))?;
+        match flag.as_str() {
+            "--slug" => slug = Some(value.clone()),
+            "--sections" => sections = value.split(',').map(|s| s.trim().to_string()).collect(),
+            "--level" => level = value.clone(),
+            "--prereqs" => {
+                prerequisites = value
+                    .split(',')
+                    .map(|id| match id.trim().parse::<u32>() {
+                        Ok(n) => Ok(format!("{:02}", n)),
+                        Err(_) => Err(format!("장 번호는 숫자여야 합니다: {}", id)),
+                    })
+                    .collect::<Result<_>>()?
+            }
+            other => return Err(format!("알 수 없는 옵션: {}", other)),
+        }
+    }
+
+    let slug = match slug {
+        Some(slug) => slug,
+        None => slugify(title).ok_or_else(|| {
+            format!(
+                "제목 '{}' 으로 모듈 이름을 만들 수 없습니다 - --slug 로 지정하세요",
+                title
+            )
+        })?,
+    };
+    for name in std::iter::once(&slug).chain(&sections) {
+        if !is_snake_case(name) {
+            return Err(format!("'{}' 는 snake_case 가 아닙니다", name));
+        }
+    }
+    let level = match level.as_str() {
+        "beginner" => "Beginner",
+        "intermediate" => "Intermediate",
+        "advanced" => "Advanced",
+        other => {
+            return Err(format!(
+                "난이도는 beginner, intermediate, advanced 중 하나: {}",
+                other
+            ))
+        }
+    };
+    Ok(Lesson {
+        id: format!("{:02}", number),
+        slug,
+        title: title.to_string(),
+        sections,
+        level: level.to_string(),
+        prerequisites,
+    })
+}
+
+// "Error Handling" → "error_handling", 영문/숫자가 아닌 글자가 있으면 None
+fn slugify(title: &str) -> Option<String> {
+    let mut slug = String::new();
+    for c in title.chars() {
+        match c {
+            'a'..='z' | '0'..='9' => slug.push(c),
+            'A'..='Z' => slug.push(c.to_ascii_lowercase()),
+            ' ' | '-' | '_' if !slug.is_empty() && !slug.ends_with('_') => slug.push('_'),
+            ' ' | '-' | '_' => {}
+            _ => return None,
+        }
+    }
+    let slug = slug.trim_end_matches('_').to_string();
+    is_snake_case(&slug).then_some(slug)
+}
+
+fn is_snake_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+// 장 목록 줄 - ChapterInfo { id: "07", slug: "traits", title: "..." },
+fn chapter_entries(chapters_rs: &str) -> Vec<(String, String)> {
+    chapters_rs
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix("ChapterInfo { id: \"")?;
+            let (id, rest) = rest.split_once('"')?;
+            let rest = rest.split_once("slug: \"")?.1;
+            let (slug, _) = rest.split_once('"')?;
+            Some((id.to_string(), slug.to_string()))
+        })
+        .collect()
+}
+
+fn check_unused(lesson: &Lesson, chapters_rs: &str) -> Result<()> {
+    for (id, slug) in chapter_entries(chapters_rs) {
+        if id == lesson.id {
+            return Err(format!("{}장은 이미 있습니다 ({})", id, slug));
+        }
+        if slug == lesson.slug {
+            return Err(format!("'{}' 는 {}장이 이미 쓰고 있습니다", slug, id));
+        }
+    }
+    Ok(())
+}
+
+// 먼저 볼 장은 이미 있는 앞 번호의 장이어야 함
+fn check_prerequisites(lesson: &Lesson, chapters_rs: &str) -> Result<()> {
+    let known = chapter_entries(chapters_rs);
+    for id in &lesson.prerequisites {
+        if !known.iter().any(|(known_id, _)| known_id == id) || *id >= lesson.id {
+            return Err(format!(
+                "--prereqs 의 {}장은 {}장보다 앞에 있는 장이어야 합니다",
+                id, lesson.id
+            ));
+        }
+    }
+    Ok(())
+}
+
+// 표의 장 줄 `    ("07", ...),` 의 번호 - "04::static_lifetime" 같은 절 줄은 제외
+fn table_id(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("    (\"")?;
+    let (id, _) = rest.split_once('"')?;
+    (!id.is_empty() && id.bytes().all(|b| b.is_ascii_digit())).then(|| id.to_string())
+}
+
+// pattern 으로 시작하는 줄 중 번호가 새 장보다 작은 마지막 줄 뒤에 삽입 - 번호 순서 유지
+fn insert_in_order(
+    text: &str,
+    lesson: &Lesson,
+    number_of: impl Fn(&str) -> Option<String>,
+    new_line: &str,
+) -> Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let matches: Vec<(usize, String)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| number_of(line).map(|n| (i, n)))
+        .collect();
+    let Some(&(last_match, _)) = matches.last() else {
+        return Err(format!(
+            "'{}' 를 넣을 위치를 찾지 못했습니다",
+            new_line.trim()
+        ));
+    };
+    let at = matches
+        .iter()
+        .rfind(|(_, n)| n.as_str() < lesson.id.as_str())
+        .map(|(i, _)| i + 1)
+        .unwrap_or(matches[0].0)
+        .min(last_match + 1);
+
+    let mut out: Vec<&str> = lines[..at].to_vec();
+    out.push(new_line);
+    out.extend_from_slice(&lines[at..]);
+    Ok(out.join("\n") + "\n")
+}
+
+fn register_in_chapters(chapters_rs: &str, lesson: &Lesson) -> Result<String> {
+    let text = insert_in_order(
+        chapters_rs,
+        lesson,
+        |line| {
+            let rest = line.strip_prefix("    ChapterInfo { id: \"")?;
+            Some(rest.split_once('"')?.0.to_string())
+        },
+        &format!(
+            "    ChapterInfo {{ id: \"{}\", slug: \"{}\", title: \"{}\" }},",
+            lesson.id,
+            lesson.slug,
+            lesson.title.replace('"', "\\\"")
+        ),
+    )?;
+    let text = insert_in_order(
+        &text,
+        lesson,
+        |line| {
+            let rest = line.strip_prefix("        \"")?;
+            rest.contains("=> include_str!(")
+                .then(|| rest.split_once('"').map(|(id, _)| id.to_string()))?
+        },
+        &format!(
+            "        \"{}\" => include_str!(\"{}.rs\"),",
+            lesson.id,
+            lesson.module()
+        ),
+    )?;
+    if lesson.prerequisites.is_empty() {
+        return Ok(text);
+    }
+    let quoted: Vec<String> = lesson
+        .prerequisites
+        .iter()
+        .map(|id| format!("\"{}\"", id))
+        .collect();
+    insert_in_order(
+        &text,
+        lesson,
+        |line| table_id(line).filter(|_| line.contains("&[")),
+        &format!("    (\"{}\", &[{}]),", lesson.id, quoted.join(", ")),
+    )
+}
+
+// CHAPTER_LEVELS 에 장의 기본 난이도 - SECTION_LEVELS 의 "장::절" 줄과는 번호 모양으로 구분
+fn register_in_levels(levels_rs: &str, lesson: &Lesson) -> Result<String> {
+    insert_in_order(
+        levels_rs,
+        lesson,
+        table_id,
+        &format!("    (\"{}\", {}),", lesson.id, lesson.level),
+    )
+}
+
+const RULE: &str =
+    "// ----------------------------------------------------------------------------";
+const BANNER: &str =
+    "// ============================================================================";
+
+fn module_source(lesson: &Lesson) -> String {
+    let mut s = String::new();
+    s.push_str(&format!(
+        "{}\n// {}. {}\n{}\n",
+        BANNER, lesson.id, lesson.title, BANNER
+    ));
+    s.push_str("// C++20과의 핵심 차이점:\n");
+    s.push_str("// 1. TODO\n");
+    s.push_str(&format!("{}\n\n", BANNER));
+
+    s.push_str(&format!(
+        "// 절 목록 (실행 순서) - cargo run -- {}:{} 처럼 절 하나만 실행할 때도 사용\n",
+        lesson.id, lesson.sections[0]
+    ));
+    s.push_str("pub const SECTIONS: &[(&str, fn())] = &[\n");
+    for section in &lesson.sections {
+        s.push_str(&format!("    (\"{}\", {}),\n", section, section));
+    }
+    s.push_str("];\n\n");
+
+    s.push_str("// 이 장 - main 의 장 목록에 등록\npub struct Lesson;\n\n");
+    s.push_str("impl crate::chapter::Chapter for Lesson {\n");
+    s.push_str(&format!(
+        "    fn id(&self) -> &'static str {{\n        \"{}\"\n    }}\n\n",
+        lesson.id
+    ));
+    s.push_str(&format!(
+        "    fn name(&self) -> &'static str {{\n        \"{}\"\n    }}\n\n",
+        lesson.title.replace('"', "\\\"")
+    ));
+    s.push_str("    fn description(&self) -> &'static str {\n        \"TODO: 한 줄 설명\"\n    }\n\n");
+    s.push_str("    fn topics(&self) -> &'static [&'static str] {\n        &[\"TODO\"]\n    }\n\n");
+    s.push_str("    fn sections(&self) -> &'static [(&'static str, fn())] {\n        SECTIONS\n    }\n}\n");
+
+    for section in &lesson.sections {
+        s.push_str(&format!("\n{}\n// {}\n{}\n\n", RULE, section, RULE));
+        s.push_str(&format!("fn {}() {{\n", section));
+        s.push_str(&format!("    println!(\"--- {} ---\");\n\n", section));
+        s.push_str("    // C++ 에서는:\n    // TODO: 대응하는 C++ 코드\n\n");
+        s.push_str("    // TODO: Rust 예제\n");
+        s.push_str("}\n");
+    }
+
+    // 처음부터 테스트 하나 - 절 도우미를 만들면 그 반환값을 assert_eq! 로 검사하는 테스트를 옆에 더함
+    s.push_str("\n#[cfg(test)]\nmod tests {\n    use super::*;\n\n");
+    s.push_str("    // 절은 cargo run -- NN:이름 으로 고르므로 이름이 겹치면 안 됨\n");
+    s.push_str("    #[test]\n    fn sections_are_listed_once() {\n");
+    s.push_str("        assert!(!SECTIONS.is_empty());\n");
+    s.push_str("        let mut names: Vec<&str> = SECTIONS.iter().map(|(name, _)| *name).collect();\n");
+    s.push_str("        names.sort();\n        names.dedup();\n");
+    s.push_str("        assert_eq!(names.len(), SECTIONS.len());\n    }\n}\n");
+    s
+}
+
+// 문제와 연습문제는 비어 있는 채로 - cargo run -- check 를 통과하는 최소 파일
+fn content_stub(lesson: &Lesson) -> String {
+    format!(
+        "# {}. {} - 퀴즈와 연습문제 (형식은 content/README.md 참고)\n\
+         chapter = \"{}\"\n\n\
+         # [[questions]]\n\
+         # id = \"{}-\"\n\n\
+         # [[exercises]]\n\
+         # id = \"{}-\"\n",
+        lesson.id, lesson.title, lesson.id, lesson.id, lesson.id
+    )
+}
+
+// 영어 문자열 표 - 빈 표로 시작, 장 출력이 정해지면 [[lines]] 를 더함 (다른 장의 표와 같은 머리)
+fn i18n_stub(lesson: &Lesson) -> String {
+    format!(
+        "# {}. {} - 장 출력의 영어 문자열 (cargo run -- --lang en {})\n\
+         # ko 는 소스의 println!/print! 서식 문자열 그대로, {{}} 자리는 같은 순서로 (src/i18n.rs 참고)\n\n\
+         lines = []\n",
+        lesson.id, lesson.title, lesson.id
+    )
+}
+
+// ----------------------------------------------------------------------------
+// capi-header
+// ----------------------------------------------------------------------------
+// 45장 C 라이브러리의 헤더를 capi/src/lib.rs 의 extern "C" 선언에서 생성 (cbindgen)
+// 빌드가 소스 트리에 쓰지 않도록 build.rs 대신 명시적인 명령으로 - 헤더는 커밋해 두고 C/C++ 는 include 만
+// lib.rs 를 고치고 다시 생성하지 않으면 테스트(capi_header_is_up_to_date)가 알려줌
+
+const CAPI_HEADER: &str = "capi/include/study_capi.h";
+
+fn capi_header(args: &[String]) -> Result<()> {
+    let check = match args {
+        [] => false,
+        [flag] if flag == "--check" => true,
+        _ => return Err("사용법: cargo xtask capi-header [--check]".to_string()),
+    };
+    let path = root().join(CAPI_HEADER);
+    let header = generate_capi_header()?;
+    if check {
+        if read(&path)? != header {
+            return Err(format!("{} 가 capi/src/lib.rs 와 다릅니다 - cargo xtask capi-header", CAPI_HEADER));
+        }
+        println!("{} 최신", CAPI_HEADER);
+        return Ok(());
+    }
+    write(&path, &header)?;
+    println!("생성: {}", CAPI_HEADER);
+    Ok(())
+}
+
+fn generate_capi_header() -> Result<String> {
+    let dir = root().join("capi");
+    let config = cbindgen::Config::from_file(dir.join("cbindgen.toml"))?;
+    let bindings = cbindgen::generate_with_config(&dir, config).map_err(|e| format!("cbindgen: {}", e))?;
+    let mut out = Vec::new();
+    bindings.write(&mut out);
+    String::from_utf8(out).map_err(|e| format!("cbindgen 출력이 UTF-8 이 아님: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    // 실제 장과 겹치지 않는 번호와 이름
+    fn demo() -> Lesson {
+        parse_lesson(&args(&["99", "Scaffold Demo", "--sections", "derive,json"])).unwrap()
+    }
+
+    #[test]
+    fn parses_arguments_and_enforces_names() {
+        let lesson = demo();
+        assert_eq!(lesson.id, "99");
+        assert_eq!(lesson.module(), "_99_scaffold_demo");
+        assert_eq!(lesson.sections, vec!["derive", "json"]);
+
+        assert_eq!(slugify("Error Handling").as_deref(), Some("error_handling"));
+        assert!(parse_lesson(&args(&["21", "직렬화"])).is_err());
+        assert_eq!(
+            parse_lesson(&args(&["7", "직렬화", "--slug", "serde"]))
+                .unwrap()
+                .id,
+            "07"
+        );
+        assert!(parse_lesson(&args(&["21", "X", "--slug", "Bad-Name"])).is_err());
+        assert!(parse_lesson(&args(&["abc", "Serde"])).is_err());
+    }
+
+    #[test]
+    fn existing_ids_and_slugs_are_rejected() {
+        let chapters_rs = read(&root().join("src/chapters.rs")).unwrap();
+        assert!(check_unused(&demo(), &chapters_rs).is_ok());
+        let taken = parse_lesson(&args(&["07", "Other"])).unwrap();
+        assert!(check_unused(&taken, &chapters_rs).is_err());
+        let slug = parse_lesson(&args(&["50", "Traits"])).unwrap();
+        assert!(check_unused(&slug, &chapters_rs).is_err());
+    }
+
+    #[test]
+    fn registers_in_number_order() {
+        let chapters_rs = "pub const CHAPTERS: &[ChapterInfo] = &[
+    ChapterInfo { id: \"01\", slug: \"a\", title: \"A\" },
+    ChapterInfo { id: \"30\", slug: \"b\", title: \"B\" },
+];
+
+pub fn source(id: &str) -> Option<&'static str> {
+    Some(match id {
+        \"01\" => include_str!(\"_01_a.rs\"),
+        \"30\" => include_str!(\"_30_b.rs\"),
+        _ => return None,
+    })
+}
+";
+        let lesson = parse_lesson(&args(&["21", "Serde"])).unwrap();
+        let out = register_in_chapters(chapters_rs, &lesson).unwrap();
+        assert_eq!(
+            out,
+            "pub const CHAPTERS: &[ChapterInfo] = &[
+    ChapterInfo { id: \"01\", slug: \"a\", title: \"A\" },
+    ChapterInfo { id: \"21\", slug: \"serde\", title: \"Serde\" },
+    ChapterInfo { id: \"30\", slug: \"b\", title: \"B\" },
+];
+
+pub fn source(id: &str) -> Option<&'static str> {
+    Some(match id {
+        \"01\" => include_str!(\"_01_a.rs\"),
+        \"21\" => include_str!(\"_21_serde.rs\"),
+        \"30\" => include_str!(\"_30_b.rs\"),
+        _ => return None,
+    })
+}
+"
+        );
+    }
+
+    #[test]
+    fn registers_level_and_prerequisites() {
+        let lesson = parse_lesson(&args(&[
+            "21",
+            "Serde",
+            "--level",
+            "advanced",
+            "--prereqs",
+            "1,30",
+        ]))
+        .unwrap();
+        assert_eq!(
+            (lesson.level.as_str(), lesson.prerequisites.clone()),
+            ("Advanced", vec!["01".to_string(), "30".to_string()])
+        );
+        assert!(parse_lesson(&args(&["21", "Serde", "--level", "expert"])).is_err());
+
+        let levels_rs = "pub const CHAPTER_LEVELS: &[(&str, Level)] = &[
+    (\"01\", Beginner),
+    (\"30\", Advanced),
+];
+
+pub const SECTION_LEVELS: &[(&str, Level)] = &[
+    (\"01::intro\", Beginner),
+];
+";
+        let out = register_in_levels(levels_rs, &lesson).unwrap();
+        assert!(out.contains(
+            "    (\"01\", Beginner),\n    (\"21\", Advanced),\n    (\"30\", Advanced),\n];"
+        ));
+        assert!(out.ends_with("    (\"01::intro\", Beginner),\n];\n"));
+
+        let chapters_rs = "pub const PREREQUISITES: &[(&str, &[&str])] = &[
+    (\"02\", &[\"01\"]),
+    (\"30\", &[\"02\"]),
+];
+";
+        let out = insert_in_order(
+            chapters_rs,
+            &lesson,
+            |line| table_id(line).filter(|_| line.contains("&[")),
+            "    (\"21\", &[\"01\", \"30\"]),",
+        )
+        .unwrap();
+        assert!(out.contains("(\"02\", &[\"01\"]),\n    (\"21\", &[\"01\", \"30\"]),\n    (\"30\""));
+
+        // 먼저 볼 장은 이미 있고 앞 번호여야 함
+        let real = read(&root().join("src/chapters.rs")).unwrap();
+        let later = parse_lesson(&args(&["98", "Later", "--prereqs", "07,11"])).unwrap();
+        assert!(check_prerequisites(&later, &real).is_ok());
+        let ahead = parse_lesson(&args(&["05", "Early", "--prereqs", "07"])).unwrap();
+        assert!(check_prerequisites(&ahead, &real).is_err());
+    }
+
+    #[test]
+    fn registers_in_the_real_tree() {
+        let chapters_rs = read(&root().join("src/chapters.rs")).unwrap();
+        let with_prereqs =
+            parse_lesson(&args(&["99", "Scaffold Demo", "--prereqs", "07"])).unwrap();
+        let out = register_in_chapters(&chapters_rs, &with_prereqs).unwrap();
+        assert!(out.contains("    (\"99\", &[\"07\"]),\n];"));
+        let levels_rs = read(&root().join("src/levels.rs")).unwrap();
+        let out = register_in_levels(&levels_rs, &demo()).unwrap();
+        assert!(out.contains("    (\"99\", Intermediate),\n];\n\n// 장의 기본과 다른 절"));
+        let out = register_in_chapters(&chapters_rs, &demo()).unwrap();
+        assert!(!out.contains("(\"99\", &["));
+        assert!(out.contains(
+            "    ChapterInfo { id: \"99\", slug: \"scaffold_demo\", title: \"Scaffold Demo\" },\n];"
+        ));
+        assert!(out.contains(
+            "        \"99\" => include_str!(\"_99_scaffold_demo.rs\"),\n        _ => return None,"
+        ));
+    }
+
+    #[test]
+    fn module_follows_chapter_layout() {
+        let source = module_source(&demo());
+        assert!(source.starts_with(&format!("{}\n// 99. Scaffold Demo\n", BANNER)));
+        assert!(source.contains("    (\"derive\", derive),\n    (\"json\", json),\n];"));
+        assert!(source.contains("impl crate::chapter::Chapter for Lesson {"));
+        assert!(source.contains(&format!("{}\n// json\n{}\n\nfn json() {{", RULE, RULE)));
+        assert!(source.contains("#[cfg(test)]\nmod tests {"));
+        assert!(source.contains("    #[test]\n    fn sections_are_listed_once() {"));
+        assert!(!source.contains("pub fn run()"));
+        assert!(content_stub(&demo()).contains("chapter = \"99\""));
+        let i18n = i18n_stub(&demo());
+        assert!(i18n.starts_with("# 99. Scaffold Demo - "));
+        assert!(i18n.ends_with("\nlines = []\n"));
+    }
+
+    #[test]
+    fn capi_header_is_up_to_date() {
+        let committed = read(&root().join(CAPI_HEADER)).unwrap();
+        assert!(committed == generate_capi_header().unwrap(), "cargo xtask capi-header 로 다시 생성");
+    }
+}